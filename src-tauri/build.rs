@@ -3,6 +3,7 @@ fn main() {
     build_apple_intelligence_bridge();
 
     generate_tray_translations();
+    generate_announcement_translations();
 
     stage_vc_runtime_dlls();
 
@@ -75,6 +76,27 @@ fn stage_vc_runtime_dlls() {
 /// Source of truth: src/i18n/locales/*/translation.json
 /// The English "tray" section defines the struct fields.
 fn generate_tray_translations() {
+    generate_locale_strings(&["tray"], "TrayStrings", "tray_translations.rs", "tray");
+}
+
+/// Generate screen reader announcement strings from frontend locale files.
+///
+/// Reuses the overlay labels ("overlay" section, string keys only) so spoken
+/// feedback matches the overlay, plus the "announcements" section for
+/// messages the overlay never shows.
+fn generate_announcement_translations() {
+    generate_locale_strings(
+        &["overlay", "announcements"],
+        "AnnouncementStrings",
+        "announcement_translations.rs",
+        "announcement",
+    );
+}
+
+/// Generate a `struct_name` with one String field per string key found in the
+/// English `sections`, and a `TRANSLATIONS` map with a value per locale.
+/// Keys missing from a locale are generated as empty strings.
+fn generate_locale_strings(sections: &[&str], struct_name: &str, file_name: &str, label: &str) {
     use std::collections::BTreeMap;
     use std::fs;
     use std::path::Path;
@@ -101,8 +123,18 @@ fn generate_tray_translations() {
         let content = fs::read_to_string(&json_path).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
 
-        if let Some(tray) = parsed.get("tray").cloned() {
-            translations.insert(lang, tray);
+        let mut merged = serde_json::Map::new();
+        for section in sections {
+            if let Some(values) = parsed.get(*section).and_then(|v| v.as_object()) {
+                for (key, value) in values {
+                    if value.is_string() {
+                        merged.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        if !merged.is_empty() {
+            translations.insert(lang, serde_json::Value::Object(merged));
         }
     }
 
@@ -119,22 +151,24 @@ fn generate_tray_translations() {
     );
 
     // Struct
-    out.push_str("#[derive(Debug, Clone)]\npub struct TrayStrings {\n");
+    out.push_str(&format!(
+        "#[derive(Debug, Clone)]\npub struct {struct_name} {{\n"
+    ));
     for (rust_field, _) in &fields {
         out.push_str(&format!("    pub {rust_field}: String,\n"));
     }
     out.push_str("}\n\n");
 
     // Static map
-    out.push_str(
-        "pub static TRANSLATIONS: Lazy<HashMap<&'static str, TrayStrings>> = Lazy::new(|| {\n",
-    );
+    out.push_str(&format!(
+        "pub static TRANSLATIONS: Lazy<HashMap<&'static str, {struct_name}>> = Lazy::new(|| {{\n"
+    ));
     out.push_str("    let mut m = HashMap::new();\n");
 
-    for (lang, tray) in &translations {
-        out.push_str(&format!("    m.insert(\"{lang}\", TrayStrings {{\n"));
+    for (lang, strings) in &translations {
+        out.push_str(&format!("    m.insert(\"{lang}\", {struct_name} {{\n"));
         for (rust_field, json_key) in &fields {
-            let val = tray.get(json_key).and_then(|v| v.as_str()).unwrap_or("");
+            let val = strings.get(json_key).and_then(|v| v.as_str()).unwrap_or("");
             out.push_str(&format!(
                 "        {rust_field}: \"{}\".to_string(),\n",
                 escape_string(val)
@@ -145,7 +179,7 @@ fn generate_tray_translations() {
 
     out.push_str("    m\n});\n");
 
-    let out_path = Path::new(&out_dir).join("tray_translations.rs");
+    let out_path = Path::new(&out_dir).join(file_name);
     let existing = fs::read_to_string(&out_path).ok();
     let changed = existing.as_deref() != Some(out.as_str());
 
@@ -153,7 +187,7 @@ fn generate_tray_translations() {
         fs::write(&out_path, out).unwrap();
 
         println!(
-            "cargo:warning=Generated tray translations: {} languages, {} fields",
+            "cargo:warning=Generated {label} translations: {} languages, {} fields",
             translations.len(),
             fields.len()
         );
@@ -0,0 +1,289 @@
+//! Screen reader announcements for recording state changes.
+//!
+//! Announcements are opt-in and hooked into the same places that drive the
+//! recording overlay, so screen reader users hear every transition sighted
+//! users see. Text reuses the overlay labels from the frontend locale files
+//! (generated by build.rs) and is handed to a background worker, so callers
+//! never wait on the platform accessibility APIs.
+//!
+//! - Windows: UI Automation notification events (Narrator, NVDA, JAWS)
+//! - macOS: NSAccessibility announcement requests (VoiceOver)
+//! - Other platforms: not supported, announcements are dropped
+
+use log::{debug, warn};
+use once_cell::sync::Lazy;
+use std::sync::mpsc::{self, Sender};
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::settings::{AppSettings, ScreenReaderVerbosity};
+
+#[allow(dead_code)]
+mod strings {
+    use once_cell::sync::Lazy;
+    use std::collections::HashMap;
+
+    // Auto-generated AnnouncementStrings struct and TRANSLATIONS static
+    include!(concat!(env!("OUT_DIR"), "/announcement_translations.rs"));
+}
+
+use strings::{AnnouncementStrings, TRANSLATIONS};
+
+struct Announcement {
+    app: AppHandle,
+    text: String,
+}
+
+static QUEUE: Lazy<Mutex<Option<Sender<Announcement>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Announces that recording has started.
+pub fn announce_recording_started(app: &AppHandle, settings: &AppSettings) {
+    announce(app, settings, ScreenReaderVerbosity::Minimal, |lang| {
+        localized(lang, |s| &s.recording_started)
+    });
+}
+
+/// Announces a processing stage using the same label as the overlay state
+/// (`transcribing`, `sending`, `thinking`, `finalizing`). Verbose only.
+pub fn announce_stage(app: &AppHandle, settings: &AppSettings, state: &str) {
+    announce(app, settings, ScreenReaderVerbosity::Verbose, |lang| {
+        stage_label(lang, state).unwrap_or_default()
+    });
+}
+
+/// Announces that the current operation was cancelled.
+pub fn announce_cancelled(app: &AppHandle, settings: &AppSettings) {
+    announce(app, settings, ScreenReaderVerbosity::Minimal, |lang| {
+        localized(lang, |s| &s.cancelled)
+    });
+}
+
+/// Announces a completed paste with its word count.
+pub fn announce_pasted(app: &AppHandle, settings: &AppSettings, text: &str) {
+    announce(app, settings, ScreenReaderVerbosity::Minimal, |lang| {
+        pasted_message(lang, text.split_whitespace().count())
+    });
+}
+
+/// Announces an error with the message shown on the error overlay.
+pub fn announce_error(app: &AppHandle, settings: &AppSettings, message: &str) {
+    announce(app, settings, ScreenReaderVerbosity::Minimal, |_| {
+        message.to_string()
+    });
+}
+
+fn announce(
+    app: &AppHandle,
+    settings: &AppSettings,
+    level: ScreenReaderVerbosity,
+    message: impl FnOnce(&str) -> String,
+) {
+    if !settings.screen_reader_announcements_enabled || settings.screen_reader_verbosity < level {
+        return;
+    }
+    let text = message(&settings.app_language);
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+    enqueue(Announcement {
+        app: app.clone(),
+        text: text.to_string(),
+    });
+}
+
+fn enqueue(announcement: Announcement) {
+    let mut queue = QUEUE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if queue.is_none() {
+        *queue = spawn_worker();
+    }
+    if let Some(sender) = queue.as_ref() {
+        if sender.send(announcement).is_err() {
+            // Worker exited; respawn on the next announcement.
+            *queue = None;
+        }
+    }
+}
+
+fn spawn_worker() -> Option<Sender<Announcement>> {
+    let (sender, receiver) = mpsc::channel::<Announcement>();
+    let spawned = std::thread::Builder::new()
+        .name("screen-reader-announcer".to_string())
+        .spawn(move || {
+            platform::init_thread();
+            while let Ok(announcement) = receiver.recv() {
+                debug!("Screen reader announcement: {}", announcement.text);
+                if let Err(e) = platform::announce(&announcement.app, &announcement.text) {
+                    warn!("Failed to send screen reader announcement: {}", e);
+                }
+            }
+        });
+    match spawned {
+        Ok(_) => Some(sender),
+        Err(e) => {
+            warn!("Failed to start screen reader announcer: {}", e);
+            None
+        }
+    }
+}
+
+fn language_code(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or("en")
+}
+
+/// Looks up a string for `locale`, falling back to English when the locale
+/// is unknown or has no translation for it yet.
+fn localized(locale: &str, field: fn(&AnnouncementStrings) -> &String) -> String {
+    TRANSLATIONS
+        .get(language_code(locale))
+        .map(field)
+        .filter(|value| !value.is_empty())
+        .or_else(|| TRANSLATIONS.get("en").map(field))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn stage_label(locale: &str, state: &str) -> Option<String> {
+    let field: fn(&AnnouncementStrings) -> &String = match state {
+        "transcribing" => |s| &s.transcribing,
+        "sending" => |s| &s.sending,
+        "thinking" => |s| &s.thinking,
+        "finalizing" => |s| &s.finalizing,
+        _ => return None,
+    };
+    // Overlay labels end with an ellipsis that some screen readers read aloud.
+    Some(
+        localized(locale, field)
+            .trim_end_matches(['.', '…'])
+            .to_string(),
+    )
+}
+
+fn pasted_message(locale: &str, words: usize) -> String {
+    if words == 1 {
+        localized(locale, |s| &s.pasted_one)
+    } else {
+        localized(locale, |s| &s.pasted).replace("{{count}}", &words.to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use tauri::{AppHandle, Manager};
+    use windows::core::BSTR;
+    use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
+    use windows::Win32::UI::Accessibility::{
+        NotificationKind_Other, NotificationProcessing_ImportantMostRecent,
+        UiaHostProviderFromHwnd, UiaRaiseNotificationEvent,
+    };
+
+    const ACTIVITY_ID: &str = "AivoRelay.StateChange";
+
+    pub fn init_thread() {
+        // If already initialized on this thread, this does nothing.
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        }
+    }
+
+    pub fn announce(app: &AppHandle, text: &str) -> Result<(), String> {
+        let window = app
+            .get_webview_window("main")
+            .ok_or("Main window not available")?;
+        let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+        unsafe {
+            let provider = UiaHostProviderFromHwnd(hwnd).map_err(|e| e.to_string())?;
+            UiaRaiseNotificationEvent(
+                &provider,
+                NotificationKind_Other,
+                NotificationProcessing_ImportantMostRecent,
+                &BSTR::from(text),
+                &BSTR::from(ACTIVITY_ID),
+            )
+            .map_err(|e| e.to_string())
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2::runtime::AnyObject;
+    use objc2::MainThreadMarker;
+    use objc2_app_kit::{
+        NSAccessibilityAnnouncementKey, NSAccessibilityAnnouncementRequestedNotification,
+        NSAccessibilityPostNotificationWithUserInfo, NSAccessibilityPriorityKey,
+        NSAccessibilityPriorityLevel, NSApplication,
+    };
+    use objc2_foundation::{NSDictionary, NSNumber, NSString};
+    use tauri::AppHandle;
+
+    pub fn init_thread() {}
+
+    pub fn announce(app: &AppHandle, text: &str) -> Result<(), String> {
+        let text = text.to_string();
+        // AppKit objects may only be touched on the main thread; posting is
+        // dispatched there without waiting for it.
+        app.run_on_main_thread(move || {
+            let Some(mtm) = MainThreadMarker::new() else {
+                return;
+            };
+            let application = NSApplication::sharedApplication(mtm);
+            let element: &AnyObject = (*application).as_ref();
+            let message = NSString::from_str(&text);
+            let priority = NSNumber::new_isize(NSAccessibilityPriorityLevel::High.0);
+            unsafe {
+                let user_info = NSDictionary::<NSString, AnyObject>::from_slices(
+                    &[NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey],
+                    &[message.as_ref(), priority.as_ref()],
+                );
+                NSAccessibilityPostNotificationWithUserInfo(
+                    element,
+                    NSAccessibilityAnnouncementRequestedNotification,
+                    Some(&user_info),
+                );
+            }
+        })
+        .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+mod platform {
+    use tauri::AppHandle;
+
+    pub fn init_thread() {}
+
+    pub fn announce(_app: &AppHandle, _text: &str) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_labels_reuse_overlay_text_without_ellipsis() {
+        assert_eq!(
+            stage_label("en", "transcribing").as_deref(),
+            Some("Transcribing")
+        );
+        assert_eq!(stage_label("en", "recording"), None);
+    }
+
+    #[test]
+    fn pasted_message_counts_words() {
+        assert_eq!(pasted_message("en", 42), "Pasted 42 words");
+        assert_eq!(pasted_message("en", 1), "Pasted 1 word");
+    }
+
+    #[test]
+    fn unknown_locale_falls_back_to_english() {
+        assert_eq!(
+            localized("xx-YY", |s| &s.recording_started),
+            "Recording started"
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! Local HTTP API for triggering actions from other tools (Stream Deck,
+//! AutoHotkey, scripts) without simulating the global shortcuts.
+//!
+//! Off by default. When enabled it listens on 127.0.0.1 only, and every request
+//! must carry `Authorization: Bearer <action_api_token>`.
+//!
+//! - `POST /action/{binding_id}/start`
+//! - `POST /action/{binding_id}/stop`
+//! - `POST /action/{binding_id}/cancel`
+//! - `GET /status`
+//!
+//! Requests run through the same `ACTION_MAP` entries as the shortcuts, and the
+//! actions keep their own session-state guards, so an API start can never race
+//! a hotkey into a second recording. Requests the current state does not allow
+//! (start while recording or processing, stop with nothing recording) get a 409
+//! with the current status.
+
+use crate::actions::{transcribe_action_for_binding, ACTION_MAP};
+use crate::managers::connector::constant_time_eq;
+use crate::session_manager::{self, ManagedSessionState, SessionPhase, SessionState};
+use crate::settings::{get_settings, write_settings, AppSettings};
+use crate::shortcut::is_binding_enabled_for_settings;
+use crate::ManagedToggleState;
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use log::{error, info, warn};
+use serde::Serialize;
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Manager};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio::time::timeout;
+
+/// Shortcut label passed to actions started through the API (shows up in logs).
+const API_SHORTCUT_LABEL: &str = "action_api";
+const STOP_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ApiCommand {
+    Start,
+    Stop,
+    Cancel,
+}
+
+/// Why a command was refused before reaching the action.
+#[derive(Debug, PartialEq, Eq)]
+enum Rejection {
+    /// The session is in a state that does not allow the command (409).
+    Busy(&'static str),
+    /// The command makes no sense for this action (400).
+    Invalid(&'static str),
+}
+
+#[derive(Serialize)]
+struct StatusResponse {
+    #[serde(flatten)]
+    session: session_manager::SessionStateChangedEvent,
+    /// Milliseconds since the current recording started, while recording.
+    recording_elapsed_ms: Option<u64>,
+    active_profile_id: String,
+    active_profile_name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<StatusResponse>,
+}
+
+#[derive(Clone)]
+struct ApiState {
+    app_handle: AppHandle,
+    /// Held across the state check and the action call so two API requests
+    /// never interleave.
+    dispatch_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+struct RunningServer {
+    port: u16,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+pub struct ActionApiServer {
+    app_handle: AppHandle,
+    running: Mutex<Option<RunningServer>>,
+    dispatch_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl ActionApiServer {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            running: Mutex::new(None),
+            dispatch_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Starts the listener if the API is enabled in settings. Generates the
+    /// bearer token on first start.
+    pub fn start(&self) -> Result<(), String> {
+        let settings = get_settings(&self.app_handle);
+        if !settings.action_api_enabled {
+            return Ok(());
+        }
+
+        let mut running = self.running.lock().unwrap_or_else(|p| p.into_inner());
+        if running.is_some() {
+            return Ok(());
+        }
+
+        let port = settings.action_api_port;
+        if port < 1024 {
+            return Err(format!(
+                "Port {} is not allowed. Please use a port number of 1024 or higher.",
+                port
+            ));
+        }
+        ensure_token(&self.app_handle, settings)?;
+
+        let std_listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .map_err(|e| format!("Failed to bind action API to port {}: {}", port, e))?;
+        std_listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("Failed to configure action API listener: {}", e))?;
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let state = ApiState {
+            app_handle: self.app_handle.clone(),
+            dispatch_lock: self.dispatch_lock.clone(),
+        };
+
+        let task = tauri::async_runtime::spawn(async move {
+            let listener = match TcpListener::from_std(std_listener) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    error!("Action API: failed to initialize listener: {}", e);
+                    return;
+                }
+            };
+            info!("Action API listening on 127.0.0.1:{}", port);
+
+            let router = Router::new()
+                .route("/status", get(handle_status))
+                .route("/action/{binding_id}/start", post(handle_start))
+                .route("/action/{binding_id}/stop", post(handle_stop))
+                .route("/action/{binding_id}/cancel", post(handle_cancel))
+                .with_state(state);
+
+            if let Err(e) = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+            {
+                error!("Action API server error: {}", e);
+            }
+
+            info!("Action API stopped");
+        });
+
+        *running = Some(RunningServer {
+            port,
+            shutdown: shutdown_tx,
+            task,
+        });
+        Ok(())
+    }
+
+    /// Signals the listener to shut down without waiting for it.
+    pub fn stop(&self) {
+        let _ = self.signal_shutdown();
+    }
+
+    /// Applies the current settings: stops the listener, waits for it to
+    /// release the port and starts it again if the API is still enabled.
+    pub async fn restart(&self) -> Result<(), String> {
+        if let Some(server) = self.signal_shutdown() {
+            if timeout(STOP_TIMEOUT, server.task).await.is_err() {
+                warn!(
+                    "Action API on port {} did not stop within {:?}",
+                    server.port, STOP_TIMEOUT
+                );
+            }
+        }
+        self.start()
+    }
+
+    fn signal_shutdown(&self) -> Option<RunningServer> {
+        let server = self
+            .running
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .take()?;
+        let _ = server.shutdown.send(());
+        Some(server)
+    }
+}
+
+fn generate_token() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|e| format!("Failed to generate action API token: {}", e))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn ensure_token(app: &AppHandle, mut settings: AppSettings) -> Result<(), String> {
+    if !settings.action_api_token.is_empty() {
+        return Ok(());
+    }
+    settings.action_api_token = generate_token()?.into();
+    write_settings(app, settings);
+    Ok(())
+}
+
+/// Replaces the bearer token. Takes effect for the next request.
+pub fn regenerate_token(app: &AppHandle) -> Result<(), String> {
+    let mut settings = get_settings(app);
+    settings.action_api_token = generate_token()?.into();
+    write_settings(app, settings);
+    Ok(())
+}
+
+/// Checks an `Authorization` header value against the configured token.
+fn is_authorized(authorization: Option<&str>, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    let Some(value) = authorization else {
+        return false;
+    };
+    let Some((scheme, provided)) = value.trim().split_once(' ') else {
+        return false;
+    };
+    scheme.eq_ignore_ascii_case("bearer")
+        && constant_time_eq(provided.trim().as_bytes(), token.as_bytes())
+}
+
+/// Decides whether `command` may run for `binding_id` in the current session.
+fn check_command(
+    command: ApiCommand,
+    is_instant: bool,
+    phase: SessionPhase,
+    active_binding: Option<&str>,
+    binding_id: &str,
+) -> Result<(), Rejection> {
+    let owns_session = active_binding == Some(binding_id);
+    match command {
+        ApiCommand::Start if is_instant => Ok(()),
+        ApiCommand::Start if phase == SessionPhase::Idle => Ok(()),
+        ApiCommand::Start => Err(Rejection::Busy("A recording is already in progress")),
+        ApiCommand::Stop | ApiCommand::Cancel if is_instant => Err(Rejection::Invalid(
+            "This action runs once and cannot be stopped or cancelled",
+        )),
+        ApiCommand::Stop if phase == SessionPhase::Recording && owns_session => Ok(()),
+        ApiCommand::Stop => Err(Rejection::Busy("This action is not recording")),
+        ApiCommand::Cancel if phase != SessionPhase::Idle && owns_session => Ok(()),
+        ApiCommand::Cancel => Err(Rejection::Busy("This action is not running")),
+    }
+}
+
+fn session_snapshot(app: &AppHandle) -> (SessionPhase, Option<String>) {
+    let state = app.state::<ManagedSessionState>();
+    let guard = session_manager::lock_session_state(&state, "action_api");
+    match &*guard {
+        SessionState::Idle => (SessionPhase::Idle, None),
+        SessionState::Recording { binding_id, .. } => {
+            (SessionPhase::Recording, Some(binding_id.clone()))
+        }
+        SessionState::Processing { binding_id, .. } => {
+            (SessionPhase::Processing, Some(binding_id.clone()))
+        }
+    }
+}
+
+fn current_status(app: &AppHandle) -> StatusResponse {
+    let settings = get_settings(app);
+    let active_profile_name = settings
+        .transcription_profile(&settings.active_profile_id)
+        .map(|profile| profile.name.clone());
+    StatusResponse {
+        session: session_manager::current_session_event(),
+        recording_elapsed_ms: session_manager::current_recording_elapsed_ms(app),
+        active_profile_id: settings.active_profile_id,
+        active_profile_name,
+    }
+}
+
+fn set_toggle_state(app: &AppHandle, binding_id: &str, active: bool) {
+    let toggle_state_manager = app.state::<ManagedToggleState>();
+    let mut states = toggle_state_manager
+        .lock()
+        .unwrap_or_else(|p| p.into_inner());
+    states.active_toggles.insert(binding_id.to_string(), active);
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: T) -> Response {
+    let mut response = (status, Json(body)).into_response();
+    response
+        .headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    response
+}
+
+fn error_response(status: StatusCode, message: &str, app: Option<&AppHandle>) -> Response {
+    json_response(
+        status,
+        ErrorResponse {
+            error: message.to_string(),
+            status: app.map(current_status),
+        },
+    )
+}
+
+fn unauthorized(headers: &HeaderMap, app: &AppHandle) -> Option<Response> {
+    let authorization = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    if is_authorized(authorization, &get_settings(app).action_api_token) {
+        return None;
+    }
+    let mut response = error_response(StatusCode::UNAUTHORIZED, "Unauthorized", None);
+    response
+        .headers_mut()
+        .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    Some(response)
+}
+
+/// Runs a command against the action registered for `binding_id`. Blocking:
+/// actions expect to be called from a shortcut thread, not the async runtime.
+fn run_command(app: &AppHandle, binding_id: &str, command: ApiCommand) -> Response {
+    let Some(action) = transcribe_action_for_binding(binding_id) else {
+        return error_response(StatusCode::NOT_FOUND, "Unknown binding", None);
+    };
+
+    let settings = get_settings(app);
+    if !is_binding_enabled_for_settings(&settings, binding_id) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            "This action is disabled in settings",
+            None,
+        );
+    }
+
+    let (phase, active_binding) = session_snapshot(app);
+    match check_command(
+        command,
+        action.is_instant(),
+        phase,
+        active_binding.as_deref(),
+        binding_id,
+    ) {
+        Ok(()) => {}
+        Err(Rejection::Busy(message)) => {
+            return error_response(StatusCode::CONFLICT, message, Some(app))
+        }
+        Err(Rejection::Invalid(message)) => {
+            return error_response(StatusCode::BAD_REQUEST, message, None)
+        }
+    }
+
+    match command {
+        ApiCommand::Start => {
+            if !action.is_instant() {
+                if crate::quiet_hours::blocks_recording_start(app, &settings) {
+                    return error_response(
+                        StatusCode::CONFLICT,
+                        "Recording is blocked during quiet hours",
+                        Some(app),
+                    );
+                }
+                // Keep toggle-mode shortcuts in sync so the next key press stops it.
+                set_toggle_state(app, binding_id, true);
+            }
+            info!("Action API: starting '{}'", binding_id);
+            action.start(app, binding_id, API_SHORTCUT_LABEL);
+        }
+        ApiCommand::Stop => {
+            set_toggle_state(app, binding_id, false);
+            info!("Action API: stopping '{}'", binding_id);
+            action.stop(app, binding_id, API_SHORTCUT_LABEL);
+        }
+        ApiCommand::Cancel => {
+            info!("Action API: cancelling '{}'", binding_id);
+            if let Some(cancel) = ACTION_MAP.get("cancel") {
+                cancel.start(app, "cancel", API_SHORTCUT_LABEL);
+            }
+        }
+    }
+
+    json_response(StatusCode::OK, current_status(app))
+}
+
+async fn dispatch(
+    state: ApiState,
+    headers: HeaderMap,
+    binding_id: String,
+    command: ApiCommand,
+) -> Response {
+    if let Some(response) = unauthorized(&headers, &state.app_handle) {
+        return response;
+    }
+
+    let _guard = state.dispatch_lock.lock().await;
+    let app = state.app_handle.clone();
+    match tauri::async_runtime::spawn_blocking(move || run_command(&app, &binding_id, command))
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            error!("Action API: command task failed: {}", e);
+            error_response(StatusCode::INTERNAL_SERVER_ERROR, "Internal error", None)
+        }
+    }
+}
+
+async fn handle_start(
+    State(state): State<ApiState>,
+    Path(binding_id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    dispatch(state, headers, binding_id, ApiCommand::Start).await
+}
+
+async fn handle_stop(
+    State(state): State<ApiState>,
+    Path(binding_id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    dispatch(state, headers, binding_id, ApiCommand::Stop).await
+}
+
+async fn handle_cancel(
+    State(state): State<ApiState>,
+    Path(binding_id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    dispatch(state, headers, binding_id, ApiCommand::Cancel).await
+}
+
+async fn handle_status(State(state): State<ApiState>, headers: HeaderMap) -> Response {
+    if let Some(response) = unauthorized(&headers, &state.app_handle) {
+        return response;
+    }
+    json_response(StatusCode::OK, current_status(&state.app_handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_matching_bearer_token() {
+        let token = "0123456789abcdef";
+        assert!(is_authorized(Some("Bearer 0123456789abcdef"), token));
+        assert!(is_authorized(Some("bearer  0123456789abcdef "), token));
+
+        assert!(!is_authorized(None, token));
+        assert!(!is_authorized(Some("Bearer wrong"), token));
+        assert!(!is_authorized(Some("Basic 0123456789abcdef"), token));
+        assert!(!is_authorized(Some("0123456789abcdef"), token));
+        // An empty configured token never authorizes anything.
+        assert!(!is_authorized(Some("Bearer "), ""));
+    }
+
+    #[test]
+    fn start_is_refused_while_busy() {
+        use SessionPhase::*;
+        assert_eq!(
+            check_command(ApiCommand::Start, false, Idle, None, "transcribe"),
+            Ok(())
+        );
+        for phase in [Recording, Processing] {
+            assert!(matches!(
+                check_command(
+                    ApiCommand::Start,
+                    false,
+                    phase,
+                    Some("transcribe"),
+                    "transcribe"
+                ),
+                Err(Rejection::Busy(_))
+            ));
+        }
+        // One-shot actions such as repaste do not take the session.
+        assert_eq!(
+            check_command(
+                ApiCommand::Start,
+                true,
+                Recording,
+                Some("transcribe"),
+                "repaste_last"
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn stop_and_cancel_need_the_binding_to_own_the_session() {
+        use SessionPhase::*;
+        let binding = Some("transcribe");
+        assert_eq!(
+            check_command(ApiCommand::Stop, false, Recording, binding, "transcribe"),
+            Ok(())
+        );
+        assert!(matches!(
+            check_command(ApiCommand::Stop, false, Processing, binding, "transcribe"),
+            Err(Rejection::Busy(_))
+        ));
+        assert!(matches!(
+            check_command(
+                ApiCommand::Stop,
+                false,
+                Recording,
+                binding,
+                "send_to_extension"
+            ),
+            Err(Rejection::Busy(_))
+        ));
+        assert_eq!(
+            check_command(ApiCommand::Cancel, false, Processing, binding, "transcribe"),
+            Ok(())
+        );
+        assert!(matches!(
+            check_command(ApiCommand::Cancel, false, Idle, None, "transcribe"),
+            Err(Rejection::Busy(_))
+        ));
+        assert!(matches!(
+            check_command(ApiCommand::Stop, true, Idle, None, "repaste_last"),
+            Err(Rejection::Invalid(_))
+        ));
+    }
+}
@@ -4,8 +4,11 @@ use crate::audio_feedback::{
     play_feedback_sound, play_feedback_sound_blocking, play_result_ready_sound, SoundType,
 };
 use crate::audio_toolkit::{
-    apply_custom_words, is_microphone_access_denied, is_no_input_device_error,
+    apply_casing, apply_custom_words, is_microphone_access_denied, is_no_input_device_error,
+    strip_non_final_punctuation, words_match_ignoring_punctuation, MicrophoneOpenFailure,
 };
+use crate::file_transcription_diarization::render_speaker_labeled_transcript;
+use crate::interview::{self, InterviewChannels};
 use crate::managers::audio::{AudioRecordingManager, StartRecordingError};
 use crate::managers::connector::ConnectorManager;
 use crate::managers::deepgram_realtime::{
@@ -13,9 +16,10 @@ use crate::managers::deepgram_realtime::{
     FinalChunkCallback as DeepgramFinalChunkCallback,
 };
 use crate::managers::deepgram_stt::{DeepgramSttManager, DeepgramTranscriptionOptions};
-use crate::managers::history::HistoryManager;
+use crate::managers::history::{HistoryEntry, HistoryManager};
 use crate::managers::llm_operation::LlmOperationTracker;
 use crate::managers::model::{self, EngineType, ModelManager};
+use crate::managers::openai_realtime::OpenAiRealtimeManager;
 use crate::managers::openai_realtime_whisper::{
     FinalChunkCallback as OpenAiRealtimeWhisperFinalChunkCallback, OpenAiRealtimeWhisperManager,
     OpenAiRealtimeWhisperOptions,
@@ -24,33 +28,44 @@ use crate::managers::remote_stt::RemoteSttManager;
 use crate::managers::soniox_realtime::{
     FinalChunkCallback, SonioxRealtimeManager, SonioxRealtimeOptions,
 };
-use crate::managers::soniox_stt::{SonioxAsyncTranscriptionOptions, SonioxSttManager};
-use crate::managers::transcription::TranscriptionManager;
+use crate::managers::soniox_stt::{
+    SonioxAsyncTranscript, SonioxAsyncTranscriptionOptions, SonioxSttManager,
+};
+use crate::managers::transcription::{LocalTranscript, TranscriptionManager};
+use crate::managers::usage_stats::{
+    audio_ms_for_samples, UsageCounters, UsageRecord, UsageStatsManager,
+};
+use crate::output_length_limit::{truncate_to_limits, OutputLengthLimits};
+use crate::output_whitespace::TrailingAdjustment;
 use crate::session_manager::{self, ManagedSessionState};
 use crate::settings::{
-    apply_output_whitespace_policy_for_settings, get_settings, AppSettings, LlmFeature,
-    LlmPostProcessBenchmarkResult, TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
+    apply_output_whitespace_policy_for_settings, get_settings, AppSettings, LLMPrompt, LlmFeature,
+    LlmPostProcessBenchmarkResult, MicMode, OutputDestination, OutputLimitStrategy,
+    PostProcessProvider, PunctuationMode, TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::soniox_stream_processor::SonioxStreamProcessor;
+use crate::subtitle::SubtitleSegment;
+use crate::transcription_confidence::ConfidenceAccumulator;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::url_security::REMOTE_STT_PRESET_OPENAI;
 use crate::utils::{
     self, show_finalizing_overlay, show_recording_overlay, show_sending_overlay,
     show_thinking_overlay, show_transcribing_overlay,
 };
+use crate::voice_command_matcher::{find_matching_command, FuzzyMatchConfig};
 use crate::ManagedToggleState;
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
 use log::{debug, error, info, warn};
-use natural::phonetics::soundex;
 use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::time::{Duration, Instant};
 use strsim::normalized_levenshtein;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_opener::OpenerExt;
 
 // Shortcut Action Trait
 pub trait ShortcutAction: Send + Sync {
@@ -80,27 +95,50 @@ pub const LIVE_SOUND_TRANSCRIPTION_BINDING_ID: &str = "live_sound_transcription"
 
 struct AiReplaceSelectionAction;
 
+struct TranscribeAndSearchAction;
 struct SendToExtensionAction;
 struct SendToExtensionWithSelectionAction;
 struct SendScreenshotToExtensionAction;
 
 struct RepastLastAction;
+struct RepasteLastNAction;
+struct UndoLastPasteAction;
 
 const REPASTE_LAST_PRE_PASTE_DELAY_MS: u64 = 100;
 
 struct CycleProfileAction;
+struct PauseResumeRecordingAction;
 struct PreviewDeleteLastWordShortcutAction;
+struct TogglePrivacyModeAction;
+
+struct ToggleDictationLanguageAction;
+struct BoostNextTranscriptionAction;
 #[cfg(target_os = "windows")]
 struct SpawnVoiceButtonAction;
 
 use crate::settings::TranscriptionProfile;
 
-enum PostProcessTranscriptionOutcome {
+enum OutputTranslationOutcome {
+    Translated(String),
+    /// Translation was not possible; the untranslated text is kept.
+    Failed,
+    Cancelled,
+}
+
+pub(crate) enum PostProcessTranscriptionOutcome {
     Skipped,
     Cancelled,
     Processed {
         text: String,
         prompt_template: String,
+        /// Set when the prompt's output length limit had to be enforced.
+        output_limit_note: Option<String>,
+        /// Provider that produced `text` (the fallback one after a retry).
+        provider_id: String,
+        /// Full model response when `text` was extracted from JSON output.
+        raw_response: Option<String>,
+        /// The transcript was post-processed in chunks and stitched together.
+        chunked: bool,
     },
 }
 
@@ -110,6 +148,7 @@ struct StopRecordingContext {
     audio_cancel_generation: u64,
     captured_profile_id: Option<String>,
     current_app: String,
+    window_context: RecordingWindowContext,
     recording_settings: AppSettings,
     recording_elapsed: Duration,
 }
@@ -152,11 +191,29 @@ struct LlmTemplateContext {
     time_local: String,
     date_iso: String,
     translate_to_english: String,
+    window_title: String,
+    selection_url: String,
+    /// Clipboard text, read only when a template uses `${clipboard}`.
+    clipboard: String,
+}
+
+/// Frontmost window details captured at recording start for prompt variables.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecordingWindowContext {
+    window_title: String,
+    /// Browser address bar contents; filled in asynchronously after capture.
+    selection_url: String,
+    /// Process name or bundle identifier, see
+    /// [`crate::active_app::get_frontmost_app_id`].
+    app_id: String,
 }
 
 /// Tracks the frontmost app captured at recording start, keyed by binding_id.
 static RECORDING_APP_CONTEXT: Lazy<Mutex<HashMap<String, String>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
+/// Tracks the frontmost window details captured at recording start, keyed by binding_id.
+static RECORDING_WINDOW_CONTEXT: Lazy<Mutex<HashMap<String, RecordingWindowContext>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 type SharedSonioxStreamProcessor = Arc<Mutex<SonioxStreamProcessor>>;
 static SONIOX_STREAM_PROCESSORS: Lazy<Mutex<HashMap<String, SharedSonioxStreamProcessor>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
@@ -168,6 +225,9 @@ static OPENAI_REALTIME_WHISPER_STREAM_EMITTED: Lazy<Mutex<HashMap<String, bool>>
     Lazy::new(|| Mutex::new(HashMap::new()));
 static FORCE_POST_PROCESS_BINDINGS: Lazy<Mutex<HashSet<String>>> =
     Lazy::new(|| Mutex::new(HashSet::new()));
+/// Chunked live preview workers for non-streaming local models, keyed by binding_id.
+static LOCAL_STREAMING_PREVIEWS: Lazy<Mutex<HashMap<String, std::thread::JoinHandle<()>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 const RECORDING_SAMPLE_RATE_HZ: f32 = 16_000.0;
 const SONIOX_REALTIME_FALLBACK_ASYNC_THRESHOLD_SECS: f32 = 20.0;
 const SONIOX_LONG_AUDIO_TIMEOUT_MULTIPLIER: f32 = 2.0;
@@ -177,6 +237,11 @@ const LIVE_QUICK_CANCEL_THRESHOLD_MS: u64 = 500;
 pub(crate) const OPENAI_REALTIME_WHISPER_LIVE_FINALIZE_TIMEOUT_MS: u32 = 5_000;
 const LOCAL_PREVIEW_AUTO_MIN_SAMPLES: usize = 16_000;
 const LOCAL_PREVIEW_MANUAL_MIN_SAMPLES: usize = 1;
+const PUNCTUATION_LLM_TIMEOUT: Duration = Duration::from_secs(8);
+const PUNCTUATION_LLM_PROMPT: &str =
+    "Add or normalize the punctuation of the transcription below. \
+Change nothing else: keep every word, its spelling and its order exactly as given. \
+Return only the corrected text.\n\nTranscription:\n";
 
 #[derive(Debug, Default)]
 struct LocalPreviewAutoFlushRuntime {
@@ -229,22 +294,71 @@ struct SlidingLmRequest {
 }
 
 fn capture_recording_app_context(binding_id: &str) {
-    #[cfg(target_os = "windows")]
-    let app_name = crate::active_app::get_frontmost_app_name().unwrap_or_default();
+    let window = crate::active_app::capture_foreground_window();
+    let app_name = window
+        .and_then(crate::active_app::window_title)
+        .unwrap_or_default();
 
-    #[cfg(not(target_os = "windows"))]
-    let app_name = String::new();
+    if let Ok(mut context) = RECORDING_WINDOW_CONTEXT.lock() {
+        context.insert(
+            binding_id.to_string(),
+            RecordingWindowContext {
+                window_title: app_name.clone(),
+                selection_url: String::new(),
+                app_id: crate::active_app::get_frontmost_app_id().unwrap_or_default(),
+            },
+        );
+    }
+
+    // UI Automation is too slow for the recording start path, so the browser
+    // URL is read in the background for the window that was active right now.
+    if let Some(window) = window {
+        let binding_id = binding_id.to_string();
+        let window_title = app_name.clone();
+        std::thread::spawn(move || {
+            let Some(url) = crate::active_app::browser_url_for_window(window) else {
+                return;
+            };
+            if let Ok(mut contexts) = RECORDING_WINDOW_CONTEXT.lock() {
+                if let Some(context) = contexts
+                    .get_mut(&binding_id)
+                    .filter(|context| context.window_title == window_title)
+                {
+                    context.selection_url = url;
+                }
+            }
+        });
+    }
 
     if let Ok(mut context) = RECORDING_APP_CONTEXT.lock() {
         context.insert(binding_id.to_string(), app_name);
     }
 }
 
-fn take_recording_app_context(binding_id: &str) -> String {
-    if let Ok(mut context) = RECORDING_APP_CONTEXT.lock() {
-        return context.remove(binding_id).unwrap_or_default();
+/// Removes and returns the app name and window details captured when
+/// `binding_id` started recording.
+fn take_recording_app_context(binding_id: &str) -> (String, RecordingWindowContext) {
+    let window_context = RECORDING_WINDOW_CONTEXT
+        .lock()
+        .ok()
+        .and_then(|mut contexts| contexts.remove(binding_id))
+        .unwrap_or_default();
+    let current_app = RECORDING_APP_CONTEXT
+        .lock()
+        .ok()
+        .and_then(|mut context| context.remove(binding_id))
+        .unwrap_or_default();
+    (current_app, window_context)
+}
+
+/// Window details of whatever is frontmost right now, for flows that do not
+/// start from a recording. The browser URL is not read here.
+fn current_window_context() -> RecordingWindowContext {
+    RecordingWindowContext {
+        window_title: crate::active_app::get_frontmost_app_name().unwrap_or_default(),
+        selection_url: String::new(),
+        app_id: crate::active_app::get_frontmost_app_id().unwrap_or_default(),
     }
-    String::new()
 }
 
 fn peek_recording_app_context(binding_id: &str) -> String {
@@ -490,8 +604,15 @@ fn handle_remote_transcription_error_with_retry_session(
 fn register_soniox_stream_processor(
     binding_id: &str,
     settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
 ) -> SharedSonioxStreamProcessor {
-    let processor = Arc::new(Mutex::new(SonioxStreamProcessor::from_settings(settings)));
+    let processor = Arc::new(Mutex::new(
+        SonioxStreamProcessor::from_settings(settings, settings.custom_words_for_profile(profile))
+            .with_output_casing(
+                settings.output_casing_for(profile),
+                &settings.output_casing_exceptions,
+            ),
+    ));
     if let Ok(mut processors) = SONIOX_STREAM_PROCESSORS.lock() {
         processors.insert(binding_id.to_string(), Arc::clone(&processor));
     }
@@ -578,9 +699,7 @@ fn resolve_effective_language(
     settings: &AppSettings,
     profile: Option<&TranscriptionProfile>,
 ) -> String {
-    let requested = profile
-        .map(|p| p.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let requested = settings.requested_language(profile);
 
     if settings.transcription_provider != TranscriptionProvider::Local {
         return requested;
@@ -590,6 +709,14 @@ fn resolve_effective_language(
     let Some(model_info) = mm.get_model_info(&settings.selected_model) else {
         return requested;
     };
+    // Single-language models ignore the language toggle.
+    let requested = if model::supports_language_selection(&model_info.supported_languages) {
+        requested
+    } else {
+        profile
+            .map(|p| p.language.clone())
+            .unwrap_or_else(|| settings.selected_language.clone())
+    };
 
     model::effective_language(
         &requested,
@@ -613,28 +740,56 @@ fn resolve_profile_name(profile: Option<&TranscriptionProfile>) -> String {
         .unwrap_or_else(|| "Default".to_string())
 }
 
-fn resolve_short_prev_transcript(settings: &AppSettings, current_app: &str) -> String {
-    if !settings.llm_context_prev_transcript_enabled || current_app.trim().is_empty() {
+fn resolve_context_profile_id(profile: Option<&TranscriptionProfile>) -> &str {
+    profile
+        .map(|p| p.id.as_str())
+        .unwrap_or(crate::transcript_context::DEFAULT_CONTEXT_PROFILE_ID)
+}
+
+/// Blocked apps behave as if ${short_prev_transcript} were disabled for them.
+fn is_transcript_context_blocked(settings: &AppSettings, current_app: &str) -> bool {
+    crate::transcript_context::is_app_blocked(current_app, &settings.transcript_context_blocklist)
+}
+
+fn resolve_short_prev_transcript(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    current_app: &str,
+) -> String {
+    if !settings.llm_context_prev_transcript_enabled
+        || current_app.trim().is_empty()
+        || is_transcript_context_blocked(settings, current_app)
+    {
         return String::new();
     }
 
     crate::transcript_context::get_short_prev_transcript(
         current_app,
+        resolve_context_profile_id(profile),
+        settings.llm_context_prev_transcript_profile_fallback,
         clamp_prev_transcript_words(settings),
         clamp_prev_transcript_expiry(settings),
     )
 }
 
-fn update_short_prev_transcript(settings: &AppSettings, current_app: &str, transcription: &str) {
+fn update_short_prev_transcript(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    current_app: &str,
+    transcription: &str,
+) {
     if !settings.llm_context_prev_transcript_enabled
         || current_app.trim().is_empty()
         || transcription.trim().is_empty()
+        || is_transcript_context_blocked(settings, current_app)
+        || crate::privacy_mode::should_skip("transcript context update")
     {
         return;
     }
 
     crate::transcript_context::update_transcript_context(
         current_app,
+        resolve_context_profile_id(profile),
         transcription,
         clamp_prev_transcript_words(settings),
         clamp_prev_transcript_expiry(settings),
@@ -646,6 +801,7 @@ fn build_llm_template_context(
     settings: &AppSettings,
     profile: Option<&TranscriptionProfile>,
     current_app: &str,
+    window_context: &RecordingWindowContext,
     output: &str,
     instruction: &str,
     selection: &str,
@@ -658,13 +814,102 @@ fn build_llm_template_context(
         instruction: instruction.to_string(),
         selection: selection.to_string(),
         current_app: current_app.to_string(),
-        short_prev_transcript: resolve_short_prev_transcript(settings, current_app),
+        short_prev_transcript: resolve_short_prev_transcript(settings, profile, current_app),
         language: resolve_effective_language(app, settings, profile),
         profile_name: resolve_profile_name(profile),
         time_local: now.format("%A, %B %-d, %Y %-I:%M:%S %p").to_string(),
         date_iso: now.to_rfc3339(),
         translate_to_english: translate_to_english.to_string(),
+        window_title: window_context.window_title.clone(),
+        selection_url: window_context.selection_url.clone(),
+        clipboard: String::new(),
+    }
+}
+
+const CLIPBOARD_TEMPLATE_VARIABLE: &str = "${clipboard}";
+
+impl LlmTemplateContext {
+    /// Fills `${clipboard}` when one of `templates` uses it. The clipboard is
+    /// left untouched otherwise, so call this before the flow pastes anything.
+    fn with_clipboard_for(
+        mut self,
+        app: &AppHandle,
+        settings: &AppSettings,
+        templates: &[&str],
+    ) -> Self {
+        if templates
+            .iter()
+            .any(|template| template.contains(CLIPBOARD_TEMPLATE_VARIABLE))
+        {
+            // Non-text clipboard contents read as an error and become "".
+            let text = app.clipboard().read_text().unwrap_or_default();
+            self.clipboard = truncate_clipboard_variable(
+                &text,
+                settings.llm_clipboard_variable_max_chars as usize,
+            );
+        }
+        self
+    }
+}
+
+/// Cuts `text` to `max_chars` characters, marking the cut with an ellipsis.
+fn truncate_clipboard_variable(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// The prompt post-processing runs for a recording.
+enum PostProcessPromptChoice<'a> {
+    /// A non-empty `llm_prompt_override` on the active profile.
+    ProfileOverride(&'a TranscriptionProfile, &'a str),
+    /// The globally selected prompt.
+    Selected(&'a LLMPrompt),
+}
+
+impl<'a> PostProcessPromptChoice<'a> {
+    fn template(&self) -> &'a str {
+        match self {
+            Self::ProfileOverride(_, prompt) => prompt,
+            Self::Selected(prompt) => prompt.prompt.as_str(),
+        }
+    }
+}
+
+/// Picks the prompt for `profile`: a profile override wins over the selected
+/// prompt. Errors explain why no prompt applies.
+fn select_post_process_prompt<'a>(
+    settings: &'a AppSettings,
+    profile: Option<&'a TranscriptionProfile>,
+) -> Result<PostProcessPromptChoice<'a>, String> {
+    if let Some((profile, prompt)) = profile.and_then(|p| {
+        p.llm_prompt_override
+            .as_deref()
+            .filter(|prompt| !prompt.trim().is_empty())
+            .map(|prompt| (p, prompt))
+    }) {
+        return Ok(PostProcessPromptChoice::ProfileOverride(profile, prompt));
     }
+
+    let selected_prompt_id = settings
+        .post_process_selected_prompt_id
+        .as_ref()
+        .ok_or_else(|| "no prompt is selected".to_string())?;
+    settings
+        .post_process_prompts
+        .iter()
+        .find(|prompt| &prompt.id == selected_prompt_id)
+        .map(PostProcessPromptChoice::Selected)
+        .ok_or_else(|| format!("prompt '{}' was not found", selected_prompt_id))
+}
+
+/// Prompt text post-processing will use for `profile`.
+fn post_process_prompt_template<'a>(
+    settings: &'a AppSettings,
+    profile: Option<&'a TranscriptionProfile>,
+) -> &'a str {
+    select_post_process_prompt(settings, profile).map_or("", |choice| choice.template())
 }
 
 fn apply_llm_template_vars(template: &str, context: &LlmTemplateContext) -> String {
@@ -679,12 +924,217 @@ fn apply_llm_template_vars(template: &str, context: &LlmTemplateContext) -> Stri
         .replace("${time_local}", &context.time_local)
         .replace("${date_iso}", &context.date_iso)
         .replace("${translate_to_english}", &context.translate_to_english)
+        .replace("${window_title}", &context.window_title)
+        .replace("${selection_url}", &context.selection_url)
+        .replace(CLIPBOARD_TEMPLATE_VARIABLE, &context.clipboard)
+}
+
+#[cfg(test)]
+mod llm_template_tests {
+    use super::*;
+
+    #[test]
+    fn clipboard_variable_is_truncated_with_marker() {
+        assert_eq!(truncate_clipboard_variable("héllo", 5), "héllo");
+        assert_eq!(truncate_clipboard_variable("héllo world", 5), "héllo…");
+        assert_eq!(truncate_clipboard_variable("", 0), "");
+    }
+
+    #[test]
+    fn clipboard_variable_is_substituted() {
+        let context = LlmTemplateContext {
+            output: "draft".to_string(),
+            clipboard: "example".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply_llm_template_vars("Rewrite ${output} like ${clipboard}", &context),
+            "Rewrite draft like example"
+        );
+    }
+}
+
+/// Appends the final text to the profile's file output, if one is configured.
+fn queue_file_output(
+    app: &AppHandle,
+    settings: &AppSettings,
+    profile_id: Option<&str>,
+    current_app: &str,
+    window_context: &RecordingWindowContext,
+    final_text: &str,
+) {
+    let Some(profile) = profile_id
+        .filter(|id| *id != "default")
+        .and_then(|id| settings.transcription_profile(id))
+    else {
+        return;
+    };
+    let Some(config) = profile.file_output.as_ref() else {
+        return;
+    };
+    if final_text.trim().is_empty() || crate::privacy_mode::should_skip("file output") {
+        return;
+    }
+
+    let context = build_llm_template_context(
+        app,
+        settings,
+        Some(profile),
+        current_app,
+        window_context,
+        final_text,
+        "",
+        "",
+    )
+    .with_clipboard_for(app, settings, &[&config.entry_template]);
+    let entry = apply_llm_template_vars(&config.entry_template, &context);
+    crate::file_output::queue_append(app, config, entry);
+}
+
+/// Counts a finished transcription in the local usage statistics.
+fn record_dictation_usage(
+    app: &AppHandle,
+    processed: &ProcessedTranscription,
+    profile_id: Option<&str>,
+    stt_provider: &str,
+    sample_count: usize,
+) {
+    let mut record = UsageRecord::dictation(&processed.final_text, sample_count);
+    record.stt_provider = Some(stt_provider.to_string());
+    record.profile_id = profile_id.filter(|id| *id != "default").map(str::to_string);
+    if processed.post_processed_text.is_some() {
+        record.counters.llm_post_processes = 1;
+        record.llm_provider = processed.post_process_provider.clone();
+    }
+    app.state::<Arc<UsageStatsManager>>().record(record);
 }
 
 fn is_blank_transcription(transcription: &str) -> bool {
     transcription.trim().is_empty()
 }
 
+/// Minimum reasoning budget accepted by OpenRouter/Anthropic.
+const MIN_REASONING_BUDGET: u32 = 1024;
+/// Upper bound for adaptive reasoning budgets.
+const MAX_ADAPTIVE_REASONING_BUDGET: u32 = 16_384;
+
+/// Decides whether post-processing reasoning is used for a transcript of
+/// `word_count` words and with which budget.
+///
+/// Reasoning stays off at or below `enabled_over_words`. With
+/// `budget_per_100_words` the budget grows with the transcript; otherwise
+/// `base_budget` is used. Either way it is clamped to
+/// `MIN_REASONING_BUDGET..=MAX_ADAPTIVE_REASONING_BUDGET`.
+fn compute_adaptive_reasoning(
+    enabled: bool,
+    base_budget: u32,
+    enabled_over_words: Option<u32>,
+    budget_per_100_words: Option<u32>,
+    word_count: usize,
+) -> (bool, u32) {
+    let enabled =
+        enabled && enabled_over_words.map_or(true, |threshold| word_count > threshold as usize);
+
+    let budget = match budget_per_100_words {
+        Some(per_100_words) => (word_count as u64)
+            .saturating_mul(u64::from(per_100_words))
+            .div_ceil(100),
+        None => u64::from(base_budget),
+    };
+    let budget = budget.clamp(
+        u64::from(MIN_REASONING_BUDGET),
+        u64::from(MAX_ADAPTIVE_REASONING_BUDGET),
+    ) as u32;
+
+    (enabled, budget)
+}
+
+/// Returns the prompt's model override if it can be sent to `provider`.
+///
+/// Apple Intelligence reads its "model" as a token limit, so a named model
+/// cannot apply there; it is logged and the inherited model is used.
+fn prompt_model_override_for_provider(
+    prompt: &LLMPrompt,
+    provider: &PostProcessProvider,
+) -> Option<String> {
+    let model = prompt
+        .model_override
+        .as_deref()
+        .map(str::trim)
+        .filter(|model| !model.is_empty())?;
+
+    if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+        warn!(
+            "Ignoring model override '{}' of prompt '{}': not valid for provider '{}'",
+            model, prompt.name, provider.id
+        );
+        return None;
+    }
+
+    Some(model.to_string())
+}
+
+/// Applies the prompt's reasoning on/off and budget overrides on top of the
+/// resolved profile/global config.
+fn apply_prompt_reasoning_overrides(
+    config: crate::llm_client::ReasoningConfig,
+    prompt: Option<&LLMPrompt>,
+) -> crate::llm_client::ReasoningConfig {
+    let Some(prompt) = prompt else {
+        return config;
+    };
+    if prompt.reasoning_enabled_override.is_none() && prompt.reasoning_budget_override.is_none() {
+        return config;
+    }
+
+    crate::llm_client::ReasoningConfig::new(
+        prompt.reasoning_enabled_override.unwrap_or(config.enabled),
+        prompt.reasoning_budget_override.unwrap_or(config.budget),
+    )
+}
+
+/// Builds the post-processing reasoning config for `transcript`, applying the
+/// profile's word thresholds over the global ones.
+fn resolve_post_process_reasoning(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    transcript: &str,
+) -> crate::llm_client::ReasoningConfig {
+    let enabled_over_words = profile
+        .and_then(|p| p.llm_reasoning_enabled_over_words)
+        .or(settings.post_process_reasoning_enabled_over_words);
+    let budget_per_100_words = profile
+        .and_then(|p| p.llm_reasoning_budget_per_100_words)
+        .or(settings.post_process_reasoning_budget_per_100_words);
+
+    if enabled_over_words.is_none() && budget_per_100_words.is_none() {
+        return crate::llm_client::ReasoningConfig::new(
+            settings.post_process_reasoning_enabled,
+            settings.post_process_reasoning_budget,
+        );
+    }
+
+    let word_count = transcript.split_whitespace().count();
+    let (enabled, budget) = compute_adaptive_reasoning(
+        settings.post_process_reasoning_enabled,
+        settings.post_process_reasoning_budget,
+        enabled_over_words,
+        budget_per_100_words,
+        word_count,
+    );
+    if settings.post_process_reasoning_enabled {
+        info!(
+            "Post-processing reasoning {} for {} words (threshold: {:?}, budget: {})",
+            if enabled { "enabled" } else { "skipped" },
+            word_count,
+            enabled_over_words,
+            budget
+        );
+    }
+
+    crate::llm_client::ReasoningConfig::new(enabled, budget)
+}
+
 /// Post-process transcription with LLM, optionally using profile-specific settings.
 ///
 /// If `profile` is Some, uses the profile's LLM settings:
@@ -692,6 +1142,8 @@ fn is_blank_transcription(transcription: &str) -> bool {
 /// - `profile.llm_prompt_override` overrides the global prompt (if set)
 /// - `profile.llm_model_override` overrides the global model (if set and valid for current provider)
 ///
+/// A selected global prompt may further override the model and reasoning settings.
+///
 /// If `profile` is None (default profile), uses global settings.
 /// If `force_manual` is true, the enable flag gates are bypassed.
 async fn maybe_post_process_transcription(
@@ -739,14 +1191,41 @@ async fn maybe_post_process_transcription(
         }
     };
 
-    // Determine model: profile override > global setting
+    // Determine prompt: profile override > global selected prompt
+    let choice = match select_post_process_prompt(settings, profile) {
+        Ok(choice) => choice,
+        Err(reason) => {
+            debug!("Post-processing skipped because {}", reason);
+            return PostProcessTranscriptionOutcome::Skipped;
+        }
+    };
+    let prompt_template = choice.template().to_string();
+    let (output_limits, output_json_path, selected_prompt) = match choice {
+        PostProcessPromptChoice::ProfileOverride(p, _) => (
+            OutputLengthLimits::new(p.llm_max_output_words, p.llm_max_output_sentences),
+            p.llm_output_json_path.clone(),
+            None,
+        ),
+        PostProcessPromptChoice::Selected(prompt) => (
+            OutputLengthLimits::new(prompt.max_output_words, prompt.max_output_sentences),
+            prompt.output_json_path.clone(),
+            Some(prompt),
+        ),
+    };
+
+    if prompt_template.trim().is_empty() {
+        debug!("Post-processing skipped because the selected prompt is empty");
+        return PostProcessTranscriptionOutcome::Skipped;
+    }
+
+    // Determine model: prompt override > profile override > global setting
     let global_model = settings
         .post_process_models
         .get(&provider.id)
         .cloned()
         .unwrap_or_default();
 
-    let model = match profile {
+    let inherited_model = match profile {
         Some(p) => {
             // Use profile override if set and non-empty, otherwise fall back to global
             p.llm_model_override
@@ -757,6 +1236,11 @@ async fn maybe_post_process_transcription(
         }
         None => global_model,
     };
+    let prompt_model_override =
+        selected_prompt.and_then(|prompt| prompt_model_override_for_provider(prompt, &provider));
+    let model = prompt_model_override
+        .clone()
+        .unwrap_or_else(|| inherited_model.clone());
 
     if model.trim().is_empty() {
         debug!(
@@ -766,48 +1250,6 @@ async fn maybe_post_process_transcription(
         return PostProcessTranscriptionOutcome::Skipped;
     }
 
-    // Determine prompt: profile override > global selected prompt
-    let prompt_template = match profile {
-        Some(p)
-            if p.llm_prompt_override
-                .as_ref()
-                .map_or(false, |s| !s.trim().is_empty()) =>
-        {
-            // Use profile's prompt override
-            p.llm_prompt_override.clone().unwrap()
-        }
-        _ => {
-            // Use global selected prompt
-            let selected_prompt_id = match &settings.post_process_selected_prompt_id {
-                Some(id) => id.clone(),
-                None => {
-                    debug!("Post-processing skipped because no prompt is selected");
-                    return PostProcessTranscriptionOutcome::Skipped;
-                }
-            };
-
-            match settings
-                .post_process_prompts
-                .iter()
-                .find(|prompt| prompt.id == selected_prompt_id)
-            {
-                Some(prompt) => prompt.prompt.clone(),
-                None => {
-                    debug!(
-                        "Post-processing skipped because prompt '{}' was not found",
-                        selected_prompt_id
-                    );
-                    return PostProcessTranscriptionOutcome::Skipped;
-                }
-            }
-        }
-    };
-
-    if prompt_template.trim().is_empty() {
-        debug!("Post-processing skipped because the selected prompt is empty");
-        return PostProcessTranscriptionOutcome::Skipped;
-    }
-
     debug!(
         "Starting LLM post-processing with provider '{}' (model: {})",
         provider.id, model
@@ -847,9 +1289,23 @@ async fn maybe_post_process_transcription(
                             "Apple Intelligence post-processing succeeded. Output length: {} chars",
                             result.len()
                         );
+                        let (result, raw_response) =
+                            extract_post_process_json_output(output_json_path.as_deref(), result);
+                        // Re-asking is not supported for the on-device model; always truncate.
+                        let (text, output_limit_note) = enforce_post_process_output_limits(
+                            settings,
+                            &output_limits,
+                            result,
+                            None,
+                        )
+                        .await;
                         PostProcessTranscriptionOutcome::Processed {
-                            text: result,
+                            text,
                             prompt_template,
+                            output_limit_note,
+                            provider_id: provider.id.clone(),
+                            raw_response,
+                            chunked: false,
                         }
                     }
                 }
@@ -879,35 +1335,62 @@ async fn maybe_post_process_transcription(
     let operation_id = llm_tracker.start_operation();
     show_thinking_overlay(app);
 
-    // On Windows, use secure key storage
-    #[cfg(target_os = "windows")]
-    let api_key = crate::secure_keys::get_post_process_api_key(&provider.id);
-
-    // On non-Windows, use JSON settings
-    #[cfg(not(target_os = "windows"))]
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
-
-    // Build reasoning config from settings
-    let reasoning_config = crate::llm_client::ReasoningConfig::new(
-        settings.post_process_reasoning_enabled,
-        settings.post_process_reasoning_budget,
+    // Build reasoning config from settings, scaled by transcript length,
+    // then let the selected prompt override it
+    let reasoning_config = apply_prompt_reasoning_overrides(
+        resolve_post_process_reasoning(settings, profile, &template_context.output),
+        selected_prompt,
     )
     .with_disable_by_default_on_compatible_providers(true);
+    let request = PostProcessRequest {
+        provider,
+        model,
+        inherited_model: &inherited_model,
+        prompt_model_override: prompt_model_override.as_deref(),
+        reasoning: reasoning_config,
+    };
 
-    // Send the chat completion request with optional reasoning
-    match crate::llm_client::send_chat_completion_with_reasoning(
-        &provider,
+    if let Some(chunk_chars) = post_process_chunk_chars(
+        settings,
+        &prompt_template,
+        template_context,
+        &processed_prompt,
+    ) {
+        return post_process_in_chunks(
+            settings,
+            &llm_tracker,
+            operation_id,
+            &request,
+            template_context,
+            chunk_chars,
+            PostProcessPrompt {
+                template: prompt_template,
+                output_limits,
+                output_json_path: output_json_path.as_deref(),
+            },
+        )
+        .await;
+    }
+
+    let Some(PostProcessResponse {
+        result,
+        provider,
+        model,
         api_key,
-        &model,
-        processed_prompt,
-        reasoning_config,
+    }) = send_post_process_request(
+        settings,
+        &llm_tracker,
+        operation_id,
+        &request,
+        &processed_prompt,
     )
     .await
-    {
+    else {
+        return PostProcessTranscriptionOutcome::Cancelled;
+    };
+    let reasoning_config = request.reasoning;
+
+    match result {
         Ok(Some(content)) => {
             if llm_tracker.is_cancelled(operation_id) {
                 debug!(
@@ -917,24 +1400,40 @@ async fn maybe_post_process_transcription(
                 return PostProcessTranscriptionOutcome::Cancelled;
             }
 
-            // Strip invisible Unicode characters that some LLMs (e.g., Qwen) may insert
-            let content = if settings.zero_width_filter_enabled {
-                content
-                    .replace('\u{200B}', "") // Zero-Width Space
-                    .replace('\u{200C}', "") // Zero-Width Non-Joiner
-                    .replace('\u{200D}', "") // Zero-Width Joiner
-                    .replace('\u{FEFF}', "") // Byte Order Mark / Zero-Width No-Break Space
-            } else {
-                content
-            };
+            let content = strip_zero_width_chars(settings, content);
             debug!(
                 "LLM post-processing succeeded for provider '{}'. Output length: {} chars",
                 provider.id,
                 content.len()
             );
-            PostProcessTranscriptionOutcome::Processed {
-                text: content,
+
+            let (content, raw_response) =
+                extract_post_process_json_output(output_json_path.as_deref(), content);
+            let reask = PostProcessReask {
+                provider: &provider,
+                api_key,
+                model: &model,
+                prompt: &processed_prompt,
+                reasoning: reasoning_config,
+            };
+            let (content, output_limit_note) =
+                enforce_post_process_output_limits(settings, &output_limits, content, Some(reask))
+                    .await;
+            if llm_tracker.is_cancelled(operation_id) {
+                debug!(
+                    "LLM post-processing operation {} was cancelled, discarding result",
+                    operation_id
+                );
+                return PostProcessTranscriptionOutcome::Cancelled;
+            }
+
+            PostProcessTranscriptionOutcome::Processed {
+                text: content,
                 prompt_template,
+                output_limit_note,
+                provider_id: provider.id.clone(),
+                raw_response,
+                chunked: false,
             }
         }
         Ok(None) => {
@@ -968,6 +1467,657 @@ async fn maybe_post_process_transcription(
     }
 }
 
+/// Provider, model and reasoning a post-processing request is sent with.
+struct PostProcessRequest<'a> {
+    provider: PostProcessProvider,
+    model: String,
+    /// Model the prompt would use without its model override
+    inherited_model: &'a str,
+    prompt_model_override: Option<&'a str>,
+    reasoning: crate::llm_client::ReasoningConfig,
+}
+
+/// Response to a post-processing request with the provider, model and API key
+/// that produced it (the fallback ones after a retry).
+struct PostProcessResponse {
+    result: Result<Option<String>, String>,
+    provider: PostProcessProvider,
+    model: String,
+    api_key: String,
+}
+
+/// Sends `prompt`, retrying once with the inherited model when the prompt's
+/// model override is rejected and once against the fallback provider when the
+/// provider is unreachable. Returns `None` when the operation is cancelled;
+/// cancelling drops the request future so the HTTP call is aborted.
+async fn send_post_process_request(
+    settings: &AppSettings,
+    llm_tracker: &LlmOperationTracker,
+    operation_id: u64,
+    request: &PostProcessRequest<'_>,
+    prompt: &str,
+) -> Option<PostProcessResponse> {
+    let mut provider = request.provider.clone();
+    let mut model = request.model.clone();
+    let mut api_key = settings.post_process_api_key(&provider.id);
+
+    let Some(mut result) = llm_tracker
+        .run_cancellable(
+            operation_id,
+            crate::llm_client::try_chat_completion_with_reasoning(
+                &provider,
+                api_key.clone(),
+                &model,
+                prompt.to_string(),
+                request.reasoning.clone(),
+            ),
+        )
+        .await
+    else {
+        debug!(
+            "LLM post-processing operation {} was cancelled in flight",
+            operation_id
+        );
+        return None;
+    };
+
+    // A prompt's model override may not exist on this provider; retry with the
+    // model the prompt would otherwise inherit instead of dropping post-processing.
+    if let (Err(err), Some(override_model)) = (&result, request.prompt_model_override) {
+        if !err.is_transient()
+            && !request.inherited_model.trim().is_empty()
+            && request.inherited_model != override_model
+            && !llm_tracker.is_cancelled(operation_id)
+        {
+            warn!(
+                "LLM post-processing with prompt model override '{}' failed for provider '{}': {}. Retrying with model '{}'",
+                override_model, provider.id, err, request.inherited_model
+            );
+            model = request.inherited_model.to_string();
+            let Some(inherited_result) = llm_tracker
+                .run_cancellable(
+                    operation_id,
+                    crate::llm_client::try_chat_completion_with_reasoning(
+                        &provider,
+                        api_key.clone(),
+                        &model,
+                        prompt.to_string(),
+                        request.reasoning.clone(),
+                    ),
+                )
+                .await
+            else {
+                debug!(
+                    "LLM post-processing operation {} was cancelled in flight",
+                    operation_id
+                );
+                return None;
+            };
+            result = inherited_result;
+        }
+    }
+
+    // Retry once against the fallback provider when the primary one is unreachable.
+    if let Err(err) = &result {
+        if let Some((fallback_provider, fallback_model)) = settings.post_process_fallback() {
+            if err.is_transient() && !llm_tracker.is_cancelled(operation_id) {
+                warn!(
+                    "LLM post-processing failed for provider '{}': {}. Retrying with fallback provider '{}' (model: {})",
+                    provider.id, err, fallback_provider.id, fallback_model
+                );
+                provider = fallback_provider.clone();
+                model = fallback_model;
+                api_key = settings.post_process_api_key(&provider.id);
+                let Some(fallback_result) = llm_tracker
+                    .run_cancellable(
+                        operation_id,
+                        crate::llm_client::try_chat_completion_with_reasoning(
+                            &provider,
+                            api_key.clone(),
+                            &model,
+                            prompt.to_string(),
+                            request.reasoning.clone(),
+                        ),
+                    )
+                    .await
+                else {
+                    debug!(
+                        "LLM post-processing operation {} was cancelled in flight",
+                        operation_id
+                    );
+                    return None;
+                };
+                result = fallback_result;
+            }
+        }
+    }
+
+    Some(PostProcessResponse {
+        result: result.map_err(String::from),
+        provider,
+        model,
+        api_key,
+    })
+}
+
+/// Smallest `${output}` slice a chunk is cut to, however long the rest of the
+/// prompt is.
+const MIN_POST_PROCESS_CHUNK_CHARS: usize = 500;
+
+/// Characters of `${output}` per chunk when the rendered prompt exceeds
+/// `post_process_chunk_threshold_chars`; `None` when chunking is off or the
+/// prompt fits.
+fn post_process_chunk_chars(
+    settings: &AppSettings,
+    prompt_template: &str,
+    template_context: &LlmTemplateContext,
+    processed_prompt: &str,
+) -> Option<usize> {
+    let threshold = settings.post_process_chunk_threshold_chars? as usize;
+    if processed_prompt.chars().count() <= threshold {
+        return None;
+    }
+    let output_uses = prompt_template.matches("${output}").count();
+    if output_uses == 0 {
+        return None;
+    }
+
+    let prompt_chars = apply_llm_template_vars(
+        prompt_template,
+        &LlmTemplateContext {
+            output: String::new(),
+            ..template_context.clone()
+        },
+    )
+    .chars()
+    .count();
+    let chunk_chars = threshold.saturating_sub(prompt_chars) / output_uses;
+    if chunk_chars < MIN_POST_PROCESS_CHUNK_CHARS {
+        warn!(
+            "Post-processing prompt leaves {} chars for the transcript under the {} char chunk threshold; using {} char chunks",
+            chunk_chars, threshold, MIN_POST_PROCESS_CHUNK_CHARS
+        );
+    }
+    Some(chunk_chars.max(MIN_POST_PROCESS_CHUNK_CHARS))
+}
+
+/// Prompt details applied to the stitched output of a chunked run.
+struct PostProcessPrompt<'a> {
+    template: String,
+    output_limits: OutputLengthLimits,
+    output_json_path: Option<&'a str>,
+}
+
+/// Post-processes `template_context.output` in chunks of `chunk_chars`,
+/// one request at a time, and stitches the results together. A chunk whose
+/// request fails keeps its raw text; if every chunk fails the whole run is
+/// skipped. Cancellation is checked between chunks.
+async fn post_process_in_chunks(
+    settings: &AppSettings,
+    llm_tracker: &LlmOperationTracker,
+    operation_id: u64,
+    request: &PostProcessRequest<'_>,
+    template_context: &LlmTemplateContext,
+    chunk_chars: usize,
+    prompt: PostProcessPrompt<'_>,
+) -> PostProcessTranscriptionOutcome {
+    let chunks =
+        crate::post_process_chunking::split_into_chunks(&template_context.output, chunk_chars);
+    info!(
+        "Post-processing {} chars in {} chunks of up to {} chars",
+        template_context.output.chars().count(),
+        chunks.len(),
+        chunk_chars
+    );
+
+    let mut outputs = Vec::with_capacity(chunks.len());
+    let mut provider_id = None;
+    for (index, chunk) in chunks.iter().enumerate() {
+        if llm_tracker.is_cancelled(operation_id) {
+            debug!(
+                "LLM post-processing operation {} was cancelled before chunk {}",
+                operation_id,
+                index + 1
+            );
+            return PostProcessTranscriptionOutcome::Cancelled;
+        }
+
+        let chunk_prompt = apply_llm_template_vars(
+            &prompt.template,
+            &LlmTemplateContext {
+                output: chunk.text.clone(),
+                ..template_context.clone()
+            },
+        );
+        let Some(response) =
+            send_post_process_request(settings, llm_tracker, operation_id, request, &chunk_prompt)
+                .await
+        else {
+            return PostProcessTranscriptionOutcome::Cancelled;
+        };
+        match response.result {
+            Ok(Some(content)) if !content.trim().is_empty() => {
+                let content = strip_zero_width_chars(settings, content);
+                let (content, _) =
+                    extract_post_process_json_output(prompt.output_json_path, content);
+                provider_id = Some(response.provider.id);
+                outputs.push(content);
+            }
+            Ok(_) => {
+                warn!(
+                    "LLM post-processing of chunk {}/{} returned no content; keeping the raw chunk",
+                    index + 1,
+                    chunks.len()
+                );
+                outputs.push(chunk.text.clone());
+            }
+            Err(err) => {
+                warn!(
+                    "LLM post-processing of chunk {}/{} failed for provider '{}': {}. Keeping the raw chunk.",
+                    index + 1,
+                    chunks.len(),
+                    response.provider.id,
+                    err
+                );
+                outputs.push(chunk.text.clone());
+            }
+        }
+    }
+
+    if llm_tracker.is_cancelled(operation_id) {
+        return PostProcessTranscriptionOutcome::Cancelled;
+    }
+    let Some(provider_id) = provider_id else {
+        error!(
+            "LLM post-processing failed for every chunk. Falling back to original transcription."
+        );
+        return PostProcessTranscriptionOutcome::Skipped;
+    };
+
+    let stitched = crate::post_process_chunking::stitch_chunks(&chunks, &outputs);
+    // Re-asking would send the whole transcript again; always truncate.
+    let (text, output_limit_note) =
+        enforce_post_process_output_limits(settings, &prompt.output_limits, stitched, None).await;
+    PostProcessTranscriptionOutcome::Processed {
+        text,
+        prompt_template: prompt.template,
+        output_limit_note,
+        provider_id,
+        raw_response: None,
+        chunked: true,
+    }
+}
+
+/// Replaces `content` with the value at the prompt's JSON output path, returning
+/// the raw response alongside. Falls back to the raw response when it does not
+/// parse or the path is missing.
+fn extract_post_process_json_output(
+    output_json_path: Option<&str>,
+    content: String,
+) -> (String, Option<String>) {
+    let Some(path) = output_json_path else {
+        return (content, None);
+    };
+    match crate::llm_json_output::extract(&content, path) {
+        Ok(extracted) => {
+            debug!(
+                "Extracted JSON output path '{}' ({} of {} chars)",
+                path,
+                extracted.len(),
+                content.len()
+            );
+            (extracted, Some(content))
+        }
+        Err(e) => {
+            warn!(
+                "Failed to extract JSON output path '{}', using the raw response: {}",
+                path, e
+            );
+            (content, None)
+        }
+    }
+}
+
+/// Strip invisible Unicode characters that some LLMs (e.g., Qwen) may insert
+fn strip_zero_width_chars(settings: &AppSettings, content: String) -> String {
+    if settings.zero_width_filter_enabled {
+        content
+            .replace('\u{200B}', "") // Zero-Width Space
+            .replace('\u{200C}', "") // Zero-Width Non-Joiner
+            .replace('\u{200D}', "") // Zero-Width Joiner
+            .replace('\u{FEFF}', "") // Byte Order Mark / Zero-Width No-Break Space
+    } else {
+        content
+    }
+}
+
+/// Applies the profile's punctuation mode (falling back to the global one).
+/// Runs after custom words; never fails, `LlmLight` keeps the text as-is on error.
+async fn apply_punctuation_mode(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    text: &str,
+) -> String {
+    let mode = profile
+        .and_then(|p| p.punctuation_mode)
+        .unwrap_or(settings.punctuation_mode);
+    if is_blank_transcription(text) {
+        return text.to_string();
+    }
+
+    match mode {
+        PunctuationMode::AsIs => text.to_string(),
+        PunctuationMode::Strip => strip_non_final_punctuation(text),
+        PunctuationMode::LlmLight => match punctuate_with_llm(settings, profile, text).await {
+            Ok(punctuated) => punctuated,
+            Err(e) => {
+                warn!(
+                    "Light punctuation pass failed, keeping punctuation as-is: {}",
+                    e
+                );
+                text.to_string()
+            }
+        },
+    }
+}
+
+/// Minimal "punctuation only" LLM pass using the configured post-process
+/// provider, independent of whether post-processing itself is enabled.
+async fn punctuate_with_llm(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    text: &str,
+) -> Result<String, String> {
+    let provider = settings
+        .active_post_process_provider()
+        .cloned()
+        .ok_or_else(|| "No post-process provider is selected".to_string())?;
+    if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+        return Err("Apple Intelligence is not supported for punctuation".to_string());
+    }
+
+    let model = profile
+        .and_then(|p| p.llm_model_override.clone())
+        .filter(|m| !m.trim().is_empty())
+        .or_else(|| settings.post_process_models.get(&provider.id).cloned())
+        .unwrap_or_default();
+    if model.trim().is_empty() {
+        return Err(format!(
+            "No model configured for provider '{}'",
+            provider.label
+        ));
+    }
+
+    #[cfg(target_os = "windows")]
+    let api_key = crate::secure_keys::get_post_process_api_key(&provider.id);
+    #[cfg(not(target_os = "windows"))]
+    let api_key = settings
+        .post_process_api_keys
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+
+    let request = crate::llm_client::send_chat_completion_with_reasoning(
+        &provider,
+        api_key,
+        &model,
+        format!("{}{}", PUNCTUATION_LLM_PROMPT, text),
+        crate::llm_client::ReasoningConfig::new(false, settings.post_process_reasoning_budget)
+            .with_disable_by_default_on_compatible_providers(true),
+    );
+    let content = tokio::time::timeout(PUNCTUATION_LLM_TIMEOUT, request)
+        .await
+        .map_err(|_| {
+            format!(
+                "request timed out after {}s",
+                PUNCTUATION_LLM_TIMEOUT.as_secs()
+            )
+        })??
+        .ok_or_else(|| "LLM API response has no content".to_string())?;
+
+    let content = strip_zero_width_chars(settings, content).trim().to_string();
+    if content.is_empty() {
+        return Err("LLM API response is empty".to_string());
+    }
+    if !words_match_ignoring_punctuation(text, &content) {
+        return Err("LLM changed more than punctuation".to_string());
+    }
+    Ok(content)
+}
+
+fn output_translation_prompt(language: &str, text: &str) -> String {
+    format!(
+        "Translate the text below into {}. Keep its meaning and tone, and preserve the \
+formatting exactly: every line break, blank line, list marker and bit of markup stays where it is. \
+Return only the translation, without notes, quotes or explanations.\n\nText:\n{}",
+        language, text
+    )
+}
+
+/// Name of the target language as the translation prompt spells it out.
+fn output_translation_target(code: &str) -> String {
+    match code {
+        "zh-Hans" => "Simplified Chinese".to_string(),
+        "zh-Hant" => "Traditional Chinese".to_string(),
+        _ => {
+            let name = crate::language_toggle::language_display_name(code);
+            if name == code.to_uppercase() {
+                format!("the language with code \"{}\"", code)
+            } else {
+                name
+            }
+        }
+    }
+}
+
+/// Whether two language codes name the same output language. Chinese codes
+/// are compared in full because the script (zh-Hans/zh-Hant) matters.
+fn is_same_output_language(a: &str, b: &str) -> bool {
+    let primary = |code: &str| {
+        code.split(['-', '_'])
+            .next()
+            .unwrap_or(code)
+            .to_ascii_lowercase()
+    };
+    if primary(a) == "zh" {
+        return a.eq_ignore_ascii_case(b);
+    }
+    primary(a) == primary(b)
+}
+
+/// Language the output is translated into, or None when translation is off,
+/// the dictation language already matches, or the text was streamed live.
+fn resolve_output_translation_language<'a>(
+    settings: &'a AppSettings,
+    profile: Option<&'a TranscriptionProfile>,
+    requested_language: &str,
+) -> Option<&'a str> {
+    let target = settings
+        .output_translation_language_for(profile)
+        .map(str::trim)
+        .filter(|code| !code.is_empty())?;
+    if is_same_output_language(target, requested_language) {
+        debug!(
+            "Output translation skipped: dictation language '{}' already matches '{}'",
+            requested_language, target
+        );
+        return None;
+    }
+    if !post_process_allowed_for_provider(settings) {
+        debug!("Output translation skipped for live streaming transcription");
+        return None;
+    }
+    Some(target)
+}
+
+/// Translates the final text with the post-processing provider. Runs after
+/// post-processing, or on its own when post-processing is off.
+async fn translate_output(
+    app: &AppHandle,
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    text: &str,
+    language: &str,
+) -> OutputTranslationOutcome {
+    let Some(provider) = settings.active_post_process_provider().cloned() else {
+        warn!("Output translation skipped because no post-process provider is selected");
+        return OutputTranslationOutcome::Failed;
+    };
+    if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
+        warn!("Output translation is not supported with Apple Intelligence");
+        return OutputTranslationOutcome::Failed;
+    }
+
+    let model = profile
+        .and_then(|p| p.llm_model_override.clone())
+        .filter(|m| !m.trim().is_empty())
+        .or_else(|| settings.post_process_models.get(&provider.id).cloned())
+        .unwrap_or_default();
+    if model.trim().is_empty() {
+        warn!(
+            "Output translation skipped because provider '{}' has no model configured",
+            provider.id
+        );
+        return OutputTranslationOutcome::Failed;
+    }
+
+    let llm_tracker = app.state::<Arc<LlmOperationTracker>>();
+    let operation_id = llm_tracker.start_operation();
+    show_thinking_overlay(app);
+
+    debug!(
+        "Translating output into '{}' with provider '{}' (model: {})",
+        language, provider.id, model
+    );
+    let Some(result) = llm_tracker
+        .run_cancellable(
+            operation_id,
+            crate::llm_client::send_chat_completion_with_reasoning(
+                &provider,
+                settings.post_process_api_key(&provider.id),
+                &model,
+                output_translation_prompt(&output_translation_target(language), text),
+                crate::llm_client::ReasoningConfig::new(
+                    false,
+                    settings.post_process_reasoning_budget,
+                )
+                .with_disable_by_default_on_compatible_providers(true),
+            ),
+        )
+        .await
+    else {
+        debug!(
+            "Output translation operation {} was cancelled in flight",
+            operation_id
+        );
+        return OutputTranslationOutcome::Cancelled;
+    };
+    if llm_tracker.is_cancelled(operation_id) {
+        debug!(
+            "Output translation operation {} was cancelled, discarding result",
+            operation_id
+        );
+        return OutputTranslationOutcome::Cancelled;
+    }
+
+    match result {
+        Ok(Some(content)) => {
+            let content = strip_zero_width_chars(settings, content);
+            let translated = content.trim();
+            if translated.is_empty() {
+                warn!(
+                    "Output translation returned an empty response, keeping the untranslated text"
+                );
+                OutputTranslationOutcome::Failed
+            } else {
+                OutputTranslationOutcome::Translated(translated.to_string())
+            }
+        }
+        Ok(None) => {
+            warn!("Output translation response has no content, keeping the untranslated text");
+            OutputTranslationOutcome::Failed
+        }
+        Err(e) => {
+            warn!(
+                "Output translation into '{}' failed for provider '{}', keeping the untranslated text: {}",
+                language, provider.id, e
+            );
+            OutputTranslationOutcome::Failed
+        }
+    }
+}
+
+/// Everything needed to repeat a post-processing request with a "shorten" instruction.
+struct PostProcessReask<'a> {
+    provider: &'a PostProcessProvider,
+    api_key: String,
+    model: &'a str,
+    prompt: &'a str,
+    reasoning: crate::llm_client::ReasoningConfig,
+}
+
+/// Enforces the prompt's word/sentence limit on post-processed output. Runs before
+/// text replacements and the whitespace policy. Returns the (possibly shortened)
+/// text and a history note when enforcement occurred.
+async fn enforce_post_process_output_limits(
+    settings: &AppSettings,
+    limits: &OutputLengthLimits,
+    text: String,
+    reask: Option<PostProcessReask<'_>>,
+) -> (String, Option<String>) {
+    if limits.is_empty() || !limits.is_exceeded_by(&text) {
+        return (text, None);
+    }
+
+    let limit_label = limits.describe();
+    if settings.post_process_output_limit_strategy == OutputLimitStrategy::Reask {
+        if let Some(reask) = reask {
+            debug!(
+                "Post-processed output exceeds limit ({}); re-asking once",
+                limit_label
+            );
+            let prompt = format!("{}{}", reask.prompt, limits.shorten_instruction());
+            match crate::llm_client::send_chat_completion_with_reasoning(
+                reask.provider,
+                reask.api_key,
+                reask.model,
+                prompt,
+                reask.reasoning,
+            )
+            .await
+            {
+                Ok(Some(shortened)) if !shortened.trim().is_empty() => {
+                    let shortened = strip_zero_width_chars(settings, shortened);
+                    if !limits.is_exceeded_by(&shortened) {
+                        return (
+                            shortened,
+                            Some(format!("Output shortened by re-asking ({})", limit_label)),
+                        );
+                    }
+                    return (
+                        truncate_to_limits(&shortened, limits),
+                        Some(format!(
+                            "Output re-asked and truncated to limit ({})",
+                            limit_label
+                        )),
+                    );
+                }
+                Ok(_) => warn!("Shortening re-ask returned no content; truncating instead"),
+                Err(e) => warn!("Shortening re-ask failed: {}; truncating instead", e),
+            }
+        }
+    }
+
+    debug!(
+        "Post-processed output exceeds limit ({}); truncating",
+        limit_label
+    );
+    (
+        truncate_to_limits(&text, limits),
+        Some(format!("Output truncated to limit ({})", limit_label)),
+    )
+}
+
 fn build_llm_post_process_benchmark_result(
     timestamp_ms: i64,
     provider_id: String,
@@ -1248,6 +2398,13 @@ pub(crate) struct ProcessedTranscription {
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
     pub post_process_requested: bool,
+    pub post_process_note: Option<String>,
+    /// LLM provider that produced `post_processed_text`, when the LLM ran.
+    pub post_process_provider: Option<String>,
+    /// Full LLM response when the prompt's JSON output path was extracted.
+    pub post_process_raw_response: Option<String>,
+    /// The LLM processed the transcript in chunks.
+    pub post_process_chunked: bool,
 }
 
 pub(crate) fn reset_toggle_state(app: &AppHandle, binding_id: &str) {
@@ -1276,6 +2433,8 @@ struct FinishGuard {
     binding_id: String,
     operation_id: u64,
     finished: bool,
+    /// Cancel shortcut kept registered through Processing, released on finish.
+    holds_cancel_shortcut: bool,
 }
 
 impl FinishGuard {
@@ -1285,9 +2444,15 @@ impl FinishGuard {
             binding_id,
             operation_id,
             finished: false,
+            holds_cancel_shortcut: false,
         }
     }
 
+    fn holding_cancel_shortcut(mut self, holds_cancel_shortcut: bool) -> Self {
+        self.holds_cancel_shortcut = holds_cancel_shortcut;
+        self
+    }
+
     fn finish(&mut self) {
         if self.finished {
             return;
@@ -1298,6 +2463,13 @@ impl FinishGuard {
             "FinishGuard: finishing shortcut '{}' operation {}",
             self.binding_id, self.operation_id
         );
+        // Skip if a new recording already started and registered its own.
+        if self.holds_cancel_shortcut
+            && session_manager::current_session_event().state
+                != session_manager::SessionPhase::Recording
+        {
+            crate::shortcut::unregister_cancel_shortcut(&self.app);
+        }
         if session_manager::exit_processing_if_matches(&self.app, self.operation_id) {
             reset_toggle_state(&self.app, &self.binding_id);
         } else {
@@ -1337,6 +2509,15 @@ fn show_ai_replace_error_overlay(app: &AppHandle, message: impl Into<String>) {
 }
 
 fn show_recording_start_error_overlay(app: &AppHandle, error: &StartRecordingError) {
+    if let Some(failure) = error
+        .microphone_failure()
+        .filter(|failure| *failure != MicrophoneOpenFailure::Other)
+    {
+        error!("Recording start error ({:?}): {}", failure, error);
+        crate::plus_overlay_state::show_mic_error_overlay(app, Some(failure));
+        return;
+    }
+
     let category = if error.is_microphone_related() {
         crate::plus_overlay_state::OverlayErrorCategory::MicrophoneUnavailable
     } else {
@@ -1353,6 +2534,29 @@ struct RecordingErrorPayload {
     detail: String,
 }
 
+/// Saves an AI Replace entry to history in the background unless privacy mode
+/// skips it. `response` is None when the LLM never answered.
+fn spawn_save_ai_replace_history(
+    app: &AppHandle,
+    instruction: String,
+    selection: String,
+    response: Option<String>,
+    rejected: bool,
+) {
+    let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
+    tauri::async_runtime::spawn(async move {
+        if crate::privacy_mode::should_skip("AI Replace history entry") {
+            return;
+        }
+        if let Err(e) = hm
+            .save_ai_replace_entry(instruction, selection, response, rejected)
+            .await
+        {
+            error!("Failed to save AI Replace entry to history: {}", e);
+        }
+    });
+}
+
 fn maybe_restore_ai_replace_selection(
     app: &AppHandle,
     original_text: &str,
@@ -1395,13 +2599,24 @@ fn maybe_restore_ai_replace_selection(
 /// IMPORTANT: We hold the session state lock throughout the entire operation to prevent
 /// race conditions when the user rapidly presses the shortcut key.
 fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
-    let settings = get_settings(app);
+    let mut settings = get_settings(app);
+    // An armed boost only changes this recording's settings snapshot; it is
+    // consumed below once the start is accepted.
+    let boost = crate::boost::armed_override();
+    if let Some(boost) = &boost {
+        boost.apply(&mut settings);
+    }
+    if interview::is_interview_binding(&settings, binding_id) {
+        interview::disable_streaming(&mut settings);
+    }
 
     // Load model in the background if using local transcription
     let tm = app.state::<Arc<TranscriptionManager>>();
     if settings.transcription_provider == TranscriptionProvider::Local {
         let rm = Arc::clone(&app.state::<Arc<AudioRecordingManager>>());
-        tm.initiate_model_load();
+        // Keep an already loaded model from idling out between start and stop.
+        tm.touch_activity();
+        tm.initiate_model_load_for(&settings.selected_model);
         std::thread::spawn(move || {
             if let Err(e) = rm.preload_audio_recorder() {
                 debug!("Audio recorder pre-load failed: {}", e);
@@ -1469,6 +2684,15 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
         "start_recording_with_feedback: captured_profile_id={:?} for binding={}",
         captured_profile_id, binding_id
     );
+    // Live providers type text as it arrives; clipboard and file destinations
+    // need the finished text, so those profiles record buffered.
+    if captured_profile_id
+        .as_ref()
+        .and_then(|profile_id| settings.transcription_profile(profile_id))
+        .is_some_and(|profile| !profile.inserts_at_cursor())
+    {
+        disable_streaming_insert(&mut settings);
+    }
 
     let should_latch_decapitalize_for_standard_output = is_transcribe_binding_id(binding_id)
         && settings.text_replacement_decapitalize_after_edit_key_enabled
@@ -1481,22 +2705,30 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
         should_use_native_local_streaming(app, &settings, captured_profile, binding_id);
     let native_stream_direct_output =
         use_native_local_streaming && native_streaming_live_output_enabled(&settings);
-    let native_stream_language = captured_profile
-        .map(|profile| profile.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let native_stream_language = settings.single_engine_language(captured_profile);
     let native_stream_translate = captured_profile
         .map(|profile| profile.translate_to_english)
         .unwrap_or(settings.translate_to_english);
+    // Resolved once here so a profile switch mid-session never moves the mic.
+    let input_device_override =
+        captured_profile.and_then(|profile| profile.input_device_override.clone());
 
     let operation_id = session_manager::next_operation_id();
-    *state_guard = session_manager::SessionState::Recording {
-        session: Arc::clone(&session),
-        binding_id: binding_id.to_string(),
-        operation_id,
-        started_at: Instant::now(),
-        captured_profile_id,
-        captured_settings: settings.clone(),
-    };
+    if boost.is_some() {
+        crate::boost::consume(app, operation_id);
+    }
+    session_manager::replace_state(
+        app,
+        &mut state_guard,
+        session_manager::SessionState::Recording {
+            session: Arc::clone(&session),
+            binding_id: binding_id.to_string(),
+            operation_id,
+            started_at: Instant::now(),
+            captured_profile_id,
+            captured_settings: settings.clone(),
+        },
+    );
 
     // Capture the active app context at recording start for prompt variables.
     capture_recording_app_context(binding_id);
@@ -1528,6 +2760,7 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
                 }));
             }
             TranscriptionProvider::RemoteOpenAiCompatible
+            | TranscriptionProvider::RemoteOpenAiRealtime
                 if should_use_openai_realtime_whisper_live(&settings) =>
             {
                 let openai_realtime_whisper_manager =
@@ -1573,15 +2806,18 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
             stream_router.feed(&frame);
         }));
     }
-    let is_always_on = settings.always_on_microphone;
-    debug!("Microphone mode - always_on: {}", is_always_on);
+    let is_always_on = settings.microphone_mode_for_binding(binding_id) == MicMode::AlwaysOn;
+    debug!(
+        "Microphone mode for '{}' - always_on: {}",
+        binding_id, is_always_on
+    );
 
     let mut recording_error: Option<StartRecordingError> = None;
     let mut recording_started_at: Option<Instant> = None;
     if is_always_on {
         // Always-on mode: the stream is already open, but still arm recording before UI/audio feedback.
         debug!("Always-on mode: Starting recording before audio feedback");
-        match rm.try_start_recording_detailed(binding_id) {
+        match rm.try_start_recording_detailed(binding_id, input_device_override.as_deref()) {
             Ok(()) => {
                 recording_started_at = Some(Instant::now());
                 rm.apply_media_pause();
@@ -1602,7 +2838,7 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
         // On-demand mode: Start recording first, then play audio feedback, then apply mute
         debug!("On-demand mode: Starting recording first, then audio feedback");
         let recording_start_time = Instant::now();
-        match rm.try_start_recording_detailed(binding_id) {
+        match rm.try_start_recording_detailed(binding_id, input_device_override.as_deref()) {
             Ok(()) => {
                 recording_started_at = Some(Instant::now());
                 rm.apply_media_pause();
@@ -1671,7 +2907,10 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
 
         // Register cancel shortcut now that recording is confirmed
         session.register_cancel_shortcut();
+        session.start_elapsed_timer(binding_id);
         crate::recording_auto_stop::start_auto_stop_timer(app, binding_id);
+        crate::recording_auto_stop::start_max_duration_timer(app, binding_id);
+        crate::recording_auto_stop::start_silence_auto_stop(app, binding_id);
         change_tray_icon(app, TrayIconState::Recording);
         show_recording_overlay(app);
     } else {
@@ -1693,7 +2932,7 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
         let state = app.state::<ManagedSessionState>();
         let mut state_guard =
             session_manager::lock_session_state(&state, "start_recording_with_feedback cleanup");
-        *state_guard = session_manager::SessionState::Idle;
+        session_manager::replace_state(app, &mut state_guard, session_manager::SessionState::Idle);
         drop(state_guard);
 
         if let Some(err) = recording_error.as_ref() {
@@ -1701,6 +2940,10 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
             let detail = err.to_string();
             let error_type = if is_microphone_access_denied(&detail) {
                 "microphone_permission_denied"
+            } else if err.microphone_failure() == Some(MicrophoneOpenFailure::DeviceInUse) {
+                "microphone_in_use"
+            } else if err.microphone_failure() == Some(MicrophoneOpenFailure::FormatNotSupported) {
+                "microphone_format_not_supported"
             } else if is_no_input_device_error(&detail) {
                 "no_input_device"
             } else {
@@ -1711,7 +2954,7 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
                 RecordingErrorPayload { error_type, detail },
             );
         } else {
-            crate::plus_overlay_state::show_mic_error_overlay(app);
+            crate::plus_overlay_state::show_mic_error_overlay(app, None);
         }
     }
 
@@ -1723,7 +2966,15 @@ fn start_recording_with_feedback(app: &AppHandle, binding_id: &str) -> bool {
 /// Result of a transcription operation
 pub enum TranscriptionOutcome {
     /// Transcription succeeded with the given text
-    Success(String),
+    Success {
+        text: String,
+        /// Average token confidence (0.0–1.0); `None` if the provider reports none
+        confidence: Option<f32>,
+        /// Segment timings for history; `None` unless a local engine reported them
+        segments: Option<Vec<SubtitleSegment>>,
+        /// Provider whose text won a race-mode run; `None` outside race mode
+        race_winner: Option<&'static str>,
+    },
     /// Operation was cancelled (Remote STT only)
     Cancelled,
     /// Error occurred - for Remote STT, error is already shown in overlay
@@ -1735,17 +2986,30 @@ pub enum TranscriptionOutcome {
     },
 }
 
+/// What history records about a transcription besides its text.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TranscriptDetails {
+    /// Segment timings; `None` unless a local engine reported them
+    pub(crate) segments: Option<Vec<SubtitleSegment>>,
+    /// Average token confidence; `None` if the provider reports none
+    pub(crate) confidence: Option<f32>,
+    /// Provider whose text won a race-mode run; `None` outside race mode
+    pub(crate) race_winner: Option<&'static str>,
+}
+
 struct StoppedTranscribeRecording {
     samples: Vec<f32>,
     pre_saved_file_name: Option<String>,
     post_process_requested: bool,
     quick_tap_skipped: bool,
+    /// Separate mic/system channels when the binding is in interview mode.
+    interview: Option<InterviewChannels>,
 }
 
 /// Detailed result for transcription fetch + cleanup decisions.
 /// Used when callers need to decide whether UI cleanup is still required.
 enum TranscriptionFetchOutcome {
-    Success((String, Vec<f32>)),
+    Success((String, Vec<f32>, TranscriptDetails)),
     Cancelled,
     ErrorOverlayShown,
     ErrorNoOverlay,
@@ -1809,7 +3073,55 @@ async fn perform_transcription_for_profile_with_retry_action(
         profile.as_ref().map(|p| &p.name)
     );
 
+    // Callers keep their own copy of the raw samples for history, so only the
+    // audio sent to the engine is filtered.
+    let samples = if settings.audio_preprocessing_enabled {
+        crate::audio_toolkit::audio::preprocess_for_transcription(
+            &samples,
+            crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE,
+            settings.audio_preprocessing_high_pass_hz,
+        )
+    } else {
+        samples
+    };
+
+    if let Some(local_model) = race_local_model(app, settings) {
+        return race_local_and_remote_transcription(
+            app,
+            samples,
+            binding_id,
+            profile,
+            settings,
+            local_model,
+            include_retry_action,
+            show_remote_error,
+        )
+        .await;
+    }
+
+    transcribe_with_selected_provider(
+        app,
+        samples,
+        binding_id,
+        profile,
+        settings,
+        include_retry_action,
+        show_remote_error,
+    )
+    .await
+}
+
+async fn transcribe_with_selected_provider(
+    app: &AppHandle,
+    samples: Vec<f32>,
+    binding_id: Option<&str>,
+    profile: Option<&TranscriptionProfile>,
+    settings: &AppSettings,
+    include_retry_action: bool,
+    show_remote_error: bool,
+) -> TranscriptionOutcome {
     let preview_output_only_enabled = should_route_output_to_preview(settings, profile);
+    let custom_words = settings.custom_words_for_profile(profile);
 
     if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiCompatible {
         // Determine translate_to_english: use profile setting if available, otherwise global setting
@@ -1818,11 +3130,9 @@ async fn perform_transcription_for_profile_with_retry_action(
             .map(|p| p.translate_to_english)
             .unwrap_or(settings.translate_to_english);
 
-        // Determine language: use profile setting if available, otherwise global setting
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
+        // Determine language: use profile setting if available, otherwise global setting.
+        // The endpoint takes a single language, so multilingual profiles use auto-detect.
+        let language = settings.single_engine_language(profile);
 
         // Log the request details
         if let Some(p) = &profile {
@@ -1863,8 +3173,8 @@ async fn perform_transcription_for_profile_with_retry_action(
             }
         } else {
             let prompt = crate::settings::resolve_stt_prompt(
+                settings,
                 profile,
-                &settings.transcription_prompts,
                 &settings.remote_stt.model_id,
             );
 
@@ -1880,10 +3190,10 @@ async fn perform_transcription_for_profile_with_retry_action(
         }
         .map(|text| {
             // Apply custom word corrections
-            let corrected = if settings.custom_words_enabled && !settings.custom_words.is_empty() {
+            let corrected = if settings.custom_words_enabled && !custom_words.is_empty() {
                 apply_custom_words(
                     &text,
-                    &settings.custom_words,
+                    &custom_words,
                     settings.word_correction_threshold,
                     settings.custom_words_ngram_enabled,
                 )
@@ -1912,7 +3222,12 @@ async fn perform_transcription_for_profile_with_retry_action(
         }
 
         match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
+            Ok(text) => TranscriptionOutcome::Success {
+                text,
+                confidence: None,
+                segments: None,
+                race_winner: None,
+            },
             Err(err) => {
                 let err_str = format!("{}", err);
                 if show_remote_error {
@@ -1926,10 +3241,7 @@ async fn perform_transcription_for_profile_with_retry_action(
         }
     } else if settings.transcription_provider == TranscriptionProvider::RemoteSoniox {
         // Determine language: use profile setting if available, otherwise global setting
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
+        let language = settings.requested_language(profile);
 
         #[cfg(target_os = "windows")]
         let api_key = crate::secure_keys::get_soniox_api_key();
@@ -1968,11 +3280,18 @@ async fn perform_transcription_for_profile_with_retry_action(
             && binding_id
                 .map(|id| id == "transcribe" || id.starts_with("transcribe_profile_"))
                 .unwrap_or(false);
+        let include_speaker_labels =
+            settings.soniox_enable_speaker_diarization && settings.soniox_include_speaker_labels;
 
         let result = if should_stream_insert {
             let app_handle = app.clone();
-            let stream_processor =
-                Arc::new(Mutex::new(SonioxStreamProcessor::from_settings(&settings)));
+            let stream_processor = Arc::new(Mutex::new(
+                SonioxStreamProcessor::from_settings(settings, custom_words.clone())
+                    .with_output_casing(
+                        settings.output_casing_for(profile),
+                        &settings.output_casing_exceptions,
+                    ),
+            ));
             let stream_processor_for_callback = Arc::clone(&stream_processor);
             let soniox_manager_for_callback = Arc::clone(&soniox_manager);
             let streamed_result = soniox_manager
@@ -2024,14 +3343,16 @@ async fn perform_transcription_for_profile_with_retry_action(
                 )
                 .await;
 
+            // Streamed chunks are pasted as they arrive, so speaker labels never
+            // apply here.
             match streamed_result {
-                Ok(text) => match stream_processor.lock() {
+                Ok(transcript) => match stream_processor.lock() {
                     Ok(mut processor) => {
                         let tail_delta = processor.flush();
                         drop(processor);
 
                         if tail_delta.is_empty() {
-                            Ok(text)
+                            Ok(transcript)
                         } else {
                             let ah_for_call = app.clone();
                             let ah_for_closure = ah_for_call.clone();
@@ -2049,7 +3370,7 @@ async fn perform_transcription_for_profile_with_retry_action(
                                     ah_for_closure.clone(),
                                 );
                             }) {
-                                Ok(_) => Ok(text),
+                                Ok(_) => Ok(transcript),
                                 Err(err) => Err(anyhow::anyhow!(
                                     "Failed to queue stream tail paste: {}",
                                     err
@@ -2086,7 +3407,6 @@ async fn perform_transcription_for_profile_with_retry_action(
                         soniox_options,
                     )
                     .await
-                    .map(|transcript| transcript.text)
             } else if optimized_delivery_enabled && soniox_live_manager.has_active_session() {
                 let fallback_transcribe = || async {
                     soniox_manager
@@ -2098,6 +3418,7 @@ async fn perform_transcription_for_profile_with_retry_action(
                             &samples,
                             Some(language.as_str()),
                             soniox_context.clone(),
+                            include_speaker_labels,
                         )
                         .await
                 };
@@ -2120,7 +3441,11 @@ async fn perform_transcription_for_profile_with_retry_action(
                             optimized_ms,
                             text.len()
                         );
-                        Ok(text)
+                        Ok(SonioxAsyncTranscript {
+                            confidence: soniox_live_manager.take_last_confidence(),
+                            speaker_blocks: soniox_live_manager.take_last_speaker_blocks(),
+                            ..SonioxAsyncTranscript::from(text)
+                        })
                     }
                     Ok(_) => {
                         warn!(
@@ -2151,6 +3476,7 @@ async fn perform_transcription_for_profile_with_retry_action(
                         &samples,
                         Some(language.as_str()),
                         soniox_context.clone(),
+                        include_speaker_labels,
                     )
                     .await
             }
@@ -2168,14 +3494,13 @@ async fn perform_transcription_for_profile_with_retry_action(
                     soniox_options,
                 )
                 .await
-                .map(|transcript| transcript.text)
         };
 
-        let result = result.map(|text| {
-            let corrected = if settings.custom_words_enabled && !settings.custom_words.is_empty() {
+        let postprocess = |text: String| {
+            let corrected = if settings.custom_words_enabled && !custom_words.is_empty() {
                 apply_custom_words(
                     &text,
-                    &settings.custom_words,
+                    &custom_words,
                     settings.word_correction_threshold,
                     settings.custom_words_ngram_enabled,
                 )
@@ -2191,6 +3516,19 @@ async fn perform_transcription_for_profile_with_retry_action(
             } else {
                 corrected
             }
+        };
+        let result = result.map(|transcript| {
+            let confidence = transcript.confidence;
+            // Labeled output is corrected per utterance so the "Speaker N:"
+            // prefixes stay intact.
+            if include_speaker_labels {
+                if let Some(labeled) =
+                    render_speaker_labeled_transcript(transcript.speaker_blocks, &postprocess)
+                {
+                    return (labeled, confidence);
+                }
+            }
+            (postprocess(transcript.text), confidence)
         });
 
         if soniox_manager.is_cancelled(operation_id) {
@@ -2202,7 +3540,12 @@ async fn perform_transcription_for_profile_with_retry_action(
         }
 
         match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
+            Ok((text, confidence)) => TranscriptionOutcome::Success {
+                text,
+                confidence,
+                segments: None,
+                race_winner: None,
+            },
             Err(err) => {
                 let err_str = format!("{}", err);
                 if soniox_manager.is_cancelled(operation_id)
@@ -2210,19 +3553,18 @@ async fn perform_transcription_for_profile_with_retry_action(
                 {
                     return TranscriptionOutcome::Cancelled;
                 }
-                let _ = app.emit("remote-stt-error", err_str.clone());
-                crate::plus_overlay_state::handle_transcription_error(app, &err_str);
+                if show_remote_error {
+                    let _ = app.emit("remote-stt-error", err_str.clone());
+                    crate::plus_overlay_state::handle_transcription_error(app, &err_str);
+                }
                 TranscriptionOutcome::Error {
                     message: err_str,
-                    shown_in_overlay: true,
+                    shown_in_overlay: show_remote_error,
                 }
             }
         }
     } else if settings.transcription_provider == TranscriptionProvider::RemoteDeepgram {
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
+        let language = settings.requested_language(profile);
 
         #[cfg(target_os = "windows")]
         let api_key = crate::secure_keys::get_deepgram_api_key();
@@ -2265,17 +3607,16 @@ async fn perform_transcription_for_profile_with_retry_action(
             )
             .await
             .map(|text| {
-                let corrected =
-                    if settings.custom_words_enabled && !settings.custom_words.is_empty() {
-                        apply_custom_words(
-                            &text,
-                            &settings.custom_words,
-                            settings.word_correction_threshold,
-                            settings.custom_words_ngram_enabled,
-                        )
-                    } else {
-                        text
-                    };
+                let corrected = if settings.custom_words_enabled && !custom_words.is_empty() {
+                    apply_custom_words(
+                        &text,
+                        &custom_words,
+                        settings.word_correction_threshold,
+                        settings.custom_words_ngram_enabled,
+                    )
+                } else {
+                    text
+                };
                 if settings.filler_word_filter_enabled {
                     crate::audio_toolkit::filter_transcription_output(
                         &corrected,
@@ -2296,7 +3637,12 @@ async fn perform_transcription_for_profile_with_retry_action(
         }
 
         match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
+            Ok(text) => TranscriptionOutcome::Success {
+                text,
+                confidence: None,
+                segments: None,
+                race_winner: None,
+            },
             Err(err) => {
                 let err_str = format!("{}", err);
                 if deepgram_manager.is_cancelled(operation_id)
@@ -2304,69 +3650,650 @@ async fn perform_transcription_for_profile_with_retry_action(
                 {
                     return TranscriptionOutcome::Cancelled;
                 }
-                let _ = app.emit("remote-stt-error", err_str.clone());
-                crate::plus_overlay_state::handle_transcription_error(app, &err_str);
+                if show_remote_error {
+                    let _ = app.emit("remote-stt-error", err_str.clone());
+                    crate::plus_overlay_state::handle_transcription_error(app, &err_str);
+                }
+                TranscriptionOutcome::Error {
+                    message: err_str,
+                    shown_in_overlay: show_remote_error,
+                }
+            }
+        }
+    } else if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiRealtime {
+        let language = settings.single_engine_language(profile);
+        log::info!(
+            "Transcription using OpenAI Realtime: base_url={}, model={}, lang={}",
+            settings.openai_realtime_base_url,
+            settings.openai_realtime_model,
+            language
+        );
+
+        let openai_realtime_manager = app.state::<Arc<OpenAiRealtimeManager>>();
+        let operation_id = openai_realtime_manager.start_operation();
+        let result = openai_realtime_manager
+            .transcribe(
+                operation_id,
+                &samples,
+                build_openai_realtime_whisper_options(settings, &language),
+            )
+            .await
+            .map(|text| {
+                let corrected = if settings.custom_words_enabled && !custom_words.is_empty() {
+                    apply_custom_words(
+                        &text,
+                        &custom_words,
+                        settings.word_correction_threshold,
+                        settings.custom_words_ngram_enabled,
+                    )
+                } else {
+                    text
+                };
+                if settings.filler_word_filter_enabled {
+                    crate::audio_toolkit::filter_transcription_output(
+                        &corrected,
+                        language.as_str(),
+                        &settings.custom_filler_words,
+                    )
+                } else {
+                    corrected
+                }
+            });
+
+        if openai_realtime_manager.is_cancelled(operation_id) {
+            debug!(
+                "OpenAI Realtime transcription operation {} was cancelled, discarding result",
+                operation_id
+            );
+            return TranscriptionOutcome::Cancelled;
+        }
+
+        match result {
+            Ok(text) => TranscriptionOutcome::Success {
+                text,
+                confidence: None,
+                segments: None,
+                race_winner: None,
+            },
+            Err(err) => {
+                let err_str = format!("{}", err);
+                if show_remote_error {
+                    let _ = app.emit("remote-stt-error", err_str.clone());
+                    crate::plus_overlay_state::handle_transcription_error(app, &err_str);
+                }
+                TranscriptionOutcome::Error {
+                    message: err_str,
+                    shown_in_overlay: show_remote_error,
+                }
+            }
+        }
+    } else {
+        let tm = app.state::<Arc<TranscriptionManager>>();
+
+        if let Err(err) = tm.ensure_model_loaded(&settings.selected_model) {
+            let err_str = format!("{}", err);
+            debug!("Local transcription model load failed: {}", err_str);
+            return TranscriptionOutcome::Error {
+                message: err_str,
+                shown_in_overlay: false,
+            };
+        }
+
+        // Keep the samples for a single retry with the fallback model.
+        let fallback =
+            local_fallback_model(app, settings).map(|model_id| (model_id, samples.clone()));
+
+        let result = transcribe_with_local_model(&tm, settings, profile, samples);
+        let result = match (result, fallback) {
+            (Ok(transcript), Some((model_id, samples))) => Ok(retry_with_local_fallback_model(
+                &tm, settings, profile, &model_id, samples, transcript,
+            )),
+            (result, _) => result,
+        };
+
+        match result {
+            Ok(transcript) => TranscriptionOutcome::Success {
+                text: transcript.text,
+                confidence: None,
+                segments: transcript.segments,
+                race_winner: None,
+            },
+            Err(err) => {
+                let err_str = format!("{}", err);
+                debug!("Local transcription error: {}", err_str);
                 TranscriptionOutcome::Error {
                     message: err_str,
-                    shown_in_overlay: true,
+                    shown_in_overlay: false,
+                }
+            }
+        }
+    }
+}
+
+/// Runs `samples` through the selected local model, with profile overrides
+/// when a profile is given.
+fn transcribe_with_local_model(
+    tm: &TranscriptionManager,
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    samples: Vec<f32>,
+) -> anyhow::Result<LocalTranscript> {
+    if let Some(p) = profile {
+        log::info!(
+            "Transcription using Local model '{}' with profile '{}' (lang={}, translate={})",
+            settings.selected_model,
+            p.name,
+            p.single_engine_language(),
+            p.translate_to_english
+        );
+        tm.transcribe_with_overrides(
+            samples,
+            Some(p.single_engine_language()),
+            Some(p.translate_to_english),
+            // Use select_stt_prompt to respect stt_prompt_override_enabled flag;
+            // the engine expands ${custom_words} itself.
+            crate::settings::select_stt_prompt(
+                Some(p),
+                &settings.transcription_prompts,
+                &settings.selected_model,
+            ),
+            Some(settings.custom_words_for_profile(Some(p))),
+            settings.custom_words_enabled,
+        )
+    } else {
+        log::info!(
+            "Transcription using Local model: {}",
+            settings.selected_model
+        );
+        tm.transcribe(samples, settings.custom_words_enabled)
+    }
+}
+
+/// The local model to race against the remote provider, if race mode applies.
+/// Providers that paste while streaming are left alone, and a model that is
+/// not downloaded skips the race.
+fn race_local_model(app: &AppHandle, settings: &AppSettings) -> Option<String> {
+    if !settings.transcription_race_mode {
+        return None;
+    }
+    let streams_output = match settings.transcription_provider {
+        TranscriptionProvider::Local => return None,
+        TranscriptionProvider::RemoteOpenAiCompatible => false,
+        TranscriptionProvider::RemoteSoniox => settings.soniox_live_enabled,
+        TranscriptionProvider::RemoteDeepgram => settings.deepgram_live_enabled,
+        TranscriptionProvider::RemoteOpenAiRealtime => {
+            !settings.openai_realtime_whisper_flatten_enabled
+        }
+    };
+    let model_id = settings.selected_model.trim();
+    if streams_output || model_id.is_empty() {
+        return None;
+    }
+    match app.state::<Arc<ModelManager>>().get_model_info(model_id) {
+        Some(info) if info.is_downloaded => Some(model_id.to_string()),
+        _ => {
+            debug!(
+                "Race mode skipped: local model '{}' is not downloaded",
+                model_id
+            );
+            None
+        }
+    }
+}
+
+fn cancel_remote_provider_operation(app: &AppHandle, provider: TranscriptionProvider) {
+    match provider {
+        TranscriptionProvider::Local => {}
+        TranscriptionProvider::RemoteOpenAiCompatible => {
+            app.state::<Arc<RemoteSttManager>>().cancel()
+        }
+        TranscriptionProvider::RemoteSoniox => app.state::<Arc<SonioxSttManager>>().cancel(),
+        TranscriptionProvider::RemoteDeepgram => app.state::<Arc<DeepgramSttManager>>().cancel(),
+        TranscriptionProvider::RemoteOpenAiRealtime => {
+            app.state::<Arc<OpenAiRealtimeManager>>().cancel()
+        }
+    }
+}
+
+fn has_transcribed_text(outcome: &TranscriptionOutcome) -> bool {
+    matches!(outcome, TranscriptionOutcome::Success { text, .. } if !text.trim().is_empty())
+}
+
+/// Which side of a race produced the kept outcome.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RaceSide {
+    Remote,
+    Local,
+}
+
+/// The outcome a race keeps; `winner` is `None` when neither side produced
+/// text or the remote side was cancelled.
+struct RaceOutcome {
+    outcome: TranscriptionOutcome,
+    winner: Option<RaceSide>,
+}
+
+/// Keeps whichever side returns non-empty text first. The first side to
+/// finish with text wins and the other is stopped through its cancel
+/// callback; its future is dropped, so a late result is never looked at.
+/// When the first side fails the other is awaited, and when neither
+/// produces text the remote outcome is returned.
+async fn run_transcription_race<R, L>(
+    remote: R,
+    local: L,
+    cancel_remote: impl FnOnce(),
+    cancel_local: impl FnOnce(),
+) -> RaceOutcome
+where
+    R: std::future::Future<Output = TranscriptionOutcome>,
+    L: std::future::Future<Output = TranscriptionOutcome>,
+{
+    let remote = std::pin::pin!(remote);
+    let local = std::pin::pin!(local);
+    let (remote_outcome, local_outcome) = match futures::future::select(remote, local).await {
+        futures::future::Either::Left((remote_outcome, local)) => {
+            if has_transcribed_text(&remote_outcome) {
+                cancel_local();
+                return RaceOutcome {
+                    outcome: remote_outcome,
+                    winner: Some(RaceSide::Remote),
+                };
+            }
+            if matches!(remote_outcome, TranscriptionOutcome::Cancelled) {
+                cancel_local();
+                return RaceOutcome {
+                    outcome: remote_outcome,
+                    winner: None,
+                };
+            }
+            (remote_outcome, local.await)
+        }
+        futures::future::Either::Right((local_outcome, remote)) => {
+            if has_transcribed_text(&local_outcome) {
+                cancel_remote();
+                return RaceOutcome {
+                    outcome: local_outcome,
+                    winner: Some(RaceSide::Local),
+                };
+            }
+            (remote.await, local_outcome)
+        }
+    };
+
+    if has_transcribed_text(&remote_outcome) {
+        RaceOutcome {
+            outcome: remote_outcome,
+            winner: Some(RaceSide::Remote),
+        }
+    } else if has_transcribed_text(&local_outcome) {
+        RaceOutcome {
+            outcome: local_outcome,
+            winner: Some(RaceSide::Local),
+        }
+    } else {
+        RaceOutcome {
+            outcome: remote_outcome,
+            winner: None,
+        }
+    }
+}
+
+/// Runs the remote provider and the local model on the same samples and keeps
+/// whichever returns non-empty text first (see `run_transcription_race`). The
+/// remote side never reports errors itself, so a loser failing late cannot
+/// disturb the overlay. Only when neither produces text is the remote error
+/// shown.
+#[allow(clippy::too_many_arguments)]
+async fn race_local_and_remote_transcription(
+    app: &AppHandle,
+    samples: Vec<f32>,
+    binding_id: Option<&str>,
+    profile: Option<&TranscriptionProfile>,
+    settings: &AppSettings,
+    local_model: String,
+    include_retry_action: bool,
+    show_remote_error: bool,
+) -> TranscriptionOutcome {
+    info!(
+        "Race mode: transcribing with {} and local model '{}'",
+        settings.transcription_provider.as_str(),
+        local_model
+    );
+    let tm = app.state::<Arc<TranscriptionManager>>().inner().clone();
+    let race_id = tm.start_race_operation();
+    let local_settings = settings.clone();
+    let local_profile = profile.cloned();
+    let local_samples = samples.clone();
+    let local_tm = Arc::clone(&tm);
+    let local = tauri::async_runtime::spawn_blocking(move || -> anyhow::Result<LocalTranscript> {
+        let tm = local_tm;
+        let ensure_current = || {
+            if tm.is_race_cancelled(race_id) {
+                anyhow::bail!("Local race transcription was cancelled");
+            }
+            Ok(())
+        };
+        ensure_current()?;
+        tm.ensure_model_loaded(&local_model)?;
+        ensure_current()?;
+        let transcript = transcribe_with_local_model(
+            &tm,
+            &local_settings,
+            local_profile.as_ref(),
+            local_samples,
+        )?;
+        ensure_current()?;
+        Ok(transcript)
+    });
+    let local = async move {
+        match local.await {
+            Ok(Ok(transcript)) => TranscriptionOutcome::Success {
+                text: transcript.text,
+                confidence: None,
+                segments: transcript.segments,
+                race_winner: None,
+            },
+            Ok(Err(err)) => TranscriptionOutcome::Error {
+                message: err.to_string(),
+                shown_in_overlay: false,
+            },
+            Err(err) => TranscriptionOutcome::Error {
+                message: err.to_string(),
+                shown_in_overlay: false,
+            },
+        }
+    };
+    let remote = transcribe_with_selected_provider(
+        app,
+        samples,
+        binding_id,
+        profile,
+        settings,
+        include_retry_action,
+        false,
+    );
+
+    let race = run_transcription_race(
+        remote,
+        local,
+        // Dropping the remote future abandons the request; the manager cancel
+        // makes any late result read as cancelled.
+        || cancel_remote_provider_operation(app, settings.transcription_provider),
+        || tm.cancel_race_operation(race_id),
+    )
+    .await;
+
+    let winner = match race.winner {
+        Some(RaceSide::Remote) => settings.transcription_provider.as_str(),
+        Some(RaceSide::Local) => TranscriptionProvider::Local.as_str(),
+        None => {
+            return match race.outcome {
+                TranscriptionOutcome::Error { message, .. } => {
+                    if show_remote_error {
+                        handle_remote_transcription_error(app, &message, include_retry_action);
+                    }
+                    TranscriptionOutcome::Error {
+                        message,
+                        shown_in_overlay: show_remote_error,
+                    }
                 }
+                outcome => outcome,
+            };
+        }
+    };
+    info!("Race mode: {} won", winner);
+    match race.outcome {
+        TranscriptionOutcome::Success {
+            text,
+            confidence,
+            segments,
+            ..
+        } => TranscriptionOutcome::Success {
+            text,
+            confidence,
+            segments,
+            race_winner: Some(winner),
+        },
+        outcome => outcome,
+    }
+}
+
+#[cfg(test)]
+mod race_tests {
+    use super::{run_transcription_race, RaceSide, TranscriptionOutcome};
+    use std::cell::Cell;
+
+    fn success(text: &str) -> TranscriptionOutcome {
+        TranscriptionOutcome::Success {
+            text: text.to_string(),
+            confidence: None,
+            segments: None,
+            race_winner: None,
+        }
+    }
+
+    fn error(message: &str) -> TranscriptionOutcome {
+        TranscriptionOutcome::Error {
+            message: message.to_string(),
+            shown_in_overlay: false,
+        }
+    }
+
+    /// Resolves to `outcome` on its second poll, so the other side finishes first.
+    fn after_yield(
+        outcome: TranscriptionOutcome,
+    ) -> impl std::future::Future<Output = TranscriptionOutcome> {
+        let mut outcome = Some(outcome);
+        let mut yielded = false;
+        futures::future::poll_fn(move |cx| {
+            if yielded {
+                return std::task::Poll::Ready(outcome.take().expect("polled after completion"));
             }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        })
+    }
+
+    fn text_of(outcome: &TranscriptionOutcome) -> Option<&str> {
+        match outcome {
+            TranscriptionOutcome::Success { text, .. } => Some(text.as_str()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn first_side_with_text_wins_and_cancels_the_other() {
+        let remote_cancelled = Cell::new(false);
+        let local_cancelled = Cell::new(false);
+        let race = futures::executor::block_on(run_transcription_race(
+            async { success("remote") },
+            futures::future::pending(),
+            || remote_cancelled.set(true),
+            || local_cancelled.set(true),
+        ));
+        assert_eq!(race.winner, Some(RaceSide::Remote));
+        assert_eq!(text_of(&race.outcome), Some("remote"));
+        assert!(local_cancelled.get());
+        assert!(!remote_cancelled.get());
+
+        let race = futures::executor::block_on(run_transcription_race(
+            futures::future::pending(),
+            async { success("local") },
+            || remote_cancelled.set(true),
+            || {},
+        ));
+        assert_eq!(race.winner, Some(RaceSide::Local));
+        assert_eq!(text_of(&race.outcome), Some("local"));
+        assert!(remote_cancelled.get());
+    }
+
+    #[test]
+    fn one_failing_side_waits_for_the_other() {
+        let race = futures::executor::block_on(run_transcription_race(
+            async { error("remote failed") },
+            async { success("local") },
+            || {},
+            || {},
+        ));
+        assert_eq!(race.winner, Some(RaceSide::Local));
+        assert_eq!(text_of(&race.outcome), Some("local"));
+
+        let race = futures::executor::block_on(run_transcription_race(
+            after_yield(success("remote")),
+            async { error("local failed") },
+            || {},
+            || {},
+        ));
+        assert_eq!(race.winner, Some(RaceSide::Remote));
+        assert_eq!(text_of(&race.outcome), Some("remote"));
+    }
+
+    #[test]
+    fn both_failing_keeps_the_remote_error() {
+        let race = futures::executor::block_on(run_transcription_race(
+            async { error("remote failed") },
+            async { success("  ") },
+            || {},
+            || {},
+        ));
+        assert_eq!(race.winner, None);
+        assert!(matches!(
+            race.outcome,
+            TranscriptionOutcome::Error { ref message, .. } if message == "remote failed"
+        ));
+    }
+}
+
+/// Audible samples (0.3 s at 16 kHz) needed before an empty local result
+/// counts as missed speech. Recorded audio has already passed the VAD.
+const LOCAL_FALLBACK_MIN_SPEECH_SAMPLES: usize = 4_800;
+const LOCAL_FALLBACK_SPEECH_LEVEL: f32 = 0.01;
+
+/// The model for a local retry: enabled, downloaded and not the selected model.
+/// Only the batch local path asks for it; streaming and remote providers never retry.
+fn local_fallback_model(app: &AppHandle, settings: &AppSettings) -> Option<String> {
+    if !settings.local_fallback_enabled {
+        return None;
+    }
+    let model_id = settings.local_fallback_model.as_deref()?.trim();
+    if model_id.is_empty() || model_id == settings.selected_model {
+        return None;
+    }
+    match app.state::<Arc<ModelManager>>().get_model_info(model_id) {
+        Some(info) if info.is_downloaded => Some(model_id.to_string()),
+        _ => {
+            debug!(
+                "Local fallback model '{}' is not downloaded, skipping retry",
+                model_id
+            );
+            None
         }
+    }
+}
+
+/// Why a local transcription looks broken, if it does.
+fn local_output_issue(text: &str, samples: &[f32], repetition_threshold: f32) -> Option<String> {
+    if text.trim().is_empty() {
+        let audible = samples
+            .iter()
+            .filter(|sample| sample.abs() > LOCAL_FALLBACK_SPEECH_LEVEL)
+            .count();
+        return (audible >= LOCAL_FALLBACK_MIN_SPEECH_SAMPLES)
+            .then(|| "empty output despite speech".to_string());
+    }
+    let ratio = crate::audio_toolkit::repetition_ratio(text);
+    (ratio > repetition_threshold).then(|| format!("repetition ratio {:.2}", ratio))
+}
+
+/// Re-runs `samples` once through the fallback model when the primary output
+/// looks broken, keeping the primary output if the retry fails or is empty.
+fn retry_with_local_fallback_model(
+    tm: &TranscriptionManager,
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    model_id: &str,
+    samples: Vec<f32>,
+    primary: LocalTranscript,
+) -> LocalTranscript {
+    let Some(issue) = local_output_issue(
+        &primary.text,
+        &samples,
+        settings.local_fallback_repetition_threshold,
+    ) else {
+        return primary;
+    };
+
+    info!(
+        "Local transcription looks broken ({}), retrying with fallback model '{}'",
+        issue, model_id
+    );
+    if let Err(err) = tm.ensure_model_loaded(model_id) {
+        warn!(
+            "Failed to load local fallback model '{}': {}",
+            model_id, err
+        );
+        return primary;
+    }
+    let retried = tm.transcribe_with_overrides(
+        samples,
+        profile.map(|p| p.single_engine_language()),
+        profile.map(|p| p.translate_to_english),
+        crate::settings::select_stt_prompt(profile, &settings.transcription_prompts, model_id),
+        Some(settings.custom_words_for_profile(profile)),
+        settings.custom_words_enabled,
+    );
+
+    // Put the selected model back so the next dictation does not wait for it.
+    if settings.model_unload_timeout == crate::settings::ModelUnloadTimeout::Immediately {
+        tm.maybe_unload_immediately("local fallback retry");
     } else {
-        let tm = app.state::<Arc<TranscriptionManager>>();
+        tm.initiate_model_load_for(&settings.selected_model);
+    }
 
-        if let Err(err) = tm.ensure_model_loaded(&settings.selected_model) {
-            let err_str = format!("{}", err);
-            debug!("Local transcription model load failed: {}", err_str);
-            return TranscriptionOutcome::Error {
-                message: err_str,
-                shown_in_overlay: false,
-            };
+    match retried {
+        Ok(transcript) if !transcript.text.trim().is_empty() => {
+            info!(
+                "Local fallback model '{}' output: {:?} (primary output: {:?})",
+                model_id, transcript.text, primary.text
+            );
+            transcript
         }
-
-        // Use profile overrides for local transcription if available
-        let result = if let Some(p) = &profile {
-            log::info!(
-                "Transcription using Local model '{}' with profile '{}' (lang={}, translate={})",
-                settings.selected_model,
-                p.name,
-                p.language,
-                p.translate_to_english
+        Ok(_) => {
+            warn!(
+                "Local fallback model '{}' returned nothing, keeping primary output: {:?}",
+                model_id, primary.text
             );
-            tm.transcribe_with_overrides(
-                samples,
-                Some(&p.language),
-                Some(p.translate_to_english),
-                // Use resolve_stt_prompt to respect stt_prompt_override_enabled flag
-                crate::settings::resolve_stt_prompt(
-                    Some(p),
-                    &settings.transcription_prompts,
-                    &settings.selected_model,
-                ),
-                settings.custom_words_enabled,
-            )
-        } else {
-            log::info!(
-                "Transcription using Local model: {}",
-                settings.selected_model
+            primary
+        }
+        Err(err) => {
+            warn!(
+                "Local fallback model '{}' failed ({}), keeping primary output: {:?}",
+                model_id, err, primary.text
             );
-            tm.transcribe(samples, settings.custom_words_enabled)
-        };
-
-        match result {
-            Ok(text) => TranscriptionOutcome::Success(text),
-            Err(err) => {
-                let err_str = format!("{}", err);
-                debug!("Local transcription error: {}", err_str);
-                TranscriptionOutcome::Error {
-                    message: err_str,
-                    shown_in_overlay: false,
-                }
-            }
+            primary
         }
     }
 }
 
+#[cfg(test)]
+mod local_fallback_tests {
+    use super::local_output_issue;
+
+    #[test]
+    fn empty_output_needs_audible_speech() {
+        assert!(local_output_issue("", &vec![0.0; 16_000], 0.6).is_none());
+        assert!(local_output_issue(" ", &vec![0.2; 16_000], 0.6).is_some());
+    }
+
+    #[test]
+    fn repetition_loops_trigger_a_retry() {
+        let speech = vec![0.2; 16_000];
+        assert!(local_output_issue("the the the the the the", &speech, 0.6).is_some());
+        assert!(local_output_issue("see you at the meeting", &speech, 0.6).is_none());
+    }
+}
+
 // ============================================================================
 
 /// Prepares the application state for stopping a recording.
@@ -2385,6 +4312,26 @@ fn prepare_stop_recording_with_options(
     binding_id: &str,
     show_processing_overlay: bool,
 ) -> Option<StopRecordingContext> {
+    prepare_stop_recording_inner(app, binding_id, show_processing_overlay, false)
+        .map(|(context, _)| context)
+}
+
+/// Same as `prepare_stop_recording`, but keeps the cancel shortcut registered
+/// through Processing. The returned flag says whether it is still held; pass it
+/// to `FinishGuard::holding_cancel_shortcut` so it is released on finish.
+fn prepare_stop_recording_keeping_cancel_shortcut(
+    app: &AppHandle,
+    binding_id: &str,
+) -> Option<(StopRecordingContext, bool)> {
+    prepare_stop_recording_inner(app, binding_id, true, true)
+}
+
+fn prepare_stop_recording_inner(
+    app: &AppHandle,
+    binding_id: &str,
+    show_processing_overlay: bool,
+    keep_cancel_shortcut: bool,
+) -> Option<(StopRecordingContext, bool)> {
     let audio_cancel_generation = app
         .state::<Arc<AudioRecordingManager>>()
         .cancel_generation();
@@ -2408,10 +4355,14 @@ fn prepare_stop_recording_with_options(
             let recording_settings = captured_settings.clone();
             let operation_id = *operation_id;
             // Transition to Processing state
-            *state_guard = session_manager::SessionState::Processing {
-                binding_id: binding_id.to_string(),
-                operation_id,
-            };
+            session_manager::replace_state(
+                app,
+                &mut state_guard,
+                session_manager::SessionState::Processing {
+                    binding_id: binding_id.to_string(),
+                    operation_id,
+                },
+            );
             Some((
                 session,
                 operation_id,
@@ -2456,11 +4407,16 @@ fn prepare_stop_recording_with_options(
         recording_elapsed,
     )) = result
     {
-        let current_app = take_recording_app_context(binding_id);
+        let (current_app, window_context) = take_recording_app_context(binding_id);
 
         // Explicitly finish the session to trigger cleanup
-        // This unregisters the cancel shortcut exactly once
-        session.finish();
+        // This unregisters the cancel shortcut exactly once (unless kept)
+        let holds_cancel_shortcut = if keep_cancel_shortcut {
+            session.finish_keeping_cancel_shortcut()
+        } else {
+            session.finish();
+            false
+        };
 
         change_tray_icon(app, TrayIconState::Transcribing);
         if show_processing_overlay {
@@ -2476,14 +4432,18 @@ fn prepare_stop_recording_with_options(
         rm.resume_media_if_paused();
 
         play_feedback_sound(app, SoundType::Stop);
-        Some(StopRecordingContext {
-            operation_id,
-            audio_cancel_generation,
-            captured_profile_id,
-            current_app,
-            recording_settings,
-            recording_elapsed,
-        })
+        Some((
+            StopRecordingContext {
+                operation_id,
+                audio_cancel_generation,
+                captured_profile_id,
+                current_app,
+                window_context,
+                recording_settings,
+                recording_elapsed,
+            },
+            holds_cancel_shortcut,
+        ))
     } else {
         None
     }
@@ -2503,7 +4463,7 @@ async fn get_transcription_or_cleanup(
     binding_id: &str,
     captured_profile_id: Option<String>,
     recording_settings: AppSettings,
-) -> Option<(String, Vec<f32>)> {
+) -> Option<(String, Vec<f32>, TranscriptDetails)> {
     match get_transcription_or_cleanup_detailed(
         app,
         binding_id,
@@ -2556,7 +4516,11 @@ async fn get_transcription_or_cleanup_detailed(
             if has_openai_realtime_whisper_session {
                 openai_realtime_whisper_manager.cancel();
             }
-            return TranscriptionFetchOutcome::Success((String::new(), samples));
+            return TranscriptionFetchOutcome::Success((
+                String::new(),
+                samples,
+                TranscriptDetails::default(),
+            ));
         }
 
         if has_soniox_live_session
@@ -2567,8 +4531,19 @@ async fn get_transcription_or_cleanup_detailed(
                 .await
             {
                 Ok(text) => {
-                    let filtered = apply_soniox_output_filters(&recording_settings, text);
-                    return TranscriptionFetchOutcome::Success((filtered, samples));
+                    let filtered = apply_soniox_output_filters(
+                        &recording_settings,
+                        captured_profile_id.as_deref(),
+                        text,
+                    );
+                    return TranscriptionFetchOutcome::Success((
+                        filtered,
+                        samples,
+                        TranscriptDetails {
+                            confidence: soniox_live_manager.take_last_confidence(),
+                            ..TranscriptDetails::default()
+                        },
+                    ));
                 }
                 Err(err) => {
                     let err_str = format!("{}", err);
@@ -2587,8 +4562,16 @@ async fn get_transcription_or_cleanup_detailed(
                 .await
             {
                 Ok(text) => {
-                    let filtered = apply_soniox_output_filters(&recording_settings, text);
-                    return TranscriptionFetchOutcome::Success((filtered, samples));
+                    let filtered = apply_soniox_output_filters(
+                        &recording_settings,
+                        captured_profile_id.as_deref(),
+                        text,
+                    );
+                    return TranscriptionFetchOutcome::Success((
+                        filtered,
+                        samples,
+                        TranscriptDetails::default(),
+                    ));
                 }
                 Err(err) => {
                     let err_str = format!("{}", err);
@@ -2607,8 +4590,16 @@ async fn get_transcription_or_cleanup_detailed(
                 .await
             {
                 Ok(text) => {
-                    let filtered = apply_soniox_output_filters(&recording_settings, text);
-                    return TranscriptionFetchOutcome::Success((filtered, samples));
+                    let filtered = apply_soniox_output_filters(
+                        &recording_settings,
+                        captured_profile_id.as_deref(),
+                        text,
+                    );
+                    return TranscriptionFetchOutcome::Success((
+                        filtered,
+                        samples,
+                        TranscriptDetails::default(),
+                    ));
                 }
                 Err(err) => {
                     let err_str = format!("{}", err);
@@ -2636,9 +4627,20 @@ async fn get_transcription_or_cleanup_detailed(
         )
         .await
         {
-            TranscriptionOutcome::Success(text) => {
-                TranscriptionFetchOutcome::Success((text, samples))
-            }
+            TranscriptionOutcome::Success {
+                text,
+                confidence,
+                segments,
+                race_winner,
+            } => TranscriptionFetchOutcome::Success((
+                text,
+                samples,
+                TranscriptDetails {
+                    segments,
+                    confidence,
+                    race_winner,
+                },
+            )),
             TranscriptionOutcome::Cancelled => TranscriptionFetchOutcome::Cancelled,
             TranscriptionOutcome::Error {
                 shown_in_overlay, ..
@@ -2666,7 +4668,7 @@ async fn get_transcription_or_cleanup_detailed(
 }
 
 async fn save_recording_wav_for_history(app: &AppHandle, samples: &[f32]) -> Option<String> {
-    if samples.is_empty() {
+    if samples.is_empty() || crate::privacy_mode::should_skip("history recording WAV") {
         return None;
     }
 
@@ -2700,6 +4702,10 @@ fn save_failed_transcription_entry(
     file_name: String,
     post_process_requested: bool,
 ) -> Option<i64> {
+    if crate::privacy_mode::should_skip("failed transcription history entry") {
+        return None;
+    }
+
     let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
     match hm.save_entry(file_name, String::new(), post_process_requested, None, None) {
         Ok(entry) => Some(entry.id),
@@ -2744,6 +4750,7 @@ async fn stop_recording_for_transcribe_action(
                 rm.clear_stream_frame_callback();
                 app.state::<Arc<SonioxRealtimeManager>>().cancel();
             }
+            finish_local_streaming_preview(app, binding_id).await;
             app.state::<Arc<TranscriptionManager>>().cancel_stream();
             debug!("No samples retrieved from recording stop");
             utils::hide_recording_overlay(app);
@@ -2751,6 +4758,7 @@ async fn stop_recording_for_transcribe_action(
             return None;
         }
     };
+    finish_local_streaming_preview(app, binding_id).await;
 
     if should_skip_transcription_for_quick_tap(binding_id, recording_settings, samples.len()) {
         debug!(
@@ -2764,6 +4772,7 @@ async fn stop_recording_for_transcribe_action(
             pre_saved_file_name: None,
             post_process_requested: false,
             quick_tap_skipped: true,
+            interview: None,
         });
     }
 
@@ -2780,6 +4789,12 @@ async fn stop_recording_for_transcribe_action(
         .and_then(|profile_id| recording_settings.transcription_profile(profile_id));
     let post_process_requested =
         resolve_history_post_process_requested(recording_settings, profile);
+    // Interview recordings keep both sides of the conversation in history.
+    let interview = rm.take_interview_channels();
+    let samples = match &interview {
+        Some(channels) => channels.mixdown(),
+        None => samples,
+    };
     let pre_saved_file_name = save_recording_wav_for_history(app, &samples).await;
 
     Some(StoppedTranscribeRecording {
@@ -2787,6 +4802,7 @@ async fn stop_recording_for_transcribe_action(
         pre_saved_file_name,
         post_process_requested,
         quick_tap_skipped: false,
+        interview,
     })
 }
 
@@ -2796,8 +4812,9 @@ async fn transcribe_stopped_recording_for_transcribe_action(
     captured_profile_id: Option<String>,
     recording_settings: AppSettings,
     current_app: String,
+    window_context: RecordingWindowContext,
     stopped: StoppedTranscribeRecording,
-) -> Option<(String, Vec<f32>, Option<String>, bool)> {
+) -> Option<(String, TranscriptDetails, Vec<f32>, Option<String>, bool)> {
     let samples = stopped.samples;
     let pre_saved_file_name = stopped.pre_saved_file_name;
     let post_process_requested = stopped.post_process_requested;
@@ -2805,27 +4822,59 @@ async fn transcribe_stopped_recording_for_transcribe_action(
         &recording_settings,
         captured_profile_id.as_ref(),
     );
+    // A retry would replay the mixed audio, so interview turns are not retried.
     let remote_retry_enabled = recording_settings.transcription_provider
         == TranscriptionProvider::RemoteOpenAiCompatible
         && is_transcribe_binding_id(binding_id)
-        && !preview_output_only_enabled;
+        && !preview_output_only_enabled
+        && stopped.interview.is_none();
 
-    match perform_transcription_for_profile_with_retry_action(
-        app,
-        samples.clone(),
-        Some(binding_id),
-        captured_profile_id.clone(),
-        &recording_settings,
-        false,
-        !remote_retry_enabled,
-    )
-    .await
-    {
-        TranscriptionOutcome::Success(text) => {
+    let outcome = match &stopped.interview {
+        Some(channels) => {
+            transcribe_interview_channels(
+                app,
+                binding_id,
+                captured_profile_id.clone(),
+                &recording_settings,
+                channels,
+            )
+            .await
+        }
+        None => {
+            perform_transcription_for_profile_with_retry_action(
+                app,
+                samples.clone(),
+                Some(binding_id),
+                captured_profile_id.clone(),
+                &recording_settings,
+                false,
+                !remote_retry_enabled,
+            )
+            .await
+        }
+    };
+
+    match outcome {
+        TranscriptionOutcome::Success {
+            text,
+            confidence,
+            segments,
+            race_winner,
+        } => {
             if remote_retry_enabled {
                 clear_last_remote_recording_retry();
             }
-            Some((text, samples, pre_saved_file_name, post_process_requested))
+            Some((
+                text,
+                TranscriptDetails {
+                    segments,
+                    confidence,
+                    race_winner,
+                },
+                samples,
+                pre_saved_file_name,
+                post_process_requested,
+            ))
         }
         TranscriptionOutcome::Cancelled => {
             if remote_retry_enabled {
@@ -2851,6 +4900,7 @@ async fn transcribe_stopped_recording_for_transcribe_action(
                             captured_profile_id: captured_profile_id.clone(),
                             recording_settings: recording_settings.clone(),
                             current_app: current_app.clone(),
+                            window_context: window_context.clone(),
                             pre_saved_file_name: pre_saved_file_name.clone(),
                             failed_history_entry_id,
                         };
@@ -2884,6 +4934,75 @@ async fn transcribe_stopped_recording_for_transcribe_action(
     }
 }
 
+/// Transcribes each interview turn from its own channel and renders the
+/// turns in time order as "Me:" / "Them:" lines. A failed turn is left out
+/// so the rest of the conversation survives; only when every turn fails is
+/// the first failure returned.
+async fn transcribe_interview_channels(
+    app: &AppHandle,
+    binding_id: &str,
+    captured_profile_id: Option<String>,
+    recording_settings: &AppSettings,
+    channels: &InterviewChannels,
+) -> TranscriptionOutcome {
+    let turns = channels.turns();
+    debug!("Interview recording split into {} turns", turns.len());
+
+    let mut lines = Vec::with_capacity(turns.len());
+    let mut confidence = ConfidenceAccumulator::default();
+    let mut first_failure = None;
+    for turn in &turns {
+        debug!(
+            "Transcribing interview turn {} at {:.1}s",
+            turn.speaker.label(),
+            turn.start_secs()
+        );
+        match perform_transcription_for_profile(
+            app,
+            channels.turn_samples(turn),
+            Some(binding_id),
+            captured_profile_id.clone(),
+            recording_settings,
+        )
+        .await
+        {
+            TranscriptionOutcome::Success {
+                text,
+                confidence: turn_confidence,
+                ..
+            } => {
+                if let Some(score) = turn_confidence {
+                    confidence.push(score);
+                }
+                lines.push((turn.speaker, text));
+            }
+            TranscriptionOutcome::Cancelled => return TranscriptionOutcome::Cancelled,
+            outcome @ TranscriptionOutcome::Error { .. } => {
+                warn!(
+                    "Skipping interview turn {} at {:.1}s after a failed transcription",
+                    turn.speaker.label(),
+                    turn.start_secs()
+                );
+                first_failure.get_or_insert(outcome);
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        if let Some(outcome) = first_failure {
+            return outcome;
+        }
+    }
+
+    // Turn timings are relative to each turn, so the joined text has none.
+    TranscriptionOutcome::Success {
+        text: interview::render_transcript(&lines),
+        confidence: confidence.average(),
+        segments: None,
+        race_winner: None,
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn retry_last_remote_transcription(app: AppHandle) -> Result<(), String> {
@@ -2907,8 +5026,20 @@ pub async fn retry_last_remote_transcription(app: AppHandle) -> Result<(), Strin
         )
         .await;
 
-        let transcription = match outcome {
-            TranscriptionOutcome::Success(text) => text,
+        let (transcription, details) = match outcome {
+            TranscriptionOutcome::Success {
+                text,
+                confidence,
+                segments,
+                race_winner,
+            } => (
+                text,
+                TranscriptDetails {
+                    segments,
+                    confidence,
+                    race_winner,
+                },
+            ),
             TranscriptionOutcome::Cancelled => {
                 clear_last_remote_recording_retry_by_id(request.retry_id);
                 utils::hide_recording_overlay(&app);
@@ -2935,9 +5066,11 @@ pub async fn retry_last_remote_transcription(app: AppHandle) -> Result<(), Strin
             &app,
             &request.recording_settings,
             transcription,
+            details,
             request.samples,
             request.captured_profile_id,
             &request.current_app,
+            &request.window_context,
             request.pre_saved_file_name,
             Some(request.failed_history_entry_id),
             false,
@@ -2990,10 +5123,27 @@ fn resolve_profile_for_binding<'a>(
     None
 }
 
+/// Whether recordings go over an OpenAI Realtime WebSocket session: always for
+/// the OpenAI Realtime provider, and for the OpenAI preset of the
+/// OpenAI-compatible provider when gpt-realtime-whisper is selected.
 fn is_openai_realtime_whisper_selected(settings: &AppSettings) -> bool {
-    settings.transcription_provider == TranscriptionProvider::RemoteOpenAiCompatible
-        && settings.remote_stt.provider_preset == REMOTE_STT_PRESET_OPENAI
-        && OpenAiRealtimeWhisperManager::is_realtime_model(&settings.remote_stt.model_id)
+    match settings.transcription_provider {
+        TranscriptionProvider::RemoteOpenAiRealtime => true,
+        TranscriptionProvider::RemoteOpenAiCompatible => {
+            settings.remote_stt.provider_preset == REMOTE_STT_PRESET_OPENAI
+                && OpenAiRealtimeWhisperManager::is_realtime_model(&settings.remote_stt.model_id)
+        }
+        _ => false,
+    }
+}
+
+/// API key for the selected OpenAI Realtime session.
+pub(crate) fn openai_realtime_whisper_api_key(settings: &AppSettings) -> anyhow::Result<String> {
+    if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiRealtime {
+        Ok(crate::managers::openai_realtime::api_key())
+    } else {
+        crate::managers::remote_stt::get_remote_stt_api_key(&settings.remote_stt)
+    }
 }
 
 fn should_use_openai_realtime_whisper_live(settings: &AppSettings) -> bool {
@@ -3015,7 +5165,8 @@ fn should_route_live_output_to_preview(settings: &AppSettings) -> bool {
             settings.deepgram_live_enabled
                 && DeepgramRealtimeManager::is_realtime_model(&settings.deepgram_model)
         }
-        TranscriptionProvider::RemoteOpenAiCompatible => {
+        TranscriptionProvider::RemoteOpenAiCompatible
+        | TranscriptionProvider::RemoteOpenAiRealtime => {
             should_use_openai_realtime_whisper_live(settings)
         }
         _ => false,
@@ -3123,6 +5274,112 @@ fn should_use_local_preview_auto_flush(
         && !local_model_supports_native_streaming(app, settings)
 }
 
+fn should_use_local_streaming_preview(
+    app: &AppHandle,
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    binding_id: &str,
+) -> bool {
+    is_transcribe_binding_id(binding_id)
+        && settings.transcription_provider == TranscriptionProvider::Local
+        && settings.local_streaming_preview_enabled
+        && settings.soniox_live_preview_enabled
+        && !should_route_output_to_preview(settings, profile)
+        && !local_model_supports_native_streaming(app, settings)
+}
+
+/// Starts transcribing the recording in windows and shows the running text in
+/// the live preview. Nothing is pasted; the final text still comes from the
+/// full transcription after the recording stops.
+fn start_local_streaming_preview(
+    app: &AppHandle,
+    binding_id: &str,
+    operation_stamp: OperationStamp,
+    custom_words: Vec<crate::audio_toolkit::CustomWord>,
+    apply_custom_words_enabled: bool,
+) {
+    let (frames_tx, frames_rx) = mpsc::channel::<Vec<f32>>();
+    let tm = app.state::<Arc<TranscriptionManager>>().inner().clone();
+    let app_for_partials = app.clone();
+    let on_partial = Arc::new(move |text: &str| {
+        if operation_stamp.was_cancelled(&app_for_partials) {
+            return;
+        }
+        crate::overlay::emit_soniox_live_preview_update(&app_for_partials, text, "");
+    }) as crate::managers::transcription::LocalStreamingPartialCallback;
+
+    let spawned = std::thread::Builder::new()
+        .name("local-streaming-preview".to_string())
+        .spawn(move || {
+            match tm.transcribe_streaming(
+                frames_rx,
+                Some(custom_words),
+                apply_custom_words_enabled,
+                on_partial,
+            ) {
+                Ok(preview) => debug!(
+                    "Local streaming preview finished with {} chars",
+                    preview.chars().count()
+                ),
+                Err(err) => warn!("Local streaming preview stopped: {}", err),
+            }
+        });
+    let worker = match spawned {
+        Ok(worker) => worker,
+        Err(err) => {
+            warn!("Failed to start local streaming preview: {}", err);
+            return;
+        }
+    };
+    if let Ok(mut previews) = LOCAL_STREAMING_PREVIEWS.lock() {
+        previews.insert(binding_id.to_string(), worker);
+    }
+
+    crate::overlay::begin_soniox_live_preview_session();
+    crate::overlay::reset_soniox_live_preview(app);
+    crate::overlay::show_soniox_live_preview_window(app);
+    app.state::<Arc<AudioRecordingManager>>()
+        .set_stream_frame_callback(Arc::new(move |frame| {
+            let _ = frames_tx.send(frame);
+        }));
+}
+
+/// Closes the frame feed of the binding's preview worker and waits for its
+/// current window, so the final transcription gets the engine back.
+async fn finish_local_streaming_preview(app: &AppHandle, binding_id: &str) {
+    let worker = LOCAL_STREAMING_PREVIEWS
+        .lock()
+        .ok()
+        .and_then(|mut previews| previews.remove(binding_id));
+    let Some(worker) = worker else {
+        return;
+    };
+    app.state::<Arc<AudioRecordingManager>>()
+        .clear_stream_frame_callback();
+    if let Err(err) = tauri::async_runtime::spawn_blocking(move || worker.join()).await {
+        warn!("Failed to wait for local streaming preview: {}", err);
+    }
+    crate::overlay::end_soniox_live_preview_session();
+    crate::overlay::hide_soniox_live_preview_window(app);
+}
+
+/// Stops every local streaming preview without waiting. Workers exit after
+/// their current window because the frame feed is already cleared.
+pub(crate) fn cancel_local_streaming_previews(app: &AppHandle) {
+    let had_previews = LOCAL_STREAMING_PREVIEWS
+        .lock()
+        .map(|mut previews| {
+            let had_previews = !previews.is_empty();
+            previews.clear();
+            had_previews
+        })
+        .unwrap_or(false);
+    if had_previews {
+        crate::overlay::end_soniox_live_preview_session();
+        crate::overlay::hide_soniox_live_preview_window(app);
+    }
+}
+
 fn local_preview_auto_flush_interval(settings: &AppSettings) -> Duration {
     Duration::from_millis(
         settings
@@ -3273,7 +5530,7 @@ async fn run_local_preview_flush(
         )
         .await
         {
-            TranscriptionOutcome::Success(text) => {
+            TranscriptionOutcome::Success { text, .. } => {
                 if crate::managers::preview_output_mode::is_active_for_binding(&binding_id) {
                     let existing = crate::managers::preview_output_mode::recording_prefix_text();
                     let text = filter_local_preview_flush_text(&existing, &text);
@@ -4705,13 +6962,26 @@ async fn paste_preview_buffer_to_target(
 
     // Avoid pasting back into the preview window itself after an action click.
     crate::overlay::hide_live_preview_window(app);
-    tokio::time::sleep(Duration::from_millis(90)).await;
+    let settings = get_settings(app);
+    let focus_timeout = Duration::from_millis(settings.focus_restore_timeout_ms);
+    let focus_left_preview = tokio::task::spawn_blocking(move || {
+        crate::active_app::wait_for_foreign_foreground(focus_timeout)
+    })
+    .await
+    .unwrap_or(false);
+    if !focus_left_preview {
+        debug!(
+            "Preview paste: focus did not leave AivoRelay within {:?}",
+            focus_timeout
+        );
+    }
+    tokio::time::sleep(Duration::from_millis(settings.focus_restore_paste_delay_ms)).await;
     if operation_stamp.is_some_and(|stamp| !stamp.is_current(app) || stamp.was_cancelled(app)) {
         return Ok(false);
     }
 
     let ah_for_paste = app.clone();
-    let main_thread_timeout_ms = get_settings(app).paste_delay_ms.saturating_add(1500);
+    let main_thread_timeout_ms = settings.paste_delay_ms.saturating_add(1500);
     run_on_main_thread_sync(app, main_thread_timeout_ms, move || {
         if operation_stamp.is_some_and(|stamp| stamp.was_cancelled(&ah_for_paste)) {
             return;
@@ -4874,9 +7144,15 @@ pub(crate) async fn process_live_sound_transcription_text(app: AppHandle) -> Res
         &settings,
         profile,
         &current_app,
+        &current_window_context(),
         &original_text,
         "",
         "",
+    )
+    .with_clipboard_for(
+        &app,
+        &settings,
+        &[post_process_prompt_template(&settings, profile)],
     );
 
     let result =
@@ -4894,18 +7170,91 @@ pub(crate) async fn process_live_sound_transcription_text(app: AppHandle) -> Res
             PostProcessTranscriptionOutcome::Cancelled => Ok(()),
         };
 
-    crate::managers::live_sound_transcription::set_processing_llm(&app, false);
+    crate::managers::live_sound_transcription::set_processing_llm(&app, false);
+
+    match result {
+        Ok(()) => {
+            crate::managers::live_sound_transcription::set_error(&app, None);
+            Ok(())
+        }
+        Err(err) => {
+            crate::managers::live_sound_transcription::set_error(&app, Some(err.clone()));
+            Err(err)
+        }
+    }
+}
+
+/// Re-runs post-processing on a history entry's raw transcription with the
+/// given prompt and, optionally, another provider, bypassing the enable
+/// gates. The entry's profile only supplies the language; the focused app,
+/// selection and window details are left empty.
+pub(crate) async fn reprocess_history_transcription(
+    app: &AppHandle,
+    entry: &HistoryEntry,
+    prompt_id: &str,
+    provider_id: Option<&str>,
+) -> Result<PostProcessTranscriptionOutcome, String> {
+    let mut settings = get_settings(app);
+    if !settings
+        .post_process_prompts
+        .iter()
+        .any(|prompt| prompt.id == prompt_id)
+    {
+        return Err(format!("Prompt '{}' was not found", prompt_id));
+    }
+    settings.post_process_selected_prompt_id = Some(prompt_id.to_string());
+    if let Some(provider_id) = provider_id {
+        if settings.post_process_provider(provider_id).is_none() {
+            return Err(format!("Provider '{}' was not found", provider_id));
+        }
+        settings.post_process_provider_id = provider_id.to_string();
+    }
+
+    let profile = entry
+        .profile_id
+        .as_deref()
+        .and_then(|id| settings.transcription_profile(id));
+    let template_context = build_llm_template_context(
+        app,
+        &settings,
+        profile,
+        "",
+        &RecordingWindowContext::default(),
+        &entry.transcription_text,
+        "",
+        "",
+    )
+    .with_clipboard_for(
+        app,
+        &settings,
+        &[post_process_prompt_template(&settings, None)],
+    );
+
+    let outcome =
+        maybe_post_process_transcription(app, &settings, None, &template_context, true).await;
+    if let PostProcessTranscriptionOutcome::Processed { provider_id, .. } = &outcome {
+        app.state::<Arc<UsageStatsManager>>().record(UsageRecord {
+            llm_provider: Some(provider_id.clone()),
+            counters: UsageCounters {
+                llm_post_processes: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+    Ok(outcome)
+}
 
-    match result {
-        Ok(()) => {
-            crate::managers::live_sound_transcription::set_error(&app, None);
-            Ok(())
-        }
-        Err(err) => {
-            crate::managers::live_sound_transcription::set_error(&app, Some(err.clone()));
-            Err(err)
+/// Pastes `text` into the focused app the way the repaste action does.
+pub(crate) async fn paste_history_text(app: &AppHandle, text: String) {
+    tokio::time::sleep(Duration::from_millis(REPASTE_LAST_PRE_PASTE_DELAY_MS)).await;
+    let app_clone = app.clone();
+    app.run_on_main_thread(move || {
+        if let Err(err) = utils::paste(text, app_clone) {
+            error!("Failed to paste reprocessed history text: {}", err);
         }
-    }
+    })
+    .ok();
 }
 
 fn stop_transcribe_binding_from_preview(app: &AppHandle, binding_id: &str) -> Result<(), String> {
@@ -4945,9 +7294,14 @@ fn is_recording_for_binding(app: &AppHandle, binding_id: &str) -> bool {
     )
 }
 
-fn use_push_to_talk_for_transcribe_binding(settings: &AppSettings, binding_id: &str) -> bool {
+pub(crate) fn use_push_to_talk_for_transcribe_binding(
+    settings: &AppSettings,
+    binding_id: &str,
+) -> bool {
     if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
         true
+    } else if let Some(use_push_to_talk) = settings.push_to_talk_override(binding_id) {
+        use_push_to_talk
     } else if binding_id == "transcribe" {
         if settings.active_profile_id == "default" {
             settings.push_to_talk
@@ -5003,8 +7357,12 @@ fn close_preview_output_mode_workflow(app: &AppHandle, clear_text: bool) {
     crate::overlay::hide_live_preview_window(app);
 }
 
-fn is_transcribe_binding_id(binding_id: &str) -> bool {
-    binding_id == "transcribe" || binding_id.starts_with("transcribe_")
+/// Bindings whose output is pasted like the main transcribe action. Transcribe
+/// and Search shares the prefix but opens a browser instead.
+pub(crate) fn is_transcribe_binding_id(binding_id: &str) -> bool {
+    binding_id == "transcribe"
+        || (binding_id.starts_with("transcribe_")
+            && binding_id != crate::settings::TRANSCRIBE_AND_SEARCH_BINDING_ID)
 }
 
 pub(crate) fn live_sound_use_live_streaming(settings: &AppSettings) -> bool {
@@ -5018,7 +7376,8 @@ pub(crate) fn live_sound_use_live_streaming(settings: &AppSettings) -> bool {
             settings.deepgram_live_enabled
                 && DeepgramRealtimeManager::is_realtime_model(&settings.deepgram_model)
         }
-        TranscriptionProvider::RemoteOpenAiCompatible => {
+        TranscriptionProvider::RemoteOpenAiCompatible
+        | TranscriptionProvider::RemoteOpenAiRealtime => {
             should_use_openai_realtime_whisper_live(settings)
         }
         _ => false,
@@ -5029,10 +7388,7 @@ pub(crate) fn build_soniox_options_for_live_sound(
     settings: &AppSettings,
 ) -> crate::managers::soniox_realtime::SonioxRealtimeOptions {
     let profile = resolve_profile_for_binding(settings, LIVE_SOUND_TRANSCRIPTION_BINDING_ID);
-    let language = profile
-        .as_ref()
-        .map(|p| p.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let language = settings.requested_language(profile);
     build_soniox_realtime_options(
         settings,
         &language,
@@ -5045,10 +7401,7 @@ pub(crate) fn build_deepgram_options_for_live_sound(
     settings: &AppSettings,
 ) -> crate::managers::deepgram_realtime::DeepgramRealtimeOptions {
     let profile = resolve_profile_for_binding(settings, LIVE_SOUND_TRANSCRIPTION_BINDING_ID);
-    let language = profile
-        .as_ref()
-        .map(|p| p.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let language = settings.requested_language(profile);
     build_deepgram_realtime_options(settings, &language, LIVE_SOUND_TRANSCRIPTION_BINDING_ID)
 }
 
@@ -5056,10 +7409,7 @@ pub(crate) fn build_openai_options_for_live_sound(
     settings: &AppSettings,
 ) -> OpenAiRealtimeWhisperOptions {
     let profile = resolve_profile_for_binding(settings, LIVE_SOUND_TRANSCRIPTION_BINDING_ID);
-    let language = profile
-        .as_ref()
-        .map(|p| p.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let language = settings.requested_language(profile);
     build_openai_realtime_whisper_options(settings, &language)
 }
 
@@ -5073,13 +7423,24 @@ fn should_use_live_streaming(settings: &AppSettings) -> bool {
             settings.deepgram_live_enabled
                 && DeepgramRealtimeManager::is_realtime_model(&settings.deepgram_model)
         }
-        TranscriptionProvider::RemoteOpenAiCompatible => {
+        TranscriptionProvider::RemoteOpenAiCompatible
+        | TranscriptionProvider::RemoteOpenAiRealtime => {
             should_use_openai_realtime_whisper_live(settings)
         }
         _ => false,
     }
 }
 
+/// Turns off every path that inserts text while recording, in a recording
+/// snapshot, so the result is delivered once after stop.
+fn disable_streaming_insert(settings: &mut AppSettings) {
+    settings.soniox_live_enabled = false;
+    settings.soniox_live_preview_enabled = false;
+    settings.deepgram_live_enabled = false;
+    settings.openai_realtime_whisper_flatten_enabled = true;
+    settings.native_streaming_live_output_models.clear();
+}
+
 fn should_use_soniox_optimized_delivery(
     settings: &AppSettings,
     binding_id: &str,
@@ -5113,7 +7474,8 @@ fn should_use_live_for_recording(app: &AppHandle, binding_id: &str) -> bool {
                 deepgram_live_manager.has_active_session()
                     && should_use_live_streaming(captured_settings)
             }
-            TranscriptionProvider::RemoteOpenAiCompatible => {
+            TranscriptionProvider::RemoteOpenAiCompatible
+            | TranscriptionProvider::RemoteOpenAiRealtime => {
                 let openai_realtime_whisper_manager =
                     Arc::clone(&app.state::<Arc<OpenAiRealtimeWhisperManager>>());
                 openai_realtime_whisper_manager.has_active_session()
@@ -5134,10 +7496,7 @@ fn setup_and_start_live(
     binding_id: &str,
 ) -> Result<(), String> {
     let profile = resolve_profile_for_binding(settings, binding_id);
-    let language = profile
-        .as_ref()
-        .map(|p| p.language.clone())
-        .unwrap_or_else(|| settings.selected_language.clone());
+    let language = settings.requested_language(profile);
 
     match settings.transcription_provider {
         TranscriptionProvider::RemoteSoniox => {
@@ -5177,13 +7536,14 @@ fn setup_and_start_live(
                 })
         }
         TranscriptionProvider::RemoteOpenAiCompatible
+        | TranscriptionProvider::RemoteOpenAiRealtime
             if should_use_openai_realtime_whisper_live(settings) =>
         {
             let options = build_openai_realtime_whisper_options(settings, &language);
             let openai_realtime_whisper_manager =
                 Arc::clone(&app.state::<Arc<OpenAiRealtimeWhisperManager>>());
-            let api_key = crate::managers::remote_stt::get_remote_stt_api_key(&settings.remote_stt)
-                .map_err(|e| format!("{}", e))?;
+            let api_key =
+                openai_realtime_whisper_api_key(settings).map_err(|e| format!("{}", e))?;
             openai_realtime_whisper_manager
                 .start_session(binding_id, &api_key, options, None)
                 .map_err(|e| {
@@ -5236,26 +7596,24 @@ fn build_soniox_realtime_options(
     profile: Option<&TranscriptionProfile>,
     binding_id: &str,
 ) -> SonioxRealtimeOptions {
-    let mut language_hints = if settings.soniox_use_profile_language_hint_only {
-        Vec::new()
+    let language_hints = resolve_soniox_language_hints_for_settings(settings, language, profile);
+    let enable_endpoint_detection = if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
+        settings
+            .live_sound_soniox_endpoint_detection
+            .unwrap_or(settings.soniox_enable_endpoint_detection)
     } else {
-        let normalized_hints = crate::language_resolver::normalize_soniox_hint_list(
-            settings.soniox_language_hints.clone(),
-        );
-        if !normalized_hints.rejected.is_empty() {
-            warn!(
-                "Ignoring unsupported Soniox language hints: {}",
-                normalized_hints.rejected.join(", ")
-            );
-        }
-        normalized_hints.normalized
+        settings.soniox_enable_endpoint_detection
     };
-
-    if settings.soniox_use_profile_language_hint_only || language_hints.is_empty() {
-        if let Some(profile_hint) = resolve_soniox_hint_from_language(language) {
-            language_hints.push(profile_hint);
-        }
-    }
+    // Endpoint auto-stop only applies to toggle-mode dictation; push-to-talk
+    // already stops on release.
+    let auto_stop_on_endpoint = profile
+        .and_then(|p| p.soniox_live_auto_stop_on_endpoint)
+        .unwrap_or(settings.soniox_live_auto_stop_on_endpoint);
+    let auto_stop_endpoint_count = (auto_stop_on_endpoint
+        && enable_endpoint_detection
+        && binding_id != LIVE_SOUND_TRANSCRIPTION_BINDING_ID
+        && !use_push_to_talk_for_transcribe_binding(settings, binding_id))
+    .then(|| settings.soniox_live_auto_stop_endpoint_count.clamp(1, 5));
 
     SonioxRealtimeOptions {
         language_hints,
@@ -5268,13 +7626,7 @@ fn build_soniox_realtime_options(
             settings.soniox_enable_speaker_diarization
         },
         enable_language_identification: settings.soniox_enable_language_identification,
-        enable_endpoint_detection: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
-            settings
-                .live_sound_soniox_endpoint_detection
-                .unwrap_or(settings.soniox_enable_endpoint_detection)
-        } else {
-            settings.soniox_enable_endpoint_detection
-        },
+        enable_endpoint_detection,
         max_endpoint_delay_ms: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
             settings
                 .live_sound_soniox_max_endpoint_delay_ms
@@ -5286,170 +7638,499 @@ fn build_soniox_realtime_options(
         keepalive_interval_seconds: settings.soniox_keepalive_interval_seconds,
         context: crate::settings::resolve_soniox_context(profile, settings),
         show_preview: true,
+        auto_stop_endpoint_count,
+        reconnect_attempts: settings.soniox_live_reconnect_attempts,
+    }
+}
+
+/// Resolves the Soniox hint list for a session.
+///
+/// The profile set is the primary `language` hint plus the profile's
+/// `additional_language_hints`. With `soniox_use_profile_language_hint_only`
+/// only that set is sent; otherwise the global hints lead, followed by the
+/// additional hints, and the primary hint is added only when no global hints exist.
+/// A language picked with the language toggle always leads the list.
+fn resolve_soniox_language_hints_for_settings(
+    settings: &AppSettings,
+    language: &str,
+    profile: Option<&TranscriptionProfile>,
+) -> Vec<String> {
+    let global_hints = if settings.soniox_use_profile_language_hint_only {
+        Vec::new()
+    } else {
+        settings.soniox_language_hints.clone()
+    };
+    let is_toggle_language = [
+        &settings.language_toggle_primary,
+        &settings.language_toggle_secondary,
+    ]
+    .iter()
+    .any(|toggle| !toggle.trim().is_empty() && toggle.trim().eq_ignore_ascii_case(language));
+
+    let primary_hint = resolve_soniox_hint_from_language(language);
+
+    let mut candidate_hints = Vec::new();
+    if is_toggle_language {
+        candidate_hints.extend(primary_hint.clone());
+    }
+    let include_primary_hint = settings.soniox_use_profile_language_hint_only
+        || crate::language_resolver::normalize_soniox_hint_list(&global_hints)
+            .normalized
+            .is_empty();
+    candidate_hints.extend(global_hints);
+    if include_primary_hint {
+        candidate_hints.extend(primary_hint);
+    }
+    if let Some(profile) = profile {
+        candidate_hints.extend(profile.additional_language_hints.iter().cloned());
+    }
+
+    let normalized_hints = crate::language_resolver::normalize_soniox_hint_list(candidate_hints);
+    if !normalized_hints.rejected.is_empty() {
+        warn!(
+            "Ignoring unsupported Soniox language hints: {}",
+            normalized_hints.rejected.join(", ")
+        );
+    }
+    normalized_hints.normalized
+}
+
+fn build_soniox_async_options_for_shortcut(
+    settings: &AppSettings,
+    language: &str,
+    profile: Option<&TranscriptionProfile>,
+) -> SonioxAsyncTranscriptionOptions {
+    let language_hints = resolve_soniox_language_hints_for_settings(settings, language, profile);
+    SonioxAsyncTranscriptionOptions {
+        language_hints: if language_hints.is_empty() {
+            None
+        } else {
+            Some(language_hints)
+        },
+        context: crate::settings::resolve_soniox_context(profile, settings),
+        enable_speaker_diarization: Some(settings.soniox_enable_speaker_diarization),
+        enable_language_identification: Some(settings.soniox_enable_language_identification),
+    }
+}
+
+fn build_deepgram_realtime_options(
+    settings: &AppSettings,
+    language: &str,
+    binding_id: &str,
+) -> DeepgramRealtimeOptions {
+    DeepgramRealtimeOptions {
+        language: Some(language.to_string()),
+        smart_format: settings.deepgram_smart_format,
+        interim_results: settings.deepgram_interim_results,
+        diarize: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
+            settings.live_sound_enable_speaker_diarization
+        } else {
+            settings.deepgram_diarize
+        },
+        endpointing_enabled: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
+            settings
+                .live_sound_deepgram_endpointing_enabled
+                .unwrap_or(settings.deepgram_endpointing_enabled)
+        } else {
+            settings.deepgram_endpointing_enabled
+        },
+        endpointing_ms: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
+            settings
+                .live_sound_deepgram_endpointing_ms
+                .unwrap_or(settings.deepgram_endpointing_ms)
+        } else {
+            settings.deepgram_endpointing_ms
+        },
+        keepalive_interval_seconds: settings.deepgram_keepalive_interval_seconds,
+    }
+}
+
+fn build_openai_realtime_whisper_options(
+    settings: &AppSettings,
+    language: &str,
+) -> OpenAiRealtimeWhisperOptions {
+    if settings.transcription_provider == TranscriptionProvider::RemoteOpenAiRealtime {
+        return crate::managers::openai_realtime::build_options(settings, language);
+    }
+    OpenAiRealtimeWhisperOptions {
+        language: Some(language.to_string()),
+        delay: settings.openai_realtime_whisper_delay,
+        ..Default::default()
+    }
+}
+fn apply_soniox_output_filters(
+    settings: &AppSettings,
+    captured_profile_id: Option<&str>,
+    text: String,
+) -> String {
+    let profile = captured_profile_id.and_then(|id| settings.transcription_profile(id));
+    let custom_words = settings.custom_words_for_profile(profile);
+    let corrected = if settings.custom_words_enabled && !custom_words.is_empty() {
+        apply_custom_words(
+            &text,
+            &custom_words,
+            settings.word_correction_threshold,
+            settings.custom_words_ngram_enabled,
+        )
+    } else {
+        text
+    };
+
+    if settings.filler_word_filter_enabled {
+        crate::audio_toolkit::filter_transcription_output(
+            &corrected,
+            &settings.selected_language,
+            &settings.custom_filler_words,
+        )
+    } else {
+        corrected
+    }
+}
+
+fn resolve_stream_trailing_adjustment(
+    settings: &AppSettings,
+    original_text: &str,
+) -> TrailingAdjustment {
+    crate::output_whitespace::stream_trailing_adjustment(
+        original_text,
+        settings.output_whitespace_leading_mode,
+        settings.output_whitespace_trailing_mode,
+    )
+}
+
+fn apply_stream_trailing_adjustment(app: &AppHandle, adjustment: &TrailingAdjustment) {
+    if adjustment.delete_chars > 0 {
+        let _ =
+            crate::clipboard::delete_last_stream_characters(app.clone(), adjustment.delete_chars);
+    }
+    if !adjustment.append.is_empty() {
+        let _ = crate::clipboard::paste_stream_chunk(adjustment.append.clone(), app.clone());
+    }
+}
+
+fn should_run_transcription_post_process(post_process_requested: bool, text: &str) -> bool {
+    post_process_requested && !text.trim().is_empty()
+}
+
+#[cfg(test)]
+mod push_to_talk_tests {
+    use super::*;
+
+    #[test]
+    fn binding_override_takes_precedence_over_profile_setting() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.push_to_talk = true;
+        assert!(use_push_to_talk_for_transcribe_binding(
+            &settings,
+            "transcribe"
+        ));
+
+        settings
+            .bindings
+            .get_mut("transcribe")
+            .unwrap()
+            .push_to_talk_override = Some(false);
+        assert!(!use_push_to_talk_for_transcribe_binding(
+            &settings,
+            "transcribe"
+        ));
+
+        // Live sound always records while held, regardless of overrides.
+        assert!(use_push_to_talk_for_transcribe_binding(
+            &settings,
+            LIVE_SOUND_TRANSCRIPTION_BINDING_ID
+        ));
+    }
+}
+
+#[cfg(test)]
+mod soniox_language_hint_tests {
+    use super::*;
+
+    #[test]
+    fn primary_hint_is_used_without_global_hints() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.soniox_language_hints = Vec::new();
+        settings.soniox_use_profile_language_hint_only = false;
+
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", None),
+            vec!["en".to_string()]
+        );
+    }
+
+    #[test]
+    fn profile_only_flag_ignores_global_hints() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.soniox_language_hints = vec!["de".to_string(), "fr".to_string()];
+        settings.soniox_use_profile_language_hint_only = true;
+
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", None),
+            vec!["en".to_string()]
+        );
+
+        settings.soniox_use_profile_language_hint_only = false;
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", None),
+            vec!["de".to_string(), "fr".to_string()]
+        );
+    }
+
+    fn profile_with_additional_hints(language: &str, hints: &[&str]) -> TranscriptionProfile {
+        let mut profile: TranscriptionProfile = serde_json::from_value(serde_json::json!({
+            "id": "profile_1",
+            "name": "Multilingual",
+            "language": language,
+            "translate_to_english": false,
+        }))
+        .unwrap();
+        profile.additional_language_hints = hints.iter().map(|hint| hint.to_string()).collect();
+        profile
+    }
+
+    #[test]
+    fn additional_hints_follow_global_hints() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.soniox_language_hints = vec!["de".to_string()];
+        settings.soniox_use_profile_language_hint_only = false;
+        let profile = profile_with_additional_hints("en", &["fr", "es"]);
+
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", Some(&profile)),
+            vec!["de".to_string(), "fr".to_string(), "es".to_string()]
+        );
+
+        settings.soniox_use_profile_language_hint_only = true;
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", Some(&profile)),
+            vec!["en".to_string(), "fr".to_string(), "es".to_string()]
+        );
+    }
+
+    #[test]
+    fn additional_hints_are_deduplicated() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.soniox_language_hints = vec!["de".to_string(), "FR".to_string()];
+        settings.soniox_use_profile_language_hint_only = false;
+        let profile = profile_with_additional_hints("en", &["fr", "de", " es ", "es"]);
+
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", Some(&profile)),
+            vec!["de".to_string(), "fr".to_string(), "es".to_string()]
+        );
+
+        settings.soniox_use_profile_language_hint_only = true;
+        let profile = profile_with_additional_hints("en", &["en", "fr"]);
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "en", Some(&profile)),
+            vec!["en".to_string(), "fr".to_string()]
+        );
+    }
+
+    #[test]
+    fn toggled_language_leads_global_hints() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.soniox_language_hints = vec!["en".to_string(), "de".to_string()];
+        settings.soniox_use_profile_language_hint_only = false;
+        settings.language_toggle_primary = "en".to_string();
+        settings.language_toggle_secondary = "ru".to_string();
+
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "ru", None),
+            vec!["ru".to_string(), "en".to_string(), "de".to_string()]
+        );
+        assert_eq!(
+            resolve_soniox_language_hints_for_settings(&settings, "fr", None),
+            vec!["en".to_string(), "de".to_string()]
+        );
+    }
+}
+
+#[cfg(test)]
+mod adaptive_reasoning_tests {
+    use super::{compute_adaptive_reasoning, MAX_ADAPTIVE_REASONING_BUDGET, MIN_REASONING_BUDGET};
+
+    #[test]
+    fn reasoning_turns_on_only_above_word_threshold() {
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, Some(50), None, 50),
+            (false, 2048)
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, Some(50), None, 51),
+            (true, 2048)
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, Some(0), None, 1),
+            (true, 2048)
+        );
+    }
+
+    #[test]
+    fn global_toggle_still_disables_reasoning() {
+        let (enabled, _) = compute_adaptive_reasoning(false, 2048, Some(10), Some(500), 1000);
+        assert!(!enabled);
+    }
+
+    #[test]
+    fn budget_scales_per_hundred_words_rounding_up() {
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), 150),
+            (true, 1500)
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), 201),
+            (true, 2010)
+        );
+    }
+
+    #[test]
+    fn scaled_budget_respects_provider_minimum() {
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), 0).1,
+            MIN_REASONING_BUDGET
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), 102).1,
+            MIN_REASONING_BUDGET
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), 103).1,
+            1030
+        );
     }
-}
 
-fn resolve_soniox_language_hints_for_settings(
-    settings: &AppSettings,
-    language: &str,
-) -> Vec<String> {
-    let mut language_hints = if settings.soniox_use_profile_language_hint_only {
-        Vec::new()
-    } else {
-        let normalized_hints = crate::language_resolver::normalize_soniox_hint_list(
-            settings.soniox_language_hints.clone(),
+    #[test]
+    fn scaled_budget_respects_hard_cap() {
+        let below_cap_words = MAX_ADAPTIVE_REASONING_BUDGET as usize / 10;
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), below_cap_words).1,
+            below_cap_words as u32 * 10
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(1000), below_cap_words + 1).1,
+            MAX_ADAPTIVE_REASONING_BUDGET
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, 2048, None, Some(u32::MAX), usize::MAX / 2).1,
+            MAX_ADAPTIVE_REASONING_BUDGET
         );
-        if !normalized_hints.rejected.is_empty() {
-            warn!(
-                "Ignoring unsupported Soniox language hints: {}",
-                normalized_hints.rejected.join(", ")
-            );
-        }
-        normalized_hints.normalized
-    };
-
-    if settings.soniox_use_profile_language_hint_only || language_hints.is_empty() {
-        if let Some(profile_hint) = resolve_soniox_hint_from_language(language) {
-            language_hints.push(profile_hint);
-        }
     }
 
-    language_hints
-}
+    #[test]
+    fn fixed_budget_is_used_without_per_word_scaling() {
+        assert_eq!(
+            compute_adaptive_reasoning(true, 4096, Some(20), None, 5000),
+            (true, 4096)
+        );
+    }
 
-fn build_soniox_async_options_for_shortcut(
-    settings: &AppSettings,
-    language: &str,
-    profile: Option<&TranscriptionProfile>,
-) -> SonioxAsyncTranscriptionOptions {
-    let language_hints = resolve_soniox_language_hints_for_settings(settings, language);
-    SonioxAsyncTranscriptionOptions {
-        language_hints: if language_hints.is_empty() {
-            None
-        } else {
-            Some(language_hints)
-        },
-        context: crate::settings::resolve_soniox_context(profile, settings),
-        enable_speaker_diarization: Some(settings.soniox_enable_speaker_diarization),
-        enable_language_identification: Some(settings.soniox_enable_language_identification),
+    #[test]
+    fn out_of_range_fixed_budget_is_clamped() {
+        assert_eq!(
+            compute_adaptive_reasoning(true, 0, Some(20), None, 5000),
+            (true, MIN_REASONING_BUDGET)
+        );
+        assert_eq!(
+            compute_adaptive_reasoning(true, u32::MAX, Some(20), None, 5000),
+            (true, MAX_ADAPTIVE_REASONING_BUDGET)
+        );
     }
 }
 
-fn build_deepgram_realtime_options(
-    settings: &AppSettings,
-    language: &str,
-    binding_id: &str,
-) -> DeepgramRealtimeOptions {
-    DeepgramRealtimeOptions {
-        language: Some(language.to_string()),
-        smart_format: settings.deepgram_smart_format,
-        interim_results: settings.deepgram_interim_results,
-        diarize: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
-            settings.live_sound_enable_speaker_diarization
-        } else {
-            settings.deepgram_diarize
-        },
-        endpointing_enabled: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
-            settings
-                .live_sound_deepgram_endpointing_enabled
-                .unwrap_or(settings.deepgram_endpointing_enabled)
-        } else {
-            settings.deepgram_endpointing_enabled
-        },
-        endpointing_ms: if binding_id == LIVE_SOUND_TRANSCRIPTION_BINDING_ID {
-            settings
-                .live_sound_deepgram_endpointing_ms
-                .unwrap_or(settings.deepgram_endpointing_ms)
-        } else {
-            settings.deepgram_endpointing_ms
-        },
-        keepalive_interval_seconds: settings.deepgram_keepalive_interval_seconds,
+#[cfg(test)]
+mod prompt_override_tests {
+    use super::{apply_prompt_reasoning_overrides, prompt_model_override_for_provider};
+    use crate::llm_client::ReasoningConfig;
+    use crate::settings::{LLMPrompt, PostProcessProvider, APPLE_INTELLIGENCE_PROVIDER_ID};
+
+    fn prompt(
+        model_override: Option<&str>,
+        reasoning_enabled_override: Option<bool>,
+        reasoning_budget_override: Option<u32>,
+    ) -> LLMPrompt {
+        LLMPrompt {
+            id: "prompt_1".to_string(),
+            name: "Formal email".to_string(),
+            prompt: "${output}".to_string(),
+            max_output_words: None,
+            max_output_sentences: None,
+            output_json_path: None,
+            model_override: model_override.map(str::to_string),
+            reasoning_enabled_override,
+            reasoning_budget_override,
+        }
+    }
+
+    fn provider(id: &str) -> PostProcessProvider {
+        PostProcessProvider {
+            id: id.to_string(),
+            label: id.to_string(),
+            base_url: String::new(),
+            allow_base_url_edit: false,
+            allow_insecure_http: false,
+            models_endpoint: None,
+            auth_style: crate::settings::ApiAuthStyle::Bearer,
+            extra_query_params: Default::default(),
+        }
     }
-}
 
-fn build_openai_realtime_whisper_options(
-    settings: &AppSettings,
-    language: &str,
-) -> OpenAiRealtimeWhisperOptions {
-    OpenAiRealtimeWhisperOptions {
-        language: Some(language.to_string()),
-        delay: settings.openai_realtime_whisper_delay,
+    #[test]
+    fn model_override_is_trimmed_and_blank_inherits() {
+        let openrouter = provider("openrouter");
+        assert_eq!(
+            prompt_model_override_for_provider(
+                &prompt(Some(" anthropic/claude-sonnet-4 "), None, None),
+                &openrouter
+            )
+            .as_deref(),
+            Some("anthropic/claude-sonnet-4")
+        );
+        assert_eq!(
+            prompt_model_override_for_provider(&prompt(Some("  "), None, None), &openrouter),
+            None
+        );
+        assert_eq!(
+            prompt_model_override_for_provider(&prompt(None, None, None), &openrouter),
+            None
+        );
     }
-}
-fn apply_soniox_output_filters(settings: &AppSettings, text: String) -> String {
-    let corrected = if settings.custom_words_enabled && !settings.custom_words.is_empty() {
-        apply_custom_words(
-            &text,
-            &settings.custom_words,
-            settings.word_correction_threshold,
-            settings.custom_words_ngram_enabled,
-        )
-    } else {
-        text
-    };
 
-    if settings.filler_word_filter_enabled {
-        crate::audio_toolkit::filter_transcription_output(
-            &corrected,
-            &settings.selected_language,
-            &settings.custom_filler_words,
-        )
-    } else {
-        corrected
+    #[test]
+    fn model_override_is_ignored_for_apple_intelligence() {
+        assert_eq!(
+            prompt_model_override_for_provider(
+                &prompt(Some("gpt-4o"), None, None),
+                &provider(APPLE_INTELLIGENCE_PROVIDER_ID)
+            ),
+            None
+        );
     }
-}
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum StreamTrailingAdjustment {
-    None,
-    AppendSpaces(usize),
-    RemoveCharacters(usize),
-}
+    #[test]
+    fn reasoning_overrides_replace_only_the_fields_they_set() {
+        let resolved = ReasoningConfig::new(false, 2048);
 
-fn count_trailing_whitespace_chars(text: &str) -> usize {
-    text.chars()
-        .rev()
-        .take_while(|ch| ch.is_whitespace())
-        .count()
-}
+        let config = apply_prompt_reasoning_overrides(resolved.clone(), None);
+        assert_eq!((config.enabled, config.budget), (false, 2048));
 
-fn resolve_stream_trailing_adjustment(
-    settings: &AppSettings,
-    original_text: &str,
-) -> StreamTrailingAdjustment {
-    let adjusted = apply_output_whitespace_policy_for_settings(original_text, settings);
-    let original_trailing_count = count_trailing_whitespace_chars(original_text);
-    let adjusted_trailing_count = count_trailing_whitespace_chars(&adjusted);
-
-    if adjusted_trailing_count > original_trailing_count {
-        StreamTrailingAdjustment::AppendSpaces(adjusted_trailing_count - original_trailing_count)
-    } else if original_trailing_count > adjusted_trailing_count {
-        StreamTrailingAdjustment::RemoveCharacters(
-            original_trailing_count - adjusted_trailing_count,
-        )
-    } else {
-        StreamTrailingAdjustment::None
-    }
-}
+        let config = apply_prompt_reasoning_overrides(
+            resolved.clone(),
+            Some(&prompt(None, Some(true), None)),
+        );
+        assert_eq!((config.enabled, config.budget), (true, 2048));
 
-fn apply_stream_trailing_adjustment(app: &AppHandle, adjustment: StreamTrailingAdjustment) {
-    match adjustment {
-        StreamTrailingAdjustment::None => {}
-        StreamTrailingAdjustment::AppendSpaces(count) => {
-            if count > 0 {
-                let _ = crate::clipboard::paste_stream_chunk(" ".repeat(count), app.clone());
-            }
-        }
-        StreamTrailingAdjustment::RemoveCharacters(count) => {
-            if count > 0 {
-                let _ = crate::clipboard::delete_last_stream_characters(app.clone(), count);
-            }
-        }
-    }
-}
+        let config = apply_prompt_reasoning_overrides(
+            ReasoningConfig::new(true, 2048),
+            Some(&prompt(None, None, Some(8192))),
+        );
+        assert_eq!((config.enabled, config.budget), (true, 8192));
 
-fn should_run_transcription_post_process(post_process_requested: bool, text: &str) -> bool {
-    post_process_requested && !text.trim().is_empty()
+        let config =
+            apply_prompt_reasoning_overrides(resolved, Some(&prompt(None, Some(true), Some(100))));
+        assert_eq!((config.enabled, config.budget), (true, 1024));
+    }
 }
 
 #[cfg(test)]
@@ -5490,17 +8171,52 @@ mod transcription_post_process_tests {
     }
 }
 
+#[cfg(test)]
+mod output_translation_tests {
+    use super::{is_same_output_language, output_translation_target};
+
+    #[test]
+    fn same_language_ignores_region() {
+        assert!(is_same_output_language("es", "es-ES"));
+        assert!(is_same_output_language("en_US", "en"));
+        assert!(!is_same_output_language("es", "en"));
+        assert!(!is_same_output_language("es", "auto"));
+    }
+
+    #[test]
+    fn chinese_scripts_are_distinct() {
+        assert!(is_same_output_language("zh-Hans", "zh-hans"));
+        assert!(!is_same_output_language("zh-Hans", "zh-Hant"));
+        assert!(!is_same_output_language("zh-Hant", "zh"));
+    }
+
+    #[test]
+    fn target_names_known_and_unknown_codes() {
+        assert_eq!(output_translation_target("es"), "Spanish");
+        assert_eq!(output_translation_target("zh-Hant"), "Traditional Chinese");
+        assert_eq!(
+            output_translation_target("sv"),
+            "the language with code \"sv\""
+        );
+    }
+}
+
 pub(crate) async fn process_transcription_output(
     app: &AppHandle,
     settings: &AppSettings,
     transcription: &str,
     profile_id: Option<&str>,
     current_app: &str,
+    window_context: &RecordingWindowContext,
     force_post_process: bool,
 ) -> Option<ProcessedTranscription> {
     let mut final_text = transcription.to_string();
     let mut post_processed_text: Option<String> = None;
     let mut post_process_prompt: Option<String> = None;
+    let mut post_process_note: Option<String> = None;
+    let mut post_process_provider: Option<String> = None;
+    let mut post_process_raw_response: Option<String> = None;
+    let mut post_process_chunked = false;
 
     // Look up the profile if a custom profile is being used
     let profile = profile_id
@@ -5512,6 +8228,10 @@ pub(crate) async fn process_transcription_output(
         resolve_history_post_process_requested(settings, profile)
     };
 
+    // Streamed live chunks never pass through here, so in live mode this only
+    // affects the history entry and the clipboard copy.
+    final_text = apply_punctuation_mode(settings, profile, &final_text).await;
+
     // Helper closure for applying text replacements
     let apply_replacements = |text: &str| -> String {
         if settings.text_replacements_enabled && !settings.text_replacements.is_empty() {
@@ -5539,15 +8259,36 @@ pub(crate) async fn process_transcription_output(
     let requested_language = profile
         .map(|p| p.language.as_str())
         .unwrap_or(settings.selected_language.as_str());
-    if let Some(converted_text) =
-        maybe_convert_chinese_variant(requested_language, &final_text).await
-    {
-        final_text = converted_text;
+    let output_translation_language =
+        resolve_output_translation_language(settings, profile, requested_language);
+    // A translation into Chinese writes the requested script itself; converting
+    // the source first would only be undone or doubled.
+    let translation_handles_chinese = output_translation_language
+        .is_some_and(|language| language.to_ascii_lowercase().starts_with("zh"));
+    if !translation_handles_chinese {
+        if let Some(converted_text) =
+            maybe_convert_chinese_variant(requested_language, &final_text).await
+        {
+            final_text = converted_text;
+        }
     }
 
     if should_run_transcription_post_process(post_process_requested, &final_text) {
-        let template_context =
-            build_llm_template_context(app, settings, profile, current_app, &final_text, "", "");
+        let template_context = build_llm_template_context(
+            app,
+            settings,
+            profile,
+            current_app,
+            window_context,
+            &final_text,
+            "",
+            "",
+        )
+        .with_clipboard_for(
+            app,
+            settings,
+            &[post_process_prompt_template(settings, profile)],
+        );
 
         match maybe_post_process_transcription(
             app,
@@ -5569,16 +8310,37 @@ pub(crate) async fn process_transcription_output(
             PostProcessTranscriptionOutcome::Processed {
                 text,
                 prompt_template,
+                output_limit_note,
+                provider_id,
+                raw_response,
+                chunked,
             } => {
                 final_text = text.clone();
                 post_processed_text = Some(text);
                 post_process_prompt = Some(prompt_template);
+                post_process_note = output_limit_note;
+                post_process_provider = Some(provider_id);
+                post_process_raw_response = raw_response;
+                post_process_chunked = chunked;
             }
         }
     } else if final_text != transcription {
         post_processed_text = Some(final_text.clone());
     }
 
+    if let Some(language) = output_translation_language {
+        if !is_blank_transcription(&final_text) {
+            match translate_output(app, settings, profile, &final_text, language).await {
+                OutputTranslationOutcome::Translated(text) => {
+                    final_text = text.clone();
+                    post_processed_text = Some(text);
+                }
+                OutputTranslationOutcome::Failed => {}
+                OutputTranslationOutcome::Cancelled => return None,
+            }
+        }
+    }
+
     // Apply text replacements AFTER LLM if NOT configured for before
     if !settings.text_replacements_before_llm {
         final_text = apply_replacements(&final_text);
@@ -5589,17 +8351,25 @@ pub(crate) async fn process_transcription_output(
     final_text =
         crate::text_replacement_decapitalize::maybe_decapitalize_next_chunk_standard(&final_text);
 
+    if let Some(mode) = settings.output_casing_for(profile) {
+        final_text = apply_casing(&final_text, mode.into(), &settings.output_casing_exceptions);
+    }
+
     final_text = apply_output_whitespace_policy_for_settings(&final_text, settings);
 
-    // Keep recent transcript context per app for prompt variable ${short_prev_transcript}.
+    // Keep recent transcript context per app and profile for prompt variable ${short_prev_transcript}.
     // Use raw transcription (before post-processing) to avoid compounding LLM output.
-    update_short_prev_transcript(settings, current_app, transcription);
+    update_short_prev_transcript(settings, profile, current_app, transcription);
 
     Some(ProcessedTranscription {
         final_text,
         post_processed_text,
         post_process_prompt,
         post_process_requested,
+        post_process_note,
+        post_process_provider,
+        post_process_raw_response,
+        post_process_chunked,
     })
 }
 
@@ -5615,20 +8385,24 @@ async fn apply_post_processing_and_history(
     app: &AppHandle,
     settings: &AppSettings,
     transcription: String,
+    details: TranscriptDetails,
     samples: Vec<f32>,
     profile_id: Option<String>,
     current_app: &str,
+    window_context: &RecordingWindowContext,
     pre_saved_file_name: Option<String>,
     failed_history_entry_id: Option<i64>,
     force_post_process: bool,
     operation_stamp: Option<OperationStamp>,
 ) -> Option<String> {
+    let confidence = details.confidence;
     let processed = process_transcription_output(
         app,
         settings,
         &transcription,
         profile_id.as_deref(),
         current_app,
+        window_context,
         force_post_process,
     )
     .await?;
@@ -5643,8 +8417,43 @@ async fn apply_post_processing_and_history(
         }
     }
 
+    queue_file_output(
+        app,
+        settings,
+        profile_id.as_deref(),
+        current_app,
+        window_context,
+        &processed.final_text,
+    );
+    crate::webhook::queue_delivery(
+        app,
+        settings,
+        profile_id.as_deref(),
+        current_app,
+        &transcription,
+        &processed.final_text,
+    );
+
+    let stt_provider = details
+        .race_winner
+        .unwrap_or(settings.transcription_provider.as_str());
+    record_dictation_usage(
+        app,
+        &processed,
+        profile_id.as_deref(),
+        stt_provider,
+        samples.len(),
+    );
+
+    if crate::privacy_mode::should_skip("transcription history entry") {
+        return Some(processed.final_text);
+    }
+
     let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
     let history_app = app.clone();
+    let session_profile_id = profile_id.unwrap_or_else(|| "default".to_string());
+    let session_target_app = current_app.to_string();
+    let session_gap_secs = i64::from(settings.history_session_gap_minutes) * 60;
     tauri::async_runtime::spawn(async move {
         if let Some(stamp) = operation_stamp {
             if stamp.was_cancelled(&history_app) {
@@ -5655,6 +8464,7 @@ async fn apply_post_processing_and_history(
                 return;
             }
         }
+        let mut trimmed_start_secs = 0.0;
         let save_result = if let Some(id) = failed_history_entry_id {
             hm.update_transcription(
                 id,
@@ -5662,7 +8472,6 @@ async fn apply_post_processing_and_history(
                 processed.post_processed_text.clone(),
                 processed.post_process_prompt.clone(),
             )
-            .map(|_| ())
         } else if let Some(file_name) = pre_saved_file_name {
             hm.save_entry(
                 file_name,
@@ -5671,7 +8480,6 @@ async fn apply_post_processing_and_history(
                 processed.post_processed_text.clone(),
                 processed.post_process_prompt.clone(),
             )
-            .map(|_| ())
         } else {
             hm.save_transcription(
                 samples,
@@ -5681,10 +8489,68 @@ async fn apply_post_processing_and_history(
                 processed.post_process_prompt.clone(),
             )
             .await
+            .map(|(entry, trimmed)| {
+                trimmed_start_secs = trimmed;
+                entry
+            })
         };
 
-        if let Err(e) = save_result {
-            error!("Failed to save transcription to history: {}", e);
+        match save_result {
+            Ok(entry) => {
+                if let Err(e) = hm.set_stt_provider(entry.id, stt_provider) {
+                    error!("Failed to record transcription provider in history: {}", e);
+                }
+                if failed_history_entry_id.is_none() {
+                    if let Err(e) = hm.assign_session_group(
+                        entry.id,
+                        &session_profile_id,
+                        &session_target_app,
+                        session_gap_secs,
+                    ) {
+                        error!("Failed to assign dictation session in history: {}", e);
+                    }
+                }
+                if let Some(provider_id) = processed.post_process_provider.as_deref() {
+                    if let Err(e) = hm.set_post_process_provider(entry.id, Some(provider_id)) {
+                        error!("Failed to record post-process provider in history: {}", e);
+                    }
+                }
+                if let Some(raw_response) = processed.post_process_raw_response.as_deref() {
+                    if let Err(e) = hm.set_post_process_raw_response(entry.id, Some(raw_response)) {
+                        error!(
+                            "Failed to record raw post-process response in history: {}",
+                            e
+                        );
+                    }
+                }
+                if processed.post_process_chunked {
+                    if let Err(e) = hm.set_post_process_chunked(entry.id, true) {
+                        error!("Failed to record chunked post-processing in history: {}", e);
+                    }
+                }
+                if let Some(segments) = details.segments.as_deref() {
+                    // Timings refer to the full recording; the saved audio may start later.
+                    let segments = crate::subtitle::shift_segments(segments, trimmed_start_secs);
+                    if let Err(e) = hm.set_timestamps(entry.id, Some(&segments)) {
+                        error!("Failed to record segment timestamps in history: {}", e);
+                    }
+                }
+                if confidence.is_some() {
+                    if let Err(e) = hm.set_confidence(entry.id, confidence) {
+                        error!("Failed to record confidence in history: {}", e);
+                    }
+                }
+                if entry.post_process_note != processed.post_process_note {
+                    if let Err(e) =
+                        hm.set_post_process_note(entry.id, processed.post_process_note.clone())
+                    {
+                        error!("Failed to record post-process note in history: {}", e);
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Failed to save transcription to history: {}", e);
+            }
         }
     });
 
@@ -5699,6 +8565,7 @@ fn build_extension_message(
     instruction: &str,
     selection: &str,
     current_app: &str,
+    window_context: &RecordingWindowContext,
 ) -> String {
     let instruction_trimmed = instruction.trim();
     let selection_trimmed = selection.trim();
@@ -5734,10 +8601,12 @@ fn build_extension_message(
             settings,
             None,
             current_app,
+            window_context,
             selection_trimmed,
             instruction_trimmed,
             selection_trimmed,
-        );
+        )
+        .with_clipboard_for(app, settings, &[user_template]);
         apply_llm_template_vars(user_template, &template_context)
     };
 
@@ -5757,6 +8626,7 @@ async fn ai_replace_with_llm(
     selected_text: &str,
     instruction: &str,
     current_app: &str,
+    window_context: &RecordingWindowContext,
 ) -> Result<String, String> {
     let provider = settings
         .active_ai_replace_provider()
@@ -5814,10 +8684,12 @@ async fn ai_replace_with_llm(
         settings,
         active_profile,
         current_app,
+        window_context,
         selected_text,
         instruction,
         selected_text,
-    );
+    )
+    .with_clipboard_for(app, settings, &[&user_template, &system_prompt]);
 
     let user_prompt = apply_llm_template_vars(&user_template, &template_context);
     let system_prompt = apply_llm_template_vars(&system_prompt, &template_context);
@@ -5879,7 +8751,10 @@ impl ShortcutAction for TranscribeAction {
         let start_time = Instant::now();
         debug!("TranscribeAction::start called for binding: {}", binding_id);
 
-        let settings = get_settings(app);
+        let mut settings = get_settings(app);
+        if interview::is_interview_binding(&settings, binding_id) {
+            interview::disable_streaming(&mut settings);
+        }
         let use_live_streaming = should_use_live_streaming(&settings);
         let profile = resolve_profile_for_binding(&settings, binding_id);
         let optimized_delivery_profile_id = profile.map(|p| p.id.clone());
@@ -5897,6 +8772,8 @@ impl ShortcutAction for TranscribeAction {
             should_use_native_local_streaming(app, &settings, profile, binding_id);
         let use_local_preview_streaming =
             should_use_local_preview_auto_flush(app, &settings, profile, binding_id);
+        let use_local_streaming_preview =
+            should_use_local_streaming_preview(app, &settings, profile, binding_id);
 
         if !start_recording_with_feedback(app, binding_id) {
             // Recording failed to start (e.g., system busy) - reset toggle state
@@ -5921,6 +8798,16 @@ impl ShortcutAction for TranscribeAction {
                 .cancel_generation(),
         };
 
+        if use_local_streaming_preview {
+            start_local_streaming_preview(
+                app,
+                binding_id,
+                operation_stamp,
+                settings.custom_words_for_profile(profile),
+                settings.custom_words_enabled,
+            );
+        }
+
         if native_streaming_live_output {
             if let Err(error) =
                 crate::clipboard::begin_streaming_paste_session(app, recording_operation_id)
@@ -5959,10 +8846,7 @@ impl ShortcutAction for TranscribeAction {
         }
 
         if use_soniox_optimized_delivery && !use_live_streaming {
-            let language = profile
-                .as_ref()
-                .map(|p| p.language.clone())
-                .unwrap_or_else(|| settings.selected_language.clone());
+            let language = settings.requested_language(profile);
             let binding_id = binding_id.to_string();
             let app_handle = app.clone();
             let soniox_live_manager = Arc::clone(&app.state::<Arc<SonioxRealtimeManager>>());
@@ -6003,10 +8887,7 @@ impl ShortcutAction for TranscribeAction {
                 }
             }
 
-            let language = profile
-                .as_ref()
-                .map(|p| p.language.clone())
-                .unwrap_or_else(|| settings.selected_language.clone());
+            let language = settings.requested_language(profile);
             let binding_id = binding_id.to_string();
             let app_handle = app.clone();
             let _ = take_soniox_stream_processor(&binding_id);
@@ -6017,7 +8898,11 @@ impl ShortcutAction for TranscribeAction {
                 None
             } else {
                 set_soniox_stream_emitted(&binding_id, false);
-                Some(register_soniox_stream_processor(&binding_id, &settings))
+                Some(register_soniox_stream_processor(
+                    &binding_id,
+                    &settings,
+                    profile,
+                ))
             };
 
             match settings.transcription_provider {
@@ -6189,13 +9074,12 @@ impl ShortcutAction for TranscribeAction {
                     }
                 }
                 TranscriptionProvider::RemoteOpenAiCompatible
+                | TranscriptionProvider::RemoteOpenAiRealtime
                     if should_use_openai_realtime_whisper_live(&settings) =>
                 {
                     let openai_realtime_whisper_manager =
                         Arc::clone(&app.state::<Arc<OpenAiRealtimeWhisperManager>>());
-                    let api_key = match crate::managers::remote_stt::get_remote_stt_api_key(
-                        &settings.remote_stt,
-                    ) {
+                    let api_key = match openai_realtime_whisper_api_key(&settings) {
                         Ok(api_key) => api_key,
                         Err(err) => {
                             let _ = take_soniox_stream_processor(&binding_id);
@@ -6374,6 +9258,7 @@ impl ShortcutAction for TranscribeAction {
             }
             let profile_id_for_postprocess = stop_context.captured_profile_id.clone();
             let current_app = stop_context.current_app.clone();
+            let window_context = stop_context.window_context.clone();
             let operation_stamp = stop_context.operation_stamp();
             let recording_operation_id = stop_context.operation_id;
 
@@ -6561,8 +9446,16 @@ impl ShortcutAction for TranscribeAction {
                     return;
                 }
                 let mut recovered_from_soniox_replay = false;
+                let mut details = TranscriptDetails {
+                    confidence: soniox_live_manager.take_last_confidence(),
+                    ..TranscriptDetails::default()
+                };
                 let transcription = match transcription_result {
-                    Ok(text) => apply_soniox_output_filters(&recording_settings, text),
+                    Ok(text) => apply_soniox_output_filters(
+                        &recording_settings,
+                        profile_id_for_postprocess.as_deref(),
+                        text,
+                    ),
                     Err(err) => {
                         let err_str = format!("{}", err);
                         let can_replay_soniox = should_replay_soniox_live_timeout(
@@ -6594,8 +9487,18 @@ impl ShortcutAction for TranscribeAction {
                             )
                             .await
                             {
-                                TranscriptionOutcome::Success(text) => {
+                                TranscriptionOutcome::Success {
+                                    text,
+                                    confidence,
+                                    segments,
+                                    race_winner,
+                                } => {
                                     recovered_from_soniox_replay = true;
+                                    details = TranscriptDetails {
+                                        segments,
+                                        confidence,
+                                        race_winner,
+                                    };
                                     text
                                 }
                                 TranscriptionOutcome::Cancelled => {
@@ -6759,8 +9662,9 @@ impl ShortcutAction for TranscribeAction {
 
                 let stream_trailing_adjustment =
                     resolve_stream_trailing_adjustment(&recording_settings, &transcription);
-                let copy_to_clipboard = recording_settings.clipboard_handling
-                    == crate::settings::ClipboardHandling::CopyToClipboard;
+                let copy_to_clipboard =
+                    crate::privacy_mode::clipboard_handling(recording_settings.clipboard_handling)
+                        == crate::settings::ClipboardHandling::CopyToClipboard;
                 let transcription_before_post_process = transcription.clone();
                 let preview_processing_before_insert = should_show_preview_processing_before_insert(
                     &recording_settings,
@@ -6772,13 +9676,16 @@ impl ShortcutAction for TranscribeAction {
                     start_preview_processing_before_insert(&ah);
                 }
 
+                let confidence = details.confidence;
                 let final_text = match apply_post_processing_and_history(
                     &ah,
                     &recording_settings,
                     transcription,
+                    details,
                     samples,
                     profile_id_for_postprocess,
                     &current_app,
+                    &window_context,
                     None,
                     None,
                     force_post_process,
@@ -6818,6 +9725,10 @@ impl ShortcutAction for TranscribeAction {
                     finish_guard.finish();
                     return;
                 }
+                let low_confidence = crate::transcription_confidence::low_confidence(
+                    confidence.filter(|_| !preview_output_only_enabled),
+                    recording_settings.low_confidence_warning_threshold,
+                );
 
                 let ah_clone = ah.clone();
                 let final_text_for_ui = final_text.clone();
@@ -6875,7 +9786,7 @@ impl ShortcutAction for TranscribeAction {
                                 // chunks arrived. Apply only the final boundary adjustment.
                                 apply_stream_trailing_adjustment(
                                     &ah_clone,
-                                    stream_trailing_adjustment,
+                                    &stream_trailing_adjustment,
                                 );
                             }
                             if copy_to_clipboard {
@@ -6884,7 +9795,7 @@ impl ShortcutAction for TranscribeAction {
                         }
                     }
 
-                    utils::hide_recording_overlay(&ah_clone);
+                    close_overlay_after_output(&ah_clone, low_confidence);
                     change_tray_icon(&ah_clone, TrayIconState::Idle);
                 }) {
                     warn!("{}", err);
@@ -6949,6 +9860,7 @@ impl ShortcutAction for TranscribeAction {
         };
         let captured_profile_id = stop_context.captured_profile_id.clone();
         let current_app = stop_context.current_app.clone();
+        let window_context = stop_context.window_context.clone();
         let recording_settings = stop_context.recording_settings.clone();
         let operation_stamp = stop_context.operation_stamp();
         let recording_operation_id = stop_context.operation_id;
@@ -6971,6 +9883,18 @@ impl ShortcutAction for TranscribeAction {
         let force_post_process = take_force_post_process_for_binding(&binding_id);
         let preview_output_only_enabled = preview_output_only_enabled;
         let invoked_from_preview_action = invoked_from_preview_action;
+        let captured_profile = captured_profile_id
+            .as_ref()
+            .and_then(|profile_id| recording_settings.transcription_profile(profile_id));
+        let output_destination = captured_profile
+            .map(|profile| profile.output_destination.clone())
+            .unwrap_or_default();
+        let file_output_replaces_paste = captured_profile.is_some_and(|profile| {
+            profile
+                .file_output
+                .as_ref()
+                .is_some_and(|config| config.instead_of_paste)
+        });
 
         tauri::async_runtime::spawn(async move {
             let mut finish_guard =
@@ -7042,7 +9966,11 @@ impl ShortcutAction for TranscribeAction {
             {
                 let tm = Arc::clone(&ah.state::<Arc<TranscriptionManager>>());
                 if tm.is_streaming() || tm.stream_router().is_open() {
-                    match tm.finalize_stream() {
+                    let profile = captured_profile_id.as_ref().and_then(|profile_id| {
+                        recording_settings.transcription_profile(profile_id)
+                    });
+                    let custom_words = recording_settings.custom_words_for_profile(profile);
+                    match tm.finalize_stream(Some(custom_words)) {
                         Ok(Some(text)) if !text.trim().is_empty() => Some(text),
                         Ok(_) => None,
                         Err(err) => {
@@ -7069,10 +9997,11 @@ impl ShortcutAction for TranscribeAction {
                 None
             };
 
-            let (transcription, samples, pre_saved_file_name, post_process_requested) =
+            let (transcription, details, samples, pre_saved_file_name, post_process_requested) =
                 if stopped.quick_tap_skipped {
                     (
                         String::new(),
+                        TranscriptDetails::default(),
                         stopped.samples,
                         stopped.pre_saved_file_name,
                         stopped.post_process_requested,
@@ -7080,6 +10009,7 @@ impl ShortcutAction for TranscribeAction {
                 } else if let Some(text) = native_stream_text {
                     (
                         text,
+                        TranscriptDetails::default(),
                         stopped.samples,
                         stopped.pre_saved_file_name,
                         stopped.post_process_requested,
@@ -7091,6 +10021,7 @@ impl ShortcutAction for TranscribeAction {
                         captured_profile_id,
                         recording_settings.clone(),
                         current_app.clone(),
+                        window_context.clone(),
                         stopped,
                     )
                     .await
@@ -7147,7 +10078,7 @@ impl ShortcutAction for TranscribeAction {
                 if let Some(file_name) = pre_saved_file_name {
                     save_failed_transcription_entry(&ah, file_name, post_process_requested);
                 }
-                utils::hide_recording_overlay(&ah);
+                close_overlay_after_output(&ah, None);
                 change_tray_icon(&ah, TrayIconState::Idle);
                 finish_guard.finish();
                 return;
@@ -7156,10 +10087,10 @@ impl ShortcutAction for TranscribeAction {
             let stream_trailing_adjustment = if is_soniox_streaming_insert {
                 resolve_stream_trailing_adjustment(&recording_settings, &transcription)
             } else {
-                StreamTrailingAdjustment::None
+                TrailingAdjustment::default()
             };
             let copy_to_clipboard = if is_soniox_streaming_insert {
-                recording_settings.clipboard_handling
+                crate::privacy_mode::clipboard_handling(recording_settings.clipboard_handling)
                     == crate::settings::ClipboardHandling::CopyToClipboard
             } else {
                 false
@@ -7174,13 +10105,16 @@ impl ShortcutAction for TranscribeAction {
                 start_preview_processing_before_insert(&ah);
             }
 
+            let confidence = details.confidence;
             let final_text = match apply_post_processing_and_history(
                 &ah,
                 &recording_settings,
                 transcription,
+                details,
                 samples,
                 profile_id_for_postprocess,
                 &current_app,
+                &window_context,
                 pre_saved_file_name,
                 None,
                 force_post_process,
@@ -7217,6 +10151,10 @@ impl ShortcutAction for TranscribeAction {
                 finish_guard.finish();
                 return;
             }
+            let low_confidence = crate::transcription_confidence::low_confidence(
+                confidence.filter(|_| !preview_output_only_enabled),
+                recording_settings.low_confidence_warning_threshold,
+            );
 
             let ah_clone = ah.clone();
             let final_text_for_ui = final_text.clone();
@@ -7229,9 +10167,36 @@ impl ShortcutAction for TranscribeAction {
                 } else {
                     None
                 };
+            // Continuation mode only joins whole pastes; streaming inserts
+            // handle their boundaries through the output whitespace policy.
+            let text_to_paste = if uses_streaming_insert {
+                final_text.clone()
+            } else {
+                crate::smart_join::apply(&recording_settings, &window_context.app_id, &final_text)
+            };
+            let paste_app_id = window_context.app_id.clone();
             if !preview_output_only_enabled {
                 before_dictation_final_output(&ah, &final_text);
             }
+            let destination_failed = match &output_destination {
+                OutputDestination::AppendToFile { path_template }
+                    if !preview_output_only_enabled =>
+                {
+                    match crate::file_output::append_to_destination(path_template, &final_text) {
+                        Ok(path) => {
+                            debug!("Appended transcription to {}", path.display());
+                            play_result_ready_sound(&ah);
+                            false
+                        }
+                        Err(err) => {
+                            error!("Failed to append transcription: {}", err);
+                            crate::plus_overlay_state::handle_transcription_error(&ah, &err);
+                            true
+                        }
+                    }
+                }
+                _ => false,
+            };
             ah.run_on_main_thread(move || {
                 if operation_stamp.was_cancelled(&ah_clone) {
                     debug!(
@@ -7243,20 +10208,48 @@ impl ShortcutAction for TranscribeAction {
                 if uses_streaming_insert && !preview_output_only_enabled {
                     // Streaming paths already inserted only committed text incrementally.
                     // Apply only boundary-level trailing adjustment at finalization.
-                    apply_stream_trailing_adjustment(&ah_clone, stream_trailing_adjustment);
+                    apply_stream_trailing_adjustment(&ah_clone, &stream_trailing_adjustment);
                     if copy_to_clipboard {
                         let _ = ah_clone.clipboard().write_text(final_text_for_ui.clone());
                     }
                 } else if !preview_output_only_enabled {
-                    match utils::paste(final_text_for_ui.clone(), ah_clone.clone()) {
-                        Ok(()) => play_result_ready_sound(&ah_clone),
-                        Err(err) => {
-                            error!("Failed to paste transcription: {}", err);
-                            let _ = ah_clone.emit("paste-error", ());
+                    match &output_destination {
+                        // The profile's file output already has the text.
+                        OutputDestination::InsertAtCursor if file_output_replaces_paste => {
+                            play_result_ready_sound(&ah_clone);
+                        }
+                        OutputDestination::InsertAtCursor => {
+                            match utils::paste(text_to_paste.clone(), ah_clone.clone()) {
+                                Ok(()) => {
+                                    crate::smart_join::record_paste(
+                                        &get_settings(&ah_clone),
+                                        &paste_app_id,
+                                        &text_to_paste,
+                                    );
+                                    play_result_ready_sound(&ah_clone);
+                                }
+                                Err(err) => {
+                                    error!("Failed to paste transcription: {}", err);
+                                    let _ = ah_clone.emit("paste-error", ());
+                                }
+                            }
                         }
+                        OutputDestination::ClipboardOnly => {
+                            match ah_clone.clipboard().write_text(final_text_for_ui.clone()) {
+                                Ok(()) => play_result_ready_sound(&ah_clone),
+                                Err(err) => {
+                                    error!("Failed to copy transcription: {}", err);
+                                    let _ = ah_clone.emit("paste-error", ());
+                                }
+                            }
+                        }
+                        OutputDestination::AppendToFile { .. } => {}
                     }
                 }
-                utils::hide_recording_overlay(&ah_clone);
+                // A failed file append keeps the error overlay up.
+                if !destination_failed {
+                    close_overlay_after_output(&ah_clone, low_confidence);
+                }
                 change_tray_icon(&ah_clone, TrayIconState::Idle);
             })
             .ok();
@@ -7299,6 +10292,245 @@ impl ShortcutAction for TranscribeAction {
     }
 }
 
+/// Hides the overlay once output is delivered, or swaps it for the
+/// low-confidence notice so the user reviews the pasted text. A recording cut
+/// short by a disconnected microphone reports that instead.
+fn close_overlay_after_output(app: &AppHandle, low_confidence: Option<f32>) {
+    if app
+        .state::<Arc<AudioRecordingManager>>()
+        .take_input_device_lost()
+    {
+        crate::plus_overlay_state::show_error_overlay(
+            app,
+            crate::plus_overlay_state::OverlayErrorCategory::MicrophoneDisconnected,
+        );
+        return;
+    }
+    match low_confidence {
+        Some(confidence) => crate::overlay::show_low_confidence_overlay(app, confidence),
+        None => utils::hide_recording_overlay(app),
+    }
+}
+
+/// Saves a Transcribe and Search result to history with the "search" action type.
+fn save_search_history_entry(
+    app: &AppHandle,
+    transcription: String,
+    samples: Vec<f32>,
+    processed: &ProcessedTranscription,
+    stt_provider: &'static str,
+) {
+    if crate::privacy_mode::should_skip("search history entry") {
+        return;
+    }
+
+    let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
+    let post_process_requested = processed.post_process_requested;
+    let post_processed_text = processed.post_processed_text.clone();
+    let post_process_prompt = processed.post_process_prompt.clone();
+    tauri::async_runtime::spawn(async move {
+        let entry = match hm
+            .save_transcription(
+                samples,
+                transcription,
+                post_process_requested,
+                post_processed_text,
+                post_process_prompt,
+            )
+            .await
+        {
+            Ok((entry, _)) => entry,
+            Err(e) => {
+                error!("Failed to save search transcription to history: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = hm.set_action_type(entry.id, "search", stt_provider) {
+            error!("Failed to mark history entry as search: {}", e);
+        }
+    });
+}
+
+impl ShortcutAction for TranscribeAndSearchAction {
+    fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        debug!(
+            "TranscribeAndSearchAction::start called for binding: {}",
+            binding_id
+        );
+
+        // Live streaming is not used: nothing may be typed into the focused app.
+        if !start_recording_with_feedback(app, binding_id) {
+            reset_toggle_state(app, binding_id);
+        }
+    }
+
+    fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let stop_context = match prepare_stop_recording(app, binding_id) {
+            Some(context) => context,
+            None => return, // No active session - nothing to do
+        };
+        let operation_stamp = stop_context.operation_stamp();
+        let StopRecordingContext {
+            operation_id: recording_operation_id,
+            current_app,
+            window_context,
+            recording_settings,
+            ..
+        } = stop_context;
+
+        let ah = app.clone();
+        let binding_id = binding_id.to_string();
+
+        tauri::async_runtime::spawn(async move {
+            let mut finish_guard =
+                FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id);
+
+            let (transcription, samples, _) = match get_transcription_or_cleanup(
+                &ah,
+                &binding_id,
+                None,
+                recording_settings.clone(),
+            )
+            .await
+            {
+                Some(res) => res,
+                None => {
+                    finish_guard.finish();
+                    return;
+                }
+            };
+
+            let hide_overlay = |app: &AppHandle| {
+                utils::hide_recording_overlay(app);
+                change_tray_icon(app, TrayIconState::Idle);
+            };
+
+            if transcription.trim().is_empty() {
+                hide_overlay(&ah);
+                finish_guard.finish();
+                return;
+            }
+
+            // Post-processing is skipped unless explicitly enabled for this action.
+            let mut search_settings = recording_settings.clone();
+            if !search_settings.transcribe_and_search_post_process_enabled {
+                search_settings.post_process_enabled = false;
+            }
+            let Some(processed) = process_transcription_output(
+                &ah,
+                &search_settings,
+                &transcription,
+                None,
+                &current_app,
+                &window_context,
+                false,
+            )
+            .await
+            else {
+                hide_overlay(&ah);
+                finish_guard.finish();
+                return;
+            };
+
+            if !finish_guard.is_current() || operation_stamp.was_cancelled(&ah) {
+                finish_guard.finish();
+                return;
+            }
+
+            save_search_history_entry(
+                &ah,
+                transcription,
+                samples,
+                &processed,
+                search_settings.transcription_provider.as_str(),
+            );
+
+            let query = processed.final_text.trim();
+            if query.is_empty() {
+                debug!("Search skipped because the processed transcription is empty");
+            } else {
+                let template = search_settings
+                    .bindings
+                    .get(&binding_id)
+                    .and_then(|binding| binding.search_url_template.as_deref())
+                    .unwrap_or(&search_settings.transcribe_and_search_url_template);
+                match crate::url_security::build_search_url(template, query) {
+                    Ok(url) => {
+                        if let Err(e) = ah.opener().open_url(url, None::<String>) {
+                            error!("Failed to open search URL: {}", e);
+                        }
+                    }
+                    Err(e) => error!("Invalid search URL template: {}", e),
+                }
+            }
+
+            let ah_clone = ah.clone();
+            ah.run_on_main_thread(move || hide_overlay(&ah_clone)).ok();
+
+            finish_guard.finish();
+        });
+    }
+}
+
+/// Queues `text` for the extension. If the extension is offline, or the queue
+/// refuses the text, and `extension_offline_fallback_to_clipboard` is on, the
+/// text is copied to the clipboard instead. Returns true when it was.
+fn deliver_to_extension(app: &AppHandle, cm: &ConnectorManager, text: &str) -> bool {
+    let fallback_to_clipboard = get_settings(app).extension_offline_fallback_to_clipboard;
+    if cm.is_online() || !fallback_to_clipboard {
+        match cm.queue_message(text) {
+            Ok(id) => {
+                debug!("Connector message queued with id: {}", id);
+                app.state::<Arc<UsageStatsManager>>().record(UsageRecord {
+                    counters: UsageCounters {
+                        extension_messages: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+                return false;
+            }
+            Err(e) => {
+                error!("Failed to queue connector message: {}", e);
+                if !fallback_to_clipboard {
+                    return false;
+                }
+            }
+        }
+    } else {
+        info!("Extension is offline, copying the final text to the clipboard instead");
+    }
+
+    match app.clipboard().write_text(text.to_string()) {
+        Ok(()) => true,
+        Err(e) => {
+            error!("Failed to copy extension text to clipboard: {}", e);
+            false
+        }
+    }
+}
+
+fn finish_extension_delivery(
+    app: &AppHandle,
+    mut finish_guard: FinishGuard,
+    copied_to_clipboard: bool,
+) {
+    let ah_clone = app.clone();
+    app.run_on_main_thread(move || {
+        // The clipboard notice replaces the overlay and hides it by itself.
+        if !copied_to_clipboard {
+            utils::hide_recording_overlay(&ah_clone);
+        }
+        change_tray_icon(&ah_clone, TrayIconState::Idle);
+    })
+    .ok();
+
+    finish_guard.finish();
+    if copied_to_clipboard {
+        crate::overlay::show_extension_offline_clipboard_overlay(app);
+    }
+}
+
 impl ShortcutAction for SendToExtensionAction {
     fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         let start_time = Instant::now();
@@ -7343,34 +10575,41 @@ impl ShortcutAction for SendToExtensionAction {
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
-        if !cm.is_online() {
+        if !cm.is_online() && !get_settings(app).extension_offline_fallback_to_clipboard {
             // Extension went offline - take session to trigger cleanup via Drop
             let _ = session_manager::take_session_if_matches(app, binding_id);
             let _ = take_recording_app_context(binding_id);
             return;
         }
 
-        let stop_context = match prepare_stop_recording(app, binding_id) {
-            Some(context) => context,
-            None => return, // No active session - nothing to do
-        };
+        // Keep the cancel shortcut alive while the LLM is thinking, so cancel
+        // can still stop the message from reaching the connector.
+        let (stop_context, holds_cancel_shortcut) =
+            match prepare_stop_recording_keeping_cancel_shortcut(app, binding_id) {
+                Some(prepared) => prepared,
+                None => return, // No active session - nothing to do
+            };
         let operation_stamp = stop_context.operation_stamp();
         let StopRecordingContext {
             operation_id: recording_operation_id,
             current_app,
+            window_context,
             recording_settings,
             ..
         } = stop_context;
 
         let ah = app.clone();
         let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
+        let llm_tracker = Arc::clone(&app.state::<Arc<LlmOperationTracker>>());
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
             let mut finish_guard =
-                FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id);
+                FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id)
+                    .holding_cancel_shortcut(holds_cancel_shortcut);
+            let llm_operation_id = llm_tracker.start_operation();
 
-            let (transcription, samples) = match get_transcription_or_cleanup(
+            let (transcription, samples, details) = match get_transcription_or_cleanup(
                 &ah,
                 &binding_id,
                 None,
@@ -7397,9 +10636,11 @@ impl ShortcutAction for SendToExtensionAction {
                 &ah,
                 &recording_settings,
                 transcription,
+                details,
                 samples,
                 None,
                 &current_app,
+                &window_context,
                 None,
                 None,
                 false,
@@ -7414,24 +10655,13 @@ impl ShortcutAction for SendToExtensionAction {
                 }
             };
 
-            if !finish_guard.is_current() {
+            if !finish_guard.is_current() || llm_tracker.is_cancelled(llm_operation_id) {
                 finish_guard.finish();
                 return;
             }
 
-            match cm.queue_message(&final_text) {
-                Ok(id) => debug!("Connector message queued with id: {}", id),
-                Err(e) => error!("Failed to queue connector message: {}", e),
-            }
-
-            let ah_clone = ah.clone();
-            ah.run_on_main_thread(move || {
-                utils::hide_recording_overlay(&ah_clone);
-                change_tray_icon(&ah_clone, TrayIconState::Idle);
-            })
-            .ok();
-
-            finish_guard.finish();
+            let copied_to_clipboard = deliver_to_extension(&ah, &cm, &final_text);
+            finish_extension_delivery(&ah, finish_guard, copied_to_clipboard);
         });
     }
 }
@@ -7480,34 +10710,41 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
-        if !cm.is_online() {
+        if !cm.is_online() && !get_settings(app).extension_offline_fallback_to_clipboard {
             // Extension went offline - take session to trigger cleanup via Drop
             let _ = session_manager::take_session_if_matches(app, binding_id);
             let _ = take_recording_app_context(binding_id);
             return;
         }
 
-        let stop_context = match prepare_stop_recording(app, binding_id) {
-            Some(context) => context,
-            None => return, // No active session - nothing to do
-        };
+        // Keep the cancel shortcut alive while the LLM is thinking, so cancel
+        // can still stop the message from reaching the connector.
+        let (stop_context, holds_cancel_shortcut) =
+            match prepare_stop_recording_keeping_cancel_shortcut(app, binding_id) {
+                Some(prepared) => prepared,
+                None => return, // No active session - nothing to do
+            };
         let operation_stamp = stop_context.operation_stamp();
         let StopRecordingContext {
             operation_id: recording_operation_id,
             current_app,
+            window_context,
             recording_settings,
             ..
         } = stop_context;
 
         let ah = app.clone();
         let cm = Arc::clone(&app.state::<Arc<ConnectorManager>>());
+        let llm_tracker = Arc::clone(&app.state::<Arc<LlmOperationTracker>>());
         let binding_id = binding_id.to_string();
 
         tauri::async_runtime::spawn(async move {
             let mut finish_guard =
-                FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id);
+                FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id)
+                    .holding_cancel_shortcut(holds_cancel_shortcut);
+            let llm_operation_id = llm_tracker.start_operation();
 
-            let (transcription, samples) = match get_transcription_or_cleanup(
+            let (transcription, samples, details) = match get_transcription_or_cleanup(
                 &ah,
                 &binding_id,
                 None,
@@ -7550,9 +10787,11 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                     &ah,
                     &recording_settings,
                     transcription,
+                    details,
                     samples,
                     None,
                     &current_app,
+                    &window_context,
                     None,
                     None,
                     false,
@@ -7568,7 +10807,7 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                 }
             };
 
-            if !finish_guard.is_current() {
+            if !finish_guard.is_current() || llm_tracker.is_cancelled(llm_operation_id) {
                 finish_guard.finish();
                 return;
             }
@@ -7580,20 +10819,14 @@ impl ShortcutAction for SendToExtensionWithSelectionAction {
                 &final_transcription,
                 &selected_text,
                 &current_app,
+                &window_context,
             );
 
-            if !message.trim().is_empty() {
-                let _ = cm.queue_message(&message);
-            }
-
-            let ah_clone = ah.clone();
-            ah.run_on_main_thread(move || {
-                utils::hide_recording_overlay(&ah_clone);
-                change_tray_icon(&ah_clone, TrayIconState::Idle);
-            })
-            .ok();
-
-            finish_guard.finish();
+            // Selection capture takes a moment; cancel may have landed meanwhile.
+            let copied_to_clipboard = !message.trim().is_empty()
+                && !llm_tracker.is_cancelled(llm_operation_id)
+                && deliver_to_extension(&ah, &cm, &message);
+            finish_extension_delivery(&ah, finish_guard, copied_to_clipboard);
         });
     }
 }
@@ -7604,6 +10837,29 @@ fn emit_screenshot_error(app: &AppHandle, message: impl Into<String>) {
     let _ = app.emit("screenshot-error", message);
 }
 
+/// Reads the clipboard image as PNG. `Ok(None)` means the clipboard holds no image.
+fn read_clipboard_image_png(app: &AppHandle) -> Result<Option<Vec<u8>>, String> {
+    let image = match app.clipboard().read_image() {
+        Ok(image) => image,
+        Err(e) => {
+            debug!("Clipboard has no readable image: {}", e);
+            return Ok(None);
+        }
+    };
+
+    #[cfg(target_os = "windows")]
+    {
+        crate::region_capture::encode_rgba_to_png(image.rgba(), image.width(), image.height())
+            .map(Some)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = image;
+        Err("Clipboard screenshot capture is only supported on Windows.".to_string())
+    }
+}
+
 /// Expands Windows-style environment variables like %USERPROFILE% in a path string.
 /// On non-Windows platforms, returns the path unchanged.
 #[cfg(target_os = "windows")]
@@ -7915,7 +11171,7 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
             let mut finish_guard =
                 FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id);
 
-            let (voice_text, samples) = match get_transcription_or_cleanup(
+            let (voice_text, samples, _) = match get_transcription_or_cleanup(
                 &ah,
                 &binding_id,
                 None,
@@ -8023,6 +11279,43 @@ impl ShortcutAction for SendScreenshotToExtensionAction {
                 return;
             }
 
+            if recording_settings.screenshot_capture_method
+                == crate::settings::ScreenshotCaptureMethod::Clipboard
+            {
+                match read_clipboard_image_png(&ah) {
+                    Ok(Some(image_data)) => {
+                        let _ = cm.queue_bundle_message_bytes(
+                            &final_voice_text,
+                            image_data,
+                            "image/png",
+                        );
+                        finish_guard.finish();
+                        return;
+                    }
+                    Ok(None)
+                        if !recording_settings
+                            .screenshot_capture_command
+                            .trim()
+                            .is_empty() =>
+                    {
+                        debug!("No image on clipboard; falling back to screenshot folder watch");
+                    }
+                    Ok(None) => {
+                        emit_screenshot_error(
+                            &ah,
+                            "No image on clipboard. Copy a screenshot first (e.g. Win+Shift+S).",
+                        );
+                        finish_guard.finish();
+                        return;
+                    }
+                    Err(e) => {
+                        emit_screenshot_error(&ah, &e);
+                        finish_guard.finish();
+                        return;
+                    }
+                }
+            }
+
             // Validate screenshot folder before launching capture tool
             let screenshot_folder =
                 PathBuf::from(expand_env_vars(&recording_settings.screenshot_folder));
@@ -8139,6 +11432,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
         let StopRecordingContext {
             operation_id: recording_operation_id,
             current_app,
+            window_context,
             recording_settings,
             ..
         } = stop_context;
@@ -8155,7 +11449,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
             let llm_tracker = Arc::clone(&ah.state::<Arc<LlmOperationTracker>>());
             let operation_id = llm_tracker.start_operation();
 
-            let (transcription, _) = match get_transcription_or_cleanup_detailed(
+            let (transcription, samples, _) = match get_transcription_or_cleanup_detailed(
                 &ah,
                 &binding_id,
                 None,
@@ -8200,7 +11494,17 @@ impl ShortcutAction for AiReplaceSelectionAction {
                 return;
             }
 
-            let selected_text = match utils::capture_selection_text(&ah) {
+            // The preview keeps the selection in place until the user accepts,
+            // so copy instead of cut; there is then nothing to restore on error.
+            let confirm_before_paste = recording_settings.ai_replace_confirm_before_paste;
+            let restore_on_error =
+                recording_settings.ai_replace_restore_on_error && !confirm_before_paste;
+            let captured_selection = if confirm_before_paste {
+                utils::capture_selection_text_copy(&ah)
+            } else {
+                utils::capture_selection_text(&ah)
+            };
+            let selected_text = match captured_selection {
                 Ok(text) => text,
                 Err(_) => {
                     if recording_settings.ai_replace_allow_no_selection {
@@ -8228,7 +11532,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
                 maybe_restore_ai_replace_selection(
                     &ah,
                     &selected_text,
-                    recording_settings.ai_replace_restore_on_error,
+                    restore_on_error,
                     "LLM cancellation",
                 );
                 // cancel_current_operation already handled overlay/session cleanup.
@@ -8237,16 +11541,15 @@ impl ShortcutAction for AiReplaceSelectionAction {
 
             show_thinking_overlay(&ah);
 
-            let hm = Arc::clone(&ah.state::<Arc<HistoryManager>>());
             let instruction_for_history = transcription.clone();
             let selection_for_history = selected_text.clone();
-
             match ai_replace_with_llm(
                 &ah,
                 &recording_settings,
                 &selected_text,
                 &transcription,
                 &current_app,
+                &window_context,
             )
             .await
             {
@@ -8260,7 +11563,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         maybe_restore_ai_replace_selection(
                             &ah,
                             &selected_text,
-                            recording_settings.ai_replace_restore_on_error,
+                            restore_on_error,
                             "LLM cancellation",
                         );
                         // Overlay already hidden by cancel_current_operation
@@ -8268,27 +11571,68 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         return;
                     }
 
-                    // Save to history with AI response
-                    let hm_clone = Arc::clone(&hm);
-                    let instruction_clone = instruction_for_history.clone();
-                    let selection_clone = selection_for_history.clone();
-                    let output_for_history = output.clone();
-                    tauri::async_runtime::spawn(async move {
-                        if let Err(e) = hm_clone
-                            .save_ai_replace_entry(
-                                instruction_clone,
-                                selection_clone,
-                                Some(output_for_history),
-                            )
-                            .await
-                        {
-                            error!("Failed to save AI Replace entry to history: {}", e);
-                        }
+                    // Counted once the LLM has answered, so failed calls are not.
+                    ah.state::<Arc<UsageStatsManager>>().record(UsageRecord {
+                        stt_provider: Some(
+                            recording_settings
+                                .transcription_provider
+                                .as_str()
+                                .to_string(),
+                        ),
+                        profile_id: None,
+                        llm_provider: recording_settings
+                            .active_ai_replace_provider()
+                            .map(|provider| provider.id.clone()),
+                        counters: UsageCounters {
+                            words: transcription.split_whitespace().count() as u64,
+                            characters: transcription.chars().count() as u64,
+                            audio_ms: audio_ms_for_samples(samples.len()),
+                            ai_replaces: 1,
+                            ..Default::default()
+                        },
                     });
 
+                    if confirm_before_paste {
+                        utils::hide_recording_overlay(&ah);
+                        let decision = crate::ai_replace_confirm::request_decision(
+                            &ah,
+                            &selected_text,
+                            &output,
+                            recording_settings.ai_replace_confirm_timeout_seconds,
+                        )
+                        .await;
+                        if llm_tracker.is_cancelled(operation_id) || !finish_guard.is_current() {
+                            debug!(
+                                "LLM operation {} was cancelled during the AI Replace preview",
+                                operation_id
+                            );
+                            return;
+                        }
+                        if decision != crate::ai_replace_confirm::AiReplaceDecision::Accepted {
+                            debug!("AI Replace result not pasted ({:?})", decision);
+                            spawn_save_ai_replace_history(
+                                &ah,
+                                instruction_for_history,
+                                selection_for_history,
+                                Some(output),
+                                true,
+                            );
+                            change_tray_icon(&ah, TrayIconState::Idle);
+                            finish_guard.finish();
+                            return;
+                        }
+                    }
+
+                    spawn_save_ai_replace_history(
+                        &ah,
+                        instruction_for_history,
+                        selection_for_history,
+                        Some(output.clone()),
+                        false,
+                    );
+
                     let ah_clone = ah.clone();
                     let restore_text = selected_text.clone();
-                    let restore_on_error = recording_settings.ai_replace_restore_on_error;
                     let llm_tracker_for_apply = Arc::clone(&llm_tracker);
                     let operation_id_for_apply = operation_id;
                     ah.run_on_main_thread(move || {
@@ -8343,7 +11687,7 @@ impl ShortcutAction for AiReplaceSelectionAction {
                         maybe_restore_ai_replace_selection(
                             &ah,
                             &selected_text,
-                            recording_settings.ai_replace_restore_on_error,
+                            restore_on_error,
                             "LLM cancellation",
                         );
                         // exit_processing already called by cancel
@@ -8351,23 +11695,18 @@ impl ShortcutAction for AiReplaceSelectionAction {
                     }
 
                     // Save to history with no AI response (indicates failure)
-                    tauri::async_runtime::spawn(async move {
-                        if let Err(e) = hm
-                            .save_ai_replace_entry(
-                                instruction_for_history,
-                                selection_for_history,
-                                None, // Response never received
-                            )
-                            .await
-                        {
-                            error!("Failed to save AI Replace entry to history: {}", e);
-                        }
-                    });
+                    spawn_save_ai_replace_history(
+                        &ah,
+                        instruction_for_history,
+                        selection_for_history,
+                        None,
+                        false,
+                    );
 
                     maybe_restore_ai_replace_selection(
                         &ah,
                         &selected_text,
-                        recording_settings.ai_replace_restore_on_error,
+                        restore_on_error,
                         "AI Replace error",
                     );
 
@@ -8420,6 +11759,20 @@ impl ShortcutAction for TestAction {
     }
 }
 
+/// Text a repaste action pastes for a history entry: the AI response for AI
+/// Replace entries (None if it never arrived), otherwise the post-processed
+/// text, falling back to the transcription.
+fn history_entry_repaste_text(entry: HistoryEntry) -> Option<String> {
+    match entry.action_type.as_str() {
+        "ai_replace" => entry.ai_response,
+        _ => Some(
+            entry
+                .post_processed_text
+                .unwrap_or(entry.transcription_text),
+        ),
+    }
+}
+
 // Repaste Last Action
 impl ShortcutAction for RepastLastAction {
     fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
@@ -8444,28 +11797,13 @@ impl ShortcutAction for RepastLastAction {
 
             match hm.get_latest_entry() {
                 Ok(Some(entry)) => {
-                    // Determine what text to paste based on action type
-                    let text_to_paste = match entry.action_type.as_str() {
-                        "ai_replace" => {
-                            // For AI Replace, use the AI response if available
-                            match entry.ai_response {
-                                Some(response) => response,
-                                None => {
-                                    // AI response never received
-                                    let _ = ah.emit(
-                                        "repaste-error",
-                                        "AI response was never received for this entry.",
-                                    );
-                                    return;
-                                }
-                            }
-                        }
-                        _ => {
-                            // For regular transcription, prefer post-processed text, fall back to transcription
-                            entry
-                                .post_processed_text
-                                .unwrap_or(entry.transcription_text)
-                        }
+                    let Some(text_to_paste) = history_entry_repaste_text(entry) else {
+                        // AI response never received
+                        let _ = ah.emit(
+                            "repaste-error",
+                            "AI response was never received for this entry.",
+                        );
+                        return;
                     };
 
                     if text_to_paste.trim().is_empty() {
@@ -8508,6 +11846,111 @@ impl ShortcutAction for RepastLastAction {
     }
 }
 
+// Repaste Last N Action
+impl ShortcutAction for RepasteLastNAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        debug!("RepasteLastNAction::start called");
+
+        let ah = app.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let settings = get_settings(&ah);
+            let count = settings
+                .repaste_last_count
+                .clamp(1, crate::settings::MAX_REPASTE_LAST_COUNT);
+            let hm = Arc::clone(&ah.state::<Arc<HistoryManager>>());
+
+            match hm.get_latest_entries(count as usize, settings.repaste_last_include_ai_replace) {
+                Ok(entries) if entries.is_empty() => {
+                    let _ = ah.emit("repaste-error", "No history entries available.");
+                }
+                Ok(entries) => {
+                    let text_to_paste = entries
+                        .into_iter()
+                        .filter_map(history_entry_repaste_text)
+                        .filter(|text| !text.trim().is_empty())
+                        .collect::<Vec<_>>()
+                        .join(&settings.repaste_last_separator);
+
+                    if text_to_paste.trim().is_empty() {
+                        let _ = ah.emit("repaste-error", "No text available to repaste.");
+                        return;
+                    }
+
+                    tokio::time::sleep(Duration::from_millis(REPASTE_LAST_PRE_PASTE_DELAY_MS))
+                        .await;
+
+                    let ah_clone = ah.clone();
+                    ah.run_on_main_thread(move || {
+                        let _ = utils::paste(text_to_paste, ah_clone);
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    error!("Failed to get latest history entries: {}", e);
+                    let _ = ah.emit("repaste-error", "Failed to retrieve history.");
+                }
+            }
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Repaste is instant, nothing to do on stop
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+
+    fn instant_fire_on_release(&self) -> bool {
+        true
+    }
+}
+
+// Undo Last Paste Action
+impl ShortcutAction for UndoLastPasteAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        debug!("UndoLastPasteAction::start called");
+
+        let ah = app.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let max_age =
+                Duration::from_secs(u64::from(get_settings(&ah).undo_last_paste_timeout_secs));
+
+            // Same pause as repaste, so the Backspaces don't combine with
+            // modifiers of the shortcut that is still being released.
+            tokio::time::sleep(Duration::from_millis(REPASTE_LAST_PRE_PASTE_DELAY_MS)).await;
+
+            let ah_clone = ah.clone();
+            ah.run_on_main_thread(move || {
+                match crate::clipboard::undo_last_paste(ah_clone.clone(), max_age) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        crate::overlay::show_undo_paste_overlay(&ah_clone, "Nothing to undo");
+                    }
+                    Err(err) => {
+                        error!("Failed to undo last paste: {}", err);
+                    }
+                }
+            })
+            .ok();
+        });
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Undo is instant, nothing to do on stop
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+
+    fn instant_fire_on_release(&self) -> bool {
+        true
+    }
+}
+
 // ============================================================================
 // Cycle Transcription Profile Action
 // ============================================================================
@@ -8549,6 +11992,121 @@ impl ShortcutAction for CycleProfileAction {
     }
 }
 
+impl ShortcutAction for PauseResumeRecordingAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        let recording_binding_id = {
+            let state = app.state::<ManagedSessionState>();
+            let state_guard =
+                session_manager::lock_session_state(&state, "PauseResumeRecordingAction::start");
+            match &*state_guard {
+                session_manager::SessionState::Recording { binding_id, .. } => binding_id.clone(),
+                _ => {
+                    debug!("PauseResumeRecordingAction: No active recording, ignoring");
+                    return;
+                }
+            }
+        };
+
+        let rm = app.state::<Arc<AudioRecordingManager>>();
+        let pause = !rm.is_recording_paused();
+        if !rm.set_recording_paused(pause) {
+            return;
+        }
+
+        if pause {
+            // No VAD verdicts arrive while paused; resuming starts a fresh tracker.
+            rm.clear_speech_activity_callback();
+            change_tray_icon(app, TrayIconState::Paused);
+            crate::overlay::show_paused_overlay(app);
+        } else {
+            crate::recording_auto_stop::start_silence_auto_stop(app, &recording_binding_id);
+            change_tray_icon(app, TrayIconState::Recording);
+            show_recording_overlay(app);
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Instant action: nothing to do on key release.
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+impl ShortcutAction for ToggleDictationLanguageAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Switching mid-recording would mix languages within one session.
+        {
+            let state = app.state::<ManagedSessionState>();
+            let state_guard =
+                session_manager::lock_session_state(&state, "ToggleDictationLanguageAction::start");
+            if !matches!(*state_guard, session_manager::SessionState::Idle) {
+                debug!("ToggleDictationLanguageAction: System busy, ignoring");
+                return;
+            }
+        }
+
+        match crate::language_toggle::toggle(app) {
+            Ok(language) => crate::overlay::show_language_switch_overlay(
+                app,
+                &crate::language_toggle::language_display_name(&language),
+            ),
+            Err(e) => warn!("Failed to toggle dictation language: {}", e),
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Instant action: nothing to do on key release.
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+impl ShortcutAction for BoostNextTranscriptionAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Pressing again while armed cancels the boost.
+        if crate::boost::status().armed {
+            crate::boost::disarm(app);
+            crate::overlay::show_boost_overlay(app, "Boost off");
+            return;
+        }
+        match crate::boost::arm(app, None) {
+            Ok(status) => {
+                if let Some(boost) = &status.boost {
+                    crate::overlay::show_boost_overlay(app, &crate::boost::overlay_label(boost));
+                }
+            }
+            Err(e) => warn!("Failed to arm boost: {}", e),
+        }
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Instant action: nothing to do on key release.
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
+impl ShortcutAction for TogglePrivacyModeAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        let status = crate::privacy_mode::toggle(app);
+        crate::overlay::show_privacy_mode_overlay(app, status.active);
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // Instant action: nothing to do on key release.
+    }
+
+    fn is_instant(&self) -> bool {
+        true
+    }
+}
+
 impl ShortcutAction for PreviewDeleteLastWordShortcutAction {
     fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
         let ah = app.clone();
@@ -8606,6 +12164,7 @@ struct RemoteRecordingRetryRequest {
     captured_profile_id: Option<String>,
     recording_settings: AppSettings,
     current_app: String,
+    window_context: RecordingWindowContext,
     pre_saved_file_name: Option<String>,
     failed_history_entry_id: i64,
 }
@@ -8663,137 +12222,6 @@ pub struct CommandConfirmPayload {
     pub auto_run_seconds: u32,
 }
 
-/// Configuration for the hybrid fuzzy matching algorithm
-#[derive(Debug, Clone)]
-pub struct FuzzyMatchConfig {
-    /// Whether to use Levenshtein distance for character-level matching
-    pub use_levenshtein: bool,
-    /// Per-word Levenshtein threshold (0.0-1.0, lower = more tolerant of typos)
-    pub levenshtein_threshold: f64,
-    /// Whether to use phonetic (Soundex) matching
-    pub use_phonetic: bool,
-    /// Phonetic match boost multiplier (0.0-1.0)
-    pub phonetic_boost: f64,
-    /// Word similarity threshold - minimum score for a word pair to be considered matching
-    pub word_similarity_threshold: f64,
-}
-
-impl Default for FuzzyMatchConfig {
-    fn default() -> Self {
-        Self {
-            use_levenshtein: true,
-            levenshtein_threshold: 0.3,
-            use_phonetic: true,
-            phonetic_boost: 0.5,
-            word_similarity_threshold: 0.7,
-        }
-    }
-}
-
-impl FuzzyMatchConfig {
-    /// Create config from AppSettings
-    pub fn from_settings(settings: &AppSettings) -> Self {
-        Self {
-            use_levenshtein: settings.voice_command_use_levenshtein,
-            levenshtein_threshold: settings.voice_command_levenshtein_threshold,
-            use_phonetic: settings.voice_command_use_phonetic,
-            phonetic_boost: settings.voice_command_phonetic_boost,
-            word_similarity_threshold: settings.voice_command_word_similarity_threshold,
-        }
-    }
-}
-
-/// Computes word-level similarity using hybrid algorithm:
-/// - Levenshtein distance for typo tolerance
-/// - Soundex phonetic matching for pronunciation similarity
-/// Returns a value between 0.0 and 1.0.
-fn compute_word_similarity(word_a: &str, word_b: &str, config: &FuzzyMatchConfig) -> f64 {
-    // Exact match
-    if word_a == word_b {
-        return 1.0;
-    }
-
-    let mut score: f64 = 0.0;
-
-    // Levenshtein (character-level edit distance)
-    if config.use_levenshtein {
-        let lev_score = normalized_levenshtein(word_a, word_b);
-        // Only accept if above threshold (1.0 - threshold gives minimum required similarity)
-        if lev_score >= (1.0 - config.levenshtein_threshold) {
-            score = score.max(lev_score);
-        }
-    }
-
-    // Phonetic matching (Soundex)
-    if config.use_phonetic && soundex(word_a, word_b) {
-        // Phonetic match - boost the score
-        let phonetic_score = config.word_similarity_threshold
-            + config.phonetic_boost * (1.0 - config.word_similarity_threshold);
-        score = score.max(phonetic_score.min(1.0));
-    }
-
-    score
-}
-
-/// Computes a similarity score between two strings using a hybrid word-matching approach.
-/// For each word in the transcription, finds the best matching word in the trigger phrase.
-/// Returns a value between 0.0 and 1.0.
-fn compute_similarity(a: &str, b: &str, config: &FuzzyMatchConfig) -> f64 {
-    let a_lower = a.to_lowercase();
-    let b_lower = b.to_lowercase();
-
-    // Exact match
-    if a_lower == b_lower {
-        return 1.0;
-    }
-
-    let a_words: Vec<&str> = a_lower.split_whitespace().collect();
-    let b_words: Vec<&str> = b_lower.split_whitespace().collect();
-
-    if a_words.is_empty() || b_words.is_empty() {
-        return 0.0;
-    }
-
-    // For each word in 'a', find the best matching word in 'b'
-    let mut total_score: f64 = 0.0;
-    let mut matched_count = 0;
-
-    for a_word in &a_words {
-        let mut best_match_score: f64 = 0.0;
-
-        for b_word in &b_words {
-            let word_score = compute_word_similarity(a_word, b_word, config);
-            if word_score >= config.word_similarity_threshold {
-                best_match_score = best_match_score.max(word_score);
-            }
-        }
-
-        if best_match_score >= config.word_similarity_threshold {
-            total_score += best_match_score;
-            matched_count += 1;
-        }
-    }
-
-    // Score is based on:
-    // 1. How many words from 'a' matched something in 'b' (coverage)
-    // 2. How well they matched (quality)
-    // 3. Length ratio to penalize very different lengths
-    let coverage = matched_count as f64 / a_words.len() as f64;
-    let quality = if matched_count > 0 {
-        total_score / matched_count as f64
-    } else {
-        0.0
-    };
-
-    // Length penalty - favor similar length phrases
-    let len_ratio =
-        (a_words.len().min(b_words.len()) as f64) / (a_words.len().max(b_words.len()) as f64);
-
-    // Final score combines coverage, quality, and length similarity
-    // Coverage is most important (70%), quality matters (20%), length is a tiebreaker (10%)
-    coverage * 0.7 + quality * coverage * 0.2 + len_ratio * 0.1
-}
-
 /// Format ExecutionPolicy for frontend display.
 fn format_execution_policy(policy: crate::settings::ExecutionPolicy) -> Option<String> {
     use crate::settings::ExecutionPolicy;
@@ -8805,41 +12233,6 @@ fn format_execution_policy(policy: crate::settings::ExecutionPolicy) -> Option<S
     }
 }
 
-/// Finds the best matching predefined command for the given transcription.
-/// Returns (command, similarity_score) if a match above threshold is found.
-pub fn find_matching_command(
-    transcription: &str,
-    commands: &[crate::settings::VoiceCommand],
-    default_threshold: f64,
-    config: &FuzzyMatchConfig,
-) -> Option<(crate::settings::VoiceCommand, f64)> {
-    let mut best_match: Option<(crate::settings::VoiceCommand, f64)> = None;
-
-    for cmd in commands.iter().filter(|c| c.enabled) {
-        let threshold = if cmd.similarity_threshold > 0.0 {
-            cmd.similarity_threshold
-        } else {
-            default_threshold
-        };
-
-        let score = compute_similarity(transcription, &cmd.trigger_phrase, config);
-
-        if score >= threshold {
-            match &best_match {
-                Some((_, best_score)) if score > *best_score => {
-                    best_match = Some((cmd.clone(), score));
-                }
-                None => {
-                    best_match = Some((cmd.clone(), score));
-                }
-                _ => {}
-            }
-        }
-    }
-
-    best_match
-}
-
 /// Generates a PowerShell command using LLM based on user's spoken request
 #[cfg(target_os = "windows")]
 async fn generate_command_with_llm_with_settings(
@@ -8874,10 +12267,12 @@ async fn generate_command_with_llm_with_settings(
         settings,
         None,
         &current_app,
+        &current_window_context(),
         spoken_text,
         spoken_text,
         "",
-    );
+    )
+    .with_clipboard_for(app, settings, &[&settings.voice_command_system_prompt]);
     let system_prompt =
         apply_llm_template_vars(&settings.voice_command_system_prompt, &template_context);
     let user_prompt = spoken_text.to_string();
@@ -9000,7 +12395,7 @@ impl ShortcutAction for VoiceCommandAction {
             let mut finish_guard =
                 FinishGuard::new(ah.clone(), binding_id.clone(), recording_operation_id);
 
-            let (transcription, _) = match get_transcription_or_cleanup(
+            let (transcription, _, _) = match get_transcription_or_cleanup(
                 &ah,
                 &binding_id,
                 None,
@@ -9221,6 +12616,34 @@ pub fn preview_clear_action(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Replaces the preview text with the user's edit and discards interim text.
+/// Refused while recording, since the running session would drop the audio
+/// it has not finalized yet.
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_set_text(app: AppHandle, text: String) -> Result<(), String> {
+    let state = crate::managers::preview_output_mode::get_state_payload();
+    if !state.active {
+        return Err("Output to Preview workflow is not active.".to_string());
+    }
+    if state.processing_llm {
+        return Err("LLM processing is already running.".to_string());
+    }
+
+    if state.recording
+        || state
+            .binding_id
+            .as_deref()
+            .is_some_and(|binding_id| is_recording_for_binding(&app, binding_id))
+    {
+        return Err("Stop recording before editing the preview text.".to_string());
+    }
+
+    replace_preview_text_for_output_mode(&app, &text);
+    crate::managers::preview_output_mode::set_error(&app, None);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn preview_insert_action(app: AppHandle) -> Result<(), String> {
@@ -9299,9 +12722,15 @@ pub async fn preview_llm_process_action(app: AppHandle) -> Result<(), String> {
             &settings,
             profile,
             &current_app,
+            &current_window_context(),
             &original_text,
             "",
             "",
+        )
+        .with_clipboard_for(
+            &app,
+            &settings,
+            &[post_process_prompt_template(&settings, profile)],
         );
 
         final_result = match maybe_post_process_transcription(
@@ -9480,6 +12909,10 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "transcribe".to_string(),
         Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        crate::settings::TRANSCRIBE_AND_SEARCH_BINDING_ID.to_string(),
+        Arc::new(TranscribeAndSearchAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "send_to_extension".to_string(),
         Arc::new(SendToExtensionAction) as Arc<dyn ShortcutAction>,
@@ -9504,6 +12937,14 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "repaste_last".to_string(),
         Arc::new(RepastLastAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "repaste_last_n".to_string(),
+        Arc::new(RepasteLastNAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "undo_last_paste".to_string(),
+        Arc::new(UndoLastPasteAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "test".to_string(),
         Arc::new(TestAction) as Arc<dyn ShortcutAction>,
@@ -9512,10 +12953,26 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "cycle_profile".to_string(),
         Arc::new(CycleProfileAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        "pause_resume".to_string(),
+        Arc::new(PauseResumeRecordingAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         crate::settings::PREVIEW_DELETE_LAST_WORD_BINDING_ID.to_string(),
         Arc::new(PreviewDeleteLastWordShortcutAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        crate::privacy_mode::PRIVACY_MODE_BINDING_ID.to_string(),
+        Arc::new(TogglePrivacyModeAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        crate::language_toggle::LANGUAGE_TOGGLE_BINDING_ID.to_string(),
+        Arc::new(ToggleDictationLanguageAction) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        crate::boost::BOOST_BINDING_ID.to_string(),
+        Arc::new(BoostNextTranscriptionAction) as Arc<dyn ShortcutAction>,
+    );
     #[cfg(target_os = "windows")]
     map.insert(
         "spawn_button".to_string(),
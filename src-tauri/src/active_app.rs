@@ -1,14 +1,69 @@
 /// Gets the frontmost/active window title for context variables.
-#[cfg(target_os = "windows")]
 pub fn get_frontmost_app_name() -> Option<String> {
+    capture_foreground_window().and_then(window_title)
+}
+
+/// Stable identifier of the frontmost app: the executable name on Windows and
+/// Linux (X11 only), the bundle identifier on macOS. Unlike the window title,
+/// it stays the same while the user switches documents or tabs.
+#[cfg(target_os = "windows")]
+pub fn get_frontmost_app_id() -> Option<String> {
+    let window = capture_foreground_window()?;
+    let hwnd = windows::Win32::Foundation::HWND(window.0 as *mut core::ffi::c_void);
+    let process_path = window_process_path(hwnd)?;
+    let file_name = process_path.rsplit(['\\', '/']).next()?;
+    Some(file_name.to_lowercase()).filter(|name| !name.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_frontmost_app_id() -> Option<String> {
+    let app = objc2_app_kit::NSWorkspace::sharedWorkspace().frontmostApplication()?;
+    Some(app.bundleIdentifier()?.to_string()).filter(|id| !id.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_frontmost_app_id() -> Option<String> {
+    let xprop = |args: &[&str]| -> Option<String> {
+        let output = std::process::Command::new("xprop")
+            .args(args)
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    // `_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007`
+    let active = xprop(&["-root", "_NET_ACTIVE_WINDOW"])?;
+    let window_id = last_xprop_field(&active)?;
+    // `_NET_WM_PID(CARDINAL) = 4242`
+    let pid = xprop(&["-id", window_id, "_NET_WM_PID"])?;
+    let pid: u32 = last_xprop_field(&pid)?.parse().ok()?;
+    let name = std::fs::read_to_string(format!("/proc/{pid}/comm")).ok()?;
+    Some(name.trim().to_string()).filter(|name| !name.is_empty())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+pub fn get_frontmost_app_id() -> Option<String> {
+    None
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn last_xprop_field(output: &str) -> Option<&str> {
+    output.split_whitespace().last()
+}
+
+/// Title bar text of `window`, or `None` when it has none.
+#[cfg(target_os = "windows")]
+pub fn window_title(window: ForegroundWindow) -> Option<String> {
     use std::ffi::OsString;
     use std::os::windows::ffi::OsStringExt;
-    use windows::Win32::UI::WindowsAndMessaging::{
-        GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW,
-    };
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::{GetWindowTextLengthW, GetWindowTextW};
 
     unsafe {
-        let hwnd = GetForegroundWindow();
+        let hwnd = HWND(window.0 as *mut core::ffi::c_void);
         let length = GetWindowTextLengthW(hwnd);
         if length <= 0 {
             return None;
@@ -34,6 +89,228 @@ pub fn get_frontmost_app_name() -> Option<String> {
 }
 
 #[cfg(not(target_os = "windows"))]
-pub fn get_frontmost_app_name() -> Option<String> {
+pub fn window_title(_window: ForegroundWindow) -> Option<String> {
+    None
+}
+
+/// Top-level window captured before AivoRelay shows its own UI, so focus can be
+/// handed back to the user's app afterwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForegroundWindow(isize);
+
+/// Browser executables whose address bar is read for `${selection_url}`.
+#[cfg(any(target_os = "windows", test))]
+const BROWSER_EXECUTABLES: &[&str] = &[
+    "chrome.exe",
+    "msedge.exe",
+    "firefox.exe",
+    "brave.exe",
+    "opera.exe",
+    "vivaldi.exe",
+    "arc.exe",
+];
+
+#[cfg(any(target_os = "windows", test))]
+fn is_browser_executable(path: &str) -> bool {
+    let file_name = path.rsplit(['\\', '/']).next().unwrap_or(path);
+    BROWSER_EXECUTABLES
+        .iter()
+        .any(|browser| file_name.eq_ignore_ascii_case(browser))
+}
+
+/// Reads the address bar of `window` via UI Automation when it belongs to a
+/// known browser. Can take a few hundred milliseconds; call off the hot path.
+#[cfg(target_os = "windows")]
+pub fn browser_url_for_window(window: ForegroundWindow) -> Option<String> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+    use windows::Win32::System::Variant::VARIANT;
+    use windows::Win32::UI::Accessibility::{
+        CUIAutomation, IUIAutomation, IUIAutomationValuePattern, TreeScope_Descendants,
+        UIA_ControlTypePropertyId, UIA_EditControlTypeId, UIA_ValuePatternId,
+    };
+
+    let hwnd = HWND(window.0 as *mut core::ffi::c_void);
+    let process_path = window_process_path(hwnd)?;
+    if !is_browser_executable(&process_path) {
+        return None;
+    }
+
+    unsafe {
+        // If already initialized on this thread, this does nothing.
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let automation: IUIAutomation =
+            CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER).ok()?;
+        let root = automation.ElementFromHandle(hwnd).ok()?;
+        // The address bar is the first edit control in every supported browser.
+        let condition = automation
+            .CreatePropertyCondition(
+                UIA_ControlTypePropertyId,
+                &VARIANT::from(UIA_EditControlTypeId.0),
+            )
+            .ok()?;
+        let address_bar = root.FindFirst(TreeScope_Descendants, &condition).ok()?;
+        let pattern: IUIAutomationValuePattern =
+            address_bar.GetCurrentPatternAs(UIA_ValuePatternId).ok()?;
+        let url = pattern.CurrentValue().ok()?.to_string();
+        let url = url.trim();
+        if url.is_empty() {
+            None
+        } else {
+            Some(url.to_string())
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn browser_url_for_window(_window: ForegroundWindow) -> Option<String> {
     None
 }
+
+#[cfg(target_os = "windows")]
+fn window_process_path(hwnd: windows::Win32::Foundation::HWND) -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+    unsafe {
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+        if process_id == 0 {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut length = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut length,
+        );
+        let _ = CloseHandle(process);
+        result.ok()?;
+        Some(String::from_utf16_lossy(&buffer[..length as usize]))
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn capture_foreground_window() -> Option<ForegroundWindow> {
+    use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_invalid() {
+        None
+    } else {
+        Some(ForegroundWindow(hwnd.0 as isize))
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn capture_foreground_window() -> Option<ForegroundWindow> {
+    None
+}
+
+/// Returns true when the current foreground window belongs to this process
+/// (main window, preview, region picker, ...).
+#[cfg(target_os = "windows")]
+fn foreground_window_is_own_process() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_invalid() {
+            return false;
+        }
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+        process_id == std::process::id()
+    }
+}
+
+/// Brings the captured window back to the foreground and polls until Windows
+/// reports it as active, or `timeout` elapses. Returns true when focus was verified.
+#[cfg(target_os = "windows")]
+pub fn restore_foreground_window(target: ForegroundWindow, timeout: std::time::Duration) -> bool {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::SetForegroundWindow;
+
+    let hwnd = HWND(target.0 as *mut core::ffi::c_void);
+    if !unsafe { SetForegroundWindow(hwnd) }.as_bool() {
+        log::debug!("SetForegroundWindow refused focus restore");
+    }
+
+    wait_for_foreground(timeout, || capture_foreground_window() == Some(target))
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn restore_foreground_window(_target: ForegroundWindow, _timeout: std::time::Duration) -> bool {
+    false
+}
+
+/// Waits until focus has left AivoRelay's own windows (e.g. after hiding the
+/// preview window). Returns true when a foreign window became active in time.
+#[cfg(target_os = "windows")]
+pub fn wait_for_foreign_foreground(timeout: std::time::Duration) -> bool {
+    wait_for_foreground(timeout, || !foreground_window_is_own_process())
+}
+
+/// Without a foreground-window API, fall back to the legacy fixed settle delay.
+#[cfg(not(target_os = "windows"))]
+pub fn wait_for_foreign_foreground(timeout: std::time::Duration) -> bool {
+    std::thread::sleep(timeout.min(std::time::Duration::from_millis(90)));
+    true
+}
+
+#[cfg(target_os = "windows")]
+fn wait_for_foreground(timeout: std::time::Duration, is_ready: impl Fn() -> bool) -> bool {
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+    let started = std::time::Instant::now();
+    loop {
+        if is_ready() {
+            return true;
+        }
+        if started.elapsed() >= timeout {
+            return false;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_browser_executable, last_xprop_field};
+
+    #[test]
+    fn recognizes_browser_executables_by_file_name() {
+        assert!(is_browser_executable(
+            r"C:\Program Files\Google\Chrome\Application\chrome.exe"
+        ));
+        assert!(is_browser_executable(
+            r"C:\Program Files\Mozilla Firefox\FIREFOX.EXE"
+        ));
+        assert!(!is_browser_executable(r"C:\Windows\notepad.exe"));
+        assert!(!is_browser_executable(r"C:\Tools\chrome.exe.bak"));
+    }
+
+    #[test]
+    fn reads_the_value_of_an_xprop_line() {
+        assert_eq!(
+            last_xprop_field("_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n"),
+            Some("0x3a00007")
+        );
+        assert_eq!(
+            last_xprop_field("_NET_WM_PID(CARDINAL) = 4242"),
+            Some("4242")
+        );
+        assert_eq!(last_xprop_field(""), None);
+    }
+}
@@ -0,0 +1,291 @@
+//! Accept/reject preview shown before an AI Replace result is pasted.
+//!
+//! The action waits on [`request_decision`] while the preview window pulls the
+//! pending payload and answers through `ai_replace_confirm_accept` /
+//! `ai_replace_confirm_reject`. Unanswered previews are rejected on timeout so
+//! the session cannot stay in Processing.
+
+use log::{debug, error};
+use serde::Serialize;
+use specta::Type;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tokio::sync::oneshot;
+
+pub const AI_REPLACE_CONFIRM_WINDOW_LABEL: &str = "ai_replace_confirm";
+
+/// Above this many token comparisons the diff falls back to a plain
+/// "everything removed, everything added" pair.
+const MAX_DIFF_CELLS: usize = 250_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AiReplaceDecision {
+    Accepted,
+    Rejected,
+    TimedOut,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffKind {
+    Equal,
+    Removed,
+    Added,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Type)]
+pub struct DiffSegment {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct AiReplaceConfirmPayload {
+    pub original: String,
+    pub proposed: String,
+    pub diff: Vec<DiffSegment>,
+    pub timeout_seconds: u32,
+}
+
+struct PendingConfirm {
+    payload: AiReplaceConfirmPayload,
+    sender: oneshot::Sender<AiReplaceDecision>,
+}
+
+static PENDING: Mutex<Option<PendingConfirm>> = Mutex::new(None);
+
+fn lock_pending() -> std::sync::MutexGuard<'static, Option<PendingConfirm>> {
+    PENDING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Shows the preview for `proposed` and waits for the user's answer, at most
+/// `timeout_seconds`. Focus is handed back to the previously active window
+/// before returning [`AiReplaceDecision::Accepted`], so a paste lands there.
+pub async fn request_decision(
+    app: &AppHandle,
+    original: &str,
+    proposed: &str,
+    timeout_seconds: u32,
+) -> AiReplaceDecision {
+    let (sender, receiver) = oneshot::channel();
+    let payload = AiReplaceConfirmPayload {
+        original: original.to_string(),
+        proposed: proposed.to_string(),
+        diff: word_diff(original, proposed),
+        timeout_seconds,
+    };
+    if let Some(previous) = lock_pending().replace(PendingConfirm { payload, sender }) {
+        let _ = previous.sender.send(AiReplaceDecision::Rejected);
+    }
+
+    let previous_foreground = crate::active_app::capture_foreground_window();
+    if let Err(err) = crate::overlay::show_ai_replace_confirm_window(app) {
+        error!("Failed to open AI Replace preview: {}", err);
+        lock_pending().take();
+        return AiReplaceDecision::Rejected;
+    }
+
+    let timeout = Duration::from_secs(timeout_seconds as u64);
+    let decision = match tokio::time::timeout(timeout, receiver).await {
+        Ok(Ok(decision)) => decision,
+        Ok(Err(_)) => AiReplaceDecision::Rejected,
+        Err(_) => {
+            debug!("AI Replace preview timed out after {}s", timeout_seconds);
+            lock_pending().take();
+            AiReplaceDecision::TimedOut
+        }
+    };
+
+    if let Some(window) = app.get_webview_window(AI_REPLACE_CONFIRM_WINDOW_LABEL) {
+        let _ = window.destroy();
+    }
+    if decision == AiReplaceDecision::Accepted {
+        return_focus(app, previous_foreground).await;
+    }
+    decision
+}
+
+/// Payload for the preview window, if a decision is pending.
+pub fn pending_payload() -> Option<AiReplaceConfirmPayload> {
+    lock_pending()
+        .as_ref()
+        .map(|pending| pending.payload.clone())
+}
+
+/// Answers the pending preview. Returns false when nothing was waiting.
+pub fn resolve(decision: AiReplaceDecision) -> bool {
+    match lock_pending().take() {
+        Some(pending) => pending.sender.send(decision).is_ok(),
+        None => false,
+    }
+}
+
+async fn return_focus(
+    app: &AppHandle,
+    previous_foreground: Option<crate::active_app::ForegroundWindow>,
+) {
+    let settings = crate::settings::get_settings(app);
+    let timeout = Duration::from_millis(settings.focus_restore_timeout_ms);
+    let restored = tokio::task::spawn_blocking(move || match previous_foreground {
+        Some(target) => crate::active_app::restore_foreground_window(target, timeout),
+        None => crate::active_app::wait_for_foreign_foreground(timeout),
+    })
+    .await
+    .unwrap_or(false);
+    if !restored {
+        debug!(
+            "Focus restore after AI Replace preview not confirmed within {:?}",
+            timeout
+        );
+    }
+
+    tokio::time::sleep(Duration::from_millis(settings.focus_restore_paste_delay_ms)).await;
+}
+
+/// Splits text into alternating runs of whitespace and non-whitespace, so the
+/// tokens concatenate back to the input.
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_whitespace = None;
+    for (index, ch) in text.char_indices() {
+        let is_whitespace = ch.is_whitespace();
+        if in_whitespace.is_some_and(|previous| previous != is_whitespace) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        in_whitespace = Some(is_whitespace);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push_segment(segments: &mut Vec<DiffSegment>, kind: DiffKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match segments.last_mut() {
+        Some(last) if last.kind == kind => last.text.push_str(text),
+        _ => segments.push(DiffSegment {
+            kind,
+            text: text.to_string(),
+        }),
+    }
+}
+
+/// Word-level diff from `original` to `proposed` (longest common subsequence
+/// over word and whitespace tokens). Adjacent segments of one kind are merged.
+pub fn word_diff(original: &str, proposed: &str) -> Vec<DiffSegment> {
+    let old = tokenize(original);
+    let new = tokenize(proposed);
+    let mut segments = Vec::new();
+
+    if (old.len() + 1).saturating_mul(new.len() + 1) > MAX_DIFF_CELLS {
+        push_segment(&mut segments, DiffKind::Removed, original);
+        push_segment(&mut segments, DiffKind::Added, proposed);
+        return segments;
+    }
+
+    // lcs[i][j] = length of the common subsequence of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lcs = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            push_segment(&mut segments, DiffKind::Equal, old[i]);
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            push_segment(&mut segments, DiffKind::Removed, old[i]);
+            i += 1;
+        } else {
+            push_segment(&mut segments, DiffKind::Added, new[j]);
+            j += 1;
+        }
+    }
+    for token in &old[i..] {
+        push_segment(&mut segments, DiffKind::Removed, token);
+    }
+    for token in &new[j..] {
+        push_segment(&mut segments, DiffKind::Added, token);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(kind: DiffKind, text: &str) -> DiffSegment {
+        DiffSegment {
+            kind,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn replaced_word_is_marked_removed_then_added() {
+        assert_eq!(
+            word_diff("the quick fox", "the slow fox"),
+            vec![
+                segment(DiffKind::Equal, "the "),
+                segment(DiffKind::Removed, "quick"),
+                segment(DiffKind::Added, "slow"),
+                segment(DiffKind::Equal, " fox"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sides_reassemble_from_segments() {
+        let original = "Hello  world,\nthis is a test.";
+        let proposed = "Hello world,\nthis was a small test!";
+        let diff = word_diff(original, proposed);
+
+        let side = |skip: DiffKind| {
+            diff.iter()
+                .filter(|segment| segment.kind != skip)
+                .map(|segment| segment.text.as_str())
+                .collect::<String>()
+        };
+        assert_eq!(side(DiffKind::Added), original);
+        assert_eq!(side(DiffKind::Removed), proposed);
+    }
+
+    #[test]
+    fn empty_selection_is_a_single_insertion() {
+        assert_eq!(
+            word_diff("", "new text"),
+            vec![segment(DiffKind::Added, "new text")]
+        );
+        assert!(word_diff("", "").is_empty());
+    }
+
+    #[test]
+    fn oversized_inputs_fall_back_to_whole_replacement() {
+        let original = "a ".repeat(400);
+        let proposed = "b ".repeat(400);
+        assert_eq!(
+            word_diff(&original, &proposed),
+            vec![
+                segment(DiffKind::Removed, &original),
+                segment(DiffKind::Added, &proposed),
+            ]
+        );
+    }
+}
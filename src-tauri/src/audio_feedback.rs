@@ -3,19 +3,45 @@ use crate::settings::{self, AppSettings};
 use cpal::traits::{DeviceTrait, HostTrait};
 use log::{debug, error, warn};
 use rodio::OutputStreamBuilder;
+use serde::Serialize;
+use specta::Type;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{mpsc, OnceLock};
 use std::thread;
 use std::time::Duration;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 
 pub enum SoundType {
     Start,
     Stop,
 }
 
+/// Silent cue emitted as `visual-feedback` when audio feedback is off.
+#[derive(Serialize, Clone, Copy, Debug, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum VisualFeedbackKind {
+    Start,
+    Stop,
+    Error,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct VisualFeedbackEvent {
+    pub kind: VisualFeedbackKind,
+}
+
+impl From<&SoundType> for VisualFeedbackKind {
+    fn from(sound_type: &SoundType) -> Self {
+        match sound_type {
+            SoundType::Start => VisualFeedbackKind::Start,
+            SoundType::Stop => VisualFeedbackKind::Stop,
+        }
+    }
+}
+
 /// Maximum time callers may wait for a feedback cue before continuing the
 /// recording pipeline without it.
 const BLOCKING_PLAY_TIMEOUT: Duration = Duration::from_secs(3);
@@ -24,6 +50,9 @@ const BLOCKING_PLAY_TIMEOUT: Duration = Duration::from_secs(3);
 /// long-lived feedback worker.
 const PLAYBACK_STALL_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// Bumped for every recording playback so a newer one stops the previous one.
+static RECORDING_PLAYBACK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 fn resolve_sound_path(
     app: &AppHandle,
     settings: &AppSettings,
@@ -96,7 +125,11 @@ pub fn init(app: &AppHandle) {
 
 pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
+    if crate::quiet_hours::mutes_feedback(&settings) {
+        return;
+    }
     if !settings.audio_feedback {
+        emit_visual_feedback_for(app, &settings, (&sound_type).into());
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
@@ -106,7 +139,11 @@ pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
 
 pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
+    if crate::quiet_hours::mutes_feedback(&settings) {
+        return;
+    }
     if !settings.audio_feedback {
+        emit_visual_feedback_for(app, &settings, (&sound_type).into());
         return;
     }
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
@@ -114,6 +151,28 @@ pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     }
 }
 
+/// Emits a visual-only cue for the overlay (and optionally blinks the tray)
+/// when audio feedback is disabled and visual feedback is enabled.
+pub fn emit_visual_feedback(app: &AppHandle, kind: VisualFeedbackKind) {
+    let settings = settings::get_settings(app);
+    if settings.audio_feedback {
+        return;
+    }
+    emit_visual_feedback_for(app, &settings, kind);
+}
+
+fn emit_visual_feedback_for(app: &AppHandle, settings: &AppSettings, kind: VisualFeedbackKind) {
+    if !settings.visual_feedback_enabled {
+        return;
+    }
+    if let Err(e) = app.emit("visual-feedback", VisualFeedbackEvent { kind }) {
+        warn!("Failed to emit visual feedback event: {}", e);
+    }
+    if settings.visual_feedback_tray_blink {
+        crate::tray::blink_tray_icon(app);
+    }
+}
+
 /// Plays the selected theme's start cue as a distinct "result ready" signal.
 /// This setting is independent from recording start/stop feedback so users can
 /// enable only the completion cue when that better suits their workflow.
@@ -134,6 +193,56 @@ pub fn play_test_sound(app: &AppHandle, sound_type: SoundType) {
     }
 }
 
+/// Plays a saved recording on the feedback output device. Recordings can be
+/// minutes long, so they get their own stream and thread instead of the
+/// feedback worker; starting another playback stops the previous one.
+pub fn play_recording(app: &AppHandle, path: PathBuf) -> Result<(), String> {
+    let settings = settings::get_settings(app);
+    let device = settings.selected_output_device.clone();
+    let generation = RECORDING_PLAYBACK_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    thread::Builder::new()
+        .name("recording-playback".into())
+        .spawn(move || {
+            let stream = match create_stream(device.as_deref()) {
+                Ok(stream) => stream,
+                Err(error) => {
+                    error!("Failed to open recording playback output stream: {}", error);
+                    return;
+                }
+            };
+            if let Err(error) = play_recording_on_stream(&stream, &path, generation) {
+                error!("Failed to play recording '{}': {}", path.display(), error);
+            }
+        })
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start recording playback: {}", e))
+}
+
+/// Stops the recording started by `play_recording`, if any.
+pub fn stop_recording_playback() {
+    RECORDING_PLAYBACK_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+fn play_recording_on_stream(
+    stream: &rodio::OutputStream,
+    path: &Path,
+    generation: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::open(path)?;
+    let sink = rodio::play(stream.mixer(), BufReader::new(file))?;
+
+    while !sink.empty() {
+        if RECORDING_PLAYBACK_GENERATION.load(Ordering::SeqCst) != generation {
+            sink.stop();
+            break;
+        }
+        thread::sleep(Duration::from_millis(25));
+    }
+
+    Ok(())
+}
+
 fn send_play(settings: &AppSettings, path: PathBuf, done: Option<mpsc::Sender<()>>) {
     let _ = player().send(Request::Play {
         path,
@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+/// Samples per level window (~100 ms at 16 kHz).
+const LEVEL_WINDOW_SAMPLES: usize = 1_600;
+/// Peaks at or above this are reported as clipping.
+const CLIPPING_THRESHOLD: f32 = 0.99;
+
+/// RMS and peak of one ~100 ms window of recorded audio.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct InputLevel {
+    pub rms: f32,
+    pub peak: f32,
+    pub clipping: bool,
+}
+
+/// Folds recorded 16 kHz frames into fixed windows and reports one level per window.
+#[derive(Debug, Default)]
+pub struct InputLevelMeter {
+    sum_squares: f64,
+    peak: f32,
+    samples: usize,
+}
+
+impl InputLevelMeter {
+    /// Returns the level of the last window completed by `frame`, if any.
+    pub fn feed(&mut self, frame: &[f32]) -> Option<InputLevel> {
+        let mut level = None;
+        for &sample in frame {
+            self.sum_squares += (sample as f64) * (sample as f64);
+            self.peak = self.peak.max(sample.abs());
+            self.samples += 1;
+            if self.samples == LEVEL_WINDOW_SAMPLES {
+                level = Some(self.finish_window());
+            }
+        }
+        level
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn finish_window(&mut self) -> InputLevel {
+        let rms = (self.sum_squares / self.samples as f64).sqrt() as f32;
+        let peak = self.peak.min(1.0);
+        self.reset();
+        InputLevel {
+            rms,
+            peak,
+            clipping: peak >= CLIPPING_THRESHOLD,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_one_level_per_window() {
+        let mut meter = InputLevelMeter::default();
+        assert_eq!(meter.feed(&[0.5; 480]), None);
+        assert_eq!(meter.feed(&[0.5; 480]), None);
+        assert_eq!(meter.feed(&[0.5; 480]), None);
+
+        let level = meter.feed(&[0.5; 480]).expect("window completed");
+        assert!((level.rms - 0.5).abs() < 1e-6);
+        assert_eq!(level.peak, 0.5);
+        assert!(!level.clipping);
+    }
+
+    #[test]
+    fn flags_clipping_and_silence() {
+        let mut meter = InputLevelMeter::default();
+        let mut frame = vec![0.0; LEVEL_WINDOW_SAMPLES];
+        frame[10] = -1.0;
+        assert!(meter.feed(&frame).unwrap().clipping);
+
+        let silent = meter.feed(&[0.0; LEVEL_WINDOW_SAMPLES]).unwrap();
+        assert_eq!(silent.rms, 0.0);
+        assert_eq!(silent.peak, 0.0);
+        assert!(!silent.clipping);
+    }
+}
@@ -1,16 +1,25 @@
 // Re-export all audio components
 mod device;
+mod level_meter;
 mod noise_suppression;
+mod preprocessing;
+mod probe;
 mod recorder;
 mod resampler;
 mod utils;
 mod visualizer;
 
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
+pub use level_meter::{InputLevel, InputLevelMeter};
 pub use noise_suppression::NoiseSuppressor;
+pub use preprocessing::{
+    preprocess_for_transcription, MAX_HIGH_PASS_CUTOFF_HZ, MIN_HIGH_PASS_CUTOFF_HZ,
+};
+pub use probe::{probe_input_devices, InputDeviceProbe};
 pub use recorder::{
-    is_microphone_access_denied, is_no_input_device_error, AudioCaptureSource, AudioRecorder,
-    StreamFrameCallback,
+    classify_microphone_open_error, is_device_lost_error, is_microphone_access_denied,
+    is_no_input_device_error, AudioCaptureSource, AudioRecorder, FrameTapCallback, InputFormat,
+    MicrophoneOpenFailure, SpeechActivityCallback, StreamErrorCallback, StreamFrameCallback,
 };
 pub use resampler::FrameResampler;
 pub use utils::{encode_wav_bytes, read_wav_samples, save_wav_file, verify_wav_file};
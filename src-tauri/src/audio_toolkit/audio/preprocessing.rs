@@ -0,0 +1,254 @@
+use rustfft::{num_complex::Complex32, FftPlanner};
+
+/// Lowest and highest accepted high-pass cutoffs in Hz.
+pub const MIN_HIGH_PASS_CUTOFF_HZ: u32 = 20;
+pub const MAX_HIGH_PASS_CUTOFF_HZ: u32 = 300;
+
+const GATE_FRAME_SIZE: usize = 512;
+const GATE_HOP_SIZE: usize = GATE_FRAME_SIZE / 2;
+/// Share of the quietest frames averaged into the noise profile.
+const GATE_NOISE_FRAME_FRACTION: f32 = 0.1;
+/// A bin passes when its magnitude exceeds the noise profile by this factor.
+const GATE_THRESHOLD: f32 = 2.0;
+/// Gain applied to gated bins (about -20 dB); a hard zero sounds watery.
+const GATE_FLOOR_GAIN: f32 = 0.1;
+
+/// Second-order Butterworth high-pass section (RBJ cookbook coefficients).
+struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl Biquad {
+    fn high_pass(sample_rate: u32, cutoff_hz: f32) -> Self {
+        let omega = 2.0 * std::f32::consts::PI * cutoff_hz / sample_rate as f32;
+        let alpha = omega.sin() / (2.0 * std::f32::consts::FRAC_1_SQRT_2);
+        let cos = omega.cos();
+        let a0 = 1.0 + alpha;
+
+        Self {
+            b0: (1.0 + cos) / 2.0 / a0,
+            b1: -(1.0 + cos) / a0,
+            b2: (1.0 + cos) / 2.0 / a0,
+            a1: -2.0 * cos / a0,
+            a2: (1.0 - alpha) / a0,
+            x1: 0.0,
+            x2: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        }
+    }
+
+    fn process(&mut self, x: f32) -> f32 {
+        let y = self.b0 * x + self.b1 * self.x1 + self.b2 * self.x2
+            - self.a1 * self.y1
+            - self.a2 * self.y2;
+        self.x2 = self.x1;
+        self.x1 = x;
+        self.y2 = self.y1;
+        self.y1 = y;
+        y
+    }
+}
+
+/// Fourth-order high-pass (two cascaded Butterworth sections, 24 dB/octave)
+/// that removes fan rumble and mains hum below the speech band.
+pub struct HighPassFilter {
+    stages: [Biquad; 2],
+}
+
+impl HighPassFilter {
+    pub fn new(sample_rate: u32, cutoff_hz: u32) -> Self {
+        let cutoff = cutoff_hz.clamp(MIN_HIGH_PASS_CUTOFF_HZ, MAX_HIGH_PASS_CUTOFF_HZ) as f32;
+        Self {
+            stages: [
+                Biquad::high_pass(sample_rate, cutoff),
+                Biquad::high_pass(sample_rate, cutoff),
+            ],
+        }
+    }
+
+    pub fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            *sample = self
+                .stages
+                .iter_mut()
+                .fold(*sample, |value, stage| stage.process(value));
+        }
+    }
+}
+
+/// Attenuates frequency bins that stay near the recording's noise floor.
+///
+/// The noise profile is the average spectrum of the quietest frames, so
+/// steady sounds (fans, hiss) are gated while speech passes. Buffers shorter
+/// than one analysis frame are returned unchanged.
+pub fn spectral_noise_gate(samples: &[f32]) -> Vec<f32> {
+    if samples.len() < GATE_FRAME_SIZE {
+        return samples.to_vec();
+    }
+
+    let mut planner = FftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(GATE_FRAME_SIZE);
+    let ifft = planner.plan_fft_inverse(GATE_FRAME_SIZE);
+
+    // Periodic sqrt-Hann on analysis and synthesis sums to one at 50% overlap.
+    let window: Vec<f32> = (0..GATE_FRAME_SIZE)
+        .map(|i| {
+            let hann = 0.5
+                * (1.0 - (2.0 * std::f32::consts::PI * i as f32 / GATE_FRAME_SIZE as f32).cos());
+            hann.sqrt()
+        })
+        .collect();
+
+    // Pad so every input sample is covered by two overlapping frames.
+    let mut padded = vec![0.0; GATE_HOP_SIZE];
+    padded.extend_from_slice(samples);
+    let frame_count = padded.len().div_ceil(GATE_HOP_SIZE);
+    padded.resize((frame_count + 1) * GATE_HOP_SIZE, 0.0);
+
+    let spectra: Vec<Vec<Complex32>> = (0..frame_count)
+        .map(|frame| {
+            let start = frame * GATE_HOP_SIZE;
+            let mut buffer: Vec<Complex32> = padded[start..start + GATE_FRAME_SIZE]
+                .iter()
+                .zip(&window)
+                .map(|(sample, w)| Complex32::new(sample * w, 0.0))
+                .collect();
+            fft.process(&mut buffer);
+            buffer
+        })
+        .collect();
+
+    let noise_profile = estimate_noise_profile(&spectra);
+
+    let mut output = vec![0.0; padded.len()];
+    let scale = 1.0 / GATE_FRAME_SIZE as f32;
+    for (frame, spectrum) in spectra.into_iter().enumerate() {
+        let mut buffer: Vec<Complex32> = spectrum
+            .into_iter()
+            .zip(&noise_profile)
+            .map(|(bin, noise)| {
+                if bin.norm() >= noise * GATE_THRESHOLD {
+                    bin
+                } else {
+                    bin * GATE_FLOOR_GAIN
+                }
+            })
+            .collect();
+        ifft.process(&mut buffer);
+
+        let start = frame * GATE_HOP_SIZE;
+        for (i, bin) in buffer.iter().enumerate() {
+            output[start + i] += bin.re * scale * window[i];
+        }
+    }
+
+    output[GATE_HOP_SIZE..GATE_HOP_SIZE + samples.len()].to_vec()
+}
+
+fn estimate_noise_profile(spectra: &[Vec<Complex32>]) -> Vec<f32> {
+    let mut by_energy: Vec<(f32, &Vec<Complex32>)> = spectra
+        .iter()
+        .map(|spectrum| (spectrum.iter().map(|bin| bin.norm_sqr()).sum(), spectrum))
+        .collect();
+    by_energy.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let quiet_count = ((spectra.len() as f32 * GATE_NOISE_FRAME_FRACTION) as usize).max(1);
+    let mut profile = vec![0.0; GATE_FRAME_SIZE];
+    for (_, spectrum) in by_energy.iter().take(quiet_count) {
+        for (acc, bin) in profile.iter_mut().zip(spectrum.iter()) {
+            *acc += bin.norm();
+        }
+    }
+    for value in &mut profile {
+        *value /= quiet_count as f32;
+    }
+    profile
+}
+
+/// High-pass filter followed by the spectral noise gate, for buffered
+/// recordings right before transcription.
+pub fn preprocess_for_transcription(
+    samples: &[f32],
+    sample_rate: u32,
+    high_pass_cutoff_hz: u32,
+) -> Vec<f32> {
+    let mut filtered = samples.to_vec();
+    HighPassFilter::new(sample_rate, high_pass_cutoff_hz).process(&mut filtered);
+    spectral_noise_gate(&filtered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 16_000;
+
+    fn sine(frequency: f32, seconds: f32, amplitude: f32) -> Vec<f32> {
+        let len = (SAMPLE_RATE as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| {
+                amplitude
+                    * (2.0 * std::f32::consts::PI * frequency * i as f32 / SAMPLE_RATE as f32).sin()
+            })
+            .collect()
+    }
+
+    /// RMS after the first 0.25 s, so the filter's settling time is ignored.
+    fn settled_rms(samples: &[f32]) -> f32 {
+        let tail = &samples[SAMPLE_RATE as usize / 4..];
+        (tail.iter().map(|s| s * s).sum::<f32>() / tail.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn high_pass_attenuates_hum_and_keeps_speech_band() {
+        let mut hum = sine(50.0, 1.0, 0.5);
+        let mut tone = sine(1_000.0, 1.0, 0.5);
+        let hum_rms = settled_rms(&hum);
+        let tone_rms = settled_rms(&tone);
+
+        HighPassFilter::new(SAMPLE_RATE, 80).process(&mut hum);
+        HighPassFilter::new(SAMPLE_RATE, 80).process(&mut tone);
+
+        assert!(settled_rms(&hum) < hum_rms * 0.25);
+        assert!(settled_rms(&tone) > tone_rms * 0.95);
+    }
+
+    #[test]
+    fn noise_gate_reduces_steady_noise_and_keeps_bursts() {
+        let mut seed = 0x1234_5678u32;
+        let mut noise = || {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as f32 / (1u32 << 24) as f32 * 0.02 - 0.01
+        };
+        let tone = sine(1_000.0, 2.0, 0.3);
+        let burst = tone.len() / 4..tone.len() * 3 / 4;
+        let input: Vec<f32> = tone
+            .iter()
+            .enumerate()
+            .map(|(i, s)| noise() + if burst.contains(&i) { *s } else { 0.0 })
+            .collect();
+
+        let output = spectral_noise_gate(&input);
+        assert_eq!(output.len(), input.len());
+
+        let rms = |s: &[f32]| (s.iter().map(|x| x * x).sum::<f32>() / s.len() as f32).sqrt();
+        let quiet = 0..tone.len() / 8;
+        assert!(rms(&output[quiet.clone()]) < rms(&input[quiet]) * 0.5);
+        assert!(rms(&output[burst.clone()]) > rms(&input[burst]) * 0.9);
+    }
+
+    #[test]
+    fn short_buffers_pass_through() {
+        let samples = vec![0.1; GATE_FRAME_SIZE - 1];
+        assert_eq!(spectral_noise_gate(&samples), samples);
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use cpal::{
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    SampleFormat,
+};
+use serde::Serialize;
+
+use crate::audio_toolkit::{
+    audio::{classify_microphone_open_error, MicrophoneOpenFailure},
+    constants,
+};
+
+/// Sample rates reported as supported when a device's config ranges cover them.
+const PROBE_SAMPLE_RATES: [u32; 7] = [8_000, 16_000, 22_050, 32_000, 44_100, 48_000, 96_000];
+
+/// Result of opening one input device for a short test capture.
+#[derive(Clone, Debug, Serialize)]
+pub struct InputDeviceProbe {
+    pub name: String,
+    pub is_default: bool,
+    pub open_ok: bool,
+    /// At least one non-silent sample arrived during the capture.
+    pub got_audio: bool,
+    pub error: Option<String>,
+    pub sample_rates: Vec<u32>,
+    pub channels: Vec<u16>,
+    /// Rate the test stream was opened at (16 kHz when the device offers it).
+    pub opened_sample_rate: Option<u32>,
+    /// Another app holds the device (WASAPI exclusive mode on Windows).
+    pub exclusive_mode_conflict: bool,
+    pub timed_out: bool,
+}
+
+impl InputDeviceProbe {
+    fn new(name: String, is_default: bool) -> Self {
+        Self {
+            name,
+            is_default,
+            open_ok: false,
+            got_audio: false,
+            error: None,
+            sample_rates: Vec::new(),
+            channels: Vec::new(),
+            opened_sample_rate: None,
+            exclusive_mode_conflict: false,
+            timed_out: false,
+        }
+    }
+
+    fn fail(mut self, error: String) -> Self {
+        self.exclusive_mode_conflict = cfg!(target_os = "windows")
+            && classify_microphone_open_error(&error) == MicrophoneOpenFailure::DeviceInUse;
+        self.error = Some(error);
+        self
+    }
+}
+
+/// Opens every input device of the cpal host for `capture` and reports what
+/// happened. Each device runs on its own thread and is abandoned after
+/// `timeout`, so a wedged driver cannot stall the remaining devices.
+pub fn probe_input_devices(
+    capture: Duration,
+    timeout: Duration,
+) -> Result<Vec<InputDeviceProbe>, Box<dyn std::error::Error>> {
+    let devices = super::list_input_devices()?;
+    let mut probes = Vec::with_capacity(devices.len());
+
+    for (index, info) in devices.into_iter().enumerate() {
+        let (name, is_default) = (info.name, info.is_default);
+        let (result_tx, result_rx) = mpsc::channel();
+        let thread_name = name.clone();
+        let spawned = thread::Builder::new()
+            .name("audio-device-probe".to_string())
+            .spawn(move || {
+                let _ = result_tx.send(probe_device(index, thread_name, is_default, capture));
+            });
+
+        let probe = match spawned {
+            Ok(_) => match result_rx.recv_timeout(timeout) {
+                Ok(probe) => probe,
+                Err(_) => {
+                    let mut probe = InputDeviceProbe::new(name, is_default);
+                    probe.timed_out = true;
+                    probe.fail(format!("Device did not respond within {:?}", timeout))
+                }
+            },
+            Err(err) => InputDeviceProbe::new(name, is_default)
+                .fail(format!("Failed to start probe thread: {}", err)),
+        };
+        probes.push(probe);
+    }
+
+    Ok(probes)
+}
+
+/// Runs on the probe thread. The device is looked up again by index because
+/// cpal devices are not `Send` on every host.
+fn probe_device(
+    index: usize,
+    name: String,
+    is_default: bool,
+    capture: Duration,
+) -> InputDeviceProbe {
+    let mut probe = InputDeviceProbe::new(name, is_default);
+    let host = crate::audio_toolkit::get_cpal_host();
+    let device = match host.input_devices().map(|mut devices| devices.nth(index)) {
+        Ok(Some(device)) => device,
+        Ok(None) => return probe.fail("Device disappeared during the probe".to_string()),
+        Err(err) => return probe.fail(err.to_string()),
+    };
+
+    let ranges: Vec<cpal::SupportedStreamConfigRange> = match device.supported_input_configs() {
+        Ok(configs) => configs.collect(),
+        Err(err) => return probe.fail(format!("Could not read supported formats: {}", err)),
+    };
+    probe.sample_rates = PROBE_SAMPLE_RATES
+        .into_iter()
+        .filter(|rate| {
+            ranges.iter().any(|range| {
+                range.min_sample_rate().0 <= *rate && range.max_sample_rate().0 >= *rate
+            })
+        })
+        .collect();
+    probe.channels = ranges.iter().map(|range| range.channels()).collect();
+    probe.channels.sort_unstable();
+    probe.channels.dedup();
+
+    let target_rate = cpal::SampleRate(constants::WHISPER_SAMPLE_RATE);
+    let config = match ranges.iter().find(|range| {
+        range.min_sample_rate() <= target_rate && range.max_sample_rate() >= target_rate
+    }) {
+        Some(range) => range.with_sample_rate(target_rate),
+        None => match device.default_input_config() {
+            Ok(config) => config,
+            Err(err) => return probe.fail(format!("No usable input format: {}", err)),
+        },
+    };
+    probe.opened_sample_rate = Some(config.sample_rate().0);
+
+    let sample_format = config.sample_format();
+    let got_audio = Arc::new(AtomicBool::new(false));
+    let got_audio_cb = Arc::clone(&got_audio);
+    let stream = device.build_input_stream_raw(
+        &config.config(),
+        sample_format,
+        move |data: &cpal::Data, _: &cpal::InputCallbackInfo| {
+            // Unsigned formats are centred on the midpoint rather than zero.
+            let silent = match sample_format {
+                SampleFormat::U8 => 0x80,
+                _ => 0,
+            };
+            if data.bytes().iter().any(|&byte| byte != silent) {
+                got_audio_cb.store(true, Ordering::Relaxed);
+            }
+        },
+        |err| log::warn!("Audio probe stream error: {}", err),
+        None,
+    );
+    let stream = match stream {
+        Ok(stream) => stream,
+        Err(err) => return probe.fail(err.to_string()),
+    };
+    if let Err(err) = stream.play() {
+        return probe.fail(err.to_string());
+    }
+
+    thread::sleep(capture);
+    drop(stream);
+    probe.open_ok = true;
+    probe.got_audio = got_audio.load(Ordering::Relaxed);
+    probe
+}
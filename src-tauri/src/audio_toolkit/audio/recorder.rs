@@ -28,6 +28,8 @@ enum Cmd {
         reply_tx: mpsc::Sender<Vec<f32>>,
     },
     Stop(mpsc::Sender<Vec<f32>>),
+    /// While paused, captured frames are dropped instead of recorded or streamed.
+    SetPaused(bool),
     Shutdown,
 }
 
@@ -43,6 +45,31 @@ pub enum AudioCaptureSource {
 }
 
 pub type StreamFrameCallback = Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>;
+/// Receives the VAD verdict (`true` = speech) for every recorded frame.
+pub type SpeechActivityCallback = Arc<dyn Fn(bool) + Send + Sync + 'static>;
+/// Sees every recorded frame without touching the stream frame callback.
+pub type FrameTapCallback = Arc<dyn Fn(&[f32]) + Send + Sync + 'static>;
+/// Receives errors reported by the capture stream, on the audio host's thread.
+pub type StreamErrorCallback = Arc<dyn Fn(&cpal::StreamError) + Send + Sync + 'static>;
+
+/// Format negotiated with the capture device for the open stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputFormat {
+    pub source: AudioCaptureSource,
+    pub device_name: String,
+    /// Rate the device delivers samples at
+    pub device_sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: String,
+    /// Rate of recorded samples and stream frames (always 16 kHz)
+    pub output_sample_rate: u32,
+}
+
+impl InputFormat {
+    pub fn is_resampling(&self) -> bool {
+        self.device_sample_rate != self.output_sample_rate
+    }
+}
 
 pub struct AudioRecorder {
     device: Option<Device>,
@@ -51,9 +78,13 @@ pub struct AudioRecorder {
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     stream_frame_cb: Arc<Mutex<Option<StreamFrameCallback>>>,
+    speech_activity_cb: Arc<Mutex<Option<SpeechActivityCallback>>>,
+    frame_tap_cb: Arc<Mutex<Option<FrameTapCallback>>>,
+    stream_error_cb: Arc<Mutex<Option<StreamErrorCallback>>>,
     microphone_input_gain: Arc<Mutex<f32>>,
     microphone_noise_cancellation_enabled: Arc<AtomicBool>,
     config_cache: Arc<Mutex<Option<(AudioCaptureSource, String, cpal::SupportedStreamConfig)>>>,
+    active_format: Arc<Mutex<Option<InputFormat>>>,
 }
 
 impl AudioRecorder {
@@ -65,9 +96,13 @@ impl AudioRecorder {
             vad: None,
             level_cb: None,
             stream_frame_cb: Arc::new(Mutex::new(None)),
+            speech_activity_cb: Arc::new(Mutex::new(None)),
+            frame_tap_cb: Arc::new(Mutex::new(None)),
+            stream_error_cb: Arc::new(Mutex::new(None)),
             microphone_input_gain: Arc::new(Mutex::new(1.0)),
             microphone_noise_cancellation_enabled: Arc::new(AtomicBool::new(false)),
             config_cache: Arc::new(Mutex::new(None)),
+            active_format: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -100,6 +135,31 @@ impl AudioRecorder {
         }
     }
 
+    /// Only fires while a VAD is attached and the recorder is capturing.
+    pub fn set_speech_activity_callback(&self, callback: Option<SpeechActivityCallback>) {
+        if let Ok(mut guard) = self.speech_activity_cb.lock() {
+            *guard = callback;
+        }
+    }
+
+    pub fn set_frame_tap_callback(&self, callback: Option<FrameTapCallback>) {
+        if let Ok(mut guard) = self.frame_tap_cb.lock() {
+            *guard = callback;
+        }
+    }
+
+    /// Kept across reopen, like the other callbacks.
+    pub fn set_stream_error_callback(&self, callback: Option<StreamErrorCallback>) {
+        if let Ok(mut guard) = self.stream_error_cb.lock() {
+            *guard = callback;
+        }
+    }
+
+    /// Format of the open stream; `None` while the recorder is closed.
+    pub fn active_format(&self) -> Option<InputFormat> {
+        self.active_format.lock().unwrap().clone()
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         self.open_with_source(device, AudioCaptureSource::Microphone)
     }
@@ -136,16 +196,22 @@ impl AudioRecorder {
         let vad = self.vad.clone();
         let level_cb = self.level_cb.clone();
         let stream_frame_cb = Arc::clone(&self.stream_frame_cb);
+        let speech_activity_cb = Arc::clone(&self.speech_activity_cb);
+        let frame_tap_cb = Arc::clone(&self.frame_tap_cb);
+        let stream_error_cb = Arc::clone(&self.stream_error_cb);
         let microphone_input_gain = Arc::clone(&self.microphone_input_gain);
         let microphone_noise_cancellation_enabled =
             Arc::clone(&self.microphone_noise_cancellation_enabled);
         let config_cache = Arc::clone(&self.config_cache);
+        let active_format = Arc::clone(&self.active_format);
 
         let worker = std::thread::spawn(move || {
             let stop_flag = Arc::new(AtomicBool::new(false));
             let stop_flag_for_stream = Arc::clone(&stop_flag);
+            let device_lost = Arc::new(AtomicBool::new(false));
+            let device_lost_for_stream = Arc::clone(&device_lost);
 
-            let init_result = (|| -> Result<(cpal::Stream, u32), String> {
+            let init_result = (|| -> Result<(cpal::Stream, InputFormat), String> {
                 let config_started = Instant::now();
                 let device_name = thread_device.name().unwrap_or_default();
                 let cached_config = config_cache
@@ -177,6 +243,21 @@ impl AudioRecorder {
                     channels,
                     config.sample_format()
                 );
+                if sample_rate != constants::WHISPER_SAMPLE_RATE {
+                    log::info!(
+                        "Capture device runs at {} Hz; resampling to {} Hz",
+                        sample_rate,
+                        constants::WHISPER_SAMPLE_RATE
+                    );
+                }
+                let format = InputFormat {
+                    source,
+                    device_name: device_name.clone(),
+                    device_sample_rate: sample_rate,
+                    channels: config.channels(),
+                    sample_format: format!("{:?}", config.sample_format()),
+                    output_sample_rate: constants::WHISPER_SAMPLE_RATE,
+                };
 
                 let build_started = Instant::now();
                 let stream = match config.sample_format() {
@@ -186,6 +267,8 @@ impl AudioRecorder {
                         sample_tx,
                         channels,
                         stop_flag_for_stream,
+                        device_lost_for_stream,
+                        stream_error_cb,
                     )
                     .map_err(|e| format!("Failed to build audio stream: {}", e))?,
                     cpal::SampleFormat::I8 => AudioRecorder::build_stream::<i8>(
@@ -194,6 +277,8 @@ impl AudioRecorder {
                         sample_tx,
                         channels,
                         stop_flag_for_stream,
+                        device_lost_for_stream,
+                        stream_error_cb,
                     )
                     .map_err(|e| format!("Failed to build audio stream: {}", e))?,
                     cpal::SampleFormat::I16 => AudioRecorder::build_stream::<i16>(
@@ -202,6 +287,8 @@ impl AudioRecorder {
                         sample_tx,
                         channels,
                         stop_flag_for_stream,
+                        device_lost_for_stream,
+                        stream_error_cb,
                     )
                     .map_err(|e| format!("Failed to build audio stream: {}", e))?,
                     cpal::SampleFormat::I32 => AudioRecorder::build_stream::<i32>(
@@ -210,6 +297,8 @@ impl AudioRecorder {
                         sample_tx,
                         channels,
                         stop_flag_for_stream,
+                        device_lost_for_stream,
+                        stream_error_cb,
                     )
                     .map_err(|e| format!("Failed to build audio stream: {}", e))?,
                     cpal::SampleFormat::F32 => AudioRecorder::build_stream::<f32>(
@@ -218,6 +307,8 @@ impl AudioRecorder {
                         sample_tx,
                         channels,
                         stop_flag_for_stream,
+                        device_lost_for_stream,
+                        stream_error_cb,
                     )
                     .map_err(|e| format!("Failed to build audio stream: {}", e))?,
                     other => return Err(format!("Unsupported sample format: {:?}", other)),
@@ -241,11 +332,13 @@ impl AudioRecorder {
                     *config_cache.lock().unwrap() = Some((source, device_name, config));
                 }
 
-                Ok((stream, sample_rate))
+                Ok((stream, format))
             })();
 
             match init_result {
-                Ok((stream, sample_rate)) => {
+                Ok((stream, format)) => {
+                    let sample_rate = format.device_sample_rate;
+                    *active_format.lock().unwrap() = Some(format);
                     let _ = init_tx.send(Ok(()));
                     run_consumer(
                         sample_rate,
@@ -254,12 +347,16 @@ impl AudioRecorder {
                         cmd_rx,
                         level_cb,
                         stream_frame_cb,
+                        speech_activity_cb,
+                        frame_tap_cb,
                         source,
                         microphone_input_gain,
                         microphone_noise_cancellation_enabled,
                         stop_flag,
+                        device_lost,
                     );
                     drop(stream);
+                    *active_format.lock().unwrap() = None;
                 }
                 Err(error_message) => {
                     *config_cache.lock().unwrap() = None;
@@ -302,6 +399,13 @@ impl AudioRecorder {
         Ok(())
     }
 
+    pub fn set_paused(&self, paused: bool) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(tx) = &self.cmd_tx {
+            tx.send(Cmd::SetPaused(paused))?;
+        }
+        Ok(())
+    }
+
     pub fn stop(&self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
         let (resp_tx, resp_rx) = mpsc::channel();
         if let Some(tx) = &self.cmd_tx {
@@ -360,6 +464,8 @@ impl AudioRecorder {
         sample_tx: mpsc::Sender<AudioChunk>,
         channels: usize,
         stop_flag: Arc<AtomicBool>,
+        device_lost: Arc<AtomicBool>,
+        stream_error_cb: Arc<Mutex<Option<StreamErrorCallback>>>,
     ) -> Result<cpal::Stream, cpal::BuildStreamError>
     where
         T: Sample + SizedSample + Send + 'static,
@@ -404,12 +510,18 @@ impl AudioRecorder {
             }
         };
 
-        device.build_input_stream(
-            &config.clone().into(),
-            stream_cb,
-            |err| log::error!("Stream error: {}", err),
-            None,
-        )
+        let error_cb = move |err: cpal::StreamError| {
+            log::error!("Stream error: {}", err);
+            if is_device_lost_error(&err) {
+                device_lost.store(true, Ordering::Relaxed);
+            }
+            let callback = stream_error_cb.lock().ok().and_then(|guard| guard.clone());
+            if let Some(callback) = callback {
+                callback(&err);
+            }
+        };
+
+        device.build_input_stream(&config.clone().into(), stream_cb, error_cb, None)
     }
 
     fn get_preferred_config(
@@ -508,6 +620,11 @@ impl AudioRecorder {
     }
 }
 
+/// The capture device went away mid-stream (unplugged or disabled).
+pub fn is_device_lost_error(err: &cpal::StreamError) -> bool {
+    matches!(err, cpal::StreamError::DeviceNotAvailable)
+}
+
 pub fn is_microphone_access_denied(error_message: &str) -> bool {
     let normalized = error_message.to_lowercase();
     normalized.contains("access is denied")
@@ -522,6 +639,68 @@ pub fn is_no_input_device_error(error_message: &str) -> bool {
             && normalized.contains("coreaudio"))
 }
 
+/// Why the microphone could not be opened, as far as the cpal/WASAPI error text tells us.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MicrophoneOpenFailure {
+    /// Another app holds the device (WASAPI exclusive mode or a busy ALSA device).
+    DeviceInUse,
+    /// The device rejected every stream format we asked for.
+    FormatNotSupported,
+    AccessDenied,
+    Other,
+}
+
+impl MicrophoneOpenFailure {
+    /// Transient conflicts are often resolved once the other app finishes releasing the device.
+    pub fn is_transient(self) -> bool {
+        matches!(self, MicrophoneOpenFailure::DeviceInUse)
+    }
+
+    pub fn user_message(self) -> Option<&'static str> {
+        match self {
+            MicrophoneOpenFailure::DeviceInUse => Some(
+                "Another app is using the microphone exclusively. Close it or switch to a different input device.",
+            ),
+            MicrophoneOpenFailure::FormatNotSupported => Some(
+                "The microphone does not support the requested audio format. Try a different input device or change its format in the system sound settings.",
+            ),
+            MicrophoneOpenFailure::AccessDenied => Some(
+                "Microphone access was denied. Allow microphone access for desktop apps in the system privacy settings.",
+            ),
+            MicrophoneOpenFailure::Other => None,
+        }
+    }
+}
+
+pub fn classify_microphone_open_error(error_message: &str) -> MicrophoneOpenFailure {
+    let normalized = error_message.to_lowercase();
+    // AUDCLNT_E_DEVICE_IN_USE / AUDCLNT_E_EXCLUSIVE_MODE_NOT_ALLOWED, ALSA EBUSY.
+    if normalized.contains("0x8889000a")
+        || normalized.contains("0x8889000e")
+        || normalized.contains("device_in_use")
+        || normalized.contains("exclusive mode")
+        || normalized.contains("device or resource busy")
+        || normalized.contains("device is busy")
+        || normalized.contains("in use by another")
+    {
+        return MicrophoneOpenFailure::DeviceInUse;
+    }
+    if is_microphone_access_denied(error_message)
+        || normalized.contains("microphone access was denied")
+    {
+        return MicrophoneOpenFailure::AccessDenied;
+    }
+    // AUDCLNT_E_UNSUPPORTED_FORMAT and cpal's StreamConfigNotSupported.
+    if normalized.contains("0x88890008")
+        || normalized.contains("unsupported_format")
+        || normalized.contains("stream configuration is not supported")
+        || normalized.contains("format not supported")
+    {
+        return MicrophoneOpenFailure::FormatNotSupported;
+    }
+    MicrophoneOpenFailure::Other
+}
+
 fn normalize_capture_open_error(source: AudioCaptureSource, error_message: String) -> String {
     if source == AudioCaptureSource::Microphone && is_microphone_access_denied(&error_message) {
         return "Microphone access was denied by Windows. Enable Settings > Privacy & security > Microphone, make sure desktop app access is allowed, then restart the app.".to_string();
@@ -540,8 +719,14 @@ fn visualizer_window_size(sample_rate: u32) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::{is_microphone_access_denied, is_no_input_device_error};
+    use super::{
+        classify_microphone_open_error, is_microphone_access_denied, is_no_input_device_error,
+        AudioCaptureSource, AudioVisualiser, FrameResampler, MicrophoneOpenFailure,
+    };
     use crate::audio_toolkit::constants;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::time::{Duration, Instant};
 
     #[test]
     fn detects_access_is_denied() {
@@ -600,6 +785,136 @@ mod tests {
         assert_eq!(super::visualizer_window_size(16_000), 512);
         assert_eq!(super::visualizer_window_size(48_000), 2048);
     }
+
+    #[test]
+    fn classifies_exclusive_mode_conflicts() {
+        assert_eq!(
+            classify_microphone_open_error(
+                "A backend-specific error has occurred: 0x8889000A (AUDCLNT_E_DEVICE_IN_USE)"
+            ),
+            MicrophoneOpenFailure::DeviceInUse
+        );
+        assert_eq!(
+            classify_microphone_open_error(
+                "ALSA function 'snd_pcm_open' failed: Device or resource busy"
+            ),
+            MicrophoneOpenFailure::DeviceInUse
+        );
+    }
+
+    #[test]
+    fn classifies_format_and_access_errors() {
+        assert_eq!(
+            classify_microphone_open_error(
+                "The requested stream configuration is not supported by the device."
+            ),
+            MicrophoneOpenFailure::FormatNotSupported
+        );
+        assert_eq!(
+            classify_microphone_open_error("WASAPI error: 0x80070005"),
+            MicrophoneOpenFailure::AccessDenied
+        );
+        assert_eq!(
+            classify_microphone_open_error("device not found"),
+            MicrophoneOpenFailure::Other
+        );
+    }
+
+    /// Consumer state driven by commands, as `run_consumer` would hold it.
+    struct ConsumerState {
+        recording: bool,
+        paused: bool,
+        processed_samples: Vec<f32>,
+        pending_chunk: Option<super::AudioChunk>,
+    }
+
+    impl ConsumerState {
+        fn run(&mut self, cmd: super::Cmd, sample_rx: &mpsc::Receiver<super::AudioChunk>) {
+            let mut frame_resampler =
+                FrameResampler::new(16_000, 16_000, Duration::from_millis(30));
+            let mut visualizer = AudioVisualiser::new(16_000, 512, 16, 400.0, 4000.0);
+            super::process_consumer_cmd(
+                cmd,
+                &mut self.recording,
+                &mut self.paused,
+                &mut self.processed_samples,
+                Some(&mut self.pending_chunk),
+                sample_rx,
+                &mut frame_resampler,
+                &None,
+                &Arc::new(Mutex::new(None)),
+                &mut visualizer,
+                AudioCaptureSource::Microphone,
+                &Arc::new(Mutex::new(1.0)),
+                &Arc::new(AtomicBool::new(false)),
+                &mut None,
+                &Arc::new(AtomicBool::new(false)),
+                &AtomicBool::new(false),
+            );
+        }
+
+        fn stop(&mut self, tail: Vec<f32>) -> Vec<f32> {
+            let (sample_tx, sample_rx) = mpsc::channel();
+            sample_tx.send(super::AudioChunk::Samples(tail)).unwrap();
+            sample_tx.send(super::AudioChunk::EndOfStream).unwrap();
+            let (reply_tx, reply_rx) = mpsc::channel();
+            self.run(super::Cmd::Stop(reply_tx), &sample_rx);
+            reply_rx.recv().unwrap()
+        }
+    }
+
+    fn recording_state() -> ConsumerState {
+        ConsumerState {
+            recording: true,
+            paused: false,
+            processed_samples: vec![0.25; 480],
+            pending_chunk: None,
+        }
+    }
+
+    #[test]
+    fn pause_only_applies_while_recording() {
+        let (_sample_tx, sample_rx) = mpsc::channel();
+        let mut state = ConsumerState {
+            recording: false,
+            ..recording_state()
+        };
+        state.run(super::Cmd::SetPaused(true), &sample_rx);
+        assert!(!state.paused);
+
+        state.run(super::Cmd::Start(Instant::now()), &sample_rx);
+        state.run(super::Cmd::SetPaused(true), &sample_rx);
+        assert!(state.paused);
+
+        state.run(super::Cmd::Start(Instant::now()), &sample_rx);
+        assert!(!state.paused);
+    }
+
+    #[test]
+    fn stop_while_paused_keeps_only_audio_from_before_the_pause() {
+        let mut state = recording_state();
+        state.paused = true;
+        state.pending_chunk = Some(super::AudioChunk::Samples(vec![0.5; 480]));
+
+        let samples = state.stop(vec![0.5; 480]);
+
+        assert_eq!(samples, vec![0.25; 480]);
+        assert!(!state.paused);
+        assert!(!state.recording);
+    }
+
+    #[test]
+    fn stop_after_resume_keeps_the_tail() {
+        let mut state = recording_state();
+        let (_sample_tx, sample_rx) = mpsc::channel();
+        state.run(super::Cmd::SetPaused(true), &sample_rx);
+        state.run(super::Cmd::SetPaused(false), &sample_rx);
+
+        let samples = state.stop(vec![0.5; 480]);
+
+        assert_eq!(samples.len(), 960);
+        assert_eq!(&samples[..480], &[0.25; 480][..]);
+    }
 }
 
 fn handle_frame(
@@ -607,19 +922,36 @@ fn handle_frame(
     recording: bool,
     vad: &Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     out_buf: &mut Vec<f32>,
-) {
+) -> Option<bool> {
     if !recording {
-        return;
+        return None;
     }
 
     if let Some(vad_arc) = vad {
         let mut det = vad_arc.lock().unwrap();
         match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
-            VadFrame::Speech(buf) => out_buf.extend_from_slice(buf),
-            VadFrame::Noise => {}
+            VadFrame::Speech(buf) => {
+                out_buf.extend_from_slice(buf);
+                Some(true)
+            }
+            VadFrame::Noise => Some(false),
         }
     } else {
         out_buf.extend_from_slice(samples);
+        None
+    }
+}
+
+fn emit_speech_activity(
+    speech_activity_cb: &Arc<Mutex<Option<SpeechActivityCallback>>>,
+    is_speech: bool,
+) {
+    let callback = speech_activity_cb
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone());
+    if let Some(callback) = callback {
+        callback(is_speech);
     }
 }
 
@@ -630,6 +962,13 @@ fn emit_stream_frame(stream_frame_cb: &Arc<Mutex<Option<StreamFrameCallback>>>,
     }
 }
 
+fn emit_frame_tap(frame_tap_cb: &Arc<Mutex<Option<FrameTapCallback>>>, samples: &[f32]) {
+    let callback = frame_tap_cb.lock().ok().and_then(|guard| guard.clone());
+    if let Some(callback) = callback {
+        callback(samples);
+    }
+}
+
 fn microphone_input_gain_from_db(db: f32) -> f32 {
     let sanitized = if db.is_finite() {
         db.clamp(0.0, constants::MAX_MICROPHONE_INPUT_BOOST_DB)
@@ -699,6 +1038,7 @@ fn apply_noise_cancellation_if_needed<'a>(
 fn process_consumer_cmd(
     cmd: Cmd,
     recording: &mut bool,
+    paused: &mut bool,
     processed_samples: &mut Vec<f32>,
     pending_chunk: Option<&mut Option<AudioChunk>>,
     sample_rx: &mpsc::Receiver<AudioChunk>,
@@ -711,6 +1051,7 @@ fn process_consumer_cmd(
     microphone_noise_cancellation_enabled: &Arc<AtomicBool>,
     noise_suppressor: &mut Option<NoiseSuppressor>,
     stop_flag: &Arc<AtomicBool>,
+    device_lost: &AtomicBool,
 ) -> bool {
     match cmd {
         Cmd::Start(sent_at) => {
@@ -722,6 +1063,7 @@ fn process_consumer_cmd(
             processed_samples.clear();
             *noise_suppressor = None;
             *recording = true;
+            *paused = false;
             visualizer.reset();
             frame_resampler.reset();
             if let Some(v) = vad {
@@ -749,8 +1091,17 @@ fn process_consumer_cmd(
             let _ = reply_tx.send(flushed);
             false
         }
+        Cmd::SetPaused(value) => {
+            if *recording {
+                *paused = value;
+            }
+            false
+        }
         Cmd::Stop(reply_tx) => {
             *recording = false;
+            // Audio captured while paused is discarded, including the tail
+            // drained below.
+            let drop_tail = std::mem::take(paused);
             stop_flag.store(true, Ordering::Relaxed);
 
             if let Some(Some(AudioChunk::Samples(remaining))) =
@@ -764,13 +1115,23 @@ fn process_consumer_cmd(
                         microphone_noise_cancellation_enabled,
                         noise_suppressor,
                     );
-                    emit_stream_frame(stream_frame_cb, enhanced.as_ref());
-                    handle_frame(enhanced.as_ref(), true, vad, processed_samples)
+                    if !drop_tail {
+                        emit_stream_frame(stream_frame_cb, enhanced.as_ref());
+                        handle_frame(enhanced.as_ref(), true, vad, processed_samples);
+                    }
                 });
             }
 
+            // A lost device never delivers EndOfStream, so only drain what
+            // already arrived instead of waiting for it.
+            let device_lost = device_lost.load(Ordering::Relaxed);
+            let wait = if device_lost {
+                Duration::ZERO
+            } else {
+                Duration::from_secs(2)
+            };
             loop {
-                match sample_rx.recv_timeout(Duration::from_secs(2)) {
+                match sample_rx.recv_timeout(wait) {
                     Ok(AudioChunk::Samples(remaining)) => {
                         frame_resampler.push(&remaining, &mut |frame: &[f32]| {
                             let adjusted =
@@ -781,11 +1142,14 @@ fn process_consumer_cmd(
                                 microphone_noise_cancellation_enabled,
                                 noise_suppressor,
                             );
-                            emit_stream_frame(stream_frame_cb, enhanced.as_ref());
-                            handle_frame(enhanced.as_ref(), true, vad, processed_samples)
+                            if !drop_tail {
+                                emit_stream_frame(stream_frame_cb, enhanced.as_ref());
+                                handle_frame(enhanced.as_ref(), true, vad, processed_samples);
+                            }
                         });
                     }
                     Ok(AudioChunk::EndOfStream) => break,
+                    Err(_) if device_lost => break,
                     Err(_) => {
                         log::warn!("Timed out waiting for EndOfStream from audio callback");
                         break;
@@ -801,8 +1165,10 @@ fn process_consumer_cmd(
                     microphone_noise_cancellation_enabled,
                     noise_suppressor,
                 );
-                emit_stream_frame(stream_frame_cb, enhanced.as_ref());
-                handle_frame(enhanced.as_ref(), true, vad, processed_samples)
+                if !drop_tail {
+                    emit_stream_frame(stream_frame_cb, enhanced.as_ref());
+                    handle_frame(enhanced.as_ref(), true, vad, processed_samples);
+                }
             });
 
             let _ = reply_tx.send(std::mem::take(processed_samples));
@@ -825,10 +1191,13 @@ fn run_consumer(
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     stream_frame_cb: Arc<Mutex<Option<StreamFrameCallback>>>,
+    speech_activity_cb: Arc<Mutex<Option<SpeechActivityCallback>>>,
+    frame_tap_cb: Arc<Mutex<Option<FrameTapCallback>>>,
     source: AudioCaptureSource,
     microphone_input_gain: Arc<Mutex<f32>>,
     microphone_noise_cancellation_enabled: Arc<AtomicBool>,
     stop_flag: Arc<AtomicBool>,
+    device_lost: Arc<AtomicBool>,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
@@ -838,6 +1207,7 @@ fn run_consumer(
 
     let mut processed_samples = Vec::<f32>::new();
     let mut recording = false;
+    let mut paused = false;
     let mut noise_suppressor: Option<NoiseSuppressor> = None;
 
     const BUCKETS: usize = 16;
@@ -849,6 +1219,7 @@ fn run_consumer(
             if process_consumer_cmd(
                 cmd,
                 &mut recording,
+                &mut paused,
                 &mut processed_samples,
                 None,
                 &sample_rx,
@@ -861,6 +1232,7 @@ fn run_consumer(
                 &microphone_noise_cancellation_enabled,
                 &mut noise_suppressor,
                 &stop_flag,
+                &device_lost,
             ) {
                 return;
             }
@@ -878,6 +1250,7 @@ fn run_consumer(
             if process_consumer_cmd(
                 cmd,
                 &mut recording,
+                &mut paused,
                 &mut processed_samples,
                 Some(&mut pending_chunk),
                 &sample_rx,
@@ -890,6 +1263,7 @@ fn run_consumer(
                 &microphone_noise_cancellation_enabled,
                 &mut noise_suppressor,
                 &stop_flag,
+                &device_lost,
             ) {
                 return;
             }
@@ -908,7 +1282,8 @@ fn run_consumer(
 
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
             let adjusted = apply_input_gain_if_needed(frame, source, &microphone_input_gain);
-            if recording {
+            // While paused the level meter keeps running but frames are dropped.
+            if recording && !paused {
                 let enhanced = apply_noise_cancellation_if_needed(
                     adjusted,
                     source,
@@ -916,9 +1291,14 @@ fn run_consumer(
                     &mut noise_suppressor,
                 );
                 emit_stream_frame(&stream_frame_cb, enhanced.as_ref());
-                handle_frame(enhanced.as_ref(), true, &vad, &mut processed_samples)
-            } else {
-                handle_frame(adjusted.as_ref(), false, &vad, &mut processed_samples)
+                emit_frame_tap(&frame_tap_cb, enhanced.as_ref());
+                if let Some(is_speech) =
+                    handle_frame(enhanced.as_ref(), true, &vad, &mut processed_samples)
+                {
+                    emit_speech_activity(&speech_activity_cb, is_speech);
+                }
+            } else if !recording {
+                handle_frame(adjusted.as_ref(), false, &vad, &mut processed_samples);
             }
         });
     }
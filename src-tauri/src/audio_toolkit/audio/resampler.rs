@@ -111,6 +111,9 @@ impl FrameResampler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64::consts::PI;
+
+    const OUT_RATE: usize = 16_000;
 
     fn collect_recording(resampler: &mut FrameResampler, input: &[f32]) -> Vec<f32> {
         let mut output = Vec::new();
@@ -119,6 +122,84 @@ mod tests {
         output
     }
 
+    fn tone(rate: usize, hz: f64, secs: f64) -> Vec<f32> {
+        let len = (rate as f64 * secs) as usize;
+        (0..len)
+            .map(|i| (0.5 * (2.0 * PI * hz * i as f64 / rate as f64).sin()) as f32)
+            .collect()
+    }
+
+    /// Linear sweep from `from_hz` to `to_hz` over `secs`.
+    fn sweep(rate: usize, from_hz: f64, to_hz: f64, secs: f64) -> Vec<f32> {
+        let len = (rate as f64 * secs) as usize;
+        (0..len)
+            .map(|i| {
+                let t = i as f64 / rate as f64;
+                let phase = 2.0 * PI * (from_hz * t + (to_hz - from_hz) * t * t / (2.0 * secs));
+                (0.5 * phase.sin()) as f32
+            })
+            .collect()
+    }
+
+    /// Frequency estimated from upward zero crossings.
+    fn estimate_hz(samples: &[f32], rate: usize) -> f64 {
+        let crossings = samples
+            .windows(2)
+            .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+            .count();
+        crossings as f64 * rate as f64 / samples.len() as f64
+    }
+
+    #[test]
+    fn resampled_tones_keep_their_pitch() {
+        for in_rate in [48_000, 44_100, OUT_RATE] {
+            for hz in [220.0, 1_000.0, 3_000.0] {
+                let mut resampler =
+                    FrameResampler::new(in_rate, OUT_RATE, Duration::from_millis(30));
+                let output = collect_recording(&mut resampler, &tone(in_rate, hz, 1.5));
+
+                // Duration is preserved up to the zero padding of the last chunk and frame.
+                let expected_len = OUT_RATE * 3 / 2;
+                assert!(
+                    output.len().abs_diff(expected_len) <= 1_000,
+                    "{in_rate} Hz input produced {} samples",
+                    output.len()
+                );
+
+                let measured = estimate_hz(&output[OUT_RATE / 4..OUT_RATE * 5 / 4], OUT_RATE);
+                assert!(
+                    (measured - hz).abs() <= hz * 0.01,
+                    "{hz} Hz tone at {in_rate} Hz came out at {measured} Hz"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn resampled_sweep_keeps_its_pitch_over_time() {
+        const SECS: f64 = 2.0;
+        const FROM_HZ: f64 = 200.0;
+        const TO_HZ: f64 = 4_000.0;
+        let window = OUT_RATE / 10;
+
+        for in_rate in [48_000, 44_100] {
+            let mut resampler = FrameResampler::new(in_rate, OUT_RATE, Duration::from_millis(30));
+            let output = collect_recording(&mut resampler, &sweep(in_rate, FROM_HZ, TO_HZ, SECS));
+
+            for index in 2..18 {
+                let start = index * window;
+                let centre_secs = (start + window / 2) as f64 / OUT_RATE as f64;
+                let expected = FROM_HZ + (TO_HZ - FROM_HZ) * centre_secs / SECS;
+                let measured = estimate_hz(&output[start..start + window], OUT_RATE);
+                // Allows for the resampler's few milliseconds of delay on a moving pitch.
+                assert!(
+                    (measured - expected).abs() <= expected * 0.03 + 120.0,
+                    "{in_rate} Hz sweep at {centre_secs:.2}s: expected {expected} Hz, got {measured} Hz"
+                );
+            }
+        }
+    }
+
     #[test]
     fn reset_clears_wrapper_buffers() {
         let mut resampling = FrameResampler::new(48_000, 16_000, Duration::from_millis(30));
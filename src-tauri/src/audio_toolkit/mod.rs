@@ -5,10 +5,17 @@ pub mod utils;
 pub mod vad;
 
 pub use audio::{
-    encode_wav_bytes, is_microphone_access_denied, is_no_input_device_error, list_input_devices,
-    list_output_devices, read_wav_samples, save_wav_file, verify_wav_file, AudioCaptureSource,
-    AudioRecorder, CpalDeviceInfo, StreamFrameCallback,
+    classify_microphone_open_error, encode_wav_bytes, is_device_lost_error,
+    is_microphone_access_denied, is_no_input_device_error, list_input_devices, list_output_devices,
+    read_wav_samples, save_wav_file, verify_wav_file, AudioCaptureSource, AudioRecorder,
+    CpalDeviceInfo, FrameTapCallback, InputFormat, InputLevelMeter, MicrophoneOpenFailure,
+    SpeechActivityCallback, StreamErrorCallback, StreamFrameCallback,
+};
+pub use text::{
+    apply_casing, apply_custom_words, char_error_counts, filter_transcription_output,
+    repetition_ratio, strip_non_final_punctuation, word_error_counts,
+    words_match_ignoring_punctuation, AsCustomWord, CaseStyle, CasingState, CustomWord,
+    CustomWordKind, ErrorCounts,
 };
-pub use text::{apply_custom_words, filter_transcription_output};
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
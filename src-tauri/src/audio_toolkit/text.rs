@@ -1,8 +1,159 @@
 use natural::phonetics::soundex;
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{NoExpand, Regex, RegexBuilder};
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use strsim::levenshtein;
 
+/// How a custom word entry is matched against transcribed text.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomWordKind {
+    /// Sound-alike and near-miss spellings of `pattern` are corrected to it.
+    #[default]
+    Fuzzy,
+    /// `pattern` is replaced literally wherever it appears as a whole word.
+    Exact,
+    /// `pattern` is a regular expression; `replacement` may use `$1`-style groups.
+    Regex,
+}
+
+/// One custom word entry. Plain strings from older settings load as fuzzy
+/// entries.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct CustomWord {
+    pub pattern: String,
+    /// Text written in place of a match. Empty for fuzzy entries, which
+    /// write `pattern` itself.
+    pub replacement: String,
+    pub kind: CustomWordKind,
+    /// Ignored by fuzzy entries, which always match case-insensitively.
+    pub case_sensitive: bool,
+}
+
+impl CustomWord {
+    pub fn fuzzy(word: impl Into<String>) -> Self {
+        Self {
+            pattern: word.into(),
+            replacement: String::new(),
+            kind: CustomWordKind::Fuzzy,
+            case_sensitive: false,
+        }
+    }
+
+    /// Text a fuzzy match is corrected to.
+    fn fuzzy_target(&self) -> &str {
+        if self.replacement.is_empty() {
+            &self.pattern
+        } else {
+            &self.replacement
+        }
+    }
+
+    /// Compiles an exact or regex entry into the regex used for replacement.
+    /// Exact patterns only match at word boundaries.
+    pub fn compile(&self) -> Result<Regex, String> {
+        let source = match self.kind {
+            CustomWordKind::Fuzzy => return Err("Fuzzy entries are not compiled".to_string()),
+            CustomWordKind::Exact => {
+                let pattern = self.pattern.trim();
+                if pattern.is_empty() {
+                    return Err("Pattern is empty".to_string());
+                }
+                let starts_with_word = pattern.chars().next().is_some_and(is_word_char);
+                let ends_with_word = pattern.chars().next_back().is_some_and(is_word_char);
+                format!(
+                    "{}{}{}",
+                    if starts_with_word { r"\b" } else { "" },
+                    regex::escape(pattern),
+                    if ends_with_word { r"\b" } else { "" }
+                )
+            }
+            CustomWordKind::Regex => {
+                if self.pattern.is_empty() {
+                    return Err("Pattern is empty".to_string());
+                }
+                self.pattern.clone()
+            }
+        };
+        RegexBuilder::new(&source)
+            .case_insensitive(!self.case_sensitive)
+            .size_limit(1 << 20)
+            .build()
+            .map_err(|e| e.to_string())
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Field layout of a structured entry; missing fields take their defaults.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct CustomWordFields {
+    pattern: String,
+    replacement: String,
+    kind: CustomWordKind,
+    case_sensitive: bool,
+}
+
+// Custom deserializer to accept both the old plain-string word list and entries.
+impl<'de> Deserialize<'de> for CustomWord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CustomWordVisitor;
+
+        impl<'de> Visitor<'de> for CustomWordVisitor {
+            type Value = CustomWord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a word string or a custom word entry")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<CustomWord, E> {
+                Ok(CustomWord::fuzzy(value))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<CustomWord, A::Error> {
+                let fields =
+                    CustomWordFields::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(CustomWord {
+                    pattern: fields.pattern,
+                    replacement: fields.replacement,
+                    kind: fields.kind,
+                    case_sensitive: fields.case_sensitive,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(CustomWordVisitor)
+    }
+}
+
+/// Anything [`apply_custom_words`] accepts as an entry; plain strings are
+/// fuzzy words.
+pub trait AsCustomWord {
+    fn as_custom_word(&self) -> Cow<'_, CustomWord>;
+}
+
+impl AsCustomWord for CustomWord {
+    fn as_custom_word(&self) -> Cow<'_, CustomWord> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsCustomWord for String {
+    fn as_custom_word(&self) -> Cow<'_, CustomWord> {
+        Cow::Owned(CustomWord::fuzzy(self.as_str()))
+    }
+}
+
 /// Builds an n-gram string by cleaning and concatenating words.
 ///
 /// Strips non-alphanumeric chars from each token, lowercases, and joins
@@ -69,15 +220,15 @@ fn supports_soundex(key: &str) -> bool {
 /// `threshold` is found.
 fn find_best_match<'a>(
     candidate: &str,
-    custom_words: &'a [String],
+    custom_words: &[&'a str],
     custom_word_match_keys: &[CustomWordMatchKey],
     threshold: f64,
-) -> Option<(&'a String, f64)> {
+) -> Option<(&'a str, f64)> {
     if !is_supported_fuzzy_key(candidate) || candidate.chars().count() > 50 {
         return None;
     }
 
-    let mut best_match: Option<&String> = None;
+    let mut best_match: Option<&str> = None;
     let mut best_score = f64::MAX;
 
     for custom_word_match_key in custom_word_match_keys {
@@ -110,7 +261,7 @@ fn find_best_match<'a>(
         };
 
         if combined_score < threshold && combined_score < best_score {
-            best_match = Some(&custom_words[custom_word_match_key.word_index]);
+            best_match = Some(custom_words[custom_word_match_key.word_index]);
             best_score = combined_score;
         }
     }
@@ -118,25 +269,28 @@ fn find_best_match<'a>(
     best_match.map(|m| (m, best_score))
 }
 
-/// Applies custom word corrections to transcribed text using fuzzy matching
+/// Applies custom word corrections to transcribed text
 ///
-/// This function corrects words in the input text by finding the best matches
-/// from a list of custom words using a combination of:
+/// Fuzzy entries correct words in the input text by finding the best matches
+/// using a combination of:
 /// - Levenshtein distance for string similarity
 /// - Soundex phonetic matching for pronunciation similarity
 /// - N-gram matching for split-token artifacts (e.g., "Chat G P T" -> "ChatGPT")
 ///
+/// Exact and regex entries then run as deterministic replacements, in list
+/// order, over the fuzzy-corrected text. Invalid regexes are skipped.
+///
 /// # Arguments
 /// * `text` - The input text to correct
-/// * `custom_words` - List of custom words to match against
-/// * `threshold` - Maximum similarity score to accept (0.0 = exact match, 1.0 = any match)
+/// * `custom_words` - Custom word entries (or plain fuzzy words) to apply
+/// * `threshold` - Maximum similarity score to accept for fuzzy entries (0.0 = exact match, 1.0 = any match)
 /// * `enable_ngram` - Enable 2-3 token greedy n-gram matching
 ///
 /// # Returns
 /// The corrected text with custom words applied
-pub fn apply_custom_words(
+pub fn apply_custom_words<W: AsCustomWord>(
     text: &str,
-    custom_words: &[String],
+    custom_words: &[W],
     threshold: f64,
     enable_ngram: bool,
 ) -> String {
@@ -144,8 +298,64 @@ pub fn apply_custom_words(
         return text.to_string();
     }
 
+    let entries: Vec<Cow<'_, CustomWord>> = custom_words
+        .iter()
+        .map(|word| word.as_custom_word())
+        .collect();
+    let fuzzy_words: Vec<&str> = entries
+        .iter()
+        .filter(|entry| entry.kind == CustomWordKind::Fuzzy)
+        .map(|entry| entry.fuzzy_target())
+        .collect();
+    let fuzzy_patterns: Vec<&str> = entries
+        .iter()
+        .filter(|entry| entry.kind == CustomWordKind::Fuzzy)
+        .map(|entry| entry.pattern.as_str())
+        .collect();
+
+    let mut result = if fuzzy_words.is_empty() {
+        text.to_string()
+    } else {
+        apply_fuzzy_words(text, &fuzzy_patterns, &fuzzy_words, threshold, enable_ngram)
+    };
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.kind != CustomWordKind::Fuzzy)
+    {
+        match entry.compile() {
+            Ok(regex) if entry.kind == CustomWordKind::Exact => {
+                result = regex
+                    .replace_all(&result, NoExpand(entry.replacement.as_str()))
+                    .into_owned();
+            }
+            Ok(regex) => {
+                result = regex
+                    .replace_all(&result, entry.replacement.as_str())
+                    .into_owned();
+            }
+            Err(error) => {
+                log::debug!(
+                    "Skipping custom word pattern {:?}: {}",
+                    entry.pattern,
+                    error
+                );
+            }
+        }
+    }
+    result
+}
+
+/// Fuzzy pass of [`apply_custom_words`]. `patterns[i]` is matched and
+/// replaced with `targets[i]`.
+fn apply_fuzzy_words(
+    text: &str,
+    patterns: &[&str],
+    targets: &[&str],
+    threshold: f64,
+    enable_ngram: bool,
+) -> String {
     // Pre-compute normalized comparison keys, including spoken forms of '&'.
-    let custom_word_match_keys: Vec<CustomWordMatchKey> = custom_words
+    let custom_word_match_keys: Vec<CustomWordMatchKey> = patterns
         .iter()
         .enumerate()
         .flat_map(|(index, word)| build_custom_word_match_keys(word, index))
@@ -157,7 +367,7 @@ pub fn apply_custom_words(
     let max_ngram = if enable_ngram { 3 } else { 1 };
 
     while i < words.len() {
-        let mut best_match: Option<(usize, &String, f64)> = None;
+        let mut best_match: Option<(usize, &str, f64)> = None;
 
         // Consider n-grams up to the configured maximum and choose the closest
         // match. A longest-first match can consume a following ordinary word
@@ -182,7 +392,7 @@ pub fn apply_custom_words(
             }
 
             if let Some((replacement, score)) =
-                find_best_match(&ngram, custom_words, &custom_word_match_keys, threshold)
+                find_best_match(&ngram, targets, &custom_word_match_keys, threshold)
             {
                 let is_better = best_match
                     .as_ref()
@@ -398,6 +608,390 @@ pub fn filter_transcription_output(
     filtered.trim().to_string()
 }
 
+/// Whether the period at `idx` can be dropped: it is followed by whitespace and
+/// a lowercase word, and does not belong to an ellipsis, an initial ("J.") or a
+/// dotted abbreviation ("e.g.").
+fn is_strippable_period(chars: &[char], idx: usize) -> bool {
+    if idx > 0 && chars[idx - 1] == '.' {
+        return false;
+    }
+    if !chars.get(idx + 1).is_some_and(|c| c.is_whitespace()) {
+        return false;
+    }
+    let next_word_lowercase = chars[idx + 1..]
+        .iter()
+        .find(|c| !c.is_whitespace())
+        .is_some_and(|c| c.is_lowercase());
+    if !next_word_lowercase {
+        return false;
+    }
+
+    let word_start = chars[..idx]
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .map(|pos| pos + 1)
+        .unwrap_or(0);
+    let word = &chars[word_start..idx];
+    word.len() > 1 && !word.contains(&'.')
+}
+
+/// Removes commas and periods that do not end a sentence, for providers that
+/// over-punctuate.
+///
+/// Sentence-final periods, ellipses, decimals ("3.5"), thousands separators
+/// ("1,000") and dotted abbreviations ("e.g.") are kept. Other punctuation,
+/// including quotes, is left untouched.
+pub fn strip_non_final_punctuation(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+
+    for (idx, &ch) in chars.iter().enumerate() {
+        let prev = idx.checked_sub(1).map(|prev_idx| chars[prev_idx]);
+        let next = chars.get(idx + 1).copied();
+        match ch {
+            ',' => {
+                let is_digit_group = prev.is_some_and(|c| c.is_ascii_digit())
+                    && next.is_some_and(|c| c.is_ascii_digit());
+                if is_digit_group {
+                    result.push(ch);
+                } else if prev.is_some_and(|c| !c.is_whitespace())
+                    && next.is_some_and(char::is_alphanumeric)
+                {
+                    // "word,word" must not glue the words together.
+                    result.push(' ');
+                } else if prev.is_some_and(char::is_whitespace)
+                    && next.is_some_and(char::is_whitespace)
+                {
+                    result.pop();
+                }
+            }
+            '.' if is_strippable_period(&chars, idx) => {}
+            _ => result.push(ch),
+        }
+    }
+
+    result
+}
+
+/// Whether two texts contain the same words, ignoring punctuation and case.
+/// Used to reject punctuation-only rewrites that changed anything else.
+pub fn words_match_ignoring_punctuation(left: &str, right: &str) -> bool {
+    let keys = |text: &str| -> Vec<String> {
+        text.split_whitespace()
+            .map(build_match_key)
+            .filter(|key| !key.is_empty())
+            .collect()
+    };
+    keys(left) == keys(right)
+}
+
+/// Edit errors of a hypothesis against a reference, as counted by a
+/// Levenshtein alignment (substitutions + deletions + insertions).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorCounts {
+    pub errors: usize,
+    pub reference_len: usize,
+}
+
+impl ErrorCounts {
+    /// Error rate relative to the reference length. An empty reference yields
+    /// 0.0 when the hypothesis is empty too, and 1.0 otherwise.
+    pub fn rate(&self) -> f64 {
+        if self.reference_len == 0 {
+            return if self.errors == 0 { 0.0 } else { 1.0 };
+        }
+        self.errors as f64 / self.reference_len as f64
+    }
+}
+
+impl std::ops::AddAssign for ErrorCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.errors += other.errors;
+        self.reference_len += other.reference_len;
+    }
+}
+
+fn edit_distance<T: PartialEq>(hypothesis: &[T], reference: &[T]) -> usize {
+    let mut previous: Vec<usize> = (0..=reference.len()).collect();
+    let mut current = vec![0; reference.len() + 1];
+
+    for (i, hyp_item) in hypothesis.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, ref_item) in reference.iter().enumerate() {
+            let substitution = previous[j] + usize::from(hyp_item != ref_item);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[reference.len()]
+}
+
+/// Lowercased words with punctuation removed, so evaluation measures
+/// recognition errors rather than formatting differences.
+fn evaluation_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(build_match_key)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Word-level errors (for WER) of `hypothesis` against `reference`.
+pub fn word_error_counts(hypothesis: &str, reference: &str) -> ErrorCounts {
+    let hypothesis_words = evaluation_words(hypothesis);
+    let reference_words = evaluation_words(reference);
+    ErrorCounts {
+        errors: edit_distance(&hypothesis_words, &reference_words),
+        reference_len: reference_words.len(),
+    }
+}
+
+/// Character-level errors (for CER) of `hypothesis` against `reference`,
+/// using the same normalization as [`word_error_counts`].
+pub fn char_error_counts(hypothesis: &str, reference: &str) -> ErrorCounts {
+    let hypothesis_chars: Vec<char> = evaluation_words(hypothesis).join(" ").chars().collect();
+    let reference_chars: Vec<char> = evaluation_words(reference).join(" ").chars().collect();
+    ErrorCounts {
+        errors: edit_distance(&hypothesis_chars, &reference_chars),
+        reference_len: reference_chars.len(),
+    }
+}
+
+/// Longest phrase, in words, that [`repetition_ratio`] looks for.
+const MAX_REPEATED_PHRASE_WORDS: usize = 4;
+/// A phrase has to repeat this many times in a row to count as a loop.
+const MIN_PHRASE_REPEATS: usize = 3;
+
+/// Share of words that sit in a short phrase repeated back to back at least
+/// three times, like "the the the" or "thank you thank you thank you".
+/// Decoder loops score close to 1.0, ordinary speech close to 0.0.
+pub fn repetition_ratio(text: &str) -> f32 {
+    let words = evaluation_words(text);
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let mut covered = vec![false; words.len()];
+    for n in 1..=MAX_REPEATED_PHRASE_WORDS {
+        let mut i = 0;
+        while i + n <= words.len() {
+            let phrase = &words[i..i + n];
+            let mut repeats = 1;
+            while i + (repeats + 1) * n <= words.len()
+                && &words[i + repeats * n..i + (repeats + 1) * n] == phrase
+            {
+                repeats += 1;
+            }
+            if repeats >= MIN_PHRASE_REPEATS {
+                covered[i..i + repeats * n].fill(true);
+                i += repeats * n;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    covered.iter().filter(|c| **c).count() as f32 / words.len() as f32
+}
+
+/// Case styles for [`apply_casing`] and [`CasingState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseStyle {
+    Lower,
+    Upper,
+    /// Capitalize every word; all-caps acronyms and exception words are kept.
+    Title,
+    /// Capitalize the first word of each sentence and lowercase the rest,
+    /// keeping acronyms and exception words.
+    Sentence,
+    /// `words_joined_like_this`; punctuation is dropped.
+    Snake,
+    /// `wordsJoinedLikeThis`; punctuation is dropped.
+    Camel,
+}
+
+/// Incremental casing, so text fed in chunks comes out exactly as if it had
+/// been cased in one piece. Title, Sentence, Snake and Camel decide per whole
+/// word, so the word still being typed is held back until a separator or
+/// [`CasingState::finish`].
+///
+/// Words are runs of Unicode letters and digits; an apostrophe inside a word
+/// belongs to it ("don't"), everything else (spaces, hyphens, punctuation)
+/// separates words.
+#[derive(Debug, Clone)]
+pub struct CasingState {
+    style: CaseStyle,
+    /// Lowercased exception word -> spelling to output (Title and Sentence).
+    exceptions: HashMap<String, String>,
+    word: String,
+    words_emitted: usize,
+    /// A separator followed the last emitted word (Snake and Camel).
+    separator_pending: bool,
+    sentence_start: bool,
+    /// A sentence terminator was seen; the next whitespace starts a sentence.
+    terminator_pending: bool,
+}
+
+impl CasingState {
+    pub fn new(style: CaseStyle, exceptions: &[String]) -> Self {
+        let exceptions = exceptions
+            .iter()
+            .map(|word| word.trim())
+            .filter(|word| !word.is_empty())
+            .map(|word| (word.to_lowercase(), word.to_string()))
+            .collect();
+        Self {
+            style,
+            exceptions,
+            word: String::new(),
+            words_emitted: 0,
+            separator_pending: false,
+            sentence_start: true,
+            terminator_pending: false,
+        }
+    }
+
+    /// Cases `chunk` and returns the text that is ready to be emitted.
+    pub fn push(&mut self, chunk: &str) -> String {
+        let mut out = String::new();
+        for c in chunk.chars() {
+            if c.is_alphanumeric() || (!self.word.is_empty() && is_word_apostrophe(c)) {
+                self.word.push(c);
+                continue;
+            }
+            self.emit_word(&mut out);
+            self.emit_separator(c, &mut out);
+        }
+        out
+    }
+
+    /// Returns the held-back word at the end of the text.
+    pub fn finish(&mut self) -> String {
+        let mut out = String::new();
+        self.emit_word(&mut out);
+        out
+    }
+
+    fn emit_word(&mut self, out: &mut String) {
+        if self.word.is_empty() {
+            return;
+        }
+        let word = std::mem::take(&mut self.word);
+        match self.style {
+            CaseStyle::Lower => out.push_str(&word.to_lowercase()),
+            CaseStyle::Upper => out.push_str(&word.to_uppercase()),
+            CaseStyle::Title => out.push_str(&self.keep_or(&word, capitalize)),
+            CaseStyle::Sentence => {
+                if self.sentence_start {
+                    out.push_str(&self.keep_or(&word, capitalize));
+                } else {
+                    out.push_str(&self.keep_or(&word, str::to_lowercase));
+                }
+                self.sentence_start = false;
+            }
+            CaseStyle::Snake => {
+                if self.words_emitted > 0 && self.separator_pending {
+                    out.push('_');
+                }
+                out.push_str(&without_apostrophes(&word).to_lowercase());
+            }
+            CaseStyle::Camel => {
+                let bare = without_apostrophes(&word);
+                if self.words_emitted > 0 && self.separator_pending {
+                    out.push_str(&capitalize(&bare));
+                } else {
+                    out.push_str(&bare.to_lowercase());
+                }
+            }
+        }
+        self.words_emitted += 1;
+        self.separator_pending = false;
+        self.terminator_pending = false;
+    }
+
+    fn emit_separator(&mut self, c: char, out: &mut String) {
+        match self.style {
+            CaseStyle::Snake | CaseStyle::Camel => {
+                if self.words_emitted == 0 {
+                    if c.is_whitespace() {
+                        out.push(c);
+                    }
+                } else {
+                    self.separator_pending = true;
+                }
+            }
+            _ => {
+                if matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？') {
+                    self.terminator_pending = true;
+                } else if c.is_whitespace() {
+                    if self.terminator_pending || c == '\n' {
+                        self.sentence_start = true;
+                    }
+                    self.terminator_pending = false;
+                } else if !is_closing_punctuation(c) {
+                    self.terminator_pending = false;
+                }
+                out.push(c);
+            }
+        }
+    }
+
+    /// Acronyms and exception words keep their spelling; other words go
+    /// through `case`.
+    fn keep_or(&self, word: &str, case: impl Fn(&str) -> String) -> String {
+        if let Some(spelling) = self.exceptions.get(&word.to_lowercase()) {
+            return spelling.clone();
+        }
+        if is_acronym(word) {
+            return word.to_string();
+        }
+        case(word)
+    }
+}
+
+/// Applies `style` to a whole text. Same result as feeding it to a
+/// [`CasingState`] in any number of chunks.
+pub fn apply_casing(text: &str, style: CaseStyle, exceptions: &[String]) -> String {
+    let mut state = CasingState::new(style, exceptions);
+    let mut out = state.push(text);
+    out.push_str(&state.finish());
+    out
+}
+
+fn is_word_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’'
+}
+
+fn is_closing_punctuation(c: char) -> bool {
+    matches!(c, '"' | '\'' | '’' | '”' | ')' | ']' | '»' | '」')
+}
+
+fn without_apostrophes(word: &str) -> String {
+    word.chars().filter(|c| !is_word_apostrophe(*c)).collect()
+}
+
+/// "NASA", "GPU2": two or more letters, all uppercase.
+fn is_acronym(word: &str) -> bool {
+    word.chars().filter(|c| c.is_alphabetic()).count() >= 2
+        && word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .all(char::is_uppercase)
+}
+
+/// First letter uppercase, the rest lowercase; a word starting with a digit
+/// ("2nd") is only lowercased.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        _ => word.to_lowercase(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -442,7 +1036,7 @@ mod tests {
     #[test]
     fn test_empty_custom_words() {
         let text = "hello world";
-        let custom_words = vec![];
+        let custom_words: Vec<String> = vec![];
         let result = apply_custom_words(text, &custom_words, 0.5, true);
         assert_eq!(result, "hello world");
     }
@@ -532,6 +1126,63 @@ mod tests {
         assert_eq!(result, text);
     }
 
+    fn entry(pattern: &str, replacement: &str, kind: CustomWordKind) -> CustomWord {
+        CustomWord {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            kind,
+            case_sensitive: false,
+        }
+    }
+
+    #[test]
+    fn test_apply_custom_words_exact_entries_replace_whole_words() {
+        let custom_words = vec![entry("teh", "the", CustomWordKind::Exact)];
+        let result = apply_custom_words("Teh cat saw teh tehran map", &custom_words, 0.0, true);
+        assert_eq!(result, "the cat saw the tehran map");
+
+        let mut case_sensitive = entry("API", "A.P.I.", CustomWordKind::Exact);
+        case_sensitive.case_sensitive = true;
+        let result = apply_custom_words("api and API", &[case_sensitive], 0.0, true);
+        assert_eq!(result, "api and A.P.I.");
+    }
+
+    #[test]
+    fn test_apply_custom_words_regex_entries_run_after_fuzzy_pass() {
+        let custom_words = vec![
+            entry(r"ticket (\d+)", "#$1", CustomWordKind::Regex),
+            CustomWord::fuzzy("Kubernetes"),
+            entry(r"Kubernetes", "K8s", CustomWordKind::Regex),
+        ];
+        let result = apply_custom_words("see ticket 42 about kubernets", &custom_words, 0.18, true);
+        assert_eq!(result, "see #42 about K8s");
+    }
+
+    #[test]
+    fn test_apply_custom_words_skips_invalid_regex() {
+        let custom_words = vec![
+            entry("(unclosed", "x", CustomWordKind::Regex),
+            entry("foo", "bar", CustomWordKind::Exact),
+        ];
+        let result = apply_custom_words("foo  (unclosed", &custom_words, 0.0, true);
+        assert_eq!(result, "bar  (unclosed");
+    }
+
+    #[test]
+    fn test_custom_word_deserializes_legacy_strings_and_entries() {
+        let words: Vec<CustomWord> = serde_json::from_str(
+            r#"["Tauri", {"pattern": "teh", "replacement": "the", "kind": "exact"}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            words,
+            vec![
+                CustomWord::fuzzy("Tauri"),
+                entry("teh", "the", CustomWordKind::Exact)
+            ]
+        );
+    }
+
     #[test]
     fn test_filter_filler_words() {
         let text = "So um I was thinking uh about this";
@@ -637,4 +1288,188 @@ mod tests {
         let result = filter_transcription_output(text, "en", &custom_filler_words);
         assert_eq!(result, "like this should stay, but this should go");
     }
+
+    #[test]
+    fn test_strip_punctuation_keeps_sentence_final_and_quotes() {
+        assert_eq!(
+            strip_non_final_punctuation("He said, \"wait, what?\" and left."),
+            "He said \"wait what?\" and left."
+        );
+        assert_eq!(
+            strip_non_final_punctuation("\"Stop,\" she said. Then, silence."),
+            "\"Stop\" she said. Then silence."
+        );
+        assert_eq!(
+            strip_non_final_punctuation("She said \"go.\" then left."),
+            "She said \"go.\" then left."
+        );
+    }
+
+    #[test]
+    fn test_strip_punctuation_keeps_ellipses_numbers_and_abbreviations() {
+        assert_eq!(
+            strip_non_final_punctuation("Well... I think, maybe… not. so, yes..."),
+            "Well... I think maybe… not so yes..."
+        );
+        assert_eq!(
+            strip_non_final_punctuation("It costs 1,000 or 3.5, e.g. a lot , really."),
+            "It costs 1,000 or 3.5 e.g. a lot really."
+        );
+        assert_eq!(strip_non_final_punctuation("one,two"), "one two");
+    }
+
+    #[test]
+    fn test_words_match_ignoring_punctuation() {
+        assert!(words_match_ignoring_punctuation(
+            "hello world how are you",
+            "Hello, world! How are you?"
+        ));
+        assert!(!words_match_ignoring_punctuation(
+            "hello world",
+            "Hello, dear world."
+        ));
+    }
+
+    #[test]
+    fn test_word_error_counts_known_pairs() {
+        let counts = word_error_counts(
+            "the quick brown fax jumps over",
+            "the quick brown fox jumps over",
+        );
+        assert_eq!((counts.errors, counts.reference_len), (1, 6));
+
+        // One deletion ("the") and one insertion ("lazy").
+        let counts = word_error_counts(
+            "quick brown fox jumps over lazy",
+            "the quick brown fox jumps over",
+        );
+        assert_eq!(counts.errors, 2);
+        assert!((counts.rate() - 2.0 / 6.0).abs() < 1e-9);
+
+        // Case and punctuation are not counted as errors.
+        assert_eq!(word_error_counts("Hello, World!", "hello world").errors, 0);
+        assert_eq!(word_error_counts("", "").rate(), 0.0);
+        assert_eq!(word_error_counts("extra", "").rate(), 1.0);
+    }
+
+    #[test]
+    fn test_char_error_counts_known_pairs() {
+        let counts = char_error_counts("kitten", "sitting");
+        assert_eq!((counts.errors, counts.reference_len), (3, 7));
+
+        let mut total = char_error_counts("abc", "abd");
+        total += char_error_counts("xy", "xy");
+        assert_eq!((total.errors, total.reference_len), (1, 5));
+    }
+
+    fn cased_in_chunks(text: &str, style: CaseStyle, chunk_len: usize) -> String {
+        let mut state = CasingState::new(style, &[]);
+        let chars: Vec<char> = text.chars().collect();
+        let mut out = String::new();
+        for chunk in chars.chunks(chunk_len) {
+            out.push_str(&state.push(&chunk.iter().collect::<String>()));
+        }
+        out.push_str(&state.finish());
+        out
+    }
+
+    #[test]
+    fn test_casing_hyphenated_words_and_numbers() {
+        let text = "a well-known API for version 2 of the 3rd-party tool.";
+        let cases = [
+            (
+                CaseStyle::Lower,
+                "a well-known api for version 2 of the 3rd-party tool.",
+            ),
+            (
+                CaseStyle::Upper,
+                "A WELL-KNOWN API FOR VERSION 2 OF THE 3RD-PARTY TOOL.",
+            ),
+            (
+                CaseStyle::Title,
+                "A Well-Known API For Version 2 Of The 3rd-Party Tool.",
+            ),
+            (
+                CaseStyle::Sentence,
+                "A well-known API for version 2 of the 3rd-party tool.",
+            ),
+            (
+                CaseStyle::Snake,
+                "a_well_known_api_for_version_2_of_the_3rd_party_tool",
+            ),
+            (
+                CaseStyle::Camel,
+                "aWellKnownApiForVersion2OfThe3rdPartyTool",
+            ),
+        ];
+        for (style, expected) in cases {
+            assert_eq!(apply_casing(text, style, &[]), expected, "{:?}", style);
+        }
+    }
+
+    #[test]
+    fn test_casing_keeps_acronyms_and_exceptions() {
+        let exceptions = vec!["iPhone".to_string(), "I".to_string()];
+        assert_eq!(
+            apply_casing("NASA said i like my IPHONE", CaseStyle::Title, &exceptions),
+            "NASA Said I Like My iPhone"
+        );
+        assert_eq!(
+            apply_casing(
+                "ok. then i asked NASA. what's next?",
+                CaseStyle::Sentence,
+                &exceptions
+            ),
+            "Ok. Then I asked NASA. What's next?"
+        );
+        assert_eq!(
+            apply_casing("ÉCOLE straße", CaseStyle::Title, &[]),
+            "ÉCOLE Straße"
+        );
+    }
+
+    #[test]
+    fn test_casing_is_the_same_in_chunks() {
+        let text = "hello there. it's NASA's 2nd-stage test!  done";
+        for style in [
+            CaseStyle::Lower,
+            CaseStyle::Upper,
+            CaseStyle::Title,
+            CaseStyle::Sentence,
+            CaseStyle::Snake,
+            CaseStyle::Camel,
+        ] {
+            let whole = apply_casing(text, style, &[]);
+            for chunk_len in 1..6 {
+                assert_eq!(
+                    cased_in_chunks(text, style, chunk_len),
+                    whole,
+                    "{:?}",
+                    style
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_repetition_ratio_flags_decoder_loops() {
+        assert_eq!(repetition_ratio("the the the the the the"), 1.0);
+        assert_eq!(
+            repetition_ratio("Thank you. Thank you. Thank you. Thank you."),
+            1.0
+        );
+        let ratio = repetition_ratio("so I said we should go go go go go go go now");
+        assert!(ratio > 0.5 && ratio < 1.0, "{}", ratio);
+    }
+
+    #[test]
+    fn test_repetition_ratio_ignores_normal_speech() {
+        assert_eq!(repetition_ratio(""), 0.0);
+        assert_eq!(
+            repetition_ratio("the cat sat on the mat and the dog sat on the rug"),
+            0.0
+        );
+        // Two repeats are normal emphasis, not a loop.
+        assert_eq!(repetition_ratio("very very good, no no"), 0.0);
+    }
 }
@@ -28,6 +28,8 @@ pub trait VoiceActivityDetector: Send + Sync {
 
 mod silero;
 mod smoothed;
+mod trim;
 
-pub use silero::SileroVad;
+pub use silero::{SileroVad, SILERO_FRAME_SAMPLES};
 pub use smoothed::SmoothedVad;
+pub use trim::speech_bounds;
@@ -7,7 +7,7 @@ use super::{VadFrame, VoiceActivityDetector};
 use crate::audio_toolkit::constants;
 
 const SILERO_FRAME_MS: u32 = 30;
-const SILERO_FRAME_SAMPLES: usize =
+pub const SILERO_FRAME_SAMPLES: usize =
     (constants::WHISPER_SAMPLE_RATE * SILERO_FRAME_MS / 1000) as usize;
 
 pub struct SileroVad {
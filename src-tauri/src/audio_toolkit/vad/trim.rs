@@ -0,0 +1,114 @@
+use anyhow::Result;
+use std::ops::Range;
+
+use super::VoiceActivityDetector;
+
+/// Range of `samples` between the first and last frame the VAD reports as
+/// speech, widened by `padding` samples on each side. Returns None when no
+/// frame contains speech. A trailing partial frame is zero-padded.
+pub fn speech_bounds(
+    vad: &mut dyn VoiceActivityDetector,
+    samples: &[f32],
+    frame_len: usize,
+    padding: usize,
+) -> Result<Option<Range<usize>>> {
+    if frame_len == 0 {
+        anyhow::bail!("frame length must be positive");
+    }
+
+    vad.reset();
+    let mut first_speech: Option<usize> = None;
+    let mut last_speech_end = 0;
+    let mut padded = vec![0.0; frame_len];
+
+    for (index, chunk) in samples.chunks(frame_len).enumerate() {
+        let frame = if chunk.len() == frame_len {
+            chunk
+        } else {
+            padded.fill(0.0);
+            padded[..chunk.len()].copy_from_slice(chunk);
+            &padded
+        };
+        if vad.is_voice(frame)? {
+            let start = index * frame_len;
+            first_speech.get_or_insert(start);
+            last_speech_end = start + chunk.len();
+        }
+    }
+
+    Ok(first_speech
+        .map(|start| start.saturating_sub(padding)..(last_speech_end + padding).min(samples.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::VadFrame;
+    use super::*;
+
+    const SAMPLE_RATE: usize = 16_000;
+    const FRAME: usize = 480;
+
+    /// Energy detector standing in for Silero in tests.
+    struct EnergyVad;
+
+    impl VoiceActivityDetector for EnergyVad {
+        fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+            Ok(if rms > 0.05 {
+                VadFrame::Speech(frame)
+            } else {
+                VadFrame::Noise
+            })
+        }
+    }
+
+    fn tone(seconds: f32) -> Vec<f32> {
+        let len = (SAMPLE_RATE as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| {
+                0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / SAMPLE_RATE as f32).sin()
+            })
+            .collect()
+    }
+
+    fn silence(seconds: f32) -> Vec<f32> {
+        vec![0.0; (SAMPLE_RATE as f32 * seconds) as usize]
+    }
+
+    #[test]
+    fn trims_silence_around_a_tone_and_keeps_padding() {
+        let lead = silence(1.5);
+        let body = tone(0.96);
+        let tail = silence(0.9);
+        let samples = [lead.clone(), body.clone(), tail].concat();
+        let padding = SAMPLE_RATE / 5;
+
+        let range = speech_bounds(&mut EnergyVad, &samples, FRAME, padding)
+            .unwrap()
+            .unwrap();
+
+        // The tone starts and ends on frame boundaries here, so the bounds are exact.
+        assert_eq!(range.start, lead.len() - padding);
+        assert_eq!(range.end, lead.len() + body.len() + padding);
+    }
+
+    #[test]
+    fn padding_is_clamped_to_the_buffer() {
+        let samples = [tone(0.3), silence(0.05)].concat();
+        let range = speech_bounds(&mut EnergyVad, &samples, FRAME, SAMPLE_RATE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(range, 0..samples.len());
+    }
+
+    #[test]
+    fn all_silence_has_no_speech_bounds() {
+        let samples = silence(2.0);
+        assert!(speech_bounds(&mut EnergyVad, &samples, FRAME, 3200)
+            .unwrap()
+            .is_none());
+        assert!(speech_bounds(&mut EnergyVad, &[], FRAME, 3200)
+            .unwrap()
+            .is_none());
+    }
+}
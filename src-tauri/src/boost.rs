@@ -0,0 +1,256 @@
+//! One-shot "boost accuracy" override for the next dictation.
+//!
+//! Arming keeps a provider/model (and optional post-processing prompt) in
+//! memory. The next recording start consumes it and applies it to the
+//! settings snapshot captured for that recording only, so profile language
+//! and prompts still apply and nothing is written to settings. A boost that
+//! is not used expires after `boost_expiry_secs`.
+
+use log::info;
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::managers::model::ModelManager;
+use crate::session_manager::{self, SessionPhase};
+use crate::settings::{self, AppSettings, BoostOverride, TranscriptionProvider};
+
+pub const BOOST_BINDING_ID: &str = "boost_next_transcription";
+pub const BOOST_STATE_CHANGED_EVENT: &str = "boost-state-changed";
+
+struct ArmedBoost {
+    boost: BoostOverride,
+    expires_at: Instant,
+}
+
+static ARMED: Mutex<Option<ArmedBoost>> = Mutex::new(None);
+/// Operation that consumed the last boost, for the overlay badge.
+static BOOSTED_OPERATION: Mutex<Option<u64>> = Mutex::new(None);
+// Bumped on every change so a stale expiry timer never disarms a newer boost.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct BoostStatus {
+    pub armed: bool,
+    pub boost: Option<BoostOverride>,
+    /// Seconds until the armed boost expires.
+    pub remaining_secs: Option<u64>,
+}
+
+fn current_status(now: Instant) -> BoostStatus {
+    let mut armed = ARMED.lock().unwrap_or_else(|e| e.into_inner());
+    if armed.as_ref().is_some_and(|a| a.expires_at <= now) {
+        *armed = None;
+    }
+    BoostStatus {
+        armed: armed.is_some(),
+        boost: armed.as_ref().map(|a| a.boost.clone()),
+        remaining_secs: armed
+            .as_ref()
+            .map(|a| a.expires_at.saturating_duration_since(now).as_secs()),
+    }
+}
+
+pub fn status() -> BoostStatus {
+    current_status(Instant::now())
+}
+
+/// The armed boost without consuming it.
+pub fn armed_override() -> Option<BoostOverride> {
+    status().boost
+}
+
+/// Whether the overlay should show the boost badge: a boost is armed or the
+/// current recording was started with one.
+pub fn badge_visible() -> bool {
+    if status().armed {
+        return true;
+    }
+    let event = session_manager::current_session_event();
+    let boosted = *BOOSTED_OPERATION.lock().unwrap_or_else(|e| e.into_inner());
+    event.state != SessionPhase::Idle && boosted.is_some() && event.operation_id == boosted
+}
+
+fn notify_changed(app: &AppHandle, status: &BoostStatus) {
+    let _ = app.emit(BOOST_STATE_CHANGED_EVENT, status.clone());
+}
+
+/// Checks that a boost can actually be used with the current settings.
+fn validate(app: &AppHandle, settings: &AppSettings, boost: &BoostOverride) -> Result<(), String> {
+    if boost.is_empty() {
+        return Err("No boost provider, model or prompt is configured".to_string());
+    }
+    let provider = boost.provider.unwrap_or(settings.transcription_provider);
+    if provider == TranscriptionProvider::Local {
+        if let Some(model) = boost.model() {
+            let downloaded = app
+                .state::<Arc<ModelManager>>()
+                .get_model_info(model)
+                .is_some_and(|info| info.is_downloaded);
+            if !downloaded {
+                return Err(format!("Boost model '{}' is not downloaded", model));
+            }
+        }
+    }
+    if let Some(prompt_id) = &boost.post_process_prompt_id {
+        let known = settings
+            .post_process_prompts
+            .iter()
+            .any(|p| &p.id == prompt_id);
+        if !known {
+            return Err(format!("Boost prompt '{}' was not found", prompt_id));
+        }
+    }
+    Ok(())
+}
+
+/// Arms `boost` (or the configured boost when None) for the next recording.
+pub fn arm(app: &AppHandle, boost: Option<BoostOverride>) -> Result<BoostStatus, String> {
+    let settings = settings::get_settings(app);
+    let boost = boost.unwrap_or_else(|| settings.boost_override.clone());
+    validate(app, &settings, &boost)?;
+
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let expiry = Duration::from_secs(u64::from(settings.boost_expiry_secs.max(1)));
+    *ARMED.lock().unwrap_or_else(|e| e.into_inner()) = Some(ArmedBoost {
+        boost: boost.clone(),
+        expires_at: Instant::now() + expiry,
+    });
+    info!(
+        "Boost armed for the next dictation (provider: {:?}, model: {:?}, prompt: {:?})",
+        boost.provider, boost.model, boost.post_process_prompt_id
+    );
+
+    let expiry_app = app.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(expiry);
+        if GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let status = status();
+        if !status.armed {
+            info!("Boost expired without a recording");
+            notify_changed(&expiry_app, &status);
+        }
+    });
+
+    let status = status();
+    notify_changed(app, &status);
+    Ok(status)
+}
+
+pub fn disarm(app: &AppHandle) -> BoostStatus {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    let was_armed = ARMED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .is_some();
+    if was_armed {
+        info!("Boost disarmed");
+    }
+    let status = status();
+    notify_changed(app, &status);
+    status
+}
+
+/// Takes the armed boost for the recording `operation_id`. The boost is
+/// cleared whether or not the recording completes, so a cancelled boosted
+/// recording never leaves it armed.
+pub fn consume(app: &AppHandle, operation_id: u64) -> Option<BoostOverride> {
+    let boost = {
+        let mut armed = ARMED.lock().unwrap_or_else(|e| e.into_inner());
+        let boost = armed
+            .take()
+            .filter(|a| a.expires_at > Instant::now())
+            .map(|a| a.boost);
+        boost?
+    };
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    *BOOSTED_OPERATION.lock().unwrap_or_else(|e| e.into_inner()) = Some(operation_id);
+    info!("Boost applied to operation {}", operation_id);
+    notify_changed(app, &status());
+    Some(boost)
+}
+
+/// Short description for the overlay, e.g. "Boost: large-v3".
+pub fn overlay_label(boost: &BoostOverride) -> String {
+    let target = boost
+        .model()
+        .map(str::to_string)
+        .or_else(|| boost.provider.map(|p| p.as_str().to_string()))
+        .unwrap_or_else(|| "prompt".to_string());
+    format!("Boost: {}", target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_sets_model_of_boosted_provider() {
+        let mut settings = settings::get_default_settings();
+        settings.transcription_provider = TranscriptionProvider::Local;
+        let selected_model = settings.selected_model.clone();
+        let boost = BoostOverride {
+            provider: Some(TranscriptionProvider::RemoteSoniox),
+            model: Some(" stt-async-v3 ".to_string()),
+            post_process_prompt_id: None,
+        };
+
+        boost.apply(&mut settings);
+
+        assert_eq!(
+            settings.transcription_provider,
+            TranscriptionProvider::RemoteSoniox
+        );
+        assert_eq!(settings.soniox_model, "stt-async-v3");
+        assert_eq!(settings.selected_model, selected_model);
+    }
+
+    #[test]
+    fn apply_prompt_enables_post_processing() {
+        let mut settings = settings::get_default_settings();
+        settings.post_process_enabled = false;
+        let boost = BoostOverride {
+            post_process_prompt_id: Some("careful".to_string()),
+            ..BoostOverride::default()
+        };
+
+        boost.apply(&mut settings);
+
+        assert!(settings.post_process_enabled);
+        assert_eq!(
+            settings.post_process_selected_prompt_id.as_deref(),
+            Some("careful")
+        );
+    }
+
+    #[test]
+    fn blank_model_counts_as_empty() {
+        let boost = BoostOverride {
+            model: Some("  ".to_string()),
+            ..BoostOverride::default()
+        };
+        assert!(boost.is_empty());
+    }
+
+    #[test]
+    fn expired_boost_reads_as_disarmed() {
+        let now = Instant::now();
+        *ARMED.lock().unwrap() = Some(ArmedBoost {
+            boost: BoostOverride::default(),
+            expires_at: now + Duration::from_secs(120),
+        });
+
+        let status = current_status(now);
+        assert!(status.armed);
+        assert_eq!(status.remaining_secs, Some(120));
+
+        assert!(!current_status(now + Duration::from_secs(120)).armed);
+        assert!(ARMED.lock().unwrap().is_none());
+    }
+}
@@ -20,6 +20,11 @@ pub struct CliArgs {
     #[arg(long)]
     pub debug: bool,
 
+    /// Run an isolated instance with its own settings, history, models, logs
+    /// and API keys stored under this directory (e.g. a separate work profile).
+    #[arg(long, value_name = "PATH")]
+    pub profile_dir: Option<PathBuf>,
+
     /// Transcribe this WAV (16 kHz mono) headlessly and exit. Runs the same
     /// batch transcription path as the app: no mic, no VAD, no download.
     #[arg(short = 'f', long, value_name = "WAV")]
@@ -1,12 +1,16 @@
 use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, AutoSubmitKey, ClipboardHandling, PasteMethod};
+use crate::settings::{get_settings, AppSettings, AutoSubmitKey, ClipboardHandling, PasteMethod};
+use crate::stuck_modifiers;
 use enigo::{Direction, Enigo, Key, Keyboard};
 use log::{debug, info, warn};
 use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[cfg(target_os = "linux")]
 use crate::utils::is_wayland;
@@ -33,6 +37,238 @@ struct StreamingPasteSession {
 static STREAMING_PASTE_SESSION: Lazy<Mutex<Option<StreamingPasteSession>>> =
     Lazy::new(|| Mutex::new(None));
 
+// Operation id, paste method and delay resolved when the current streaming
+// session began. Finalization adjustments are queued before the session ends,
+// so they still go out the same way; ending the session clears it.
+static STREAM_PASTE_TARGET: Lazy<Mutex<Option<(u64, PasteMethod, u64)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+// Set by `cancel_current_operation` to stop a `TypeCharacters` paste that is
+// still typing; cleared when the next one starts.
+static TYPING_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+// Only the tail of a stream is ever adjusted; older chunks are dropped.
+const STREAM_LEDGER_MAX_SCALARS: usize = 512;
+
+/// What a single Backspace press erases in the target application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StreamDeletionUnit {
+    /// One press per Unicode scalar value.
+    Scalar,
+    /// One press per UTF-16 code unit. Typed input on Windows arrives as
+    /// UTF-16 units, and targets that accept it erase surrogate halves one
+    /// at a time.
+    Utf16,
+    /// One press per grapheme cluster, and only for clusters that lie fully
+    /// inside the requested range. Never splits an emoji, but may leave
+    /// some of the requested text behind.
+    Grapheme,
+}
+
+struct StreamChunk {
+    text: String,
+    scalars: usize,
+    utf16_units: usize,
+}
+
+impl StreamChunk {
+    fn new(text: String) -> Self {
+        Self {
+            scalars: text.chars().count(),
+            utf16_units: text.encode_utf16().count(),
+            text,
+        }
+    }
+}
+
+/// Text pasted during the current stream, kept per chunk so trailing
+/// adjustments know what they are removing.
+#[derive(Default)]
+struct StreamLedger {
+    chunks: VecDeque<StreamChunk>,
+}
+
+impl StreamLedger {
+    fn record(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.chunks.push_back(StreamChunk::new(text.to_string()));
+        while self.chunks.len() > 1 && self.total_scalars() > STREAM_LEDGER_MAX_SCALARS {
+            self.chunks.pop_front();
+        }
+    }
+
+    fn total_scalars(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.scalars).sum()
+    }
+
+    fn total_utf16_units(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.utf16_units).sum()
+    }
+
+    fn text(&self) -> String {
+        self.chunks
+            .iter()
+            .map(|chunk| chunk.text.as_str())
+            .collect()
+    }
+
+    /// Forgets the last `scalars` characters after they were erased.
+    fn remove_scalars(&mut self, mut scalars: usize) {
+        while scalars > 0 {
+            let Some(last) = self.chunks.back_mut() else {
+                return;
+            };
+            if last.scalars <= scalars {
+                scalars -= last.scalars;
+                self.chunks.pop_back();
+                continue;
+            }
+            let keep = last.scalars - scalars;
+            let byte_index = last
+                .text
+                .char_indices()
+                .nth(keep)
+                .map(|(index, _)| index)
+                .unwrap_or(last.text.len());
+            last.text.truncate(byte_index);
+            last.scalars = keep;
+            last.utf16_units = last.text.encode_utf16().count();
+            return;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.chunks.clear();
+    }
+}
+
+static STREAM_LEDGER: Lazy<Mutex<StreamLedger>> = Lazy::new(|| Mutex::new(StreamLedger::default()));
+
+/// Text inserted by the most recent paste (or all chunks of the current
+/// stream), so "Undo Last Paste" knows exactly what to erase.
+struct LastInsertion {
+    text: String,
+    paste_method: PasteMethod,
+    inserted_at: Instant,
+}
+
+static LAST_INSERTION: Lazy<Mutex<Option<LastInsertion>>> = Lazy::new(|| Mutex::new(None));
+
+fn record_insertion(text: &str, paste_method: PasteMethod, append: bool) {
+    if text.is_empty() || paste_method == PasteMethod::None {
+        return;
+    }
+    let Ok(mut guard) = LAST_INSERTION.lock() else {
+        return;
+    };
+    match guard.as_mut() {
+        Some(last) if append && last.paste_method == paste_method => {
+            last.text.push_str(text);
+            last.inserted_at = Instant::now();
+        }
+        _ => {
+            *guard = Some(LastInsertion {
+                text: text.to_string(),
+                paste_method,
+                inserted_at: Instant::now(),
+            });
+        }
+    }
+}
+
+fn trim_last_insertion(scalars: usize) {
+    let Ok(mut guard) = LAST_INSERTION.lock() else {
+        return;
+    };
+    if let Some(last) = guard.as_mut() {
+        let keep = last.text.chars().count().saturating_sub(scalars);
+        let byte_index = last
+            .text
+            .char_indices()
+            .nth(keep)
+            .map(|(index, _)| index)
+            .unwrap_or(last.text.len());
+        last.text.truncate(byte_index);
+        if last.text.is_empty() {
+            *guard = None;
+        }
+    }
+}
+
+/// Drops the tracked insertion, e.g. when a new recording starts.
+pub fn forget_last_insertion() {
+    if let Ok(mut guard) = LAST_INSERTION.lock() {
+        *guard = None;
+    }
+}
+
+/// Text as the target application stores it: a CRLF pair is a single line
+/// break, erased by one Backspace.
+fn normalize_inserted_line_breaks(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct StreamDeletePlan {
+    key_presses: usize,
+    removed_scalars: usize,
+}
+
+fn stream_deletion_unit(paste_method: PasteMethod, conservative: bool) -> StreamDeletionUnit {
+    if conservative {
+        return StreamDeletionUnit::Grapheme;
+    }
+    match paste_method {
+        PasteMethod::Direct | PasteMethod::TypeCharacters if cfg!(target_os = "windows") => {
+            StreamDeletionUnit::Utf16
+        }
+        _ => StreamDeletionUnit::Scalar,
+    }
+}
+
+/// Plans how to erase the last `scalars` characters of `pasted`. Characters
+/// beyond the known text get one press each, except in grapheme mode where
+/// unverifiable text is left alone.
+fn plan_stream_delete(pasted: &str, scalars: usize, unit: StreamDeletionUnit) -> StreamDeletePlan {
+    let pasted_scalars = pasted.chars().count();
+    let known = scalars.min(pasted_scalars);
+    let unknown = scalars - known;
+    let cut = pasted
+        .char_indices()
+        .nth(pasted_scalars - known)
+        .map(|(index, _)| index)
+        .unwrap_or(pasted.len());
+    let removed = &pasted[cut..];
+
+    match unit {
+        StreamDeletionUnit::Scalar => StreamDeletePlan {
+            key_presses: scalars,
+            removed_scalars: scalars,
+        },
+        StreamDeletionUnit::Utf16 => StreamDeletePlan {
+            key_presses: removed.encode_utf16().count() + unknown,
+            removed_scalars: scalars,
+        },
+        StreamDeletionUnit::Grapheme => {
+            let whole_clusters: Vec<&str> = pasted
+                .grapheme_indices(true)
+                .rev()
+                .take_while(|(index, _)| *index >= cut)
+                .map(|(_, cluster)| cluster)
+                .collect();
+            StreamDeletePlan {
+                key_presses: whole_clusters.len(),
+                removed_scalars: whole_clusters
+                    .iter()
+                    .map(|cluster| cluster.chars().count())
+                    .sum(),
+            }
+        }
+    }
+}
+
 /// Windows-only: Advanced clipboard backup/restore that preserves all formats
 #[cfg(target_os = "windows")]
 mod win_clipboard {
@@ -478,8 +714,7 @@ fn convert_text_for_clipboard(text: &str, convert_lf_to_crlf: bool) -> String {
     #[cfg(target_os = "windows")]
     {
         if convert_lf_to_crlf {
-            // First normalize any existing CRLF to LF, then convert all LF to CRLF.
-            return text.replace("\r\n", "\n").replace('\n', "\r\n");
+            return crate::output_whitespace::WhitespaceRule::ConvertLfToCrlf.apply(text);
         }
     }
     text.to_string()
@@ -505,6 +740,22 @@ fn send_paste_shortcut(enigo: &mut Enigo, paste_method: &PasteMethod) -> Result<
     Ok(())
 }
 
+/// Writes text to the clipboard, through wl-copy on Wayland when installed.
+fn write_clipboard_text(app_handle: &AppHandle, text: &str) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    if is_wayland() && is_wl_copy_available() {
+        match write_clipboard_via_wl_copy(text) {
+            Ok(()) => return Ok(()),
+            Err(e) => warn!("{}; falling back to the clipboard plugin", e),
+        }
+    }
+
+    app_handle
+        .clipboard()
+        .write_text(text)
+        .map_err(|e| e.to_string())
+}
+
 fn paste_via_clipboard_no_restore(
     enigo: &mut Enigo,
     text: &str,
@@ -513,11 +764,9 @@ fn paste_via_clipboard_no_restore(
     paste_delay_ms: u64,
     convert_lf_to_crlf: bool,
 ) -> Result<Instant, String> {
-    let clipboard = app_handle.clipboard();
     let text = convert_text_for_clipboard(text, convert_lf_to_crlf);
 
-    clipboard
-        .write_text(&text)
+    write_clipboard_text(app_handle, &text)
         .map_err(|e| format!("Failed to write to clipboard: {}", e))?;
 
     std::thread::sleep(Duration::from_millis(paste_delay_ms));
@@ -546,14 +795,43 @@ fn wait_for_clipboard_consumer(paste_sent_at: Option<Instant>) {
     }
 }
 
+/// Paste method and delay for the app the text is about to go to, i.e. the
+/// frontmost one.
+fn resolve_paste_method(settings: &AppSettings) -> (PasteMethod, u64) {
+    let app_name = crate::active_app::get_frontmost_app_name().unwrap_or_default();
+    settings.paste_method_for_app(&app_name)
+}
+
+/// Paste method and delay chosen when the current streaming session began.
+fn stream_paste_target(settings: &AppSettings) -> (PasteMethod, u64) {
+    STREAM_PASTE_TARGET
+        .lock()
+        .ok()
+        .and_then(|target| *target)
+        .map(|(_, method, delay_ms)| (method, delay_ms))
+        .unwrap_or((settings.paste_method, settings.paste_delay_ms))
+}
+
+/// Forgets the streaming paste target, only if it belongs to `operation_id`
+/// when one is given.
+fn clear_stream_paste_target(operation_id: Option<u64>) {
+    if let Ok(mut target) = STREAM_PASTE_TARGET.lock() {
+        let matches = match (*target, operation_id) {
+            (Some((target_id, _, _)), Some(id)) => target_id == id,
+            _ => true,
+        };
+        if matches {
+            *target = None;
+        }
+    }
+}
+
 fn restore_streaming_session(
     session: StreamingPasteSession,
     app_handle: &AppHandle,
 ) -> Result<(), String> {
     wait_for_clipboard_consumer(session.last_clipboard_paste_sent_at);
 
-    let clipboard = app_handle.clipboard();
-
     if session.clipboard_handling == ClipboardHandling::RestoreAdvanced {
         #[cfg(target_os = "windows")]
         if let Some(backup) = session.advanced_backup {
@@ -569,8 +847,7 @@ fn restore_streaming_session(
         session.clipboard_handling,
         ClipboardHandling::DontModify | ClipboardHandling::RestoreAdvanced
     ) {
-        clipboard
-            .write_text(&session.text_backup)
+        write_clipboard_text(app_handle, &session.text_backup)
             .map_err(|e| format!("Failed to restore clipboard: {}", e))?;
     }
 
@@ -581,9 +858,17 @@ pub fn begin_streaming_paste_session(
     app_handle: &AppHandle,
     operation_id: u64,
 ) -> Result<(), String> {
+    if let Ok(mut ledger) = STREAM_LEDGER.lock() {
+        ledger.clear();
+    }
+
     let settings = get_settings(app_handle);
+    let (paste_method, paste_delay_ms) = resolve_paste_method(&settings);
+    if let Ok(mut target) = STREAM_PASTE_TARGET.lock() {
+        *target = Some((operation_id, paste_method, paste_delay_ms));
+    }
     if !matches!(
-        settings.paste_method,
+        paste_method,
         PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert
     ) {
         return Ok(());
@@ -606,9 +891,10 @@ pub fn begin_streaming_paste_session(
     }
 
     let clipboard = app_handle.clipboard();
+    let clipboard_handling = crate::privacy_mode::clipboard_handling(settings.clipboard_handling);
 
     let text_backup = if matches!(
-        settings.clipboard_handling,
+        clipboard_handling,
         ClipboardHandling::DontModify | ClipboardHandling::RestoreAdvanced
     ) {
         clipboard.read_text().unwrap_or_default()
@@ -640,9 +926,9 @@ pub fn begin_streaming_paste_session(
 
     let new_session = StreamingPasteSession {
         operation_id,
-        paste_method: settings.paste_method,
-        clipboard_handling: settings.clipboard_handling,
-        paste_delay_ms: settings.paste_delay_ms,
+        paste_method,
+        clipboard_handling,
+        paste_delay_ms,
         convert_lf_to_crlf: settings.convert_lf_to_crlf,
         text_backup,
         last_clipboard_paste_sent_at: None,
@@ -656,6 +942,7 @@ pub fn begin_streaming_paste_session(
 }
 
 pub fn end_streaming_paste_session(app_handle: &AppHandle) -> Result<(), String> {
+    clear_stream_paste_target(None);
     let mut guard = STREAMING_PASTE_SESSION
         .lock()
         .map_err(|_| "Streaming clipboard session lock poisoned".to_string())?;
@@ -670,6 +957,7 @@ pub fn end_streaming_paste_session_if_matches(
     app_handle: &AppHandle,
     operation_id: u64,
 ) -> Result<bool, String> {
+    clear_stream_paste_target(Some(operation_id));
     let mut guard = STREAMING_PASTE_SESSION
         .lock()
         .map_err(|_| "Streaming clipboard session lock poisoned".to_string())?;
@@ -752,8 +1040,7 @@ fn paste_via_clipboard(
         clipboard_handling,
         ClipboardHandling::DontModify | ClipboardHandling::RestoreAdvanced
     ) {
-        clipboard
-            .write_text(&text_backup)
+        write_clipboard_text(app_handle, &text_backup)
             .map_err(|e| format!("Failed to restore clipboard: {}", e))?;
     }
 
@@ -766,21 +1053,15 @@ fn paste_via_clipboard(
 fn try_send_key_combo_linux(paste_method: &PasteMethod) -> Result<bool, String> {
     if is_wayland() {
         // Wayland: prefer wtype, then dotool, then ydotool
-        if is_wtype_available() {
-            info!("Using wtype for key combo");
-            send_key_combo_via_wtype(paste_method)?;
-            return Ok(true);
-        }
-        if is_dotool_available() {
-            info!("Using dotool for key combo");
-            send_key_combo_via_dotool(paste_method)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for key combo");
-            send_key_combo_via_ydotool(paste_method)?;
-            return Ok(true);
-        }
+        return run_wayland_input_tools(
+            "key combo",
+            paste_method,
+            &[
+                ("wtype", is_wtype_available, send_key_combo_via_wtype),
+                ("dotool", is_dotool_available, send_key_combo_via_dotool),
+                ("ydotool", is_ydotool_available, send_key_combo_via_ydotool),
+            ],
+        );
     } else {
         // X11: prefer xdotool, then ydotool
         if is_xdotool_available() {
@@ -804,21 +1085,15 @@ fn try_send_key_combo_linux(paste_method: &PasteMethod) -> Result<bool, String>
 fn try_direct_typing_linux(text: &str) -> Result<bool, String> {
     if is_wayland() {
         // Wayland: prefer wtype, then dotool, then ydotool
-        if is_wtype_available() {
-            info!("Using wtype for direct text input");
-            type_text_via_wtype(text)?;
-            return Ok(true);
-        }
-        if is_dotool_available() {
-            info!("Using dotool for direct text input");
-            type_text_via_dotool(text)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for direct text input");
-            type_text_via_ydotool(text)?;
-            return Ok(true);
-        }
+        return run_wayland_input_tools(
+            "direct text input",
+            text,
+            &[
+                ("wtype", is_wtype_available, type_text_via_wtype),
+                ("dotool", is_dotool_available, type_text_via_dotool),
+                ("ydotool", is_ydotool_available, type_text_via_ydotool),
+            ],
+        );
     } else {
         // X11: prefer xdotool, then ydotool
         if is_xdotool_available() {
@@ -836,6 +1111,51 @@ fn try_direct_typing_linux(text: &str) -> Result<bool, String> {
     Ok(false)
 }
 
+/// A Wayland input tool: name, capability check and the action to run.
+#[cfg(target_os = "linux")]
+type WaylandInputTool<T> = (&'static str, fn() -> bool, fn(&T) -> Result<(), String>);
+
+/// Runs the first usable Wayland input tool that succeeds.
+/// wtype needs the virtual-keyboard protocol, which GNOME and KDE do not
+/// offer, so a failing tool falls through to the next one. Returns `Ok(false)`
+/// when no tool is usable so the caller can fall back to enigo, which only
+/// reaches XWayland windows.
+#[cfg(target_os = "linux")]
+fn run_wayland_input_tools<T: ?Sized>(
+    action: &str,
+    input: &T,
+    tools: &[WaylandInputTool<T>],
+) -> Result<bool, String> {
+    let mut last_error = None;
+    for (name, is_usable, run) in tools {
+        if !is_usable() {
+            continue;
+        }
+        info!("Using {} for {}", name, action);
+        match run(input) {
+            Ok(()) => return Ok(true),
+            Err(e) => {
+                warn!("{} failed for {}: {}", name, action, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) => Err(format!(
+            "{}. On GNOME and KDE, install dotool or run ydotoold with access to /dev/uinput.",
+            e
+        )),
+        None => {
+            warn!(
+                "No Wayland input tool is usable for {}; install wtype, dotool or ydotool (with ydotoold running). Falling back to enigo, which only reaches XWayland windows.",
+                action
+            );
+            Ok(false)
+        }
+    }
+}
+
 /// Check if wtype is available (Wayland text input tool)
 #[cfg(target_os = "linux")]
 fn is_wtype_available() -> bool {
@@ -846,7 +1166,8 @@ fn is_wtype_available() -> bool {
         .unwrap_or(false)
 }
 
-/// Check if dotool is available (another Wayland text input tool)
+/// Check if dotool is available (another Wayland text input tool).
+/// dotool writes to /dev/uinput directly, so the device must be writable.
 #[cfg(target_os = "linux")]
 fn is_dotool_available() -> bool {
     Command::new("which")
@@ -854,9 +1175,14 @@ fn is_dotool_available() -> bool {
         .output()
         .map(|output| output.status.success())
         .unwrap_or(false)
+        && std::fs::OpenOptions::new()
+            .write(true)
+            .open("/dev/uinput")
+            .is_ok()
 }
 
-/// Check if ydotool is available (uinput-based, works on both Wayland and X11)
+/// Check if ydotool is available (uinput-based, works on both Wayland and X11).
+/// The client only talks to the ydotoold daemon, so its socket must exist.
 #[cfg(target_os = "linux")]
 fn is_ydotool_available() -> bool {
     Command::new("which")
@@ -864,6 +1190,64 @@ fn is_ydotool_available() -> bool {
         .output()
         .map(|output| output.status.success())
         .unwrap_or(false)
+        && ydotool_socket_exists()
+}
+
+#[cfg(target_os = "linux")]
+fn ydotool_socket_exists() -> bool {
+    let mut candidates = Vec::new();
+    if let Ok(socket) = std::env::var("YDOTOOL_SOCKET") {
+        candidates.push(std::path::PathBuf::from(socket));
+    }
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        candidates.push(std::path::Path::new(&runtime_dir).join(".ydotool_socket"));
+    }
+    candidates.push(std::path::PathBuf::from("/tmp/.ydotool_socket"));
+    candidates.iter().any(|path| path.exists())
+}
+
+/// Check if wl-copy is available (wl-clipboard, sets the Wayland clipboard)
+#[cfg(target_os = "linux")]
+fn is_wl_copy_available() -> bool {
+    Command::new("which")
+        .arg("wl-copy")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Sets the clipboard through wl-copy, which owns the selection natively
+/// instead of going through XWayland.
+#[cfg(target_os = "linux")]
+fn write_clipboard_via_wl_copy(text: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = Command::new("wl-copy")
+        .arg("--type")
+        .arg("text/plain;charset=utf-8")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn wl-copy: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("Failed to write to wl-copy stdin: {}", e))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait for wl-copy: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("wl-copy failed: {}", stderr));
+    }
+
+    Ok(())
 }
 
 #[cfg(target_os = "linux")]
@@ -1067,6 +1451,124 @@ fn paste_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
     input::paste_text_direct(enigo, text)
 }
 
+/// A single simulated keystroke of a `TypeCharacters` paste.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TypedKey {
+    Char(char),
+    Enter,
+}
+
+/// Splits text into keystrokes. Line breaks (LF, CRLF or a lone CR) become
+/// one Enter press each.
+fn typed_keys(text: &str) -> Vec<TypedKey> {
+    let mut keys = Vec::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                keys.push(TypedKey::Enter);
+            }
+            '\n' => keys.push(TypedKey::Enter),
+            c => keys.push(TypedKey::Char(c)),
+        }
+    }
+    keys
+}
+
+/// Stops a `TypeCharacters` paste that is in progress.
+pub fn cancel_typing() {
+    TYPING_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+/// Types text one keystroke at a time with `delay_ms` between keystrokes, for
+/// targets that ignore clipboard shortcuts. Each character is sent as Unicode
+/// input, so it does not depend on the active keyboard layout.
+fn paste_type_characters(enigo: &mut Enigo, text: &str, delay_ms: u64) -> Result<(), String> {
+    TYPING_CANCELLED.store(false, Ordering::SeqCst);
+    type_characters(enigo, text, delay_ms).map(|_| ())
+}
+
+/// Like [`paste_type_characters`] without resetting the cancel flag. Returns
+/// false when typing was cancelled.
+fn type_characters(enigo: &mut Enigo, text: &str, delay_ms: u64) -> Result<bool, String> {
+    let mut buffer = [0u8; 4];
+    type_keys(
+        &typed_keys(text),
+        Duration::from_millis(delay_ms),
+        |key| match key {
+            TypedKey::Enter => enigo
+                .key(Key::Return, Direction::Click)
+                .map_err(|e| format!("Failed to press Return key: {}", e)),
+            TypedKey::Char(c) => enigo
+                .text(c.encode_utf8(&mut buffer))
+                .map_err(|e| format!("Failed to type character: {}", e)),
+        },
+    )
+}
+
+/// Sends `keys` with `delay` between them, checking `TYPING_CANCELLED` before
+/// each one. Returns false when typing was cancelled.
+fn type_keys(
+    keys: &[TypedKey],
+    delay: Duration,
+    mut send: impl FnMut(TypedKey) -> Result<(), String>,
+) -> Result<bool, String> {
+    for (index, key) in keys.iter().enumerate() {
+        if index > 0 && !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+        if TYPING_CANCELLED.load(Ordering::SeqCst) {
+            info!("Character typing cancelled after {} keystrokes", index);
+            return Ok(false);
+        }
+        send(*key)?;
+    }
+    Ok(true)
+}
+
+/// Types a whole transcription on a worker thread, so the main thread stays
+/// free to handle `cancel_current_operation` while it runs. The usual
+/// post-paste steps follow once typing has finished; a cancelled paste skips them.
+fn spawn_typed_paste(
+    app_handle: AppHandle,
+    text: String,
+    settings: AppSettings,
+    clipboard_handling: ClipboardHandling,
+) -> Result<(), String> {
+    TYPING_CANCELLED.store(false, Ordering::SeqCst);
+    std::thread::Builder::new()
+        .name("type-characters".into())
+        .spawn(move || {
+            let result = (|| {
+                let enigo_state = app_handle
+                    .try_state::<EnigoState>()
+                    .ok_or_else(|| "Enigo state not initialized".to_string())?;
+                let mut enigo = enigo_state
+                    .0
+                    .lock()
+                    .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
+                if type_characters(&mut enigo, &text, settings.type_characters_delay_ms)? {
+                    finish_paste(
+                        &app_handle,
+                        &mut enigo,
+                        &settings,
+                        &text,
+                        PasteMethod::TypeCharacters,
+                        clipboard_handling,
+                    )?;
+                }
+                Ok::<(), String>(())
+            })();
+            if let Err(e) = result {
+                log::error!("Failed to type transcription: {}", e);
+                let _ = app_handle.emit("paste-error", ());
+            }
+        })
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start typing: {}", e))
+}
+
 fn send_return_key(enigo: &mut Enigo, key_type: AutoSubmitKey) -> Result<(), String> {
     match key_type {
         AutoSubmitKey::Enter => {
@@ -1116,9 +1618,8 @@ fn should_send_auto_submit(auto_submit: bool, paste_method: PasteMethod) -> bool
 
 pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
     let settings = get_settings(&app_handle);
-    let paste_method = settings.paste_method;
-    let clipboard_handling = settings.clipboard_handling;
-    let paste_delay_ms = settings.paste_delay_ms;
+    let (paste_method, paste_delay_ms) = resolve_paste_method(&settings);
+    let clipboard_handling = crate::privacy_mode::clipboard_handling(settings.clipboard_handling);
 
     info!(
         "Using paste method: {:?}, clipboard handling: {:?}, delay: {}ms",
@@ -1142,6 +1643,10 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         PasteMethod::Direct => {
             paste_direct(&mut enigo, &text)?;
         }
+        PasteMethod::TypeCharacters => {
+            drop(enigo);
+            return spawn_typed_paste(app_handle.clone(), text, settings, clipboard_handling);
+        }
         PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
             paste_via_clipboard(
                 &mut enigo,
@@ -1155,20 +1660,57 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         }
     }
 
-    if should_send_auto_submit(settings.auto_submit, paste_method) {
+    finish_paste(
+        &app_handle,
+        &mut enigo,
+        &settings,
+        &text,
+        paste_method,
+        clipboard_handling,
+    )
+}
+
+/// Auto-submit, undo bookkeeping, modifier recovery and the optional
+/// clipboard copy that follow a paste.
+fn finish_paste(
+    app_handle: &AppHandle,
+    enigo: &mut Enigo,
+    settings: &AppSettings,
+    text: &str,
+    paste_method: PasteMethod,
+    clipboard_handling: ClipboardHandling,
+) -> Result<(), String> {
+    let auto_submit_sent = should_send_auto_submit(settings.auto_submit, paste_method);
+    if auto_submit_sent {
         std::thread::sleep(Duration::from_millis(50));
-        send_return_key(&mut enigo, settings.auto_submit_key)?;
+        send_return_key(enigo, settings.auto_submit_key)?;
+        // Submitted text has left the input field; there is nothing to undo.
+        forget_last_insertion();
+    } else {
+        record_insertion(text, paste_method, false);
     }
 
+    stuck_modifiers::recover_after_paste(
+        app_handle,
+        enigo,
+        settings.stuck_modifier_recovery_enabled,
+        &stuck_modifiers::synthesized_modifiers(
+            paste_method,
+            auto_submit_sent.then_some(settings.auto_submit_key),
+        ),
+    );
+
     // After pasting, optionally copy to clipboard based on settings
     // (only if CopyToClipboard mode, which means we intentionally want to keep the transcription)
     if clipboard_handling == ClipboardHandling::CopyToClipboard {
-        let clipboard = app_handle.clipboard();
-        clipboard
-            .write_text(&text)
+        write_clipboard_text(app_handle, text)
             .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
     }
 
+    if paste_method != PasteMethod::None {
+        crate::accessibility_announcer::announce_pasted(app_handle, settings, text);
+    }
+
     Ok(())
 }
 
@@ -1201,6 +1743,12 @@ pub fn paste_stream_chunk(text: String, app_handle: AppHandle) -> Result<(), Str
         .lock()
         .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
 
+    let settings = get_settings(&app_handle);
+    let (fallback_method, fallback_delay_ms) = stream_paste_target(&settings);
+    let paste_method = active_stream_config
+        .map(|(paste_method, _, _)| paste_method)
+        .unwrap_or(fallback_method);
+
     if let Some((paste_method, paste_delay_ms, convert_lf_to_crlf)) = active_stream_config {
         match paste_method {
             PasteMethod::None => {
@@ -1209,6 +1757,9 @@ pub fn paste_stream_chunk(text: String, app_handle: AppHandle) -> Result<(), Str
             PasteMethod::Direct => {
                 paste_direct(&mut enigo, &text)?;
             }
+            PasteMethod::TypeCharacters => {
+                paste_type_characters(&mut enigo, &text, settings.type_characters_delay_ms)?;
+            }
             PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
                 // Do not overwrite a previous chunk until the target has had a
                 // chance to read it from the clipboard.
@@ -1232,21 +1783,23 @@ pub fn paste_stream_chunk(text: String, app_handle: AppHandle) -> Result<(), Str
         }
     } else {
         // No active streaming session: preserve safe fallback behavior.
-        let settings = get_settings(&app_handle);
-        match settings.paste_method {
+        match fallback_method {
             PasteMethod::None => {
                 info!("PasteMethod::None selected - skipping streaming chunk paste");
             }
             PasteMethod::Direct => {
                 paste_direct(&mut enigo, &text)?;
             }
+            PasteMethod::TypeCharacters => {
+                paste_type_characters(&mut enigo, &text, settings.type_characters_delay_ms)?;
+            }
             PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
                 paste_via_clipboard(
                     &mut enigo,
                     &text,
                     &app_handle,
-                    &settings.paste_method,
-                    settings.paste_delay_ms,
+                    &fallback_method,
+                    fallback_delay_ms,
                     settings.convert_lf_to_crlf,
                     ClipboardHandling::DontModify,
                 )?
@@ -1256,14 +1809,63 @@ pub fn paste_stream_chunk(text: String, app_handle: AppHandle) -> Result<(), Str
 
     drop(stream_session_guard);
 
+    stuck_modifiers::recover_after_paste(
+        &app_handle,
+        &mut enigo,
+        settings.stuck_modifier_recovery_enabled,
+        &stuck_modifiers::synthesized_modifiers(paste_method, None),
+    );
+    drop(enigo);
+
+    if let Ok(mut ledger) = STREAM_LEDGER.lock() {
+        ledger.record(&text);
+    }
+    record_insertion(&text, paste_method, true);
+
     Ok(())
 }
 
+/// Erases the last `count` characters (Unicode scalars) of the current
+/// stream. The number of Backspace presses depends on the paste method and
+/// on what was actually pasted, so emoji are not left half-deleted.
 pub fn delete_last_stream_characters(app_handle: AppHandle, count: usize) -> Result<(), String> {
     if count == 0 {
         return Ok(());
     }
 
+    let settings = get_settings(&app_handle);
+    let paste_method = STREAMING_PASTE_SESSION
+        .lock()
+        .ok()
+        .and_then(|guard| guard.as_ref().map(|session| session.paste_method))
+        .unwrap_or_else(|| stream_paste_target(&settings).0);
+    if paste_method == PasteMethod::None {
+        return Ok(());
+    }
+
+    let unit = stream_deletion_unit(paste_method, settings.stream_conservative_delete);
+    let mut ledger = STREAM_LEDGER
+        .lock()
+        .map_err(|_| "Stream ledger lock poisoned".to_string())?;
+    let plan = plan_stream_delete(&ledger.text(), count, unit);
+    debug!(
+        "Deleting {} stream characters as {:?}: {} key presses ({} scalars / {} UTF-16 units tracked)",
+        count,
+        unit,
+        plan.key_presses,
+        ledger.total_scalars(),
+        ledger.total_utf16_units()
+    );
+    if plan.removed_scalars < count {
+        info!(
+            "Conservative stream delete kept {} characters to avoid splitting a grapheme",
+            count - plan.removed_scalars
+        );
+    }
+    ledger.remove_scalars(plan.removed_scalars);
+    drop(ledger);
+    trim_last_insertion(plan.removed_scalars);
+
     let enigo_state = app_handle
         .try_state::<EnigoState>()
         .ok_or("Enigo state not initialized")?;
@@ -1272,7 +1874,7 @@ pub fn delete_last_stream_characters(app_handle: AppHandle, count: usize) -> Res
         .lock()
         .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
 
-    for _ in 0..count {
+    for _ in 0..plan.key_presses {
         enigo
             .key(Key::Backspace, Direction::Press)
             .map_err(|e| format!("Failed to press Backspace key: {}", e))?;
@@ -1284,6 +1886,52 @@ pub fn delete_last_stream_characters(app_handle: AppHandle, count: usize) -> Res
     Ok(())
 }
 
+/// Erases the text inserted by the last paste or stream with Backspace, if it
+/// is younger than `max_age`. Returns `Ok(false)` when nothing is tracked.
+pub fn undo_last_paste(app_handle: AppHandle, max_age: Duration) -> Result<bool, String> {
+    let last = LAST_INSERTION
+        .lock()
+        .map_err(|_| "Last insertion lock poisoned".to_string())?
+        .take();
+    let Some(last) = last.filter(|last| last.inserted_at.elapsed() <= max_age) else {
+        return Ok(false);
+    };
+
+    let settings = get_settings(&app_handle);
+    let text = normalize_inserted_line_breaks(&last.text);
+    let unit = stream_deletion_unit(last.paste_method, settings.stream_conservative_delete);
+    let plan = plan_stream_delete(&text, text.chars().count(), unit);
+    debug!(
+        "Undoing last paste: {} key presses for {} characters as {:?}",
+        plan.key_presses,
+        text.chars().count(),
+        unit
+    );
+
+    if let Ok(mut ledger) = STREAM_LEDGER.lock() {
+        ledger.clear();
+    }
+
+    let enigo_state = app_handle
+        .try_state::<EnigoState>()
+        .ok_or("Enigo state not initialized")?;
+    let mut enigo = enigo_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
+
+    for _ in 0..plan.key_presses {
+        enigo
+            .key(Key::Backspace, Direction::Press)
+            .map_err(|e| format!("Failed to press Backspace key: {}", e))?;
+        enigo
+            .key(Key::Backspace, Direction::Release)
+            .map_err(|e| format!("Failed to release Backspace key: {}", e))?;
+    }
+
+    Ok(true)
+}
+
 fn restore_cut_selection_from_backup(app_handle: &AppHandle, text: &str) -> Result<(), String> {
     if text.is_empty() {
         return Ok(());
@@ -1426,6 +2074,18 @@ mod tests {
         assert_eq!(convert_text_for_clipboard(input, true), input);
     }
 
+    #[test]
+    fn undo_counts_crlf_as_one_line_break() {
+        let text = normalize_inserted_line_breaks("one\r\ntwo\r\n");
+        assert_eq!(text, "one\ntwo\n");
+        let plan = plan_stream_delete(&text, text.chars().count(), StreamDeletionUnit::Utf16);
+        assert_eq!(plan.key_presses, 8);
+
+        let emoji = normalize_inserted_line_breaks("hi 👍");
+        let plan = plan_stream_delete(&emoji, emoji.chars().count(), StreamDeletionUnit::Utf16);
+        assert_eq!(plan.key_presses, 5);
+    }
+
     #[test]
     fn auto_submit_requires_setting_enabled() {
         assert!(!should_send_auto_submit(false, PasteMethod::CtrlV));
@@ -1476,4 +2136,219 @@ mod tests {
             Duration::ZERO
         );
     }
+
+    // U+1F44D U+1F3FD: thumbs up with a medium skin tone.
+    const THUMBS_UP_MEDIUM: &str = "\u{1F44D}\u{1F3FD}";
+    // Man, ZWJ, woman, ZWJ, girl.
+    const FAMILY_ZWJ: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    // Regional indicators U and A.
+    const FLAG_UA: &str = "\u{1F1FA}\u{1F1E6}";
+
+    #[test]
+    fn stream_ledger_tracks_scalars_and_utf16_units_per_chunk() {
+        let mut ledger = StreamLedger::default();
+        ledger.record(&format!("ok {}", THUMBS_UP_MEDIUM));
+        ledger.record(&format!("{} ", FLAG_UA));
+
+        assert_eq!(ledger.total_scalars(), 8);
+        assert_eq!(ledger.total_utf16_units(), 12);
+
+        ledger.remove_scalars(4);
+        assert_eq!(ledger.text(), "ok \u{1F44D}");
+        assert_eq!(ledger.total_scalars(), 4);
+        assert_eq!(ledger.total_utf16_units(), 5);
+    }
+
+    #[test]
+    fn utf16_deletion_counts_surrogate_pairs() {
+        let pasted = format!("Nice {} ", THUMBS_UP_MEDIUM);
+        assert_eq!(
+            plan_stream_delete(&pasted, 3, StreamDeletionUnit::Scalar),
+            StreamDeletePlan {
+                key_presses: 3,
+                removed_scalars: 3
+            }
+        );
+        assert_eq!(
+            plan_stream_delete(&pasted, 3, StreamDeletionUnit::Utf16),
+            StreamDeletePlan {
+                key_presses: 5,
+                removed_scalars: 3
+            }
+        );
+
+        let family = format!("hi {}", FAMILY_ZWJ);
+        assert_eq!(
+            plan_stream_delete(&family, 5, StreamDeletionUnit::Utf16).key_presses,
+            8
+        );
+    }
+
+    #[test]
+    fn grapheme_deletion_never_splits_skin_tone_sequences() {
+        let pasted = format!("Nice {} ", THUMBS_UP_MEDIUM);
+        assert_eq!(
+            plan_stream_delete(&pasted, 2, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 1,
+                removed_scalars: 1
+            }
+        );
+        assert_eq!(
+            plan_stream_delete(&pasted, 3, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 2,
+                removed_scalars: 3
+            }
+        );
+    }
+
+    #[test]
+    fn grapheme_deletion_treats_zwj_sequences_and_flags_as_one_cluster() {
+        let family = format!("hi {}", FAMILY_ZWJ);
+        assert_eq!(
+            plan_stream_delete(&family, 5, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 1,
+                removed_scalars: 5
+            }
+        );
+        assert_eq!(
+            plan_stream_delete(&family, 4, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 0,
+                removed_scalars: 0
+            }
+        );
+
+        let flag = format!("{} ", FLAG_UA);
+        assert_eq!(
+            plan_stream_delete(&flag, 2, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 1,
+                removed_scalars: 1
+            }
+        );
+    }
+
+    #[test]
+    fn untracked_characters_are_only_deleted_outside_conservative_mode() {
+        assert_eq!(
+            plan_stream_delete("ab", 4, StreamDeletionUnit::Utf16).key_presses,
+            4
+        );
+        assert_eq!(
+            plan_stream_delete("ab", 4, StreamDeletionUnit::Grapheme),
+            StreamDeletePlan {
+                key_presses: 2,
+                removed_scalars: 2
+            }
+        );
+    }
+
+    #[test]
+    fn typed_keys_turn_line_breaks_into_enter() {
+        assert_eq!(
+            typed_keys("a\nb\r\nc\r"),
+            vec![
+                TypedKey::Char('a'),
+                TypedKey::Enter,
+                TypedKey::Char('b'),
+                TypedKey::Enter,
+                TypedKey::Char('c'),
+                TypedKey::Enter,
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_keys_keep_non_ascii_characters_whole() {
+        assert_eq!(
+            typed_keys("Grüße, Привет"),
+            "Grüße, Привет"
+                .chars()
+                .map(TypedKey::Char)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn typing_stops_between_characters_once_cancelled() {
+        TYPING_CANCELLED.store(false, Ordering::SeqCst);
+        let mut sent = Vec::new();
+
+        let completed = type_keys(&typed_keys("abcd"), Duration::ZERO, |key| {
+            sent.push(key);
+            if sent.len() == 2 {
+                cancel_typing();
+            }
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(!completed);
+        assert_eq!(sent, vec![TypedKey::Char('a'), TypedKey::Char('b')]);
+        TYPING_CANCELLED.store(false, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn deletion_unit_follows_paste_method() {
+        assert_eq!(
+            stream_deletion_unit(PasteMethod::CtrlV, false),
+            StreamDeletionUnit::Scalar
+        );
+        assert_eq!(
+            stream_deletion_unit(PasteMethod::Direct, true),
+            StreamDeletionUnit::Grapheme
+        );
+
+        #[cfg(target_os = "windows")]
+        assert_eq!(
+            stream_deletion_unit(PasteMethod::Direct, false),
+            StreamDeletionUnit::Utf16
+        );
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(
+            stream_deletion_unit(PasteMethod::Direct, false),
+            StreamDeletionUnit::Scalar
+        );
+    }
+    fn last_inserted_text() -> Option<String> {
+        LAST_INSERTION
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|last| last.text.clone())
+    }
+
+    // One test, since the tracked insertion is process-wide.
+    #[test]
+    fn last_insertion_tracks_appends_and_trims() {
+        forget_last_insertion();
+        record_insertion("ignored", PasteMethod::None, false);
+        record_insertion("", PasteMethod::CtrlV, false);
+        assert_eq!(last_inserted_text(), None);
+
+        record_insertion("Hello", PasteMethod::CtrlV, false);
+        record_insertion(" wörld", PasteMethod::CtrlV, true);
+        assert_eq!(last_inserted_text().as_deref(), Some("Hello wörld"));
+
+        // A new paste, or a stream chunk with another method, starts over.
+        record_insertion("next", PasteMethod::Direct, true);
+        assert_eq!(last_inserted_text().as_deref(), Some("next"));
+        record_insertion("Second", PasteMethod::CtrlV, false);
+        assert_eq!(last_inserted_text().as_deref(), Some("Second"));
+
+        // Trimming counts characters, not bytes.
+        record_insertion(" päss", PasteMethod::CtrlV, true);
+        trim_last_insertion(3);
+        assert_eq!(last_inserted_text().as_deref(), Some("Second p"));
+
+        // Erasing everything forgets the insertion.
+        trim_last_insertion(100);
+        assert_eq!(last_inserted_text(), None);
+        trim_last_insertion(1);
+        assert_eq!(last_inserted_text(), None);
+    }
 }
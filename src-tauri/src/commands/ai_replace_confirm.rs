@@ -0,0 +1,28 @@
+//! Tauri commands for the AI Replace accept/reject preview window.
+
+use crate::ai_replace_confirm::{self, AiReplaceConfirmPayload, AiReplaceDecision};
+
+/// Called from the preview window once mounted.
+#[tauri::command]
+#[specta::specta]
+pub fn ai_replace_confirm_get_payload() -> Option<AiReplaceConfirmPayload> {
+    ai_replace_confirm::pending_payload()
+}
+
+/// Pastes the proposed replacement.
+#[tauri::command]
+#[specta::specta]
+pub fn ai_replace_confirm_accept() -> Result<(), String> {
+    if ai_replace_confirm::resolve(AiReplaceDecision::Accepted) {
+        Ok(())
+    } else {
+        Err("No AI Replace result is waiting for confirmation.".to_string())
+    }
+}
+
+/// Discards the proposed replacement; the selection is left untouched.
+#[tauri::command]
+#[specta::specta]
+pub fn ai_replace_confirm_reject() {
+    ai_replace_confirm::resolve(AiReplaceDecision::Rejected);
+}
@@ -1,15 +1,20 @@
 use crate::audio_feedback;
-use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
+use crate::audio_toolkit::audio::{
+    list_input_devices, list_output_devices, probe_input_devices, AudioCaptureSource,
+    MAX_HIGH_PASS_CUTOFF_HZ, MIN_HIGH_PASS_CUTOFF_HZ,
+};
 use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
 use crate::managers::microphone_auto_switch;
 use crate::settings::{
     get_settings, microphone_input_boost_device_key, sanitize_microphone_input_boost_db,
-    write_settings, LiveSoundCaptureSource,
+    write_settings, LiveSoundCaptureSource, MicMode,
 };
-use log::warn;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
 #[cfg(target_os = "windows")]
@@ -48,6 +53,20 @@ pub struct AudioDevice {
     pub is_default: bool,
 }
 
+/// Debug view of the negotiated capture format.
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct ActiveInputFormat {
+    /// "microphone" or "system_output_loopback"
+    pub source: String,
+    pub device_name: String,
+    pub device_sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: String,
+    pub output_sample_rate: u32,
+    /// True when device audio is resampled to `output_sample_rate`
+    pub resampling: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionAccess {
@@ -175,6 +194,35 @@ pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), Str
         .map_err(|e| format!("Failed to update microphone mode: {}", e))
 }
 
+/// Sets or clears (`mode = None`) the always-on/on-demand override for a binding family.
+/// The override takes effect from the next recording started by that family.
+#[tauri::command]
+#[specta::specta]
+pub fn update_microphone_mode_override(
+    app: AppHandle,
+    binding_family: String,
+    mode: Option<MicMode>,
+) -> Result<(), String> {
+    let binding_family = binding_family.trim().to_string();
+    if binding_family.is_empty() {
+        return Err("Binding family must not be empty".to_string());
+    }
+
+    let mut settings = get_settings(&app);
+    match mode {
+        Some(mode) => {
+            settings
+                .microphone_mode_overrides
+                .insert(binding_family, mode);
+        }
+        None => {
+            settings.microphone_mode_overrides.remove(&binding_family);
+        }
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
@@ -364,6 +412,116 @@ pub fn is_recording(app: AppHandle) -> bool {
     audio_manager.is_recording()
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn get_active_input_format(app: AppHandle) -> Option<ActiveInputFormat> {
+    let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+    audio_manager
+        .active_input_format()
+        .map(|format| ActiveInputFormat {
+            source: match format.source {
+                AudioCaptureSource::Microphone => "microphone",
+                AudioCaptureSource::SystemOutputLoopback => "system_output_loopback",
+            }
+            .to_string(),
+            resampling: format.is_resampling(),
+            device_name: format.device_name,
+            device_sample_rate: format.device_sample_rate,
+            channels: format.channels,
+            sample_format: format.sample_format,
+            output_sample_rate: format.output_sample_rate,
+        })
+}
+
+/// How long each device is recorded during `run_audio_diagnostics`.
+const DIAGNOSTICS_CAPTURE: Duration = Duration::from_secs(1);
+/// A device that has not finished its probe by then is reported as timed out.
+const DIAGNOSTICS_DEVICE_TIMEOUT: Duration = Duration::from_secs(2);
+
+static DIAGNOSTICS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// One input device as seen by `run_audio_diagnostics`.
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct AudioDeviceDiagnostics {
+    pub device_name: String,
+    pub is_default: bool,
+    pub open_ok: bool,
+    /// Non-silent samples arrived during the test capture.
+    pub got_audio: bool,
+    pub error: Option<String>,
+    pub sample_rates: Vec<u32>,
+    pub channels: Vec<u16>,
+    pub opened_sample_rate: Option<u32>,
+    /// Windows only: another app holds the device in exclusive mode.
+    pub exclusive_mode_conflict: bool,
+    pub timed_out: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct AudioDiagnosticsReport {
+    pub devices: Vec<AudioDeviceDiagnostics>,
+}
+
+/// Opens each input device for a short capture and reports which ones work.
+/// Refused unless the session is idle, so it never competes with a recording.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_audio_diagnostics(app: AppHandle) -> Result<AudioDiagnosticsReport, String> {
+    {
+        let state = app.state::<crate::session_manager::ManagedSessionState>();
+        let state_guard = crate::session_manager::lock_session_state(&state, "audio_diagnostics");
+        if !matches!(*state_guard, crate::session_manager::SessionState::Idle) {
+            return Err("Audio diagnostics can only run while no recording is active".to_string());
+        }
+    }
+    if DIAGNOSTICS_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("Audio diagnostics are already running".to_string());
+    }
+
+    let result = tauri::async_runtime::spawn_blocking(|| {
+        probe_input_devices(DIAGNOSTICS_CAPTURE, DIAGNOSTICS_DEVICE_TIMEOUT)
+            .map_err(|e| format!("Failed to list audio devices: {}", e))
+    })
+    .await;
+    DIAGNOSTICS_RUNNING.store(false, Ordering::SeqCst);
+    let probes = result.map_err(|e| format!("Audio diagnostics failed: {}", e))??;
+
+    let devices: Vec<AudioDeviceDiagnostics> = probes
+        .into_iter()
+        .map(|probe| AudioDeviceDiagnostics {
+            device_name: probe.name,
+            is_default: probe.is_default,
+            open_ok: probe.open_ok,
+            got_audio: probe.got_audio,
+            error: probe.error,
+            sample_rates: probe.sample_rates,
+            channels: probe.channels,
+            opened_sample_rate: probe.opened_sample_rate,
+            exclusive_mode_conflict: probe.exclusive_mode_conflict,
+            timed_out: probe.timed_out,
+        })
+        .collect();
+
+    info!("Audio diagnostics: {} input device(s)", devices.len());
+    for device in &devices {
+        info!(
+            "Audio diagnostics: '{}' default={} open_ok={} got_audio={} rates={:?} channels={:?} opened_rate={:?} exclusive_conflict={} timed_out={} error={}",
+            device.device_name,
+            device.is_default,
+            device.open_ok,
+            device.got_audio,
+            device.sample_rates,
+            device.channels,
+            device.opened_sample_rate,
+            device.exclusive_mode_conflict,
+            device.timed_out,
+            device.error.as_deref().unwrap_or("-")
+        );
+    }
+
+    Ok(AudioDiagnosticsReport { devices })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_vad_threshold_setting(app: AppHandle, threshold: f32) -> Result<(), String> {
@@ -438,3 +596,68 @@ pub fn change_microphone_noise_cancellation_enabled_setting(
 
     Ok(())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_recover_input_device_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.auto_recover_input_device = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_recording_level_meter_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.recording_level_meter_enabled = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_audio_preprocessing_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.audio_preprocessing_enabled = enabled;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Range `change_audio_preprocessing_high_pass_hz_setting` clamps the cutoff to.
+#[derive(Serialize, Type)]
+pub struct HighPassCutoffLimits {
+    min_hz: u32,
+    max_hz: u32,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_audio_preprocessing_high_pass_limits() -> HighPassCutoffLimits {
+    HighPassCutoffLimits {
+        min_hz: MIN_HIGH_PASS_CUTOFF_HZ,
+        max_hz: MAX_HIGH_PASS_CUTOFF_HZ,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_audio_preprocessing_high_pass_hz_setting(
+    app: AppHandle,
+    cutoff_hz: u32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.audio_preprocessing_high_pass_hz =
+        cutoff_hz.clamp(MIN_HIGH_PASS_CUTOFF_HZ, MAX_HIGH_PASS_CUTOFF_HZ);
+    write_settings(&app, settings);
+    Ok(())
+}
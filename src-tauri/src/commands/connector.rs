@@ -2,7 +2,10 @@
 //!
 //! Commands to control and query the connector server status.
 
-use crate::managers::connector::{active_pending_password, ConnectorManager, ConnectorStatus};
+use crate::managers::connector::{
+    active_pending_password, ConnectorHealthCheck, ConnectorManager, ConnectorStatus,
+    PendingConnectorMessage,
+};
 use crate::settings::{get_settings, write_settings};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use rsa::pkcs8::EncodePublicKey;
@@ -372,6 +375,15 @@ pub fn connector_is_online(manager: State<Arc<ConnectorManager>>) -> bool {
     manager.is_online()
 }
 
+/// Probe the connector now (settings "test connection" button)
+#[tauri::command]
+#[specta::specta]
+pub async fn force_connector_health_check(
+    manager: State<'_, Arc<ConnectorManager>>,
+) -> Result<ConnectorHealthCheck, String> {
+    Ok(manager.force_health_check().await)
+}
+
 /// Start the connector server
 #[tauri::command]
 #[specta::specta]
@@ -408,6 +420,23 @@ pub fn connector_cancel_message(
     manager.cancel_queued_message(&message_id)
 }
 
+/// List text and bundle messages the extension has not acknowledged yet
+#[tauri::command]
+#[specta::specta]
+pub fn get_pending_connector_messages(
+    manager: State<Arc<ConnectorManager>>,
+) -> Vec<PendingConnectorMessage> {
+    manager.pending_messages()
+}
+
+/// Drop a pending message so it is never (re)delivered
+/// Returns false if the message was already acknowledged, expired or discarded
+#[tauri::command]
+#[specta::specta]
+pub fn discard_connector_message(manager: State<Arc<ConnectorManager>>, id: String) -> bool {
+    manager.discard_message(&id)
+}
+
 /// Export the bundled browser connector extension zip into a folder selected by the user.
 #[tauri::command]
 #[specta::specta]
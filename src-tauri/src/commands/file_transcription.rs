@@ -1,34 +1,41 @@
 //! File transcription commands - transcribe audio files to text
 //!
-//! Supports common audio formats: wav, mp3, m4a, ogg, flac, webm
+//! Supports common audio formats: wav, mp3, m4a, ogg, flac, webm, and the
+//! audio track of mp4, mkv, mov and m4v videos.
 //! Uses the same transcription infrastructure as live recording.
 
 use crate::actions::LIVE_SOUND_TRANSCRIPTION_BINDING_ID;
-use crate::audio_toolkit::apply_custom_words;
+use crate::audio_toolkit::{apply_custom_words, encode_wav_bytes};
 use crate::file_transcription_diarization::{
-    create_diarized_transcript_session, normalize_raw_speaker_blocks, reapply_diarized_transcript,
-    render_diarized_transcript, DiarizedTranscriptBlock, DiarizedTranscriptProvider,
-    FileTranscriptionSpeakerNameInput, FileTranscriptionSpeakerSession, RawSpeakerBlock,
+    create_diarized_transcript_session, normalize_raw_speaker_blocks, process_diarized_blocks,
+    reapply_diarized_transcript, render_diarized_transcript, DiarizedTranscriptBlock,
+    DiarizedTranscriptProvider, FileTranscriptionSpeakerNameInput, FileTranscriptionSpeakerSession,
+    RawSpeakerBlock,
 };
 use crate::managers::deepgram_stt::{DeepgramSttManager, DeepgramTranscriptionOptions};
+use crate::managers::history::HistoryManager;
 use crate::managers::remote_stt::RemoteSttManager;
 use crate::managers::soniox_stt::{SonioxAsyncTranscriptionOptions, SonioxSttManager};
-use crate::managers::transcription::{FileTranscriptionChunkTraceEntry, TranscriptionManager};
+use crate::managers::transcription::{
+    FileTranscriptionChunkTraceEntry, FileTranscriptionExecutionMeta, TranscriptionManager,
+    FILE_TRANSCRIPTION_SAMPLE_RATE,
+};
 use crate::session_manager::ManagedSessionState;
 use crate::settings::{
     apply_output_whitespace_policy_for_settings, get_settings, resolve_live_sound_provider,
-    write_settings, AppSettings, FileTranscriptionChunkingMode, TranscriptionProvider,
+    write_settings, AppSettings, FileTranscriptionChunkingMode, TranscriptionProfile,
+    TranscriptionProvider,
 };
 use crate::subtitle::{
     get_format_extension, segments_to_srt, segments_to_vtt, OutputFormat, SubtitleSegment,
 };
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 
 /// Result of a file transcription operation
 #[derive(Serialize, Type)]
@@ -62,6 +69,26 @@ pub struct DeepgramFileTranscriptionOptions {
     pub multichannel: Option<bool>,
 }
 
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FileTranscriptionSegmentStatus {
+    Started,
+    Completed,
+    Failed,
+}
+
+/// Progress of one segment of a long file transcription
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTranscriptionProgressEvent {
+    /// 1-based index of the segment
+    pub segment_index: usize,
+    pub segment_count: usize,
+    pub start_secs: f32,
+    pub end_secs: f32,
+    pub status: FileTranscriptionSegmentStatus,
+}
+
 #[derive(Serialize, Type, Debug, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct FileTranscriptionRecordingState {
@@ -95,6 +122,18 @@ pub fn change_file_transcription_chunking_max_minutes_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_file_transcription_segment_minutes_setting(
+    app: AppHandle,
+    minutes: f32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.file_transcription_segment_minutes = minutes.clamp(1.0, 120.0);
+    write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn reapply_transcription_speaker_names(
@@ -130,6 +169,8 @@ pub fn get_file_transcription_recording_state(
 
 /// Supported audio file extensions
 const SUPPORTED_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "ogg", "flac", "webm"];
+/// Containers decoded by picking their first audio track.
+const VIDEO_CONTAINER_EXTENSIONS: &[&str] = &["mp4", "mkv", "mov", "m4v"];
 const SONIOX_LATEST_ASYNC_MODEL: &str = "stt-async-v5";
 const FILE_TRANSCRIPTION_CANCELLED_MESSAGE: &str = "File transcription was cancelled";
 
@@ -173,8 +214,13 @@ fn ensure_file_transcription_not_cancelled(app: &AppHandle) -> Result<(), String
 
 /// Transcribe an audio file to text
 ///
+/// Video containers are decoded to their first audio track. Files longer than
+/// `file_transcription_segment_minutes` are split at silence and transcribed
+/// segment by segment; a segment that fails leaves an inline marker instead
+/// of failing the whole file.
+///
 /// # Arguments
-/// * `file_path` - Path to the audio file
+/// * `file_path` - Path to the audio or video file
 /// * `profile_id` - Optional transcription profile ID (uses active profile if not specified)
 /// * `save_to_file` - If true, saves the transcription to a file in Documents folder
 /// * `output_format` - Output format: "text" (default), "srt", or "vtt"
@@ -210,12 +256,18 @@ pub async fn transcribe_audio_file(
         .and_then(|e| e.to_str())
         .map(|e| e.to_lowercase())
         .unwrap_or_default();
+    let is_video_container = VIDEO_CONTAINER_EXTENSIONS.contains(&extension.as_str());
 
-    if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+    if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) && !is_video_container {
         return Err(format!(
             "Unsupported audio format: .{}. Supported formats: {}",
             extension,
-            SUPPORTED_EXTENSIONS.join(", ")
+            SUPPORTED_EXTENSIONS
+                .iter()
+                .chain(VIDEO_CONTAINER_EXTENSIONS)
+                .copied()
+                .collect::<Vec<_>>()
+                .join(", ")
         ));
     }
 
@@ -233,56 +285,90 @@ pub async fn transcribe_audio_file(
         custom_words_enabled_override.unwrap_or(settings.custom_words_enabled);
     let should_apply_custom_words = apply_custom_words_enabled && !settings.custom_words.is_empty();
     let mut info_message: Option<String> = None;
-    let mut speaker_session: Option<FileTranscriptionSpeakerSession> = None;
 
     // Perform transcription - get segments for subtitle formats
     let needs_segments = matches!(format, OutputFormat::Srt | OutputFormat::Vtt);
 
     // If model_override is provided, we must use the local manager path with that model.
     // Otherwise, check if we should use remote.
-    let use_remote = model_override.is_none()
-        && settings.transcription_provider == TranscriptionProvider::RemoteOpenAiCompatible;
-    let use_soniox = model_override.is_none()
-        && settings.transcription_provider == TranscriptionProvider::RemoteSoniox;
-    let use_deepgram = model_override.is_none()
-        && settings.transcription_provider == TranscriptionProvider::RemoteDeepgram;
+    let backend = if model_override.is_some() {
+        FileTranscriptionBackend::Local
+    } else {
+        match settings.transcription_provider {
+            TranscriptionProvider::RemoteOpenAiCompatible => FileTranscriptionBackend::Remote,
+            TranscriptionProvider::RemoteSoniox => FileTranscriptionBackend::Soniox,
+            TranscriptionProvider::RemoteDeepgram => FileTranscriptionBackend::Deepgram,
+            _ => FileTranscriptionBackend::Local,
+        }
+    };
     let use_local = file_transcription_uses_local_model(&settings, model_override.as_deref());
+    let use_deepgram = backend == FileTranscriptionBackend::Deepgram;
     if use_local && active_recording_uses_local_model(&app) {
         return Err(
             "Local file transcription is unavailable while a local recording is active."
                 .to_string(),
         );
     }
-    let _local_transcription_guard = if use_local {
-        Some(
-            app.state::<Arc<TranscriptionManager>>()
-                .begin_file_transcription_operation(),
-        )
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    let mut file_transcription_guard = if use_local {
+        Some(tm.begin_file_transcription_operation())
     } else {
         None
     };
-    let samples = if use_deepgram {
-        Vec::new()
-    } else {
-        if use_local {
-            ensure_file_transcription_not_cancelled(&app)?;
+
+    if use_local {
+        ensure_file_transcription_not_cancelled(&app)?;
+    }
+    let samples = match decode_file_audio(&path, is_video_container) {
+        Ok(samples) => samples,
+        // Deepgram decodes the original upload itself, so an audio file the
+        // local decoders cannot read is still sent, just unsegmented.
+        Err(e) if use_deepgram && !is_video_container => {
+            debug!("Sending undecoded audio file to Deepgram: {}", e);
+            Vec::new()
         }
-        let samples = decode_audio_file(&path).map_err(|e| {
+        Err(e) => {
             error!("Failed to decode audio file: {}", e);
-            format!("Failed to decode audio file: {}", e)
-        })?;
-        if use_local {
-            ensure_file_transcription_not_cancelled(&app)?;
+            return Err(format!("Failed to decode audio file: {}", e));
         }
-
-        if samples.is_empty() {
-            return Err("Audio file contains no audio data".to_string());
+    };
+    if use_local {
+        ensure_file_transcription_not_cancelled(&app)?;
+    }
+    if samples.is_empty() && !use_deepgram {
+        return Err("Audio file contains no audio data".to_string());
+    }
+    debug!("Decoded {} samples from audio file", samples.len());
+
+    let max_segment_samples = (settings.file_transcription_segment_minutes.max(1.0)
+        * 60.0
+        * FILE_TRANSCRIPTION_SAMPLE_RATE) as usize;
+    let segment_ranges = if samples.len() > max_segment_samples {
+        // Cancellation is checked between segments for every provider.
+        if file_transcription_guard.is_none() {
+            file_transcription_guard = Some(tm.begin_file_transcription_operation());
         }
-
-        debug!("Decoded {} samples from audio file", samples.len());
-        samples
+        let (frame_size, silent_frames) = match tm.detect_file_silence_frames(&samples) {
+            Ok(frames) => frames,
+            Err(e) => {
+                ensure_file_transcription_not_cancelled(&app)?;
+                warn!("Splitting long file without silence detection: {}", e);
+                (1, Vec::new())
+            }
+        };
+        plan_file_segments(
+            &silent_frames,
+            frame_size,
+            samples.len(),
+            max_segment_samples,
+        )
+    } else {
+        vec![(0, samples.len())]
     };
-    let deepgram_audio_bytes = if use_deepgram {
+    let segmented = segment_ranges.len() > 1;
+    let _file_transcription_guard = file_transcription_guard;
+
+    let deepgram_file_bytes = if use_deepgram && !segmented && !is_video_container {
         let bytes = std::fs::read(&path).map_err(|e| {
             error!("Failed to read audio file for Deepgram: {}", e);
             format!("Failed to read audio file: {}", e)
@@ -292,364 +378,57 @@ pub async fn transcribe_audio_file(
         None
     };
 
-    let mut local_execution_meta = None;
-    let (transcription_text, segments) = if use_remote {
-        // Remote STT - currently doesn't support segments
-        let remote_manager = app.state::<Arc<RemoteSttManager>>();
-        let operation_id = remote_manager.start_operation();
-
-        // Determine translate_to_english: use profile setting if available, otherwise global setting
-        let translate_to_english = profile
-            .as_ref()
-            .map(|p| p.translate_to_english)
-            .unwrap_or(settings.translate_to_english);
-
-        // Determine language: use profile setting if available, otherwise global setting
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
-
-        let prompt = crate::settings::resolve_stt_prompt(
-            profile,
-            &settings.transcription_prompts,
-            &settings.remote_stt.model_id,
-        );
-
-        let text = remote_manager
-            .transcribe_with_operation(
-                operation_id,
-                &settings.remote_stt,
-                &samples,
-                prompt,
-                Some(language.clone()),
-                translate_to_english,
-            )
-            .await
-            .map_err(|e| format!("Remote transcription failed: {}", e))?;
-
-        // Apply custom word corrections
-        let corrected = if should_apply_custom_words {
-            apply_custom_words(
-                &text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-                settings.custom_words_ngram_enabled,
-            )
-        } else {
-            text
-        };
-
-        // Apply filler word filter (if enabled)
-        let corrected = if settings.filler_word_filter_enabled {
-            crate::audio_toolkit::filter_transcription_output(
-                &corrected,
-                language.as_str(),
-                &settings.custom_filler_words,
-            )
-        } else {
-            corrected
-        };
-
-        // For remote STT without segment support, create a single segment
-        // spanning the estimated duration if subtitle format is requested
-        let segs = if needs_segments {
-            // Estimate duration: ~150 words per minute average
-            let word_count = corrected.split_whitespace().count();
-            let estimated_duration = (word_count as f32 / 150.0) * 60.0;
-            Some(vec![SubtitleSegment {
-                start: 0.0,
-                end: estimated_duration.max(1.0),
-                text: corrected.clone(),
-            }])
-        } else {
-            None
-        };
-
-        (corrected, segs)
-    } else if use_soniox {
-        // Soniox remote STT - currently doesn't support segments
-        let soniox_manager = app.state::<Arc<SonioxSttManager>>();
-        let operation_id = soniox_manager.start_operation();
-        let selected_soniox_model = settings.soniox_model.trim();
-        let selected_model_for_message = if selected_soniox_model.is_empty() {
-            "(empty)"
-        } else {
-            selected_soniox_model
-        };
-
-        if selected_soniox_model != SONIOX_LATEST_ASYNC_MODEL {
-            info_message = Some(format!(
-                "Soniox API detected. We are auto switching for the following model: {}. Selected model was '{}'. Reason: Transcribe File uses Soniox async endpoint (/v1/transcriptions), and latest-only mode enforces the latest async model.",
-                SONIOX_LATEST_ASYNC_MODEL, selected_model_for_message
-            ));
-        }
-
-        // Determine language: use profile setting if available, otherwise global setting
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
-
-        let soniox_options_override = soniox_options_override.unwrap_or_default();
-        let language_hints =
-            normalize_soniox_language_hints(soniox_options_override.language_hints.clone())
-                .or_else(|| {
-                    normalize_soniox_language_hints(Some(settings.soniox_language_hints.clone()))
-                });
-        let enable_speaker_diarization = soniox_options_override
-            .enable_speaker_diarization
-            .unwrap_or(settings.soniox_enable_speaker_diarization);
-        let enable_language_identification = soniox_options_override
-            .enable_language_identification
-            .unwrap_or(settings.soniox_enable_language_identification);
-        let soniox_options = SonioxAsyncTranscriptionOptions {
-            language_hints,
-            context: crate::settings::resolve_soniox_context(profile, &settings),
-            enable_speaker_diarization: Some(enable_speaker_diarization),
-            enable_language_identification: Some(enable_language_identification),
-        };
-
-        #[cfg(target_os = "windows")]
-        let api_key = crate::secure_keys::get_soniox_api_key();
-
-        #[cfg(not(target_os = "windows"))]
-        let api_key = String::new();
-
-        let transcript = soniox_manager
-            .transcribe_file_async(
-                Some(operation_id),
-                &api_key,
-                SONIOX_LATEST_ASYNC_MODEL,
-                settings.soniox_timeout_seconds,
-                &samples,
-                Some(language.as_str()),
-                soniox_options,
-            )
-            .await
-            .map_err(|e| format!("Soniox transcription failed: {}", e))?;
-
-        if soniox_manager.is_cancelled(operation_id) {
-            return Err("Soniox transcription was cancelled".to_string());
-        }
-
-        let (corrected, new_speaker_session) = if let Some((rendered_text, session)) =
-            build_diarized_text_output(
-                DiarizedTranscriptProvider::Soniox,
-                transcript.speaker_blocks,
-                &format,
-                save_to_file,
-                &settings,
-                should_apply_custom_words,
-            )? {
-            (rendered_text, session)
-        } else {
-            (
-                apply_transcription_post_processing(
-                    transcript.text,
-                    &settings,
-                    should_apply_custom_words,
-                ),
-                None,
-            )
-        };
-        speaker_session = new_speaker_session;
-
-        // For remote STT without segment support, create a single segment
-        // spanning the estimated duration if subtitle format is requested
-        let segs = if needs_segments {
-            Some(build_estimated_remote_segments(&corrected))
-        } else {
-            None
-        };
-
-        (corrected, segs)
-    } else if use_deepgram {
-        let deepgram_manager = app.state::<Arc<DeepgramSttManager>>();
-        let operation_id = deepgram_manager.start_operation();
-
-        let language = profile
-            .as_ref()
-            .map(|p| p.language.clone())
-            .unwrap_or_else(|| settings.selected_language.clone());
-
-        #[cfg(target_os = "windows")]
-        let api_key = crate::secure_keys::get_deepgram_api_key();
-
-        #[cfg(not(target_os = "windows"))]
-        let api_key = String::new();
-
-        let deepgram_options = DeepgramTranscriptionOptions {
-            interim_results: Some(settings.deepgram_interim_results),
-            smart_format: Some(settings.deepgram_smart_format),
-            diarize: Some(
-                deepgram_options_override
-                    .as_ref()
-                    .and_then(|options| options.diarize)
-                    .unwrap_or(settings.deepgram_diarize),
-            ),
-            multichannel: Some(
-                deepgram_options_override
-                    .as_ref()
-                    .and_then(|options| options.multichannel)
-                    .unwrap_or(false),
-            ),
-        };
-        let audio_bytes = deepgram_audio_bytes
-            .as_deref()
-            .ok_or_else(|| "Deepgram audio payload is missing".to_string())?;
-
-        let transcript = deepgram_manager
-            .transcribe_prerecorded_bytes(
-                Some(operation_id),
-                &api_key,
-                &settings.deepgram_model,
-                settings.deepgram_timeout_seconds,
-                audio_bytes,
-                Some(language.as_str()),
-                deepgram_options,
-            )
-            .await
-            .map_err(|e| format!("Deepgram transcription failed: {}", e))?;
-
-        if deepgram_manager.is_cancelled(operation_id) {
-            return Err("Deepgram transcription was cancelled".to_string());
-        }
-
-        let (corrected, new_speaker_session) = if let Some((rendered_text, session)) =
-            build_diarized_text_output(
-                DiarizedTranscriptProvider::Deepgram,
-                transcript.speaker_blocks,
-                &format,
-                save_to_file,
-                &settings,
-                should_apply_custom_words,
-            )? {
-            (rendered_text, session)
-        } else {
-            (
-                apply_transcription_post_processing(
-                    transcript.text,
-                    &settings,
-                    should_apply_custom_words,
-                ),
-                None,
-            )
-        };
-        speaker_session = new_speaker_session;
-
-        let segs = if needs_segments {
-            Some(build_estimated_remote_segments(&corrected))
-        } else {
-            None
-        };
+    let run = FileTranscriptionRun {
+        settings: &settings,
+        profile,
+        backend,
+        format,
+        needs_segments,
+        // Speaker renaming works on one provider transcript; a segmented
+        // file is several of them.
+        create_speaker_session: !save_to_file && !segmented,
+        model_override: model_override.as_deref(),
+        apply_custom_words_enabled,
+        should_apply_custom_words,
+        soniox_options_override,
+        deepgram_options_override,
+    };
 
-        (corrected, segs)
+    let local_model = if use_local {
+        Some(prepare_local_file_model(&tm, model_override.as_deref())?)
     } else {
-        // Local transcription with segment support
-        let tm = app.state::<Arc<TranscriptionManager>>();
-        let loaded_model_before_override = tm.get_current_model();
-        let override_changed_loaded_model = model_override
-            .as_ref()
-            .is_some_and(|model_id| loaded_model_before_override.as_deref() != Some(model_id));
-
-        let restore_loaded_model = || -> Result<(), String> {
-            if !override_changed_loaded_model {
-                return Ok(());
-            }
-
-            match loaded_model_before_override.as_deref() {
-                Some(previous_model_id) => tm.load_model(previous_model_id).map_err(|e| {
-                    format!(
-                        "Failed to restore previously loaded model '{}': {}",
-                        previous_model_id, e
-                    )
-                }),
-                None => tm
-                    .unload_model()
-                    .map_err(|e| format!("Failed to unload temporary override model: {}", e)),
-            }
-        };
-
-        // If override is provided, load that model first
-        if let Some(model_id) = &model_override {
-            info!("Using override model: {}", model_id);
-            // We need to ensure this model is loaded.
-            // Note: The TM currently holds one loaded model. Switching it here might affect global state,
-            // but file transcription is a distinct action.
-            // However, load_model is async-ish in the background or blocking?
-            // `load_model` in TM is synchronous (blocking) but `initiate_model_load` is async.
-            // We need it loaded NOW.
-
-            // First check if it's already the current one
-            if override_changed_loaded_model {
-                if let Err(load_error) = tm.load_model(model_id) {
-                    let load_error = format!("Failed to load override model: {}", load_error);
-                    return match restore_loaded_model() {
-                        Ok(()) => Err(load_error),
-                        Err(restore_error) => Err(format!("{}; {}", load_error, restore_error)),
-                    };
-                }
-            }
-        } else {
-            // Ensure default model is loaded before transcription
-            tm.initiate_model_load();
-        }
-
-        let transcription_model_id = model_override
-            .as_deref()
-            .unwrap_or(&settings.selected_model);
-        let result = if needs_segments {
-            if let Some(p) = &profile {
-                tm.transcribe_file_with_segments(
-                    samples,
-                    Some(&p.language),
-                    Some(p.translate_to_english),
-                    crate::settings::resolve_stt_prompt(
-                        Some(p),
-                        &settings.transcription_prompts,
-                        transcription_model_id,
-                    ),
-                    apply_custom_words_enabled,
-                )
-                .map_err(|e| format!("Local transcription failed: {}", e))
-            } else {
-                tm.transcribe_file_with_segments(
-                    samples,
-                    None,
-                    None,
-                    None,
-                    apply_custom_words_enabled,
-                )
-                .map_err(|e| format!("Local transcription failed: {}", e))
-            }
-        } else {
-            let text_result = if let Some(p) = &profile {
-                tm.transcribe_file_text(
-                    samples,
-                    Some(&p.language),
-                    Some(p.translate_to_english),
-                    crate::settings::resolve_stt_prompt(
-                        Some(p),
-                        &settings.transcription_prompts,
-                        transcription_model_id,
-                    ),
-                    apply_custom_words_enabled,
-                )
-                .map_err(|e| format!("Local transcription failed: {}", e))
-            } else {
-                tm.transcribe_file_text(samples, None, None, None, apply_custom_words_enabled)
-                    .map_err(|e| format!("Local transcription failed: {}", e))
-            };
-            text_result.map(|(text, meta)| (text, None, meta))
-        };
-
-        restore_loaded_model()?;
-
-        let (text, segs, meta) = result?;
-        local_execution_meta = Some(meta);
-        (text, segs)
+        None
+    };
+    let (outcome, history_samples) = if segmented {
+        let outcome =
+            transcribe_file_in_segments(&app, &run, &samples, &segment_ranges, &mut info_message)
+                .await;
+        (outcome, Some(samples))
+    } else {
+        let outcome =
+            transcribe_file_part(&app, &run, samples, deepgram_file_bytes, &mut info_message).await;
+        (outcome, None)
     };
+    if let Some(local_model) = &local_model {
+        local_model.restore(&tm)?;
+    }
+    let FileTranscriptionPart {
+        text: transcription_text,
+        segments,
+        local_meta: local_execution_meta,
+        speaker_session,
+    } = outcome?;
+
+    if segmented {
+        append_info_message(
+            &mut info_message,
+            format!(
+                "Long file split into {} segments of up to {:.0} min at pauses.",
+                segment_ranges.len(),
+                settings.file_transcription_segment_minutes.max(1.0)
+            ),
+        );
+    }
 
     if let Some(meta) = local_execution_meta
         .as_ref()
@@ -711,6 +490,17 @@ pub async fn transcribe_audio_file(
         ),
     );
 
+    // Long files are worth keeping: the combined transcript goes to history.
+    if let Some(samples) = history_samples {
+        let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
+        if let Err(e) = hm
+            .save_transcription(samples, transcription_text.clone(), false, None, None)
+            .await
+        {
+            error!("Failed to save file transcription to history: {}", e);
+        }
+    }
+
     // Save to file if requested
     let saved_file_path = if save_to_file {
         let output_path = get_output_file_path(&path, format)?;
@@ -735,6 +525,637 @@ pub async fn transcribe_audio_file(
     })
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileTranscriptionBackend {
+    Local,
+    Remote,
+    Soniox,
+    Deepgram,
+}
+
+/// Options shared by every part of one file transcription.
+struct FileTranscriptionRun<'a> {
+    settings: &'a AppSettings,
+    profile: Option<&'a TranscriptionProfile>,
+    backend: FileTranscriptionBackend,
+    format: OutputFormat,
+    needs_segments: bool,
+    create_speaker_session: bool,
+    model_override: Option<&'a str>,
+    apply_custom_words_enabled: bool,
+    should_apply_custom_words: bool,
+    soniox_options_override: Option<SonioxFileTranscriptionOptions>,
+    deepgram_options_override: Option<DeepgramFileTranscriptionOptions>,
+}
+
+/// Transcript of a whole file or of one segment, with subtitle timings
+/// relative to its own start.
+struct FileTranscriptionPart {
+    text: String,
+    segments: Option<Vec<SubtitleSegment>>,
+    local_meta: Option<FileTranscriptionExecutionMeta>,
+    speaker_session: Option<FileTranscriptionSpeakerSession>,
+}
+
+/// The model loaded before an override model was swapped in for a file.
+struct LocalFileModel {
+    previous_model_id: Option<String>,
+    override_changed_loaded_model: bool,
+}
+
+impl LocalFileModel {
+    fn restore(&self, tm: &TranscriptionManager) -> Result<(), String> {
+        if !self.override_changed_loaded_model {
+            return Ok(());
+        }
+
+        match self.previous_model_id.as_deref() {
+            Some(previous_model_id) => tm.load_model(previous_model_id).map_err(|e| {
+                format!(
+                    "Failed to restore previously loaded model '{}': {}",
+                    previous_model_id, e
+                )
+            }),
+            None => tm
+                .unload_model()
+                .map_err(|e| format!("Failed to unload temporary override model: {}", e)),
+        }
+    }
+}
+
+/// Loads the override model (or the selected model) before the first part of
+/// a local file transcription.
+fn prepare_local_file_model(
+    tm: &TranscriptionManager,
+    model_override: Option<&str>,
+) -> Result<LocalFileModel, String> {
+    let loaded_model_before_override = tm.get_current_model();
+    let local_model = LocalFileModel {
+        override_changed_loaded_model: model_override
+            .is_some_and(|model_id| loaded_model_before_override.as_deref() != Some(model_id)),
+        previous_model_id: loaded_model_before_override,
+    };
+
+    // If override is provided, load that model first
+    if let Some(model_id) = model_override {
+        info!("Using override model: {}", model_id);
+        // `load_model` blocks until the model is ready, which is what a file
+        // transcription needs. Swapping the loaded model affects global
+        // state, so it is restored once the file is done.
+        if local_model.override_changed_loaded_model {
+            if let Err(load_error) = tm.load_model(model_id) {
+                let load_error = format!("Failed to load override model: {}", load_error);
+                return match local_model.restore(tm) {
+                    Ok(()) => Err(load_error),
+                    Err(restore_error) => Err(format!("{}; {}", load_error, restore_error)),
+                };
+            }
+        }
+    } else {
+        // Ensure default model is loaded before transcription
+        tm.initiate_model_load();
+    }
+
+    Ok(local_model)
+}
+
+/// Transcribes `samples` segment by segment. Failed segments become inline
+/// markers; the run only fails when it is cancelled or no segment succeeds.
+async fn transcribe_file_in_segments(
+    app: &AppHandle,
+    run: &FileTranscriptionRun<'_>,
+    samples: &[f32],
+    ranges: &[(usize, usize)],
+    info_message: &mut Option<String>,
+) -> Result<FileTranscriptionPart, String> {
+    let segment_count = ranges.len();
+    let mut texts = Vec::with_capacity(segment_count);
+    let mut subtitle_segments = run.needs_segments.then(Vec::new);
+    let mut local_meta: Option<FileTranscriptionExecutionMeta> = None;
+    let mut failed_segments = 0usize;
+    let mut last_error = None;
+
+    for (index, &(start, end)) in ranges.iter().enumerate() {
+        ensure_file_transcription_not_cancelled(app)?;
+        let start_secs = start as f32 / FILE_TRANSCRIPTION_SAMPLE_RATE;
+        let end_secs = end as f32 / FILE_TRANSCRIPTION_SAMPLE_RATE;
+        emit_file_transcription_progress(
+            app,
+            FileTranscriptionProgressEvent {
+                segment_index: index + 1,
+                segment_count,
+                start_secs,
+                end_secs,
+                status: FileTranscriptionSegmentStatus::Started,
+            },
+        );
+
+        let result =
+            transcribe_file_part(app, run, samples[start..end].to_vec(), None, info_message).await;
+        // A cancelled provider request also comes back as an error; it must
+        // stop the run rather than be recorded as a failed segment.
+        ensure_file_transcription_not_cancelled(app)?;
+
+        let status = match result {
+            Ok(part) => {
+                let text = part.text.trim();
+                if !text.is_empty() {
+                    texts.push(text.to_string());
+                }
+                if let (Some(all), Some(segments)) = (subtitle_segments.as_mut(), part.segments) {
+                    all.extend(segments.into_iter().map(|mut segment| {
+                        segment.start += start_secs;
+                        segment.end += start_secs;
+                        segment
+                    }));
+                }
+                if let Some(meta) = part.local_meta {
+                    merge_local_execution_meta(&mut local_meta, meta, start_secs);
+                }
+                FileTranscriptionSegmentStatus::Completed
+            }
+            Err(e) => {
+                warn!(
+                    "File transcription segment {}/{} ({}–{}) failed: {}",
+                    index + 1,
+                    segment_count,
+                    format_segment_time(start_secs),
+                    format_segment_time(end_secs),
+                    e
+                );
+                let marker = failed_segment_marker(start_secs, end_secs);
+                if let Some(all) = subtitle_segments.as_mut() {
+                    all.push(SubtitleSegment {
+                        start: start_secs,
+                        end: end_secs,
+                        text: marker.clone(),
+                    });
+                }
+                texts.push(marker);
+                failed_segments += 1;
+                last_error = Some(e);
+                FileTranscriptionSegmentStatus::Failed
+            }
+        };
+        emit_file_transcription_progress(
+            app,
+            FileTranscriptionProgressEvent {
+                segment_index: index + 1,
+                segment_count,
+                start_secs,
+                end_secs,
+                status,
+            },
+        );
+    }
+
+    if failed_segments == segment_count {
+        return Err(last_error.unwrap_or_else(|| "File transcription failed".to_string()));
+    }
+    if failed_segments > 0 {
+        append_info_message(
+            info_message,
+            format!(
+                "{} of {} segments could not be transcribed and are marked in the text.",
+                failed_segments, segment_count
+            ),
+        );
+    }
+
+    Ok(FileTranscriptionPart {
+        text: texts.join("\n\n"),
+        segments: subtitle_segments,
+        local_meta,
+        speaker_session: None,
+    })
+}
+
+fn merge_local_execution_meta(
+    total: &mut Option<FileTranscriptionExecutionMeta>,
+    meta: FileTranscriptionExecutionMeta,
+    offset_secs: f32,
+) {
+    let total = total.get_or_insert_with(FileTranscriptionExecutionMeta::default);
+    total.used_vad_chunking |= meta.used_vad_chunking;
+    total.chunk_count += meta.chunk_count;
+    for mut entry in meta.chunking_trace {
+        entry.chunk_index = total.chunking_trace.len() + 1;
+        entry.start_secs += offset_secs;
+        entry.end_secs += offset_secs;
+        total.chunking_trace.push(entry);
+    }
+}
+
+/// Transcribes one part of a file with the run's provider. Deepgram gets
+/// `deepgram_file_bytes` when the original upload can be sent as is, and a
+/// WAV of `samples` otherwise.
+async fn transcribe_file_part(
+    app: &AppHandle,
+    run: &FileTranscriptionRun<'_>,
+    samples: Vec<f32>,
+    deepgram_file_bytes: Option<Vec<u8>>,
+    info_message: &mut Option<String>,
+) -> Result<FileTranscriptionPart, String> {
+    let settings = run.settings;
+    let profile = run.profile;
+    let needs_segments = run.needs_segments;
+    let should_apply_custom_words = run.should_apply_custom_words;
+
+    let part = match run.backend {
+        FileTranscriptionBackend::Remote => {
+            // Remote STT - currently doesn't support segments
+            let remote_manager = app.state::<Arc<RemoteSttManager>>();
+            let operation_id = remote_manager.start_operation();
+
+            // Determine translate_to_english: use profile setting if available, otherwise global setting
+            let translate_to_english = profile
+                .as_ref()
+                .map(|p| p.translate_to_english)
+                .unwrap_or(settings.translate_to_english);
+
+            // Determine language: use profile setting if available, otherwise global setting
+            let language = profile
+                .as_ref()
+                .map(|p| p.language.clone())
+                .unwrap_or_else(|| settings.selected_language.clone());
+
+            let prompt = crate::settings::resolve_stt_prompt(
+                settings,
+                profile,
+                &settings.remote_stt.model_id,
+            );
+
+            let text = remote_manager
+                .transcribe_with_operation(
+                    operation_id,
+                    &settings.remote_stt,
+                    &samples,
+                    prompt,
+                    Some(language.clone()),
+                    translate_to_english,
+                )
+                .await
+                .map_err(|e| format!("Remote transcription failed: {}", e))?;
+
+            // Apply custom word corrections
+            let corrected = if should_apply_custom_words {
+                apply_custom_words(
+                    &text,
+                    &settings.custom_words,
+                    settings.word_correction_threshold,
+                    settings.custom_words_ngram_enabled,
+                )
+            } else {
+                text
+            };
+
+            // Apply filler word filter (if enabled)
+            let corrected = if settings.filler_word_filter_enabled {
+                crate::audio_toolkit::filter_transcription_output(
+                    &corrected,
+                    language.as_str(),
+                    &settings.custom_filler_words,
+                )
+            } else {
+                corrected
+            };
+
+            // For remote STT without segment support, create a single segment
+            // spanning the estimated duration if subtitle format is requested
+            let segs = if needs_segments {
+                // Estimate duration: ~150 words per minute average
+                let word_count = corrected.split_whitespace().count();
+                let estimated_duration = (word_count as f32 / 150.0) * 60.0;
+                Some(vec![SubtitleSegment {
+                    start: 0.0,
+                    end: estimated_duration.max(1.0),
+                    text: corrected.clone(),
+                }])
+            } else {
+                None
+            };
+
+            FileTranscriptionPart {
+                text: corrected,
+                segments: segs,
+                local_meta: None,
+                speaker_session: None,
+            }
+        }
+        FileTranscriptionBackend::Soniox => {
+            // Soniox remote STT - currently doesn't support segments
+            let soniox_manager = app.state::<Arc<SonioxSttManager>>();
+            let operation_id = soniox_manager.start_operation();
+            let selected_soniox_model = settings.soniox_model.trim();
+            let selected_model_for_message = if selected_soniox_model.is_empty() {
+                "(empty)"
+            } else {
+                selected_soniox_model
+            };
+
+            if selected_soniox_model != SONIOX_LATEST_ASYNC_MODEL {
+                *info_message = Some(format!(
+                    "Soniox API detected. We are auto switching for the following model: {}. Selected model was '{}'. Reason: Transcribe File uses Soniox async endpoint (/v1/transcriptions), and latest-only mode enforces the latest async model.",
+                    SONIOX_LATEST_ASYNC_MODEL, selected_model_for_message
+                ));
+            }
+
+            // Determine language: use profile setting if available, otherwise global setting
+            let language = profile
+                .as_ref()
+                .map(|p| p.language.clone())
+                .unwrap_or_else(|| settings.selected_language.clone());
+
+            let soniox_options_override = run.soniox_options_override.clone().unwrap_or_default();
+            let language_hints =
+                normalize_soniox_language_hints(soniox_options_override.language_hints.clone())
+                    .or_else(|| {
+                        normalize_soniox_language_hints(Some(
+                            settings.soniox_language_hints.clone(),
+                        ))
+                    });
+            let enable_speaker_diarization = soniox_options_override
+                .enable_speaker_diarization
+                .unwrap_or(settings.soniox_enable_speaker_diarization);
+            let enable_language_identification = soniox_options_override
+                .enable_language_identification
+                .unwrap_or(settings.soniox_enable_language_identification);
+            let soniox_options = SonioxAsyncTranscriptionOptions {
+                language_hints,
+                context: crate::settings::resolve_soniox_context(profile, settings),
+                enable_speaker_diarization: Some(enable_speaker_diarization),
+                enable_language_identification: Some(enable_language_identification),
+            };
+
+            #[cfg(target_os = "windows")]
+            let api_key = crate::secure_keys::get_soniox_api_key();
+
+            #[cfg(not(target_os = "windows"))]
+            let api_key = String::new();
+
+            let transcript = soniox_manager
+                .transcribe_file_async(
+                    Some(operation_id),
+                    &api_key,
+                    SONIOX_LATEST_ASYNC_MODEL,
+                    settings.soniox_timeout_seconds,
+                    &samples,
+                    Some(language.as_str()),
+                    soniox_options,
+                )
+                .await
+                .map_err(|e| format!("Soniox transcription failed: {}", e))?;
+
+            if soniox_manager.is_cancelled(operation_id) {
+                return Err("Soniox transcription was cancelled".to_string());
+            }
+
+            let (corrected, speaker_session) = if let Some((rendered_text, session)) =
+                build_diarized_text_output(
+                    DiarizedTranscriptProvider::Soniox,
+                    transcript.speaker_blocks,
+                    &run.format,
+                    !run.create_speaker_session,
+                    settings,
+                    should_apply_custom_words,
+                )? {
+                (rendered_text, session)
+            } else {
+                (
+                    apply_transcription_post_processing(
+                        transcript.text,
+                        settings,
+                        should_apply_custom_words,
+                    ),
+                    None,
+                )
+            };
+
+            // For remote STT without segment support, create a single segment
+            // spanning the estimated duration if subtitle format is requested
+            let segs = if needs_segments {
+                Some(build_estimated_remote_segments(&corrected))
+            } else {
+                None
+            };
+
+            FileTranscriptionPart {
+                text: corrected,
+                segments: segs,
+                local_meta: None,
+                speaker_session,
+            }
+        }
+        FileTranscriptionBackend::Deepgram => {
+            let deepgram_manager = app.state::<Arc<DeepgramSttManager>>();
+            let operation_id = deepgram_manager.start_operation();
+
+            let language = profile
+                .as_ref()
+                .map(|p| p.language.clone())
+                .unwrap_or_else(|| settings.selected_language.clone());
+
+            #[cfg(target_os = "windows")]
+            let api_key = crate::secure_keys::get_deepgram_api_key();
+
+            #[cfg(not(target_os = "windows"))]
+            let api_key = String::new();
+
+            let deepgram_options_override = run.deepgram_options_override.as_ref();
+            let deepgram_options = DeepgramTranscriptionOptions {
+                interim_results: Some(settings.deepgram_interim_results),
+                smart_format: Some(settings.deepgram_smart_format),
+                diarize: Some(
+                    deepgram_options_override
+                        .and_then(|options| options.diarize)
+                        .unwrap_or(settings.deepgram_diarize),
+                ),
+                multichannel: Some(
+                    deepgram_options_override
+                        .and_then(|options| options.multichannel)
+                        .unwrap_or(false),
+                ),
+            };
+            let audio_bytes = match deepgram_file_bytes {
+                Some(bytes) => bytes,
+                None if !samples.is_empty() => encode_wav_bytes(&samples)
+                    .map_err(|e| format!("Failed to encode audio for Deepgram: {}", e))?,
+                None => return Err("Deepgram audio payload is missing".to_string()),
+            };
+
+            let transcript = deepgram_manager
+                .transcribe_prerecorded_bytes(
+                    Some(operation_id),
+                    &api_key,
+                    &settings.deepgram_model,
+                    settings.deepgram_timeout_seconds,
+                    &audio_bytes,
+                    Some(language.as_str()),
+                    deepgram_options,
+                )
+                .await
+                .map_err(|e| format!("Deepgram transcription failed: {}", e))?;
+
+            if deepgram_manager.is_cancelled(operation_id) {
+                return Err("Deepgram transcription was cancelled".to_string());
+            }
+
+            let (corrected, speaker_session) = if let Some((rendered_text, session)) =
+                build_diarized_text_output(
+                    DiarizedTranscriptProvider::Deepgram,
+                    transcript.speaker_blocks,
+                    &run.format,
+                    !run.create_speaker_session,
+                    settings,
+                    should_apply_custom_words,
+                )? {
+                (rendered_text, session)
+            } else {
+                (
+                    apply_transcription_post_processing(
+                        transcript.text,
+                        settings,
+                        should_apply_custom_words,
+                    ),
+                    None,
+                )
+            };
+
+            let segs = if needs_segments {
+                Some(build_estimated_remote_segments(&corrected))
+            } else {
+                None
+            };
+
+            FileTranscriptionPart {
+                text: corrected,
+                segments: segs,
+                local_meta: None,
+                speaker_session,
+            }
+        }
+        FileTranscriptionBackend::Local => {
+            // Local transcription with segment support
+            let tm = app.state::<Arc<TranscriptionManager>>();
+            let apply_custom_words_enabled = run.apply_custom_words_enabled;
+            let transcription_model_id = run.model_override.unwrap_or(&settings.selected_model);
+            let (text, segs, meta) = if needs_segments {
+                if let Some(p) = &profile {
+                    tm.transcribe_file_with_segments(
+                        samples,
+                        Some(&p.language),
+                        Some(p.translate_to_english),
+                        crate::settings::select_stt_prompt(
+                            Some(p),
+                            &settings.transcription_prompts,
+                            transcription_model_id,
+                        ),
+                        apply_custom_words_enabled,
+                    )
+                    .map_err(|e| format!("Local transcription failed: {}", e))
+                } else {
+                    tm.transcribe_file_with_segments(
+                        samples,
+                        None,
+                        None,
+                        None,
+                        apply_custom_words_enabled,
+                    )
+                    .map_err(|e| format!("Local transcription failed: {}", e))
+                }
+            } else {
+                let text_result = if let Some(p) = &profile {
+                    tm.transcribe_file_text(
+                        samples,
+                        Some(&p.language),
+                        Some(p.translate_to_english),
+                        crate::settings::select_stt_prompt(
+                            Some(p),
+                            &settings.transcription_prompts,
+                            transcription_model_id,
+                        ),
+                        apply_custom_words_enabled,
+                    )
+                    .map_err(|e| format!("Local transcription failed: {}", e))
+                } else {
+                    tm.transcribe_file_text(samples, None, None, None, apply_custom_words_enabled)
+                        .map_err(|e| format!("Local transcription failed: {}", e))
+                };
+                text_result.map(|(text, meta)| (text, None, meta))
+            }?;
+
+            FileTranscriptionPart {
+                text,
+                segments: segs,
+                local_meta: Some(meta),
+                speaker_session: None,
+            }
+        }
+    };
+
+    Ok(part)
+}
+
+fn emit_file_transcription_progress(app: &AppHandle, event: FileTranscriptionProgressEvent) {
+    let _ = app.emit("file-transcription-progress", event);
+}
+
+/// Splits `total_samples` into ranges of at most `max_segment_samples`.
+/// Each cut goes in the middle of the last silent run (one flag per
+/// `frame_size` samples) in the second half of the segment, or at the limit
+/// when that half has no silence.
+fn plan_file_segments(
+    silent_frames: &[bool],
+    frame_size: usize,
+    total_samples: usize,
+    max_segment_samples: usize,
+) -> Vec<(usize, usize)> {
+    let frame_size = frame_size.max(1);
+    let max_segment_samples = max_segment_samples.max(frame_size * 2);
+    let is_silent = |frame: usize| silent_frames.get(frame).copied().unwrap_or(false);
+
+    let mut ranges = Vec::new();
+    let mut start = 0usize;
+    while total_samples - start > max_segment_samples {
+        let limit = start + max_segment_samples;
+        let first_frame = (start + max_segment_samples / 2).div_ceil(frame_size);
+        let cut = (first_frame..limit / frame_size)
+            .rev()
+            .find(|&frame| is_silent(frame))
+            .map(|last_silent| {
+                let mut run_start = last_silent;
+                while run_start > first_frame && is_silent(run_start - 1) {
+                    run_start -= 1;
+                }
+                (run_start + last_silent + 1) * frame_size / 2
+            })
+            .unwrap_or(limit);
+        ranges.push((start, cut));
+        start = cut;
+    }
+    ranges.push((start, total_samples));
+    ranges
+}
+
+/// `m:ss`, or `h:mm:ss` from an hour on.
+fn format_segment_time(secs: f32) -> String {
+    let total = secs.max(0.0).round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+fn failed_segment_marker(start_secs: f32, end_secs: f32) -> String {
+    format!(
+        "[transcription failed for {}–{}]",
+        format_segment_time(start_secs),
+        format_segment_time(end_secs)
+    )
+}
+
 fn append_info_message(info_message: &mut Option<String>, next_message: String) {
     match info_message {
         Some(existing) if !existing.is_empty() => {
@@ -789,34 +1210,9 @@ fn apply_transcription_post_processing_to_blocks(
     settings: &AppSettings,
     should_apply_custom_words: bool,
 ) -> Vec<DiarizedTranscriptBlock> {
-    let mut processed_blocks: Vec<DiarizedTranscriptBlock> = Vec::new();
-
-    for block in blocks {
-        let text =
-            apply_transcription_post_processing(block.text, settings, should_apply_custom_words);
-        let trimmed = text.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        if let Some(last_block) = processed_blocks.last_mut() {
-            if last_block.speaker_id == block.speaker_id {
-                if !last_block.text.is_empty() {
-                    last_block.text.push(' ');
-                }
-                last_block.text.push_str(trimmed);
-                continue;
-            }
-        }
-
-        processed_blocks.push(DiarizedTranscriptBlock {
-            speaker_id: block.speaker_id,
-            default_name: block.default_name,
-            text: trimmed.to_string(),
-        });
-    }
-
-    processed_blocks
+    process_diarized_blocks(blocks, |text| {
+        apply_transcription_post_processing(text, settings, should_apply_custom_words)
+    })
 }
 
 fn build_diarized_text_output(
@@ -899,6 +1295,107 @@ fn normalize_soniox_language_hints(hints: Option<Vec<String>>) -> Option<Vec<Str
     }
 }
 
+fn decode_file_audio(path: &PathBuf, is_video_container: bool) -> Result<Vec<f32>, String> {
+    if is_video_container {
+        decode_video_container(path)
+    } else {
+        decode_audio_file(path)
+    }
+}
+
+/// Decode the first audio track of a video container to f32 PCM samples at 16kHz
+fn decode_video_container(path: &PathBuf) -> Result<Vec<f32>, String> {
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+    use symphonia::core::errors::Error as SymphoniaError;
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    let file = std::fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("Failed to read media container: {}", e))?;
+    let mut format = probed.format;
+
+    // Video tracks have no sample rate, so this skips them.
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| {
+            track.codec_params.codec != CODEC_TYPE_NULL && track.codec_params.sample_rate.is_some()
+        })
+        .ok_or_else(|| "Media file has no audio track".to_string())?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(16000);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
+
+    let mut mono_samples = Vec::new();
+    let mut sample_buffer: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(format!("Failed to read audio packet: {}", e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(e)) => {
+                debug!("Skipping undecodable audio packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(format!("Failed to decode audio: {}", e)),
+        };
+        let channels = decoded.spec().channels.count().max(1);
+        let buffer = sample_buffer
+            .get_or_insert_with(|| SampleBuffer::new(decoded.capacity() as u64, *decoded.spec()));
+        if buffer.capacity() < decoded.capacity() * channels {
+            *buffer = SampleBuffer::new(decoded.capacity() as u64, *decoded.spec());
+        }
+        buffer.copy_interleaved_ref(decoded);
+        mono_samples.extend(
+            buffer
+                .samples()
+                .chunks(channels)
+                .map(|chunk| chunk.iter().sum::<f32>() / channels as f32),
+        );
+    }
+
+    debug!(
+        "Video audio track: {} Hz, {} samples",
+        sample_rate,
+        mono_samples.len()
+    );
+
+    let target_sample_rate = 16000;
+    if sample_rate != target_sample_rate {
+        resample_audio(&mono_samples, sample_rate, target_sample_rate)
+    } else {
+        Ok(mono_samples)
+    }
+}
+
 /// Decode an audio file to f32 PCM samples at 16kHz
 fn decode_audio_file(path: &PathBuf) -> Result<Vec<f32>, String> {
     use rodio::Source;
@@ -1093,3 +1590,48 @@ fn get_output_file_path(audio_path: &PathBuf, format: OutputFormat) -> Result<Pa
 
     Ok(output_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_file_segments_keeps_short_files_whole() {
+        assert_eq!(plan_file_segments(&[], 512, 1_000, 2_000), vec![(0, 1_000)]);
+    }
+
+    #[test]
+    fn plan_file_segments_cuts_in_the_last_pause_of_each_window() {
+        let frame_size = 10;
+        let mut silent_frames = vec![false; 30];
+        // An early pause in the first half is ignored; the later one is used.
+        silent_frames[2] = true;
+        silent_frames[6] = true;
+        silent_frames[7] = true;
+        silent_frames[8] = true;
+
+        let ranges = plan_file_segments(&silent_frames, frame_size, 300, 100);
+
+        assert_eq!(ranges[0], (0, 75));
+        assert!(ranges.iter().all(|(start, end)| end - start <= 100));
+        assert_eq!(ranges.last().map(|range| range.1), Some(300));
+        assert!(ranges.windows(2).all(|pair| pair[0].1 == pair[1].0));
+    }
+
+    #[test]
+    fn plan_file_segments_falls_back_to_hard_cuts_without_silence() {
+        assert_eq!(
+            plan_file_segments(&[], 10, 250, 100),
+            vec![(0, 100), (100, 200), (200, 250)]
+        );
+    }
+
+    #[test]
+    fn failed_segment_marker_formats_minutes_and_hours() {
+        assert_eq!(
+            failed_segment_marker(750.0, 840.0),
+            "[transcription failed for 12:30–14:00]"
+        );
+        assert_eq!(format_segment_time(3_725.0), "1:02:05");
+    }
+}
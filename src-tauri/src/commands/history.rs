@@ -1,12 +1,26 @@
 use crate::actions::{
-    perform_transcription_for_profile, process_transcription_output, TranscriptionOutcome,
+    paste_history_text, perform_transcription_for_profile, process_transcription_output,
+    reprocess_history_transcription, PostProcessTranscriptionOutcome, RecordingWindowContext,
+    TranscriptionOutcome,
 };
+use crate::audio_toolkit::{apply_custom_words, char_error_counts, word_error_counts, ErrorCounts};
 use crate::managers::{
-    history::{HistoryManager, PaginatedHistory},
+    history::{
+        entry_final_text, export_entries, group_sessions, HistoryEntry, HistoryExportFormat,
+        HistoryManager, HistoryPostProcessRevision, HistorySearchQuery, HistorySearchResults,
+        HistorySession, HistoryTranslation, PaginatedHistory,
+    },
+    llm_operation::LlmOperationTracker,
     transcription::TranscriptionManager,
 };
+use crate::settings::TranscriptionProvider;
+use crate::subtitle::{segments_to_srt, segments_to_vtt, OutputFormat};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 
 #[tauri::command]
 #[specta::specta]
@@ -22,6 +36,32 @@ pub async fn get_history_entries(
         .map_err(|e| e.to_string())
 }
 
+/// Searches the whole history, newest first. `query` is matched case-insensitively
+/// against the transcription, post-processed text and AI response; the date range
+/// is in unix seconds, inclusive. Returns one page of summaries and the total count.
+#[tauri::command]
+#[specta::specta]
+pub async fn search_history(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    query: Option<String>,
+    action_type: Option<String>,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<HistorySearchResults, String> {
+    history_manager
+        .search_entries(&HistorySearchQuery {
+            text: query,
+            action_type,
+            from: from_timestamp,
+            to: to_timestamp,
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(50),
+        })
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn toggle_history_entry_saved(
@@ -81,6 +121,34 @@ pub async fn retry_history_entry_transcription(
     _transcription_manager: State<'_, Arc<TranscriptionManager>>,
     id: i64,
 ) -> Result<(), String> {
+    retranscribe_entry(&app, &history_manager, id, true)
+        .await
+        .map(|_| ())
+}
+
+/// Session binding used while a history entry is being re-transcribed.
+const HISTORY_RETRANSCRIBE_BINDING_ID: &str = "history_retranscribe";
+
+/// Re-runs transcription on a history entry's stored audio with the current
+/// provider. `use_current_settings` picks the active profile; otherwise the
+/// profile the entry was dictated with is reused.
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    use_current_settings: bool,
+) -> Result<HistoryEntry, String> {
+    retranscribe_entry(&app, &history_manager, entry_id, use_current_settings).await
+}
+
+async fn retranscribe_entry(
+    app: &AppHandle,
+    history_manager: &HistoryManager,
+    id: i64,
+    use_current_settings: bool,
+) -> Result<HistoryEntry, String> {
     let entry = history_manager
         .get_entry_by_id(id)
         .await
@@ -99,33 +167,108 @@ pub async fn retry_history_entry_transcription(
         return Err("Recording has no audio samples".to_string());
     }
 
-    let settings = crate::settings::get_settings(&app);
-    let transcription =
-        match perform_transcription_for_profile(&app, samples, None, None, &settings).await {
-            TranscriptionOutcome::Success(text) => text,
-            TranscriptionOutcome::Cancelled => {
-                return Err("Re-transcription was cancelled".to_string());
-            }
-            TranscriptionOutcome::Error { message, .. } => {
-                return Err(message);
-            }
+    let settings = crate::settings::get_settings(app);
+    let profile_id = if use_current_settings {
+        Some(settings.active_profile_id.clone())
+    } else {
+        entry.profile_id.clone()
+    }
+    .filter(|profile_id| profile_id != "default");
+
+    let operation_id = begin_retranscription(app)?;
+    crate::shortcut::register_cancel_shortcut(app);
+    crate::overlay::show_transcribing_overlay(app);
+
+    let result = retranscribe_samples(
+        app,
+        history_manager,
+        id,
+        samples,
+        profile_id,
+        &settings,
+        operation_id,
+    )
+    .await;
+
+    crate::shortcut::unregister_cancel_shortcut(app);
+    crate::session_manager::exit_processing_if_matches(app, operation_id);
+    crate::overlay::hide_recording_overlay(app);
+    result
+}
+
+/// Claims the idle session for the re-transcription so the cancel shortcut
+/// and new recordings treat it like regular processing.
+fn begin_retranscription(app: &AppHandle) -> Result<u64, String> {
+    use crate::session_manager::{self, ManagedSessionState, SessionState};
+
+    let state = app.state::<ManagedSessionState>();
+    let mut state_guard = session_manager::lock_session_state(&state, "retranscribe_history_entry");
+    if !matches!(&*state_guard, SessionState::Idle) {
+        return Err("Wait for the current recording or transcription to finish".to_string());
+    }
+
+    let operation_id = session_manager::next_operation_id();
+    session_manager::replace_state(
+        app,
+        &mut state_guard,
+        SessionState::Processing {
+            binding_id: HISTORY_RETRANSCRIBE_BINDING_ID.to_string(),
+            operation_id,
+        },
+    );
+    Ok(operation_id)
+}
+
+async fn retranscribe_samples(
+    app: &AppHandle,
+    history_manager: &HistoryManager,
+    id: i64,
+    samples: Vec<f32>,
+    profile_id: Option<String>,
+    settings: &crate::settings::AppSettings,
+    operation_id: u64,
+) -> Result<HistoryEntry, String> {
+    let cancelled = || "Re-transcription was cancelled".to_string();
+
+    let (transcription, confidence, timestamps) =
+        match perform_transcription_for_profile(app, samples, None, profile_id.clone(), settings)
+            .await
+        {
+            TranscriptionOutcome::Success {
+                text,
+                confidence,
+                segments,
+                ..
+            } => (text, confidence, segments),
+            TranscriptionOutcome::Cancelled => return Err(cancelled()),
+            TranscriptionOutcome::Error { message, .. } => return Err(message),
         };
 
+    if !crate::session_manager::is_operation_current(app, operation_id) {
+        return Err(cancelled());
+    }
+
     if transcription.is_empty() {
         return Err("Recording contains no speech".to_string());
     }
 
+    // Post-processing follows the same profile as the transcription.
     let processed = process_transcription_output(
-        &app,
-        &settings,
+        app,
+        settings,
         &transcription,
-        None,
+        profile_id.as_deref(),
         "History retry",
+        &RecordingWindowContext::default(),
         false,
     )
     .await
     .ok_or_else(|| "Re-transcription post-processing was cancelled".to_string())?;
 
+    if !crate::session_manager::is_operation_current(app, operation_id) {
+        return Err(cancelled());
+    }
+
     history_manager
         .update_transcription(
             id,
@@ -133,10 +276,81 @@ pub async fn retry_history_entry_transcription(
             processed.post_processed_text,
             processed.post_process_prompt,
         )
-        .map(|_| ())
+        .map_err(|e| e.to_string())?;
+    if let Err(e) = history_manager.set_stt_provider(id, settings.transcription_provider.as_str()) {
+        log::warn!(
+            "Failed to record re-transcription provider in history: {}",
+            e
+        );
+    }
+    if let Err(e) =
+        history_manager.set_post_process_provider(id, processed.post_process_provider.as_deref())
+    {
+        log::warn!(
+            "Failed to record re-transcription post-process provider in history: {}",
+            e
+        );
+    }
+    if let Err(e) = history_manager
+        .set_post_process_raw_response(id, processed.post_process_raw_response.as_deref())
+    {
+        log::warn!(
+            "Failed to record re-transcription raw post-process response in history: {}",
+            e
+        );
+    }
+    if let Err(e) = history_manager.set_post_process_chunked(id, processed.post_process_chunked) {
+        log::warn!(
+            "Failed to record re-transcription chunked post-processing in history: {}",
+            e
+        );
+    }
+    // Old timings no longer match the new text, so they are replaced or cleared.
+    if let Err(e) = history_manager.set_timestamps(id, timestamps.as_deref()) {
+        log::warn!(
+            "Failed to record re-transcription timestamps in history: {}",
+            e
+        );
+    }
+    if let Err(e) = history_manager.set_confidence(id, confidence) {
+        log::warn!(
+            "Failed to record re-transcription confidence in history: {}",
+            e
+        );
+    }
+    history_manager
+        .set_post_process_note(id, processed.post_process_note)
         .map_err(|e| e.to_string())
 }
 
+/// Plays a history entry's recording on the audio feedback output device.
+#[tauri::command]
+#[specta::specta]
+pub async fn play_history_audio(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+) -> Result<(), String> {
+    let entry = history_manager
+        .get_entry_by_id(entry_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", entry_id))?;
+
+    let audio_path = history_manager.get_audio_file_path(&entry.file_name);
+    if !audio_path.exists() {
+        return Err("The recording for this entry no longer exists".to_string());
+    }
+
+    crate::audio_feedback::play_recording(&app, audio_path)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn stop_history_audio() {
+    crate::audio_feedback::stop_recording_playback();
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn update_history_limit(
@@ -184,3 +398,841 @@ pub async fn update_recording_retention_period(
 
     Ok(())
 }
+
+static REAPPLY_CUSTOM_WORDS_CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Emit a progress event every this many entries.
+const REAPPLY_CUSTOM_WORDS_PROGRESS_STEP: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ReapplyCustomWordsProgress {
+    pub processed: usize,
+    pub total: usize,
+    pub changed: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ReapplyCustomWordsResult {
+    pub processed: usize,
+    /// Entries whose corrected text was added, updated or cleared.
+    pub changed: usize,
+    pub cancelled: bool,
+}
+
+/// Re-runs custom word correction with the current list and threshold over the
+/// raw text of the `last_n` most recent transcriptions. The result is stored as
+/// each entry's `corrected_text`; the original transcription is left untouched.
+#[tauri::command]
+#[specta::specta]
+pub async fn reapply_custom_words_to_history(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    last_n: usize,
+) -> Result<ReapplyCustomWordsResult, String> {
+    let settings = crate::settings::get_settings(&app);
+    if settings.custom_words.is_empty() {
+        return Err("No custom words configured".to_string());
+    }
+
+    REAPPLY_CUSTOM_WORDS_CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    let history_manager = Arc::clone(&history_manager);
+    let limit = last_n.min(crate::settings::MAX_HISTORY_LIMIT);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let entries = history_manager
+            .get_recent_transcription_entries(limit)
+            .map_err(|e| e.to_string())?;
+        let total = entries.len();
+        let correct = |text: &str| {
+            apply_custom_words(
+                text,
+                &settings.custom_words,
+                settings.word_correction_threshold,
+                settings.custom_words_ngram_enabled,
+            )
+        };
+
+        let mut processed = 0;
+        let mut changed = 0;
+        let mut cancelled = false;
+        for entry in entries {
+            if REAPPLY_CUSTOM_WORDS_CANCEL_REQUESTED.load(Ordering::SeqCst) {
+                cancelled = true;
+                break;
+            }
+
+            let corrected = if entry.transcription_text.trim().is_empty() {
+                None
+            } else {
+                Some(correct(&entry.transcription_text))
+                    .filter(|corrected| *corrected != entry.transcription_text)
+            };
+            if corrected != entry.corrected_text {
+                history_manager
+                    .set_corrected_text(entry.id, corrected)
+                    .map_err(|e| e.to_string())?;
+                changed += 1;
+            }
+
+            processed += 1;
+            if processed % REAPPLY_CUSTOM_WORDS_PROGRESS_STEP == 0 || processed == total {
+                let _ = app.emit(
+                    "custom-words-reapply-progress",
+                    ReapplyCustomWordsProgress {
+                        processed,
+                        total,
+                        changed,
+                    },
+                );
+            }
+        }
+
+        if !cancelled {
+            let refreshed = crate::transcript_context::rewrite_transcript_context(correct);
+            log::debug!(
+                "Re-applied custom words to {} transcript context entries",
+                refreshed
+            );
+        }
+
+        log::info!(
+            "Re-applied custom words to {} history entries ({} changed{})",
+            processed,
+            changed,
+            if cancelled { ", cancelled" } else { "" }
+        );
+        Ok(ReapplyCustomWordsResult {
+            processed,
+            changed,
+            cancelled,
+        })
+    })
+    .await
+    .map_err(|e| format!("Custom word re-application failed: {}", e))?
+}
+
+/// Stops a running `reapply_custom_words_to_history` after the current entry.
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_reapply_custom_words_to_history() {
+    REAPPLY_CUSTOM_WORDS_CANCEL_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_session_gap_minutes_setting(
+    app: AppHandle,
+    minutes: u32,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_session_gap_minutes =
+        minutes.min(crate::settings::MAX_HISTORY_SESSION_GAP_MINUTES);
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Dictation sessions, newest first. Entries saved before sessions existed
+/// (or with grouping disabled) are not part of any session.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_sessions(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<Vec<HistorySession>, String> {
+    history_manager
+        .get_grouped_entries()
+        .map(|entries| group_sessions(&entries))
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HistorySessionExportFormat {
+    /// Takes joined by blank lines
+    Text,
+    /// Takes under a session heading, each take as its own paragraph
+    Markdown,
+}
+
+fn format_session_document(entries: &[HistoryEntry], format: HistorySessionExportFormat) -> String {
+    let takes: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry_final_text(entry).trim())
+        .filter(|text| !text.is_empty())
+        .collect();
+    match format {
+        HistorySessionExportFormat::Text => format!("{}\n", takes.join("\n\n")),
+        HistorySessionExportFormat::Markdown => {
+            let title = entries
+                .first()
+                .map(|entry| entry.title.as_str())
+                .unwrap_or("");
+            format!("# {}\n\n{}\n", title, takes.join("\n\n"))
+        }
+    }
+}
+
+/// Concatenates the takes of a dictation session in dictation order into one
+/// document and returns it.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history_session(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    group_id: String,
+    format: HistorySessionExportFormat,
+) -> Result<String, String> {
+    let entries = history_manager
+        .get_session_entries(&group_id)
+        .map_err(|e| e.to_string())?;
+    if entries.is_empty() {
+        return Err(format!("Dictation session {} not found", group_id));
+    }
+    Ok(format_session_document(&entries, format))
+}
+
+/// Formats the segment timings saved with a history entry as SRT or VTT
+/// subtitles. Entries without saved timings are an error rather than getting
+/// made-up timings.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history_entry_subtitles(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    format: OutputFormat,
+) -> Result<String, String> {
+    let segments = history_manager
+        .get_timestamps(entry_id)
+        .map_err(|e| e.to_string())?;
+    let Some(segments) = segments else {
+        let stt_provider = history_manager
+            .get_entry_by_id(entry_id)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|entry| entry.stt_provider);
+        return Err(match stt_provider.as_deref() {
+            Some(provider) if provider != TranscriptionProvider::Local.as_str() => format!(
+                "No timestamps for this entry: it was transcribed with {}, which does not return segment timings",
+                provider
+            ),
+            _ => "No timestamps for this entry. Turn on \"Save timestamps\" and transcribe with a local model that reports segments".to_string(),
+        });
+    };
+
+    match format {
+        OutputFormat::Srt => Ok(segments_to_srt(&segments)),
+        OutputFormat::Vtt => Ok(segments_to_vtt(&segments)),
+        OutputFormat::Text => Err("Subtitle export supports only SRT and VTT".to_string()),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_timestamps_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_timestamps_enabled = enabled;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_trim_silence_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_trim_silence_enabled = enabled;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_history_trim_silence_padding_setting(
+    app: AppHandle,
+    padding_ms: u32,
+) -> Result<(), String> {
+    if padding_ms > crate::settings::MAX_HISTORY_TRIM_SILENCE_PADDING_MS {
+        return Err(format!(
+            "Silence padding must be at most {} ms",
+            crate::settings::MAX_HISTORY_TRIM_SILENCE_PADDING_MS
+        ));
+    }
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_trim_silence_padding_ms = padding_ms;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_low_confidence_warning_threshold_setting(
+    app: AppHandle,
+    threshold: f32,
+) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("Confidence threshold must be between 0.0 and 1.0".to_string());
+    }
+    let mut settings = crate::settings::get_settings(&app);
+    settings.low_confidence_warning_threshold = threshold;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_repaste_last_count_setting(app: AppHandle, count: u32) -> Result<(), String> {
+    let max_count = crate::settings::MAX_REPASTE_LAST_COUNT;
+    if count == 0 || count > max_count {
+        return Err(format!("Repaste count must be between 1 and {}", max_count));
+    }
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.repaste_last_count = count;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_repaste_last_separator_setting(
+    app: AppHandle,
+    separator: String,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.repaste_last_separator = separator;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_repaste_last_include_ai_replace_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.repaste_last_include_ai_replace = enabled;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_undo_last_paste_timeout_setting(app: AppHandle, seconds: u32) -> Result<(), String> {
+    let max_seconds = crate::settings::MAX_UNDO_LAST_PASTE_TIMEOUT_SECS;
+    if seconds == 0 || seconds > max_seconds {
+        return Err(format!(
+            "Undo timeout must be between 1 and {} seconds",
+            max_seconds
+        ));
+    }
+
+    let mut settings = crate::settings::get_settings(&app);
+    settings.undo_last_paste_timeout_secs = seconds;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Writes history entries inside the optional timestamp range (unix seconds,
+/// inclusive) to a JSON or CSV file in the app data directory and returns its path.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_history(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    format: HistoryExportFormat,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+) -> Result<String, String> {
+    if let (Some(from), Some(to)) = (from_timestamp, to_timestamp) {
+        if from > to {
+            return Err("Export range start is after its end".to_string());
+        }
+    }
+
+    let entries = history_manager
+        .get_entries_in_range(from_timestamp, to_timestamp)
+        .map_err(|e| e.to_string())?;
+    let contents = export_entries(&entries, format).map_err(|e| e.to_string())?;
+
+    let export_dir = crate::portable::app_data_dir(&app)
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    std::fs::create_dir_all(&export_dir)
+        .map_err(|e| format!("Failed to create export directory: {}", e))?;
+    let path = export_dir.join(format!(
+        "history-export-{}.{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    ));
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write export file: {}", e))?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_accuracy_evaluation_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.accuracy_evaluation_enabled = enabled;
+    crate::settings::write_settings(&app, settings);
+    Ok(())
+}
+
+fn ensure_accuracy_evaluation_enabled(app: &AppHandle) -> Result<(), String> {
+    if crate::settings::get_settings(app).accuracy_evaluation_enabled {
+        Ok(())
+    } else {
+        Err("Accuracy evaluation mode is disabled".to_string())
+    }
+}
+
+/// Stores the correct final text for a history entry, used as the reference
+/// by `compute_accuracy_stats`. An empty reference clears it.
+#[tauri::command]
+#[specta::specta]
+pub async fn record_reference_text(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    reference: String,
+) -> Result<HistoryEntry, String> {
+    ensure_accuracy_evaluation_enabled(&app)?;
+    let reference = Some(reference.trim().to_string()).filter(|text| !text.is_empty());
+    history_manager
+        .set_reference_text(entry_id, reference)
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct ErrorRates {
+    /// Word error rate, 0.0 = perfect.
+    pub wer: f64,
+    /// Character error rate, 0.0 = perfect.
+    pub cer: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct AccuracyGroupStats {
+    /// `None` for the aggregate over all providers.
+    pub provider: Option<String>,
+    pub entry_count: usize,
+    pub reference_words: usize,
+    /// Raw transcription against the reference.
+    pub raw: ErrorRates,
+    /// Final text (post-processed if available) against the reference.
+    pub post_processed: ErrorRates,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct AccuracyStats {
+    pub overall: AccuracyGroupStats,
+    pub by_provider: Vec<AccuracyGroupStats>,
+}
+
+#[derive(Default)]
+struct AccuracyAccumulator {
+    entry_count: usize,
+    raw_words: ErrorCounts,
+    raw_chars: ErrorCounts,
+    final_words: ErrorCounts,
+    final_chars: ErrorCounts,
+}
+
+impl AccuracyAccumulator {
+    fn add(&mut self, entry: &HistoryEntry, reference: &str) {
+        let final_text = entry
+            .post_processed_text
+            .as_deref()
+            .unwrap_or(&entry.transcription_text);
+        self.entry_count += 1;
+        self.raw_words += word_error_counts(&entry.transcription_text, reference);
+        self.raw_chars += char_error_counts(&entry.transcription_text, reference);
+        self.final_words += word_error_counts(final_text, reference);
+        self.final_chars += char_error_counts(final_text, reference);
+    }
+
+    fn into_stats(self, provider: Option<String>) -> AccuracyGroupStats {
+        AccuracyGroupStats {
+            provider,
+            entry_count: self.entry_count,
+            reference_words: self.raw_words.reference_len,
+            raw: ErrorRates {
+                wer: self.raw_words.rate(),
+                cer: self.raw_chars.rate(),
+            },
+            post_processed: ErrorRates {
+                wer: self.final_words.rate(),
+                cer: self.final_chars.rate(),
+            },
+        }
+    }
+}
+
+/// Computes WER/CER of the raw and post-processed text against the reference
+/// for every history entry that has one. Rates are pooled over all reference
+/// words/characters, so long entries weigh more than short ones. Runs locally.
+#[tauri::command]
+#[specta::specta]
+pub async fn compute_accuracy_stats(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<AccuracyStats, String> {
+    ensure_accuracy_evaluation_enabled(&app)?;
+    let history_manager = Arc::clone(&history_manager);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let entries = history_manager
+            .get_entries_with_reference()
+            .map_err(|e| e.to_string())?;
+
+        let mut overall = AccuracyAccumulator::default();
+        let mut by_provider: BTreeMap<String, AccuracyAccumulator> = BTreeMap::new();
+        for entry in &entries {
+            let Some(reference) = entry.reference_text.as_deref() else {
+                continue;
+            };
+            let provider = entry
+                .stt_provider
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string());
+            overall.add(entry, reference);
+            by_provider
+                .entry(provider)
+                .or_default()
+                .add(entry, reference);
+        }
+
+        Ok(AccuracyStats {
+            overall: overall.into_stats(None),
+            by_provider: by_provider
+                .into_iter()
+                .map(|(provider, accumulator)| accumulator.into_stats(Some(provider)))
+                .collect(),
+        })
+    })
+    .await
+    .map_err(|e| format!("Accuracy evaluation failed: {}", e))?
+}
+
+/// Why `translate_history_entry` produced no translation.
+#[derive(Serialize, Debug, Clone, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranslateHistoryError {
+    EntryNotFound,
+    EmptyText,
+    /// The target language is blank or contains a comma
+    InvalidLanguage,
+    NoProvider,
+    NoModel {
+        provider_id: String,
+    },
+    Cancelled,
+    Failed {
+        message: String,
+    },
+}
+
+impl From<anyhow::Error> for TranslateHistoryError {
+    fn from(err: anyhow::Error) -> Self {
+        TranslateHistoryError::Failed {
+            message: err.to_string(),
+        }
+    }
+}
+
+fn history_translation_prompt(language: &str, text: &str) -> String {
+    format!(
+        "Translate the text below into {}. Keep its meaning, tone and formatting. \
+Return only the translation, without notes, quotes or explanations.\n\nText:\n{}",
+        language, text
+    )
+}
+
+/// Translates the entry's final text (post-processed if present, else raw)
+/// with the active post-processing provider and stores the result on the
+/// entry. Every call adds a new translation, so an entry can carry several.
+#[tauri::command]
+#[specta::specta]
+pub async fn translate_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    target_language: String,
+) -> Result<HistoryTranslation, TranslateHistoryError> {
+    let language = target_language.trim();
+    if language.is_empty() || language.contains(',') {
+        return Err(TranslateHistoryError::InvalidLanguage);
+    }
+
+    let entry = history_manager
+        .get_entry_by_id(entry_id)
+        .await?
+        .ok_or(TranslateHistoryError::EntryNotFound)?;
+    let text = entry_final_text(&entry).trim().to_string();
+    if text.is_empty() {
+        return Err(TranslateHistoryError::EmptyText);
+    }
+
+    let settings = crate::settings::get_settings(&app);
+    let provider = settings
+        .active_post_process_provider()
+        .cloned()
+        .ok_or(TranslateHistoryError::NoProvider)?;
+    let model = settings
+        .post_process_models
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+    if model.trim().is_empty() {
+        return Err(TranslateHistoryError::NoModel {
+            provider_id: provider.id.clone(),
+        });
+    }
+
+    let prompt = history_translation_prompt(language, &text);
+    let llm_tracker = app.state::<Arc<LlmOperationTracker>>();
+    let operation_id = llm_tracker.start_operation();
+
+    let result = if provider.id == crate::settings::APPLE_INTELLIGENCE_PROVIDER_ID {
+        #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+        {
+            if !crate::apple_intelligence::check_apple_intelligence_availability() {
+                return Err(TranslateHistoryError::Failed {
+                    message: "Apple Intelligence is not currently available".to_string(),
+                });
+            }
+            let token_limit = model.trim().parse::<i32>().unwrap_or(0);
+            crate::apple_intelligence::process_text(&prompt, token_limit).map(Some)
+        }
+
+        #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+        {
+            Err("Apple Intelligence is not supported on this device".to_string())
+        }
+    } else {
+        #[cfg(target_os = "windows")]
+        let api_key = crate::secure_keys::get_post_process_api_key(&provider.id);
+
+        #[cfg(not(target_os = "windows"))]
+        let api_key = settings
+            .post_process_api_keys
+            .get(&provider.id)
+            .cloned()
+            .unwrap_or_default();
+
+        crate::llm_client::send_chat_completion_with_reasoning(
+            &provider,
+            api_key,
+            &model,
+            prompt,
+            crate::llm_client::ReasoningConfig::new(false, settings.post_process_reasoning_budget)
+                .with_disable_by_default_on_compatible_providers(true),
+        )
+        .await
+    };
+
+    if llm_tracker.is_cancelled(operation_id) {
+        return Err(TranslateHistoryError::Cancelled);
+    }
+
+    let translated = match result {
+        Ok(Some(content)) if !content.trim().is_empty() => content.trim().to_string(),
+        Ok(_) => {
+            return Err(TranslateHistoryError::Failed {
+                message: "LLM response has no content".to_string(),
+            })
+        }
+        Err(message) => return Err(TranslateHistoryError::Failed { message }),
+    };
+
+    Ok(history_manager.add_translation(entry_id, language, &translated, &provider.id, &model)?)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_translations(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+) -> Result<Vec<HistoryTranslation>, String> {
+    history_manager
+        .get_translations(entry_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Why `reprocess_history_entry` produced no new post-processed text.
+#[derive(Serialize, Debug, Clone, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReprocessHistoryError {
+    EntryNotFound,
+    /// AI Replace entries have no dictated text to post-process
+    AiReplaceEntry,
+    EmptyText,
+    /// Provider, model or prompt is missing or unusable
+    NotConfigured {
+        message: String,
+    },
+    Cancelled,
+    Failed {
+        message: String,
+    },
+}
+
+impl From<anyhow::Error> for ReprocessHistoryError {
+    fn from(err: anyhow::Error) -> Self {
+        ReprocessHistoryError::Failed {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Post-processes the entry's raw transcription again with `prompt_id` (and
+/// `provider_id`, if given) and stores the result as its post-processed text.
+/// The text it replaces is kept as a revision. With `paste` the result is
+/// also pasted into the focused app.
+#[tauri::command]
+#[specta::specta]
+pub async fn reprocess_history_entry(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    prompt_id: String,
+    provider_id: Option<String>,
+    paste: Option<bool>,
+) -> Result<HistoryEntry, ReprocessHistoryError> {
+    let entry = history_manager
+        .get_entry_by_id(entry_id)
+        .await?
+        .ok_or(ReprocessHistoryError::EntryNotFound)?;
+    if entry.action_type == "ai_replace" {
+        return Err(ReprocessHistoryError::AiReplaceEntry);
+    }
+    if entry.transcription_text.trim().is_empty() {
+        return Err(ReprocessHistoryError::EmptyText);
+    }
+
+    let outcome =
+        reprocess_history_transcription(&app, &entry, &prompt_id, provider_id.as_deref()).await;
+    crate::overlay::hide_recording_overlay(&app);
+
+    let (text, prompt_template, output_limit_note, provider_id, raw_response, chunked) =
+        match outcome.map_err(|message| ReprocessHistoryError::NotConfigured { message })? {
+            PostProcessTranscriptionOutcome::Processed {
+                text,
+                prompt_template,
+                output_limit_note,
+                provider_id,
+                raw_response,
+                chunked,
+            } => (
+                text,
+                prompt_template,
+                output_limit_note,
+                provider_id,
+                raw_response,
+                chunked,
+            ),
+            PostProcessTranscriptionOutcome::Skipped => {
+                return Err(ReprocessHistoryError::NotConfigured {
+                    message:
+                        "LLM processing could not run. Check provider, model, and prompt settings."
+                            .to_string(),
+                })
+            }
+            PostProcessTranscriptionOutcome::Cancelled => {
+                return Err(ReprocessHistoryError::Cancelled)
+            }
+        };
+
+    let updated = history_manager.set_reprocessed_text(
+        entry_id,
+        &text,
+        &prompt_template,
+        &provider_id,
+        output_limit_note.as_deref(),
+        raw_response.as_deref(),
+        chunked,
+    )?;
+
+    if paste.unwrap_or(false) {
+        paste_history_text(&app, text).await;
+    }
+
+    Ok(updated)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_history_entry_post_process_revisions(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+) -> Result<Vec<HistoryPostProcessRevision>, String> {
+    history_manager
+        .get_post_process_revisions(entry_id)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, transcription: &str, post_processed: Option<&str>) -> HistoryEntry {
+        HistoryEntry {
+            id,
+            file_name: format!("take-{}.wav", id),
+            timestamp: 1_700_000_000 + id,
+            saved: false,
+            title: format!("Take {}", id),
+            transcription_text: transcription.to_string(),
+            post_processed_text: post_processed.map(str::to_string),
+            post_process_prompt: None,
+            post_process_requested: post_processed.is_some(),
+            post_process_note: None,
+            corrected_text: None,
+            reference_text: None,
+            stt_provider: None,
+            post_process_provider: None,
+            post_process_raw_response: None,
+            post_process_chunked: false,
+            action_type: "transcribe".to_string(),
+            original_selection: None,
+            ai_response: None,
+            ai_response_rejected: false,
+            session_group_id: Some("session-1".to_string()),
+            profile_id: None,
+            target_app: None,
+            confidence: None,
+            translation_languages: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn session_text_joins_final_takes_in_order() {
+        let entries = [
+            entry(1, " first take ", None),
+            entry(2, "raw second", Some("Second take.")),
+            entry(3, "   ", None),
+        ];
+
+        assert_eq!(
+            format_session_document(&entries, HistorySessionExportFormat::Text),
+            "first take\n\nSecond take.\n"
+        );
+    }
+
+    #[test]
+    fn session_markdown_is_titled_after_the_first_take() {
+        let entries = [entry(1, "Intro.", None), entry(2, "Body.", None)];
+
+        assert_eq!(
+            format_session_document(&entries, HistorySessionExportFormat::Markdown),
+            "# Take 1\n\nIntro.\n\nBody.\n"
+        );
+    }
+}
@@ -39,7 +39,7 @@ pub async fn key_listener_register_shortcut(
 
     key_listener_state
         .manager
-        .register_shortcut(id, binding)
+        .register_shortcut(id, binding, false)
         .await
 }
 
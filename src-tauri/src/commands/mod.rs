@@ -1,3 +1,4 @@
+pub mod ai_replace_confirm;
 pub mod asset_preview;
 pub mod audio;
 pub mod connector;
@@ -8,7 +9,9 @@ pub mod live_sound_transcription;
 pub mod models;
 pub mod region_capture;
 pub mod remote_stt;
+pub mod session_bundle;
 pub mod transcription;
+pub mod usage_stats;
 pub mod voice_activation_button;
 pub mod voice_command;
 
@@ -73,6 +76,84 @@ pub fn cancel_operation(app: AppHandle) {
     cancel_current_operation(&app);
 }
 
+/// Drops cached ${short_prev_transcript} context for all apps, one app, or one app+profile pair.
+#[tauri::command]
+#[specta::specta]
+pub fn clear_transcript_context(scope: crate::transcript_context::TranscriptContextScope) -> usize {
+    crate::transcript_context::clear_transcript_context(&scope)
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct TranscriptContextClearedEvent {
+    pub app_name: String,
+    pub removed: usize,
+}
+
+/// Purges cached ${short_prev_transcript} context for every profile of an app
+/// (app name compared case-insensitively) and emits `transcript-context-cleared`.
+#[tauri::command]
+#[specta::specta]
+pub fn clear_transcript_context_for_app(app: AppHandle, app_name: String) -> usize {
+    let target = app_name.trim().to_lowercase();
+    let removed = crate::transcript_context::clear_transcript_context_where(|stored_app| {
+        stored_app.trim().to_lowercase() == target
+    });
+
+    let _ = app.emit(
+        "transcript-context-cleared",
+        TranscriptContextClearedEvent { app_name, removed },
+    );
+    removed
+}
+
+/// Turns privacy mode on or off; emits `privacy-mode-changed`.
+#[tauri::command]
+#[specta::specta]
+pub fn set_privacy_mode(app: AppHandle, enabled: bool) -> crate::privacy_mode::PrivacyModeStatus {
+    crate::privacy_mode::set_active(&app, enabled)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_privacy_mode_status() -> crate::privacy_mode::PrivacyModeStatus {
+    crate::privacy_mode::status()
+}
+
+/// Arms a one-shot provider/model override for the next dictation; None uses
+/// the configured boost. Emits `boost-state-changed`.
+#[tauri::command]
+#[specta::specta]
+pub fn boost_next_transcription(
+    app: AppHandle,
+    boost: Option<crate::settings::BoostOverride>,
+) -> Result<crate::boost::BoostStatus, String> {
+    let status = crate::boost::arm(&app, boost)?;
+    if let Some(boost) = &status.boost {
+        crate::overlay::show_boost_overlay(&app, &crate::boost::overlay_label(boost));
+    }
+    Ok(status)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_boost_next_transcription(app: AppHandle) -> crate::boost::BoostStatus {
+    crate::boost::disarm(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_boost_status() -> crate::boost::BoostStatus {
+    crate::boost::status()
+}
+
+/// Latest recording session lifecycle state, in the same shape as the
+/// `session-state-changed` event.
+#[tauri::command]
+#[specta::specta]
+pub fn get_session_state() -> crate::session_manager::SessionStateChangedEvent {
+    crate::session_manager::current_session_event()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
@@ -94,6 +175,13 @@ pub fn get_app_settings(app: AppHandle) -> Result<AppSettings, String> {
     Ok(get_settings(&app))
 }
 
+/// Writes any coalesced settings changes to disk now, e.g. before a relaunch.
+#[tauri::command]
+#[specta::specta]
+pub fn flush_app_settings(app: AppHandle) {
+    crate::settings::flush_settings(&app);
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn take_settings_store_reset_notice() -> bool {
@@ -378,6 +466,7 @@ pub fn debug_show_error_overlay(app: AppHandle, category: String) {
         "ParseError" => OverlayErrorCategory::ParseError,
         "ExtensionOffline" => OverlayErrorCategory::ExtensionOffline,
         "MicrophoneUnavailable" => OverlayErrorCategory::MicrophoneUnavailable,
+        "MicrophoneDisconnected" => OverlayErrorCategory::MicrophoneDisconnected,
         _ => OverlayErrorCategory::Unknown,
     };
 
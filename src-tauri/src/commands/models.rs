@@ -426,3 +426,26 @@ pub async fn cancel_download(
         .cancel_download(&model_id)
         .map_err(|e| e.to_string())
 }
+
+/// Stops a model download but keeps the partial file so it can resume later.
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_model_download(
+    model_manager: State<'_, Arc<ModelManager>>,
+    model_id: String,
+) -> Result<(), String> {
+    model_manager
+        .pause_download(&model_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Brings the main window forward on the Models screen, e.g. from the
+/// missing-model error overlay.
+#[tauri::command]
+#[specta::specta]
+pub fn open_models_settings(app_handle: AppHandle) -> Result<(), String> {
+    crate::show_main_window(&app_handle);
+    app_handle
+        .emit("open-settings-section", "models")
+        .map_err(|e| e.to_string())
+}
@@ -72,6 +72,27 @@ pub fn deepgram_clear_api_key() -> Result<(), String> {
     crate::secure_keys::clear_deepgram_api_key().map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn openai_realtime_has_api_key() -> Result<bool, String> {
+    Ok(crate::secure_keys::has_openai_realtime_api_key())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn openai_realtime_set_api_key(api_key: String) -> Result<(), String> {
+    if api_key.trim().is_empty() {
+        return Err("API key cannot be empty".to_string());
+    }
+    crate::secure_keys::set_openai_realtime_api_key(api_key.trim()).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn openai_realtime_clear_api_key() -> Result<(), String> {
+    crate::secure_keys::clear_openai_realtime_api_key().map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn remote_stt_get_debug_dump(
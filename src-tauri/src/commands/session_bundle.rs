@@ -0,0 +1,307 @@
+//! Session bundle export/import.
+//!
+//! A bundle is a zip with `manifest.json`, the history entry's `audio.wav`
+//! and a redacted `settings.json`, so a transcription/LLM problem can be
+//! reproduced on another machine.
+
+use crate::managers::history::HistoryManager;
+use crate::settings::{get_settings, AppSettings, LLMPrompt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const SESSION_BUNDLE_FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const AUDIO_FILE_NAME: &str = "audio.wav";
+const SETTINGS_FILE_NAME: &str = "settings.json";
+const REDACTED_VALUE: &str = "[REDACTED]";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionBundleManifest {
+    pub format_version: u32,
+    pub app_version: String,
+    pub exported_at: i64,
+    pub entry_id: i64,
+    pub entry_timestamp: i64,
+    pub entry_title: String,
+    pub action_type: String,
+    pub raw_transcription: String,
+    pub post_processed_text: Option<String>,
+    pub post_process_requested: bool,
+    /// Expanded prompt as sent to the LLM (stored with the history entry).
+    pub post_process_prompt: Option<String>,
+    /// Selected prompt template at export time.
+    pub prompt_template: Option<LLMPrompt>,
+    /// Provider/model identifiers at export time; history entries do not record them.
+    pub transcription_provider: String,
+    pub transcription_model: String,
+    pub post_process_provider_id: String,
+    pub post_process_model: Option<String>,
+    pub has_audio: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedSessionBundle {
+    pub manifest: SessionBundleManifest,
+    /// Redacted settings snapshot from the exporting machine.
+    pub settings_json: Option<String>,
+    /// History entry created for the imported session, usable with the
+    /// history retry/reprocess commands. `None` when the bundle had no audio.
+    pub history_entry_id: Option<i64>,
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.ends_with("api_key")
+        || key.ends_with("api_keys")
+        || key.ends_with("_token")
+        || key.contains("password")
+        || key.contains("secret")
+}
+
+fn redact_all_strings(value: &mut Value) {
+    match value {
+        Value::String(text) if !text.is_empty() => *text = REDACTED_VALUE.to_string(),
+        Value::Array(items) => items.iter_mut().for_each(redact_all_strings),
+        Value::Object(map) => map.values_mut().for_each(redact_all_strings),
+        _ => {}
+    }
+}
+
+fn redact_secrets(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if is_secret_key(key) {
+                    redact_all_strings(child);
+                } else {
+                    redact_secrets(child);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
+fn redacted_settings_json(settings: &AppSettings) -> Result<String, String> {
+    let mut value = serde_json::to_value(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    redact_secrets(&mut value);
+    serde_json::to_string_pretty(&value).map_err(|e| format!("Failed to serialize settings: {}", e))
+}
+
+fn selected_prompt_template(settings: &AppSettings) -> Option<LLMPrompt> {
+    let selected_id = settings.post_process_selected_prompt_id.as_deref()?;
+    settings
+        .post_process_prompts
+        .iter()
+        .find(|prompt| prompt.id == selected_id)
+        .cloned()
+}
+
+fn enum_identifier<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(text)) => text,
+        _ => String::new(),
+    }
+}
+
+fn write_zip_file<W: Write + io::Seek>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    contents: &[u8],
+) -> Result<(), String> {
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(name, options)
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))?;
+    zip.write_all(contents)
+        .map_err(|e| format!("Failed to write {} to bundle: {}", name, e))
+}
+
+fn read_zip_file(archive: &mut ZipArchive<File>, name: &str) -> Result<Option<Vec<u8>>, String> {
+    let mut entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {} from bundle: {}", name, e)),
+    };
+    let mut contents = Vec::new();
+    entry
+        .read_to_end(&mut contents)
+        .map_err(|e| format!("Failed to read {} from bundle: {}", name, e))?;
+    Ok(Some(contents))
+}
+
+/// Packages a history entry (audio, raw/processed text, prompts, provider ids and a
+/// redacted settings snapshot) into a zip at `path`.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_session_bundle(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    entry_id: i64,
+    path: String,
+) -> Result<SessionBundleManifest, String> {
+    let entry = history_manager
+        .get_entry_by_id(entry_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", entry_id))?;
+
+    let settings = get_settings(&app);
+    let audio_path = history_manager.get_audio_file_path(&entry.file_name);
+    let audio_bytes = if entry.file_name.is_empty() || !audio_path.exists() {
+        None
+    } else {
+        Some(fs::read(&audio_path).map_err(|e| format!("Failed to read recording: {}", e))?)
+    };
+
+    let manifest = SessionBundleManifest {
+        format_version: SESSION_BUNDLE_FORMAT_VERSION,
+        app_version: app.package_info().version.to_string(),
+        exported_at: chrono::Utc::now().timestamp(),
+        entry_id: entry.id,
+        entry_timestamp: entry.timestamp,
+        entry_title: entry.title,
+        action_type: entry.action_type,
+        raw_transcription: entry.transcription_text,
+        post_processed_text: entry.post_processed_text,
+        post_process_requested: entry.post_process_requested,
+        post_process_prompt: entry.post_process_prompt,
+        prompt_template: selected_prompt_template(&settings),
+        transcription_provider: enum_identifier(&settings.transcription_provider),
+        transcription_model: settings.selected_model.clone(),
+        post_process_provider_id: settings.post_process_provider_id.clone(),
+        post_process_model: settings
+            .post_process_models
+            .get(&settings.post_process_provider_id)
+            .cloned(),
+        has_audio: audio_bytes.is_some(),
+    };
+
+    let manifest_json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize bundle manifest: {}", e))?;
+    let settings_json = redacted_settings_json(&settings)?;
+
+    let file = File::create(Path::new(&path))
+        .map_err(|e| format!("Failed to create bundle file: {}", e))?;
+    let mut zip = ZipWriter::new(file);
+    write_zip_file(&mut zip, MANIFEST_FILE_NAME, manifest_json.as_bytes())?;
+    write_zip_file(&mut zip, SETTINGS_FILE_NAME, settings_json.as_bytes())?;
+    if let Some(audio_bytes) = audio_bytes.as_deref() {
+        write_zip_file(&mut zip, AUDIO_FILE_NAME, audio_bytes)?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize bundle: {}", e))?;
+
+    log::info!("Exported session bundle for entry {} to {}", entry_id, path);
+    Ok(manifest)
+}
+
+/// Loads a session bundle. When it contains audio, the session is added to
+/// history so it can be re-transcribed or re-processed locally.
+#[tauri::command]
+#[specta::specta]
+pub async fn import_session_bundle(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    path: String,
+) -> Result<ImportedSessionBundle, String> {
+    let file =
+        File::open(Path::new(&path)).map_err(|e| format!("Failed to open bundle file: {}", e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed to read bundle zip: {}", e))?;
+
+    let manifest_bytes = read_zip_file(&mut archive, MANIFEST_FILE_NAME)?
+        .ok_or_else(|| "Bundle is missing manifest.json".to_string())?;
+    let manifest: SessionBundleManifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| format!("Invalid bundle manifest: {}", e))?;
+    if manifest.format_version > SESSION_BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "Unsupported bundle format version {} (expected {} or older)",
+            manifest.format_version, SESSION_BUNDLE_FORMAT_VERSION
+        ));
+    }
+
+    let settings_json = read_zip_file(&mut archive, SETTINGS_FILE_NAME)?
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+
+    let history_entry_id = match read_zip_file(&mut archive, AUDIO_FILE_NAME)? {
+        Some(audio_bytes) => {
+            let file_name = format!(
+                "aivorelay-imported-{}.wav",
+                chrono::Utc::now().timestamp_millis()
+            );
+            fs::write(
+                history_manager.recordings_dir().join(&file_name),
+                audio_bytes,
+            )
+            .map_err(|e| format!("Failed to store imported recording: {}", e))?;
+            let entry = history_manager
+                .save_entry(
+                    file_name,
+                    manifest.raw_transcription.clone(),
+                    manifest.post_process_requested,
+                    manifest.post_processed_text.clone(),
+                    manifest.post_process_prompt.clone(),
+                )
+                .map_err(|e| e.to_string())?;
+            Some(entry.id)
+        }
+        None => None,
+    };
+
+    log::info!("Imported session bundle from {}", path);
+    Ok(ImportedSessionBundle {
+        manifest,
+        settings_json,
+        history_entry_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn secret_keys_are_recognized() {
+        assert!(is_secret_key("post_process_api_keys"));
+        assert!(is_secret_key("soniox_api_key"));
+        assert!(is_secret_key("Action_API_Token"));
+        assert!(is_secret_key("webhook_secret"));
+        assert!(is_secret_key("proxy_password"));
+        assert!(!is_secret_key("api_key_hint_shown"));
+        assert!(!is_secret_key("selected_model"));
+        assert!(!is_secret_key("token_limit"));
+    }
+
+    #[test]
+    fn redaction_replaces_nested_secret_values_only() {
+        let mut value = json!({
+            "selected_model": "parakeet",
+            "post_process_api_keys": { "openai": "sk-live", "groq": "" },
+            "profiles": [{ "name": "Work", "webhook_secret": "hunter2" }],
+            "remote": { "auth_token": ["a", "b"], "timeout": 30 },
+        });
+        redact_secrets(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "selected_model": "parakeet",
+                "post_process_api_keys": { "openai": REDACTED_VALUE, "groq": "" },
+                "profiles": [{ "name": "Work", "webhook_secret": REDACTED_VALUE }],
+                "remote": { "auth_token": [REDACTED_VALUE, REDACTED_VALUE], "timeout": 30 },
+            })
+        );
+    }
+}
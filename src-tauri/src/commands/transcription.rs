@@ -1,6 +1,6 @@
 use crate::managers::transcription::{
     apply_accelerator_settings, get_available_accelerators as collect_available_accelerators,
-    AvailableAccelerators, TranscriptionManager,
+    AvailableAccelerators, ModelLoadState, TranscriptionManager,
 };
 use crate::settings::{
     get_settings, write_settings, ModelUnloadTimeout, OrtAcceleratorSetting,
@@ -17,6 +17,33 @@ pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
     write_settings(&app, settings);
 }
 
+/// Idle minutes before unloading when `model_unload_timeout` is `custom`.
+#[tauri::command]
+#[specta::specta]
+pub fn change_model_keep_loaded_minutes_setting(app: AppHandle, minutes: u32) {
+    let mut settings = get_settings(&app);
+    settings.model_keep_loaded_minutes = minutes.clamp(1, 1440);
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_model_preload_on_startup_setting(app: AppHandle, enabled: bool) {
+    let mut settings = get_settings(&app);
+    settings.model_preload_on_startup = enabled;
+    write_settings(&app, settings);
+}
+
+/// Whether a local model is currently in memory; kept current by the
+/// `model-load-state` event.
+#[tauri::command]
+#[specta::specta]
+pub fn get_model_load_state(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+) -> ModelLoadState {
+    transcription_manager.model_load_state()
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn unload_model_manually(
@@ -0,0 +1,22 @@
+//! Tauri commands for local usage statistics.
+
+use crate::managers::usage_stats::{UsageStats, UsageStatsManager, UsageStatsRange};
+use std::sync::Arc;
+use tauri::State;
+
+/// Aggregated usage for `range`, ending today.
+#[tauri::command]
+#[specta::specta]
+pub fn get_usage_stats(
+    manager: State<Arc<UsageStatsManager>>,
+    range: UsageStatsRange,
+) -> UsageStats {
+    manager.stats(range)
+}
+
+/// Clears all usage statistics.
+#[tauri::command]
+#[specta::specta]
+pub fn reset_usage_stats(manager: State<Arc<UsageStatsManager>>) -> Result<(), String> {
+    manager.reset()
+}
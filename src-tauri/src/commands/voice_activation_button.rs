@@ -93,3 +93,32 @@ pub fn voice_activation_button_release(app: AppHandle) -> Result<(), String> {
     action.stop(&app, "transcribe", "voice_activation_button");
     Ok(())
 }
+
+/// Names of the connected monitors, in the order `voice_button_monitor`
+/// display indexes refer to.
+#[tauri::command]
+#[specta::specta]
+pub fn voice_activation_button_list_monitors(app: AppHandle) -> Result<Vec<String>, String> {
+    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
+    Ok(monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| {
+            let size = monitor.size();
+            let name = monitor
+                .name()
+                .map(|name| name.trim_start_matches(r"\\.\").to_string())
+                .unwrap_or_else(|| format!("Display {}", index + 1));
+            format!("{} ({}x{})", name, size.width, size.height)
+        })
+        .collect())
+}
+
+/// Forgets the dragged positions and moves an open button back to its default spot.
+#[tauri::command]
+#[specta::specta]
+pub fn reset_voice_button_position(app: AppHandle) -> Result<(), String> {
+    crate::voice_button_position::reset(&app);
+    crate::overlay::update_voice_activation_button_position(&app);
+    Ok(())
+}
@@ -9,6 +9,9 @@ use std::os::windows::process::CommandExt;
 use std::process::Command;
 
 use crate::settings::{ExecutionPolicy, ResolvedExecutionOptions};
+use crate::voice_command_matcher::{
+    dry_run, explain_matches, FuzzyMatchConfig, VoiceCommandDryRun, VoiceCommandMatchExplanation,
+};
 
 #[cfg(target_os = "windows")]
 const CREATE_NEW_CONSOLE: u32 = 0x00000010;
@@ -57,23 +60,18 @@ pub fn execute_voice_command(
     execute_powershell_command(&script, &options)
 }
 
-/// Internal function to execute PowerShell commands.
+/// Builds the PowerShell invocation for `script`. Silent scripts run hidden;
+/// windowed ones get their own console with `-NoExit` so it stays open.
 #[cfg(target_os = "windows")]
-fn execute_powershell_command(
+pub(crate) fn build_powershell_command(
     script: &str,
     options: &ResolvedExecutionOptions,
-) -> Result<String, String> {
+) -> Command {
     let shell = if options.use_pwsh {
         "pwsh"
     } else {
         "powershell"
     };
-
-    info!(
-        "Executing voice command via {}: {} (silent={}, no_profile={}, policy={:?})",
-        shell, script, options.silent, options.no_profile, options.execution_policy
-    );
-
     let mut cmd = Command::new(shell);
 
     // Add -NoProfile flag if requested
@@ -108,53 +106,46 @@ fn execute_powershell_command(
         }
     }
 
-    // Add the command
-    cmd.args(["-Command", script]);
-
     if options.silent {
-        // Silent execution: hide window, fire-and-forget (non-blocking)
+        cmd.args(["-Command", script]);
         cmd.creation_flags(CREATE_NO_WINDOW);
+    } else {
+        // -NoExit goes before -Command to keep the window open
+        cmd.args(["-NoExit", "-Command", script]);
+        cmd.creation_flags(CREATE_NEW_CONSOLE);
+    }
+    cmd
+}
+
+/// Internal function to execute PowerShell commands.
+#[cfg(target_os = "windows")]
+fn execute_powershell_command(
+    script: &str,
+    options: &ResolvedExecutionOptions,
+) -> Result<String, String> {
+    let shell = if options.use_pwsh {
+        "pwsh"
+    } else {
+        "powershell"
+    };
 
+    info!(
+        "Executing voice command via {}: {} (silent={}, no_profile={}, policy={:?})",
+        shell, script, options.silent, options.no_profile, options.execution_policy
+    );
+
+    let mut cmd = build_powershell_command(script, options);
+
+    if options.silent {
+        // Silent execution: hidden window, fire-and-forget (non-blocking)
         cmd.spawn()
             .map_err(|e| format!("Failed to spawn command: {}", e))?;
 
         Ok("Command started in background".to_string())
     } else {
-        // Windowed execution: show console, add -NoExit to keep window open
         debug!("Opening {} window with -NoExit for: {}", shell, script);
 
-        // Rebuild command with -NoExit before -Command
-        let mut windowed_cmd = Command::new(shell);
-
-        if options.no_profile {
-            windowed_cmd.arg("-NoProfile");
-        }
-
-        match options.execution_policy {
-            ExecutionPolicy::Default => {}
-            ExecutionPolicy::Bypass => {
-                windowed_cmd.args(["-ExecutionPolicy", "Bypass"]);
-            }
-            ExecutionPolicy::Unrestricted => {
-                windowed_cmd.args(["-ExecutionPolicy", "Unrestricted"]);
-            }
-            ExecutionPolicy::RemoteSigned => {
-                windowed_cmd.args(["-ExecutionPolicy", "RemoteSigned"]);
-            }
-        }
-
-        if let Some(ref dir) = options.working_directory {
-            if !dir.trim().is_empty() {
-                windowed_cmd.current_dir(dir);
-            }
-        }
-
-        // Add -NoExit before -Command to keep window open
-        windowed_cmd.args(["-NoExit", "-Command", script]);
-        windowed_cmd.creation_flags(CREATE_NEW_CONSOLE);
-
-        windowed_cmd
-            .spawn()
+        cmd.spawn()
             .map_err(|e| format!("Failed to open {} window: {}", shell, e))?;
 
         Ok("Command opened in PowerShell window".to_string())
@@ -185,10 +176,9 @@ pub async fn test_voice_command_mock(
     app: tauri::AppHandle,
     mock_text: String,
 ) -> Result<String, String> {
-    use crate::actions::{
-        find_matching_command, generate_command_with_llm, CommandConfirmPayload, FuzzyMatchConfig,
-    };
+    use crate::actions::{generate_command_with_llm, CommandConfirmPayload};
     use crate::settings::get_settings;
+    use crate::voice_command_matcher::find_matching_command;
     use log::debug;
 
     if mock_text.trim().is_empty() {
@@ -284,6 +274,47 @@ pub async fn test_voice_command_mock(
     ))
 }
 
+/// Explains how a transcription scores against every enabled voice command:
+/// the per-word similarity matrix, coverage/quality/length components, the
+/// final score and whether it passed the command's threshold.
+#[tauri::command]
+#[specta::specta]
+pub fn explain_voice_command_match(
+    app: tauri::AppHandle,
+    transcription: String,
+) -> Result<Vec<VoiceCommandMatchExplanation>, String> {
+    let settings = crate::settings::get_settings(&app);
+    let fuzzy_config = FuzzyMatchConfig::from_settings(&settings);
+
+    Ok(explain_matches(
+        &transcription,
+        &settings.voice_commands,
+        settings.voice_command_default_threshold,
+        &fuzzy_config,
+    ))
+}
+
+/// Dry run of voice command matching for tuning the fuzzy thresholds: scores
+/// `transcription` against every enabled command, including which mechanism
+/// (exact/levenshtein/phonetic) matched each word. Nothing is executed, so it
+/// works even while voice commands are turned off.
+#[tauri::command]
+#[specta::specta]
+pub fn test_voice_command_match(
+    app: tauri::AppHandle,
+    transcription: String,
+) -> Result<VoiceCommandDryRun, String> {
+    let settings = crate::settings::get_settings(&app);
+    let fuzzy_config = FuzzyMatchConfig::from_settings(&settings);
+
+    Ok(dry_run(
+        &transcription,
+        &settings.voice_commands,
+        settings.voice_command_default_threshold,
+        &fuzzy_config,
+    ))
+}
+
 /// Format ExecutionPolicy for frontend display.
 #[cfg(target_os = "windows")]
 fn format_execution_policy(policy: ExecutionPolicy) -> Option<String> {
@@ -0,0 +1,343 @@
+//! Appends finished transcriptions to a per-profile file, e.g. a daily note.
+//!
+//! Appends run on a single worker thread, so rapid dictations land in order
+//! and never interleave. Failures are reported with `file-output-error` and
+//! never affect the paste. With `instead_of_paste` the profile appends only.
+//!
+//! Profiles whose output destination is a file write here instead of pasting;
+//! those appends use the same worker but wait for it, so failures reach the
+//! error overlay.
+
+use log::{debug, error};
+use serde::Serialize;
+use specta::Type;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use tauri::{AppHandle, Emitter};
+
+use crate::settings::{FileOutputSettings, OutputDestination};
+
+pub const FILE_OUTPUT_ERROR_EVENT: &str = "file-output-error";
+pub const DEFAULT_ENTRY_TEMPLATE: &str = "${output}";
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct FileOutputErrorEvent {
+    pub path: String,
+    pub error: String,
+}
+
+/// How the worker reports the outcome of an append.
+enum AppendReport {
+    /// Failures are emitted as `file-output-error`.
+    Event(AppHandle),
+    /// The result is sent back to a caller waiting for the write.
+    Reply(mpsc::SyncSender<std::io::Result<()>>),
+}
+
+struct AppendJob {
+    path: PathBuf,
+    entry: String,
+    /// Separates the entry from earlier ones with a blank line.
+    blank_line_separated: bool,
+    report: AppendReport,
+}
+
+static WRITER: Mutex<Option<mpsc::Sender<AppendJob>>> = Mutex::new(None);
+
+/// Sender of the append worker, which is started on first use.
+fn writer() -> std::io::Result<mpsc::Sender<AppendJob>> {
+    let mut writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(tx) = writer.as_ref() {
+        return Ok(tx.clone());
+    }
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .name("file-output".into())
+        .spawn(move || writer_worker(rx))?;
+    *writer = Some(tx.clone());
+    Ok(tx)
+}
+
+fn writer_worker(rx: mpsc::Receiver<AppendJob>) {
+    for job in rx {
+        let result = if job.blank_line_separated {
+            append_separated_entry(&job.path, &job.entry)
+        } else {
+            append_entry(&job.path, &job.entry)
+        };
+        match job.report {
+            AppendReport::Reply(done) => {
+                let _ = done.send(result);
+            }
+            AppendReport::Event(app) => match result {
+                Ok(()) => debug!("Appended transcription to {}", job.path.display()),
+                Err(err) => emit_error(&app, &job.path, &err.to_string()),
+            },
+        }
+    }
+}
+
+/// Hands `job` to the append worker.
+fn send_job(job: AppendJob) -> Result<(), String> {
+    let tx = writer().map_err(|err| format!("Failed to start file output worker: {}", err))?;
+    tx.send(job)
+        .map_err(|_| "file output worker is not running".to_string())
+}
+
+fn emit_error(app: &AppHandle, path: &Path, error: &str) {
+    error!("File output to {} failed: {}", path.display(), error);
+    let _ = app.emit(
+        FILE_OUTPUT_ERROR_EVENT,
+        FileOutputErrorEvent {
+            path: path.display().to_string(),
+            error: error.to_string(),
+        },
+    );
+}
+
+/// Expands `${date_iso}` in a file name or path to the date part only
+/// (`YYYY-MM-DD`).
+pub fn render_filename(template: &str, date: chrono::NaiveDate) -> String {
+    template.replace("${date_iso}", &date.format("%Y-%m-%d").to_string())
+}
+
+fn resolve_path(config: &FileOutputSettings, date: chrono::NaiveDate) -> PathBuf {
+    Path::new(&config.directory).join(render_filename(&config.filename_template, date))
+}
+
+/// Appends `entry` as its own newline-terminated block, creating the file and
+/// its directory as needed. A file that does not end with a newline (e.g.
+/// edited by hand) gets one first, so the entry never joins the last line.
+fn append_entry(path: &Path, entry: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(path)?;
+
+    let mut text = String::with_capacity(entry.len() + 2);
+    if !ends_with_newline(&mut file)? {
+        text.push('\n');
+    }
+    text.push_str(entry);
+    if !entry.ends_with('\n') {
+        text.push('\n');
+    }
+    file.write_all(text.as_bytes())
+}
+
+/// True for empty files and files whose last byte is `\n`.
+fn ends_with_newline(file: &mut File) -> std::io::Result<bool> {
+    if file.metadata()?.len() == 0 {
+        return Ok(true);
+    }
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::End(-1))?;
+    file.read_exact(&mut last)?;
+    Ok(last[0] == b'\n')
+}
+
+/// Queues `entry` for appending to today's file of `config`.
+pub fn queue_append(app: &AppHandle, config: &FileOutputSettings, entry: String) {
+    let path = resolve_path(config, chrono::Local::now().date_naive());
+    let job = AppendJob {
+        path: path.clone(),
+        entry,
+        blank_line_separated: false,
+        report: AppendReport::Event(app.clone()),
+    };
+    if let Err(err) = send_job(job) {
+        emit_error(app, &path, &err);
+    }
+}
+
+/// Appends `text` to the file of an output destination under a timestamp
+/// header, a blank line after the previous entry. The write goes through the
+/// append worker; this waits for it and returns the written path.
+pub fn append_to_destination(path_template: &str, text: &str) -> Result<PathBuf, String> {
+    let now = chrono::Local::now();
+    let path = PathBuf::from(render_filename(path_template, now.date_naive()));
+    let entry = format!(
+        "## {}\n{}",
+        now.format("%Y-%m-%d %H:%M:%S"),
+        text.trim_end()
+    );
+    let (done, result) = mpsc::sync_channel(1);
+    send_job(AppendJob {
+        path: path.clone(),
+        entry,
+        blank_line_separated: true,
+        report: AppendReport::Reply(done),
+    })
+    .and_then(|()| {
+        result
+            .recv()
+            .map_err(|_| "file output worker stopped".to_string())?
+            .map_err(|err| err.to_string())
+    })
+    .map_err(|err| format!("Could not append to {}: {}", path.display(), err))?;
+    Ok(path)
+}
+
+fn append_separated_entry(path: &Path, entry: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let separator = if file.metadata()?.len() == 0 {
+        ""
+    } else {
+        "\n\n"
+    };
+    file.write_all(format!("{separator}{entry}").as_bytes())
+}
+
+/// Trims and validates a profile's output destination before it is saved.
+pub fn normalize_destination(destination: OutputDestination) -> Result<OutputDestination, String> {
+    let OutputDestination::AppendToFile { path_template } = destination else {
+        return Ok(destination);
+    };
+    let path_template = path_template.trim().to_string();
+    if path_template.is_empty() {
+        return Err("Output file path is required".to_string());
+    }
+    let today = chrono::Local::now().date_naive();
+    if !Path::new(&render_filename(&path_template, today)).is_absolute() {
+        return Err("Output file path must be absolute".to_string());
+    }
+    Ok(OutputDestination::AppendToFile { path_template })
+}
+
+/// Trims and validates a profile's file output before it is saved.
+pub fn normalize_settings(
+    config: Option<FileOutputSettings>,
+) -> Result<Option<FileOutputSettings>, String> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+    let directory = config.directory.trim().to_string();
+    let filename_template = config.filename_template.trim().to_string();
+    if directory.is_empty() {
+        return Err("File output directory is required".to_string());
+    }
+    if filename_template.is_empty() {
+        return Err("File output file name is required".to_string());
+    }
+    if filename_template.contains(['/', '\\']) {
+        return Err("File output file name must not contain path separators".to_string());
+    }
+    let entry_template = if config.entry_template.trim().is_empty() {
+        DEFAULT_ENTRY_TEMPLATE.to_string()
+    } else {
+        config.entry_template
+    };
+    Ok(Some(FileOutputSettings {
+        directory,
+        filename_template,
+        entry_template,
+        instead_of_paste: config.instead_of_paste,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("aivorelay-file-output-{name}-{unique}"))
+    }
+
+    #[test]
+    fn filename_uses_date_part_only() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+
+        assert_eq!(render_filename("${date_iso}.md", date), "2026-03-09.md");
+        assert_eq!(render_filename("notes.md", date), "notes.md");
+    }
+
+    #[test]
+    fn append_creates_directory_and_terminates_entries() {
+        let dir = temp_dir("append");
+        let path = dir.join("nested").join("2026-03-09.md");
+
+        append_entry(&path, "- first").unwrap();
+        append_entry(&path, "- second\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "- first\n- second\n");
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn append_starts_new_line_after_hand_edited_file() {
+        let dir = temp_dir("edited");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("note.md");
+        fs::write(&path, "# Today").unwrap();
+
+        append_entry(&path, "- entry").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# Today\n- entry\n");
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn destination_appends_go_through_the_worker() {
+        let dir = temp_dir("worker");
+        let template = dir.join("${date_iso}.md").display().to_string();
+
+        let first = append_to_destination(&template, "hello\n").unwrap();
+        let second = append_to_destination(&template, "world").unwrap();
+
+        assert_eq!(first, second);
+        assert!(!first.display().to_string().contains("${date_iso}"));
+        let written = fs::read_to_string(&first).unwrap();
+        assert!(written.starts_with("## "));
+        assert!(written.contains("\nhello\n\n## "));
+        assert!(written.ends_with("\nworld"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn destination_entries_are_separated_by_a_blank_line() {
+        let dir = temp_dir("destination");
+        let path = dir.join("journal.md");
+
+        append_separated_entry(&path, "## first\nhello").unwrap();
+        append_separated_entry(&path, "## second\nworld").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "## first\nhello\n\n## second\nworld"
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn normalize_rejects_incomplete_settings() {
+        let config = |directory: &str, filename_template: &str| FileOutputSettings {
+            directory: directory.to_string(),
+            filename_template: filename_template.to_string(),
+            entry_template: "  ".to_string(),
+            instead_of_paste: false,
+        };
+
+        assert!(normalize_settings(Some(config(" ", "${date_iso}.md"))).is_err());
+        assert!(normalize_settings(Some(config("/notes", "a/b.md"))).is_err());
+        let normalized = normalize_settings(Some(config(" /notes ", "${date_iso}.md")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(normalized.directory, "/notes");
+        assert_eq!(normalized.entry_template, DEFAULT_ENTRY_TEMPLATE);
+    }
+}
@@ -198,6 +198,60 @@ pub fn render_diarized_transcript(
         .join("\n")
 }
 
+/// Runs `process` over each block's text, dropping blocks that end up empty
+/// and merging neighbours that now belong to the same speaker.
+pub fn process_diarized_blocks<F>(
+    blocks: Vec<DiarizedTranscriptBlock>,
+    mut process: F,
+) -> Vec<DiarizedTranscriptBlock>
+where
+    F: FnMut(String) -> String,
+{
+    let mut processed_blocks = Vec::new();
+
+    for block in blocks {
+        let text = process(block.text);
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        push_or_merge_block(
+            &mut processed_blocks,
+            block.speaker_id,
+            block.default_name,
+            trimmed.to_string(),
+        );
+    }
+
+    processed_blocks
+}
+
+/// Renders speaker blocks as `Speaker N: text` lines for pasted output,
+/// numbering speakers from 1 in order of appearance. `process` runs per
+/// utterance so corrections never touch the labels. Returns None when no
+/// labeled text remains.
+pub fn render_speaker_labeled_transcript<F>(
+    raw_blocks: Vec<RawSpeakerBlock>,
+    process: F,
+) -> Option<String>
+where
+    F: FnMut(String) -> String,
+{
+    let blocks = process_diarized_blocks(normalize_raw_speaker_blocks(raw_blocks), process);
+    if blocks.is_empty() {
+        return None;
+    }
+
+    Some(
+        blocks
+            .iter()
+            .map(|block| format!("Speaker {}: {}", block.speaker_id + 1, block.text))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
 fn read_artifact(artifact_path: &str) -> Result<DiarizedTranscriptArtifact, String> {
     let validated_path = validate_artifact_path(artifact_path)?;
     let raw = fs::read_to_string(&validated_path)
@@ -335,3 +389,60 @@ fn sanitize_speaker_name(default_name: &str, name: &str) -> String {
         cleaned
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(speaker_key: &str, text: &str) -> RawSpeakerBlock {
+        RawSpeakerBlock {
+            speaker_key: speaker_key.to_string(),
+            default_name: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn labels_speakers_in_order_of_appearance() {
+        let rendered = render_speaker_labeled_transcript(
+            vec![
+                raw("2", "Hello there."),
+                raw("1", "Hi."),
+                raw("2", "How are you?"),
+            ],
+            |text| text,
+        );
+
+        assert_eq!(
+            rendered.as_deref(),
+            Some("Speaker 1: Hello there.\nSpeaker 2: Hi.\nSpeaker 1: How are you?")
+        );
+    }
+
+    #[test]
+    fn processing_runs_per_utterance_and_merges_emptied_turns() {
+        let mut seen = Vec::new();
+        let rendered = render_speaker_labeled_transcript(
+            vec![raw("1", "um so"), raw("2", "um"), raw("1", "we ship")],
+            |text| {
+                seen.push(text.clone());
+                text.replace("um", "").trim().to_string()
+            },
+        );
+
+        assert_eq!(seen, vec!["um so", "um", "we ship"]);
+        assert_eq!(rendered.as_deref(), Some("Speaker 1: so we ship"));
+    }
+
+    #[test]
+    fn no_speaker_blocks_render_nothing() {
+        assert_eq!(
+            render_speaker_labeled_transcript(Vec::new(), |text| text),
+            None
+        );
+        assert_eq!(
+            render_speaker_labeled_transcript(vec![raw("1", "uh")], |_| String::new()),
+            None
+        );
+    }
+}
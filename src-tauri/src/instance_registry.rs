@@ -0,0 +1,228 @@
+//! Coordination between app instances running side by side with different
+//! `--profile-dir` data directories.
+//!
+//! Each instance publishes a small record (profile, pid, claimed shortcuts)
+//! in a shared temp directory and refreshes it on a heartbeat. Records that
+//! stop being refreshed are treated as belonging to an instance that exited.
+
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+/// A record not refreshed for this long belongs to an instance that is gone.
+const STALE_AFTER_SECS: i64 = 45;
+const DEFAULT_INSTANCE_KEY: &str = "default";
+const PROFILE_LOCK_FILE: &str = "instance.lock";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct InstanceRecord {
+    instance_key: String,
+    profile: Option<String>,
+    pid: u32,
+    updated_at: i64,
+    /// Binding ID -> normalized shortcut string.
+    shortcuts: BTreeMap<String, String>,
+}
+
+static OWN_SHORTCUTS: Lazy<Mutex<BTreeMap<String, String>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+static HEARTBEAT_STARTED: OnceLock<()> = OnceLock::new();
+/// Held for the life of the process; see `ensure_exclusive_profile`.
+static PROFILE_LOCK: OnceLock<File> = OnceLock::new();
+
+fn registry_dir() -> PathBuf {
+    std::env::temp_dir().join("aivorelay-instances")
+}
+
+fn own_instance_key() -> String {
+    crate::portable::profile_namespace().unwrap_or_else(|| DEFAULT_INSTANCE_KEY.to_string())
+}
+
+fn record_path(instance_key: &str) -> PathBuf {
+    registry_dir().join(format!("{}.json", instance_key))
+}
+
+fn is_fresh(record: &InstanceRecord) -> bool {
+    chrono::Utc::now().timestamp() - record.updated_at <= STALE_AFTER_SECS
+}
+
+fn read_record(path: &std::path::Path) -> Option<InstanceRecord> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn instance_label(record: &InstanceRecord) -> String {
+    match &record.profile {
+        Some(profile) => format!("profile '{}'", profile),
+        None => "the main instance".to_string(),
+    }
+}
+
+fn write_own_record() {
+    let shortcuts = OWN_SHORTCUTS
+        .lock()
+        .map(|shortcuts| shortcuts.clone())
+        .unwrap_or_default();
+    let record = InstanceRecord {
+        instance_key: own_instance_key(),
+        profile: crate::portable::profile_name().map(str::to_string),
+        pid: std::process::id(),
+        updated_at: chrono::Utc::now().timestamp(),
+        shortcuts,
+    };
+
+    if let Err(e) = std::fs::create_dir_all(registry_dir()) {
+        debug!("Failed to create instance registry directory: {}", e);
+        return;
+    }
+    match serde_json::to_string(&record) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(record_path(&record.instance_key), json) {
+                debug!("Failed to write instance record: {}", e);
+            }
+        }
+        Err(e) => debug!("Failed to serialize instance record: {}", e),
+    }
+}
+
+/// Fails when another process already runs with the same `--profile-dir`.
+/// Instances without a profile dir are guarded by the single-instance plugin.
+///
+/// The check is an OS lock on a file in the profile dir, held until this
+/// process exits; the OS drops it even after a crash, so a stale lock never
+/// blocks a restart.
+pub fn ensure_exclusive_profile() -> Result<(), String> {
+    let (Some(profile), Some(dir)) = (crate::portable::profile_name(), crate::portable::data_dir())
+    else {
+        return Ok(());
+    };
+
+    let path = dir.join(PROFILE_LOCK_FILE);
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    match file.try_lock() {
+        Ok(()) => {
+            let _ = PROFILE_LOCK.set(file);
+            Ok(())
+        }
+        Err(TryLockError::WouldBlock) => {
+            let holder = read_record(&record_path(&own_instance_key()))
+                .filter(|record| record.pid != std::process::id())
+                .map(|record| format!(" (pid {})", record.pid))
+                .unwrap_or_default();
+            Err(format!(
+                "Another instance{} is already running with profile '{}'",
+                holder, profile
+            ))
+        }
+        Err(TryLockError::Error(e)) => Err(format!("Failed to lock {}: {}", path.display(), e)),
+    }
+}
+
+/// Publishes this instance's record and keeps it fresh in the background.
+pub fn start_heartbeat() {
+    if HEARTBEAT_STARTED.set(()).is_err() {
+        return;
+    }
+
+    write_own_record();
+    let spawned = thread::Builder::new()
+        .name("instance-heartbeat".into())
+        .spawn(|| loop {
+            thread::sleep(HEARTBEAT_INTERVAL);
+            write_own_record();
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start instance heartbeat: {}", e);
+    }
+}
+
+fn normalize_shortcut(shortcut: &str) -> String {
+    let mut parts: Vec<String> = shortcut
+        .split('+')
+        .map(|part| part.trim().to_lowercase())
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.as_str() {
+            "control" => "ctrl".to_string(),
+            "option" => "alt".to_string(),
+            "command" | "cmd" | "meta" | "win" | "windows" => "super".to_string(),
+            _ => part,
+        })
+        .collect();
+    parts.sort();
+    parts.join("+")
+}
+
+/// Returns a description of the other live instance that already owns
+/// `shortcut`, if any.
+pub fn shortcut_owner_in_other_instance(shortcut: &str) -> Option<String> {
+    let normalized = normalize_shortcut(shortcut);
+    if normalized.is_empty() {
+        return None;
+    }
+
+    let own_key = own_instance_key();
+    let entries = std::fs::read_dir(registry_dir()).ok()?;
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| read_record(&entry.path()))
+        .filter(|record| record.instance_key != own_key && record.pid != std::process::id())
+        .filter(is_fresh)
+        .find(|record| record.shortcuts.values().any(|owned| *owned == normalized))
+        .map(|record| instance_label(&record))
+}
+
+/// Records that this instance now owns `shortcut` for `binding_id`.
+pub fn claim_shortcut(binding_id: &str, shortcut: &str) {
+    if let Ok(mut shortcuts) = OWN_SHORTCUTS.lock() {
+        shortcuts.insert(binding_id.to_string(), normalize_shortcut(shortcut));
+    }
+    if HEARTBEAT_STARTED.get().is_some() {
+        write_own_record();
+    }
+}
+
+pub fn release_shortcut(binding_id: &str) {
+    let removed = OWN_SHORTCUTS
+        .lock()
+        .map(|mut shortcuts| shortcuts.remove(binding_id).is_some())
+        .unwrap_or(false);
+    if removed && HEARTBEAT_STARTED.get().is_some() {
+        write_own_record();
+    }
+}
+
+/// Removes this instance's record so others stop seeing its shortcuts at once.
+pub fn remove_own_record() {
+    let path = record_path(&own_instance_key());
+    if read_record(&path).is_some_and(|record| record.pid == std::process::id()) {
+        let _ = std::fs::remove_file(&path);
+        info!("Removed instance registry record");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_modifier_aliases_and_order() {
+        assert_eq!(
+            normalize_shortcut("Control+Shift+Space"),
+            normalize_shortcut("shift + ctrl + space")
+        );
+        assert_eq!(normalize_shortcut("Cmd+K"), normalize_shortcut("super+k"));
+        assert_ne!(normalize_shortcut("ctrl+k"), normalize_shortcut("alt+k"));
+    }
+}
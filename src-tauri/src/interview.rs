@@ -0,0 +1,350 @@
+//! Interview mode: the microphone ("Me") and the system output ("Them") are
+//! recorded as separate channels and transcribed turn by turn.
+//!
+//! Both channels are placed on a shared timeline by frame arrival time, so
+//! the stretches where WASAPI loopback delivers nothing (silent speakers) are
+//! filled with zeros instead of pulling later audio forward. Each channel is
+//! then cut into turns from its own speech activity, so overlapping speech is
+//! kept; the turns are transcribed from their channel and merged by start time.
+
+use crate::settings::AppSettings;
+
+pub const SAMPLE_RATE: usize = 16_000;
+pub const UNSUPPORTED_MESSAGE: &str =
+    "Interview mode needs system audio loopback capture, which is only available on Windows.";
+
+/// Late frames within this distance are appended as-is to absorb callback jitter.
+const ARRIVAL_TOLERANCE_SAMPLES: usize = SAMPLE_RATE / 10;
+const ACTIVITY_WINDOW_SAMPLES: usize = SAMPLE_RATE * 30 / 1000;
+const ACTIVITY_RMS_THRESHOLD: f32 = 0.01;
+/// Runs with less speech than this (~240 ms) are treated as noise or cross-talk.
+const MIN_TURN_ACTIVE_WINDOWS: usize = 8;
+/// Pauses up to this long (~0.8 s) stay inside a turn.
+const MAX_TURN_PAUSE_WINDOWS: usize = 27;
+/// Audio kept before and after the detected speech of a turn.
+const TURN_PADDING_SAMPLES: usize = SAMPLE_RATE / 5;
+
+pub fn is_supported() -> bool {
+    cfg!(target_os = "windows")
+}
+
+pub fn is_interview_binding(settings: &AppSettings, binding_id: &str) -> bool {
+    settings
+        .bindings
+        .get(binding_id)
+        .is_some_and(|binding| binding.interview_mode)
+}
+
+/// Turns off every live/streaming path in a recording snapshot. Interview
+/// recordings are transcribed per turn after stop, and their frame callbacks
+/// are owned by the channel capture.
+pub fn disable_streaming(settings: &mut AppSettings) {
+    settings.soniox_live_enabled = false;
+    settings.soniox_live_preview_enabled = false;
+    settings.soniox_optimize_delivery_preconnect_enabled = false;
+    settings.deepgram_live_enabled = false;
+    settings.openai_realtime_whisper_flatten_enabled = true;
+    settings.native_streaming_live_output_models.clear();
+    settings.local_preview_auto_flush_enabled = false;
+    settings.local_streaming_preview_enabled = false;
+    settings.preview_output_only_enabled = false;
+    for profile in &mut settings.transcription_profiles {
+        profile.preview_output_only_enabled = false;
+    }
+}
+
+/// One channel placed on the recording's wall-clock timeline.
+#[derive(Debug, Default)]
+pub struct ChannelTimeline {
+    samples: Vec<f32>,
+}
+
+impl ChannelTimeline {
+    /// Adds a frame that finished arriving `arrived_at` samples after the
+    /// recording started. Gaps longer than the jitter tolerance become silence.
+    pub fn push(&mut self, frame: &[f32], arrived_at: usize) {
+        let expected_start = arrived_at.saturating_sub(frame.len());
+        if expected_start > self.samples.len() + ARRIVAL_TOLERANCE_SAMPLES {
+            self.samples.resize(expected_start, 0.0);
+        }
+        self.samples.extend_from_slice(frame);
+    }
+
+    pub fn into_samples(self) -> Vec<f32> {
+        self.samples
+    }
+}
+
+/// Both channels of a finished interview recording, aligned at sample 0.
+#[derive(Debug, Clone, Default)]
+pub struct InterviewChannels {
+    pub me: Vec<f32>,
+    pub them: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Speaker {
+    Me,
+    Them,
+}
+
+impl Speaker {
+    pub fn label(self) -> &'static str {
+        match self {
+            Speaker::Me => "Me",
+            Speaker::Them => "Them",
+        }
+    }
+}
+
+/// A span of the timeline, in samples, attributed to one speaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub speaker: Speaker,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Turn {
+    pub fn start_secs(&self) -> f32 {
+        self.start as f32 / SAMPLE_RATE as f32
+    }
+}
+
+impl InterviewChannels {
+    pub fn turns(&self) -> Vec<Turn> {
+        split_turns(&self.me, &self.them)
+    }
+
+    /// Audio for `turn`, taken from the speaker's own channel. Turns shorter
+    /// than a second are padded the same way short recordings are.
+    pub fn turn_samples(&self, turn: &Turn) -> Vec<f32> {
+        let channel = match turn.speaker {
+            Speaker::Me => &self.me,
+            Speaker::Them => &self.them,
+        };
+        let end = turn.end.min(channel.len());
+        let start = turn.start.min(end);
+        let mut samples = channel[start..end].to_vec();
+        if !samples.is_empty() && samples.len() < SAMPLE_RATE {
+            samples.resize(SAMPLE_RATE * 5 / 4, 0.0);
+        }
+        samples
+    }
+
+    /// Both channels mixed to mono, for the history recording.
+    pub fn mixdown(&self) -> Vec<f32> {
+        let len = self.me.len().max(self.them.len());
+        (0..len)
+            .map(|i| {
+                let me = self.me.get(i).copied().unwrap_or(0.0);
+                let them = self.them.get(i).copied().unwrap_or(0.0);
+                ((me + them) * 0.5).clamp(-1.0, 1.0)
+            })
+            .collect()
+    }
+}
+
+fn window_rms(channel: &[f32], window: usize) -> f32 {
+    let start = window * ACTIVITY_WINDOW_SAMPLES;
+    let end = (start + ACTIVITY_WINDOW_SAMPLES).min(channel.len());
+    if start >= end {
+        return 0.0;
+    }
+    let energy: f32 = channel[start..end].iter().map(|s| s * s).sum();
+    (energy / (end - start) as f32).sqrt()
+}
+
+/// Speech spans of one channel, in samples. Windows above the activity
+/// threshold are joined across short pauses; spans with too little speech
+/// (a brief "mm-hm" or speaker bleed) are dropped.
+fn channel_turns(channel: &[f32], speaker: Speaker) -> Vec<Turn> {
+    let windows = channel.len().div_ceil(ACTIVITY_WINDOW_SAMPLES);
+    let mut runs: Vec<(usize, usize, usize)> = Vec::new();
+    for window in 0..windows {
+        if window_rms(channel, window) < ACTIVITY_RMS_THRESHOLD {
+            continue;
+        }
+        match runs.last_mut() {
+            Some((_, end_window, active_windows))
+                if window - *end_window <= MAX_TURN_PAUSE_WINDOWS =>
+            {
+                *end_window = window + 1;
+                *active_windows += 1;
+            }
+            _ => runs.push((window, window + 1, 1)),
+        }
+    }
+
+    runs.into_iter()
+        .filter(|(_, _, active_windows)| *active_windows >= MIN_TURN_ACTIVE_WINDOWS)
+        .map(|(first_window, end_window, _)| Turn {
+            speaker,
+            start: (first_window * ACTIVITY_WINDOW_SAMPLES).saturating_sub(TURN_PADDING_SAMPLES),
+            end: (end_window * ACTIVITY_WINDOW_SAMPLES + TURN_PADDING_SAMPLES).min(channel.len()),
+        })
+        .collect()
+}
+
+/// Splits each aligned channel into its own turns and merges them by start
+/// time, "Me" first on a tie. Turns of different speakers may overlap.
+pub fn split_turns(me: &[f32], them: &[f32]) -> Vec<Turn> {
+    let mut turns = channel_turns(me, Speaker::Me);
+    turns.extend(channel_turns(them, Speaker::Them));
+    turns.sort_by_key(|turn| turn.start);
+    turns
+}
+
+/// Renders transcribed turns as "Me: ..." / "Them: ..." lines, joining
+/// consecutive lines from the same speaker and skipping empty ones.
+pub fn render_transcript(lines: &[(Speaker, String)]) -> String {
+    let mut merged: Vec<(Speaker, String)> = Vec::new();
+    for (speaker, text) in lines {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        match merged.last_mut() {
+            Some((last_speaker, last_text)) if last_speaker == speaker => {
+                last_text.push(' ');
+                last_text.push_str(&text);
+            }
+            _ => merged.push((*speaker, text)),
+        }
+    }
+
+    merged
+        .iter()
+        .map(|(speaker, text)| format!("{}: {}", speaker.label(), text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(samples: usize) -> Vec<f32> {
+        (0..samples)
+            .map(|i| if i % 2 == 0 { 0.2 } else { -0.2 })
+            .collect()
+    }
+
+    fn silence(samples: usize) -> Vec<f32> {
+        vec![0.0; samples]
+    }
+
+    /// Turn edges fall on activity window boundaries.
+    fn assert_near(actual: usize, expected: usize) {
+        assert!(
+            actual.abs_diff(expected) < ACTIVITY_WINDOW_SAMPLES,
+            "{actual} is not within a window of {expected}"
+        );
+    }
+
+    #[test]
+    fn timeline_fills_loopback_gaps_with_silence() {
+        let mut timeline = ChannelTimeline::default();
+        timeline.push(&[0.5; 480], 480);
+        // Nothing arrives for a second, then playback resumes.
+        timeline.push(&[0.5; 480], SAMPLE_RATE + 960);
+
+        let samples = timeline.into_samples();
+        assert_eq!(samples.len(), SAMPLE_RATE + 960);
+        assert_eq!(samples[480], 0.0);
+        assert_eq!(samples[SAMPLE_RATE + 480], 0.5);
+    }
+
+    #[test]
+    fn timeline_absorbs_callback_jitter() {
+        let mut timeline = ChannelTimeline::default();
+        timeline.push(&[0.5; 480], 480);
+        timeline.push(&[0.5; 480], 960 + ARRIVAL_TOLERANCE_SAMPLES);
+
+        assert_eq!(timeline.into_samples().len(), 960);
+    }
+
+    #[test]
+    fn turns_are_merged_in_time_order() {
+        let second = SAMPLE_RATE;
+        let mut me = tone(second);
+        me.extend(silence(2 * second));
+        let mut them = silence(second + second / 2);
+        them.extend(tone(second));
+        them.extend(silence(second / 2));
+
+        let turns = split_turns(&me, &them);
+
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].speaker, Speaker::Me);
+        assert_eq!(turns[0].start, 0);
+        assert_near(turns[0].end, second + TURN_PADDING_SAMPLES);
+        assert_eq!(turns[1].speaker, Speaker::Them);
+        assert_near(turns[1].start, second + second / 2 - TURN_PADDING_SAMPLES);
+        assert_near(turns[1].end, 2 * second + second / 2 + TURN_PADDING_SAMPLES);
+    }
+
+    #[test]
+    fn overlapping_speech_keeps_both_turns() {
+        let second = SAMPLE_RATE;
+        let me = tone(2 * second);
+        let mut them = silence(second / 2);
+        them.extend(tone(second));
+
+        let turns = split_turns(&me, &them);
+
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].speaker, Speaker::Me);
+        assert_eq!(turns[0].end, 2 * second);
+        assert_eq!(turns[1].speaker, Speaker::Them);
+        assert!(turns[1].start < turns[0].end);
+    }
+
+    #[test]
+    fn short_pauses_stay_inside_a_turn() {
+        let second = SAMPLE_RATE;
+        let mut me = tone(second);
+        me.extend(silence(second / 2));
+        me.extend(tone(second));
+        me.extend(silence(2 * second));
+        me.extend(tone(second));
+
+        let turns = split_turns(&me, &[]);
+
+        assert_eq!(turns.len(), 2);
+        assert_near(turns[0].end, 2 * second + second / 2 + TURN_PADDING_SAMPLES);
+        assert_near(
+            turns[1].start,
+            4 * second + second / 2 - TURN_PADDING_SAMPLES,
+        );
+    }
+
+    #[test]
+    fn short_interjection_does_not_split_a_turn() {
+        let second = SAMPLE_RATE;
+        let them = tone(3 * second);
+        let mut me = silence(second);
+        me.extend((0..1_600).map(|i| if i % 2 == 0 { 0.5 } else { -0.5 }));
+        me.extend(silence(2 * second - 1_600));
+
+        let turns = split_turns(&me, &them);
+
+        assert_eq!(turns.len(), 1);
+        assert_eq!(turns[0].speaker, Speaker::Them);
+    }
+
+    #[test]
+    fn transcript_merges_consecutive_lines_and_skips_blanks() {
+        let lines = vec![
+            (Speaker::Me, "Hello there.".to_string()),
+            (Speaker::Them, "  ".to_string()),
+            (Speaker::Me, "How are you?".to_string()),
+            (Speaker::Them, "Fine,  thanks.".to_string()),
+        ];
+
+        assert_eq!(
+            render_transcript(&lines),
+            "Me: Hello there. How are you?\nThem: Fine, thanks."
+        );
+    }
+}
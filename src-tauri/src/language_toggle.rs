@@ -0,0 +1,163 @@
+//! Instant toggle between two configured dictation languages.
+//!
+//! With the default profile the toggle writes `selected_language`. With a
+//! non-default profile it keeps an in-memory override for that profile, so the
+//! profile itself is never rewritten and a restart restores its language.
+
+use log::info;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::managers::model::{self, ModelManager};
+use crate::settings::{self, AppSettings, TranscriptionProvider};
+
+pub const LANGUAGE_TOGGLE_BINDING_ID: &str = "toggle_dictation_language";
+
+/// Profile id -> language chosen with the toggle.
+static PROFILE_OVERRIDES: Lazy<Mutex<HashMap<String, String>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct DictationLanguageChangedEvent {
+    pub language: String,
+    /// None when the global language was changed.
+    pub profile_id: Option<String>,
+}
+
+/// Language set with the toggle for `profile_id`, if any.
+pub fn profile_language_override(profile_id: &str) -> Option<String> {
+    PROFILE_OVERRIDES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(profile_id)
+        .cloned()
+}
+
+/// Returns the other toggle language; anything but `primary` switches to it.
+fn next_language(current: &str, primary: &str, secondary: &str) -> String {
+    if current.trim().eq_ignore_ascii_case(primary) {
+        secondary.to_string()
+    } else {
+        primary.to_string()
+    }
+}
+
+/// Whether the selected local model accepts a language choice at all.
+fn supports_language_selection(app: &AppHandle, settings: &AppSettings) -> bool {
+    if settings.transcription_provider != TranscriptionProvider::Local {
+        return true;
+    }
+    app.state::<Arc<ModelManager>>()
+        .get_model_info(&settings.selected_model)
+        .map_or(true, |info| {
+            model::supports_language_selection(&info.supported_languages)
+        })
+}
+
+/// Swaps the dictation language of the active profile and returns the new one.
+pub fn toggle(app: &AppHandle) -> Result<String, String> {
+    let mut settings = settings::get_settings(app);
+    let primary = settings.language_toggle_primary.trim().to_string();
+    let secondary = settings.language_toggle_secondary.trim().to_string();
+    if primary.is_empty() || secondary.is_empty() {
+        return Err("Both language toggle languages must be configured".to_string());
+    }
+    if !supports_language_selection(app, &settings) {
+        return Err("The selected model does not support choosing a language".to_string());
+    }
+
+    let profile = (settings.active_profile_id != "default")
+        .then(|| settings.transcription_profile(&settings.active_profile_id))
+        .flatten()
+        .cloned();
+
+    let event = match profile {
+        Some(profile) => {
+            let current =
+                profile_language_override(&profile.id).unwrap_or_else(|| profile.language.clone());
+            let language = next_language(&current, &primary, &secondary);
+            {
+                let mut overrides = PROFILE_OVERRIDES.lock().unwrap_or_else(|e| e.into_inner());
+                if language == profile.language {
+                    overrides.remove(&profile.id);
+                } else {
+                    overrides.insert(profile.id.clone(), language.clone());
+                }
+            }
+            DictationLanguageChangedEvent {
+                language,
+                profile_id: Some(profile.id),
+            }
+        }
+        None => {
+            let language = next_language(&settings.selected_language, &primary, &secondary);
+            settings.selected_language = language.clone();
+            settings::write_settings(app, settings);
+            DictationLanguageChangedEvent {
+                language,
+                profile_id: None,
+            }
+        }
+    };
+
+    info!(
+        "Dictation language toggled to '{}' (profile: {:?})",
+        event.language, event.profile_id
+    );
+    let language = event.language.clone();
+    let _ = app.emit("dictation-language-changed", event);
+    Ok(language)
+}
+
+/// Short display name for the overlay; unknown codes are shown uppercased.
+pub fn language_display_name(code: &str) -> String {
+    let name = match code.split(['-', '_']).next().unwrap_or(code) {
+        "auto" => "Auto-detect",
+        "ar" => "Arabic",
+        "de" => "German",
+        "en" => "English",
+        "es" => "Spanish",
+        "fr" => "French",
+        "hi" => "Hindi",
+        "it" => "Italian",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "pt" => "Portuguese",
+        "ru" => "Russian",
+        "tr" => "Turkish",
+        "uk" => "Ukrainian",
+        "zh" => "Chinese",
+        _ => return code.to_uppercase(),
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_alternates_between_configured_languages() {
+        assert_eq!(next_language("en", "en", "ru"), "ru");
+        assert_eq!(next_language("ru", "en", "ru"), "en");
+    }
+
+    #[test]
+    fn unrelated_language_switches_to_primary() {
+        assert_eq!(next_language("auto", "en", "ru"), "en");
+        assert_eq!(next_language("de", "en", "ru"), "en");
+    }
+
+    #[test]
+    fn display_name_falls_back_to_code() {
+        assert_eq!(language_display_name("ru"), "Russian");
+        assert_eq!(language_display_name("zh-Hans"), "Chinese");
+        assert_eq!(language_display_name("xx"), "XX");
+    }
+}
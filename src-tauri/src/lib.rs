@@ -1,24 +1,41 @@
+mod accessibility_announcer;
+mod action_api;
 mod actions;
 mod active_app;
+mod ai_replace_confirm;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
+mod boost;
 mod catalog;
 pub mod cli;
 mod clipboard;
 mod commands;
+mod file_output;
 mod file_transcription_diarization;
 mod helpers;
 mod hotkey_guide;
 mod input;
 mod input_source;
+mod instance_registry;
+mod interview;
 mod language_resolver;
+mod language_toggle;
+mod lifecycle_hooks;
 mod llm_client;
+mod llm_json_output;
 mod managers;
+mod output_length_limit;
+mod output_whitespace;
 mod overlay;
 mod plus_overlay_state;
 mod portable;
+mod post_process_chunking;
+mod power_events;
+mod privacy_mode;
+mod profile_schedule;
+mod quiet_hours;
 mod recording_auto_stop;
 #[cfg(target_os = "windows")]
 mod region_capture;
@@ -27,16 +44,25 @@ mod session_manager;
 mod settings;
 mod shortcut;
 mod shortcut_handy_keys;
+mod shortcut_integrity;
+#[cfg(target_os = "linux")]
+mod shortcut_portal;
 mod signal_handle;
+mod smart_join;
 mod soniox_stream_processor;
+mod stuck_modifiers;
 pub mod subtitle;
 mod text_output_hooks;
 mod text_replacement_decapitalize;
 mod transcript_context;
+mod transcription_confidence;
 mod tray;
 mod tray_i18n;
 mod url_security;
 mod utils;
+mod voice_button_position;
+mod voice_command_matcher;
+mod webhook;
 mod webview_hardening;
 #[cfg(target_os = "windows")]
 mod webview_runtime;
@@ -55,11 +81,13 @@ use managers::history::HistoryManager;
 use managers::key_listener::KeyListenerState;
 use managers::llm_operation::LlmOperationTracker;
 use managers::model::ModelManager;
+use managers::openai_realtime::OpenAiRealtimeManager;
 use managers::openai_realtime_whisper::OpenAiRealtimeWhisperManager;
 use managers::remote_stt::RemoteSttManager;
 use managers::soniox_realtime::SonioxRealtimeManager;
 use managers::soniox_stt::SonioxSttManager;
 use managers::transcription::TranscriptionManager;
+use managers::usage_stats::UsageStatsManager;
 #[cfg(unix)]
 use signal_hook::consts::SIGUSR2;
 #[cfg(unix)]
@@ -159,7 +187,7 @@ pub struct PressTimestamps {
 
 pub type ManagedPressTimestamps = Mutex<PressTimestamps>;
 
-fn show_main_window(app: &AppHandle) {
+pub(crate) fn show_main_window(app: &AppHandle) {
     if let Some(main_window) = app.get_webview_window("main") {
         if let Err(e) = main_window.unminimize() {
             log::error!("Failed to unminimize window: {}", e);
@@ -385,6 +413,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
         OpenAiRealtimeWhisperManager::new(app_handle)
             .expect("Failed to initialize OpenAI realtime Whisper STT"),
     );
+    let openai_realtime_manager = Arc::new(
+        OpenAiRealtimeManager::new(app_handle).expect("Failed to initialize OpenAI Realtime STT"),
+    );
     let soniox_realtime_manager = Arc::new(
         SonioxRealtimeManager::new(app_handle).expect("Failed to initialize Soniox realtime STT"),
     );
@@ -401,7 +432,9 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     let connector_manager = Arc::new(
         ConnectorManager::new(app_handle).expect("Failed to initialize connector manager"),
     );
+    let action_api_server = Arc::new(action_api::ActionApiServer::new(app_handle));
     let llm_operation_tracker = Arc::new(LlmOperationTracker::new());
+    let usage_stats_manager = Arc::new(UsageStatsManager::new(app_handle));
 
     // Initialize key listener
     let key_listener_state = KeyListenerState::new(app_handle.clone());
@@ -412,13 +445,16 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(transcription_manager.clone());
     app_handle.manage(remote_stt_manager.clone());
     app_handle.manage(openai_realtime_whisper_manager.clone());
+    app_handle.manage(openai_realtime_manager.clone());
     app_handle.manage(soniox_realtime_manager.clone());
     app_handle.manage(soniox_stt_manager.clone());
     app_handle.manage(deepgram_realtime_manager.clone());
     app_handle.manage(deepgram_stt_manager.clone());
     app_handle.manage(llm_operation_tracker.clone());
+    app_handle.manage(usage_stats_manager);
     app_handle.manage(history_manager.clone());
     app_handle.manage(connector_manager.clone());
+    app_handle.manage(action_api_server.clone());
     app_handle.manage(key_listener_state);
     app_handle.manage(settings::DictationStatsEditState::default());
 
@@ -426,6 +462,12 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // WASAPI stream against microphone shutdown for every cue.
     audio_feedback::init(app_handle);
 
+    if current_settings.model_preload_on_startup
+        && should_eagerly_initialize_local_backends(&current_settings)
+    {
+        transcription_manager.initiate_model_load();
+    }
+
     // Initialize region capture state (Windows only)
     #[cfg(target_os = "windows")]
     app_handle.manage(std::sync::Mutex::new(
@@ -439,8 +481,21 @@ fn initialize_core_logic(app_handle: &AppHandle) {
         }
     }
 
+    // Start the local action API for external tools (if enabled)
+    if let Err(e) = action_api_server.start() {
+        log::error!("Failed to start action API: {}", e);
+    }
+
+    // Publish this instance before registering shortcuts so other
+    // --profile-dir instances can see which bindings it owns.
+    instance_registry::start_heartbeat();
+
     // Initialize the shortcuts
     shortcut::init_shortcuts(app_handle);
+    shortcut_integrity::start_integrity_monitor(app_handle);
+    profile_schedule::start_schedule_monitor(app_handle);
+    power_events::start_watcher();
+    lifecycle_hooks::fire_app_start(app_handle);
 
     #[cfg(unix)]
     let signals = Signals::new(&[SIGUSR2]).unwrap();
@@ -620,10 +675,41 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 return;
             }
 
+            if let Some(profile_id) = tray::parse_profile_menu_selection(event.id.as_ref()) {
+                if let Err(err) = shortcut::set_active_profile(app.clone(), profile_id) {
+                    log::error!("Failed to switch profile via tray: {}", err);
+                }
+                tray::refresh_tray_menu(app, None);
+                return;
+            }
+
+            if let Some(provider) = tray::parse_provider_menu_selection(event.id.as_ref()) {
+                match shortcut::change_transcription_provider_setting(app.clone(), provider.clone())
+                {
+                    Ok(()) => log::info!("Transcription provider switched to {} via tray.", provider),
+                    Err(err) => {
+                        log::error!("Failed to switch transcription provider via tray: {}", err)
+                    }
+                }
+                tray::refresh_tray_menu(app, None);
+                return;
+            }
+
             match event.id.as_ref() {
                 "settings" => {
                     show_main_window(app);
                 }
+                tray::TRAY_POST_PROCESS_ID => {
+                    let enabled = !settings::get_settings(app).post_process_enabled;
+                    if let Err(err) = shortcut::change_post_process_enabled_setting(app.clone(), enabled)
+                    {
+                        log::error!("Failed to toggle post-processing via tray: {}", err);
+                    }
+                    tray::refresh_tray_menu(app, None);
+                }
+                tray::TRAY_PRIVACY_MODE_ID => {
+                    privacy_mode::toggle(app);
+                }
                 tray::TRAY_SHORTCUT_GUIDE_SHOW_IN_MAIN_ID => {
                     let mut settings = settings::get_settings(app);
                     settings.show_tray_shortcut_guide_in_main_menu = true;
@@ -679,6 +765,7 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 }
                 "quit" => {
                     APP_QUIT_REQUESTED.store(true, Ordering::SeqCst);
+                    instance_registry::remove_own_record();
                     app.exit(0);
                 }
                 _ => {}
@@ -701,6 +788,18 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.listen("model-state-changed", move |_| {
         tray::refresh_tray_menu(&tray_refresh_handle, None);
     });
+    for event in ["settings-changed", "active-profile-changed"] {
+        let tray_refresh_handle = app_handle.clone();
+        app_handle.listen(event, move |_| {
+            tray::refresh_tray_menu_unless_recording(&tray_refresh_handle);
+        });
+    }
+    for event in ["remote-stt-error", "ai-replace-error"] {
+        let tray_error_handle = app_handle.clone();
+        app_handle.listen(event, move |event| {
+            tray::record_last_error(&tray_error_handle, event.payload());
+        });
+    }
 
     // Get the autostart manager and configure based on user setting
     let autostart_manager = app_handle.autolaunch();
@@ -878,7 +977,7 @@ fn run_headless_transcription(app: &AppHandle, args: &CliArgs) -> i32 {
 
         let started = Instant::now();
         match tm.transcribe(samples.clone(), apply_custom_words_enabled) {
-            Ok(output) => text = output,
+            Ok(output) => text = output.text,
             Err(err) => {
                 eprintln!("error: transcribe failed: {}", err);
                 return 1;
@@ -922,7 +1021,8 @@ fn run_headless_transcription(app: &AppHandle, args: &CliArgs) -> i32 {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run(cli_args: CliArgs) {
-    portable::init();
+    // Reported from `setup`, once logging is up.
+    let profile_dir_error = portable::init(cli_args.profile_dir.as_deref()).err();
 
     // Release keeps the existing RUST_LOG filter. Dev builds instead use the
     // process-local Dev Console Log Level selector below, so it can reliably
@@ -937,10 +1037,15 @@ pub fn run(cli_args: CliArgs) {
             collect_commands![
         shortcut::change_binding,
         shortcut::reset_binding,
+        shortcut::change_binding_suppress_keypress_setting,
+        shortcut::change_binding_interview_mode_setting,
+        shortcut::change_binding_ptt_override,
         shortcut::change_ptt_setting,
         shortcut::change_preview_output_only_enabled_setting,
         shortcut::change_audio_feedback_setting,
         shortcut::change_result_ready_audio_feedback_setting,
+        shortcut::change_visual_feedback_enabled_setting,
+        shortcut::change_visual_feedback_tray_blink_setting,
         shortcut::change_audio_feedback_volume_setting,
         shortcut::change_sound_theme_setting,
         shortcut::change_start_hidden_setting,
@@ -948,13 +1053,18 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_show_tray_shortcut_guide_setting,
         shortcut::change_translate_to_english_setting,
         shortcut::change_selected_language_setting,
+        shortcut::change_language_toggle_primary_setting,
+        shortcut::change_language_toggle_secondary_setting,
         shortcut::change_transcription_provider_setting,
+        shortcut::change_transcription_race_mode_setting,
         shortcut::change_transcription_prompt_setting,
         shortcut::change_overlay_position_setting,
         shortcut::change_recording_overlay_enabled_setting,
         shortcut::change_auto_position_allow_reserved_areas_setting,
         shortcut::change_error_overlay_auto_hide_ms_setting,
         shortcut::change_error_feedback_enabled_setting,
+        shortcut::change_screen_reader_announcements_enabled_setting,
+        shortcut::change_screen_reader_verbosity_setting,
         shortcut::change_recording_overlay_custom_enabled_setting,
         shortcut::change_recording_overlay_show_drag_grip_setting,
         shortcut::change_recording_overlay_theme_setting,
@@ -964,6 +1074,7 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_recording_overlay_animated_border_mode_setting,
         shortcut::change_recording_overlay_show_status_icon_setting,
         shortcut::change_recording_overlay_show_cancel_button_setting,
+        shortcut::change_overlay_show_timer_setting,
         shortcut::change_recording_overlay_bar_count_setting,
         shortcut::change_recording_overlay_width_setting,
         shortcut::change_recording_overlay_bar_width_setting,
@@ -993,6 +1104,7 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_local_preview_auto_flush_enabled_setting,
         shortcut::change_local_preview_auto_flush_interval_setting,
         shortcut::change_local_preview_auto_flush_overlap_setting,
+        shortcut::change_local_streaming_preview_enabled_setting,
         shortcut::change_soniox_live_preview_sliding_lm_window_enabled_setting,
         shortcut::change_soniox_live_preview_sliding_lm_window_prompt_setting,
         shortcut::change_soniox_live_preview_sliding_lm_window_tail_words_setting,
@@ -1033,13 +1145,26 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_word_correction_threshold_setting,
         shortcut::change_paste_method_setting,
         shortcut::change_paste_delay_ms_setting,
+        shortcut::add_app_paste_override,
+        shortcut::update_app_paste_override,
+        shortcut::delete_app_paste_override,
+        shortcut::change_type_characters_delay_ms_setting,
         shortcut::change_clipboard_handling_setting,
         shortcut::change_auto_submit_setting,
         shortcut::change_auto_submit_key_setting,
+        shortcut::change_stuck_modifier_recovery_enabled_setting,
         shortcut::change_convert_lf_to_crlf_setting,
+        shortcut::change_stream_conservative_delete_setting,
+        shortcut::change_privacy_mode_duration_setting,
+        shortcut::change_boost_override_setting,
+        shortcut::change_boost_expiry_secs_setting,
         shortcut::change_remote_stt_base_url_setting,
         shortcut::change_remote_stt_provider_preset_setting,
         shortcut::change_remote_stt_allow_insecure_http_setting,
+        shortcut::change_remote_stt_auth_style_setting,
+        shortcut::change_remote_stt_extra_query_params_setting,
+        shortcut::change_remote_stt_retry_attempts_setting,
+        shortcut::change_remote_stt_retry_backoff_ms_setting,
         shortcut::change_remote_stt_model_id_setting,
         shortcut::change_openai_realtime_whisper_delay_setting,
         shortcut::change_openai_realtime_whisper_flatten_enabled_setting,
@@ -1050,14 +1175,23 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_soniox_context_general_json_setting,
         shortcut::change_soniox_context_text_setting,
         shortcut::change_soniox_context_terms_setting,
+        shortcut::change_soniox_sync_context_from_custom_words_setting,
         shortcut::change_soniox_use_profile_language_hint_only_setting,
         shortcut::change_soniox_language_hints_strict_setting,
         shortcut::change_soniox_endpoint_detection_setting,
         shortcut::change_soniox_max_endpoint_delay_ms_setting,
+        shortcut::change_soniox_live_auto_stop_on_endpoint_setting,
+        shortcut::change_soniox_live_auto_stop_endpoint_count_setting,
+        shortcut::change_auto_fallback_on_missing_model_setting,
+        shortcut::change_local_fallback_enabled_setting,
+        shortcut::change_local_fallback_model_setting,
+        shortcut::change_local_fallback_repetition_threshold_setting,
         shortcut::change_soniox_endpoint_sensitivity_setting,
         shortcut::change_soniox_language_identification_setting,
         shortcut::change_soniox_speaker_diarization_setting,
+        shortcut::change_soniox_include_speaker_labels_setting,
         shortcut::change_soniox_keepalive_interval_seconds_setting,
+        shortcut::change_soniox_live_reconnect_attempts_setting,
         shortcut::change_soniox_live_finalize_timeout_ms_setting,
         shortcut::change_soniox_live_instant_stop_setting,
         shortcut::change_soniox_optimize_delivery_preconnect_enabled_setting,
@@ -1076,11 +1210,16 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_deepgram_endpointing_enabled_setting,
         shortcut::change_deepgram_endpointing_ms_setting,
         shortcut::reset_deepgram_settings_to_defaults,
+        shortcut::change_openai_realtime_base_url_setting,
+        shortcut::change_openai_realtime_model_setting,
         shortcut::change_remote_stt_debug_capture_setting,
         shortcut::change_remote_stt_debug_mode_setting,
         shortcut::change_post_process_enabled_setting,
         shortcut::change_post_process_reasoning_enabled_setting,
         shortcut::change_post_process_reasoning_budget_setting,
+        shortcut::change_post_process_reasoning_enabled_over_words_setting,
+        shortcut::change_post_process_reasoning_budget_per_100_words_setting,
+        shortcut::change_post_process_chunk_threshold_chars_setting,
         shortcut::change_ai_replace_reasoning_enabled_setting,
         shortcut::change_ai_replace_reasoning_budget_setting,
         shortcut::change_voice_command_reasoning_enabled_setting,
@@ -1102,13 +1241,25 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_voice_command_word_similarity_threshold_setting,
         shortcut::change_post_process_base_url_setting,
         shortcut::change_post_process_custom_http_override_setting,
+        shortcut::change_post_process_custom_auth_style_setting,
+        shortcut::change_post_process_custom_extra_query_params_setting,
         shortcut::change_post_process_api_key_setting,
         shortcut::change_post_process_model_setting,
         shortcut::set_post_process_provider,
+        shortcut::change_post_process_fallback_provider_setting,
+        shortcut::change_post_process_fallback_model_setting,
         shortcut::fetch_post_process_models,
         shortcut::fetch_llm_models,
         shortcut::add_post_process_prompt,
         shortcut::update_post_process_prompt,
+        shortcut::change_post_process_prompt_output_limits,
+        shortcut::change_post_process_prompt_output_json_path,
+        shortcut::change_post_process_output_limit_strategy_setting,
+        shortcut::change_llm_clipboard_variable_max_chars_setting,
+        shortcut::change_punctuation_mode_setting,
+        shortcut::change_output_casing_setting,
+        shortcut::change_output_translation_language_setting,
+        shortcut::change_output_casing_exceptions_setting,
         shortcut::delete_post_process_prompt,
         shortcut::set_post_process_selected_prompt,
         shortcut::change_post_process_benchmark_collapsed_setting,
@@ -1122,21 +1273,38 @@ pub fn run(cli_args: CliArgs) {
         shortcut::delete_transcription_profile,
         shortcut::get_active_profile,
         shortcut::set_active_profile,
+        profile_schedule::change_profile_schedule_setting,
+        profile_schedule::get_effective_profile_source,
+        quiet_hours::change_quiet_hours_setting,
         shortcut::cycle_to_next_profile,
+        shortcut::set_profile_cycle_order,
         shortcut::change_profile_switch_overlay_enabled_setting,
         shortcut::update_custom_words,
+        shortcut::validate_custom_word_pattern,
+        shortcut::export_custom_words,
+        shortcut::import_custom_words,
+        shortcut::change_transcript_context_blocklist_setting,
         shortcut::change_custom_words_enabled_setting,
         shortcut::change_custom_words_ngram_enabled_setting,
+        shortcut::change_stt_prompt_auto_inject_custom_words_setting,
+        shortcut::change_stt_prompt_custom_words_max_chars_setting,
         shortcut::suspend_binding,
         shortcut::resume_binding,
+        shortcut_integrity::verify_shortcuts_now,
+        shortcut::change_shortcut_integrity_check_interval_seconds_setting,
+        shortcut::change_shortcut_double_tap_window_setting,
         shortcut_handy_keys::start_handy_keys_recording,
         shortcut_handy_keys::stop_handy_keys_recording,
         shortcut::change_mute_while_recording_setting,
+        shortcut::change_mute_while_recording_exempt_setting,
         shortcut::change_pause_media_while_recording_setting,
         shortcut::change_filter_silence_setting,
         shortcut::change_recording_auto_stop_enabled_setting,
         shortcut::change_recording_auto_stop_timeout_seconds_setting,
         shortcut::change_recording_auto_stop_paste_setting,
+        shortcut::change_auto_stop_on_silence_enabled_setting,
+        shortcut::change_auto_stop_silence_seconds_setting,
+        shortcut::change_max_recording_duration_seconds_setting,
         shortcut::change_extra_recording_buffer_setting,
         shortcut::change_lazy_stream_close_setting,
         shortcut::change_ai_replace_system_prompt_setting,
@@ -1144,6 +1312,8 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_ai_replace_max_chars_setting,
         shortcut::change_ai_replace_restore_on_error_setting,
         shortcut::change_ai_replace_allow_no_selection_setting,
+        shortcut::change_ai_replace_confirm_before_paste_setting,
+        shortcut::change_ai_replace_confirm_timeout_seconds_setting,
         shortcut::change_ai_replace_no_selection_system_prompt_setting,
         shortcut::change_ai_replace_no_selection_user_prompt_setting,
         shortcut::change_ai_replace_allow_quick_tap_setting,
@@ -1169,16 +1339,34 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_voice_command_push_to_talk_setting,
         shortcut::change_connector_auto_open_enabled_setting,
         shortcut::change_connector_auto_open_url_setting,
+        shortcut::change_connector_health_check_interval_seconds_setting,
+        shortcut::change_connector_heartbeat_interval_seconds_setting,
+        shortcut::change_connector_heartbeat_grace_seconds_setting,
+        shortcut::change_extension_offline_fallback_to_clipboard_setting,
+        shortcut::change_connector_message_max_age_setting,
+        shortcut::change_transcribe_and_search_url_template_setting,
+        shortcut::change_binding_search_url_template_setting,
+        shortcut::change_transcribe_and_search_post_process_enabled_setting,
         shortcut::change_connector_port_setting,
         shortcut::change_connector_password_setting,
         shortcut::rotate_connector_password_now,
         shortcut::change_connector_enabled_setting,
+        shortcut::change_action_api_enabled_setting,
+        shortcut::change_action_api_port_setting,
+        shortcut::regenerate_action_api_token,
+        webhook::change_webhook_enabled_setting,
+        webhook::change_webhook_url_setting,
+        webhook::change_webhook_secret_setting,
+        webhook::change_webhook_profile_filter_setting,
+        webhook::test_webhook,
         shortcut::change_connector_allow_any_cors_setting,
         shortcut::change_connector_cors_setting,
         shortcut::change_connector_encryption_enabled_setting,
         shortcut::change_screenshot_capture_method_setting,
         shortcut::change_screenshot_capture_command_setting,
         shortcut::change_native_region_capture_mode_setting,
+        shortcut::change_focus_restore_timeout_ms_setting,
+        shortcut::change_focus_restore_paste_delay_ms_setting,
         shortcut::change_screenshot_folder_setting,
         shortcut::change_screenshot_require_recent_setting,
         shortcut::change_screenshot_timeout_seconds_setting,
@@ -1194,6 +1382,7 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_beta_voice_commands_enabled_setting,
         shortcut::change_voice_button_show_aot_toggle_setting,
         shortcut::change_voice_button_single_click_close_setting,
+        shortcut::change_voice_button_monitor_setting,
         shortcut::change_text_replacements_enabled_setting,
         shortcut::change_text_replacements_setting,
         shortcut::change_text_replacements_before_llm_setting,
@@ -1208,18 +1397,31 @@ pub fn run(cli_args: CliArgs) {
         shortcut::change_text_replacement_decapitalize_standard_post_recording_monitor_ms_setting,
         shortcut::change_output_whitespace_leading_mode_setting,
         shortcut::change_output_whitespace_trailing_mode_setting,
+        shortcut::change_smart_join_enabled_setting,
+        shortcut::change_smart_join_expiry_setting,
+        shortcut::preview_whitespace_policy,
         shortcut::change_remember_window_size_setting,
         shortcut::change_remember_window_position_setting,
         shortcut::change_sidebar_pinned_setting,
         shortcut::change_sidebar_width_setting,
         shortcut::get_current_shortcut_engine,
+        shortcut::replay_shortcut_portal_error,
         shortcut::set_shortcut_engine_setting,
         shortcut::get_tauri_incompatible_shortcuts,
         trigger_update_check,
         commands::cancel_operation,
+        commands::clear_transcript_context,
+        commands::clear_transcript_context_for_app,
+        commands::set_privacy_mode,
+        commands::get_privacy_mode_status,
+        commands::boost_next_transcription,
+        commands::cancel_boost_next_transcription,
+        commands::get_boost_status,
+        commands::get_session_state,
         commands::get_app_dir_path,
         commands::is_portable,
         commands::get_app_settings,
+        commands::flush_app_settings,
         commands::take_settings_store_reset_notice,
         commands::get_default_settings,
         commands::get_log_dir_path,
@@ -1247,6 +1449,9 @@ pub fn run(cli_args: CliArgs) {
         commands::remote_stt::deepgram_has_api_key,
         commands::remote_stt::deepgram_set_api_key,
         commands::remote_stt::deepgram_clear_api_key,
+        commands::remote_stt::openai_realtime_has_api_key,
+        commands::remote_stt::openai_realtime_set_api_key,
+        commands::remote_stt::openai_realtime_clear_api_key,
         commands::remote_stt::remote_stt_get_debug_dump,
         commands::remote_stt::remote_stt_clear_debug,
         commands::remote_stt::remote_stt_test_connection,
@@ -1259,12 +1464,15 @@ pub fn run(cli_args: CliArgs) {
         commands::models::download_model,
         commands::models::delete_model,
         commands::models::cancel_download,
+        commands::models::cancel_model_download,
         commands::models::set_active_model,
         commands::models::get_current_model,
         commands::models::get_transcription_model_status,
         commands::models::has_any_models_available,
         commands::models::get_active_gpu_vram_status,
+        commands::models::open_models_settings,
         commands::audio::update_microphone_mode,
+        commands::audio::update_microphone_mode_override,
         commands::audio::get_available_microphones,
         commands::audio::get_windows_microphone_permission_status,
         commands::audio::open_microphone_privacy_settings,
@@ -1280,10 +1488,17 @@ pub fn run(cli_args: CliArgs) {
         commands::audio::set_clamshell_microphone,
         commands::audio::set_live_sound_microphone,
         commands::audio::is_recording,
+        commands::audio::get_active_input_format,
+        commands::audio::run_audio_diagnostics,
         commands::audio::change_vad_threshold_setting,
         commands::audio::change_microphone_input_boost_db_setting,
         commands::audio::change_microphone_input_boost_for_device_setting,
         commands::audio::change_microphone_noise_cancellation_enabled_setting,
+        commands::audio::change_auto_recover_input_device_setting,
+        commands::audio::change_recording_level_meter_enabled_setting,
+        commands::audio::change_audio_preprocessing_enabled_setting,
+        commands::audio::change_audio_preprocessing_high_pass_hz_setting,
+        commands::audio::get_audio_preprocessing_high_pass_limits,
         commands::live_sound_transcription::live_sound_transcription_start,
         commands::live_sound_transcription::live_sound_transcription_stop,
         commands::live_sound_transcription::live_sound_transcription_clear,
@@ -1298,41 +1513,87 @@ pub fn run(cli_args: CliArgs) {
         commands::live_sound_transcription::set_live_sound_deepgram_endpointing_enabled,
         commands::live_sound_transcription::set_live_sound_deepgram_endpointing_ms,
         commands::transcription::set_model_unload_timeout,
+        commands::transcription::change_model_keep_loaded_minutes_setting,
+        commands::transcription::change_model_preload_on_startup_setting,
+        commands::transcription::get_model_load_state,
         commands::transcription::change_whisper_accelerator_setting,
         commands::transcription::change_ort_accelerator_setting,
         commands::transcription::change_whisper_gpu_device,
         commands::transcription::get_available_accelerators,
         commands::transcription::unload_model_manually,
         commands::history::get_history_entries,
+        commands::history::search_history,
         commands::history::toggle_history_entry_saved,
         commands::history::get_audio_file_path,
         commands::history::delete_history_entry,
         commands::history::delete_all_history_entries,
         commands::history::retry_history_entry_transcription,
+        commands::history::retranscribe_history_entry,
+        commands::history::play_history_audio,
+        commands::history::stop_history_audio,
+        commands::history::reapply_custom_words_to_history,
+        commands::history::cancel_reapply_custom_words_to_history,
+        commands::history::change_accuracy_evaluation_enabled_setting,
+        commands::history::export_history_entry_subtitles,
+        commands::history::change_history_timestamps_enabled_setting,
+        commands::history::change_history_trim_silence_enabled_setting,
+        commands::history::change_history_trim_silence_padding_setting,
+        commands::history::change_low_confidence_warning_threshold_setting,
+        commands::history::change_repaste_last_count_setting,
+        commands::history::change_repaste_last_separator_setting,
+        commands::history::change_repaste_last_include_ai_replace_setting,
+        commands::history::change_undo_last_paste_timeout_setting,
+        commands::history::record_reference_text,
+        commands::history::compute_accuracy_stats,
+        commands::usage_stats::get_usage_stats,
+        commands::usage_stats::reset_usage_stats,
+        commands::history::change_history_session_gap_minutes_setting,
+        commands::history::get_history_sessions,
+        commands::history::export_history_session,
+        commands::history::export_history,
+        commands::history::translate_history_entry,
+        commands::history::get_history_entry_translations,
+        commands::history::reprocess_history_entry,
+        commands::history::get_history_entry_post_process_revisions,
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
+        commands::session_bundle::export_session_bundle,
+        commands::session_bundle::import_session_bundle,
         commands::connector::connector_get_status,
         commands::connector::connector_is_online,
+        commands::connector::force_connector_health_check,
         commands::connector::connector_start_server,
         commands::connector::connector_stop_server,
         commands::connector::connector_queue_message,
         commands::connector::connector_cancel_message,
+        commands::connector::get_pending_connector_messages,
+        commands::connector::discard_connector_message,
         commands::connector::connector_export_bundled_extension,
         commands::region_capture::region_capture_get_data,
         commands::region_capture::region_capture_confirm,
         commands::region_capture::region_capture_cancel,
+        commands::ai_replace_confirm::ai_replace_confirm_get_payload,
+        commands::ai_replace_confirm::ai_replace_confirm_accept,
+        commands::ai_replace_confirm::ai_replace_confirm_reject,
         commands::voice_command::execute_voice_command,
+        lifecycle_hooks::change_lifecycle_hooks_setting,
+        lifecycle_hooks::test_lifecycle_hook,
         commands::voice_command::test_voice_command_mock,
+        commands::voice_command::explain_voice_command_match,
+        commands::voice_command::test_voice_command_match,
         commands::voice_activation_button::spawn_voice_activation_button_window,
         commands::voice_activation_button::voice_activation_button_get_push_to_talk,
         commands::voice_activation_button::voice_activation_button_get_show_aot_toggle,
         commands::voice_activation_button::voice_activation_button_get_single_click_close,
         commands::voice_activation_button::voice_activation_button_press,
         commands::voice_activation_button::voice_activation_button_release,
+        commands::voice_activation_button::voice_activation_button_list_monitors,
+        commands::voice_activation_button::reset_voice_button_position,
         commands::file_transcription::transcribe_audio_file,
         commands::file_transcription::get_file_transcription_recording_state,
         commands::file_transcription::change_file_transcription_chunking_mode_setting,
         commands::file_transcription::change_file_transcription_chunking_max_minutes_setting,
+        commands::file_transcription::change_file_transcription_segment_minutes_setting,
         commands::file_transcription::reapply_transcription_speaker_names,
         commands::key_listener::key_listener_start,
         commands::key_listener::key_listener_stop,
@@ -1340,6 +1601,7 @@ pub fn run(cli_args: CliArgs) {
         commands::key_listener::key_listener_unregister_shortcut,
         actions::preview_close_action,
         actions::preview_clear_action,
+        actions::preview_set_text,
         actions::preview_insert_action,
         actions::preview_llm_process_action,
         actions::run_llm_post_process_benchmark,
@@ -1383,6 +1645,14 @@ pub fn run(cli_args: CliArgs) {
     // mut is required on macOS where we add the nspanel plugin
     let headless_mode = cli_args.transcribe_file.is_some() || cli_args.list_devices;
 
+    let startup_error = profile_dir_error.or_else(|| {
+        if headless_mode {
+            None
+        } else {
+            instance_registry::ensure_exclusive_profile().err()
+        }
+    });
+
     #[allow(unused_mut)]
     let mut builder = tauri::Builder::default()
         .device_event_filter(tauri::DeviceEventFilter::Always)
@@ -1441,7 +1711,9 @@ pub fn run(cli_args: CliArgs) {
         builder = builder.plugin(tauri_nspanel::init());
     }
 
-    if !headless_mode {
+    // Instances with their own --profile-dir must not forward to (or receive
+    // from) the main instance; they are isolated by `ensure_exclusive_profile`.
+    if !headless_mode && cli_args.profile_dir.is_none() {
         builder = builder.plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
             if args.iter().any(|arg| arg == "--toggle-transcription") {
                 send_transcription_input(app, "transcribe", "CLI");
@@ -1475,6 +1747,7 @@ pub fn run(cli_args: CliArgs) {
         .manage(Mutex::new(PressTimestamps::default()))
         .manage(Mutex::new(session_manager::SessionState::default()))
         .manage(recording_auto_stop::new_managed_state())
+        .manage(recording_auto_stop::new_max_duration_state())
         .manage(
             std::sync::Mutex::new(std::collections::HashSet::<String>::new())
                 as shortcut::RdevShortcutsSet,
@@ -1482,6 +1755,11 @@ pub fn run(cli_args: CliArgs) {
         .manage(std::sync::Mutex::new(settings::ShortcutEngine::default())
             as shortcut::ActiveShortcutEngine)
         .setup(move |app| {
+            if let Some(error) = startup_error {
+                log::error!("{}", error);
+                return Err(error.into());
+            }
+
             if headless_mode {
                 let app_handle = app.handle().clone();
                 managers::transcription::init_transcribe_backend();
@@ -1596,7 +1874,12 @@ pub fn run(cli_args: CliArgs) {
             tauri::WindowEvent::Resized(_) => {
                 save_main_window_geometry(window, true, false);
             }
-            tauri::WindowEvent::Moved(_) => {
+            tauri::WindowEvent::Moved(position) => {
+                if window.label() == "voice_activation_button" {
+                    let scale = window.scale_factor().unwrap_or(1.0);
+                    let logical = position.to_logical::<f64>(scale);
+                    voice_button_position::record_move(&window.app_handle(), logical.x, logical.y);
+                }
                 save_main_window_geometry(window, false, true);
             }
             tauri::WindowEvent::CloseRequested { api, .. } => {
@@ -1611,6 +1894,7 @@ pub fn run(cli_args: CliArgs) {
 
                     let settings = get_settings(&window.app_handle());
                     if !settings.show_tray_icon {
+                        instance_registry::remove_own_record();
                         window.app_handle().exit(0);
                         return;
                     }
@@ -1637,6 +1921,19 @@ pub fn run(cli_args: CliArgs) {
             _ => {}
         })
         .invoke_handler(specta_builder.invoke_handler())
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .unwrap_or_else(|e| {
+            log::error!("Failed to start the application: {}", e);
+            std::process::exit(1);
+        })
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                lifecycle_hooks::run_app_exit(app);
+                if let Some(action_api) = app.try_state::<Arc<action_api::ActionApiServer>>() {
+                    action_api.stop();
+                }
+                voice_button_position::flush(app);
+                settings::flush_settings(app);
+            }
+        });
 }
@@ -0,0 +1,360 @@
+//! User-defined commands run on app and recording lifecycle events.
+//!
+//! Hooks of one event run in order on a background thread, so a slow hook
+//! never holds up startup or the recording state machine. Silent hooks are
+//! waited for up to `timeout_secs` (then killed) and their exit code is logged;
+//! windowed hooks are only spawned. Exit hooks are the exception: the app waits
+//! a short while for them so they get a chance to run before the process ends.
+
+use log::{info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use crate::settings::{
+    self, AppSettings, LifecycleHook, LifecycleHookKind, LifecycleHooks, ResolvedExecutionOptions,
+};
+
+/// How long app exit waits for exit hooks in total.
+const EXIT_HOOKS_BUDGET: Duration = Duration::from_secs(5);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// How long to wait for output after the hook exited.
+const OUTPUT_GRACE: Duration = Duration::from_millis(500);
+/// Output kept per stream for the log and the test command.
+const MAX_OUTPUT_CHARS: usize = 4000;
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct LifecycleHookOutput {
+    pub hook_id: String,
+    /// None when the hook was opened in a window, timed out or failed to start
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub timed_out: bool,
+    /// Set when the command could not be started
+    pub error: Option<String>,
+}
+
+fn kind_label(kind: LifecycleHookKind) -> &'static str {
+    match kind {
+        LifecycleHookKind::AppStart => "app start",
+        LifecycleHookKind::AppExit => "app exit",
+        LifecycleHookKind::RecordingStart => "recording start",
+        LifecycleHookKind::RecordingEnd => "recording end",
+    }
+}
+
+fn enabled_hooks(hooks: &LifecycleHooks, kind: LifecycleHookKind) -> Vec<LifecycleHook> {
+    hooks
+        .hooks(kind)
+        .iter()
+        .filter(|hook| hook.enabled && !hook.script.trim().is_empty())
+        .cloned()
+        .collect()
+}
+
+/// On Windows hooks run like voice commands, through PowerShell.
+#[cfg(target_os = "windows")]
+fn build_command(script: &str, options: &ResolvedExecutionOptions) -> Command {
+    crate::commands::voice_command::build_powershell_command(script, options)
+}
+
+/// Outside Windows hooks run through `sh`, or `pwsh` when requested.
+#[cfg(not(target_os = "windows"))]
+fn build_command(script: &str, options: &ResolvedExecutionOptions) -> Command {
+    let mut cmd = if options.use_pwsh {
+        let mut cmd = Command::new("pwsh");
+        if options.no_profile {
+            cmd.arg("-NoProfile");
+        }
+        if options.execution_policy != settings::ExecutionPolicy::Default {
+            log::debug!("Execution policy is ignored outside Windows");
+        }
+        cmd.args(["-NonInteractive", "-Command", script]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", script]);
+        cmd
+    };
+    if let Some(dir) = options.working_directory.as_deref() {
+        if !dir.trim().is_empty() {
+            cmd.current_dir(dir);
+        }
+    }
+    cmd
+}
+
+/// Reads a pipe on its own thread. A hook that launches a long-running app in
+/// the background may pass the pipe on, so readers are never joined.
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> mpsc::Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        let text = String::from_utf8_lossy(&bytes);
+        let text = text.trim_end();
+        let text = match text.char_indices().nth(MAX_OUTPUT_CHARS) {
+            Some((cut, _)) => format!("{}…", &text[..cut]),
+            None => text.to_string(),
+        };
+        let _ = tx.send(text);
+    });
+    rx
+}
+
+/// Waits for `child` until `timeout`, killing it afterwards.
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> (Option<i32>, bool) {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return (status.code(), false),
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return (None, true);
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                warn!("Failed to wait for lifecycle hook: {}", e);
+                return (None, false);
+            }
+        }
+    }
+}
+
+fn run_hook(
+    hook: &LifecycleHook,
+    options: &ResolvedExecutionOptions,
+    timeout: Duration,
+) -> LifecycleHookOutput {
+    let mut output = LifecycleHookOutput {
+        hook_id: hook.id.clone(),
+        exit_code: None,
+        stdout: String::new(),
+        stderr: String::new(),
+        timed_out: false,
+        error: None,
+    };
+
+    let mut cmd = build_command(&hook.script, options);
+    if options.silent {
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            output.error = Some(format!("Failed to start command: {}", e));
+            return output;
+        }
+    };
+    if !options.silent {
+        // Windowed hooks stay open for the user; there is nothing to wait for.
+        return output;
+    }
+
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+    let (exit_code, timed_out) = wait_with_timeout(&mut child, timeout);
+    output.exit_code = exit_code;
+    output.timed_out = timed_out;
+    output.stdout = stdout.recv_timeout(OUTPUT_GRACE).unwrap_or_default();
+    output.stderr = stderr.recv_timeout(OUTPUT_GRACE).unwrap_or_default();
+    output
+}
+
+fn log_output(kind: LifecycleHookKind, output: &LifecycleHookOutput) {
+    let label = kind_label(kind);
+    if let Some(error) = &output.error {
+        warn!("Lifecycle hook '{}' ({}): {}", output.hook_id, label, error);
+    } else if output.timed_out {
+        warn!(
+            "Lifecycle hook '{}' ({}) timed out and was killed",
+            output.hook_id, label
+        );
+    } else if let Some(code) = output.exit_code {
+        if code == 0 {
+            info!(
+                "Lifecycle hook '{}' ({}) exited with 0",
+                output.hook_id, label
+            );
+        } else {
+            warn!(
+                "Lifecycle hook '{}' ({}) exited with {}: {}",
+                output.hook_id, label, code, output.stderr
+            );
+        }
+    } else {
+        info!(
+            "Lifecycle hook '{}' ({}) opened in a window",
+            output.hook_id, label
+        );
+    }
+}
+
+fn run_hooks(
+    kind: LifecycleHookKind,
+    hooks: &[LifecycleHook],
+    settings: &AppSettings,
+) -> Vec<LifecycleHookOutput> {
+    let timeout = Duration::from_secs(settings.lifecycle_hooks.timeout_secs.max(1) as u64);
+    hooks
+        .iter()
+        .map(|hook| {
+            let options = hook.resolve_execution_options(&settings.voice_command_defaults);
+            let output = run_hook(hook, &options, timeout);
+            log_output(kind, &output);
+            output
+        })
+        .collect()
+}
+
+/// Runs the enabled hooks for `kind` on a background thread and returns at once.
+pub fn fire(settings: &AppSettings, kind: LifecycleHookKind) {
+    let hooks = enabled_hooks(&settings.lifecycle_hooks, kind);
+    if hooks.is_empty() {
+        return;
+    }
+
+    let settings = settings.clone();
+    if let Err(e) = thread::Builder::new()
+        .name("lifecycle-hooks".into())
+        .spawn(move || {
+            run_hooks(kind, &hooks, &settings);
+        })
+    {
+        warn!("Failed to start {} hooks: {}", kind_label(kind), e);
+    }
+}
+
+pub fn fire_app_start(app: &AppHandle) {
+    fire(&settings::get_settings(app), LifecycleHookKind::AppStart);
+}
+
+/// Runs the exit hooks, waiting at most `EXIT_HOOKS_BUDGET` for them.
+pub fn run_app_exit(app: &AppHandle) {
+    let settings = settings::get_settings(app);
+    let hooks = enabled_hooks(&settings.lifecycle_hooks, LifecycleHookKind::AppExit);
+    if hooks.is_empty() {
+        return;
+    }
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name("lifecycle-hooks-exit".into())
+        .spawn(move || {
+            run_hooks(LifecycleHookKind::AppExit, &hooks, &settings);
+            let _ = done_tx.send(());
+        });
+    match spawned {
+        Ok(_) => {
+            if done_rx.recv_timeout(EXIT_HOOKS_BUDGET).is_err() {
+                warn!("App exit hooks still running after {:?}", EXIT_HOOKS_BUDGET);
+            }
+        }
+        Err(e) => warn!("Failed to start app exit hooks: {}", e),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_lifecycle_hooks_setting(app: AppHandle, hooks: LifecycleHooks) -> Result<(), String> {
+    if hooks.timeout_secs == 0 {
+        return Err("Hook timeout must be at least one second".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.lifecycle_hooks = hooks;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Runs the hooks for `kind` now, including disabled ones, and returns their output.
+#[tauri::command]
+#[specta::specta]
+pub async fn test_lifecycle_hook(
+    app: AppHandle,
+    kind: LifecycleHookKind,
+) -> Result<Vec<LifecycleHookOutput>, String> {
+    let settings = settings::get_settings(&app);
+    let hooks: Vec<LifecycleHook> = settings
+        .lifecycle_hooks
+        .hooks(kind)
+        .iter()
+        .filter(|hook| !hook.script.trim().is_empty())
+        .cloned()
+        .collect();
+    if hooks.is_empty() {
+        return Err(format!("No {} hooks configured", kind_label(kind)));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || run_hooks(kind, &hooks, &settings))
+        .await
+        .map_err(|e| format!("Hook test failed: {}", e))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::settings::ExecutionPolicy;
+
+    fn hook(script: &str) -> LifecycleHook {
+        LifecycleHook {
+            id: "lh_test".to_string(),
+            script: script.to_string(),
+            enabled: true,
+            silent: true,
+            no_profile: false,
+            use_pwsh: false,
+            execution_policy: None,
+            working_directory: None,
+        }
+    }
+
+    fn options() -> ResolvedExecutionOptions {
+        ResolvedExecutionOptions {
+            silent: true,
+            no_profile: false,
+            use_pwsh: false,
+            execution_policy: ExecutionPolicy::Default,
+            working_directory: None,
+        }
+    }
+
+    #[test]
+    fn captures_exit_code_and_output() {
+        let output = run_hook(
+            &hook("echo out; echo err >&2; exit 3"),
+            &options(),
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(output.exit_code, Some(3));
+        assert_eq!(output.stdout, "out");
+        assert_eq!(output.stderr, "err");
+        assert!(!output.timed_out);
+    }
+
+    #[test]
+    fn kills_hooks_that_exceed_the_timeout() {
+        let started = Instant::now();
+        let output = run_hook(
+            &hook("exec sleep 10"),
+            &options(),
+            Duration::from_millis(200),
+        );
+
+        assert!(output.timed_out);
+        assert_eq!(output.exit_code, None);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::settings::PostProcessProvider;
 use crate::url_security::canonical_llm_provider_base_url;
 use log::{debug, info, warn};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, REFERER, USER_AGENT};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, REFERER, USER_AGENT};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Extended Thinking / Reasoning (OpenRouter)
@@ -100,9 +101,10 @@ fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<Header
             );
             headers.insert("anthropic-version", HeaderValue::from_static("2023-06-01"));
         } else {
+            let (name, value) = provider.auth_style.header(api_key);
             headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", api_key))
+                name,
+                HeaderValue::from_str(&value)
                     .map_err(|e| format!("Invalid authorization header value: {}", e))?,
             );
         }
@@ -120,6 +122,70 @@ fn create_client(provider: &PostProcessProvider, api_key: &str) -> Result<reqwes
         .map_err(|e| format!("Failed to build HTTP client: {}", e))
 }
 
+/// Error for a non-success response. A 401 also hints at the auth style, since
+/// Azure OpenAI rejects Bearer keys and vice versa.
+fn api_status_error(provider: &PostProcessProvider, status: StatusCode, body: &str) -> String {
+    let message = format!("API request failed with status {}: {}", status, body);
+    if status == StatusCode::UNAUTHORIZED && provider.id != "anthropic" {
+        return format!("{} {}", message, provider.auth_style.unauthorized_hint());
+    }
+    message
+}
+
+/// Why a chat completion request failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatCompletionError {
+    /// The provider could not be reached or did not answer in time.
+    Unreachable(String),
+    /// The provider answered with a non-success status.
+    Status { status: StatusCode, message: String },
+    /// Anything else, e.g. an invalid provider setup or an unreadable response.
+    Other(String),
+}
+
+impl ChatCompletionError {
+    /// Whether the request may succeed elsewhere or later: the provider was
+    /// unreachable or answered with a 5xx, as opposed to a rejected request
+    /// that would fail the same way again.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Unreachable(_) => true,
+            Self::Status { status, .. } => status.is_server_error(),
+            Self::Other(_) => false,
+        }
+    }
+
+    fn from_request(context: &str, error: reqwest::Error) -> Self {
+        let message = format!("{}: {}", context, error);
+        if error.is_connect() || error.is_timeout() {
+            Self::Unreachable(message)
+        } else {
+            Self::Other(message)
+        }
+    }
+}
+
+impl std::fmt::Display for ChatCompletionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreachable(message) | Self::Other(message) => f.write_str(message),
+            Self::Status { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl From<String> for ChatCompletionError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<ChatCompletionError> for String {
+    fn from(error: ChatCompletionError) -> Self {
+        error.to_string()
+    }
+}
+
 /// Send a chat completion with Extended Thinking / Reasoning support
 pub async fn send_chat_completion_with_reasoning(
     provider: &PostProcessProvider,
@@ -128,6 +194,20 @@ pub async fn send_chat_completion_with_reasoning(
     prompt: String,
     reasoning: ReasoningConfig,
 ) -> Result<Option<String>, String> {
+    try_chat_completion_with_reasoning(provider, api_key, model, prompt, reasoning)
+        .await
+        .map_err(String::from)
+}
+
+/// Like [`send_chat_completion_with_reasoning`], keeping the kind of failure
+/// so callers can tell transient errors from rejected requests.
+pub async fn try_chat_completion_with_reasoning(
+    provider: &PostProcessProvider,
+    api_key: String,
+    model: &str,
+    prompt: String,
+    reasoning: ReasoningConfig,
+) -> Result<Option<String>, ChatCompletionError> {
     send_chat_completion_with_messages_internal(
         provider,
         api_key,
@@ -164,7 +244,9 @@ pub async fn send_chat_completion_with_system_and_reasoning(
         content: user_prompt,
     });
 
-    send_chat_completion_with_messages_internal(provider, api_key, model, messages, reasoning).await
+    send_chat_completion_with_messages_internal(provider, api_key, model, messages, reasoning)
+        .await
+        .map_err(String::from)
 }
 
 /// Internal function that sends the actual chat completion request
@@ -175,7 +257,7 @@ async fn send_chat_completion_with_messages_internal(
     model: &str,
     messages: Vec<ChatMessage>,
     reasoning: ReasoningConfig,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, ChatCompletionError> {
     let base_url = canonical_llm_provider_base_url(provider)?;
     let url = format!("{}/chat/completions", base_url);
 
@@ -240,10 +322,11 @@ async fn send_chat_completion_with_messages_internal(
 
     let response = client
         .post(&url)
+        .query(&provider.extra_query_params)
         .json(&request_body)
         .send()
         .await
-        .map_err(|e| format!("HTTP request failed: {}", e))?;
+        .map_err(|e| ChatCompletionError::from_request("HTTP request failed", e))?;
 
     let status = response.status();
 
@@ -272,10 +355,11 @@ async fn send_chat_completion_with_messages_internal(
 
         let fallback_response = client
             .post(&url)
+            .query(&provider.extra_query_params)
             .json(&fallback_request)
             .send()
             .await
-            .map_err(|e| format!("HTTP request failed (fallback): {}", e))?;
+            .map_err(|e| ChatCompletionError::from_request("HTTP request failed (fallback)", e))?;
 
         let fallback_status = fallback_response.status();
         if !fallback_status.is_success() {
@@ -283,10 +367,10 @@ async fn send_chat_completion_with_messages_internal(
                 .text()
                 .await
                 .unwrap_or_else(|_| "Failed to read error response".to_string());
-            return Err(format!(
-                "API request failed with status {}: {}",
-                fallback_status, fallback_error
-            ));
+            return Err(ChatCompletionError::Status {
+                status: fallback_status,
+                message: api_status_error(provider, fallback_status, &fallback_error),
+            });
         }
 
         let completion: ChatCompletionResponse = fallback_response
@@ -305,10 +389,10 @@ async fn send_chat_completion_with_messages_internal(
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error response".to_string());
-        return Err(format!(
-            "API request failed with status {}: {}",
-            status, error_text
-        ));
+        return Err(ChatCompletionError::Status {
+            status,
+            message: api_status_error(provider, status, &error_text),
+        });
     }
 
     let completion: ChatCompletionResponse = response
@@ -358,6 +442,7 @@ pub async fn fetch_models(
 
     let response = client
         .get(&url)
+        .query(&provider.extra_query_params)
         .send()
         .await
         .map_err(|e| format!("Failed to fetch models: {}", e))?;
@@ -368,10 +453,11 @@ pub async fn fetch_models(
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!(
-            "Model list request failed ({}): {}",
-            status, error_text
-        ));
+        let mut message = format!("Model list request failed ({}): {}", status, error_text);
+        if status == StatusCode::UNAUTHORIZED && provider.id != "anthropic" {
+            message = format!("{} {}", message, provider.auth_style.unauthorized_hint());
+        }
+        return Err(message);
     }
 
     let parsed: serde_json::Value = response
@@ -402,3 +488,58 @@ pub async fn fetch_models(
 
     Ok(models)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::ApiAuthStyle;
+
+    #[test]
+    fn transient_errors_are_network_and_server_failures() {
+        let status = |status: StatusCode| ChatCompletionError::Status {
+            status,
+            message: String::new(),
+        };
+
+        assert!(ChatCompletionError::Unreachable("connect".to_string()).is_transient());
+        assert!(status(StatusCode::SERVICE_UNAVAILABLE).is_transient());
+        assert!(!status(StatusCode::BAD_REQUEST).is_transient());
+        assert!(!status(StatusCode::UNAUTHORIZED).is_transient());
+        assert!(!ChatCompletionError::Other("parse".to_string()).is_transient());
+    }
+
+    fn provider(auth_style: ApiAuthStyle) -> PostProcessProvider {
+        PostProcessProvider {
+            id: "custom".to_string(),
+            label: "Custom".to_string(),
+            base_url: "https://example.openai.azure.com/openai/deployments/gpt".to_string(),
+            allow_base_url_edit: true,
+            allow_insecure_http: false,
+            models_endpoint: None,
+            auth_style,
+            extra_query_params: Default::default(),
+        }
+    }
+
+    #[test]
+    fn azure_auth_style_sends_api_key_header() {
+        let headers = build_headers(&provider(ApiAuthStyle::AzureApiKey), "secret").unwrap();
+        assert_eq!(headers.get("api-key").unwrap(), "secret");
+        assert!(headers.get("authorization").is_none());
+
+        let headers = build_headers(&provider(ApiAuthStyle::Bearer), "secret").unwrap();
+        assert_eq!(headers.get("authorization").unwrap(), "Bearer secret");
+        assert!(headers.get("api-key").is_none());
+    }
+
+    #[test]
+    fn unauthorized_errors_hint_at_the_auth_style() {
+        let bearer = provider(ApiAuthStyle::Bearer);
+        let error = api_status_error(&bearer, StatusCode::UNAUTHORIZED, "denied");
+        assert!(error.starts_with("API request failed with status 401"));
+        assert!(error.contains("Azure API key"));
+
+        let error = api_status_error(&bearer, StatusCode::BAD_REQUEST, "bad");
+        assert!(!error.contains("auth style"));
+    }
+}
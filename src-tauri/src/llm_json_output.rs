@@ -0,0 +1,139 @@
+//! Structured JSON output for post-processing prompts.
+//!
+//! A prompt with `output_json_path` set asks the model for JSON itself; the
+//! prompt text is sent unchanged. Only the response is handled differently:
+//! it is parsed as JSON (markdown code fences and chatter around the object
+//! are tolerated) and the value at the path becomes the final text.
+
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses `$.a.b[0]`, `a.b[0]` and `$["a"]` style paths.
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, String> {
+    let trimmed = path.trim();
+    let rest = trimmed.strip_prefix('$').unwrap_or(trimmed);
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '.' => i += 1,
+            '[' => {
+                let close = chars[i..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .map(|offset| i + offset)
+                    .ok_or_else(|| format!("Unclosed '[' in JSON path '{}'", trimmed))?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+                let quoted = inner
+                    .strip_prefix('"')
+                    .and_then(|s| s.strip_suffix('"'))
+                    .or_else(|| inner.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')));
+                let segment = match quoted {
+                    Some(key) => PathSegment::Key(key.to_string()),
+                    None => PathSegment::Index(inner.parse().map_err(|_| {
+                        format!("Invalid index '{}' in JSON path '{}'", inner, trimmed)
+                    })?),
+                };
+                segments.push(segment);
+                i = close + 1;
+            }
+            _ => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|&c| c == '.' || c == '[')
+                    .map_or(chars.len(), |offset| i + offset);
+                segments.push(PathSegment::Key(chars[i..end].iter().collect()));
+                i = end;
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Returns the JSON document inside `response`: the contents of a code fence
+/// when there is one, otherwise the span from the first `{`/`[` to the last
+/// `}`/`]`.
+fn json_candidate(response: &str) -> &str {
+    let trimmed = response.trim();
+    if let Some(start) = trimmed.find("```") {
+        let after_fence = &trimmed[start + 3..];
+        // Skip the info string ("json") on the opening fence line.
+        let body_start = after_fence.find('\n').map_or(0, |i| i + 1);
+        let body = &after_fence[body_start..];
+        let body = body.find("```").map_or(body, |end| &body[..end]);
+        return body.trim();
+    }
+
+    let start = trimmed.find(['{', '[']);
+    let end = trimmed.rfind(['}', ']']);
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => &trimmed[start..=end],
+        _ => trimmed,
+    }
+}
+
+/// Extracts the value at `path` from the JSON in `response`. Strings are
+/// returned as-is, other values as compact JSON.
+pub fn extract(response: &str, path: &str) -> Result<String, String> {
+    let segments = parse_path(path)?;
+    let document: Value = serde_json::from_str(json_candidate(response))
+        .map_err(|e| format!("Response is not valid JSON: {}", e))?;
+
+    let mut value = &document;
+    for segment in &segments {
+        let next = match segment {
+            PathSegment::Key(key) => value.get(key.as_str()),
+            PathSegment::Index(index) => value.get(*index),
+        };
+        value = next.ok_or_else(|| format!("JSON path '{}' not found in response", path))?;
+    }
+
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Null => Err(format!("JSON path '{}' is null in response", path)),
+        other => Ok(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_from_plain_and_fenced_json() {
+        let plain = r#"{"title": "Hi", "body": "Hello there"}"#;
+        assert_eq!(extract(plain, "$.body").unwrap(), "Hello there");
+        assert_eq!(extract(plain, "title").unwrap(), "Hi");
+
+        let fenced = "```json\n{\"body\": \"Fenced\"}\n```";
+        assert_eq!(extract(fenced, "$.body").unwrap(), "Fenced");
+
+        let chatty = "Sure! Here you go:\n{\"body\": \"Chatty\"}\nLet me know.";
+        assert_eq!(extract(chatty, "$.body").unwrap(), "Chatty");
+    }
+
+    #[test]
+    fn follows_nested_keys_and_indexes() {
+        let response = r#"{"items": [{"text": "a"}, {"text": "b"}], "meta": {"n": 2}}"#;
+        assert_eq!(extract(response, "$.items[1].text").unwrap(), "b");
+        assert_eq!(extract(response, "$['meta'].n").unwrap(), "2");
+        assert_eq!(extract(response, "$.meta").unwrap(), r#"{"n":2}"#);
+    }
+
+    #[test]
+    fn reports_invalid_json_and_missing_paths() {
+        assert!(extract("not json", "$.body").is_err());
+        assert!(extract(r#"{"title": "x"}"#, "$.body").is_err());
+        assert!(extract(r#"{"body": null}"#, "$.body").is_err());
+        assert!(extract(r#"{"body": "x"}"#, "$.items[").is_err());
+    }
+}
@@ -1,22 +1,26 @@
 use crate::audio_toolkit::{
-    list_input_devices, list_output_devices, vad::SmoothedVad, AudioCaptureSource, AudioRecorder,
-    SileroVad, StreamFrameCallback,
+    classify_microphone_open_error, is_device_lost_error, list_input_devices, list_output_devices,
+    vad::SmoothedVad, AudioCaptureSource, AudioRecorder, FrameTapCallback, InputFormat,
+    InputLevelMeter, MicrophoneOpenFailure, SileroVad, SpeechActivityCallback, StreamErrorCallback,
+    StreamFrameCallback,
 };
 use crate::helpers::clamshell;
+use crate::interview::{self, ChannelTimeline, InterviewChannels};
 use crate::settings::{
-    get_settings, resolve_live_sound_provider, AppSettings, LiveSoundCaptureSource,
-    TranscriptionProvider,
+    get_settings, microphone_binding_family, resolve_live_sound_provider, AppSettings,
+    LifecycleHookKind, LiveSoundCaptureSource, MicMode, TranscriptionProvider,
 };
 use crate::utils;
 use log::{debug, error, info, warn};
 use std::fmt;
 use std::path::Path;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 
 const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+const DEVICE_IN_USE_RETRY_DELAY: Duration = Duration::from_millis(300);
 
 fn set_mute(mute: bool) {
     // Expected behavior:
@@ -506,6 +510,21 @@ impl StartRecordingError {
     pub fn is_microphone_related(&self) -> bool {
         matches!(self.source(), Some(AudioCaptureSource::Microphone))
     }
+
+    /// Classifies a microphone open/start failure; `None` for non-microphone errors.
+    pub fn microphone_failure(&self) -> Option<MicrophoneOpenFailure> {
+        match self {
+            StartRecordingError::StreamOpenFailed {
+                source: AudioCaptureSource::Microphone,
+                message,
+            }
+            | StartRecordingError::RecorderStartFailed {
+                source: AudioCaptureSource::Microphone,
+                message,
+            } => Some(classify_microphone_open_error(message)),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for StartRecordingError {
@@ -528,6 +547,99 @@ impl fmt::Display for StartRecordingError {
     }
 }
 
+/// System output loopback recorded next to the microphone for interview mode.
+struct InterviewCapture {
+    loopback: AudioRecorder,
+    me: Arc<Mutex<ChannelTimeline>>,
+    them: Arc<Mutex<ChannelTimeline>>,
+}
+
+fn timeline_frame_callback(
+    timeline: Arc<Mutex<ChannelTimeline>>,
+    started_at: Instant,
+) -> StreamFrameCallback {
+    Arc::new(move |frame: Vec<f32>| {
+        let arrived_at =
+            (started_at.elapsed().as_secs_f64() * interview::SAMPLE_RATE as f64) as usize;
+        if let Ok(mut timeline) = timeline.lock() {
+            timeline.push(&frame, arrived_at);
+        }
+    })
+}
+
+/// Capture operations needed to carry a recording over to another device.
+trait RecoverableCapture {
+    fn stop_capture(&self) -> Result<Vec<f32>, String>;
+    fn reopen_default_device(&mut self, source: AudioCaptureSource) -> Result<(), String>;
+    fn start_capture(&self) -> Result<(), String>;
+}
+
+impl RecoverableCapture for AudioRecorder {
+    fn stop_capture(&self) -> Result<Vec<f32>, String> {
+        self.stop().map_err(|e| e.to_string())
+    }
+
+    fn reopen_default_device(&mut self, source: AudioCaptureSource) -> Result<(), String> {
+        let _ = self.close();
+        self.open_with_source(None, source)
+            .map_err(|e| e.to_string())
+    }
+
+    fn start_capture(&self) -> Result<(), String> {
+        self.start().map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DeviceLossOutcome {
+    /// Capture continues on the default device.
+    Recovered,
+    /// The session ends with the samples captured so far.
+    Finalize,
+}
+
+/// With `auto_recover`, moves the samples captured before the loss into
+/// `carried` and restarts capture on the default device. Otherwise the
+/// recorder is left alone so the regular stop path collects its samples.
+fn recover_from_device_loss<R: RecoverableCapture>(
+    rec: &mut R,
+    source: AudioCaptureSource,
+    carried: &mut Vec<f32>,
+    auto_recover: bool,
+) -> DeviceLossOutcome {
+    if !auto_recover {
+        return DeviceLossOutcome::Finalize;
+    }
+    match rec.stop_capture() {
+        Ok(samples) => carried.extend(samples),
+        Err(err) => warn!(
+            "Failed to collect samples from the lost input device: {}",
+            err
+        ),
+    }
+    if let Err(err) = rec
+        .reopen_default_device(source)
+        .and_then(|()| rec.start_capture())
+    {
+        warn!("Failed to reopen the default input device: {}", err);
+        return DeviceLossOutcome::Finalize;
+    }
+    DeviceLossOutcome::Recovered
+}
+
+/// Sets `lost` the first time the capture device goes away and calls
+/// `on_lost`; further errors are ignored until `lost` is cleared.
+fn device_loss_callback(
+    lost: Arc<AtomicBool>,
+    on_lost: impl Fn() + Send + Sync + 'static,
+) -> StreamErrorCallback {
+    Arc::new(move |err: &cpal::StreamError| {
+        if is_device_lost_error(err) && !lost.swap(true, Ordering::AcqRel) {
+            on_lost();
+        }
+    })
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
 fn create_audio_recorder(
@@ -583,13 +695,25 @@ pub struct AudioRecordingManager {
     recorder: Arc<Mutex<Option<AudioRecorder>>>,
     is_open: Arc<Mutex<bool>>,
     is_recording: Arc<Mutex<bool>>,
+    is_paused: Arc<AtomicBool>,
     mute_state: Arc<Mutex<MuteState>>,
     paused_media_sessions: Arc<Mutex<Vec<String>>>,
     close_generation: Arc<AtomicU64>,
     cancel_generation: Arc<AtomicU64>,
     active_selection: Arc<Mutex<Option<ActiveRecorderSelection>>>,
     stream_frame_callback: Arc<Mutex<Option<StreamFrameCallback>>>,
+    speech_activity_callback: Arc<Mutex<Option<SpeechActivityCallback>>>,
+    /// Emits `recording-level` while set; cleared as soon as recording stops.
+    level_meter_active: Arc<AtomicBool>,
+    level_meter: Arc<Mutex<InputLevelMeter>>,
     cached_device: Arc<Mutex<Option<(ActiveRecorderSelection, cpal::Device)>>>,
+    interview_capture: Arc<Mutex<Option<InterviewCapture>>>,
+    interview_channels: Arc<Mutex<Option<InterviewChannels>>>,
+    /// The input device disappeared during the current recording and it was
+    /// not recovered. Cleared when the next recording starts.
+    input_device_lost: Arc<AtomicBool>,
+    /// Audio captured before switching to the default device after a loss.
+    carried_samples: Arc<Mutex<Vec<f32>>>,
 }
 
 impl AudioRecordingManager {
@@ -611,13 +735,21 @@ impl AudioRecordingManager {
             recorder: Arc::new(Mutex::new(None)),
             is_open: Arc::new(Mutex::new(false)),
             is_recording: Arc::new(Mutex::new(false)),
+            is_paused: Arc::new(AtomicBool::new(false)),
             mute_state: Arc::new(Mutex::new(MuteState::default())),
             paused_media_sessions: Arc::new(Mutex::new(Vec::new())),
             close_generation: Arc::new(AtomicU64::new(0)),
             cancel_generation: Arc::new(AtomicU64::new(0)),
             active_selection: Arc::new(Mutex::new(None)),
             stream_frame_callback: Arc::new(Mutex::new(None)),
+            speech_activity_callback: Arc::new(Mutex::new(None)),
+            level_meter_active: Arc::new(AtomicBool::new(false)),
+            level_meter: Arc::new(Mutex::new(InputLevelMeter::default())),
             cached_device: Arc::new(Mutex::new(None)),
+            interview_capture: Arc::new(Mutex::new(None)),
+            interview_channels: Arc::new(Mutex::new(None)),
+            input_device_lost: Arc::new(AtomicBool::new(false)),
+            carried_samples: Arc::new(Mutex::new(Vec::new())),
         };
 
         // Always-on?  Open immediately.
@@ -647,6 +779,26 @@ impl AudioRecordingManager {
         settings.selected_microphone.clone()
     }
 
+    /// Effective stream mode for a binding: a per-family override wins,
+    /// otherwise the manager's global mode applies.
+    fn mode_for_binding(&self, settings: &AppSettings, binding_id: &str) -> MicrophoneMode {
+        match settings
+            .microphone_mode_overrides
+            .get(microphone_binding_family(binding_id))
+        {
+            Some(MicMode::AlwaysOn) => MicrophoneMode::AlwaysOn,
+            Some(MicMode::OnDemand) => MicrophoneMode::OnDemand,
+            None => self.mode.lock().unwrap().clone(),
+        }
+    }
+
+    fn active_recording_binding_id(&self) -> Option<String> {
+        match &*self.state.lock().unwrap() {
+            RecordingState::Recording { binding_id } => Some(binding_id.clone()),
+            _ => None,
+        }
+    }
+
     pub fn invalidate_device_cache(&self) {
         *self.cached_device.lock().unwrap() = None;
     }
@@ -673,6 +825,28 @@ impl AudioRecordingManager {
         }
     }
 
+    /// Points a microphone selection at `device_name` if that input device is
+    /// present, otherwise keeps the global device.
+    fn apply_input_device_override(
+        &self,
+        selection: &mut ActiveRecorderSelection,
+        device_name: &str,
+    ) {
+        let candidate = ActiveRecorderSelection {
+            source: AudioCaptureSource::Microphone,
+            device_name: Some(device_name.to_string()),
+        };
+        if self.resolve_device_for_selection(&candidate).is_some() {
+            debug!("Using profile input device '{}'", device_name);
+            *selection = candidate;
+        } else {
+            warn!(
+                "Profile input device '{}' is not available; falling back to the global microphone",
+                device_name
+            );
+        }
+    }
+
     fn resolve_device_for_selection(
         &self,
         selection: &ActiveRecorderSelection,
@@ -751,13 +925,19 @@ impl AudioRecordingManager {
 
     /* ---------- microphone life-cycle -------------------------------------- */
 
-    /// Applies mute if mute_while_recording is enabled and stream is open.
+    /// Applies mute if mute_while_recording is enabled for the active binding and stream is open.
     /// The user's previous mute state is captured once per recording.
     pub fn apply_mute(&self) {
         let settings = get_settings(&self.app_handle);
         if !settings.mute_while_recording {
             return;
         }
+        if let Some(binding_id) = self.active_recording_binding_id() {
+            if !settings.mute_while_recording_for_binding(&binding_id) {
+                debug!("Mute skipped: binding '{}' is exempt", binding_id);
+                return;
+            }
+        }
 
         let is_open = self.is_open.lock().unwrap();
         if !*is_open {
@@ -875,12 +1055,138 @@ impl AudioRecordingManager {
             {
                 recorder.set_stream_frame_callback(Some(cb));
             }
+            if let Some(cb) = self
+                .speech_activity_callback
+                .lock()
+                .ok()
+                .and_then(|guard| guard.clone())
+            {
+                recorder.set_speech_activity_callback(Some(cb));
+            }
+            recorder.set_frame_tap_callback(Some(self.level_meter_tap()));
+            recorder.set_stream_error_callback(Some(self.device_loss_handler()));
             *recorder_opt = Some(recorder);
         }
 
         Ok(())
     }
 
+    fn level_meter_tap(&self) -> FrameTapCallback {
+        let app_handle = self.app_handle.clone();
+        let active = Arc::clone(&self.level_meter_active);
+        let meter = Arc::clone(&self.level_meter);
+        Arc::new(move |frame: &[f32]| {
+            if !active.load(Ordering::Acquire) {
+                return;
+            }
+            let level = meter.lock().ok().and_then(|mut meter| meter.feed(frame));
+            if let Some(level) = level {
+                let _ = app_handle.emit("recording-level", level);
+            }
+        })
+    }
+
+    fn stop_level_meter(&self) {
+        self.level_meter_active.store(false, Ordering::Release);
+    }
+
+    fn device_loss_handler(&self) -> StreamErrorCallback {
+        let app_handle = self.app_handle.clone();
+        device_loss_callback(Arc::clone(&self.input_device_lost), move || {
+            // Runs on the audio host's thread, which recovery tears down.
+            let app_handle = app_handle.clone();
+            std::thread::spawn(move || {
+                app_handle
+                    .state::<Arc<AudioRecordingManager>>()
+                    .handle_input_device_loss();
+            });
+        })
+    }
+
+    /// Reacts to the capture device going away: recovers onto the default
+    /// device when `auto_recover_input_device` is on, otherwise stops the
+    /// recording early so the captured audio is still transcribed.
+    fn handle_input_device_loss(&self) {
+        // Held throughout so the recording cannot stop or restart underneath.
+        let state = self.state.lock().unwrap();
+        let binding_id = match &*state {
+            RecordingState::Recording { binding_id } => binding_id.clone(),
+            // stop_recording sees the flag and closes the dead stream.
+            RecordingState::Stopping => return,
+            RecordingState::Idle => {
+                drop(state);
+                warn!("Input device disconnected while idle; closing the capture stream");
+                self.invalidate_device_cache();
+                self.stop_microphone_stream();
+                self.input_device_lost.store(false, Ordering::Release);
+                return;
+            }
+        };
+
+        let settings = get_settings(&self.app_handle);
+        let source = self
+            .active_selection
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|selection| selection.source)
+            .unwrap_or(AudioCaptureSource::Microphone);
+        let outcome = match self.recorder.lock().unwrap().as_mut() {
+            Some(rec) => recover_from_device_loss(
+                rec,
+                source,
+                &mut self.carried_samples.lock().unwrap(),
+                settings.auto_recover_input_device,
+            ),
+            None => DeviceLossOutcome::Finalize,
+        };
+        self.invalidate_device_cache();
+
+        match outcome {
+            DeviceLossOutcome::Recovered => {
+                *self.active_selection.lock().unwrap() = Some(ActiveRecorderSelection {
+                    source,
+                    device_name: None,
+                });
+                self.input_device_lost.store(false, Ordering::Release);
+                info!(
+                    "Input device disconnected; recording for binding '{}' continues on the default device",
+                    binding_id
+                );
+            }
+            DeviceLossOutcome::Finalize => {
+                drop(state);
+                warn!(
+                    "Input device disconnected; finalizing recording for binding '{}'",
+                    binding_id
+                );
+                // Live sessions get no further frames; stopping finalizes them
+                // with the audio they already received.
+                self.clear_stream_frame_callback();
+                crate::recording_auto_stop::stop_recording_for_binding(
+                    &self.app_handle,
+                    &binding_id,
+                    "device_disconnected",
+                );
+            }
+        }
+    }
+
+    /// Whether the last recording ended because its input device went away.
+    /// Clears the flag.
+    pub fn take_input_device_lost(&self) -> bool {
+        self.input_device_lost.swap(false, Ordering::AcqRel)
+    }
+
+    fn take_carried_samples(&self, samples: Vec<f32>) -> Vec<f32> {
+        let mut carried = std::mem::take(&mut *self.carried_samples.lock().unwrap());
+        if carried.is_empty() {
+            return samples;
+        }
+        carried.extend(samples);
+        carried
+    }
+
     fn start_stream_for_selection(
         &self,
         selection: ActiveRecorderSelection,
@@ -936,8 +1242,22 @@ impl AudioRecordingManager {
                 warn!("Recorder open failed ({first_err}); re-resolving device and retrying once");
                 self.invalidate_device_cache();
                 let fresh_device = self.resolve_device_for_selection(&selection);
-                rec.open_with_source(fresh_device, selection.source)
-                    .map_err(|e| anyhow::anyhow!("Failed to open recorder: {}", e))?;
+                if let Err(retry_err) = rec.open_with_source(fresh_device.clone(), selection.source)
+                {
+                    let failure = classify_microphone_open_error(&retry_err.to_string());
+                    if selection.source != AudioCaptureSource::Microphone || !failure.is_transient()
+                    {
+                        return Err(anyhow::anyhow!("Failed to open recorder: {}", retry_err));
+                    }
+                    // Exclusive-mode conflicts are often momentary while another app releases the device.
+                    warn!(
+                        "Microphone is in use by another app ({retry_err}); retrying in {:?}",
+                        DEVICE_IN_USE_RETRY_DELAY
+                    );
+                    std::thread::sleep(DEVICE_IN_USE_RETRY_DELAY);
+                    rec.open_with_source(fresh_device, selection.source)
+                        .map_err(|e| anyhow::anyhow!("Failed to open recorder: {}", e))?;
+                }
             }
         }
 
@@ -976,6 +1296,7 @@ impl AudioRecordingManager {
         if let Some(rec) = self.recorder.lock().unwrap().as_mut() {
             // If still recording, stop first.
             if *self.is_recording.lock().unwrap() {
+                self.stop_level_meter();
                 let _ = rec.stop();
                 *self.is_recording.lock().unwrap() = false;
             }
@@ -1010,12 +1331,20 @@ impl AudioRecordingManager {
 
     /* ---------- recording --------------------------------------------------- */
 
+    /// Starts recording for `binding_id`. A profile's `input_device_override`
+    /// replaces the global microphone when that device is still connected.
     pub fn try_start_recording_detailed(
         &self,
         binding_id: &str,
+        input_device_override: Option<&str>,
     ) -> Result<(), StartRecordingError> {
         let settings = get_settings(&self.app_handle);
-        let selection = self.resolve_selection_for_binding(&settings, Some(binding_id));
+        let mut selection = self.resolve_selection_for_binding(&settings, Some(binding_id));
+        if let Some(device_name) = input_device_override {
+            if selection.source == AudioCaptureSource::Microphone {
+                self.apply_input_device_override(&mut selection, device_name);
+            }
+        }
         if selection.source == AudioCaptureSource::Microphone {
             if let Err(err) =
                 crate::managers::microphone_auto_switch::reconcile_selected_microphone_before_recording(
@@ -1042,24 +1371,60 @@ impl AudioRecordingManager {
                 });
             }
 
+            *self.interview_channels.lock().unwrap() = None;
+            self.input_device_lost.store(false, Ordering::Release);
+            self.carried_samples.lock().unwrap().clear();
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                let interview_capture = if selection.source == AudioCaptureSource::Microphone
+                    && interview::is_interview_binding(&settings, binding_id)
+                {
+                    Some(self.open_interview_capture(rec)?)
+                } else {
+                    None
+                };
+
                 if let Err(err) = rec.start() {
                     let message = err.to_string();
                     error!(
                         "Failed to start recorder for binding {binding_id}: {}",
                         message
                     );
+                    if let Some(capture) = interview_capture {
+                        self.close_interview_capture(rec, capture);
+                    }
                     return Err(StartRecordingError::RecorderStartFailed {
                         source: selection.source,
                         message,
                     });
                 }
 
+                if let Some(capture) = interview_capture {
+                    if let Err(err) = capture.loopback.start() {
+                        let message = format!("Failed to start system audio capture: {}", err);
+                        error!("{}", message);
+                        let _ = rec.stop();
+                        self.close_interview_capture(rec, capture);
+                        return Err(StartRecordingError::RecorderStartFailed {
+                            source: AudioCaptureSource::SystemOutputLoopback,
+                            message,
+                        });
+                    }
+                    *self.interview_capture.lock().unwrap() = Some(capture);
+                    info!("Interview mode: recording microphone and system output separately");
+                }
+
                 *self.is_recording.lock().unwrap() = true;
+                self.is_paused.store(false, Ordering::Release);
+                if let Ok(mut meter) = self.level_meter.lock() {
+                    meter.reset();
+                }
+                self.level_meter_active
+                    .store(settings.recording_level_meter_enabled, Ordering::Release);
                 *state = RecordingState::Recording {
                     binding_id: binding_id.to_string(),
                 };
                 debug!("Recording started for binding {binding_id}");
+                crate::lifecycle_hooks::fire(&settings, LifecycleHookKind::RecordingStart);
                 return Ok(());
             }
             error!("Recorder not available");
@@ -1139,6 +1504,7 @@ impl AudioRecordingManager {
             } if active == binding_id => {
                 *state = RecordingState::Stopping;
                 drop(state);
+                self.stop_level_meter();
 
                 let settings = get_settings(&self.app_handle);
                 if should_apply_extra_recording_buffer(&settings, binding_id) {
@@ -1158,24 +1524,36 @@ impl AudioRecordingManager {
                     }
                 }
 
-                let samples = if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
-                    match rec.stop() {
-                        Ok(buf) => buf,
-                        Err(e) => {
-                            error!("stop() failed: {e}");
-                            Vec::new()
-                        }
-                    }
-                } else {
-                    error!("Recorder not available");
-                    Vec::new()
-                };
+                let (samples, interview_channels) =
+                    if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                        let samples = match rec.stop() {
+                            Ok(buf) => buf,
+                            Err(e) => {
+                                error!("stop() failed: {e}");
+                                Vec::new()
+                            }
+                        };
+                        (samples, self.finish_interview_capture(rec))
+                    } else {
+                        error!("Recorder not available");
+                        (Vec::new(), None)
+                    };
+                let samples = self.take_carried_samples(samples);
 
                 *self.is_recording.lock().unwrap() = false;
+                self.is_paused.store(false, Ordering::Release);
                 *self.state.lock().unwrap() = RecordingState::Idle;
+                crate::lifecycle_hooks::fire(&settings, LifecycleHookKind::RecordingEnd);
 
-                // In on-demand mode, close the microphone lazily only for real mic capture.
-                if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
+                if self.input_device_lost.load(Ordering::Acquire) {
+                    // The stream died with its device; the next recording reopens one.
+                    self.invalidate_device_cache();
+                    self.stop_microphone_stream();
+                } else if matches!(
+                    self.mode_for_binding(&settings, binding_id),
+                    MicrophoneMode::OnDemand
+                ) {
+                    // In on-demand mode, close the microphone lazily only for real mic capture.
                     if self.should_use_lazy_stream_close() {
                         self.schedule_lazy_close();
                     } else {
@@ -1187,6 +1565,7 @@ impl AudioRecordingManager {
                     debug!("Recording stop cancelled; discarding captured samples");
                     return None;
                 }
+                *self.interview_channels.lock().unwrap() = interview_channels;
 
                 // Pad if very short
                 let s_len = samples.len();
@@ -1234,7 +1613,43 @@ impl AudioRecordingManager {
             Vec::new()
         };
 
-        Some(samples)
+        if samples.is_empty() {
+            return Some(samples);
+        }
+        Some(self.take_carried_samples(samples))
+    }
+
+    /// Pauses or resumes the active recording. Frames captured while paused
+    /// are dropped, so the session stays open without growing the buffer.
+    /// Returns false when no recording is active.
+    pub fn set_recording_paused(&self, paused: bool) -> bool {
+        let state = self.state.lock().unwrap();
+        if !matches!(&*state, RecordingState::Recording { .. }) {
+            return false;
+        }
+
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            if let Err(e) = rec.set_paused(paused) {
+                error!("set_paused() failed: {e}");
+                return false;
+            }
+        } else {
+            error!("Recorder not available");
+            return false;
+        }
+        if let Some(capture) = self.interview_capture.lock().unwrap().as_ref() {
+            if let Err(e) = capture.loopback.set_paused(paused) {
+                warn!("Failed to pause system audio capture: {}", e);
+            }
+        }
+
+        self.is_paused.store(paused, Ordering::Release);
+        debug!("Recording {}", if paused { "paused" } else { "resumed" });
+        true
+    }
+
+    pub fn is_recording_paused(&self) -> bool {
+        self.is_paused.load(Ordering::Acquire)
     }
 
     pub fn is_recording(&self) -> bool {
@@ -1249,19 +1664,32 @@ impl AudioRecordingManager {
         self.cancel_generation.fetch_add(1, Ordering::AcqRel);
         let mut state = self.state.lock().unwrap();
 
-        match *state {
-            RecordingState::Recording { .. } => {
+        match &*state {
+            RecordingState::Recording { binding_id } => {
+                let binding_id = binding_id.clone();
                 *state = RecordingState::Idle;
                 drop(state);
+                self.stop_level_meter();
 
                 if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                     let _ = rec.stop(); // Discard the result
+                    let _ = self.finish_interview_capture(rec);
                 }
+                self.carried_samples.lock().unwrap().clear();
 
                 *self.is_recording.lock().unwrap() = false;
+                self.is_paused.store(false, Ordering::Release);
 
-                // In on-demand mode, close the microphone lazily only for real mic capture.
-                if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
+                let settings = get_settings(&self.app_handle);
+                crate::lifecycle_hooks::fire(&settings, LifecycleHookKind::RecordingEnd);
+                if self.input_device_lost.load(Ordering::Acquire) {
+                    self.invalidate_device_cache();
+                    self.stop_microphone_stream();
+                } else if matches!(
+                    self.mode_for_binding(&settings, &binding_id),
+                    MicrophoneMode::OnDemand
+                ) {
+                    // In on-demand mode, close the microphone lazily only for real mic capture.
                     if self.should_use_lazy_stream_close() {
                         self.schedule_lazy_close();
                     } else {
@@ -1275,6 +1703,81 @@ impl AudioRecordingManager {
             RecordingState::Idle => {}
         }
     }
+
+    /* ---------- interview mode ---------------------------------------------- */
+
+    /// Opens the default output device for loopback and points both the
+    /// microphone and the loopback frame callbacks at a shared timeline.
+    fn open_interview_capture(
+        &self,
+        mic: &AudioRecorder,
+    ) -> Result<InterviewCapture, StartRecordingError> {
+        let source = AudioCaptureSource::SystemOutputLoopback;
+        if !interview::is_supported() {
+            return Err(StartRecordingError::StreamOpenFailed {
+                source,
+                message: interview::UNSUPPORTED_MESSAGE.to_string(),
+            });
+        }
+
+        let mut loopback =
+            AudioRecorder::new().map_err(|e| StartRecordingError::StreamOpenFailed {
+                source,
+                message: format!("Failed to create system audio recorder: {}", e),
+            })?;
+        loopback.open_with_source(None, source).map_err(|e| {
+            StartRecordingError::StreamOpenFailed {
+                source,
+                message: format!("Failed to open system audio capture: {}", e),
+            }
+        })?;
+
+        let started_at = Instant::now();
+        let me = Arc::new(Mutex::new(ChannelTimeline::default()));
+        let them = Arc::new(Mutex::new(ChannelTimeline::default()));
+        mic.set_stream_frame_callback(Some(timeline_frame_callback(Arc::clone(&me), started_at)));
+        loopback.set_stream_frame_callback(Some(timeline_frame_callback(
+            Arc::clone(&them),
+            started_at,
+        )));
+
+        Ok(InterviewCapture { loopback, me, them })
+    }
+
+    fn close_interview_capture(&self, mic: &AudioRecorder, mut capture: InterviewCapture) {
+        mic.set_stream_frame_callback(self.stream_frame_callback.lock().unwrap().clone());
+        if let Err(e) = capture.loopback.close() {
+            warn!("Failed to close system audio capture: {}", e);
+        }
+    }
+
+    /// Stops the loopback channel after the microphone has stopped and returns
+    /// both aligned channels, if this recording was in interview mode.
+    fn finish_interview_capture(&self, mic: &AudioRecorder) -> Option<InterviewChannels> {
+        let capture = self.interview_capture.lock().unwrap().take()?;
+        if let Err(e) = capture.loopback.stop() {
+            warn!("Failed to stop system audio capture: {}", e);
+        }
+        let me = std::mem::take(&mut *capture.me.lock().unwrap()).into_samples();
+        let them = std::mem::take(&mut *capture.them.lock().unwrap()).into_samples();
+        self.close_interview_capture(mic, capture);
+        Some(InterviewChannels { me, them })
+    }
+
+    /// Channels of the last stopped interview-mode recording.
+    pub fn take_interview_channels(&self) -> Option<InterviewChannels> {
+        self.interview_channels.lock().unwrap().take()
+    }
+
+    /// Format of the main capture stream, if it is open.
+    pub fn active_input_format(&self) -> Option<InputFormat> {
+        self.recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|rec| rec.active_format())
+    }
+
     pub fn update_vad_threshold(&self, threshold: f32) {
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
             rec.set_vad_threshold(threshold);
@@ -1330,4 +1833,154 @@ impl AudioRecordingManager {
             rec.set_stream_frame_callback(None);
         }
     }
+
+    pub fn set_speech_activity_callback(&self, callback: SpeechActivityCallback) {
+        if let Ok(mut guard) = self.speech_activity_callback.lock() {
+            *guard = Some(callback.clone());
+        }
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_speech_activity_callback(Some(callback));
+        }
+    }
+
+    pub fn clear_speech_activity_callback(&self) {
+        if let Ok(mut guard) = self.speech_activity_callback.lock() {
+            *guard = None;
+        }
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.set_speech_activity_callback(None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Stands in for `AudioRecorder`: buffers pushed samples and lets the test
+    /// fire the registered stream error callback.
+    struct MockRecorder {
+        buffer: Vec<f32>,
+        error_cb: Option<StreamErrorCallback>,
+        reopen_fails: bool,
+        reopened: bool,
+    }
+
+    impl MockRecorder {
+        fn new(reopen_fails: bool) -> Self {
+            Self {
+                buffer: Vec::new(),
+                error_cb: None,
+                reopen_fails,
+                reopened: false,
+            }
+        }
+
+        fn inject_stream_error(&self, err: cpal::StreamError) {
+            if let Some(callback) = &self.error_cb {
+                callback(&err);
+            }
+        }
+    }
+
+    impl RecoverableCapture for MockRecorder {
+        fn stop_capture(&self) -> Result<Vec<f32>, String> {
+            Ok(self.buffer.clone())
+        }
+
+        fn reopen_default_device(&mut self, _source: AudioCaptureSource) -> Result<(), String> {
+            self.buffer.clear();
+            if self.reopen_fails {
+                return Err("No input device found".to_string());
+            }
+            self.reopened = true;
+            Ok(())
+        }
+
+        fn start_capture(&self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn armed_recorder(reopen_fails: bool) -> (MockRecorder, Arc<AtomicBool>, Arc<AtomicUsize>) {
+        let lost = Arc::new(AtomicBool::new(false));
+        let notified = Arc::new(AtomicUsize::new(0));
+        let mut rec = MockRecorder::new(reopen_fails);
+        let counter = Arc::clone(&notified);
+        rec.error_cb = Some(device_loss_callback(Arc::clone(&lost), move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+        (rec, lost, notified)
+    }
+
+    #[test]
+    fn unplug_with_auto_recover_keeps_audio_from_both_devices() {
+        let (mut rec, lost, notified) = armed_recorder(false);
+        rec.buffer = vec![0.1; 160];
+
+        rec.inject_stream_error(cpal::StreamError::DeviceNotAvailable);
+        rec.inject_stream_error(cpal::StreamError::DeviceNotAvailable);
+        assert!(lost.load(Ordering::SeqCst));
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        let mut carried = Vec::new();
+        let outcome =
+            recover_from_device_loss(&mut rec, AudioCaptureSource::Microphone, &mut carried, true);
+        assert_eq!(outcome, DeviceLossOutcome::Recovered);
+        assert!(rec.reopened);
+
+        rec.buffer = vec![0.2; 80];
+        carried.extend(rec.stop_capture().unwrap());
+        assert_eq!(carried.len(), 240);
+        assert_eq!(carried[0], 0.1);
+        assert_eq!(carried[239], 0.2);
+    }
+
+    #[test]
+    fn unplug_without_auto_recover_leaves_samples_for_the_stop_path() {
+        let (mut rec, lost, notified) = armed_recorder(false);
+        rec.buffer = vec![0.1; 160];
+
+        rec.inject_stream_error(cpal::StreamError::DeviceNotAvailable);
+        assert!(lost.load(Ordering::SeqCst));
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        let mut carried = Vec::new();
+        let outcome = recover_from_device_loss(
+            &mut rec,
+            AudioCaptureSource::Microphone,
+            &mut carried,
+            false,
+        );
+        assert_eq!(outcome, DeviceLossOutcome::Finalize);
+        assert!(!rec.reopened);
+        assert!(carried.is_empty());
+        assert_eq!(rec.stop_capture().unwrap().len(), 160);
+    }
+
+    #[test]
+    fn failed_reopen_finalizes_with_audio_captured_before_the_loss() {
+        let (mut rec, _lost, _notified) = armed_recorder(true);
+        rec.buffer = vec![0.1; 160];
+        rec.inject_stream_error(cpal::StreamError::DeviceNotAvailable);
+
+        let mut carried = Vec::new();
+        let outcome =
+            recover_from_device_loss(&mut rec, AudioCaptureSource::Microphone, &mut carried, true);
+        assert_eq!(outcome, DeviceLossOutcome::Finalize);
+        assert_eq!(carried.len(), 160);
+    }
+
+    #[test]
+    fn other_stream_errors_do_not_end_the_session() {
+        let (rec, lost, notified) = armed_recorder(false);
+        rec.inject_stream_error(cpal::StreamError::BackendSpecific {
+            err: cpal::BackendSpecificError {
+                description: "buffer underrun".to_string(),
+            },
+        });
+        assert!(!lost.load(Ordering::SeqCst));
+        assert_eq!(notified.load(Ordering::SeqCst), 0);
+    }
 }
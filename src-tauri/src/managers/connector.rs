@@ -6,6 +6,10 @@
 //! Supports long-polling: extension can send `wait=N` query parameter to hold
 //! the connection open for up to N seconds waiting for new messages.
 //!
+//! Text and bundle messages are kept in a persisted outbox until the extension
+//! acknowledges them with `POST /messages {"type": "ack", "ids": [...]}`, and are
+//! redelivered when it reconnects (see `connector_outbox`).
+//!
 //! OWASP hardening notes for this local service:
 //! - Minimize attack surface: bind only to 127.0.0.1 and keep the route set small.
 //! - Secure by default: reject malformed security settings instead of widening access.
@@ -16,12 +20,13 @@
 //! - Keep the protocol narrow: only expose the headers, methods, and payload shapes
 //!   that the extension actually needs.
 
-use crate::settings::{default_connector_password, get_settings, write_settings};
+use super::connector_outbox::{Outbox, OutboxAttachment, OutboxEntry};
+use crate::settings::{default_connector_password, get_settings, write_settings, AppSettings};
 use axum::{
     body::Body,
     extract::{Path, Query, State},
     http::{header, HeaderValue, Method, StatusCode, Uri},
-    response::Response,
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
@@ -56,9 +61,6 @@ use sha2::{Digest, Sha256};
 
 /// Default server port (same as test-server.ps1)
 const DEFAULT_PORT: u16 = 38243;
-/// Timeout in milliseconds - if no poll for this duration, consider disconnected
-/// Must be longer than MAX_WAIT_SECONDS to account for long-polling
-const POLL_TIMEOUT_MS: i64 = 35_000;
 /// Keepalive interval in milliseconds
 const KEEPALIVE_INTERVAL_MS: i64 = 15_000;
 /// Maximum messages to keep in queue
@@ -67,13 +69,21 @@ const KEEPALIVE_INTERVAL_MS: i64 = 15_000;
 /// may be dropped on purpose so memory stays bounded and fresh messages win.
 /// Keepalives share the same queue intentionally, so prolonged offline periods
 /// naturally age out stale backlog instead of preserving it forever.
+/// Text and bundle messages are also kept in the outbox until acknowledged and
+/// are put back in this queue when the extension reconnects.
 const MAX_MESSAGES: usize = 100;
-/// How long to keep blobs available for download (5 minutes)
+/// How long to keep blobs available for download (5 minutes); at least as long
+/// as the message max age while the message is unacknowledged
 const BLOB_EXPIRY_MS: i64 = 300_000;
-/// Maximum long-poll wait time in seconds
+/// How long a handed-out message may go unacknowledged before it is sent again
+const ACK_TIMEOUT_MS: i64 = 60_000;
+/// Maximum long-poll wait time in seconds (also caps the heartbeat interval)
 const MAX_WAIT_SECONDS: u32 = 30;
+/// Shortest re-evaluation step once the cached state is about to time out
+const MIN_HEALTH_RECHECK_MS: i64 = 100;
 /// Default long-poll wait (0 = immediate response for backward compat)
 const DEFAULT_WAIT_SECONDS: u32 = 0;
+const PRIVACY_MODE_CONNECTOR_ERROR: &str = "Privacy mode is on; nothing was sent to the extension";
 /// When a background restart takes longer than this, warn the UI.
 const RESTART_DELAY_WARNING_MS: u64 = 4_000;
 /// Cooldown for auth-failure toasts in ms
@@ -119,6 +129,13 @@ const HEADER_REQUEST_MAC: &str = "x-aivorelay-request-mac";
 const HEADER_RESPONSE_MAC: &str = "x-aivorelay-response-mac";
 const HEADER_PAYLOAD_ENCRYPTED: &str = "x-aivorelay-payload-encrypted";
 const HEADER_EXTENSION_ID: &str = "x-aivorelay-extension-id";
+/// Optional header carrying the extension's manifest version.
+const HEADER_EXTENSION_VERSION: &str = "x-aivorelay-extension-version";
+/// Recording actions whose final text is queued for the extension.
+const EXTENSION_TEXT_BINDING_IDS: &[&str] =
+    &["send_to_extension", "send_to_extension_with_selection"];
+/// Timeout for the local /health probe used by the "test connection" button.
+const HEALTH_CHECK_REQUEST_TIMEOUT_MS: u64 = 2_000;
 type HmacSha256 = Hmac<Sha256>;
 
 /// Extension connection status
@@ -133,6 +150,69 @@ pub enum ExtensionStatus {
     Unknown,
 }
 
+/// Why the cached extension connection state changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionChangeReason {
+    /// Extension polled within the timeout window
+    Connected,
+    /// Extension stopped polling (or the health probe timed out)
+    Timeout,
+    /// Connector server is not running or refused the health probe
+    Refused,
+    /// Extension requests are failing authentication
+    Auth,
+}
+
+/// Cached connection state maintained by the heartbeat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ConnectorHealth {
+    online: bool,
+    reason: ConnectionChangeReason,
+}
+
+impl Default for ConnectorHealth {
+    fn default() -> Self {
+        Self {
+            online: false,
+            reason: ConnectionChangeReason::Refused,
+        }
+    }
+}
+
+/// Event payload for extension-connection-changed
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ExtensionConnectionChangedEvent {
+    pub online: bool,
+    pub reason: ConnectionChangeReason,
+    /// Last time extension polled (Unix timestamp in ms), 0 if never
+    pub last_poll_at: i64,
+}
+
+/// Event payload for connector-status-changed, used by the recording overlay
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ConnectorStatusChangedEvent {
+    pub online: bool,
+    pub reason: ConnectionChangeReason,
+    pub last_poll_at: i64,
+    /// A send-to-extension recording is in progress
+    pub extension_recording: bool,
+    /// The final text goes to the clipboard if the extension stays offline
+    pub fallback_to_clipboard: bool,
+}
+
+/// Result of an explicit connector health check
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ConnectorHealthCheck {
+    pub online: bool,
+    pub reason: ConnectionChangeReason,
+    /// Round trip to the local /health endpoint, None if it failed
+    pub latency_ms: Option<u64>,
+    /// Version reported by the extension on its last poll, if any
+    pub extension_version: Option<String>,
+    pub last_poll_at: i64,
+}
+
 /// Status info returned to frontend
 #[derive(Debug, Clone, Serialize, Type)]
 pub struct ConnectorStatus {
@@ -229,6 +309,9 @@ struct MessagesResponse {
 struct PostBody {
     #[serde(rename = "type", default)]
     msg_type: Option<String>,
+    /// Message IDs for `type: "ack"`
+    #[serde(default)]
+    ids: Vec<String>,
 }
 
 /// POST body for authenticated session creation.
@@ -293,6 +376,32 @@ pub struct MessageCancelledEvent {
     pub id: String,
 }
 
+/// Event payload for connector-message-acknowledged
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct MessageAcknowledgedEvent {
+    pub id: String,
+}
+
+/// Event payload for connector-message-expired
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct MessageExpiredEvent {
+    pub id: String,
+    pub text: String,
+}
+
+/// Outbound message the extension has not acknowledged yet
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct PendingConnectorMessage {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub msg_type: String,
+    pub text: String,
+    pub queued_at: i64,
+    /// Handed to the extension at least once since the last reconnect
+    pub delivered: bool,
+    pub attachment_count: u32,
+}
+
 /// Internal state shared between handlers
 struct ConnectorState {
     /// Queue of messages waiting to be picked up by extension
@@ -303,6 +412,109 @@ struct ConnectorState {
     blobs: HashMap<String, PendingBlob>,
     /// Set of message IDs that have been delivered (for deduplication)
     delivered_ids: HashSet<String>,
+    /// Text and bundle messages waiting for an acknowledgement
+    outbox: Outbox,
+}
+
+impl ConnectorState {
+    /// Drop a message from the live queue together with its blobs.
+    fn forget(&mut self, entry: &OutboxEntry) {
+        self.messages.retain(|m| m.id != entry.message.id);
+        self.delivered_ids.remove(&entry.message.id);
+        for attachment in entry.message.attachments.iter().flatten() {
+            self.blobs.remove(&attachment.att_id);
+        }
+    }
+
+    /// Put unacknowledged outbox messages back at the end of the live queue.
+    fn requeue_unacknowledged(&mut self, now: i64) -> usize {
+        let queued: HashSet<String> = self.messages.iter().map(|m| m.id.clone()).collect();
+        let redelivered = self
+            .outbox
+            .take_for_redelivery(now, |id| queued.contains(id));
+        self.requeue(redelivered)
+    }
+
+    /// Put messages handed out more than [`ACK_TIMEOUT_MS`] ago without an
+    /// acknowledgement back at the end of the live queue.
+    fn requeue_timed_out(&mut self, now: i64) -> usize {
+        let redelivered = self.outbox.take_unacknowledged(now, ACK_TIMEOUT_MS);
+        self.requeue(redelivered)
+    }
+
+    fn requeue(&mut self, redelivered: Vec<QueuedMessage>) -> usize {
+        for message in &redelivered {
+            self.messages.retain(|m| m.id != message.id);
+            self.delivered_ids.remove(&message.id);
+        }
+
+        let count = redelivered.len();
+        self.messages.extend(redelivered);
+        while self.messages.len() > MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+        count
+    }
+
+    /// Reload attachments for messages left over from the previous run and queue them again.
+    /// Returns the messages that expired while the app was not running.
+    fn restore_outbox(&mut self, port: u16, now: i64, max_age_ms: i64) -> Vec<OutboxEntry> {
+        let expired = self.outbox.take_expired(now, max_age_ms);
+
+        let blob_lifetime_ms = max_age_ms.max(BLOB_EXPIRY_MS);
+        let mut unreadable = Vec::new();
+        for entry in self.outbox.entries() {
+            let expires_at = entry.queued_at + blob_lifetime_ms;
+            for attachment in entry.message.attachments.iter().flatten() {
+                let stored = entry
+                    .attachments
+                    .iter()
+                    .find(|stored| stored.att_id == attachment.att_id);
+                let data = match stored.map(|stored| std::fs::read(&stored.path)) {
+                    Some(Ok(data)) => data,
+                    Some(Err(e)) => {
+                        warn!("Dropping connector message {}: {}", entry.message.id, e);
+                        unreadable.push(entry.message.id.clone());
+                        break;
+                    }
+                    None => {
+                        unreadable.push(entry.message.id.clone());
+                        break;
+                    }
+                };
+                self.blobs.insert(
+                    attachment.att_id.clone(),
+                    PendingBlob {
+                        data,
+                        mime_type: attachment
+                            .mime
+                            .clone()
+                            .unwrap_or_else(|| "image/png".to_string()),
+                        expires_at,
+                    },
+                );
+            }
+        }
+        for id in unreadable {
+            if let Some(entry) = self.outbox.remove(&id) {
+                self.forget(&entry);
+            }
+        }
+
+        // The port may have changed since these messages were queued.
+        self.outbox.update_entries(|entry| {
+            let expires_at = entry.queued_at + blob_lifetime_ms;
+            for attachment in entry.message.attachments.iter_mut().flatten() {
+                attachment.fetch.url = blob_fetch_url(port, &attachment.att_id);
+                attachment.fetch.expires_at = Some(expires_at);
+            }
+        });
+        let restored = self.requeue_unacknowledged(now);
+        if restored > 0 {
+            info!("Restored {} unacknowledged connector message(s)", restored);
+        }
+        expired
+    }
 }
 
 /// Per-session symmetric encryption and authentication material.
@@ -375,6 +587,12 @@ struct AppState {
     auth_failure_count: Arc<AtomicU32>,
     /// Earliest time when a new auth failure response may be returned
     auth_backoff_until_ms: Arc<AtomicI64>,
+    /// Timestamp (ms) of the last rejected auth attempt
+    last_auth_failure_at: Arc<AtomicI64>,
+    /// Cached connection state, updated by the heartbeat and on polls
+    health: Arc<Mutex<ConnectorHealth>>,
+    /// Version reported by the extension on its last poll
+    extension_version: Arc<Mutex<Option<String>>>,
 }
 
 pub struct ConnectorManager {
@@ -403,6 +621,12 @@ pub struct ConnectorManager {
     auth_backoff_until_ms: Arc<AtomicI64>,
     /// Prevent overlapping background restart workers when export reapplies settings.
     background_restart_in_progress: Arc<AtomicBool>,
+    /// Timestamp (ms) of the last rejected auth attempt
+    last_auth_failure_at: Arc<AtomicI64>,
+    /// Cached connection state, updated by the heartbeat and on polls
+    health: Arc<Mutex<ConnectorHealth>>,
+    /// Version reported by the extension on its last poll
+    extension_version: Arc<Mutex<Option<String>>>,
 }
 
 impl ConnectorManager {
@@ -419,17 +643,22 @@ impl ConnectorManager {
             DEFAULT_PORT
         };
 
+        let mut state = ConnectorState {
+            messages: VecDeque::new(),
+            last_keepalive: 0,
+            blobs: HashMap::new(),
+            delivered_ids: HashSet::new(),
+            outbox: Outbox::load(crate::portable::app_data_dir(app_handle).ok()),
+        };
+        let expired = state.restore_outbox(port, now_ms(), message_max_age_ms(&settings));
+        report_expired_messages(app_handle, expired);
+
         let manager = Self {
             app_handle: app_handle.clone(),
             last_poll_at: Arc::new(AtomicI64::new(0)),
             server_running: Arc::new(AtomicBool::new(false)),
             port: Arc::new(RwLock::new(port)),
-            state: Arc::new(Mutex::new(ConnectorState {
-                messages: VecDeque::new(),
-                last_keepalive: 0,
-                blobs: HashMap::new(),
-                delivered_ids: HashSet::new(),
-            })),
+            state: Arc::new(Mutex::new(state)),
             stop_flag: Arc::new(AtomicBool::new(false)),
             message_notify: Arc::new(Notify::new()),
             server_error: Arc::new(RwLock::new(None)),
@@ -438,6 +667,9 @@ impl ConnectorManager {
             auth_failure_count: Arc::new(AtomicU32::new(0)),
             auth_backoff_until_ms: Arc::new(AtomicI64::new(0)),
             background_restart_in_progress: Arc::new(AtomicBool::new(false)),
+            last_auth_failure_at: Arc::new(AtomicI64::new(0)),
+            health: Arc::new(Mutex::new(ConnectorHealth::default())),
+            extension_version: Arc::new(Mutex::new(None)),
         };
 
         Ok(manager)
@@ -554,6 +786,9 @@ impl ConnectorManager {
             sessions: self.sessions.clone(),
             auth_failure_count: self.auth_failure_count.clone(),
             auth_backoff_until_ms: self.auth_backoff_until_ms.clone(),
+            last_auth_failure_at: self.last_auth_failure_at.clone(),
+            health: self.health.clone(),
+            extension_version: self.extension_version.clone(),
         };
 
         // Use a per-instance stop flag so older restart tasks cannot outlive a fresh server.
@@ -563,6 +798,8 @@ impl ConnectorManager {
         let server_running = self.server_running.clone();
         let app_handle = self.app_handle.clone();
         let last_poll_at = self.last_poll_at.clone();
+        let last_auth_failure_at = self.last_auth_failure_at.clone();
+        let health = self.health.clone();
         let state = self.state.clone();
         let server_error = self.server_error.clone();
 
@@ -591,9 +828,11 @@ impl ConnectorManager {
             let status_global_stop_flag = global_stop_flag.clone();
             let status_app_handle = app_handle.clone();
             let status_last_poll = last_poll_at.clone();
+            let status_last_auth_failure = last_auth_failure_at.clone();
+            let status_server_running = server_running.clone();
+            let status_health = health.clone();
+            // Heartbeat: re-evaluate the cached connection state on the configured interval.
             tokio::spawn(async move {
-                let mut was_online = false;
-
                 loop {
                     if status_local_stop_flag.load(Ordering::SeqCst)
                         || status_global_stop_flag.load(Ordering::SeqCst)
@@ -601,30 +840,35 @@ impl ConnectorManager {
                         break;
                     }
 
-                    let now = now_ms();
+                    let settings = get_settings(&status_app_handle);
+                    let timeout_ms = poll_timeout_ms(&settings);
                     let last_poll = status_last_poll.load(Ordering::SeqCst);
-
-                    if last_poll > 0 {
-                        let is_online = (now - last_poll) < POLL_TIMEOUT_MS;
-                        if is_online != was_online {
-                            let status = if is_online {
-                                ExtensionStatus::Online
-                            } else {
-                                ExtensionStatus::Offline
-                            };
-                            info!("Extension status changed: {:?}", status);
-                            let _ = status_app_handle.emit("extension-status-changed", status);
-                            was_online = is_online;
-                        }
+                    let now = now_ms();
+                    let next = evaluate_connector_health(
+                        status_server_running.load(Ordering::SeqCst),
+                        last_poll,
+                        status_last_auth_failure.load(Ordering::SeqCst),
+                        now,
+                        timeout_ms,
+                    );
+                    apply_connector_health(&status_app_handle, &status_health, next, last_poll);
+
+                    // Wake up right when a missed poll would flip the state to
+                    // offline instead of waiting for the next regular check.
+                    let mut sleep_ms =
+                        i64::from(settings.connector_health_check_interval_seconds.max(1)) * 1000;
+                    if next.online {
+                        let until_timeout = last_poll + timeout_ms - now;
+                        sleep_ms = sleep_ms.min(until_timeout.max(MIN_HEALTH_RECHECK_MS));
                     }
-
-                    tokio::time::sleep(Duration::from_millis(500)).await;
+                    tokio::time::sleep(Duration::from_millis(sleep_ms as u64)).await;
                 }
             });
 
             let keepalive_local_stop_flag = local_stop_flag.clone();
             let keepalive_global_stop_flag = global_stop_flag.clone();
             let keepalive_state = state.clone();
+            let keepalive_app_handle = app_handle.clone();
             tokio::spawn(async move {
                 loop {
                     if keepalive_local_stop_flag.load(Ordering::SeqCst)
@@ -634,7 +878,8 @@ impl ConnectorManager {
                     }
 
                     let now = now_ms();
-                    {
+                    let max_age_ms = message_max_age_ms(&get_settings(&keepalive_app_handle));
+                    let expired = {
                         let mut state_guard = keepalive_state.lock().unwrap();
 
                         if now - state_guard.last_keepalive > KEEPALIVE_INTERVAL_MS {
@@ -652,8 +897,24 @@ impl ConnectorManager {
                             }
                         }
 
+                        let expired = state_guard.outbox.take_expired(now, max_age_ms);
+                        for entry in &expired {
+                            state_guard.forget(entry);
+                        }
+
+                        let redelivered = state_guard.requeue_timed_out(now);
+                        if redelivered > 0 {
+                            info!(
+                                "Redelivering {} connector message(s) the extension did not acknowledge",
+                                redelivered
+                            );
+                        }
+
                         state_guard.blobs.retain(|_, blob| blob.expires_at > now);
-                    }
+                        expired
+                    };
+
+                    report_expired_messages(&keepalive_app_handle, expired);
 
                     tokio::time::sleep(Duration::from_secs(5)).await;
                 }
@@ -683,6 +944,7 @@ impl ConnectorManager {
                     .route("/messages", get(handle_get_messages))
                     .route("/messages", post(handle_post_messages))
                     .route("/blob/{att_id}", get(handle_get_blob))
+                    .route("/health", get(handle_health))
                     .with_state(app_state.clone())
             };
 
@@ -700,6 +962,7 @@ impl ConnectorManager {
                             header::HeaderName::from_static(HEADER_CLIENT_TIMESTAMP),
                             header::HeaderName::from_static(HEADER_REQUEST_MAC),
                             header::HeaderName::from_static(HEADER_EXTENSION_ID),
+                            header::HeaderName::from_static(HEADER_EXTENSION_VERSION),
                         ])
                         .expose_headers([
                             header::HeaderName::from_static(HEADER_PROTOCOL_VERSION),
@@ -729,6 +992,7 @@ impl ConnectorManager {
                             header::HeaderName::from_static(HEADER_CLIENT_TIMESTAMP),
                             header::HeaderName::from_static(HEADER_REQUEST_MAC),
                             header::HeaderName::from_static(HEADER_EXTENSION_ID),
+                            header::HeaderName::from_static(HEADER_EXTENSION_VERSION),
                         ])
                         .expose_headers([
                             header::HeaderName::from_static(HEADER_PROTOCOL_VERSION),
@@ -756,6 +1020,12 @@ impl ConnectorManager {
             local_stop_flag.store(true, Ordering::SeqCst);
             server_running.store(false, Ordering::SeqCst);
             info!("Connector server stopped");
+            apply_connector_health(
+                &app_handle,
+                &health,
+                ConnectorHealth::default(),
+                last_poll_at.load(Ordering::SeqCst),
+            );
             let _ = app_handle.emit("extension-status-changed", ExtensionStatus::Unknown);
         });
 
@@ -883,6 +1153,9 @@ impl ConnectorManager {
 
     /// Queue a message to be sent to the extension.
     pub fn queue_message(&self, text: &str) -> Result<String, String> {
+        if crate::privacy_mode::should_skip("connector message") {
+            return Err(PRIVACY_MODE_CONNECTOR_ERROR.to_string());
+        }
         let trimmed = text.trim();
         if trimmed.is_empty() {
             return Err("Message is empty".to_string());
@@ -892,14 +1165,21 @@ impl ConnectorManager {
         let ts = now_ms();
 
         {
-            let mut state = self.state.lock().unwrap();
-            state.messages.push_back(QueuedMessage {
+            let message = QueuedMessage {
                 id: msg_id.clone(),
                 msg_type: "text".to_string(),
                 text: trimmed.to_string(),
                 ts,
                 attachments: None,
+            };
+            let mut state = self.state.lock().unwrap();
+            state.outbox.push(OutboxEntry {
+                message: message.clone(),
+                queued_at: ts,
+                attachments: Vec::new(),
+                delivered_at: None,
             });
+            state.messages.push_back(message);
 
             while state.messages.len() > MAX_MESSAGES {
                 state.messages.pop_front();
@@ -922,6 +1202,9 @@ impl ConnectorManager {
 
     /// Queue a bundle message with an image attachment.
     pub fn queue_bundle_message(&self, text: &str, image_path: &PathBuf) -> Result<String, String> {
+        if crate::privacy_mode::should_skip("connector bundle message") {
+            return Err(PRIVACY_MODE_CONNECTOR_ERROR.to_string());
+        }
         let data =
             std::fs::read(image_path).map_err(|e| format!("Failed to read image file: {}", e))?;
 
@@ -948,13 +1231,13 @@ impl ConnectorManager {
         let att_id = uuid_simple();
         let msg_id = uuid_simple();
         let now = now_ms();
-        let expires_at = now + BLOB_EXPIRY_MS;
+        let expires_at = now + blob_lifetime_ms(&get_settings(&self.app_handle));
 
         let port = match self.port.try_read() {
             Ok(guard) => *guard,
             Err(_) => DEFAULT_PORT,
         };
-        let fetch_url = format!("http://127.0.0.1:{}/blob/{}", port, att_id);
+        let fetch_url = blob_fetch_url(port, &att_id);
 
         let attachment = BundleAttachment {
             att_id: att_id.clone(),
@@ -971,7 +1254,25 @@ impl ConnectorManager {
         };
 
         {
+            let message = QueuedMessage {
+                id: msg_id.clone(),
+                msg_type: "bundle".to_string(),
+                text: text.trim().to_string(),
+                ts: now,
+                attachments: Some(vec![attachment]),
+            };
             let mut state = self.state.lock().unwrap();
+            state.outbox.push(OutboxEntry {
+                message: message.clone(),
+                queued_at: now,
+                attachments: vec![OutboxAttachment {
+                    att_id: att_id.clone(),
+                    mime_type: mime_type.to_string(),
+                    path: image_path.clone(),
+                    owned: false,
+                }],
+                delivered_at: None,
+            });
             state.blobs.insert(
                 att_id,
                 PendingBlob {
@@ -980,13 +1281,7 @@ impl ConnectorManager {
                     expires_at,
                 },
             );
-            state.messages.push_back(QueuedMessage {
-                id: msg_id.clone(),
-                msg_type: "bundle".to_string(),
-                text: text.trim().to_string(),
-                ts: now,
-                attachments: Some(vec![attachment]),
-            });
+            state.messages.push_back(message);
 
             while state.messages.len() > MAX_MESSAGES {
                 state.messages.pop_front();
@@ -1020,17 +1315,20 @@ impl ConnectorManager {
         data: Vec<u8>,
         mime_type: &str,
     ) -> Result<String, String> {
+        if crate::privacy_mode::should_skip("connector bundle message") {
+            return Err(PRIVACY_MODE_CONNECTOR_ERROR.to_string());
+        }
         let file_size = data.len() as u64;
         let att_id = uuid_simple();
         let msg_id = uuid_simple();
         let now = now_ms();
-        let expires_at = now + BLOB_EXPIRY_MS;
+        let expires_at = now + blob_lifetime_ms(&get_settings(&self.app_handle));
 
         let port = match self.port.try_read() {
             Ok(guard) => *guard,
             Err(_) => DEFAULT_PORT,
         };
-        let fetch_url = format!("http://127.0.0.1:{}/blob/{}", port, att_id);
+        let fetch_url = blob_fetch_url(port, &att_id);
 
         let attachment = BundleAttachment {
             att_id: att_id.clone(),
@@ -1050,7 +1348,31 @@ impl ConnectorManager {
         };
 
         {
+            let message = QueuedMessage {
+                id: msg_id.clone(),
+                msg_type: "bundle".to_string(),
+                text: text.trim().to_string(),
+                ts: now,
+                attachments: Some(vec![attachment]),
+            };
             let mut state = self.state.lock().unwrap();
+            // Keep a copy on disk only so the bundle survives a restart;
+            // redelivery serves the bytes already held in memory.
+            let stored = state
+                .outbox
+                .store_image(&att_id, mime_type, &data)
+                .map(|path| OutboxAttachment {
+                    att_id: att_id.clone(),
+                    mime_type: mime_type.to_string(),
+                    path,
+                    owned: true,
+                });
+            state.outbox.push(OutboxEntry {
+                message: message.clone(),
+                queued_at: now,
+                attachments: stored.into_iter().collect(),
+                delivered_at: None,
+            });
             state.blobs.insert(
                 att_id,
                 PendingBlob {
@@ -1059,13 +1381,7 @@ impl ConnectorManager {
                     expires_at,
                 },
             );
-            state.messages.push_back(QueuedMessage {
-                id: msg_id.clone(),
-                msg_type: "bundle".to_string(),
-                text: text.trim().to_string(),
-                ts: now,
-                attachments: Some(vec![attachment]),
-            });
+            state.messages.push_back(message);
 
             while state.messages.len() > MAX_MESSAGES {
                 state.messages.pop_front();
@@ -1104,6 +1420,7 @@ impl ConnectorManager {
         state.messages.retain(|m| m.id != message_id);
 
         if state.messages.len() < original_len {
+            state.outbox.remove(message_id);
             drop(state);
 
             let _ = self.app_handle.emit(
@@ -1120,6 +1437,51 @@ impl ConnectorManager {
         }
     }
 
+    /// List outbound messages the extension has not acknowledged yet.
+    pub fn pending_messages(&self) -> Vec<PendingConnectorMessage> {
+        let state = self.state.lock().unwrap();
+        state
+            .outbox
+            .entries()
+            .iter()
+            .map(|entry| PendingConnectorMessage {
+                id: entry.message.id.clone(),
+                msg_type: entry.message.msg_type.clone(),
+                text: entry.message.text.clone(),
+                queued_at: entry.queued_at,
+                delivered: entry.delivered_at.is_some(),
+                attachment_count: entry
+                    .message
+                    .attachments
+                    .as_ref()
+                    .map_or(0, |a| a.len() as u32),
+            })
+            .collect()
+    }
+
+    /// Drop an outbound message, delivered or not, so it is never sent again.
+    pub fn discard_message(&self, message_id: &str) -> bool {
+        let discarded = {
+            let mut state = self.state.lock().unwrap();
+            let entry = state.outbox.remove(message_id);
+            if let Some(entry) = &entry {
+                state.forget(entry);
+            }
+            entry.is_some()
+        };
+
+        if discarded {
+            let _ = self.app_handle.emit(
+                "connector-message-cancelled",
+                MessageCancelledEvent {
+                    id: message_id.to_string(),
+                },
+            );
+            info!("Discarded connector message: {}", message_id);
+        }
+        discarded
+    }
+
     /// Get current connection status.
     pub fn get_status(&self) -> ConnectorStatus {
         let last_poll = self.last_poll_at.load(Ordering::SeqCst);
@@ -1136,7 +1498,7 @@ impl ConnectorManager {
 
         let status = if !server_running || last_poll == 0 {
             ExtensionStatus::Unknown
-        } else if (now - last_poll) < POLL_TIMEOUT_MS {
+        } else if (now - last_poll) < poll_timeout_ms(&get_settings(&self.app_handle)) {
             ExtensionStatus::Online
         } else {
             ExtensionStatus::Offline
@@ -1151,13 +1513,71 @@ impl ConnectorManager {
         }
     }
 
-    /// Check if the extension is currently online.
+    /// Check if the extension is currently online (cached by the heartbeat, no I/O).
     pub fn is_online(&self) -> bool {
+        self.health
+            .lock()
+            .map(|health| health.online)
+            .unwrap_or(false)
+    }
+
+    /// Probe the local /health endpoint and refresh the cached connection state.
+    pub async fn force_health_check(&self) -> ConnectorHealthCheck {
+        let port = *self.port.read().await;
+        let started = std::time::Instant::now();
+        let probe = match reqwest::Client::builder()
+            .timeout(Duration::from_millis(HEALTH_CHECK_REQUEST_TIMEOUT_MS))
+            .build()
+        {
+            Ok(client) => client
+                .get(format!("http://127.0.0.1:{}/health", port))
+                .send()
+                .await
+                .and_then(|response| response.error_for_status()),
+            Err(e) => Err(e),
+        };
+
         let last_poll = self.last_poll_at.load(Ordering::SeqCst);
-        if last_poll == 0 {
-            return false;
+        let (latency_ms, next) = match probe {
+            Ok(_) => (
+                Some(started.elapsed().as_millis() as u64),
+                evaluate_connector_health(
+                    self.server_running.load(Ordering::SeqCst),
+                    last_poll,
+                    self.last_auth_failure_at.load(Ordering::SeqCst),
+                    now_ms(),
+                    poll_timeout_ms(&get_settings(&self.app_handle)),
+                ),
+            ),
+            Err(e) => {
+                warn!("Connector health probe failed: {}", e);
+                let reason = if e.is_timeout() {
+                    ConnectionChangeReason::Timeout
+                } else {
+                    ConnectionChangeReason::Refused
+                };
+                (
+                    None,
+                    ConnectorHealth {
+                        online: false,
+                        reason,
+                    },
+                )
+            }
+        };
+        apply_connector_health(&self.app_handle, &self.health, next, last_poll);
+
+        ConnectorHealthCheck {
+            online: next.online,
+            reason: next.reason,
+            latency_ms,
+            extension_version: self
+                .extension_version
+                .lock()
+                .ok()
+                .and_then(|version| version.clone()),
+            last_poll_at: last_poll,
         }
-        (now_ms() - last_poll) < POLL_TIMEOUT_MS
     }
 
     /// Password changes invalidate existing sessions, since bootstrap auth changes.
@@ -1191,19 +1611,46 @@ async fn handle_get_messages(
 
     let now = now_ms();
     let old_poll = app_state.last_poll_at.swap(now, Ordering::SeqCst);
-
-    if old_poll == 0 || (now - old_poll) >= POLL_TIMEOUT_MS {
+    let reconnected = old_poll == 0 || (now - old_poll) >= poll_timeout_ms(&settings);
+    if reconnected {
         info!("Extension connected (polling started)");
-        let _ = app_state
-            .app_handle
-            .emit("extension-status-changed", ExtensionStatus::Online);
+    }
+    if let Some(version) = headers
+        .get(header::HeaderName::from_static(HEADER_EXTENSION_VERSION))
+        .and_then(|value| value.to_str().ok())
+    {
+        if let Ok(mut extension_version) = app_state.extension_version.lock() {
+            *extension_version = Some(version.trim().to_string());
+        }
+    }
+    let came_online = apply_connector_health(
+        &app_state.app_handle,
+        &app_state.health,
+        ConnectorHealth {
+            online: true,
+            reason: ConnectionChangeReason::Connected,
+        },
+        now,
+    );
+    if reconnected || came_online {
+        // Anything handed out before the extension went away may never have arrived.
+        let redelivered = app_state.state.lock().unwrap().requeue_unacknowledged(now);
+        if redelivered > 0 {
+            info!(
+                "Redelivering {} unacknowledged connector message(s)",
+                redelivered
+            );
+        }
     }
 
     let cursor = query.since.unwrap_or(0);
+    // Holding a poll no longer than the heartbeat interval makes every poll a
+    // ping/pong round trip: a dead extension misses the next one within the
+    // grace period.
     let wait_seconds = query
         .wait
         .unwrap_or(DEFAULT_WAIT_SECONDS)
-        .min(MAX_WAIT_SECONDS);
+        .min(heartbeat_interval_seconds(&settings));
 
     let (messages, delivered_ids) = if wait_seconds > 0 {
         let deadline = tokio::time::Instant::now() + Duration::from_secs(wait_seconds as u64);
@@ -1249,6 +1696,8 @@ async fn handle_get_messages(
             );
         }
 
+        state_guard.outbox.mark_delivered(&delivered_ids, now);
+
         let current_ids: HashSet<_> = state_guard.messages.iter().map(|m| m.id.clone()).collect();
         state_guard
             .delivered_ids
@@ -1470,9 +1919,13 @@ async fn handle_post_messages(
     };
 
     if let Ok(post_body) = serde_json::from_str::<PostBody>(&body) {
-        if post_body.msg_type.as_deref() == Some("password_ack") {
-            info!("Extension acknowledged password - committing...");
-            commit_pending_password(&app_state.app_handle);
+        match post_body.msg_type.as_deref() {
+            Some("password_ack") => {
+                info!("Extension acknowledged password - committing...");
+                commit_pending_password(&app_state.app_handle);
+            }
+            Some("ack") => acknowledge_messages(&app_state, &post_body.ids),
+            _ => {}
         }
     }
 
@@ -1555,6 +2008,67 @@ async fn handle_get_blob(
 // Helpers
 // ============================================================================
 
+fn blob_fetch_url(port: u16, att_id: &str) -> String {
+    format!("http://127.0.0.1:{}/blob/{}", port, att_id)
+}
+
+/// Warns about and emits `connector-message-expired` for messages dropped by
+/// the max-age rule, the only way a message leaves without an acknowledgement.
+fn report_expired_messages(app_handle: &AppHandle, expired: Vec<OutboxEntry>) {
+    for entry in expired {
+        warn!(
+            "Dropping connector message {} after it went unacknowledged too long",
+            entry.message.id
+        );
+        let _ = app_handle.emit(
+            "connector-message-expired",
+            MessageExpiredEvent {
+                id: entry.message.id,
+                text: entry.message.text,
+            },
+        );
+    }
+}
+
+/// How long an unacknowledged message stays in the outbox.
+fn message_max_age_ms(settings: &AppSettings) -> i64 {
+    let minutes = settings
+        .connector_message_max_age_minutes
+        .clamp(1, crate::settings::MAX_CONNECTOR_MESSAGE_MAX_AGE_MINUTES);
+    i64::from(minutes) * 60_000
+}
+
+fn blob_lifetime_ms(settings: &AppSettings) -> i64 {
+    message_max_age_ms(settings).max(BLOB_EXPIRY_MS)
+}
+
+/// Remove acknowledged messages from the outbox. Their blobs stay downloadable
+/// for the regular grace period in case the extension fetches them afterwards.
+fn acknowledge_messages(app_state: &AppState, ids: &[String]) {
+    let acknowledged = {
+        let mut state_guard = app_state.state.lock().unwrap();
+        let grace_until = now_ms() + BLOB_EXPIRY_MS;
+        let acknowledged = state_guard.outbox.acknowledge(ids);
+        for entry in &acknowledged {
+            for attachment in entry.message.attachments.iter().flatten() {
+                if let Some(blob) = state_guard.blobs.get_mut(&attachment.att_id) {
+                    blob.expires_at = blob.expires_at.min(grace_until);
+                }
+            }
+        }
+        acknowledged
+    };
+
+    for entry in acknowledged {
+        let _ = app_state.app_handle.emit(
+            "connector-message-acknowledged",
+            MessageAcknowledgedEvent {
+                id: entry.message.id,
+            },
+        );
+    }
+}
+
 fn get_pending_messages(
     state: &Arc<Mutex<ConnectorState>>,
     cursor: i64,
@@ -1580,6 +2094,9 @@ fn get_pending_messages(
 }
 
 fn register_auth_failure(app_state: &AppState) -> i64 {
+    app_state
+        .last_auth_failure_at
+        .store(now_ms(), Ordering::SeqCst);
     let failure_count = app_state.auth_failure_count.fetch_add(1, Ordering::SeqCst) + 1;
     let exponent = failure_count.saturating_sub(1).min(4);
     let delay_ms = (INITIAL_AUTH_BACKOFF_MS * (1_i64 << exponent)).min(MAX_AUTH_BACKOFF_MS);
@@ -1631,6 +2148,110 @@ fn maybe_emit_auth_failure_toast(app_state: &AppState) {
     }
 }
 
+/// Unauthenticated liveness probe; reveals nothing beyond the protocol version.
+async fn handle_health() -> Response {
+    apply_security_headers(
+        Json(serde_json::json!({
+            "ok": true,
+            "protocolVersion": CONNECTOR_PROTOCOL_VERSION
+        }))
+        .into_response(),
+    )
+}
+
+/// Longest time a long poll is held open; the extension polls at least this often.
+fn heartbeat_interval_seconds(settings: &AppSettings) -> u32 {
+    settings
+        .connector_heartbeat_interval_seconds
+        .clamp(1, MAX_WAIT_SECONDS)
+}
+
+/// How long after its last poll the extension still counts as online: one
+/// heartbeat interval plus the grace period for the next poll to arrive.
+fn poll_timeout_ms(settings: &AppSettings) -> i64 {
+    let grace_seconds = settings.connector_heartbeat_grace_seconds.max(1);
+    i64::from(heartbeat_interval_seconds(settings) + grace_seconds) * 1000
+}
+
+/// Derive the connection state from server/poll/auth timestamps.
+fn evaluate_connector_health(
+    server_running: bool,
+    last_poll: i64,
+    last_auth_failure_at: i64,
+    now: i64,
+    poll_timeout_ms: i64,
+) -> ConnectorHealth {
+    let (online, reason) = if !server_running {
+        (false, ConnectionChangeReason::Refused)
+    } else if last_poll > 0 && (now - last_poll) < poll_timeout_ms {
+        (true, ConnectionChangeReason::Connected)
+    } else if last_auth_failure_at > last_poll && (now - last_auth_failure_at) < poll_timeout_ms {
+        (false, ConnectionChangeReason::Auth)
+    } else {
+        (false, ConnectionChangeReason::Timeout)
+    };
+    ConnectorHealth { online, reason }
+}
+
+/// Stores `next` and returns the previous state, or None when nothing
+/// changed. Heartbeat ticks and polls that confirm the current state land
+/// here too, so they must not count as a change.
+fn swap_connector_health(
+    health: &Mutex<ConnectorHealth>,
+    next: ConnectorHealth,
+) -> Option<ConnectorHealth> {
+    let mut current = health.lock().ok()?;
+    let previous = std::mem::replace(&mut *current, next);
+    (previous != next).then_some(previous)
+}
+
+/// Store the new connection state and emit events only when it actually changes.
+/// Returns true when the extension just came online.
+fn apply_connector_health(
+    app_handle: &AppHandle,
+    health: &Mutex<ConnectorHealth>,
+    next: ConnectorHealth,
+    last_poll_at: i64,
+) -> bool {
+    let Some(previous) = swap_connector_health(health, next) else {
+        return false;
+    };
+
+    info!(
+        "Extension connection changed: online={} reason={:?}",
+        next.online, next.reason
+    );
+    let _ = app_handle.emit(
+        "extension-connection-changed",
+        ExtensionConnectionChangedEvent {
+            online: next.online,
+            reason: next.reason,
+            last_poll_at,
+        },
+    );
+    let _ = app_handle.emit(
+        "connector-status-changed",
+        ConnectorStatusChangedEvent {
+            online: next.online,
+            reason: next.reason,
+            last_poll_at,
+            extension_recording: EXTENSION_TEXT_BINDING_IDS.iter().any(|binding_id| {
+                crate::session_manager::recording_operation_id(app_handle, binding_id).is_some()
+            }),
+            fallback_to_clipboard: get_settings(app_handle).extension_offline_fallback_to_clipboard,
+        },
+    );
+    if previous.online != next.online {
+        let status = if next.online {
+            ExtensionStatus::Online
+        } else {
+            ExtensionStatus::Offline
+        };
+        let _ = app_handle.emit("extension-status-changed", status);
+    }
+    !previous.online && next.online
+}
+
 fn unauthorized_response() -> Response {
     let response = Response::builder()
         .status(StatusCode::UNAUTHORIZED)
@@ -1834,7 +2455,7 @@ async fn validate_session_request(
     })
 }
 
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
     let mut res = if a.len() == b.len() { 0u8 } else { 1u8 };
     let n = std::cmp::min(a.len(), b.len());
     for i in 0..n {
@@ -2465,3 +3086,118 @@ fn json_session_response<T: Serialize>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_health_is_not_a_change() {
+        let connected = ConnectorHealth {
+            online: true,
+            reason: ConnectionChangeReason::Connected,
+        };
+        let timed_out = ConnectorHealth {
+            online: false,
+            reason: ConnectionChangeReason::Timeout,
+        };
+        let auth = ConnectorHealth {
+            online: false,
+            reason: ConnectionChangeReason::Auth,
+        };
+        let health = Mutex::new(ConnectorHealth::default());
+
+        assert_eq!(
+            swap_connector_health(&health, connected),
+            Some(ConnectorHealth::default())
+        );
+        assert_eq!(swap_connector_health(&health, connected), None);
+        assert_eq!(swap_connector_health(&health, connected), None);
+        assert_eq!(swap_connector_health(&health, timed_out), Some(connected));
+        assert_eq!(swap_connector_health(&health, timed_out), None);
+        // Staying offline for a different reason is still a change.
+        assert_eq!(swap_connector_health(&health, auth), Some(timed_out));
+    }
+
+    #[test]
+    fn missed_heartbeat_turns_offline_after_grace_period() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.connector_heartbeat_interval_seconds = 3;
+        settings.connector_heartbeat_grace_seconds = 2;
+        let timeout_ms = poll_timeout_ms(&settings);
+        assert_eq!(timeout_ms, 5_000);
+
+        let last_poll = 100_000;
+        let online = evaluate_connector_health(true, last_poll, 0, last_poll + 4_900, timeout_ms);
+        assert!(online.online);
+        let offline = evaluate_connector_health(true, last_poll, 0, last_poll + 5_000, timeout_ms);
+        assert_eq!(
+            offline,
+            ConnectorHealth {
+                online: false,
+                reason: ConnectionChangeReason::Timeout,
+            }
+        );
+    }
+
+    #[test]
+    fn health_reports_each_connection_state() {
+        let timeout_ms = 5_000;
+        let now = 1_000_000;
+        let health = |running, last_poll, last_auth_failure| {
+            evaluate_connector_health(running, last_poll, last_auth_failure, now, timeout_ms)
+        };
+
+        assert_eq!(
+            health(false, now - 1_000, 0),
+            ConnectorHealth {
+                online: false,
+                reason: ConnectionChangeReason::Refused,
+            }
+        );
+        assert_eq!(
+            health(true, now - 1_000, 0),
+            ConnectorHealth {
+                online: true,
+                reason: ConnectionChangeReason::Connected,
+            }
+        );
+        // A fresh poll wins over an older auth failure.
+        assert!(health(true, now - 1_000, now - 2_000).online);
+        assert_eq!(
+            health(true, now - 10_000, now - 1_000),
+            ConnectorHealth {
+                online: false,
+                reason: ConnectionChangeReason::Auth,
+            }
+        );
+        assert_eq!(
+            health(true, 0, now - 1_000).reason,
+            ConnectionChangeReason::Auth
+        );
+        // Auth failures age out like polls do.
+        assert_eq!(
+            health(true, now - 20_000, now - 10_000).reason,
+            ConnectionChangeReason::Timeout
+        );
+        assert_eq!(
+            health(true, 0, 0),
+            ConnectorHealth {
+                online: false,
+                reason: ConnectionChangeReason::Timeout,
+            }
+        );
+    }
+
+    #[test]
+    fn heartbeat_interval_is_capped_by_long_poll_limit() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.connector_heartbeat_interval_seconds = 600;
+        settings.connector_heartbeat_grace_seconds = 0;
+        assert_eq!(heartbeat_interval_seconds(&settings), MAX_WAIT_SECONDS);
+        assert_eq!(
+            poll_timeout_ms(&settings),
+            i64::from(MAX_WAIT_SECONDS + 1) * 1000
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//! Persisted outbound queue for connector messages.
+//!
+//! Text and bundle messages stay in the outbox until the extension
+//! acknowledges them by ID or they outlive the configured max age, so a
+//! message sent while the extension is offline (or while the app restarts) is
+//! still delivered once it reconnects. A poll only marks a message in flight;
+//! if no acknowledgement follows, the message is sent again.
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use super::connector::QueuedMessage;
+
+const OUTBOX_FILE: &str = "connector_outbox.json";
+const OUTBOX_FILES_DIR: &str = "connector_outbox";
+
+/// Image backing a bundle attachment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxAttachment {
+    pub att_id: String,
+    pub mime_type: String,
+    pub path: PathBuf,
+    /// Written by the outbox itself and deleted with the entry. Screenshots
+    /// picked up from the user's folder are only referenced.
+    pub owned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub message: QueuedMessage,
+    /// When the message was first queued; the max age counts from here.
+    pub queued_at: i64,
+    #[serde(default)]
+    pub attachments: Vec<OutboxAttachment>,
+    /// When a poll last handed the message out; `None` until then and again
+    /// once it is queued for redelivery.
+    #[serde(skip)]
+    pub delivered_at: Option<i64>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct OutboxFile {
+    #[serde(default)]
+    entries: Vec<OutboxEntry>,
+}
+
+pub struct Outbox {
+    /// App data directory; `None` keeps the outbox in memory only.
+    data_dir: Option<PathBuf>,
+    entries: Vec<OutboxEntry>,
+}
+
+impl Outbox {
+    pub fn load(data_dir: Option<PathBuf>) -> Self {
+        let file = data_dir
+            .as_ref()
+            .and_then(|dir| fs::read_to_string(dir.join(OUTBOX_FILE)).ok())
+            .and_then(|json| match serde_json::from_str::<OutboxFile>(&json) {
+                Ok(file) => Some(file),
+                Err(e) => {
+                    warn!("Ignoring unreadable connector outbox: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            data_dir,
+            entries: file.entries,
+        }
+    }
+
+    pub fn entries(&self) -> &[OutboxEntry] {
+        &self.entries
+    }
+
+    pub fn push(&mut self, entry: OutboxEntry) {
+        self.entries.push(entry);
+        self.save();
+    }
+
+    /// Writes image bytes next to the outbox so the bundle survives a restart.
+    pub fn store_image(&self, att_id: &str, mime_type: &str, data: &[u8]) -> Option<PathBuf> {
+        let dir = self.data_dir.as_ref()?.join(OUTBOX_FILES_DIR);
+        let extension = mime_type.split('/').nth(1).unwrap_or("png");
+        let path = dir.join(format!("{}.{}", att_id, extension));
+        let written = fs::create_dir_all(&dir).and_then(|_| fs::write(&path, data));
+        match written {
+            Ok(()) => Some(path),
+            Err(e) => {
+                warn!("Failed to store connector attachment {}: {}", att_id, e);
+                None
+            }
+        }
+    }
+
+    /// Marks messages a poll handed to the extension as in flight. They stay
+    /// in the outbox until acknowledged.
+    pub fn mark_delivered(&mut self, ids: &[String], now: i64) {
+        for entry in &mut self.entries {
+            if ids.contains(&entry.message.id) {
+                entry.delivered_at = Some(now);
+            }
+        }
+    }
+
+    /// Removes acknowledged messages and returns them.
+    pub fn acknowledge(&mut self, ids: &[String]) -> Vec<OutboxEntry> {
+        self.remove_where(|entry| ids.contains(&entry.message.id))
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<OutboxEntry> {
+        self.remove_where(|entry| entry.message.id == id).pop()
+    }
+
+    /// Removes messages queued more than `max_age_ms` ago and returns them.
+    pub fn take_expired(&mut self, now: i64, max_age_ms: i64) -> Vec<OutboxEntry> {
+        self.remove_where(|entry| now - entry.queued_at >= max_age_ms)
+    }
+
+    /// Applies `update` to every entry, e.g. to refresh blob URLs.
+    pub fn update_entries(&mut self, update: impl Fn(&mut OutboxEntry)) {
+        if self.entries.is_empty() {
+            return;
+        }
+        for entry in &mut self.entries {
+            update(entry);
+        }
+        self.save();
+    }
+
+    /// Returns the messages to send again: everything delivered but never
+    /// acknowledged, plus anything `is_queued` no longer finds in the live
+    /// queue. They are re-stamped with `now` so cursor-based polls pick them up.
+    pub fn take_for_redelivery(
+        &mut self,
+        now: i64,
+        is_queued: impl Fn(&str) -> bool,
+    ) -> Vec<QueuedMessage> {
+        self.take_where(now, |entry| {
+            entry.delivered_at.is_some() || !is_queued(&entry.message.id)
+        })
+    }
+
+    /// Returns in-flight messages handed out at least `ack_timeout_ms` ago
+    /// without an acknowledgement, re-stamped with `now` for redelivery.
+    pub fn take_unacknowledged(&mut self, now: i64, ack_timeout_ms: i64) -> Vec<QueuedMessage> {
+        self.take_where(now, |entry| {
+            entry
+                .delivered_at
+                .is_some_and(|delivered_at| now - delivered_at >= ack_timeout_ms)
+        })
+    }
+
+    fn take_where(
+        &mut self,
+        now: i64,
+        matches: impl Fn(&OutboxEntry) -> bool,
+    ) -> Vec<QueuedMessage> {
+        let mut redelivered = Vec::new();
+        for entry in &mut self.entries {
+            if matches(entry) {
+                entry.delivered_at = None;
+                entry.message.ts = now;
+                redelivered.push(entry.message.clone());
+            }
+        }
+        if !redelivered.is_empty() {
+            self.save();
+        }
+        redelivered
+    }
+
+    fn remove_where(&mut self, matches: impl Fn(&OutboxEntry) -> bool) -> Vec<OutboxEntry> {
+        let (removed, kept): (Vec<_>, Vec<_>) = self.entries.drain(..).partition(|e| matches(e));
+        self.entries = kept;
+        if removed.is_empty() {
+            return removed;
+        }
+
+        for attachment in removed.iter().flat_map(|entry| &entry.attachments) {
+            if attachment.owned {
+                if let Err(e) = fs::remove_file(&attachment.path) {
+                    warn!(
+                        "Failed to delete connector attachment {}: {}",
+                        attachment.path.display(),
+                        e
+                    );
+                }
+            }
+        }
+        self.save();
+        removed
+    }
+
+    fn save(&self) {
+        let Some(dir) = self.data_dir.as_ref() else {
+            return;
+        };
+        let file = OutboxFile {
+            entries: self.entries.clone(),
+        };
+        let result = serde_json::to_vec_pretty(&file)
+            .map_err(|e| e.to_string())
+            .and_then(|json| fs::write(dir.join(OUTBOX_FILE), json).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("Failed to save connector outbox: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, queued_at: i64) -> OutboxEntry {
+        OutboxEntry {
+            message: QueuedMessage {
+                id: id.to_string(),
+                msg_type: "text".to_string(),
+                text: format!("message {}", id),
+                ts: queued_at,
+                attachments: None,
+            },
+            queued_at,
+            attachments: Vec::new(),
+            delivered_at: None,
+        }
+    }
+
+    fn ids(outbox: &Outbox) -> Vec<&str> {
+        outbox
+            .entries()
+            .iter()
+            .map(|e| e.message.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn polled_messages_stay_until_acknowledged() {
+        let mut outbox = Outbox::load(None);
+        outbox.push(entry("a", 0));
+        outbox.push(entry("b", 0));
+
+        outbox.mark_delivered(&["a".to_string(), "b".to_string()], 100);
+        assert_eq!(ids(&outbox), vec!["a", "b"]);
+
+        let acked = outbox.acknowledge(&["a".to_string()]);
+        assert_eq!(acked.len(), 1);
+        assert_eq!(acked[0].message.id, "a");
+        assert_eq!(ids(&outbox), vec!["b"]);
+    }
+
+    #[test]
+    fn unacknowledged_messages_are_redelivered_on_reconnect() {
+        let mut outbox = Outbox::load(None);
+        outbox.push(entry("a", 0));
+        outbox.push(entry("b", 0));
+        outbox.push(entry("c", 0));
+
+        outbox.mark_delivered(&["a".to_string(), "b".to_string()], 100);
+        outbox.acknowledge(&["a".to_string()]);
+
+        // "c" was never handed out and is still in the live queue.
+        let redelivered = outbox.take_for_redelivery(500, |id| id == "c");
+        assert_eq!(redelivered.len(), 1);
+        assert_eq!(redelivered[0].id, "b");
+        assert_eq!(redelivered[0].ts, 500);
+        assert!(outbox.entries().iter().all(|e| e.delivered_at.is_none()));
+    }
+
+    #[test]
+    fn unacknowledged_messages_are_redelivered_after_the_timeout() {
+        let mut outbox = Outbox::load(None);
+        outbox.push(entry("a", 0));
+        outbox.push(entry("b", 0));
+        outbox.mark_delivered(&["a".to_string()], 1_000);
+
+        assert!(outbox.take_unacknowledged(1_500, 1_000).is_empty());
+        let redelivered = outbox.take_unacknowledged(2_000, 1_000);
+        assert_eq!(redelivered.len(), 1);
+        assert_eq!(redelivered[0].id, "a");
+        assert_eq!(ids(&outbox), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn messages_missing_from_live_queue_are_redelivered() {
+        let mut outbox = Outbox::load(None);
+        outbox.push(entry("a", 0));
+
+        let redelivered = outbox.take_for_redelivery(10, |_| false);
+        assert_eq!(redelivered.len(), 1);
+        assert_eq!(outbox.entries()[0].message.ts, 10);
+    }
+
+    #[test]
+    fn expired_messages_are_removed() {
+        let mut outbox = Outbox::load(None);
+        outbox.push(entry("old", 0));
+        outbox.push(entry("new", 5_000));
+
+        let expired = outbox.take_expired(10_000, 10_000);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].message.id, "old");
+        assert_eq!(ids(&outbox), vec!["new"]);
+    }
+}
@@ -1,15 +1,20 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Local, Utc};
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
+use rusqlite::functions::FunctionFlags;
 use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Emitter};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
 
-use crate::audio_toolkit::save_wav_file;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::vad::{speech_bounds, SILERO_FRAME_SAMPLES};
+use crate::audio_toolkit::{save_wav_file, SileroVad, VoiceActivityDetector};
+use crate::subtitle::SubtitleSegment;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -40,6 +45,76 @@ static MIGRATIONS: &[M] = &[
     M::up(
         "ALTER TABLE transcription_history ADD COLUMN post_process_requested BOOLEAN NOT NULL DEFAULT 0;",
     ),
+    M::up("ALTER TABLE transcription_history ADD COLUMN post_process_note TEXT;"),
+    M::up("ALTER TABLE transcription_history ADD COLUMN corrected_text TEXT;"),
+    // Accuracy self-evaluation: user-supplied reference text and the STT provider used
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN reference_text TEXT;
+         ALTER TABLE transcription_history ADD COLUMN stt_provider TEXT;",
+    ),
+    // Dictation sessions: consecutive takes for the same profile and target app
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN session_group_id TEXT;
+         ALTER TABLE transcription_history ADD COLUMN profile_id TEXT;
+         ALTER TABLE transcription_history ADD COLUMN target_app TEXT;",
+    ),
+    // On-demand translations of an entry; several languages per entry.
+    // The trigger keeps every delete path from leaving orphaned rows behind.
+    M::up(
+        "CREATE TABLE IF NOT EXISTS history_translations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            entry_id INTEGER NOT NULL,
+            language TEXT NOT NULL,
+            text TEXT NOT NULL,
+            provider_id TEXT NOT NULL,
+            model TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_history_translations_entry ON history_translations(entry_id);
+         CREATE TRIGGER IF NOT EXISTS delete_history_translations
+         AFTER DELETE ON transcription_history
+         BEGIN
+             DELETE FROM history_translations WHERE entry_id = OLD.id;
+         END;",
+    ),
+    // LLM provider that produced the post-processed text (differs from the
+    // selected one when the fallback provider answered)
+    M::up("ALTER TABLE transcription_history ADD COLUMN post_process_provider TEXT;"),
+    // Segment timings (JSON array of SubtitleSegment) for SRT/VTT export
+    M::up("ALTER TABLE transcription_history ADD COLUMN timestamps_json TEXT;"),
+    // Average token confidence reported by the transcription provider
+    M::up("ALTER TABLE transcription_history ADD COLUMN confidence REAL;"),
+    // Full LLM response when a prompt's JSON output path was extracted
+    M::up("ALTER TABLE transcription_history ADD COLUMN post_process_raw_response TEXT;"),
+    // AI Replace responses the user declined in the confirm-before-paste preview
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN ai_response_rejected BOOLEAN NOT NULL DEFAULT 0;",
+    ),
+    // History search filters on and orders by timestamp
+    M::up(
+        "CREATE INDEX IF NOT EXISTS idx_transcription_history_timestamp ON transcription_history(timestamp);",
+    ),
+    // Post-processed texts replaced by re-running post-processing on an entry
+    M::up(
+        "CREATE TABLE IF NOT EXISTS history_post_process_revisions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            entry_id INTEGER NOT NULL,
+            text TEXT NOT NULL,
+            prompt TEXT,
+            provider_id TEXT,
+            created_at INTEGER NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_history_post_process_revisions_entry ON history_post_process_revisions(entry_id);
+         CREATE TRIGGER IF NOT EXISTS delete_history_post_process_revisions
+         AFTER DELETE ON transcription_history
+         BEGIN
+             DELETE FROM history_post_process_revisions WHERE entry_id = OLD.id;
+         END;",
+    ),
+    // Post-processing ran the transcript in chunks (see `post_process_chunk_threshold_chars`)
+    M::up(
+        "ALTER TABLE transcription_history ADD COLUMN post_process_chunked BOOLEAN NOT NULL DEFAULT 0;",
+    ),
 ];
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -48,6 +123,55 @@ pub struct PaginatedHistory {
     pub has_more: bool,
 }
 
+/// Upper bound for one page of search results.
+const SEARCH_MAX_LIMIT: usize = 100;
+/// Characters of entry text included in a search result.
+const SEARCH_PREVIEW_CHARS: usize = 200;
+
+/// Filters for [`HistoryManager::search_entries`]; unset fields match everything.
+#[derive(Clone, Debug, Default)]
+pub struct HistorySearchQuery {
+    /// Matched case-insensitively against the transcription, post-processed
+    /// text and AI response
+    pub text: Option<String>,
+    pub action_type: Option<String>,
+    /// Inclusive range in unix seconds
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Text column a search query matched in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HistorySearchField {
+    TranscriptionText,
+    PostProcessedText,
+    AiResponse,
+}
+
+/// Search result row: enough to list and open an entry, without its full text.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistoryEntrySummary {
+    pub id: i64,
+    pub timestamp: i64,
+    pub saved: bool,
+    pub title: String,
+    pub action_type: String,
+    /// Start of the matched text, or of the final text when there is no query
+    pub preview: String,
+    /// First column the query matched in; None when searching without text
+    pub matched_field: Option<HistorySearchField>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistorySearchResults {
+    pub entries: Vec<HistoryEntrySummary>,
+    /// Matching entries across all pages
+    pub total: usize,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 #[serde(tag = "action")]
 pub enum HistoryUpdatePayload {
@@ -74,18 +198,222 @@ pub struct HistoryEntry {
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
     pub post_process_requested: bool,
+    /// Notes about adjustments made to the post-processed output (e.g. length limit enforcement)
+    pub post_process_note: Option<String>,
+    /// Raw transcription with the current custom words re-applied; the original is kept as-is
+    pub corrected_text: Option<String>,
+    /// User-supplied correct final text, used for accuracy self-evaluation
+    pub reference_text: Option<String>,
+    /// Transcription provider that produced the entry (e.g. "local", "remote_soniox")
+    pub stt_provider: Option<String>,
+    /// LLM provider that produced the post-processed text
+    pub post_process_provider: Option<String>,
+    /// Full LLM response when the post-processed text was extracted from JSON
+    pub post_process_raw_response: Option<String>,
+    /// The post-processed text was produced in chunks and stitched together
+    #[serde(default)]
+    pub post_process_chunked: bool,
     /// Type of action: "transcribe", "ai_replace", etc.
     pub action_type: String,
     /// For AI Replace: the original selected text that was transformed
     pub original_selection: Option<String>,
     /// For AI Replace: the AI response (None if request failed/never received)
     pub ai_response: Option<String>,
+    /// For AI Replace: the response was declined in the preview and never pasted
+    #[serde(default)]
+    pub ai_response_rejected: bool,
+    /// Dictation session shared by consecutive takes (see `assign_session_group`)
+    pub session_group_id: Option<String>,
+    /// Transcription profile the entry was dictated with
+    pub profile_id: Option<String>,
+    /// Application that was focused when the entry was dictated
+    pub target_app: Option<String>,
+    /// Average token confidence (0.0–1.0); None if the provider reports none
+    pub confidence: Option<f32>,
+    /// Languages the entry has been translated into (see `get_translations`)
+    #[serde(default)]
+    pub translation_languages: Vec<String>,
+}
+
+/// A translation of a history entry, produced on demand by the post-process provider.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistoryTranslation {
+    pub id: i64,
+    pub entry_id: i64,
+    /// Target language as requested, e.g. "German" or "de"
+    pub language: String,
+    pub text: String,
+    pub provider_id: String,
+    pub model: String,
+    pub created_at: i64,
+}
+
+/// A post-processed text that was replaced when the entry was reprocessed.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistoryPostProcessRevision {
+    pub id: i64,
+    pub entry_id: i64,
+    pub text: String,
+    pub prompt: Option<String>,
+    pub provider_id: Option<String>,
+    /// When the text was replaced, not when it was produced
+    pub created_at: i64,
+}
+
+/// Consecutive history entries dictated with the same profile into the same app.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct HistorySession {
+    pub group_id: String,
+    /// Entry ids in dictation order
+    pub entry_ids: Vec<i64>,
+    pub profile_id: Option<String>,
+    pub target_app: Option<String>,
+    /// Timestamp of the first take
+    pub started_at: i64,
+    /// Timestamp of the last take
+    pub ended_at: i64,
+    /// Words across all takes, counting the post-processed text where present
+    pub word_count: usize,
+}
+
+/// Text an entry contributed to the document: post-processed output if any.
+pub fn entry_final_text(entry: &HistoryEntry) -> &str {
+    entry
+        .post_processed_text
+        .as_deref()
+        .unwrap_or(&entry.transcription_text)
+}
+
+/// Builds sessions from grouped entries given oldest first; sessions are
+/// returned newest first.
+pub fn group_sessions(entries: &[HistoryEntry]) -> Vec<HistorySession> {
+    let mut sessions: Vec<HistorySession> = Vec::new();
+    for entry in entries {
+        let Some(group_id) = entry.session_group_id.as_deref() else {
+            continue;
+        };
+        let words = entry_final_text(entry).split_whitespace().count();
+        match sessions.iter_mut().find(|s| s.group_id == group_id) {
+            Some(session) => {
+                session.entry_ids.push(entry.id);
+                session.ended_at = session.ended_at.max(entry.timestamp);
+                session.word_count += words;
+            }
+            None => sessions.push(HistorySession {
+                group_id: group_id.to_string(),
+                entry_ids: vec![entry.id],
+                profile_id: entry.profile_id.clone(),
+                target_app: entry.target_app.clone(),
+                started_at: entry.timestamp,
+                ended_at: entry.timestamp,
+                word_count: words,
+            }),
+        }
+    }
+    sessions.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    sessions
+}
+
+/// File format for exported history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryExportFormat {
+    /// Array of full entries.
+    Json,
+    /// One row per entry with the main text columns.
+    Csv,
+}
+
+impl HistoryExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            HistoryExportFormat::Json => "json",
+            HistoryExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Serializes entries for export in the given format.
+pub fn export_entries(entries: &[HistoryEntry], format: HistoryExportFormat) -> Result<String> {
+    match format {
+        HistoryExportFormat::Json => Ok(serde_json::to_string_pretty(entries)?),
+        HistoryExportFormat::Csv => Ok(entries_to_csv(entries)),
+    }
+}
+
+const CSV_HEADER: [&str; 5] = [
+    "timestamp",
+    "action_type",
+    "transcription_text",
+    "post_processed_text",
+    "ai_response",
+];
+
+fn entries_to_csv(entries: &[HistoryEntry]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for entry in entries {
+        let timestamp = DateTime::from_timestamp(entry.timestamp, 0)
+            .map(|datetime| datetime.to_rfc3339())
+            .unwrap_or_else(|| entry.timestamp.to_string());
+        let fields = [
+            timestamp.as_str(),
+            entry.action_type.as_str(),
+            entry.transcription_text.as_str(),
+            entry.post_processed_text.as_deref().unwrap_or(""),
+            entry.ai_response.as_deref().unwrap_or(""),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quotes a CSV field (RFC 4180) when it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Unicode-aware lowercasing; SQLite's own `lower()` and `LIKE` fold ASCII only.
+fn fold_case(text: &str) -> String {
+    text.to_lowercase()
+}
+
+/// Registers `history_contains(text, folded_needle)` on the connection.
+fn register_search_function(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "history_contains",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let text = ctx.get::<Option<String>>(0)?;
+            let needle = ctx.get::<String>(1)?;
+            Ok(text.is_some_and(|text| fold_case(&text).contains(&needle)))
+        },
+    )?;
+    Ok(())
+}
+
+fn search_preview(text: &str) -> String {
+    let mut chars = text.chars();
+    let mut preview: String = chars.by_ref().take(SEARCH_PREVIEW_CHARS).collect();
+    if chars.next().is_some() {
+        preview.push('…');
+    }
+    preview
 }
 
 pub struct HistoryManager {
     app_handle: AppHandle,
     recordings_dir: PathBuf,
     db_path: PathBuf,
+    /// VAD used to trim saved recordings, loaded on first use.
+    trim_vad: Mutex<Option<SileroVad>>,
 }
 
 impl HistoryManager {
@@ -105,6 +433,7 @@ impl HistoryManager {
             app_handle: app_handle.clone(),
             recordings_dir,
             db_path,
+            trim_vad: Mutex::new(None),
         };
 
         // Initialize database and run migrations synchronously
@@ -225,7 +554,10 @@ impl HistoryManager {
         &self.recordings_dir
     }
 
-    /// Save a transcription to history (both database and WAV file)
+    /// Save a transcription to history (both database and WAV file).
+    ///
+    /// Also returns how many seconds were trimmed from the start of the saved
+    /// audio; segment timings must be shifted by it before they are stored.
     pub async fn save_transcription(
         &self,
         audio_samples: Vec<f32>,
@@ -233,116 +565,881 @@ impl HistoryManager {
         post_process_requested: bool,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
-    ) -> Result<()> {
+    ) -> Result<(HistoryEntry, f32)> {
         let file_name = format!("aivorelay-{}.wav", chrono::Utc::now().timestamp_millis());
 
+        // Transcription already ran on the full recording; only the saved copy is trimmed.
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let (audio_samples, trimmed_start) = if settings.history_trim_silence_enabled {
+            self.trim_silence(
+                audio_samples,
+                settings.vad_threshold,
+                settings.history_trim_silence_padding_ms,
+            )
+        } else {
+            (audio_samples, 0)
+        };
+
         // Save WAV file
         let file_path = self.recordings_dir.join(&file_name);
         save_wav_file(file_path, &audio_samples)?;
 
-        self.save_entry(
+        let entry = self.save_entry(
             file_name,
             transcription_text,
             post_process_requested,
             post_processed_text,
             post_process_prompt,
         )?;
-
-        Ok(())
+        Ok((entry, trimmed_start as f32 / WHISPER_SAMPLE_RATE as f32))
+    }
+
+    /// Drops leading and trailing non-speech, keeping `padding_ms` of it on
+    /// each side, and returns the kept samples with the number cut from the
+    /// start. Returns the samples unchanged when the VAD is unavailable or
+    /// hears no speech at all, so silent takes never become empty files.
+    fn trim_silence(
+        &self,
+        samples: Vec<f32>,
+        vad_threshold: f32,
+        padding_ms: u32,
+    ) -> (Vec<f32>, usize) {
+        let mut trim_vad = self.trim_vad.lock().unwrap_or_else(|p| p.into_inner());
+        if trim_vad.is_none() {
+            let vad_path = match self.app_handle.path().resolve(
+                "resources/models/silero_vad_v4.onnx",
+                tauri::path::BaseDirectory::Resource,
+            ) {
+                Ok(path) => path,
+                Err(e) => {
+                    warn!("Skipping history silence trim: VAD model not found: {}", e);
+                    return (samples, 0);
+                }
+            };
+            match SileroVad::new(&vad_path, vad_threshold) {
+                Ok(vad) => *trim_vad = Some(vad),
+                Err(e) => {
+                    warn!("Skipping history silence trim: {}", e);
+                    return (samples, 0);
+                }
+            }
+        }
+        let Some(vad) = trim_vad.as_mut() else {
+            return (samples, 0);
+        };
+        vad.set_threshold(vad_threshold);
+
+        let padding = padding_ms as usize * WHISPER_SAMPLE_RATE as usize / 1000;
+        match speech_bounds(vad, &samples, SILERO_FRAME_SAMPLES, padding) {
+            Ok(Some(range)) if range.len() < samples.len() => {
+                debug!(
+                    "Trimmed {} of {} samples of silence from history recording",
+                    samples.len() - range.len(),
+                    samples.len()
+                );
+                let start = range.start;
+                (samples[range].to_vec(), start)
+            }
+            Ok(_) => (samples, 0),
+            Err(e) => {
+                warn!("Skipping history silence trim: {}", e);
+                (samples, 0)
+            }
+        }
+    }
+
+    pub fn save_entry(
+        &self,
+        file_name: String,
+        transcription_text: String,
+        post_process_requested: bool,
+        post_processed_text: Option<String>,
+        post_process_prompt: Option<String>,
+    ) -> Result<HistoryEntry> {
+        let timestamp = Utc::now().timestamp();
+        let title = self.format_timestamp_title(timestamp);
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO transcription_history (
+                file_name,
+                timestamp,
+                saved,
+                title,
+                transcription_text,
+                post_processed_text,
+                post_process_prompt,
+                post_process_requested,
+                action_type
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                file_name,
+                timestamp,
+                false,
+                title,
+                transcription_text,
+                post_processed_text,
+                post_process_prompt,
+                post_process_requested,
+                "transcribe"
+            ],
+        )?;
+
+        let entry = HistoryEntry {
+            id: conn.last_insert_rowid(),
+            file_name,
+            timestamp,
+            saved: false,
+            title,
+            transcription_text,
+            post_processed_text,
+            post_process_prompt,
+            post_process_requested,
+            post_process_note: None,
+            corrected_text: None,
+            reference_text: None,
+            stt_provider: None,
+            post_process_provider: None,
+            post_process_raw_response: None,
+            post_process_chunked: false,
+            action_type: "transcribe".to_string(),
+            original_selection: None,
+            ai_response: None,
+            ai_response_rejected: false,
+            session_group_id: None,
+            profile_id: None,
+            target_app: None,
+            confidence: None,
+            translation_languages: Vec::new(),
+        };
+
+        debug!("Saved transcription to database");
+        self.cleanup_old_entries()?;
+        self.emit_history_added(entry.clone());
+        Ok(entry)
+    }
+
+    pub fn update_transcription(
+        &self,
+        id: i64,
+        transcription_text: String,
+        post_processed_text: Option<String>,
+        post_process_prompt: Option<String>,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let updated = conn.execute(
+            "UPDATE transcription_history
+             SET transcription_text = ?1,
+                 post_processed_text = ?2,
+                 post_process_prompt = ?3
+             WHERE id = ?4",
+            params![
+                transcription_text,
+                post_processed_text,
+                post_process_prompt,
+                id
+            ],
+        )?;
+
+        if updated == 0 {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let entry = conn
+            .query_row(
+                "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+                 FROM transcription_history
+                 WHERE id = ?1",
+                params![id],
+                Self::map_history_entry,
+            )?;
+
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    /// Record a note about how the post-processed output was adjusted.
+    pub fn set_post_process_note(&self, id: i64, note: Option<String>) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let updated = conn.execute(
+            "UPDATE transcription_history SET post_process_note = ?1 WHERE id = ?2",
+            params![note, id],
+        )?;
+
+        if updated == 0 {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let entry = conn.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?;
+
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    /// Store (or clear) the custom-word corrected variant of an entry's raw text.
+    pub fn set_corrected_text(
+        &self,
+        id: i64,
+        corrected_text: Option<String>,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let updated = conn.execute(
+            "UPDATE transcription_history SET corrected_text = ?1 WHERE id = ?2",
+            params![corrected_text, id],
+        )?;
+
+        if updated == 0 {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let entry = conn.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?;
+
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    /// Change the action type of an entry (e.g. "search" for Transcribe and Search)
+    /// and record which transcription provider produced it.
+    pub fn set_action_type(
+        &self,
+        id: i64,
+        action_type: &str,
+        stt_provider: &str,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let updated = conn.execute(
+            "UPDATE transcription_history SET action_type = ?1, stt_provider = ?2 WHERE id = ?3",
+            params![action_type, stt_provider, id],
+        )?;
+
+        if updated == 0 {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let entry = conn.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?;
+
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    /// Store (or clear) the reference text used for accuracy self-evaluation.
+    pub fn set_reference_text(
+        &self,
+        id: i64,
+        reference_text: Option<String>,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let updated = conn.execute(
+            "UPDATE transcription_history SET reference_text = ?1 WHERE id = ?2",
+            params![reference_text, id],
+        )?;
+
+        if updated == 0 {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let entry = conn.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?;
+
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    /// Record which transcription provider produced an entry.
+    pub fn set_stt_provider(&self, id: i64, provider: &str) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET stt_provider = ?1 WHERE id = ?2",
+            params![provider, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_post_process_provider(&self, id: i64, provider: Option<&str>) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET post_process_provider = ?1 WHERE id = ?2",
+            params![provider, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_post_process_raw_response(&self, id: i64, response: Option<&str>) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET post_process_raw_response = ?1 WHERE id = ?2",
+            params![response, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_post_process_chunked(&self, id: i64, chunked: bool) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET post_process_chunked = ?1 WHERE id = ?2",
+            params![chunked, id],
+        )?;
+        Ok(())
+    }
+
+    /// Record the provider-reported confidence of an entry.
+    pub fn set_confidence(&self, id: i64, confidence: Option<f32>) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE transcription_history SET confidence = ?1 WHERE id = ?2",
+            params![confidence, id],
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the segment timings of entry `id`; None clears them.
+    pub fn set_timestamps(&self, id: i64, segments: Option<&[SubtitleSegment]>) -> Result<()> {
+        let conn = self.get_connection()?;
+        Self::set_timestamps_with_conn(&conn, id, segments)
+    }
+
+    fn set_timestamps_with_conn(
+        conn: &Connection,
+        id: i64,
+        segments: Option<&[SubtitleSegment]>,
+    ) -> Result<()> {
+        let json = segments.map(serde_json::to_string).transpose()?;
+        conn.execute(
+            "UPDATE transcription_history SET timestamps_json = ?1 WHERE id = ?2",
+            params![json, id],
+        )?;
+        Ok(())
+    }
+
+    /// Segment timings saved with entry `id`. Ok(None) when the entry has none
+    /// because its engine reported no timings or capture was off.
+    pub fn get_timestamps(&self, id: i64) -> Result<Option<Vec<SubtitleSegment>>> {
+        let conn = self.get_connection()?;
+        Self::get_timestamps_with_conn(&conn, id)
+    }
+
+    fn get_timestamps_with_conn(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<Option<Vec<SubtitleSegment>>> {
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT timestamps_json FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| anyhow!("History entry {} not found", id))?;
+        json.map(|json| serde_json::from_str(&json).map_err(Into::into))
+            .transpose()
+    }
+
+    /// Records the profile and target app of a new transcription entry and puts
+    /// it into the dictation session of the previous take when that take used
+    /// the same profile and app and was saved at most `gap_secs` before this
+    /// one. Otherwise the entry starts a new session. A gap of 0 disables grouping.
+    pub fn assign_session_group(
+        &self,
+        id: i64,
+        profile_id: &str,
+        target_app: &str,
+        gap_secs: i64,
+    ) -> Result<HistoryEntry> {
+        let conn = self.get_connection()?;
+        let entry =
+            Self::assign_session_group_with_conn(&conn, id, profile_id, target_app, gap_secs)?;
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    fn assign_session_group_with_conn(
+        conn: &Connection,
+        id: i64,
+        profile_id: &str,
+        target_app: &str,
+        gap_secs: i64,
+    ) -> Result<HistoryEntry> {
+        let timestamp: i64 = conn
+            .query_row(
+                "SELECT timestamp FROM transcription_history WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| anyhow!("History entry {} not found", id))?;
+
+        let group_id = if gap_secs > 0 {
+            let previous: Option<(i64, Option<String>, Option<String>, Option<String>)> = conn
+                .query_row(
+                    "SELECT timestamp, session_group_id, profile_id, target_app
+                     FROM transcription_history
+                     WHERE id < ?1
+                       AND COALESCE(action_type, 'transcribe') = 'transcribe'
+                     ORDER BY id DESC
+                     LIMIT 1",
+                    params![id],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                )
+                .optional()?;
+            let continued = previous.and_then(|(prev_timestamp, group, prev_profile, prev_app)| {
+                (timestamp - prev_timestamp <= gap_secs
+                    && prev_profile.as_deref() == Some(profile_id)
+                    && prev_app.as_deref() == Some(target_app))
+                .then_some(group)
+                .flatten()
+            });
+            Some(continued.unwrap_or_else(|| format!("session-{}", id)))
+        } else {
+            None
+        };
+
+        conn.execute(
+            "UPDATE transcription_history
+             SET session_group_id = ?1, profile_id = ?2, target_app = ?3
+             WHERE id = ?4",
+            params![group_id, profile_id, target_app, id],
+        )?;
+
+        Ok(conn.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?)
+    }
+
+    /// Stores a translation of entry `id` and returns it. An entry may hold
+    /// several translations, including more than one for the same language.
+    pub fn add_translation(
+        &self,
+        id: i64,
+        language: &str,
+        text: &str,
+        provider_id: &str,
+        model: &str,
+    ) -> Result<HistoryTranslation> {
+        let conn = self.get_connection()?;
+        let translation =
+            Self::add_translation_with_conn(&conn, id, language, text, provider_id, model)?;
+        if let Some(entry) = conn
+            .query_row(
+                "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+                 FROM transcription_history
+                 WHERE id = ?1",
+                params![id],
+                Self::map_history_entry,
+            )
+            .optional()?
+        {
+            self.emit_history_updated(&entry);
+        }
+        Ok(translation)
+    }
+
+    fn add_translation_with_conn(
+        conn: &Connection,
+        id: i64,
+        language: &str,
+        text: &str,
+        provider_id: &str,
+        model: &str,
+    ) -> Result<HistoryTranslation> {
+        let exists: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM transcription_history WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(anyhow!("History entry {} not found", id));
+        }
+
+        let created_at = Utc::now().timestamp();
+        conn.execute(
+            "INSERT INTO history_translations (entry_id, language, text, provider_id, model, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![id, language, text, provider_id, model, created_at],
+        )?;
+
+        Ok(HistoryTranslation {
+            id: conn.last_insert_rowid(),
+            entry_id: id,
+            language: language.to_string(),
+            text: text.to_string(),
+            provider_id: provider_id.to_string(),
+            model: model.to_string(),
+            created_at,
+        })
+    }
+
+    /// Translations of entry `id`, oldest first.
+    pub fn get_translations(&self, id: i64) -> Result<Vec<HistoryTranslation>> {
+        let conn = self.get_connection()?;
+        Self::get_translations_with_conn(&conn, id)
+    }
+
+    fn get_translations_with_conn(conn: &Connection, id: i64) -> Result<Vec<HistoryTranslation>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, entry_id, language, text, provider_id, model, created_at
+             FROM history_translations
+             WHERE entry_id = ?1
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map(params![id], |row| {
+            Ok(HistoryTranslation {
+                id: row.get("id")?,
+                entry_id: row.get("entry_id")?,
+                language: row.get("language")?,
+                text: row.get("text")?,
+                provider_id: row.get("provider_id")?,
+                model: row.get("model")?,
+                created_at: row.get("created_at")?,
+            })
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Replaces the post-processed text of entry `id` with a reprocessed one.
+    /// The previous post-processed text, if any, is kept as a revision.
+    pub fn set_reprocessed_text(
+        &self,
+        id: i64,
+        text: &str,
+        prompt: &str,
+        provider_id: &str,
+        note: Option<&str>,
+        raw_response: Option<&str>,
+        chunked: bool,
+    ) -> Result<HistoryEntry> {
+        let mut conn = self.get_connection()?;
+        let entry = Self::set_reprocessed_text_with_conn(
+            &mut conn,
+            id,
+            text,
+            prompt,
+            provider_id,
+            note,
+            raw_response,
+            chunked,
+        )?;
+        self.emit_history_updated(&entry);
+        Ok(entry)
+    }
+
+    fn set_reprocessed_text_with_conn(
+        conn: &mut Connection,
+        id: i64,
+        text: &str,
+        prompt: &str,
+        provider_id: &str,
+        note: Option<&str>,
+        raw_response: Option<&str>,
+        chunked: bool,
+    ) -> Result<HistoryEntry> {
+        let tx = conn.transaction()?;
+        let previous: Option<(Option<String>, Option<String>, Option<String>)> = tx
+            .query_row(
+                "SELECT post_processed_text, post_process_prompt, post_process_provider
+                 FROM transcription_history
+                 WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+        let Some((previous_text, previous_prompt, previous_provider)) = previous else {
+            return Err(anyhow!("History entry {} not found", id));
+        };
+
+        if let Some(previous_text) = previous_text {
+            tx.execute(
+                "INSERT INTO history_post_process_revisions (entry_id, text, prompt, provider_id, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    id,
+                    previous_text,
+                    previous_prompt,
+                    previous_provider,
+                    Utc::now().timestamp()
+                ],
+            )?;
+        }
+
+        tx.execute(
+            "UPDATE transcription_history
+             SET post_processed_text = ?1,
+                 post_process_prompt = ?2,
+                 post_process_provider = ?3,
+                 post_process_note = ?4,
+                 post_process_raw_response = ?5,
+                 post_process_chunked = ?6
+             WHERE id = ?7",
+            params![text, prompt, provider_id, note, raw_response, chunked, id],
+        )?;
+
+        let entry = tx.query_row(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE id = ?1",
+            params![id],
+            Self::map_history_entry,
+        )?;
+        tx.commit()?;
+        Ok(entry)
+    }
+
+    /// Post-processed texts entry `id` had before it was reprocessed, oldest first.
+    pub fn get_post_process_revisions(&self, id: i64) -> Result<Vec<HistoryPostProcessRevision>> {
+        let conn = self.get_connection()?;
+        Self::get_post_process_revisions_with_conn(&conn, id)
+    }
+
+    fn get_post_process_revisions_with_conn(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<Vec<HistoryPostProcessRevision>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, entry_id, text, prompt, provider_id, created_at
+             FROM history_post_process_revisions
+             WHERE entry_id = ?1
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map(params![id], |row| {
+            Ok(HistoryPostProcessRevision {
+                id: row.get("id")?,
+                entry_id: row.get("entry_id")?,
+                text: row.get("text")?,
+                prompt: row.get("prompt")?,
+                provider_id: row.get("provider_id")?,
+                created_at: row.get("created_at")?,
+            })
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Entries that belong to a dictation session, oldest first.
+    pub fn get_grouped_entries(&self) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE session_group_id IS NOT NULL
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map([], Self::map_history_entry)?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Takes of one dictation session, oldest first.
+    pub fn get_session_entries(&self, group_id: &str) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE session_group_id = ?1
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map(params![group_id], Self::map_history_entry)?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Transcription entries that have a reference text, oldest first.
+    pub fn get_entries_with_reference(&self) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE reference_text IS NOT NULL
+               AND COALESCE(action_type, 'transcribe') = 'transcribe'
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt.query_map([], Self::map_history_entry)?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Entries with a timestamp inside the optional `[from, to]` range (unix
+    /// seconds, inclusive), oldest first.
+    pub fn get_entries_in_range(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        Self::get_entries_in_range_with_conn(&conn, from, to)
+    }
+
+    fn get_entries_in_range_with_conn(
+        conn: &Connection,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<Vec<HistoryEntry>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE (?1 IS NULL OR timestamp >= ?1)
+               AND (?2 IS NULL OR timestamp <= ?2)
+             ORDER BY timestamp ASC, id ASC",
+        )?;
+        let rows = stmt.query_map(params![from, to], Self::map_history_entry)?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
     }
 
-    pub fn save_entry(
-        &self,
-        file_name: String,
-        transcription_text: String,
-        post_process_requested: bool,
-        post_processed_text: Option<String>,
-        post_process_prompt: Option<String>,
-    ) -> Result<HistoryEntry> {
-        let timestamp = Utc::now().timestamp();
-        let title = self.format_timestamp_title(timestamp);
+    /// Most recent transcription entries (AI Replace entries excluded), newest first.
+    pub fn get_recent_transcription_entries(&self, limit: usize) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
-        conn.execute(
-            "INSERT INTO transcription_history (
-                file_name,
-                timestamp,
-                saved,
-                title,
-                transcription_text,
-                post_processed_text,
-                post_process_prompt,
-                post_process_requested,
-                action_type
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-            params![
-                file_name,
-                timestamp,
-                false,
-                title,
-                transcription_text,
-                post_processed_text,
-                post_process_prompt,
-                post_process_requested,
-                "transcribe"
-            ],
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE COALESCE(action_type, 'transcribe') = 'transcribe'
+             ORDER BY id DESC
+             LIMIT ?1",
         )?;
-
-        let entry = HistoryEntry {
-            id: conn.last_insert_rowid(),
-            file_name,
-            timestamp,
-            saved: false,
-            title,
-            transcription_text,
-            post_processed_text,
-            post_process_prompt,
-            post_process_requested,
-            action_type: "transcribe".to_string(),
-            original_selection: None,
-            ai_response: None,
-        };
-
-        debug!("Saved transcription to database");
-        self.cleanup_old_entries()?;
-        self.emit_history_added(entry.clone());
-        Ok(entry)
+        let rows = stmt.query_map(params![limit as i64], Self::map_history_entry)?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
     }
 
-    pub fn update_transcription(
-        &self,
-        id: i64,
-        transcription_text: String,
-        post_processed_text: Option<String>,
-        post_process_prompt: Option<String>,
-    ) -> Result<HistoryEntry> {
+    /// One page of entries matching `query`, newest first, with the total
+    /// number of matches.
+    pub fn search_entries(&self, query: &HistorySearchQuery) -> Result<HistorySearchResults> {
         let conn = self.get_connection()?;
-        let updated = conn.execute(
-            "UPDATE transcription_history
-             SET transcription_text = ?1,
-                 post_processed_text = ?2,
-                 post_process_prompt = ?3
-             WHERE id = ?4",
+        Self::search_entries_with_conn(&conn, query)
+    }
+
+    fn search_entries_with_conn(
+        conn: &Connection,
+        query: &HistorySearchQuery,
+    ) -> Result<HistorySearchResults> {
+        register_search_function(conn)?;
+
+        let needle = query
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(fold_case);
+        let action_type = query
+            .action_type
+            .as_deref()
+            .filter(|value| !value.is_empty());
+        let filter = "WHERE (?1 IS NULL
+                    OR history_contains(transcription_text, ?1)
+                    OR history_contains(post_processed_text, ?1)
+                    OR history_contains(ai_response, ?1))
+               AND (?2 IS NULL OR COALESCE(action_type, 'transcribe') = ?2)
+               AND (?3 IS NULL OR timestamp >= ?3)
+               AND (?4 IS NULL OR timestamp <= ?4)";
+
+        let total: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM transcription_history {}", filter),
+            params![needle, action_type, query.from, query.to],
+            |row| row.get(0),
+        )?;
+
+        let limit = query.limit.clamp(1, SEARCH_MAX_LIMIT);
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, timestamp, saved, title, action_type, transcription_text, post_processed_text, ai_response
+             FROM transcription_history
+             {}
+             ORDER BY timestamp DESC, id DESC
+             LIMIT ?5 OFFSET ?6",
+            filter
+        ))?;
+        let rows = stmt.query_map(
             params![
-                transcription_text,
-                post_processed_text,
-                post_process_prompt,
-                id
+                needle,
+                action_type,
+                query.from,
+                query.to,
+                limit as i64,
+                query.offset as i64
             ],
+            |row| {
+                let transcription_text: String = row.get("transcription_text")?;
+                let post_processed_text: Option<String> = row.get("post_processed_text")?;
+                let ai_response: Option<String> = row.get("ai_response")?;
+                let fields = [
+                    (
+                        HistorySearchField::TranscriptionText,
+                        Some(transcription_text.as_str()),
+                    ),
+                    (
+                        HistorySearchField::PostProcessedText,
+                        post_processed_text.as_deref(),
+                    ),
+                    (HistorySearchField::AiResponse, ai_response.as_deref()),
+                ];
+                let matched = needle.as_deref().and_then(|needle| {
+                    fields.iter().find_map(|(field, text)| {
+                        text.filter(|text| fold_case(text).contains(needle))
+                            .map(|text| (*field, text))
+                    })
+                });
+                let (matched_field, preview) = match matched {
+                    Some((field, text)) => (Some(field), text),
+                    None => (
+                        None,
+                        ai_response
+                            .as_deref()
+                            .or(post_processed_text.as_deref())
+                            .unwrap_or(&transcription_text),
+                    ),
+                };
+
+                Ok(HistoryEntrySummary {
+                    id: row.get("id")?,
+                    timestamp: row.get("timestamp")?,
+                    saved: row.get("saved")?,
+                    title: row.get("title")?,
+                    action_type: row
+                        .get::<_, Option<String>>("action_type")?
+                        .unwrap_or_else(|| "transcribe".to_string()),
+                    preview: search_preview(preview),
+                    matched_field,
+                })
+            },
         )?;
 
-        if updated == 0 {
-            return Err(anyhow!("History entry {} not found", id));
-        }
-
-        let entry = conn
-            .query_row(
-                "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
-                 FROM transcription_history
-                 WHERE id = ?1",
-                params![id],
-                Self::map_history_entry,
-            )?;
+        Ok(HistorySearchResults {
+            entries: rows.collect::<std::result::Result<Vec<_>, _>>()?,
+            total: total as usize,
+        })
+    }
 
+    fn emit_history_updated(&self, entry: &HistoryEntry) {
         if let Err(e) = self.app_handle.emit(
             "history-update-payload",
             &HistoryUpdatePayload::Updated {
@@ -355,8 +1452,6 @@ impl HistoryManager {
         if let Err(e) = self.app_handle.emit("history-updated", ()) {
             error!("Failed to emit history-updated event: {}", e);
         }
-
-        Ok(entry)
     }
 
     pub fn cleanup_old_entries(&self) -> Result<()> {
@@ -507,11 +1602,31 @@ impl HistoryManager {
             post_process_requested: row
                 .get::<_, Option<bool>>("post_process_requested")?
                 .unwrap_or(false),
+            post_process_note: row.get("post_process_note")?,
+            corrected_text: row.get("corrected_text")?,
+            reference_text: row.get("reference_text")?,
+            stt_provider: row.get("stt_provider")?,
+            post_process_provider: row.get("post_process_provider")?,
+            post_process_raw_response: row.get("post_process_raw_response")?,
+            post_process_chunked: row
+                .get::<_, Option<bool>>("post_process_chunked")?
+                .unwrap_or(false),
             action_type: row
                 .get::<_, Option<String>>("action_type")?
                 .unwrap_or_else(|| "transcribe".to_string()),
             original_selection: row.get("original_selection")?,
             ai_response: row.get("ai_response")?,
+            ai_response_rejected: row
+                .get::<_, Option<bool>>("ai_response_rejected")?
+                .unwrap_or(false),
+            session_group_id: row.get("session_group_id")?,
+            profile_id: row.get("profile_id")?,
+            target_app: row.get("target_app")?,
+            confidence: row.get("confidence")?,
+            translation_languages: row
+                .get::<_, Option<String>>("translation_languages")?
+                .map(|languages| languages.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
         })
     }
 
@@ -580,7 +1695,8 @@ impl HistoryManager {
         let mut entries = match (cursor, limit) {
             (Some(cursor_id), Some(page_size)) => {
                 let mut stmt = conn.prepare(
-                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
                      FROM transcription_history
                      WHERE id < ?1
                      ORDER BY id DESC
@@ -594,7 +1710,8 @@ impl HistoryManager {
             }
             (None, Some(page_size)) => {
                 let mut stmt = conn.prepare(
-                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
                      FROM transcription_history
                      ORDER BY id DESC
                      LIMIT ?1",
@@ -605,7 +1722,8 @@ impl HistoryManager {
             }
             (_, None) => {
                 let mut stmt = conn.prepare(
-                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+                    "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
                      FROM transcription_history
                      ORDER BY id DESC",
                 )?;
@@ -629,7 +1747,8 @@ impl HistoryManager {
 
     fn get_latest_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
              FROM transcription_history
              ORDER BY timestamp DESC
              LIMIT 1",
@@ -640,6 +1759,43 @@ impl HistoryManager {
         Ok(entry)
     }
 
+    /// Returns up to `n` of the newest entries with text to paste, oldest first.
+    /// AI Replace entries count only when `include_ai_replace` is set and their
+    /// response was received.
+    pub fn get_latest_entries(
+        &self,
+        n: usize,
+        include_ai_replace: bool,
+    ) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        Self::get_latest_entries_with_conn(&conn, n, include_ai_replace)
+    }
+
+    fn get_latest_entries_with_conn(
+        conn: &Connection,
+        n: usize,
+        include_ai_replace: bool,
+    ) -> Result<Vec<HistoryEntry>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
+             FROM transcription_history
+             WHERE (COALESCE(action_type, 'transcribe') != 'ai_replace' AND transcription_text != '')
+                OR (?2 AND action_type = 'ai_replace' AND COALESCE(ai_response, '') != '')
+             ORDER BY timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let rows = stmt.query_map(
+            params![n as i64, include_ai_replace],
+            Self::map_history_entry,
+        )?;
+        let mut entries = rows.collect::<std::result::Result<Vec<_>, _>>()?;
+        entries.reverse();
+
+        Ok(entries)
+    }
+
     pub fn get_latest_completed_entry(&self) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         Self::get_latest_completed_entry_with_conn(&conn)
@@ -647,7 +1803,8 @@ impl HistoryManager {
 
     fn get_latest_completed_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
              FROM transcription_history
              WHERE action_type = 'transcribe' AND transcription_text != ''
              ORDER BY timestamp DESC
@@ -690,7 +1847,8 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, action_type, original_selection, ai_response
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_requested, post_process_note, corrected_text, reference_text, stt_provider, post_process_provider, post_process_raw_response, action_type, original_selection, ai_response, session_group_id, profile_id, target_app, confidence, ai_response_rejected, post_process_chunked,
+                (SELECT group_concat(DISTINCT language) FROM history_translations WHERE entry_id = transcription_history.id) AS translation_languages
              FROM transcription_history WHERE id = ?1",
         )?;
 
@@ -773,6 +1931,7 @@ impl HistoryManager {
         instruction: String,
         original_selection: String,
         ai_response: Option<String>,
+        ai_response_rejected: bool,
     ) -> Result<()> {
         let timestamp = Utc::now().timestamp();
         let file_name = format!("ai-replace-{}.txt", timestamp); // Virtual file, not actually created
@@ -780,8 +1939,8 @@ impl HistoryManager {
 
         let conn = self.get_connection()?;
         conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, action_type, original_selection, ai_response) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![file_name, timestamp, false, title, instruction, "ai_replace", original_selection, ai_response],
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, action_type, original_selection, ai_response, ai_response_rejected) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![file_name, timestamp, false, title, instruction, "ai_replace", original_selection, ai_response, ai_response_rejected],
         )?;
 
         debug!("Saved AI Replace entry to database");
@@ -796,9 +1955,22 @@ impl HistoryManager {
             post_processed_text: None,
             post_process_prompt: None,
             post_process_requested: false,
+            post_process_note: None,
+            corrected_text: None,
+            reference_text: None,
+            stt_provider: None,
+            post_process_provider: None,
+            post_process_raw_response: None,
+            post_process_chunked: false,
             action_type: "ai_replace".to_string(),
             original_selection: Some(original_selection),
             ai_response,
+            ai_response_rejected,
+            session_group_id: None,
+            profile_id: None,
+            target_app: None,
+            confidence: None,
+            translation_languages: Vec::new(),
         });
 
         Ok(())
@@ -833,9 +2005,39 @@ mod tests {
                 post_processed_text TEXT,
                 post_process_prompt TEXT,
                 post_process_requested BOOLEAN NOT NULL DEFAULT 0,
+                post_process_note TEXT,
+                corrected_text TEXT,
+                reference_text TEXT,
+                stt_provider TEXT,
+                post_process_provider TEXT,
+                post_process_raw_response TEXT,
+                timestamps_json TEXT,
                 action_type TEXT DEFAULT 'transcribe',
                 original_selection TEXT,
-                ai_response TEXT
+                ai_response TEXT,
+                session_group_id TEXT,
+                profile_id TEXT,
+                target_app TEXT,
+                confidence REAL,
+                ai_response_rejected BOOLEAN NOT NULL DEFAULT 0,
+                post_process_chunked BOOLEAN NOT NULL DEFAULT 0
+            );
+            CREATE TABLE history_translations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entry_id INTEGER NOT NULL,
+                language TEXT NOT NULL,
+                text TEXT NOT NULL,
+                provider_id TEXT NOT NULL,
+                model TEXT NOT NULL,
+                created_at INTEGER NOT NULL
+            );
+            CREATE TABLE history_post_process_revisions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entry_id INTEGER NOT NULL,
+                text TEXT NOT NULL,
+                prompt TEXT,
+                provider_id TEXT,
+                created_at INTEGER NOT NULL
             );",
         )
         .expect("create transcription_history table");
@@ -1028,6 +2230,53 @@ mod tests {
         assert_eq!(entry.ai_response.as_deref(), Some("rewritten"));
     }
 
+    #[test]
+    fn get_latest_entries_returns_newest_entries_oldest_first() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "first", None);
+        insert_entry(&conn, 200, "second", None);
+        insert_entry(&conn, 300, "third", Some("processed"));
+
+        let entries = HistoryManager::get_latest_entries_with_conn(&conn, 2, false)
+            .expect("fetch latest entries");
+
+        let timestamps: Vec<i64> = entries.iter().map(|entry| entry.timestamp).collect();
+        assert_eq!(timestamps, vec![200, 300]);
+        assert_eq!(entries[1].post_processed_text.as_deref(), Some("processed"));
+    }
+
+    #[test]
+    fn get_latest_entries_skips_empty_and_ai_replace_rows_by_default() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "spoken", None);
+        insert_entry(&conn, 200, "", None);
+        insert_ai_replace_entry(&conn, 300, "rewrite this", "original", Some("rewritten"));
+
+        let entries = HistoryManager::get_latest_entries_with_conn(&conn, 3, false)
+            .expect("fetch latest entries");
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].transcription_text, "spoken");
+    }
+
+    #[test]
+    fn get_latest_entries_includes_answered_ai_replace_rows_when_requested() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "spoken", None);
+        insert_ai_replace_entry(&conn, 200, "rewrite this", "original", Some("rewritten"));
+        insert_ai_replace_entry(&conn, 300, "rewrite that", "original", None);
+
+        let entries = HistoryManager::get_latest_entries_with_conn(&conn, 3, true)
+            .expect("fetch latest entries");
+
+        let action_types: Vec<&str> = entries
+            .iter()
+            .map(|entry| entry.action_type.as_str())
+            .collect();
+        assert_eq!(action_types, vec!["transcribe", "ai_replace"]);
+        assert_eq!(entries[1].ai_response.as_deref(), Some("rewritten"));
+    }
+
     #[test]
     fn get_latest_completed_entry_prefers_newest_non_empty_transcribe_row() {
         let conn = setup_conn();
@@ -1092,4 +2341,385 @@ mod tests {
             "callers must treat query failures as referenced and preserve audio"
         );
     }
+
+    fn assign(conn: &Connection, id: i64, profile: &str, app: &str) -> HistoryEntry {
+        HistoryManager::assign_session_group_with_conn(conn, id, profile, app, 300)
+            .expect("assign session group")
+    }
+
+    #[test]
+    fn consecutive_takes_share_a_session() {
+        let conn = setup_conn();
+        let first = insert_entry_with_file(&conn, "a.wav", 1_000, "first take");
+        let second = insert_entry_with_file(&conn, "b.wav", 1_200, "second take");
+        let third = insert_entry_with_file(&conn, "c.wav", 1_450, "third take");
+
+        let first = assign(&conn, first, "default", "Word");
+        let second = assign(&conn, second, "default", "Word");
+        let third = assign(&conn, third, "default", "Word");
+
+        assert!(first.session_group_id.is_some());
+        assert_eq!(second.session_group_id, first.session_group_id);
+        assert_eq!(third.session_group_id, first.session_group_id);
+    }
+
+    #[test]
+    fn gap_profile_or_app_change_starts_new_session() {
+        let conn = setup_conn();
+        let base = insert_entry_with_file(&conn, "a.wav", 1_000, "base");
+        let late = insert_entry_with_file(&conn, "b.wav", 1_400, "late");
+        let other_app = insert_entry_with_file(&conn, "c.wav", 1_450, "other app");
+        let other_profile = insert_entry_with_file(&conn, "d.wav", 1_500, "other profile");
+
+        let groups = [
+            assign(&conn, base, "default", "Word"),
+            assign(&conn, late, "default", "Word"),
+            assign(&conn, other_app, "default", "Slack"),
+            assign(&conn, other_profile, "email", "Slack"),
+        ]
+        .map(|entry| entry.session_group_id.expect("grouped"));
+
+        for (i, group) in groups.iter().enumerate() {
+            assert_eq!(groups.iter().filter(|g| *g == group).count(), 1, "take {i}");
+        }
+    }
+
+    #[test]
+    fn zero_gap_disables_grouping() {
+        let conn = setup_conn();
+        let id = insert_entry_with_file(&conn, "a.wav", 1_000, "take");
+
+        let entry = HistoryManager::assign_session_group_with_conn(&conn, id, "default", "Word", 0)
+            .expect("assign session group");
+
+        assert_eq!(entry.session_group_id, None);
+        assert_eq!(entry.target_app.as_deref(), Some("Word"));
+    }
+
+    #[test]
+    fn sessions_sum_words_and_span_takes() {
+        let conn = setup_conn();
+        let first = insert_entry_with_file(&conn, "a.wav", 1_000, "one two");
+        let second = insert_entry_with_file(&conn, "b.wav", 1_100, "three four five");
+        let entries = vec![
+            assign(&conn, first, "default", "Word"),
+            assign(&conn, second, "default", "Word"),
+        ];
+
+        let sessions = group_sessions(&entries);
+
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].entry_ids, vec![first, second]);
+        assert_eq!(sessions[0].word_count, 5);
+        assert_eq!(
+            (sessions[0].started_at, sessions[0].ended_at),
+            (1_000, 1_100)
+        );
+    }
+
+    #[test]
+    fn translations_are_listed_on_the_entry() {
+        let conn = setup_conn();
+        let id = insert_entry_with_file(&conn, "a.wav", 1_000, "hello");
+
+        HistoryManager::add_translation_with_conn(&conn, id, "German", "hallo", "openai", "m")
+            .expect("add translation");
+        HistoryManager::add_translation_with_conn(&conn, id, "German", "servus", "openai", "m")
+            .expect("add second translation");
+
+        let translations =
+            HistoryManager::get_translations_with_conn(&conn, id).expect("get translations");
+        assert_eq!(translations.len(), 2);
+        assert_eq!(translations[1].text, "servus");
+
+        let entry = HistoryManager::get_latest_entry_with_conn(&conn)
+            .expect("fetch latest entry")
+            .expect("entry exists");
+        assert_eq!(entry.translation_languages, vec!["German".to_string()]);
+    }
+
+    #[test]
+    fn translating_missing_entry_fails() {
+        let conn = setup_conn();
+        assert!(
+            HistoryManager::add_translation_with_conn(&conn, 42, "German", "x", "openai", "m")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn reprocessing_keeps_previous_post_processed_text_as_revision() {
+        let mut conn = setup_conn();
+        insert_entry(&conn, 1_000, "hello", None);
+        let id = conn.last_insert_rowid();
+
+        let entry = HistoryManager::set_reprocessed_text_with_conn(
+            &mut conn, id, "Hello.", "casual", "openai", None, None, false,
+        )
+        .expect("first reprocess");
+        assert_eq!(entry.post_processed_text.as_deref(), Some("Hello."));
+        assert!(
+            HistoryManager::get_post_process_revisions_with_conn(&conn, id)
+                .expect("get revisions")
+                .is_empty()
+        );
+
+        let entry = HistoryManager::set_reprocessed_text_with_conn(
+            &mut conn,
+            id,
+            "Dear Sir, hello.",
+            "formal",
+            "anthropic",
+            Some("truncated"),
+            None,
+            true,
+        )
+        .expect("second reprocess");
+        assert_eq!(
+            entry.post_processed_text.as_deref(),
+            Some("Dear Sir, hello.")
+        );
+        assert_eq!(entry.post_process_prompt.as_deref(), Some("formal"));
+        assert_eq!(entry.post_process_note.as_deref(), Some("truncated"));
+        assert!(entry.post_process_chunked);
+        assert_eq!(entry.transcription_text, "hello");
+
+        let revisions =
+            HistoryManager::get_post_process_revisions_with_conn(&conn, id).expect("get revisions");
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].text, "Hello.");
+        assert_eq!(revisions[0].prompt.as_deref(), Some("casual"));
+        assert_eq!(revisions[0].provider_id.as_deref(), Some("openai"));
+    }
+
+    #[test]
+    fn reprocessing_missing_entry_fails() {
+        let mut conn = setup_conn();
+        assert!(HistoryManager::set_reprocessed_text_with_conn(
+            &mut conn, 42, "x", "p", "openai", None, None, false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn timestamps_round_trip_and_default_to_none() {
+        let conn = setup_conn();
+        let id = insert_entry_with_file(&conn, "a.wav", 1_000, "hello world");
+        assert!(HistoryManager::get_timestamps_with_conn(&conn, id)
+            .expect("read timestamps")
+            .is_none());
+
+        let segments = vec![SubtitleSegment {
+            start: 0.0,
+            end: 1.5,
+            text: "hello world".to_string(),
+        }];
+        HistoryManager::set_timestamps_with_conn(&conn, id, Some(&segments))
+            .expect("set timestamps");
+        let stored = HistoryManager::get_timestamps_with_conn(&conn, id)
+            .expect("read timestamps")
+            .expect("timestamps saved");
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].end, 1.5);
+        assert_eq!(stored[0].text, "hello world");
+
+        HistoryManager::set_timestamps_with_conn(&conn, id, None).expect("clear timestamps");
+        assert!(HistoryManager::get_timestamps_with_conn(&conn, id)
+            .expect("read timestamps")
+            .is_none());
+
+        assert!(HistoryManager::get_timestamps_with_conn(&conn, 42).is_err());
+    }
+
+    #[test]
+    fn entries_in_range_are_filtered_and_oldest_first() {
+        let conn = setup_conn();
+        insert_entry(&conn, 300, "third", None);
+        insert_entry(&conn, 100, "first", None);
+        insert_entry(&conn, 200, "second", None);
+
+        let all = HistoryManager::get_entries_in_range_with_conn(&conn, None, None)
+            .expect("fetch all entries");
+        let texts: Vec<_> = all.iter().map(|e| e.transcription_text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second", "third"]);
+
+        let ranged = HistoryManager::get_entries_in_range_with_conn(&conn, Some(150), Some(300))
+            .expect("fetch ranged entries");
+        let texts: Vec<_> = ranged
+            .iter()
+            .map(|e| e.transcription_text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["second", "third"]);
+    }
+
+    #[test]
+    fn csv_export_escapes_fields_and_leaves_missing_text_empty() {
+        let conn = setup_conn();
+        insert_entry(&conn, 0, "hello, \"world\"\nsecond line", None);
+        insert_ai_replace_entry(&conn, 60, "shorten", "long text", Some("short"));
+
+        let entries = HistoryManager::get_entries_in_range_with_conn(&conn, None, None)
+            .expect("fetch entries");
+        let csv = export_entries(&entries, HistoryExportFormat::Csv).expect("export csv");
+
+        assert_eq!(
+            csv,
+            "timestamp,action_type,transcription_text,post_processed_text,ai_response\r\n\
+             1970-01-01T00:00:00+00:00,transcribe,\"hello, \"\"world\"\"\nsecond line\",,\r\n\
+             1970-01-01T00:01:00+00:00,ai_replace,shorten,,short\r\n"
+        );
+    }
+
+    #[test]
+    fn json_export_is_an_array_of_full_entries() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "first", Some("First."));
+
+        let entries = HistoryManager::get_entries_in_range_with_conn(&conn, None, None)
+            .expect("fetch entries");
+        let json = export_entries(&entries, HistoryExportFormat::Json).expect("export json");
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+
+        assert_eq!(parsed.as_array().map(Vec::len), Some(1));
+        assert_eq!(parsed[0]["post_processed_text"], "First.");
+        assert_eq!(parsed[0]["file_name"], "aivorelay-100.wav");
+    }
+
+    fn search(conn: &Connection, query: HistorySearchQuery) -> HistorySearchResults {
+        HistoryManager::search_entries_with_conn(conn, &query).expect("search history")
+    }
+
+    fn text_query(text: &str) -> HistorySearchQuery {
+        HistorySearchQuery {
+            text: Some(text.to_string()),
+            limit: 50,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn search_folds_unicode_case() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "Встреча в ПОНЕДЕЛЬНИК", None);
+        insert_entry(&conn, 200, "Grüße aus MÜNCHEN", None);
+        insert_entry(&conn, 300, "plain ascii", None);
+
+        let results = search(&conn, text_query("понедельник"));
+        assert_eq!(results.total, 1);
+        assert_eq!(results.entries[0].timestamp, 100);
+
+        let results = search(&conn, text_query("münchen"));
+        assert_eq!(results.total, 1);
+        assert_eq!(
+            results.entries[0].matched_field,
+            Some(HistorySearchField::TranscriptionText)
+        );
+        assert_eq!(results.entries[0].preview, "Grüße aus MÜNCHEN");
+
+        assert_eq!(search(&conn, text_query("GRÜßE")).total, 1);
+        assert_eq!(search(&conn, text_query("zürich")).total, 0);
+    }
+
+    #[test]
+    fn search_matches_post_processed_text_alone() {
+        let conn = setup_conn();
+        insert_entry(
+            &conn,
+            100,
+            "um so the meeting is moved",
+            Some("The quarterly review moved."),
+        );
+        insert_entry(&conn, 200, "quarter past nine", None);
+
+        let results = search(&conn, text_query("QUARTERLY"));
+        assert_eq!(results.total, 1);
+        let summary = &results.entries[0];
+        assert_eq!(summary.timestamp, 100);
+        assert_eq!(
+            summary.matched_field,
+            Some(HistorySearchField::PostProcessedText)
+        );
+        assert_eq!(summary.preview, "The quarterly review moved.");
+    }
+
+    #[test]
+    fn search_matches_ai_responses() {
+        let conn = setup_conn();
+        insert_ai_replace_entry(&conn, 100, "translate", "hello", Some("Bonjour"));
+
+        let results = search(&conn, text_query("bonjour"));
+        assert_eq!(results.total, 1);
+        assert_eq!(results.entries[0].action_type, "ai_replace");
+        assert_eq!(
+            results.entries[0].matched_field,
+            Some(HistorySearchField::AiResponse)
+        );
+    }
+
+    #[test]
+    fn search_filters_by_action_type_and_date_range() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "note one", None);
+        insert_entry(&conn, 200, "note two", None);
+        insert_ai_replace_entry(&conn, 300, "note three", "text", Some("note"));
+
+        let ai_only = search(
+            &conn,
+            HistorySearchQuery {
+                action_type: Some("ai_replace".to_string()),
+                ..text_query("note")
+            },
+        );
+        assert_eq!(ai_only.total, 1);
+        assert_eq!(ai_only.entries[0].timestamp, 300);
+
+        let ranged = search(
+            &conn,
+            HistorySearchQuery {
+                from: Some(150),
+                to: Some(300),
+                ..text_query("note")
+            },
+        );
+        let timestamps: Vec<_> = ranged.entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![300, 200]);
+    }
+
+    #[test]
+    fn search_pages_newest_first_with_total() {
+        let conn = setup_conn();
+        for timestamp in 1..=5 {
+            insert_entry(
+                &conn,
+                timestamp * 100,
+                &format!("entry {}", timestamp),
+                None,
+            );
+        }
+
+        let page = search(
+            &conn,
+            HistorySearchQuery {
+                offset: 2,
+                limit: 2,
+                ..Default::default()
+            },
+        );
+        assert_eq!(page.total, 5);
+        let timestamps: Vec<_> = page.entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![300, 200]);
+        assert!(page.entries.iter().all(|e| e.matched_field.is_none()));
+
+        let blank = search(&conn, text_query("   "));
+        assert_eq!(blank.total, 5);
+    }
+
+    #[test]
+    fn search_preview_is_cut_at_a_char_boundary() {
+        let text = "é".repeat(SEARCH_PREVIEW_CHARS + 1);
+        let preview = search_preview(&text);
+        assert_eq!(preview.chars().count(), SEARCH_PREVIEW_CHARS + 1);
+        assert!(preview.ends_with('…'));
+        assert_eq!(search_preview("short"), "short");
+    }
 }
@@ -1,11 +1,24 @@
 use log::{debug, error, info, warn};
 use rdev::{Event, EventType, Key};
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
 const DECAPITALIZE_MONITOR_SHORTCUT_ID_PREFIX: &str = "__text_replacement_decapitalize_monitor__";
+/// Default time allowed between the two presses of a `double:` binding.
+pub const DEFAULT_DOUBLE_TAP_WINDOW_MS: u32 = 350;
+/// Accepted values for `shortcut_double_tap_window_ms`.
+pub const DOUBLE_TAP_WINDOW_RANGE_MS: RangeInclusive<u32> = 100..=1_000;
+/// Threshold for `hold:` bindings that do not give one.
+const DEFAULT_HOLD_THRESHOLD_MS: u64 = 500;
+const MIN_HOLD_THRESHOLD_MS: u64 = 100;
+const MAX_HOLD_THRESHOLD_MS: u64 = 5_000;
+/// How long a hold timer backs off when the event handler holds the locks.
+const HOLD_TIMER_RETRY_INTERVAL: Duration = Duration::from_millis(2);
 
 /// State for tracking active key modifiers (Ctrl, Shift, Alt, Win)
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type)]
@@ -45,16 +58,31 @@ impl ModifierState {
     }
 }
 
+/// How the key of a binding has to be pressed for the shortcut to fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutTrigger {
+    /// Plain chord: pressed on key down, released on key up
+    Press,
+    /// `double:F9` - pressed on the second key down within the double-tap window
+    DoubleTap,
+    /// `hold:F9/600` - pressed once the key has been held for `threshold_ms`
+    Hold { threshold_ms: u64 },
+}
+
 /// A registered shortcut with its trigger key and required modifiers
 /// For modifier-only shortcuts (like Ctrl+Alt), key will be None
 #[derive(Debug, Clone)]
 pub struct RegisteredShortcut {
     pub key: Option<Key>,
     pub modifiers: ModifierState,
+    pub trigger: ShortcutTrigger,
     pub original_binding: String,
     /// When true, a shortcut with a main key matches regardless of extra modifiers.
     /// Used by passive monitor features that should trigger on key presence in combos.
     pub match_main_key_in_any_combo: bool,
+    /// Swallow the matching key press/release so the focused app never sees it.
+    /// Only honored on Windows, where such a binding installs a grab hook.
+    pub suppress_keypress: bool,
 }
 
 /// Shortcut event sent to the app
@@ -65,6 +93,72 @@ pub struct ShortcutEvent {
     pub pressed: bool,
 }
 
+/// First press of a `double:` binding, waiting for the second one
+#[derive(Debug, Clone, Copy)]
+struct FirstTap {
+    pressed_at: Instant,
+    released: bool,
+}
+
+/// Progress of double-tap and hold bindings between key events
+#[derive(Debug)]
+struct TriggerState {
+    double_tap_window: Duration,
+    first_taps: HashMap<String, FirstTap>,
+    /// `hold:` bindings whose key is down but below the threshold, with the
+    /// press generation their timer checks against
+    pending_holds: HashMap<String, u64>,
+    next_hold_generation: u64,
+}
+
+impl Default for TriggerState {
+    fn default() -> Self {
+        Self {
+            double_tap_window: Duration::from_millis(u64::from(DEFAULT_DOUBLE_TAP_WINDOW_MS)),
+            first_taps: HashMap::new(),
+            pending_holds: HashMap::new(),
+            next_hold_generation: 0,
+        }
+    }
+}
+
+impl TriggerState {
+    fn forget(&mut self, id: &str) {
+        self.first_taps.remove(id);
+        self.pending_holds.remove(id);
+    }
+}
+
+/// A `hold:` press waiting on the hold timer thread for its threshold
+struct PendingHold {
+    shortcut: RegisteredShortcut,
+    generation: u64,
+    deadline: Instant,
+}
+
+/// Sent from the event handler to the hold timer thread
+enum HoldTimerCommand {
+    Start {
+        id: String,
+        hold: PendingHold,
+    },
+    /// The key was released before the threshold.
+    Cancel {
+        id: String,
+    },
+}
+
+/// Windows `rdev::grab` hook that runs next to the passive listener only while
+/// a binding has `suppress_keypress`
+#[cfg(target_os = "windows")]
+#[derive(Default)]
+struct GrabHook {
+    thread: Mutex<Option<std::thread::JoinHandle<()>>>,
+    /// Set once the grab hook sees events; the listener then skips them, since
+    /// the grab hook runs first and already handled them.
+    handling_events: Arc<AtomicBool>,
+}
+
 /// Main key listener manager with shortcut support
 pub struct KeyListenerManager {
     app_handle: Arc<AppHandle>,
@@ -74,52 +168,145 @@ pub struct KeyListenerManager {
     shortcuts: Arc<Mutex<HashMap<String, RegisteredShortcut>>>,
     /// Track which shortcuts are currently "held down" to detect release
     active_shortcuts: Arc<Mutex<HashMap<String, bool>>>,
+    /// Keys whose press was swallowed; their release is swallowed too.
+    suppressed_keys: Arc<Mutex<Vec<Key>>>,
+    trigger_state: Arc<Mutex<TriggerState>>,
+    /// Feeds the single thread that fires every `hold:` binding.
+    hold_timer: Sender<HoldTimerCommand>,
+    #[cfg(target_os = "windows")]
+    grab_hook: GrabHook,
 }
 
 impl KeyListenerManager {
     /// Create a new key listener manager
     pub fn new(app_handle: AppHandle) -> Self {
+        let app_handle = Arc::new(app_handle);
+        let active_shortcuts = Arc::new(Mutex::new(HashMap::new()));
+        let trigger_state = Arc::new(Mutex::new(TriggerState::default()));
+        let hold_timer = Self::spawn_hold_timer(
+            app_handle.clone(),
+            active_shortcuts.clone(),
+            trigger_state.clone(),
+        );
+
         Self {
-            app_handle: Arc::new(app_handle),
+            app_handle,
             running: Arc::new(Mutex::new(false)),
             listener_thread_started: Arc::new(AtomicBool::new(false)),
             modifiers: Arc::new(Mutex::new(ModifierState::default())),
             shortcuts: Arc::new(Mutex::new(HashMap::new())),
-            active_shortcuts: Arc::new(Mutex::new(HashMap::new())),
+            active_shortcuts,
+            suppressed_keys: Arc::new(Mutex::new(Vec::new())),
+            trigger_state,
+            hold_timer,
+            #[cfg(target_os = "windows")]
+            grab_hook: GrabHook::default(),
+        }
+    }
+
+    /// Set how quickly the second press of a `double:` binding has to follow the first.
+    pub fn set_double_tap_window_ms(&self, window_ms: u32) {
+        let window_ms = window_ms.clamp(
+            *DOUBLE_TAP_WINDOW_RANGE_MS.start(),
+            *DOUBLE_TAP_WINDOW_RANGE_MS.end(),
+        );
+        if let Ok(mut trigger_state) = self.trigger_state.lock() {
+            trigger_state.double_tap_window = Duration::from_millis(u64::from(window_ms));
         }
     }
 
-    /// Register a shortcut from a string like "ctrl+shift+a" or "caps lock"
-    pub async fn register_shortcut(&self, id: String, binding: String) -> Result<(), String> {
-        let (key, modifiers) = parse_shortcut_string(&binding)?;
+    /// Register a shortcut from a string like "ctrl+shift+a", "caps lock",
+    /// "double:f9" or "hold:f9/600"
+    pub async fn register_shortcut(
+        &self,
+        id: String,
+        binding: String,
+        suppress_keypress: bool,
+    ) -> Result<(), String> {
+        let (trigger, key, modifiers) = parse_binding(&binding)?;
         let match_main_key_in_any_combo = id.starts_with(DECAPITALIZE_MONITOR_SHORTCUT_ID_PREFIX);
 
         let shortcut = RegisteredShortcut {
             key,
             modifiers,
+            trigger,
             original_binding: binding.clone(),
             match_main_key_in_any_combo,
+            // Passive monitors and modifier-only shortcuts must always reach the focused app.
+            // A hold only fires after the key is already down, so its press is never swallowed.
+            suppress_keypress: suppress_keypress
+                && key.is_some()
+                && !match_main_key_in_any_combo
+                && !matches!(trigger, ShortcutTrigger::Hold { .. }),
         };
 
-        let mut shortcuts = self.shortcuts.lock().map_err(|e| e.to_string())?;
-        shortcuts.insert(id.clone(), shortcut);
+        {
+            let mut shortcuts = self.shortcuts.lock().map_err(|e| e.to_string())?;
+            shortcuts.insert(id.clone(), shortcut);
+        }
         info!("Registered rdev shortcut '{}': {}", id, binding);
+        self.sync_grab_hook();
         Ok(())
     }
 
     /// Unregister a shortcut by ID
     pub async fn unregister_shortcut(&self, id: &str) -> Result<(), String> {
-        let mut shortcuts = self.shortcuts.lock().map_err(|e| e.to_string())?;
-        if shortcuts.remove(id).is_some() {
+        let removed = {
+            let mut shortcuts = self.shortcuts.lock().map_err(|e| e.to_string())?;
+            if let Ok(mut trigger_state) = self.trigger_state.lock() {
+                trigger_state.forget(id);
+            }
+            shortcuts.remove(id).is_some()
+        };
+        if removed {
             info!("Unregistered rdev shortcut '{}'", id);
+            self.sync_grab_hook();
             Ok(())
         } else {
             Err(format!("Shortcut '{}' not found", id))
         }
     }
 
+    /// Whether a shortcut with this ID is present in the listener's table.
+    pub fn has_shortcut(&self, id: &str) -> bool {
+        self.shortcuts
+            .lock()
+            .map(|shortcuts| shortcuts.contains_key(id))
+            .unwrap_or(false)
+    }
+
+    /// Whether the rdev listener thread is alive. The flag is cleared when the
+    /// hook exits with an error, so a later `start()` spawns a fresh thread.
+    pub fn is_listener_thread_alive(&self) -> bool {
+        self.listener_thread_started.load(Ordering::SeqCst)
+    }
+
+    /// Modifiers currently held according to the listener, or None when it is
+    /// not processing events.
+    pub fn physical_modifier_state(&self) -> Option<ModifierState> {
+        let running = self.running.lock().map(|running| *running).unwrap_or(false);
+        if !running || !self.is_listener_thread_alive() {
+            return None;
+        }
+        self.modifiers
+            .lock()
+            .ok()
+            .map(|modifiers| modifiers.clone())
+    }
+
     /// Start listening for keyboard events
     pub async fn start(&self) -> Result<(), String> {
+        self.start_listener()
+    }
+
+    /// Same as `start`, for callers outside an async context.
+    pub fn start_listener(&self) -> Result<(), String> {
+        self.enable_listener()?;
+        self.sync_grab_hook();
+        Ok(())
+    }
+
+    fn enable_listener(&self) -> Result<(), String> {
         {
             let mut running_guard = self.running.lock().map_err(|e| e.to_string())?;
             if *running_guard {
@@ -150,20 +337,34 @@ impl KeyListenerManager {
         let modifiers = self.modifiers.clone();
         let shortcuts = self.shortcuts.clone();
         let active_shortcuts = self.active_shortcuts.clone();
+        let suppressed_keys = self.suppressed_keys.clone();
+        let trigger_state = self.trigger_state.clone();
+        let hold_timer = self.hold_timer.clone();
         let listener_thread_started = self.listener_thread_started.clone();
 
+        #[cfg(target_os = "windows")]
+        let grab_handling_events = self.grab_hook.handling_events.clone();
+
         std::thread::spawn(move || {
             let running_for_events = running.clone();
-            if let Err(e) = rdev::listen(move |event| {
+            let result = rdev::listen(move |event| {
+                #[cfg(target_os = "windows")]
+                if grab_handling_events.load(Ordering::SeqCst) {
+                    return;
+                }
                 Self::handle_event(
-                    event,
+                    &event,
                     &app_handle,
                     &running_for_events,
                     &modifiers,
                     &shortcuts,
                     &active_shortcuts,
+                    &suppressed_keys,
+                    &trigger_state,
+                    &hold_timer,
                 );
-            }) {
+            });
+            if let Err(e) = result {
                 error!("Failed to start key listener: {:?}", e);
                 if let Ok(mut running_lock) = running.lock() {
                     *running_lock = false;
@@ -197,23 +398,126 @@ impl KeyListenerManager {
             active.clear();
         }
 
+        if let Ok(mut suppressed) = self.suppressed_keys.lock() {
+            suppressed.clear();
+        }
+
+        if let Ok(mut trigger_state) = self.trigger_state.lock() {
+            trigger_state.first_taps.clear();
+            trigger_state.pending_holds.clear();
+        }
+
+        self.sync_grab_hook();
         Ok(())
     }
 
+    /// Whether the listener is enabled and some binding swallows its key.
+    #[cfg(target_os = "windows")]
+    fn needs_grab_hook(&self) -> bool {
+        let running = self.running.lock().map(|running| *running).unwrap_or(false);
+        running
+            && self
+                .shortcuts
+                .lock()
+                .map(|shortcuts| shortcuts.values().any(|s| s.suppress_keypress))
+                .unwrap_or(false)
+    }
+
+    /// Install the grab hook when a binding needs `suppress_keypress` and remove
+    /// it once none does, so plain bindings only ever use the passive listener.
+    #[cfg(target_os = "windows")]
+    fn sync_grab_hook(&self) {
+        let wanted = self.needs_grab_hook();
+        let Ok(mut thread) = self.grab_hook.thread.lock() else {
+            return;
+        };
+        if thread.as_ref().is_some_and(|handle| handle.is_finished()) {
+            thread.take();
+        }
+
+        match (wanted, thread.is_some()) {
+            (true, false) => *thread = Some(self.spawn_grab_thread()),
+            (false, true) => {
+                info!("Removing key grab hook; no binding suppresses its key press");
+                if let Err(e) = rdev::exit_grab() {
+                    warn!("Failed to remove key grab hook: {:?}", e);
+                    return;
+                }
+                if let Some(handle) = thread.take() {
+                    let _ = handle.join();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn sync_grab_hook(&self) {}
+
+    /// Run `rdev::grab` on its own thread. It sees events before the passive
+    /// listener, swallows the ones a `suppress_keypress` binding matches and
+    /// passes the rest through.
+    #[cfg(target_os = "windows")]
+    fn spawn_grab_thread(&self) -> std::thread::JoinHandle<()> {
+        info!("Installing key grab hook for keypress suppression");
+
+        let app_handle = self.app_handle.clone();
+        let running = self.running.clone();
+        let modifiers = self.modifiers.clone();
+        let shortcuts = self.shortcuts.clone();
+        let active_shortcuts = self.active_shortcuts.clone();
+        let suppressed_keys = self.suppressed_keys.clone();
+        let trigger_state = self.trigger_state.clone();
+        let hold_timer = self.hold_timer.clone();
+        let handling_events = self.grab_hook.handling_events.clone();
+
+        std::thread::spawn(move || {
+            let handling_events_in_hook = handling_events.clone();
+            let result = rdev::grab(move |event| {
+                handling_events_in_hook.store(true, Ordering::SeqCst);
+                let suppress = Self::handle_event(
+                    &event,
+                    &app_handle,
+                    &running,
+                    &modifiers,
+                    &shortcuts,
+                    &active_shortcuts,
+                    &suppressed_keys,
+                    &trigger_state,
+                    &hold_timer,
+                );
+                if suppress {
+                    None
+                } else {
+                    Some(event)
+                }
+            });
+            handling_events.store(false, Ordering::SeqCst);
+            if let Err(e) = result {
+                error!("Key grab hook failed: {:?}", e);
+            }
+        })
+    }
+
     /// Handle individual keyboard events - must be non-blocking!
+    /// Returns true when the event should be swallowed instead of reaching the focused app.
+    #[allow(clippy::too_many_arguments)]
     fn handle_event(
-        event: Event,
+        event: &Event,
         app_handle: &Arc<AppHandle>,
         running: &Arc<Mutex<bool>>,
         modifiers: &Arc<Mutex<ModifierState>>,
         shortcuts: &Arc<Mutex<HashMap<String, RegisteredShortcut>>>,
         active_shortcuts: &Arc<Mutex<HashMap<String, bool>>>,
-    ) {
+        suppressed_keys: &Arc<Mutex<Vec<Key>>>,
+        trigger_state: &Arc<Mutex<TriggerState>>,
+        hold_timer: &Sender<HoldTimerCommand>,
+    ) -> bool {
         let Ok(running_guard) = running.try_lock() else {
-            return;
+            return false;
         };
         if !*running_guard {
-            return;
+            return false;
         }
         drop(running_guard);
 
@@ -222,7 +526,7 @@ impl KeyListenerManager {
                 // Update modifiers - non-blocking with try_lock or unwrap_or_else
                 let current_mods = {
                     let Ok(mut mods) = modifiers.try_lock() else {
-                        return; // Skip if can't get lock immediately
+                        return false; // Skip if can't get lock immediately
                     };
                     mods.update(key, true);
                     mods.clone()
@@ -230,11 +534,15 @@ impl KeyListenerManager {
 
                 // Check if this key press matches any registered shortcut
                 let Ok(shortcuts_guard) = shortcuts.try_lock() else {
-                    return;
+                    return false;
                 };
                 let Ok(mut active_guard) = active_shortcuts.try_lock() else {
-                    return;
+                    return false;
+                };
+                let Ok(mut trigger_guard) = trigger_state.try_lock() else {
+                    return false;
                 };
+                let mut suppress = false;
 
                 for (id, shortcut) in shortcuts_guard.iter() {
                     let matches = match shortcut.key {
@@ -258,29 +566,99 @@ impl KeyListenerManager {
                         }
                     };
 
-                    if matches {
-                        // Only fire if not already active (prevent key repeat)
-                        if !active_guard.get(id).copied().unwrap_or(false) {
-                            active_guard.insert(id.clone(), true);
-                            debug!("Shortcut pressed: {} ({})", id, shortcut.original_binding);
+                    if !matches {
+                        // Any other key between the two taps breaks a double tap.
+                        if shortcut.trigger == ShortcutTrigger::DoubleTap
+                            && !Self::is_modifier_key(key)
+                        {
+                            trigger_guard.first_taps.remove(id);
+                        }
+                        continue;
+                    }
 
-                            let event = ShortcutEvent {
-                                id: id.clone(),
-                                binding: shortcut.original_binding.clone(),
-                                pressed: true,
-                            };
-                            if let Err(e) = app_handle.emit("rdev-shortcut", &event) {
-                                warn!("Failed to emit rdev-shortcut event: {}", e);
+                    // Only fire if not already active (prevent key repeat)
+                    let already_active = active_guard.get(id).copied().unwrap_or(false);
+                    match shortcut.trigger {
+                        ShortcutTrigger::Press => {
+                            // Key repeats are swallowed too, not just the first press.
+                            suppress |= shortcut.suppress_keypress;
+                            if !already_active {
+                                active_guard.insert(id.clone(), true);
+                                Self::emit_shortcut_event(app_handle, id, shortcut, true);
                             }
                         }
+                        ShortcutTrigger::DoubleTap => {
+                            if already_active {
+                                suppress |= shortcut.suppress_keypress;
+                                continue;
+                            }
+                            let now = Instant::now();
+                            let window = trigger_guard.double_tap_window;
+                            match trigger_guard.first_taps.get(id).copied() {
+                                // Key repeat of the first tap
+                                Some(tap) if !tap.released => {}
+                                Some(tap) if now.duration_since(tap.pressed_at) <= window => {
+                                    trigger_guard.first_taps.remove(id);
+                                    suppress |= shortcut.suppress_keypress;
+                                    active_guard.insert(id.clone(), true);
+                                    Self::emit_shortcut_event(app_handle, id, shortcut, true);
+                                }
+                                // The first tap always reaches the focused app.
+                                _ => {
+                                    trigger_guard.first_taps.insert(
+                                        id.clone(),
+                                        FirstTap {
+                                            pressed_at: now,
+                                            released: false,
+                                        },
+                                    );
+                                }
+                            }
+                        }
+                        ShortcutTrigger::Hold { threshold_ms } => {
+                            if already_active || trigger_guard.pending_holds.contains_key(id) {
+                                continue;
+                            }
+                            trigger_guard.next_hold_generation += 1;
+                            let generation = trigger_guard.next_hold_generation;
+                            trigger_guard.pending_holds.insert(id.clone(), generation);
+                            let _ = hold_timer.send(HoldTimerCommand::Start {
+                                id: id.clone(),
+                                hold: PendingHold {
+                                    shortcut: shortcut.clone(),
+                                    generation,
+                                    deadline: Instant::now() + Duration::from_millis(threshold_ms),
+                                },
+                            });
+                        }
+                    }
+                }
+
+                if suppress {
+                    if let Ok(mut suppressed) = suppressed_keys.try_lock() {
+                        if !suppressed.contains(&key) {
+                            suppressed.push(key);
+                        }
                     }
                 }
+                suppress
             }
             EventType::KeyRelease(key) => {
+                // Only swallow a release whose press was swallowed, so a shortcut that was
+                // unregistered mid-press (e.g. cancel after the session ends) still releases cleanly.
+                let suppress = suppressed_keys
+                    .try_lock()
+                    .map(|mut suppressed| {
+                        let was_suppressed = suppressed.contains(&key);
+                        suppressed.retain(|suppressed_key| *suppressed_key != key);
+                        was_suppressed
+                    })
+                    .unwrap_or(false);
+
                 // Update modifiers
                 let current_mods = {
                     let Ok(mut mods) = modifiers.try_lock() else {
-                        return;
+                        return suppress;
                     };
                     mods.update(key, false);
                     mods.clone()
@@ -288,10 +666,13 @@ impl KeyListenerManager {
 
                 // Check if releasing this key deactivates any shortcuts
                 let Ok(shortcuts_guard) = shortcuts.try_lock() else {
-                    return;
+                    return suppress;
                 };
                 let Ok(mut active_guard) = active_shortcuts.try_lock() else {
-                    return;
+                    return suppress;
+                };
+                let Ok(mut trigger_guard) = trigger_state.try_lock() else {
+                    return suppress;
                 };
 
                 for (id, shortcut) in shortcuts_guard.iter() {
@@ -316,26 +697,162 @@ impl KeyListenerManager {
                     };
 
                     if should_release || modifier_released {
+                        match shortcut.trigger {
+                            ShortcutTrigger::Press => {}
+                            ShortcutTrigger::DoubleTap => {
+                                if let Some(tap) = trigger_guard.first_taps.get_mut(id) {
+                                    tap.released = true;
+                                }
+                            }
+                            // Let go before the threshold: it was just a tap.
+                            ShortcutTrigger::Hold { .. } => {
+                                if trigger_guard.pending_holds.remove(id).is_some() {
+                                    let _ = hold_timer
+                                        .send(HoldTimerCommand::Cancel { id: id.clone() });
+                                }
+                            }
+                        }
+
                         if active_guard.get(id).copied().unwrap_or(false) {
                             active_guard.insert(id.clone(), false);
-                            debug!("Shortcut released: {} ({})", id, shortcut.original_binding);
-
-                            let event = ShortcutEvent {
-                                id: id.clone(),
-                                binding: shortcut.original_binding.clone(),
-                                pressed: false,
-                            };
-                            if let Err(e) = app_handle.emit("rdev-shortcut", &event) {
-                                warn!("Failed to emit rdev-shortcut event: {}", e);
-                            }
+                            Self::emit_shortcut_event(app_handle, id, shortcut, false);
                         }
                     }
                 }
+                suppress
             }
-            _ => {}
+            _ => false,
+        }
+    }
+
+    fn emit_shortcut_event(
+        app_handle: &AppHandle,
+        id: &str,
+        shortcut: &RegisteredShortcut,
+        pressed: bool,
+    ) {
+        if pressed {
+            debug!("Shortcut pressed: {} ({})", id, shortcut.original_binding);
+        } else {
+            debug!("Shortcut released: {} ({})", id, shortcut.original_binding);
+        }
+
+        let event = ShortcutEvent {
+            id: id.to_string(),
+            binding: shortcut.original_binding.clone(),
+            pressed,
+        };
+        if let Err(e) = app_handle.emit("rdev-shortcut", &event) {
+            warn!("Failed to emit rdev-shortcut event: {}", e);
         }
     }
 
+    /// Start the one thread that fires `hold:` bindings once their threshold
+    /// passes. It lives as long as the manager's command sender.
+    fn spawn_hold_timer(
+        app_handle: Arc<AppHandle>,
+        active_shortcuts: Arc<Mutex<HashMap<String, bool>>>,
+        trigger_state: Arc<Mutex<TriggerState>>,
+    ) -> Sender<HoldTimerCommand> {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            Self::run_hold_timer(
+                receiver,
+                &active_shortcuts,
+                &trigger_state,
+                |id, shortcut| {
+                    Self::emit_shortcut_event(&app_handle, id, shortcut, true);
+                },
+            );
+        });
+        sender
+    }
+
+    /// Wait for the earliest pending deadline and fire each hold that is
+    /// still down when it passes. A release cancels its deadline; a hold that
+    /// was released and pressed again is skipped through its press generation.
+    fn run_hold_timer(
+        receiver: Receiver<HoldTimerCommand>,
+        active_shortcuts: &Mutex<HashMap<String, bool>>,
+        trigger_state: &Mutex<TriggerState>,
+        mut fire: impl FnMut(&str, &RegisteredShortcut),
+    ) {
+        let mut pending: HashMap<String, PendingHold> = HashMap::new();
+        loop {
+            let command = match pending.values().map(|hold| hold.deadline).min() {
+                Some(deadline) => {
+                    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(command) => Some(command),
+                        Err(RecvTimeoutError::Timeout) => None,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                None => match receiver.recv() {
+                    Ok(command) => Some(command),
+                    Err(_) => return,
+                },
+            };
+            match command {
+                Some(HoldTimerCommand::Start { id, hold }) => {
+                    pending.insert(id, hold);
+                }
+                Some(HoldTimerCommand::Cancel { id }) => {
+                    pending.remove(&id);
+                }
+                None => {}
+            }
+
+            let now = Instant::now();
+            let due: Vec<String> = pending
+                .iter()
+                .filter(|(_, hold)| hold.deadline <= now)
+                .map(|(id, _)| id.clone())
+                .collect();
+            for id in due {
+                let Some(mut hold) = pending.remove(&id) else {
+                    continue;
+                };
+                match Self::try_fire_hold(active_shortcuts, trigger_state, &id, hold.generation) {
+                    Some(true) => fire(&id, &hold.shortcut),
+                    Some(false) => {}
+                    None => {
+                        hold.deadline = now + HOLD_TIMER_RETRY_INTERVAL;
+                        pending.insert(id, hold);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Marks a pending hold as active if it is still the press `generation`
+    /// started. Like the event handler it never blocks on the locks: `None`
+    /// means one of them was busy and the caller should retry.
+    fn try_fire_hold(
+        active_shortcuts: &Mutex<HashMap<String, bool>>,
+        trigger_state: &Mutex<TriggerState>,
+        id: &str,
+        generation: u64,
+    ) -> Option<bool> {
+        // Same lock order as the event handler: active shortcuts, then trigger state.
+        let mut active_guard = match active_shortcuts.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => return None,
+            Err(TryLockError::Poisoned(_)) => return Some(false),
+        };
+        let mut trigger_guard = match trigger_state.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => return None,
+            Err(TryLockError::Poisoned(_)) => return Some(false),
+        };
+        if trigger_guard.pending_holds.get(id) != Some(&generation) {
+            return Some(false);
+        }
+        trigger_guard.pending_holds.remove(id);
+        active_guard.insert(id.to_string(), true);
+        Some(true)
+    }
+
     /// Check if a key is a modifier key
     fn is_modifier_key(key: Key) -> bool {
         matches!(
@@ -389,6 +906,61 @@ pub fn parse_shortcut_string(binding: &str) -> Result<(Option<Key>, ModifierStat
     Ok((main_key, modifiers))
 }
 
+/// Split a `double:` or `hold:<chord>/<ms>` prefix off a binding string.
+/// Returns the trigger and the remaining (normalized) chord.
+pub fn parse_shortcut_trigger(binding: &str) -> Result<(ShortcutTrigger, String), String> {
+    let normalized = normalize_shortcut_binding(binding);
+
+    if let Some(chord) = normalized.strip_prefix("double:") {
+        return Ok((ShortcutTrigger::DoubleTap, chord.trim().to_string()));
+    }
+
+    if let Some(rest) = normalized.strip_prefix("hold:") {
+        // "/" is also a key, so only a numeric suffix counts as the threshold.
+        let (chord, threshold_ms) = match rest.rsplit_once('/') {
+            Some((chord, ms)) if !ms.is_empty() && ms.chars().all(|c| c.is_ascii_digit()) => {
+                let threshold_ms = ms
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid hold time: '{}'", ms))?;
+                (chord, threshold_ms)
+            }
+            _ => (rest, DEFAULT_HOLD_THRESHOLD_MS),
+        };
+        if !(MIN_HOLD_THRESHOLD_MS..=MAX_HOLD_THRESHOLD_MS).contains(&threshold_ms) {
+            return Err(format!(
+                "Hold time must be between {} and {} ms",
+                MIN_HOLD_THRESHOLD_MS, MAX_HOLD_THRESHOLD_MS
+            ));
+        }
+        return Ok((
+            ShortcutTrigger::Hold { threshold_ms },
+            chord.trim().to_string(),
+        ));
+    }
+
+    Ok((ShortcutTrigger::Press, normalized))
+}
+
+/// Parse a binding including an optional `double:`/`hold:` prefix.
+pub fn parse_binding(
+    binding: &str,
+) -> Result<(ShortcutTrigger, Option<Key>, ModifierState), String> {
+    let (trigger, chord) = parse_shortcut_trigger(binding)?;
+    let (key, modifiers) = parse_shortcut_string(&chord)?;
+    if trigger != ShortcutTrigger::Press && key.is_none() {
+        return Err(
+            "Double-tap and hold shortcuts need a main key, not only modifiers".to_string(),
+        );
+    }
+    Ok((trigger, key, modifiers))
+}
+
+/// Whether a binding uses a trigger only the rdev engine understands.
+pub fn has_trigger_prefix(binding: &str) -> bool {
+    let normalized = normalize_shortcut_binding(binding);
+    normalized.starts_with("double:") || normalized.starts_with("hold:")
+}
+
 fn normalize_shortcut_binding(raw: &str) -> String {
     let mut normalized = raw.trim().to_lowercase();
     // Legacy frontend token used "numpad +" which collides with '+' as the delimiter.
@@ -551,3 +1123,192 @@ impl KeyListenerState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_bindings_are_press_triggers() {
+        let (trigger, key, modifiers) = parse_binding("ctrl+shift+a").unwrap();
+        assert_eq!(trigger, ShortcutTrigger::Press);
+        assert_eq!(key, Some(Key::KeyA));
+        assert!(modifiers.ctrl && modifiers.shift);
+    }
+
+    #[test]
+    fn double_and_hold_prefixes_are_parsed() {
+        let (trigger, key, _) = parse_binding("double:F9").unwrap();
+        assert_eq!(trigger, ShortcutTrigger::DoubleTap);
+        assert_eq!(key, Some(Key::F9));
+
+        let (trigger, key, modifiers) = parse_binding("hold:ctrl+F9/600").unwrap();
+        assert_eq!(trigger, ShortcutTrigger::Hold { threshold_ms: 600 });
+        assert_eq!(key, Some(Key::F9));
+        assert!(modifiers.ctrl);
+
+        let (trigger, _, _) = parse_binding("hold:F9").unwrap();
+        assert_eq!(
+            trigger,
+            ShortcutTrigger::Hold {
+                threshold_ms: DEFAULT_HOLD_THRESHOLD_MS
+            }
+        );
+    }
+
+    #[test]
+    fn hold_threshold_does_not_eat_slash_key() {
+        let (trigger, key, _) = parse_binding("hold:num/").unwrap();
+        assert_eq!(
+            trigger,
+            ShortcutTrigger::Hold {
+                threshold_ms: DEFAULT_HOLD_THRESHOLD_MS
+            }
+        );
+        assert_eq!(key, Some(Key::KpDivide));
+
+        let (_, key, _) = parse_binding("hold:ctrl+//800").unwrap();
+        assert_eq!(key, Some(Key::Slash));
+    }
+
+    #[test]
+    fn invalid_trigger_bindings_are_rejected() {
+        assert!(parse_binding("double:ctrl+alt").is_err());
+        assert!(parse_binding("hold:F9/20").is_err());
+        assert!(parse_binding("hold:F9/60000").is_err());
+        assert!(parse_shortcut_string("double:f9").is_err());
+    }
+
+    fn pending_hold(id: &str, generation: u64) -> Mutex<TriggerState> {
+        let mut state = TriggerState::default();
+        state.pending_holds.insert(id.to_string(), generation);
+        Mutex::new(state)
+    }
+
+    #[test]
+    fn hold_fires_only_for_the_press_that_started_it() {
+        let active = Mutex::new(HashMap::new());
+        let trigger_state = pending_hold("dictate", 2);
+
+        // Released and pressed again: the first press's timer is stale.
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 1),
+            Some(false)
+        );
+        assert!(active.lock().unwrap().is_empty());
+
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 2),
+            Some(true)
+        );
+        assert_eq!(active.lock().unwrap().get("dictate"), Some(&true));
+        assert!(trigger_state.lock().unwrap().pending_holds.is_empty());
+
+        // Fires once per press.
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 2),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn hold_timer_backs_off_while_the_handler_holds_a_lock() {
+        let active = Mutex::new(HashMap::new());
+        let trigger_state = pending_hold("dictate", 1);
+
+        let guard = trigger_state.lock().unwrap();
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 1),
+            None
+        );
+        drop(guard);
+
+        let guard = active.lock().unwrap();
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 1),
+            None
+        );
+        drop(guard);
+
+        assert_eq!(
+            KeyListenerManager::try_fire_hold(&active, &trigger_state, "dictate", 1),
+            Some(true)
+        );
+    }
+
+    fn hold_start(id: &str, generation: u64, deadline: Instant) -> HoldTimerCommand {
+        let (trigger, key, modifiers) = parse_binding("hold:F9").unwrap();
+        HoldTimerCommand::Start {
+            id: id.to_string(),
+            hold: PendingHold {
+                shortcut: RegisteredShortcut {
+                    key,
+                    modifiers,
+                    trigger,
+                    original_binding: "hold:F9".to_string(),
+                    match_main_key_in_any_combo: false,
+                    suppress_keypress: false,
+                },
+                generation,
+                deadline,
+            },
+        }
+    }
+
+    #[test]
+    fn one_hold_timer_fires_due_holds_and_skips_cancelled_ones() {
+        let active = Mutex::new(HashMap::new());
+        let trigger_state = pending_hold("dictate", 1);
+        trigger_state
+            .lock()
+            .unwrap()
+            .pending_holds
+            .insert("translate".to_string(), 1);
+
+        // Both deadlines are still ahead when the cancel arrives.
+        let deadline = Instant::now() + Duration::from_millis(30);
+        let (sender, receiver) = mpsc::channel();
+        sender.send(hold_start("dictate", 1, deadline)).unwrap();
+        sender.send(hold_start("translate", 1, deadline)).unwrap();
+        sender
+            .send(HoldTimerCommand::Cancel {
+                id: "translate".to_string(),
+            })
+            .unwrap();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            drop(sender);
+        });
+
+        let mut fired = Vec::new();
+        KeyListenerManager::run_hold_timer(receiver, &active, &trigger_state, |id, _| {
+            fired.push(id.to_string())
+        });
+
+        assert_eq!(fired, vec!["dictate"]);
+        assert_eq!(active.lock().unwrap().get("dictate"), Some(&true));
+        assert!(!active.lock().unwrap().contains_key("translate"));
+    }
+
+    #[test]
+    fn forgetting_a_binding_drops_its_pending_triggers() {
+        let mut state = TriggerState::default();
+        state.pending_holds.insert("dictate".to_string(), 1);
+        state.first_taps.insert(
+            "dictate".to_string(),
+            FirstTap {
+                pressed_at: Instant::now(),
+                released: true,
+            },
+        );
+
+        state.forget("dictate");
+        assert!(state.pending_holds.is_empty());
+        assert!(state.first_taps.is_empty());
+    }
+
+    #[test]
+    fn default_double_tap_window_is_in_range() {
+        assert!(DOUBLE_TAP_WINDOW_RANGE_MS.contains(&DEFAULT_DOUBLE_TAP_WINDOW_MS));
+    }
+}
@@ -182,6 +182,7 @@ fn start_live_session(
         }
 
         TranscriptionProvider::RemoteOpenAiCompatible
+        | TranscriptionProvider::RemoteOpenAiRealtime
             if crate::actions::live_sound_use_live_streaming(settings) =>
         {
             let manager =
@@ -194,7 +195,7 @@ fn start_live_session(
                 manager_cb.push_audio_frame(frame);
             })));
 
-            let api_key = crate::managers::remote_stt::get_remote_stt_api_key(&settings.remote_stt)
+            let api_key = crate::actions::openai_realtime_whisper_api_key(settings)
                 .map_err(|e| format!("Failed to get OpenAI API key: {}", e))?;
             let options = crate::actions::build_openai_options_for_live_sound(settings);
             manager
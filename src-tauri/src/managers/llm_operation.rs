@@ -3,7 +3,11 @@
 //! Provides cancellation tracking for LLM requests (AI Replace, etc.)
 //! Similar pattern to RemoteSttManager's operation tracking.
 
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
 /// Tracks LLM operations and allows cancellation.
 /// When cancel() is called, all operations started before that point are marked as cancelled.
@@ -12,6 +16,8 @@ pub struct LlmOperationTracker {
     current_operation_id: AtomicU64,
     /// Operations with ID less than this value are considered cancelled
     cancelled_before_id: AtomicU64,
+    /// Cancellation tokens for requests currently awaited via `run_cancellable`
+    active_requests: Mutex<HashMap<u64, CancellationToken>>,
 }
 
 impl LlmOperationTracker {
@@ -19,6 +25,7 @@ impl LlmOperationTracker {
         Self {
             current_operation_id: AtomicU64::new(0),
             cancelled_before_id: AtomicU64::new(0),
+            active_requests: Mutex::new(HashMap::new()),
         }
     }
 
@@ -32,6 +39,11 @@ impl LlmOperationTracker {
         let current = self.current_operation_id.load(Ordering::SeqCst);
         self.cancelled_before_id
             .store(current + 1, Ordering::SeqCst);
+        for (operation_id, token) in self.active_requests.lock().unwrap().iter() {
+            if *operation_id < current + 1 {
+                token.cancel();
+            }
+        }
         log::info!(
             "LlmOperationTracker: cancelled all operations up to id {}",
             current + 1
@@ -42,6 +54,33 @@ impl LlmOperationTracker {
     pub fn is_cancelled(&self, operation_id: u64) -> bool {
         operation_id < self.cancelled_before_id.load(Ordering::SeqCst)
     }
+
+    /// Awaits `future` unless the operation is cancelled first.
+    /// On cancellation the future is dropped (aborting any in-flight HTTP
+    /// request) and `None` is returned.
+    pub async fn run_cancellable<F: Future>(
+        &self,
+        operation_id: u64,
+        future: F,
+    ) -> Option<F::Output> {
+        let cancel_token = CancellationToken::new();
+        self.active_requests
+            .lock()
+            .unwrap()
+            .insert(operation_id, cancel_token.clone());
+        if self.is_cancelled(operation_id) {
+            cancel_token.cancel();
+        }
+
+        let result = tokio::select! {
+            biased;
+            _ = cancel_token.cancelled() => None,
+            output = future => Some(output),
+        };
+
+        self.active_requests.lock().unwrap().remove(&operation_id);
+        result
+    }
 }
 
 impl Default for LlmOperationTracker {
@@ -49,3 +88,44 @@ impl Default for LlmOperationTracker {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_aborts_pending_request() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let tracker = LlmOperationTracker::new();
+        let operation_id = tracker.start_operation();
+
+        let result = runtime.block_on(async {
+            let pending = tracker.run_cancellable(operation_id, std::future::pending::<()>());
+            let cancel = async {
+                tokio::task::yield_now().await;
+                tracker.cancel();
+            };
+            let (result, _) = tokio::join!(pending, cancel);
+            result
+        });
+
+        assert_eq!(result, None);
+        assert!(tracker.active_requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn completed_request_returns_output() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let tracker = LlmOperationTracker::new();
+        let operation_id = tracker.start_operation();
+
+        let result = runtime.block_on(tracker.run_cancellable(operation_id, async { 42 }));
+
+        assert_eq!(result, Some(42));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod audio;
 pub mod connector;
+pub mod connector_outbox;
 pub mod deepgram_realtime;
 pub mod deepgram_stt;
 pub mod gguf_meta;
@@ -13,9 +14,11 @@ pub mod model;
 pub mod model_capabilities;
 pub(crate) mod moonshine_streaming_shim;
 mod native_streaming_latency;
+pub mod openai_realtime;
 pub mod openai_realtime_whisper;
 pub mod preview_output_mode;
 pub mod remote_stt;
 pub mod soniox_realtime;
 pub mod soniox_stt;
 pub mod transcription;
+pub mod usage_stats;
@@ -17,6 +17,7 @@ use std::fs::File;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tar::Archive;
 use tauri::{AppHandle, Emitter, Manager};
 use tokio_util::sync::CancellationToken;
@@ -95,6 +96,8 @@ pub struct ModelInfo {
     pub sha256: Option<String>,
     pub size_mb: u64,
     pub is_downloaded: bool,
+    #[serde(default)]
+    pub available_on_disk: bool, // Whether the model files passed the presence/integrity check
     pub is_downloading: bool,
     pub partial_size: u64,
     pub is_directory: bool,
@@ -148,6 +151,12 @@ fn canonicalize_supported_languages(languages: Vec<String>) -> Vec<String> {
     canonical
 }
 
+/// A model limited to a single language ignores the requested language.
+/// An empty list means the languages are unknown, so any choice is allowed.
+pub fn supports_language_selection(supported_languages: &[String]) -> bool {
+    supported_languages.len() != 1
+}
+
 pub fn effective_language(
     requested_language: &str,
     supported_languages: &[String],
@@ -221,6 +230,136 @@ pub struct DownloadProgress {
     pub downloaded: u64,
     pub total: u64,
     pub percentage: f64,
+    /// Recent transfer rate; 0 until the first measurement window closes.
+    pub bytes_per_second: u64,
+}
+
+impl DownloadProgress {
+    fn new(model_id: &str, downloaded: u64, total: u64, bytes_per_second: u64) -> Self {
+        let percentage = if total > 0 {
+            (downloaded as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+        Self {
+            model_id: model_id.to_string(),
+            downloaded,
+            total,
+            percentage,
+            bytes_per_second,
+        }
+    }
+}
+
+/// Transfer rate over the last measurement window (about half a second).
+struct TransferRate {
+    window_start: Instant,
+    window_bytes: u64,
+    bytes_per_second: u64,
+}
+
+impl TransferRate {
+    const WINDOW: Duration = Duration::from_millis(500);
+
+    fn new() -> Self {
+        Self {
+            window_start: Instant::now(),
+            window_bytes: 0,
+            bytes_per_second: 0,
+        }
+    }
+
+    /// Adds `bytes` to the current window and returns the latest rate.
+    fn record(&mut self, bytes: u64) -> u64 {
+        self.window_bytes = self.window_bytes.saturating_add(bytes);
+        let elapsed = self.window_start.elapsed();
+        if elapsed >= Self::WINDOW {
+            self.bytes_per_second = (self.window_bytes as f64 / elapsed.as_secs_f64()) as u64;
+            self.window_start = Instant::now();
+            self.window_bytes = 0;
+        }
+        self.bytes_per_second
+    }
+}
+
+/// Sidecar stored next to a direct-URL `.partial` download. It records how
+/// many bytes were flushed at the last checkpoint and what the file is a
+/// download of, so a resume never appends to a foreign or torn file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PartialDownloadState {
+    url: String,
+    sha256: Option<String>,
+    bytes_downloaded: u64,
+}
+
+/// Bytes written between sidecar checkpoints.
+const PARTIAL_CHECKPOINT_BYTES: u64 = 8 * 1024 * 1024;
+
+fn partial_state_path(partial_path: &Path) -> PathBuf {
+    let mut name = partial_path.as_os_str().to_owned();
+    name.push(".json");
+    PathBuf::from(name)
+}
+
+fn write_partial_state(partial_path: &Path, state: &PartialDownloadState) {
+    let result = serde_json::to_vec(state)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| fs::write(partial_state_path(partial_path), json).map_err(Into::into));
+    if let Err(error) = result {
+        warn!(
+            "Failed to write download checkpoint for {:?}: {}",
+            partial_path, error
+        );
+    }
+}
+
+fn discard_partial(partial_path: &Path) {
+    let _ = fs::remove_file(partial_path);
+    let _ = fs::remove_file(partial_state_path(partial_path));
+}
+
+/// Byte offset a download of `url` can resume from. The `.partial` file is
+/// truncated to the last checkpoint; a file whose sidecar is missing, names
+/// another source or checksum, or claims more bytes than exist is discarded.
+fn resumable_offset(partial_path: &Path, url: &str, sha256: Option<&str>) -> u64 {
+    let Ok(metadata) = partial_path.metadata() else {
+        let _ = fs::remove_file(partial_state_path(partial_path));
+        return 0;
+    };
+
+    let state = fs::read(partial_state_path(partial_path))
+        .ok()
+        .and_then(|json| serde_json::from_slice::<PartialDownloadState>(&json).ok());
+    let usable = state.filter(|state| {
+        state.url == url
+            && state.sha256.as_deref() == sha256
+            && state.bytes_downloaded <= metadata.len()
+    });
+    let Some(state) = usable else {
+        warn!(
+            "Discarding partial download {:?}: checkpoint missing or does not match",
+            partial_path
+        );
+        discard_partial(partial_path);
+        return 0;
+    };
+
+    if metadata.len() > state.bytes_downloaded {
+        let truncated = fs::OpenOptions::new()
+            .write(true)
+            .open(partial_path)
+            .and_then(|file| file.set_len(state.bytes_downloaded));
+        if let Err(error) = truncated {
+            warn!(
+                "Discarding partial download {:?}: failed to truncate to checkpoint: {}",
+                partial_path, error
+            );
+            discard_partial(partial_path);
+            return 0;
+        }
+    }
+
+    state.bytes_downloaded
 }
 
 #[derive(Clone)]
@@ -233,6 +372,7 @@ struct HfDownloadProgress {
 struct HfDownloadProgressState {
     downloaded: u64,
     total: u64,
+    rate: TransferRate,
 }
 
 impl HfDownloadProgress {
@@ -243,24 +383,15 @@ impl HfDownloadProgress {
             state: Arc::new(Mutex::new(HfDownloadProgressState {
                 downloaded: 0,
                 total: fallback_total,
+                rate: TransferRate::new(),
             })),
         }
     }
 
-    fn emit(&self, downloaded: u64, total: u64) {
-        let percentage = if total > 0 {
-            (downloaded as f64 / total as f64) * 100.0
-        } else {
-            0.0
-        };
+    fn emit(&self, downloaded: u64, total: u64, bytes_per_second: u64) {
         let _ = self.app_handle.emit(
             "model-download-progress",
-            &DownloadProgress {
-                model_id: self.model_id.clone(),
-                downloaded,
-                total,
-                percentage,
-            },
+            &DownloadProgress::new(&self.model_id, downloaded, total, bytes_per_second),
         );
     }
 }
@@ -272,17 +403,19 @@ impl Progress for HfDownloadProgress {
             let mut state = self.state.lock().unwrap();
             state.downloaded = 0;
             state.total = total;
+            state.rate = TransferRate::new();
         }
-        self.emit(0, total);
+        self.emit(0, total, 0);
     }
 
     async fn update(&mut self, size: usize) {
-        let (downloaded, total) = {
+        let (downloaded, total, bytes_per_second) = {
             let mut state = self.state.lock().unwrap();
             state.downloaded = state.downloaded.saturating_add(size as u64);
-            (state.downloaded, state.total)
+            let bytes_per_second = state.rate.record(size as u64);
+            (state.downloaded, state.total, bytes_per_second)
         };
-        self.emit(downloaded, total);
+        self.emit(downloaded, total, bytes_per_second);
     }
 
     async fn finish(&mut self) {
@@ -291,8 +424,79 @@ impl Progress for HfDownloadProgress {
             state.downloaded = state.total;
             state.total
         };
-        self.emit(total, total);
+        self.emit(total, total, 0);
+    }
+}
+
+/// Payload of the `model-missing` event.
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ModelMissingEvent {
+    pub model_id: String,
+    /// Path that failed the check, or the file name when it could not be resolved.
+    pub path: String,
+    pub reason: String,
+    /// Model selected in its place when the automatic fallback kicked in.
+    pub fallback_model_id: Option<String>,
+}
+
+const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+/// Legacy ggml containers (`ggml`, `ggmf`, `ggjt`) store their magic as a little-endian u32.
+const GGML_MAGICS: [&[u8; 4]; 3] = [b"lmgg", b"fmgg", b"tjgg"];
+
+fn model_magic_is_valid(path: &Path, magic: &[u8; 4]) -> bool {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("gguf") => magic == GGUF_MAGIC,
+        Some("bin") => magic == GGUF_MAGIC || GGML_MAGICS.contains(&magic),
+        _ => true,
+    }
+}
+
+/// Checks that model files are present and look complete. Returns why they
+/// are unusable, or `None` when the check passes.
+fn check_model_files(path: &Path, is_directory: bool, expected_size_mb: u64) -> Option<String> {
+    if is_directory {
+        return match fs::read_dir(path) {
+            Ok(mut entries) => entries
+                .next()
+                .is_none()
+                .then(|| "model directory is empty".to_string()),
+            Err(_) if path.exists() => Some("model path is not a directory".to_string()),
+            Err(_) => Some("model directory is missing".to_string()),
+        };
+    }
+
+    let len = match fs::metadata(path) {
+        Ok(metadata) if metadata.is_file() => metadata.len(),
+        Ok(_) => return Some("model path is not a file".to_string()),
+        Err(_) => return Some("model file is missing".to_string()),
+    };
+    if len == 0 {
+        return Some("model file is empty".to_string());
     }
+    // Catalog sizes are approximate, so only flag files that are clearly cut short.
+    if len < expected_size_mb.saturating_mul(1024 * 1024) / 2 {
+        return Some(format!(
+            "model file is truncated ({} MB of about {} MB)",
+            len / (1024 * 1024),
+            expected_size_mb
+        ));
+    }
+
+    let mut magic = [0u8; 4];
+    if File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_err()
+    {
+        return Some("model file is unreadable".to_string());
+    }
+    if !model_magic_is_valid(path, &magic) {
+        return Some("model file has an unrecognized header".to_string());
+    }
+    None
 }
 
 pub struct ModelManager {
@@ -363,6 +567,7 @@ impl ModelManager {
                 ),
                 size_mb: 465,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: false,
@@ -393,6 +598,7 @@ impl ModelManager {
                 ),
                 size_mb: 469,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: false,
@@ -422,6 +628,7 @@ impl ModelManager {
                 ),
                 size_mb: 1549,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: false,
@@ -451,6 +658,7 @@ impl ModelManager {
                 ),
                 size_mb: 1031,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: false,
@@ -481,6 +689,7 @@ impl ModelManager {
                 ),
                 size_mb: 1030,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: false,
@@ -516,6 +725,7 @@ impl ModelManager {
                 ),
                 size_mb: 451,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -545,6 +755,7 @@ impl ModelManager {
                 ),
                 size_mb: 456,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -574,6 +785,7 @@ impl ModelManager {
                 ),
                 size_mb: 55,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -605,6 +817,7 @@ impl ModelManager {
                 ),
                 size_mb: 31,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -636,6 +849,7 @@ impl ModelManager {
                 ),
                 size_mb: 99,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -667,6 +881,7 @@ impl ModelManager {
                 ),
                 size_mb: 192,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -697,6 +912,7 @@ impl ModelManager {
                 ),
                 size_mb: 152,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -729,6 +945,7 @@ impl ModelManager {
                 ),
                 size_mb: 151,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -764,6 +981,7 @@ impl ModelManager {
                 ),
                 size_mb: 146,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -802,6 +1020,7 @@ impl ModelManager {
                 ),
                 size_mb: 691,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -839,6 +1058,7 @@ impl ModelManager {
                 ),
                 size_mb: 1708,
                 is_downloaded: false,
+                available_on_disk: false,
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
@@ -983,6 +1203,7 @@ impl ModelManager {
                     sha256: None,
                     size_mb: file.size_bytes / (1024 * 1024),
                     is_downloaded: false,
+                    available_on_disk: false,
                     is_downloading: false,
                     partial_size: 0,
                     is_directory: false,
@@ -1179,9 +1400,99 @@ impl ModelManager {
             }
         }
 
+        for model in models.values_mut() {
+            model.available_on_disk = model.is_downloaded && self.disk_problem(model).is_none();
+        }
+
         Ok(())
     }
 
+    fn model_disk_path(&self, model: &ModelInfo) -> Option<PathBuf> {
+        match model_hf_source(model) {
+            Some((repo_id, revision, filename)) => hf_cached_path(&repo_id, &revision, &filename),
+            None => Some(self.models_dir.join(&model.filename)),
+        }
+    }
+
+    /// Path of the model's files and why they are unusable, if they are.
+    fn disk_problem(&self, model: &ModelInfo) -> Option<(String, String)> {
+        let Some(path) = self.model_disk_path(model) else {
+            return Some((
+                model.filename.clone(),
+                "model file is missing from the Hugging Face cache".to_string(),
+            ));
+        };
+        check_model_files(&path, model.is_directory, model.size_mb)
+            .map(|reason| (path.display().to_string(), reason))
+    }
+
+    /// Side-effect free variant of [`Self::verify_model_on_disk`] for error reporting.
+    pub fn model_disk_problem(&self, model_id: &str) -> Option<(String, String)> {
+        let model = self.get_model_info(model_id)?;
+        self.disk_problem(&model)
+    }
+
+    /// Re-checks a model's files before it is used. On failure the model is
+    /// marked unavailable, a `model-missing` event is emitted and, when
+    /// `auto_fallback_on_missing_model` is on, another usable model is selected.
+    pub fn verify_model_on_disk(&self, model_id: &str) -> Option<ModelMissingEvent> {
+        let model = self.get_model_info(model_id)?;
+        let Some((path, reason)) = self.disk_problem(&model) else {
+            if let Some(model) = self.available_models.lock().unwrap().get_mut(model_id) {
+                model.available_on_disk = true;
+            }
+            return None;
+        };
+
+        warn!("Model '{}' is unusable: {} ({})", model_id, reason, path);
+        if let Some(model) = self.available_models.lock().unwrap().get_mut(model_id) {
+            model.available_on_disk = false;
+            model.is_downloaded = model.is_downloaded && Path::new(&path).exists();
+        }
+
+        let fallback_model_id = if get_settings(&self.app_handle).auto_fallback_on_missing_model {
+            self.select_fallback_model(model_id)
+        } else {
+            None
+        };
+
+        let event = ModelMissingEvent {
+            model_id: model_id.to_string(),
+            path,
+            reason,
+            fallback_model_id,
+        };
+        let _ = self.app_handle.emit("model-missing", &event);
+        let _ = self.app_handle.emit("models-updated", ());
+        Some(event)
+    }
+
+    fn select_fallback_model(&self, missing_model_id: &str) -> Option<String> {
+        let fallback_id = {
+            let models = self.available_models.lock().unwrap();
+            models
+                .values()
+                .find(|model| model.id != missing_model_id && model.available_on_disk)
+                .map(|model| model.id.clone())
+        };
+        let Some(fallback_id) = fallback_id else {
+            warn!(
+                "Selected model {} is unavailable and no downloaded models were found.",
+                missing_model_id
+            );
+            return None;
+        };
+
+        let mut settings = get_settings(&self.app_handle);
+        settings.selected_model = fallback_id.clone();
+        write_settings(&self.app_handle, settings);
+        info!(
+            "Falling back from missing model '{}' to '{}'",
+            missing_model_id, fallback_id
+        );
+        Some(fallback_id)
+    }
+
     fn auto_select_model_if_needed(&self) -> Result<()> {
         let mut settings = get_settings(&self.app_handle);
 
@@ -1201,37 +1512,25 @@ impl ModelManager {
             }
         }
 
-        let models = self.available_models.lock().unwrap();
-        let selected_model_available = if settings.selected_model.is_empty() {
-            false
-        } else {
-            models
-                .get(&settings.selected_model)
-                .map(|model| model.is_downloaded)
-                .unwrap_or(false)
-        };
+        if !settings.selected_model.is_empty() {
+            // A missing selection is reported and, if enabled, replaced by a fallback.
+            self.verify_model_on_disk(&settings.selected_model);
+            return Ok(());
+        }
 
-        // If no model is selected or selected model is unavailable, auto-select the first downloaded model.
-        if settings.selected_model.is_empty() || !selected_model_available {
-            // Find the first available (downloaded) model
-            if let Some(available_model) = models.values().find(|model| model.is_downloaded) {
-                info!(
-                    "Auto-selecting model: {} ({})",
-                    available_model.id, available_model.name
-                );
+        // No model selected yet: pick the first usable one.
+        let models = self.available_models.lock().unwrap();
+        if let Some(available_model) = models.values().find(|model| model.available_on_disk) {
+            info!(
+                "Auto-selecting model: {} ({})",
+                available_model.id, available_model.name
+            );
 
-                // Update settings with the selected model
-                let mut updated_settings = settings;
-                updated_settings.selected_model = available_model.id.clone();
-                write_settings(&self.app_handle, updated_settings);
+            let mut updated_settings = settings;
+            updated_settings.selected_model = available_model.id.clone();
+            write_settings(&self.app_handle, updated_settings);
 
-                info!("Successfully auto-selected model: {}", available_model.id);
-            } else if !settings.selected_model.is_empty() {
-                warn!(
-                    "Selected model {} is unavailable and no downloaded models were found.",
-                    settings.selected_model
-                );
-            }
+            info!("Successfully auto-selected model: {}", available_model.id);
         }
 
         Ok(())
@@ -1341,6 +1640,7 @@ impl ModelManager {
                     sha256: None,
                     size_mb,
                     is_downloaded: true,
+                    available_on_disk: true,
                     is_downloading: false,
                     partial_size: 0,
                     is_directory: false,
@@ -1445,6 +1745,7 @@ impl ModelManager {
                         sha256: None,
                         size_mb,
                         is_downloaded: true,
+                        available_on_disk: true,
                         is_downloading: false,
                         partial_size: 0,
                         is_directory: false,
@@ -1512,12 +1813,12 @@ impl ModelManager {
         let result: Result<bool> = async {
             let _ = self.app_handle.emit(
                 "model-download-progress",
-                &DownloadProgress {
-                    model_id: model_info.id.clone(),
-                    downloaded: 0,
-                    total: model_info.size_mb.saturating_mul(1024 * 1024),
-                    percentage: 0.0,
-                },
+                &DownloadProgress::new(
+                    &model_info.id,
+                    0,
+                    model_info.size_mb.saturating_mul(1024 * 1024),
+                    0,
+                ),
             );
 
             // Every catalog repository is public. Ignore cached credentials so
@@ -1598,9 +1899,7 @@ impl ModelManager {
             .join(format!("{}.partial", &model_info.filename));
 
         if model_path.exists() {
-            if partial_path.exists() {
-                let _ = fs::remove_file(&partial_path);
-            }
+            discard_partial(&partial_path);
             self.update_download_status()?;
             return Ok(());
         }
@@ -1618,14 +1917,16 @@ impl ModelManager {
         }
 
         let result: Result<()> = async {
-            let mut resume_from = if partial_path.exists() {
-                let size = partial_path.metadata()?.len();
-                info!("Resuming download of model {} from byte {}", model_id, size);
-                size
+            let mut resume_from =
+                resumable_offset(&partial_path, &url, model_info.sha256.as_deref());
+            if resume_from > 0 {
+                info!(
+                    "Resuming download of model {} from byte {}",
+                    model_id, resume_from
+                );
             } else {
                 info!("Starting fresh download of model {} from {}", model_id, url);
-                0
-            };
+            }
 
             let client = reqwest::Client::new();
             let mut request = client.get(&url);
@@ -1642,7 +1943,7 @@ impl ModelManager {
                     model_id
                 );
                 drop(response);
-                let _ = fs::remove_file(&partial_path);
+                discard_partial(&partial_path);
                 resume_from = 0;
                 response = client.get(&url).send().await?;
             }
@@ -1673,24 +1974,36 @@ impl ModelManager {
                 std::fs::File::create(&partial_path)?
             };
 
-            let initial_progress = DownloadProgress {
-                model_id: model_id.to_string(),
-                downloaded,
-                total: total_size,
-                percentage: if total_size > 0 {
-                    (downloaded as f64 / total_size as f64) * 100.0
-                } else {
-                    0.0
-                },
+            let mut checkpoint = PartialDownloadState {
+                url: url.clone(),
+                sha256: model_info.sha256.clone(),
+                bytes_downloaded: downloaded,
             };
-            let _ = self
-                .app_handle
-                .emit("model-download-progress", &initial_progress);
+            // Flushes what has been written so far and records it as the
+            // resume point. Bytes past the checkpoint are dropped on resume.
+            let mut save_checkpoint = |file: &mut File, downloaded: u64| {
+                if file.sync_data().is_ok() {
+                    checkpoint.bytes_downloaded = downloaded;
+                    write_partial_state(&partial_path, &checkpoint);
+                }
+            };
+            save_checkpoint(&mut file, downloaded);
+
+            let mut rate = TransferRate::new();
+            let _ = self.app_handle.emit(
+                "model-download-progress",
+                &DownloadProgress::new(model_id, downloaded, total_size, 0),
+            );
 
+            let mut last_checkpoint = downloaded;
             loop {
                 tokio::select! {
                     _ = cancel_token.cancelled() => {
                         info!("Download cancelled for model: {}", model_id);
+                        // cancel_download may already have removed the file.
+                        if partial_path.exists() {
+                            save_checkpoint(&mut file, downloaded);
+                        }
                         drop(file);
                         self.clear_download_state(model_id, &partial_path);
                         let _ = self.app_handle.emit("model-download-cancelled", model_id);
@@ -1701,22 +2014,23 @@ impl ModelManager {
                             Some(Ok(chunk)) => {
                                 file.write_all(&chunk)?;
                                 downloaded += chunk.len() as u64;
+                                if downloaded - last_checkpoint >= PARTIAL_CHECKPOINT_BYTES {
+                                    save_checkpoint(&mut file, downloaded);
+                                    last_checkpoint = downloaded;
+                                }
 
-                                let percentage = if total_size > 0 {
-                                    (downloaded as f64 / total_size as f64) * 100.0
-                                } else {
-                                    0.0
-                                };
-
-                                let progress = DownloadProgress {
-                                    model_id: model_id.to_string(),
+                                let progress = DownloadProgress::new(
+                                    model_id,
                                     downloaded,
-                                    total: total_size,
-                                    percentage,
-                                };
+                                    total_size,
+                                    rate.record(chunk.len() as u64),
+                                );
                                 let _ = self.app_handle.emit("model-download-progress", &progress);
                             }
-                            Some(Err(error)) => return Err(error.into()),
+                            Some(Err(error)) => {
+                                save_checkpoint(&mut file, downloaded);
+                                return Err(error.into());
+                            }
                             None => break,
                         }
                     }
@@ -1725,6 +2039,7 @@ impl ModelManager {
 
             file.flush()?;
             drop(file);
+            let _ = fs::remove_file(partial_state_path(&partial_path));
 
             if total_size > 0 {
                 let actual_size = partial_path.metadata()?.len();
@@ -1821,6 +2136,7 @@ impl ModelManager {
                 if let Some(model) = models.get_mut(model_id) {
                     model.is_downloading = false;
                     model.is_downloaded = true;
+                    model.available_on_disk = true;
                     model.partial_size = 0;
                 }
             }
@@ -1914,6 +2230,7 @@ impl ModelManager {
         if partial_path.exists() {
             info!("Deleting partial file at: {:?}", partial_path);
             fs::remove_file(&partial_path)?;
+            let _ = fs::remove_file(partial_state_path(&partial_path));
             info!("Partial file deleted successfully");
             deleted_something = true;
         }
@@ -1989,9 +2306,20 @@ impl ModelManager {
         }
     }
 
+    /// Stops a download and discards its partial file.
     pub fn cancel_download(&self, model_id: &str) -> Result<()> {
         debug!("ModelManager: cancel_download called for: {}", model_id);
+        self.stop_download(model_id, false)
+    }
+
+    /// Stops a download but keeps its partial file, so the next
+    /// `download_model` resumes where this one stopped.
+    pub fn pause_download(&self, model_id: &str) -> Result<()> {
+        debug!("ModelManager: pause_download called for: {}", model_id);
+        self.stop_download(model_id, true)
+    }
 
+    fn stop_download(&self, model_id: &str, keep_partial: bool) -> Result<()> {
         let model_info = {
             let models = self.available_models.lock().unwrap();
             models.get(model_id).cloned()
@@ -2000,6 +2328,15 @@ impl ModelManager {
         let model_info =
             model_info.ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
 
+        // hf-hub keeps no partial file of its own, so there is nothing to
+        // resume from.
+        if keep_partial && model_hf_source(&model_info).is_some() {
+            return Err(anyhow::anyhow!(
+                "Hugging Face downloads cannot be paused: {}",
+                model_id
+            ));
+        }
+
         // Cancel the download task via cancellation token.
         let cancellation_sent = {
             let tokens = self.cancellation_tokens.lock().unwrap();
@@ -2022,22 +2359,27 @@ impl ModelManager {
             }
         }
 
-        // Delete the partial file so the model returns to "downloadable" state
+        // Delete the partial file so the model returns to "downloadable" state,
+        // unless it is kept for a later resume
         let partial_path = self
             .models_dir
             .join(format!("{}.partial", &model_info.filename));
-        if partial_path.exists() {
-            if let Err(e) = fs::remove_file(&partial_path) {
-                warn!("Failed to delete partial file {:?}: {}", partial_path, e);
-            } else {
-                info!(
-                    "Deleted partial file for cancelled download: {:?}",
-                    partial_path
-                );
+        if !keep_partial {
+            if partial_path.exists() {
+                if let Err(e) = fs::remove_file(&partial_path) {
+                    warn!("Failed to delete partial file {:?}: {}", partial_path, e);
+                } else {
+                    info!(
+                        "Deleted partial file for cancelled download: {:?}",
+                        partial_path
+                    );
+                }
             }
+            let _ = fs::remove_file(partial_state_path(&partial_path));
         }
 
-        // Update download status to reflect current state
+        // Update download status to reflect current state (including the
+        // size of a kept partial file)
         self.update_download_status()?;
 
         // Direct-URL downloads emit this from their existing stream loop.
@@ -2047,14 +2389,21 @@ impl ModelManager {
             let _ = self.app_handle.emit("model-download-cancelled", model_id);
         }
 
-        info!("Download cancelled for: {}", model_id);
+        info!(
+            "Download {} for: {}",
+            if keep_partial { "paused" } else { "cancelled" },
+            model_id
+        );
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{effective_language, ModelManager};
+    use super::{
+        check_model_files, effective_language, partial_state_path, resumable_offset,
+        write_partial_state, ModelManager, PartialDownloadState,
+    };
     use std::fs;
     use std::path::PathBuf;
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -2129,6 +2478,58 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn checkpoint(bytes_downloaded: u64) -> PartialDownloadState {
+        PartialDownloadState {
+            url: "https://example.com/model.bin".to_string(),
+            sha256: Some("abc".to_string()),
+            bytes_downloaded,
+        }
+    }
+
+    #[test]
+    fn resume_truncates_partial_to_checkpoint() {
+        let path = temp_file_path("resume");
+        fs::write(&path, b"hello world").unwrap();
+        write_partial_state(&path, &checkpoint(5));
+
+        let offset = resumable_offset(&path, "https://example.com/model.bin", Some("abc"));
+
+        assert_eq!(offset, 5);
+        assert_eq!(fs::read(&path).unwrap(), b"hello");
+
+        let _ = fs::remove_file(partial_state_path(&path));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn resume_discards_partial_with_mismatched_checkpoint() {
+        let path = temp_file_path("foreign");
+        fs::write(&path, b"hello").unwrap();
+        write_partial_state(&path, &checkpoint(5));
+
+        let offset = resumable_offset(&path, "https://example.com/model.bin", Some("def"));
+
+        assert_eq!(offset, 0);
+        assert!(!path.exists());
+        assert!(!partial_state_path(&path).exists());
+    }
+
+    #[test]
+    fn resume_discards_partial_shorter_than_checkpoint_or_without_one() {
+        let short = temp_file_path("short");
+        fs::write(&short, b"hel").unwrap();
+        write_partial_state(&short, &checkpoint(5));
+        let legacy = temp_file_path("legacy");
+        fs::write(&legacy, b"hello").unwrap();
+
+        let url = "https://example.com/model.bin";
+        assert_eq!(resumable_offset(&short, url, Some("abc")), 0);
+        assert_eq!(resumable_offset(&legacy, url, Some("abc")), 0);
+        assert!(!short.exists());
+        assert!(!partial_state_path(&short).exists());
+        assert!(!legacy.exists());
+    }
+
     #[test]
     fn compute_sha256_matches_known_hash_for_empty_file() {
         let path = temp_file_path("empty");
@@ -2200,4 +2601,52 @@ mod tests {
         assert!(error.contains("missing-voice-model"));
         assert!(error.contains("Please retry"));
     }
+
+    #[test]
+    fn check_model_files_reports_missing_file() {
+        let path = temp_file_path("deleted").with_extension("bin");
+
+        let reason = check_model_files(&path, false, 0).unwrap();
+
+        assert!(reason.contains("missing"));
+    }
+
+    #[test]
+    fn check_model_files_accepts_ggml_and_gguf_headers() {
+        let ggml = temp_file_path("ggml").with_extension("bin");
+        let gguf = temp_file_path("gguf").with_extension("gguf");
+        fs::write(&ggml, b"lmgg\0\0\0\0").unwrap();
+        fs::write(&gguf, b"GGUF\x03\0\0\0").unwrap();
+
+        assert_eq!(check_model_files(&ggml, false, 0), None);
+        assert_eq!(check_model_files(&gguf, false, 0), None);
+
+        let _ = fs::remove_file(ggml);
+        let _ = fs::remove_file(gguf);
+    }
+
+    #[test]
+    fn check_model_files_rejects_bad_header_and_truncation() {
+        let html = temp_file_path("html").with_extension("gguf");
+        fs::write(&html, b"<!DOCTYPE html>").unwrap();
+
+        let bad_header = check_model_files(&html, false, 0).unwrap();
+        let truncated = check_model_files(&html, false, 465).unwrap();
+
+        assert!(bad_header.contains("header"));
+        assert!(truncated.contains("truncated"));
+        let _ = fs::remove_file(html);
+    }
+
+    #[test]
+    fn check_model_files_requires_non_empty_directory() {
+        let dir = temp_file_path("model-dir");
+        fs::create_dir_all(&dir).unwrap();
+
+        assert!(check_model_files(&dir, true, 0).unwrap().contains("empty"));
+        fs::write(dir.join("model.onnx"), b"onnx").unwrap();
+        assert_eq!(check_model_files(&dir, true, 0), None);
+
+        let _ = fs::remove_dir_all(dir);
+    }
 }
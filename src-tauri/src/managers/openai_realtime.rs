@@ -0,0 +1,119 @@
+//! The OpenAI Realtime transcription provider.
+//!
+//! Unlike the OpenAI preset of the OpenAI-compatible provider, it has its own
+//! endpoint, model and API key. Audio goes over the Realtime WebSocket protocol
+//! of [`OpenAiRealtimeWhisperManager`]: live sessions use that manager as is,
+//! and recordings transcribed after stop go through
+//! [`OpenAiRealtimeManager::transcribe`], which adds the same cancellation
+//! bookkeeping as the other batch providers.
+
+use crate::managers::openai_realtime_whisper::{
+    OpenAiRealtimeWhisperManager, OpenAiRealtimeWhisperOptions,
+};
+use crate::settings::AppSettings;
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+pub struct OpenAiRealtimeManager {
+    app_handle: AppHandle,
+    /// Monotonically increasing operation ID; when cancel() is called, all
+    /// operations started before that point should abort.
+    current_operation_id: AtomicU64,
+    /// The operation ID at the time cancel() was last called.
+    cancelled_before_id: AtomicU64,
+}
+
+impl OpenAiRealtimeManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            current_operation_id: AtomicU64::new(0),
+            cancelled_before_id: AtomicU64::new(0),
+        })
+    }
+
+    /// Returns a new operation ID for tracking cancellation.
+    pub fn start_operation(&self) -> u64 {
+        self.current_operation_id.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Marks all operations started before now as cancelled.
+    pub fn cancel(&self) {
+        let current = self.current_operation_id.load(Ordering::SeqCst);
+        self.cancelled_before_id
+            .store(current + 1, Ordering::SeqCst);
+        log::debug!(
+            "OpenAiRealtimeManager: cancelled all operations up to id {}",
+            current
+        );
+    }
+
+    /// Returns true if the given operation ID has been cancelled.
+    pub fn is_cancelled(&self, operation_id: u64) -> bool {
+        operation_id < self.cancelled_before_id.load(Ordering::SeqCst)
+    }
+
+    /// Sends a finished recording through one Realtime session.
+    pub async fn transcribe(
+        &self,
+        operation_id: u64,
+        samples: &[f32],
+        options: OpenAiRealtimeWhisperOptions,
+    ) -> Result<String> {
+        let whisper_manager =
+            Arc::clone(&self.app_handle.state::<Arc<OpenAiRealtimeWhisperManager>>());
+        let result = whisper_manager
+            .transcribe_flattened(samples, &api_key(), options)
+            .await;
+        if self.is_cancelled(operation_id) {
+            return Err(anyhow!("OpenAI Realtime transcription was cancelled"));
+        }
+        result
+    }
+}
+
+/// The provider's API key; empty when none is stored.
+pub fn api_key() -> String {
+    #[cfg(target_os = "windows")]
+    {
+        crate::secure_keys::get_openai_realtime_api_key()
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        String::new()
+    }
+}
+
+/// Session options for the stored endpoint and model. The endpoint was
+/// validated when it was saved, so it is used as is.
+pub fn build_options(settings: &AppSettings, language: &str) -> OpenAiRealtimeWhisperOptions {
+    OpenAiRealtimeWhisperOptions {
+        language: Some(language.to_string()),
+        delay: settings.openai_realtime_whisper_delay,
+        base_url: Some(settings.openai_realtime_base_url.clone()),
+        model: Some(settings.openai_realtime_model.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_use_the_provider_endpoint_and_model() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.openai_realtime_base_url = "https://proxy.example.com/v1".to_string();
+        settings.openai_realtime_model = "gpt-4o-transcribe".to_string();
+
+        let options = build_options(&settings, "de");
+
+        assert_eq!(options.language.as_deref(), Some("de"));
+        assert_eq!(
+            options.base_url.as_deref(),
+            Some("https://proxy.example.com/v1")
+        );
+        assert_eq!(options.model.as_deref(), Some("gpt-4o-transcribe"));
+    }
+}
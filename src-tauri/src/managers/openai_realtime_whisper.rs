@@ -34,6 +34,11 @@ pub type FinalChunkCallback = Arc<dyn Fn(String) + Send + Sync + 'static>;
 pub struct OpenAiRealtimeWhisperOptions {
     pub language: Option<String>,
     pub delay: crate::settings::OpenAiRealtimeWhisperDelay,
+    /// Base URL validated when it was saved. `None` uses the public OpenAI
+    /// endpoint.
+    pub base_url: Option<String>,
+    /// Transcription model; `None` uses gpt-realtime-whisper.
+    pub model: Option<String>,
 }
 
 impl Default for OpenAiRealtimeWhisperOptions {
@@ -41,6 +46,8 @@ impl Default for OpenAiRealtimeWhisperOptions {
         Self {
             language: None,
             delay: crate::settings::OpenAiRealtimeWhisperDelay::Low,
+            base_url: None,
+            model: None,
         }
     }
 }
@@ -146,7 +153,7 @@ impl OpenAiRealtimeWhisperManager {
 
         let join_handle = tauri::async_runtime::spawn(async move {
             let session_result: Result<()> = async {
-                let mut request = Self::build_realtime_ws_url()
+                let mut request = Self::build_realtime_ws_url(options.base_url.as_deref())
                     .into_client_request()
                     .map_err(|e| {
                         anyhow!("Failed to create OpenAI Realtime Whisper request: {}", e)
@@ -293,7 +300,7 @@ impl OpenAiRealtimeWhisperManager {
         }
 
         let pcm_bytes = resample_16khz_f32_to_24khz_pcm16(audio_samples);
-        let mut request = Self::build_realtime_ws_url()
+        let mut request = Self::build_realtime_ws_url(options.base_url.as_deref())
             .into_client_request()
             .map_err(|e| anyhow!("Failed to create OpenAI Realtime Whisper request: {}", e))?;
         request.headers_mut().insert(
@@ -434,10 +441,17 @@ impl OpenAiRealtimeWhisperManager {
     }
 
     fn build_session_update_payload(options: &OpenAiRealtimeWhisperOptions) -> Value {
-        let mut transcription = json!({
-            "model": OPENAI_REALTIME_WHISPER_MODEL,
-            "delay": options.delay.as_str(),
-        });
+        let model = options
+            .model
+            .as_deref()
+            .map(str::trim)
+            .filter(|model| !model.is_empty())
+            .unwrap_or(OPENAI_REALTIME_WHISPER_MODEL);
+        let mut transcription = json!({ "model": model });
+        // Only gpt-realtime-whisper takes a delay; other transcription models reject it.
+        if Self::is_realtime_model(model) {
+            transcription["delay"] = json!(options.delay.as_str());
+        }
 
         if let Some(language) = Self::normalize_language(options.language.clone()) {
             transcription["language"] = json!(language);
@@ -461,8 +475,23 @@ impl OpenAiRealtimeWhisperManager {
         })
     }
 
-    fn build_realtime_ws_url() -> String {
-        OPENAI_REALTIME_TRANSCRIPTION_WS_URL.to_string()
+    /// Maps an http(s) base URL to its ws(s) realtime endpoint.
+    fn build_realtime_ws_url(base_url: Option<&str>) -> String {
+        let Some(base_url) = base_url
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+        else {
+            return OPENAI_REALTIME_TRANSCRIPTION_WS_URL.to_string();
+        };
+
+        let ws_base = if let Some(rest) = base_url.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = base_url.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            base_url.to_string()
+        };
+        format!("{}/realtime?intent=transcription", ws_base)
     }
 
     fn live_commit_interval_ms_for_delay(
@@ -938,6 +967,8 @@ mod tests {
             &OpenAiRealtimeWhisperOptions {
                 language: Some("en".to_string()),
                 delay: crate::settings::OpenAiRealtimeWhisperDelay::Medium,
+                base_url: None,
+                model: None,
             },
         );
 
@@ -967,9 +998,23 @@ mod tests {
         assert!(payload.get("response").is_none());
     }
 
+    #[test]
+    fn session_update_uses_configured_model_without_delay() {
+        let payload = OpenAiRealtimeWhisperManager::build_session_update_payload(
+            &OpenAiRealtimeWhisperOptions {
+                model: Some("gpt-4o-transcribe".to_string()),
+                ..Default::default()
+            },
+        );
+        let transcription = &payload["session"]["audio"]["input"]["transcription"];
+
+        assert_eq!(transcription["model"], "gpt-4o-transcribe");
+        assert!(transcription.get("delay").is_none());
+    }
+
     #[test]
     fn websocket_url_uses_transcription_intent_not_model_query() {
-        let url = OpenAiRealtimeWhisperManager::build_realtime_ws_url();
+        let url = OpenAiRealtimeWhisperManager::build_realtime_ws_url(None);
 
         assert!(url.contains("/v1/realtime"));
         assert!(url.contains("intent=transcription"));
@@ -978,6 +1023,22 @@ mod tests {
         assert!(!url.contains("model=gpt-realtime-2"));
     }
 
+    #[test]
+    fn websocket_url_follows_base_url() {
+        assert_eq!(
+            OpenAiRealtimeWhisperManager::build_realtime_ws_url(Some("https://api.openai.com/v1")),
+            OPENAI_REALTIME_TRANSCRIPTION_WS_URL
+        );
+        assert_eq!(
+            OpenAiRealtimeWhisperManager::build_realtime_ws_url(Some("http://127.0.0.1:8080/v1/")),
+            "ws://127.0.0.1:8080/v1/realtime?intent=transcription"
+        );
+        assert_eq!(
+            OpenAiRealtimeWhisperManager::build_realtime_ws_url(Some("  ")),
+            OPENAI_REALTIME_TRANSCRIPTION_WS_URL
+        );
+    }
+
     #[test]
     fn live_commit_interval_tracks_delay_setting() {
         assert_eq!(
@@ -23,6 +23,8 @@ use tokio_util::sync::CancellationToken;
 const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
 /// Default connection timeout (10 seconds)
 const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+/// Upper bound for a single retry backoff delay (30 seconds)
+const MAX_RETRY_BACKOFF_MS: u64 = 30_000;
 
 const REMOTE_STT_SERVICE: &str = "fi.maxits.aivorelay";
 const REMOTE_STT_USER_PREFIX: &str = "remote_stt_api_key";
@@ -41,6 +43,34 @@ const OPENAI_REALTIME_AGENT_DEFAULT_PROMPT: &str =
      The user may provide custom words that are rare in the language; try to recognize them properly. \
      Make sure to properly recognize names, product names, and vocabulary exactly when recognizable.";
 
+/// A Remote STT failure that may succeed when retried (connection failure, 5xx, 429).
+#[derive(Debug)]
+struct TransientRemoteSttError(String);
+
+impl std::fmt::Display for TransientRemoteSttError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TransientRemoteSttError {}
+
+fn is_transient_remote_stt_error(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<TransientRemoteSttError>().is_some()
+}
+
+fn is_transient_remote_stt_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Backoff before retrying after the given failed attempt (1-based): doubles each time.
+fn remote_stt_retry_delay(backoff_ms: u64, failed_attempt: u32) -> Duration {
+    let factor = 1u64
+        .checked_shl(failed_attempt.saturating_sub(1))
+        .unwrap_or(u64::MAX);
+    Duration::from_millis(backoff_ms.saturating_mul(factor).min(MAX_RETRY_BACKOFF_MS))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum RemoteSttApiKeySource {
     Scoped,
@@ -496,7 +526,8 @@ impl RemoteSttManager {
         let result = tokio::select! {
             biased;
             _ = cancel_token.cancelled() => Err(anyhow!("Remote STT operation was cancelled")),
-            result = self.transcribe_inner(
+            result = self.transcribe_with_retries(
+                operation_id,
                 settings,
                 audio_samples,
                 prompt,
@@ -509,6 +540,75 @@ impl RemoteSttManager {
         result
     }
 
+    /// Runs the request, retrying transient failures with exponential backoff.
+    /// Auth/validation errors are returned immediately.
+    async fn transcribe_with_retries(
+        &self,
+        operation_id: u64,
+        settings: &RemoteSttSettings,
+        audio_samples: &[f32],
+        prompt: Option<String>,
+        language: Option<String>,
+        translate_to_english: bool,
+    ) -> Result<String> {
+        let app_settings = crate::settings::get_settings(&self.app_handle);
+        let max_attempts = app_settings.remote_stt_retry_attempts.max(1);
+        let backoff_ms = app_settings.remote_stt_retry_backoff_ms;
+
+        let mut attempt = 1;
+        loop {
+            let error = match self
+                .transcribe_inner(
+                    settings,
+                    audio_samples,
+                    prompt.clone(),
+                    language.clone(),
+                    translate_to_english,
+                )
+                .await
+            {
+                Ok(text) => return Ok(text),
+                Err(e) => e,
+            };
+
+            if attempt >= max_attempts
+                || !is_transient_remote_stt_error(&error)
+                || self.is_cancelled(operation_id)
+            {
+                return Err(error);
+            }
+
+            let delay = remote_stt_retry_delay(backoff_ms, attempt);
+            self.record_error(
+                settings,
+                format!(
+                    "Remote STT attempt {}/{} failed, retrying in {}ms",
+                    attempt,
+                    max_attempts,
+                    delay.as_millis()
+                ),
+            );
+            log::warn!(
+                "Remote STT attempt {}/{} failed, retrying in {}ms: {}",
+                attempt,
+                max_attempts,
+                delay.as_millis(),
+                error
+            );
+            crate::overlay::show_remote_stt_retrying_overlay(
+                &self.app_handle,
+                attempt + 1,
+                max_attempts,
+            );
+
+            tokio::time::sleep(delay).await;
+            if self.is_cancelled(operation_id) {
+                return Err(anyhow!("Remote STT operation was cancelled"));
+            }
+            attempt += 1;
+        }
+    }
+
     async fn transcribe_inner(
         &self,
         settings: &RemoteSttSettings,
@@ -683,18 +783,26 @@ impl RemoteSttManager {
             }
         }
 
+        let (auth_name, auth_value) = settings.auth_style.header(&api_key.value);
         let start = Instant::now();
         let response = self
             .client
             .post(url)
-            .bearer_auth(&api_key.value)
+            .header(auth_name, auth_value)
+            .query(&settings.extra_query_params)
             .multipart(form)
             .send()
             .await
             .map_err(|e| {
                 let message = format!("Remote STT request failed: {}", e);
                 self.record_error(settings, message.clone());
-                anyhow!(message)
+                // Only a failed connection is known not to have reached the
+                // server; after a timeout the upload may already be processed.
+                if e.is_connect() {
+                    anyhow::Error::new(TransientRemoteSttError(message))
+                } else {
+                    anyhow!(message)
+                }
             })?;
 
         let status = response.status();
@@ -718,11 +826,18 @@ impl RemoteSttManager {
         if !status.is_success() {
             let snippet = String::from_utf8_lossy(&body);
             let snippet = snippet.chars().take(500).collect::<String>();
-            let message = format!(
-                "Remote STT failed: status={} elapsed_ms={} body_snippet={}",
-                status, elapsed_ms, snippet
+            let message = with_unauthorized_hint(
+                settings,
+                status,
+                format!(
+                    "Remote STT failed: status={} elapsed_ms={} body_snippet={}",
+                    status, elapsed_ms, snippet
+                ),
             );
             self.record_error(settings, message.clone());
+            if is_transient_remote_stt_status(status) {
+                return Err(TransientRemoteSttError(message).into());
+            }
             return Err(anyhow!(message));
         }
 
@@ -1257,11 +1372,13 @@ impl RemoteSttManager {
             );
         }
 
+        let (auth_name, auth_value) = settings.auth_style.header(&api_key.value);
         let start = Instant::now();
         let response = self
             .client
             .get(url)
-            .bearer_auth(&api_key.value)
+            .header(auth_name, auth_value)
+            .query(&settings.extra_query_params)
             .send()
             .await
             .map_err(|e| {
@@ -1287,9 +1404,13 @@ impl RemoteSttManager {
             let body = response.bytes().await.unwrap_or_default();
             let snippet = String::from_utf8_lossy(&body);
             let snippet = snippet.chars().take(500).collect::<String>();
-            let message = format!(
-                "Remote STT test failed: status={} elapsed_ms={} body_snippet={}",
-                status, elapsed_ms, snippet
+            let message = with_unauthorized_hint(
+                settings,
+                status,
+                format!(
+                    "Remote STT test failed: status={} elapsed_ms={} body_snippet={}",
+                    status, elapsed_ms, snippet
+                ),
             );
             self.record_error(settings, message.clone());
             return Err(anyhow!(message));
@@ -1300,6 +1421,20 @@ impl RemoteSttManager {
     }
 }
 
+/// A 401 from a mismatched auth style looks like a bad key, so say which
+/// header was sent.
+fn with_unauthorized_hint(
+    settings: &RemoteSttSettings,
+    status: reqwest::StatusCode,
+    message: String,
+) -> String {
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        format!("{} {}", message, settings.auth_style.unauthorized_hint())
+    } else {
+        message
+    }
+}
+
 fn remote_stt_api_key_scope(settings: &RemoteSttSettings) -> &'static str {
     match settings.provider_preset.as_str() {
         REMOTE_STT_PRESET_GROQ => REMOTE_STT_PRESET_GROQ,
@@ -1504,9 +1639,43 @@ pub fn has_remote_stt_api_key(_settings: &RemoteSttSettings) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        remote_stt_api_key_clear_targets, select_remote_stt_api_key, supports_translation,
-        RemoteSttApiKeySource,
+        is_transient_remote_stt_error, is_transient_remote_stt_status,
+        remote_stt_api_key_clear_targets, remote_stt_retry_delay, select_remote_stt_api_key,
+        supports_translation, RemoteSttApiKeySource, TransientRemoteSttError,
     };
+    use anyhow::anyhow;
+    use std::time::Duration;
+
+    #[test]
+    fn remote_stt_retry_delay_doubles_and_caps() {
+        assert_eq!(remote_stt_retry_delay(500, 1), Duration::from_millis(500));
+        assert_eq!(remote_stt_retry_delay(500, 2), Duration::from_millis(1000));
+        assert_eq!(remote_stt_retry_delay(500, 3), Duration::from_millis(2000));
+        assert_eq!(
+            remote_stt_retry_delay(500, 80),
+            Duration::from_millis(30_000)
+        );
+    }
+
+    #[test]
+    fn remote_stt_retries_only_transient_failures() {
+        assert!(is_transient_remote_stt_status(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(is_transient_remote_stt_status(
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(!is_transient_remote_stt_status(
+            reqwest::StatusCode::UNAUTHORIZED
+        ));
+        assert!(!is_transient_remote_stt_status(
+            reqwest::StatusCode::BAD_REQUEST
+        ));
+
+        let transient = anyhow::Error::new(TransientRemoteSttError("timeout".to_string()));
+        assert!(is_transient_remote_stt_error(&transient));
+        assert!(!is_transient_remote_stt_error(&anyhow!("status=401")));
+    }
 
     #[test]
     fn gpt_realtime_2_supports_remote_stt_translation() {
@@ -1,18 +1,22 @@
 use crate::file_transcription_diarization::RawSpeakerBlock;
 use crate::settings::SonioxContext;
+use crate::transcription_confidence::ConfidenceAccumulator;
 use anyhow::{anyhow, Result};
 use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use log::{debug, info, warn};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tauri::async_runtime::JoinHandle;
 use tauri::{AppHandle, Emitter};
+use tokio::net::TcpStream;
 use tokio::sync::mpsc;
 use tokio::time::{timeout, MissedTickBehavior};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 const SONIOX_WS_URL: &str = "wss://stt-rt.soniox.com/transcribe-websocket";
 const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
@@ -20,6 +24,20 @@ const AUDIO_QUEUE_CAPACITY: usize = 256;
 const DEFAULT_KEEPALIVE_INTERVAL_SECONDS: u32 = 10;
 const MIN_KEEPALIVE_INTERVAL_SECONDS: u32 = 5;
 const MAX_KEEPALIVE_INTERVAL_SECONDS: u32 = 20;
+/// Endpoint auto-stop never triggers this early in a session.
+const ENDPOINT_AUTO_STOP_ARM_AFTER: Duration = Duration::from_secs(3);
+/// Time the user has to resume talking once auto-stop is announced.
+const ENDPOINT_AUTO_STOP_GRACE: Duration = Duration::from_secs(1);
+const DEFAULT_RECONNECT_ATTEMPTS: u32 = 3;
+pub const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+/// Delay before a reconnect attempt, multiplied by the attempt number.
+const RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound on how long `finish_session` waits for a reconnect to flush.
+const MAX_RECONNECT_FINISH_WAIT: Duration = Duration::from_secs(60);
+/// pcm_s16le, mono, 16 kHz.
+const PCM_BYTES_PER_MS: u64 = 32;
+/// Unfinalized audio kept for replay after a socket drop (two minutes).
+const MAX_UNFINALIZED_AUDIO_BYTES: u64 = 2 * 60 * 1000 * PCM_BYTES_PER_MS;
 
 pub type FinalChunkCallback = Arc<dyn Fn(String) + Send + Sync + 'static>;
 
@@ -35,6 +53,11 @@ pub struct SonioxRealtimeOptions {
     pub keepalive_interval_seconds: u32,
     pub context: Option<SonioxContext>,
     pub show_preview: bool,
+    /// Consecutive endpoints without new speech that stop the recording
+    /// (None = disabled).
+    pub auto_stop_endpoint_count: Option<u32>,
+    /// Reconnect attempts after an unexpected socket drop (0 = disabled).
+    pub reconnect_attempts: u32,
 }
 
 impl Default for SonioxRealtimeOptions {
@@ -50,10 +73,104 @@ impl Default for SonioxRealtimeOptions {
             keepalive_interval_seconds: DEFAULT_KEEPALIVE_INTERVAL_SECONDS,
             context: None,
             show_preview: true,
+            auto_stop_endpoint_count: None,
+            reconnect_attempts: DEFAULT_RECONNECT_ATTEMPTS,
         }
     }
 }
 
+#[derive(Clone, Serialize)]
+struct SonioxAutoStopEvent {
+    binding_id: String,
+    /// "pending", "cancelled" or "stopping".
+    state: &'static str,
+    grace_ms: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum EndpointAutoStopSignal {
+    None,
+    Pending,
+    Cancelled,
+}
+
+/// Counts Soniox `<end>` endpoints and decides when a toggle-mode recording
+/// should stop on its own.
+struct EndpointAutoStopTracker {
+    required_endpoints: u32,
+    armed_at: Instant,
+    consecutive_endpoints: u32,
+    pending_since: Option<Instant>,
+    fired: bool,
+}
+
+impl EndpointAutoStopTracker {
+    fn new(required_endpoints: u32, started_at: Instant) -> Self {
+        Self {
+            required_endpoints: required_endpoints.max(1),
+            armed_at: started_at + ENDPOINT_AUTO_STOP_ARM_AFTER,
+            consecutive_endpoints: 0,
+            pending_since: None,
+            fired: false,
+        }
+    }
+
+    fn observe(&mut self, tokens: &[SonioxToken], now: Instant) -> EndpointAutoStopSignal {
+        if self.fired {
+            return EndpointAutoStopSignal::None;
+        }
+
+        let was_pending = self.pending_since.is_some();
+        for token in tokens {
+            match token.text.as_str() {
+                "<end>" => self.consecutive_endpoints += 1,
+                "<fin>" => {}
+                text if !text.trim().is_empty() => {
+                    self.consecutive_endpoints = 0;
+                    self.pending_since = None;
+                }
+                _ => {}
+            }
+        }
+
+        if was_pending && self.pending_since.is_none() {
+            return EndpointAutoStopSignal::Cancelled;
+        }
+        if self.pending_since.is_none()
+            && self.consecutive_endpoints >= self.required_endpoints
+            && now >= self.armed_at
+        {
+            self.pending_since = Some(now);
+            return EndpointAutoStopSignal::Pending;
+        }
+        EndpointAutoStopSignal::None
+    }
+
+    fn stop_deadline(&self) -> Option<Instant> {
+        if self.fired {
+            return None;
+        }
+        self.pending_since
+            .map(|pending_since| pending_since + ENDPOINT_AUTO_STOP_GRACE)
+    }
+
+    fn mark_fired(&mut self) {
+        self.fired = true;
+        self.pending_since = None;
+    }
+}
+
+fn emit_auto_stop_event(app_handle: &AppHandle, binding_id: &str, state: &'static str) {
+    let _ = app_handle.emit(
+        "soniox-live-auto-stop",
+        SonioxAutoStopEvent {
+            binding_id: binding_id.to_string(),
+            state,
+            grace_ms: ENDPOINT_AUTO_STOP_GRACE.as_millis() as u64,
+        },
+    );
+}
+
 #[derive(Serialize)]
 struct SonioxStartRequest {
     api_key: String,
@@ -81,6 +198,8 @@ struct SonioxToken {
     is_final: bool,
     #[serde(default)]
     speaker: Option<Value>,
+    #[serde(default)]
+    confidence: Option<f32>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -93,8 +212,94 @@ struct SonioxResponse {
     error_code: Option<u16>,
     #[serde(default)]
     error_message: Option<String>,
+    /// Milliseconds of audio covered by final tokens so far.
+    #[serde(default)]
+    final_audio_proc_ms: Option<u64>,
+}
+
+/// Error reported by Soniox itself; reconnecting would not help.
+#[derive(Debug)]
+struct SonioxServerError(String);
+
+impl std::fmt::Display for SonioxServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SonioxServerError {}
+
+/// Whether a dropped session gets another connection attempt.
+fn should_reconnect(err: &anyhow::Error, failed_attempts: u32, reconnect_attempts: u32) -> bool {
+    err.downcast_ref::<SonioxServerError>().is_none() && failed_attempts < reconnect_attempts
+}
+
+/// Audio streamed on the current socket that Soniox has not finalized yet,
+/// plus anything captured while reconnecting. Replaying it on a new socket
+/// recovers the lost tail without re-emitting already final text.
+#[derive(Default)]
+struct UnfinalizedAudio {
+    chunks: VecDeque<Vec<u8>>,
+    /// Stream offset (bytes) of the first buffered byte.
+    start_offset: u64,
+    len: u64,
+}
+
+impl UnfinalizedAudio {
+    fn push(&mut self, chunk: &[u8]) {
+        self.chunks.push_back(chunk.to_vec());
+        self.len += chunk.len() as u64;
+        while self.len > MAX_UNFINALIZED_AUDIO_BYTES {
+            let Some(dropped) = self.chunks.pop_front() else {
+                break;
+            };
+            self.start_offset += dropped.len() as u64;
+            self.len -= dropped.len() as u64;
+        }
+    }
+
+    fn mark_finalized(&mut self, final_audio_proc_ms: u64) {
+        let finalized_offset = final_audio_proc_ms * PCM_BYTES_PER_MS;
+        while let Some(front) = self.chunks.front_mut() {
+            if self.start_offset >= finalized_offset {
+                break;
+            }
+            let skip = finalized_offset - self.start_offset;
+            if skip >= front.len() as u64 {
+                let dropped = front.len() as u64;
+                self.chunks.pop_front();
+                self.start_offset += dropped;
+                self.len -= dropped;
+            } else {
+                front.drain(..skip as usize);
+                self.start_offset += skip;
+                self.len -= skip;
+            }
+        }
+    }
+
+    /// Offsets restart from zero on the new socket.
+    fn take_for_replay(&mut self) -> Vec<Vec<u8>> {
+        std::mem::take(self).chunks.into()
+    }
+}
+
+/// Session state that survives reconnects.
+struct SessionLoopState {
+    unfinalized_audio: UnfinalizedAudio,
+    endpoint_auto_stop: Option<EndpointAutoStopTracker>,
+    finish_requested: bool,
+    /// The current socket delivered a payload; resets the reconnect budget.
+    received_payload: bool,
+    reconnecting: Arc<AtomicBool>,
+    /// Confidence of final tokens, shared with `ActiveSession` for finish.
+    confidence: Arc<Mutex<ConfidenceAccumulator>>,
+    /// Speaker blocks of final tokens, shared with `ActiveSession` for finish.
+    speaker_blocks: Arc<Mutex<Vec<RawSpeakerBlock>>>,
 }
 
+type SonioxSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
 fn parse_soniox_speaker_key(value: &Value) -> Option<String> {
     if let Some(speaker) = value.as_str() {
         let trimmed = speaker.trim();
@@ -181,6 +386,20 @@ fn build_soniox_raw_speaker_blocks(tokens: &[SonioxToken], is_final: bool) -> Ve
     blocks
 }
 
+/// Appends the blocks of a new final chunk, continuing the last block when
+/// the same speaker keeps talking across chunks.
+fn append_speaker_blocks(blocks: &mut Vec<RawSpeakerBlock>, chunk: Vec<RawSpeakerBlock>) {
+    for block in chunk {
+        match blocks.last_mut() {
+            Some(last) if last.speaker_key == block.speaker_key => {
+                last.text.push(' ');
+                last.text.push_str(&block.text);
+            }
+            _ => blocks.push(block),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum ControlMessage {
     Audio(Vec<u8>),
@@ -194,6 +413,9 @@ struct ActiveSession {
     audio_tx: mpsc::Sender<Vec<u8>>,
     control_tx: mpsc::UnboundedSender<ControlMessage>,
     final_text: Arc<Mutex<String>>,
+    reconnecting: Arc<AtomicBool>,
+    confidence: Arc<Mutex<ConfidenceAccumulator>>,
+    speaker_blocks: Arc<Mutex<Vec<RawSpeakerBlock>>>,
     join_handle: JoinHandle<Result<()>>,
 }
 
@@ -211,6 +433,10 @@ pub struct SonioxRealtimeManager {
     active_session: Mutex<Option<ActiveSession>>,
     session_params: Mutex<Option<SessionParams>>,
     pending_audio: Mutex<Vec<Vec<u8>>>,
+    /// Average final-token confidence of the last finished session.
+    last_confidence: Mutex<Option<f32>>,
+    /// Speaker blocks of the last finished session.
+    last_speaker_blocks: Mutex<Vec<RawSpeakerBlock>>,
 }
 
 impl SonioxRealtimeManager {
@@ -220,6 +446,8 @@ impl SonioxRealtimeManager {
             active_session: Mutex::new(None),
             session_params: Mutex::new(None),
             pending_audio: Mutex::new(Vec::new()),
+            last_confidence: Mutex::new(None),
+            last_speaker_blocks: Mutex::new(Vec::new()),
         })
     }
 
@@ -330,7 +558,10 @@ impl SonioxRealtimeManager {
             keepalive_interval_seconds,
             context,
             show_preview,
+            auto_stop_endpoint_count,
+            reconnect_attempts,
         } = options;
+        let reconnect_attempts = reconnect_attempts.min(MAX_RECONNECT_ATTEMPTS);
 
         let mut keepalive_interval_seconds = keepalive_interval_seconds;
         keepalive_interval_seconds = keepalive_interval_seconds.clamp(
@@ -367,6 +598,12 @@ impl SonioxRealtimeManager {
         let (control_tx, control_rx) = mpsc::unbounded_channel::<ControlMessage>();
         let final_text = Arc::new(Mutex::new(String::new()));
         let final_text_for_task = Arc::clone(&final_text);
+        let reconnecting = Arc::new(AtomicBool::new(false));
+        let reconnecting_for_task = Arc::clone(&reconnecting);
+        let confidence = Arc::new(Mutex::new(ConfidenceAccumulator::default()));
+        let confidence_for_task = Arc::clone(&confidence);
+        let speaker_blocks = Arc::new(Mutex::new(Vec::new()));
+        let speaker_blocks_for_task = Arc::clone(&speaker_blocks);
         let start_payload_for_task = start_payload;
         let app_handle_for_task = self.app_handle.clone();
         let binding_id_for_task = binding_id.to_string();
@@ -377,34 +614,84 @@ impl SonioxRealtimeManager {
 
         let join_handle = tauri::async_runtime::spawn(async move {
             let session_result: Result<()> = async {
-                let (stream, _) = timeout(
-                    Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
-                    connect_async(SONIOX_WS_URL),
-                )
-                .await
-                .map_err(|_| anyhow!("Timed out while connecting to Soniox WebSocket"))?
-                .map_err(|e| anyhow!("Failed to connect to Soniox WebSocket: {}", e))?;
-
-                let (mut write, mut read) = stream.split();
-                write
-                    .send(Message::Text(start_payload_for_task.into()))
-                    .await
-                    .map_err(|e| anyhow!("Failed to send Soniox start request: {}", e))?;
-
-                Self::run_session_loop(
-                    &mut write,
-                    &mut read,
-                    audio_rx,
-                    control_rx,
-                    final_text_for_task,
-                    keepalive_interval_seconds,
-                    app_handle_for_task.clone(),
-                    binding_id_for_task.clone(),
-                    live_sound_session_id,
-                    show_preview,
-                    on_final_chunk,
-                )
-                .await
+                let mut audio_rx = audio_rx;
+                let mut control_rx = control_rx;
+                let mut state = SessionLoopState {
+                    unfinalized_audio: UnfinalizedAudio::default(),
+                    endpoint_auto_stop: auto_stop_endpoint_count
+                        .map(|count| EndpointAutoStopTracker::new(count, Instant::now())),
+                    finish_requested: false,
+                    received_payload: false,
+                    reconnecting: reconnecting_for_task,
+                    confidence: confidence_for_task,
+                    speaker_blocks: speaker_blocks_for_task,
+                };
+                let mut stream = Self::connect_socket(&start_payload_for_task).await?;
+                let mut reconnected = false;
+                let mut failed_attempts = 0u32;
+
+                loop {
+                    let (mut write, mut read) = stream.split();
+                    let result = async {
+                        if reconnected {
+                            Self::replay_unfinalized_audio(&mut write, &mut state).await?;
+                        }
+                        Self::run_session_loop(
+                            &mut write,
+                            &mut read,
+                            &mut audio_rx,
+                            &mut control_rx,
+                            &mut state,
+                            Arc::clone(&final_text_for_task),
+                            keepalive_interval_seconds,
+                            app_handle_for_task.clone(),
+                            binding_id_for_task.clone(),
+                            live_sound_session_id,
+                            show_preview,
+                            on_final_chunk.clone(),
+                        )
+                        .await
+                    }
+                    .await;
+
+                    let mut err = match result {
+                        Ok(()) => return Ok(()),
+                        Err(err) => err,
+                    };
+                    if std::mem::take(&mut state.received_payload) {
+                        failed_attempts = 0;
+                    }
+
+                    stream = loop {
+                        if !should_reconnect(&err, failed_attempts, reconnect_attempts) {
+                            return Err(err);
+                        }
+                        failed_attempts += 1;
+                        state.reconnecting.store(true, Ordering::Relaxed);
+                        warn!(
+                            "Soniox live connection lost (binding='{}'): {}; reconnecting (attempt {}/{})",
+                            binding_id_for_task, err, failed_attempts, reconnect_attempts
+                        );
+                        match Self::reconnect_while_buffering(
+                            &start_payload_for_task,
+                            failed_attempts,
+                            &mut audio_rx,
+                            &mut control_rx,
+                            &mut state,
+                        )
+                        .await
+                        {
+                            Ok(Some(stream)) => break stream,
+                            Ok(None) => return Ok(()),
+                            Err(connect_err) => err = connect_err,
+                        }
+                    };
+                    reconnected = true;
+                    info!(
+                        "Reconnected Soniox live session for binding '{}'",
+                        binding_id_for_task
+                    );
+                }
             }
             .await;
 
@@ -458,6 +745,9 @@ impl SonioxRealtimeManager {
             audio_tx,
             control_tx,
             final_text,
+            reconnecting,
+            confidence,
+            speaker_blocks,
             join_handle,
         };
         *active_session_guard = Some(active);
@@ -494,15 +784,103 @@ impl SonioxRealtimeManager {
         self.active_session.lock().is_some()
     }
 
+    async fn connect_socket(start_payload: &str) -> Result<SonioxSocket> {
+        let (mut stream, _) = timeout(
+            Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            connect_async(SONIOX_WS_URL),
+        )
+        .await
+        .map_err(|_| anyhow!("Timed out while connecting to Soniox WebSocket"))?
+        .map_err(|e| anyhow!("Failed to connect to Soniox WebSocket: {}", e))?;
+
+        stream
+            .send(Message::Text(start_payload.to_string().into()))
+            .await
+            .map_err(|e| anyhow!("Failed to send Soniox start request: {}", e))?;
+        Ok(stream)
+    }
+
+    /// Waits out the backoff and opens a new socket while still accepting
+    /// audio and control messages. Returns `None` if cancelled meanwhile.
+    async fn reconnect_while_buffering(
+        start_payload: &str,
+        attempt: u32,
+        audio_rx: &mut mpsc::Receiver<Vec<u8>>,
+        control_rx: &mut mpsc::UnboundedReceiver<ControlMessage>,
+        state: &mut SessionLoopState,
+    ) -> Result<Option<SonioxSocket>> {
+        let connect = async {
+            tokio::time::sleep(RECONNECT_BACKOFF * attempt).await;
+            Self::connect_socket(start_payload).await
+        };
+        tokio::pin!(connect);
+
+        loop {
+            tokio::select! {
+                result = &mut connect => return result.map(Some),
+                Some(audio_chunk) = audio_rx.recv() => {
+                    state.unfinalized_audio.push(&audio_chunk);
+                }
+                Some(control) = control_rx.recv() => {
+                    match control {
+                        ControlMessage::Audio(bytes) => state.unfinalized_audio.push(&bytes),
+                        // Finalize is resent together with Finish after replay.
+                        ControlMessage::Finalize => {}
+                        ControlMessage::Finish => state.finish_requested = true,
+                        ControlMessage::Cancel => return Ok(None),
+                    }
+                }
+            }
+        }
+    }
+
+    async fn replay_unfinalized_audio<S>(write: &mut S, state: &mut SessionLoopState) -> Result<()>
+    where
+        S: Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        for audio_chunk in state.unfinalized_audio.take_for_replay() {
+            state.unfinalized_audio.push(&audio_chunk);
+            write
+                .send(Message::Binary(audio_chunk.into()))
+                .await
+                .map_err(|e| anyhow!("Failed to replay audio to Soniox: {}", e))?;
+        }
+        if state.finish_requested {
+            Self::send_finish(write).await?;
+        }
+        Ok(())
+    }
+
+    async fn send_finish<S>(write: &mut S) -> Result<()>
+    where
+        S: Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        write
+            .send(Message::Text(r#"{"type":"finalize"}"#.to_string().into()))
+            .await
+            .map_err(|e| anyhow!("Failed to send Soniox finalize control message: {}", e))?;
+        // Empty frame gracefully closes the stream.
+        write
+            .send(Message::Binary(Vec::new().into()))
+            .await
+            .map_err(|e| anyhow!("Failed to finalize Soniox audio stream: {}", e))?;
+        write
+            .flush()
+            .await
+            .map_err(|e| anyhow!("Failed to flush Soniox WebSocket stream: {}", e))
+    }
+
     async fn drain_audio_queue<S>(
         write: &mut S,
         audio_rx: &mut mpsc::Receiver<Vec<u8>>,
+        unfinalized_audio: &mut UnfinalizedAudio,
         last_audio_or_control: &mut Instant,
     ) -> Result<()>
     where
         S: Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
     {
         while let Ok(audio_chunk) = audio_rx.try_recv() {
+            unfinalized_audio.push(&audio_chunk);
             write
                 .send(Message::Binary(audio_chunk.into()))
                 .await
@@ -516,8 +894,9 @@ impl SonioxRealtimeManager {
     async fn run_session_loop<S, R>(
         write: &mut S,
         read: &mut R,
-        mut audio_rx: mpsc::Receiver<Vec<u8>>,
-        mut control_rx: mpsc::UnboundedReceiver<ControlMessage>,
+        audio_rx: &mut mpsc::Receiver<Vec<u8>>,
+        control_rx: &mut mpsc::UnboundedReceiver<ControlMessage>,
+        state: &mut SessionLoopState,
         final_text: Arc<Mutex<String>>,
         keepalive_interval_seconds: u32,
         app_handle: AppHandle,
@@ -539,22 +918,28 @@ impl SonioxRealtimeManager {
 
         let mut finished = false;
         loop {
+            let auto_stop_deadline = state
+                .endpoint_auto_stop
+                .as_ref()
+                .and_then(EndpointAutoStopTracker::stop_deadline);
             tokio::select! {
                 Some(control) = control_rx.recv() => {
                     match control {
                         ControlMessage::Audio(bytes) => {
+                            state.unfinalized_audio.push(&bytes);
                             write.send(Message::Binary(bytes.into())).await
                                 .map_err(|e| anyhow!("Failed to send Soniox queued audio chunk: {}", e))?;
                             last_audio_or_control = Instant::now();
                         }
                         ControlMessage::Finalize => {
-                            Self::drain_audio_queue(write, &mut audio_rx, &mut last_audio_or_control).await?;
+                            Self::drain_audio_queue(write, audio_rx, &mut state.unfinalized_audio, &mut last_audio_or_control).await?;
                             write.send(finalize_payload.clone()).await
                                 .map_err(|e| anyhow!("Failed to send Soniox finalize control message: {}", e))?;
                             last_audio_or_control = Instant::now();
                         }
                         ControlMessage::Finish => {
-                            Self::drain_audio_queue(write, &mut audio_rx, &mut last_audio_or_control).await?;
+                            state.finish_requested = true;
+                            Self::drain_audio_queue(write, audio_rx, &mut state.unfinalized_audio, &mut last_audio_or_control).await?;
                             // Empty frame gracefully closes the stream.
                             write.send(Message::Binary(Vec::new().into())).await
                                 .map_err(|e| anyhow!("Failed to finalize Soniox audio stream: {}", e))?;
@@ -568,6 +953,7 @@ impl SonioxRealtimeManager {
                     }
                 }
                 Some(audio_chunk) = audio_rx.recv() => {
+                    state.unfinalized_audio.push(&audio_chunk);
                     write.send(Message::Binary(audio_chunk.into())).await
                         .map_err(|e| anyhow!("Failed to send audio chunk to Soniox: {}", e))?;
                     last_audio_or_control = Instant::now();
@@ -587,12 +973,34 @@ impl SonioxRealtimeManager {
                         Message::Text(text) => {
                             let payload: SonioxResponse = serde_json::from_str(text.as_ref()).map_err(|e| {
                                 let preview: String = text.chars().take(200).collect();
-                                anyhow!("Invalid Soniox WebSocket payload: {} (body: {})", e, preview)
+                                anyhow::Error::new(SonioxServerError(format!("Invalid Soniox WebSocket payload: {} (body: {})", e, preview)))
                             })?;
 
                             if let Some(code) = payload.error_code {
                                 let message = payload.error_message.unwrap_or_else(|| "Unknown Soniox WebSocket error".to_string());
-                                return Err(anyhow!("Soniox WebSocket error {}: {}", code, message));
+                                return Err(anyhow::Error::new(SonioxServerError(format!("Soniox WebSocket error {}: {}", code, message))));
+                            }
+
+                            if !state.received_payload {
+                                state.received_payload = true;
+                                state.reconnecting.store(false, Ordering::Relaxed);
+                            }
+                            if let Some(final_audio_proc_ms) = payload.final_audio_proc_ms {
+                                state.unfinalized_audio.mark_finalized(final_audio_proc_ms);
+                            }
+
+                            if let Some(tracker) = state.endpoint_auto_stop.as_mut() {
+                                match tracker.observe(&payload.tokens, Instant::now()) {
+                                    EndpointAutoStopSignal::Pending => {
+                                        debug!("Soniox endpoint reached; auto-stopping binding '{}' after grace period", binding_id);
+                                        emit_auto_stop_event(&app_handle, &binding_id, "pending");
+                                    }
+                                    EndpointAutoStopSignal::Cancelled => {
+                                        debug!("Speech resumed; cancelled Soniox endpoint auto-stop");
+                                        emit_auto_stop_event(&app_handle, &binding_id, "cancelled");
+                                    }
+                                    EndpointAutoStopSignal::None => {}
+                                }
                             }
 
                             let is_finished_payload = payload.finished;
@@ -612,6 +1020,9 @@ impl SonioxRealtimeManager {
                                 if token.is_final {
                                     chunk_text.push_str(&token.text);
                                     final_token_count += 1;
+                                    if let Some(score) = token.confidence {
+                                        state.confidence.lock().push(score);
+                                    }
                                 } else {
                                     interim_text.push_str(&token.text);
                                     non_final_token_count += 1;
@@ -627,6 +1038,10 @@ impl SonioxRealtimeManager {
                                     let mut guard = final_text.lock();
                                     guard.push_str(&chunk_text);
                                 }
+                                append_speaker_blocks(
+                                    &mut state.speaker_blocks.lock(),
+                                    build_soniox_raw_speaker_blocks(&payload.tokens, true),
+                                );
                                 if let Some(cb) = &on_final_chunk {
                                     cb(chunk_text.clone());
                                 }
@@ -716,6 +1131,25 @@ impl SonioxRealtimeManager {
                         _ => {}
                     }
                 }
+                _ = tokio::time::sleep_until(tokio::time::Instant::from_std(
+                    auto_stop_deadline.unwrap_or_else(Instant::now),
+                )), if auto_stop_deadline.is_some() => {
+                    if let Some(tracker) = state.endpoint_auto_stop.as_mut() {
+                        tracker.mark_fired();
+                    }
+                    info!("Auto-stopping Soniox live recording for binding '{}' after endpoint", binding_id);
+                    emit_auto_stop_event(&app_handle, &binding_id, "stopping");
+                    // Stopping finishes this session, so run it outside the loop.
+                    let app_for_stop = app_handle.clone();
+                    let binding_for_stop = binding_id.clone();
+                    tauri::async_runtime::spawn(async move {
+                        crate::recording_auto_stop::stop_recording_for_binding(
+                            &app_for_stop,
+                            &binding_for_stop,
+                            "soniox_endpoint",
+                        );
+                    });
+                }
                 _ = keepalive_tick.tick() => {
                     if Instant::now().duration_since(last_audio_or_control)
                         >= Duration::from_secs(keepalive_interval_seconds as u64)
@@ -823,10 +1257,17 @@ impl SonioxRealtimeManager {
             binding_id,
             control_tx,
             final_text,
+            reconnecting,
+            confidence,
+            speaker_blocks,
             mut join_handle,
             ..
         } = session;
-        let read_final_text = || -> String { final_text.lock().trim().to_string() };
+        let read_final_text = || -> String {
+            *self.last_confidence.lock() = confidence.lock().average();
+            *self.last_speaker_blocks.lock() = speaker_blocks.lock().clone();
+            final_text.lock().trim().to_string()
+        };
 
         // Manual finalization first, then graceful stream end.
         let _ = control_tx.send(ControlMessage::Finalize);
@@ -835,7 +1276,26 @@ impl SonioxRealtimeManager {
         // Bound stop/finalization wait to a short, predictable window so
         // stop action can return to idle promptly even on unstable networks.
         let wait_ms = timeout_ms.clamp(100, 20000) as u64;
-        let join_result = timeout(Duration::from_millis(wait_ms), &mut join_handle).await;
+        let mut join_result = timeout(Duration::from_millis(wait_ms), &mut join_handle).await;
+
+        // A reconnect in progress replays unfinalized audio, so keep waiting
+        // until it has flushed (one more window once it reports back).
+        let reconnect_deadline = Instant::now() + MAX_RECONNECT_FINISH_WAIT;
+        let mut was_reconnecting = false;
+        while join_result.is_err() && Instant::now() < reconnect_deadline {
+            let is_reconnecting = reconnecting.load(Ordering::Relaxed);
+            if !is_reconnecting && !was_reconnecting {
+                break;
+            }
+            was_reconnecting = is_reconnecting;
+            debug!(
+                "Waiting for reconnected Soniox live session to flush (binding='{}')",
+                binding_id
+            );
+            let extra_wait = Duration::from_millis(wait_ms)
+                .max(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS));
+            join_result = timeout(extra_wait, &mut join_handle).await;
+        }
 
         match join_result {
             Ok(Ok(Ok(()))) => {}
@@ -905,6 +1365,18 @@ impl SonioxRealtimeManager {
         Ok(text)
     }
 
+    /// Average final-token confidence of the last finished session, if Soniox
+    /// reported any. Clears it so it never carries over to a later take.
+    pub fn take_last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().take()
+    }
+
+    /// Speaker blocks of the last finished session; empty unless diarization
+    /// was on. Cleared like the confidence.
+    pub fn take_last_speaker_blocks(&self) -> Vec<RawSpeakerBlock> {
+        std::mem::take(&mut *self.last_speaker_blocks.lock())
+    }
+
     pub fn cancel(&self) {
         let hide_preview_if_needed = |binding_id: Option<&str>| {
             if binding_id == Some(crate::actions::LIVE_SOUND_TRANSCRIPTION_BINDING_ID) {
@@ -942,3 +1414,199 @@ fn frame_16khz_mono_to_pcm_s16le_bytes(frame: &[f32]) -> Vec<u8> {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(text: &str) -> SonioxToken {
+        SonioxToken {
+            text: text.to_string(),
+            is_final: true,
+            speaker: None,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn endpoint_auto_stop_waits_for_arm_delay_and_required_endpoints() {
+        let start = Instant::now();
+        let mut tracker = EndpointAutoStopTracker::new(2, start);
+
+        let early = start + Duration::from_secs(1);
+        assert_eq!(
+            tracker.observe(&[token("hi"), token("<end>")], early),
+            EndpointAutoStopSignal::None
+        );
+        assert_eq!(
+            tracker.observe(&[token("<end>")], early),
+            EndpointAutoStopSignal::None
+        );
+
+        let armed = start + ENDPOINT_AUTO_STOP_ARM_AFTER;
+        assert_eq!(tracker.observe(&[], armed), EndpointAutoStopSignal::Pending);
+        assert_eq!(
+            tracker.stop_deadline(),
+            Some(armed + ENDPOINT_AUTO_STOP_GRACE)
+        );
+
+        tracker.mark_fired();
+        assert_eq!(tracker.stop_deadline(), None);
+        assert_eq!(
+            tracker.observe(&[token("<end>")], armed),
+            EndpointAutoStopSignal::None
+        );
+    }
+
+    #[test]
+    fn new_speech_cancels_pending_endpoint_auto_stop() {
+        let start = Instant::now();
+        let now = start + ENDPOINT_AUTO_STOP_ARM_AFTER;
+        let mut tracker = EndpointAutoStopTracker::new(1, start);
+
+        assert_eq!(
+            tracker.observe(&[token("<end>")], now),
+            EndpointAutoStopSignal::Pending
+        );
+        assert_eq!(
+            tracker.observe(&[token(" "), token("<fin>")], now),
+            EndpointAutoStopSignal::None
+        );
+        assert_eq!(
+            tracker.observe(&[token("more")], now),
+            EndpointAutoStopSignal::Cancelled
+        );
+        assert_eq!(tracker.stop_deadline(), None);
+    }
+
+    #[test]
+    fn unfinalized_audio_keeps_only_the_tail_after_final_tokens() {
+        let mut audio = UnfinalizedAudio::default();
+        audio.push(&[1; 64]);
+        audio.push(&[2; 64]);
+        audio.push(&[3; 64]);
+
+        // 3 ms = 96 bytes: the first chunk and half of the second are final.
+        audio.mark_finalized(3);
+        audio.mark_finalized(1);
+        let replay = audio.take_for_replay();
+        assert_eq!(replay, vec![vec![2; 32], vec![3; 64]]);
+
+        // Offsets restart on the new socket.
+        for chunk in &replay {
+            audio.push(chunk);
+        }
+        audio.mark_finalized(1);
+        assert_eq!(audio.take_for_replay(), vec![vec![3; 64]]);
+    }
+
+    fn loop_state() -> SessionLoopState {
+        SessionLoopState {
+            unfinalized_audio: UnfinalizedAudio::default(),
+            endpoint_auto_stop: None,
+            finish_requested: false,
+            received_payload: false,
+            reconnecting: Arc::new(AtomicBool::new(true)),
+            confidence: Arc::new(Mutex::new(ConfidenceAccumulator::default())),
+            speaker_blocks: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn reconnect_stops_on_server_errors_and_spent_budget() {
+        let dropped = anyhow!("connection reset");
+        assert!(should_reconnect(&dropped, 0, 3));
+        assert!(should_reconnect(&dropped, 2, 3));
+        assert!(!should_reconnect(&dropped, 3, 3));
+        assert!(!should_reconnect(&dropped, 0, 0));
+
+        let rejected = anyhow::Error::new(SonioxServerError("401 invalid key".to_string()));
+        assert!(!should_reconnect(&rejected, 0, 3));
+    }
+
+    #[test]
+    fn reconnect_buffers_audio_and_finish_until_cancelled() {
+        let (_audio_tx, mut audio_rx) = mpsc::channel(AUDIO_QUEUE_CAPACITY);
+        let (control_tx, mut control_rx) = mpsc::unbounded_channel();
+        control_tx.send(ControlMessage::Audio(vec![7; 64])).unwrap();
+        control_tx.send(ControlMessage::Finalize).unwrap();
+        control_tx.send(ControlMessage::Finish).unwrap();
+        control_tx.send(ControlMessage::Cancel).unwrap();
+        let mut state = loop_state();
+
+        let result = runtime().block_on(SonioxRealtimeManager::reconnect_while_buffering(
+            "{}",
+            1,
+            &mut audio_rx,
+            &mut control_rx,
+            &mut state,
+        ));
+
+        assert!(matches!(result, Ok(None)));
+        assert!(state.finish_requested);
+        assert_eq!(state.unfinalized_audio.take_for_replay(), vec![vec![7; 64]]);
+    }
+
+    #[test]
+    fn speaker_blocks_continue_across_final_chunks() {
+        let block = |speaker: &str, text: &str| RawSpeakerBlock {
+            speaker_key: speaker.to_string(),
+            default_name: None,
+            text: text.to_string(),
+        };
+        let mut blocks = Vec::new();
+        append_speaker_blocks(&mut blocks, vec![block("1", "Hello there.")]);
+        append_speaker_blocks(
+            &mut blocks,
+            vec![block("1", "How are you?"), block("2", "Fine.")],
+        );
+        append_speaker_blocks(&mut blocks, vec![block("1", "Good.")]);
+
+        assert_eq!(
+            blocks,
+            vec![
+                block("1", "Hello there. How are you?"),
+                block("2", "Fine."),
+                block("1", "Good."),
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_resends_unfinalized_audio_then_finish() {
+        let mut state = loop_state();
+        state.unfinalized_audio.push(&[1; 64]);
+        state.unfinalized_audio.push(&[2; 64]);
+        state.unfinalized_audio.mark_finalized(2);
+        state.finish_requested = true;
+        let mut sink = Vec::<Message>::new().sink_map_err(
+            |never: std::convert::Infallible| -> tokio_tungstenite::tungstenite::Error {
+                match never {}
+            },
+        );
+
+        runtime()
+            .block_on(SonioxRealtimeManager::replay_unfinalized_audio(
+                &mut sink, &mut state,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            sink.into_inner(),
+            vec![
+                Message::Binary(vec![2; 64].into()),
+                Message::Text(r#"{"type":"finalize"}"#.to_string().into()),
+                Message::Binary(Vec::new().into()),
+            ]
+        );
+        // The replayed audio stays buffered until the new socket finalizes it.
+        assert_eq!(state.unfinalized_audio.take_for_replay(), vec![vec![2; 64]]);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::audio_toolkit::encode_wav_bytes;
 use crate::file_transcription_diarization::RawSpeakerBlock;
 use crate::settings::SonioxContext;
+use crate::transcription_confidence::ConfidenceAccumulator;
 use anyhow::{anyhow, Result};
 use futures_util::{SinkExt, StreamExt};
 use log::{debug, info, warn};
@@ -40,6 +41,8 @@ struct SonioxStartRequest {
     language_hints: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     context: Option<SonioxContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_speaker_diarization: Option<bool>,
     // This fallback WS path keeps endpoint detection enabled to ensure the
     // server emits proper completion/finalization signals for full-clip uploads.
     enable_endpoint_detection: bool,
@@ -50,6 +53,10 @@ struct SonioxToken {
     text: String,
     #[serde(default)]
     is_final: bool,
+    #[serde(default)]
+    speaker: Option<Value>,
+    #[serde(default)]
+    confidence: Option<f32>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -118,12 +125,26 @@ struct SonioxAsyncTranscriptToken {
     text: String,
     #[serde(default)]
     speaker: Option<Value>,
+    #[serde(default)]
+    confidence: Option<f32>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct SonioxAsyncTranscript {
     pub text: String,
     pub speaker_blocks: Vec<RawSpeakerBlock>,
+    /// Average token confidence, if Soniox reported any.
+    pub confidence: Option<f32>,
+}
+
+impl From<String> for SonioxAsyncTranscript {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            speaker_blocks: Vec::new(),
+            confidence: None,
+        }
+    }
 }
 
 pub struct SonioxSttManager {
@@ -425,7 +446,8 @@ impl SonioxSttManager {
         audio_data: &[u8],
         language_hints: Option<Vec<String>>,
         context: Option<SonioxContext>,
-    ) -> Result<String> {
+        enable_speaker_diarization: bool,
+    ) -> Result<SonioxAsyncTranscript> {
         let started = Instant::now();
         self.ensure_not_cancelled(operation_id)?;
         Self::ensure_within_timeout(started, timeout_seconds)?;
@@ -451,6 +473,7 @@ impl SonioxSttManager {
             num_channels: Some(SONIOX_FALLBACK_CHANNELS),
             language_hints,
             context,
+            enable_speaker_diarization: enable_speaker_diarization.then_some(true),
             // This manager's WS mode is a non-live full-clip upload fallback.
             // We currently keep this fixed instead of exposing the full live
             // tuning surface from soniox_realtime.rs.
@@ -522,7 +545,8 @@ impl SonioxSttManager {
         );
 
         let read_started_at = Instant::now();
-        let mut final_tokens: Vec<String> = Vec::new();
+        let mut final_tokens: Vec<SonioxAsyncTranscriptToken> = Vec::new();
+        let mut confidence = ConfidenceAccumulator::default();
         let mut finished = false;
         let mut text_frame_count = 0usize;
         let mut final_token_count = 0usize;
@@ -581,7 +605,14 @@ impl SonioxSttManager {
                             continue;
                         }
                         if !token.text.is_empty() && token.text != "<end>" {
-                            final_tokens.push(token.text);
+                            if let Some(score) = token.confidence {
+                                confidence.push(score);
+                            }
+                            final_tokens.push(SonioxAsyncTranscriptToken {
+                                text: token.text,
+                                speaker: token.speaker,
+                                confidence: token.confidence,
+                            });
                         }
                     }
 
@@ -635,7 +666,15 @@ impl SonioxSttManager {
             ));
         }
 
-        let final_text = final_tokens.concat();
+        let final_text: String = final_tokens
+            .iter()
+            .map(|token| token.text.as_str())
+            .collect();
+        let speaker_blocks = if enable_speaker_diarization {
+            Self::build_async_speaker_blocks(&final_tokens)
+        } else {
+            Vec::new()
+        };
         info!(
             "Soniox WebSocket fallback timings: total_ms={}, connect_ms={}, build_start_payload_ms={}, send_start_ms={}, upload_ms={}, send_finalize_ms={}, send_end_marker_ms={}, flush_ms={}, wait_finished_ms={}, first_response_wait_ms={:?}, first_final_token_wait_ms={:?}, audio_format=pcm_s16le, audio_bytes={}, audio_chunks={}, text_frames={}, final_tokens={}, non_final_tokens={}, output_len={}, soniox_audio_final_proc_ms={:?}, soniox_audio_total_proc_ms={:?}",
             started.elapsed().as_millis(),
@@ -659,7 +698,11 @@ impl SonioxSttManager {
             soniox_audio_total_proc_ms
         );
 
-        Ok(final_text)
+        Ok(SonioxAsyncTranscript {
+            text: final_text,
+            speaker_blocks,
+            confidence: confidence.average(),
+        })
     }
 
     async fn transcribe_once_ws_with_callback<F>(
@@ -672,7 +715,7 @@ impl SonioxSttManager {
         language_hints: Option<Vec<String>>,
         context: Option<SonioxContext>,
         on_final_chunk: &mut F,
-    ) -> Result<String>
+    ) -> Result<SonioxAsyncTranscript>
     where
         F: FnMut(&str) -> Result<()>,
     {
@@ -698,6 +741,7 @@ impl SonioxSttManager {
             num_channels: Some(SONIOX_FALLBACK_CHANNELS),
             language_hints,
             context,
+            enable_speaker_diarization: None,
             enable_endpoint_detection: true,
         };
 
@@ -736,6 +780,7 @@ impl SonioxSttManager {
             .map_err(|e| anyhow!("Failed to flush Soniox WebSocket stream: {}", e))?;
 
         let mut final_tokens: Vec<String> = Vec::new();
+        let mut confidence = ConfidenceAccumulator::default();
         let mut finished = false;
 
         loop {
@@ -780,6 +825,9 @@ impl SonioxSttManager {
                         if token.text.is_empty() || token.text == "<end>" {
                             continue;
                         }
+                        if let Some(score) = token.confidence {
+                            confidence.push(score);
+                        }
                         chunk_text.push_str(&token.text);
                         final_tokens.push(token.text);
                     }
@@ -830,7 +878,11 @@ impl SonioxSttManager {
             ));
         }
 
-        Ok(final_tokens.concat())
+        Ok(SonioxAsyncTranscript {
+            text: final_tokens.concat(),
+            speaker_blocks: Vec::new(),
+            confidence: confidence.average(),
+        })
     }
 
     async fn upload_file_impl(&self, api_key: &str, wav_data: &[u8]) -> Result<String> {
@@ -979,9 +1031,14 @@ impl SonioxSttManager {
             )
         })?;
 
+        let mut confidence = ConfidenceAccumulator::default();
+        for score in payload.tokens.iter().filter_map(|token| token.confidence) {
+            confidence.push(score);
+        }
         Ok(SonioxAsyncTranscript {
             text: payload.text,
             speaker_blocks: Self::build_async_speaker_blocks(&payload.tokens),
+            confidence: confidence.average(),
         })
     }
 
@@ -1032,6 +1089,7 @@ impl SonioxSttManager {
 
     // Non-live shortcut fallback path: collect full audio locally, then
     // transcribe via Soniox WebSocket in one request/response session.
+    // Speaker blocks are only filled when diarization is requested.
     pub async fn transcribe(
         &self,
         operation_id: Option<u64>,
@@ -1041,9 +1099,10 @@ impl SonioxSttManager {
         audio_samples: &[f32],
         language: Option<&str>,
         context: Option<SonioxContext>,
-    ) -> Result<String> {
+        enable_speaker_diarization: bool,
+    ) -> Result<SonioxAsyncTranscript> {
         if audio_samples.is_empty() {
-            return Ok(String::new());
+            return Ok(SonioxAsyncTranscript::default());
         }
         if api_key.trim().is_empty() {
             return Err(anyhow!("Soniox API key is missing"));
@@ -1068,7 +1127,7 @@ impl SonioxSttManager {
             encode_ms
         );
 
-        let text = self
+        let transcript = self
             .with_retry("Soniox WebSocket transcription", operation_id, || async {
                 self.transcribe_once_ws(
                     operation_id,
@@ -1078,18 +1137,20 @@ impl SonioxSttManager {
                     &audio_data,
                     language_hints.clone(),
                     context.clone(),
+                    enable_speaker_diarization,
                 )
                 .await
             })
             .await?;
         info!(
-            "Soniox WebSocket transcription completed in {}ms, encode_ms={}, output_len={}",
+            "Soniox WebSocket transcription completed in {}ms, encode_ms={}, output_len={}, speaker_blocks={}",
             started_at.elapsed().as_millis(),
             encode_ms,
-            text.len()
+            transcript.text.len(),
+            transcript.speaker_blocks.len()
         );
 
-        Ok(text)
+        Ok(transcript)
     }
 
     // Live transcription path with streaming callback for final chunks.
@@ -1104,12 +1165,12 @@ impl SonioxSttManager {
         language: Option<&str>,
         context: Option<SonioxContext>,
         mut on_final_chunk: F,
-    ) -> Result<String>
+    ) -> Result<SonioxAsyncTranscript>
     where
         F: FnMut(&str) -> Result<()>,
     {
         if audio_samples.is_empty() {
-            return Ok(String::new());
+            return Ok(SonioxAsyncTranscript::default());
         }
         if api_key.trim().is_empty() {
             return Err(anyhow!("Soniox API key is missing"));
@@ -1134,7 +1195,7 @@ impl SonioxSttManager {
             encode_ms
         );
 
-        let text = self
+        let transcript = self
             .transcribe_once_ws_with_callback(
                 operation_id,
                 api_key,
@@ -1150,10 +1211,10 @@ impl SonioxSttManager {
             "Soniox WebSocket streaming transcription completed in {}ms, encode_ms={}, output_len={}",
             started_at.elapsed().as_millis(),
             encode_ms,
-            text.len()
+            transcript.text.len()
         );
 
-        Ok(text)
+        Ok(transcript)
     }
 
     // File transcription path: Soniox async REST API.
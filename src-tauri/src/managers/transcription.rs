@@ -1,4 +1,4 @@
-use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
+use crate::audio_toolkit::{apply_custom_words, filter_transcription_output, CustomWord};
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::model::{self, EngineType, ModelManager, NativeStreamingLatencyKind};
 use crate::managers::moonshine_streaming_shim::{self, CommittedTextSink};
@@ -43,6 +43,13 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
+/// Payload of the `model-load-state` event: whether a local model is in memory.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ModelLoadState {
+    pub loaded: bool,
+    pub model_id: Option<String>,
+}
+
 enum LoadedEngine {
     TranscribeCpp(Session),
     Whisper(WhisperEngine),
@@ -83,6 +90,9 @@ enum StreamCmd {
 /// crosses this boundary, so callers can safely insert the chunks elsewhere.
 pub type NativeStreamCommittedCallback = Arc<dyn Fn(String) + Send + Sync>;
 
+/// Receives the running preview text of a chunked local transcription.
+pub type LocalStreamingPartialCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
 fn native_stream_committed_delta(previous: &mut String, current: &str) -> Option<String> {
     let Some(delta) = current.strip_prefix(previous.as_str()) else {
         warn!(
@@ -369,26 +379,26 @@ impl Drop for FileTranscriptionCancelGuard {
     }
 }
 
+/// Whisper prompt with `${custom_words}` expanded. Prompts without the
+/// placeholder still get the word list appended, as before the variable existed.
 fn build_whisper_initial_prompt(
     base_prompt: Option<String>,
-    custom_words: &[String],
+    custom_words: &[CustomWord],
     include_custom_words: bool,
+    max_chars: u32,
 ) -> Option<String> {
-    let custom_words_prompt = if include_custom_words && !custom_words.is_empty() {
-        Some(custom_words.join(", "))
+    let words_list = if include_custom_words {
+        crate::settings::custom_words_prompt_list(
+            &crate::settings::custom_word_vocabulary(custom_words),
+            max_chars as usize,
+        )
     } else {
-        None
+        String::new()
     };
-
-    match (base_prompt, custom_words_prompt) {
-        (Some(prompt), Some(words)) => Some(format!("{}\n{}", prompt, words)),
-        (Some(prompt), None) => Some(prompt),
-        (None, Some(words)) => Some(words),
-        (None, None) => None,
-    }
+    crate::settings::expand_custom_words_in_prompt(base_prompt, &words_list, true)
 }
 
-const FILE_TRANSCRIPTION_SAMPLE_RATE: f32 = 16_000.0;
+pub(crate) const FILE_TRANSCRIPTION_SAMPLE_RATE: f32 = 16_000.0;
 const FILE_TRANSCRIPTION_CHUNK_PADDING_SECS: f32 = 0.25;
 const FILE_TRANSCRIPTION_MIN_CHUNK_SECS: f32 = 1.0;
 const FILE_TRANSCRIPTION_SMART_SPLIT_SEARCH_SECS: f32 = 5.0;
@@ -397,6 +407,78 @@ const FILE_TRANSCRIPTION_VAD_HANGOVER_FRAMES: usize = 15;
 const FILE_TRANSCRIPTION_VAD_ONSET_FRAMES: usize = 2;
 const FILE_TRANSCRIPTION_COHERE_MAX_CHUNK_SECS: f32 = 15.0;
 
+// Chunked local streaming cuts a window at the first silence after
+// LOCAL_STREAMING_WINDOW_SECS. Without a pause it cuts at the maximum and
+// carries the tail over, so a word split by the cut is heard again in full.
+const LOCAL_STREAMING_WINDOW_SECS: f32 = 5.0;
+const LOCAL_STREAMING_MAX_WINDOW_SECS: f32 = 8.0;
+const LOCAL_STREAMING_OVERLAP_SECS: f32 = 1.0;
+// Longest run of words matched when dropping text repeated by the overlap.
+const LOCAL_STREAMING_MAX_OVERLAP_WORDS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocalStreamingCut {
+    /// Cut at a pause; the next window starts with fresh audio.
+    Silence,
+    /// Cut mid-speech; the next window repeats the overlap.
+    Forced,
+}
+
+fn local_streaming_cut(
+    window_samples: usize,
+    is_speech: bool,
+    heard_speech: bool,
+) -> Option<LocalStreamingCut> {
+    let window_secs = window_samples as f32 / FILE_TRANSCRIPTION_SAMPLE_RATE;
+    if window_secs >= LOCAL_STREAMING_MAX_WINDOW_SECS {
+        Some(LocalStreamingCut::Forced)
+    } else if window_secs >= LOCAL_STREAMING_WINDOW_SECS && heard_speech && !is_speech {
+        Some(LocalStreamingCut::Silence)
+    } else {
+        None
+    }
+}
+
+fn overlap_word_key(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Appends the text of one window to the running preview. After an
+/// overlapping cut the window starts with audio that was already
+/// transcribed, so the longest run of leading words that repeats the end of
+/// the preview is dropped.
+fn append_local_streaming_text(text: &mut String, window_text: &str, overlapping: bool) {
+    let incoming: Vec<&str> = window_text.split_whitespace().collect();
+    let mut skip = 0;
+    if overlapping {
+        let existing: Vec<&str> = text.split_whitespace().collect();
+        let max_overlap = LOCAL_STREAMING_MAX_OVERLAP_WORDS
+            .min(existing.len())
+            .min(incoming.len());
+        skip = (1..=max_overlap)
+            .rev()
+            .find(|&n| {
+                existing[existing.len() - n..]
+                    .iter()
+                    .zip(&incoming[..n])
+                    .all(|(a, b)| overlap_word_key(a) == overlap_word_key(b))
+            })
+            .unwrap_or(0);
+    }
+
+    let rest = incoming[skip..].join(" ");
+    if rest.is_empty() {
+        return;
+    }
+    if !text.is_empty() {
+        text.push(' ');
+    }
+    text.push_str(&rest);
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum FileTranscriptionChunkProfile {
     Default,
@@ -531,12 +613,23 @@ pub struct TranscriptionManager {
     stream_active: Arc<AtomicBool>,
     next_stream_worker_id: Arc<AtomicU64>,
     file_transcription_cancel_requested: Arc<AtomicBool>,
+    /// Race operations with an id at or below this were cancelled.
+    race_cancelled_through: Arc<AtomicU64>,
+    next_race_operation_id: Arc<AtomicU64>,
     shutdown_signal: Arc<AtomicBool>,
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
 }
 
+/// Text of a full local transcription with its segment timings for history.
+#[derive(Clone, Debug, Default)]
+pub struct LocalTranscript {
+    pub text: String,
+    /// `None` unless the engine reported segments and `history_timestamps_enabled` is on.
+    pub segments: Option<Vec<crate::subtitle::SubtitleSegment>>,
+}
+
 impl TranscriptionManager {
     pub fn new(app_handle: &AppHandle, model_manager: Arc<ModelManager>) -> Result<Self> {
         let manager = Self {
@@ -551,6 +644,8 @@ impl TranscriptionManager {
             stream_active: Arc::new(AtomicBool::new(false)),
             next_stream_worker_id: Arc::new(AtomicU64::new(1)),
             file_transcription_cancel_requested: Arc::new(AtomicBool::new(false)),
+            race_cancelled_through: Arc::new(AtomicU64::new(0)),
+            next_race_operation_id: Arc::new(AtomicU64::new(1)),
             shutdown_signal: Arc::new(AtomicBool::new(false)),
             watcher_handle: Arc::new(Mutex::new(None)),
             is_loading: Arc::new(Mutex::new(false)),
@@ -584,41 +679,15 @@ impl TranscriptionManager {
                     let is_recording = app_handle_cloned
                         .try_state::<Arc<AudioRecordingManager>>()
                         .map_or(false, |manager| manager.is_recording());
-                    if is_recording {
+                    if is_recording || manager_cloned.is_streaming() {
                         manager_cloned.touch_activity();
                         continue;
                     }
 
-                    if let Some(limit_seconds) = timeout.to_seconds() {
-                        let last = manager_cloned.last_activity.load(Ordering::Relaxed);
-                        let now_ms = Self::now_ms();
-                        let idle_ms = now_ms.saturating_sub(last);
-                        let limit_ms = limit_seconds * 1000;
-
-                        if idle_ms > limit_ms {
-                            // idle -> unload
-                            if manager_cloned.is_model_loaded() {
-                                let unload_start = std::time::Instant::now();
-                                info!(
-                                    "Model idle for {}s (limit: {}s), unloading",
-                                    idle_ms / 1000,
-                                    limit_seconds
-                                );
-
-                                match manager_cloned.unload_model() {
-                                    Ok(()) => {
-                                        let unload_duration = unload_start.elapsed();
-                                        info!(
-                                            "Model unloaded due to inactivity (took {}ms)",
-                                            unload_duration.as_millis()
-                                        );
-                                    }
-                                    Err(e) => {
-                                        error!("Failed to unload idle model: {}", e);
-                                    }
-                                }
-                            }
-                        }
+                    if let Some(limit_seconds) =
+                        timeout.to_seconds(settings.model_keep_loaded_minutes)
+                    {
+                        manager_cloned.unload_if_idle(limit_seconds * 1000);
                     }
                 }
                 debug!("Idle watcher thread shutting down gracefully");
@@ -644,10 +713,25 @@ impl TranscriptionManager {
             .as_millis() as u64
     }
 
-    fn touch_activity(&self) {
+    /// Restarts the idle-unload countdown, e.g. when a recording starts.
+    pub fn touch_activity(&self) {
         self.last_activity.store(Self::now_ms(), Ordering::Relaxed);
     }
 
+    pub fn model_load_state(&self) -> ModelLoadState {
+        let model_id = self.get_current_model();
+        ModelLoadState {
+            loaded: model_id.is_some() && self.is_model_loaded(),
+            model_id,
+        }
+    }
+
+    fn emit_model_load_state(&self) {
+        let _ = self
+            .app_handle
+            .emit("model-load-state", self.model_load_state());
+    }
+
     pub fn stream_router(&self) -> Arc<StreamRouter> {
         Arc::clone(&self.stream_router)
     }
@@ -985,7 +1069,12 @@ impl TranscriptionManager {
         }
     }
 
-    pub fn finalize_stream(&self) -> Result<Option<String>> {
+    /// `custom_words_override` replaces the global custom words for the
+    /// final text, e.g. with the recording profile's list.
+    pub fn finalize_stream(
+        &self,
+        custom_words_override: Option<Vec<CustomWord>>,
+    ) -> Result<Option<String>> {
         let Some(tx) = self.stream_router.take() else {
             return Ok(None);
         };
@@ -1008,7 +1097,10 @@ impl TranscriptionManager {
             }
         };
 
-        let settings = get_settings(&self.app_handle);
+        let mut settings = get_settings(&self.app_handle);
+        if let Some(custom_words) = custom_words_override {
+            settings.custom_words = custom_words;
+        }
         let final_text = post_process_stream_text(raw, &settings, &selected_language);
         self.maybe_unload_immediately("streaming transcription");
         Ok(Some(final_text))
@@ -1021,6 +1113,22 @@ impl TranscriptionManager {
         self.stream_active.store(false, Ordering::Release);
     }
 
+    /// Id for a local transcription racing a remote provider. The race
+    /// cancels it cooperatively: callers check [`Self::is_race_cancelled`]
+    /// between steps, a running engine call is not interrupted.
+    pub fn start_race_operation(&self) -> u64 {
+        self.next_race_operation_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn cancel_race_operation(&self, operation_id: u64) {
+        self.race_cancelled_through
+            .fetch_max(operation_id, Ordering::SeqCst);
+    }
+
+    pub fn is_race_cancelled(&self, operation_id: u64) -> bool {
+        operation_id <= self.race_cancelled_through.load(Ordering::SeqCst)
+    }
+
     pub fn cancel_file_transcription(&self) {
         self.file_transcription_cancel_requested
             .store(true, Ordering::Relaxed);
@@ -1100,6 +1208,7 @@ impl TranscriptionManager {
                 error: None,
             },
         );
+        self.emit_model_load_state();
 
         let unload_duration = unload_start.elapsed();
         debug!(
@@ -1153,6 +1262,54 @@ impl TranscriptionManager {
                 error: None,
             },
         );
+        self.emit_model_load_state();
+    }
+
+    /// Unloads the model once nothing has used it for `limit_ms`. The engine
+    /// lock is only tried and idleness is re-checked while holding it, so a
+    /// transcription that is running or just returned the engine keeps it.
+    fn unload_if_idle(&self, limit_ms: u64) {
+        // Checked before the engine lock; the transcribe path takes them in this order.
+        if *self.is_loading.lock().unwrap() {
+            return;
+        }
+        let mut engine = match self.engine.try_lock() {
+            Ok(engine) => engine,
+            Err(TryLockError::WouldBlock) => return,
+            Err(TryLockError::Poisoned(poisoned)) => {
+                warn!("Engine mutex was poisoned during idle unload, recovering");
+                poisoned.into_inner()
+            }
+        };
+
+        let idle_ms = Self::now_ms().saturating_sub(self.last_activity.load(Ordering::Relaxed));
+        if engine.is_none() || idle_ms <= limit_ms {
+            return;
+        }
+
+        info!(
+            "Model idle for {}s (limit: {}s), unloading",
+            idle_ms / 1000,
+            limit_ms / 1000
+        );
+        *engine = None;
+        drop(engine);
+
+        {
+            let mut current_model = self.current_model_id.lock().unwrap();
+            *current_model = None;
+        }
+
+        let _ = self.app_handle.emit(
+            "model-state-changed",
+            ModelStateEvent {
+                event_type: "unloaded".to_string(),
+                model_id: None,
+                model_name: None,
+                error: None,
+            },
+        );
+        self.emit_model_load_state();
     }
 
     pub fn load_model(&self, model_id: &str) -> Result<()> {
@@ -1232,6 +1389,19 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!(error_msg));
         }
 
+        if let Some(missing) = self.model_manager.verify_model_on_disk(model_id) {
+            let error_msg = format!("Model file missing: {} ({})", missing.path, missing.reason);
+            emit_loading_failed(&error_msg);
+            if let Some(fallback_model_id) = missing.fallback_model_id.as_deref() {
+                info!(
+                    "Loading fallback model '{}' instead of '{}'",
+                    fallback_model_id, model_id
+                );
+                return self.load_model_inner(fallback_model_id, device_index);
+            }
+            return Err(anyhow::anyhow!(error_msg));
+        }
+
         let model_path = self.model_manager.get_model_path(model_id)?;
 
         if matches!(&model_info.engine_type, EngineType::TranscribeCpp) {
@@ -1397,6 +1567,7 @@ impl TranscriptionManager {
                 error: None,
             },
         );
+        self.emit_model_load_state();
 
         let load_duration = load_start.elapsed();
         debug!(
@@ -1440,7 +1611,12 @@ impl TranscriptionManager {
     /// Kicks off the model loading in a background thread if it's not already loaded
     pub fn initiate_model_load(&self) {
         let settings = get_settings(&self.app_handle);
-        let model_id = settings.selected_model.trim().to_string();
+        self.initiate_model_load_for(&settings.selected_model);
+    }
+
+    /// Like `initiate_model_load`, for a model other than the selected one.
+    pub fn initiate_model_load_for(&self, model_id: &str) {
+        let model_id = model_id.trim().to_string();
 
         if model_id.is_empty() {
             error!("Failed to load model: no local transcription model is selected");
@@ -1468,7 +1644,109 @@ impl TranscriptionManager {
         current_model.clone()
     }
 
-    pub fn transcribe(&self, audio: Vec<f32>, apply_custom_words_enabled: bool) -> Result<String> {
+    pub fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        apply_custom_words_enabled: bool,
+    ) -> Result<LocalTranscript> {
+        self.transcribe_samples(audio, None, apply_custom_words_enabled, true)
+    }
+
+    /// Transcribes audio while it is being recorded, for a live preview.
+    ///
+    /// Frames from `frames` are cut into windows of about five seconds at
+    /// silence boundaries found by the Silero VAD. Each window is transcribed
+    /// as soon as it is cut and the running text is passed to `on_partial`.
+    /// Returns the joined preview once `frames` closes. The preview is not
+    /// authoritative: callers still transcribe the full recording afterwards,
+    /// which keeps context across windows and applies the remaining profile
+    /// overrides.
+    pub fn transcribe_streaming(
+        &self,
+        frames: mpsc::Receiver<Vec<f32>>,
+        custom_words_override: Option<Vec<CustomWord>>,
+        apply_custom_words_enabled: bool,
+        on_partial: LocalStreamingPartialCallback,
+    ) -> Result<String> {
+        let settings = get_settings(&self.app_handle);
+        let vad_model_path = self.resolve_file_transcription_vad_model_path()?;
+        let silero = ChunkingSileroVad::new(&vad_model_path, settings.vad_threshold)
+            .map_err(|e| anyhow::anyhow!("Failed to create chunking VAD: {}", e))?;
+        let mut vad = ChunkingSmoothedVad::new(
+            Box::new(silero),
+            FILE_TRANSCRIPTION_VAD_PREFILL_FRAMES,
+            FILE_TRANSCRIPTION_VAD_HANGOVER_FRAMES,
+            FILE_TRANSCRIPTION_VAD_ONSET_FRAMES,
+        );
+        let frame_size = vad.frame_size();
+        let overlap_samples =
+            (LOCAL_STREAMING_OVERLAP_SECS * FILE_TRANSCRIPTION_SAMPLE_RATE) as usize;
+
+        let mut pending = Vec::new();
+        let mut window = Vec::new();
+        let mut heard_speech = false;
+        let mut window_overlaps = false;
+        let mut preview = String::new();
+
+        while let Ok(frame) = frames.recv() {
+            pending.extend_from_slice(&frame);
+
+            let mut consumed = 0;
+            while pending.len() - consumed >= frame_size {
+                let vad_frame = &pending[consumed..consumed + frame_size];
+                consumed += frame_size;
+                let is_speech = vad
+                    .is_speech(vad_frame)
+                    .map_err(|e| anyhow::anyhow!("Chunking VAD failed: {}", e))?;
+                window.extend_from_slice(vad_frame);
+                heard_speech |= is_speech;
+
+                let Some(cut) = local_streaming_cut(window.len(), is_speech, heard_speech) else {
+                    continue;
+                };
+                let carry_over = match cut {
+                    LocalStreamingCut::Silence => Vec::new(),
+                    LocalStreamingCut::Forced => {
+                        window[window.len().saturating_sub(overlap_samples)..].to_vec()
+                    }
+                };
+                let window_audio = std::mem::replace(&mut window, carry_over);
+                // Whisper invents text for silence, so windows without speech
+                // are skipped.
+                if heard_speech {
+                    match self.transcribe_samples(
+                        window_audio,
+                        custom_words_override.clone(),
+                        apply_custom_words_enabled,
+                        false,
+                    ) {
+                        Ok(transcript) => {
+                            append_local_streaming_text(
+                                &mut preview,
+                                &transcript.text,
+                                window_overlaps,
+                            );
+                            on_partial(&preview);
+                        }
+                        Err(e) => warn!("Local streaming window failed: {}", e),
+                    }
+                }
+                window_overlaps = cut == LocalStreamingCut::Forced;
+                heard_speech = window_overlaps;
+            }
+            pending.drain(..consumed);
+        }
+
+        Ok(preview)
+    }
+
+    fn transcribe_samples(
+        &self,
+        audio: Vec<f32>,
+        custom_words_override: Option<Vec<CustomWord>>,
+        apply_custom_words_enabled: bool,
+        unload_when_done: bool,
+    ) -> Result<LocalTranscript> {
         #[cfg(debug_assertions)]
         if std::env::var("HANDY_FORCE_TRANSCRIPTION_FAILURE").is_ok() {
             return Err(anyhow::anyhow!(
@@ -1485,8 +1763,10 @@ impl TranscriptionManager {
 
         if audio.is_empty() {
             debug!("Empty audio vector");
-            self.maybe_unload_immediately("empty audio");
-            return Ok(String::new());
+            if unload_when_done {
+                self.maybe_unload_immediately("empty audio");
+            }
+            return Ok(LocalTranscript::default());
         }
 
         // Check if model is loaded, if not try to load it
@@ -1499,12 +1779,20 @@ impl TranscriptionManager {
 
             let engine_guard = self.lock_engine();
             if engine_guard.is_none() {
+                let selected_model = get_settings(&self.app_handle).selected_model;
+                if let Some((path, reason)) = self.model_manager.model_disk_problem(&selected_model)
+                {
+                    return Err(anyhow::anyhow!("Model file missing: {} ({})", path, reason));
+                }
                 return Err(anyhow::anyhow!("Model is not loaded for transcription."));
             }
         }
 
         // Get current settings for configuration
-        let settings = get_settings(&self.app_handle);
+        let mut settings = get_settings(&self.app_handle);
+        if let Some(custom_words) = custom_words_override {
+            settings.custom_words = custom_words;
+        }
         let active_model = self
             .get_current_model()
             .unwrap_or_else(|| settings.selected_model.clone());
@@ -1576,6 +1864,7 @@ impl TranscriptionManager {
                                     },
                                     &settings.custom_words,
                                     apply_custom_words_enabled,
+                                    settings.stt_prompt_custom_words_max_chars,
                                 ),
                                 ..Default::default()
                             };
@@ -1664,7 +1953,10 @@ impl TranscriptionManager {
 
             match transcribe_result {
                 Ok(inner_result) => {
-                    // Success or normal error — put the engine back
+                    // Success or normal error — put the engine back. Refresh the
+                    // activity time first so the idle watcher cannot unload it
+                    // right after a long transcription.
+                    self.touch_activity();
                     let mut engine_guard = self.lock_engine();
                     *engine_guard = Some(engine);
                     inner_result?
@@ -1696,6 +1988,7 @@ impl TranscriptionManager {
                             error: Some(format!("Engine panicked: {}", panic_msg)),
                         },
                     );
+                    self.emit_model_load_state();
 
                     return Err(anyhow::anyhow!(
                         "Transcription engine panicked: {}. The model has been unloaded and will reload on next attempt.",
@@ -1705,6 +1998,19 @@ impl TranscriptionManager {
             }
         };
 
+        // Streaming preview windows are not the final take, so their timings
+        // are never kept.
+        let segments = if unload_when_done {
+            history_segments(
+                &result,
+                &settings,
+                &effective_language,
+                apply_custom_words_enabled,
+            )
+        } else {
+            None
+        };
+
         let filtered_result = post_process_transcription_text(
             result.text,
             &settings,
@@ -1732,12 +2038,17 @@ impl TranscriptionManager {
             info!("Transcription result: {}", final_result);
         }
 
-        self.maybe_unload_immediately("transcription");
+        if unload_when_done {
+            self.maybe_unload_immediately("transcription");
+        }
 
-        Ok(final_result)
+        Ok(LocalTranscript {
+            text: final_result,
+            segments,
+        })
     }
 
-    /// Transcribe audio with optional language/translation/prompt overrides.
+    /// Transcribe audio with optional language/translation/prompt/custom-word overrides.
     /// Used by transcription profiles to override global settings.
     pub fn transcribe_with_overrides(
         &self,
@@ -1745,8 +2056,9 @@ impl TranscriptionManager {
         language_override: Option<&str>,
         translate_override: Option<bool>,
         prompt_override: Option<String>,
+        custom_words_override: Option<Vec<CustomWord>>,
         apply_custom_words_enabled: bool,
-    ) -> Result<String> {
+    ) -> Result<LocalTranscript> {
         // Update last activity timestamp
         self.touch_activity();
 
@@ -1756,7 +2068,7 @@ impl TranscriptionManager {
 
         if audio.len() == 0 {
             debug!("Empty audio vector");
-            return Ok(String::new());
+            return Ok(LocalTranscript::default());
         }
 
         // Check if model is loaded
@@ -1772,9 +2084,12 @@ impl TranscriptionManager {
             }
         }
 
-        let settings = get_settings(&self.app_handle);
+        let mut settings = get_settings(&self.app_handle);
 
         // Apply overrides
+        if let Some(custom_words) = custom_words_override {
+            settings.custom_words = custom_words;
+        }
         let selected_language = language_override
             .map(|s| s.to_string())
             .unwrap_or_else(|| settings.selected_language.clone());
@@ -1831,6 +2146,7 @@ impl TranscriptionManager {
                                 }),
                             &settings.custom_words,
                             apply_custom_words_enabled,
+                            settings.stt_prompt_custom_words_max_chars,
                         ),
                         ..Default::default()
                     };
@@ -1911,6 +2227,13 @@ impl TranscriptionManager {
             }
         };
 
+        let segments = history_segments(
+            &result,
+            &settings,
+            &effective_language,
+            apply_custom_words_enabled,
+        );
+
         let filtered_result = post_process_transcription_text(
             result.text,
             &settings,
@@ -1940,7 +2263,10 @@ impl TranscriptionManager {
             }
         }
 
-        Ok(final_result)
+        Ok(LocalTranscript {
+            text: final_result,
+            segments,
+        })
     }
 
     pub fn transcribe_file_text(
@@ -2226,6 +2552,7 @@ impl TranscriptionManager {
                                 }),
                             &settings.custom_words,
                             apply_custom_words_enabled,
+                            settings.stt_prompt_custom_words_max_chars,
                         ),
                         ..Default::default()
                     };
@@ -2448,6 +2775,34 @@ impl TranscriptionManager {
             .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}", e))
     }
 
+    /// Runs the chunking VAD over a whole file and returns its frame size
+    /// with one flag per full frame, `true` where the frame is silent.
+    pub fn detect_file_silence_frames(&self, audio: &[f32]) -> Result<(usize, Vec<bool>)> {
+        self.ensure_file_transcription_not_cancelled()?;
+        let settings = get_settings(&self.app_handle);
+        let vad_model_path = self.resolve_file_transcription_vad_model_path()?;
+        let silero = ChunkingSileroVad::new(&vad_model_path, settings.vad_threshold)
+            .map_err(|e| anyhow::anyhow!("Failed to create chunking VAD: {}", e))?;
+        let mut vad = ChunkingSmoothedVad::new(
+            Box::new(silero),
+            FILE_TRANSCRIPTION_VAD_PREFILL_FRAMES,
+            FILE_TRANSCRIPTION_VAD_HANGOVER_FRAMES,
+            FILE_TRANSCRIPTION_VAD_ONSET_FRAMES,
+        );
+
+        let frame_size = vad.frame_size();
+        let mut silent_frames = Vec::with_capacity(audio.len() / frame_size.max(1));
+        for frame in audio.chunks_exact(frame_size) {
+            self.ensure_file_transcription_not_cancelled()?;
+            let is_speech = vad
+                .is_speech(frame)
+                .map_err(|e| anyhow::anyhow!("Chunking VAD failed: {}", e))?;
+            silent_frames.push(!is_speech);
+        }
+
+        Ok((frame_size, silent_frames))
+    }
+
     fn transcribe_speech_model_file(
         &self,
         model: &mut dyn SpeechModel,
@@ -3283,6 +3638,37 @@ fn post_process_transcription_text(
     })
 }
 
+/// Segment timings of a finished transcription for history, cleaned up like
+/// its text. Engines that report no segments give `None`, so history never
+/// gets fabricated timings.
+fn history_segments(
+    result: &TranscriptionResult,
+    settings: &AppSettings,
+    selected_language: &str,
+    apply_custom_words_enabled: bool,
+) -> Option<Vec<crate::subtitle::SubtitleSegment>> {
+    if !settings.history_timestamps_enabled {
+        return None;
+    }
+    let segments = result
+        .segments
+        .as_ref()?
+        .iter()
+        .map(|seg| crate::subtitle::SubtitleSegment {
+            start: seg.start,
+            end: seg.end,
+            text: post_process_transcription_text(
+                seg.text.clone(),
+                settings,
+                selected_language,
+                apply_custom_words_enabled,
+            ),
+        })
+        .filter(|seg| !seg.text.trim().is_empty())
+        .collect::<Vec<_>>();
+    (!segments.is_empty()).then_some(segments)
+}
+
 /// Optional text cleanup must never discard a successful model result. The
 /// transform is pure and owns its input, so recovering the untouched text is
 /// safe even if a bug in custom-word or filler filtering unwinds.
@@ -3812,6 +4198,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn local_streaming_cuts_at_silence_after_window_and_forces_at_maximum() {
+        let samples = |secs: f32| (secs * FILE_TRANSCRIPTION_SAMPLE_RATE) as usize;
+
+        assert_eq!(local_streaming_cut(samples(3.0), false, true), None);
+        assert_eq!(local_streaming_cut(samples(5.5), true, true), None);
+        assert_eq!(local_streaming_cut(samples(5.5), false, false), None);
+        assert_eq!(
+            local_streaming_cut(samples(5.5), false, true),
+            Some(LocalStreamingCut::Silence)
+        );
+        assert_eq!(
+            local_streaming_cut(samples(8.0), true, true),
+            Some(LocalStreamingCut::Forced)
+        );
+    }
+
+    #[test]
+    fn local_streaming_text_drops_words_repeated_by_overlap() {
+        let mut text = String::from("we should ship the release on");
+        append_local_streaming_text(&mut text, "Release on Friday, then rest.", true);
+        assert_eq!(text, "we should ship the release on Friday, then rest.");
+
+        let mut text = String::from("and then they said");
+        append_local_streaming_text(&mut text, "said hello", false);
+        assert_eq!(text, "and then they said said hello");
+
+        let mut text = String::new();
+        append_local_streaming_text(&mut text, "  first window ", true);
+        assert_eq!(text, "first window");
+    }
+
     #[test]
     fn chunk_trace_records_indices_timing_and_reason() {
         let mut trace = Vec::new();
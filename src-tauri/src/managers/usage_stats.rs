@@ -0,0 +1,417 @@
+//! Local usage statistics: how much is dictated per day, per STT provider and
+//! per profile, and how often the LLM runs.
+//!
+//! Counters live in memory and are written to `usage_stats.json` by a flush
+//! thread a few seconds after the first change, so a burst of short
+//! dictations costs one write. Nothing here leaves the machine.
+
+use chrono::{Duration as ChronoDuration, Local, NaiveDate};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+
+const USAGE_STATS_FILE: &str = "usage_stats.json";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+/// Delay between the first unsaved change and the write that covers it.
+const FLUSH_DELAY: Duration = Duration::from_secs(5);
+
+/// Additive counters kept per day and per group.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct UsageCounters {
+    #[serde(default)]
+    pub dictations: u64,
+    #[serde(default)]
+    pub words: u64,
+    #[serde(default)]
+    pub characters: u64,
+    /// Recorded audio, in milliseconds
+    #[serde(default)]
+    pub audio_ms: u64,
+    /// LLM post-processing runs that produced text
+    #[serde(default)]
+    pub llm_post_processes: u64,
+    #[serde(default)]
+    pub ai_replaces: u64,
+    /// Messages queued for the browser extension
+    #[serde(default)]
+    pub extension_messages: u64,
+}
+
+impl UsageCounters {
+    fn add(&mut self, other: &UsageCounters) {
+        self.dictations = self.dictations.saturating_add(other.dictations);
+        self.words = self.words.saturating_add(other.words);
+        self.characters = self.characters.saturating_add(other.characters);
+        self.audio_ms = self.audio_ms.saturating_add(other.audio_ms);
+        self.llm_post_processes = self
+            .llm_post_processes
+            .saturating_add(other.llm_post_processes);
+        self.ai_replaces = self.ai_replaces.saturating_add(other.ai_replaces);
+        self.extension_messages = self
+            .extension_messages
+            .saturating_add(other.extension_messages);
+    }
+}
+
+/// Audio duration of 16 kHz mono samples, in milliseconds.
+pub fn audio_ms_for_samples(sample_count: usize) -> u64 {
+    sample_count as u64 * 1000 / u64::from(WHISPER_SAMPLE_RATE)
+}
+
+/// One recorded use, attributed to the groups it counts towards.
+#[derive(Clone, Debug, Default)]
+pub struct UsageRecord {
+    /// STT provider (`TranscriptionProvider::as_str`), if speech was transcribed
+    pub stt_provider: Option<String>,
+    /// Transcription profile; `None` counts towards "default"
+    pub profile_id: Option<String>,
+    /// LLM provider id, when the LLM ran
+    pub llm_provider: Option<String>,
+    pub counters: UsageCounters,
+}
+
+impl UsageRecord {
+    /// A transcription whose final text is `text`.
+    pub fn dictation(text: &str, sample_count: usize) -> Self {
+        Self {
+            counters: UsageCounters {
+                dictations: 1,
+                words: text.split_whitespace().count() as u64,
+                characters: text.chars().count() as u64,
+                audio_ms: audio_ms_for_samples(sample_count),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct DayUsage {
+    #[serde(default)]
+    totals: UsageCounters,
+    #[serde(default)]
+    by_stt_provider: BTreeMap<String, UsageCounters>,
+    #[serde(default)]
+    by_llm_provider: BTreeMap<String, UsageCounters>,
+    #[serde(default)]
+    by_profile: BTreeMap<String, UsageCounters>,
+}
+
+impl DayUsage {
+    fn apply(&mut self, record: &UsageRecord) {
+        self.totals.add(&record.counters);
+        if let Some(provider) = &record.stt_provider {
+            self.by_stt_provider
+                .entry(provider.clone())
+                .or_default()
+                .add(&record.counters);
+        }
+        if let Some(provider) = &record.llm_provider {
+            self.by_llm_provider
+                .entry(provider.clone())
+                .or_default()
+                .add(&record.counters);
+        }
+        let profile = record.profile_id.as_deref().unwrap_or("default");
+        self.by_profile
+            .entry(profile.to_string())
+            .or_default()
+            .add(&record.counters);
+    }
+}
+
+/// On-disk layout: one entry per local date (YYYY-MM-DD).
+#[derive(Default, Serialize, Deserialize)]
+struct UsageStatsFile {
+    #[serde(default)]
+    days: BTreeMap<String, DayUsage>,
+}
+
+/// Period covered by [`UsageStatsManager::stats`], ending today.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageStatsRange {
+    Today,
+    Last7Days,
+    Last30Days,
+    AllTime,
+}
+
+impl UsageStatsRange {
+    /// First day of the range; `None` for all time.
+    fn start(self, today: NaiveDate) -> Option<NaiveDate> {
+        let days_back = match self {
+            Self::Today => 0,
+            Self::Last7Days => 6,
+            Self::Last30Days => 29,
+            Self::AllTime => return None,
+        };
+        Some(today - ChronoDuration::days(days_back))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageDay {
+    /// Local date, YYYY-MM-DD
+    pub date: String,
+    pub counters: UsageCounters,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageGroup {
+    pub key: String,
+    pub counters: UsageCounters,
+}
+
+/// Aggregates for a range. `days` has an entry for every day of a bounded
+/// range, so charts need no gap filling.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct UsageStats {
+    pub range: UsageStatsRange,
+    pub totals: UsageCounters,
+    pub days: Vec<UsageDay>,
+    pub by_stt_provider: Vec<UsageGroup>,
+    pub by_llm_provider: Vec<UsageGroup>,
+    pub by_profile: Vec<UsageGroup>,
+}
+
+pub struct UsageStatsManager {
+    /// `None` keeps statistics in memory only.
+    path: Option<PathBuf>,
+    file: Arc<Mutex<UsageStatsFile>>,
+    flush_tx: Mutex<Option<mpsc::Sender<()>>>,
+}
+
+impl UsageStatsManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        let path = crate::portable::app_data_dir(app_handle)
+            .ok()
+            .map(|dir| dir.join(USAGE_STATS_FILE));
+        let file = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| match serde_json::from_str::<UsageStatsFile>(&json) {
+                Ok(file) => Some(file),
+                Err(e) => {
+                    warn!("Ignoring unreadable usage statistics: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            file: Arc::new(Mutex::new(file)),
+            flush_tx: Mutex::new(None),
+        }
+    }
+
+    fn lock_file(&self) -> std::sync::MutexGuard<'_, UsageStatsFile> {
+        self.file.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Adds `record` to today's counters. Skipped in privacy mode.
+    pub fn record(&self, record: UsageRecord) {
+        if crate::privacy_mode::should_skip("usage statistics") {
+            return;
+        }
+        let today = Local::now().date_naive().format(DATE_FORMAT).to_string();
+        self.lock_file()
+            .days
+            .entry(today)
+            .or_default()
+            .apply(&record);
+        self.schedule_flush();
+    }
+
+    pub fn stats(&self, range: UsageStatsRange) -> UsageStats {
+        let file = self.lock_file();
+        aggregate(&file, range, Local::now().date_naive())
+    }
+
+    /// Drops every counter and removes the file right away.
+    pub fn reset(&self) -> Result<(), String> {
+        self.lock_file().days.clear();
+        if let Some(path) = &self.path {
+            match fs::remove_file(path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(format!("Failed to remove usage statistics: {}", e)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Wakes the flush thread, starting it on first use. Changes arriving
+    /// while it waits are covered by the same write.
+    fn schedule_flush(&self) {
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+        let mut flush_tx = self.flush_tx.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(tx) = flush_tx.as_ref() {
+            if tx.send(()).is_ok() {
+                return;
+            }
+        }
+
+        let (tx, rx) = mpsc::channel::<()>();
+        let file = Arc::clone(&self.file);
+        let spawned = thread::Builder::new()
+            .name("usage-stats-flush".into())
+            .spawn(move || {
+                while rx.recv().is_ok() {
+                    thread::sleep(FLUSH_DELAY);
+                    while rx.try_recv().is_ok() {}
+                    let json = {
+                        let file = file.lock().unwrap_or_else(|e| e.into_inner());
+                        serde_json::to_string(&*file)
+                    };
+                    match json.map_err(|e| e.to_string()).and_then(|json| {
+                        write_atomically(&path, json.as_bytes()).map_err(|e| e.to_string())
+                    }) {
+                        Ok(()) => debug!("Saved usage statistics to {}", path.display()),
+                        Err(e) => warn!("Failed to save usage statistics: {}", e),
+                    }
+                }
+            });
+        match spawned {
+            Ok(_) => {
+                let _ = tx.send(());
+                *flush_tx = Some(tx);
+            }
+            Err(e) => warn!("Failed to start usage statistics flush thread: {}", e),
+        }
+    }
+}
+
+/// Writes through a temporary file so a crash never leaves half a file.
+fn write_atomically(path: &std::path::Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, data)?;
+    fs::rename(&tmp, path)
+}
+
+fn aggregate(file: &UsageStatsFile, range: UsageStatsRange, today: NaiveDate) -> UsageStats {
+    let start = range.start(today);
+    let in_range = |date: &NaiveDate| start.map_or(true, |start| *date >= start) && *date <= today;
+
+    let mut totals = UsageCounters::default();
+    let mut by_day: BTreeMap<NaiveDate, UsageCounters> = BTreeMap::new();
+    let mut by_stt_provider: BTreeMap<String, UsageCounters> = BTreeMap::new();
+    let mut by_llm_provider: BTreeMap<String, UsageCounters> = BTreeMap::new();
+    let mut by_profile: BTreeMap<String, UsageCounters> = BTreeMap::new();
+
+    for (date, day) in &file.days {
+        let Ok(date) = NaiveDate::parse_from_str(date, DATE_FORMAT) else {
+            continue;
+        };
+        if !in_range(&date) {
+            continue;
+        }
+        totals.add(&day.totals);
+        by_day.entry(date).or_default().add(&day.totals);
+        for (groups, source) in [
+            (&mut by_stt_provider, &day.by_stt_provider),
+            (&mut by_llm_provider, &day.by_llm_provider),
+            (&mut by_profile, &day.by_profile),
+        ] {
+            for (key, counters) in source {
+                groups.entry(key.clone()).or_default().add(counters);
+            }
+        }
+    }
+
+    if let Some(start) = start {
+        let mut date = start;
+        while date <= today {
+            by_day.entry(date).or_default();
+            date += ChronoDuration::days(1);
+        }
+    }
+
+    let groups = |map: BTreeMap<String, UsageCounters>| {
+        map.into_iter()
+            .map(|(key, counters)| UsageGroup { key, counters })
+            .collect()
+    };
+    UsageStats {
+        range,
+        totals,
+        days: by_day
+            .into_iter()
+            .map(|(date, counters)| UsageDay {
+                date: date.format(DATE_FORMAT).to_string(),
+                counters,
+            })
+            .collect(),
+        by_stt_provider: groups(by_stt_provider),
+        by_llm_provider: groups(by_llm_provider),
+        by_profile: groups(by_profile),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, DATE_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn dictation_record_counts_words_and_audio() {
+        let record = UsageRecord::dictation("hello  there world", 24_000);
+
+        assert_eq!(record.counters.dictations, 1);
+        assert_eq!(record.counters.words, 3);
+        assert_eq!(record.counters.audio_ms, 1_500);
+    }
+
+    #[test]
+    fn aggregate_fills_range_and_groups_by_provider_and_profile() {
+        let mut file = UsageStatsFile::default();
+        let mut record = UsageRecord::dictation("one two", 16_000);
+        record.stt_provider = Some("local".to_string());
+        file.days
+            .entry("2026-03-08".to_string())
+            .or_default()
+            .apply(&record);
+        record.profile_id = Some("profile_1".to_string());
+        file.days
+            .entry("2026-03-10".to_string())
+            .or_default()
+            .apply(&record);
+        file.days
+            .entry("2026-01-01".to_string())
+            .or_default()
+            .apply(&record);
+
+        let stats = aggregate(&file, UsageStatsRange::Last7Days, date("2026-03-10"));
+
+        assert_eq!(stats.days.len(), 7);
+        assert_eq!(stats.days.last().unwrap().date, "2026-03-10");
+        assert_eq!(stats.totals.words, 4);
+        assert_eq!(stats.by_stt_provider.len(), 1);
+        assert_eq!(stats.by_stt_provider[0].counters.dictations, 2);
+        let profiles: Vec<&str> = stats.by_profile.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(profiles, ["default", "profile_1"]);
+
+        let all_time = aggregate(&file, UsageStatsRange::AllTime, date("2026-03-10"));
+        assert_eq!(all_time.totals.dictations, 3);
+        assert_eq!(all_time.days.len(), 3);
+    }
+}
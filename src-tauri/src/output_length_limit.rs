@@ -0,0 +1,269 @@
+//! Post-hoc enforcement of per-prompt output length limits.
+//!
+//! Models often ignore "answer in two sentences". After post-processing the
+//! output is checked against `max_output_words` / `max_output_sentences` and
+//! either truncated at a sentence boundary or re-asked once.
+
+/// Abbreviations whose trailing period never ends a sentence, even when the
+/// next word is capitalized ("Dr. Smith").
+const NON_TERMINAL_ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "vs", "no", "fig", "approx", "dept",
+    "inc", "ltd", "co", "corp", "gen", "gov", "sen", "rep", "col", "lt", "capt", "sgt",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutputLengthLimits {
+    pub max_words: Option<u32>,
+    pub max_sentences: Option<u32>,
+}
+
+impl OutputLengthLimits {
+    pub fn new(max_words: Option<u32>, max_sentences: Option<u32>) -> Self {
+        Self {
+            max_words: max_words.filter(|limit| *limit > 0),
+            max_sentences: max_sentences.filter(|limit| *limit > 0),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max_words.is_none() && self.max_sentences.is_none()
+    }
+
+    pub fn is_exceeded_by(&self, text: &str) -> bool {
+        let too_many_words = self
+            .max_words
+            .is_some_and(|limit| text.split_whitespace().count() > limit as usize);
+        let too_many_sentences = self
+            .max_sentences
+            .is_some_and(|limit| count_sentences(text) > limit as usize);
+        too_many_words || too_many_sentences
+    }
+
+    /// Instruction appended to the original prompt when re-asking the model.
+    pub fn shorten_instruction(&self) -> String {
+        let limit = match (self.max_sentences, self.max_words) {
+            (Some(sentences), Some(words)) => {
+                format!("{} sentences and {} words", sentences, words)
+            }
+            (Some(sentences), None) => format!("{} sentences", sentences),
+            (None, Some(words)) => format!("{} words", words),
+            (None, None) => return String::new(),
+        };
+        format!(
+            "\n\nIMPORTANT: Your answer must be at most {}. Shorten it accordingly and return only the shortened text.",
+            limit
+        )
+    }
+
+    /// Short description stored with the history entry.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(sentences) = self.max_sentences {
+            parts.push(format!("{} sentences", sentences));
+        }
+        if let Some(words) = self.max_words {
+            parts.push(format!("{} words", words));
+        }
+        parts.join(", ")
+    }
+}
+
+fn is_sentence_terminator(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '…' | '。' | '！' | '？')
+}
+
+fn is_closing_punctuation(ch: char) -> bool {
+    matches!(ch, '"' | '\'' | ')' | ']' | '»' | '”' | '’' | '」')
+}
+
+/// Whether the period at `dot_idx` belongs to an abbreviation such as
+/// "e.g.", "Dr." or an initial ("J.").
+fn is_abbreviation_period(text: &str, dot_idx: usize) -> bool {
+    let word_start = text[..dot_idx]
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_whitespace() || *ch == '(' || *ch == '"')
+        .map(|(idx, ch)| idx + ch.len_utf8())
+        .unwrap_or(0);
+    let word = &text[word_start..dot_idx];
+    if word.is_empty() {
+        return false;
+    }
+    // Dotted abbreviations: "e.g", "i.e", "U.S"
+    if word.contains('.') && word.chars().all(|ch| ch.is_alphabetic() || ch == '.') {
+        return true;
+    }
+    // Single-letter initials: "J. R. R. Tolkien"
+    let mut chars = word.chars();
+    if let (Some(first), None) = (chars.next(), chars.next()) {
+        if first.is_uppercase() {
+            return true;
+        }
+    }
+    NON_TERMINAL_ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Byte offsets just past each sentence end (terminator plus any closing
+/// quotes/brackets). Trailing text without a terminator is not included.
+pub fn sentence_end_offsets(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        if !is_sentence_terminator(ch) {
+            continue;
+        }
+
+        let mut end = idx + ch.len_utf8();
+        // Collapse runs like "?!" or "..." into one terminator.
+        while let Some(&(next_idx, next_ch)) = chars.peek() {
+            if is_sentence_terminator(next_ch) || is_closing_punctuation(next_ch) {
+                end = next_idx + next_ch.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let rest = &text[end..];
+        let is_cjk_terminator = matches!(ch, '。' | '！' | '？');
+        if !rest.is_empty() && !is_cjk_terminator && !rest.starts_with(char::is_whitespace) {
+            // "3.5", "example.com"
+            continue;
+        }
+
+        if ch == '.' && end == idx + 1 {
+            if is_abbreviation_period(text, idx) {
+                continue;
+            }
+            // A lowercase continuation means the period did not end the sentence.
+            let next_word_lowercase = rest
+                .trim_start()
+                .chars()
+                .next()
+                .is_some_and(|next| next.is_lowercase());
+            if next_word_lowercase {
+                continue;
+            }
+        }
+
+        ends.push(end);
+    }
+
+    ends
+}
+
+pub fn count_sentences(text: &str) -> usize {
+    let ends = sentence_end_offsets(text);
+    let has_trailing_fragment = text[ends.last().copied().unwrap_or(0)..]
+        .chars()
+        .any(|ch| !ch.is_whitespace());
+    ends.len() + usize::from(has_trailing_fragment)
+}
+
+/// Byte offset just past the `word_limit`-th word.
+fn word_limit_offset(text: &str, word_limit: usize) -> Option<usize> {
+    let mut words_seen = 0;
+    let mut in_word = false;
+    for (idx, ch) in text.char_indices() {
+        if ch.is_whitespace() {
+            if in_word {
+                words_seen += 1;
+                in_word = false;
+                if words_seen == word_limit {
+                    return Some(idx);
+                }
+            }
+        } else {
+            in_word = true;
+        }
+    }
+    None
+}
+
+/// Truncates `text` to satisfy `limits`, cutting at the last sentence boundary
+/// that fits. When not even the first sentence fits the word limit, the text
+/// is cut at the word limit instead.
+pub fn truncate_to_limits(text: &str, limits: &OutputLengthLimits) -> String {
+    let text = text.trim();
+    let ends = sentence_end_offsets(text);
+
+    let mut cut = text.len();
+    if let Some(max_sentences) = limits.max_sentences {
+        if let Some(&end) = ends.get(max_sentences as usize - 1) {
+            cut = cut.min(end);
+        }
+    }
+
+    if let Some(max_words) = limits.max_words {
+        if let Some(word_cut) = word_limit_offset(&text[..cut], max_words as usize) {
+            cut = match ends.iter().rev().find(|&&end| end <= word_cut) {
+                Some(&sentence_end) => sentence_end,
+                None => word_cut,
+            };
+        }
+    }
+
+    text[..cut]
+        .trim_end()
+        .trim_end_matches([',', ';', ':'])
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sentence_boundaries_skip_abbreviations() {
+        let text = "Use a helper, e.g. a closure. Dr. Smith agreed! Then we shipped it? Yes.";
+        assert_eq!(count_sentences(text), 4);
+
+        let ends = sentence_end_offsets(text);
+        assert_eq!(&text[..ends[0]], "Use a helper, e.g. a closure.");
+        assert_eq!(
+            &text[..ends[1]],
+            "Use a helper, e.g. a closure. Dr. Smith agreed!"
+        );
+    }
+
+    #[test]
+    fn sentence_boundaries_ignore_decimals_and_handle_quotes() {
+        let text = "Version 3.5 shipped. He said \"done.\" Then J. R. R. Tolkien wrote more";
+        let ends = sentence_end_offsets(text);
+        assert_eq!(ends.len(), 2);
+        assert_eq!(&text[..ends[1]], "Version 3.5 shipped. He said \"done.\"");
+        assert_eq!(count_sentences(text), 3);
+    }
+
+    #[test]
+    fn truncates_to_sentence_limit() {
+        let limits = OutputLengthLimits::new(None, Some(2));
+        let text = "First point, i.e. the main one. Second point. Third point. Fourth.";
+        assert!(limits.is_exceeded_by(text));
+        assert_eq!(
+            truncate_to_limits(text, &limits),
+            "First point, i.e. the main one. Second point."
+        );
+    }
+
+    #[test]
+    fn word_limit_prefers_sentence_boundary() {
+        let limits = OutputLengthLimits::new(Some(6), None);
+        assert_eq!(
+            truncate_to_limits("One two three. Four five six seven.", &limits),
+            "One two three."
+        );
+        assert_eq!(
+            truncate_to_limits("One two three four five six seven eight.", &limits),
+            "One two three four five six"
+        );
+    }
+
+    #[test]
+    fn limits_within_bounds_are_not_exceeded() {
+        let limits = OutputLengthLimits::new(Some(10), Some(2));
+        assert!(!limits.is_exceeded_by("Short answer. Done."));
+        assert!(OutputLengthLimits::new(Some(0), None).is_empty());
+    }
+}
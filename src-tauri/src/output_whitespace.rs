@@ -0,0 +1,335 @@
+//! Output whitespace policy, expressed as small rules that run in order.
+//!
+//! Batch output runs the leading rule, then the trailing rule. Clipboard
+//! pastes on Windows additionally convert LF to CRLF. Streaming output emits
+//! the leading-adjusted text chunk by chunk and fixes up only the trailing
+//! boundary at finalization (see [`stream_trailing_adjustment`]).
+
+use serde::Serialize;
+use specta::Type;
+
+use crate::settings::{AppSettings, OutputWhitespaceMode, PasteMethod};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum WhitespaceRule {
+    TrimLeading,
+    AddLeadingSpace,
+    TrimTrailing,
+    AddTrailingSpace,
+    ConvertLfToCrlf,
+}
+
+impl WhitespaceRule {
+    /// Applies the rule. Every rule leaves empty text empty, so a trailing
+    /// space is never added to output the leading rule removed entirely.
+    pub fn apply(self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+        match self {
+            WhitespaceRule::TrimLeading => text.trim_start_matches(char::is_whitespace).to_string(),
+            WhitespaceRule::AddLeadingSpace => {
+                if text.starts_with(char::is_whitespace) {
+                    text.to_string()
+                } else {
+                    format!(" {}", text)
+                }
+            }
+            WhitespaceRule::TrimTrailing => text.trim_end_matches(char::is_whitespace).to_string(),
+            WhitespaceRule::AddTrailingSpace => {
+                if text.ends_with(char::is_whitespace) {
+                    text.to_string()
+                } else {
+                    format!("{} ", text)
+                }
+            }
+            // Normalize existing CRLF first so it is never doubled.
+            WhitespaceRule::ConvertLfToCrlf => text.replace("\r\n", "\n").replace('\n', "\r\n"),
+        }
+    }
+}
+
+pub fn leading_rule(mode: OutputWhitespaceMode) -> Option<WhitespaceRule> {
+    match mode {
+        OutputWhitespaceMode::Preserve => None,
+        OutputWhitespaceMode::RemoveIfPresent => Some(WhitespaceRule::TrimLeading),
+        OutputWhitespaceMode::AddIfMissing => Some(WhitespaceRule::AddLeadingSpace),
+    }
+}
+
+pub fn trailing_rule(mode: OutputWhitespaceMode) -> Option<WhitespaceRule> {
+    match mode {
+        OutputWhitespaceMode::Preserve => None,
+        OutputWhitespaceMode::RemoveIfPresent => Some(WhitespaceRule::TrimTrailing),
+        OutputWhitespaceMode::AddIfMissing => Some(WhitespaceRule::AddTrailingSpace),
+    }
+}
+
+pub fn policy_rules(
+    leading_mode: OutputWhitespaceMode,
+    trailing_mode: OutputWhitespaceMode,
+) -> Vec<WhitespaceRule> {
+    leading_rule(leading_mode)
+        .into_iter()
+        .chain(trailing_rule(trailing_mode))
+        .collect()
+}
+
+pub fn apply_rules(text: &str, rules: &[WhitespaceRule]) -> String {
+    rules
+        .iter()
+        .fold(text.to_string(), |current, rule| rule.apply(&current))
+}
+
+/// Whether a paste with these settings goes through the LF to CRLF
+/// conversion. Only clipboard pastes on Windows convert.
+pub fn converts_line_endings(settings: &AppSettings, paste_method: PasteMethod) -> bool {
+    cfg!(target_os = "windows")
+        && settings.convert_lf_to_crlf
+        && matches!(
+            paste_method,
+            PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert
+        )
+}
+
+/// Finalization fix-up for streamed output: delete the last `delete_chars`
+/// characters (Unicode scalars), then type `append`.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq, Type)]
+pub struct TrailingAdjustment {
+    pub delete_chars: usize,
+    pub append: String,
+}
+
+impl TrailingAdjustment {
+    pub fn is_none(&self) -> bool {
+        self.delete_chars == 0 && self.append.is_empty()
+    }
+
+    pub fn apply_to(&self, text: &str) -> String {
+        let keep = text.chars().count().saturating_sub(self.delete_chars);
+        let mut result: String = text.chars().take(keep).collect();
+        result.push_str(&self.append);
+        result
+    }
+}
+
+fn apply_rule(text: &str, rule: Option<WhitespaceRule>) -> String {
+    rule.map_or_else(|| text.to_string(), |rule| rule.apply(text))
+}
+
+fn trailing_whitespace_run(text: &str) -> &str {
+    &text[text.trim_end_matches(char::is_whitespace).len()..]
+}
+
+/// Adjustment that turns the streamed text into the batch output.
+///
+/// The stream emits `text` with only the leading rule applied. The trailing
+/// rule changes nothing but the trailing whitespace run, so replacing that
+/// run is enough: for every `text` and pair of modes,
+/// `stream_trailing_adjustment(text, l, t).apply_to(&streamed) ==
+/// apply_output_whitespace_policy(text, l, t)` where `streamed` is `text`
+/// after the leading rule. Only the differing tail of the run is deleted and
+/// retyped, so a trailing newline the policy replaces (rather than just
+/// extends or shortens) is converted too instead of being left in place.
+pub fn stream_trailing_adjustment(
+    text: &str,
+    leading_mode: OutputWhitespaceMode,
+    trailing_mode: OutputWhitespaceMode,
+) -> TrailingAdjustment {
+    let streamed = apply_rule(text, leading_rule(leading_mode));
+    let batch = apply_rule(&streamed, trailing_rule(trailing_mode));
+
+    let streamed_run = trailing_whitespace_run(&streamed);
+    let batch_run = trailing_whitespace_run(&batch);
+    let common = streamed_run
+        .chars()
+        .zip(batch_run.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    TrailingAdjustment {
+        delete_chars: streamed_run.chars().count() - common,
+        append: batch_run.chars().skip(common).collect(),
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct WhitespacePolicyStep {
+    pub rule: WhitespaceRule,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct WhitespacePolicyPreview {
+    /// The sample after the leading and trailing rules (what history keeps).
+    pub output: String,
+    /// What the target app receives with the current paste method.
+    pub pasted: String,
+    /// Rules that changed the text, in the order they ran.
+    pub steps: Vec<WhitespacePolicyStep>,
+    /// Fix-up a streaming provider applies at finalization for this sample.
+    pub stream_adjustment: TrailingAdjustment,
+}
+
+pub fn preview(sample: &str, settings: &AppSettings) -> WhitespacePolicyPreview {
+    let mut rules = policy_rules(
+        settings.output_whitespace_leading_mode,
+        settings.output_whitespace_trailing_mode,
+    );
+    let policy_rule_count = rules.len();
+    if converts_line_endings(settings, settings.paste_method) {
+        rules.push(WhitespaceRule::ConvertLfToCrlf);
+    }
+
+    let mut steps = Vec::new();
+    let mut current = sample.to_string();
+    let mut output = current.clone();
+    for (index, rule) in rules.into_iter().enumerate() {
+        let after = rule.apply(&current);
+        if after != current {
+            steps.push(WhitespacePolicyStep {
+                rule,
+                before: current,
+                after: after.clone(),
+            });
+        }
+        current = after;
+        if index + 1 == policy_rule_count {
+            output = current.clone();
+        }
+    }
+
+    WhitespacePolicyPreview {
+        output,
+        pasted: current,
+        steps,
+        stream_adjustment: stream_trailing_adjustment(
+            sample,
+            settings.output_whitespace_leading_mode,
+            settings.output_whitespace_trailing_mode,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{apply_output_whitespace_policy, get_default_settings};
+
+    const MODES: [OutputWhitespaceMode; 3] = [
+        OutputWhitespaceMode::Preserve,
+        OutputWhitespaceMode::RemoveIfPresent,
+        OutputWhitespaceMode::AddIfMissing,
+    ];
+    const SAMPLES: [&str; 9] = [
+        "",
+        " ",
+        "\n",
+        "hello",
+        " hello",
+        "hello ",
+        "hello\n",
+        "  hello \n",
+        "line one\nline two\r\n",
+    ];
+
+    #[test]
+    fn rules_keep_empty_text_empty() {
+        for rule in [
+            WhitespaceRule::TrimLeading,
+            WhitespaceRule::AddLeadingSpace,
+            WhitespaceRule::TrimTrailing,
+            WhitespaceRule::AddTrailingSpace,
+            WhitespaceRule::ConvertLfToCrlf,
+        ] {
+            assert_eq!(rule.apply(""), "");
+        }
+    }
+
+    #[test]
+    fn whitespace_only_text_trimmed_at_start_gets_no_trailing_space() {
+        assert_eq!(
+            apply_output_whitespace_policy(
+                "  \n",
+                OutputWhitespaceMode::RemoveIfPresent,
+                OutputWhitespaceMode::AddIfMissing,
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn crlf_conversion_does_not_double_existing_crlf() {
+        assert_eq!(
+            WhitespaceRule::ConvertLfToCrlf.apply("a\nb\r\nc"),
+            "a\r\nb\r\nc"
+        );
+    }
+
+    #[test]
+    fn streamed_output_matches_batch_output_for_all_modes() {
+        for leading in MODES {
+            for trailing in MODES {
+                for sample in SAMPLES {
+                    let streamed = apply_rule(sample, leading_rule(leading));
+                    let adjustment = stream_trailing_adjustment(sample, leading, trailing);
+                    assert_eq!(
+                        adjustment.apply_to(&streamed),
+                        apply_output_whitespace_policy(sample, leading, trailing),
+                        "sample {:?} with {:?}/{:?}",
+                        sample,
+                        leading,
+                        trailing
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn adjustment_only_retypes_the_differing_tail() {
+        let adjustment = stream_trailing_adjustment(
+            "hello \n",
+            OutputWhitespaceMode::Preserve,
+            OutputWhitespaceMode::RemoveIfPresent,
+        );
+        assert_eq!(
+            adjustment,
+            TrailingAdjustment {
+                delete_chars: 2,
+                append: String::new(),
+            }
+        );
+
+        let adjustment = stream_trailing_adjustment(
+            "hello",
+            OutputWhitespaceMode::Preserve,
+            OutputWhitespaceMode::AddIfMissing,
+        );
+        assert_eq!(adjustment.delete_chars, 0);
+        assert_eq!(adjustment.append, " ");
+        assert!(stream_trailing_adjustment(
+            "hello\n",
+            OutputWhitespaceMode::Preserve,
+            OutputWhitespaceMode::AddIfMissing,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn preview_lists_only_rules_that_changed_the_sample() {
+        let mut settings = get_default_settings();
+        settings.output_whitespace_leading_mode = OutputWhitespaceMode::RemoveIfPresent;
+        settings.output_whitespace_trailing_mode = OutputWhitespaceMode::AddIfMissing;
+
+        let preview = preview("hello", &settings);
+
+        assert_eq!(preview.output, "hello ");
+        assert_eq!(preview.steps.len(), 1);
+        assert_eq!(preview.steps[0].rule, WhitespaceRule::AddTrailingSpace);
+        assert_eq!(preview.steps[0].before, "hello");
+        assert_eq!(preview.stream_adjustment.append, " ");
+    }
+}
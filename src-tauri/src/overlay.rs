@@ -66,6 +66,8 @@ const RECORDING_OVERLAY_TASKBAR_VERTICAL_LIFT: f64 = 5.0;
 // Command Confirmation Overlay dimensions
 const COMMAND_CONFIRM_WIDTH: f64 = 520.0;
 const COMMAND_CONFIRM_HEIGHT: f64 = 280.0;
+const AI_REPLACE_CONFIRM_WIDTH: f64 = 640.0;
+const AI_REPLACE_CONFIRM_HEIGHT: f64 = 380.0;
 const VOICE_BUTTON_WIDTH: f64 = 80.0;
 const VOICE_BUTTON_HEIGHT: f64 = 80.0;
 const SONIOX_LIVE_PREVIEW_SMALL_WIDTH: f64 = 560.0;
@@ -88,12 +90,29 @@ struct OverlayStatePayload {
     state: String,
     decapitalize_eligible: bool,
     decapitalize_armed: bool,
+    privacy_mode: bool,
+    boost: bool,
 }
 
 #[derive(Serialize, Clone)]
 struct TransientMessageOverlayPayload {
     state: String,
     message: String,
+    profile: Option<ProfileSwitchDetails>,
+}
+
+/// Mode a profile switch landed in, shown under the profile name.
+#[derive(Serialize, Clone)]
+pub struct ProfileSwitchDetails {
+    pub language: String,
+    pub translate_to_english: bool,
+    pub post_process_enabled: bool,
+}
+
+#[derive(Serialize, Clone)]
+struct RemoteSttRetryingOverlayPayload {
+    attempt: u32,
+    max_attempts: u32,
 }
 
 #[derive(Serialize, Clone, Default, Type)]
@@ -237,6 +256,8 @@ fn build_overlay_state_payload(
         state: state.to_string(),
         decapitalize_eligible: indicator.eligible,
         decapitalize_armed: indicator.armed,
+        privacy_mode: crate::privacy_mode::is_active(),
+        boost: crate::boost::badge_visible(),
     }
 }
 
@@ -1165,7 +1186,7 @@ fn reassert_recording_overlay_window_geometry(app_handle: &AppHandle) {
 }
 
 pub fn show_positioned_recording_overlay_window(app_handle: &AppHandle) {
-    if !settings::get_settings(app_handle).recording_overlay_enabled {
+    if !recording_overlay_visible(&settings::get_settings(app_handle)) {
         return;
     }
 
@@ -1754,6 +1775,11 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// The overlay is enabled and not silenced by quiet hours.
+fn recording_overlay_visible(settings: &settings::AppSettings) -> bool {
+    settings.recording_overlay_enabled && !crate::quiet_hours::mutes_feedback(settings)
+}
+
 /// Shows the recording overlay window after applying the latest layout/state.
 pub fn show_recording_overlay(app_handle: &AppHandle) {
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
@@ -1765,7 +1791,8 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
 
     // Visibility is independent from the selected automatic/manual position.
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    crate::accessibility_announcer::announce_recording_started(app_handle, &settings);
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -1778,13 +1805,31 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// Shows the paused state while a recording is paused with the pause/resume shortcut.
+pub fn show_paused_overlay(app_handle: &AppHandle) {
+    let settings = settings::get_settings(app_handle);
+    if !recording_overlay_visible(&settings) {
+        return;
+    }
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let payload = build_overlay_state_payload("paused", &settings);
+        let _ = overlay_window.emit("show-overlay", payload);
+    }
+}
+
 /// Shows the transcribing overlay window
 pub fn show_transcribing_overlay(app_handle: &AppHandle) {
+    crate::session_manager::set_processing_stage(
+        app_handle,
+        crate::session_manager::ProcessingStage::Transcribing,
+    );
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
     plus_overlay_state::invalidate_error_overlay_auto_hide();
 
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    crate::accessibility_announcer::announce_stage(app_handle, &settings, "transcribing");
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -1799,11 +1844,16 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
 
 /// Shows the sending overlay window (for remote API calls)
 pub fn show_sending_overlay(app_handle: &AppHandle) {
+    crate::session_manager::set_processing_stage(
+        app_handle,
+        crate::session_manager::ProcessingStage::Transcribing,
+    );
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
     plus_overlay_state::invalidate_error_overlay_auto_hide();
 
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    crate::accessibility_announcer::announce_stage(app_handle, &settings, "sending");
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -1816,13 +1866,41 @@ pub fn show_sending_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// Shows the retrying overlay while a transient Remote STT failure is retried.
+/// `attempt` is the upcoming attempt number (2 for the first retry).
+pub fn show_remote_stt_retrying_overlay(app_handle: &AppHandle, attempt: u32, max_attempts: u32) {
+    // Cancel pending error auto-hide timers so a new active overlay is not hidden.
+    plus_overlay_state::invalidate_error_overlay_auto_hide();
+
+    let settings = settings::get_settings(app_handle);
+    if !recording_overlay_visible(&settings) {
+        return;
+    }
+
+    set_recording_overlay_default_layout(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let payload = RemoteSttRetryingOverlayPayload {
+            attempt,
+            max_attempts,
+        };
+        let _ = overlay_window.emit("remote-stt-retrying", payload);
+        show_positioned_recording_overlay_window(app_handle);
+    }
+}
+
 /// Shows the thinking overlay window (for LLM processing)
 pub fn show_thinking_overlay(app_handle: &AppHandle) {
+    crate::session_manager::set_processing_stage(
+        app_handle,
+        crate::session_manager::ProcessingStage::Thinking,
+    );
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
     plus_overlay_state::invalidate_error_overlay_auto_hide();
 
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    crate::accessibility_announcer::announce_stage(app_handle, &settings, "thinking");
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -1837,11 +1915,16 @@ pub fn show_thinking_overlay(app_handle: &AppHandle) {
 
 /// Shows the finalizing overlay window (for Soniox live stop/finalization)
 pub fn show_finalizing_overlay(app_handle: &AppHandle) {
+    crate::session_manager::set_processing_stage(
+        app_handle,
+        crate::session_manager::ProcessingStage::Finalizing,
+    );
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
     plus_overlay_state::invalidate_error_overlay_auto_hide();
 
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    crate::accessibility_announcer::announce_stage(app_handle, &settings, "finalizing");
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -2354,12 +2437,19 @@ fn calculate_command_confirm_position(app_handle: &AppHandle) -> Option<(f64, f6
     Some((x, y))
 }
 
-/// Calculates bottom-center position for the floating voice activation button window.
+/// Calculates the position for the floating voice activation button window:
+/// the saved drag position for the monitor layout, or bottom-center of the
+/// preferred monitor.
 fn calculate_voice_button_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
-    let monitor = get_monitor_with_cursor(app_handle)?;
+    let settings = settings::get_settings(app_handle);
+    if let Some(position) = saved_voice_button_position(app_handle, &settings) {
+        return Some(position);
+    }
+
+    let monitor = get_voice_button_preferred_monitor(app_handle, &settings.voice_button_monitor)?;
     let bounds = get_monitor_logical_auto_position_bounds(
         &monitor,
-        settings::get_settings(app_handle).auto_position_allow_reserved_areas,
+        settings.auto_position_allow_reserved_areas,
     );
 
     let x = bounds.x + (bounds.width - VOICE_BUTTON_WIDTH) / 2.0;
@@ -2367,6 +2457,48 @@ fn calculate_voice_button_position(app_handle: &AppHandle) -> Option<(f64, f64)>
     Some((x, y))
 }
 
+/// Saved position clamped onto the work area of the nearest monitor, so a
+/// button left on an unplugged display comes back on screen.
+fn saved_voice_button_position(
+    app_handle: &AppHandle,
+    settings: &settings::AppSettings,
+) -> Option<(f64, f64)> {
+    let monitors = app_handle.available_monitors().ok()?;
+    let layout = crate::voice_button_position::layout_key(&monitors);
+    let saved =
+        crate::voice_button_position::saved_position(&settings.voice_button_positions, &layout)?;
+    let monitor = get_monitor_for_logical_point(
+        app_handle,
+        saved.x + VOICE_BUTTON_WIDTH / 2.0,
+        saved.y + VOICE_BUTTON_HEIGHT / 2.0,
+    )?;
+    let bounds = get_monitor_logical_auto_position_bounds(&monitor, false);
+    Some(crate::voice_button_position::clamp_into(
+        saved.x,
+        saved.y,
+        VOICE_BUTTON_WIDTH,
+        VOICE_BUTTON_HEIGHT,
+        (bounds.x, bounds.y, bounds.width, bounds.height),
+    ))
+}
+
+fn get_voice_button_preferred_monitor(
+    app_handle: &AppHandle,
+    preference: &str,
+) -> Option<tauri::Monitor> {
+    use crate::voice_button_position::MonitorPreference;
+
+    let preferred = match MonitorPreference::parse(preference) {
+        Some(MonitorPreference::Primary) => app_handle.primary_monitor().ok().flatten(),
+        Some(MonitorPreference::Index(index)) => app_handle
+            .available_monitors()
+            .ok()
+            .and_then(|monitors| monitors.into_iter().nth(index)),
+        Some(MonitorPreference::Cursor) | None => None,
+    };
+    preferred.or_else(|| get_monitor_with_cursor(app_handle))
+}
+
 pub fn update_command_confirm_position(app_handle: &AppHandle) {
     if let Some(window) = app_handle.get_webview_window("command_confirm") {
         if let Some((x, y)) = calculate_command_confirm_position(app_handle) {
@@ -2379,6 +2511,7 @@ pub fn update_command_confirm_position(app_handle: &AppHandle) {
 pub fn update_voice_activation_button_position(app_handle: &AppHandle) {
     if let Some(window) = app_handle.get_webview_window("voice_activation_button") {
         if let Some((x, y)) = calculate_voice_button_position(app_handle) {
+            crate::voice_button_position::suppress_move_tracking();
             let _ = window.set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
         }
     }
@@ -2404,6 +2537,7 @@ pub fn show_voice_activation_button_window(app_handle: &AppHandle) -> Result<(),
         existing
     } else if let Some((x, y)) = initial_position {
         is_new_window = true;
+        crate::voice_button_position::suppress_move_tracking();
         info!(
             "Creating new voice activation button window at ({}, {})",
             x, y
@@ -2450,6 +2584,7 @@ pub fn show_voice_activation_button_window(app_handle: &AppHandle) -> Result<(),
         }
     } else {
         is_new_window = true;
+        crate::voice_button_position::suppress_move_tracking();
         info!("Primary position unavailable; using fallback position (100, 100)");
         // Fallback if monitor detection fails.
         let mut builder = WebviewWindowBuilder::new(
@@ -2499,6 +2634,7 @@ pub fn show_voice_activation_button_window(app_handle: &AppHandle) -> Result<(),
 
     // Only set position for new windows - preserve user's drag position for existing ones
     if is_new_window {
+        crate::voice_button_position::suppress_move_tracking();
         let _ = window.set_size(tauri::Size::Logical(tauri::LogicalSize {
             width: VOICE_BUTTON_WIDTH,
             height: VOICE_BUTTON_HEIGHT,
@@ -2643,17 +2779,89 @@ pub fn show_command_confirm_overlay(
     }
 }
 
+/// Opens the AI Replace accept/reject preview centered on the cursor's
+/// monitor. The window fetches its payload once mounted.
+pub fn show_ai_replace_confirm_window(app_handle: &AppHandle) -> Result<(), String> {
+    let window_label = crate::ai_replace_confirm::AI_REPLACE_CONFIRM_WINDOW_LABEL;
+    if let Some(existing) = app_handle.get_webview_window(window_label) {
+        let _ = existing.destroy();
+    }
+
+    let monitor = get_monitor_with_cursor(app_handle)
+        .ok_or("Could not find a monitor for the AI Replace preview")?;
+    let bounds = get_monitor_logical_auto_position_bounds(
+        &monitor,
+        settings::get_settings(app_handle).auto_position_allow_reserved_areas,
+    );
+    let x = bounds.x + (bounds.width - AI_REPLACE_CONFIRM_WIDTH) / 2.0;
+    let y = bounds.y + (bounds.height - AI_REPLACE_CONFIRM_HEIGHT) / 2.0;
+
+    let mut builder = tauri::WebviewWindowBuilder::new(
+        app_handle,
+        window_label,
+        tauri::WebviewUrl::App("src/ai-replace-confirm/index.html".into()),
+    )
+    .title("AI Replace")
+    .position(x, y)
+    .inner_size(AI_REPLACE_CONFIRM_WIDTH, AI_REPLACE_CONFIRM_HEIGHT)
+    .resizable(false)
+    .maximizable(false)
+    .minimizable(false)
+    .closable(false)
+    .decorations(false)
+    .shadow(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .transparent(true)
+    .focused(true);
+
+    #[cfg(target_os = "windows")]
+    match crate::webview_runtime::config(app_handle) {
+        Ok(runtime) => {
+            builder = builder.data_directory(runtime.data_directory);
+            if let Some(browser_args) = runtime.additional_browser_args {
+                builder = builder.additional_browser_args(&browser_args);
+            }
+        }
+        Err(error) => log::error!("Failed to configure the shared WebView runtime: {error}"),
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    if let Some(data_dir) = crate::portable::data_dir() {
+        builder = builder.data_directory(data_dir.join("webview"));
+    }
+
+    let window = builder
+        .build()
+        .map_err(|e| format!("Failed to create AI Replace preview window: {}", e))?;
+    crate::webview_hardening::disable_browser_accelerator_keys(&window);
+    #[cfg(target_os = "windows")]
+    force_overlay_topmost(&window);
+    let _ = window.set_focus();
+    Ok(())
+}
+
 fn show_transient_message_overlay(
     app_handle: &AppHandle,
     overlay_state: &str,
     message: &str,
     auto_hide_ms: u64,
+) {
+    show_transient_overlay(app_handle, overlay_state, message, None, auto_hide_ms);
+}
+
+fn show_transient_overlay(
+    app_handle: &AppHandle,
+    overlay_state: &str,
+    message: &str,
+    profile: Option<ProfileSwitchDetails>,
+    auto_hide_ms: u64,
 ) {
     // Cancel pending error auto-hide timers so a new active overlay is not hidden.
     plus_overlay_state::invalidate_error_overlay_auto_hide();
 
     let settings = settings::get_settings(app_handle);
-    if !settings.recording_overlay_enabled {
+    if !recording_overlay_visible(&settings) {
         return;
     }
 
@@ -2667,6 +2875,16 @@ fn show_transient_message_overlay(
         return;
     }
 
+    emit_transient_message_overlay(app_handle, overlay_state, message, profile, auto_hide_ms);
+}
+
+fn emit_transient_message_overlay(
+    app_handle: &AppHandle,
+    overlay_state: &str,
+    message: &str,
+    profile: Option<ProfileSwitchDetails>,
+    auto_hide_ms: u64,
+) {
     set_recording_overlay_default_layout(app_handle);
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
@@ -2675,6 +2893,7 @@ fn show_transient_message_overlay(
         let payload = TransientMessageOverlayPayload {
             state: overlay_state.to_string(),
             message: message.to_string(),
+            profile,
         };
         let _ = overlay_window.emit("show-message-overlay", payload);
         show_positioned_recording_overlay_window(app_handle);
@@ -2704,9 +2923,20 @@ fn show_transient_message_overlay(
 // ============================================================================
 
 /// Shows a brief overlay notification when switching transcription profiles.
-/// Uses the existing recording overlay to display the profile name, then auto-hides.
-pub fn show_profile_switch_overlay(app_handle: &AppHandle, profile_name: &str) {
-    show_transient_message_overlay(app_handle, "profile_switch", profile_name, 1500);
+/// Uses the existing recording overlay to display the profile name and its
+/// language/translation/post-processing mode, then auto-hides.
+pub fn show_profile_switch_overlay(
+    app_handle: &AppHandle,
+    profile_name: &str,
+    details: ProfileSwitchDetails,
+) {
+    show_transient_overlay(
+        app_handle,
+        "profile_switch",
+        profile_name,
+        Some(details),
+        2000,
+    );
 }
 
 /// Shows a brief overlay notification when the selected microphone changes.
@@ -2715,6 +2945,68 @@ pub fn show_microphone_switch_overlay(app_handle: &AppHandle, microphone_name: &
     show_transient_message_overlay(app_handle, "microphone_switch", microphone_name, 1500);
 }
 
+/// Shows a brief overlay notification with the new dictation language.
+pub fn show_language_switch_overlay(app_handle: &AppHandle, language_name: &str) {
+    show_transient_message_overlay(app_handle, "profile_switch", language_name, 1500);
+}
+
+/// Shows a brief overlay notice when a boost is armed for the next dictation.
+pub fn show_boost_overlay(app_handle: &AppHandle, label: &str) {
+    show_transient_message_overlay(app_handle, "profile_switch", label, 1500);
+}
+
+/// Shows a brief overlay notice when privacy mode is toggled. Reuses the
+/// profile-switch layout, which renders a single line of text.
+pub fn show_privacy_mode_overlay(app_handle: &AppHandle, active: bool) {
+    let message = if active {
+        "Privacy mode on"
+    } else {
+        "Privacy mode off"
+    };
+    show_transient_message_overlay(app_handle, "profile_switch", message, 1500);
+}
+
+/// Shows a brief overlay notice when quiet hours ignore a recording shortcut.
+pub fn show_quiet_hours_overlay(app_handle: &AppHandle, end_time: &str) {
+    let message = format!("Quiet hours until {}", end_time);
+    show_transient_message_overlay(app_handle, "profile_switch", &message, 2500);
+}
+
+/// Shows a brief overlay notice when a send-to-extension result went to the
+/// clipboard because the extension was offline.
+pub fn show_extension_offline_clipboard_overlay(app_handle: &AppHandle) {
+    show_transient_message_overlay(
+        app_handle,
+        "profile_switch",
+        "Extension offline, text copied to clipboard",
+        2500,
+    );
+}
+
+/// Shows a brief overlay notice when "Undo Last Paste" has nothing to erase.
+pub fn show_undo_paste_overlay(app_handle: &AppHandle, message: &str) {
+    show_transient_message_overlay(app_handle, "undo_paste", message, 1500);
+}
+
+/// Replaces the closing overlay after a paste when the transcription confidence
+/// is below `low_confidence_warning_threshold`, so the user reviews the text.
+/// Called while the finished operation still owns the overlay, hence no Idle check.
+pub fn show_low_confidence_overlay(app_handle: &AppHandle, confidence: f32) {
+    plus_overlay_state::invalidate_error_overlay_auto_hide();
+
+    let settings = settings::get_settings(app_handle);
+    if !recording_overlay_visible(&settings) {
+        hide_recording_overlay(app_handle);
+        return;
+    }
+
+    let message = format!(
+        "Low confidence ({}%) — review text",
+        (confidence * 100.0).round() as u32
+    );
+    emit_transient_message_overlay(app_handle, "low_confidence", &message, None, 4000);
+}
+
 pub fn emit_recording_overlay_position_settings_changed(app_handle: &AppHandle) {
     let _ = app_handle.emit("recording-overlay-position-settings-changed", ());
 }
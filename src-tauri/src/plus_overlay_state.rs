@@ -49,6 +49,9 @@ pub enum OverlayErrorCategory {
     ParseError,
     ExtensionOffline,
     MicrophoneUnavailable,
+    /// The input device went away while recording.
+    MicrophoneDisconnected,
+    ModelMissing,
     Unknown,
 }
 
@@ -68,6 +71,8 @@ impl OverlayErrorCategory {
             OverlayErrorCategory::ParseError => "Invalid response",
             OverlayErrorCategory::ExtensionOffline => "Extension offline",
             OverlayErrorCategory::MicrophoneUnavailable => "Mic unavailable",
+            OverlayErrorCategory::MicrophoneDisconnected => "Mic disconnected",
+            OverlayErrorCategory::ModelMissing => "Model file missing",
             OverlayErrorCategory::Unknown => "Transcription failed",
         }
     }
@@ -152,6 +157,7 @@ pub enum OverlayCanonicalErrorCode {
     EParse,
     EExtensionOffline,
     EMicUnavailable,
+    EModelMissing,
     EUnknown,
 }
 
@@ -168,6 +174,7 @@ impl OverlayCanonicalErrorCode {
             OverlayCanonicalErrorCode::EParse => "Invalid response",
             OverlayCanonicalErrorCode::EExtensionOffline => "Extension offline",
             OverlayCanonicalErrorCode::EMicUnavailable => "Mic unavailable",
+            OverlayCanonicalErrorCode::EModelMissing => "Model file missing",
             OverlayCanonicalErrorCode::EUnknown => "Transcription failed",
         }
     }
@@ -177,7 +184,8 @@ impl OverlayCanonicalErrorCode {
             OverlayCanonicalErrorCode::EAuth
             | OverlayCanonicalErrorCode::EBadRequest
             | OverlayCanonicalErrorCode::EBilling
-            | OverlayCanonicalErrorCode::EMicUnavailable => false,
+            | OverlayCanonicalErrorCode::EMicUnavailable
+            | OverlayCanonicalErrorCode::EModelMissing => false,
             OverlayCanonicalErrorCode::ERateLimit
             | OverlayCanonicalErrorCode::ETimeout
             | OverlayCanonicalErrorCode::ENetwork
@@ -216,6 +224,13 @@ pub struct OverlayRetryAction {
     pub label: &'static str,
 }
 
+fn open_models_action() -> OverlayRetryAction {
+    OverlayRetryAction {
+        command: "open_models_settings",
+        label: "Open models",
+    }
+}
+
 /// Extended overlay payload with error information
 #[derive(Clone, Debug, Serialize)]
 pub struct OverlayPayload {
@@ -231,6 +246,9 @@ pub struct OverlayPayload {
 }
 
 fn detect_provider(err_lower: &str) -> OverlayErrorProvider {
+    if err_lower.contains("model file missing") {
+        return OverlayErrorProvider::Local;
+    }
     if err_lower.contains("waiting for soniox live session completion")
         || err_lower.contains("soniox live session timed out")
         || err_lower.contains("soniox live session join failed")
@@ -257,6 +275,9 @@ fn detect_provider(err_lower: &str) -> OverlayErrorProvider {
 }
 
 fn detect_transport(err_lower: &str) -> OverlayErrorTransport {
+    if err_lower.contains("model file missing") {
+        return OverlayErrorTransport::Local;
+    }
     if err_lower.contains("waiting for soniox live session completion")
         || err_lower.contains("soniox live session timed out")
         || err_lower.contains("soniox live session join failed")
@@ -383,6 +404,9 @@ fn detect_canonical_code(
     status_code: Option<u16>,
     provider: &OverlayErrorProvider,
 ) -> OverlayCanonicalErrorCode {
+    if err_lower.contains("model file missing") {
+        return OverlayCanonicalErrorCode::EModelMissing;
+    }
     if let Some(status) = status_code {
         return match status {
             401 | 403 => OverlayCanonicalErrorCode::EAuth,
@@ -504,6 +528,7 @@ fn canonical_to_category(code: &OverlayCanonicalErrorCode) -> OverlayErrorCatego
         OverlayCanonicalErrorCode::EParse => OverlayErrorCategory::ParseError,
         OverlayCanonicalErrorCode::EExtensionOffline => OverlayErrorCategory::ExtensionOffline,
         OverlayCanonicalErrorCode::EMicUnavailable => OverlayErrorCategory::MicrophoneUnavailable,
+        OverlayCanonicalErrorCode::EModelMissing => OverlayErrorCategory::ModelMissing,
         OverlayCanonicalErrorCode::EUnknown => OverlayErrorCategory::Unknown,
     }
 }
@@ -512,6 +537,9 @@ fn detect_specific_category(
     err_lower: &str,
     canonical_code: &OverlayCanonicalErrorCode,
 ) -> OverlayErrorCategory {
+    if matches!(canonical_code, OverlayCanonicalErrorCode::EModelMissing) {
+        return OverlayErrorCategory::ModelMissing;
+    }
     if err_lower.contains("certificate")
         || err_lower.contains("unknownissuer")
         || err_lower.contains("certnotvalidforname")
@@ -570,6 +598,7 @@ fn build_display_code(
         OverlayCanonicalErrorCode::EParse => "E_PARSE",
         OverlayCanonicalErrorCode::EExtensionOffline => "E_EXT",
         OverlayCanonicalErrorCode::EMicUnavailable => "E_MIC",
+        OverlayCanonicalErrorCode::EModelMissing => "E_MODEL",
         OverlayCanonicalErrorCode::EUnknown => "E_UNKNOWN",
     };
     format!("{} {}", provider.code_prefix(), canonical_label)
@@ -598,7 +627,12 @@ fn build_error_envelope_from_string(err_string: &str) -> OverlayErrorEnvelope {
     let err_lower = err_string.to_lowercase();
     let provider = detect_provider(&err_lower);
     let transport = detect_transport(&err_lower);
-    let status_code = extract_3_digit_status_code(err_string);
+    // Model paths can contain digits that look like HTTP status codes.
+    let status_code = if err_lower.contains("model file missing") {
+        None
+    } else {
+        extract_3_digit_status_code(err_string)
+    };
     let provider_code = detect_provider_code(&err_lower);
     let canonical_code = detect_canonical_code(&err_lower, status_code, &provider);
     let phase = detect_phase(&err_lower);
@@ -643,13 +677,19 @@ fn build_default_envelope_from_category(
         OverlayErrorCategory::ServerError => OverlayCanonicalErrorCode::EServer,
         OverlayErrorCategory::ParseError => OverlayCanonicalErrorCode::EParse,
         OverlayErrorCategory::ExtensionOffline => OverlayCanonicalErrorCode::EExtensionOffline,
-        OverlayErrorCategory::MicrophoneUnavailable => OverlayCanonicalErrorCode::EMicUnavailable,
+        OverlayErrorCategory::MicrophoneUnavailable
+        | OverlayErrorCategory::MicrophoneDisconnected => {
+            OverlayCanonicalErrorCode::EMicUnavailable
+        }
+        OverlayErrorCategory::ModelMissing => OverlayCanonicalErrorCode::EModelMissing,
         OverlayErrorCategory::Unknown => OverlayCanonicalErrorCode::EUnknown,
     };
 
     let provider = match category {
         OverlayErrorCategory::ExtensionOffline => OverlayErrorProvider::Extension,
-        OverlayErrorCategory::MicrophoneUnavailable => OverlayErrorProvider::Local,
+        OverlayErrorCategory::MicrophoneUnavailable
+        | OverlayErrorCategory::MicrophoneDisconnected
+        | OverlayErrorCategory::ModelMissing => OverlayErrorProvider::Local,
         _ => OverlayErrorProvider::Unknown,
     };
     let transport = if matches!(provider, OverlayErrorProvider::Local) {
@@ -683,6 +723,15 @@ fn show_error_overlay_internal(
     retry_session_id: Option<u64>,
 ) -> bool {
     let settings = crate::settings::get_settings(app);
+    crate::audio_feedback::emit_visual_feedback(
+        app,
+        crate::audio_feedback::VisualFeedbackKind::Error,
+    );
+    crate::accessibility_announcer::announce_error(
+        app,
+        &settings,
+        error_message.as_deref().unwrap_or(category.display_text()),
+    );
     if !settings.error_feedback_enabled
         || !settings.recording_overlay_enabled
     {
@@ -824,7 +873,14 @@ fn show_categorized_error_overlay_internal(
     let mut envelope = build_error_envelope_from_string(err_string);
     let err_lower = err_string.to_lowercase();
     let category = detect_specific_category(&err_lower, &envelope.canonical_code);
-    envelope.user_message = category.display_text().to_string();
+    let retry_action = if matches!(category, OverlayErrorCategory::ModelMissing) {
+        // Keep the file path visible; retrying cannot help until the model is restored.
+        envelope.user_message = err_string.trim().to_string();
+        Some(open_models_action())
+    } else {
+        envelope.user_message = category.display_text().to_string();
+        retry_action
+    };
     envelope.context = context;
     envelope.configuration_target = configuration_target;
     log::error!(
@@ -844,9 +900,20 @@ fn show_categorized_error_overlay_internal(
 
 /// Show error overlay for microphone unavailability.
 /// This is called when the microphone cannot be opened (e.g., device busy, permissions, etc.)
-pub fn show_mic_error_overlay(app: &AppHandle) {
-    log::warn!("Showing microphone error overlay");
-    show_error_overlay(app, OverlayErrorCategory::MicrophoneUnavailable);
+/// A known `failure` replaces the generic text with an actionable message.
+pub fn show_mic_error_overlay(
+    app: &AppHandle,
+    failure: Option<crate::audio_toolkit::MicrophoneOpenFailure>,
+) {
+    log::warn!("Showing microphone error overlay ({:?})", failure);
+    match failure.and_then(|failure| failure.user_message()) {
+        Some(message) => show_error_overlay_with_message(
+            app,
+            OverlayErrorCategory::MicrophoneUnavailable,
+            message,
+        ),
+        None => show_error_overlay(app, OverlayErrorCategory::MicrophoneUnavailable),
+    }
 }
 
 #[cfg(test)]
@@ -859,6 +926,19 @@ mod tests {
         detect_specific_category(&err_lower, &envelope.canonical_code)
     }
 
+    #[test]
+    fn test_categorize_model_missing_ignores_digits_in_path() {
+        let err = "Model file missing: /models/canary-180m-flash (model directory is missing)";
+        let envelope = build_error_envelope_from_string(err);
+
+        assert!(matches!(
+            categorize_error(err),
+            OverlayErrorCategory::ModelMissing
+        ));
+        assert_eq!(envelope.display_code, "LOCAL E_MODEL");
+        assert!(!envelope.retryable);
+    }
+
     #[test]
     fn test_categorize_tls_certificate() {
         assert!(matches!(
@@ -1,28 +1,72 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use tauri::Manager;
 
 static PORTABLE_DATA_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+static PROFILE_NAME: OnceLock<Option<String>> = OnceLock::new();
 
-pub fn init() {
-    PORTABLE_DATA_DIR.get_or_init(|| {
-        let exe_path = std::env::current_exe().ok()?;
-        let exe_dir = exe_path.parent()?;
+/// Resolves the data directory override. An explicit `--profile-dir` wins over
+/// portable mode so a second, isolated instance can run next to the first.
+/// Fails when the profile dir cannot be created, leaving nothing overridden.
+pub fn init(profile_dir: Option<&Path>) -> Result<(), String> {
+    let Some(profile_dir) = profile_dir else {
+        PORTABLE_DATA_DIR.get_or_init(portable_data_dir);
+        return Ok(());
+    };
 
-        if exe_dir.join("portable").exists() {
-            let data_dir = exe_dir.join("Data");
-            std::fs::create_dir_all(&data_dir).ok()?;
-            Some(data_dir)
-        } else {
-            None
-        }
-    });
+    let data_dir = std::path::absolute(profile_dir)
+        .and_then(|dir| std::fs::create_dir_all(&dir).map(|()| dir))
+        .map_err(|e| format!("Cannot use profile dir {}: {}", profile_dir.display(), e))?;
+    let name = profile_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| profile_dir.to_string_lossy().into_owned());
+
+    PROFILE_NAME.get_or_init(|| Some(name));
+    PORTABLE_DATA_DIR.get_or_init(|| Some(data_dir));
+    Ok(())
+}
+
+/// `Data` next to the executable when a `portable` marker file exists.
+fn portable_data_dir() -> Option<PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    let exe_dir = exe_path.parent()?;
+
+    if exe_dir.join("portable").exists() {
+        let data_dir = exe_dir.join("Data");
+        std::fs::create_dir_all(&data_dir).ok()?;
+        Some(data_dir)
+    } else {
+        None
+    }
 }
 
 pub fn data_dir() -> Option<&'static PathBuf> {
     PORTABLE_DATA_DIR.get().and_then(|dir| dir.as_ref())
 }
 
+/// Display name of the `--profile-dir` instance (the directory name), if any.
+pub fn profile_name() -> Option<&'static str> {
+    PROFILE_NAME.get().and_then(|name| name.as_deref())
+}
+
+/// Stable namespace for per-profile resources that live outside the data dir
+/// (credential vault entries, cross-instance shortcut claims).
+pub fn profile_namespace() -> Option<String> {
+    use sha2::{Digest, Sha256};
+
+    let name = profile_name()?;
+    let dir = data_dir()?;
+    let digest = Sha256::digest(dir.to_string_lossy().as_bytes());
+    let suffix: String = digest[..4].iter().map(|b| format!("{:02x}", b)).collect();
+    let sanitized: String = name
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect();
+    Some(format!("{}-{}", sanitized, suffix))
+}
+
 pub fn app_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, tauri::Error> {
     if let Some(dir) = data_dir() {
         Ok(dir.clone())
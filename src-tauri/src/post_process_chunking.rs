@@ -0,0 +1,278 @@
+//! Chunked LLM post-processing of long transcripts.
+//!
+//! When the rendered post-processing prompt is longer than
+//! `post_process_chunk_threshold_chars`, `${output}` is cut into chunks at
+//! paragraph and then sentence boundaries. Each chunk is post-processed on its
+//! own and the results are joined with the whitespace that originally
+//! separated the chunks.
+
+use crate::output_length_limit::sentence_end_offsets;
+
+/// A piece of the transcript sent to the LLM on its own.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextChunk {
+    pub text: String,
+    /// Whitespace that followed the chunk in the original text
+    pub separator: String,
+}
+
+/// Byte ranges of a piece of text: `start..body_end` is its content and
+/// `body_end..end` the whitespace that follows it.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    start: usize,
+    body_end: usize,
+    end: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Boundary {
+    Paragraph,
+    Sentence,
+    Word,
+    Char,
+}
+
+impl Boundary {
+    fn finer(self) -> Option<Self> {
+        match self {
+            Boundary::Paragraph => Some(Boundary::Sentence),
+            Boundary::Sentence => Some(Boundary::Word),
+            Boundary::Word => Some(Boundary::Char),
+            Boundary::Char => None,
+        }
+    }
+}
+
+/// Splits `text` into chunks of at most `max_chars` characters, preferring
+/// paragraph breaks, then sentence ends, then spaces. A single word longer
+/// than `max_chars` is cut between characters.
+pub fn split_into_chunks(text: &str, max_chars: usize) -> Vec<TextChunk> {
+    let max_chars = max_chars.max(1);
+    let body_end = text.trim_end().len();
+    let whole = Span {
+        start: 0,
+        body_end,
+        end: text.len(),
+    };
+    if body_end == 0 {
+        return Vec::new();
+    }
+
+    let spans = refine(text, whole, max_chars, Boundary::Paragraph);
+    let mut chunks = Vec::new();
+    let mut current: Option<(Span, usize)> = None;
+    for span in spans {
+        let span_chars = text[span.start..span.body_end].chars().count();
+        current = match current {
+            Some((chunk, chunk_chars)) => {
+                let joined_chars =
+                    chunk_chars + text[chunk.body_end..span.start].chars().count() + span_chars;
+                if joined_chars <= max_chars {
+                    Some((
+                        Span {
+                            start: chunk.start,
+                            body_end: span.body_end,
+                            end: span.end,
+                        },
+                        joined_chars,
+                    ))
+                } else {
+                    chunks.push(chunk_at(text, chunk));
+                    Some((span, span_chars))
+                }
+            }
+            None => Some((span, span_chars)),
+        };
+    }
+    if let Some((chunk, _)) = current {
+        chunks.push(chunk_at(text, chunk));
+    }
+    chunks
+}
+
+/// Joins processed chunks with the separators of the chunks they came from.
+pub fn stitch_chunks(chunks: &[TextChunk], outputs: &[String]) -> String {
+    let mut stitched = String::new();
+    for (chunk, output) in chunks.iter().zip(outputs) {
+        stitched.push_str(output.trim());
+        stitched.push_str(&chunk.separator);
+    }
+    stitched.trim_end().to_string()
+}
+
+fn chunk_at(text: &str, span: Span) -> TextChunk {
+    TextChunk {
+        text: text[span.start..span.body_end].to_string(),
+        separator: text[span.body_end..span.end].to_string(),
+    }
+}
+
+/// Cuts `span` at `boundary` until every piece fits `max_chars`.
+fn refine(text: &str, span: Span, max_chars: usize, boundary: Boundary) -> Vec<Span> {
+    if text[span.start..span.body_end].chars().count() <= max_chars {
+        return vec![span];
+    }
+
+    let body = &text[span.start..span.body_end];
+    let ends: Vec<usize> = match boundary {
+        Boundary::Paragraph => paragraph_ends(body),
+        Boundary::Sentence => sentence_end_offsets(body),
+        Boundary::Word => word_ends(body),
+        Boundary::Char => char_ends(body, max_chars),
+    };
+    let pieces = split_span(text, span, &ends);
+    match boundary.finer() {
+        Some(finer) => pieces
+            .into_iter()
+            .flat_map(|piece| refine(text, piece, max_chars, finer))
+            .collect(),
+        None => pieces,
+    }
+}
+
+/// Splits `span` after each of `ends` (offsets into its body), attaching the
+/// whitespace that follows a cut to the piece before it.
+fn split_span(text: &str, span: Span, ends: &[usize]) -> Vec<Span> {
+    let mut pieces = Vec::new();
+    let mut start = span.start;
+    for &end in ends {
+        let body_end = span.start + end;
+        if body_end <= start || body_end >= span.body_end {
+            continue;
+        }
+        let rest = &text[body_end..span.body_end];
+        let end = body_end + (rest.len() - rest.trim_start().len());
+        pieces.push(Span {
+            start,
+            body_end,
+            end,
+        });
+        start = end;
+    }
+    pieces.push(Span {
+        start,
+        body_end: span.body_end,
+        end: span.end,
+    });
+    pieces
+}
+
+/// Offsets where a blank line starts.
+fn paragraph_ends(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut run_start = None;
+    let mut newlines = 0;
+    for (idx, ch) in text.char_indices() {
+        if ch.is_whitespace() {
+            run_start.get_or_insert(idx);
+            newlines += usize::from(ch == '\n');
+        } else {
+            if newlines >= 2 {
+                ends.extend(run_start);
+            }
+            run_start = None;
+            newlines = 0;
+        }
+    }
+    ends
+}
+
+/// Offsets where each whitespace run starts.
+fn word_ends(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut previous_whitespace = true;
+    for (idx, ch) in text.char_indices() {
+        let whitespace = ch.is_whitespace();
+        if whitespace && !previous_whitespace {
+            ends.push(idx);
+        }
+        previous_whitespace = whitespace;
+    }
+    ends
+}
+
+/// Offsets after every `max_chars` characters.
+fn char_ends(text: &str, max_chars: usize) -> Vec<usize> {
+    text.char_indices()
+        .map(|(idx, _)| idx)
+        .skip(max_chars)
+        .step_by(max_chars)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(chunks: &[TextChunk]) -> Vec<&str> {
+        chunks.iter().map(|chunk| chunk.text.as_str()).collect()
+    }
+
+    #[test]
+    fn short_text_is_one_chunk() {
+        let chunks = split_into_chunks("Hello there. General Kenobi.\n", 100);
+        assert_eq!(texts(&chunks), vec!["Hello there. General Kenobi."]);
+        assert_eq!(chunks[0].separator, "\n");
+    }
+
+    #[test]
+    fn prefers_paragraph_breaks() {
+        let text = "First paragraph here.\n\nSecond one is here.\n\nThird.";
+        let chunks = split_into_chunks(text, 45);
+        assert_eq!(
+            texts(&chunks),
+            vec!["First paragraph here.\n\nSecond one is here.", "Third."]
+        );
+        assert_eq!(chunks[0].separator, "\n\n");
+    }
+
+    #[test]
+    fn splits_long_paragraph_at_sentences_with_multibyte_text() {
+        let text = "Привет, мир. Как дела у тебя? Всё хорошо! 你好。世界很大。";
+        let chunks = split_into_chunks(text, 20);
+        assert_eq!(
+            texts(&chunks),
+            vec![
+                "Привет, мир.",
+                "Как дела у тебя?",
+                "Всё хорошо! 你好。世界很大。"
+            ]
+        );
+        for chunk in &chunks {
+            assert!(chunk.text.chars().count() <= 20);
+        }
+        assert_eq!(chunks[0].separator, " ");
+    }
+
+    #[test]
+    fn cuts_cjk_sentences_without_spaces() {
+        let text = "今日は晴れです。明日は雨です。";
+        let chunks = split_into_chunks(text, 8);
+        assert_eq!(texts(&chunks), vec!["今日は晴れです。", "明日は雨です。"]);
+        assert_eq!(chunks[0].separator, "");
+    }
+
+    #[test]
+    fn falls_back_to_words_and_characters() {
+        let chunks = split_into_chunks("one two three überlangeswort", 6);
+        assert_eq!(
+            texts(&chunks),
+            vec!["one", "two", "three", "überla", "ngeswo", "rt"]
+        );
+    }
+
+    #[test]
+    fn stitching_unchanged_chunks_restores_the_text() {
+        let text = "Erste Zeile. Zweite Zeile?\n\nÜberschrift\n\nLetzter Satz… Ende.";
+        let chunks = split_into_chunks(text, 15);
+        assert!(chunks.len() > 1);
+        let outputs: Vec<String> = chunks.iter().map(|chunk| chunk.text.clone()).collect();
+        assert_eq!(stitch_chunks(&chunks, &outputs), text);
+    }
+
+    #[test]
+    fn blank_text_has_no_chunks() {
+        assert!(split_into_chunks(" \n ", 10).is_empty());
+    }
+}
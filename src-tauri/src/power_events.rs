@@ -0,0 +1,164 @@
+//! Wake-from-sleep notifications from the operating system.
+//!
+//! - Windows: `PowerRegisterSuspendResumeNotification`, the callback form of
+//!   `WM_POWERBROADCAST` that works without a window.
+//! - Linux: logind's `PrepareForSleep(false)` signal on the system bus.
+//! - macOS: `NSWorkspaceDidWakeNotification`.
+//!
+//! Listeners added with `on_resume` run on a short-lived thread per wake, so
+//! they may block without holding up the OS callback.
+
+use log::{info, warn};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+type ResumeListener = Arc<dyn Fn() + Send + Sync>;
+
+static LISTENERS: Mutex<Vec<ResumeListener>> = Mutex::new(Vec::new());
+static WATCHER_STARTED: OnceLock<()> = OnceLock::new();
+
+/// Runs `listener` after every wake from sleep.
+pub fn on_resume(listener: impl Fn() + Send + Sync + 'static) {
+    LISTENERS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Arc::new(listener));
+}
+
+fn notify_resume() {
+    let listeners = LISTENERS.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    if listeners.is_empty() {
+        return;
+    }
+    info!("System resumed from sleep");
+    let spawned = thread::Builder::new()
+        .name("power-resume".into())
+        .spawn(move || listeners.iter().for_each(|listener| listener()));
+    if let Err(e) = spawned {
+        warn!("Failed to run resume listeners: {}", e);
+    }
+}
+
+/// Subscribes to the platform's resume notification. Safe to call more than once.
+pub fn start_watcher() {
+    if WATCHER_STARTED.set(()).is_err() {
+        return;
+    }
+    if let Err(e) = platform::watch() {
+        warn!("Resume-from-sleep notifications unavailable: {}", e);
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+    use windows::Win32::System::Power::{
+        PowerRegisterSuspendResumeNotification, DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS, HPOWERNOTIFY,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{DEVICE_NOTIFY_CALLBACK, PBT_APMRESUMEAUTOMATIC};
+
+    unsafe extern "system" fn on_power_event(
+        _context: *const c_void,
+        event_type: u32,
+        _setting: *const c_void,
+    ) -> u32 {
+        // Sent on every wake, with or without user input.
+        if event_type == PBT_APMRESUMEAUTOMATIC {
+            super::notify_resume();
+        }
+        ERROR_SUCCESS.0
+    }
+
+    pub(super) fn watch() -> Result<(), String> {
+        // The subscription lasts for the life of the process, so neither the
+        // parameters nor the registration handle are ever freed.
+        let parameters = Box::leak(Box::new(DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS {
+            Callback: Some(on_power_event),
+            Context: std::ptr::null_mut(),
+        }));
+        let mut registration = HPOWERNOTIFY::default();
+        let result = unsafe {
+            PowerRegisterSuspendResumeNotification(
+                DEVICE_NOTIFY_CALLBACK,
+                HANDLE(parameters as *mut DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS as *mut c_void),
+                &mut registration,
+            )
+        };
+        if result != ERROR_SUCCESS {
+            return Err(format!(
+                "PowerRegisterSuspendResumeNotification failed: {:?}",
+                result
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use futures::StreamExt;
+    use log::warn;
+
+    async fn watch_logind() -> zbus::Result<()> {
+        let connection = zbus::Connection::system().await?;
+        let proxy = zbus::Proxy::new(
+            &connection,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await?;
+        let mut signals = proxy.receive_signal("PrepareForSleep").await?;
+        while let Some(message) = signals.next().await {
+            // `true` right before suspending, `false` once the system is back.
+            let going_to_sleep: bool = message.body().deserialize()?;
+            if !going_to_sleep {
+                super::notify_resume();
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn watch() -> Result<(), String> {
+        tauri::async_runtime::spawn(async {
+            if let Err(e) = watch_logind().await {
+                warn!("Lost logind sleep notifications: {}", e);
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use block2::RcBlock;
+    use objc2_app_kit::{NSWorkspace, NSWorkspaceDidWakeNotification};
+    use objc2_foundation::NSNotification;
+    use std::ptr::NonNull;
+
+    pub(super) fn watch() -> Result<(), String> {
+        let block = RcBlock::new(|_notification: NonNull<NSNotification>| {
+            super::notify_resume();
+        });
+        let center = NSWorkspace::sharedWorkspace().notificationCenter();
+        let observer = unsafe {
+            center.addObserverForName_object_queue_usingBlock(
+                Some(NSWorkspaceDidWakeNotification),
+                None,
+                None,
+                &block,
+            )
+        };
+        // Observed for the life of the process.
+        std::mem::forget(observer);
+        Ok(())
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+mod platform {
+    pub(super) fn watch() -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+}
@@ -0,0 +1,155 @@
+//! Runtime privacy mode.
+//!
+//! While active, dictation output is only typed into the target app: history,
+//! transcript context and connector messages are skipped, and the clipboard is
+//! always restored. The flag lives in memory only, so a restart turns it off.
+
+use log::{debug, info};
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+use crate::settings::{self, ClipboardHandling};
+
+pub const PRIVACY_MODE_BINDING_ID: &str = "toggle_privacy_mode";
+
+struct ActivePrivacyMode {
+    /// `None` keeps privacy mode on until it is turned off or the app exits.
+    expires_at: Option<Instant>,
+}
+
+static ACTIVE: Mutex<Option<ActivePrivacyMode>> = Mutex::new(None);
+// Bumped on every change so a stale expiry timer never turns off a newer session.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct PrivacyModeStatus {
+    pub active: bool,
+    /// Seconds until privacy mode turns itself off, if it expires.
+    pub remaining_secs: Option<u64>,
+}
+
+fn current_status(now: Instant) -> PrivacyModeStatus {
+    let mut active = ACTIVE.lock().unwrap_or_else(|e| e.into_inner());
+    if active
+        .as_ref()
+        .and_then(|mode| mode.expires_at)
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        *active = None;
+    }
+
+    PrivacyModeStatus {
+        active: active.is_some(),
+        remaining_secs: active
+            .as_ref()
+            .and_then(|mode| mode.expires_at)
+            .map(|expires_at| expires_at.saturating_duration_since(now).as_secs()),
+    }
+}
+
+pub fn status() -> PrivacyModeStatus {
+    current_status(Instant::now())
+}
+
+pub fn is_active() -> bool {
+    status().active
+}
+
+/// Returns true (and logs which side effect was dropped) when privacy mode
+/// should suppress `side_effect`.
+pub fn should_skip(side_effect: &str) -> bool {
+    if is_active() {
+        debug!("Privacy mode: skipping {}", side_effect);
+        true
+    } else {
+        false
+    }
+}
+
+/// Clipboard handling to use for a paste; privacy mode never leaves the
+/// transcript on the clipboard.
+pub fn clipboard_handling(configured: ClipboardHandling) -> ClipboardHandling {
+    if configured == ClipboardHandling::CopyToClipboard
+        && should_skip("copying output to clipboard")
+    {
+        ClipboardHandling::DontModify
+    } else {
+        configured
+    }
+}
+
+fn notify_changed(app: &AppHandle, status: &PrivacyModeStatus) {
+    let _ = app.emit("privacy-mode-changed", status.clone());
+    crate::tray::refresh_tray_menu(app, None);
+}
+
+pub fn set_active(app: &AppHandle, enabled: bool) -> PrivacyModeStatus {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let duration_minutes = settings::get_settings(app).privacy_mode_duration_minutes;
+    let duration =
+        (duration_minutes > 0).then(|| Duration::from_secs(u64::from(duration_minutes) * 60));
+
+    {
+        let mut active = ACTIVE.lock().unwrap_or_else(|e| e.into_inner());
+        *active = enabled.then(|| ActivePrivacyMode {
+            expires_at: duration.map(|duration| Instant::now() + duration),
+        });
+    }
+
+    if enabled {
+        match duration_minutes {
+            0 => info!("Privacy mode enabled until turned off"),
+            minutes => info!("Privacy mode enabled for {} minutes", minutes),
+        }
+    } else {
+        info!("Privacy mode disabled");
+    }
+
+    if let (true, Some(duration)) = (enabled, duration) {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            if GENERATION.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            let status = status();
+            if !status.active {
+                info!("Privacy mode expired");
+                notify_changed(&app, &status);
+            }
+        });
+    }
+
+    let status = status();
+    notify_changed(app, &status);
+    status
+}
+
+pub fn toggle(app: &AppHandle) -> PrivacyModeStatus {
+    set_active(app, !is_active())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_privacy_mode_reads_as_inactive() {
+        let now = Instant::now();
+        *ACTIVE.lock().unwrap() = Some(ActivePrivacyMode {
+            expires_at: Some(now + Duration::from_secs(90)),
+        });
+
+        let status = current_status(now);
+        assert!(status.active);
+        assert_eq!(status.remaining_secs, Some(90));
+
+        let status = current_status(now + Duration::from_secs(90));
+        assert!(!status.active);
+        assert!(ACTIVE.lock().unwrap().is_none());
+    }
+}
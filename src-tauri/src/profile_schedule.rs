@@ -0,0 +1,382 @@
+//! Time-of-day profile switching.
+//!
+//! A background thread evaluates `profile_schedule` on every tick and right
+//! after resume from sleep. The first rule covering the current local time is
+//! the active rule; whenever the active rule changes or the machine wakes (a
+//! boundary) its profile is applied through the same path as `set_active_profile`. A manual switch
+//! holds off the schedule until the next boundary, and a switch that comes due
+//! while a recording is running or being processed waits until Idle.
+
+use chrono::{Datelike, Local, NaiveDateTime, NaiveTime, Weekday};
+use log::{info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::settings::{self, AppSettings, ProfileScheduleRule, ScheduleDay};
+
+const TICK_INTERVAL: Duration = Duration::from_secs(20);
+/// Shorter interval while a switch waits for the session to become idle.
+const PENDING_TICK_INTERVAL: Duration = Duration::from_secs(2);
+
+static MONITOR_STARTED: OnceLock<()> = OnceLock::new();
+static STATE: Mutex<ScheduleState> = Mutex::new(ScheduleState::new());
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileSource {
+    Manual,
+    Schedule,
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct EffectiveProfileSource {
+    pub profile_id: String,
+    pub source: ProfileSource,
+    /// Index into `profile_schedule` of the rule covering the current time
+    pub active_rule_index: Option<usize>,
+    /// Profile of the active rule, waiting for the session to become idle
+    pub pending_profile_id: Option<String>,
+    /// A manual switch holds off the schedule until the next rule boundary
+    pub manual_override: bool,
+}
+
+struct ScheduleState {
+    evaluated: bool,
+    /// Rule that covered the time at the last evaluation; a change is a boundary.
+    active_rule: Option<ProfileScheduleRule>,
+    source: ProfileSource,
+    manual_override: bool,
+    pending_profile_id: Option<String>,
+}
+
+impl ScheduleState {
+    const fn new() -> Self {
+        Self {
+            evaluated: false,
+            active_rule: None,
+            source: ProfileSource::Manual,
+            manual_override: false,
+            pending_profile_id: None,
+        }
+    }
+
+    /// Updates the state for the rule covering the current time and returns
+    /// the profile to switch to now, if any.
+    fn advance(
+        &mut self,
+        rule: Option<&ProfileScheduleRule>,
+        active_profile_id: &str,
+        idle: bool,
+    ) -> Option<String> {
+        if !self.evaluated || self.active_rule.as_ref() != rule {
+            self.evaluated = true;
+            self.active_rule = rule.cloned();
+            self.manual_override = false;
+            self.pending_profile_id = None;
+            if let Some(rule) = rule {
+                if rule.profile_id == active_profile_id {
+                    self.source = ProfileSource::Schedule;
+                } else {
+                    self.pending_profile_id = Some(rule.profile_id.clone());
+                }
+            }
+        }
+
+        if self.manual_override || !idle {
+            return None;
+        }
+        let profile_id = self.pending_profile_id.take()?;
+        self.source = ProfileSource::Schedule;
+        Some(profile_id)
+    }
+
+    fn note_manual_switch(&mut self) {
+        self.source = ProfileSource::Manual;
+        self.manual_override = self.active_rule.is_some();
+        self.pending_profile_id = None;
+    }
+
+    /// A wake from sleep counts as a rule boundary: the next evaluation
+    /// re-applies the covering rule even if it did not change.
+    fn note_resume(&mut self) {
+        self.evaluated = false;
+    }
+}
+
+fn lock_state() -> std::sync::MutexGuard<'static, ScheduleState> {
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn weekday(day: ScheduleDay) -> Weekday {
+    match day {
+        ScheduleDay::Mon => Weekday::Mon,
+        ScheduleDay::Tue => Weekday::Tue,
+        ScheduleDay::Wed => Weekday::Wed,
+        ScheduleDay::Thu => Weekday::Thu,
+        ScheduleDay::Fri => Weekday::Fri,
+        ScheduleDay::Sat => Weekday::Sat,
+        ScheduleDay::Sun => Weekday::Sun,
+    }
+}
+
+pub(crate) fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+fn starts_on(days: &[ScheduleDay], day: Weekday) -> bool {
+    days.is_empty() || days.iter().any(|d| weekday(*d) == day)
+}
+
+/// Whether the daily window `start_time`..`end_time` covers `now`. Days name
+/// the day a window starts on, so an overnight window started on Friday still
+/// covers early Saturday.
+pub fn window_covers(
+    days: &[ScheduleDay],
+    start_time: &str,
+    end_time: &str,
+    now: NaiveDateTime,
+) -> bool {
+    let (Some(start), Some(end)) = (parse_time(start_time), parse_time(end_time)) else {
+        return false;
+    };
+    let time = now.time();
+    let today = now.weekday();
+    if start < end {
+        starts_on(days, today) && time >= start && time < end
+    } else if start == end {
+        starts_on(days, today)
+    } else {
+        (starts_on(days, today) && time >= start) || (starts_on(days, today.pred()) && time < end)
+    }
+}
+
+/// Whether `rule` covers `now` (see `window_covers`).
+pub fn rule_covers(rule: &ProfileScheduleRule, now: NaiveDateTime) -> bool {
+    window_covers(&rule.days, &rule.start_time, &rule.end_time, now)
+}
+
+/// Index of the first rule covering `now`.
+pub fn active_rule_index(rules: &[ProfileScheduleRule], now: NaiveDateTime) -> Option<usize> {
+    rules.iter().position(|rule| rule_covers(rule, now))
+}
+
+pub fn validate_rules(settings: &AppSettings, rules: &[ProfileScheduleRule]) -> Result<(), String> {
+    for (index, rule) in rules.iter().enumerate() {
+        if parse_time(&rule.start_time).is_none() || parse_time(&rule.end_time).is_none() {
+            return Err(format!(
+                "Schedule rule {} needs start and end times as HH:MM",
+                index + 1
+            ));
+        }
+        if rule.profile_id != "default"
+            && !settings
+                .transcription_profiles
+                .iter()
+                .any(|p| p.id == rule.profile_id)
+        {
+            return Err(format!("Profile '{}' not found", rule.profile_id));
+        }
+    }
+    Ok(())
+}
+
+fn session_is_idle(app: &AppHandle) -> bool {
+    let state = app.state::<crate::session_manager::ManagedSessionState>();
+    let state_guard = crate::session_manager::lock_session_state(&state, "profile_schedule");
+    matches!(*state_guard, crate::session_manager::SessionState::Idle)
+}
+
+fn evaluate(app: &AppHandle) {
+    let settings = settings::get_settings(app);
+    let now = Local::now().naive_local();
+    let rule = active_rule_index(&settings.profile_schedule, now)
+        .map(|index| &settings.profile_schedule[index]);
+    let idle = session_is_idle(app);
+
+    let switch_to = lock_state().advance(rule, &settings.active_profile_id, idle);
+    if let Some(profile_id) = switch_to {
+        info!("Profile schedule switching to '{}'", profile_id);
+        if let Err(e) = crate::shortcut::apply_active_profile(app, &profile_id) {
+            warn!("Scheduled profile switch failed: {}", e);
+        }
+    }
+}
+
+/// Records a switch made by the user, which wins until the next rule boundary.
+pub fn note_manual_switch() {
+    lock_state().note_manual_switch();
+}
+
+pub fn start_schedule_monitor(app: &AppHandle) {
+    if MONITOR_STARTED.set(()).is_err() {
+        return;
+    }
+
+    let resume_app = app.clone();
+    crate::power_events::on_resume(move || {
+        info!("Re-evaluating profile schedule after resume");
+        lock_state().note_resume();
+        evaluate(&resume_app);
+    });
+
+    let app = app.clone();
+    thread::Builder::new()
+        .name("profile-schedule".into())
+        .spawn(move || loop {
+            evaluate(&app);
+
+            let interval = if lock_state().pending_profile_id.is_some() {
+                PENDING_TICK_INTERVAL
+            } else {
+                TICK_INTERVAL
+            };
+            thread::sleep(interval);
+        })
+        .map(|_| ())
+        .unwrap_or_else(|e| warn!("Failed to start profile schedule monitor: {}", e));
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_profile_schedule_setting(
+    app: AppHandle,
+    rules: Vec<ProfileScheduleRule>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    validate_rules(&settings, &rules)?;
+    settings.profile_schedule = rules;
+    settings::write_settings(&app, settings);
+    evaluate(&app);
+    Ok(())
+}
+
+/// Reports whether the active profile was set by the schedule or by hand.
+#[tauri::command]
+#[specta::specta]
+pub fn get_effective_profile_source(app: AppHandle) -> EffectiveProfileSource {
+    let settings = settings::get_settings(&app);
+    let active_rule_index =
+        active_rule_index(&settings.profile_schedule, Local::now().naive_local());
+    let state = lock_state();
+    EffectiveProfileSource {
+        profile_id: settings.active_profile_id,
+        source: state.source,
+        active_rule_index,
+        pending_profile_id: state.pending_profile_id.clone(),
+        manual_override: state.manual_override,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn rule(days: &[ScheduleDay], start: &str, end: &str, profile_id: &str) -> ProfileScheduleRule {
+        ProfileScheduleRule {
+            days: days.to_vec(),
+            start_time: start.to_string(),
+            end_time: end.to_string(),
+            profile_id: profile_id.to_string(),
+        }
+    }
+
+    /// 2026-10-12 is a Monday.
+    fn at(day_of_month: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, day_of_month)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn window_covers_listed_days_only() {
+        let standup = rule(
+            &[ScheduleDay::Mon, ScheduleDay::Fri],
+            "09:00",
+            "12:00",
+            "de",
+        );
+
+        assert!(rule_covers(&standup, at(12, 9, 0)));
+        assert!(!rule_covers(&standup, at(12, 12, 0)));
+        assert!(!rule_covers(&standup, at(13, 10, 0)));
+        assert!(rule_covers(&standup, at(16, 11, 59)));
+    }
+
+    #[test]
+    fn overnight_window_belongs_to_its_start_day() {
+        let late = rule(&[ScheduleDay::Fri], "22:00", "02:00", "night");
+
+        assert!(rule_covers(&late, at(16, 23, 0)));
+        assert!(rule_covers(&late, at(17, 1, 30)));
+        assert!(!rule_covers(&late, at(16, 1, 30)));
+        assert!(!rule_covers(&late, at(17, 2, 0)));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = vec![
+            rule(&[], "09:00", "12:00", "de"),
+            rule(&[], "08:00", "18:00", "en"),
+        ];
+
+        assert_eq!(active_rule_index(&rules, at(12, 10, 0)), Some(0));
+        assert_eq!(active_rule_index(&rules, at(12, 13, 0)), Some(1));
+        assert_eq!(active_rule_index(&rules, at(12, 19, 0)), None);
+    }
+
+    #[test]
+    fn switch_waits_for_idle() {
+        let mut state = ScheduleState::new();
+        let morning = rule(&[], "09:00", "12:00", "de");
+
+        assert_eq!(state.advance(Some(&morning), "default", false), None);
+        assert_eq!(state.pending_profile_id.as_deref(), Some("de"));
+        assert_eq!(
+            state.advance(Some(&morning), "default", true).as_deref(),
+            Some("de")
+        );
+        assert_eq!(state.source, ProfileSource::Schedule);
+        assert_eq!(state.advance(Some(&morning), "de", true), None);
+    }
+
+    #[test]
+    fn manual_switch_holds_until_next_boundary() {
+        let mut state = ScheduleState::new();
+        let morning = rule(&[], "09:00", "12:00", "de");
+        let afternoon = rule(&[], "12:00", "18:00", "en");
+
+        assert!(state.advance(Some(&morning), "default", true).is_some());
+        state.note_manual_switch();
+        assert_eq!(state.advance(Some(&morning), "fr", true), None);
+        assert_eq!(state.source, ProfileSource::Manual);
+
+        assert_eq!(
+            state.advance(Some(&afternoon), "fr", true).as_deref(),
+            Some("en")
+        );
+        assert!(!state.manual_override);
+    }
+
+    #[test]
+    fn resume_reapplies_the_covering_rule() {
+        let mut state = ScheduleState::new();
+        let morning = rule(&[], "09:00", "12:00", "de");
+
+        assert!(state.advance(Some(&morning), "default", true).is_some());
+        state.note_manual_switch();
+        assert_eq!(state.advance(Some(&morning), "fr", true), None);
+
+        state.note_resume();
+        assert_eq!(
+            state.advance(Some(&morning), "fr", true).as_deref(),
+            Some("de")
+        );
+        assert!(!state.manual_override);
+    }
+}
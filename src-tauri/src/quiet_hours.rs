@@ -0,0 +1,142 @@
+//! Scheduled quiet hours.
+//!
+//! While the configured daily window covers the local time, recording
+//! shortcuts either cannot start a new recording or run silently (no feedback
+//! sounds, no overlay), depending on `QuietHoursBehavior`. Only new starts are
+//! affected: a recording that is running when the window begins can still be
+//! stopped and finishes normally. A blocked press shows one notice per window.
+
+use chrono::{Duration, Local, NaiveDateTime};
+use log::info;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::profile_schedule::{parse_time, window_covers};
+use crate::settings::{self, AppSettings, QuietHoursBehavior, QuietHoursSettings};
+
+/// Start of the window the last blocked-press notice was shown for.
+static NOTIFIED_WINDOW: Mutex<Option<NaiveDateTime>> = Mutex::new(None);
+
+fn is_active_at(config: &QuietHoursSettings, now: NaiveDateTime) -> bool {
+    config.enabled && window_covers(&config.days, &config.start_time, &config.end_time, now)
+}
+
+/// Start of the window covering `now`: today's start time, or yesterday's
+/// for the early-morning part of an overnight window.
+fn window_start(config: &QuietHoursSettings, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let start = parse_time(&config.start_time)?;
+    let today = now.date().and_time(start);
+    Some(if now.time() >= start {
+        today
+    } else {
+        today - Duration::days(1)
+    })
+}
+
+fn behavior_active(settings: &AppSettings, behavior: QuietHoursBehavior) -> bool {
+    settings.quiet_hours.behavior == behavior
+        && is_active_at(&settings.quiet_hours, Local::now().naive_local())
+}
+
+/// Feedback sounds and overlays are suppressed right now.
+pub fn mutes_feedback(settings: &AppSettings) -> bool {
+    behavior_active(settings, QuietHoursBehavior::MuteFeedback)
+}
+
+/// Whether a recording shortcut press must be ignored. Presses that would
+/// stop or toggle off a running recording are never blocked; presses while a
+/// previous recording is still processing would start a new one, so they are.
+pub fn blocks_recording_start(app: &AppHandle, settings: &AppSettings) -> bool {
+    if !behavior_active(settings, QuietHoursBehavior::BlockShortcuts) {
+        return false;
+    }
+
+    let state = app.state::<crate::session_manager::ManagedSessionState>();
+    let state_guard = crate::session_manager::lock_session_state(&state, "quiet_hours");
+    !matches!(
+        *state_guard,
+        crate::session_manager::SessionState::Recording { .. }
+    )
+}
+
+/// Tells the user once per quiet window that a shortcut was ignored.
+pub fn note_blocked_press(app: &AppHandle, settings: &AppSettings) {
+    let config = &settings.quiet_hours;
+    let Some(start) = window_start(config, Local::now().naive_local()) else {
+        return;
+    };
+    {
+        let mut notified = NOTIFIED_WINDOW.lock().unwrap_or_else(|e| e.into_inner());
+        if *notified == Some(start) {
+            return;
+        }
+        *notified = Some(start);
+    }
+
+    info!(
+        "Quiet hours active until {}; ignoring recording shortcuts",
+        config.end_time
+    );
+    crate::overlay::show_quiet_hours_overlay(app, &config.end_time);
+}
+
+pub fn validate(config: &QuietHoursSettings) -> Result<(), String> {
+    if parse_time(&config.start_time).is_none() || parse_time(&config.end_time).is_none() {
+        return Err("Quiet hours need start and end times as HH:MM".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_quiet_hours_setting(
+    app: AppHandle,
+    quiet_hours: QuietHoursSettings,
+) -> Result<(), String> {
+    validate(&quiet_hours)?;
+    let mut settings = settings::get_settings(&app);
+    settings.quiet_hours = quiet_hours;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    /// 2026-10-12 is a Monday.
+    fn at(day_of_month: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, day_of_month)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn night() -> QuietHoursSettings {
+        QuietHoursSettings {
+            enabled: true,
+            ..QuietHoursSettings::default()
+        }
+    }
+
+    #[test]
+    fn overnight_window_crosses_midnight() {
+        let config = night();
+
+        assert!(is_active_at(&config, at(12, 22, 0)));
+        assert!(is_active_at(&config, at(13, 6, 59)));
+        assert!(!is_active_at(&config, at(13, 7, 0)));
+        assert!(!is_active_at(&config, at(13, 21, 59)));
+        assert!(!is_active_at(&QuietHoursSettings::default(), at(12, 23, 0)));
+    }
+
+    #[test]
+    fn both_halves_of_a_night_share_one_window() {
+        let config = night();
+
+        assert_eq!(window_start(&config, at(12, 23, 0)), Some(at(12, 22, 0)));
+        assert_eq!(window_start(&config, at(13, 3, 0)), Some(at(12, 22, 0)));
+        assert_eq!(window_start(&config, at(13, 22, 30)), Some(at(13, 22, 0)));
+    }
+}
@@ -1,10 +1,15 @@
-use crate::actions::{reset_toggle_state, transcribe_action_for_binding};
+use crate::actions::{
+    reset_toggle_state, transcribe_action_for_binding, use_push_to_talk_for_transcribe_binding,
+};
+use crate::managers::audio::AudioRecordingManager;
 use crate::settings::get_settings;
 use crate::utils::cancel_current_operation;
 use log::{debug, info};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
-use tauri::{AppHandle, Manager};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
 
 pub struct AutoStopToken {
     pub notify: tokio::sync::Notify,
@@ -86,13 +91,7 @@ pub fn start_auto_stop_timer(app: &AppHandle, binding_id: &str) {
 
         if paste {
             debug!("Auto-stop: stopping action for binding_id {}", binding_id);
-            if let Some(action) = transcribe_action_for_binding(&binding_id) {
-                action.stop(&app_clone, &binding_id, "auto_stop");
-                reset_toggle_state(&app_clone, &binding_id);
-            } else {
-                log::error!("Auto-stop: no action found for binding_id {}", binding_id);
-                cancel_current_operation(&app_clone);
-            }
+            stop_recording_for_binding(&app_clone, &binding_id, "auto_stop");
         } else {
             debug!("Auto-stop: cancelling current operation");
             cancel_current_operation(&app_clone);
@@ -100,7 +99,221 @@ pub fn start_auto_stop_timer(app: &AppHandle, binding_id: &str) {
     });
 }
 
+/// Share of `max_recording_duration_seconds` after which
+/// `recording-duration-warning` is emitted.
+const MAX_DURATION_WARNING_RATIO: f64 = 0.8;
+
+/// Token of the running max-duration timer. Kept apart from
+/// [`ManagedAutoStopToken`] so both limits can be armed for one recording.
+pub struct MaxDurationTimer(Mutex<Option<Arc<AutoStopToken>>>);
+
+pub fn new_max_duration_state() -> MaxDurationTimer {
+    MaxDurationTimer(Mutex::new(None))
+}
+
+#[derive(Clone, Serialize)]
+struct RecordingDurationWarning {
+    binding_id: String,
+    limit_seconds: u32,
+    remaining_seconds: u64,
+}
+
+/// Instants at which to warn and to stop a recording that began at `started_at`.
+fn max_duration_deadlines(started_at: Instant, limit: Duration) -> (Instant, Instant) {
+    (
+        started_at + limit.mul_f64(MAX_DURATION_WARNING_RATIO),
+        started_at + limit,
+    )
+}
+
+/// Finalizes the recording for `binding_id` once it has run for
+/// `max_recording_duration_seconds`, measured from the session start. Applies
+/// to push-to-talk as well, even while the key is still held.
+pub fn start_max_duration_timer(app: &AppHandle, binding_id: &str) {
+    let limit_secs = get_settings(app).max_recording_duration_seconds;
+    if limit_secs == 0 {
+        return;
+    }
+    let Some((operation_id, started_at)) =
+        crate::session_manager::recording_started_at(app, binding_id)
+    else {
+        return;
+    };
+
+    let token = Arc::new(AutoStopToken {
+        notify: tokio::sync::Notify::new(),
+    });
+    if let Ok(mut state) = app.state::<MaxDurationTimer>().0.lock() {
+        if let Some(previous) = state.replace(Arc::clone(&token)) {
+            previous.notify.notify_one();
+        }
+    } else {
+        log::error!("Failed to lock MaxDurationTimer");
+        return;
+    }
+
+    let app = app.clone();
+    let binding_id = binding_id.to_string();
+
+    tauri::async_runtime::spawn(async move {
+        let limit = Duration::from_secs(limit_secs as u64);
+        let (warn_at, stop_at) = max_duration_deadlines(started_at, limit);
+
+        tokio::select! {
+            _ = tokio::time::sleep_until(warn_at.into()) => {}
+            _ = token.notify.notified() => return,
+        }
+        if crate::session_manager::recording_operation_id(&app, &binding_id) != Some(operation_id) {
+            return;
+        }
+        let _ = app.emit(
+            "recording-duration-warning",
+            RecordingDurationWarning {
+                binding_id: binding_id.clone(),
+                limit_seconds: limit_secs,
+                remaining_seconds: stop_at.saturating_duration_since(Instant::now()).as_secs(),
+            },
+        );
+
+        tokio::select! {
+            _ = tokio::time::sleep_until(stop_at.into()) => {}
+            _ = token.notify.notified() => return,
+        }
+
+        let is_current_timer = app
+            .state::<MaxDurationTimer>()
+            .0
+            .lock()
+            .map(|mut state| {
+                let is_current = state
+                    .as_ref()
+                    .is_some_and(|current| Arc::ptr_eq(current, &token));
+                if is_current {
+                    state.take();
+                }
+                is_current
+            })
+            .unwrap_or(false);
+        if !is_current_timer
+            || crate::session_manager::recording_operation_id(&app, &binding_id)
+                != Some(operation_id)
+        {
+            return;
+        }
+
+        info!(
+            "Recording for binding '{}' reached the {} second limit; finalizing",
+            binding_id, limit_secs
+        );
+        stop_recording_for_binding(&app, &binding_id, "max_duration");
+    });
+}
+
+/// Silence auto-stop never fires this soon after recording starts, so the
+/// user has time to begin speaking.
+const SILENCE_AUTO_STOP_GRACE: Duration = Duration::from_secs(2);
+
+/// Tracks VAD verdicts and reports when trailing silence exceeds the threshold.
+struct SilenceTracker {
+    started_at: Instant,
+    last_speech_at: Option<Instant>,
+    threshold: Duration,
+}
+
+impl SilenceTracker {
+    fn new(started_at: Instant, threshold: Duration) -> Self {
+        Self {
+            started_at,
+            last_speech_at: None,
+            threshold,
+        }
+    }
+
+    /// Returns true once silence has lasted `threshold` past the grace period.
+    fn observe(&mut self, is_speech: bool, now: Instant) -> bool {
+        if is_speech {
+            self.last_speech_at = Some(now);
+            return false;
+        }
+        if now.duration_since(self.started_at) < SILENCE_AUTO_STOP_GRACE {
+            return false;
+        }
+        let silent_since = self.last_speech_at.unwrap_or(self.started_at);
+        now.duration_since(silent_since) >= self.threshold
+    }
+}
+
+/// Stops hands-free recordings after `auto_stop_silence_seconds` of silence,
+/// using the recorder's VAD. Push-to-talk recordings are left alone.
+pub fn start_silence_auto_stop(app: &AppHandle, binding_id: &str) {
+    let settings = get_settings(app);
+    if !settings.auto_stop_on_silence_enabled
+        || use_push_to_talk_for_transcribe_binding(&settings, binding_id)
+    {
+        return;
+    }
+    let Some(operation_id) = crate::session_manager::recording_operation_id(app, binding_id) else {
+        return;
+    };
+
+    let threshold = Duration::from_secs(settings.auto_stop_silence_seconds as u64);
+    let tracker = Mutex::new(SilenceTracker::new(Instant::now(), threshold));
+    let fired = AtomicBool::new(false);
+    let app_clone = app.clone();
+    let binding_id = binding_id.to_string();
+
+    let callback = Arc::new(move |is_speech: bool| {
+        if fired.load(Ordering::Relaxed) {
+            return;
+        }
+        let should_stop = tracker
+            .lock()
+            .map(|mut tracker| tracker.observe(is_speech, Instant::now()))
+            .unwrap_or(false);
+        if !should_stop || fired.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        // The callback runs on the audio consumer thread, which the stop path
+        // waits on, so hand the stop off to the async runtime.
+        let app = app_clone.clone();
+        let binding_id = binding_id.clone();
+        tauri::async_runtime::spawn(async move {
+            if crate::session_manager::recording_operation_id(&app, &binding_id)
+                != Some(operation_id)
+            {
+                return;
+            }
+            info!(
+                "Stopping recording for binding '{}' after {} seconds of silence",
+                binding_id,
+                threshold.as_secs()
+            );
+            stop_recording_for_binding(&app, &binding_id, "auto_stop_silence");
+        });
+    });
+
+    app.state::<Arc<AudioRecordingManager>>()
+        .set_speech_activity_callback(callback);
+    debug!("Silence auto-stop armed for binding '{}'", binding_id);
+}
+
+/// Stops the recording for `binding_id` the same way releasing its hotkey
+/// does, then resets the toggle state.
+pub fn stop_recording_for_binding(app: &AppHandle, binding_id: &str, shortcut_str: &str) {
+    if let Some(action) = transcribe_action_for_binding(binding_id) {
+        action.stop(app, binding_id, shortcut_str);
+        reset_toggle_state(app, binding_id);
+    } else {
+        log::error!("Auto-stop: no action found for binding_id {}", binding_id);
+        cancel_current_operation(app);
+    }
+}
+
 pub fn cancel_auto_stop_timer(app: &AppHandle) {
+    app.state::<Arc<AudioRecordingManager>>()
+        .clear_speech_activity_callback();
+
     if let Ok(mut state) = app.state::<ManagedAutoStopToken>().lock() {
         if let Some(token) = state.take() {
             token.notify.notify_one();
@@ -109,4 +322,46 @@ pub fn cancel_auto_stop_timer(app: &AppHandle) {
     } else {
         log::error!("Failed to lock ManagedAutoStopToken for cancel");
     }
+
+    if let Ok(mut state) = app.state::<MaxDurationTimer>().0.lock() {
+        if let Some(token) = state.take() {
+            token.notify.notify_one();
+            debug!("Signaled max recording duration timer to cancel");
+        }
+    } else {
+        log::error!("Failed to lock MaxDurationTimer for cancel");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_duration_warns_at_eighty_percent_of_the_limit() {
+        let start = Instant::now();
+        let (warn_at, stop_at) = max_duration_deadlines(start, Duration::from_secs(600));
+
+        assert_eq!(warn_at - start, Duration::from_secs(480));
+        assert_eq!(stop_at - start, Duration::from_secs(600));
+    }
+
+    #[test]
+    fn silence_tracker_waits_for_grace_period() {
+        let start = Instant::now();
+        let mut tracker = SilenceTracker::new(start, Duration::from_secs(1));
+
+        assert!(!tracker.observe(false, start + Duration::from_millis(1500)));
+        assert!(tracker.observe(false, start + Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn silence_tracker_measures_from_last_speech() {
+        let start = Instant::now();
+        let mut tracker = SilenceTracker::new(start, Duration::from_secs(3));
+
+        assert!(!tracker.observe(true, start + Duration::from_secs(4)));
+        assert!(!tracker.observe(false, start + Duration::from_secs(6)));
+        assert!(tracker.observe(false, start + Duration::from_secs(7)));
+    }
 }
@@ -192,6 +192,19 @@ fn capture_virtual_screen_rgba(
     Ok(canvas)
 }
 
+/// Encodes raw RGBA pixels (e.g. a clipboard image) as PNG.
+pub fn encode_rgba_to_png(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    use screenshots::image::{self, ImageEncoder};
+
+    let mut png_bytes: Vec<u8> = Vec::new();
+    let encoder = image::codecs::png::PngEncoder::new(&mut png_bytes);
+    encoder
+        .write_image(rgba, width, height, image::ColorType::Rgba8)
+        .map_err(|e| format!("Failed to encode clipboard PNG: {}", e))?;
+
+    Ok(png_bytes)
+}
+
 #[cfg(target_os = "windows")]
 fn capture_virtual_screen_png(virtual_info: &VirtualScreenInfo) -> Result<Vec<u8>, String> {
     use screenshots::image::{self, ImageEncoder};
@@ -276,6 +289,9 @@ pub async fn open_region_picker(
     app: &AppHandle,
     mode: NativeRegionCaptureMode,
 ) -> RegionCaptureResult {
+    // Remember where the user was working so focus can be handed back after the picker closes.
+    let previous_foreground = crate::active_app::capture_foreground_window();
+
     // Close any existing region capture window first and wait for it to be destroyed
     if let Some(existing_window) = app.get_webview_window("region_capture") {
         debug!("Closing existing region capture window");
@@ -385,12 +401,46 @@ pub async fn open_region_picker(
     }
 
     // Wait for result from overlay
-    match rx.await {
+    let result = match rx.await {
         Ok(result) => result,
         Err(_) => {
             RegionCaptureResult::Error("Region capture channel closed unexpectedly".to_string())
         }
+    };
+
+    restore_focus_after_picker(app, previous_foreground).await;
+    result
+}
+
+/// Hands focus back to the window that was active before the picker opened,
+/// then waits the configured settle delay so a follow-up paste lands there.
+#[cfg(target_os = "windows")]
+async fn restore_focus_after_picker(
+    app: &AppHandle,
+    previous_foreground: Option<crate::active_app::ForegroundWindow>,
+) {
+    let Some(previous_foreground) = previous_foreground else {
+        return;
+    };
+
+    let settings = crate::settings::get_settings(app);
+    let timeout = std::time::Duration::from_millis(settings.focus_restore_timeout_ms);
+    let restored = tokio::task::spawn_blocking(move || {
+        crate::active_app::restore_foreground_window(previous_foreground, timeout)
+    })
+    .await
+    .unwrap_or(false);
+    if !restored {
+        debug!(
+            "Focus restore after region capture not confirmed within {:?}",
+            timeout
+        );
     }
+
+    tokio::time::sleep(std::time::Duration::from_millis(
+        settings.focus_restore_paste_delay_ms,
+    ))
+    .await;
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -22,6 +22,8 @@ pub enum KeyType {
     SonioxStt,
     /// Deepgram cloud STT API key
     DeepgramStt,
+    /// OpenAI Realtime transcription API key
+    OpenAiRealtimeStt,
 }
 
 impl KeyType {
@@ -32,14 +34,20 @@ impl KeyType {
             KeyType::VoiceCommand => "voice_command_api_key",
             KeyType::SonioxStt => "soniox_api_key",
             KeyType::DeepgramStt => "deepgram_api_key",
+            KeyType::OpenAiRealtimeStt => "openai_realtime_api_key",
         }
     }
 
-    /// Build the credential user/account name
+    /// Build the credential user/account name. `--profile-dir` instances get
+    /// their own namespace so work and personal keys never mix.
     fn credential_name(&self, provider_id: Option<&str>) -> String {
-        match provider_id {
+        let name = match provider_id {
             Some(id) => format!("{}_{}", self.prefix(), id),
             None => self.prefix().to_string(),
+        };
+        match crate::portable::profile_namespace() {
+            Some(namespace) => format!("profile_{}_{}", namespace, name),
+            None => name,
         }
     }
 }
@@ -180,6 +188,26 @@ pub fn has_deepgram_api_key() -> bool {
     !get_deepgram_api_key().trim().is_empty()
 }
 
+/// Get OpenAI Realtime STT API key
+pub fn get_openai_realtime_api_key() -> String {
+    get_api_key(KeyType::OpenAiRealtimeStt, None).unwrap_or_default()
+}
+
+/// Set OpenAI Realtime STT API key
+pub fn set_openai_realtime_api_key(key: &str) -> Result<()> {
+    set_api_key(KeyType::OpenAiRealtimeStt, None, key)
+}
+
+/// Clear OpenAI Realtime STT API key
+pub fn clear_openai_realtime_api_key() -> Result<()> {
+    set_api_key(KeyType::OpenAiRealtimeStt, None, "")
+}
+
+/// Returns whether OpenAI Realtime STT API key is present
+pub fn has_openai_realtime_api_key() -> bool {
+    !get_openai_realtime_api_key().trim().is_empty()
+}
+
 // ============================================================================
 // Migration from JSON settings to secure storage
 // ============================================================================
@@ -8,17 +8,28 @@
 //! - Registers the cancel shortcut on creation
 //! - Unregisters it exactly once on Drop
 //! - Tracks what resources were acquired to only release what was actually acquired
+//!
+//! Every state transition goes through [`replace_state`], which also emits the
+//! `session-state-changed` event for external status displays.
+//!
+//! While recording, [`RecordingSession::start_elapsed_timer`] emits
+//! `recording-elapsed` once per second for the overlay timer.
 
 use crate::managers::audio::AudioRecordingManager;
-use crate::settings::AppSettings;
+use crate::settings::{get_settings, AppSettings, TranscriptionProvider};
 use crate::shortcut;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::hide_recording_overlay;
 use log::{debug, warn};
+use serde::Serialize;
+use specta::Type;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
-use std::time::Instant;
-use tauri::{AppHandle, Manager};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const SESSION_STATE_CHANGED_EVENT: &str = "session-state-changed";
+pub const RECORDING_ELAPSED_EVENT: &str = "recording-elapsed";
 
 /// Represents the current state of the recording system.
 /// This is the single source of truth for whether we're recording or processing.
@@ -58,6 +69,244 @@ impl Default for SessionState {
 /// Managed state type for the session
 pub type ManagedSessionState = Mutex<SessionState>;
 
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionPhase {
+    Idle,
+    Recording,
+    Processing,
+}
+
+/// What the Processing state is currently doing, following the overlay.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingStage {
+    Transcribing,
+    Thinking,
+    Finalizing,
+}
+
+/// Payload of the `session-state-changed` event.
+///
+/// Idle events keep the binding, profile and provider of the session that
+/// just ended. Timestamps are Unix epoch milliseconds.
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct SessionStateChangedEvent {
+    pub state: SessionPhase,
+    pub binding_id: Option<String>,
+    pub profile_id: Option<String>,
+    pub provider: Option<TranscriptionProvider>,
+    pub operation_id: Option<u64>,
+    /// Only set while Processing.
+    pub stage: Option<ProcessingStage>,
+    pub changed_at_ms: u64,
+    pub recording_started_at_ms: Option<u64>,
+    pub processing_started_at_ms: Option<u64>,
+}
+
+impl SessionStateChangedEvent {
+    fn idle(changed_at_ms: u64) -> Self {
+        Self {
+            state: SessionPhase::Idle,
+            binding_id: None,
+            profile_id: None,
+            provider: None,
+            operation_id: None,
+            stage: None,
+            changed_at_ms,
+            recording_started_at_ms: None,
+            processing_started_at_ms: None,
+        }
+    }
+}
+
+/// Payload of the `recording-elapsed` event, emitted every second while recording.
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct RecordingElapsedEvent {
+    pub binding_id: String,
+    pub operation_id: u64,
+    /// Wall time since the recording started, including paused time.
+    pub elapsed_ms: u64,
+    /// Time left before `max_recording_duration_seconds` stops the recording.
+    pub remaining_ms: Option<u64>,
+    /// The overlay freezes its timer while this is set.
+    pub paused: bool,
+}
+
+impl RecordingElapsedEvent {
+    fn new(
+        binding_id: &str,
+        operation_id: u64,
+        elapsed: Duration,
+        max_duration_seconds: u32,
+        paused: bool,
+    ) -> Self {
+        let remaining_ms = (max_duration_seconds > 0).then(|| {
+            Duration::from_secs(max_duration_seconds as u64)
+                .saturating_sub(elapsed)
+                .as_millis() as u64
+        });
+        Self {
+            binding_id: binding_id.to_string(),
+            operation_id,
+            elapsed_ms: elapsed.as_millis() as u64,
+            remaining_ms,
+            paused,
+        }
+    }
+}
+
+/// When the tick after `elapsed` is due: the next whole second since the
+/// start. Computed from the start instead of adding a second per tick, so slow
+/// ticks neither accumulate drift nor fire in a burst to catch up.
+fn next_elapsed_tick(started_at: Instant, elapsed: Duration) -> Instant {
+    started_at + Duration::from_secs(elapsed.as_secs() + 1)
+}
+
+/// Last emitted lifecycle event, used to carry metadata across transitions.
+static LAST_EVENT: Mutex<Option<SessionStateChangedEvent>> = Mutex::new(None);
+
+fn now_unix_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn phase_of(state: &SessionState) -> SessionPhase {
+    match state {
+        SessionState::Idle => SessionPhase::Idle,
+        SessionState::Recording { .. } => SessionPhase::Recording,
+        SessionState::Processing { .. } => SessionPhase::Processing,
+    }
+}
+
+/// Builds the event for entering `next`, or None when the phase did not change.
+fn event_for_transition(
+    previous: Option<&SessionStateChangedEvent>,
+    next: &SessionState,
+    now_ms: u64,
+) -> Option<SessionStateChangedEvent> {
+    let previous_phase = previous.map_or(SessionPhase::Idle, |event| event.state);
+    if previous_phase == phase_of(next) {
+        return None;
+    }
+
+    let event = match next {
+        SessionState::Idle => SessionStateChangedEvent {
+            state: SessionPhase::Idle,
+            stage: None,
+            changed_at_ms: now_ms,
+            ..previous
+                .cloned()
+                .unwrap_or_else(|| SessionStateChangedEvent::idle(now_ms))
+        },
+        SessionState::Recording {
+            binding_id,
+            operation_id,
+            captured_profile_id,
+            captured_settings,
+            ..
+        } => SessionStateChangedEvent {
+            state: SessionPhase::Recording,
+            binding_id: Some(binding_id.clone()),
+            profile_id: captured_profile_id.clone(),
+            provider: Some(captured_settings.transcription_provider),
+            operation_id: Some(*operation_id),
+            stage: None,
+            changed_at_ms: now_ms,
+            recording_started_at_ms: Some(now_ms),
+            processing_started_at_ms: None,
+        },
+        SessionState::Processing {
+            binding_id,
+            operation_id,
+        } => {
+            // Profile, provider and start time carry over from the recording
+            // of the same operation.
+            let recording = previous.filter(|event| event.operation_id == Some(*operation_id));
+            SessionStateChangedEvent {
+                state: SessionPhase::Processing,
+                binding_id: Some(binding_id.clone()),
+                profile_id: recording.and_then(|event| event.profile_id.clone()),
+                provider: recording.and_then(|event| event.provider),
+                operation_id: Some(*operation_id),
+                stage: None,
+                changed_at_ms: now_ms,
+                recording_started_at_ms: recording.and_then(|event| event.recording_started_at_ms),
+                processing_started_at_ms: Some(now_ms),
+            }
+        }
+    };
+    Some(event)
+}
+
+fn lock_last_event() -> MutexGuard<'static, Option<SessionStateChangedEvent>> {
+    LAST_EVENT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn emit_session_event(app: &AppHandle, event: SessionStateChangedEvent) {
+    debug!(
+        "Session state changed: {:?} (binding: {:?}, stage: {:?})",
+        event.state, event.binding_id, event.stage
+    );
+    *lock_last_event() = Some(event.clone());
+    let _ = app.emit(SESSION_STATE_CHANGED_EVENT, event);
+}
+
+fn publish_transition(app: &AppHandle, next: &SessionState) {
+    let event = event_for_transition(lock_last_event().as_ref(), next, now_unix_ms());
+    if let Some(event) = event {
+        emit_session_event(app, event);
+    }
+}
+
+/// Replaces the session state and emits `session-state-changed` when the
+/// phase changes. All transitions must go through here.
+pub fn replace_state(
+    app: &AppHandle,
+    state: &mut SessionState,
+    next: SessionState,
+) -> SessionState {
+    if matches!(next, SessionState::Recording { .. }) {
+        // A new take makes the previous paste ineligible for "Undo Last Paste".
+        crate::clipboard::forget_last_insertion();
+    }
+    publish_transition(app, &next);
+    std::mem::replace(state, next)
+}
+
+/// Records the current Processing stage. Ignored outside Processing.
+pub fn set_processing_stage(app: &AppHandle, stage: ProcessingStage) {
+    let event = {
+        let last_event = lock_last_event();
+        match last_event.as_ref() {
+            Some(event)
+                if event.state == SessionPhase::Processing && event.stage != Some(stage) =>
+            {
+                Some(SessionStateChangedEvent {
+                    stage: Some(stage),
+                    changed_at_ms: now_unix_ms(),
+                    ..event.clone()
+                })
+            }
+            _ => None,
+        }
+    };
+    if let Some(event) = event {
+        emit_session_event(app, event);
+    }
+}
+
+/// Returns the most recent lifecycle event (Idle before the first recording).
+pub fn current_session_event() -> SessionStateChangedEvent {
+    lock_last_event()
+        .clone()
+        .unwrap_or_else(|| SessionStateChangedEvent::idle(now_unix_ms()))
+}
+
 static NEXT_OPERATION_ID: AtomicU64 = AtomicU64::new(1);
 
 /// Returns a process-local generation ID for a recording/processing lifecycle.
@@ -89,6 +338,31 @@ pub fn recording_operation_id(app: &AppHandle, expected_binding_id: &str) -> Opt
     }
 }
 
+/// Milliseconds since the current recording started, or None when not recording.
+pub fn current_recording_elapsed_ms(app: &AppHandle) -> Option<u64> {
+    let state = app.state::<ManagedSessionState>();
+    let state_guard = lock_session_state(&state, "current_recording_elapsed_ms");
+    match &*state_guard {
+        SessionState::Recording { started_at, .. } => Some(started_at.elapsed().as_millis() as u64),
+        _ => None,
+    }
+}
+
+/// Operation id and start time of the recording for `expected_binding_id`.
+pub fn recording_started_at(app: &AppHandle, expected_binding_id: &str) -> Option<(u64, Instant)> {
+    let state = app.state::<ManagedSessionState>();
+    let state_guard = lock_session_state(&state, "recording_started_at");
+    match &*state_guard {
+        SessionState::Recording {
+            binding_id,
+            operation_id,
+            started_at,
+            ..
+        } if binding_id == expected_binding_id => Some((*operation_id, *started_at)),
+        _ => None,
+    }
+}
+
 pub fn has_current_operation_for_binding(app: &AppHandle, expected_binding_id: &str) -> bool {
     let state = app.state::<ManagedSessionState>();
     let state_guard = lock_session_state(&state, "has_current_operation_for_binding");
@@ -140,6 +414,8 @@ pub struct RecordingSession {
     mute_applied: AtomicBool,
     /// Track if Drop cleanup has already run (for explicit finish() calls)
     cleaned_up: AtomicBool,
+    /// Set on cleanup to stop the `recording-elapsed` timer task
+    elapsed_timer_stopped: Arc<AtomicBool>,
 }
 
 impl std::fmt::Debug for RecordingSession {
@@ -171,9 +447,52 @@ impl RecordingSession {
             cancel_shortcut_registered: AtomicBool::new(false), // Will be set when actually registered
             mute_applied: AtomicBool::new(will_apply_mute),
             cleaned_up: AtomicBool::new(false),
+            elapsed_timer_stopped: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Emits `recording-elapsed` every second until this session finishes or
+    /// drops, unless `overlay_show_timer` is off. Call once the recording for
+    /// `binding_id` has its confirmed start time.
+    pub fn start_elapsed_timer(&self, binding_id: &str) {
+        let settings = get_settings(&self.app);
+        if !settings.overlay_show_timer {
+            return;
+        }
+        let Some((operation_id, started_at)) = recording_started_at(&self.app, binding_id) else {
+            return;
+        };
+
+        let app = self.app.clone();
+        let binding_id = binding_id.to_string();
+        let stopped = Arc::clone(&self.elapsed_timer_stopped);
+        let max_duration_seconds = settings.max_recording_duration_seconds;
+        tauri::async_runtime::spawn(async move {
+            loop {
+                if stopped.load(Ordering::SeqCst)
+                    || recording_operation_id(&app, &binding_id) != Some(operation_id)
+                {
+                    break;
+                }
+                let elapsed = started_at.elapsed();
+                let paused = app
+                    .state::<Arc<AudioRecordingManager>>()
+                    .is_recording_paused();
+                let _ = app.emit(
+                    RECORDING_ELAPSED_EVENT,
+                    RecordingElapsedEvent::new(
+                        &binding_id,
+                        operation_id,
+                        elapsed,
+                        max_duration_seconds,
+                        paused,
+                    ),
+                );
+                tokio::time::sleep_until(next_elapsed_tick(started_at, elapsed).into()).await;
+            }
+        });
+    }
+
     /// Registers the cancel shortcut for this session.
     /// Safe to call multiple times - only registers once.
     pub fn register_cancel_shortcut(&self) {
@@ -194,9 +513,21 @@ impl RecordingSession {
         self.do_cleanup();
     }
 
+    /// Like finish(), but leaves the cancel shortcut registered so it keeps
+    /// working through the Processing phase. Returns true if the shortcut was
+    /// registered; the caller then owns unregistering it.
+    pub fn finish_keeping_cancel_shortcut(&self) -> bool {
+        let kept = self
+            .cancel_shortcut_registered
+            .swap(false, Ordering::SeqCst);
+        self.finish();
+        kept
+    }
+
     /// Internal cleanup logic, shared by finish() and Drop.
     fn do_cleanup(&self) {
         debug!("RecordingSession: Performing cleanup");
+        self.elapsed_timer_stopped.store(true, Ordering::SeqCst);
 
         // Unregister cancel shortcut if we registered it
         if self
@@ -244,7 +575,7 @@ pub fn take_session(app: &AppHandle) -> Option<(Arc<RecordingSession>, String)>
     let state = app.state::<ManagedSessionState>();
     let mut state_guard = lock_session_state(&state, "take_session");
 
-    match std::mem::replace(&mut *state_guard, SessionState::Idle) {
+    match replace_state(app, &mut state_guard, SessionState::Idle) {
         SessionState::Recording {
             session,
             binding_id,
@@ -281,7 +612,7 @@ pub fn take_session_if_matches(
         SessionState::Recording { binding_id, .. } if binding_id == expected_binding_id => {
             // Matches, take it
             if let SessionState::Recording { session, .. } =
-                std::mem::replace(&mut *state_guard, SessionState::Idle)
+                replace_state(app, &mut state_guard, SessionState::Idle)
             {
                 debug!(
                     "take_session_if_matches: Took session for {}",
@@ -317,7 +648,7 @@ pub fn exit_processing(app: &AppHandle) {
 
     if let SessionState::Processing { binding_id, .. } = &*state_guard {
         debug!("exit_processing: Exiting Processing for {}", binding_id);
-        *state_guard = SessionState::Idle;
+        replace_state(app, &mut state_guard, SessionState::Idle);
     } else {
         debug!("exit_processing: Not in Processing state, ignoring");
     }
@@ -329,7 +660,11 @@ pub fn exit_processing_if_matches(app: &AppHandle, expected_operation_id: u64) -
     let state = app.state::<ManagedSessionState>();
     let mut state_guard = lock_session_state(&state, "exit_processing_if_matches");
 
-    exit_processing_state_if_matches(&mut state_guard, expected_operation_id)
+    let exited = exit_processing_state_if_matches(&mut state_guard, expected_operation_id);
+    if exited {
+        publish_transition(app, &state_guard);
+    }
+    exited
 }
 
 fn exit_processing_state_if_matches(state: &mut SessionState, expected_operation_id: u64) -> bool {
@@ -367,7 +702,87 @@ fn exit_processing_state_if_matches(state: &mut SessionState, expected_operation
 
 #[cfg(test)]
 mod tests {
-    use super::{exit_processing_state_if_matches, is_operation_current_state, SessionState};
+    use super::{
+        event_for_transition, exit_processing_state_if_matches, is_operation_current_state,
+        next_elapsed_tick, ProcessingStage, RecordingElapsedEvent, SessionPhase, SessionState,
+        SessionStateChangedEvent,
+    };
+    use crate::settings::TranscriptionProvider;
+    use std::time::{Duration, Instant};
+
+    fn recording_event(operation_id: u64) -> SessionStateChangedEvent {
+        SessionStateChangedEvent {
+            state: SessionPhase::Recording,
+            binding_id: Some("transcribe".to_string()),
+            profile_id: Some("profile_1".to_string()),
+            provider: Some(TranscriptionProvider::Local),
+            operation_id: Some(operation_id),
+            stage: None,
+            changed_at_ms: 1_000,
+            recording_started_at_ms: Some(1_000),
+            processing_started_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn processing_event_carries_recording_metadata() {
+        let recording = recording_event(7);
+        let processing = SessionState::Processing {
+            binding_id: "transcribe".to_string(),
+            operation_id: 7,
+        };
+
+        let event = event_for_transition(Some(&recording), &processing, 5_000).unwrap();
+        assert_eq!(event.state, SessionPhase::Processing);
+        assert_eq!(event.profile_id.as_deref(), Some("profile_1"));
+        assert_eq!(event.provider, Some(TranscriptionProvider::Local));
+        assert_eq!(event.recording_started_at_ms, Some(1_000));
+        assert_eq!(event.processing_started_at_ms, Some(5_000));
+    }
+
+    #[test]
+    fn processing_event_drops_metadata_from_other_operation() {
+        let recording = recording_event(7);
+        let processing = SessionState::Processing {
+            binding_id: "transcribe".to_string(),
+            operation_id: 8,
+        };
+
+        let event = event_for_transition(Some(&recording), &processing, 5_000).unwrap();
+        assert_eq!(event.profile_id, None);
+        assert_eq!(event.recording_started_at_ms, None);
+    }
+
+    #[test]
+    fn idle_event_keeps_finished_session_and_clears_stage() {
+        let processing = SessionStateChangedEvent {
+            state: SessionPhase::Processing,
+            stage: Some(ProcessingStage::Thinking),
+            processing_started_at_ms: Some(5_000),
+            ..recording_event(7)
+        };
+
+        let event = event_for_transition(Some(&processing), &SessionState::Idle, 9_000).unwrap();
+        assert_eq!(event.state, SessionPhase::Idle);
+        assert_eq!(event.stage, None);
+        assert_eq!(event.binding_id.as_deref(), Some("transcribe"));
+        assert_eq!(event.changed_at_ms, 9_000);
+    }
+
+    #[test]
+    fn unchanged_phase_emits_nothing() {
+        assert!(event_for_transition(None, &SessionState::Idle, 1_000).is_none());
+
+        let processing = SessionStateChangedEvent {
+            state: SessionPhase::Processing,
+            ..recording_event(7)
+        };
+        let same = SessionState::Processing {
+            binding_id: "transcribe".to_string(),
+            operation_id: 7,
+        };
+        assert!(event_for_transition(Some(&processing), &same, 2_000).is_none());
+    }
 
     #[test]
     fn operation_ownership_matches_generation() {
@@ -408,4 +823,35 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn elapsed_event_counts_down_only_with_a_limit() {
+        let event =
+            RecordingElapsedEvent::new("transcribe", 7, Duration::from_millis(12_400), 60, true);
+        assert_eq!(event.elapsed_ms, 12_400);
+        assert_eq!(event.remaining_ms, Some(47_600));
+        assert!(event.paused);
+
+        let unlimited =
+            RecordingElapsedEvent::new("transcribe", 7, Duration::from_secs(5), 0, false);
+        assert_eq!(unlimited.remaining_ms, None);
+
+        let overrun =
+            RecordingElapsedEvent::new("transcribe", 7, Duration::from_secs(61), 60, false);
+        assert_eq!(overrun.remaining_ms, Some(0));
+    }
+
+    #[test]
+    fn elapsed_ticks_stay_on_whole_seconds_from_start() {
+        let started_at = Instant::now();
+        assert_eq!(
+            next_elapsed_tick(started_at, Duration::ZERO),
+            started_at + Duration::from_secs(1)
+        );
+        // A late tick lands on the next whole second instead of drifting.
+        assert_eq!(
+            next_elapsed_tick(started_at, Duration::from_millis(3_250)),
+            started_at + Duration::from_secs(4)
+        );
+    }
 }
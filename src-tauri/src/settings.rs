@@ -8,10 +8,13 @@ use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_store::StoreExt;
 
+use crate::audio_toolkit::{CustomWord, CustomWordKind};
 use crate::url_security::{
     infer_remote_stt_preset, is_plain_http_url, remote_stt_base_url_for_preset,
     LLM_ANTHROPIC_BASE_URL, LLM_CEREBRAS_BASE_URL, LLM_GROQ_BASE_URL, LLM_OPENAI_BASE_URL,
@@ -22,6 +25,12 @@ use crate::url_security::{
 pub const APPLE_INTELLIGENCE_PROVIDER_ID: &str = "apple_intelligence";
 pub const APPLE_INTELLIGENCE_DEFAULT_MODEL_ID: &str = "Apple Intelligence";
 pub const MAX_HISTORY_LIMIT: usize = 1000;
+pub const MAX_HISTORY_TRIM_SILENCE_PADDING_MS: u32 = 2000;
+pub const MAX_REPASTE_LAST_COUNT: u32 = 20;
+pub const MAX_UNDO_LAST_PASTE_TIMEOUT_SECS: u32 = 600;
+pub const MAX_SMART_JOIN_EXPIRY_SECS: u32 = 3600;
+pub const MAX_CONNECTOR_MESSAGE_MAX_AGE_MINUTES: u32 = 1440;
+pub const MAX_HISTORY_SESSION_GAP_MINUTES: u32 = 240;
 pub const MAX_LLM_POST_PROCESS_BENCHMARK_LOG_ITEMS: usize = 50;
 pub const MAX_DICTATION_STATS_COUNT: u64 = 9_007_199_254_740_991;
 pub const DICTATION_STATS_WARNING_THRESHOLD: u64 =
@@ -32,6 +41,14 @@ pub struct DictationStatsEditState(pub AtomicBool);
 
 static SETTINGS_STORE_RESET_NOTICE_PENDING: AtomicBool = AtomicBool::new(false);
 
+/// Quiet period after the last settings change before it is written to disk.
+const SETTINGS_WRITE_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Authoritative in-memory settings. Once populated, reads never touch the
+/// store; writes land here first and reach disk through `SETTINGS_WRITER`.
+static SETTINGS_CACHE: Mutex<Option<AppSettings>> = Mutex::new(None);
+static SETTINGS_WRITER: OnceLock<WriteCoalescer<AppSettings>> = OnceLock::new();
+
 /// Returns and clears the one-shot notice set when a malformed settings store
 /// was replaced with defaults during this app session.
 pub fn take_settings_store_reset_notice() -> bool {
@@ -118,9 +135,25 @@ pub struct ShortcutBinding {
     pub description: String,
     pub default_binding: String,
     pub current_binding: String,
+    /// Swallow the key event so the focused app never sees it (rdev engine on Windows only).
+    #[serde(default)]
+    pub suppress_keypress: bool,
+    /// Record the microphone and system output as separate channels and label
+    /// the transcript "Me:" / "Them:" (Windows only).
+    #[serde(default)]
+    pub interview_mode: bool,
+    /// Push-to-talk (`true`) or toggle (`false`) for this binding; `None`
+    /// falls back to the feature's own push-to-talk setting.
+    #[serde(default)]
+    pub push_to_talk_override: Option<bool>,
+    /// Search URL template for a search binding; `None` uses the global
+    /// `transcribe_and_search_url_template`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_url_template: Option<String>,
 }
 
 pub const PREVIEW_DELETE_LAST_WORD_BINDING_ID: &str = "preview_delete_last_word";
+pub const TRANSCRIBE_AND_SEARCH_BINDING_ID: &str = "transcribe_and_search";
 
 pub fn build_preview_delete_last_word_binding(current_binding: String) -> ShortcutBinding {
     ShortcutBinding {
@@ -129,6 +162,10 @@ pub fn build_preview_delete_last_word_binding(current_binding: String) -> Shortc
         description: "Delete the last word from the active preview workflow.".to_string(),
         default_binding: String::new(),
         current_binding,
+        suppress_keypress: false,
+        interview_mode: false,
+        push_to_talk_override: None,
+        search_url_template: None,
     }
 }
 
@@ -137,6 +174,110 @@ pub struct LLMPrompt {
     pub id: String,
     pub name: String,
     pub prompt: String,
+    /// Optional word limit enforced on the post-processed output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_words: Option<u32>,
+    /// Optional sentence limit enforced on the post-processed output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_sentences: Option<u32>,
+    /// Optional JSON path (e.g. `$.body`) into the model's response. The prompt
+    /// itself has to ask for JSON; when set, the value at this path becomes the
+    /// final text and the raw response is kept in history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_json_path: Option<String>,
+    /// Model used for this prompt instead of the profile/global model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_override: Option<String>,
+    /// Turns reasoning on or off for this prompt regardless of the global setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning_enabled_override: Option<bool>,
+    /// Fixed reasoning budget for this prompt, replacing the global or adaptive one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning_budget_override: Option<u32>,
+}
+
+/// How post-processed output exceeding a prompt's length limit is handled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputLimitStrategy {
+    /// Cut the output at the nearest sentence boundary that fits.
+    #[default]
+    Truncate,
+    /// Re-ask the model once with a "shorten" instruction, truncating if it still overflows.
+    Reask,
+}
+
+/// Punctuation normalization applied after custom words, for providers that
+/// over- or under-punctuate.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PunctuationMode {
+    /// Keep the provider's punctuation unchanged.
+    #[default]
+    AsIs,
+    /// Remove commas and periods except sentence-final ones.
+    Strip,
+    /// Ask the post-process LLM to add or normalize punctuation only.
+    LlmLight,
+}
+
+/// Where a profile's finished transcription goes instead of the usual paste.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OutputDestination {
+    /// Paste at the cursor like any other transcription.
+    #[default]
+    InsertAtCursor,
+    /// Copy to the clipboard without pasting.
+    ClipboardOnly,
+    /// Append to a file; `path_template` expands `${date_iso}` like file output.
+    AppendToFile { path_template: String },
+}
+
+impl OutputDestination {
+    pub fn inserts_at_cursor(&self) -> bool {
+        matches!(self, Self::InsertAtCursor)
+    }
+}
+
+/// Deterministic casing applied to the output after text replacements.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum CasingMode {
+    Lower,
+    Upper,
+    /// Capitalize every word, keeping acronyms and exception words.
+    Title,
+    /// Capitalize the first word of each sentence, lowercase the rest.
+    Sentence,
+    Snake,
+    Camel,
+}
+
+impl From<CasingMode> for crate::audio_toolkit::CaseStyle {
+    fn from(mode: CasingMode) -> Self {
+        match mode {
+            CasingMode::Lower => Self::Lower,
+            CasingMode::Upper => Self::Upper,
+            CasingMode::Title => Self::Title,
+            CasingMode::Sentence => Self::Sentence,
+            CasingMode::Snake => Self::Snake,
+            CasingMode::Camel => Self::Camel,
+        }
+    }
+}
+
+/// How much the screen reader announcer says about recording state changes.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Type, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreenReaderVerbosity {
+    /// Recording start, cancellation, paste results and errors.
+    #[default]
+    Minimal,
+    /// Also every processing stage shown on the overlay.
+    Verbose,
 }
 
 /// Per-profile LLM post-processing settings.
@@ -147,6 +288,16 @@ pub struct ProfileLlmSettings {
     pub enabled: bool,
     pub prompt_override: Option<String>,
     pub model_override: Option<String>,
+    #[serde(default)]
+    pub max_output_words: Option<u32>,
+    #[serde(default)]
+    pub max_output_sentences: Option<u32>,
+    #[serde(default)]
+    pub output_json_path: Option<String>,
+    #[serde(default)]
+    pub reasoning_enabled_over_words: Option<u32>,
+    #[serde(default)]
+    pub reasoning_budget_per_100_words: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -171,6 +322,79 @@ impl SonioxContext {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleDay {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+/// Switches the active profile during a time window (see `profile_schedule`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct ProfileScheduleRule {
+    /// Days the window starts on; empty means every day.
+    #[serde(default)]
+    pub days: Vec<ScheduleDay>,
+    /// Local time as "HH:MM". An end before the start runs past midnight;
+    /// equal times cover the whole day.
+    pub start_time: String,
+    pub end_time: String,
+    pub profile_id: String,
+}
+
+/// What quiet hours do while they are active.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum QuietHoursBehavior {
+    /// Recording shortcuts cannot start a new recording.
+    #[default]
+    BlockShortcuts,
+    /// Recording still works, but feedback sounds and overlays are suppressed.
+    MuteFeedback,
+}
+
+/// Saved position of the floating voice button for one monitor layout
+/// (logical coordinates).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Type)]
+pub struct VoiceButtonPosition {
+    pub x: f64,
+    pub y: f64,
+    /// Unix milliseconds; the newest entry is used for unknown layouts.
+    pub saved_at: i64,
+}
+
+/// Daily window in which recording shortcuts or feedback are paused.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct QuietHoursSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Days the window starts on; empty means every day.
+    #[serde(default)]
+    pub days: Vec<ScheduleDay>,
+    /// Local time as "HH:MM". An end before the start runs past midnight.
+    pub start_time: String,
+    pub end_time: String,
+    #[serde(default)]
+    pub behavior: QuietHoursBehavior,
+}
+
+impl Default for QuietHoursSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            days: Vec::new(),
+            start_time: "22:00".to_string(),
+            end_time: "07:00".to_string(),
+            behavior: QuietHoursBehavior::default(),
+        }
+    }
+}
+
 /// A custom transcription profile with its own language and translation settings.
 /// Each profile creates a separate shortcut binding (e.g., "transcribe_profile_abc123").
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -208,6 +432,33 @@ pub struct TranscriptionProfile {
     /// Enforce language hints strictly in Soniox (None = inherit global)
     #[serde(default)]
     pub soniox_language_hints_strict: Option<bool>,
+    /// Extra languages spoken alongside `language` (e.g. bilingual meetings). Soniox
+    /// receives them as language hints; single-language engines (local Whisper,
+    /// OpenAI-compatible Remote STT) fall back to "auto" when any are set.
+    /// `language` stays the primary language for ${language} and Chinese conversion.
+    #[serde(default)]
+    pub additional_language_hints: Vec<String>,
+    /// Punctuation normalization for this profile (None = inherit global)
+    #[serde(default)]
+    pub punctuation_mode: Option<PunctuationMode>,
+    /// Output casing for this profile (None = leave casing unchanged)
+    #[serde(default)]
+    pub output_casing: Option<CasingMode>,
+    /// Language code the final output is translated into (None = no translation)
+    #[serde(default)]
+    pub output_translation_language: Option<String>,
+    /// Input device used when recording with this profile (None = global microphone)
+    #[serde(default)]
+    pub input_device_override: Option<String>,
+    /// Stop on Soniox endpoint detection in toggle mode (None = inherit global)
+    #[serde(default)]
+    pub soniox_live_auto_stop_on_endpoint: Option<bool>,
+    /// Also append each transcription to a file, e.g. a daily note (None = off)
+    #[serde(default)]
+    pub file_output: Option<FileOutputSettings>,
+    /// Where the final output goes; anything but the cursor records without streaming
+    #[serde(default)]
+    pub output_destination: OutputDestination,
     // ==================== LLM Post-Processing Settings ====================
     /// Whether LLM post-processing is enabled for this profile
     /// Inherits from global post_process_enabled when profile is created
@@ -221,6 +472,21 @@ pub struct TranscriptionProfile {
     /// If Some, uses this model instead of the global model for the current provider
     #[serde(default)]
     pub llm_model_override: Option<String>,
+    /// Output word limit applied when `llm_prompt_override` is used
+    #[serde(default)]
+    pub llm_max_output_words: Option<u32>,
+    /// Output sentence limit applied when `llm_prompt_override` is used
+    #[serde(default)]
+    pub llm_max_output_sentences: Option<u32>,
+    /// Output JSON path applied when `llm_prompt_override` is used
+    #[serde(default)]
+    pub llm_output_json_path: Option<String>,
+    /// Override of `post_process_reasoning_enabled_over_words` (None = inherit global)
+    #[serde(default)]
+    pub llm_reasoning_enabled_over_words: Option<u32>,
+    /// Override of `post_process_reasoning_budget_per_100_words` (None = inherit global)
+    #[serde(default)]
+    pub llm_reasoning_budget_per_100_words: Option<u32>,
     /// Soniox context.general as JSON array string.
     #[serde(default)]
     pub soniox_context_general_json: String,
@@ -230,6 +496,74 @@ pub struct TranscriptionProfile {
     /// Soniox context.terms list.
     #[serde(default)]
     pub soniox_context_terms: Vec<String>,
+    /// Extra custom words merged ahead of the global list (None = global list only)
+    #[serde(default)]
+    pub custom_words_override: Option<Vec<String>>,
+}
+
+/// Per-profile file that finished transcriptions are appended to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct FileOutputSettings {
+    /// Directory of the file; created on the first append.
+    pub directory: String,
+    /// File name; `${date_iso}` expands to the current date (YYYY-MM-DD).
+    pub filename_template: String,
+    /// Entry written per transcription; supports the prompt variables and `${output}`.
+    pub entry_template: String,
+    /// Append instead of pasting; otherwise the text is pasted as well.
+    #[serde(default)]
+    pub instead_of_paste: bool,
+}
+
+/// One-shot provider/model override armed with `boost_next_transcription`.
+/// Unset fields keep the regular setting.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Type)]
+pub struct BoostOverride {
+    #[serde(default)]
+    pub provider: Option<TranscriptionProvider>,
+    /// Model of the (boosted) provider: local model id or remote model name.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Post-processing prompt to use instead of the selected one.
+    #[serde(default)]
+    pub post_process_prompt_id: Option<String>,
+}
+
+impl BoostOverride {
+    /// The model, unless blank.
+    pub fn model(&self) -> Option<&str> {
+        self.model
+            .as_deref()
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.provider.is_none() && self.model().is_none() && self.post_process_prompt_id.is_none()
+    }
+
+    /// Applies the override to a recording's settings snapshot. Profile
+    /// settings (language, STT prompt, LLM overrides) are resolved later from
+    /// the same snapshot and keep taking precedence.
+    pub fn apply(&self, settings: &mut AppSettings) {
+        if let Some(provider) = self.provider {
+            settings.transcription_provider = provider;
+        }
+        if let Some(model) = self.model().map(str::to_string) {
+            let target = match settings.transcription_provider {
+                TranscriptionProvider::Local => &mut settings.selected_model,
+                TranscriptionProvider::RemoteOpenAiCompatible => &mut settings.remote_stt.model_id,
+                TranscriptionProvider::RemoteSoniox => &mut settings.soniox_model,
+                TranscriptionProvider::RemoteDeepgram => &mut settings.deepgram_model,
+                TranscriptionProvider::RemoteOpenAiRealtime => &mut settings.openai_realtime_model,
+            };
+            *target = model;
+        }
+        if let Some(prompt_id) = &self.post_process_prompt_id {
+            settings.post_process_enabled = true;
+            settings.post_process_selected_prompt_id = Some(prompt_id.clone());
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -241,6 +575,15 @@ pub struct DiarizationSpeakerNameProfile {
 }
 
 impl TranscriptionProfile {
+    /// Whether the finished text is pasted at the cursor.
+    pub fn inserts_at_cursor(&self) -> bool {
+        self.output_destination.inserts_at_cursor()
+            && !self
+                .file_output
+                .as_ref()
+                .is_some_and(|config| config.instead_of_paste)
+    }
+
     /// Resolves the STT prompt based on profile override settings.
     /// Returns the profile's system_prompt if override is enabled, otherwise None
     /// (caller should fall back to global prompt).
@@ -256,12 +599,159 @@ impl TranscriptionProfile {
             None
         }
     }
+
+    /// True when the profile lists languages beyond its primary `language`.
+    pub fn has_additional_languages(&self) -> bool {
+        self.additional_language_hints
+            .iter()
+            .any(|hint| !hint.eq_ignore_ascii_case(&self.language))
+    }
+
+    /// Language for a single-language engine: "auto" when additional languages
+    /// are configured, the primary `language` otherwise.
+    pub fn single_engine_language(&self) -> &str {
+        if self.has_additional_languages() {
+            "auto"
+        } else {
+            &self.language
+        }
+    }
+}
+
+/// Trims custom words and drops blanks and exact duplicates, keeping the
+/// first occurrence's position.
+pub fn normalize_custom_words(words: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for word in words {
+        let word = word.trim();
+        if !word.is_empty() && !normalized.iter().any(|existing| existing == word) {
+            normalized.push(word.to_string());
+        }
+    }
+    normalized
+}
+
+/// Trims entry patterns and drops blank patterns and exact duplicates, keeping
+/// the first occurrence's position.
+pub fn normalize_custom_word_entries(
+    entries: impl IntoIterator<Item = CustomWord>,
+) -> Vec<CustomWord> {
+    let mut normalized: Vec<CustomWord> = Vec::new();
+    for mut entry in entries {
+        if entry.kind != CustomWordKind::Regex {
+            entry.pattern = entry.pattern.trim().to_string();
+        }
+        if !entry.pattern.trim().is_empty() && !normalized.contains(&entry) {
+            normalized.push(entry);
+        }
+    }
+    normalized
+}
+
+/// Words a transcription engine should be primed with: the fuzzy entries.
+/// Exact and regex entries are post-transcription rules only.
+pub fn custom_word_vocabulary(entries: &[CustomWord]) -> Vec<String> {
+    entries
+        .iter()
+        .filter(|entry| entry.kind == CustomWordKind::Fuzzy)
+        .map(|entry| {
+            if entry.replacement.is_empty() {
+                entry.pattern.clone()
+            } else {
+                entry.replacement.clone()
+            }
+        })
+        .collect()
+}
+
+/// Largest custom words file accepted by import.
+pub const MAX_CUSTOM_WORDS_FILE_BYTES: usize = 1024 * 1024;
+
+/// Parses a newline-delimited UTF-8 custom words file into trimmed, non-blank lines.
+pub fn parse_custom_words_file(bytes: &[u8]) -> Result<Vec<String>, String> {
+    if bytes.len() > MAX_CUSTOM_WORDS_FILE_BYTES {
+        return Err(format!(
+            "Custom words file is too large ({} KB, limit is {} KB)",
+            bytes.len() / 1024,
+            MAX_CUSTOM_WORDS_FILE_BYTES / 1024
+        ));
+    }
+    let text = std::str::from_utf8(bytes).map_err(|e| {
+        format!(
+            "Custom words file is not valid UTF-8 (invalid byte at offset {})",
+            e.valid_up_to()
+        )
+    })?;
+    Ok(text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Placeholder in transcription prompts replaced with the custom word list.
+pub const CUSTOM_WORDS_PROMPT_VARIABLE: &str = "${custom_words}";
+
+/// Comma-separated custom words, cut at a word boundary so the list stays
+/// within `max_chars`.
+pub fn custom_words_prompt_list(words: &[String], max_chars: usize) -> String {
+    let mut list = String::new();
+    for word in words
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+    {
+        let separator = if list.is_empty() { "" } else { ", " };
+        if list.chars().count() + separator.len() + word.chars().count() > max_chars {
+            break;
+        }
+        list.push_str(separator);
+        list.push_str(word);
+    }
+    list
 }
 
-/// Resolves the STT prompt to use for transcription.
+/// Replaces `${custom_words}` in `prompt` with `words_list`. Without the
+/// placeholder the list is appended on its own line when `append` is set.
+pub fn expand_custom_words_in_prompt(
+    prompt: Option<String>,
+    words_list: &str,
+    append: bool,
+) -> Option<String> {
+    match prompt {
+        Some(prompt) if prompt.contains(CUSTOM_WORDS_PROMPT_VARIABLE) => {
+            let expanded = prompt.replace(CUSTOM_WORDS_PROMPT_VARIABLE, words_list);
+            (!expanded.trim().is_empty()).then_some(expanded)
+        }
+        Some(prompt) if append && !words_list.is_empty() => {
+            Some(format!("{}\n{}", prompt, words_list))
+        }
+        None if append && !words_list.is_empty() => Some(words_list.to_string()),
+        prompt => prompt,
+    }
+}
+
+/// Resolves the STT prompt to use for transcription, with `${custom_words}`
+/// expanded and the word list appended when `stt_prompt_auto_inject_custom_words`
+/// is on. See [`select_stt_prompt`] for which prompt is picked.
+pub fn resolve_stt_prompt(
+    settings: &AppSettings,
+    profile: Option<&TranscriptionProfile>,
+    model_id: &str,
+) -> Option<String> {
+    expand_custom_words_in_prompt(
+        select_stt_prompt(profile, &settings.transcription_prompts, model_id),
+        &settings.stt_prompt_custom_words(profile),
+        settings.stt_prompt_auto_inject_custom_words,
+    )
+}
+
+/// Picks the STT prompt as written, without expanding `${custom_words}`.
 /// - If profile exists and has override enabled: uses profile's prompt (or None if empty)
 /// - Otherwise: uses the global per-model prompt from transcription_prompts
-pub fn resolve_stt_prompt(
+pub fn select_stt_prompt(
     profile: Option<&TranscriptionProfile>,
     transcription_prompts: &HashMap<String, String>,
     model_id: &str,
@@ -355,6 +845,57 @@ pub fn build_soniox_context_from_parts(
     Ok(Some(context))
 }
 
+/// Appends custom words to the explicit Soniox context terms for a single session.
+/// Explicit terms keep priority; custom words that would push the context past
+/// `SONIOX_CONTEXT_MAX_CHARS` are dropped.
+fn merge_custom_words_into_soniox_terms(
+    general_json: &str,
+    text: &str,
+    explicit_terms: &[String],
+    custom_words: &[String],
+) -> Vec<String> {
+    let mut terms = normalize_soniox_terms(explicit_terms);
+    let base_context = SonioxContext {
+        general: parse_soniox_context_general_json(general_json).unwrap_or_default(),
+        text: text.trim().to_string(),
+        terms: terms.clone(),
+    };
+    let mut used_chars = serde_json::to_string(&base_context)
+        .map(|json| json.chars().count())
+        .unwrap_or(0);
+    if terms.is_empty() {
+        used_chars += r#","terms":[]"#.len();
+    }
+
+    let mut dropped = 0usize;
+    for word in normalize_soniox_terms(custom_words) {
+        let lowered = word.to_lowercase();
+        if terms.iter().any(|term| term.to_lowercase() == lowered) {
+            continue;
+        }
+        // Quoted term plus its separating comma.
+        let cost = serde_json::to_string(&word)
+            .map(|json| json.chars().count())
+            .unwrap_or(word.chars().count() + 2)
+            + 1;
+        if dropped > 0 || used_chars + cost > SONIOX_CONTEXT_MAX_CHARS {
+            dropped += 1;
+            continue;
+        }
+        used_chars += cost;
+        terms.push(word);
+    }
+
+    if dropped > 0 {
+        warn!(
+            "Soniox context limit reached: dropped {} custom word(s) from context terms",
+            dropped
+        );
+    }
+
+    terms
+}
+
 pub fn resolve_soniox_context(
     profile: Option<&TranscriptionProfile>,
     settings: &AppSettings,
@@ -373,6 +914,25 @@ pub fn resolve_soniox_context(
         )
     };
 
+    // Soniox takes context terms instead of a prompt, so auto-injected
+    // custom words go there.
+    let custom_words =
+        if settings.stt_prompt_auto_inject_custom_words && settings.custom_words_enabled {
+            custom_word_vocabulary(&settings.custom_words_for_profile(profile))
+        } else if settings.soniox_sync_context_from_custom_words {
+            custom_word_vocabulary(&settings.custom_words)
+        } else {
+            Vec::new()
+        };
+    let merged_terms;
+    let terms = if !custom_words.is_empty() {
+        merged_terms =
+            merge_custom_words_into_soniox_terms(general_json, text, terms, &custom_words);
+        merged_terms.as_slice()
+    } else {
+        terms
+    };
+
     match build_soniox_context_from_parts(general_json, text, terms) {
         Ok(context) => context,
         Err(err) => {
@@ -510,6 +1070,103 @@ impl VoiceCommandDefaults {
     }
 }
 
+/// App and recording events that can run user-defined commands.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleHookKind {
+    AppStart,
+    AppExit,
+    RecordingStart,
+    RecordingEnd,
+}
+
+/// A command run when a lifecycle event fires, with the same execution
+/// options as a voice command.
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct LifecycleHook {
+    /// Unique identifier (e.g., "lh_1704067200000")
+    pub id: String,
+    /// The script/command to execute
+    pub script: String,
+    /// Recording hooks run on every dictation, so each one can be switched off
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    // ==================== Execution Options ====================
+    /// Silent execution (hidden window, output captured)
+    #[serde(default = "default_true")]
+    pub silent: bool,
+    /// Skip profile loading (-NoProfile flag)
+    #[serde(default)]
+    pub no_profile: bool,
+    /// Use PowerShell 7 (pwsh) instead of Windows PowerShell 5.1
+    #[serde(default)]
+    pub use_pwsh: bool,
+    /// Execution policy (None = inherit from voice command defaults)
+    #[serde(default)]
+    pub execution_policy: Option<ExecutionPolicy>,
+    /// Working directory for this command (None = current directory)
+    #[serde(default)]
+    pub working_directory: Option<String>,
+}
+
+impl LifecycleHook {
+    pub fn resolve_execution_options(
+        &self,
+        defaults: &VoiceCommandDefaults,
+    ) -> ResolvedExecutionOptions {
+        ResolvedExecutionOptions {
+            silent: self.silent,
+            no_profile: self.no_profile,
+            use_pwsh: self.use_pwsh,
+            execution_policy: self.execution_policy.unwrap_or(defaults.execution_policy),
+            working_directory: self.working_directory.clone(),
+        }
+    }
+}
+
+/// User commands run at app start/exit and when a recording starts/ends.
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct LifecycleHooks {
+    #[serde(default)]
+    pub app_start: Vec<LifecycleHook>,
+    #[serde(default)]
+    pub app_exit: Vec<LifecycleHook>,
+    #[serde(default)]
+    pub recording_start: Vec<LifecycleHook>,
+    #[serde(default)]
+    pub recording_end: Vec<LifecycleHook>,
+    /// A silent hook still running after this many seconds is killed
+    #[serde(default = "default_lifecycle_hook_timeout_secs")]
+    pub timeout_secs: u32,
+}
+
+impl Default for LifecycleHooks {
+    fn default() -> Self {
+        Self {
+            app_start: Vec::new(),
+            app_exit: Vec::new(),
+            recording_start: Vec::new(),
+            recording_end: Vec::new(),
+            timeout_secs: default_lifecycle_hook_timeout_secs(),
+        }
+    }
+}
+
+impl LifecycleHooks {
+    pub fn hooks(&self, kind: LifecycleHookKind) -> &[LifecycleHook] {
+        match kind {
+            LifecycleHookKind::AppStart => &self.app_start,
+            LifecycleHookKind::AppExit => &self.app_exit,
+            LifecycleHookKind::RecordingStart => &self.recording_start,
+            LifecycleHookKind::RecordingEnd => &self.recording_end,
+        }
+    }
+}
+
+fn default_lifecycle_hook_timeout_secs() -> u32 {
+    30
+}
+
 /// A text replacement rule that substitutes one text pattern with another.
 /// Supports escape sequences for special characters (e.g., \n for newline).
 /// Used to automatically fix common misheard phrases or apply consistent formatting.
@@ -696,63 +1353,15 @@ pub fn apply_text_replacements(text: &str, replacements: &[TextReplacement]) ->
     result
 }
 
-pub fn text_has_leading_whitespace(text: &str) -> bool {
-    text.chars()
-        .next()
-        .map(|ch| ch.is_whitespace())
-        .unwrap_or(false)
-}
-
-pub fn text_has_trailing_whitespace(text: &str) -> bool {
-    text.chars()
-        .last()
-        .map(|ch| ch.is_whitespace())
-        .unwrap_or(false)
-}
-
 pub fn apply_output_whitespace_policy(
     text: &str,
     leading_mode: OutputWhitespaceMode,
     trailing_mode: OutputWhitespaceMode,
 ) -> String {
-    if text.is_empty() {
-        return String::new();
-    }
-
-    let mut result = match leading_mode {
-        OutputWhitespaceMode::Preserve => text.to_string(),
-        OutputWhitespaceMode::RemoveIfPresent => {
-            text.trim_start_matches(char::is_whitespace).to_string()
-        }
-        OutputWhitespaceMode::AddIfMissing => {
-            if text_has_leading_whitespace(text) {
-                text.to_string()
-            } else {
-                format!(" {}", text)
-            }
-        }
-    };
-
-    if result.is_empty() {
-        return result;
-    }
-
-    result = match trailing_mode {
-        OutputWhitespaceMode::Preserve => result,
-        OutputWhitespaceMode::RemoveIfPresent => {
-            result.trim_end_matches(char::is_whitespace).to_string()
-        }
-        OutputWhitespaceMode::AddIfMissing => {
-            if text_has_trailing_whitespace(&result) {
-                result
-            } else {
-                result.push(' ');
-                result
-            }
-        }
-    };
-
-    result
+    crate::output_whitespace::apply_rules(
+        text,
+        &crate::output_whitespace::policy_rules(leading_mode, trailing_mode),
+    )
 }
 
 pub fn apply_output_whitespace_policy_for_settings(text: &str, settings: &AppSettings) -> String {
@@ -763,6 +1372,39 @@ pub fn apply_output_whitespace_policy_for_settings(text: &str, settings: &AppSet
     )
 }
 
+/// How an OpenAI-compatible endpoint expects the API key.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiAuthStyle {
+    /// `Authorization: Bearer <key>`
+    #[default]
+    Bearer,
+    /// `api-key: <key>`, as Azure OpenAI deployments expect.
+    AzureApiKey,
+}
+
+impl ApiAuthStyle {
+    /// Header name and value carrying `api_key`.
+    pub fn header(self, api_key: &str) -> (&'static str, String) {
+        match self {
+            ApiAuthStyle::Bearer => ("Authorization", format!("Bearer {}", api_key)),
+            ApiAuthStyle::AzureApiKey => ("api-key", api_key.to_string()),
+        }
+    }
+
+    /// Appended to 401 errors, since a mismatched auth style looks like a bad key.
+    pub fn unauthorized_hint(self) -> &'static str {
+        match self {
+            ApiAuthStyle::Bearer => {
+                "If this is an Azure OpenAI deployment, switch the auth style to Azure API key."
+            }
+            ApiAuthStyle::AzureApiKey => {
+                "The key was sent as an api-key header; switch the auth style to Bearer if this endpoint is not Azure OpenAI."
+            }
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct PostProcessProvider {
     pub id: String,
@@ -774,6 +1416,12 @@ pub struct PostProcessProvider {
     pub allow_insecure_http: bool,
     #[serde(default)]
     pub models_endpoint: Option<String>,
+    /// Only the custom provider can change this; built-in providers use Bearer.
+    #[serde(default)]
+    pub auth_style: ApiAuthStyle,
+    /// Added to every request URL, e.g. `api-version` for Azure OpenAI.
+    #[serde(default)]
+    pub extra_query_params: HashMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -826,6 +1474,21 @@ pub enum TranscriptionProvider {
     RemoteSoniox,
     #[serde(rename = "remote_deepgram")]
     RemoteDeepgram,
+    #[serde(rename = "remote_openai_realtime")]
+    RemoteOpenAiRealtime,
+}
+
+impl TranscriptionProvider {
+    /// Serialized name, as stored in settings and history.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::RemoteOpenAiCompatible => "remote_openai_compatible",
+            Self::RemoteSoniox => "remote_soniox",
+            Self::RemoteDeepgram => "remote_deepgram",
+            Self::RemoteOpenAiRealtime => "remote_openai_realtime",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -849,6 +1512,8 @@ pub const SONIOX_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS: u32 = 500;
 pub const DEEPGRAM_DEFAULT_MODEL: &str = "nova-3";
 pub const DEEPGRAM_DEFAULT_ENDPOINTING_MS: u32 = 400;
 pub const DEEPGRAM_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS: u32 = 1200;
+pub const OPENAI_REALTIME_DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+pub const OPENAI_REALTIME_DEFAULT_MODEL: &str = "gpt-realtime-whisper";
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "lowercase")]
@@ -892,6 +1557,9 @@ pub enum ShortcutEngine {
     /// Use rdev low-level hooks (all keys supported, higher CPU usage)
     /// Supports ALL keys including Caps Lock, Num Lock, and modifier-only shortcuts
     Rdev,
+    /// Use the XDG Desktop Portal GlobalShortcuts interface (Linux Wayland only)
+    /// Selected automatically on Wayland sessions, where key grabs and hooks are unavailable
+    Portal,
 }
 
 impl Default for ShortcutEngine {
@@ -924,6 +1592,12 @@ pub struct RemoteSttSettings {
     pub debug_capture: bool,
     #[serde(default = "default_remote_stt_debug_mode")]
     pub debug_mode: RemoteSttDebugMode,
+    /// Only the custom preset can change this; other presets use Bearer.
+    #[serde(default)]
+    pub auth_style: ApiAuthStyle,
+    /// Added to the request URL, e.g. `api-version` for Azure OpenAI.
+    #[serde(default)]
+    pub extra_query_params: HashMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -1062,6 +1736,8 @@ pub enum SonioxLivePreviewTheme {
 pub enum ScreenshotCaptureMethod {
     ExternalProgram,
     Native,
+    /// Image already on the clipboard (e.g. Win+Shift+S).
+    Clipboard,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -1090,6 +1766,8 @@ pub enum ModelUnloadTimeout {
     Hour1,
     #[serde(rename = "sec_5", alias = "sec5")]
     Sec5, // Debug mode only
+    /// Unload after `model_keep_loaded_minutes` of inactivity
+    Custom,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -1141,6 +1819,69 @@ pub enum PasteMethod {
     None,
     ShiftInsert,
     CtrlShiftV,
+    /// Types the text one character at a time for targets that ignore
+    /// clipboard paste shortcuts (some terminals, RDP and Citrix sessions)
+    TypeCharacters,
+}
+
+/// Paste method used instead of the global one when the frontmost app at
+/// recording start matches `app_pattern`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct AppPasteOverride {
+    pub id: String,
+    /// Case-insensitive substring of the app (window) name, or a `*`/`?`
+    /// pattern matched against the whole name when `glob` is set.
+    pub app_pattern: String,
+    pub method: PasteMethod,
+    /// Replaces the global `paste_delay_ms` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_delay_ms: Option<u64>,
+    #[serde(default)]
+    pub glob: bool,
+}
+
+impl AppPasteOverride {
+    pub fn matches(&self, app_name: &str) -> bool {
+        let pattern = self.app_pattern.trim().to_lowercase();
+        if pattern.is_empty() || app_name.trim().is_empty() {
+            return false;
+        }
+        let app_name = app_name.to_lowercase();
+        if self.glob {
+            let pattern: Vec<char> = pattern.chars().collect();
+            let name: Vec<char> = app_name.chars().collect();
+            glob_matches(&pattern, &name)
+        } else {
+            app_name.contains(&pattern)
+        }
+    }
+}
+
+/// `*` matches any run of characters and `?` exactly one.
+fn glob_matches(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -1213,6 +1954,26 @@ impl Default for OutputWhitespaceMode {
     }
 }
 
+/// Per-binding microphone stream policy.
+/// `AlwaysOn` keeps the capture stream open after the recording stops;
+/// `OnDemand` closes it (or schedules a lazy close) once the recording ends.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum MicMode {
+    AlwaysOn,
+    OnDemand,
+}
+
+/// Maps a shortcut binding ID to the key used by per-binding microphone overrides.
+/// All transcription bindings (main, default and profile shortcuts) share the "transcribe" family.
+pub fn microphone_binding_family(binding_id: &str) -> &str {
+    if binding_id == "transcribe" || binding_id.starts_with("transcribe_") {
+        "transcribe"
+    } else {
+        binding_id
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum LiveSoundCaptureSource {
@@ -1254,7 +2015,8 @@ pub fn resolve_live_sound_provider(settings: &AppSettings) -> TranscriptionProvi
 }
 
 impl ModelUnloadTimeout {
-    pub fn to_minutes(self) -> Option<u64> {
+    /// `custom_minutes` is `model_keep_loaded_minutes`, used by [`Self::Custom`].
+    pub fn to_minutes(self, custom_minutes: u32) -> Option<u64> {
         match self {
             ModelUnloadTimeout::Never => None,
             ModelUnloadTimeout::Immediately => Some(0), // Special case for immediate unloading
@@ -1264,15 +2026,16 @@ impl ModelUnloadTimeout {
             ModelUnloadTimeout::Min15 => Some(15),
             ModelUnloadTimeout::Hour1 => Some(60),
             ModelUnloadTimeout::Sec5 => Some(0), // Special case for debug - handled separately
+            ModelUnloadTimeout::Custom => Some(custom_minutes.max(1) as u64),
         }
     }
 
-    pub fn to_seconds(self) -> Option<u64> {
+    pub fn to_seconds(self, custom_minutes: u32) -> Option<u64> {
         match self {
             ModelUnloadTimeout::Never => None,
             ModelUnloadTimeout::Immediately => Some(0), // Special case for immediate unloading
             ModelUnloadTimeout::Sec5 => Some(5),
-            _ => self.to_minutes().map(|m| m * 60),
+            _ => self.to_minutes(custom_minutes).map(|m| m * 60),
         }
     }
 }
@@ -1428,6 +2191,12 @@ pub struct AppSettings {
     pub audio_feedback: bool,
     #[serde(default)]
     pub result_ready_audio_feedback: bool,
+    /// Emit overlay start/stop/error cues instead of sounds when audio feedback is off.
+    #[serde(default)]
+    pub visual_feedback_enabled: bool,
+    /// Also blink the tray icon for visual-only cues.
+    #[serde(default)]
+    pub visual_feedback_tray_blink: bool,
     #[serde(default = "default_audio_feedback_volume")]
     pub audio_feedback_volume: f32,
     #[serde(default = "default_sound_theme")]
@@ -1446,14 +2215,42 @@ pub struct AppSettings {
     pub update_checks_enabled: bool,
     #[serde(default = "default_model")]
     pub selected_model: String,
+    /// Switch to another downloaded model when the selected one is missing or damaged on disk.
+    #[serde(default = "default_true")]
+    pub auto_fallback_on_missing_model: bool,
+    /// Re-run a local transcription through `local_fallback_model` when the
+    /// output is empty despite speech or stuck in a repetition loop.
+    #[serde(default)]
+    pub local_fallback_enabled: bool,
+    /// Larger local model used for the retry; must be downloaded.
+    #[serde(default)]
+    pub local_fallback_model: Option<String>,
+    /// Repetition ratio (0.0-1.0) above which the output counts as a loop.
+    #[serde(default = "default_local_fallback_repetition_threshold")]
+    pub local_fallback_repetition_threshold: f32,
     #[serde(default = "default_transcription_provider")]
     pub transcription_provider: TranscriptionProvider,
+    /// Experimental: with a remote provider selected, also run the downloaded
+    /// local model and keep whichever returns text first.
+    #[serde(default)]
+    pub transcription_race_mode: bool,
     #[serde(default = "default_remote_stt_settings")]
     pub remote_stt: RemoteSttSettings,
+    /// Total Remote STT request attempts, including the first one.
+    #[serde(default = "default_remote_stt_retry_attempts")]
+    pub remote_stt_retry_attempts: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    #[serde(default = "default_remote_stt_retry_backoff_ms")]
+    pub remote_stt_retry_backoff_ms: u64,
     #[serde(default)]
     pub openai_realtime_whisper_delay: OpenAiRealtimeWhisperDelay,
     #[serde(default)]
     pub openai_realtime_whisper_flatten_enabled: bool,
+    /// Endpoint of the OpenAI Realtime provider, validated when saved.
+    #[serde(default = "default_openai_realtime_base_url")]
+    pub openai_realtime_base_url: String,
+    #[serde(default = "default_openai_realtime_model")]
+    pub openai_realtime_model: String,
     #[serde(default = "default_soniox_model")]
     pub soniox_model: String,
     #[serde(default = "default_soniox_timeout_seconds")]
@@ -1468,6 +2265,9 @@ pub struct AppSettings {
     pub soniox_context_text: String,
     #[serde(default)]
     pub soniox_context_terms: Vec<String>,
+    /// Merge `custom_words` into the Soniox context terms at session start (not persisted).
+    #[serde(default = "default_false")]
+    pub soniox_sync_context_from_custom_words: bool,
     #[serde(default = "default_false")]
     pub soniox_use_profile_language_hint_only: bool,
     #[serde(default = "default_false")]
@@ -1476,14 +2276,26 @@ pub struct AppSettings {
     pub soniox_enable_endpoint_detection: bool,
     #[serde(default = "default_soniox_max_endpoint_delay_ms")]
     pub soniox_max_endpoint_delay_ms: u32,
+    /// Stop a toggle-mode Soniox live recording once speech has ended.
+    #[serde(default = "default_false")]
+    pub soniox_live_auto_stop_on_endpoint: bool,
+    /// Consecutive endpoints without new speech before auto-stop begins.
+    #[serde(default = "default_soniox_live_auto_stop_endpoint_count")]
+    pub soniox_live_auto_stop_endpoint_count: u32,
     #[serde(default = "default_soniox_endpoint_sensitivity")]
     pub soniox_endpoint_sensitivity: f32,
     #[serde(default = "default_true")]
     pub soniox_enable_language_identification: bool,
     #[serde(default = "default_true")]
     pub soniox_enable_speaker_diarization: bool,
+    /// Prefix non-streaming Soniox output with "Speaker N:" labels when diarization is on.
+    #[serde(default = "default_false")]
+    pub soniox_include_speaker_labels: bool,
     #[serde(default = "default_soniox_keepalive_interval_seconds")]
     pub soniox_keepalive_interval_seconds: u32,
+    /// Reconnect attempts after the live socket drops mid-session (0 = disabled).
+    #[serde(default = "default_soniox_live_reconnect_attempts")]
+    pub soniox_live_reconnect_attempts: u32,
     #[serde(default = "default_soniox_live_finalize_timeout_ms")]
     pub soniox_live_finalize_timeout_ms: u32,
     #[serde(default = "default_false")]
@@ -1520,6 +2332,10 @@ pub struct AppSettings {
     pub deepgram_endpointing_ms: u32,
     #[serde(default = "default_always_on_microphone")]
     pub always_on_microphone: bool,
+    /// Per-binding-family overrides of `always_on_microphone` (e.g. "transcribe", "ai_replace_selection").
+    /// Families without an entry follow the global flag.
+    #[serde(default)]
+    pub microphone_mode_overrides: HashMap<String, MicMode>,
     #[serde(default)]
     pub selected_microphone: Option<String>,
     #[serde(default)]
@@ -1555,6 +2371,18 @@ pub struct AppSettings {
     pub translate_to_english: bool,
     #[serde(default = "default_selected_language")]
     pub selected_language: String,
+    /// First language of the `toggle_dictation_language` action
+    #[serde(default = "default_language_toggle_primary")]
+    pub language_toggle_primary: String,
+    /// Second language of the `toggle_dictation_language` action (empty = not configured)
+    #[serde(default)]
+    pub language_toggle_secondary: String,
+    /// Override armed by the `boost_next_transcription` action
+    #[serde(default)]
+    pub boost_override: BoostOverride,
+    /// Seconds an armed boost waits for a recording before it expires
+    #[serde(default = "default_boost_expiry_secs")]
+    pub boost_expiry_secs: u32,
     #[serde(default = "default_overlay_position")]
     pub overlay_position: OverlayPosition,
     // The JSON normalization step derives this from legacy `overlay_position`
@@ -1579,6 +2407,11 @@ pub struct AppSettings {
     /// Show runtime errors in the recording overlay.
     #[serde(default = "default_true")]
     pub error_feedback_enabled: bool,
+    /// Announce recording state changes through the OS screen reader.
+    #[serde(default)]
+    pub screen_reader_announcements_enabled: bool,
+    #[serde(default)]
+    pub screen_reader_verbosity: ScreenReaderVerbosity,
     #[serde(default)]
     pub recording_overlay_custom_enabled: bool,
     #[serde(default)]
@@ -1597,6 +2430,9 @@ pub struct AppSettings {
     pub recording_overlay_show_status_icon: bool,
     #[serde(default = "default_true")]
     pub recording_overlay_show_cancel_button: bool,
+    /// Show elapsed recording time (or a countdown when a max duration is set) on the overlay.
+    #[serde(default = "default_true")]
+    pub overlay_show_timer: bool,
     #[serde(default = "default_recording_overlay_bar_count")]
     pub recording_overlay_bar_count: u8,
     #[serde(default = "default_recording_overlay_width_px")]
@@ -1731,6 +2567,11 @@ pub struct AppSettings {
         alias = "soniox_live_preview_local_auto_flush_overlap_ms"
     )]
     pub local_preview_auto_flush_overlap_ms: u16,
+    /// Show chunked partial results from non-streaming local models in the
+    /// live preview while recording. The pasted text still comes from the
+    /// full transcription after recording stops.
+    #[serde(default)]
+    pub local_streaming_preview_enabled: bool,
     #[serde(default)]
     pub soniox_live_preview_sliding_lm_window_enabled: bool,
     #[serde(default = "default_soniox_live_preview_sliding_lm_window_prompt")]
@@ -1741,20 +2582,71 @@ pub struct AppSettings {
     pub debug_mode: bool,
     #[serde(default = "default_log_level")]
     pub log_level: LogLevel,
+    /// Custom word entries. Older settings stored plain strings, which load
+    /// as fuzzy entries.
     #[serde(default)]
-    pub custom_words: Vec<String>,
+    pub custom_words: Vec<CustomWord>,
     #[serde(default = "default_custom_words_enabled")]
     pub custom_words_enabled: bool,
+    /// Append the custom word list to STT prompts that have no
+    /// `${custom_words}` placeholder (Soniox gets them as context terms).
+    #[serde(default)]
+    pub stt_prompt_auto_inject_custom_words: bool,
+    /// Character budget for the custom word list inserted into STT prompts.
+    #[serde(default = "default_stt_prompt_custom_words_max_chars")]
+    pub stt_prompt_custom_words_max_chars: u32,
     #[serde(default = "default_custom_words_ngram_enabled")]
     pub custom_words_ngram_enabled: bool,
     #[serde(default)]
     pub model_unload_timeout: ModelUnloadTimeout,
+    /// Idle minutes before the local model is unloaded when
+    /// `model_unload_timeout` is `custom`
+    #[serde(default = "default_model_keep_loaded_minutes")]
+    pub model_keep_loaded_minutes: u32,
+    /// Load the selected local model during app start instead of on first use
+    #[serde(default)]
+    pub model_preload_on_startup: bool,
     #[serde(default = "default_word_correction_threshold")]
     pub word_correction_threshold: f64,
     #[serde(default = "default_history_limit")]
     pub history_limit: usize,
     #[serde(default = "default_recording_retention_period")]
     pub recording_retention_period: RecordingRetentionPeriod,
+    /// History takes with the same profile and target app that follow each
+    /// other within this many minutes form one dictation session; 0 disables.
+    #[serde(default = "default_history_session_gap_minutes")]
+    pub history_session_gap_minutes: u32,
+    /// Opt-in: allows recording reference texts on history entries and
+    /// computing WER/CER statistics from them.
+    #[serde(default)]
+    pub accuracy_evaluation_enabled: bool,
+    /// Opt-in: keeps segment timings from local engines that report them
+    /// with each history entry, for SRT/VTT export.
+    #[serde(default)]
+    pub history_timestamps_enabled: bool,
+    /// Strip leading and trailing silence (per VAD) from recordings before
+    /// they are saved to history. Transcription always gets the full audio.
+    #[serde(default)]
+    pub history_trim_silence_enabled: bool,
+    /// Silence kept on each side of the speech when trimming, in milliseconds.
+    #[serde(default = "default_history_trim_silence_padding_ms")]
+    pub history_trim_silence_padding_ms: u32,
+    /// After pasting, warn in the overlay when the provider-reported
+    /// confidence falls below this value (0.0–1.0); 0.0 disables the warning.
+    #[serde(default)]
+    pub low_confidence_warning_threshold: f32,
+    /// Number of recent history entries pasted by "Repaste Last N".
+    #[serde(default = "default_repaste_last_count")]
+    pub repaste_last_count: u32,
+    /// Text placed between entries pasted by "Repaste Last N".
+    #[serde(default = "default_repaste_last_separator")]
+    pub repaste_last_separator: String,
+    /// Whether "Repaste Last N" also pastes AI Replace responses.
+    #[serde(default)]
+    pub repaste_last_include_ai_replace: bool,
+    /// How long after a paste "Undo Last Paste" may still erase it.
+    #[serde(default = "default_undo_last_paste_timeout_secs")]
+    pub undo_last_paste_timeout_secs: u32,
     #[serde(default)]
     pub dictation_stats_enabled: bool,
     #[serde(default)]
@@ -1769,15 +2661,30 @@ pub struct AppSettings {
     pub paste_method: PasteMethod,
     #[serde(default = "default_paste_delay_ms")]
     pub paste_delay_ms: u64,
+    /// Per-app replacements for `paste_method`/`paste_delay_ms`; the first
+    /// matching entry wins.
+    #[serde(default)]
+    pub app_paste_overrides: Vec<AppPasteOverride>,
+    /// Pause between characters for `PasteMethod::TypeCharacters`
+    #[serde(default = "default_type_characters_delay_ms")]
+    pub type_characters_delay_ms: u64,
     /// Convert LF to CRLF before clipboard paste (fixes newlines on Windows)
     #[serde(default = "default_true")]
     pub convert_lf_to_crlf: bool,
+    /// When removing streamed text, only erase whole grapheme clusters (emoji,
+    /// flags) and skip anything that cannot be verified, even if that leaves
+    /// some text behind.
+    #[serde(default)]
+    pub stream_conservative_delete: bool,
     #[serde(default)]
     pub clipboard_handling: ClipboardHandling,
     #[serde(default = "default_auto_submit")]
     pub auto_submit: bool,
     #[serde(default)]
     pub auto_submit_key: AutoSubmitKey,
+    /// After a simulated paste, release synthesized modifiers the OS still reports as held.
+    #[serde(default = "default_true")]
+    pub stuck_modifier_recovery_enabled: bool,
     #[serde(default = "default_post_process_enabled")]
     pub post_process_enabled: bool,
     #[serde(default = "default_post_process_provider_id")]
@@ -1788,10 +2695,35 @@ pub struct AppSettings {
     pub post_process_api_keys: SecretMap,
     #[serde(default = "default_post_process_models")]
     pub post_process_models: HashMap<String, String>,
+    /// Provider retried once when the post-processing request fails with a
+    /// network or 5xx error (None = fall back to the raw transcription).
+    #[serde(default)]
+    pub post_process_fallback_provider_id: Option<String>,
+    /// Model for the fallback provider (None = its model in `post_process_models`).
+    #[serde(default)]
+    pub post_process_fallback_model: Option<String>,
     #[serde(default = "default_post_process_prompts")]
     pub post_process_prompts: Vec<LLMPrompt>,
     #[serde(default)]
     pub post_process_selected_prompt_id: Option<String>,
+    /// How outputs exceeding a prompt's word/sentence limit are shortened.
+    #[serde(default)]
+    pub post_process_output_limit_strategy: OutputLimitStrategy,
+    /// Longest clipboard text inserted for `${clipboard}` in LLM prompts.
+    #[serde(default = "default_llm_clipboard_variable_max_chars")]
+    pub llm_clipboard_variable_max_chars: u32,
+    /// Punctuation normalization for the default profile.
+    #[serde(default)]
+    pub punctuation_mode: PunctuationMode,
+    /// Output casing for the default profile (None = leave casing unchanged).
+    #[serde(default)]
+    pub output_casing: Option<CasingMode>,
+    /// Words Title and Sentence casing output exactly as written here.
+    #[serde(default = "default_output_casing_exceptions")]
+    pub output_casing_exceptions: Vec<String>,
+    /// Language code the default profile's output is translated into (None = no translation).
+    #[serde(default)]
+    pub output_translation_language: Option<String>,
     #[serde(default = "default_post_process_benchmark_collapsed")]
     pub post_process_benchmark_collapsed: bool,
     #[serde(default = "default_post_process_benchmark_system_prompt")]
@@ -1813,6 +2745,17 @@ pub struct AppSettings {
     /// Expiry window (seconds) for ${short_prev_transcript}.
     #[serde(default = "default_llm_context_prev_transcript_expiry_seconds")]
     pub llm_context_prev_transcript_expiry_seconds: u64,
+    /// When a profile has no ${short_prev_transcript} entry for the app yet,
+    /// read the app's default-profile entry instead.
+    #[serde(default = "default_true")]
+    pub llm_context_prev_transcript_profile_fallback: bool,
+    /// App-name substrings (case-insensitive) whose dictations are never stored
+    /// or reused as ${short_prev_transcript} context.
+    #[serde(default)]
+    pub transcript_context_blocklist: Vec<String>,
+    /// Minutes before privacy mode turns itself off (0 = until turned off or restart).
+    #[serde(default = "default_privacy_mode_duration_minutes")]
+    pub privacy_mode_duration_minutes: u32,
     #[serde(default = "default_ai_replace_system_prompt")]
     pub ai_replace_system_prompt: String,
     #[serde(default = "default_ai_replace_user_prompt")]
@@ -1823,6 +2766,12 @@ pub struct AppSettings {
     pub ai_replace_restore_on_error: bool,
     #[serde(default = "default_ai_replace_allow_no_selection")]
     pub ai_replace_allow_no_selection: bool,
+    /// Show an accept/reject preview of the AI Replace result instead of pasting it
+    #[serde(default)]
+    pub ai_replace_confirm_before_paste: bool,
+    /// Seconds before an unanswered AI Replace preview is rejected (5..600)
+    #[serde(default = "default_ai_replace_confirm_timeout_seconds")]
+    pub ai_replace_confirm_timeout_seconds: u32,
     #[serde(default = "default_ai_replace_no_selection_system_prompt")]
     pub ai_replace_no_selection_system_prompt: String,
     #[serde(default = "default_ai_replace_no_selection_user_prompt")]
@@ -1848,6 +2797,13 @@ pub struct AppSettings {
     pub send_to_extension_with_selection_system_prompt: String,
     #[serde(default = "default_send_to_extension_with_selection_user_prompt")]
     pub send_to_extension_with_selection_user_prompt: String,
+    /// Search URL opened by the "Transcribe and Search" action; `${query}` is
+    /// replaced with the URL-encoded transcription.
+    #[serde(default = "default_transcribe_and_search_url_template")]
+    pub transcribe_and_search_url_template: String,
+    /// Whether "Transcribe and Search" runs LLM post-processing before searching.
+    #[serde(default)]
+    pub transcribe_and_search_post_process_enabled: bool,
     /// Whether the "Send Transcription to Extension" action is enabled (risky feature)
     #[serde(default)]
     pub send_to_extension_enabled: bool,
@@ -1868,6 +2824,9 @@ pub struct AppSettings {
     pub ai_replace_selection_push_to_talk: bool,
     #[serde(default)]
     pub mute_while_recording: bool,
+    /// Binding families that never mute system audio, even when mute_while_recording is enabled.
+    #[serde(default)]
+    pub mute_while_recording_exempt_bindings: Vec<String>,
     #[serde(default)]
     pub pause_media_while_recording: bool,
     #[serde(default = "default_filter_silence")]
@@ -1876,6 +2835,9 @@ pub struct AppSettings {
     pub file_transcription_chunking_mode: FileTranscriptionChunkingMode,
     #[serde(default = "default_file_transcription_chunking_max_minutes")]
     pub file_transcription_chunking_max_minutes: f32,
+    /// Files longer than this are split at pauses and transcribed segment by segment.
+    #[serde(default = "default_file_transcription_segment_minutes")]
+    pub file_transcription_segment_minutes: f32,
     /// Optional microphone-only preamp in dB, saved per microphone device name.
     #[serde(default = "default_microphone_input_boost_db_by_device")]
     pub microphone_input_boost_db_by_device: HashMap<String, f32>,
@@ -1885,6 +2847,20 @@ pub struct AppSettings {
     /// Microphone-only RNNoise suppression before VAD/STT. Loopback capture is left untouched.
     #[serde(default = "default_false")]
     pub microphone_noise_cancellation_enabled: bool,
+    /// When the microphone disappears mid-recording, reopen the default input
+    /// device and keep recording (leaving a gap) instead of finalizing early.
+    #[serde(default = "default_false")]
+    pub auto_recover_input_device: bool,
+    /// Emit `recording-level` RMS/peak events (~10 per second) while recording.
+    #[serde(default = "default_true")]
+    pub recording_level_meter_enabled: bool,
+    /// High-pass filter and spectral noise gate on buffered recordings before
+    /// transcription. History keeps the unprocessed audio.
+    #[serde(default = "default_false")]
+    pub audio_preprocessing_enabled: bool,
+    /// Cutoff of the pre-processing high-pass filter in Hz (20-300).
+    #[serde(default = "default_audio_preprocessing_high_pass_hz")]
+    pub audio_preprocessing_high_pass_hz: u32,
     #[serde(default = "default_connector_port")]
     pub connector_port: u16,
     #[serde(default = "default_connector_enabled")]
@@ -1899,10 +2875,54 @@ pub struct AppSettings {
     pub connector_auto_open_enabled: bool,
     #[serde(default = "default_connector_auto_open_url")]
     pub connector_auto_open_url: String,
+    /// Interval (seconds) of the connector heartbeat that refreshes the cached online state.
+    #[serde(default = "default_connector_health_check_interval_seconds")]
+    pub connector_health_check_interval_seconds: u32,
+    /// Longest time (seconds) a connector long poll is held open, so the
+    /// extension has to poll again at least this often.
+    #[serde(default = "default_connector_heartbeat_interval_seconds")]
+    pub connector_heartbeat_interval_seconds: u32,
+    /// Extra seconds allowed for the next poll before the extension counts as offline.
+    #[serde(default = "default_connector_heartbeat_grace_seconds")]
+    pub connector_heartbeat_grace_seconds: u32,
+    /// Copy the final text of a send-to-extension recording to the clipboard
+    /// when the extension is offline at stop time, instead of dropping it.
+    #[serde(default = "default_true")]
+    pub extension_offline_fallback_to_clipboard: bool,
+    /// Minutes an unacknowledged connector message is kept for redelivery.
+    #[serde(default = "default_connector_message_max_age_minutes")]
+    pub connector_message_max_age_minutes: u32,
+    /// Local HTTP API for triggering actions from other tools (127.0.0.1 only).
+    #[serde(default)]
+    pub action_api_enabled: bool,
+    #[serde(default = "default_action_api_port")]
+    pub action_api_port: u16,
+    /// Bearer token for the action API, generated when the API first starts.
+    #[serde(default)]
+    pub action_api_token: SecretString,
+    /// POST each final transcript to `webhook_url` in the background.
+    #[serde(default)]
+    pub webhook_enabled: bool,
+    #[serde(default)]
+    pub webhook_url: String,
+    /// Key for the HMAC-SHA256 signature header; empty sends unsigned requests.
+    #[serde(default)]
+    pub webhook_secret: SecretString,
+    /// Only send transcripts of this profile ("default" for the default profile).
+    /// `None` sends every transcript.
+    #[serde(default)]
+    pub webhook_profile_id: Option<String>,
     #[serde(default = "default_screenshot_capture_method")]
     pub screenshot_capture_method: ScreenshotCaptureMethod,
     #[serde(default = "default_native_region_capture_mode")]
     pub native_region_capture_mode: NativeRegionCaptureMode,
+    /// Max time to wait for the previous foreground app to regain focus after
+    /// the region picker or preview window closes.
+    #[serde(default = "default_focus_restore_timeout_ms")]
+    pub focus_restore_timeout_ms: u64,
+    /// Extra settle delay after focus is restored, before any follow-up paste.
+    #[serde(default = "default_focus_restore_paste_delay_ms")]
+    pub focus_restore_paste_delay_ms: u64,
     #[serde(default = "default_screenshot_capture_command")]
     pub screenshot_capture_command: String,
     #[serde(default = "default_screenshot_folder")]
@@ -1964,6 +2984,16 @@ pub struct AppSettings {
     /// Whether to show an overlay notification when switching profiles
     #[serde(default = "default_true")]
     pub profile_switch_overlay_enabled: bool,
+    /// Order the cycle shortcut walks profiles in; profiles not listed follow in
+    /// creation order (see `profile_cycle_ids`)
+    #[serde(default)]
+    pub profile_cycle_order: Vec<String>,
+    /// Time-of-day rules that switch the active profile; the first matching rule wins.
+    #[serde(default)]
+    pub profile_schedule: Vec<ProfileScheduleRule>,
+    /// Daily window that pauses recording shortcuts or feedback (see `quiet_hours`).
+    #[serde(default)]
+    pub quiet_hours: QuietHoursSettings,
     // ==================== Voice Command Center ====================
     /// Whether the Voice Command feature is enabled
     #[serde(default)]
@@ -1986,6 +3016,9 @@ pub struct AppSettings {
     /// Default execution options for new voice commands and LLM fallback
     #[serde(default)]
     pub voice_command_defaults: VoiceCommandDefaults,
+    /// Commands run on app and recording lifecycle events
+    #[serde(default)]
+    pub lifecycle_hooks: LifecycleHooks,
     // DEPRECATED: voice_command_template - kept for migration only
     #[serde(default)]
     pub voice_command_template: String,
@@ -2005,6 +3038,18 @@ pub struct AppSettings {
     /// Token budget for post-processing extended thinking (min: 1024, default: 2048)
     #[serde(default = "default_reasoning_budget")]
     pub post_process_reasoning_budget: u32,
+    /// Only use post-processing reasoning for transcripts longer than this many
+    /// words (None = always when reasoning is enabled)
+    #[serde(default)]
+    pub post_process_reasoning_enabled_over_words: Option<u32>,
+    /// Scale the post-processing reasoning budget with transcript length
+    /// (None = use `post_process_reasoning_budget` as is)
+    #[serde(default)]
+    pub post_process_reasoning_budget_per_100_words: Option<u32>,
+    /// Post-process in chunks when the rendered prompt is longer than this many
+    /// characters (None = never chunk)
+    #[serde(default)]
+    pub post_process_chunk_threshold_chars: Option<u32>,
     /// Whether to enable extended thinking for AI Replace LLM calls
     #[serde(default)]
     pub ai_replace_reasoning_enabled: bool,
@@ -2053,6 +3098,13 @@ pub struct AppSettings {
     /// Whether clicking the close "x" once should close the floating voice button window
     #[serde(default)]
     pub voice_button_single_click_close: bool,
+    /// Last dragged position of the floating voice button, keyed by monitor layout
+    #[serde(default)]
+    pub voice_button_positions: HashMap<String, VoiceButtonPosition>,
+    /// Monitor the voice button opens on without a saved position:
+    /// "cursor", "primary" or a zero-based display index
+    #[serde(default = "default_voice_button_monitor")]
+    pub voice_button_monitor: String,
     // ==================== Text Replacement ====================
     /// Whether text replacement feature is enabled globally
     #[serde(default)]
@@ -2097,6 +3149,13 @@ pub struct AppSettings {
     /// - add_if_missing: suffix one trailing space when missing
     #[serde(default)]
     pub output_whitespace_trailing_mode: OutputWhitespaceMode,
+    /// Continuation mode: join a dictation onto the previous paste into the
+    /// same app with a space, capitalizing after a finished sentence.
+    #[serde(default)]
+    pub smart_join_enabled: bool,
+    /// How long the previous paste into an app counts for continuation mode.
+    #[serde(default = "default_smart_join_expiry_secs")]
+    pub smart_join_expiry_secs: u32,
     // ==================== Audio Processing ====================
     /// Whether to filter filler words (uh, um, hmm, etc.) from transcriptions
     #[serde(default)]
@@ -2125,6 +3184,12 @@ pub struct AppSettings {
     /// - "rdev": Supports all keys, but uses more CPU (processes every keystroke)
     #[serde(default)]
     pub shortcut_engine: ShortcutEngine,
+    /// Seconds between background checks that re-register lost shortcuts (0 disables).
+    #[serde(default = "default_shortcut_integrity_check_interval_seconds")]
+    pub shortcut_integrity_check_interval_seconds: u32,
+    /// Max time between the two presses of a `double:` binding (rdev engine).
+    #[serde(default = "default_shortcut_double_tap_window_ms")]
+    pub shortcut_double_tap_window_ms: u32,
     // ==================== Recording Auto-Stop ====================
     /// Whether the recording auto-stop safety timer is enabled
     #[serde(default)]
@@ -2135,6 +3200,16 @@ pub struct AppSettings {
     /// When true, auto-stop pastes normally; when false, cancels/wipes the recording
     #[serde(default = "default_true")]
     pub recording_auto_stop_paste: bool,
+    /// Stop hands-free (toggle) recordings once the VAD hears sustained silence
+    #[serde(default)]
+    pub auto_stop_on_silence_enabled: bool,
+    /// Seconds of silence before the silence auto-stop fires (1..30, default 3)
+    #[serde(default = "default_auto_stop_silence_seconds")]
+    pub auto_stop_silence_seconds: u32,
+    /// Hard cap on a recording's length in seconds; finalizes it like a shortcut
+    /// press when reached (0 = unlimited, otherwise 10..14400)
+    #[serde(default)]
+    pub max_recording_duration_seconds: u32,
     /// Extra trailing capture time for local STT paths after hotkey release (0..1500 ms)
     #[serde(default)]
     pub extra_recording_buffer_ms: u64,
@@ -2173,6 +3248,14 @@ fn default_recording_auto_stop_timeout_seconds() -> u32 {
     1800
 }
 
+fn default_auto_stop_silence_seconds() -> u32 {
+    3
+}
+
+fn default_audio_preprocessing_high_pass_hz() -> u32 {
+    80
+}
+
 fn default_whisper_gpu_device() -> i32 {
     -1
 }
@@ -2213,9 +3296,19 @@ fn default_remote_stt_settings() -> RemoteSttSettings {
         model_id: REMOTE_STT_GROQ_DEFAULT_MODEL.to_string(),
         debug_capture: default_remote_stt_debug_capture(),
         debug_mode: default_remote_stt_debug_mode(),
+        auth_style: ApiAuthStyle::Bearer,
+        extra_query_params: HashMap::new(),
     }
 }
 
+fn default_remote_stt_retry_attempts() -> u32 {
+    3
+}
+
+fn default_remote_stt_retry_backoff_ms() -> u64 {
+    500
+}
+
 fn default_soniox_model() -> String {
     SONIOX_DEFAULT_MODEL.to_string()
 }
@@ -2228,6 +3321,14 @@ fn default_soniox_live_enabled() -> bool {
     true
 }
 
+fn default_local_fallback_repetition_threshold() -> f32 {
+    0.6
+}
+
+fn default_output_casing_exceptions() -> Vec<String> {
+    vec!["I".to_string()]
+}
+
 fn default_soniox_language_hints() -> Vec<String> {
     vec!["en".to_string()]
 }
@@ -2236,6 +3337,10 @@ fn default_soniox_max_endpoint_delay_ms() -> u32 {
     SONIOX_DEFAULT_MAX_ENDPOINT_DELAY_MS
 }
 
+fn default_soniox_live_auto_stop_endpoint_count() -> u32 {
+    1
+}
+
 fn default_soniox_endpoint_sensitivity() -> f32 {
     SONIOX_DEFAULT_ENDPOINT_SENSITIVITY
 }
@@ -2244,6 +3349,10 @@ fn default_soniox_keepalive_interval_seconds() -> u32 {
     10
 }
 
+fn default_soniox_live_reconnect_attempts() -> u32 {
+    3
+}
+
 fn default_soniox_live_finalize_timeout_ms() -> u32 {
     SONIOX_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS
 }
@@ -2252,6 +3361,14 @@ fn default_deepgram_model() -> String {
     DEEPGRAM_DEFAULT_MODEL.to_string()
 }
 
+fn default_openai_realtime_base_url() -> String {
+    OPENAI_REALTIME_DEFAULT_BASE_URL.to_string()
+}
+
+fn default_openai_realtime_model() -> String {
+    OPENAI_REALTIME_DEFAULT_MODEL.to_string()
+}
+
 fn default_deepgram_timeout_seconds() -> u32 {
     3600
 }
@@ -2284,6 +3401,10 @@ fn default_file_transcription_chunking_max_minutes() -> f32 {
     0.5
 }
 
+fn default_file_transcription_segment_minutes() -> f32 {
+    10.0
+}
+
 fn default_microphone_input_boost_db() -> f32 {
     0.0
 }
@@ -2346,6 +3467,14 @@ fn default_selected_language() -> String {
     "auto".to_string()
 }
 
+fn default_language_toggle_primary() -> String {
+    "en".to_string()
+}
+
+fn default_boost_expiry_secs() -> u32 {
+    120
+}
+
 pub(crate) fn default_overlay_position() -> OverlayPosition {
     OverlayPosition::BottomLeft
 }
@@ -2568,6 +3697,10 @@ fn default_log_level() -> LogLevel {
     LogLevel::Error
 }
 
+fn default_model_keep_loaded_minutes() -> u32 {
+    30
+}
+
 fn default_word_correction_threshold() -> f64 {
     0.18
 }
@@ -2576,6 +3709,14 @@ fn default_paste_delay_ms() -> u64 {
     60
 }
 
+fn default_type_characters_delay_ms() -> u64 {
+    5
+}
+
+fn default_privacy_mode_duration_minutes() -> u32 {
+    30
+}
+
 fn default_auto_submit() -> bool {
     false
 }
@@ -2592,10 +3733,42 @@ fn default_history_limit() -> usize {
     5
 }
 
+fn default_repaste_last_count() -> u32 {
+    3
+}
+
+fn default_repaste_last_separator() -> String {
+    "\n".to_string()
+}
+
+fn default_undo_last_paste_timeout_secs() -> u32 {
+    30
+}
+
+fn default_smart_join_expiry_secs() -> u32 {
+    120
+}
+
+fn default_history_session_gap_minutes() -> u32 {
+    5
+}
+
+fn default_history_trim_silence_padding_ms() -> u32 {
+    200
+}
+
 fn default_recording_retention_period() -> RecordingRetentionPeriod {
     RecordingRetentionPeriod::PreserveLimit
 }
 
+fn default_shortcut_integrity_check_interval_seconds() -> u32 {
+    60
+}
+
+fn default_shortcut_double_tap_window_ms() -> u32 {
+    crate::managers::key_listener::DEFAULT_DOUBLE_TAP_WINDOW_MS
+}
+
 fn default_audio_feedback_volume() -> f32 {
     1.0
 }
@@ -2646,6 +3819,10 @@ fn default_connector_port() -> u16 {
     38243
 }
 
+fn default_action_api_port() -> u16 {
+    38244
+}
+
 fn default_connector_enabled() -> bool {
     false
 }
@@ -2670,6 +3847,38 @@ fn default_connector_auto_open_url() -> String {
     "".to_string()
 }
 
+fn default_transcribe_and_search_url_template() -> String {
+    crate::url_security::DEFAULT_SEARCH_URL_TEMPLATE.to_string()
+}
+
+fn default_connector_health_check_interval_seconds() -> u32 {
+    10
+}
+
+fn default_llm_clipboard_variable_max_chars() -> u32 {
+    8000
+}
+
+/// Allowed character budgets for custom words in STT prompts; the settings
+/// slider uses the same bounds.
+pub const STT_PROMPT_CUSTOM_WORDS_MAX_CHARS_RANGE: std::ops::RangeInclusive<u32> = 100..=2000;
+
+fn default_stt_prompt_custom_words_max_chars() -> u32 {
+    600
+}
+
+fn default_connector_heartbeat_interval_seconds() -> u32 {
+    3
+}
+
+fn default_connector_heartbeat_grace_seconds() -> u32 {
+    2
+}
+
+fn default_connector_message_max_age_minutes() -> u32 {
+    10
+}
+
 fn default_screenshot_capture_method() -> ScreenshotCaptureMethod {
     ScreenshotCaptureMethod::Native
 }
@@ -2678,6 +3887,14 @@ fn default_native_region_capture_mode() -> NativeRegionCaptureMode {
     NativeRegionCaptureMode::LiveDesktop
 }
 
+fn default_focus_restore_timeout_ms() -> u64 {
+    300
+}
+
+fn default_focus_restore_paste_delay_ms() -> u64 {
+    30
+}
+
 fn default_screenshot_capture_command() -> String {
     r#"& "C:\Program Files\ShareX\ShareX.exe" -RectangleRegion"#.to_string()
 }
@@ -2730,6 +3947,10 @@ fn default_voice_command_levenshtein_threshold() -> f64 {
     0.3 // 30% of word length can be edits (typos)
 }
 
+fn default_voice_button_monitor() -> String {
+    "cursor".to_string()
+}
+
 fn default_voice_command_phonetic_boost() -> f64 {
     0.5 // Phonetic matches get 50% boost
 }
@@ -2810,6 +4031,10 @@ fn default_ai_replace_allow_no_selection() -> bool {
     true
 }
 
+fn default_ai_replace_confirm_timeout_seconds() -> u32 {
+    60
+}
+
 fn default_true() -> bool {
     true
 }
@@ -2853,6 +4078,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
         PostProcessProvider {
             id: "zai".to_string(),
@@ -2861,6 +4088,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
         PostProcessProvider {
             id: "openrouter".to_string(),
@@ -2869,6 +4098,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
         PostProcessProvider {
             id: "anthropic".to_string(),
@@ -2877,6 +4108,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
         PostProcessProvider {
             id: "groq".to_string(),
@@ -2885,6 +4118,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
         PostProcessProvider {
             id: "cerebras".to_string(),
@@ -2893,6 +4128,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: Some("/models".to_string()),
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         },
     ];
 
@@ -2909,6 +4146,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             allow_base_url_edit: false,
             allow_insecure_http: false,
             models_endpoint: None,
+            auth_style: ApiAuthStyle::Bearer,
+            extra_query_params: HashMap::new(),
         });
     }
 
@@ -2920,6 +4159,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
         allow_base_url_edit: false,
         allow_insecure_http: false,
         models_endpoint: Some("/models".to_string()),
+        auth_style: ApiAuthStyle::Bearer,
+        extra_query_params: HashMap::new(),
     });
 
     // Custom provider always comes last
@@ -2930,6 +4171,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
         allow_base_url_edit: true,
         allow_insecure_http: false,
         models_endpoint: Some("/models".to_string()),
+        auth_style: ApiAuthStyle::Bearer,
+        extra_query_params: HashMap::new(),
     });
 
     providers
@@ -2966,6 +4209,12 @@ fn default_post_process_prompts() -> Vec<LLMPrompt> {
         id: "default_improve_transcriptions".to_string(),
         name: "Improve Transcriptions".to_string(),
         prompt: "Clean this transcript:\n1. Fix spelling, capitalization, and punctuation errors\n2. Convert number words to digits (twenty-five → 25, ten percent → 10%, five dollars → $5)\n3. Replace spoken punctuation with symbols (period → ., comma → ,, question mark → ?)\n4. Remove filler words (um, uh, like as filler)\n5. Keep the language in the original version (if it was french, keep it in french for example)\n\nPreserve exact meaning and word order. Do not paraphrase or reorder content.\n\nReturn only the cleaned transcript.\n\nTranscript:\n${output}".to_string(),
+        max_output_words: None,
+        max_output_sentences: None,
+        output_json_path: None,
+        model_override: None,
+        reasoning_enabled_override: None,
+        reasoning_budget_override: None,
     }]
 }
 
@@ -3011,6 +4260,13 @@ fn ensure_post_process_defaults(settings: &mut AppSettings) -> bool {
                     existing.allow_insecure_http = false;
                     changed = true;
                 }
+                if existing.auth_style != ApiAuthStyle::Bearer
+                    || !existing.extra_query_params.is_empty()
+                {
+                    existing.auth_style = ApiAuthStyle::Bearer;
+                    existing.extra_query_params.clear();
+                    changed = true;
+                }
             }
         }
 
@@ -3194,6 +4450,10 @@ pub fn get_default_settings() -> AppSettings {
             description: "Converts your speech into text.".to_string(),
             default_binding: default_shortcut.to_string(),
             current_binding: default_shortcut.to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     bindings.insert(
@@ -3204,6 +4464,10 @@ pub fn get_default_settings() -> AppSettings {
             description: "Send transcription to AivoRelay Connector.".to_string(),
             default_binding: default_send_shortcut.to_string(),
             current_binding: default_send_shortcut.to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     bindings.insert(
@@ -3215,6 +4479,10 @@ pub fn get_default_settings() -> AppSettings {
                 .to_string(),
             default_binding: default_send_selection_shortcut.to_string(),
             current_binding: default_send_selection_shortcut.to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     #[cfg(target_os = "windows")]
@@ -3228,6 +4496,10 @@ pub fn get_default_settings() -> AppSettings {
                     .to_string(),
             default_binding: "ctrl+shift+space".to_string(),
             current_binding: "ctrl+shift+space".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     #[cfg(target_os = "windows")]
@@ -3241,6 +4513,10 @@ pub fn get_default_settings() -> AppSettings {
                     .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     bindings.insert(
@@ -3251,6 +4527,10 @@ pub fn get_default_settings() -> AppSettings {
             description: "Cancels the current recording.".to_string(),
             default_binding: "escape".to_string(),
             current_binding: "escape".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
     bindings.insert(
@@ -3261,58 +4541,180 @@ pub fn get_default_settings() -> AppSettings {
             description: "Repastes latest text. Or, in case of network error, resends the recording and tries again.".to_string(),
             default_binding: "ctrl+shift+z".to_string(),
             current_binding: "ctrl+shift+z".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
-    #[cfg(target_os = "windows")]
     bindings.insert(
-        "voice_command".to_string(),
+        "repaste_last_n".to_string(),
+        ShortcutBinding {
+            id: "repaste_last_n".to_string(),
+            name: "Repaste Last N".to_string(),
+            description: "Pastes the last few transcriptions joined together, oldest first."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    bindings.insert(
+        "undo_last_paste".to_string(),
+        ShortcutBinding {
+            id: "undo_last_paste".to_string(),
+            name: "Undo Last Paste".to_string(),
+            description: "Erases the text AivoRelay just inserted with Backspace.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    #[cfg(target_os = "windows")]
+    bindings.insert(
+        "voice_command".to_string(),
+        ShortcutBinding {
+            id: "voice_command".to_string(),
+            name: "Voice Command".to_string(),
+            description: "Speak to run predefined scripts or get AI-suggested PowerShell commands."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    #[cfg(target_os = "windows")]
+    bindings.insert(
+        "spawn_button".to_string(),
+        ShortcutBinding {
+            id: "spawn_button".to_string(),
+            name: "Spawn Voice Activation Button".to_string(),
+            description: "Open a floating on-screen voice activation button window.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    // Default profile shortcut (optional - uses global settings when active)
+    bindings.insert(
+        "transcribe_default".to_string(),
+        ShortcutBinding {
+            id: "transcribe_default".to_string(),
+            name: "Transcribe (Default Profile)".to_string(),
+            description: "Transcribe using global language settings, regardless of active profile."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    // Cycle through transcription profiles
+    bindings.insert(
+        "cycle_profile".to_string(),
+        ShortcutBinding {
+            id: "cycle_profile".to_string(),
+            name: "Cycle Transcription Profile".to_string(),
+            description: "Switch to the next transcription profile in the rotation.".to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    bindings.insert(
+        "pause_resume".to_string(),
+        ShortcutBinding {
+            id: "pause_resume".to_string(),
+            name: "Pause/Resume Recording".to_string(),
+            description: "Pause the active recording without ending it; press again to resume."
+                .to_string(),
+            default_binding: "".to_string(),
+            current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
+        },
+    );
+    bindings.insert(
+        PREVIEW_DELETE_LAST_WORD_BINDING_ID.to_string(),
+        build_preview_delete_last_word_binding(String::new()),
+    );
+    bindings.insert(
+        TRANSCRIBE_AND_SEARCH_BINDING_ID.to_string(),
         ShortcutBinding {
-            id: "voice_command".to_string(),
-            name: "Voice Command".to_string(),
-            description: "Speak to run predefined scripts or get AI-suggested PowerShell commands."
-                .to_string(),
+            id: TRANSCRIBE_AND_SEARCH_BINDING_ID.to_string(),
+            name: "Transcribe and Search".to_string(),
+            description: "Converts your speech into a web search in your browser.".to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
-    #[cfg(target_os = "windows")]
     bindings.insert(
-        "spawn_button".to_string(),
+        crate::language_toggle::LANGUAGE_TOGGLE_BINDING_ID.to_string(),
         ShortcutBinding {
-            id: "spawn_button".to_string(),
-            name: "Spawn Voice Activation Button".to_string(),
-            description: "Open a floating on-screen voice activation button window.".to_string(),
+            id: crate::language_toggle::LANGUAGE_TOGGLE_BINDING_ID.to_string(),
+            name: "Toggle Dictation Language".to_string(),
+            description: "Switch dictation between the two configured languages.".to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
-    // Default profile shortcut (optional - uses global settings when active)
     bindings.insert(
-        "transcribe_default".to_string(),
+        crate::boost::BOOST_BINDING_ID.to_string(),
         ShortcutBinding {
-            id: "transcribe_default".to_string(),
-            name: "Transcribe (Default Profile)".to_string(),
-            description: "Transcribe using global language settings, regardless of active profile."
+            id: crate::boost::BOOST_BINDING_ID.to_string(),
+            name: "Boost Next Transcription".to_string(),
+            description: "Use the boost provider and model for the next dictation only."
                 .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
-    // Cycle through transcription profiles
     bindings.insert(
-        "cycle_profile".to_string(),
+        crate::privacy_mode::PRIVACY_MODE_BINDING_ID.to_string(),
         ShortcutBinding {
-            id: "cycle_profile".to_string(),
-            name: "Cycle Transcription Profile".to_string(),
-            description: "Switch to the next transcription profile in the rotation.".to_string(),
+            id: crate::privacy_mode::PRIVACY_MODE_BINDING_ID.to_string(),
+            name: "Toggle Privacy Mode".to_string(),
+            description: "Stop saving history and context until privacy mode is turned off."
+                .to_string(),
             default_binding: "".to_string(),
             current_binding: "".to_string(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         },
     );
-    bindings.insert(
-        PREVIEW_DELETE_LAST_WORD_BINDING_ID.to_string(),
-        build_preview_delete_last_word_binding(String::new()),
-    );
 
     AppSettings {
         bindings,
@@ -3320,6 +4722,8 @@ pub fn get_default_settings() -> AppSettings {
         preview_output_only_enabled: false,
         audio_feedback: false,
         result_ready_audio_feedback: false,
+        visual_feedback_enabled: false,
+        visual_feedback_tray_blink: false,
         audio_feedback_volume: default_audio_feedback_volume(),
         sound_theme: default_sound_theme(),
         start_hidden: default_start_hidden(),
@@ -3329,10 +4733,19 @@ pub fn get_default_settings() -> AppSettings {
         show_tray_shortcut_guide_in_main_menu: false,
         update_checks_enabled: default_update_checks_enabled(),
         selected_model: "".to_string(),
+        auto_fallback_on_missing_model: default_true(),
+        local_fallback_enabled: false,
+        local_fallback_model: None,
+        local_fallback_repetition_threshold: default_local_fallback_repetition_threshold(),
         transcription_provider: default_transcription_provider(),
+        transcription_race_mode: false,
         remote_stt: default_remote_stt_settings(),
+        remote_stt_retry_attempts: default_remote_stt_retry_attempts(),
+        remote_stt_retry_backoff_ms: default_remote_stt_retry_backoff_ms(),
         openai_realtime_whisper_delay: OpenAiRealtimeWhisperDelay::default(),
         openai_realtime_whisper_flatten_enabled: false,
+        openai_realtime_base_url: default_openai_realtime_base_url(),
+        openai_realtime_model: default_openai_realtime_model(),
         soniox_model: default_soniox_model(),
         soniox_timeout_seconds: default_soniox_timeout_seconds(),
         soniox_live_enabled: default_soniox_live_enabled(),
@@ -3340,14 +4753,19 @@ pub fn get_default_settings() -> AppSettings {
         soniox_context_general_json: String::new(),
         soniox_context_text: String::new(),
         soniox_context_terms: Vec::new(),
+        soniox_sync_context_from_custom_words: default_false(),
         soniox_use_profile_language_hint_only: default_false(),
         soniox_language_hints_strict: default_false(),
         soniox_enable_endpoint_detection: default_true(),
         soniox_max_endpoint_delay_ms: default_soniox_max_endpoint_delay_ms(),
+        soniox_live_auto_stop_on_endpoint: false,
+        soniox_live_auto_stop_endpoint_count: default_soniox_live_auto_stop_endpoint_count(),
         soniox_endpoint_sensitivity: default_soniox_endpoint_sensitivity(),
         soniox_enable_language_identification: default_true(),
         soniox_enable_speaker_diarization: default_true(),
+        soniox_include_speaker_labels: default_false(),
         soniox_keepalive_interval_seconds: default_soniox_keepalive_interval_seconds(),
+        soniox_live_reconnect_attempts: default_soniox_live_reconnect_attempts(),
         soniox_live_finalize_timeout_ms: default_soniox_live_finalize_timeout_ms(),
         soniox_live_instant_stop: default_false(),
         soniox_optimize_delivery_preconnect_enabled: default_false(),
@@ -3366,6 +4784,7 @@ pub fn get_default_settings() -> AppSettings {
         deepgram_endpointing_enabled: default_true(),
         deepgram_endpointing_ms: default_deepgram_endpointing_ms(),
         always_on_microphone: false,
+        microphone_mode_overrides: HashMap::new(),
         selected_microphone: None,
         last_manual_microphone: None,
         selected_microphone_auto_switch_enabled: default_false(),
@@ -3382,6 +4801,10 @@ pub fn get_default_settings() -> AppSettings {
         live_sound_deepgram_endpointing_ms: None,
         translate_to_english: false,
         selected_language: "auto".to_string(),
+        language_toggle_primary: default_language_toggle_primary(),
+        language_toggle_secondary: String::new(),
+        boost_override: BoostOverride::default(),
+        boost_expiry_secs: default_boost_expiry_secs(),
         overlay_position: default_overlay_position(),
         recording_overlay_enabled: default_recording_overlay_enabled(),
         auto_position_allow_reserved_areas: false,
@@ -3392,6 +4815,8 @@ pub fn get_default_settings() -> AppSettings {
         recording_overlay_custom_y_px: default_recording_overlay_custom_y_px(),
         error_overlay_auto_hide_ms: default_error_overlay_auto_hide_ms(),
         error_feedback_enabled: default_true(),
+        screen_reader_announcements_enabled: false,
+        screen_reader_verbosity: ScreenReaderVerbosity::default(),
         recording_overlay_custom_enabled: false,
         recording_overlay_show_drag_grip: true,
         recording_overlay_theme: default_recording_overlay_theme(),
@@ -3401,6 +4826,7 @@ pub fn get_default_settings() -> AppSettings {
         recording_overlay_animated_border_mode: default_recording_overlay_animated_border_mode(),
         recording_overlay_show_status_icon: default_true(),
         recording_overlay_show_cancel_button: default_true(),
+        overlay_show_timer: default_true(),
         recording_overlay_bar_count: default_recording_overlay_bar_count(),
         recording_overlay_width_px: default_recording_overlay_width_px(),
         recording_overlay_bar_width_px: default_recording_overlay_bar_width_px(),
@@ -3471,6 +4897,7 @@ pub fn get_default_settings() -> AppSettings {
         local_preview_auto_flush_enabled: default_local_preview_auto_flush_enabled(),
         local_preview_auto_flush_interval_ms: default_local_preview_auto_flush_interval_ms(),
         local_preview_auto_flush_overlap_ms: default_local_preview_auto_flush_overlap_ms(),
+        local_streaming_preview_enabled: false,
         soniox_live_preview_sliding_lm_window_enabled: false,
         soniox_live_preview_sliding_lm_window_prompt:
             default_soniox_live_preview_sliding_lm_window_prompt(),
@@ -3480,11 +4907,25 @@ pub fn get_default_settings() -> AppSettings {
         log_level: default_log_level(),
         custom_words: Vec::new(),
         custom_words_enabled: default_custom_words_enabled(),
+        stt_prompt_auto_inject_custom_words: false,
+        stt_prompt_custom_words_max_chars: default_stt_prompt_custom_words_max_chars(),
         custom_words_ngram_enabled: default_custom_words_ngram_enabled(),
         model_unload_timeout: ModelUnloadTimeout::default(),
+        model_keep_loaded_minutes: default_model_keep_loaded_minutes(),
+        model_preload_on_startup: false,
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
         recording_retention_period: default_recording_retention_period(),
+        history_session_gap_minutes: default_history_session_gap_minutes(),
+        accuracy_evaluation_enabled: false,
+        history_timestamps_enabled: false,
+        history_trim_silence_enabled: false,
+        history_trim_silence_padding_ms: default_history_trim_silence_padding_ms(),
+        low_confidence_warning_threshold: 0.0,
+        repaste_last_count: default_repaste_last_count(),
+        repaste_last_separator: default_repaste_last_separator(),
+        repaste_last_include_ai_replace: false,
+        undo_last_paste_timeout_secs: default_undo_last_paste_timeout_secs(),
         dictation_stats_enabled: false,
         dictation_word_count: 0,
         dictation_word_count_since_ms: None,
@@ -3492,17 +4933,29 @@ pub fn get_default_settings() -> AppSettings {
         dictation_character_count_since_ms: None,
         paste_method: PasteMethod::default(),
         paste_delay_ms: default_paste_delay_ms(),
+        app_paste_overrides: Vec::new(),
+        type_characters_delay_ms: default_type_characters_delay_ms(),
         convert_lf_to_crlf: true,
+        stream_conservative_delete: false,
         clipboard_handling: ClipboardHandling::default(),
         auto_submit: default_auto_submit(),
         auto_submit_key: AutoSubmitKey::default(),
+        stuck_modifier_recovery_enabled: default_true(),
         post_process_enabled: default_post_process_enabled(),
         post_process_provider_id: default_post_process_provider_id(),
         post_process_providers: default_post_process_providers(),
         post_process_api_keys: default_post_process_api_keys(),
         post_process_models: default_post_process_models(),
+        post_process_fallback_provider_id: None,
+        post_process_fallback_model: None,
         post_process_prompts: default_post_process_prompts(),
         post_process_selected_prompt_id: None,
+        post_process_output_limit_strategy: OutputLimitStrategy::default(),
+        llm_clipboard_variable_max_chars: default_llm_clipboard_variable_max_chars(),
+        punctuation_mode: PunctuationMode::default(),
+        output_casing: None,
+        output_casing_exceptions: default_output_casing_exceptions(),
+        output_translation_language: None,
         post_process_benchmark_collapsed: default_post_process_benchmark_collapsed(),
         post_process_benchmark_system_prompt: default_post_process_benchmark_system_prompt(),
         post_process_benchmark_user_message: default_post_process_benchmark_user_message(),
@@ -3512,11 +4965,16 @@ pub fn get_default_settings() -> AppSettings {
         llm_context_prev_transcript_max_words: default_llm_context_prev_transcript_max_words(),
         llm_context_prev_transcript_expiry_seconds:
             default_llm_context_prev_transcript_expiry_seconds(),
+        llm_context_prev_transcript_profile_fallback: true,
+        transcript_context_blocklist: Vec::new(),
+        privacy_mode_duration_minutes: default_privacy_mode_duration_minutes(),
         ai_replace_system_prompt: default_ai_replace_system_prompt(),
         ai_replace_user_prompt: default_ai_replace_user_prompt(),
         ai_replace_max_chars: default_ai_replace_max_chars(),
         ai_replace_restore_on_error: default_ai_replace_restore_on_error(),
         ai_replace_allow_no_selection: default_ai_replace_allow_no_selection(),
+        ai_replace_confirm_before_paste: false,
+        ai_replace_confirm_timeout_seconds: default_ai_replace_confirm_timeout_seconds(),
         ai_replace_no_selection_system_prompt: default_ai_replace_no_selection_system_prompt(),
         ai_replace_no_selection_user_prompt: default_ai_replace_no_selection_user_prompt(),
         ai_replace_allow_quick_tap: default_ai_replace_allow_quick_tap(),
@@ -3533,19 +4991,27 @@ pub fn get_default_settings() -> AppSettings {
         send_to_extension_with_selection_allow_no_voice: true,
         send_to_extension_with_selection_quick_tap_threshold_ms: default_quick_tap_threshold_ms(),
         send_to_extension_with_selection_no_voice_system_prompt: String::new(),
+        transcribe_and_search_url_template: default_transcribe_and_search_url_template(),
+        transcribe_and_search_post_process_enabled: false,
         send_to_extension_enabled: false,
         send_to_extension_push_to_talk: true,
         send_to_extension_with_selection_enabled: false,
         send_to_extension_with_selection_push_to_talk: true,
         ai_replace_selection_push_to_talk: true,
         mute_while_recording: false,
+        mute_while_recording_exempt_bindings: Vec::new(),
         pause_media_while_recording: false,
         filter_silence: default_filter_silence(),
         file_transcription_chunking_mode: default_file_transcription_chunking_mode(),
         file_transcription_chunking_max_minutes: default_file_transcription_chunking_max_minutes(),
+        file_transcription_segment_minutes: default_file_transcription_segment_minutes(),
         microphone_input_boost_db_by_device: default_microphone_input_boost_db_by_device(),
         microphone_input_boost_db: default_microphone_input_boost_db(),
         microphone_noise_cancellation_enabled: false,
+        auto_recover_input_device: false,
+        recording_level_meter_enabled: true,
+        audio_preprocessing_enabled: false,
+        audio_preprocessing_high_pass_hz: default_audio_preprocessing_high_pass_hz(),
         connector_port: default_connector_port(),
         connector_enabled: default_connector_enabled(),
         connector_encryption_enabled: default_connector_encryption_enabled(),
@@ -3553,8 +5019,22 @@ pub fn get_default_settings() -> AppSettings {
         connector_cors: default_connector_cors(),
         connector_auto_open_enabled: default_connector_auto_open_enabled(),
         connector_auto_open_url: default_connector_auto_open_url(),
+        connector_health_check_interval_seconds: default_connector_health_check_interval_seconds(),
+        connector_heartbeat_interval_seconds: default_connector_heartbeat_interval_seconds(),
+        connector_heartbeat_grace_seconds: default_connector_heartbeat_grace_seconds(),
+        extension_offline_fallback_to_clipboard: true,
+        connector_message_max_age_minutes: default_connector_message_max_age_minutes(),
+        action_api_enabled: false,
+        action_api_port: default_action_api_port(),
+        action_api_token: SecretString::default(),
+        webhook_enabled: false,
+        webhook_url: String::new(),
+        webhook_secret: SecretString::default(),
+        webhook_profile_id: None,
         screenshot_capture_method: default_screenshot_capture_method(),
         native_region_capture_mode: default_native_region_capture_mode(),
+        focus_restore_timeout_ms: default_focus_restore_timeout_ms(),
+        focus_restore_paste_delay_ms: default_focus_restore_paste_delay_ms(),
         screenshot_capture_command: default_screenshot_capture_command(),
         screenshot_folder: default_screenshot_folder(),
         screenshot_require_recent: true,
@@ -3578,6 +5058,9 @@ pub fn get_default_settings() -> AppSettings {
         diarization_speaker_name_profiles: Vec::new(),
         active_profile_id: default_active_profile_id(),
         profile_switch_overlay_enabled: true,
+        profile_cycle_order: Vec::new(),
+        profile_schedule: Vec::new(),
+        quiet_hours: QuietHoursSettings::default(),
         // Voice Command Center
         voice_command_enabled: false,
         voice_command_push_to_talk: true,
@@ -3586,6 +5069,7 @@ pub fn get_default_settings() -> AppSettings {
         voice_command_llm_fallback: true,
         voice_command_system_prompt: default_voice_command_system_prompt(),
         voice_command_defaults: VoiceCommandDefaults::default(),
+        lifecycle_hooks: LifecycleHooks::default(),
         voice_command_template: String::new(), // Deprecated, kept for migration
         voice_command_keep_window_open: false, // Deprecated, kept for migration
         voice_command_auto_run: false,
@@ -3593,6 +5077,9 @@ pub fn get_default_settings() -> AppSettings {
         // Extended Thinking / Reasoning
         post_process_reasoning_enabled: false,
         post_process_reasoning_budget: default_reasoning_budget(),
+        post_process_reasoning_enabled_over_words: None,
+        post_process_reasoning_budget_per_100_words: None,
+        post_process_chunk_threshold_chars: None,
         ai_replace_reasoning_enabled: false,
         ai_replace_reasoning_budget: default_reasoning_budget(),
         // Voice Command LLM Settings
@@ -3611,6 +5098,8 @@ pub fn get_default_settings() -> AppSettings {
         beta_voice_commands_enabled: false,
         voice_button_show_aot_toggle: false,
         voice_button_single_click_close: false,
+        voice_button_positions: HashMap::new(),
+        voice_button_monitor: default_voice_button_monitor(),
         // Text Replacement
         text_replacements_enabled: false,
         text_replacements: Vec::new(),
@@ -3627,6 +5116,8 @@ pub fn get_default_settings() -> AppSettings {
             default_text_replacement_decapitalize_standard_post_recording_monitor_ms(),
         output_whitespace_leading_mode: OutputWhitespaceMode::default(),
         output_whitespace_trailing_mode: OutputWhitespaceMode::default(),
+        smart_join_enabled: false,
+        smart_join_expiry_secs: default_smart_join_expiry_secs(),
         // Audio Processing
         filler_word_filter_enabled: false,
         custom_filler_words: None,
@@ -3637,6 +5128,9 @@ pub fn get_default_settings() -> AppSettings {
         vad_threshold: default_vad_threshold(),
         // Shortcut Engine (Windows only)
         shortcut_engine: ShortcutEngine::default(),
+        shortcut_integrity_check_interval_seconds:
+            default_shortcut_integrity_check_interval_seconds(),
+        shortcut_double_tap_window_ms: default_shortcut_double_tap_window_ms(),
         // UI State
         sidebar_pinned: false,
         sidebar_width: default_sidebar_width(),
@@ -3644,6 +5138,9 @@ pub fn get_default_settings() -> AppSettings {
         recording_auto_stop_enabled: false,
         recording_auto_stop_timeout_seconds: 1800,
         recording_auto_stop_paste: false,
+        auto_stop_on_silence_enabled: false,
+        auto_stop_silence_seconds: default_auto_stop_silence_seconds(),
+        max_recording_duration_seconds: 0,
         extra_recording_buffer_ms: 0,
         lazy_stream_close: false,
         // Window Geometry
@@ -4225,6 +5722,35 @@ fn migrate_legacy_recording_overlay_coordinates(
 }
 
 impl AppSettings {
+    /// Resolves the microphone stream policy for a binding, honoring per-family overrides.
+    pub fn microphone_mode_for_binding(&self, binding_id: &str) -> MicMode {
+        if let Some(mode) = self
+            .microphone_mode_overrides
+            .get(microphone_binding_family(binding_id))
+        {
+            return *mode;
+        }
+
+        if self.always_on_microphone {
+            MicMode::AlwaysOn
+        } else {
+            MicMode::OnDemand
+        }
+    }
+
+    /// Whether system audio should be muted while this binding records.
+    pub fn mute_while_recording_for_binding(&self, binding_id: &str) -> bool {
+        if !self.mute_while_recording {
+            return false;
+        }
+
+        let family = microphone_binding_family(binding_id);
+        !self
+            .mute_while_recording_exempt_bindings
+            .iter()
+            .any(|exempt| exempt == family)
+    }
+
     pub fn microphone_input_boost_db_for_device(&self, device_name: Option<&str>) -> f32 {
         let key = microphone_input_boost_device_key(device_name);
 
@@ -4234,12 +5760,67 @@ impl AppSettings {
             .unwrap_or(self.microphone_input_boost_db)
     }
 
+    /// Output casing for the given profile, or for the default profile when `None`.
+    pub fn output_casing_for(&self, profile: Option<&TranscriptionProfile>) -> Option<CasingMode> {
+        match profile {
+            Some(profile) => profile.output_casing,
+            None => self.output_casing,
+        }
+    }
+
+    pub fn output_translation_language_for(
+        &self,
+        profile: Option<&TranscriptionProfile>,
+    ) -> Option<&str> {
+        match profile {
+            Some(profile) => profile.output_translation_language.as_deref(),
+            None => self.output_translation_language.as_deref(),
+        }
+    }
+
     pub fn active_post_process_provider(&self) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
             .find(|provider| provider.id == self.post_process_provider_id)
     }
 
+    /// Post-processing API key for a provider.
+    pub fn post_process_api_key(&self, provider_id: &str) -> String {
+        #[cfg(target_os = "windows")]
+        {
+            crate::secure_keys::get_post_process_api_key(provider_id)
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            self.post_process_api_keys
+                .get(provider_id)
+                .cloned()
+                .unwrap_or_default()
+        }
+    }
+
+    /// Fallback provider and model for post-processing, if one is configured
+    /// and differs from the active provider. Apple Intelligence is excluded
+    /// because it runs on-device and never fails with a network error.
+    pub fn post_process_fallback(&self) -> Option<(&PostProcessProvider, String)> {
+        let provider_id = self.post_process_fallback_provider_id.as_deref()?;
+        if provider_id == self.post_process_provider_id
+            || provider_id == APPLE_INTELLIGENCE_PROVIDER_ID
+        {
+            return None;
+        }
+        let provider = self.post_process_provider(provider_id)?;
+        let model = self
+            .post_process_fallback_model
+            .as_ref()
+            .filter(|model| !model.trim().is_empty())
+            .or_else(|| self.post_process_models.get(provider_id))
+            .filter(|model| !model.trim().is_empty())?
+            .clone();
+        Some((provider, model))
+    }
+
     /// Get the active LLM provider for Voice Commands.
     /// If voice_command_provider_id is set, uses that; otherwise falls back to post-processing provider.
     pub fn active_voice_command_provider(&self) -> Option<&PostProcessProvider> {
@@ -4253,6 +5834,30 @@ impl AppSettings {
         }
     }
 
+    /// Language for engines that accept a single language (local Whisper,
+    /// OpenAI-compatible Remote STT). A profile with additional languages uses
+    /// "auto" instead of only its primary language, unless the language toggle
+    /// picked one.
+    pub fn single_engine_language(&self, profile: Option<&TranscriptionProfile>) -> String {
+        if let Some(profile) = profile {
+            let toggled = crate::language_toggle::profile_language_override(&profile.id);
+            if toggled.is_none() && profile.has_additional_languages() {
+                return "auto".to_string();
+            }
+        }
+        self.requested_language(profile)
+    }
+
+    /// Language requested for dictation with `profile` (None = global), including
+    /// a language chosen with the language toggle.
+    pub fn requested_language(&self, profile: Option<&TranscriptionProfile>) -> String {
+        match profile {
+            Some(profile) => crate::language_toggle::profile_language_override(&profile.id)
+                .unwrap_or_else(|| profile.language.clone()),
+            None => self.selected_language.clone(),
+        }
+    }
+
     /// Get a transcription profile by its ID.
     pub fn transcription_profile(&self, profile_id: &str) -> Option<&TranscriptionProfile> {
         self.transcription_profiles
@@ -4273,6 +5878,79 @@ impl AppSettings {
         }
     }
 
+    /// Profile IDs the cycle shortcut rotates through, in order: the IDs in
+    /// `profile_cycle_order`, then the remaining profiles in creation order.
+    /// "default" leads unless the order places it; profiles with
+    /// `include_in_cycle` off and IDs that no longer exist are skipped.
+    pub fn profile_cycle_ids(&self) -> Vec<String> {
+        let mut ids = Vec::new();
+        if !self.profile_cycle_order.iter().any(|id| id == "default") {
+            ids.push("default".to_string());
+        }
+        let creation_order = self.transcription_profiles.iter().map(|p| &p.id);
+        for id in self.profile_cycle_order.iter().chain(creation_order) {
+            let in_cycle = id == "default"
+                || self
+                    .transcription_profile(id)
+                    .is_some_and(|profile| profile.include_in_cycle);
+            if in_cycle && !ids.contains(id) {
+                ids.push(id.clone());
+            }
+        }
+        ids
+    }
+
+    /// Paste method and delay for text going to `app_name`: the first matching
+    /// `app_paste_overrides` entry, otherwise the global settings.
+    pub fn paste_method_for_app(&self, app_name: &str) -> (PasteMethod, u64) {
+        match self
+            .app_paste_overrides
+            .iter()
+            .find(|entry| entry.matches(app_name))
+        {
+            Some(entry) => (
+                entry.method,
+                entry.paste_delay_ms.unwrap_or(self.paste_delay_ms),
+            ),
+            None => (self.paste_method, self.paste_delay_ms),
+        }
+    }
+
+    /// Custom word list for `${custom_words}` in STT prompts; empty while
+    /// custom words are disabled.
+    pub fn stt_prompt_custom_words(&self, profile: Option<&TranscriptionProfile>) -> String {
+        if !self.custom_words_enabled {
+            return String::new();
+        }
+        custom_words_prompt_list(
+            &custom_word_vocabulary(&self.custom_words_for_profile(profile)),
+            self.stt_prompt_custom_words_max_chars as usize,
+        )
+    }
+
+    /// Custom words for a recording: the profile's own words first (as fuzzy
+    /// entries), then the global list, without duplicates.
+    pub fn custom_words_for_profile(
+        &self,
+        profile: Option<&TranscriptionProfile>,
+    ) -> Vec<CustomWord> {
+        match profile.and_then(|p| p.custom_words_override.as_ref()) {
+            Some(profile_words) => normalize_custom_word_entries(
+                profile_words
+                    .iter()
+                    .map(|word| CustomWord::fuzzy(word.as_str()))
+                    .chain(self.custom_words.iter().cloned()),
+            ),
+            None => self.custom_words.clone(),
+        }
+    }
+
+    pub fn push_to_talk_override(&self, binding_id: &str) -> Option<bool> {
+        self.bindings
+            .get(binding_id)
+            .and_then(|binding| binding.push_to_talk_override)
+    }
+
     pub fn post_process_provider(&self, provider_id: &str) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -4520,10 +6198,15 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
         default_settings
     };
 
+    cache_settings(settings.clone());
     settings
 }
 
 pub fn get_settings(app: &AppHandle) -> AppSettings {
+    if let Some(settings) = cached_settings() {
+        return settings;
+    }
+
     let settings_store_was_corrupted = settings_store_file_is_corrupted(app);
 
     let store = app
@@ -4554,19 +6237,55 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
         default_settings
     };
 
+    cache_settings(settings.clone());
     settings
 }
 
-pub fn write_settings(app: &AppHandle, mut settings: AppSettings) {
-    let store = app
-        .store(crate::portable::store_path(SETTINGS_STORE_PATH))
-        .expect("Failed to initialize store");
+fn cached_settings() -> Option<AppSettings> {
+    SETTINGS_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+fn cache_settings(settings: AppSettings) {
+    *SETTINGS_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(settings);
+}
 
+/// Updates the in-memory settings at once and schedules a debounced write,
+/// so bursts of changes (e.g. slider drags) produce a single disk write.
+pub fn write_settings(app: &AppHandle, mut settings: AppSettings) {
     if repair_runtime_settings(&mut settings) {
         debug!("Settings repaired before persisting");
     }
 
-    store.set("settings", serde_json::to_value(&settings).unwrap());
+    cache_settings(settings.clone());
+    settings_writer(app).submit(settings);
+}
+
+/// Writes any pending settings change to disk immediately. Call before exit
+/// or before anything that reads the settings file directly.
+pub fn flush_settings(app: &AppHandle) {
+    settings_writer(app).flush();
+}
+
+fn settings_writer(app: &AppHandle) -> &'static WriteCoalescer<AppSettings> {
+    SETTINGS_WRITER.get_or_init(|| {
+        let app = app.clone();
+        WriteCoalescer::new(SETTINGS_WRITE_DEBOUNCE, move |settings| {
+            persist_settings(&app, &settings)
+        })
+    })
+}
+
+fn persist_settings(app: &AppHandle, settings: &AppSettings) {
+    let store = app
+        .store(crate::portable::store_path(SETTINGS_STORE_PATH))
+        .expect("Failed to initialize store");
+
+    store.set("settings", serde_json::to_value(settings).unwrap());
 
     // Explicitly flush to disk to prevent data loss on app restart
     if let Err(e) = store.save() {
@@ -4574,6 +6293,116 @@ pub fn write_settings(app: &AppHandle, mut settings: AppSettings) {
     }
 }
 
+struct PendingWrite<T> {
+    value: Option<T>,
+    last_submit: Instant,
+    worker_running: bool,
+}
+
+struct CoalescerInner<T> {
+    delay: Duration,
+    pending: Mutex<PendingWrite<T>>,
+    // Held while taking and persisting a value so an older value can never
+    // be written after a newer one.
+    persist_lock: Mutex<()>,
+    persist: Box<dyn Fn(T) + Send + Sync>,
+}
+
+impl<T> CoalescerInner<T> {
+    fn flush(&self) {
+        let _persisting = self
+            .persist_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let value = self
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value
+            .take();
+        if let Some(value) = value {
+            (self.persist)(value);
+        }
+    }
+
+    fn run_worker(&self) {
+        loop {
+            let wait = {
+                let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+                if pending.value.is_none() {
+                    pending.worker_running = false;
+                    return;
+                }
+                self.delay.checked_sub(pending.last_submit.elapsed())
+            };
+            match wait {
+                Some(remaining) if !remaining.is_zero() => thread::sleep(remaining),
+                _ => self.flush(),
+            }
+        }
+    }
+}
+
+/// Persists only the latest submitted value, once submissions have been
+/// quiet for the configured delay.
+pub(crate) struct WriteCoalescer<T> {
+    inner: Arc<CoalescerInner<T>>,
+}
+
+impl<T: Send + 'static> WriteCoalescer<T> {
+    pub(crate) fn new(delay: Duration, persist: impl Fn(T) + Send + Sync + 'static) -> Self {
+        Self {
+            inner: Arc::new(CoalescerInner {
+                delay,
+                pending: Mutex::new(PendingWrite {
+                    value: None,
+                    last_submit: Instant::now(),
+                    worker_running: false,
+                }),
+                persist_lock: Mutex::new(()),
+                persist: Box::new(persist),
+            }),
+        }
+    }
+
+    pub(crate) fn submit(&self, value: T) {
+        let start_worker = {
+            let mut pending = self
+                .inner
+                .pending
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            pending.value = Some(value);
+            pending.last_submit = Instant::now();
+            !std::mem::replace(&mut pending.worker_running, true)
+        };
+        if !start_worker {
+            return;
+        }
+
+        let inner = Arc::clone(&self.inner);
+        let spawned = thread::Builder::new()
+            .name("settings-writer".into())
+            .spawn(move || inner.run_worker());
+        if let Err(e) = spawned {
+            warn!(
+                "Failed to start settings writer, writing immediately: {}",
+                e
+            );
+            self.inner
+                .pending
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .worker_running = false;
+            self.inner.flush();
+        }
+    }
+
+    pub(crate) fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
 pub fn get_bindings(app: &AppHandle) -> HashMap<String, ShortcutBinding> {
     let settings = get_settings(app);
 
@@ -4648,6 +6477,190 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn multilingual_profiles_use_auto_for_single_language_engines() {
+        let mut profile: TranscriptionProfile = serde_json::from_value(json!({
+            "id": "profile_1",
+            "name": "German",
+            "language": "de",
+            "translate_to_english": false,
+        }))
+        .unwrap();
+        assert_eq!(profile.single_engine_language(), "de");
+
+        profile.additional_language_hints = vec!["de".to_string()];
+        assert!(!profile.has_additional_languages());
+        assert_eq!(profile.single_engine_language(), "de");
+
+        profile.additional_language_hints = vec!["en".to_string()];
+        assert!(profile.has_additional_languages());
+        assert_eq!(profile.single_engine_language(), "auto");
+    }
+
+    #[test]
+    fn profile_custom_words_are_merged_ahead_of_global_list() {
+        let mut settings = get_default_settings();
+        settings.custom_words = vec![CustomWord::fuzzy("Tauri"), CustomWord::fuzzy("Soniox")];
+        let mut profile: TranscriptionProfile = serde_json::from_value(json!({
+            "id": "profile_1",
+            "name": "Medical",
+            "language": "en",
+            "translate_to_english": false,
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.custom_words_for_profile(Some(&profile)),
+            settings.custom_words
+        );
+
+        profile.custom_words_override = Some(vec![
+            " Ibuprofen ".to_string(),
+            "Soniox".to_string(),
+            String::new(),
+        ]);
+        assert_eq!(
+            settings.custom_words_for_profile(Some(&profile)),
+            vec![
+                CustomWord::fuzzy("Ibuprofen"),
+                CustomWord::fuzzy("Soniox"),
+                CustomWord::fuzzy("Tauri")
+            ]
+        );
+    }
+
+    #[test]
+    fn profile_cycle_follows_explicit_order_then_creation_order() {
+        let mut settings = get_default_settings();
+        for (id, include_in_cycle) in [("a", true), ("b", true), ("c", false), ("d", true)] {
+            settings.transcription_profiles.push(
+                serde_json::from_value(json!({
+                    "id": id,
+                    "name": id,
+                    "language": "en",
+                    "translate_to_english": false,
+                    "include_in_cycle": include_in_cycle,
+                }))
+                .unwrap(),
+            );
+        }
+        assert_eq!(settings.profile_cycle_ids(), vec!["default", "a", "b", "d"]);
+
+        // Excluded and deleted profiles are skipped, unlisted ones appended.
+        settings.profile_cycle_order = vec!["d".into(), "c".into(), "gone".into(), "a".into()];
+        assert_eq!(settings.profile_cycle_ids(), vec!["default", "d", "a", "b"]);
+
+        settings.profile_cycle_order = vec!["b".into(), "default".into()];
+        assert_eq!(settings.profile_cycle_ids(), vec!["b", "default", "a", "d"]);
+    }
+
+    #[test]
+    fn custom_model_unload_timeout_uses_keep_loaded_minutes() {
+        assert_eq!(ModelUnloadTimeout::Custom.to_seconds(45), Some(45 * 60));
+        assert_eq!(ModelUnloadTimeout::Custom.to_seconds(0), Some(60));
+        assert_eq!(ModelUnloadTimeout::Min5.to_seconds(45), Some(5 * 60));
+        assert_eq!(ModelUnloadTimeout::Never.to_seconds(45), None);
+    }
+
+    #[test]
+    fn app_paste_override_first_match_wins_and_falls_back_to_global() {
+        let mut settings = get_default_settings();
+        settings.paste_method = PasteMethod::CtrlV;
+        settings.paste_delay_ms = 60;
+        let entry = |pattern: &str, method, delay, glob| AppPasteOverride {
+            id: pattern.to_string(),
+            app_pattern: pattern.to_string(),
+            method,
+            paste_delay_ms: delay,
+            glob,
+        };
+        settings.app_paste_overrides = vec![
+            entry("windows terminal", PasteMethod::CtrlShiftV, None, false),
+            entry("* - RDP?", PasteMethod::TypeCharacters, Some(200), true),
+            entry("Terminal", PasteMethod::Direct, None, false),
+        ];
+
+        assert_eq!(
+            settings.paste_method_for_app("PowerShell - Windows Terminal"),
+            (PasteMethod::CtrlShiftV, 60)
+        );
+        assert_eq!(
+            settings.paste_method_for_app("office - rdp2"),
+            (PasteMethod::TypeCharacters, 200)
+        );
+        assert_eq!(
+            settings.paste_method_for_app("rdp2 - notes"),
+            (PasteMethod::CtrlV, 60)
+        );
+        assert_eq!(settings.paste_method_for_app(""), (PasteMethod::CtrlV, 60));
+    }
+
+    #[test]
+    fn app_paste_override_glob_handles_wildcards() {
+        let glob = |pattern: &str, name: &str| {
+            let pattern: Vec<char> = pattern.chars().collect();
+            let name: Vec<char> = name.chars().collect();
+            glob_matches(&pattern, &name)
+        };
+        assert!(glob("*code*", "main.rs - visual studio code"));
+        assert!(glob("a?c", "abc"));
+        assert!(glob("*", ""));
+        assert!(glob("a*b*c", "axxbyyc"));
+        assert!(!glob("a*b*c", "axxbyy"));
+        assert!(!glob("abc", "abcd"));
+    }
+
+    #[test]
+    fn custom_words_prompt_list_stays_within_budget() {
+        let words = vec!["Tauri".to_string(), " ".to_string(), "Soniox".to_string()];
+        assert_eq!(custom_words_prompt_list(&words, 13), "Tauri, Soniox");
+        assert_eq!(custom_words_prompt_list(&words, 12), "Tauri");
+        assert_eq!(custom_words_prompt_list(&words, 3), "");
+    }
+
+    #[test]
+    fn expands_custom_words_variable_in_stt_prompt() {
+        let mut settings = get_default_settings();
+        settings.custom_words = vec![CustomWord::fuzzy("Tauri"), CustomWord::fuzzy("Soniox")];
+        settings
+            .transcription_prompts
+            .insert("whisper".to_string(), "Terms: ${custom_words}.".to_string());
+
+        assert_eq!(
+            resolve_stt_prompt(&settings, None, "whisper").as_deref(),
+            Some("Terms: Tauri, Soniox.")
+        );
+        assert_eq!(resolve_stt_prompt(&settings, None, "other"), None);
+
+        settings.stt_prompt_auto_inject_custom_words = true;
+        assert_eq!(
+            resolve_stt_prompt(&settings, None, "other").as_deref(),
+            Some("Tauri, Soniox")
+        );
+
+        settings.custom_words_enabled = false;
+        assert_eq!(
+            expand_custom_words_in_prompt(Some("${custom_words}".to_string()), "", true),
+            None
+        );
+        assert_eq!(resolve_stt_prompt(&settings, None, "other"), None);
+    }
+
+    #[test]
+    fn parses_custom_words_files() {
+        let words =
+            parse_custom_words_file("\u{feff}Tauri\r\n\n  Soniox  \nTauri\n".as_bytes()).unwrap();
+        assert_eq!(words, vec!["Tauri", "Soniox", "Tauri"]);
+
+        assert!(parse_custom_words_file(&[b'a', 0xff, b'\n'])
+            .unwrap_err()
+            .contains("offset 1"));
+        assert!(
+            parse_custom_words_file(&vec![b'a'; MAX_CUSTOM_WORDS_FILE_BYTES + 1])
+                .unwrap_err()
+                .contains("too large")
+        );
+    }
+
     #[test]
     fn recognizes_malformed_settings_store_documents() {
         assert!(parse_settings_store_document(br#"{"#).is_err());
@@ -4734,6 +6747,51 @@ mod tests {
         assert!(settings.soniox_context_terms.is_empty());
     }
 
+    #[test]
+    fn stt_prompt_custom_words_skip_exact_and_regex_entries() {
+        let mut settings = get_default_settings();
+        settings.custom_words = serde_json::from_value(json!([
+            "Tauri",
+            { "pattern": "teh", "replacement": "the", "kind": "exact" },
+            { "pattern": "(\\d+) percent", "replacement": "$1%", "kind": "regex" },
+        ]))
+        .unwrap();
+
+        assert_eq!(settings.stt_prompt_custom_words(None), "Tauri");
+    }
+
+    #[test]
+    fn soniox_context_merges_custom_words_only_when_enabled() {
+        let mut settings = get_default_settings();
+        settings.soniox_context_terms = vec!["Kubernetes".to_string()];
+        settings.custom_words = vec![
+            CustomWord::fuzzy("kubernetes"),
+            CustomWord::fuzzy("AivoRelay"),
+        ];
+
+        let context = resolve_soniox_context(None, &settings).unwrap();
+        assert_eq!(context.terms, vec!["Kubernetes".to_string()]);
+
+        settings.soniox_sync_context_from_custom_words = true;
+        let context = resolve_soniox_context(None, &settings).unwrap();
+        assert_eq!(
+            context.terms,
+            vec!["Kubernetes".to_string(), "AivoRelay".to_string()]
+        );
+    }
+
+    #[test]
+    fn soniox_context_custom_word_merge_respects_limit() {
+        let explicit = vec!["explicit".to_string()];
+        let custom_words: Vec<String> = (0..2_000).map(|i| format!("word{:05}", i)).collect();
+
+        let terms = merge_custom_words_into_soniox_terms("", "", &explicit, &custom_words);
+
+        assert_eq!(terms[0], "explicit");
+        assert!(terms.len() < custom_words.len());
+        assert!(build_soniox_context_from_parts("", "", &terms).is_ok());
+    }
+
     #[test]
     fn debug_output_redacts_all_stored_secrets() {
         let mut settings = get_default_settings();
@@ -4749,6 +6807,8 @@ mod tests {
             .insert("groq".to_string(), "gsk_secret_key_24680".to_string());
         settings.connector_password = "connector-secret-password".into();
         settings.connector_pending_password = Some("connector-pending-secret".to_string()).into();
+        settings.action_api_token = "action-api-secret-token".into();
+        settings.webhook_secret = "webhook-signing-secret".into();
 
         let debug_output = format!("{:?}", settings);
 
@@ -4757,6 +6817,8 @@ mod tests {
         assert!(!debug_output.contains("gsk_secret_key_24680"));
         assert!(!debug_output.contains("connector-secret-password"));
         assert!(!debug_output.contains("connector-pending-secret"));
+        assert!(!debug_output.contains("action-api-secret-token"));
+        assert!(!debug_output.contains("webhook-signing-secret"));
         assert!(debug_output.contains("[REDACTED]"));
     }
 
@@ -4783,6 +6845,56 @@ mod tests {
         assert!(pending_out.contains("[REDACTED]"));
     }
 
+    #[test]
+    fn microphone_mode_overrides_fall_back_to_global_flag() {
+        let mut settings = get_default_settings();
+        assert_eq!(
+            settings.microphone_mode_for_binding("ai_replace_selection"),
+            MicMode::OnDemand
+        );
+
+        settings.always_on_microphone = true;
+        settings
+            .microphone_mode_overrides
+            .insert("ai_replace_selection".to_string(), MicMode::OnDemand);
+
+        assert_eq!(
+            settings.microphone_mode_for_binding("transcribe"),
+            MicMode::AlwaysOn
+        );
+        assert_eq!(
+            settings.microphone_mode_for_binding("ai_replace_selection"),
+            MicMode::OnDemand
+        );
+    }
+
+    #[test]
+    fn profile_bindings_share_the_transcribe_override_family() {
+        let mut settings = get_default_settings();
+        settings
+            .microphone_mode_overrides
+            .insert("transcribe".to_string(), MicMode::AlwaysOn);
+
+        assert_eq!(
+            settings.microphone_mode_for_binding("transcribe_profile_123"),
+            MicMode::AlwaysOn
+        );
+    }
+
+    #[test]
+    fn mute_exemptions_apply_per_binding_family() {
+        let mut settings = get_default_settings();
+        settings.mute_while_recording = true;
+        settings.mute_while_recording_exempt_bindings =
+            vec!["send_screenshot_to_extension".to_string()];
+
+        assert!(settings.mute_while_recording_for_binding("transcribe"));
+        assert!(!settings.mute_while_recording_for_binding("send_screenshot_to_extension"));
+
+        settings.mute_while_recording = false;
+        assert!(!settings.mute_while_recording_for_binding("transcribe"));
+    }
+
     #[test]
     fn dictation_word_counter_uses_whitespace_boundaries() {
         assert_eq!(count_dictation_words(" one  two\nthree\t"), 3);
@@ -4807,4 +6919,39 @@ mod tests {
             MAX_DICTATION_STATS_COUNT
         );
     }
+
+    #[test]
+    fn coalesces_rapid_writes_and_keeps_final_value() {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&written);
+        let coalescer = WriteCoalescer::new(Duration::from_millis(50), move |value: u32| {
+            sink.lock().unwrap().push(value)
+        });
+
+        for value in 0..100 {
+            coalescer.submit(value);
+        }
+        thread::sleep(Duration::from_millis(200));
+        coalescer.flush();
+
+        let written = written.lock().unwrap();
+        assert!(!written.is_empty() && written.len() <= 5, "{:?}", *written);
+        assert_eq!(written.last(), Some(&99));
+    }
+
+    #[test]
+    fn flush_persists_pending_write_immediately() {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&written);
+        let coalescer = WriteCoalescer::new(Duration::from_secs(60), move |value: u32| {
+            sink.lock().unwrap().push(value)
+        });
+
+        coalescer.submit(1);
+        coalescer.submit(2);
+        coalescer.flush();
+        coalescer.flush();
+
+        assert_eq!(*written.lock().unwrap(), vec![2]);
+    }
 }
@@ -1,13 +1,15 @@
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Listener, Manager, State};
 use tauri_plugin_autostart::ManagerExt;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
 use crate::actions::ACTION_MAP;
+use crate::audio_toolkit::{CustomWord, CustomWordKind};
+use crate::instance_registry;
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::key_listener::{KeyListenerState, ShortcutEvent};
 use crate::managers::model::ModelManager;
@@ -16,18 +18,21 @@ use crate::settings::ShortcutBinding;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::settings::APPLE_INTELLIGENCE_DEFAULT_MODEL_ID;
 use crate::settings::{
-    self, get_settings, AutoSubmitKey, ClipboardHandling, LLMPrompt, NativeStreamingLatencyPreset,
-    OutputWhitespaceMode, OverlayPosition, PasteMethod, RecordingOverlayAnimatedBorderMode,
-    RecordingOverlayBackgroundMode, RecordingOverlayBarStyle, RecordingOverlayCenterpieceMode,
-    RecordingOverlayDecapitalizeIndicatorMode, RecordingOverlayMaterialMode, RecordingOverlayTheme,
-    RemoteSttDebugMode, ShortcutEngine, SonioxLivePreviewPosition, SonioxLivePreviewSize,
-    SonioxLivePreviewTheme, SoundTheme, TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID,
-    DEEPGRAM_DEFAULT_ENDPOINTING_MS, DEEPGRAM_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS,
-    DEEPGRAM_DEFAULT_MODEL, SONIOX_DEFAULT_ENDPOINT_SENSITIVITY,
-    SONIOX_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS, SONIOX_DEFAULT_MAX_ENDPOINT_DELAY_MS,
-    SONIOX_DEFAULT_MODEL,
+    self, get_settings, ApiAuthStyle, AppPasteOverride, AutoSubmitKey, ClipboardHandling,
+    LLMPrompt, NativeStreamingLatencyPreset, OutputWhitespaceMode, OverlayPosition, PasteMethod,
+    RecordingOverlayAnimatedBorderMode, RecordingOverlayBackgroundMode, RecordingOverlayBarStyle,
+    RecordingOverlayCenterpieceMode, RecordingOverlayDecapitalizeIndicatorMode,
+    RecordingOverlayMaterialMode, RecordingOverlayTheme, RemoteSttDebugMode, ShortcutEngine,
+    SonioxLivePreviewPosition, SonioxLivePreviewSize, SonioxLivePreviewTheme, SoundTheme,
+    TranscriptionProvider, APPLE_INTELLIGENCE_PROVIDER_ID, DEEPGRAM_DEFAULT_ENDPOINTING_MS,
+    DEEPGRAM_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS, DEEPGRAM_DEFAULT_MODEL,
+    SONIOX_DEFAULT_ENDPOINT_SENSITIVITY, SONIOX_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS,
+    SONIOX_DEFAULT_MAX_ENDPOINT_DELAY_MS, SONIOX_DEFAULT_MODEL,
 };
 use crate::shortcut_handy_keys;
+use crate::shortcut_integrity;
+#[cfg(target_os = "linux")]
+use crate::shortcut_portal;
 use crate::tray;
 use crate::url_security::{
     canonical_llm_provider_base_url, remote_stt_base_url_for_preset,
@@ -118,7 +123,9 @@ fn clamp_decapitalize_standard_post_monitor_ms(value: u32) -> u32 {
 }
 
 fn is_transcribe_binding_id_for_decapitalize(binding_id: &str) -> bool {
-    binding_id == "transcribe" || binding_id.starts_with("transcribe_")
+    binding_id == "transcribe"
+        || (binding_id.starts_with("transcribe_")
+            && binding_id != settings::TRANSCRIBE_AND_SEARCH_BINDING_ID)
 }
 
 fn uses_live_streaming_for_settings(settings: &settings::AppSettings) -> bool {
@@ -142,6 +149,9 @@ fn uses_live_streaming_for_settings(settings: &settings::AppSettings) -> bool {
                     &settings.remote_stt.model_id,
                 )
         }
+        TranscriptionProvider::RemoteOpenAiRealtime => {
+            !settings.openai_realtime_whisper_flatten_enabled
+        }
         TranscriptionProvider::Local => settings
             .native_streaming_live_output_models
             .iter()
@@ -403,6 +413,10 @@ fn build_decapitalize_monitor_bindings(settings: &settings::AppSettings) -> Vec<
                 .to_string(),
             default_binding: normalized_primary_binding.clone(),
             current_binding: normalized_primary_binding.clone(),
+            suppress_keypress: false,
+            interview_mode: false,
+            push_to_talk_override: None,
+            search_url_template: None,
         });
     }
 
@@ -422,6 +436,10 @@ fn build_decapitalize_monitor_bindings(settings: &settings::AppSettings) -> Vec<
                         .to_string(),
                 default_binding: normalized_secondary_binding.clone(),
                 current_binding: normalized_secondary_binding,
+                suppress_keypress: false,
+                interview_mode: false,
+                push_to_talk_override: None,
+                search_url_template: None,
             });
         }
     }
@@ -460,7 +478,10 @@ fn sync_decapitalize_monitor_shortcut(
 }
 
 /// Whether a binding should be active based on feature toggle settings.
-fn is_binding_enabled_for_settings(settings: &settings::AppSettings, binding_id: &str) -> bool {
+pub(crate) fn is_binding_enabled_for_settings(
+    settings: &settings::AppSettings,
+    binding_id: &str,
+) -> bool {
     match binding_id {
         "send_to_extension" => settings.send_to_extension_enabled,
         "send_to_extension_with_selection" => settings.send_to_extension_with_selection_enabled,
@@ -471,7 +492,7 @@ fn is_binding_enabled_for_settings(settings: &settings::AppSettings, binding_id:
 }
 
 /// Best-effort check whether a binding is currently registered.
-fn is_binding_currently_registered(app: &AppHandle, binding: &ShortcutBinding) -> bool {
+pub(crate) fn is_binding_currently_registered(app: &AppHandle, binding: &ShortcutBinding) -> bool {
     if let Some(rdev_set) = app.try_state::<RdevShortcutsSet>() {
         let rdev_shortcuts = rdev_set.lock().expect("Failed to lock rdev shortcuts");
         if rdev_shortcuts.contains(&binding.id) {
@@ -484,6 +505,11 @@ fn is_binding_currently_registered(app: &AppHandle, binding: &ShortcutBinding) -
         return true;
     }
 
+    #[cfg(target_os = "linux")]
+    if shortcut_portal::is_registered(app, &binding.id) {
+        return true;
+    }
+
     if binding.current_binding.trim().is_empty() {
         return false;
     }
@@ -576,7 +602,7 @@ pub fn init_shortcuts(app: &AppHandle) {
                     info!("Using Tauri shortcut engine (high performance, limited key support)");
                 }
             }
-            ShortcutEngine::Tauri => {
+            ShortcutEngine::Tauri | ShortcutEngine::Portal => {
                 if user_settings.text_replacement_decapitalize_after_edit_key_enabled {
                     start_rdev_listener(app);
                     info!(
@@ -596,6 +622,27 @@ pub fn init_shortcuts(app: &AppHandle) {
         setup_rdev_shortcut_handler(app);
     }
 
+    // Wayland compositors block key grabs and global hooks, so bindings go
+    // through the desktop portal instead. Without a portal the engine above
+    // stays in place, which still sees keys in X11 windows.
+    #[cfg(target_os = "linux")]
+    if crate::utils::is_wayland() {
+        match shortcut_portal::initialize(app) {
+            Ok(()) => {
+                if let Some(active_engine_state) = app.try_state::<ActiveShortcutEngine>() {
+                    if let Ok(mut engine) = active_engine_state.lock() {
+                        *engine = ShortcutEngine::Portal;
+                    }
+                }
+                info!("Wayland session detected; using the global shortcuts portal");
+            }
+            Err(error) => warn!(
+                "Wayland session detected but the global shortcuts portal is unavailable ({}); keeping the Tauri shortcut engine",
+                error.detail
+            ),
+        }
+    }
+
     // Register all default shortcuts, applying user customizations
     for (id, default_binding) in default_bindings {
         if id == "cancel" {
@@ -670,6 +717,41 @@ fn setup_rdev_shortcut_handler(app: &AppHandle) {
     });
 }
 
+/// Resolves push-to-talk for a binding: its own override wins, then the
+/// per-feature setting, then the active profile or global toggle.
+fn binding_uses_push_to_talk(settings: &settings::AppSettings, binding_id: &str) -> bool {
+    if let Some(use_push_to_talk) = settings.push_to_talk_override(binding_id) {
+        return use_push_to_talk;
+    }
+
+    match binding_id {
+        "send_to_extension" => settings.send_to_extension_push_to_talk,
+        "send_to_extension_with_selection" => {
+            settings.send_to_extension_with_selection_push_to_talk
+        }
+        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
+        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
+        "voice_command" => settings.voice_command_push_to_talk,
+        "transcribe" => {
+            // Use active profile's PTT setting, or global if "default"
+            if settings.active_profile_id == "default" {
+                settings.push_to_talk
+            } else {
+                settings
+                    .transcription_profile(&settings.active_profile_id)
+                    .map(|p| p.push_to_talk)
+                    .unwrap_or(settings.push_to_talk)
+            }
+        }
+        // Profile-specific shortcut: use that profile's PTT
+        id if id.starts_with("transcribe_") => settings
+            .transcription_profile_by_binding(id)
+            .map(|p| p.push_to_talk)
+            .unwrap_or(settings.push_to_talk),
+        _ => settings.push_to_talk,
+    }
+}
+
 /// Handle a shortcut event from rdev (mirrors the tauri-plugin-global-shortcut handler logic)
 fn handle_rdev_shortcut_event(app: &AppHandle, event: ShortcutEvent) {
     handle_shortcut_event(app, &event.id, &event.binding, event.pressed);
@@ -737,30 +819,15 @@ pub(crate) fn handle_shortcut_event(
         return;
     }
 
-    let use_push_to_talk = match binding_id {
-        "send_to_extension" => settings.send_to_extension_push_to_talk,
-        "send_to_extension_with_selection" => {
-            settings.send_to_extension_with_selection_push_to_talk
-        }
-        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
-        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
-        "voice_command" => settings.voice_command_push_to_talk,
-        "transcribe" => {
-            if settings.active_profile_id == "default" {
-                settings.push_to_talk
-            } else {
-                settings
-                    .transcription_profile(&settings.active_profile_id)
-                    .map(|p| p.push_to_talk)
-                    .unwrap_or(settings.push_to_talk)
-            }
+    if !action.is_instant() && crate::quiet_hours::blocks_recording_start(app, &settings) {
+        log::debug!("Shortcut '{}' ignored during quiet hours", binding_id);
+        if pressed {
+            crate::quiet_hours::note_blocked_press(app, &settings);
         }
-        id if id.starts_with("transcribe_") => settings
-            .transcription_profile_by_binding(id)
-            .map(|p| p.push_to_talk)
-            .unwrap_or(settings.push_to_talk),
-        _ => settings.push_to_talk,
-    };
+        return;
+    }
+
+    let use_push_to_talk = binding_uses_push_to_talk(&settings, binding_id);
 
     if action.is_instant() {
         let should_fire = if action.instant_fire_on_release() {
@@ -915,6 +982,128 @@ pub fn change_binding(
     })
 }
 
+/// Sets or clears (`None`) the push-to-talk override for a binding.
+#[tauri::command]
+#[specta::specta]
+pub fn change_binding_ptt_override(
+    app: AppHandle,
+    id: String,
+    value: Option<bool>,
+) -> Result<BindingResponse, String> {
+    let mut settings = settings::get_settings(&app);
+    let binding = settings
+        .bindings
+        .get_mut(&id)
+        .ok_or_else(|| format!("Binding with id '{}' not found", id))?;
+    binding.push_to_talk_override = value;
+    let updated_binding = binding.clone();
+    settings::write_settings(&app, settings);
+
+    Ok(BindingResponse {
+        success: true,
+        binding: Some(updated_binding),
+        error: None,
+    })
+}
+
+/// Turns interview mode on or off for a transcribe binding. Interview mode
+/// records system output through loopback, so it is rejected off Windows.
+#[tauri::command]
+#[specta::specta]
+pub fn change_binding_interview_mode_setting(
+    app: AppHandle,
+    id: String,
+    enabled: bool,
+) -> Result<BindingResponse, String> {
+    if enabled && !crate::interview::is_supported() {
+        return Err(crate::interview::UNSUPPORTED_MESSAGE.to_string());
+    }
+    if !crate::actions::is_transcribe_binding_id(&id) {
+        return Err(format!(
+            "Interview mode is only available for transcription shortcuts, not '{}'",
+            id
+        ));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    let binding = settings
+        .bindings
+        .get_mut(&id)
+        .ok_or_else(|| format!("Binding with id '{}' not found", id))?;
+    binding.interview_mode = enabled;
+    let updated_binding = binding.clone();
+    settings::write_settings(&app, settings);
+
+    Ok(BindingResponse {
+        success: true,
+        binding: Some(updated_binding),
+        error: None,
+    })
+}
+
+/// Only the rdev engine on Windows can swallow key events.
+fn check_suppress_keypress_supported(enabled: bool, engine: ShortcutEngine) -> Result<(), String> {
+    if !enabled {
+        return Ok(());
+    }
+    if !cfg!(target_os = "windows") {
+        return Err("Keypress suppression is only available on Windows.".to_string());
+    }
+    if engine != ShortcutEngine::Rdev {
+        return Err(
+            "Keypress suppression requires the rdev shortcut engine on Windows. Switch engines in Settings → Debug → Experimental Features."
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Toggle swallowing of a binding's key events. Enabling it is rejected outside
+/// Windows and under any engine other than rdev.
+#[tauri::command]
+#[specta::specta]
+pub fn change_binding_suppress_keypress_setting(
+    app: AppHandle,
+    id: String,
+    enabled: bool,
+) -> Result<BindingResponse, String> {
+    check_suppress_keypress_supported(enabled, get_current_shortcut_engine(app.clone()))?;
+
+    let mut settings = settings::get_settings(&app);
+    let binding_to_modify = settings
+        .bindings
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| format!("Binding with id '{}' not found", id))?;
+
+    let mut updated_binding = binding_to_modify.clone();
+    updated_binding.suppress_keypress = enabled;
+
+    // The cancel binding is registered dynamically while a session is active,
+    // so it picks up the flag on its next registration.
+    if id != "cancel" && is_binding_enabled_for_settings(&settings, &id) {
+        if let Err(e) = unregister_shortcut(&app, binding_to_modify.clone()) {
+            warn!(
+                "change_binding_suppress_keypress_setting: failed to unregister '{}' (proceeding anyway): {}",
+                id, e
+            );
+        }
+        if let Err(e) = register_shortcut(&app, updated_binding.clone()) {
+            let _ = register_shortcut(&app, binding_to_modify);
+            return Err(format!("Failed to register shortcut: {}", e));
+        }
+    }
+
+    settings.bindings.insert(id, updated_binding.clone());
+    settings::write_settings(&app, settings);
+
+    Ok(BindingResponse {
+        success: true,
+        binding: Some(updated_binding),
+        error: None,
+    })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn reset_binding(app: AppHandle, id: String) -> Result<BindingResponse, String> {
@@ -975,6 +1164,27 @@ pub fn change_result_ready_audio_feedback_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_visual_feedback_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.visual_feedback_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_visual_feedback_tray_blink_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.visual_feedback_tray_blink = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_audio_feedback_volume_setting(app: AppHandle, volume: f32) -> Result<(), String> {
@@ -1020,6 +1230,34 @@ pub fn change_selected_language_setting(app: AppHandle, language: String) -> Res
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_language_toggle_primary_setting(
+    app: AppHandle,
+    language: String,
+) -> Result<(), String> {
+    let language = language.trim();
+    if language.is_empty() {
+        return Err("Primary toggle language cannot be empty".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.language_toggle_primary = language.to_string();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_language_toggle_secondary_setting(
+    app: AppHandle,
+    language: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.language_toggle_secondary = language.trim().to_string();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_transcription_provider_setting(
@@ -1031,6 +1269,7 @@ pub fn change_transcription_provider_setting(
         "remote_openai_compatible" => TranscriptionProvider::RemoteOpenAiCompatible,
         "remote_soniox" => TranscriptionProvider::RemoteSoniox,
         "remote_deepgram" => TranscriptionProvider::RemoteDeepgram,
+        "remote_openai_realtime" => TranscriptionProvider::RemoteOpenAiRealtime,
         other => {
             warn!(
                 "Invalid transcription provider '{}', defaulting to local",
@@ -1047,6 +1286,7 @@ pub fn change_transcription_provider_setting(
             TranscriptionProvider::RemoteOpenAiCompatible
                 | TranscriptionProvider::RemoteSoniox
                 | TranscriptionProvider::RemoteDeepgram
+                | TranscriptionProvider::RemoteOpenAiRealtime
         ) {
             return Err("Remote transcription providers are only available on Windows".to_string());
         }
@@ -1063,6 +1303,15 @@ pub fn change_transcription_provider_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcription_race_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.transcription_race_mode = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Result<(), String> {
@@ -1175,6 +1424,30 @@ pub fn change_error_feedback_enabled_setting(app: AppHandle, enabled: bool) -> R
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_screen_reader_announcements_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.screen_reader_announcements_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_screen_reader_verbosity_setting(
+    app: AppHandle,
+    verbosity: settings::ScreenReaderVerbosity,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.screen_reader_verbosity = verbosity;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_recording_overlay_custom_enabled_setting(
@@ -1327,6 +1600,15 @@ pub fn change_recording_overlay_show_cancel_button_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_overlay_show_timer_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_show_timer = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_recording_overlay_bar_count_setting(app: AppHandle, count: u8) -> Result<(), String> {
@@ -1832,6 +2114,18 @@ pub fn change_local_preview_auto_flush_overlap_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_local_streaming_preview_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.local_streaming_preview_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_soniox_live_preview_sliding_lm_window_enabled_setting(
@@ -2565,35 +2859,231 @@ pub fn change_voice_button_single_click_close_setting(
     Ok(())
 }
 
+/// Set the monitor the voice button opens on without a saved position:
+/// "cursor", "primary" or a zero-based display index.
 #[tauri::command]
 #[specta::specta]
-pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), String> {
+pub fn change_voice_button_monitor_setting(app: AppHandle, monitor: String) -> Result<(), String> {
+    let monitor = monitor.trim().to_string();
+    if crate::voice_button_position::MonitorPreference::parse(&monitor).is_none() {
+        return Err(format!(
+            "Invalid voice button monitor '{}': expected \"cursor\", \"primary\" or a display index",
+            monitor
+        ));
+    }
+
     let mut settings = settings::get_settings(&app);
-    settings.custom_words = words;
+    settings.voice_button_monitor = monitor.clone();
     settings::write_settings(&app, settings);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "voice_button_monitor",
+            "value": monitor
+        }),
+    );
+
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_custom_words_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+pub fn update_custom_words(app: AppHandle, words: Vec<CustomWord>) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.custom_words_enabled = enabled;
+    settings.custom_words = words;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Checks a custom word pattern while it is being edited. Returns the regex
+/// error for invalid exact or regex patterns; invalid entries are skipped
+/// when custom words are applied.
 #[tauri::command]
 #[specta::specta]
-pub fn change_custom_words_ngram_enabled_setting(
-    app: AppHandle,
-    enabled: bool,
+pub fn validate_custom_word_pattern(
+    pattern: String,
+    kind: CustomWordKind,
+    case_sensitive: bool,
 ) -> Result<(), String> {
-    let mut settings = settings::get_settings(&app);
-    settings.custom_words_ngram_enabled = enabled;
-    settings::write_settings(&app, settings);
-    Ok(())
-}
+    if pattern.trim().is_empty() {
+        return Err("Pattern is empty".to_string());
+    }
+    let entry = CustomWord {
+        pattern,
+        replacement: String::new(),
+        kind,
+        case_sensitive,
+    };
+    match kind {
+        CustomWordKind::Fuzzy => Ok(()),
+        CustomWordKind::Exact | CustomWordKind::Regex => entry.compile().map(|_| ()),
+    }
+}
+
+/// Outcome of `import_custom_words`.
+#[derive(Serialize, Type)]
+pub struct CustomWordsImportResult {
+    /// Words appended to the list.
+    pub added: usize,
+    /// Words already in the list (or repeated in the file).
+    pub skipped: usize,
+}
+
+/// Writes the fuzzy custom words to `path`, one per line. Exact and regex
+/// entries have no plain-text form and are left out.
+#[tauri::command]
+#[specta::specta]
+pub fn export_custom_words(app: AppHandle, path: String) -> Result<usize, String> {
+    let settings = settings::get_settings(&app);
+    let words: Vec<&str> = settings
+        .custom_words
+        .iter()
+        .filter(|entry| entry.kind == CustomWordKind::Fuzzy)
+        .map(|entry| entry.pattern.as_str())
+        .collect();
+    let mut contents = words.join("\n");
+    contents.push('\n');
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write custom words file: {}", e))?;
+    info!("Exported {} custom words to {}", words.len(), path);
+    Ok(words.len())
+}
+
+/// Appends the words in a newline-delimited UTF-8 file at `path` to the custom
+/// words, keeping file order and skipping words that are already present.
+#[tauri::command]
+#[specta::specta]
+pub fn import_custom_words(
+    app: AppHandle,
+    path: String,
+) -> Result<CustomWordsImportResult, String> {
+    let size = std::fs::metadata(&path)
+        .map_err(|e| format!("Failed to open custom words file: {}", e))?
+        .len();
+    if size > settings::MAX_CUSTOM_WORDS_FILE_BYTES as u64 {
+        return Err(format!(
+            "Custom words file is too large ({} KB, limit is {} KB)",
+            size / 1024,
+            settings::MAX_CUSTOM_WORDS_FILE_BYTES / 1024
+        ));
+    }
+    let bytes =
+        std::fs::read(&path).map_err(|e| format!("Failed to read custom words file: {}", e))?;
+    let imported = settings::parse_custom_words_file(&bytes)?;
+
+    let mut settings = settings::get_settings(&app);
+    let mut result = CustomWordsImportResult {
+        added: 0,
+        skipped: 0,
+    };
+    for word in imported {
+        let entry = CustomWord::fuzzy(word);
+        if settings.custom_words.contains(&entry) {
+            result.skipped += 1;
+        } else {
+            settings.custom_words.push(entry);
+            result.added += 1;
+        }
+    }
+    if result.added > 0 {
+        settings::write_settings(&app, settings);
+    }
+    info!(
+        "Imported custom words from {}: {} added, {} skipped",
+        path, result.added, result.skipped
+    );
+    Ok(result)
+}
+
+/// Updates the transcript context blocklist and drops context already
+/// collected for apps that are now blocked.
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcript_context_blocklist_setting(
+    app: AppHandle,
+    blocklist: Vec<String>,
+) -> Result<(), String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for pattern in blocklist {
+        let pattern = pattern.trim();
+        if !pattern.is_empty()
+            && !normalized
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(pattern))
+        {
+            normalized.push(pattern.to_string());
+        }
+    }
+
+    let removed = crate::transcript_context::clear_transcript_context_where(|app_name| {
+        crate::transcript_context::is_app_blocked(app_name, &normalized)
+    });
+    if removed > 0 {
+        info!(
+            "Dropped {} transcript context entries for blocked apps",
+            removed
+        );
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.transcript_context_blocklist = normalized;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_custom_words_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.custom_words_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_custom_words_ngram_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.custom_words_ngram_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_stt_prompt_auto_inject_custom_words_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.stt_prompt_auto_inject_custom_words = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_stt_prompt_custom_words_max_chars_setting(
+    app: AppHandle,
+    max_chars: u32,
+) -> Result<(), String> {
+    let range = settings::STT_PROMPT_CUSTOM_WORDS_MAX_CHARS_RANGE;
+    if !range.contains(&max_chars) {
+        return Err(format!(
+            "Custom words budget must be between {} and {} characters",
+            range.start(),
+            range.end()
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.stt_prompt_custom_words_max_chars = max_chars;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
 
 #[tauri::command]
 #[specta::specta]
@@ -2617,6 +3107,7 @@ pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(),
         "none" => PasteMethod::None,
         "shift_insert" => PasteMethod::ShiftInsert,
         "ctrl_shift_v" => PasteMethod::CtrlShiftV,
+        "type_characters" => PasteMethod::TypeCharacters,
         other => {
             warn!("Invalid paste method '{}', defaulting to ctrl_v", other);
             PasteMethod::CtrlV
@@ -2636,6 +3127,91 @@ pub fn change_paste_delay_ms_setting(app: AppHandle, delay: u64) -> Result<(), S
     Ok(())
 }
 
+fn normalize_app_paste_pattern(app_pattern: String) -> Result<String, String> {
+    let app_pattern = app_pattern.trim().to_string();
+    if app_pattern.is_empty() {
+        return Err("App pattern cannot be empty".to_string());
+    }
+    Ok(app_pattern)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn add_app_paste_override(
+    app: AppHandle,
+    app_pattern: String,
+    method: PasteMethod,
+    paste_delay_ms: Option<u64>,
+    glob: bool,
+) -> Result<AppPasteOverride, String> {
+    let mut settings = settings::get_settings(&app);
+
+    let entry = AppPasteOverride {
+        id: format!("paste_override_{}", chrono::Utc::now().timestamp_millis()),
+        app_pattern: normalize_app_paste_pattern(app_pattern)?,
+        method,
+        paste_delay_ms,
+        glob,
+    };
+
+    settings.app_paste_overrides.push(entry.clone());
+    settings::write_settings(&app, settings);
+    Ok(entry)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_app_paste_override(
+    app: AppHandle,
+    id: String,
+    app_pattern: String,
+    method: PasteMethod,
+    paste_delay_ms: Option<u64>,
+    glob: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let app_pattern = normalize_app_paste_pattern(app_pattern)?;
+
+    let Some(entry) = settings
+        .app_paste_overrides
+        .iter_mut()
+        .find(|entry| entry.id == id)
+    else {
+        return Err(format!("Paste override with id '{}' not found", id));
+    };
+    entry.app_pattern = app_pattern;
+    entry.method = method;
+    entry.paste_delay_ms = paste_delay_ms;
+    entry.glob = glob;
+
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_app_paste_override(app: AppHandle, id: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let original_len = settings.app_paste_overrides.len();
+    settings.app_paste_overrides.retain(|entry| entry.id != id);
+    if settings.app_paste_overrides.len() == original_len {
+        return Err(format!("Paste override with id '{}' not found", id));
+    }
+
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_type_characters_delay_ms_setting(app: AppHandle, delay: u64) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.type_characters_delay_ms = delay;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Result<(), String> {
@@ -2684,6 +3260,18 @@ pub fn change_auto_submit_key_setting(app: AppHandle, key: String) -> Result<(),
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_stuck_modifier_recovery_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.stuck_modifier_recovery_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_convert_lf_to_crlf_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -2693,6 +3281,57 @@ pub fn change_convert_lf_to_crlf_setting(app: AppHandle, enabled: bool) -> Resul
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_boost_override_setting(
+    app: AppHandle,
+    boost: settings::BoostOverride,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.boost_override = settings::BoostOverride {
+        model: boost
+            .model
+            .map(|model| model.trim().to_string())
+            .filter(|model| !model.is_empty()),
+        ..boost
+    };
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_boost_expiry_secs_setting(app: AppHandle, seconds: u32) -> Result<(), String> {
+    if seconds == 0 {
+        return Err("Boost expiry must be at least one second".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.boost_expiry_secs = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_privacy_mode_duration_setting(app: AppHandle, minutes: u32) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.privacy_mode_duration_minutes = minutes;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_stream_conservative_delete_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.stream_conservative_delete = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_remote_stt_base_url_setting(app: AppHandle, base_url: String) -> Result<(), String> {
@@ -2723,6 +3362,8 @@ pub fn change_remote_stt_provider_preset_setting(
     }
 
     settings.remote_stt.provider_preset = preset.clone();
+    settings.remote_stt.auth_style = ApiAuthStyle::Bearer;
+    settings.remote_stt.extra_query_params.clear();
 
     if let Some(base_url) = remote_stt_base_url_for_preset(&preset) {
         settings.remote_stt.allow_insecure_http = false;
@@ -2765,6 +3406,90 @@ pub fn change_remote_stt_allow_insecure_http_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_auth_style_setting(
+    app: AppHandle,
+    auth_style: ApiAuthStyle,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if auth_style != ApiAuthStyle::Bearer
+        && settings.remote_stt.provider_preset != REMOTE_STT_PRESET_CUSTOM
+    {
+        return Err(
+            "Only the Custom Remote STT provider allows changing the auth style.".to_string(),
+        );
+    }
+    settings.remote_stt.auth_style = auth_style;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_extra_query_params_setting(
+    app: AppHandle,
+    params: HashMap<String, String>,
+) -> Result<(), String> {
+    let params = normalize_extra_query_params(params)?;
+    let mut settings = settings::get_settings(&app);
+    if !params.is_empty() && settings.remote_stt.provider_preset != REMOTE_STT_PRESET_CUSTOM {
+        return Err(
+            "Only the Custom Remote STT provider allows extra query parameters.".to_string(),
+        );
+    }
+    settings.remote_stt.extra_query_params = params;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Trims names and values and rejects blank or duplicate names.
+fn normalize_extra_query_params(
+    params: HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    let mut normalized = HashMap::with_capacity(params.len());
+    for (name, value) in params {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Query parameter names cannot be empty.".to_string());
+        }
+        if normalized
+            .insert(name.to_string(), value.trim().to_string())
+            .is_some()
+        {
+            return Err(format!("Duplicate query parameter '{}'.", name));
+        }
+    }
+    Ok(normalized)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_retry_attempts_setting(
+    app: AppHandle,
+    attempts: u32,
+) -> Result<(), String> {
+    if !(1..=10).contains(&attempts) {
+        return Err("Remote STT retry attempts must be between 1 and 10.".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.remote_stt_retry_attempts = attempts;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_remote_stt_retry_backoff_ms_setting(
+    app: AppHandle,
+    backoff_ms: u64,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.remote_stt_retry_backoff_ms = backoff_ms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_remote_stt_model_id_setting(app: AppHandle, model_id: String) -> Result<(), String> {
@@ -2886,70 +3611,160 @@ pub fn change_soniox_context_text_setting(app: AppHandle, text: String) -> Resul
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_soniox_context_terms_setting(
+pub fn change_soniox_context_terms_setting(
+    app: AppHandle,
+    terms: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let normalized_terms = settings::normalize_soniox_terms(&terms);
+    settings::build_soniox_context_from_parts(
+        &settings.soniox_context_general_json,
+        &settings.soniox_context_text,
+        &normalized_terms,
+    )?;
+    settings.soniox_context_terms = normalized_terms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_sync_context_from_custom_words_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_sync_context_from_custom_words = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_use_profile_language_hint_only_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_use_profile_language_hint_only = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_language_hints_strict_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_language_hints_strict = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_endpoint_detection_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_enable_endpoint_detection = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_max_endpoint_delay_ms_setting(
+    app: AppHandle,
+    delay_ms: u32,
+) -> Result<(), String> {
+    if !(500..=3000).contains(&delay_ms) {
+        return Err("Soniox endpoint delay must be between 500 and 3000 ms".to_string());
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_max_endpoint_delay_ms = delay_ms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_fallback_on_missing_model_setting(
     app: AppHandle,
-    terms: Vec<String>,
+    enabled: bool,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    let normalized_terms = settings::normalize_soniox_terms(&terms);
-    settings::build_soniox_context_from_parts(
-        &settings.soniox_context_general_json,
-        &settings.soniox_context_text,
-        &normalized_terms,
-    )?;
-    settings.soniox_context_terms = normalized_terms;
+    settings.auto_fallback_on_missing_model = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_soniox_use_profile_language_hint_only_setting(
+pub fn change_local_fallback_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.local_fallback_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_local_fallback_model_setting(
     app: AppHandle,
-    enabled: bool,
+    model_id: Option<String>,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.soniox_use_profile_language_hint_only = enabled;
+    settings.local_fallback_model = model_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty());
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_soniox_language_hints_strict_setting(
+pub fn change_local_fallback_repetition_threshold_setting(
     app: AppHandle,
-    enabled: bool,
+    threshold: f32,
 ) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("Repetition threshold must be between 0 and 1".to_string());
+    }
     let mut settings = settings::get_settings(&app);
-    settings.soniox_language_hints_strict = enabled;
+    settings.local_fallback_repetition_threshold = threshold;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_soniox_endpoint_detection_setting(
+pub fn change_soniox_live_auto_stop_on_endpoint_setting(
     app: AppHandle,
     enabled: bool,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.soniox_enable_endpoint_detection = enabled;
+    settings.soniox_live_auto_stop_on_endpoint = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_soniox_max_endpoint_delay_ms_setting(
+pub fn change_soniox_live_auto_stop_endpoint_count_setting(
     app: AppHandle,
-    delay_ms: u32,
+    count: u32,
 ) -> Result<(), String> {
-    if !(500..=3000).contains(&delay_ms) {
-        return Err("Soniox endpoint delay must be between 500 and 3000 ms".to_string());
+    if !(1..=5).contains(&count) {
+        return Err("Soniox auto-stop endpoint count must be between 1 and 5".to_string());
     }
 
     let mut settings = settings::get_settings(&app);
-    settings.soniox_max_endpoint_delay_ms = delay_ms;
+    settings.soniox_live_auto_stop_endpoint_count = count;
     settings::write_settings(&app, settings);
     Ok(())
 }
@@ -2994,6 +3809,18 @@ pub fn change_soniox_speaker_diarization_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_include_speaker_labels_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_include_speaker_labels = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_soniox_keepalive_interval_seconds_setting(
@@ -3010,6 +3837,26 @@ pub fn change_soniox_keepalive_interval_seconds_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_soniox_live_reconnect_attempts_setting(
+    app: AppHandle,
+    attempts: u32,
+) -> Result<(), String> {
+    let max_attempts = crate::managers::soniox_realtime::MAX_RECONNECT_ATTEMPTS;
+    if attempts > max_attempts {
+        return Err(format!(
+            "Soniox reconnect attempts must be between 0 and {}",
+            max_attempts
+        ));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.soniox_live_reconnect_attempts = attempts;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_soniox_live_finalize_timeout_ms_setting(
@@ -3095,6 +3942,7 @@ pub fn reset_soniox_settings_to_defaults(app: AppHandle) -> Result<(), String> {
     settings.soniox_enable_language_identification = true;
     settings.soniox_enable_speaker_diarization = true;
     settings.soniox_keepalive_interval_seconds = 10;
+    settings.soniox_live_reconnect_attempts = 3;
     settings.soniox_live_finalize_timeout_ms = SONIOX_DEFAULT_LIVE_FINALIZE_TIMEOUT_MS;
     settings.soniox_live_instant_stop = false;
     settings.soniox_optimize_delivery_preconnect_enabled = false;
@@ -3123,6 +3971,36 @@ pub fn change_deepgram_model_setting(app: AppHandle, model: String) -> Result<()
     Ok(())
 }
 
+/// Validated here, once; sessions use the stored URL as is.
+#[tauri::command]
+#[specta::specta]
+pub fn change_openai_realtime_base_url_setting(
+    app: AppHandle,
+    base_url: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.openai_realtime_base_url = if base_url.trim().is_empty() {
+        settings::OPENAI_REALTIME_DEFAULT_BASE_URL.to_string()
+    } else {
+        crate::url_security::validate_openai_realtime_base_url(&base_url)?
+    };
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_openai_realtime_model_setting(app: AppHandle, model: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.openai_realtime_model = if model.trim().is_empty() {
+        settings::OPENAI_REALTIME_DEFAULT_MODEL.to_string()
+    } else {
+        model.trim().to_string()
+    };
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_deepgram_timeout_setting(app: AppHandle, timeout_seconds: u32) -> Result<(), String> {
@@ -3365,6 +4243,52 @@ pub fn change_post_process_reasoning_budget_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_reasoning_enabled_over_words_setting(
+    app: AppHandle,
+    words: Option<u32>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_reasoning_enabled_over_words = words;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_reasoning_budget_per_100_words_setting(
+    app: AppHandle,
+    budget: Option<u32>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_reasoning_budget_per_100_words = budget.filter(|budget| *budget > 0);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Smallest accepted `post_process_chunk_threshold_chars`.
+const MIN_POST_PROCESS_CHUNK_THRESHOLD_CHARS: u32 = 1_000;
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_chunk_threshold_chars_setting(
+    app: AppHandle,
+    threshold: Option<u32>,
+) -> Result<(), String> {
+    let threshold = threshold.filter(|threshold| *threshold > 0);
+    if threshold.is_some_and(|threshold| threshold < MIN_POST_PROCESS_CHUNK_THRESHOLD_CHARS) {
+        return Err(format!(
+            "Chunk threshold must be at least {} characters",
+            MIN_POST_PROCESS_CHUNK_THRESHOLD_CHARS
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_chunk_threshold_chars = threshold;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ai_replace_reasoning_enabled_setting(
@@ -3663,6 +4587,37 @@ pub fn change_post_process_custom_http_override_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_custom_auth_style_setting(
+    app: AppHandle,
+    auth_style: ApiAuthStyle,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let provider = settings
+        .post_process_provider_mut("custom")
+        .ok_or_else(|| "Provider 'custom' not found".to_string())?;
+    provider.auth_style = auth_style;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_custom_extra_query_params_setting(
+    app: AppHandle,
+    params: HashMap<String, String>,
+) -> Result<(), String> {
+    let params = normalize_extra_query_params(params)?;
+    let mut settings = settings::get_settings(&app);
+    let provider = settings
+        .post_process_provider_mut("custom")
+        .ok_or_else(|| "Provider 'custom' not found".to_string())?;
+    provider.extra_query_params = params;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 /// Generic helper to validate provider exists
 fn validate_provider_exists(
     settings: &settings::AppSettings,
@@ -3730,12 +4685,51 @@ pub fn set_post_process_provider(app: AppHandle, provider_id: String) -> Result<
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_fallback_provider_setting(
+    app: AppHandle,
+    provider_id: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if let Some(ref pid) = provider_id {
+        validate_provider_exists(&settings, pid)?;
+    }
+    settings.post_process_fallback_provider_id = provider_id;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_fallback_model_setting(
+    app: AppHandle,
+    model: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_fallback_model = model
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Blank model overrides mean the prompt inherits the profile/global model.
+fn normalize_prompt_model_override(model: Option<String>) -> Option<String> {
+    model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn add_post_process_prompt(
     app: AppHandle,
     name: String,
     prompt: String,
+    model_override: Option<String>,
+    reasoning_enabled_override: Option<bool>,
+    reasoning_budget_override: Option<u32>,
 ) -> Result<LLMPrompt, String> {
     let mut settings = settings::get_settings(&app);
 
@@ -3746,6 +4740,12 @@ pub fn add_post_process_prompt(
         id: id.clone(),
         name,
         prompt,
+        max_output_words: None,
+        max_output_sentences: None,
+        output_json_path: None,
+        model_override: normalize_prompt_model_override(model_override),
+        reasoning_enabled_override,
+        reasoning_budget_override: reasoning_budget_override.filter(|budget| *budget > 0),
     };
 
     settings.post_process_prompts.push(new_prompt.clone());
@@ -3761,6 +4761,9 @@ pub fn update_post_process_prompt(
     id: String,
     name: String,
     prompt: String,
+    model_override: Option<String>,
+    reasoning_enabled_override: Option<bool>,
+    reasoning_budget_override: Option<u32>,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
 
@@ -3771,6 +4774,10 @@ pub fn update_post_process_prompt(
     {
         existing_prompt.name = name;
         existing_prompt.prompt = prompt;
+        existing_prompt.model_override = normalize_prompt_model_override(model_override);
+        existing_prompt.reasoning_enabled_override = reasoning_enabled_override;
+        existing_prompt.reasoning_budget_override =
+            reasoning_budget_override.filter(|budget| *budget > 0);
         settings::write_settings(&app, settings);
         Ok(())
     } else {
@@ -3778,6 +4785,133 @@ pub fn update_post_process_prompt(
     }
 }
 
+/// Set optional word/sentence limits enforced on a prompt's output (`None` or 0 clears).
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_prompt_output_limits(
+    app: AppHandle,
+    id: String,
+    max_output_words: Option<u32>,
+    max_output_sentences: Option<u32>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let Some(existing_prompt) = settings
+        .post_process_prompts
+        .iter_mut()
+        .find(|p| p.id == id)
+    else {
+        return Err(format!("Prompt with id '{}' not found", id));
+    };
+    existing_prompt.max_output_words = max_output_words.filter(|limit| *limit > 0);
+    existing_prompt.max_output_sentences = max_output_sentences.filter(|limit| *limit > 0);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the JSON path extracted from a prompt's response (`None` or blank clears).
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_prompt_output_json_path(
+    app: AppHandle,
+    id: String,
+    path: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    let Some(existing_prompt) = settings
+        .post_process_prompts
+        .iter_mut()
+        .find(|p| p.id == id)
+    else {
+        return Err(format!("Prompt with id '{}' not found", id));
+    };
+    existing_prompt.output_json_path = path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_output_limit_strategy_setting(
+    app: AppHandle,
+    strategy: settings::OutputLimitStrategy,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.post_process_output_limit_strategy = strategy;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_llm_clipboard_variable_max_chars_setting(
+    app: AppHandle,
+    max_chars: u32,
+) -> Result<(), String> {
+    if !(100..=100_000).contains(&max_chars) {
+        return Err("Clipboard limit must be between 100 and 100000 characters".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.llm_clipboard_variable_max_chars = max_chars;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_punctuation_mode_setting(
+    app: AppHandle,
+    mode: settings::PunctuationMode,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.punctuation_mode = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Sets the output casing of the default profile (None = leave casing unchanged).
+#[tauri::command]
+#[specta::specta]
+pub fn change_output_casing_setting(
+    app: AppHandle,
+    mode: Option<settings::CasingMode>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.output_casing = mode;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Sets the language the default profile's output is translated into (None or blank = off).
+#[tauri::command]
+#[specta::specta]
+pub fn change_output_translation_language_setting(
+    app: AppHandle,
+    language: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.output_translation_language = normalize_output_translation_language(language);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_output_casing_exceptions_setting(
+    app: AppHandle,
+    words: Vec<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.output_casing_exceptions = words
+        .into_iter()
+        .map(|word| word.trim().to_string())
+        .filter(|word| !word.is_empty())
+        .collect();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), String> {
@@ -3946,9 +5080,27 @@ pub struct AddTranscriptionProfilePayload {
     pub stt_prompt_override_enabled: bool,
     pub push_to_talk: bool,
     #[serde(default)]
-    pub preview_output_only_enabled: bool,
+    pub preview_output_only_enabled: bool,
+    #[serde(default)]
+    pub soniox_language_hints_strict: Option<bool>,
+    #[serde(default)]
+    pub additional_language_hints: Vec<String>,
+    #[serde(default)]
+    pub punctuation_mode: Option<settings::PunctuationMode>,
+    #[serde(default)]
+    pub output_casing: Option<settings::CasingMode>,
+    #[serde(default)]
+    pub output_translation_language: Option<String>,
+    #[serde(default)]
+    pub input_device_override: Option<String>,
+    #[serde(default)]
+    pub soniox_live_auto_stop_on_endpoint: Option<bool>,
+    #[serde(default)]
+    pub file_output: Option<settings::FileOutputSettings>,
+    #[serde(default)]
+    pub output_destination: settings::OutputDestination,
     #[serde(default)]
-    pub soniox_language_hints_strict: Option<bool>,
+    pub custom_words_override: Option<Vec<String>>,
     pub include_in_cycle: Option<bool>,
     pub llm_settings: Option<settings::ProfileLlmSettings>,
     pub soniox_context_general_json: Option<String>,
@@ -3970,12 +5122,64 @@ pub struct UpdateTranscriptionProfilePayload {
     pub preview_output_only_enabled: bool,
     #[serde(default)]
     pub soniox_language_hints_strict: Option<bool>,
+    #[serde(default)]
+    pub additional_language_hints: Vec<String>,
+    #[serde(default)]
+    pub punctuation_mode: Option<settings::PunctuationMode>,
+    #[serde(default)]
+    pub output_casing: Option<settings::CasingMode>,
+    #[serde(default)]
+    pub output_translation_language: Option<String>,
+    #[serde(default)]
+    pub input_device_override: Option<String>,
+    #[serde(default)]
+    pub soniox_live_auto_stop_on_endpoint: Option<bool>,
+    #[serde(default)]
+    pub file_output: Option<settings::FileOutputSettings>,
+    #[serde(default)]
+    pub output_destination: settings::OutputDestination,
+    #[serde(default)]
+    pub custom_words_override: Option<Vec<String>>,
     pub llm_settings: settings::ProfileLlmSettings,
     pub soniox_context_general_json: Option<String>,
     pub soniox_context_text: Option<String>,
     pub soniox_context_terms: Option<Vec<String>>,
 }
 
+/// Normalizes a profile's additional Soniox language hints, rejecting the save
+/// when any code is not a supported Soniox language.
+fn validate_additional_language_hints(hints: Vec<String>) -> Result<Vec<String>, String> {
+    let normalized_hints = crate::language_resolver::normalize_soniox_hint_list(hints);
+    if !normalized_hints.rejected.is_empty() {
+        return Err(format!(
+            "Unsupported language hints: {}",
+            normalized_hints.rejected.join(", ")
+        ));
+    }
+    Ok(normalized_hints.normalized)
+}
+
+/// Trims an output translation language code; blank means "no translation".
+fn normalize_output_translation_language(language: Option<String>) -> Option<String> {
+    language
+        .map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty())
+}
+
+/// Trims a profile's input device name; blank means "use the global microphone".
+fn normalize_input_device_override(device_name: Option<String>) -> Option<String> {
+    device_name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Normalizes a profile's extra custom words; an empty list means "global list only".
+fn normalize_custom_words_override(words: Option<Vec<String>>) -> Option<Vec<String>> {
+    words
+        .map(settings::normalize_custom_words)
+        .filter(|words| !words.is_empty())
+}
+
 /// Creates a new transcription profile with its own language/translation settings.
 /// This also creates a corresponding shortcut binding and registers it.
 #[tauri::command]
@@ -3993,12 +5197,28 @@ pub fn add_transcription_profile(
         push_to_talk,
         preview_output_only_enabled,
         soniox_language_hints_strict,
+        additional_language_hints,
+        punctuation_mode,
+        output_casing,
+        output_translation_language,
+        input_device_override,
+        soniox_live_auto_stop_on_endpoint,
+        file_output,
+        output_destination,
+        custom_words_override,
         include_in_cycle,
         llm_settings,
         soniox_context_general_json,
         soniox_context_text,
         soniox_context_terms,
     } = payload;
+    let additional_language_hints = validate_additional_language_hints(additional_language_hints)?;
+    let file_output = crate::file_output::normalize_settings(file_output)?;
+    let output_destination = crate::file_output::normalize_destination(output_destination)?;
+    let output_translation_language =
+        normalize_output_translation_language(output_translation_language);
+    let input_device_override = normalize_input_device_override(input_device_override);
+    let custom_words_override = normalize_custom_words_override(custom_words_override);
 
     let mut settings = settings::get_settings(&app);
 
@@ -4014,12 +5234,41 @@ pub fn add_transcription_profile(
     };
 
     // Use provided LLM settings or inherit from global default
-    let (llm_post_process_enabled, llm_prompt_override, llm_model_override) =
-        if let Some(llm) = llm_settings {
-            (llm.enabled, llm.prompt_override, llm.model_override)
-        } else {
-            (settings.post_process_enabled, None, None)
-        };
+    let (
+        llm_post_process_enabled,
+        llm_prompt_override,
+        llm_model_override,
+        llm_max_output_words,
+        llm_max_output_sentences,
+        llm_output_json_path,
+        llm_reasoning_enabled_over_words,
+        llm_reasoning_budget_per_100_words,
+    ) = if let Some(llm) = llm_settings {
+        (
+            llm.enabled,
+            llm.prompt_override,
+            llm.model_override,
+            llm.max_output_words.filter(|limit| *limit > 0),
+            llm.max_output_sentences.filter(|limit| *limit > 0),
+            llm.output_json_path
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty()),
+            llm.reasoning_enabled_over_words,
+            llm.reasoning_budget_per_100_words
+                .filter(|budget| *budget > 0),
+        )
+    } else {
+        (
+            settings.post_process_enabled,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
 
     let general_json = soniox_context_general_json.unwrap_or_default();
     let context_text = soniox_context_text.unwrap_or_default();
@@ -4038,12 +5287,26 @@ pub fn add_transcription_profile(
         push_to_talk,
         preview_output_only_enabled,
         soniox_language_hints_strict,
+        additional_language_hints,
+        punctuation_mode,
+        output_casing,
+        output_translation_language,
+        input_device_override,
+        soniox_live_auto_stop_on_endpoint,
+        file_output,
+        output_destination,
         llm_post_process_enabled,
         llm_prompt_override,
         llm_model_override,
+        llm_max_output_words,
+        llm_max_output_sentences,
+        llm_output_json_path,
+        llm_reasoning_enabled_over_words,
+        llm_reasoning_budget_per_100_words,
         soniox_context_general_json: general_json.trim().to_string(),
         soniox_context_text: context_text.trim().to_string(),
         soniox_context_terms: context_terms,
+        custom_words_override,
     };
 
     // Create a corresponding shortcut binding (no default key assigned)
@@ -4053,6 +5316,10 @@ pub fn add_transcription_profile(
         description,
         default_binding: String::new(), // User will set the shortcut
         current_binding: String::new(),
+        suppress_keypress: false,
+        interview_mode: false,
+        push_to_talk_override: None,
+        search_url_template: None,
     };
 
     // Add to settings
@@ -4060,6 +5327,13 @@ pub fn add_transcription_profile(
     settings.bindings.insert(binding_id, binding);
     settings::write_settings(&app, settings);
 
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "transcription_profiles"
+        }),
+    );
+
     Ok(new_profile)
 }
 
@@ -4081,11 +5355,27 @@ pub fn update_transcription_profile(
         push_to_talk,
         preview_output_only_enabled,
         soniox_language_hints_strict,
+        additional_language_hints,
+        punctuation_mode,
+        output_casing,
+        output_translation_language,
+        input_device_override,
+        soniox_live_auto_stop_on_endpoint,
+        file_output,
+        output_destination,
+        custom_words_override,
         llm_settings,
         soniox_context_general_json,
         soniox_context_text,
         soniox_context_terms,
     } = payload;
+    let additional_language_hints = validate_additional_language_hints(additional_language_hints)?;
+    let file_output = crate::file_output::normalize_settings(file_output)?;
+    let output_destination = crate::file_output::normalize_destination(output_destination)?;
+    let output_translation_language =
+        normalize_output_translation_language(output_translation_language);
+    let input_device_override = normalize_input_device_override(input_device_override);
+    let custom_words_override = normalize_custom_words_override(custom_words_override);
 
     let mut settings = settings::get_settings(&app);
 
@@ -4112,9 +5402,28 @@ pub fn update_transcription_profile(
     profile.push_to_talk = push_to_talk;
     profile.preview_output_only_enabled = preview_output_only_enabled;
     profile.soniox_language_hints_strict = soniox_language_hints_strict;
+    profile.additional_language_hints = additional_language_hints;
+    profile.punctuation_mode = punctuation_mode;
+    profile.output_casing = output_casing;
+    profile.output_translation_language = output_translation_language;
+    profile.input_device_override = input_device_override;
+    profile.soniox_live_auto_stop_on_endpoint = soniox_live_auto_stop_on_endpoint;
+    profile.file_output = file_output;
+    profile.output_destination = output_destination;
+    profile.custom_words_override = custom_words_override;
     profile.llm_post_process_enabled = llm_settings.enabled;
     profile.llm_prompt_override = llm_settings.prompt_override;
     profile.llm_model_override = llm_settings.model_override;
+    profile.llm_max_output_words = llm_settings.max_output_words.filter(|limit| *limit > 0);
+    profile.llm_max_output_sentences = llm_settings.max_output_sentences.filter(|limit| *limit > 0);
+    profile.llm_output_json_path = llm_settings
+        .output_json_path
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+    profile.llm_reasoning_enabled_over_words = llm_settings.reasoning_enabled_over_words;
+    profile.llm_reasoning_budget_per_100_words = llm_settings
+        .reasoning_budget_per_100_words
+        .filter(|budget| *budget > 0);
     let general_json = soniox_context_general_json.unwrap_or_default();
     let context_text = soniox_context_text.unwrap_or_default();
     let context_terms = settings::normalize_soniox_terms(&soniox_context_terms.unwrap_or_default());
@@ -4133,6 +5442,13 @@ pub fn update_transcription_profile(
     synchronize_active_profile_preview(&mut settings);
     settings::write_settings(&app, settings);
     refresh_soniox_live_preview_window(&app);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "transcription_profiles"
+        }),
+    );
     Ok(())
 }
 
@@ -4176,6 +5492,7 @@ pub fn delete_transcription_profile(app: AppHandle, id: String) -> Result<(), St
     if settings.transcription_profiles.len() == original_len {
         return Err(format!("Profile with id '{}' not found", id));
     }
+    settings.profile_cycle_order.retain(|entry| entry != &id);
 
     // If the deleted profile was valid, check if it was active
     if settings.active_profile_id == id {
@@ -4194,6 +5511,13 @@ pub fn delete_transcription_profile(app: AppHandle, id: String) -> Result<(), St
 
     settings::write_settings(&app, settings);
     refresh_soniox_live_preview_window(&app);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "transcription_profiles"
+        }),
+    );
     Ok(())
 }
 
@@ -4210,7 +5534,16 @@ pub fn get_active_profile(app: AppHandle) -> String {
 #[tauri::command]
 #[specta::specta]
 pub fn set_active_profile(app: AppHandle, id: String) -> Result<(), String> {
-    let mut settings = settings::get_settings(&app);
+    apply_active_profile(&app, &id)?;
+    crate::profile_schedule::note_manual_switch();
+    Ok(())
+}
+
+/// Makes `id` the active profile, shows the switch overlay when idle and
+/// notifies the UI. Shared by manual and scheduled switches.
+pub(crate) fn apply_active_profile(app: &AppHandle, id: &str) -> Result<(), String> {
+    let id = id.to_string();
+    let mut settings = settings::get_settings(app);
 
     // Validate: must be "default" or an existing profile ID
     if id != "default" && !settings.transcription_profiles.iter().any(|p| p.id == id) {
@@ -4219,8 +5552,8 @@ pub fn set_active_profile(app: AppHandle, id: String) -> Result<(), String> {
 
     settings.active_profile_id = id.clone();
     synchronize_active_profile_preview(&mut settings);
-    settings::write_settings(&app, settings.clone());
-    refresh_soniox_live_preview_window(&app);
+    settings::write_settings(app, settings.clone());
+    refresh_soniox_live_preview_window(app);
 
     // Show overlay notification if enabled
     // Skip overlay if recording/processing is active to avoid hiding the recording overlay
@@ -4233,17 +5566,25 @@ pub fn set_active_profile(app: AppHandle, id: String) -> Result<(), String> {
         };
 
         if show_overlay {
-            let profile_name = if id == "default" {
-                "Default".to_string()
-            } else {
-                settings
-                    .transcription_profiles
-                    .iter()
-                    .find(|p| p.id == id)
-                    .map(|p| p.name.clone())
-                    .unwrap_or_else(|| id.clone())
+            let (profile_name, details) = match settings.transcription_profile(&id) {
+                Some(profile) => (
+                    profile.name.clone(),
+                    crate::overlay::ProfileSwitchDetails {
+                        language: profile.language.clone(),
+                        translate_to_english: profile.translate_to_english,
+                        post_process_enabled: profile.llm_post_process_enabled,
+                    },
+                ),
+                None => (
+                    "Default".to_string(),
+                    crate::overlay::ProfileSwitchDetails {
+                        language: settings.selected_language.clone(),
+                        translate_to_english: settings.translate_to_english,
+                        post_process_enabled: settings.post_process_enabled,
+                    },
+                ),
             };
-            crate::overlay::show_profile_switch_overlay(&app, &profile_name);
+            crate::overlay::show_profile_switch_overlay(app, &profile_name, details);
         }
     }
 
@@ -4254,20 +5595,13 @@ pub fn set_active_profile(app: AppHandle, id: String) -> Result<(), String> {
 }
 
 /// Cycle to the next transcription profile in the rotation.
-/// Only profiles with include_in_cycle=true participate.
-/// "default" profile is always included as the first option.
+/// Only profiles with include_in_cycle=true participate, in `profile_cycle_order`.
+/// "default" profile is always included.
 #[tauri::command]
 #[specta::specta]
 pub fn cycle_to_next_profile(app: AppHandle) -> Result<String, String> {
     let settings = settings::get_settings(&app);
-
-    // Build list of cycleable profile IDs: "default" first, then profiles with include_in_cycle=true
-    let mut cycle_ids: Vec<String> = vec!["default".to_string()];
-    for profile in &settings.transcription_profiles {
-        if profile.include_in_cycle {
-            cycle_ids.push(profile.id.clone());
-        }
-    }
+    let cycle_ids = settings.profile_cycle_ids();
 
     // If only "default" is available (no other profiles in cycle), just ensure we're on default
     if cycle_ids.len() <= 1 {
@@ -4280,7 +5614,7 @@ pub fn cycle_to_next_profile(app: AppHandle) -> Result<String, String> {
         return Ok("default".to_string());
     }
 
-    // Find current index; if active profile is not in cycle list, start from 0 (default)
+    // Find current index; if active profile is not in cycle list, start from 0
     let current_idx = cycle_ids
         .iter()
         .position(|id| id == &settings.active_profile_id)
@@ -4294,6 +5628,53 @@ pub fn cycle_to_next_profile(app: AppHandle) -> Result<String, String> {
     Ok(next_id)
 }
 
+/// Sets the order the cycle shortcut walks profiles in. Each ID must be
+/// "default" or an existing profile with include_in_cycle on; cycle members
+/// left out follow in creation order.
+#[tauri::command]
+#[specta::specta]
+pub fn set_profile_cycle_order(app: AppHandle, order: Vec<String>) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    validate_profile_cycle_order(&settings, &order)?;
+
+    settings.profile_cycle_order = order;
+    settings::write_settings(&app, settings);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "profile_cycle_order"
+        }),
+    );
+    Ok(())
+}
+
+/// Rejects duplicate ids, unknown profiles and profiles left out of the cycle.
+fn validate_profile_cycle_order(
+    settings: &settings::AppSettings,
+    order: &[String],
+) -> Result<(), String> {
+    for (index, id) in order.iter().enumerate() {
+        if order[..index].contains(id) {
+            return Err(format!("Profile '{}' is listed more than once", id));
+        }
+        if id == "default" {
+            continue;
+        }
+        match settings.transcription_profile(id) {
+            None => return Err(format!("Profile '{}' not found", id)),
+            Some(profile) if !profile.include_in_cycle => {
+                return Err(format!(
+                    "Profile '{}' is not included in the cycle",
+                    profile.name
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn fetch_post_process_models(
@@ -4413,6 +5794,32 @@ pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Res
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_mute_while_recording_exempt_setting(
+    app: AppHandle,
+    binding_family: String,
+    exempt: bool,
+) -> Result<(), String> {
+    let binding_family = binding_family.trim().to_string();
+    if binding_family.is_empty() {
+        return Err("Binding family must not be empty".to_string());
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings
+        .mute_while_recording_exempt_bindings
+        .retain(|existing| existing != &binding_family);
+    if exempt {
+        settings
+            .mute_while_recording_exempt_bindings
+            .push(binding_family);
+    }
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_pause_media_while_recording_setting(
@@ -4509,6 +5916,30 @@ pub fn change_ai_replace_allow_no_selection_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_confirm_before_paste_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_confirm_before_paste = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_confirm_timeout_seconds_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_confirm_timeout_seconds = seconds.clamp(5, 600);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_ai_replace_no_selection_system_prompt_setting(
@@ -4735,124 +6166,325 @@ pub fn change_send_to_extension_with_selection_system_prompt_setting(
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_user_prompt_setting(
+pub fn change_send_to_extension_with_selection_user_prompt_setting(
+    app: AppHandle,
+    prompt: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_user_prompt = prompt;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_send_to_extension_with_selection_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_enabled = enabled;
+    sync_feature_shortcut_registration(
+        &app,
+        &settings,
+        "send_to_extension_with_selection",
+        enabled,
+    )?;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_send_to_extension_with_selection_push_to_talk_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_push_to_talk = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_send_to_extension_with_selection_allow_no_voice_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_allow_no_voice = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_send_to_extension_with_selection_quick_tap_threshold_ms_setting(
+    app: AppHandle,
+    threshold_ms: u32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_quick_tap_threshold_ms = threshold_ms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_send_to_extension_with_selection_no_voice_system_prompt_setting(
+    app: AppHandle,
+    prompt: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.send_to_extension_with_selection_no_voice_system_prompt = prompt;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_ai_replace_selection_push_to_talk_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.ai_replace_selection_push_to_talk = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_voice_command_push_to_talk_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.voice_command_push_to_talk = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_auto_open_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.connector_auto_open_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_connector_auto_open_url_setting(app: AppHandle, url: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.connector_auto_open_url = url;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_transcribe_and_search_url_template_setting(
+    app: AppHandle,
+    template: String,
+) -> Result<(), String> {
+    let template = crate::url_security::validate_search_url_template(&template)?;
+    let mut settings = settings::get_settings(&app);
+    settings.transcribe_and_search_url_template = template;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Sets or clears (`None`) the search URL template of a single search binding.
+#[tauri::command]
+#[specta::specta]
+pub fn change_binding_search_url_template_setting(
     app: AppHandle,
-    prompt: String,
-) -> Result<(), String> {
+    id: String,
+    template: Option<String>,
+) -> Result<BindingResponse, String> {
+    let template = template
+        .filter(|template| !template.trim().is_empty())
+        .map(|template| crate::url_security::validate_search_url_template(&template))
+        .transpose()?;
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_user_prompt = prompt;
+    let binding = settings
+        .bindings
+        .get_mut(&id)
+        .ok_or_else(|| format!("Binding with id '{}' not found", id))?;
+    binding.search_url_template = template;
+    let updated_binding = binding.clone();
     settings::write_settings(&app, settings);
-    Ok(())
+
+    Ok(BindingResponse {
+        success: true,
+        binding: Some(updated_binding),
+        error: None,
+    })
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_enabled_setting(
+pub fn change_transcribe_and_search_post_process_enabled_setting(
     app: AppHandle,
     enabled: bool,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_enabled = enabled;
-    sync_feature_shortcut_registration(
-        &app,
-        &settings,
-        "send_to_extension_with_selection",
-        enabled,
-    )?;
+    settings.transcribe_and_search_post_process_enabled = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_push_to_talk_setting(
+pub fn change_connector_health_check_interval_seconds_setting(
     app: AppHandle,
-    enabled: bool,
+    seconds: u32,
 ) -> Result<(), String> {
+    if !(1..=300).contains(&seconds) {
+        return Err("Health check interval must be between 1 and 300 seconds".to_string());
+    }
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_push_to_talk = enabled;
+    settings.connector_health_check_interval_seconds = seconds;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_allow_no_voice_setting(
+pub fn change_connector_heartbeat_interval_seconds_setting(
     app: AppHandle,
-    enabled: bool,
+    seconds: u32,
 ) -> Result<(), String> {
+    if !(1..=30).contains(&seconds) {
+        return Err("Heartbeat interval must be between 1 and 30 seconds".to_string());
+    }
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_allow_no_voice = enabled;
+    settings.connector_heartbeat_interval_seconds = seconds;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_quick_tap_threshold_ms_setting(
+pub fn change_connector_heartbeat_grace_seconds_setting(
     app: AppHandle,
-    threshold_ms: u32,
+    seconds: u32,
 ) -> Result<(), String> {
+    if !(1..=30).contains(&seconds) {
+        return Err("Heartbeat grace period must be between 1 and 30 seconds".to_string());
+    }
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_quick_tap_threshold_ms = threshold_ms;
+    settings.connector_heartbeat_grace_seconds = seconds;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_send_to_extension_with_selection_no_voice_system_prompt_setting(
+pub fn change_extension_offline_fallback_to_clipboard_setting(
     app: AppHandle,
-    prompt: String,
+    enabled: bool,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.send_to_extension_with_selection_no_voice_system_prompt = prompt;
+    settings.extension_offline_fallback_to_clipboard = enabled;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_ai_replace_selection_push_to_talk_setting(
+pub fn change_connector_message_max_age_setting(
     app: AppHandle,
-    enabled: bool,
+    minutes: u32,
 ) -> Result<(), String> {
+    if !(1..=settings::MAX_CONNECTOR_MESSAGE_MAX_AGE_MINUTES).contains(&minutes) {
+        return Err(format!(
+            "Message max age must be between 1 and {} minutes",
+            settings::MAX_CONNECTOR_MESSAGE_MAX_AGE_MINUTES
+        ));
+    }
     let mut settings = settings::get_settings(&app);
-    settings.ai_replace_selection_push_to_talk = enabled;
+    settings.connector_message_max_age_minutes = minutes;
     settings::write_settings(&app, settings);
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_voice_command_push_to_talk_setting(
+pub async fn change_action_api_enabled_setting(
     app: AppHandle,
     enabled: bool,
+    action_api: State<'_, Arc<crate::action_api::ActionApiServer>>,
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
-    settings.voice_command_push_to_talk = enabled;
+    let previous_enabled = settings.action_api_enabled;
+    if previous_enabled == enabled {
+        return Ok(());
+    }
+    settings.action_api_enabled = enabled;
     settings::write_settings(&app, settings);
+
+    if enabled {
+        if let Err(err) = action_api.restart().await {
+            let mut rollback_settings = settings::get_settings(&app);
+            rollback_settings.action_api_enabled = previous_enabled;
+            settings::write_settings(&app, rollback_settings);
+            return Err(err);
+        }
+    } else {
+        action_api.stop();
+    }
+    let _ = app.emit("settings-changed", ());
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_connector_auto_open_enabled_setting(
+pub async fn change_action_api_port_setting(
     app: AppHandle,
-    enabled: bool,
+    port: u16,
+    action_api: State<'_, Arc<crate::action_api::ActionApiServer>>,
 ) -> Result<(), String> {
+    if port < 1024 {
+        return Err(format!(
+            "Port {} is not allowed. Please use a port number of 1024 or higher.",
+            port
+        ));
+    }
     let mut settings = settings::get_settings(&app);
-    settings.connector_auto_open_enabled = enabled;
+    let previous_port = settings.action_api_port;
+    if previous_port == port {
+        return Ok(());
+    }
+    settings.action_api_port = port;
     settings::write_settings(&app, settings);
+
+    if let Err(err) = action_api.restart().await {
+        let mut rollback_settings = settings::get_settings(&app);
+        rollback_settings.action_api_port = previous_port;
+        settings::write_settings(&app, rollback_settings);
+        if let Err(rollback_err) = action_api.start() {
+            error!(
+                "Failed to restart action API on port {}: {}",
+                previous_port, rollback_err
+            );
+        }
+        return Err(err);
+    }
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn change_connector_auto_open_url_setting(app: AppHandle, url: String) -> Result<(), String> {
-    let mut settings = settings::get_settings(&app);
-    settings.connector_auto_open_url = url;
-    settings::write_settings(&app, settings);
+pub fn regenerate_action_api_token(app: AppHandle) -> Result<(), String> {
+    crate::action_api::regenerate_token(&app)?;
+    let _ = app.emit("settings-changed", ());
     Ok(())
 }
 
@@ -5113,6 +6745,30 @@ pub fn change_native_region_capture_mode_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_focus_restore_timeout_ms_setting(
+    app: AppHandle,
+    timeout_ms: u64,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.focus_restore_timeout_ms = timeout_ms.min(5000);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_focus_restore_paste_delay_ms_setting(
+    app: AppHandle,
+    delay_ms: u64,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.focus_restore_paste_delay_ms = delay_ms.min(2000);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_screenshot_folder_setting(app: AppHandle, folder: String) -> Result<(), String> {
@@ -5263,12 +6919,32 @@ pub fn change_show_tray_shortcut_guide_setting(
 // Shortcut Engine Settings
 // ============================================================================
 
+/// Sends the last `shortcut-portal-error` again, for a window that opened
+/// after it was emitted. The portal is only used on Linux.
+#[tauri::command]
+#[specta::specta]
+pub fn replay_shortcut_portal_error(app: AppHandle) {
+    #[cfg(target_os = "linux")]
+    shortcut_portal::replay_error(&app);
+    #[cfg(not(target_os = "linux"))]
+    let _ = app;
+}
+
 /// Get the currently active (running) shortcut engine.
 /// This returns the engine that was selected at app startup, not the configured one.
-/// On Windows, reads from app state. On other platforms, always returns Tauri.
+/// On Windows, reads from app state. On Linux, returns Portal on Wayland
+/// sessions whose global shortcuts portal connected.
+/// On other platforms, always returns Tauri.
 #[tauri::command]
 #[specta::specta]
 pub fn get_current_shortcut_engine(app: AppHandle) -> ShortcutEngine {
+    #[cfg(target_os = "linux")]
+    {
+        app.try_state::<ActiveShortcutEngine>()
+            .and_then(|state| state.lock().ok().map(|engine| *engine))
+            .filter(|engine| *engine == ShortcutEngine::Portal)
+            .unwrap_or(ShortcutEngine::Tauri)
+    }
     #[cfg(target_os = "windows")]
     {
         // Read from state (the actual running engine), not settings (which may have changed)
@@ -5281,13 +6957,56 @@ pub fn get_current_shortcut_engine(app: AppHandle) -> ShortcutEngine {
         let settings = settings::get_settings(&app);
         settings.shortcut_engine
     }
-    #[cfg(not(target_os = "windows"))]
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
     {
         let _ = app;
         ShortcutEngine::Tauri
     }
 }
 
+/// Set how often registered shortcuts are verified in the background (0 disables).
+#[tauri::command]
+#[specta::specta]
+pub fn change_shortcut_integrity_check_interval_seconds_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), String> {
+    if seconds != 0 && !(10..=3600).contains(&seconds) {
+        return Err("Shortcut check interval must be 0 or between 10 and 3600 seconds".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.shortcut_integrity_check_interval_seconds = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set how quickly the second press of a `double:` binding has to follow the first.
+#[tauri::command]
+#[specta::specta]
+pub fn change_shortcut_double_tap_window_setting(
+    app: AppHandle,
+    window_ms: u32,
+) -> Result<(), String> {
+    let range = crate::managers::key_listener::DOUBLE_TAP_WINDOW_RANGE_MS;
+    if !range.contains(&window_ms) {
+        return Err(format!(
+            "Double-tap window must be between {} and {} ms",
+            range.start(),
+            range.end()
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.shortcut_double_tap_window_ms = window_ms;
+    settings::write_settings(&app, settings);
+
+    if let Some(key_listener_state) = app.try_state::<KeyListenerState>() {
+        key_listener_state
+            .manager
+            .set_double_tap_window_ms(window_ms);
+    }
+    Ok(())
+}
+
 /// Set the shortcut engine setting (requires app restart to take effect).
 /// On non-Windows platforms, this is a no-op.
 #[tauri::command]
@@ -5298,6 +7017,12 @@ pub fn set_shortcut_engine_setting(app: AppHandle, engine: ShortcutEngine) -> Re
         let mut settings = settings::get_settings(&app);
         let old_engine = settings.shortcut_engine;
 
+        if engine == ShortcutEngine::Portal {
+            return Err(
+                "The desktop portal shortcut engine is only available on Linux Wayland".to_string(),
+            );
+        }
+
         // If no change, return early
         if old_engine == engine {
             return Ok(());
@@ -5327,6 +7052,8 @@ pub fn set_shortcut_engine_setting(app: AppHandle, engine: ShortcutEngine) -> Re
         }
 
         settings::write_settings(&app, settings);
+        // The change only applies after a relaunch, which can follow at once.
+        settings::flush_settings(&app);
 
         // Emit event to notify frontend of the change
         let _ = app.emit(
@@ -5373,9 +7100,13 @@ pub fn get_tauri_incompatible_shortcuts(app: AppHandle) -> Vec<ShortcutBinding>
     }
 }
 
+#[cfg(target_os = "windows")]
+const TRIGGER_PREFIX_NEEDS_RDEV_ERROR: &str = "Double-tap (double:) and hold (hold:) shortcuts are only supported by the rdev engine. Switch to rdev in Settings → Debug → Experimental Features.";
+
 /// Validate that a shortcut has valid structure.
 /// Empty string is allowed and means "unbound".
 /// On Windows, validation follows the configured shortcut engine.
+/// On Wayland, the binding must convert to a portal trigger (`CTRL+ALT+a` is accepted as is).
 /// On other platforms, tauri-plugin-global-shortcut requires a main key.
 fn validate_shortcut_string(app: &AppHandle, raw: &str) -> Result<(), String> {
     if raw.trim().is_empty() {
@@ -5387,8 +7118,14 @@ fn validate_shortcut_string(app: &AppHandle, raw: &str) -> Result<(), String> {
         let normalized = normalize_shortcut_binding(raw);
         let settings = get_settings(app);
 
+        if settings.shortcut_engine != ShortcutEngine::Rdev
+            && crate::managers::key_listener::has_trigger_prefix(&normalized)
+        {
+            return Err(TRIGGER_PREFIX_NEEDS_RDEV_ERROR.to_string());
+        }
+
         match settings.shortcut_engine {
-            ShortcutEngine::Tauri => {
+            ShortcutEngine::Tauri | ShortcutEngine::Portal => {
                 if is_shortcut_tauri_compatible(&normalized) {
                     Ok(())
                 } else {
@@ -5397,13 +7134,17 @@ fn validate_shortcut_string(app: &AppHandle, raw: &str) -> Result<(), String> {
             }
             ShortcutEngine::HandyKeys => shortcut_handy_keys::validate_shortcut(&normalized),
             ShortcutEngine::Rdev => {
-                crate::managers::key_listener::parse_shortcut_string(&normalized).map(|_| ())
+                crate::managers::key_listener::parse_binding(&normalized).map(|_| ())
             }
         }
     }
 
     #[cfg(not(target_os = "windows"))]
     {
+        #[cfg(target_os = "linux")]
+        if get_current_shortcut_engine(app.clone()) == ShortcutEngine::Portal {
+            return shortcut_portal::validate_shortcut(raw);
+        }
         let _ = app;
         let normalized = normalize_shortcut_binding(raw);
         let modifiers = [
@@ -5427,6 +7168,7 @@ fn validate_shortcut_string(app: &AppHandle, raw: &str) -> Result<(), String> {
 #[tauri::command]
 #[specta::specta]
 pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), String> {
+    shortcut_integrity::set_binding_suspended(&id, true);
     if let Some(b) = settings::get_bindings(&app).get(&id).cloned() {
         if let Err(e) = unregister_shortcut(&app, b) {
             error!("suspend_binding error for id '{}': {}", id, e);
@@ -5440,6 +7182,7 @@ pub fn suspend_binding(app: AppHandle, id: String) -> Result<(), String> {
 #[tauri::command]
 #[specta::specta]
 pub fn resume_binding(app: AppHandle, id: String) -> Result<(), String> {
+    shortcut_integrity::set_binding_suspended(&id, false);
     if let Some(b) = settings::get_bindings(&app).get(&id).cloned() {
         if let Err(e) = register_shortcut(&app, b) {
             error!("resume_binding error for id '{}': {}", id, e);
@@ -5498,13 +7241,44 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
         return Ok(());
     }
 
+    // Refuse bindings owned by another running instance (different --profile-dir)
+    // instead of letting both fire or failing silently at the OS level.
+    if let Some(owner) =
+        instance_registry::shortcut_owner_in_other_instance(&binding.current_binding)
+    {
+        let error_msg = format!(
+            "Shortcut '{}' is already registered by another AivoRelay instance ({})",
+            binding.current_binding, owner
+        );
+        warn!("{}", error_msg);
+        return Err(error_msg);
+    }
+
+    let result = register_shortcut_with_engine(app, binding.clone());
+    if result.is_ok() {
+        instance_registry::claim_shortcut(&binding.id, &binding.current_binding);
+    }
+    result
+}
+
+fn register_shortcut_with_engine(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
     let settings = get_settings(app);
 
     // On Windows, check the shortcut_engine setting to decide which engine to use
     #[cfg(target_os = "windows")]
     {
+        if settings.shortcut_engine != ShortcutEngine::Rdev
+            && crate::managers::key_listener::has_trigger_prefix(&binding.current_binding)
+        {
+            warn!(
+                "Shortcut '{}' rejected: {}",
+                binding.current_binding, TRIGGER_PREFIX_NEEDS_RDEV_ERROR
+            );
+            return Err(TRIGGER_PREFIX_NEEDS_RDEV_ERROR.to_string());
+        }
+
         match settings.shortcut_engine {
-            ShortcutEngine::Tauri => {
+            ShortcutEngine::Tauri | ShortcutEngine::Portal => {
                 // Check if the shortcut is compatible with Tauri engine
                 if !is_shortcut_tauri_compatible(&binding.current_binding) {
                     // Return error - incompatible shortcuts are not allowed in Tauri mode
@@ -5526,6 +7300,10 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
     #[cfg(not(target_os = "windows"))]
     {
         let _ = settings; // suppress unused warning
+        #[cfg(target_os = "linux")]
+        if get_current_shortcut_engine(app.clone()) == ShortcutEngine::Portal {
+            return shortcut_portal::register_shortcut(app, binding);
+        }
         register_shortcut_tauri(app, binding)
     }
 }
@@ -5648,33 +7426,23 @@ fn register_shortcut_tauri(app: &AppHandle, binding: ShortcutBinding) -> Result<
                         return;
                     }
 
-                    // Determine push-to-talk setting based on binding
-                    let use_push_to_talk = match binding_id_for_closure.as_str() {
-                        "send_to_extension" => settings.send_to_extension_push_to_talk,
-                        "send_to_extension_with_selection" => settings.send_to_extension_with_selection_push_to_talk,
-                        "ai_replace_selection" => settings.ai_replace_selection_push_to_talk,
-                        "send_screenshot_to_extension" => settings.send_screenshot_to_extension_push_to_talk,
-                        "voice_command" => settings.voice_command_push_to_talk,
-                        "transcribe" => {
-                            // Use active profile's PTT setting, or global if "default"
-                            if settings.active_profile_id == "default" {
-                                settings.push_to_talk
-                            } else {
-                                settings
-                                    .transcription_profile(&settings.active_profile_id)
-                                    .map(|p| p.push_to_talk)
-                                    .unwrap_or(settings.push_to_talk)
-                            }
-                        }
-                        id if id.starts_with("transcribe_") => {
-                            // Profile-specific shortcut: use that profile's PTT
-                            settings
-                                .transcription_profile_by_binding(id)
-                                .map(|p| p.push_to_talk)
-                                .unwrap_or(settings.push_to_talk)
+                    // Quiet hours block new recordings but never a stop.
+                    if !action.is_instant()
+                        && crate::quiet_hours::blocks_recording_start(ah, &settings)
+                    {
+                        log::debug!(
+                            "Shortcut '{}' ignored during quiet hours",
+                            binding_id_for_closure
+                        );
+                        if event.state == ShortcutState::Pressed {
+                            crate::quiet_hours::note_blocked_press(ah, &settings);
                         }
-                        _ => settings.push_to_talk,
-                    };
+                        return;
+                    }
+
+                    // Determine push-to-talk setting based on binding
+                    let use_push_to_talk =
+                        binding_uses_push_to_talk(&settings, &binding_id_for_closure);
 
                     // Handle instant actions first - they fire on every press
                     // without any toggle state management
@@ -5753,6 +7521,8 @@ fn register_shortcut_tauri(app: &AppHandle, binding: ShortcutBinding) -> Result<
 }
 
 pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    instance_registry::release_shortcut(&binding.id);
+
     // Check if this is an rdev shortcut first
     if let Some(rdev_set) = app.try_state::<RdevShortcutsSet>() {
         let mut rdev_shortcuts = rdev_set.lock().expect("Failed to lock rdev shortcuts");
@@ -5774,6 +7544,11 @@ pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<
         return shortcut_handy_keys::unregister_shortcut(app, binding);
     }
 
+    #[cfg(target_os = "linux")]
+    if shortcut_portal::is_registered(app, &binding.id) {
+        return shortcut_portal::unregister_shortcut(app, binding);
+    }
+
     // If not found in rdev set, try tauri-plugin-global-shortcut
     // This now correctly handles both Windows (Tauri engine) and other platforms
     if binding.current_binding.is_empty() {
@@ -5826,12 +7601,16 @@ fn register_shortcut_via_rdev(app: &AppHandle, binding: ShortcutBinding) -> Resu
 
     // Register with the key listener manager
     let manager = key_listener_state.manager.clone();
+    manager.set_double_tap_window_ms(get_settings(app).shortcut_double_tap_window_ms);
     let id = binding.id.clone();
     let current_binding = binding.current_binding.clone();
+    let suppress_keypress = binding.suppress_keypress;
 
     // Use block_on since we're in sync context
     futures::executor::block_on(async {
-        manager.register_shortcut(id.clone(), current_binding).await
+        manager
+            .register_shortcut(id.clone(), current_binding, suppress_keypress)
+            .await
     })?;
 
     // Track that this shortcut is registered via rdev
@@ -6058,6 +7837,44 @@ pub fn change_output_whitespace_trailing_mode_setting(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_smart_join_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.smart_join_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_smart_join_expiry_setting(app: AppHandle, seconds: u32) -> Result<(), String> {
+    let max_seconds = settings::MAX_SMART_JOIN_EXPIRY_SECS;
+    if seconds == 0 || seconds > max_seconds {
+        return Err(format!(
+            "Continuation window must be between 1 and {} seconds",
+            max_seconds
+        ));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.smart_join_expiry_secs = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Runs `sample_text` through the output whitespace policy with the current
+/// settings and lists which rule changed what.
+#[tauri::command]
+#[specta::specta]
+pub fn preview_whitespace_policy(
+    app: AppHandle,
+    sample_text: String,
+) -> Result<crate::output_whitespace::WhitespacePolicyPreview, String> {
+    let settings = settings::get_settings(&app);
+    Ok(crate::output_whitespace::preview(&sample_text, &settings))
+}
+
 // ============================================================================
 // Window Geometry Settings
 // ============================================================================
@@ -6142,6 +7959,46 @@ pub fn change_recording_auto_stop_paste_setting(app: AppHandle, paste: bool) ->
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_stop_on_silence_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.auto_stop_on_silence_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_auto_stop_silence_seconds_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.auto_stop_silence_seconds = seconds.clamp(1, 30);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_max_recording_duration_seconds_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.max_recording_duration_seconds = if seconds == 0 {
+        0
+    } else {
+        seconds.clamp(10, 14400)
+    };
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_extra_recording_buffer_setting(app: AppHandle, value_ms: u64) -> Result<(), String> {
@@ -6159,3 +8016,83 @@ pub fn change_lazy_stream_close_setting(app: AppHandle, enabled: bool) -> Result
     settings::write_settings(&app, settings);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabling_keypress_suppression_is_always_allowed() {
+        assert!(check_suppress_keypress_supported(false, ShortcutEngine::Tauri).is_ok());
+        assert!(check_suppress_keypress_supported(false, ShortcutEngine::Rdev).is_ok());
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn keypress_suppression_requires_rdev_on_windows() {
+        assert!(check_suppress_keypress_supported(true, ShortcutEngine::Rdev).is_ok());
+        assert!(check_suppress_keypress_supported(true, ShortcutEngine::Tauri).is_err());
+        assert!(check_suppress_keypress_supported(true, ShortcutEngine::HandyKeys).is_err());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn keypress_suppression_is_rejected_outside_windows() {
+        let error = check_suppress_keypress_supported(true, ShortcutEngine::Rdev).unwrap_err();
+        assert!(error.contains("only available on Windows"));
+        assert!(check_suppress_keypress_supported(true, ShortcutEngine::Tauri).is_err());
+    }
+
+    fn settings_with_profiles(profiles: &[(&str, bool)]) -> settings::AppSettings {
+        let mut settings = settings::get_default_settings();
+        settings.transcription_profiles = profiles
+            .iter()
+            .map(|(id, include_in_cycle)| {
+                serde_json::from_value(serde_json::json!({
+                    "id": id,
+                    "name": format!("Profile {}", id),
+                    "language": "en",
+                    "translate_to_english": false,
+                    "include_in_cycle": include_in_cycle,
+                }))
+                .unwrap()
+            })
+            .collect();
+        settings
+    }
+
+    fn order(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn cycle_order_accepts_default_and_cycled_profiles() {
+        let settings = settings_with_profiles(&[("a", true), ("b", true)]);
+        assert!(validate_profile_cycle_order(&settings, &order(&["b", "default", "a"])).is_ok());
+        assert!(validate_profile_cycle_order(&settings, &[]).is_ok());
+    }
+
+    #[test]
+    fn cycle_order_rejects_duplicates() {
+        let settings = settings_with_profiles(&[("a", true)]);
+        let error =
+            validate_profile_cycle_order(&settings, &order(&["a", "default", "a"])).unwrap_err();
+        assert!(error.contains("more than once"));
+        assert!(validate_profile_cycle_order(&settings, &order(&["default", "default"])).is_err());
+    }
+
+    #[test]
+    fn cycle_order_rejects_unknown_profiles() {
+        let settings = settings_with_profiles(&[("a", true)]);
+        let error = validate_profile_cycle_order(&settings, &order(&["a", "missing"])).unwrap_err();
+        assert!(error.contains("'missing' not found"));
+    }
+
+    #[test]
+    fn cycle_order_rejects_profiles_left_out_of_the_cycle() {
+        let settings = settings_with_profiles(&[("a", true), ("b", false)]);
+        let error = validate_profile_cycle_order(&settings, &order(&["a", "b"])).unwrap_err();
+        assert!(error.contains("Profile b"));
+        assert!(error.contains("not included in the cycle"));
+    }
+}
@@ -0,0 +1,382 @@
+//! Periodic verification of global shortcut registrations.
+//!
+//! After fast-startup or resume from sleep, OS-level hotkey registrations and
+//! keyboard hooks can silently disappear. A background thread re-checks every
+//! expected binding, re-registers anything missing and emits
+//! `shortcuts-repaired` when something was fixed. The OS wake notification
+//! from `power_events` triggers an extra check that refreshes everything.
+
+use log::{info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::managers::key_listener::KeyListenerState;
+use crate::settings::{self, ShortcutBinding, ShortcutEngine};
+use crate::shortcut::{self, RdevShortcutsSet};
+
+/// Poll interval used while the periodic check is disabled, so re-enabling it
+/// takes effect without a restart.
+const DISABLED_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutIntegrityStatus {
+    Ok,
+    Repaired,
+    RepairFailed,
+    /// Temporarily unregistered while the user edits it in settings.
+    Suspended,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct ShortcutIntegrityEntry {
+    pub binding_id: String,
+    pub binding: String,
+    pub engine: ShortcutEngine,
+    pub status: ShortcutIntegrityStatus,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct ShortcutIntegrityReport {
+    pub checked_at: i64,
+    /// Whether the check ran because a resume from sleep was detected.
+    pub after_resume: bool,
+    /// `None` when no binding relies on the rdev listener.
+    pub rdev_listener_healthy: Option<bool>,
+    pub rdev_listener_restarted: bool,
+    pub bindings: Vec<ShortcutIntegrityEntry>,
+}
+
+impl ShortcutIntegrityReport {
+    fn repaired_count(&self) -> usize {
+        self.bindings
+            .iter()
+            .filter(|entry| entry.status == ShortcutIntegrityStatus::Repaired)
+            .count()
+    }
+}
+
+static SUSPENDED_BINDINGS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+static CHECK_LOCK: Mutex<()> = Mutex::new(());
+static MONITOR_STARTED: OnceLock<()> = OnceLock::new();
+
+fn suspended_bindings() -> &'static Mutex<HashSet<String>> {
+    SUSPENDED_BINDINGS.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Marks a binding as intentionally unregistered (while it is being edited) so
+/// the integrity check does not re-register it behind the user's back.
+pub(crate) fn set_binding_suspended(binding_id: &str, suspended: bool) {
+    if let Ok(mut suspended_ids) = suspended_bindings().lock() {
+        if suspended {
+            suspended_ids.insert(binding_id.to_string());
+        } else {
+            suspended_ids.remove(binding_id);
+        }
+    }
+}
+
+fn is_binding_suspended(binding_id: &str) -> bool {
+    suspended_bindings()
+        .lock()
+        .map(|suspended_ids| suspended_ids.contains(binding_id))
+        .unwrap_or(false)
+}
+
+/// Bindings that `init_shortcuts` would register with the current settings.
+fn expected_bindings(app_settings: &settings::AppSettings) -> Vec<ShortcutBinding> {
+    let default_bindings = settings::get_default_settings().bindings;
+    let mut expected: Vec<ShortcutBinding> = default_bindings
+        .into_iter()
+        .filter(|(id, _)| id != "cancel")
+        .filter(|(id, _)| shortcut::is_binding_enabled_for_settings(app_settings, id))
+        .map(|(id, default_binding)| {
+            app_settings
+                .bindings
+                .get(&id)
+                .cloned()
+                .unwrap_or(default_binding)
+        })
+        .collect();
+
+    for profile in &app_settings.transcription_profiles {
+        let binding_id = format!("transcribe_{}", profile.id);
+        if let Some(binding) = app_settings.bindings.get(&binding_id) {
+            expected.push(binding.clone());
+        }
+    }
+
+    expected.retain(|binding| !binding.current_binding.trim().is_empty());
+    expected.sort_by(|a, b| a.id.cmp(&b.id));
+    expected
+}
+
+fn is_rdev_binding(app: &AppHandle, binding_id: &str) -> bool {
+    app.try_state::<RdevShortcutsSet>()
+        .and_then(|rdev_set| rdev_set.lock().ok().map(|set| set.contains(binding_id)))
+        .unwrap_or(false)
+}
+
+fn binding_engine(app: &AppHandle, binding_id: &str) -> ShortcutEngine {
+    if is_rdev_binding(app, binding_id) {
+        return ShortcutEngine::Rdev;
+    }
+    #[cfg(target_os = "windows")]
+    if crate::shortcut_handy_keys::is_registered(app, binding_id) {
+        return ShortcutEngine::HandyKeys;
+    }
+    shortcut::get_current_shortcut_engine(app.clone())
+}
+
+/// Restarts the rdev listener thread if it died. Returns `(healthy, restarted)`.
+fn ensure_rdev_listener(app: &AppHandle) -> (bool, bool) {
+    let Some(key_listener_state) = app.try_state::<KeyListenerState>() else {
+        return (false, false);
+    };
+    let manager = key_listener_state.manager.clone();
+    if manager.is_listener_thread_alive() {
+        return (true, false);
+    }
+
+    warn!("rdev key listener thread is not running; restarting it");
+    match manager.start_listener() {
+        Ok(()) => (manager.is_listener_thread_alive(), true),
+        Err(e) => {
+            warn!("Failed to restart rdev key listener: {}", e);
+            (false, true)
+        }
+    }
+}
+
+/// The binding is tracked as an rdev shortcut but the listener lost it.
+fn is_rdev_binding_missing_from_listener(app: &AppHandle, binding_id: &str) -> bool {
+    app.try_state::<KeyListenerState>()
+        .map(|state| !state.manager.has_shortcut(binding_id))
+        .unwrap_or(true)
+}
+
+fn reregister(app: &AppHandle, binding: &ShortcutBinding) -> Result<(), String> {
+    // Drop stale bookkeeping first; a failed unregister just means nothing was left.
+    let _ = shortcut::unregister_shortcut(app, binding.clone());
+    if let Some(rdev_set) = app.try_state::<RdevShortcutsSet>() {
+        if let Ok(mut rdev_shortcuts) = rdev_set.lock() {
+            rdev_shortcuts.remove(&binding.id);
+        }
+    }
+    shortcut::register_shortcut(app, binding.clone())
+}
+
+fn check_binding(
+    app: &AppHandle,
+    binding: &ShortcutBinding,
+    force_refresh: bool,
+) -> ShortcutIntegrityEntry {
+    let mut entry = ShortcutIntegrityEntry {
+        binding_id: binding.id.clone(),
+        binding: binding.current_binding.clone(),
+        engine: binding_engine(app, &binding.id),
+        status: ShortcutIntegrityStatus::Ok,
+        error: None,
+    };
+
+    if is_binding_suspended(&binding.id) {
+        entry.status = ShortcutIntegrityStatus::Suspended;
+        return entry;
+    }
+
+    let registered = shortcut::is_binding_currently_registered(app, binding);
+    let needs_repair = if !registered {
+        true
+    } else if entry.engine == ShortcutEngine::Rdev {
+        // The rdev hook survives resume; only a lost table entry needs fixing.
+        is_rdev_binding_missing_from_listener(app, &binding.id)
+    } else if entry.engine == ShortcutEngine::Portal {
+        // Portal sessions survive resume, and rebinding can prompt the user again.
+        false
+    } else {
+        force_refresh
+    };
+
+    if !needs_repair {
+        return entry;
+    }
+
+    match reregister(app, binding) {
+        Ok(()) => {
+            info!(
+                "Re-registered shortcut '{}' ({})",
+                binding.id, binding.current_binding
+            );
+            entry.engine = binding_engine(app, &binding.id);
+            entry.status = ShortcutIntegrityStatus::Repaired;
+        }
+        Err(e) => {
+            warn!(
+                "Failed to re-register shortcut '{}' ({}): {}",
+                binding.id, binding.current_binding, e
+            );
+            entry.status = ShortcutIntegrityStatus::RepairFailed;
+            entry.error = Some(e);
+        }
+    }
+    entry
+}
+
+/// Verifies every expected binding and repairs missing registrations.
+/// `after_resume` forces Tauri/HandyKeys bindings to be re-registered, since
+/// their bookkeeping can still claim a registration the OS has dropped.
+fn run_integrity_check(app: &AppHandle, after_resume: bool) -> ShortcutIntegrityReport {
+    let _guard = CHECK_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let app_settings = settings::get_settings(app);
+    let bindings = expected_bindings(&app_settings);
+
+    let rdev_needed = shortcut::get_current_shortcut_engine(app.clone()) == ShortcutEngine::Rdev
+        || bindings
+            .iter()
+            .any(|binding| is_rdev_binding(app, &binding.id));
+    let (rdev_listener_healthy, rdev_listener_restarted) = if rdev_needed {
+        let (healthy, restarted) = ensure_rdev_listener(app);
+        (Some(healthy), restarted)
+    } else {
+        (None, false)
+    };
+
+    let report = ShortcutIntegrityReport {
+        checked_at: chrono::Utc::now().timestamp(),
+        after_resume,
+        rdev_listener_healthy,
+        rdev_listener_restarted,
+        bindings: bindings
+            .iter()
+            .map(|binding| check_binding(app, binding, after_resume))
+            .collect(),
+    };
+
+    let repaired = report.repaired_count();
+    if repaired > 0 || rdev_listener_restarted {
+        info!(
+            "Shortcut integrity check repaired {} binding(s){}{}",
+            repaired,
+            if rdev_listener_restarted {
+                ", restarted rdev listener"
+            } else {
+                ""
+            },
+            if after_resume { " after resume" } else { "" }
+        );
+        if let Err(e) = app.emit("shortcuts-repaired", &report) {
+            warn!("Failed to emit shortcuts-repaired event: {}", e);
+        }
+    }
+
+    report
+}
+
+/// Starts the background thread that periodically verifies shortcut
+/// registrations, and refreshes them whenever the system wakes from sleep.
+pub fn start_integrity_monitor(app: &AppHandle) {
+    if MONITOR_STARTED.set(()).is_err() {
+        return;
+    }
+
+    let resume_app = app.clone();
+    crate::power_events::on_resume(move || {
+        info!("Verifying shortcut registrations after resume");
+        run_integrity_check(&resume_app, true);
+    });
+
+    let app = app.clone();
+    thread::Builder::new()
+        .name("shortcut-integrity".into())
+        .spawn(move || loop {
+            let interval_seconds =
+                settings::get_settings(&app).shortcut_integrity_check_interval_seconds;
+            if interval_seconds == 0 {
+                thread::sleep(DISABLED_POLL_INTERVAL);
+                continue;
+            }
+
+            thread::sleep(Duration::from_secs(u64::from(interval_seconds)));
+            run_integrity_check(&app, false);
+        })
+        .map(|_| ())
+        .unwrap_or_else(|e| warn!("Failed to start shortcut integrity monitor: {}", e));
+}
+
+/// Runs the shortcut integrity check immediately and returns a per-binding report.
+#[tauri::command]
+#[specta::specta]
+pub fn verify_shortcuts_now(app: AppHandle) -> ShortcutIntegrityReport {
+    run_integrity_check(&app, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(bindings: &[ShortcutBinding]) -> Vec<&str> {
+        bindings.iter().map(|binding| binding.id.as_str()).collect()
+    }
+
+    #[test]
+    fn expected_bindings_skip_cancel_disabled_and_empty_bindings() {
+        let mut app_settings = settings::get_default_settings();
+        app_settings.send_to_extension_enabled = false;
+        let expected = expected_bindings(&app_settings);
+        let expected_ids = ids(&expected);
+
+        assert!(expected_ids.contains(&"transcribe"));
+        assert!(!expected_ids.contains(&"cancel"));
+        assert!(!expected_ids.contains(&"send_to_extension"));
+        assert!(expected_ids.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        app_settings.send_to_extension_enabled = true;
+        app_settings
+            .bindings
+            .get_mut("transcribe")
+            .unwrap()
+            .current_binding = "  ".to_string();
+        let expected = expected_bindings(&app_settings);
+        assert!(ids(&expected).contains(&"send_to_extension"));
+        assert!(!ids(&expected).contains(&"transcribe"));
+    }
+
+    #[test]
+    fn expected_bindings_use_stored_and_profile_bindings() {
+        let mut app_settings = settings::get_default_settings();
+        app_settings
+            .bindings
+            .get_mut("transcribe")
+            .unwrap()
+            .current_binding = "ctrl+shift+f9".to_string();
+        let profile: settings::TranscriptionProfile = serde_json::from_value(serde_json::json!({
+            "id": "profile_1",
+            "name": "German",
+            "language": "de",
+            "translate_to_english": false,
+        }))
+        .unwrap();
+        app_settings.transcription_profiles.push(profile);
+        let mut profile_binding = app_settings.bindings["transcribe"].clone();
+        profile_binding.id = "transcribe_profile_1".to_string();
+        profile_binding.current_binding = "ctrl+shift+f10".to_string();
+        app_settings
+            .bindings
+            .insert(profile_binding.id.clone(), profile_binding);
+
+        let expected = expected_bindings(&app_settings);
+        let transcribe = expected.iter().find(|b| b.id == "transcribe").unwrap();
+        assert_eq!(transcribe.current_binding, "ctrl+shift+f9");
+        let profile = expected
+            .iter()
+            .find(|b| b.id == "transcribe_profile_1")
+            .unwrap();
+        assert_eq!(profile.current_binding, "ctrl+shift+f10");
+    }
+}
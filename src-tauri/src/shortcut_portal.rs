@@ -0,0 +1,532 @@
+//! Global shortcuts through the XDG Desktop Portal `GlobalShortcuts` interface.
+//!
+//! Wayland compositors do not let applications grab keys or read the global
+//! key stream, so neither tauri-plugin-global-shortcut nor rdev sees anything
+//! outside our own windows. The portal asks the compositor to bind the keys
+//! instead and reports `Activated`/`Deactivated` signals for each binding.
+//!
+//! The portal binds a whole set of shortcuts per session, so registrations are
+//! collected and re-bound together on a fresh session after a short debounce.
+//!
+//! The latest portal error is kept in [`PortalShortcutErrorState`] and can be
+//! replayed for a window that opens after it was emitted.
+
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use ashpd::desktop::Session;
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::{stream, StreamExt};
+use log::{debug, error, info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::settings::ShortcutBinding;
+
+/// Registrations arriving within this window are bound in one portal request,
+/// so startup shows the compositor's confirmation dialog once.
+const REBIND_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// How long startup waits for the portal before using another engine.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Portal modifier names (XDG shortcuts specification) keyed by our aliases.
+const MODIFIERS: &[(&str, &str)] = &[
+    ("ctrl", "CTRL"),
+    ("control", "CTRL"),
+    ("alt", "ALT"),
+    ("option", "ALT"),
+    ("shift", "SHIFT"),
+    ("super", "LOGO"),
+    ("logo", "LOGO"),
+    ("meta", "LOGO"),
+    ("win", "LOGO"),
+    ("windows", "LOGO"),
+    ("cmd", "LOGO"),
+    ("command", "LOGO"),
+    ("num", "NUM"),
+];
+
+/// XKB keysym names for keys that are not a single character.
+const NAMED_KEYS: &[(&str, &str)] = &[
+    ("space", "space"),
+    ("enter", "Return"),
+    ("return", "Return"),
+    ("tab", "Tab"),
+    ("escape", "Escape"),
+    ("esc", "Escape"),
+    ("backspace", "BackSpace"),
+    ("delete", "Delete"),
+    ("del", "Delete"),
+    ("insert", "Insert"),
+    ("home", "Home"),
+    ("end", "End"),
+    ("pageup", "Page_Up"),
+    ("page_up", "Page_Up"),
+    ("pagedown", "Page_Down"),
+    ("page_down", "Page_Down"),
+    ("up", "Up"),
+    ("arrowup", "Up"),
+    ("down", "Down"),
+    ("arrowdown", "Down"),
+    ("left", "Left"),
+    ("arrowleft", "Left"),
+    ("right", "Right"),
+    ("arrowright", "Right"),
+    ("printscreen", "Print"),
+    ("print", "Print"),
+    ("pause", "Pause"),
+    ("menu", "Menu"),
+    ("numadd", "KP_Add"),
+    ("numsubtract", "KP_Subtract"),
+    ("nummultiply", "KP_Multiply"),
+    ("numdivide", "KP_Divide"),
+    ("numdecimal", "KP_Decimal"),
+    ("numenter", "KP_Enter"),
+];
+
+/// Why the portal backend could not bind shortcuts; sent with
+/// `shortcut-portal-error` so the UI can explain what to do about it.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PortalShortcutErrorKind {
+    /// The desktop has no GlobalShortcuts portal (e.g. GNOME before 48).
+    Unavailable,
+    /// The portal rejected the request or the user dismissed the dialog.
+    BindFailed,
+    /// The compositor bound fewer shortcuts than requested.
+    PartiallyBound,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct PortalShortcutError {
+    pub kind: PortalShortcutErrorKind,
+    pub detail: String,
+}
+
+impl PortalShortcutError {
+    fn unavailable(detail: impl ToString) -> Self {
+        Self {
+            kind: PortalShortcutErrorKind::Unavailable,
+            detail: detail.to_string(),
+        }
+    }
+}
+
+/// The last error the portal backend reported; cleared once every shortcut
+/// is bound.
+#[derive(Default)]
+pub struct PortalShortcutErrorState(Mutex<Option<PortalShortcutError>>);
+
+fn set_error(app: &AppHandle, error: Option<PortalShortcutError>) {
+    if let Some(state) = app.try_state::<PortalShortcutErrorState>() {
+        *state.0.lock().unwrap_or_else(|e| e.into_inner()) = error;
+    }
+}
+
+/// Records `error` and sends it with `shortcut-portal-error`.
+fn report_error(app: &AppHandle, error: PortalShortcutError) {
+    set_error(app, Some(error.clone()));
+    let _ = app.emit("shortcut-portal-error", error);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PortalShortcut {
+    binding_id: String,
+    /// The binding as stored in settings, passed on to the shortcut handlers
+    binding: String,
+    description: String,
+    trigger: String,
+}
+
+pub struct PortalShortcutsState {
+    rebind_sender: UnboundedSender<Vec<PortalShortcut>>,
+    registered: Mutex<BTreeMap<String, PortalShortcut>>,
+    generation: AtomicU64,
+}
+
+impl PortalShortcutsState {
+    /// Connects to the portal and returns once it answered, so a desktop
+    /// without the portal can fall back to another engine.
+    fn connect(app: AppHandle) -> Result<Self, PortalShortcutError> {
+        let (rebind_sender, rebind_receiver) = mpsc::unbounded();
+        let (connected_sender, connected) = std::sync::mpsc::sync_channel(1);
+        std::thread::spawn(move || {
+            tauri::async_runtime::block_on(async {
+                let proxy = match GlobalShortcuts::new().await {
+                    Ok(proxy) => proxy,
+                    Err(e) => {
+                        let _ = connected_sender.send(Err(PortalShortcutError::unavailable(e)));
+                        return;
+                    }
+                };
+                let _ = connected_sender.send(Ok(()));
+                if let Err(error) = run_portal(&app, &proxy, rebind_receiver).await {
+                    error!("Global shortcuts portal stopped: {}", error.detail);
+                    report_error(&app, error);
+                }
+            });
+        });
+
+        match connected.recv_timeout(CONNECT_TIMEOUT) {
+            Ok(Ok(())) => Ok(Self {
+                rebind_sender,
+                registered: Mutex::new(BTreeMap::new()),
+                generation: AtomicU64::new(0),
+            }),
+            Ok(Err(error)) => Err(error),
+            Err(_) => Err(PortalShortcutError::unavailable(
+                "the desktop portal did not answer",
+            )),
+        }
+    }
+
+    fn update(&self, app: &AppHandle, change: impl FnOnce(&mut BTreeMap<String, PortalShortcut>)) {
+        let mut registered = self.registered.lock().unwrap_or_else(|e| e.into_inner());
+        change(&mut registered);
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        drop(registered);
+
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(REBIND_DEBOUNCE).await;
+            let Some(state) = app.try_state::<PortalShortcutsState>() else {
+                return;
+            };
+            if state.generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            let shortcuts = state
+                .registered
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .values()
+                .cloned()
+                .collect();
+            let _ = state.rebind_sender.unbounded_send(shortcuts);
+        });
+    }
+}
+
+enum PortalEvent {
+    Rebind(Vec<PortalShortcut>),
+    Activated(String),
+    Deactivated(String),
+}
+
+/// Owns the portal session for the lifetime of the app.
+async fn run_portal(
+    app: &AppHandle,
+    proxy: &GlobalShortcuts<'_>,
+    rebind_receiver: UnboundedReceiver<Vec<PortalShortcut>>,
+) -> Result<(), PortalShortcutError> {
+    let activated = proxy
+        .receive_activated()
+        .await
+        .map_err(PortalShortcutError::unavailable)?
+        .map(|event| PortalEvent::Activated(event.shortcut_id().to_string()));
+    let deactivated = proxy
+        .receive_deactivated()
+        .await
+        .map_err(PortalShortcutError::unavailable)?
+        .map(|event| PortalEvent::Deactivated(event.shortcut_id().to_string()));
+    let mut events = stream::select(
+        stream::select(activated, deactivated),
+        rebind_receiver.map(PortalEvent::Rebind),
+    );
+    info!("Global shortcuts portal connected");
+
+    let mut session: Option<Session<'_, GlobalShortcuts<'_>>> = None;
+    let mut bound: Vec<PortalShortcut> = Vec::new();
+    while let Some(event) = events.next().await {
+        match event {
+            PortalEvent::Rebind(shortcuts) => {
+                if let Some(previous) = session.take() {
+                    if let Err(e) = previous.close().await {
+                        debug!("Failed to close previous portal session: {}", e);
+                    }
+                }
+                bound.clear();
+                if shortcuts.is_empty() {
+                    continue;
+                }
+                match bind_shortcuts(proxy, &shortcuts).await {
+                    Ok((new_session, bound_ids)) => {
+                        session = Some(new_session);
+                        report_unbound(app, &shortcuts, &bound_ids);
+                        bound = shortcuts;
+                    }
+                    Err(detail) => {
+                        error!("Failed to bind portal shortcuts: {}", detail);
+                        report_error(
+                            app,
+                            PortalShortcutError {
+                                kind: PortalShortcutErrorKind::BindFailed,
+                                detail,
+                            },
+                        );
+                    }
+                }
+            }
+            PortalEvent::Activated(id) => dispatch(app, &bound, &id, true),
+            PortalEvent::Deactivated(id) => dispatch(app, &bound, &id, false),
+        }
+    }
+    Ok(())
+}
+
+async fn bind_shortcuts<'a>(
+    proxy: &'a GlobalShortcuts<'a>,
+    shortcuts: &[PortalShortcut],
+) -> Result<(Session<'a, GlobalShortcuts<'a>>, Vec<String>), String> {
+    let session = proxy.create_session().await.map_err(|e| e.to_string())?;
+    let requested: Vec<NewShortcut> = shortcuts
+        .iter()
+        .map(|shortcut| {
+            NewShortcut::new(shortcut.binding_id.as_str(), shortcut.description.as_str())
+                .preferred_trigger(shortcut.trigger.as_str())
+        })
+        .collect();
+    let response = proxy
+        .bind_shortcuts(&session, &requested, None)
+        .await
+        .and_then(|request| request.response())
+        .map_err(|e| e.to_string())?;
+
+    let bound_ids = response
+        .shortcuts()
+        .iter()
+        .map(|shortcut| {
+            debug!(
+                "Portal bound shortcut '{}' to '{}'",
+                shortcut.id(),
+                shortcut.trigger_description()
+            );
+            shortcut.id().to_string()
+        })
+        .collect();
+    Ok((session, bound_ids))
+}
+
+fn report_unbound(app: &AppHandle, requested: &[PortalShortcut], bound_ids: &[String]) {
+    let unbound: Vec<&str> = requested
+        .iter()
+        .filter(|shortcut| !bound_ids.contains(&shortcut.binding_id))
+        .map(|shortcut| shortcut.binding.as_str())
+        .collect();
+    if unbound.is_empty() {
+        info!("Portal bound {} shortcut(s)", requested.len());
+        set_error(app, None);
+        return;
+    }
+
+    warn!("Portal did not bind shortcuts: {}", unbound.join(", "));
+    report_error(
+        app,
+        PortalShortcutError {
+            kind: PortalShortcutErrorKind::PartiallyBound,
+            detail: unbound.join(", "),
+        },
+    );
+}
+
+fn dispatch(app: &AppHandle, bound: &[PortalShortcut], binding_id: &str, pressed: bool) {
+    let Some(shortcut) = bound.iter().find(|s| s.binding_id == binding_id) else {
+        warn!("Portal event for unknown shortcut '{}'", binding_id);
+        return;
+    };
+    debug!(
+        "Portal shortcut event: binding='{}', pressed={}",
+        binding_id, pressed
+    );
+    crate::shortcut::handle_shortcut_event(app, binding_id, &shortcut.binding, pressed);
+}
+
+/// Converts a binding such as `ctrl+alt+space` or the portal's own
+/// `CTRL+ALT+space` to the portal's preferred trigger format.
+pub fn to_portal_trigger(raw: &str) -> Result<String, String> {
+    let raw = raw.trim();
+    if crate::managers::key_listener::has_trigger_prefix(raw) {
+        return Err(
+            "Double-tap (double:) and hold (hold:) shortcuts are not supported by the desktop portal"
+                .to_string(),
+        );
+    }
+
+    let mut modifiers: Vec<&str> = Vec::new();
+    let mut key: Option<String> = None;
+    for part in raw.split('+').map(str::trim) {
+        if part.is_empty() {
+            return Err(format!("Invalid shortcut '{}'", raw));
+        }
+        let lower = part.to_lowercase();
+        if let Some((_, modifier)) = MODIFIERS.iter().find(|(alias, _)| *alias == lower) {
+            if !modifiers.contains(modifier) {
+                modifiers.push(modifier);
+            }
+            continue;
+        }
+        if key.is_some() {
+            return Err(format!(
+                "Shortcut '{}' has more than one main key; the desktop portal accepts one",
+                raw
+            ));
+        }
+        key = Some(portal_key_name(part, &lower)?);
+    }
+
+    let key = key.ok_or_else(|| {
+        "Shortcut must include a main key (letter, number, F-key, etc.) in addition to modifiers"
+            .to_string()
+    })?;
+    modifiers.push(&key);
+    Ok(modifiers.join("+"))
+}
+
+fn portal_key_name(part: &str, lower: &str) -> Result<String, String> {
+    let mut chars = lower.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Ok(ch.to_string());
+    }
+    if let Some((_, name)) = NAMED_KEYS.iter().find(|(alias, _)| *alias == lower) {
+        return Ok(name.to_string());
+    }
+    if let Some(number) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+        if (1..=24).contains(&number) {
+            return Ok(format!("F{}", number));
+        }
+    }
+    if let Some(digit) = lower
+        .strip_prefix("num")
+        .or_else(|| lower.strip_prefix("kp_"))
+        .filter(|rest| rest.len() == 1 && rest.chars().all(|c| c.is_ascii_digit()))
+    {
+        return Ok(format!("KP_{}", digit));
+    }
+    // Accept XKB keysym names the way the portal writes them (e.g. `KP_Add`).
+    if NAMED_KEYS.iter().any(|(_, name)| *name == part) {
+        return Ok(part.to_string());
+    }
+    Err(format!(
+        "Key '{}' is not supported by the desktop portal",
+        part
+    ))
+}
+
+/// Connects to the portal. On error nothing is registered through it; the
+/// error is recorded and emitted, and the caller keeps its own engine.
+pub fn initialize(app: &AppHandle) -> Result<(), PortalShortcutError> {
+    if app.try_state::<PortalShortcutsState>().is_some() {
+        return Ok(());
+    }
+    app.manage(PortalShortcutErrorState::default());
+    match PortalShortcutsState::connect(app.clone()) {
+        Ok(state) => {
+            app.manage(state);
+            info!("Global shortcuts portal backend initialized");
+            Ok(())
+        }
+        Err(error) => {
+            report_error(app, error.clone());
+            Err(error)
+        }
+    }
+}
+
+/// Sends the recorded error again, for a window that opened after it was
+/// first emitted.
+pub fn replay_error(app: &AppHandle) {
+    let error = app
+        .try_state::<PortalShortcutErrorState>()
+        .and_then(|state| state.0.lock().unwrap_or_else(|e| e.into_inner()).clone());
+    if let Some(error) = error {
+        let _ = app.emit("shortcut-portal-error", error);
+    }
+}
+
+pub fn validate_shortcut(raw: &str) -> Result<(), String> {
+    if raw.trim().is_empty() {
+        return Ok(());
+    }
+    to_portal_trigger(raw).map(|_| ())
+}
+
+pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<PortalShortcutsState>()
+        .ok_or_else(|| "Global shortcuts portal is not initialized".to_string())?;
+    let shortcut = PortalShortcut {
+        trigger: to_portal_trigger(&binding.current_binding)?,
+        binding_id: binding.id.clone(),
+        binding: binding.current_binding,
+        description: binding.name,
+    };
+    state.update(app, |registered| {
+        registered.insert(binding.id, shortcut);
+    });
+    Ok(())
+}
+
+pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<PortalShortcutsState>()
+        .ok_or_else(|| "Global shortcuts portal is not initialized".to_string())?;
+    state.update(app, |registered| {
+        registered.remove(&binding.id);
+    });
+    Ok(())
+}
+
+pub fn is_registered(app: &AppHandle, binding_id: &str) -> bool {
+    app.try_state::<PortalShortcutsState>()
+        .map(|state| {
+            state
+                .registered
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .contains_key(binding_id)
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_app_bindings_to_portal_triggers() {
+        assert_eq!(
+            to_portal_trigger("ctrl+alt+space").unwrap(),
+            "CTRL+ALT+space"
+        );
+        assert_eq!(to_portal_trigger("super+shift+R").unwrap(), "LOGO+SHIFT+r");
+        assert_eq!(to_portal_trigger("ctrl+f12").unwrap(), "CTRL+F12");
+        assert_eq!(to_portal_trigger("alt+pageup").unwrap(), "ALT+Page_Up");
+        assert_eq!(to_portal_trigger("ctrl+numadd").unwrap(), "CTRL+KP_Add");
+        assert_eq!(to_portal_trigger("ctrl+num5").unwrap(), "CTRL+KP_5");
+    }
+
+    #[test]
+    fn accepts_portal_accelerator_format() {
+        assert_eq!(to_portal_trigger("CTRL+LOGO+a").unwrap(), "CTRL+LOGO+a");
+        assert_eq!(to_portal_trigger("SHIFT+KP_Add").unwrap(), "SHIFT+KP_Add");
+        assert_eq!(to_portal_trigger("LOGO+Return").unwrap(), "LOGO+Return");
+    }
+
+    #[test]
+    fn rejects_bindings_the_portal_cannot_express() {
+        assert!(to_portal_trigger("ctrl+shift").is_err());
+        assert!(to_portal_trigger("ctrl+a+b").is_err());
+        assert!(to_portal_trigger("double:ctrl").is_err());
+        assert!(to_portal_trigger("ctrl+capslock").is_err());
+        assert!(to_portal_trigger("ctrl++").is_err());
+    }
+
+    #[test]
+    fn validate_shortcut_allows_blank_strings() {
+        assert!(validate_shortcut("  ").is_ok());
+    }
+}
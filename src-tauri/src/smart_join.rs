@@ -0,0 +1,202 @@
+//! Continuation mode for back-to-back dictations into the same app.
+//!
+//! Remembers how the last paste into each app ended. Within the expiry
+//! window, the next pasted text gets a separating space when the previous
+//! paste did not end in whitespace, and its first letter is capitalized when
+//! the previous paste ended a sentence. Streaming insert paths handle their
+//! own boundaries through the output whitespace policy and are not joined.
+
+use log::debug;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::settings::AppSettings;
+
+#[derive(Clone, Copy, Debug)]
+struct PasteTail {
+    /// Last character of the pasted text.
+    last_char: char,
+    /// Last non-whitespace character, to spot a finished sentence followed
+    /// by a trailing space.
+    last_visible_char: Option<char>,
+    pasted_at: Instant,
+}
+
+/// Last paste per app, keyed by the app identifier captured at recording start.
+static PASTE_TAILS: Lazy<Mutex<HashMap<String, PasteTail>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn expiry(settings: &AppSettings) -> Duration {
+    Duration::from_secs(u64::from(
+        settings
+            .smart_join_expiry_secs
+            .clamp(1, crate::settings::MAX_SMART_JOIN_EXPIRY_SECS),
+    ))
+}
+
+fn enabled_for(settings: &AppSettings, app_id: &str) -> bool {
+    settings.smart_join_enabled && !app_id.trim().is_empty()
+}
+
+fn is_sentence_end(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '…' | '。' | '！' | '？')
+}
+
+/// Scripts written without spaces between words or sentences.
+fn is_unspaced_script(ch: char) -> bool {
+    matches!(ch,
+        '\u{3000}'..='\u{30FF}'   // CJK punctuation, Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{FF00}'..='\u{FFEF}' // Fullwidth forms
+    )
+}
+
+/// Text that attaches to the previous word without a space.
+fn attaches_left(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '…' | '%'
+    )
+}
+
+fn capitalize_first_letter(text: &str) -> String {
+    // Only the first word counts: "42 items" stays as it is.
+    let Some((index, letter)) = text.char_indices().find(|(_, ch)| ch.is_alphanumeric()) else {
+        return text.to_string();
+    };
+    if !letter.is_lowercase() {
+        return text.to_string();
+    }
+    let mut joined = String::with_capacity(text.len() + 1);
+    joined.push_str(&text[..index]);
+    joined.extend(letter.to_uppercase());
+    joined.push_str(&text[index + letter.len_utf8()..]);
+    joined
+}
+
+/// Adjusts `text` so it continues after a paste that ended with `tail`.
+fn join_after(tail: &PasteTail, text: &str) -> String {
+    let Some(first) = text.chars().next() else {
+        return String::new();
+    };
+
+    let sentence_ended = tail.last_visible_char.is_some_and(is_sentence_end);
+    let text = if sentence_ended {
+        capitalize_first_letter(text)
+    } else {
+        text.to_string()
+    };
+
+    let needs_space = !tail.last_char.is_whitespace()
+        && !first.is_whitespace()
+        && !attaches_left(first)
+        && !is_unspaced_script(tail.last_char)
+        && !is_unspaced_script(first);
+    if needs_space {
+        format!(" {}", text)
+    } else {
+        text
+    }
+}
+
+fn tail_of(text: &str, pasted_at: Instant) -> Option<PasteTail> {
+    Some(PasteTail {
+        last_char: text.chars().last()?,
+        last_visible_char: text.chars().rev().find(|ch| !ch.is_whitespace()),
+        pasted_at,
+    })
+}
+
+/// Returns `text` joined onto the last paste into `app_id`, or unchanged
+/// when continuation mode is off or that paste has expired.
+pub fn apply(settings: &AppSettings, app_id: &str, text: &str) -> String {
+    if !enabled_for(settings, app_id) {
+        return text.to_string();
+    }
+
+    let tail = match PASTE_TAILS.lock() {
+        Ok(tails) => tails.get(app_id).copied(),
+        Err(e) => {
+            debug!("Failed to lock smart join state: {}", e);
+            None
+        }
+    };
+    match tail {
+        Some(tail) if tail.pasted_at.elapsed() < expiry(settings) => join_after(&tail, text),
+        _ => text.to_string(),
+    }
+}
+
+/// Remembers how the text just pasted into `app_id` ended.
+pub fn record_paste(settings: &AppSettings, app_id: &str, pasted: &str) {
+    if !enabled_for(settings, app_id) {
+        return;
+    }
+    let Some(tail) = tail_of(pasted, Instant::now()) else {
+        return;
+    };
+
+    match PASTE_TAILS.lock() {
+        Ok(mut tails) => {
+            let expiry = expiry(settings);
+            tails.retain(|_, tail| tail.pasted_at.elapsed() < expiry);
+            tails.insert(app_id.to_string(), tail);
+        }
+        Err(e) => debug!("Failed to lock smart join state for update: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn join(previous: &str, text: &str) -> String {
+        join_after(&tail_of(previous, Instant::now()).unwrap(), text)
+    }
+
+    #[test]
+    fn sentence_end_adds_space_and_capitalizes() {
+        assert_eq!(join("world.", "hello there"), " Hello there");
+        assert_eq!(join("Really?", "yes"), " Yes");
+        assert_eq!(join("Stop!", "\"why\""), " \"Why\"");
+        assert_eq!(join("Wait…", "ok"), " Ok");
+    }
+
+    #[test]
+    fn mid_sentence_adds_space_only() {
+        assert_eq!(join("I said", "hello"), " hello");
+        assert_eq!(join("first,", "second"), " second");
+        assert_eq!(join("I said", "Hello"), " Hello");
+    }
+
+    #[test]
+    fn existing_whitespace_is_not_doubled() {
+        assert_eq!(join("world. ", "hello"), "Hello");
+        assert_eq!(join("world.\n", "hello"), "Hello");
+        assert_eq!(join("I said ", "hello"), "hello");
+        assert_eq!(join("world.", " hello"), " Hello");
+    }
+
+    #[test]
+    fn attaching_punctuation_gets_no_space() {
+        assert_eq!(join("I said", ", and then"), ", and then");
+        assert_eq!(join("the end", "."), ".");
+    }
+
+    #[test]
+    fn unspaced_scripts_get_no_space() {
+        assert_eq!(join("你好。", "世界"), "世界");
+        assert_eq!(join("hello", "世界"), "世界");
+    }
+
+    #[test]
+    fn capitalization_keeps_non_letters_and_multibyte_letters() {
+        assert_eq!(join("Done.", "42 items"), " 42 items");
+        assert_eq!(join("Fertig.", "über"), " Über");
+        assert_eq!(join("Done.", ""), "");
+    }
+}
@@ -1,5 +1,5 @@
-use crate::audio_toolkit::apply_custom_words;
-use crate::settings::{AppSettings, OutputWhitespaceMode, TextReplacement};
+use crate::audio_toolkit::{apply_custom_words, CasingState, CustomWord, CustomWordKind};
+use crate::settings::{AppSettings, CasingMode, OutputWhitespaceMode, TextReplacement};
 use log::warn;
 use regex::Regex;
 
@@ -107,20 +107,35 @@ impl StreamChunkReplacementEngine {
 pub struct SonioxStreamProcessor {
     pending_raw: String,
     stable_tail_words: usize,
-    fuzzy_enabled: bool,
-    custom_words: Vec<String>,
+    /// Entries applied to each chunk; fuzzy ones only with fuzzy correction on.
+    custom_words: Vec<CustomWord>,
     word_correction_threshold: f64,
     custom_words_ngram_enabled: bool,
     replacements: Option<StreamChunkReplacementEngine>,
     leading_mode: OutputWhitespaceMode,
     leading_applied: bool,
+    /// Carries word and sentence boundaries across chunks.
+    casing: Option<CasingState>,
 }
 
 impl SonioxStreamProcessor {
-    pub fn from_settings(settings: &AppSettings) -> Self {
+    /// `custom_words` is the recording's word list (global words plus any profile words).
+    pub fn from_settings(settings: &AppSettings, custom_words: Vec<CustomWord>) -> Self {
         let fuzzy_enabled = settings.custom_words_enabled
-            && !settings.custom_words.is_empty()
+            && !custom_words.is_empty()
             && settings.soniox_realtime_fuzzy_correction_enabled;
+        // Exact and regex entries are deterministic, so they run without the
+        // fuzzy toggle and need no safety buffer.
+        let custom_words = if !settings.custom_words_enabled {
+            Vec::new()
+        } else if fuzzy_enabled {
+            custom_words
+        } else {
+            custom_words
+                .into_iter()
+                .filter(|word| word.kind != CustomWordKind::Fuzzy)
+                .collect()
+        };
         let stable_tail_words =
             if fuzzy_enabled && settings.soniox_realtime_keep_safety_buffer_enabled {
                 DEFAULT_STABLE_TAIL_WORDS
@@ -131,16 +146,23 @@ impl SonioxStreamProcessor {
         Self {
             pending_raw: String::new(),
             stable_tail_words,
-            fuzzy_enabled,
-            custom_words: settings.custom_words.clone(),
+            custom_words,
             word_correction_threshold: settings.word_correction_threshold,
             custom_words_ngram_enabled: settings.custom_words_ngram_enabled,
             replacements: StreamChunkReplacementEngine::from_settings(settings),
             leading_mode: settings.output_whitespace_leading_mode,
             leading_applied: false,
+            casing: None,
         }
     }
 
+    /// Applies `mode` to every emitted delta; the word still being streamed is
+    /// held back until it is complete or the stream is flushed.
+    pub fn with_output_casing(mut self, mode: Option<CasingMode>, exceptions: &[String]) -> Self {
+        self.casing = mode.map(|mode| CasingState::new(mode.into(), exceptions));
+        self
+    }
+
     pub fn push_chunk(&mut self, raw_chunk: &str) -> String {
         if raw_chunk.is_empty() {
             return String::new();
@@ -158,12 +180,12 @@ impl SonioxStreamProcessor {
     }
 
     pub fn flush(&mut self) -> String {
-        if self.pending_raw.is_empty() {
-            return String::new();
-        }
-
         let remaining = std::mem::take(&mut self.pending_raw);
-        self.process_pipeline(&remaining)
+        let mut flushed = self.process_pipeline(&remaining);
+        if let Some(casing) = &mut self.casing {
+            flushed.push_str(&casing.finish());
+        }
+        flushed
     }
 
     fn process_pipeline(&mut self, text: &str) -> String {
@@ -172,8 +194,8 @@ impl SonioxStreamProcessor {
         }
 
         // Order is fixed for Soniox realtime chunks:
-        // fuzzy custom words -> text replacements -> paste delta.
-        let corrected = if self.fuzzy_enabled {
+        // custom words -> text replacements -> casing -> paste delta.
+        let corrected = if !self.custom_words.is_empty() {
             apply_custom_words_preserving_whitespace(
                 text,
                 &self.custom_words,
@@ -223,13 +245,20 @@ impl SonioxStreamProcessor {
             }
         }
 
-        crate::text_replacement_decapitalize::maybe_decapitalize_next_chunk_realtime(&processed)
+        let processed =
+            crate::text_replacement_decapitalize::maybe_decapitalize_next_chunk_realtime(
+                &processed,
+            );
+        match &mut self.casing {
+            Some(casing) => casing.push(&processed),
+            None => processed,
+        }
     }
 }
 
 fn apply_custom_words_preserving_whitespace(
     text: &str,
-    custom_words: &[String],
+    custom_words: &[CustomWord],
     threshold: f64,
     enable_ngram: bool,
 ) -> String {
@@ -252,11 +281,17 @@ fn apply_custom_words_preserving_whitespace(
 
     // apply_custom_words() tokenizes by whitespace and rejoins with single spaces.
     // Skip fuzzy for this chunk if internal whitespace is non-trivial so we do not
-    // normalize tabs/newlines or repeated spaces in streaming output.
+    // normalize tabs/newlines or repeated spaces in streaming output. Exact and
+    // regex entries leave whitespace alone, so they still run.
     let has_complex_whitespace =
         core.contains("  ") || core.chars().any(|c| matches!(c, '\n' | '\r' | '\t'));
     if has_complex_whitespace {
-        return text.to_string();
+        let deterministic: Vec<CustomWord> = custom_words
+            .iter()
+            .filter(|word| word.kind != CustomWordKind::Fuzzy)
+            .cloned()
+            .collect();
+        return apply_custom_words(text, &deterministic, threshold, enable_ngram);
     }
 
     let corrected_core = apply_custom_words(core, custom_words, threshold, enable_ngram);
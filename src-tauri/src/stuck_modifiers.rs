@@ -0,0 +1,311 @@
+//! Post-paste check for modifier keys that stay logically held after a
+//! simulated shortcut.
+//!
+//! A fast hotkey release can race the injected Ctrl+V so the OS keeps Ctrl
+//! down until the user taps it. After each paste the modifiers we synthesized
+//! are checked against the OS key state and released again if they are still
+//! reported down while the rdev listener (when running) saw no physical press.
+//! Only Windows exposes the async key state; elsewhere the check is a no-op.
+
+use enigo::{Direction, Enigo, Key, Keyboard};
+use log::{info, warn};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::key_listener::{KeyListenerState, ModifierState};
+use crate::settings::{AutoSubmitKey, PasteMethod};
+
+/// Lets a pending injected key-up reach the OS before a down key counts as stuck.
+const MODIFIER_SETTLE: Duration = Duration::from_millis(20);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modifier {
+    Control,
+    Shift,
+    Meta,
+}
+
+impl Modifier {
+    fn enigo_key(self) -> Key {
+        match self {
+            Modifier::Control => Key::Control,
+            Modifier::Shift => Key::Shift,
+            Modifier::Meta => Key::Meta,
+        }
+    }
+
+    fn is_held_in(self, state: &ModifierState) -> bool {
+        match self {
+            Modifier::Control => state.ctrl,
+            Modifier::Shift => state.shift,
+            Modifier::Meta => state.win,
+        }
+    }
+}
+
+/// Key state queries and key-up injection, abstracted so the recovery
+/// sequence can be tested without touching the real keyboard.
+pub trait ModifierInjector {
+    /// Whether the OS currently reports `modifier` as held.
+    fn is_reported_down(&self, modifier: Modifier) -> bool;
+    /// Injects a key-up for `modifier`.
+    fn release(&mut self, modifier: Modifier) -> Result<(), String>;
+    /// Waits for already injected events to be processed.
+    fn settle(&mut self);
+}
+
+/// Modifiers pressed by a paste with `paste_method`, plus the auto-submit
+/// combo when one is sent.
+pub fn synthesized_modifiers(
+    paste_method: PasteMethod,
+    auto_submit_key: Option<AutoSubmitKey>,
+) -> Vec<Modifier> {
+    let primary = if cfg!(target_os = "macos") {
+        Modifier::Meta
+    } else {
+        Modifier::Control
+    };
+    let mut modifiers = match paste_method {
+        PasteMethod::CtrlV => vec![primary],
+        PasteMethod::CtrlShiftV => vec![primary, Modifier::Shift],
+        PasteMethod::ShiftInsert => vec![Modifier::Shift],
+        PasteMethod::Direct | PasteMethod::TypeCharacters | PasteMethod::None => Vec::new(),
+    };
+    let submit_modifier = match auto_submit_key {
+        Some(AutoSubmitKey::CtrlEnter) => Some(Modifier::Control),
+        Some(AutoSubmitKey::CmdEnter) => Some(Modifier::Meta),
+        Some(AutoSubmitKey::Enter) | None => None,
+    };
+    if let Some(modifier) = submit_modifier {
+        if !modifiers.contains(&modifier) {
+            modifiers.push(modifier);
+        }
+    }
+    modifiers
+}
+
+fn reported_stuck(
+    injector: &impl ModifierInjector,
+    synthesized: &[Modifier],
+    physical: Option<&ModifierState>,
+) -> Vec<Modifier> {
+    synthesized
+        .iter()
+        .copied()
+        .filter(|modifier| {
+            injector.is_reported_down(*modifier)
+                && !physical.is_some_and(|state| modifier.is_held_in(state))
+        })
+        .collect()
+}
+
+/// Releases the `synthesized` modifiers that are still reported down after a
+/// settle delay. `physical` is the listener's view of the keys the user holds
+/// (None when the listener is not running); those keys are left alone.
+/// Returns the modifiers that were released.
+pub fn recover_stuck_modifiers(
+    injector: &mut impl ModifierInjector,
+    synthesized: &[Modifier],
+    physical: Option<&ModifierState>,
+) -> Vec<Modifier> {
+    if reported_stuck(injector, synthesized, physical).is_empty() {
+        return Vec::new();
+    }
+    injector.settle();
+
+    let mut released = Vec::new();
+    for modifier in reported_stuck(injector, synthesized, physical) {
+        match injector.release(modifier) {
+            Ok(()) => {
+                info!(
+                    "Released stuck {:?} key left down after simulated paste",
+                    modifier
+                );
+                released.push(modifier);
+            }
+            Err(err) => warn!("Failed to release stuck {:?} key: {}", modifier, err),
+        }
+    }
+    released
+}
+
+struct EnigoModifierInjector<'a> {
+    enigo: &'a mut Enigo,
+}
+
+impl ModifierInjector for EnigoModifierInjector<'_> {
+    fn is_reported_down(&self, modifier: Modifier) -> bool {
+        is_async_key_down(modifier)
+    }
+
+    fn release(&mut self, modifier: Modifier) -> Result<(), String> {
+        self.enigo
+            .key(modifier.enigo_key(), Direction::Release)
+            .map_err(|e| format!("Failed to release {:?} key: {}", modifier, e))
+    }
+
+    fn settle(&mut self) {
+        std::thread::sleep(MODIFIER_SETTLE);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn is_async_key_down(modifier: Modifier) -> bool {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        GetAsyncKeyState, VIRTUAL_KEY, VK_CONTROL, VK_LWIN, VK_RWIN, VK_SHIFT,
+    };
+
+    let is_down = |key: VIRTUAL_KEY| unsafe { GetAsyncKeyState(key.0 as i32) } < 0;
+    match modifier {
+        Modifier::Control => is_down(VK_CONTROL),
+        Modifier::Shift => is_down(VK_SHIFT),
+        Modifier::Meta => is_down(VK_LWIN) || is_down(VK_RWIN),
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_async_key_down(_modifier: Modifier) -> bool {
+    false
+}
+
+/// Modifier state seen by the rdev listener, if it is running.
+fn physical_modifier_state(app: &AppHandle) -> Option<ModifierState> {
+    app.try_state::<KeyListenerState>()?
+        .manager
+        .physical_modifier_state()
+}
+
+/// Post-paste sanity step; call while still holding the Enigo lock.
+pub fn recover_after_paste(
+    app: &AppHandle,
+    enigo: &mut Enigo,
+    enabled: bool,
+    synthesized: &[Modifier],
+) {
+    if !enabled || synthesized.is_empty() {
+        return;
+    }
+    let physical = physical_modifier_state(app);
+    let mut injector = EnigoModifierInjector { enigo };
+    recover_stuck_modifiers(&mut injector, synthesized, physical.as_ref());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Reports `down`, swaps in the next state on each settle and records
+    /// every injected event.
+    struct MockInjector {
+        down: Vec<Modifier>,
+        after_settle: VecDeque<Vec<Modifier>>,
+        events: Vec<String>,
+    }
+
+    impl MockInjector {
+        fn with_checks(checks: Vec<Vec<Modifier>>) -> Self {
+            let mut after_settle: VecDeque<_> = checks.into();
+            Self {
+                down: after_settle.pop_front().unwrap_or_default(),
+                after_settle,
+                events: Vec::new(),
+            }
+        }
+    }
+
+    impl ModifierInjector for MockInjector {
+        fn is_reported_down(&self, modifier: Modifier) -> bool {
+            self.down.contains(&modifier)
+        }
+
+        fn release(&mut self, modifier: Modifier) -> Result<(), String> {
+            self.events.push(format!("release {:?}", modifier));
+            self.down.retain(|m| *m != modifier);
+            Ok(())
+        }
+
+        fn settle(&mut self) {
+            self.events.push("settle".to_string());
+            if let Some(next) = self.after_settle.pop_front() {
+                self.down = next;
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_happens_when_keys_are_up() {
+        let mut injector = MockInjector::with_checks(vec![vec![]]);
+
+        let released = recover_stuck_modifiers(&mut injector, &[Modifier::Control], None);
+
+        assert!(released.is_empty());
+        assert!(injector.events.is_empty());
+    }
+
+    #[test]
+    fn stuck_modifier_is_released_after_settle() {
+        let stuck = vec![Modifier::Control, Modifier::Shift];
+        let mut injector = MockInjector::with_checks(vec![stuck.clone(), stuck]);
+
+        let released =
+            recover_stuck_modifiers(&mut injector, &[Modifier::Control, Modifier::Shift], None);
+
+        assert_eq!(released, vec![Modifier::Control, Modifier::Shift]);
+        assert_eq!(
+            injector.events,
+            vec!["settle", "release Control", "release Shift"]
+        );
+    }
+
+    #[test]
+    fn key_that_clears_during_settle_is_not_released() {
+        let mut injector = MockInjector::with_checks(vec![vec![Modifier::Control], vec![]]);
+
+        let released = recover_stuck_modifiers(&mut injector, &[Modifier::Control], None);
+
+        assert!(released.is_empty());
+        assert_eq!(injector.events, vec!["settle"]);
+    }
+
+    #[test]
+    fn physically_held_modifier_is_left_alone() {
+        let down = vec![Modifier::Control, Modifier::Shift];
+        let mut injector = MockInjector::with_checks(vec![down.clone(), down]);
+        let physical = ModifierState {
+            ctrl: true,
+            ..ModifierState::default()
+        };
+
+        let released = recover_stuck_modifiers(
+            &mut injector,
+            &[Modifier::Control, Modifier::Shift],
+            Some(&physical),
+        );
+
+        assert_eq!(released, vec![Modifier::Shift]);
+    }
+
+    #[test]
+    fn only_synthesized_modifiers_are_checked() {
+        let mut injector = MockInjector::with_checks(vec![vec![Modifier::Meta]]);
+
+        let released = recover_stuck_modifiers(&mut injector, &[Modifier::Shift], None);
+
+        assert!(released.is_empty());
+        assert!(injector.events.is_empty());
+    }
+
+    #[test]
+    fn synthesized_modifiers_follow_paste_method_and_auto_submit() {
+        assert_eq!(
+            synthesized_modifiers(PasteMethod::ShiftInsert, Some(AutoSubmitKey::CtrlEnter)),
+            vec![Modifier::Shift, Modifier::Control]
+        );
+        assert!(synthesized_modifiers(PasteMethod::Direct, Some(AutoSubmitKey::Enter)).is_empty());
+        assert_eq!(
+            synthesized_modifiers(PasteMethod::CtrlShiftV, None).len(),
+            2
+        );
+    }
+}
@@ -84,6 +84,19 @@ pub fn segments_to_vtt(segments: &[SubtitleSegment]) -> String {
     output
 }
 
+/// Moves `segments` `offset` seconds earlier, for audio that had its start
+/// cut off. Times that would fall before zero are clamped to it.
+pub fn shift_segments(segments: &[SubtitleSegment], offset: f32) -> Vec<SubtitleSegment> {
+    segments
+        .iter()
+        .map(|seg| SubtitleSegment {
+            start: (seg.start - offset).max(0.0),
+            end: (seg.end - offset).max(0.0),
+            text: seg.text.clone(),
+        })
+        .collect()
+}
+
 /// Get the file extension for an output format
 pub fn get_format_extension(format: OutputFormat) -> &'static str {
     match format {
@@ -169,6 +182,26 @@ mod tests {
         assert!(vtt.contains("1\n00:00:00.000 --> 00:00:01.000\nHello world\n\n"));
     }
 
+    #[test]
+    fn test_shift_segments_moves_times_earlier_and_clamps_at_zero() {
+        let segments = vec![
+            SubtitleSegment {
+                start: 0.2,
+                end: 1.5,
+                text: "Hello".to_string(),
+            },
+            SubtitleSegment {
+                start: 1.5,
+                end: 3.0,
+                text: "world".to_string(),
+            },
+        ];
+        let shifted = shift_segments(&segments, 0.5);
+        assert_eq!((shifted[0].start, shifted[0].end), (0.0, 1.0));
+        assert_eq!((shifted[1].start, shifted[1].end), (1.0, 2.5));
+        assert_eq!(shifted[1].text, "world");
+    }
+
     #[test]
     fn test_get_format_extension_returns_expected_values() {
         assert_eq!(get_format_extension(OutputFormat::Text), "txt");
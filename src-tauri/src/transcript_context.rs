@@ -1,19 +1,59 @@
 use log::debug;
 use once_cell::sync::Lazy;
+use serde::Deserialize;
+use specta::Type;
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
+/// Profile ID used when no custom transcription profile is active.
+pub const DEFAULT_CONTEXT_PROFILE_ID: &str = "default";
+
 #[derive(Clone, Debug)]
 struct TranscriptEntry {
     text: String,
     last_updated: Instant,
 }
 
-static TRANSCRIPT_CONTEXT: Lazy<Mutex<HashMap<String, TranscriptEntry>>> =
+/// Context entries are keyed by (app name, profile ID).
+type ContextKey = (String, String);
+
+static TRANSCRIPT_CONTEXT: Lazy<Mutex<HashMap<ContextKey, TranscriptEntry>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
-pub fn get_short_prev_transcript(app_name: &str, max_words: usize, expiry: Duration) -> String {
+/// Which transcript context entries to drop.
+#[derive(Deserialize, Debug, Clone, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranscriptContextScope {
+    All,
+    App {
+        app_name: String,
+    },
+    AppProfile {
+        app_name: String,
+        profile_id: String,
+    },
+}
+
+fn context_key(app_name: &str, profile_id: &str) -> ContextKey {
+    let profile_id = if profile_id.trim().is_empty() {
+        DEFAULT_CONTEXT_PROFILE_ID
+    } else {
+        profile_id
+    };
+    (app_name.to_string(), profile_id.to_string())
+}
+
+/// Reads the recent transcript for `(app_name, profile_id)`.
+/// When `fallback_to_default` is set and the profile has no entry yet,
+/// the app's default-profile entry is used instead.
+pub fn get_short_prev_transcript(
+    app_name: &str,
+    profile_id: &str,
+    fallback_to_default: bool,
+    max_words: usize,
+    expiry: Duration,
+) -> String {
     if app_name.trim().is_empty() || max_words == 0 {
         return String::new();
     }
@@ -28,14 +68,23 @@ pub fn get_short_prev_transcript(app_name: &str, max_words: usize, expiry: Durat
 
     cleanup_expired_entries(&mut context, expiry);
 
-    context
-        .get(app_name)
+    let exact_key = context_key(app_name, profile_id);
+    let entry = context.get(&exact_key).or_else(|| {
+        if fallback_to_default && exact_key.1 != DEFAULT_CONTEXT_PROFILE_ID {
+            context.get(&context_key(app_name, DEFAULT_CONTEXT_PROFILE_ID))
+        } else {
+            None
+        }
+    });
+
+    entry
         .map(|entry| trim_to_last_words(&entry.text, max_words))
         .unwrap_or_default()
 }
 
 pub fn update_transcript_context(
     app_name: &str,
+    profile_id: &str,
     transcript: &str,
     max_words: usize,
     expiry: Duration,
@@ -56,7 +105,7 @@ pub fn update_transcript_context(
 
     let incoming = trim_to_last_words(transcript, max_words);
     let entry = context
-        .entry(app_name.to_string())
+        .entry(context_key(app_name, profile_id))
         .or_insert_with(|| TranscriptEntry {
             text: String::new(),
             last_updated: Instant::now(),
@@ -72,6 +121,81 @@ pub fn update_transcript_context(
     entry.last_updated = Instant::now();
 }
 
+/// Removes transcript context entries matching `scope`. Returns the number of entries removed.
+pub fn clear_transcript_context(scope: &TranscriptContextScope) -> usize {
+    let mut context = match TRANSCRIPT_CONTEXT.lock() {
+        Ok(guard) => guard,
+        Err(e) => {
+            debug!("Failed to lock transcript context for clear: {}", e);
+            return 0;
+        }
+    };
+
+    let before = context.len();
+    match scope {
+        TranscriptContextScope::All => context.clear(),
+        TranscriptContextScope::App { app_name } => {
+            context.retain(|(app, _), _| app != app_name);
+        }
+        TranscriptContextScope::AppProfile {
+            app_name,
+            profile_id,
+        } => {
+            context.remove(&context_key(app_name, profile_id));
+        }
+    }
+    before - context.len()
+}
+
+/// Removes every entry whose app name satisfies `should_remove`, across all
+/// profiles. Returns the number of entries removed.
+pub fn clear_transcript_context_where(should_remove: impl Fn(&str) -> bool) -> usize {
+    let mut context = match TRANSCRIPT_CONTEXT.lock() {
+        Ok(guard) => guard,
+        Err(e) => {
+            debug!("Failed to lock transcript context for clear: {}", e);
+            return 0;
+        }
+    };
+
+    let before = context.len();
+    context.retain(|(app, _), _| !should_remove(app));
+    before - context.len()
+}
+
+/// Whether `app_name` matches an entry of the transcript context blocklist
+/// (case-insensitive substring match).
+pub fn is_app_blocked(app_name: &str, blocklist: &[String]) -> bool {
+    let app_name = app_name.to_lowercase();
+    blocklist
+        .iter()
+        .map(|pattern| pattern.trim())
+        .filter(|pattern| !pattern.is_empty())
+        .any(|pattern| app_name.contains(&pattern.to_lowercase()))
+}
+
+/// Rewrites every stored context entry with `rewrite` (e.g. after the custom
+/// word list changed). Returns the number of entries whose text changed.
+pub fn rewrite_transcript_context(rewrite: impl Fn(&str) -> String) -> usize {
+    let mut context = match TRANSCRIPT_CONTEXT.lock() {
+        Ok(guard) => guard,
+        Err(e) => {
+            debug!("Failed to lock transcript context for rewrite: {}", e);
+            return 0;
+        }
+    };
+
+    let mut changed = 0;
+    for entry in context.values_mut() {
+        let rewritten = rewrite(&entry.text);
+        if rewritten != entry.text {
+            entry.text = rewritten;
+            changed += 1;
+        }
+    }
+    changed
+}
+
 fn trim_to_last_words(text: &str, max_words: usize) -> String {
     let words: Vec<&str> = text.split_whitespace().collect();
     if words.len() <= max_words {
@@ -81,8 +205,8 @@ fn trim_to_last_words(text: &str, max_words: usize) -> String {
     }
 }
 
-fn cleanup_expired_entries(context: &mut HashMap<String, TranscriptEntry>, expiry: Duration) {
-    let expired: Vec<String> = context
+fn cleanup_expired_entries(context: &mut HashMap<ContextKey, TranscriptEntry>, expiry: Duration) {
+    let expired: Vec<ContextKey> = context
         .iter()
         .filter(|(_, entry)| entry.last_updated.elapsed() >= expiry)
         .map(|(key, _)| key.clone())
@@ -92,3 +216,131 @@ fn cleanup_expired_entries(context: &mut HashMap<String, TranscriptEntry>, expir
         context.remove(&key);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPIRY: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn entries_are_isolated_per_profile() {
+        let app = "test-app-isolated";
+        update_transcript_context(app, "profile_formal", "dear sir", 10, EXPIRY);
+        update_transcript_context(app, "profile_casual", "hey there", 10, EXPIRY);
+
+        assert_eq!(
+            get_short_prev_transcript(app, "profile_formal", true, 10, EXPIRY),
+            "dear sir"
+        );
+        assert_eq!(
+            get_short_prev_transcript(app, "profile_casual", true, 10, EXPIRY),
+            "hey there"
+        );
+    }
+
+    #[test]
+    fn missing_profile_falls_back_to_default_only_when_enabled() {
+        let app = "test-app-fallback";
+        update_transcript_context(app, DEFAULT_CONTEXT_PROFILE_ID, "shared words", 10, EXPIRY);
+
+        assert_eq!(
+            get_short_prev_transcript(app, "profile_new", true, 10, EXPIRY),
+            "shared words"
+        );
+        assert_eq!(
+            get_short_prev_transcript(app, "profile_new", false, 10, EXPIRY),
+            ""
+        );
+    }
+
+    #[test]
+    fn empty_profile_id_maps_to_default() {
+        let app = "test-app-empty-profile";
+        update_transcript_context(app, "", "one two", 10, EXPIRY);
+
+        assert_eq!(
+            get_short_prev_transcript(app, DEFAULT_CONTEXT_PROFILE_ID, false, 10, EXPIRY),
+            "one two"
+        );
+    }
+
+    #[test]
+    fn updates_keep_word_clamp() {
+        let app = "test-app-clamp";
+        update_transcript_context(app, "p", "one two three", 4, EXPIRY);
+        update_transcript_context(app, "p", "four five", 4, EXPIRY);
+
+        assert_eq!(
+            get_short_prev_transcript(app, "p", false, 4, EXPIRY),
+            "two three four five"
+        );
+    }
+
+    #[test]
+    fn clear_scopes_remove_matching_entries() {
+        let app = "test-app-clear";
+        update_transcript_context(app, "a", "alpha", 10, EXPIRY);
+        update_transcript_context(app, "b", "beta", 10, EXPIRY);
+
+        let removed = clear_transcript_context(&TranscriptContextScope::AppProfile {
+            app_name: app.to_string(),
+            profile_id: "a".to_string(),
+        });
+        assert_eq!(removed, 1);
+        assert_eq!(get_short_prev_transcript(app, "a", false, 10, EXPIRY), "");
+        assert_eq!(
+            get_short_prev_transcript(app, "b", false, 10, EXPIRY),
+            "beta"
+        );
+
+        clear_transcript_context(&TranscriptContextScope::App {
+            app_name: app.to_string(),
+        });
+        assert_eq!(get_short_prev_transcript(app, "b", false, 10, EXPIRY), "");
+    }
+
+    #[test]
+    fn rewrite_updates_matching_entries() {
+        let app = "test-app-rewrite";
+        update_transcript_context(app, "p", "senor fernandez called", 10, EXPIRY);
+
+        let changed =
+            rewrite_transcript_context(|text| text.replace("senor fernandez", "Señor Fernández"));
+        assert!(changed >= 1);
+        assert_eq!(
+            get_short_prev_transcript(app, "p", false, 10, EXPIRY),
+            "Señor Fernández called"
+        );
+    }
+
+    #[test]
+    fn blocklist_matches_case_insensitive_substrings() {
+        let blocklist = vec![
+            "1password".to_string(),
+            "  ".to_string(),
+            "Journal".to_string(),
+        ];
+        assert!(is_app_blocked("1Password 8", &blocklist));
+        assert!(is_app_blocked("Day One Journal", &blocklist));
+        assert!(!is_app_blocked("Slack", &blocklist));
+        assert!(!is_app_blocked("Slack", &[]));
+    }
+
+    #[test]
+    fn clear_where_removes_all_profiles_of_matching_apps() {
+        update_transcript_context("test-app-vault", "default", "secret one", 10, EXPIRY);
+        update_transcript_context("test-app-vault", "profile_vault", "secret two", 10, EXPIRY);
+        update_transcript_context("test-app-notes", "default", "keep me", 10, EXPIRY);
+
+        let removed = clear_transcript_context_where(|app| app == "test-app-vault");
+        assert_eq!(removed, 2);
+        assert!(
+            get_short_prev_transcript("test-app-vault", "default", false, 10, EXPIRY).is_empty()
+        );
+        assert_eq!(
+            get_short_prev_transcript("test-app-notes", "default", false, 10, EXPIRY),
+            "keep me"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Transcription confidence.
+//!
+//! Providers that report token-level confidence (Soniox batch and realtime)
+//! are averaged into one score per take, which travels with the transcript
+//! to the history write. Providers without confidence data leave `None`,
+//! which never triggers the low-confidence warning.
+
+/// Running average of token confidences for one transcription.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ConfidenceAccumulator {
+    sum: f64,
+    count: u32,
+}
+
+impl ConfidenceAccumulator {
+    /// Adds a token confidence; values outside 0.0–1.0 or NaN are ignored.
+    pub fn push(&mut self, confidence: f32) {
+        if (0.0..=1.0).contains(&confidence) {
+            self.sum += f64::from(confidence);
+            self.count += 1;
+        }
+    }
+
+    /// Average confidence, or `None` if no token reported one.
+    pub fn average(&self) -> Option<f32> {
+        (self.count > 0).then(|| (self.sum / f64::from(self.count)) as f32)
+    }
+}
+
+/// Returns the score when it is known and falls below the warning threshold.
+/// A threshold of 0.0 turns the warning off.
+pub fn low_confidence(confidence: Option<f32>, threshold: f32) -> Option<f32> {
+    confidence.filter(|&score| threshold > 0.0 && score < threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulator_averages_valid_scores() {
+        let mut acc = ConfidenceAccumulator::default();
+        assert_eq!(acc.average(), None);
+        acc.push(0.5);
+        acc.push(1.0);
+        acc.push(f32::NAN);
+        acc.push(1.5);
+        assert_eq!(acc.average(), Some(0.75));
+    }
+
+    #[test]
+    fn low_confidence_needs_score_and_threshold() {
+        assert_eq!(low_confidence(Some(0.4), 0.6), Some(0.4));
+        assert_eq!(low_confidence(Some(0.8), 0.6), None);
+        assert_eq!(low_confidence(None, 0.6), None);
+        assert_eq!(low_confidence(Some(0.1), 0.0), None);
+    }
+}
@@ -8,7 +8,7 @@ use crate::url_security::{
     REMOTE_STT_PRESET_GROQ, REMOTE_STT_PRESET_OPENAI,
 };
 use crate::{commands::audio, settings};
-use log::{error, info, warn};
+use log::{debug, error, info, warn};
 use std::sync::{Arc, Mutex};
 use tauri::image::Image;
 use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
@@ -20,9 +20,12 @@ use tauri_plugin_clipboard_manager::ClipboardExt;
 pub enum TrayIconState {
     Idle,
     Recording,
+    Paused,
     Transcribing,
 }
 
+const TRAY_BLINK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(150);
+
 pub struct ManagedTrayState(pub Mutex<TrayIconState>);
 
 impl Default for ManagedTrayState {
@@ -48,8 +51,11 @@ const TRAY_MODEL_LOCAL_LABEL: &str = "Local";
 const TRAY_MODEL_REMOTE_LABEL: &str = "OpenAI-compatible";
 const TRAY_MODEL_SONIOX_LABEL: &str = "Soniox";
 const TRAY_MODEL_DEEPGRAM_LABEL: &str = "Deepgram";
+const TRAY_MODEL_OPENAI_REALTIME_LABEL: &str = "OpenAI Realtime";
 const TRAY_MODEL_NO_LOCAL_MODELS_LABEL: &str = "No downloaded local models";
 const TRAY_UNLOAD_LOCAL_MODEL_LABEL: &str = "Unload Local Model";
+pub const TRAY_PRIVACY_MODE_ID: &str = "privacy_mode";
+const TRAY_PRIVACY_MODE_LABEL: &str = "Privacy Mode";
 const TRAY_NO_LOCAL_MODEL_LOADED_LABEL: &str = "No Local Model Loaded";
 const TRAY_SHORTCUT_GUIDE_LABEL: &str = "Here are the keys you set in program:";
 pub const TRAY_SHORTCUT_GUIDE_SHOW_IN_MAIN_ID: &str = "tray_shortcut_guide_show_in_main";
@@ -62,6 +68,22 @@ const TRAY_MODEL_PREFIX_LOCAL: &str = "local";
 const TRAY_MODEL_PREFIX_REMOTE: &str = "remote_openai_compatible";
 const TRAY_MODEL_PREFIX_SONIOX: &str = "remote_soniox";
 const TRAY_MODEL_PREFIX_DEEPGRAM: &str = "remote_deepgram";
+const TRAY_MODEL_PREFIX_OPENAI_REALTIME: &str = "remote_openai_realtime";
+pub const TRAY_PROFILE_MENU_PREFIX: &str = "tray_profile::";
+const TRAY_PROFILES_SUBMENU_ID: &str = "tray_profiles_submenu";
+const TRAY_PROFILES_LABEL: &str = "Profiles";
+const TRAY_DEFAULT_PROFILE_LABEL: &str = "Default";
+pub const TRAY_POST_PROCESS_ID: &str = "tray_post_process_enabled";
+const TRAY_POST_PROCESS_LABEL: &str = "Post-Processing";
+pub const TRAY_PROVIDER_MENU_PREFIX: &str = "tray_transcription_provider::";
+const TRAY_PROVIDER_SUBMENU_ID: &str = "tray_transcription_provider_submenu";
+const TRAY_PROVIDER_LABEL: &str = "Transcription Provider";
+const TRAY_LAST_ERROR_ID: &str = "tray_last_error";
+const TRAY_LAST_ERROR_PREFIX: &str = "Last error: ";
+const TRAY_LAST_ERROR_MAX_CHARS: usize = 80;
+
+/// Most recent remote STT / AI Replace error, shown as a disabled menu item.
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum AppTheme {
@@ -120,20 +142,32 @@ pub fn get_icon_path(theme: AppTheme, state: TrayIconState) -> &'static str {
         // Dark theme uses light icons
         (AppTheme::Dark, TrayIconState::Idle) => "resources/aivo_tray.png",
         (AppTheme::Dark, TrayIconState::Recording) => "resources/tray_recording.png",
+        (AppTheme::Dark, TrayIconState::Paused) => "resources/tray_paused.png",
         (AppTheme::Dark, TrayIconState::Transcribing) => "resources/tray_transcribing.png",
         // Light theme uses dark icons
         (AppTheme::Light, TrayIconState::Idle) => "resources/aivo_tray.png",
         (AppTheme::Light, TrayIconState::Recording) => "resources/tray_recording_dark.png",
+        (AppTheme::Light, TrayIconState::Paused) => "resources/tray_paused_dark.png",
         (AppTheme::Light, TrayIconState::Transcribing) => "resources/tray_transcribing_dark.png",
         // Colored theme uses pink icons (for Linux)
         (AppTheme::Colored, TrayIconState::Idle) => "resources/aivo_tray.png",
         (AppTheme::Colored, TrayIconState::Recording) => "resources/recording.png",
+        (AppTheme::Colored, TrayIconState::Paused) => "resources/paused.png",
         (AppTheme::Colored, TrayIconState::Transcribing) => "resources/transcribing.png",
     }
 }
 
 pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
-    let tray = app.state::<TrayIcon>();
+    apply_tray_icon_image(app, &icon);
+
+    // Update menu based on state
+    update_tray_menu(app, &icon, None);
+}
+
+fn apply_tray_icon_image(app: &AppHandle, icon: &TrayIconState) {
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let theme = get_current_theme(app);
 
     let icon_path = get_icon_path(theme, icon.clone());
@@ -153,9 +187,20 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
             warn!("Failed to update tray icon '{}': {}", icon_path, e);
         }
     }
+}
 
-    // Update menu based on state
-    update_tray_menu(app, &icon, None);
+/// Briefly flashes the tray icon twice as a silent cue, then restores the
+/// icon for the current state. The tray state and menu are left untouched.
+pub fn blink_tray_icon(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        for _ in 0..2 {
+            apply_tray_icon_image(&app, &TrayIconState::Transcribing);
+            std::thread::sleep(TRAY_BLINK_INTERVAL);
+            apply_tray_icon_image(&app, &current_tray_state(&app));
+            std::thread::sleep(TRAY_BLINK_INTERVAL);
+        }
+    });
 }
 
 /// Re-applies the current state when the appearance changed without changing
@@ -165,7 +210,10 @@ pub fn refresh_tray_icon(app: &AppHandle) {
 }
 
 pub fn tray_tooltip() -> String {
-    version_label()
+    match crate::portable::profile_name() {
+        Some(profile) => format!("{} [{}]", version_label(), profile),
+        None => version_label(),
+    }
 }
 
 fn version_label() -> String {
@@ -188,6 +236,74 @@ pub fn refresh_tray_menu(app: &AppHandle, locale: Option<&str>) {
     update_tray_menu(app, &state, locale);
 }
 
+/// Rebuilds the menu after a settings or profile change. Skipped while
+/// recording so the menu doesn't flicker mid-dictation; the state change at
+/// the end of the session rebuilds it anyway.
+pub fn refresh_tray_menu_unless_recording(app: &AppHandle) {
+    if matches!(
+        current_tray_state(app),
+        TrayIconState::Recording | TrayIconState::Paused
+    ) {
+        debug!("Skipping tray menu rebuild while recording");
+        return;
+    }
+    refresh_tray_menu(app, None);
+}
+
+/// Remembers the error carried by a `remote-stt-error` / `ai-replace-error`
+/// event payload for the tray's last-error item.
+pub fn record_last_error(app: &AppHandle, payload: &str) {
+    let Some(message) = error_message_from_payload(payload) else {
+        return;
+    };
+    match LAST_ERROR.lock() {
+        Ok(mut last_error) => *last_error = Some(message),
+        Err(err) => {
+            warn!("Failed to lock tray last error: {}", err);
+            return;
+        }
+    }
+    refresh_tray_menu_unless_recording(app);
+}
+
+/// Error events carry either a bare string or an object with a `message`.
+fn error_message_from_payload(payload: &str) -> Option<String> {
+    let message = match serde_json::from_str::<serde_json::Value>(payload) {
+        Ok(serde_json::Value::String(message)) => message,
+        Ok(serde_json::Value::Object(object)) => object
+            .get("message")
+            .and_then(|message| message.as_str())
+            .map(str::to_string)?,
+        _ => payload.to_string(),
+    };
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!message.is_empty()).then_some(message)
+}
+
+fn last_error_label(message: &str) -> String {
+    let mut label = String::from(TRAY_LAST_ERROR_PREFIX);
+    if message.chars().count() > TRAY_LAST_ERROR_MAX_CHARS {
+        label.extend(message.chars().take(TRAY_LAST_ERROR_MAX_CHARS - 1));
+        label.push('…');
+    } else {
+        label.push_str(message);
+    }
+    label
+}
+
+pub fn parse_profile_menu_selection(id: &str) -> Option<String> {
+    id.strip_prefix(TRAY_PROFILE_MENU_PREFIX)
+        .filter(|profile_id| !profile_id.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Returns the provider value accepted by `change_transcription_provider_setting`.
+pub fn parse_provider_menu_selection(id: &str) -> Option<String> {
+    id.strip_prefix(TRAY_PROVIDER_MENU_PREFIX)
+        .filter(|provider| !provider.trim().is_empty())
+        .map(str::to_string)
+}
+
 pub fn parse_microphone_menu_selection(id: &str) -> Option<Option<String>> {
     if id == TRAY_MICROPHONE_DEFAULT_ID {
         Some(None)
@@ -285,6 +401,14 @@ fn try_update_tray_menu(
         true,
         None::<&str>,
     )?;
+    let privacy_mode_i = CheckMenuItem::with_id(
+        app,
+        TRAY_PRIVACY_MODE_ID,
+        TRAY_PRIVACY_MODE_LABEL,
+        true,
+        crate::privacy_mode::is_active(),
+        None::<&str>,
+    )?;
     let model_loaded = app.state::<Arc<TranscriptionManager>>().is_model_loaded();
     let local_model_selected = settings.transcription_provider == TranscriptionProvider::Local
         && !settings.selected_model.trim().is_empty();
@@ -306,13 +430,22 @@ fn try_update_tray_menu(
         None::<&str>,
     )?;
     let model_menu_label = build_model_menu_label(app, &settings, &strings.model);
+    let post_process_i = CheckMenuItem::with_id(
+        app,
+        TRAY_POST_PROCESS_ID,
+        TRAY_POST_PROCESS_LABEL,
+        true,
+        settings.post_process_enabled,
+        None::<&str>,
+    )?;
+    let last_error = LAST_ERROR.lock().ok().and_then(|error| error.clone());
     let quit_i = MenuItem::with_id(app, "quit", &strings.quit, true, quit_accelerator)?;
     let separator = || PredefinedMenuItem::separator(app);
 
     let menu = Menu::new(app)?;
 
     match state {
-        TrayIconState::Recording | TrayIconState::Transcribing => {
+        TrayIconState::Recording | TrayIconState::Paused | TrayIconState::Transcribing => {
             let cancel_i = MenuItem::with_id(app, "cancel", &strings.cancel, true, None::<&str>)?;
             menu.append(&version_i)?;
             menu.append(&separator()?)?;
@@ -322,15 +455,32 @@ fn try_update_tray_menu(
             menu.append(&version_i)?;
         }
     }
+    if let Some(message) = last_error {
+        let last_error_i = MenuItem::with_id(
+            app,
+            TRAY_LAST_ERROR_ID,
+            last_error_label(&message),
+            false,
+            None::<&str>,
+        )?;
+        menu.append(&last_error_i)?;
+    }
 
     menu.append(&separator()?)?;
     append_microphone_items(&menu, app, settings.selected_microphone.as_deref())?;
     menu.append(&separator()?)?;
     menu.append(&copy_last_transcript_i)?;
+    menu.append(&privacy_mode_i)?;
 
     if state == &TrayIconState::Idle {
+        let profiles_submenu = build_profiles_submenu(app, &settings)?;
+        let provider_submenu = build_provider_submenu(app, &settings)?;
         let model_submenu = build_model_submenu(app, &model_menu_label, &settings)?;
         menu.append(&separator()?)?;
+        menu.append(&profiles_submenu)?;
+        menu.append(&post_process_i)?;
+        menu.append(&separator()?)?;
+        menu.append(&provider_submenu)?;
         menu.append(&model_submenu)?;
         menu.append(&unload_model_i)?;
         if settings.show_tray_shortcut_guide {
@@ -397,6 +547,12 @@ fn build_model_menu_label(
         TranscriptionProvider::RemoteDeepgram => {
             format!("{TRAY_MODEL_DEEPGRAM_LABEL}: {}", settings.deepgram_model)
         }
+        TranscriptionProvider::RemoteOpenAiRealtime => {
+            format!(
+                "{TRAY_MODEL_OPENAI_REALTIME_LABEL}: {}",
+                settings.openai_realtime_model
+            )
+        }
     }
 }
 
@@ -520,6 +676,86 @@ fn build_model_submenu(
     Ok(submenu)
 }
 
+fn build_profiles_submenu(
+    app: &AppHandle,
+    settings: &settings::AppSettings,
+) -> Result<Submenu<tauri::Wry>, Box<dyn std::error::Error>> {
+    let submenu = Submenu::with_id(app, TRAY_PROFILES_SUBMENU_ID, TRAY_PROFILES_LABEL, true)?;
+    let profiles = std::iter::once(("default", TRAY_DEFAULT_PROFILE_LABEL)).chain(
+        settings
+            .transcription_profiles
+            .iter()
+            .map(|profile| (profile.id.as_str(), profile.name.as_str())),
+    );
+    for (id, name) in profiles {
+        let item = CheckMenuItem::with_id(
+            app,
+            format!("{TRAY_PROFILE_MENU_PREFIX}{id}"),
+            name,
+            true,
+            settings.active_profile_id == id,
+            None::<&str>,
+        )?;
+        submenu.append(&item)?;
+    }
+
+    Ok(submenu)
+}
+
+fn build_provider_submenu(
+    app: &AppHandle,
+    settings: &settings::AppSettings,
+) -> Result<Submenu<tauri::Wry>, Box<dyn std::error::Error>> {
+    let submenu = Submenu::with_id(app, TRAY_PROVIDER_SUBMENU_ID, TRAY_PROVIDER_LABEL, true)?;
+    // Remote providers are Windows-only; change_transcription_provider_setting rejects them elsewhere.
+    let remote_available = cfg!(target_os = "windows");
+    let providers = [
+        (
+            TRAY_MODEL_PREFIX_LOCAL,
+            TRAY_MODEL_LOCAL_LABEL,
+            TranscriptionProvider::Local,
+            true,
+        ),
+        (
+            TRAY_MODEL_PREFIX_REMOTE,
+            TRAY_MODEL_REMOTE_LABEL,
+            TranscriptionProvider::RemoteOpenAiCompatible,
+            remote_available,
+        ),
+        (
+            TRAY_MODEL_PREFIX_SONIOX,
+            TRAY_MODEL_SONIOX_LABEL,
+            TranscriptionProvider::RemoteSoniox,
+            remote_available,
+        ),
+        (
+            TRAY_MODEL_PREFIX_DEEPGRAM,
+            TRAY_MODEL_DEEPGRAM_LABEL,
+            TranscriptionProvider::RemoteDeepgram,
+            remote_available,
+        ),
+        (
+            TRAY_MODEL_PREFIX_OPENAI_REALTIME,
+            TRAY_MODEL_OPENAI_REALTIME_LABEL,
+            TranscriptionProvider::RemoteOpenAiRealtime,
+            remote_available,
+        ),
+    ];
+    for (value, label, provider, enabled) in providers {
+        let item = CheckMenuItem::with_id(
+            app,
+            format!("{TRAY_PROVIDER_MENU_PREFIX}{value}"),
+            label,
+            enabled,
+            settings.transcription_provider == provider,
+            None::<&str>,
+        )?;
+        submenu.append(&item)?;
+    }
+
+    Ok(submenu)
+}
+
 fn append_submenu_header(
     submenu: &Submenu<tauri::Wry>,
     app: &AppHandle,
@@ -919,10 +1155,12 @@ pub fn copy_last_transcript(app: &AppHandle) {
 #[cfg(test)]
 mod tests {
     use super::{
-        get_icon_path, last_transcript_text, parse_microphone_menu_selection,
-        parse_model_menu_selection, tray_tooltip, AppTheme, TrayIconState, TrayModelSelection,
-        TRAY_MICROPHONE_DEFAULT_ID, TRAY_MICROPHONE_MENU_PREFIX, TRAY_MICROPHONE_MISSING_ID,
-        TRAY_MODEL_MENU_PREFIX,
+        error_message_from_payload, get_icon_path, last_error_label, last_transcript_text,
+        parse_microphone_menu_selection, parse_model_menu_selection, parse_profile_menu_selection,
+        parse_provider_menu_selection, tray_tooltip, AppTheme, TrayIconState, TrayModelSelection,
+        TRAY_LAST_ERROR_MAX_CHARS, TRAY_LAST_ERROR_PREFIX, TRAY_MICROPHONE_DEFAULT_ID,
+        TRAY_MICROPHONE_MENU_PREFIX, TRAY_MICROPHONE_MISSING_ID, TRAY_MODEL_MENU_PREFIX,
+        TRAY_PROFILE_MENU_PREFIX, TRAY_PROVIDER_MENU_PREFIX,
     };
     use crate::managers::history::HistoryEntry;
 
@@ -937,9 +1175,22 @@ mod tests {
             post_processed_text: post_processed.map(|text| text.to_string()),
             post_process_prompt: None,
             post_process_requested: false,
+            post_process_note: None,
+            corrected_text: None,
+            reference_text: None,
+            stt_provider: None,
+            post_process_provider: None,
+            post_process_raw_response: None,
+            post_process_chunked: false,
             action_type: "transcribe".to_string(),
             original_selection: None,
             ai_response: None,
+            ai_response_rejected: false,
+            session_group_id: None,
+            profile_id: None,
+            target_app: None,
+            confidence: None,
+            translation_languages: Vec::new(),
         }
     }
 
@@ -965,6 +1216,10 @@ mod tests {
             get_icon_path(AppTheme::Dark, TrayIconState::Recording),
             "resources/tray_recording.png"
         );
+        assert_eq!(
+            get_icon_path(AppTheme::Dark, TrayIconState::Paused),
+            "resources/tray_paused.png"
+        );
         assert_eq!(
             get_icon_path(AppTheme::Dark, TrayIconState::Transcribing),
             "resources/tray_transcribing.png"
@@ -977,6 +1232,10 @@ mod tests {
             get_icon_path(AppTheme::Light, TrayIconState::Recording),
             "resources/tray_recording_dark.png"
         );
+        assert_eq!(
+            get_icon_path(AppTheme::Light, TrayIconState::Paused),
+            "resources/tray_paused_dark.png"
+        );
         assert_eq!(
             get_icon_path(AppTheme::Light, TrayIconState::Transcribing),
             "resources/tray_transcribing_dark.png"
@@ -985,6 +1244,10 @@ mod tests {
             get_icon_path(AppTheme::Colored, TrayIconState::Recording),
             "resources/recording.png"
         );
+        assert_eq!(
+            get_icon_path(AppTheme::Colored, TrayIconState::Paused),
+            "resources/paused.png"
+        );
         assert_eq!(
             get_icon_path(AppTheme::Colored, TrayIconState::Transcribing),
             "resources/transcribing.png"
@@ -1045,4 +1308,59 @@ mod tests {
         );
         assert_eq!(parse_model_menu_selection("some-other-id"), None);
     }
+
+    #[test]
+    fn parse_profile_and_provider_menu_selections() {
+        assert_eq!(
+            parse_profile_menu_selection(&format!("{TRAY_PROFILE_MENU_PREFIX}default")).as_deref(),
+            Some("default")
+        );
+        assert_eq!(
+            parse_profile_menu_selection(&format!("{TRAY_PROFILE_MENU_PREFIX}profile_17"))
+                .as_deref(),
+            Some("profile_17")
+        );
+        assert_eq!(parse_profile_menu_selection(TRAY_PROFILE_MENU_PREFIX), None);
+        assert_eq!(parse_profile_menu_selection("settings"), None);
+
+        assert_eq!(
+            parse_provider_menu_selection(&format!("{TRAY_PROVIDER_MENU_PREFIX}remote_soniox"))
+                .as_deref(),
+            Some("remote_soniox")
+        );
+        assert_eq!(
+            parse_provider_menu_selection(TRAY_PROVIDER_MENU_PREFIX),
+            None
+        );
+    }
+
+    #[test]
+    fn error_message_is_read_from_string_and_object_payloads() {
+        assert_eq!(
+            error_message_from_payload(r#""Soniox API key is missing""#).as_deref(),
+            Some("Soniox API key is missing")
+        );
+        assert_eq!(
+            error_message_from_payload(
+                r#"{"message":"HTTP 429:\n rate limited","retry_action":{"command":"retry"}}"#
+            )
+            .as_deref(),
+            Some("HTTP 429: rate limited")
+        );
+        assert_eq!(error_message_from_payload(r#""  ""#), None);
+        assert_eq!(error_message_from_payload(r#"{"retry_action":null}"#), None);
+    }
+
+    #[test]
+    fn last_error_label_is_truncated() {
+        assert_eq!(last_error_label("timeout"), "Last error: timeout");
+
+        let label = last_error_label(&"x".repeat(200));
+        assert!(label.starts_with(TRAY_LAST_ERROR_PREFIX));
+        assert_eq!(
+            label.chars().count(),
+            TRAY_LAST_ERROR_PREFIX.chars().count() + TRAY_LAST_ERROR_MAX_CHARS
+        );
+        assert!(label.ends_with('…'));
+    }
 }
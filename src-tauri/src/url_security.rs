@@ -21,6 +21,9 @@ pub const LLM_ANTHROPIC_BASE_URL: &str = "https://api.anthropic.com/v1";
 pub const LLM_GROQ_BASE_URL: &str = "https://api.groq.com/openai/v1";
 pub const LLM_CEREBRAS_BASE_URL: &str = "https://api.cerebras.ai/v1";
 
+pub const SEARCH_QUERY_PLACEHOLDER: &str = "${query}";
+pub const DEFAULT_SEARCH_URL_TEMPLATE: &str = "https://www.google.com/search?q=${query}";
+
 fn parse_network_url(input: &str, context: &str) -> Result<Url, String> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -131,6 +134,66 @@ pub fn canonical_llm_provider_base_url(provider: &PostProcessProvider) -> Result
     }
 }
 
+/// Percent-encodes everything except RFC 3986 unreserved characters, so the
+/// result is safe inside a query component.
+fn encode_query_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Checks that a search URL template contains `${query}` and yields an
+/// http(s) URL. Returns the trimmed template.
+pub fn validate_search_url_template(template: &str) -> Result<String, String> {
+    let trimmed = template.trim();
+    if !trimmed.contains(SEARCH_QUERY_PLACEHOLDER) {
+        return Err(format!(
+            "Search URL template must contain {}.",
+            SEARCH_QUERY_PLACEHOLDER
+        ));
+    }
+
+    let url = parse_network_url(
+        &trimmed.replace(SEARCH_QUERY_PLACEHOLDER, "test"),
+        "Search URL template",
+    )?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err("Search URL template must start with http:// or https://.".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Checks that a webhook URL is an absolute http(s) URL and returns it trimmed.
+/// Plain HTTP is allowed since note systems often listen on localhost or a LAN.
+/// The OpenAI Realtime provider only talks to HTTPS endpoints.
+pub fn validate_openai_realtime_base_url(input: &str) -> Result<String, String> {
+    validate_network_base_url(input, false, "OpenAI Realtime base URL")
+}
+
+pub fn validate_webhook_url(input: &str) -> Result<String, String> {
+    let url = parse_network_url(input, "Webhook URL")?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err("Webhook URL must start with http:// or https://.".to_string());
+    }
+    Ok(input.trim().to_string())
+}
+
+/// Builds the search URL for `query` from `template`.
+pub fn build_search_url(template: &str, query: &str) -> Result<String, String> {
+    let template = validate_search_url_template(template)?;
+    Ok(template.replace(
+        SEARCH_QUERY_PLACEHOLDER,
+        &encode_query_component(query.trim()),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +210,8 @@ mod tests {
             model_id: "test-model".to_string(),
             debug_capture: false,
             debug_mode: crate::settings::RemoteSttDebugMode::Normal,
+            auth_style: crate::settings::ApiAuthStyle::Bearer,
+            extra_query_params: Default::default(),
         }
     }
 
@@ -158,6 +223,8 @@ mod tests {
             allow_base_url_edit: true,
             allow_insecure_http,
             models_endpoint: None,
+            auth_style: crate::settings::ApiAuthStyle::Bearer,
+            extra_query_params: Default::default(),
         }
     }
 
@@ -363,4 +430,41 @@ mod tests {
         assert!(error.contains("LLM provider base URL"));
         assert!(error.contains("must use HTTPS"));
     }
+
+    #[test]
+    fn search_url_encodes_query() {
+        assert_eq!(
+            build_search_url(DEFAULT_SEARCH_URL_TEMPLATE, " rust & tauri: 100% café? ").unwrap(),
+            "https://www.google.com/search?q=rust%20%26%20tauri%3A%20100%25%20caf%C3%A9%3F"
+        );
+    }
+
+    #[test]
+    fn search_url_template_requires_placeholder_and_web_scheme() {
+        assert!(validate_search_url_template("https://duckduckgo.com/?q=${query}").is_ok());
+        assert!(validate_search_url_template("https://duckduckgo.com/?q=").is_err());
+        assert!(validate_search_url_template("file:///tmp/${query}").is_err());
+        assert!(validate_search_url_template("javascript:alert(${query})").is_err());
+    }
+
+    #[test]
+    fn webhook_url_accepts_http_and_https_only() {
+        assert_eq!(
+            validate_webhook_url("  http://localhost:8080/notes  ").unwrap(),
+            "http://localhost:8080/notes"
+        );
+        assert!(validate_webhook_url("https://notes.example.com/hook").is_ok());
+        assert!(validate_webhook_url("ftp://notes.example.com/hook").is_err());
+        assert!(validate_webhook_url("").is_err());
+    }
+
+    #[test]
+    fn openai_realtime_base_url_requires_https() {
+        assert_eq!(
+            validate_openai_realtime_base_url(" https://proxy.example.com/v1/ ").unwrap(),
+            "https://proxy.example.com/v1"
+        );
+        assert!(validate_openai_realtime_base_url("http://proxy.example.com/v1").is_err());
+        assert!(validate_openai_realtime_base_url("not a url").is_err());
+    }
 }
@@ -2,6 +2,7 @@ use crate::managers::audio::AudioRecordingManager;
 use crate::managers::deepgram_realtime::DeepgramRealtimeManager;
 use crate::managers::deepgram_stt::DeepgramSttManager;
 use crate::managers::llm_operation::LlmOperationTracker;
+use crate::managers::openai_realtime::OpenAiRealtimeManager;
 use crate::managers::openai_realtime_whisper::OpenAiRealtimeWhisperManager;
 use crate::managers::remote_stt::RemoteSttManager;
 use crate::managers::soniox_realtime::SonioxRealtimeManager;
@@ -123,6 +124,8 @@ pub fn cancel_current_operation(app: &AppHandle) {
     remote_stt_manager.cancel();
     let openai_realtime_whisper_manager = app.state::<Arc<OpenAiRealtimeWhisperManager>>();
     openai_realtime_whisper_manager.cancel();
+    let openai_realtime_manager = app.state::<Arc<OpenAiRealtimeManager>>();
+    openai_realtime_manager.cancel();
     let soniox_live_manager = app.state::<Arc<SonioxRealtimeManager>>();
     soniox_live_manager.cancel();
     let soniox_stt_manager = app.state::<Arc<SonioxSttManager>>();
@@ -132,6 +135,8 @@ pub fn cancel_current_operation(app: &AppHandle) {
     let deepgram_stt_manager = app.state::<Arc<DeepgramSttManager>>();
     deepgram_stt_manager.cancel();
     audio_manager.clear_stream_frame_callback();
+    crate::actions::cancel_local_streaming_previews(app);
+    crate::clipboard::cancel_typing();
     if let Err(e) = crate::clipboard::end_streaming_paste_session(app) {
         warn!(
             "Failed to end streaming clipboard session during cancellation: {}",
@@ -142,10 +147,12 @@ pub fn cancel_current_operation(app: &AppHandle) {
     // Cancel any in-flight LLM requests (AI Replace, etc.)
     let llm_tracker = app.state::<Arc<LlmOperationTracker>>();
     llm_tracker.cancel();
+    crate::ai_replace_confirm::resolve(crate::ai_replace_confirm::AiReplaceDecision::Rejected);
 
     // Ensure UI is in idle state (redundant if session Drop ran, but safe)
     change_tray_icon(app, crate::tray::TrayIconState::Idle);
     hide_recording_overlay(app);
+    crate::accessibility_announcer::announce_cancelled(app, &crate::settings::get_settings(app));
     if crate::managers::preview_output_mode::is_active() {
         crate::managers::preview_output_mode::deactivate_session(app);
         crate::overlay::end_live_preview_session();
@@ -0,0 +1,235 @@
+//! Remembered position of the floating voice activation button.
+//!
+//! Drags are captured from the window's move events on the Rust side and
+//! saved per monitor layout, so plugging a laptop into a dock and back
+//! restores the right spot for each setup. Saves are debounced: a drag only
+//! writes settings once the window has been still for a moment. Moves made by
+//! the app itself (initial placement, reset) are not recorded.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::settings::{self, VoiceButtonPosition, WriteCoalescer};
+
+/// Quiet time after the last move event before the position is saved.
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(600);
+/// Move events this soon after an app-initiated move are ignored.
+const PROGRAMMATIC_MOVE_GRACE_MS: i64 = 500;
+/// Monitor layouts remembered at most; the least recently used is dropped.
+const MAX_SAVED_LAYOUTS: usize = 8;
+
+static IGNORE_MOVES_UNTIL_MS: AtomicI64 = AtomicI64::new(0);
+static POSITION_WRITER: OnceLock<WriteCoalescer<(f64, f64)>> = OnceLock::new();
+
+/// Monitor the button opens on when no position is saved for the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorPreference {
+    Cursor,
+    Primary,
+    Index(usize),
+}
+
+impl MonitorPreference {
+    /// Parses the `voice_button_monitor` setting: "cursor", "primary" or a
+    /// zero-based display index.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "cursor" => Some(Self::Cursor),
+            "primary" => Some(Self::Primary),
+            index => index.parse().ok().map(Self::Index),
+        }
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// FNV-1a over the sorted monitor rectangles and scale factors. Stable across
+/// runs and Rust versions, unlike `DefaultHasher`.
+fn layout_key_from_rects(rects: &mut [(i32, i32, u32, u32, f64)]) -> String {
+    rects.sort_by_key(|&(x, y, width, height, _)| (x, y, width, height));
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (x, y, width, height, scale) in rects.iter() {
+        let part = format!("{},{},{}x{}@{:.2};", x, y, width, height, scale);
+        for byte in part.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Identifies the current set of monitors and their arrangement.
+pub fn layout_key(monitors: &[tauri::Monitor]) -> String {
+    let mut rects: Vec<_> = monitors
+        .iter()
+        .map(|monitor| {
+            (
+                monitor.position().x,
+                monitor.position().y,
+                monitor.size().width,
+                monitor.size().height,
+                monitor.scale_factor(),
+            )
+        })
+        .collect();
+    layout_key_from_rects(&mut rects)
+}
+
+/// Position saved for `layout`, or the most recently saved one for any other
+/// layout. The caller clamps it onto a visible work area.
+pub fn saved_position(
+    positions: &HashMap<String, VoiceButtonPosition>,
+    layout: &str,
+) -> Option<VoiceButtonPosition> {
+    positions
+        .get(layout)
+        .or_else(|| positions.values().max_by_key(|p| p.saved_at))
+        .copied()
+}
+
+/// Moves `(x, y)` so a `width` x `height` window lies inside the area.
+pub fn clamp_into(
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    area: (f64, f64, f64, f64),
+) -> (f64, f64) {
+    let (area_x, area_y, area_width, area_height) = area;
+    let max_x = (area_x + area_width - width).max(area_x);
+    let max_y = (area_y + area_height - height).max(area_y);
+    (x.clamp(area_x, max_x), y.clamp(area_y, max_y))
+}
+
+/// Call before the app itself moves or creates the button window.
+pub fn suppress_move_tracking() {
+    IGNORE_MOVES_UNTIL_MS.store(now_ms() + PROGRAMMATIC_MOVE_GRACE_MS, Ordering::Relaxed);
+}
+
+fn store_position(app: &AppHandle, x: f64, y: f64) {
+    let Ok(monitors) = app.available_monitors() else {
+        return;
+    };
+    let layout = layout_key(&monitors);
+    let mut settings = settings::get_settings(app);
+    settings.voice_button_positions.insert(
+        layout,
+        VoiceButtonPosition {
+            x,
+            y,
+            saved_at: now_ms(),
+        },
+    );
+    while settings.voice_button_positions.len() > MAX_SAVED_LAYOUTS {
+        let oldest = settings
+            .voice_button_positions
+            .iter()
+            .min_by_key(|(_, p)| p.saved_at)
+            .map(|(key, _)| key.clone());
+        match oldest {
+            Some(key) => settings.voice_button_positions.remove(&key),
+            None => break,
+        };
+    }
+    settings::write_settings(app, settings);
+}
+
+fn position_writer(app: &AppHandle) -> &'static WriteCoalescer<(f64, f64)> {
+    POSITION_WRITER.get_or_init(|| {
+        let app = app.clone();
+        WriteCoalescer::new(SAVE_DEBOUNCE, move |(x, y)| store_position(&app, x, y))
+    })
+}
+
+/// Records a move of the button window (logical coordinates).
+pub fn record_move(app: &AppHandle, x: f64, y: f64) {
+    if now_ms() < IGNORE_MOVES_UNTIL_MS.load(Ordering::Relaxed) {
+        return;
+    }
+    position_writer(app).submit((x, y));
+}
+
+/// Saves a drag that is still inside the debounce window. Call before exit.
+pub fn flush(app: &AppHandle) {
+    if POSITION_WRITER.get().is_some() {
+        position_writer(app).flush();
+    }
+}
+
+/// Forgets every saved position.
+pub fn reset(app: &AppHandle) {
+    flush(app);
+    let mut settings = settings::get_settings(app);
+    if settings.voice_button_positions.is_empty() {
+        return;
+    }
+    settings.voice_button_positions.clear();
+    settings::write_settings(app, settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(x: f64, saved_at: i64) -> VoiceButtonPosition {
+        VoiceButtonPosition {
+            x,
+            y: 0.0,
+            saved_at,
+        }
+    }
+
+    #[test]
+    fn layout_key_ignores_monitor_order() {
+        let mut a = [(0, 0, 1920, 1080, 1.0), (1920, 0, 2560, 1440, 1.5)];
+        let mut b = [(1920, 0, 2560, 1440, 1.5), (0, 0, 1920, 1080, 1.0)];
+        assert_eq!(layout_key_from_rects(&mut a), layout_key_from_rects(&mut b));
+
+        let mut laptop_only = [(0, 0, 1920, 1080, 1.0)];
+        assert_ne!(
+            layout_key_from_rects(&mut a),
+            layout_key_from_rects(&mut laptop_only)
+        );
+    }
+
+    #[test]
+    fn saved_position_prefers_layout_then_most_recent() {
+        let mut positions = HashMap::new();
+        positions.insert("docked".to_string(), position(10.0, 100));
+        positions.insert("laptop".to_string(), position(20.0, 50));
+
+        assert_eq!(saved_position(&positions, "laptop").unwrap().x, 20.0);
+        assert_eq!(saved_position(&positions, "projector").unwrap().x, 10.0);
+        assert!(saved_position(&HashMap::new(), "laptop").is_none());
+    }
+
+    #[test]
+    fn clamp_pulls_off_screen_positions_into_the_work_area() {
+        let area = (0.0, 0.0, 1920.0, 1040.0);
+        assert_eq!(clamp_into(3000.0, 500.0, 80.0, 80.0, area), (1840.0, 500.0));
+        assert_eq!(clamp_into(-200.0, -5.0, 80.0, 80.0, area), (0.0, 0.0));
+        assert_eq!(clamp_into(100.0, 1100.0, 80.0, 80.0, area), (100.0, 960.0));
+    }
+
+    #[test]
+    fn monitor_preference_parses_names_and_indexes() {
+        assert_eq!(
+            MonitorPreference::parse("cursor"),
+            Some(MonitorPreference::Cursor)
+        );
+        assert_eq!(
+            MonitorPreference::parse("primary"),
+            Some(MonitorPreference::Primary)
+        );
+        assert_eq!(
+            MonitorPreference::parse("1"),
+            Some(MonitorPreference::Index(1))
+        );
+        assert_eq!(MonitorPreference::parse("left"), None);
+    }
+}
@@ -0,0 +1,695 @@
+//! Fuzzy matching of transcriptions against voice command trigger phrases.
+//!
+//! Each transcription word is compared with every trigger word using a hybrid
+//! of Levenshtein similarity (typos) and Soundex (pronunciation). The phrase
+//! score combines how many transcription words matched (coverage), how well
+//! they matched (quality) and how close the word counts are (length ratio).
+
+use natural::phonetics::soundex;
+use serde::Serialize;
+use specta::Type;
+use strsim::normalized_levenshtein;
+
+use crate::settings::{AppSettings, VoiceCommand};
+
+/// Weight of word coverage in the final score.
+const COVERAGE_WEIGHT: f64 = 0.7;
+/// Weight of match quality (scaled by coverage) in the final score.
+const QUALITY_WEIGHT: f64 = 0.2;
+/// Weight of the word-count ratio in the final score.
+const LENGTH_WEIGHT: f64 = 0.1;
+
+/// Configuration for the hybrid fuzzy matching algorithm
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct FuzzyMatchConfig {
+    /// Whether to use Levenshtein distance for character-level matching
+    pub use_levenshtein: bool,
+    /// Per-word Levenshtein threshold (0.0-1.0, lower = more tolerant of typos)
+    pub levenshtein_threshold: f64,
+    /// Whether to use phonetic (Soundex) matching
+    pub use_phonetic: bool,
+    /// Phonetic match boost multiplier (0.0-1.0)
+    pub phonetic_boost: f64,
+    /// Word similarity threshold - minimum score for a word pair to be considered matching
+    pub word_similarity_threshold: f64,
+}
+
+impl Default for FuzzyMatchConfig {
+    fn default() -> Self {
+        Self {
+            use_levenshtein: true,
+            levenshtein_threshold: 0.3,
+            use_phonetic: true,
+            phonetic_boost: 0.5,
+            word_similarity_threshold: 0.7,
+        }
+    }
+}
+
+impl FuzzyMatchConfig {
+    /// Create config from AppSettings
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            use_levenshtein: settings.voice_command_use_levenshtein,
+            levenshtein_threshold: settings.voice_command_levenshtein_threshold,
+            use_phonetic: settings.voice_command_use_phonetic,
+            phonetic_boost: settings.voice_command_phonetic_boost,
+            word_similarity_threshold: settings.voice_command_word_similarity_threshold,
+        }
+    }
+}
+
+/// Mechanism that produced a word similarity score.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum WordMatchKind {
+    Exact,
+    Levenshtein,
+    Phonetic,
+}
+
+/// A word similarity score and the mechanism behind it (None = score 0.0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WordSimilarity {
+    pub score: f64,
+    pub kind: Option<WordMatchKind>,
+}
+
+/// Computes word-level similarity using hybrid algorithm:
+/// - Levenshtein distance for typo tolerance
+/// - Soundex phonetic matching for pronunciation similarity
+///
+/// The score is between 0.0 and 1.0; `kind` says which mechanism produced it.
+pub fn word_similarity_breakdown(
+    word_a: &str,
+    word_b: &str,
+    config: &FuzzyMatchConfig,
+) -> WordSimilarity {
+    // Exact match
+    if word_a == word_b {
+        return WordSimilarity {
+            score: 1.0,
+            kind: Some(WordMatchKind::Exact),
+        };
+    }
+
+    let mut similarity = WordSimilarity {
+        score: 0.0,
+        kind: None,
+    };
+
+    // Levenshtein (character-level edit distance)
+    if config.use_levenshtein {
+        let lev_score = normalized_levenshtein(word_a, word_b);
+        // Only accept if above threshold (1.0 - threshold gives minimum required similarity)
+        if lev_score >= (1.0 - config.levenshtein_threshold) && lev_score > similarity.score {
+            similarity = WordSimilarity {
+                score: lev_score,
+                kind: Some(WordMatchKind::Levenshtein),
+            };
+        }
+    }
+
+    // Phonetic matching (Soundex)
+    if config.use_phonetic && soundex(word_a, word_b) {
+        // Phonetic match - boost the score
+        let phonetic_score = (config.word_similarity_threshold
+            + config.phonetic_boost * (1.0 - config.word_similarity_threshold))
+            .min(1.0);
+        if phonetic_score > similarity.score {
+            similarity = WordSimilarity {
+                score: phonetic_score,
+                kind: Some(WordMatchKind::Phonetic),
+            };
+        }
+    }
+
+    similarity
+}
+
+/// Word-level similarity score between 0.0 and 1.0; see `word_similarity_breakdown`.
+pub fn compute_word_similarity(word_a: &str, word_b: &str, config: &FuzzyMatchConfig) -> f64 {
+    word_similarity_breakdown(word_a, word_b, config).score
+}
+
+/// The trigger word a transcription word matched best, and how.
+#[derive(Serialize, Clone, Debug, PartialEq, Type)]
+pub struct WordMatch {
+    pub trigger_word: String,
+    pub score: f64,
+    pub kind: WordMatchKind,
+}
+
+/// Every intermediate value behind a phrase similarity score.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct SimilarityBreakdown {
+    /// Lowercased transcription words (matrix rows).
+    pub transcription_words: Vec<String>,
+    /// Lowercased trigger phrase words (matrix columns).
+    pub trigger_words: Vec<String>,
+    /// `word_scores[i][j]` is the similarity of transcription word `i` and trigger word `j`.
+    pub word_scores: Vec<Vec<f64>>,
+    /// Best accepted score per transcription word (None = no trigger word passed
+    /// the word similarity threshold).
+    pub best_word_scores: Vec<Option<f64>>,
+    /// Trigger word and mechanism behind each entry of `best_word_scores`.
+    pub best_word_matches: Vec<Option<WordMatch>>,
+    /// Share of transcription words that matched a trigger word.
+    pub coverage: f64,
+    /// Average score of the matched words.
+    pub quality: f64,
+    /// Shorter word count divided by longer word count.
+    pub length_ratio: f64,
+    /// True when the phrases are equal ignoring case (score is then 1.0).
+    pub exact_match: bool,
+    pub score: f64,
+}
+
+/// Computes the similarity of transcription `a` to trigger phrase `b` and
+/// keeps every component of the calculation.
+pub fn similarity_breakdown(a: &str, b: &str, config: &FuzzyMatchConfig) -> SimilarityBreakdown {
+    let a_lower = a.to_lowercase();
+    let b_lower = b.to_lowercase();
+
+    let a_words: Vec<&str> = a_lower.split_whitespace().collect();
+    let b_words: Vec<&str> = b_lower.split_whitespace().collect();
+
+    let word_similarities: Vec<Vec<WordSimilarity>> = a_words
+        .iter()
+        .map(|a_word| {
+            b_words
+                .iter()
+                .map(|b_word| word_similarity_breakdown(a_word, b_word, config))
+                .collect()
+        })
+        .collect();
+    let word_scores: Vec<Vec<f64>> = word_similarities
+        .iter()
+        .map(|row| row.iter().map(|similarity| similarity.score).collect())
+        .collect();
+
+    // For each word in 'a', keep the best matching word in 'b'
+    let best_word_matches: Vec<Option<WordMatch>> = word_similarities
+        .iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, similarity)| similarity.score >= config.word_similarity_threshold)
+                .reduce(|best, candidate| {
+                    if candidate.1.score > best.1.score {
+                        candidate
+                    } else {
+                        best
+                    }
+                })
+                .and_then(|(j, similarity)| {
+                    similarity.kind.map(|kind| WordMatch {
+                        trigger_word: b_words[j].to_string(),
+                        score: similarity.score,
+                        kind,
+                    })
+                })
+        })
+        .collect();
+    let best_word_scores: Vec<Option<f64>> = best_word_matches
+        .iter()
+        .map(|word_match| word_match.as_ref().map(|word_match| word_match.score))
+        .collect();
+
+    let matched: Vec<f64> = best_word_scores.iter().flatten().copied().collect();
+    let (coverage, quality, length_ratio) = if a_words.is_empty() || b_words.is_empty() {
+        (0.0, 0.0, 0.0)
+    } else {
+        let coverage = matched.len() as f64 / a_words.len() as f64;
+        let quality = if matched.is_empty() {
+            0.0
+        } else {
+            matched.iter().sum::<f64>() / matched.len() as f64
+        };
+        // Length penalty - favor similar length phrases
+        let length_ratio =
+            (a_words.len().min(b_words.len()) as f64) / (a_words.len().max(b_words.len()) as f64);
+        (coverage, quality, length_ratio)
+    };
+
+    let exact_match = a_lower == b_lower;
+    let score = if exact_match {
+        1.0
+    } else if a_words.is_empty() || b_words.is_empty() {
+        0.0
+    } else {
+        // Coverage is most important, quality matters, length is a tiebreaker
+        coverage * COVERAGE_WEIGHT
+            + quality * coverage * QUALITY_WEIGHT
+            + length_ratio * LENGTH_WEIGHT
+    };
+
+    SimilarityBreakdown {
+        transcription_words: a_words.into_iter().map(str::to_string).collect(),
+        trigger_words: b_words.into_iter().map(str::to_string).collect(),
+        word_scores,
+        best_word_scores,
+        best_word_matches,
+        coverage,
+        quality,
+        length_ratio,
+        exact_match,
+        score,
+    }
+}
+
+/// Computes a similarity score between two strings using a hybrid word-matching approach.
+/// For each word in the transcription, finds the best matching word in the trigger phrase.
+/// Returns a value between 0.0 and 1.0.
+pub fn compute_similarity(a: &str, b: &str, config: &FuzzyMatchConfig) -> f64 {
+    similarity_breakdown(a, b, config).score
+}
+
+fn command_threshold(cmd: &VoiceCommand, default_threshold: f64) -> f64 {
+    if cmd.similarity_threshold > 0.0 {
+        cmd.similarity_threshold
+    } else {
+        default_threshold
+    }
+}
+
+/// Finds the best matching predefined command for the given transcription.
+/// Returns (command, similarity_score) if a match above threshold is found.
+pub fn find_matching_command(
+    transcription: &str,
+    commands: &[VoiceCommand],
+    default_threshold: f64,
+    config: &FuzzyMatchConfig,
+) -> Option<(VoiceCommand, f64)> {
+    let mut best_match: Option<(VoiceCommand, f64)> = None;
+
+    for cmd in commands.iter().filter(|c| c.enabled) {
+        let threshold = command_threshold(cmd, default_threshold);
+        let score = compute_similarity(transcription, &cmd.trigger_phrase, config);
+
+        if score >= threshold {
+            match &best_match {
+                Some((_, best_score)) if score > *best_score => {
+                    best_match = Some((cmd.clone(), score));
+                }
+                None => {
+                    best_match = Some((cmd.clone(), score));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    best_match
+}
+
+/// Why a single voice command did or did not match a transcription.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct VoiceCommandMatchExplanation {
+    pub command_id: String,
+    pub name: String,
+    pub trigger_phrase: String,
+    /// Threshold the score was compared against (per-command or default).
+    pub threshold: f64,
+    pub passed: bool,
+    /// True for the command `find_matching_command` would pick.
+    pub selected: bool,
+    pub breakdown: SimilarityBreakdown,
+}
+
+/// Explains the match of `transcription` against every enabled command, in
+/// the order the commands are configured.
+pub fn explain_matches(
+    transcription: &str,
+    commands: &[VoiceCommand],
+    default_threshold: f64,
+    config: &FuzzyMatchConfig,
+) -> Vec<VoiceCommandMatchExplanation> {
+    let selected_id = find_matching_command(transcription, commands, default_threshold, config)
+        .map(|(cmd, _)| cmd.id);
+
+    commands
+        .iter()
+        .filter(|c| c.enabled)
+        .map(|cmd| {
+            let threshold = command_threshold(cmd, default_threshold);
+            let breakdown = similarity_breakdown(transcription, &cmd.trigger_phrase, config);
+            VoiceCommandMatchExplanation {
+                command_id: cmd.id.clone(),
+                name: cmd.name.clone(),
+                trigger_phrase: cmd.trigger_phrase.clone(),
+                threshold,
+                passed: breakdown.score >= threshold,
+                selected: selected_id.as_deref() == Some(cmd.id.as_str()),
+                breakdown,
+            }
+        })
+        .collect()
+}
+
+/// Result of matching a sample transcription against the voice commands
+/// without executing anything.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct VoiceCommandDryRun {
+    pub transcription: String,
+    pub default_threshold: f64,
+    /// Fuzzy matching settings the scores were computed with.
+    pub config: FuzzyMatchConfig,
+    /// Command `find_matching_command` would pick (None = nothing passed).
+    pub selected_command_id: Option<String>,
+    pub commands: Vec<VoiceCommandMatchExplanation>,
+}
+
+/// Matches `transcription` against every enabled command and reports the
+/// outcome together with the thresholds and config used.
+pub fn dry_run(
+    transcription: &str,
+    commands: &[VoiceCommand],
+    default_threshold: f64,
+    config: &FuzzyMatchConfig,
+) -> VoiceCommandDryRun {
+    let explanations = explain_matches(transcription, commands, default_threshold, config);
+    VoiceCommandDryRun {
+        transcription: transcription.to_string(),
+        default_threshold,
+        config: config.clone(),
+        selected_command_id: explanations
+            .iter()
+            .find(|explanation| explanation.selected)
+            .map(|explanation| explanation.command_id.clone()),
+        commands: explanations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn command(id: &str, trigger_phrase: &str) -> VoiceCommand {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "trigger_phrase": trigger_phrase,
+            "script": "",
+            "similarity_threshold": 0.0,
+        }))
+        .expect("valid voice command")
+    }
+
+    fn phonetic_score(config: &FuzzyMatchConfig) -> f64 {
+        config.word_similarity_threshold
+            + config.phonetic_boost * (1.0 - config.word_similarity_threshold)
+    }
+
+    #[test]
+    fn identical_words_score_one() {
+        let config = FuzzyMatchConfig::default();
+        assert_eq!(compute_word_similarity("notepad", "notepad", &config), 1.0);
+    }
+
+    #[test]
+    fn single_typo_uses_levenshtein_score() {
+        let config = FuzzyMatchConfig::default();
+        // One substitution in ten characters.
+        assert_close(
+            compute_word_similarity("calculater", "calculator", &config),
+            0.9,
+        );
+    }
+
+    #[test]
+    fn levenshtein_below_threshold_is_ignored() {
+        let config = FuzzyMatchConfig {
+            use_phonetic: false,
+            ..FuzzyMatchConfig::default()
+        };
+        assert_eq!(compute_word_similarity("browser", "notepad", &config), 0.0);
+    }
+
+    #[test]
+    fn looser_levenshtein_threshold_accepts_more_edits() {
+        let strict = FuzzyMatchConfig {
+            use_phonetic: false,
+            ..FuzzyMatchConfig::default()
+        };
+        let loose = FuzzyMatchConfig {
+            levenshtein_threshold: 0.4,
+            ..strict.clone()
+        };
+        // Two edits in six characters: 0.667 similarity.
+        assert_eq!(compute_word_similarity("robert", "rupert", &strict), 0.0);
+        assert_close(
+            compute_word_similarity("robert", "rupert", &loose),
+            2.0 / 3.0,
+        );
+    }
+
+    #[test]
+    fn phonetic_match_rescues_words_levenshtein_rejects() {
+        let config = FuzzyMatchConfig::default();
+        assert_close(
+            compute_word_similarity("robert", "rupert", &config),
+            phonetic_score(&config),
+        );
+    }
+
+    #[test]
+    fn phonetic_match_can_be_disabled() {
+        let config = FuzzyMatchConfig {
+            use_phonetic: false,
+            ..FuzzyMatchConfig::default()
+        };
+        assert_eq!(compute_word_similarity("robert", "rupert", &config), 0.0);
+    }
+
+    #[test]
+    fn phonetic_boost_scales_between_threshold_and_one() {
+        let none = FuzzyMatchConfig {
+            phonetic_boost: 0.0,
+            ..FuzzyMatchConfig::default()
+        };
+        let full = FuzzyMatchConfig {
+            phonetic_boost: 1.0,
+            ..FuzzyMatchConfig::default()
+        };
+        assert_close(compute_word_similarity("robert", "rupert", &none), 0.7);
+        assert_close(compute_word_similarity("robert", "rupert", &full), 1.0);
+    }
+
+    #[test]
+    fn better_levenshtein_score_wins_over_phonetic_score() {
+        let config = FuzzyMatchConfig::default();
+        // Same Soundex code, but 6/7 characters also line up.
+        assert_close(
+            compute_word_similarity("notpad", "notepad", &config),
+            6.0 / 7.0,
+        );
+    }
+
+    #[test]
+    fn word_similarity_reports_match_mechanism() {
+        let config = FuzzyMatchConfig::default();
+        let kind = |a, b| word_similarity_breakdown(a, b, &config).kind;
+        assert_eq!(kind("notepad", "notepad"), Some(WordMatchKind::Exact));
+        assert_eq!(kind("notpad", "notepad"), Some(WordMatchKind::Levenshtein));
+        assert_eq!(kind("robert", "rupert"), Some(WordMatchKind::Phonetic));
+        assert_eq!(kind("browser", "notepad"), None);
+    }
+
+    #[test]
+    fn best_word_matches_name_trigger_word_and_mechanism() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("open notpad now", "open notepad", &config);
+        let kinds: Vec<_> = breakdown
+            .best_word_matches
+            .iter()
+            .map(|word_match| {
+                word_match
+                    .as_ref()
+                    .map(|m| (m.trigger_word.as_str(), m.kind))
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Some(("open", WordMatchKind::Exact)),
+                Some(("notepad", WordMatchKind::Levenshtein)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn phrase_match_ignores_case() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("Open Notepad", "open notepad", &config);
+        assert!(breakdown.exact_match);
+        assert_eq!(breakdown.score, 1.0);
+    }
+
+    #[test]
+    fn empty_phrases_score_zero() {
+        let config = FuzzyMatchConfig::default();
+        assert_eq!(compute_similarity("", "open notepad", &config), 0.0);
+        assert_eq!(compute_similarity("open notepad", "   ", &config), 0.0);
+    }
+
+    #[test]
+    fn typo_in_phrase_still_scores_high() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("open notpad", "open notepad", &config);
+        assert_close(breakdown.coverage, 1.0);
+        assert_close(breakdown.quality, (1.0 + 6.0 / 7.0) / 2.0);
+        assert_close(breakdown.length_ratio, 1.0);
+        assert_close(breakdown.score, 0.7 + 0.2 * (1.0 + 6.0 / 7.0) / 2.0 + 0.1);
+    }
+
+    #[test]
+    fn extra_spoken_words_reduce_coverage_and_length() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("open notepad please", "open notepad", &config);
+        assert_eq!(breakdown.best_word_scores, vec![Some(1.0), Some(1.0), None]);
+        assert_close(breakdown.coverage, 2.0 / 3.0);
+        assert_close(breakdown.quality, 1.0);
+        assert_close(breakdown.length_ratio, 2.0 / 3.0);
+        assert_close(breakdown.score, 2.0 / 3.0);
+        assert!(breakdown.score < 0.75);
+    }
+
+    #[test]
+    fn missing_trigger_words_only_cost_length_ratio() {
+        let config = FuzzyMatchConfig::default();
+        // Every spoken word matched, so coverage stays full.
+        let score = compute_similarity("open notepad", "open notepad please", &config);
+        assert_close(score, 0.7 + 0.2 + 0.1 * 2.0 / 3.0);
+    }
+
+    #[test]
+    fn length_penalty_grows_with_word_count_difference() {
+        let config = FuzzyMatchConfig::default();
+        let close = compute_similarity("lock", "lock screen", &config);
+        let far = compute_similarity("lock", "lock the screen right now", &config);
+        assert_close(close, 0.95);
+        assert_close(far, 0.92);
+        assert!(close > far);
+    }
+
+    #[test]
+    fn unrelated_phrase_only_scores_length_ratio() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("lock computer", "open browser", &config);
+        assert_eq!(breakdown.best_word_scores, vec![None, None]);
+        assert_eq!(breakdown.coverage, 0.0);
+        assert_eq!(breakdown.quality, 0.0);
+        assert_close(breakdown.score, LENGTH_WEIGHT);
+    }
+
+    #[test]
+    fn word_score_matrix_has_transcription_rows_and_trigger_columns() {
+        let config = FuzzyMatchConfig::default();
+        let breakdown = similarity_breakdown("open my notepad", "open notepad", &config);
+        assert_eq!(breakdown.transcription_words, ["open", "my", "notepad"]);
+        assert_eq!(breakdown.trigger_words, ["open", "notepad"]);
+        assert_eq!(breakdown.word_scores.len(), 3);
+        assert!(breakdown.word_scores.iter().all(|row| row.len() == 2));
+        assert_eq!(breakdown.word_scores[0][0], 1.0);
+        assert_eq!(breakdown.word_scores[2][1], 1.0);
+    }
+
+    #[test]
+    fn compute_similarity_matches_breakdown_score() {
+        let config = FuzzyMatchConfig::default();
+        for (a, b) in [
+            ("open notepad please", "open notepad"),
+            ("robert", "rupert"),
+            ("lock computer", "lock the computer"),
+        ] {
+            let breakdown = similarity_breakdown(a, b, &config);
+            assert!((compute_similarity(a, b, &config) - breakdown.score).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn find_matching_command_picks_best_enabled_command() {
+        let config = FuzzyMatchConfig::default();
+        let mut disabled = command("disabled", "open notepad please");
+        disabled.enabled = false;
+        let commands = vec![
+            command("notepad", "open notepad"),
+            command("browser", "open browser"),
+            disabled,
+        ];
+
+        let (cmd, score) = find_matching_command("open notpad", &commands, 0.75, &config)
+            .expect("typo should still match");
+        assert_eq!(cmd.id, "notepad");
+        assert!(score > 0.9);
+        assert!(find_matching_command("open notepad please", &commands, 0.75, &config).is_none());
+    }
+
+    #[test]
+    fn per_command_threshold_overrides_default() {
+        let config = FuzzyMatchConfig::default();
+        let mut strict = command("strict", "open notepad");
+        strict.similarity_threshold = 0.99;
+        let commands = vec![strict];
+
+        assert!(find_matching_command("open notpad", &commands, 0.5, &config).is_none());
+        assert!(find_matching_command("open notepad", &commands, 0.5, &config).is_some());
+    }
+
+    #[test]
+    fn explain_matches_reports_every_enabled_command() {
+        let config = FuzzyMatchConfig::default();
+        let mut disabled = command("disabled", "open notepad");
+        disabled.enabled = false;
+        let commands = vec![
+            command("notepad", "open notepad"),
+            command("paint", "open paint"),
+            disabled,
+        ];
+
+        let explanations = explain_matches("open notpad", &commands, 0.75, &config);
+        assert_eq!(explanations.len(), 2);
+
+        let notepad = &explanations[0];
+        assert_eq!(notepad.command_id, "notepad");
+        assert_eq!(notepad.threshold, 0.75);
+        assert!(notepad.passed);
+        assert!(notepad.selected);
+
+        let paint = &explanations[1];
+        assert_eq!(paint.breakdown.best_word_scores.len(), 2);
+        assert!(!paint.selected);
+        assert_close(
+            paint.breakdown.score,
+            compute_similarity("open notpad", "open paint", &config),
+        );
+    }
+
+    #[test]
+    fn dry_run_reports_selected_command() {
+        let config = FuzzyMatchConfig::default();
+        let commands = vec![
+            command("notepad", "open notepad"),
+            command("paint", "open paint"),
+        ];
+
+        let report = dry_run("open notpad", &commands, 0.75, &config);
+        assert_eq!(report.selected_command_id.as_deref(), Some("notepad"));
+        assert_eq!(report.commands.len(), 2);
+
+        let report = dry_run("close everything", &commands, 0.75, &config);
+        assert_eq!(report.selected_command_id, None);
+        assert!(report.commands.iter().all(|command| !command.passed));
+    }
+}
@@ -0,0 +1,305 @@
+//! Posts each final transcript to a user-configured HTTP endpoint, e.g. a
+//! note system, without going through the browser extension.
+//!
+//! Deliveries run on the async runtime after the text is produced, so they
+//! never hold up the paste. A failed delivery is retried twice with backoff,
+//! then logged and reported with `webhook-delivery-failed`.
+//!
+//! When a secret is set, the JSON body is signed with HMAC-SHA256 and sent as
+//! `X-AivoRelay-Signature: sha256=<hex>`.
+
+use hmac::{Hmac, Mac};
+use log::{debug, error, warn};
+use reqwest::StatusCode;
+use serde::Serialize;
+use sha2::Sha256;
+use specta::Type;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use crate::settings::{self, AppSettings};
+use crate::url_security::validate_webhook_url;
+
+pub const WEBHOOK_DELIVERY_FAILED_EVENT: &str = "webhook-delivery-failed";
+const SIGNATURE_HEADER: &str = "x-aivorelay-signature";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Waits before the second and third attempt.
+const RETRY_BACKOFF: [Duration; 2] = [Duration::from_secs(2), Duration::from_secs(8)];
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Serialize, Debug, Clone)]
+struct WebhookPayload {
+    text: String,
+    raw_transcription: String,
+    profile_id: String,
+    app: String,
+    /// RFC 3339, UTC
+    timestamp: String,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct WebhookDeliveryFailedEvent {
+    pub url: String,
+    pub error: String,
+    pub attempts: u32,
+}
+
+/// Whether transcripts of `profile_id` go to the webhook.
+fn is_enabled_for_profile(settings: &AppSettings, profile_id: &str) -> bool {
+    settings.webhook_enabled
+        && !settings.webhook_url.trim().is_empty()
+        && settings
+            .webhook_profile_id
+            .as_deref()
+            .is_none_or(|filter| filter == profile_id)
+}
+
+/// Hex HMAC-SHA256 of `body`, or `None` without a secret.
+fn signature(secret: &str, body: &[u8]) -> Option<String> {
+    if secret.is_empty() {
+        return None;
+    }
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(body);
+    let digest = mac.finalize().into_bytes();
+    Some(format!(
+        "sha256={}",
+        digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    ))
+}
+
+/// Network errors, timeouts, rate limits and server errors are worth another try.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status == StatusCode::REQUEST_TIMEOUT
+}
+
+fn build_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    secret: &str,
+    body: &[u8],
+) -> Result<StatusCode, reqwest::Error> {
+    let mut request = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_vec());
+    if let Some(signature) = signature(secret, body) {
+        request = request.header(SIGNATURE_HEADER, signature);
+    }
+    Ok(request.send().await?.status())
+}
+
+async fn deliver(app: AppHandle, url: String, secret: String, body: Vec<u8>) {
+    let client = match build_client() {
+        Ok(client) => client,
+        Err(e) => return report_failure(&app, &url, &e, 0),
+    };
+
+    let mut attempts = 0u32;
+    loop {
+        attempts += 1;
+        let (error, retryable) = match post(&client, &url, &secret, &body).await {
+            Ok(status) if status.is_success() => {
+                debug!("Webhook delivered (HTTP {})", status.as_u16());
+                return;
+            }
+            Ok(status) => (format!("HTTP {}", status), is_retryable_status(status)),
+            Err(e) => (e.to_string(), true),
+        };
+
+        match RETRY_BACKOFF.get(attempts as usize - 1) {
+            Some(delay) if retryable => {
+                warn!(
+                    "Webhook delivery attempt {} failed: {}; retrying in {:?}",
+                    attempts, error, delay
+                );
+                tokio::time::sleep(*delay).await;
+            }
+            _ => return report_failure(&app, &url, &error, attempts),
+        }
+    }
+}
+
+fn report_failure(app: &AppHandle, url: &str, error: &str, attempts: u32) {
+    error!(
+        "Webhook delivery to {} failed after {} attempt(s): {}",
+        url, attempts, error
+    );
+    let _ = app.emit(
+        WEBHOOK_DELIVERY_FAILED_EVENT,
+        WebhookDeliveryFailedEvent {
+            url: url.to_string(),
+            error: error.to_string(),
+            attempts,
+        },
+    );
+}
+
+/// Sends a final transcript to the webhook in the background when the
+/// settings ask for it. Returns at once.
+pub fn queue_delivery(
+    app: &AppHandle,
+    settings: &AppSettings,
+    profile_id: Option<&str>,
+    current_app: &str,
+    raw_transcription: &str,
+    final_text: &str,
+) {
+    let profile_id = profile_id.unwrap_or("default");
+    if !is_enabled_for_profile(settings, profile_id)
+        || final_text.trim().is_empty()
+        || crate::privacy_mode::should_skip("webhook delivery")
+    {
+        return;
+    }
+
+    let payload = WebhookPayload {
+        text: final_text.to_string(),
+        raw_transcription: raw_transcription.to_string(),
+        profile_id: profile_id.to_string(),
+        app: current_app.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    };
+    let body = match serde_json::to_vec(&payload) {
+        Ok(body) => body,
+        Err(e) => return report_failure(app, &settings.webhook_url, &e.to_string(), 0),
+    };
+
+    tauri::async_runtime::spawn(deliver(
+        app.clone(),
+        settings.webhook_url.trim().to_string(),
+        settings.webhook_secret.to_string(),
+        body,
+    ));
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if enabled && settings.webhook_url.trim().is_empty() {
+        return Err("Set a webhook URL before enabling the webhook.".to_string());
+    }
+    settings.webhook_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// An empty URL clears the webhook and turns it off.
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_url_setting(app: AppHandle, url: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if url.trim().is_empty() {
+        settings.webhook_url = String::new();
+        settings.webhook_enabled = false;
+    } else {
+        settings.webhook_url = validate_webhook_url(&url)?;
+    }
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_secret_setting(app: AppHandle, secret: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.webhook_secret = secret.trim().to_string().into();
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// `None` sends every transcript; otherwise only those of `profile_id`.
+#[tauri::command]
+#[specta::specta]
+pub fn change_webhook_profile_filter_setting(
+    app: AppHandle,
+    profile_id: Option<String>,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    if let Some(id) = profile_id.as_deref() {
+        if id != "default" && settings.transcription_profile(id).is_none() {
+            return Err(format!("Profile '{}' not found", id));
+        }
+    }
+    settings.webhook_profile_id = profile_id;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Sends a sample payload once, without retries, and returns the HTTP status.
+/// Works while the webhook is disabled so the endpoint can be checked first.
+#[tauri::command]
+#[specta::specta]
+pub async fn test_webhook(app: AppHandle) -> Result<u16, String> {
+    let settings = settings::get_settings(&app);
+    let url = validate_webhook_url(&settings.webhook_url)?;
+    let payload = WebhookPayload {
+        text: "This is a test transcript from AivoRelay.".to_string(),
+        raw_transcription: "this is a test transcript from aivorelay".to_string(),
+        profile_id: settings
+            .webhook_profile_id
+            .clone()
+            .unwrap_or_else(|| "default".to_string()),
+        app: "AivoRelay".to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    };
+    let body = serde_json::to_vec(&payload).map_err(|e| e.to_string())?;
+
+    let status = post(&build_client()?, &url, &settings.webhook_secret, &body)
+        .await
+        .map_err(|e| format!("Webhook request failed: {}", e))?;
+    Ok(status.as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_is_hex_hmac_of_the_body() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            signature("Jefe", b"what do ya want for nothing?").as_deref(),
+            Some("sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+        assert_eq!(signature("", b"body"), None);
+    }
+
+    #[test]
+    fn profile_filter_limits_deliveries() {
+        let mut settings = settings::get_default_settings();
+        settings.webhook_enabled = true;
+        settings.webhook_url = "https://notes.example.com/hook".to_string();
+        assert!(is_enabled_for_profile(&settings, "default"));
+        assert!(is_enabled_for_profile(&settings, "work"));
+
+        settings.webhook_profile_id = Some("work".to_string());
+        assert!(is_enabled_for_profile(&settings, "work"));
+        assert!(!is_enabled_for_profile(&settings, "default"));
+
+        settings.webhook_url = " ".to_string();
+        assert!(!is_enabled_for_profile(&settings, "work"));
+    }
+
+    #[test]
+    fn only_transient_statuses_are_retried() {
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::UNAUTHORIZED));
+    }
+}
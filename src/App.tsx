@@ -18,7 +18,12 @@ import type { ModelStateEvent } from "./lib/types/events";
 import type { WindowsMicrophonePermissionStatus } from "./lib/types/windowsPermissions";
 
 type RecordingErrorPayload = {
-  error_type: "microphone_permission_denied" | "no_input_device" | "unknown";
+  error_type:
+    | "microphone_permission_denied"
+    | "microphone_in_use"
+    | "microphone_format_not_supported"
+    | "no_input_device"
+    | "unknown";
   detail: string;
 };
 
@@ -163,6 +168,29 @@ function App() {
         description: t("errors.pasteFailed"),
       });
     });
+    const unlistenShortcutPortal = listen<{
+      kind: "unavailable" | "bind_failed" | "partially_bound";
+      detail: string;
+    }>("shortcut-portal-error", (event) => {
+      const { kind, detail } = event.payload;
+      toast.error(t("errors.shortcutPortalTitle"), {
+        duration: ERROR_TOAST_DURATION_MS,
+        description: t(
+          kind === "unavailable"
+            ? "errors.shortcutPortalUnavailable"
+            : kind === "partially_bound"
+              ? "errors.shortcutPortalPartiallyBound"
+              : "errors.shortcutPortalBindFailed",
+          { detail },
+        ),
+      });
+    });
+    // An error raised before this window was listening is sent again.
+    void unlistenShortcutPortal.then(() =>
+      invoke("replay_shortcut_portal_error").catch((error) =>
+        console.error("Failed to replay shortcut portal error:", error),
+      ),
+    );
     const unlistenModelState = listen<ModelStateEvent>(
       "model-state-changed",
       (event) => {
@@ -182,6 +210,51 @@ function App() {
         );
       },
     );
+    const unlistenModelMissing = listen<{
+      model_id: string;
+      path: string;
+      reason: string;
+      fallback_model_id: string | null;
+    }>("model-missing", (event) => {
+      const { model_id, path, reason, fallback_model_id } = event.payload;
+      toast.error(t("errors.modelMissing", { model: model_id }), {
+        duration: ERROR_TOAST_DURATION_MS,
+        description: fallback_model_id
+          ? t("errors.modelMissingFallback", {
+              path,
+              reason,
+              fallback: fallback_model_id,
+            })
+          : `${path} (${reason})`,
+      });
+    });
+    const unlistenFileOutput = listen<{ path: string; error: string }>(
+      "file-output-error",
+      (event) => {
+        toast.error(t("errors.fileOutputFailed"), {
+          duration: ERROR_TOAST_DURATION_MS,
+          description: `${event.payload.path}: ${event.payload.error}`,
+        });
+      },
+    );
+    const unlistenWebhook = listen<{
+      url: string;
+      error: string;
+      attempts: number;
+    }>("webhook-delivery-failed", (event) => {
+      toast.error(t("errors.webhookDeliveryFailed"), {
+        duration: ERROR_TOAST_DURATION_MS,
+        description: `${event.payload.url}: ${event.payload.error}`,
+      });
+    });
+    const unlistenOpenSection = listen<string>(
+      "open-settings-section",
+      (event) => {
+        if (event.payload in SECTIONS_CONFIG) {
+          setCurrentSection(event.payload as SidebarSection);
+        }
+      },
+    );
     const unlistenModelDownloadFailed = listen<{
       model_id: string;
       error: string;
@@ -230,17 +303,33 @@ function App() {
       },
     );
 
+    const unlistenConnectorExpired = listen<{ id: string; text: string }>(
+      "connector-message-expired",
+      (event) => {
+        toast.warning(t("settings.browserConnector.pending.expiredToast"), {
+          duration: 6000,
+          description: event.payload.text || undefined,
+        });
+      },
+    );
+
     return () => {
       unlistenRemote.then((unlisten) => unlisten());
       unlistenScreenshot.then((unlisten) => unlisten());
       unlistenVoiceCommand.then((unlisten) => unlisten());
       unlistenRecording.then((unlisten) => unlisten());
       unlistenPaste.then((unlisten) => unlisten());
+      unlistenShortcutPortal.then((unlisten) => unlisten());
       unlistenModelState.then((unlisten) => unlisten());
+      unlistenModelMissing.then((unlisten) => unlisten());
+      unlistenOpenSection.then((unlisten) => unlisten());
+      unlistenFileOutput.then((unlisten) => unlisten());
+      unlistenWebhook.then((unlisten) => unlisten());
       unlistenModelDownloadFailed.then((unlisten) => unlisten());
       unlistenModelDownloadProgress.then((unlisten) => unlisten());
       unlistenModelDownloadCancelled.then((unlisten) => unlisten());
       unlistenAuthFailed.then((unlisten) => unlisten());
+      unlistenConnectorExpired.then((unlisten) => unlisten());
     };
   }, [t]);
 
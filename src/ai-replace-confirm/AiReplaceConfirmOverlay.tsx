@@ -0,0 +1,161 @@
+import { useCallback, useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface DiffSegment {
+  kind: "equal" | "removed" | "added";
+  text: string;
+}
+
+interface AiReplaceConfirmPayload {
+  original: string;
+  proposed: string;
+  diff: DiffSegment[];
+  timeout_seconds: number;
+}
+
+type View = "diff" | "sideBySide";
+
+export default function AiReplaceConfirmOverlay() {
+  const [payload, setPayload] = useState<AiReplaceConfirmPayload | null>(null);
+  const [view, setView] = useState<View>("diff");
+  const [remainingMs, setRemainingMs] = useState(0);
+  const [isResolving, setIsResolving] = useState(false);
+
+  useEffect(() => {
+    invoke<AiReplaceConfirmPayload | null>("ai_replace_confirm_get_payload")
+      .then((result) => {
+        setPayload(result);
+        if (result) {
+          setRemainingMs(result.timeout_seconds * 1000);
+          // Nothing to compare against when generating without a selection.
+          setView(result.original ? "diff" : "sideBySide");
+        }
+      })
+      .catch((err) => {
+        console.error("Failed to load AI Replace preview:", err);
+      });
+  }, []);
+
+  // Visual countdown only; the backend enforces the timeout.
+  useEffect(() => {
+    if (!payload || isResolving) return;
+    const interval = setInterval(() => {
+      setRemainingMs((prev) => Math.max(0, prev - 250));
+    }, 250);
+    return () => clearInterval(interval);
+  }, [payload, isResolving]);
+
+  const handleAccept = useCallback(() => {
+    if (isResolving) return;
+    setIsResolving(true);
+    invoke("ai_replace_confirm_accept").catch((err) => {
+      console.error("Failed to accept AI Replace result:", err);
+    });
+  }, [isResolving]);
+
+  const handleReject = useCallback(() => {
+    if (isResolving) return;
+    setIsResolving(true);
+    invoke("ai_replace_confirm_reject").catch((err) => {
+      console.error("Failed to reject AI Replace result:", err);
+    });
+  }, [isResolving]);
+
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === "Escape") {
+        e.preventDefault();
+        handleReject();
+      } else if (e.key === "Enter" && (e.ctrlKey || e.metaKey)) {
+        e.preventDefault();
+        handleAccept();
+      }
+    };
+
+    window.addEventListener("keydown", handleKeyDown);
+    return () => window.removeEventListener("keydown", handleKeyDown);
+  }, [handleAccept, handleReject]);
+
+  if (!payload) {
+    return null;
+  }
+
+  const totalMs = payload.timeout_seconds * 1000;
+  const progressPercent = totalMs > 0 ? (remainingMs / totalMs) * 100 : 0;
+
+  return (
+    <div className="ai-replace-confirm-container">
+      <div className="ai-replace-confirm-progress">
+        <div
+          className="ai-replace-confirm-progress-bar"
+          style={{ width: `${progressPercent}%` }}
+        />
+      </div>
+
+      <div className="ai-replace-confirm-header">
+        <span className="ai-replace-confirm-title">AI Replace</span>
+        <span className="ai-replace-confirm-countdown">
+          Auto-reject in {Math.ceil(remainingMs / 1000)}s
+        </span>
+        {payload.original && (
+          <div className="ai-replace-confirm-tabs">
+            <button
+              className={view === "diff" ? "active" : ""}
+              onClick={() => setView("diff")}
+            >
+              Changes
+            </button>
+            <button
+              className={view === "sideBySide" ? "active" : ""}
+              onClick={() => setView("sideBySide")}
+            >
+              Side by side
+            </button>
+          </div>
+        )}
+      </div>
+
+      {view === "diff" ? (
+        <div className="ai-replace-confirm-text">
+          {payload.diff.map((segment, index) => (
+            <span key={index} className={`diff-${segment.kind}`}>
+              {segment.text}
+            </span>
+          ))}
+        </div>
+      ) : (
+        <div className="ai-replace-confirm-columns">
+          {payload.original && (
+            <div className="ai-replace-confirm-column">
+              <span className="ai-replace-confirm-label">Original</span>
+              <div className="ai-replace-confirm-text">{payload.original}</div>
+            </div>
+          )}
+          <div className="ai-replace-confirm-column">
+            <span className="ai-replace-confirm-label">Replacement</span>
+            <div className="ai-replace-confirm-text">{payload.proposed}</div>
+          </div>
+        </div>
+      )}
+
+      <div className="ai-replace-confirm-buttons">
+        <button
+          className="ai-replace-confirm-btn reject"
+          onClick={handleReject}
+          disabled={isResolving}
+          title="Reject (Esc)"
+        >
+          Reject
+        </button>
+        <button
+          className="ai-replace-confirm-btn accept"
+          onClick={handleAccept}
+          disabled={isResolving}
+          title="Accept (Ctrl+Enter)"
+        >
+          Accept
+        </button>
+      </div>
+    </div>
+  );
+}
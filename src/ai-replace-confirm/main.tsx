@@ -0,0 +1,10 @@
+import React from "react";
+import ReactDOM from "react-dom/client";
+import AiReplaceConfirmOverlay from "./AiReplaceConfirmOverlay";
+import "./AiReplaceConfirmOverlay.css";
+
+ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
+  <React.StrictMode>
+    <AiReplaceConfirmOverlay />
+  </React.StrictMode>,
+);
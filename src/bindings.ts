@@ -1385,17 +1385,17 @@ async fetchLlmModels(feature: LlmFeature) : Promise<Result<string[], string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async addPostProcessPrompt(name: string, prompt: string) : Promise<Result<LLMPrompt, string>> {
+async addPostProcessPrompt(name: string, prompt: string, modelOverride: string | null, reasoningEnabledOverride: boolean | null, reasoningBudgetOverride: number | null) : Promise<Result<LLMPrompt, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("add_post_process_prompt", { name, prompt }) };
+    return { status: "ok", data: await TAURI_INVOKE("add_post_process_prompt", { name, prompt, modelOverride, reasoningEnabledOverride, reasoningBudgetOverride }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updatePostProcessPrompt(id: string, name: string, prompt: string) : Promise<Result<null, string>> {
+async updatePostProcessPrompt(id: string, name: string, prompt: string, modelOverride: string | null, reasoningEnabledOverride: boolean | null, reasoningBudgetOverride: number | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_post_process_prompt", { id, name, prompt }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_post_process_prompt", { id, name, prompt, modelOverride, reasoningEnabledOverride, reasoningBudgetOverride }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -1538,7 +1538,7 @@ async changeProfileSwitchOverlayEnabledSetting(enabled: boolean) : Promise<Resul
     else return { status: "error", error: e  as any };
 }
 },
-async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
+async updateCustomWords(words: CustomWord[]) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_custom_words", { words }) };
 } catch (e) {
@@ -1546,6 +1546,19 @@ async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Checks a custom word pattern while it is being edited. Returns the regex
+ * error for invalid exact or regex patterns; invalid entries are skipped
+ * when custom words are applied.
+ */
+async validateCustomWordPattern(pattern: string, kind: CustomWordKind, caseSensitive: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("validate_custom_word_pattern", { pattern, kind, caseSensitive }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeCustomWordsEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_custom_words_enabled_setting", { enabled }) };
@@ -3184,6 +3197,14 @@ async changeFileTranscriptionChunkingMaxMinutesSetting(minutes: number) : Promis
     else return { status: "error", error: e  as any };
 }
 },
+async changeFileTranscriptionSegmentMinutesSetting(minutes: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_file_transcription_segment_minutes_setting", { minutes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async reapplyTranscriptionSpeakerNames(artifactPath: string, speakerNames: FileTranscriptionSpeakerNameInput[]) : Promise<Result<string, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("reapply_transcription_speaker_names", { artifactPath, speakerNames }) };
@@ -3433,7 +3454,7 @@ native_streaming_show_interim_longer?: boolean;
  * Per-model latency presets for native transcribe.cpp streaming.
  * Missing entries intentionally preserve the runtime's accurate defaults.
  */
-native_streaming_latency_presets?: Partial<{ [key in string]: NativeStreamingLatencyPreset }>; soniox_live_preview_close_hotkey?: string; soniox_live_preview_clear_hotkey?: string; soniox_live_preview_flush_hotkey?: string; soniox_live_preview_process_hotkey?: string; soniox_live_preview_insert_hotkey?: string; soniox_live_preview_delete_until_dot_or_comma_hotkey?: string; soniox_live_preview_delete_until_dot_hotkey?: string; soniox_live_preview_delete_last_word_hotkey?: string; soniox_live_preview_show_clear_button?: boolean; soniox_live_preview_show_flush_button?: boolean; soniox_live_preview_show_process_button?: boolean; soniox_live_preview_show_insert_button?: boolean; soniox_live_preview_show_delete_until_dot_or_comma_button?: boolean; soniox_live_preview_show_delete_until_dot_button?: boolean; soniox_live_preview_show_delete_last_word_button?: boolean; soniox_live_preview_ctrl_backspace_delete_last_word?: boolean; soniox_live_preview_backspace_delete_last_char?: boolean; soniox_live_preview_show_drag_grip?: boolean; local_preview_auto_flush_enabled?: boolean; local_preview_auto_flush_interval_ms?: number; local_preview_auto_flush_overlap_ms?: number; soniox_live_preview_sliding_lm_window_enabled?: boolean; soniox_live_preview_sliding_lm_window_prompt?: string; soniox_live_preview_sliding_lm_window_tail_words?: number; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; custom_words_enabled?: boolean; custom_words_ngram_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; dictation_stats_enabled?: boolean; dictation_word_count?: number; dictation_word_count_since_ms?: number | null; dictation_character_count?: number; dictation_character_count_since_ms?: number | null; paste_method?: PasteMethod; paste_delay_ms?: number;
+native_streaming_latency_presets?: Partial<{ [key in string]: NativeStreamingLatencyPreset }>; soniox_live_preview_close_hotkey?: string; soniox_live_preview_clear_hotkey?: string; soniox_live_preview_flush_hotkey?: string; soniox_live_preview_process_hotkey?: string; soniox_live_preview_insert_hotkey?: string; soniox_live_preview_delete_until_dot_or_comma_hotkey?: string; soniox_live_preview_delete_until_dot_hotkey?: string; soniox_live_preview_delete_last_word_hotkey?: string; soniox_live_preview_show_clear_button?: boolean; soniox_live_preview_show_flush_button?: boolean; soniox_live_preview_show_process_button?: boolean; soniox_live_preview_show_insert_button?: boolean; soniox_live_preview_show_delete_until_dot_or_comma_button?: boolean; soniox_live_preview_show_delete_until_dot_button?: boolean; soniox_live_preview_show_delete_last_word_button?: boolean; soniox_live_preview_ctrl_backspace_delete_last_word?: boolean; soniox_live_preview_backspace_delete_last_char?: boolean; soniox_live_preview_show_drag_grip?: boolean; local_preview_auto_flush_enabled?: boolean; local_preview_auto_flush_interval_ms?: number; local_preview_auto_flush_overlap_ms?: number; soniox_live_preview_sliding_lm_window_enabled?: boolean; soniox_live_preview_sliding_lm_window_prompt?: string; soniox_live_preview_sliding_lm_window_tail_words?: number; debug_mode?: boolean; log_level?: LogLevel; custom_words?: CustomWord[]; custom_words_enabled?: boolean; custom_words_ngram_enabled?: boolean; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; dictation_stats_enabled?: boolean; dictation_word_count?: number; dictation_word_count_since_ms?: number | null; dictation_character_count?: number; dictation_character_count_since_ms?: number | null; paste_method?: PasteMethod; paste_delay_ms?: number;
 /**
  * Convert LF to CRLF before clipboard paste (fixes newlines on Windows)
  */
@@ -3474,7 +3495,10 @@ send_to_extension_enabled?: boolean; send_to_extension_push_to_talk?: boolean;
 /**
  * Whether the "Send Transcription + Selection to Extension" action is enabled (risky feature)
  */
-send_to_extension_with_selection_enabled?: boolean; send_to_extension_with_selection_push_to_talk?: boolean; send_to_extension_with_selection_allow_no_voice?: boolean; send_to_extension_with_selection_quick_tap_threshold_ms?: number; send_to_extension_with_selection_no_voice_system_prompt?: string; ai_replace_selection_push_to_talk?: boolean; mute_while_recording?: boolean; pause_media_while_recording?: boolean; filter_silence?: boolean; file_transcription_chunking_mode?: FileTranscriptionChunkingMode; file_transcription_chunking_max_minutes?: number; 
+send_to_extension_with_selection_enabled?: boolean; send_to_extension_with_selection_push_to_talk?: boolean; send_to_extension_with_selection_allow_no_voice?: boolean; send_to_extension_with_selection_quick_tap_threshold_ms?: number; send_to_extension_with_selection_no_voice_system_prompt?: string; ai_replace_selection_push_to_talk?: boolean; mute_while_recording?: boolean; pause_media_while_recording?: boolean; filter_silence?: boolean; file_transcription_chunking_mode?: FileTranscriptionChunkingMode; file_transcription_chunking_max_minutes?: number; /**
+ * Files longer than this are split at pauses and transcribed segment by segment.
+ */
+file_transcription_segment_minutes?: number; 
 /**
  * Optional microphone-only preamp in dB, saved per microphone device name.
  */
@@ -3804,6 +3828,36 @@ port: number;
  */
 server_error: string | null }
 export type CustomSounds = { start: boolean; stop: boolean }
+/**
+ * One custom word entry. Plain strings from older settings load as fuzzy
+ * entries.
+ */
+export type CustomWord = { pattern: string; 
+/**
+ * Text written in place of a match. Empty for fuzzy entries, which
+ * write `pattern` itself.
+ */
+replacement: string; kind: CustomWordKind; 
+/**
+ * Ignored by fuzzy entries, which always match case-insensitively.
+ */
+case_sensitive: boolean }
+/**
+ * How a custom word entry is matched against transcribed text.
+ */
+export type CustomWordKind = 
+/**
+ * Sound-alike and near-miss spellings of `pattern` are corrected to it.
+ */
+"fuzzy" | 
+/**
+ * `pattern` is replaced literally wherever it appears as a whole word.
+ */
+"exact" | 
+/**
+ * `pattern` is a regular expression; `replacement` may use `$1`-style groups.
+ */
+"regex"
 export type DecapitalizeOverlayStateResponse = { decapitalizeEligible: boolean; decapitalizeArmed: boolean }
 export type DeepgramFileTranscriptionOptions = { diarize: boolean | null; multichannel: boolean | null }
 export type DiarizationSpeakerNameProfile = { id: string; name: string; speaker_names?: string[] }
@@ -3896,7 +3950,33 @@ original_selection: string | null;
  * For AI Replace: the AI response (None if request failed/never received)
  */
 ai_response: string | null }
-export type LLMPrompt = { id: string; name: string; prompt: string }
+export type LLMPrompt = { id: string; name: string; prompt: string; 
+/**
+ * Optional word limit enforced on the post-processed output.
+ */
+max_output_words?: number | null; 
+/**
+ * Optional sentence limit enforced on the post-processed output.
+ */
+max_output_sentences?: number | null; 
+/**
+ * Optional JSON path (e.g. `$.body`) into the model's response. The prompt
+ * itself has to ask for JSON; when set, the value at this path becomes the
+ * final text and the raw response is kept in history.
+ */
+output_json_path?: string | null; 
+/**
+ * Model used for this prompt instead of the profile/global model.
+ */
+model_override?: string | null; 
+/**
+ * Turns reasoning on or off for this prompt regardless of the global setting.
+ */
+reasoning_enabled_override?: boolean | null; 
+/**
+ * Fixed reasoning budget for this prompt, replacing the global or adaptive one.
+ */
+reasoning_budget_override?: number | null }
 export type LiveSoundCaptureSource = "microphone" | "system_output" | "both"
 export type LiveSoundTranscriptSegmentPayload = { speaker_id: number | null; speaker_label: string | null; text: string; is_interim: boolean }
 export type LiveSoundTranscriptionProvider = "system" | "remote_soniox" | "remote_deepgram"
@@ -3921,7 +4001,7 @@ export type LlmFeature =
 export type LlmPostProcessBenchmarkResult = { timestamp_ms: number; provider_id: string; provider_label: string; model: string; duration_ms: number; chars_per_second: number; input_chars: number; output_chars: number; success: boolean; system_prompt: string; user_message: string; response_text: string; error: string | null }
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
 export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; sha256: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number; supports_translation: boolean; supports_streaming: boolean; native_streaming_latency_kind: NativeStreamingLatencyKind | null; supports_language_detection: boolean; is_recommended: boolean; supported_languages: string[]; is_custom: boolean }
-export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5" | "custom"
 export type NativeRegionCaptureMode = 
 /**
  * Most performant: transparent picker over the live desktop.
@@ -4023,7 +4103,12 @@ export type ShortcutEngine =
  * Use rdev low-level hooks (all keys supported, higher CPU usage)
  * Supports ALL keys including Caps Lock, Num Lock, and modifier-only shortcuts
  */
-"rdev"
+"rdev" | 
+/**
+ * Use the XDG Desktop Portal GlobalShortcuts interface (Linux Wayland only)
+ * Selected automatically on Wayland sessions, where key grabs and hooks are unavailable
+ */
+"portal"
 export type SonioxFileTranscriptionOptions = { languageHints: string[] | null; enableSpeakerDiarization: boolean | null; enableLanguageIdentification: boolean | null }
 export type SonioxLivePreviewAppearancePayload = { theme: string; opacity_percent: number; font_color: string; interim_font_color: string; accent_color: string; interim_opacity_percent: number; close_hotkey: string; clear_hotkey: string; flush_hotkey: string; process_hotkey: string; insert_hotkey: string; delete_until_dot_or_comma_hotkey: string; delete_until_dot_hotkey: string; delete_last_word_hotkey: string; show_clear_button: boolean; show_flush_button: boolean; show_process_button: boolean; show_insert_button: boolean; show_delete_until_dot_or_comma_button: boolean; show_delete_until_dot_button: boolean; show_delete_last_word_button: boolean; ctrl_backspace_delete_last_word: boolean; backspace_delete_last_char: boolean; show_drag_grip: boolean }
 export type SonioxLivePreviewChangedRange = { start: number; end: number; deleted?: boolean }
@@ -23,6 +23,7 @@ interface DownloadProgress {
   downloaded: number;
   total: number;
   percentage: number;
+  bytes_per_second: number;
 }
 
 interface ModelDownloadFailedEvent {
@@ -81,7 +82,8 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({
   const isRemoteProvider =
     transcriptionProvider === "remote_openai_compatible" ||
     transcriptionProvider === "remote_soniox" ||
-    transcriptionProvider === "remote_deepgram";
+    transcriptionProvider === "remote_deepgram" ||
+    transcriptionProvider === "remote_openai_realtime";
 
   const dropdownRef = useRef<HTMLDivElement>(null);
 
@@ -134,44 +136,17 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({
         });
         setModelStatus("downloading");
 
-        // Update download stats for speed calculation
+        // The backend measures the transfer rate over a short window.
         const now = Date.now();
         setDownloadStats((prev) => {
           const current = prev.get(progress.model_id);
           const newStats = new Map(prev);
-
-          if (!current) {
-            // First progress update - initialize
-            newStats.set(progress.model_id, {
-              startTime: now,
-              lastUpdate: now,
-              totalDownloaded: progress.downloaded,
-              speed: 0,
-            });
-          } else {
-            // Calculate speed over last few seconds
-            const timeDiff = (now - current.lastUpdate) / 1000; // seconds
-            const bytesDiff = progress.downloaded - current.totalDownloaded;
-
-            if (timeDiff > 0.5) {
-              // Update speed every 500ms
-              const currentSpeed = bytesDiff / (1024 * 1024) / timeDiff; // MB/s
-              // Smooth the speed with exponential moving average, but ensure positive values
-              const validCurrentSpeed = Math.max(0, currentSpeed);
-              const smoothedSpeed =
-                current.speed > 0
-                  ? current.speed * 0.8 + validCurrentSpeed * 0.2
-                  : validCurrentSpeed;
-
-              newStats.set(progress.model_id, {
-                startTime: current.startTime,
-                lastUpdate: now,
-                totalDownloaded: progress.downloaded,
-                speed: Math.max(0, smoothedSpeed),
-              });
-            }
-          }
-
+          newStats.set(progress.model_id, {
+            startTime: current?.startTime ?? now,
+            lastUpdate: now,
+            totalDownloaded: progress.downloaded,
+            speed: Math.max(0, (progress.bytes_per_second ?? 0) / (1024 * 1024)),
+          });
           return newStats;
         });
       },
@@ -489,6 +464,9 @@ const ModelSelector: React.FC<ModelSelectorProps> = ({
     if (transcriptionProvider === "remote_deepgram") {
       return t("modelSelector.remoteDeepgramActive", "Remote Deepgram");
     }
+    if (transcriptionProvider === "remote_openai_realtime") {
+      return t("modelSelector.remoteOpenAiRealtimeActive");
+    }
 
     if (extractingModels.size > 0) {
       if (extractingModels.size === 1) {
@@ -0,0 +1,145 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+
+const DEFAULT_ACTION_API_PORT = 38244;
+
+interface ActionApiProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const ActionApi: React.FC<ActionApiProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating, refreshSettings } =
+    useSettings();
+
+  const enabled = getSetting("action_api_enabled" as any) ?? false;
+  const port = getSetting("action_api_port" as any) ?? DEFAULT_ACTION_API_PORT;
+  const token: string = getSetting("action_api_token" as any) ?? "";
+
+  const [portInput, setPortInput] = useState(String(port));
+  const [showToken, setShowToken] = useState(false);
+
+  useEffect(() => {
+    setPortInput(String(port));
+  }, [port]);
+
+  const commitPort = async () => {
+    const value = parseInt(portInput, 10);
+    if (isNaN(value) || value === port) {
+      setPortInput(String(port));
+      return;
+    }
+    try {
+      await invoke("change_action_api_port_setting", { port: value });
+      await refreshSettings();
+    } catch (e) {
+      toast.error(String(e));
+      setPortInput(String(port));
+    }
+  };
+
+  const copyToken = async () => {
+    try {
+      await navigator.clipboard.writeText(token);
+      toast.success(t("settings.advanced.actionApi.tokenCopied"));
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  const regenerateToken = async () => {
+    try {
+      await invoke("regenerate_action_api_token");
+      await refreshSettings();
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  return (
+    <div className="flex flex-col">
+      <SettingContainer
+        title={t("settings.advanced.actionApi.title")}
+        description={t("settings.advanced.actionApi.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(checked) =>
+            updateSetting("action_api_enabled" as any, checked)
+          }
+          disabled={isUpdating("action_api_enabled" as any)}
+        />
+      </SettingContainer>
+
+      {enabled && (
+        <div className="pl-4 ml-6 border-l-2 border-surface-highlight py-2 space-y-4 relative -top-2">
+          <SettingContainer
+            title={t("settings.advanced.actionApi.portTitle")}
+            description={t("settings.advanced.actionApi.portDescription")}
+            descriptionMode={descriptionMode}
+            grouped={true}
+            layout="horizontal"
+          >
+            <Input
+              type="number"
+              min={1024}
+              max={65535}
+              variant="compact"
+              className="w-24"
+              value={portInput}
+              onChange={(e) => setPortInput(e.target.value)}
+              onBlur={commitPort}
+            />
+          </SettingContainer>
+
+          <SettingContainer
+            title={t("settings.advanced.actionApi.tokenTitle")}
+            description={t("settings.advanced.actionApi.tokenDescription")}
+            descriptionMode={descriptionMode}
+            grouped={true}
+            layout="horizontal"
+          >
+            <div className="flex items-center gap-2">
+              <Input
+                type={showToken ? "text" : "password"}
+                variant="compact"
+                className="w-48 font-mono"
+                value={token}
+                readOnly
+              />
+              <Button
+                variant="secondary"
+                size="sm"
+                onClick={() => setShowToken(!showToken)}
+              >
+                {showToken
+                  ? t("settings.advanced.actionApi.hideToken")
+                  : t("settings.advanced.actionApi.showToken")}
+              </Button>
+              <Button variant="secondary" size="sm" onClick={copyToken}>
+                {t("settings.advanced.actionApi.copyToken")}
+              </Button>
+              <Button variant="secondary" size="sm" onClick={regenerateToken}>
+                {t("settings.advanced.actionApi.regenerateToken")}
+              </Button>
+            </div>
+          </SettingContainer>
+        </div>
+      )}
+    </div>
+  );
+};
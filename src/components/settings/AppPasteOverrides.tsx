@@ -0,0 +1,198 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { type as getOsType } from "@tauri-apps/plugin-os";
+import { Trash2 } from "lucide-react";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { useSettings } from "../../hooks/useSettings";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+import { getPasteMethodOptions } from "./PasteMethod";
+import type { PasteMethod } from "@/bindings";
+
+interface AppPasteOverride {
+  id: string;
+  app_pattern: string;
+  method: PasteMethod;
+  paste_delay_ms?: number | null;
+  glob: boolean;
+}
+
+interface AppPasteOverridesProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const parseDelay = (value: string): number | null => {
+  const parsed = parseInt(value, 10);
+  return Number.isFinite(parsed) && parsed >= 0 ? parsed : null;
+};
+
+export const AppPasteOverrides: React.FC<AppPasteOverridesProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const [osType, setOsType] = useState<string>("unknown");
+    const [newPattern, setNewPattern] = useState("");
+    const [isSaving, setIsSaving] = useState(false);
+
+    useEffect(() => {
+      setOsType(getOsType());
+    }, []);
+
+    const overrides = (getSetting("app_paste_overrides" as any) ??
+      []) as AppPasteOverride[];
+    const methodOptions = getPasteMethodOptions(t, osType);
+
+    const run = async (command: string, args: Record<string, unknown>) => {
+      setIsSaving(true);
+      try {
+        await invoke(command, args);
+        await refreshSettings();
+      } catch (error) {
+        toast.error(String(error));
+      } finally {
+        setIsSaving(false);
+      }
+    };
+
+    const handleAdd = async () => {
+      if (!newPattern.trim()) return;
+      await run("add_app_paste_override", {
+        appPattern: newPattern,
+        method: (getSetting("paste_method") || "ctrl_v") as PasteMethod,
+        pasteDelayMs: null,
+        glob: false,
+      });
+      setNewPattern("");
+    };
+
+    const handleUpdate = (
+      entry: AppPasteOverride,
+      changes: Partial<AppPasteOverride>,
+    ) => {
+      const next = { ...entry, ...changes };
+      if (!next.app_pattern.trim()) return;
+      run("update_app_paste_override", {
+        id: next.id,
+        appPattern: next.app_pattern,
+        method: next.method,
+        pasteDelayMs: next.paste_delay_ms ?? null,
+        glob: next.glob,
+      });
+    };
+
+    // The frontmost app is only captured on Windows.
+    if (osType !== "windows") {
+      return null;
+    }
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.pasteMethod.appOverrides.title")}
+        description={t(
+          "settings.advanced.pasteMethod.appOverrides.description",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="stacked"
+      >
+        <div className="space-y-2">
+          {overrides.map((entry) => (
+            <div key={entry.id} className="flex items-center gap-2">
+              <Input
+                type="text"
+                className="flex-1 min-w-0"
+                defaultValue={entry.app_pattern}
+                onBlur={(e) => {
+                  if (e.target.value.trim() !== entry.app_pattern) {
+                    handleUpdate(entry, { app_pattern: e.target.value });
+                  }
+                }}
+                variant="compact"
+                disabled={isSaving}
+              />
+              <label className="flex items-center gap-1 text-xs text-mid-gray">
+                <input
+                  type="checkbox"
+                  checked={entry.glob}
+                  onChange={(e) =>
+                    handleUpdate(entry, { glob: e.target.checked })
+                  }
+                  disabled={isSaving}
+                />
+                {t("settings.advanced.pasteMethod.appOverrides.glob")}
+              </label>
+              <Dropdown
+                options={methodOptions}
+                selectedValue={entry.method}
+                onSelect={(value) =>
+                  handleUpdate(entry, { method: value as PasteMethod })
+                }
+                disabled={isSaving}
+              />
+              <Input
+                type="number"
+                min={0}
+                className="w-20"
+                defaultValue={entry.paste_delay_ms ?? ""}
+                placeholder={t(
+                  "settings.advanced.pasteMethod.appOverrides.delayPlaceholder",
+                )}
+                title={t("settings.advanced.pasteMethod.appOverrides.delay")}
+                onBlur={(e) => {
+                  const delay = parseDelay(e.target.value);
+                  if (delay !== (entry.paste_delay_ms ?? null)) {
+                    handleUpdate(entry, { paste_delay_ms: delay });
+                  }
+                }}
+                variant="compact"
+                disabled={isSaving}
+              />
+              <button
+                type="button"
+                onClick={() =>
+                  run("delete_app_paste_override", { id: entry.id })
+                }
+                disabled={isSaving}
+                className="p-1 text-mid-gray hover:text-red-400 disabled:opacity-30"
+                title={t("settings.advanced.pasteMethod.appOverrides.remove")}
+              >
+                <Trash2 width={14} height={14} />
+              </button>
+            </div>
+          ))}
+          <div className="flex items-center gap-2">
+            <Input
+              type="text"
+              className="flex-1 min-w-0"
+              value={newPattern}
+              onChange={(e) => setNewPattern(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") {
+                  e.preventDefault();
+                  handleAdd();
+                }
+              }}
+              placeholder={t(
+                "settings.advanced.pasteMethod.appOverrides.placeholder",
+              )}
+              variant="compact"
+              disabled={isSaving}
+            />
+            <Button
+              onClick={handleAdd}
+              disabled={!newPattern.trim() || isSaving}
+              variant="primary"
+              size="md"
+            >
+              {t("settings.advanced.pasteMethod.appOverrides.add")}
+            </Button>
+          </div>
+        </div>
+      </SettingContainer>
+    );
+  },
+);
@@ -0,0 +1,95 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface AudioPreprocessingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+interface HighPassCutoffLimits {
+  min_hz: number;
+  max_hz: number;
+}
+
+export const AudioPreprocessing: React.FC<AudioPreprocessingProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [limits, setLimits] = useState<HighPassCutoffLimits | null>(null);
+
+    useEffect(() => {
+      invoke<HighPassCutoffLimits>("get_audio_preprocessing_high_pass_limits")
+        .then(setLimits)
+        .catch((error) =>
+          console.error("Failed to load high-pass cutoff limits:", error),
+        );
+    }, []);
+
+    const minCutoff = limits?.min_hz ?? 0;
+    const maxCutoff = limits?.max_hz ?? Number.MAX_SAFE_INTEGER;
+
+    const enabled =
+      (getSetting("audio_preprocessing_enabled" as any) ?? false) === true;
+    const cutoffRaw = getSetting("audio_preprocessing_high_pass_hz" as any) ?? 80;
+    const cutoff = Number.isFinite(cutoffRaw)
+      ? Math.min(maxCutoff, Math.max(minCutoff, Number(cutoffRaw)))
+      : 80;
+
+    const handleCutoffChange = (event: React.ChangeEvent<HTMLInputElement>) => {
+      const value = parseInt(event.target.value, 10);
+      if (!isNaN(value)) {
+        const clamped = Math.min(maxCutoff, Math.max(minCutoff, value));
+        updateSetting("audio_preprocessing_high_pass_hz" as any, clamped);
+      }
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.sound.microphone.preprocessing.title")}
+          description={t("settings.sound.microphone.preprocessing.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <ToggleSwitch
+            checked={enabled}
+            onChange={(checked) =>
+              updateSetting("audio_preprocessing_enabled" as any, checked)
+            }
+            isUpdating={isUpdating("audio_preprocessing_enabled" as any)}
+          />
+        </SettingContainer>
+        {enabled && (
+          <SettingContainer
+            title={t("settings.sound.microphone.preprocessing.cutoffTitle")}
+            description={t(
+              "settings.sound.microphone.preprocessing.cutoffDescription",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          >
+            <div className="flex items-center space-x-2">
+              <Input
+                type="number"
+                min={limits?.min_hz}
+                max={limits?.max_hz}
+                value={cutoff}
+                onChange={handleCutoffChange}
+                disabled={isUpdating("audio_preprocessing_high_pass_hz" as any)}
+                className="w-24 text-right"
+              />
+              <span className="text-sm text-text/70">Hz</span>
+            </div>
+          </SettingContainer>
+        )}
+      </>
+    );
+  },
+);
+
+AudioPreprocessing.displayName = "AudioPreprocessing";
@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Unplug } from "lucide-react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface AutoRecoverInputDeviceProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const AutoRecoverInputDevice: React.FC<AutoRecoverInputDeviceProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled =
+      (getSetting("auto_recover_input_device" as any) ?? false) === true;
+
+    return (
+      <SettingContainer
+        title={
+          <span className="inline-flex items-center gap-2">
+            <Unplug className="h-4 w-4 text-[#9b5de5]" />
+            <span>
+              {t(
+                "settings.sound.microphone.autoRecover.title",
+                "Recover From Disconnects",
+              )}
+            </span>
+          </span>
+        }
+        description={t(
+          "settings.sound.microphone.autoRecover.description",
+          "If the microphone is unplugged while recording, switch to the default input device and keep recording. The audio from the switch is missing. When off, the recording stops and what was captured so far is transcribed.",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(checked) =>
+            updateSetting("auto_recover_input_device" as any, checked)
+          }
+          isUpdating={isUpdating("auto_recover_input_device" as any)}
+        />
+      </SettingContainer>
+    );
+  });
+
+AutoRecoverInputDevice.displayName = "AutoRecoverInputDevice";
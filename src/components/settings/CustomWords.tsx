@@ -1,11 +1,25 @@
-import React, { useState } from "react";
+import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { open, save } from "@tauri-apps/plugin-dialog";
 import { sessionToast as toast } from "@/lib/sessionToast";
 import { useSettings } from "../../hooks/useSettings";
 import { Input } from "../ui/Input";
 import { Button } from "../ui/Button";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
 import { SettingContainer } from "../ui/SettingContainer";
+import { Dropdown } from "../ui/Dropdown";
+import type { CustomWord, CustomWordKind } from "@/bindings";
+
+const fuzzyEntry = (word: string): CustomWord => ({
+  pattern: word,
+  replacement: "",
+  kind: "fuzzy",
+  case_sensitive: false,
+});
+
+const ruleKey = (entry: CustomWord) =>
+  `${entry.kind}:${entry.case_sensitive ? 1 : 0}:${entry.pattern}`;
 
 interface CustomWordsProps {
   descriptionMode?: "inline" | "tooltip";
@@ -15,9 +29,42 @@ interface CustomWordsProps {
 export const CustomWords: React.FC<CustomWordsProps> = React.memo(
   ({ descriptionMode = "tooltip", grouped = false }) => {
     const { t } = useTranslation();
-    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const { getSetting, updateSetting, isUpdating, refreshSettings } =
+      useSettings();
     const [newWord, setNewWord] = useState("");
-    const customWords = getSetting("custom_words") || [];
+    const [isTransferring, setIsTransferring] = useState(false);
+    const [rulePattern, setRulePattern] = useState("");
+    const [ruleReplacement, setRuleReplacement] = useState("");
+    const [ruleKind, setRuleKind] = useState<CustomWordKind>("exact");
+    const [ruleCaseSensitive, setRuleCaseSensitive] = useState(false);
+    const [ruleError, setRuleError] = useState<string | null>(null);
+    const customWords: CustomWord[] = getSetting("custom_words") || [];
+    const fuzzyWords = customWords
+      .filter((entry) => entry.kind === "fuzzy")
+      .map((entry) => entry.pattern);
+    const rules = customWords.filter((entry) => entry.kind !== "fuzzy");
+
+    useEffect(() => {
+      if (!rulePattern.trim()) {
+        setRuleError(null);
+        return;
+      }
+      let cancelled = false;
+      invoke("validate_custom_word_pattern", {
+        pattern: rulePattern,
+        kind: ruleKind,
+        caseSensitive: ruleCaseSensitive,
+      })
+        .then(() => {
+          if (!cancelled) setRuleError(null);
+        })
+        .catch((error) => {
+          if (!cancelled) setRuleError(String(error));
+        });
+      return () => {
+        cancelled = true;
+      };
+    }, [rulePattern, ruleKind, ruleCaseSensitive]);
     const customWordsEnabled = getSetting("custom_words_enabled");
     const isCustomWordsEnabled = customWordsEnabled ?? true;
 
@@ -29,7 +76,7 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
         !sanitizedWord.includes(" ") &&
         sanitizedWord.length <= 50
       ) {
-        if (customWords.includes(sanitizedWord)) {
+        if (fuzzyWords.includes(sanitizedWord)) {
           toast.error(
             t("settings.advanced.customWords.duplicate", {
               word: sanitizedWord,
@@ -37,7 +84,10 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
           );
           return;
         }
-        updateSetting("custom_words", [...customWords, sanitizedWord]);
+        updateSetting("custom_words", [
+          ...customWords,
+          fuzzyEntry(sanitizedWord),
+        ]);
         setNewWord("");
       }
     };
@@ -45,10 +95,85 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
     const handleRemoveWord = (wordToRemove: string) => {
       updateSetting(
         "custom_words",
-        customWords.filter((word) => word !== wordToRemove),
+        customWords.filter(
+          (entry) => entry.kind !== "fuzzy" || entry.pattern !== wordToRemove,
+        ),
       );
     };
 
+    const handleAddRule = () => {
+      if (!rulePattern.trim() || ruleError) return;
+      const rule: CustomWord = {
+        pattern: ruleKind === "exact" ? rulePattern.trim() : rulePattern,
+        replacement: ruleReplacement,
+        kind: ruleKind,
+        case_sensitive: ruleCaseSensitive,
+      };
+      if (rules.some((entry) => ruleKey(entry) === ruleKey(rule))) {
+        toast.error(
+          t("settings.advanced.customWords.duplicate", {
+            word: rule.pattern,
+          }),
+        );
+        return;
+      }
+      updateSetting("custom_words", [...customWords, rule]);
+      setRulePattern("");
+      setRuleReplacement("");
+    };
+
+    const handleRemoveRule = (ruleToRemove: CustomWord) => {
+      updateSetting(
+        "custom_words",
+        customWords.filter(
+          (entry) => ruleKey(entry) !== ruleKey(ruleToRemove),
+        ),
+      );
+    };
+
+    const handleImport = async () => {
+      const path = await open({
+        multiple: false,
+        filters: [{ name: "Text", extensions: ["txt"] }],
+      });
+      if (typeof path !== "string") return;
+      setIsTransferring(true);
+      try {
+        const result = await invoke<{ added: number; skipped: number }>(
+          "import_custom_words",
+          { path },
+        );
+        await refreshSettings();
+        toast.success(
+          t("settings.advanced.customWords.imported", {
+            added: result.added,
+            skipped: result.skipped,
+          }),
+        );
+      } catch (error) {
+        toast.error(String(error));
+      } finally {
+        setIsTransferring(false);
+      }
+    };
+
+    const handleExport = async () => {
+      const path = await save({
+        filters: [{ name: "Text", extensions: ["txt"] }],
+        defaultPath: "custom-words.txt",
+      });
+      if (!path) return;
+      setIsTransferring(true);
+      try {
+        const count = await invoke<number>("export_custom_words", { path });
+        toast.success(t("settings.advanced.customWords.exported", { count }));
+      } catch (error) {
+        toast.error(String(error));
+      } finally {
+        setIsTransferring(false);
+      }
+    };
+
     const handleKeyPress = (e: React.KeyboardEvent) => {
       if (e.key === "Enter") {
         e.preventDefault();
@@ -105,13 +230,29 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
             >
               {t("settings.advanced.customWords.add")}
             </Button>
+            <Button
+              onClick={handleImport}
+              disabled={isTransferring || isUpdating("custom_words")}
+              variant="secondary"
+              size="md"
+            >
+              {t("settings.advanced.customWords.import")}
+            </Button>
+            <Button
+              onClick={handleExport}
+              disabled={isTransferring || fuzzyWords.length === 0}
+              variant="secondary"
+              size="md"
+            >
+              {t("settings.advanced.customWords.export")}
+            </Button>
           </div>
         </SettingContainer>
-        {customWords.length > 0 && (
+        {fuzzyWords.length > 0 && (
           <div
             className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} flex flex-wrap gap-1`}
           >
-            {customWords.map((word) => (
+            {fuzzyWords.map((word) => (
               <Button
                 key={word}
                 onClick={() => handleRemoveWord(word)}
@@ -139,6 +280,117 @@ export const CustomWords: React.FC<CustomWordsProps> = React.memo(
             ))}
           </div>
         )}
+        <SettingContainer
+          title={t("settings.advanced.customWords.rules.title")}
+          description={t("settings.advanced.customWords.rules.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="stacked"
+        >
+          <div className="flex flex-wrap items-center gap-2">
+            <Dropdown
+              className="w-28"
+              options={[
+                {
+                  value: "exact",
+                  label: t("settings.advanced.customWords.rules.kinds.exact"),
+                },
+                {
+                  value: "regex",
+                  label: t("settings.advanced.customWords.rules.kinds.regex"),
+                },
+              ]}
+              selectedValue={ruleKind}
+              onSelect={(value) => setRuleKind(value as CustomWordKind)}
+              dropUp={false}
+            />
+            <Input
+              type="text"
+              className="max-w-40"
+              value={rulePattern}
+              onChange={(e) => setRulePattern(e.target.value)}
+              placeholder={t(
+                "settings.advanced.customWords.rules.patternPlaceholder",
+              )}
+              variant="compact"
+              disabled={isUpdating("custom_words")}
+            />
+            <Input
+              type="text"
+              className="max-w-40"
+              value={ruleReplacement}
+              onChange={(e) => setRuleReplacement(e.target.value)}
+              placeholder={t(
+                "settings.advanced.customWords.rules.replacementPlaceholder",
+              )}
+              variant="compact"
+              disabled={isUpdating("custom_words")}
+            />
+            <label className="flex items-center gap-1 text-sm">
+              <input
+                type="checkbox"
+                checked={ruleCaseSensitive}
+                onChange={(e) => setRuleCaseSensitive(e.target.checked)}
+              />
+              {t("settings.advanced.customWords.rules.caseSensitive")}
+            </label>
+            <Button
+              onClick={handleAddRule}
+              disabled={
+                !rulePattern.trim() ||
+                ruleError !== null ||
+                isUpdating("custom_words")
+              }
+              variant="primary"
+              size="md"
+            >
+              {t("settings.advanced.customWords.add")}
+            </Button>
+          </div>
+          {ruleError && (
+            <p className="mt-1 text-xs text-red-400">
+              {t("settings.advanced.customWords.rules.invalid", {
+                error: ruleError,
+              })}
+            </p>
+          )}
+          {rules.length > 0 && (
+            <ul className="mt-2 space-y-1">
+              {rules.map((rule) => (
+                <li
+                  key={ruleKey(rule)}
+                  className="flex items-center gap-2 text-sm"
+                >
+                  <span className="text-xs text-mid-gray uppercase">
+                    {t(
+                      `settings.advanced.customWords.rules.kinds.${rule.kind}`,
+                    )}
+                  </span>
+                  <code className="truncate">{rule.pattern}</code>
+                  <span>→</span>
+                  <code className="truncate">{rule.replacement}</code>
+                  {rule.case_sensitive && (
+                    <span className="text-xs text-mid-gray">
+                      {t("settings.advanced.customWords.rules.caseSensitive")}
+                    </span>
+                  )}
+                  <Button
+                    onClick={() => handleRemoveRule(rule)}
+                    disabled={isUpdating("custom_words")}
+                    variant="secondary"
+                    size="sm"
+                    className="ml-auto"
+                    aria-label={t("settings.advanced.customWords.remove", {
+                      word: rule.pattern,
+                    })}
+                  >
+                    ×
+                  </Button>
+                </li>
+              ))}
+            </ul>
+          )}
+        </SettingContainer>
       </>
     );
   },
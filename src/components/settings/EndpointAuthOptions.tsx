@@ -0,0 +1,111 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Textarea } from "../ui/Textarea";
+
+export type ApiAuthStyle = "bearer" | "azure_api_key";
+
+const AUTH_STYLES: ApiAuthStyle[] = ["bearer", "azure_api_key"];
+
+const formatQueryParams = (params: Record<string, string>) =>
+  Object.entries(params)
+    .map(([name, value]) => `${name}=${value}`)
+    .join("\n");
+
+/** Parses one `name=value` pair per line; blank lines are ignored. */
+const parseQueryParams = (text: string): Record<string, string> => {
+  const params: Record<string, string> = {};
+  for (const line of text.split("\n")) {
+    if (!line.trim()) continue;
+    const separator = line.indexOf("=");
+    const name = separator === -1 ? line : line.slice(0, separator);
+    params[name.trim()] =
+      separator === -1 ? "" : line.slice(separator + 1).trim();
+  }
+  return params;
+};
+
+interface EndpointAuthOptionsProps {
+  authStyle: ApiAuthStyle;
+  extraQueryParams: Record<string, string>;
+  onAuthStyleChange: (authStyle: ApiAuthStyle) => Promise<void>;
+  onExtraQueryParamsChange: (params: Record<string, string>) => Promise<void>;
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+/** Auth header and query string controls for custom OpenAI-compatible endpoints. */
+export const EndpointAuthOptions: React.FC<EndpointAuthOptionsProps> = ({
+  authStyle,
+  extraQueryParams,
+  onAuthStyleChange,
+  onExtraQueryParamsChange,
+  descriptionMode = "tooltip",
+  grouped = true,
+}) => {
+  const { t } = useTranslation();
+  const savedParams = formatQueryParams(extraQueryParams);
+  const [paramsInput, setParamsInput] = useState(savedParams);
+
+  useEffect(() => {
+    setParamsInput(savedParams);
+  }, [savedParams]);
+
+  const handleParamsBlur = async () => {
+    if (paramsInput === savedParams) return;
+    try {
+      await onExtraQueryParamsChange(parseQueryParams(paramsInput));
+    } catch (error) {
+      toast.error(String(error));
+      setParamsInput(savedParams);
+    }
+  };
+
+  const handleAuthStyleSelect = async (value: string) => {
+    try {
+      await onAuthStyleChange(value as ApiAuthStyle);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        title={t("settings.endpointAuth.authStyle.title")}
+        description={t("settings.endpointAuth.authStyle.description")}
+        descriptionMode={descriptionMode}
+        layout="horizontal"
+        grouped={grouped}
+      >
+        <Dropdown
+          selectedValue={authStyle}
+          options={AUTH_STYLES.map((value) => ({
+            value,
+            label: t(`settings.endpointAuth.authStyle.options.${value}`),
+          }))}
+          onSelect={(value) => void handleAuthStyleSelect(value)}
+        />
+      </SettingContainer>
+
+      <SettingContainer
+        title={t("settings.endpointAuth.queryParams.title")}
+        description={t("settings.endpointAuth.queryParams.description")}
+        descriptionMode={descriptionMode}
+        layout="stacked"
+        grouped={grouped}
+      >
+        <Textarea
+          variant="compact"
+          value={paramsInput}
+          onChange={(event) => setParamsInput(event.target.value)}
+          onBlur={() => void handleParamsBlur()}
+          placeholder={t("settings.endpointAuth.queryParams.placeholder")}
+          className="w-full min-h-[60px] font-mono"
+        />
+      </SettingContainer>
+    </>
+  );
+};
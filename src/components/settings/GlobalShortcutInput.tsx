@@ -3,11 +3,16 @@ import { useTranslation } from "react-i18next";
 import { type } from "@tauri-apps/plugin-os";
 import {
   getKeyName,
+  applyShortcutTrigger,
   formatKeyCombination,
   isModifierOnlyShortcut,
   normalizeKey,
+  splitShortcutTrigger,
   type OSType,
+  type ShortcutTrigger,
 } from "../../lib/utils/keyboard";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
 import { ResetButton } from "../ui/ResetButton";
 import { SettingContainer } from "../ui/SettingContainer";
 import { useSettings } from "../../hooks/useSettings";
@@ -146,7 +151,13 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
           if (!aIsModifier && bIsModifier) return 1;
           return 0;
         });
-        const newShortcut = sortedKeys.join("+");
+        // Re-recording the keys keeps a double-tap or hold trigger.
+        const { trigger, holdMs } = splitShortcutTrigger(originalBinding);
+        const newShortcut = applyShortcutTrigger(
+          sortedKeys.join("+"),
+          configuredShortcutEngine === "rdev" ? trigger : "press",
+          holdMs,
+        );
 
         if (editingShortcutId && bindings[editingShortcutId]) {
           try {
@@ -253,6 +264,27 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
     shortcutRefs.current.set(id, ref);
   };
 
+  const changeTrigger = async (
+    id: string,
+    currentBinding: string,
+    trigger: ShortcutTrigger,
+    holdMs?: number,
+  ) => {
+    const parsed = splitShortcutTrigger(currentBinding);
+    const updated = applyShortcutTrigger(
+      parsed.chord,
+      trigger,
+      holdMs ?? parsed.holdMs,
+    );
+    if (updated === currentBinding) return;
+    try {
+      await updateBinding(id, updated);
+    } catch (error) {
+      console.error("Failed to change shortcut trigger:", error);
+      showShortcutSetErrorToast(error, configuredShortcutEngine, t);
+    }
+  };
+
   if (isLoading) {
     return (
       <SettingContainer
@@ -299,6 +331,13 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
     );
   }
 
+  const currentTrigger = splitShortcutTrigger(binding.current_binding);
+  const triggerOptions = [
+    { value: "press", label: t("settings.general.shortcut.trigger.press") },
+    { value: "double", label: t("settings.general.shortcut.trigger.double") },
+    { value: "hold", label: t("settings.general.shortcut.trigger.hold") },
+  ];
+
   const translatedName = t(
     `settings.general.shortcut.bindings.${shortcutId}.name`,
     binding.name,
@@ -318,6 +357,46 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
       layout="horizontal"
     >
       <div className="flex items-center space-x-1">
+        {configuredShortcutEngine === "rdev" && binding.current_binding && (
+          <>
+            <Dropdown
+              options={triggerOptions}
+              selectedValue={currentTrigger.trigger}
+              onSelect={(value) =>
+                void changeTrigger(
+                  shortcutId,
+                  binding.current_binding,
+                  value as ShortcutTrigger,
+                )
+              }
+              disabled={disabled || isUpdating(`binding_${shortcutId}`)}
+            />
+            {currentTrigger.trigger === "hold" && (
+              <Input
+                type="number"
+                variant="compact"
+                min={100}
+                max={5000}
+                step={50}
+                defaultValue={currentTrigger.holdMs}
+                key={binding.current_binding}
+                title={t("settings.general.shortcut.trigger.holdMs")}
+                onBlur={(e) => {
+                  const holdMs = parseInt(e.target.value, 10);
+                  if (!isNaN(holdMs)) {
+                    void changeTrigger(
+                      shortcutId,
+                      binding.current_binding,
+                      "hold",
+                      Math.min(5000, Math.max(100, holdMs)),
+                    );
+                  }
+                }}
+                className="w-20 text-right"
+              />
+            )}
+          </>
+        )}
         {editingShortcutId === shortcutId ? (
           <div
             ref={(ref) => setShortcutRef(shortcutId, ref)}
@@ -0,0 +1,59 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { useModels } from "../../hooks/useModels";
+import { Dropdown } from "../ui/Dropdown";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface LocalFallbackModelProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const LocalFallbackModel: React.FC<LocalFallbackModelProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, updateSetting } = useSettings();
+  const { models } = useModels();
+
+  const enabled = (settings as any)?.local_fallback_enabled ?? false;
+  const fallbackModel: string | null =
+    (settings as any)?.local_fallback_model ?? null;
+  const options = models
+    .filter(
+      (model) => model.is_downloaded && model.id !== settings?.selected_model,
+    )
+    .map((model) => ({ value: model.id, label: model.name }));
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.localFallback.title")}
+      description={t("settings.advanced.localFallback.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <div className="flex items-center gap-2">
+        <Dropdown
+          options={options}
+          selectedValue={fallbackModel}
+          placeholder={t("settings.advanced.localFallback.placeholder")}
+          onSelect={(value) =>
+            updateSetting("local_fallback_model" as any, value || null)
+          }
+          disabled={options.length === 0}
+          dropUp={true}
+        />
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) =>
+            updateSetting("local_fallback_enabled" as any, value)
+          }
+          disabled={!fallbackModel}
+        />
+      </div>
+    </SettingContainer>
+  );
+};
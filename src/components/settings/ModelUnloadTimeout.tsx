@@ -1,9 +1,18 @@
-import React from "react";
+import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { useSettings } from "../../hooks/useSettings";
 import { type ModelUnloadTimeout } from "@/bindings";
 import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
 import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+
+interface ModelLoadState {
+  loaded: boolean;
+  model_id: string | null;
+}
 
 interface ModelUnloadTimeoutProps {
   descriptionMode?: "tooltip" | "inline";
@@ -15,7 +24,21 @@ export const ModelUnloadTimeoutSetting: React.FC<ModelUnloadTimeoutProps> = ({
   grouped = false,
 }) => {
   const { t } = useTranslation();
-  const { getSetting, updateSetting } = useSettings();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const [loadState, setLoadState] = useState<ModelLoadState | null>(null);
+
+  useEffect(() => {
+    invoke<ModelLoadState>("get_model_load_state")
+      .then(setLoadState)
+      .catch((e) => console.error("Failed to read model load state", e));
+    const unlistenPromise = listen<ModelLoadState>(
+      "model-load-state",
+      (event) => setLoadState(event.payload),
+    );
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, []);
 
   const options = [
     {
@@ -50,6 +73,10 @@ export const ModelUnloadTimeoutSetting: React.FC<ModelUnloadTimeoutProps> = ({
       value: "sec_5" as ModelUnloadTimeout,
       label: t("settings.advanced.modelUnload.options.sec5"),
     },
+    {
+      value: "custom" as ModelUnloadTimeout,
+      label: t("settings.advanced.modelUnload.options.custom"),
+    },
   ];
 
   const handleChange = (event: React.ChangeEvent<HTMLSelectElement>) => {
@@ -58,25 +85,92 @@ export const ModelUnloadTimeoutSetting: React.FC<ModelUnloadTimeoutProps> = ({
   };
 
   const currentValue = getSetting("model_unload_timeout") ?? "min_15";
+  const keepLoadedRaw = getSetting("model_keep_loaded_minutes" as any) ?? 30;
+  const keepLoadedMinutes = Number.isFinite(keepLoadedRaw)
+    ? Math.min(1440, Math.max(1, Number(keepLoadedRaw)))
+    : 30;
+  const preload = getSetting("model_preload_on_startup" as any) ?? false;
+
+  const handleKeepLoadedChange = (
+    event: React.ChangeEvent<HTMLInputElement>,
+  ) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value)) {
+      const clamped = Math.min(1440, Math.max(1, value));
+      updateSetting("model_keep_loaded_minutes" as any, clamped);
+    }
+  };
 
   return (
-    <SettingContainer
-      title={t("settings.advanced.modelUnload.title")}
-      description={t("settings.advanced.modelUnload.description")}
-      descriptionMode={descriptionMode}
-      grouped={grouped}
-    >
-      <Dropdown
-        options={options}
-        selectedValue={currentValue}
-        onSelect={(value) =>
-          handleChange({
-            target: { value },
-          } as React.ChangeEvent<HTMLSelectElement>)
-        }
-        disabled={false}
-        dropUp={true}
-      />
-    </SettingContainer>
+    <>
+      <SettingContainer
+        title={t("settings.advanced.modelUnload.title")}
+        description={t("settings.advanced.modelUnload.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center gap-2">
+          {loadState && (
+            <span className="text-xs text-mid-gray">
+              {loadState.loaded
+                ? t("settings.advanced.modelUnload.status.loaded")
+                : t("settings.advanced.modelUnload.status.unloaded")}
+            </span>
+          )}
+          <Dropdown
+            options={options}
+            selectedValue={currentValue}
+            onSelect={(value) =>
+              handleChange({
+                target: { value },
+              } as React.ChangeEvent<HTMLSelectElement>)
+            }
+            disabled={false}
+            dropUp={true}
+          />
+        </div>
+      </SettingContainer>
+      {currentValue === "custom" && (
+        <SettingContainer
+          title={t("settings.advanced.modelUnload.customMinutesTitle")}
+          description={t(
+            "settings.advanced.modelUnload.customMinutesDescription",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="horizontal"
+        >
+          <div className="flex items-center space-x-2">
+            <Input
+              type="number"
+              min={1}
+              max={1440}
+              value={keepLoadedMinutes}
+              onChange={handleKeepLoadedChange}
+              disabled={isUpdating("model_keep_loaded_minutes" as any)}
+              className="w-24 text-right"
+            />
+            <span className="text-sm text-text/70">
+              {t("settings.advanced.modelUnload.minutes")}
+            </span>
+          </div>
+        </SettingContainer>
+      )}
+      <SettingContainer
+        title={t("settings.advanced.modelUnload.preloadTitle")}
+        description={t("settings.advanced.modelUnload.preloadDescription")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <ToggleSwitch
+          checked={preload}
+          onChange={(checked) =>
+            updateSetting("model_preload_on_startup" as any, checked)
+          }
+          disabled={isUpdating("model_preload_on_startup" as any)}
+        />
+      </SettingContainer>
+    </>
   );
 };
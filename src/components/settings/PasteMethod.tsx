@@ -1,5 +1,6 @@
 import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
+import type { TFunction } from "i18next";
 import { type as getOsType } from "@tauri-apps/plugin-os";
 import { Dropdown } from "../ui/Dropdown";
 import { Slider } from "../ui/Slider";
@@ -7,6 +8,47 @@ import { SettingContainer } from "../ui/SettingContainer";
 import { useSettings } from "../../hooks/useSettings";
 import type { PasteMethod } from "@/bindings";
 
+export const getPasteMethodOptions = (t: TFunction, osType: string) => {
+  const mod = osType === "macos" ? "Cmd" : "Ctrl";
+
+  const options = [
+    {
+      value: "ctrl_v",
+      label: t("settings.advanced.pasteMethod.options.clipboard", {
+        modifier: mod,
+      }),
+    },
+    {
+      value: "direct",
+      label: t("settings.advanced.pasteMethod.options.direct"),
+    },
+    {
+      value: "type_characters",
+      label: t("settings.advanced.pasteMethod.options.typeCharacters"),
+    },
+    {
+      value: "none",
+      label: t("settings.advanced.pasteMethod.options.none"),
+    },
+  ];
+
+  // Add Shift+Insert and Ctrl+Shift+V options for Windows and Linux only
+  if (osType === "windows" || osType === "linux") {
+    options.push(
+      {
+        value: "ctrl_shift_v",
+        label: t("settings.advanced.pasteMethod.options.clipboardCtrlShiftV"),
+      },
+      {
+        value: "shift_insert",
+        label: t("settings.advanced.pasteMethod.options.clipboardShiftInsert"),
+      },
+    );
+  }
+
+  return options;
+};
+
 interface PasteMethodProps {
   descriptionMode?: "inline" | "tooltip";
   grouped?: boolean;
@@ -18,47 +60,6 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
     const { getSetting, updateSetting, isUpdating } = useSettings();
     const [osType, setOsType] = useState<string>("unknown");
 
-    const getPasteMethodOptions = (osType: string) => {
-      const mod = osType === "macos" ? "Cmd" : "Ctrl";
-
-      const options = [
-        {
-          value: "ctrl_v",
-          label: t("settings.advanced.pasteMethod.options.clipboard", {
-            modifier: mod,
-          }),
-        },
-        {
-          value: "direct",
-          label: t("settings.advanced.pasteMethod.options.direct"),
-        },
-        {
-          value: "none",
-          label: t("settings.advanced.pasteMethod.options.none"),
-        },
-      ];
-
-      // Add Shift+Insert and Ctrl+Shift+V options for Windows and Linux only
-      if (osType === "windows" || osType === "linux") {
-        options.push(
-          {
-            value: "ctrl_shift_v",
-            label: t(
-              "settings.advanced.pasteMethod.options.clipboardCtrlShiftV",
-            ),
-          },
-          {
-            value: "shift_insert",
-            label: t(
-              "settings.advanced.pasteMethod.options.clipboardShiftInsert",
-            ),
-          },
-        );
-      }
-
-      return options;
-    };
-
     useEffect(() => {
       setOsType(getOsType());
     }, []);
@@ -66,13 +67,18 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
     const selectedMethod = (getSetting("paste_method") ||
       "ctrl_v") as PasteMethod;
     const pasteDelayMs = (getSetting("paste_delay_ms") ?? 60) as number;
+    const typeCharactersDelayMs = (getSetting(
+      "type_characters_delay_ms" as any,
+    ) ?? 5) as number;
 
-    const pasteMethodOptions = getPasteMethodOptions(osType);
+    const pasteMethodOptions = getPasteMethodOptions(t, osType);
 
     const isClipboardMethod =
       selectedMethod === "ctrl_v" ||
       selectedMethod === "ctrl_shift_v" ||
       selectedMethod === "shift_insert";
+    const isTypeCharactersMethod =
+      (selectedMethod as string) === "type_characters";
 
     return (
       <>
@@ -108,6 +114,29 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
             formatValue={(value) => `${Math.round(value)}ms`}
           />
         )}
+
+        {isTypeCharactersMethod && (
+          <Slider
+            value={typeCharactersDelayMs}
+            onChange={(value) =>
+              updateSetting(
+                "type_characters_delay_ms" as any,
+                Math.round(value) as any,
+              )
+            }
+            min={0}
+            max={50}
+            step={1}
+            disabled={isUpdating("type_characters_delay_ms")}
+            label={t("settings.advanced.pasteMethod.typeCharactersDelay.title")}
+            description={t(
+              "settings.advanced.pasteMethod.typeCharactersDelay.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(value) => `${Math.round(value)}ms`}
+          />
+        )}
       </>
     );
   },
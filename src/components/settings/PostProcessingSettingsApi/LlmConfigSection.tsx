@@ -13,6 +13,7 @@ import { ApiKeyField } from "./ApiKeyField";
 import { ModelSelect } from "./ModelSelect";
 import { ExtendedThinkingSection } from "../ExtendedThinkingSection";
 import { ToggleSwitch } from "../../ui/ToggleSwitch";
+import { EndpointAuthOptions } from "../EndpointAuthOptions";
 
 const DisabledNotice: React.FC<{ children: React.ReactNode }> = ({
   children,
@@ -57,6 +58,22 @@ export const LlmConfigSection: React.FC<LlmConfigSectionProps> = ({
     }
   };
 
+  const handleCustomAuthStyleChange = async (authStyle: string) => {
+    await invoke("change_post_process_custom_auth_style_setting", {
+      authStyle,
+    });
+    await refreshSettings();
+  };
+
+  const handleCustomQueryParamsChange = async (
+    params: Record<string, string>,
+  ) => {
+    await invoke("change_post_process_custom_extra_query_params_setting", {
+      params,
+    });
+    await refreshSettings();
+  };
+
   return (
     <div className="space-y-4 pt-4">
       <div className="px-6">
@@ -180,6 +197,17 @@ export const LlmConfigSection: React.FC<LlmConfigSectionProps> = ({
                       </p>
                     </div>
                   ) : null}
+
+                  <EndpointAuthOptions
+                    authStyle={
+                      (state.selectedProvider as any)?.auth_style ?? "bearer"
+                    }
+                    extraQueryParams={
+                      (state.selectedProvider as any)?.extra_query_params ?? {}
+                    }
+                    onAuthStyleChange={handleCustomAuthStyleChange}
+                    onExtraQueryParamsChange={handleCustomQueryParamsChange}
+                  />
                 </>
               )}
 
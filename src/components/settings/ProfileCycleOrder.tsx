@@ -0,0 +1,99 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ChevronDown, ChevronUp } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+
+interface ProfileCycleOrderProps {
+  order: string[];
+  profiles: { id: string; name: string; include_in_cycle: boolean }[];
+  onSaved: () => Promise<void> | void;
+}
+
+// Mirrors AppSettings::profile_cycle_ids in the backend.
+const cycleIds = (
+  order: string[],
+  profiles: ProfileCycleOrderProps["profiles"],
+): string[] => {
+  const inCycle = (id: string) =>
+    id === "default" ||
+    profiles.some((p) => p.id === id && p.include_in_cycle);
+  const ids = order.includes("default") ? [] : ["default"];
+  for (const id of [...order, ...profiles.map((p) => p.id)]) {
+    if (inCycle(id) && !ids.includes(id)) {
+      ids.push(id);
+    }
+  }
+  return ids;
+};
+
+export const ProfileCycleOrder: React.FC<ProfileCycleOrderProps> = ({
+  order,
+  profiles,
+  onSaved,
+}) => {
+  const { t } = useTranslation();
+  const ids = cycleIds(order, profiles);
+
+  if (ids.length < 3) {
+    return null;
+  }
+
+  const move = async (index: number, offset: number) => {
+    const next = [...ids];
+    [next[index], next[index + offset]] = [next[index + offset], next[index]];
+    try {
+      await invoke("set_profile_cycle_order", { order: next });
+      await onSaved();
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  const label = (id: string) =>
+    id === "default"
+      ? t("settings.transcriptionProfiles.defaultProfile")
+      : profiles.find((p) => p.id === id)?.name ?? id;
+
+  return (
+    <div className="space-y-2">
+      <div className="flex flex-col min-w-0">
+        <span className="text-sm font-medium">
+          {t("settings.transcriptionProfiles.cycleOrder.title")}
+        </span>
+        <span className="text-xs text-mid-gray leading-snug">
+          {t("settings.transcriptionProfiles.cycleOrder.description")}
+        </span>
+      </div>
+      <ol className="space-y-1">
+        {ids.map((id, index) => (
+          <li
+            key={id}
+            className="flex items-center gap-2 rounded-md border border-mid-gray/30 px-2 py-1"
+          >
+            <span className="text-xs text-mid-gray w-4">{index + 1}</span>
+            <span className="text-sm flex-1 truncate">{label(id)}</span>
+            <button
+              type="button"
+              onClick={() => move(index, -1)}
+              disabled={index === 0}
+              className="p-1 text-mid-gray hover:text-text disabled:opacity-30"
+              title={t("settings.transcriptionProfiles.cycleOrder.moveUp")}
+            >
+              <ChevronUp width={14} height={14} />
+            </button>
+            <button
+              type="button"
+              onClick={() => move(index, 1)}
+              disabled={index === ids.length - 1}
+              className="p-1 text-mid-gray hover:text-text disabled:opacity-30"
+              title={t("settings.transcriptionProfiles.cycleOrder.moveDown")}
+            >
+              <ChevronDown width={14} height={14} />
+            </button>
+          </li>
+        ))}
+      </ol>
+    </div>
+  );
+};
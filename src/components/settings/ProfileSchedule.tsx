@@ -0,0 +1,186 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Plus, Trash2 } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { sessionToast as toast } from "@/lib/sessionToast";
+
+import { Button } from "../ui/Button";
+import { Input } from "../ui/Input";
+import { Dropdown } from "../ui/Dropdown";
+
+const DAYS = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"] as const;
+type ScheduleDay = (typeof DAYS)[number];
+
+interface ProfileScheduleRule {
+  days: ScheduleDay[];
+  start_time: string;
+  end_time: string;
+  profile_id: string;
+}
+
+interface EffectiveProfileSource {
+  profile_id: string;
+  source: "manual" | "schedule";
+  active_rule_index: number | null;
+  pending_profile_id: string | null;
+  manual_override: boolean;
+}
+
+interface ProfileScheduleProps {
+  rules: ProfileScheduleRule[];
+  profiles: { id: string; name: string }[];
+  onSaved: () => Promise<void> | void;
+}
+
+export const ProfileSchedule: React.FC<ProfileScheduleProps> = ({
+  rules,
+  profiles,
+  onSaved,
+}) => {
+  const { t } = useTranslation();
+  const [source, setSource] = useState<EffectiveProfileSource | null>(null);
+
+  const refreshSource = () =>
+    invoke<EffectiveProfileSource>("get_effective_profile_source")
+      .then(setSource)
+      .catch((e) => console.error("Failed to load profile source", e));
+
+  useEffect(() => {
+    refreshSource();
+    const unlistenPromise = listen("active-profile-changed", refreshSource);
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, [rules]);
+
+  const profileOptions = [
+    {
+      value: "default",
+      label: t("settings.transcriptionProfiles.schedule.defaultProfile"),
+    },
+    ...profiles.map((p) => ({ value: p.id, label: p.name })),
+  ];
+
+  const save = async (next: ProfileScheduleRule[]) => {
+    try {
+      await invoke("change_profile_schedule_setting", { rules: next });
+      await onSaved();
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  const updateRule = (index: number, patch: Partial<ProfileScheduleRule>) =>
+    save(rules.map((rule, i) => (i === index ? { ...rule, ...patch } : rule)));
+
+  // Time inputs report "" while a value is being typed.
+  const updateTime = (
+    index: number,
+    field: "start_time" | "end_time",
+    value: string,
+  ) => {
+    if (/^\d{2}:\d{2}$/.test(value)) {
+      updateRule(index, { [field]: value });
+    }
+  };
+
+  const toggleDay = (index: number, day: ScheduleDay) => {
+    const days = rules[index].days.includes(day)
+      ? rules[index].days.filter((d) => d !== day)
+      : DAYS.filter((d) => d === day || rules[index].days.includes(d));
+    updateRule(index, { days });
+  };
+
+  return (
+    <div className="space-y-2">
+      <div className="flex flex-col min-w-0">
+        <span className="text-sm font-medium">
+          {t("settings.transcriptionProfiles.schedule.title")}
+        </span>
+        <span className="text-xs text-mid-gray leading-snug">
+          {t("settings.transcriptionProfiles.schedule.description")}
+        </span>
+      </div>
+
+      {rules.map((rule, index) => (
+        <div
+          key={index}
+          className={`flex flex-wrap items-center gap-2 rounded-md border px-2 py-1.5 ${source?.active_rule_index === index ? "border-purple-500/50" : "border-mid-gray/30"}`}
+        >
+          <div className="flex gap-0.5">
+            {DAYS.map((day) => (
+              <button
+                key={day}
+                type="button"
+                onClick={() => toggleDay(index, day)}
+                className={`text-xs px-1.5 py-0.5 rounded ${rule.days.includes(day) ? "bg-logo-primary/30 text-text" : "text-mid-gray hover:bg-mid-gray/10"}`}
+              >
+                {t(`settings.transcriptionProfiles.schedule.days.${day}`)}
+              </button>
+            ))}
+          </div>
+          <Input
+            type="time"
+            variant="compact"
+            value={rule.start_time}
+            onChange={(e) => updateTime(index, "start_time", e.target.value)}
+          />
+          <span className="text-xs text-mid-gray">–</span>
+          <Input
+            type="time"
+            variant="compact"
+            value={rule.end_time}
+            onChange={(e) => updateTime(index, "end_time", e.target.value)}
+          />
+          <Dropdown
+            className="min-w-32"
+            options={profileOptions}
+            selectedValue={rule.profile_id}
+            onSelect={(value) => updateRule(index, { profile_id: value })}
+          />
+          <button
+            type="button"
+            onClick={() => save(rules.filter((_, i) => i !== index))}
+            className="p-1 text-mid-gray hover:text-red-400"
+            title={t("settings.transcriptionProfiles.schedule.remove")}
+          >
+            <Trash2 width={14} height={14} />
+          </button>
+        </div>
+      ))}
+
+      <div className="flex items-center justify-between gap-2">
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() =>
+            save([
+              ...rules,
+              {
+                days: [],
+                start_time: "09:00",
+                end_time: "12:00",
+                profile_id: "default",
+              },
+            ])
+          }
+        >
+          <Plus width={14} height={14} />
+          {t("settings.transcriptionProfiles.schedule.add")}
+        </Button>
+        {source && rules.length > 0 && (
+          <span className="text-xs text-mid-gray">
+            {source.pending_profile_id
+              ? t("settings.transcriptionProfiles.schedule.pending")
+              : source.manual_override
+                ? t("settings.transcriptionProfiles.schedule.manualOverride")
+                : t(
+                    `settings.transcriptionProfiles.schedule.source.${source.source}`,
+                  )}
+          </span>
+        )}
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,154 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Input } from "../ui/Input";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+
+const DAYS = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"] as const;
+type ScheduleDay = (typeof DAYS)[number];
+
+interface QuietHoursSettings {
+  enabled: boolean;
+  days: ScheduleDay[];
+  start_time: string;
+  end_time: string;
+  behavior: "block_shortcuts" | "mute_feedback";
+}
+
+const DEFAULT_QUIET_HOURS: QuietHoursSettings = {
+  enabled: false,
+  days: [],
+  start_time: "22:00",
+  end_time: "07:00",
+  behavior: "block_shortcuts",
+};
+
+interface QuietHoursProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const QuietHours: React.FC<QuietHoursProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+
+  const quietHours: QuietHoursSettings =
+    (settings as any)?.quiet_hours ?? DEFAULT_QUIET_HOURS;
+
+  const save = async (patch: Partial<QuietHoursSettings>) => {
+    try {
+      await invoke("change_quiet_hours_setting", {
+        quietHours: { ...quietHours, ...patch },
+      });
+      await refreshSettings();
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  // Time inputs report "" while a value is being typed.
+  const updateTime = (field: "start_time" | "end_time", value: string) => {
+    if (/^\d{2}:\d{2}$/.test(value)) {
+      save({ [field]: value });
+    }
+  };
+
+  const toggleDay = (day: ScheduleDay) => {
+    const days = quietHours.days.includes(day)
+      ? quietHours.days.filter((d) => d !== day)
+      : DAYS.filter((d) => d === day || quietHours.days.includes(d));
+    save({ days });
+  };
+
+  const behaviorOptions = [
+    {
+      value: "block_shortcuts",
+      label: t("settings.advanced.quietHours.behavior.blockShortcuts"),
+    },
+    {
+      value: "mute_feedback",
+      label: t("settings.advanced.quietHours.behavior.muteFeedback"),
+    },
+  ];
+
+  return (
+    <div className="flex flex-col">
+      <SettingContainer
+        title={t("settings.advanced.quietHours.title")}
+        description={t("settings.advanced.quietHours.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <ToggleSwitch
+          checked={quietHours.enabled}
+          onChange={(checked) => save({ enabled: checked })}
+        />
+      </SettingContainer>
+
+      {quietHours.enabled && (
+        <div className="pl-4 ml-6 border-l-2 border-surface-highlight py-2 space-y-4 relative -top-2">
+          <SettingContainer
+            title={t("settings.advanced.quietHours.windowTitle")}
+            description={t("settings.advanced.quietHours.windowDescription")}
+            descriptionMode={descriptionMode}
+            grouped={true}
+            layout="horizontal"
+          >
+            <div className="flex flex-wrap items-center justify-end gap-2">
+              <div className="flex gap-0.5">
+                {DAYS.map((day) => (
+                  <button
+                    key={day}
+                    type="button"
+                    onClick={() => toggleDay(day)}
+                    className={`text-xs px-1.5 py-0.5 rounded ${quietHours.days.includes(day) ? "bg-logo-primary/30 text-text" : "text-mid-gray hover:bg-mid-gray/10"}`}
+                  >
+                    {t(`settings.transcriptionProfiles.schedule.days.${day}`)}
+                  </button>
+                ))}
+              </div>
+              <Input
+                type="time"
+                variant="compact"
+                value={quietHours.start_time}
+                onChange={(e) => updateTime("start_time", e.target.value)}
+              />
+              <span className="text-xs text-mid-gray">–</span>
+              <Input
+                type="time"
+                variant="compact"
+                value={quietHours.end_time}
+                onChange={(e) => updateTime("end_time", e.target.value)}
+              />
+            </div>
+          </SettingContainer>
+
+          <SettingContainer
+            title={t("settings.advanced.quietHours.behaviorTitle")}
+            description={t("settings.advanced.quietHours.behaviorDescription")}
+            descriptionMode={descriptionMode}
+            grouped={true}
+            layout="horizontal"
+          >
+            <Dropdown
+              className="min-w-48"
+              options={behaviorOptions}
+              selectedValue={quietHours.behavior}
+              onSelect={(value) =>
+                save({ behavior: value as QuietHoursSettings["behavior"] })
+              }
+            />
+          </SettingContainer>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -20,10 +20,21 @@ export const RecordingAutoStop: React.FC<RecordingAutoStopProps> = ({
   const enabled = getSetting("recording_auto_stop_enabled" as any) ?? false;
   const timeoutRaw = getSetting("recording_auto_stop_timeout_seconds" as any) ?? 1800;
   const paste = getSetting("recording_auto_stop_paste" as any) ?? false;
+  const silenceEnabled =
+    getSetting("auto_stop_on_silence_enabled" as any) ?? false;
+  const silenceSecondsRaw = getSetting("auto_stop_silence_seconds" as any) ?? 3;
+  const maxDurationRaw =
+    getSetting("max_recording_duration_seconds" as any) ?? 0;
 
   const timeout = Number.isFinite(timeoutRaw)
     ? Math.min(7200, Math.max(10, Number(timeoutRaw)))
     : 1800;
+  const silenceSeconds = Number.isFinite(silenceSecondsRaw)
+    ? Math.min(30, Math.max(1, Number(silenceSecondsRaw)))
+    : 3;
+  const maxDuration = Number.isFinite(maxDurationRaw)
+    ? Math.min(14400, Math.max(0, Number(maxDurationRaw)))
+    : 0;
 
   const handleTimeoutChange = (event: React.ChangeEvent<HTMLInputElement>) => {
     const value = parseInt(event.target.value, 10);
@@ -33,6 +44,26 @@ export const RecordingAutoStop: React.FC<RecordingAutoStopProps> = ({
     }
   };
 
+  const handleSilenceSecondsChange = (
+    event: React.ChangeEvent<HTMLInputElement>,
+  ) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value)) {
+      const clamped = Math.min(30, Math.max(1, value));
+      updateSetting("auto_stop_silence_seconds" as any, clamped);
+    }
+  };
+
+  const handleMaxDurationChange = (
+    event: React.ChangeEvent<HTMLInputElement>,
+  ) => {
+    const value = parseInt(event.target.value, 10);
+    if (!isNaN(value)) {
+      const clamped = value <= 0 ? 0 : Math.min(14400, Math.max(10, value));
+      updateSetting("max_recording_duration_seconds" as any, clamped);
+    }
+  };
+
   return (
     <div className="flex flex-col">
       <SettingContainer
@@ -93,6 +124,74 @@ export const RecordingAutoStop: React.FC<RecordingAutoStopProps> = ({
           </SettingContainer>
         </div>
       )}
+
+      <SettingContainer
+        title={t("settings.advanced.autoStop.silenceTitle")}
+        description={t("settings.advanced.autoStop.silenceDescription")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <ToggleSwitch
+          checked={silenceEnabled}
+          onChange={(checked) =>
+            updateSetting("auto_stop_on_silence_enabled" as any, checked)
+          }
+          disabled={isUpdating("auto_stop_on_silence_enabled" as any)}
+        />
+      </SettingContainer>
+
+      {silenceEnabled && (
+        <div className="pl-4 ml-6 border-l-2 border-surface-highlight py-2 space-y-4 relative -top-2">
+          <SettingContainer
+            title={t("settings.advanced.autoStop.silenceSecondsTitle")}
+            description={t(
+              "settings.advanced.autoStop.silenceSecondsDescription",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={true}
+            layout="horizontal"
+          >
+            <div className="flex items-center space-x-2">
+              <Input
+                type="number"
+                min={1}
+                max={30}
+                value={silenceSeconds}
+                onChange={handleSilenceSecondsChange}
+                disabled={isUpdating("auto_stop_silence_seconds" as any)}
+                className="w-24 text-right"
+              />
+              <span className="text-sm text-text/70">
+                {t("settings.advanced.autoStop.seconds")}
+              </span>
+            </div>
+          </SettingContainer>
+        </div>
+      )}
+
+      <SettingContainer
+        title={t("settings.advanced.autoStop.maxDurationTitle")}
+        description={t("settings.advanced.autoStop.maxDurationDescription")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <div className="flex items-center space-x-2">
+          <Input
+            type="number"
+            min={0}
+            max={14400}
+            value={maxDuration}
+            onChange={handleMaxDurationChange}
+            disabled={isUpdating("max_recording_duration_seconds" as any)}
+            className="w-24 text-right"
+          />
+          <span className="text-sm text-text/70">
+            {t("settings.advanced.autoStop.seconds")}
+          </span>
+        </div>
+      </SettingContainer>
     </div>
   );
 };
@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Activity } from "lucide-react";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface RecordingLevelMeterProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const RecordingLevelMeter: React.FC<RecordingLevelMeterProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled =
+      (getSetting("recording_level_meter_enabled" as any) ?? true) === true;
+
+    return (
+      <SettingContainer
+        title={
+          <span className="inline-flex items-center gap-2">
+            <Activity className="h-4 w-4 text-[#9b5de5]" />
+            <span>
+              {t(
+                "settings.sound.microphone.levelMeter.title",
+                "Input Level Meter",
+              )}
+            </span>
+          </span>
+        }
+        description={t(
+          "settings.sound.microphone.levelMeter.description",
+          "Reports microphone level about 10 times per second while recording so the overlay can warn about a silent or clipping microphone. Turn off to save a little CPU.",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(checked) =>
+            updateSetting("recording_level_meter_enabled" as any, checked)
+          }
+          isUpdating={isUpdating("recording_level_meter_enabled" as any)}
+        />
+      </SettingContainer>
+    );
+  });
+
+RecordingLevelMeter.displayName = "RecordingLevelMeter";
@@ -0,0 +1,71 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface ScreenReaderAnnouncementsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const ScreenReaderAnnouncements: React.FC<ScreenReaderAnnouncementsProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled =
+      (getSetting("screen_reader_announcements_enabled" as any) as
+        | boolean
+        | undefined) ?? false;
+    const verbosity =
+      (getSetting("screen_reader_verbosity" as any) as string | undefined) ??
+      "minimal";
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(value) =>
+            updateSetting("screen_reader_announcements_enabled" as any, value)
+          }
+          isUpdating={isUpdating("screen_reader_announcements_enabled")}
+          label={t("settings.userInterface.screenReader.label")}
+          description={t("settings.userInterface.screenReader.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <SettingContainer
+          title={t("settings.userInterface.screenReader.verbosity.title")}
+          description={t(
+            "settings.userInterface.screenReader.verbosity.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <Dropdown
+            options={[
+              {
+                value: "minimal",
+                label: t(
+                  "settings.userInterface.screenReader.verbosity.minimal",
+                ),
+              },
+              {
+                value: "verbose",
+                label: t(
+                  "settings.userInterface.screenReader.verbosity.verbose",
+                ),
+              },
+            ]}
+            selectedValue={verbosity}
+            onSelect={(value) =>
+              updateSetting("screen_reader_verbosity" as any, value as any)
+            }
+            disabled={!enabled || isUpdating("screen_reader_verbosity")}
+          />
+        </SettingContainer>
+      </>
+    );
+  });
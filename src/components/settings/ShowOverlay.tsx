@@ -37,6 +37,7 @@ const ERROR_OVERLAY_CATEGORIES = [
   { value: "ParseError", label: "Parse Error" },
   { value: "ExtensionOffline", label: "Extension Offline" },
   { value: "MicrophoneUnavailable", label: "Mic Unavailable" },
+  { value: "MicrophoneDisconnected", label: "Mic Disconnected" },
   { value: "Unknown", label: "Unknown" },
 ] as const;
 
@@ -37,11 +37,16 @@ import { ModelSelect } from "./PostProcessingSettingsApi/ModelSelect";
 import { ResetButton } from "../ui/ResetButton";
 import { InfoTooltip } from "../ui/InfoTooltip";
 import { SonioxContextEditor } from "./SonioxContextEditor";
+import { ProfileSchedule } from "./ProfileSchedule";
+import { ProfileCycleOrder } from "./ProfileCycleOrder";
 import type { ModelOption } from "./PostProcessingSettingsApi/types";
 import { useSettings } from "../../hooks/useSettings";
 import { useModels } from "../../hooks/useModels";
 import { LANGUAGES, type Language } from "../../lib/constants/languages";
-import { isLanguageSupportedBySoniox } from "../../lib/constants/sonioxLanguages";
+import {
+  isLanguageSupportedBySoniox,
+  parseAndNormalizeSonioxLanguageHints,
+} from "../../lib/constants/sonioxLanguages";
 import { getModelPromptInfo } from "./TranscriptionSystemPrompt";
 import { useNavigationStore } from "../../stores/navigationStore";
 
@@ -71,6 +76,241 @@ const strictModeToApi = (value: TriStateValue): boolean | null => {
   return null;
 };
 
+const CASING_MODES = [
+  "lower",
+  "upper",
+  "title",
+  "sentence",
+  "snake",
+  "camel",
+] as const;
+type CasingMode = (typeof CASING_MODES)[number];
+
+const OutputCasingSelect: React.FC<{
+  value: CasingMode | null | undefined;
+  onChange: (value: CasingMode | null) => void;
+  disabled?: boolean;
+}> = ({ value, onChange, disabled }) => {
+  const { t } = useTranslation();
+  return (
+    <div className="space-y-2 min-w-0">
+      <label className="text-xs font-semibold text-text/70">
+        {t("settings.transcriptionProfiles.outputCasing.title")}
+      </label>
+      <Dropdown
+        selectedValue={value ?? "none"}
+        options={[
+          {
+            value: "none",
+            label: t("settings.transcriptionProfiles.outputCasing.modes.none"),
+          },
+          ...CASING_MODES.map((mode) => ({
+            value: mode,
+            label: t(`settings.transcriptionProfiles.outputCasing.modes.${mode}`),
+          })),
+        ]}
+        onSelect={(next) =>
+          onChange(next === "none" ? null : (next as CasingMode))
+        }
+        disabled={disabled}
+      />
+    </div>
+  );
+};
+
+const NO_OUTPUT_TRANSLATION = "none";
+
+const OutputTranslationSelect: React.FC<{
+  value: string | null | undefined;
+  onChange: (value: string | null) => void;
+  disabled?: boolean;
+}> = ({ value, onChange, disabled }) => {
+  const { t } = useTranslation();
+  return (
+    <div className="space-y-2 min-w-0">
+      <label className="text-xs font-semibold text-text/70">
+        {t("settings.transcriptionProfiles.outputTranslation.title")}
+      </label>
+      <Dropdown
+        selectedValue={value ?? NO_OUTPUT_TRANSLATION}
+        options={[
+          {
+            value: NO_OUTPUT_TRANSLATION,
+            label: t("settings.transcriptionProfiles.outputTranslation.off"),
+          },
+          ...LANGUAGES.filter(
+            (language) =>
+              language.value !== "auto" && language.value !== "os_input",
+          ).map((language) => ({
+            value: language.value,
+            label: language.label,
+          })),
+        ]}
+        onSelect={(next) =>
+          onChange(next === NO_OUTPUT_TRANSLATION ? null : next)
+        }
+        disabled={disabled}
+      />
+      <p className="text-xs text-mid-gray">
+        {t("settings.transcriptionProfiles.outputTranslation.description")}
+      </p>
+    </div>
+  );
+};
+
+const GLOBAL_INPUT_DEVICE = "__global__";
+
+const InputDeviceSelect: React.FC<{
+  value: string | null | undefined;
+  onChange: (value: string | null) => void;
+  disabled?: boolean;
+}> = ({ value, onChange, disabled }) => {
+  const { t } = useTranslation();
+  const { audioDevices, refreshAudioDevices } = useSettings();
+  const deviceNames = audioDevices
+    .map((device) => device.name)
+    .filter((name) => name !== "Default" && name !== "default");
+  const missing = value && !deviceNames.includes(value);
+  return (
+    <div className="space-y-2 min-w-0">
+      <label className="text-xs font-semibold text-text/70">
+        {t("settings.transcriptionProfiles.inputDevice.title")}
+      </label>
+      <Dropdown
+        selectedValue={value ?? GLOBAL_INPUT_DEVICE}
+        options={[
+          {
+            value: GLOBAL_INPUT_DEVICE,
+            label: t("settings.transcriptionProfiles.inputDevice.global"),
+          },
+          ...deviceNames.map((name) => ({ value: name, label: name })),
+          ...(missing
+            ? [
+                {
+                  value,
+                  label: t(
+                    "settings.transcriptionProfiles.inputDevice.missing",
+                    { name: value },
+                  ),
+                },
+              ]
+            : []),
+        ]}
+        onSelect={(next) =>
+          onChange(next === GLOBAL_INPUT_DEVICE ? null : next)
+        }
+        onRefresh={refreshAudioDevices}
+        disabled={disabled}
+      />
+    </div>
+  );
+};
+
+type OutputDestination =
+  | { kind: "insert_at_cursor" }
+  | { kind: "clipboard_only" }
+  | { kind: "append_to_file"; path_template: string };
+
+const OutputDestinationSelect: React.FC<{
+  value: OutputDestination | null | undefined;
+  onChange: (value: OutputDestination) => void;
+  disabled?: boolean;
+}> = ({ value, onChange, disabled }) => {
+  const { t } = useTranslation();
+  const current = value ?? { kind: "insert_at_cursor" };
+  const savedPath =
+    current.kind === "append_to_file" ? current.path_template : "";
+  const [kind, setKind] = useState<OutputDestination["kind"]>(current.kind);
+  const [path, setPath] = useState(savedPath);
+  useEffect(() => setKind(current.kind), [current.kind]);
+  useEffect(() => setPath(savedPath), [savedPath]);
+  return (
+    <div className="space-y-2 min-w-0">
+      <label className="text-xs font-semibold text-text/70">
+        {t("settings.transcriptionProfiles.outputDestination.title")}
+      </label>
+      <Dropdown
+        selectedValue={kind}
+        options={(
+          ["insert_at_cursor", "clipboard_only", "append_to_file"] as const
+        ).map((option) => ({
+          value: option,
+          label: t(`settings.transcriptionProfiles.outputDestination.${option}`),
+        }))}
+        onSelect={(next) => {
+          const nextKind = next as OutputDestination["kind"];
+          setKind(nextKind);
+          // A file destination is saved once it has a path.
+          if (nextKind !== "append_to_file") onChange({ kind: nextKind });
+          else if (path.trim())
+            onChange({ kind: nextKind, path_template: path.trim() });
+        }}
+        disabled={disabled}
+      />
+      {kind === "append_to_file" && (
+        <Input
+          type="text"
+          value={path}
+          onChange={(e) => setPath(e.target.value)}
+          onBlur={() => {
+            if (path.trim() && path.trim() !== savedPath) {
+              onChange({ kind: "append_to_file", path_template: path.trim() });
+            }
+          }}
+          placeholder={t(
+            "settings.transcriptionProfiles.outputDestination.pathPlaceholder",
+          )}
+          variant="compact"
+          disabled={disabled}
+        />
+      )}
+      <p className="text-xs text-mid-gray">
+        {t("settings.transcriptionProfiles.outputDestination.description")}
+      </p>
+    </div>
+  );
+};
+
+const ProfileCustomWordsInput: React.FC<{
+  value: string[] | null | undefined;
+  onCommit: (value: string[] | null) => void;
+  disabled?: boolean;
+}> = ({ value, onCommit, disabled }) => {
+  const { t } = useTranslation();
+  const joined = (value ?? []).join(", ");
+  const [draft, setDraft] = useState(joined);
+  useEffect(() => setDraft(joined), [joined]);
+  const commit = () => {
+    const words = draft
+      .split(",")
+      .map((word) => word.trim())
+      .filter((word) => word.length > 0);
+    if (words.join(", ") === joined) return;
+    onCommit(words.length > 0 ? words : null);
+  };
+  return (
+    <div className="space-y-2 min-w-0">
+      <label className="text-xs font-semibold text-text/70">
+        {t("settings.transcriptionProfiles.customWords.title")}
+      </label>
+      <Input
+        type="text"
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={commit}
+        placeholder={t(
+          "settings.transcriptionProfiles.customWords.placeholder",
+        )}
+        variant="compact"
+        disabled={disabled}
+      />
+      <p className="text-xs text-mid-gray">
+        {t("settings.transcriptionProfiles.customWords.description")}
+      </p>
+    </div>
+  );
+};
+
 const DEFAULT_CLEAN_PROMPT = `Clean this Speech to text transcript:
 1. Fix spelling, capitalization, and punctuation errors
 2. Convert number words to digits (twenty-five → 25, ten percent → 10%, five dollars → $5)
@@ -90,10 +330,17 @@ interface ExtendedTranscriptionProfile extends TranscriptionProfile {
   push_to_talk: boolean;
   preview_output_only_enabled: boolean;
   soniox_language_hints_strict?: boolean | null;
+  additional_language_hints?: string[];
   stt_prompt_override_enabled: boolean;
   soniox_context_general_json: string;
   soniox_context_text: string;
   soniox_context_terms: string[];
+  output_casing?: CasingMode | null;
+  output_translation_language?: string | null;
+  input_device_override?: string | null;
+  output_destination?: OutputDestination;
+  custom_words_override?: string[] | null;
+  llm_output_json_path?: string | null;
 }
 
 interface ProfileCardProps {
@@ -156,6 +403,9 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
   const [localLlmPrompt, setLocalLlmPrompt] = useState(
     profile.llm_prompt_override ?? defaultLlmPrompt,
   );
+  const [localJsonPath, setLocalJsonPath] = useState(
+    profile.llm_output_json_path ?? "",
+  );
   // Track whether user is actively editing to prevent useEffect from clobbering
   const isEditingRef = useRef(false);
 
@@ -173,6 +423,10 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
     }
   }, [profile.llm_prompt_override, defaultLlmPrompt]);
 
+  useEffect(() => {
+    setLocalJsonPath(profile.llm_output_json_path ?? "");
+  }, [profile.llm_output_json_path]);
+
   const bindingId = `transcribe_${profile.id}`;
 
   const languageLabel = useMemo(() => {
@@ -224,6 +478,41 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
     }
   };
 
+  const additionalLanguageHints = profile.additional_language_hints ?? [];
+  const savedAdditionalLanguages = additionalLanguageHints.join(", ");
+  const [additionalLanguagesInput, setAdditionalLanguagesInput] = useState(
+    savedAdditionalLanguages,
+  );
+
+  useEffect(() => {
+    setAdditionalLanguagesInput(savedAdditionalLanguages);
+  }, [savedAdditionalLanguages]);
+
+  const handleAdditionalLanguagesBlur = async () => {
+    const parsed = parseAndNormalizeSonioxLanguageHints(
+      additionalLanguagesInput,
+    );
+    if (parsed.rejected.length > 0) {
+      toast.warning(
+        `Ignored unsupported language hints: ${parsed.rejected.join(", ")}`,
+      );
+    }
+    const normalized = parsed.normalized.join(", ");
+    setAdditionalLanguagesInput(normalized);
+    if (normalized === savedAdditionalLanguages) {
+      return;
+    }
+    setIsUpdating(true);
+    try {
+      await onUpdate({
+        ...profile,
+        additional_language_hints: parsed.normalized,
+      });
+    } finally {
+      setIsUpdating(false);
+    }
+  };
+
   const handleTranslateChange = async (newTranslate: boolean) => {
     setIsUpdating(true);
     try {
@@ -349,6 +638,20 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
     }
   };
 
+  const handleJsonPathChange = async (newPath: string) => {
+    const trimmed = newPath.trim();
+    if (trimmed === (profile.llm_output_json_path ?? "")) return;
+    setIsUpdating(true);
+    try {
+      await onUpdate({
+        ...profile,
+        llm_output_json_path: trimmed || null,
+      });
+    } finally {
+      setIsUpdating(false);
+    }
+  };
+
   const handleResetToGlobal = async () => {
     if (profile.llm_prompt_override == null) return;
     setLocalLlmPrompt(defaultLlmPrompt);
@@ -678,6 +981,32 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
                       )}
                 </p>
               )}
+              <label className="text-xs font-semibold text-text/70">
+                {t(
+                  "settings.transcriptionProfiles.additionalLanguages.title",
+                  "Additional languages",
+                )}
+              </label>
+              <Input
+                type="text"
+                value={additionalLanguagesInput}
+                onChange={(e) => setAdditionalLanguagesInput(e.target.value)}
+                onBlur={() => void handleAdditionalLanguagesBlur()}
+                placeholder={t(
+                  "settings.transcriptionProfiles.additionalLanguages.placeholder",
+                  "e.g. en, de",
+                )}
+                disabled={isUpdating}
+                className="w-full"
+              />
+              {additionalLanguageHints.length > 0 && !isSonioxProvider && (
+                <p className="text-xs text-text/60">
+                  {t(
+                    "settings.transcriptionProfiles.additionalLanguages.autoNote",
+                    "Local and OpenAI-compatible models accept one language, so this profile uses auto-detect with them.",
+                  )}
+                </p>
+              )}
             </div>
 
             {/* Translate to English Toggle */}
@@ -731,6 +1060,85 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
             />
           )}
 
+          <OutputCasingSelect
+            value={profile.output_casing}
+            disabled={isUpdating}
+            onChange={async (outputCasing) => {
+              setIsUpdating(true);
+              try {
+                await onUpdate({ ...profile, output_casing: outputCasing });
+              } finally {
+                setIsUpdating(false);
+              }
+            }}
+          />
+
+          <OutputTranslationSelect
+            value={profile.output_translation_language}
+            disabled={isUpdating}
+            onChange={async (outputTranslationLanguage) => {
+              setIsUpdating(true);
+              try {
+                await onUpdate({
+                  ...profile,
+                  output_translation_language: outputTranslationLanguage,
+                });
+              } finally {
+                setIsUpdating(false);
+              }
+            }}
+          />
+
+          <InputDeviceSelect
+            value={profile.input_device_override}
+            disabled={isUpdating}
+            onChange={async (inputDeviceOverride) => {
+              setIsUpdating(true);
+              try {
+                await onUpdate({
+                  ...profile,
+                  input_device_override: inputDeviceOverride,
+                });
+              } finally {
+                setIsUpdating(false);
+              }
+            }}
+          />
+
+          <OutputDestinationSelect
+            value={profile.output_destination}
+            disabled={isUpdating}
+            onChange={async (outputDestination) => {
+              setIsUpdating(true);
+              try {
+                await onUpdate({
+                  ...profile,
+                  output_destination: outputDestination,
+                });
+              } catch (error) {
+                toast.error(String(error));
+              } finally {
+                setIsUpdating(false);
+              }
+            }}
+          />
+
+          <ProfileCustomWordsInput
+            value={profile.custom_words_override}
+            disabled={isUpdating}
+            onCommit={async (customWordsOverride) => {
+              setIsUpdating(true);
+              try {
+                await onUpdate({
+                  ...profile,
+                  custom_words_override: customWordsOverride,
+                });
+              } finally {
+                setIsUpdating(false);
+              }
+            }}
+          />
+
           {/* Voice Model Prompt Override — collapsible */}
           {!isSonioxProvider && supportsSttPrompt && (
             <details className="group rounded-lg border border-mid-gray/20 bg-mid-gray/5 overflow-hidden transition-colors open:border-purple-500/30 open:bg-purple-500/5">
@@ -1059,6 +1467,31 @@ const ProfileCard: React.FC<ProfileCardProps> = ({
                       />
                     </div>
 
+                    {/* Output JSON path (only used with the custom prompt) */}
+                    {isCustomOverride && (
+                      <div className="space-y-1">
+                        <label className="text-xs text-text/60">
+                          {t("settings.postProcessing.prompts.jsonPath.title")}
+                        </label>
+                        <p className="text-xs text-mid-gray">
+                          {t(
+                            "settings.postProcessing.prompts.jsonPath.description",
+                          )}
+                        </p>
+                        <Input
+                          value={localJsonPath}
+                          onChange={(e) => setLocalJsonPath(e.target.value)}
+                          onBlur={(e) => handleJsonPathChange(e.target.value)}
+                          placeholder={t(
+                            "settings.postProcessing.prompts.jsonPath.placeholder",
+                          )}
+                          variant="compact"
+                          className="w-full"
+                          disabled={isUpdating}
+                        />
+                      </div>
+                    )}
+
                     {/* Model Override */}
                     <div className="space-y-1">
                       <label className="text-xs text-text/60">
@@ -1471,11 +1904,20 @@ export const TranscriptionProfiles: React.FC = () => {
             enabled: profile.llm_post_process_enabled ?? false,
             promptOverride: profile.llm_prompt_override ?? null,
             modelOverride: profile.llm_model_override ?? null,
+            outputJsonPath: profile.llm_output_json_path ?? null,
           },
           sonioxContextGeneralJson: profile.soniox_context_general_json || "",
           sonioxContextText: profile.soniox_context_text || "",
           sonioxContextTerms: profile.soniox_context_terms || [],
           sonioxLanguageHintsStrict: profile.soniox_language_hints_strict ?? null,
+          additionalLanguageHints: profile.additional_language_hints ?? [],
+          outputCasing: profile.output_casing ?? null,
+          outputTranslationLanguage: profile.output_translation_language ?? null,
+          inputDeviceOverride: profile.input_device_override ?? null,
+          outputDestination: profile.output_destination ?? {
+            kind: "insert_at_cursor",
+          },
+          customWordsOverride: profile.custom_words_override ?? null,
         },
       });
       await refreshSettings();
@@ -1619,6 +2061,12 @@ export const TranscriptionProfiles: React.FC = () => {
             </div>
           </div>
 
+          <ProfileCycleOrder
+            order={(settings as any)?.profile_cycle_order ?? []}
+            profiles={profiles}
+            onSaved={refreshSettings}
+          />
+
           {/* Overlay Toggle */}
           <div className="grid gap-2 sm:grid-cols-[minmax(0,1fr)_auto] sm:items-center">
             <div className="flex flex-col min-w-0">
@@ -1638,6 +2086,12 @@ export const TranscriptionProfiles: React.FC = () => {
               />
             </div>
           </div>
+
+          <ProfileSchedule
+            rules={(settings as any)?.profile_schedule ?? []}
+            profiles={(settings as any)?.transcription_profiles ?? []}
+            onSaved={refreshSettings}
+          />
         </div>
       </SettingContainer>
 
@@ -1960,6 +2414,49 @@ export const TranscriptionProfiles: React.FC = () => {
                   />
                 )}
 
+                <div className="grid grid-cols-1 gap-3 lg:grid-cols-2">
+                  <OutputCasingSelect
+                    value={(settings as any)?.output_casing}
+                    onChange={(value) =>
+                      updateSetting &&
+                      updateSetting("output_casing" as any, value)
+                    }
+                  />
+                  <div className="space-y-2 min-w-0">
+                    <label className="text-xs font-semibold text-text/70">
+                      {t(
+                        "settings.transcriptionProfiles.outputCasing.exceptions",
+                      )}
+                    </label>
+                    <Input
+                      variant="compact"
+                      className="w-full"
+                      defaultValue={(
+                        (settings as any)?.output_casing_exceptions ?? []
+                      ).join(", ")}
+                      placeholder="iPhone, macOS"
+                      onBlur={(e) =>
+                        updateSetting &&
+                        updateSetting(
+                          "output_casing_exceptions" as any,
+                          e.target.value
+                            .split(",")
+                            .map((word) => word.trim())
+                            .filter(Boolean),
+                        )
+                      }
+                    />
+                  </div>
+                </div>
+
+                <OutputTranslationSelect
+                  value={(settings as any)?.output_translation_language}
+                  onChange={(value) =>
+                    updateSetting &&
+                    updateSetting("output_translation_language" as any, value)
+                  }
+                />
+
                 {/* Voice Model Prompt — collapsible, matches ProfileCard details style */}
                 {!isSonioxProvider && modelInfo.supportsPrompt && (
                   <details className="group rounded-lg border border-mid-gray/20 bg-mid-gray/5 overflow-hidden transition-colors open:border-purple-500/30 open:bg-purple-500/5">
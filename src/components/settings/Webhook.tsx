@@ -0,0 +1,192 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { useSettings } from "../../hooks/useSettings";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { Input } from "../ui/Input";
+import { Button } from "../ui/Button";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+
+const ALL_PROFILES = "__all__";
+
+interface WebhookProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const Webhook: React.FC<WebhookProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, getSetting, isUpdating, refreshSettings } = useSettings();
+
+  const enabled = getSetting("webhook_enabled" as any) ?? false;
+  const url: string = getSetting("webhook_url" as any) ?? "";
+  const secret: string = getSetting("webhook_secret" as any) ?? "";
+  const profileFilter: string | null =
+    getSetting("webhook_profile_id" as any) ?? null;
+  const profiles: { id: string; name: string }[] =
+    (settings as any)?.transcription_profiles ?? [];
+
+  const [urlInput, setUrlInput] = useState(url);
+  const [secretInput, setSecretInput] = useState(secret);
+  const [showSecret, setShowSecret] = useState(false);
+  const [testing, setTesting] = useState(false);
+
+  useEffect(() => {
+    setUrlInput(url);
+  }, [url]);
+
+  useEffect(() => {
+    setSecretInput(secret);
+  }, [secret]);
+
+  const run = async (command: string, args: Record<string, unknown>) => {
+    try {
+      await invoke(command, args);
+      await refreshSettings();
+      return true;
+    } catch (e) {
+      toast.error(String(e));
+      return false;
+    }
+  };
+
+  const commitUrl = async () => {
+    if (urlInput.trim() === url) return;
+    if (!(await run("change_webhook_url_setting", { url: urlInput }))) {
+      setUrlInput(url);
+    }
+  };
+
+  const commitSecret = async () => {
+    if (secretInput.trim() === secret) return;
+    await run("change_webhook_secret_setting", { secret: secretInput });
+  };
+
+  const runTest = async () => {
+    setTesting(true);
+    try {
+      const status = await invoke<number>("test_webhook");
+      if (status >= 200 && status < 300) {
+        toast.success(t("settings.advanced.webhook.testSucceeded", { status }));
+      } else {
+        toast.error(t("settings.advanced.webhook.testFailed", { status }));
+      }
+    } catch (e) {
+      toast.error(String(e));
+    } finally {
+      setTesting(false);
+    }
+  };
+
+  const profileOptions = [
+    { value: ALL_PROFILES, label: t("settings.advanced.webhook.allProfiles") },
+    {
+      value: "default",
+      label: t("settings.transcriptionProfiles.schedule.defaultProfile"),
+    },
+    ...profiles.map((p) => ({ value: p.id, label: p.name })),
+  ];
+
+  return (
+    <div className="flex flex-col">
+      <SettingContainer
+        title={t("settings.advanced.webhook.title")}
+        description={t("settings.advanced.webhook.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="horizontal"
+      >
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(checked) =>
+            run("change_webhook_enabled_setting", { enabled: checked })
+          }
+          disabled={isUpdating("webhook_enabled" as any)}
+        />
+      </SettingContainer>
+
+      <div className="pl-4 ml-6 border-l-2 border-surface-highlight py-2 space-y-4 relative -top-2">
+        <SettingContainer
+          title={t("settings.advanced.webhook.urlTitle")}
+          description={t("settings.advanced.webhook.urlDescription")}
+          descriptionMode={descriptionMode}
+          grouped={true}
+          layout="horizontal"
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="url"
+              variant="compact"
+              className="w-64"
+              placeholder="https://notes.example.com/hook"
+              value={urlInput}
+              onChange={(e) => setUrlInput(e.target.value)}
+              onBlur={commitUrl}
+            />
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={runTest}
+              disabled={testing || !url}
+            >
+              {testing
+                ? t("settings.advanced.webhook.testing")
+                : t("settings.advanced.webhook.test")}
+            </Button>
+          </div>
+        </SettingContainer>
+
+        <SettingContainer
+          title={t("settings.advanced.webhook.secretTitle")}
+          description={t("settings.advanced.webhook.secretDescription")}
+          descriptionMode={descriptionMode}
+          grouped={true}
+          layout="horizontal"
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type={showSecret ? "text" : "password"}
+              variant="compact"
+              className="w-48 font-mono"
+              value={secretInput}
+              onChange={(e) => setSecretInput(e.target.value)}
+              onBlur={commitSecret}
+            />
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={() => setShowSecret(!showSecret)}
+            >
+              {showSecret
+                ? t("settings.advanced.actionApi.hideToken")
+                : t("settings.advanced.actionApi.showToken")}
+            </Button>
+          </div>
+        </SettingContainer>
+
+        <SettingContainer
+          title={t("settings.advanced.webhook.profileTitle")}
+          description={t("settings.advanced.webhook.profileDescription")}
+          descriptionMode={descriptionMode}
+          grouped={true}
+          layout="horizontal"
+        >
+          <Dropdown
+            options={profileOptions}
+            selectedValue={profileFilter ?? ALL_PROFILES}
+            onSelect={(value) =>
+              run("change_webhook_profile_filter_setting", {
+                profileId: value === ALL_PROFILES ? null : value,
+              })
+            }
+          />
+        </SettingContainer>
+      </div>
+    </div>
+  );
+};
@@ -1,18 +1,24 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
 import { ModelUnloadTimeoutSetting } from "../ModelUnloadTimeout";
+import { LocalFallbackModel } from "../LocalFallbackModel";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { StartHidden } from "../StartHidden";
 import { AutostartToggle } from "../AutostartToggle";
 import { PasteMethodSetting } from "../PasteMethod";
+import { AppPasteOverrides } from "../AppPasteOverrides";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
 import { ConvertLfToCrlfSetting } from "../ConvertLfToCrlfSetting";
 import { AutoSubmit } from "../AutoSubmit";
 import { TellMeMore } from "../../ui/TellMeMore";
 import { RecordingAutoStop } from "../RecordingAutoStop";
+import { QuietHours } from "../QuietHours";
+import { ActionApi } from "../ActionApi";
+import { Webhook } from "../Webhook";
 import { AccelerationSelector } from "../AccelerationSelector";
 import { MuteWhileRecording } from "../MuteWhileRecording";
 import { PauseMediaWhileRecording } from "../PauseMediaWhileRecording";
+import { HandyShortcut } from "../HandyShortcut";
 
 export const AdvancedSettings: React.FC = () => {
   const { t } = useTranslation();
@@ -50,8 +56,16 @@ export const AdvancedSettings: React.FC = () => {
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
         <AutoSubmit descriptionMode="tooltip" grouped={true} />
         <RecordingAutoStop descriptionMode="tooltip" grouped={true} />
+        <QuietHours descriptionMode="tooltip" grouped={true} />
+        <HandyShortcut
+          shortcutId="pause_resume"
+          descriptionMode="tooltip"
+          grouped={true}
+        />
         <MuteWhileRecording descriptionMode="tooltip" grouped={true} />
         <PauseMediaWhileRecording descriptionMode="tooltip" grouped={true} />
+        <ActionApi descriptionMode="tooltip" grouped={true} />
+        <Webhook descriptionMode="tooltip" grouped={true} />
         <div className="px-6 pt-4">
           <TellMeMore
             title={t("settings.advanced.tellMeMore.modelUnload.title")}
@@ -62,6 +76,7 @@ export const AdvancedSettings: React.FC = () => {
           </TellMeMore>
         </div>
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
+        <LocalFallbackModel descriptionMode="tooltip" grouped={true} />
         <div className="px-6 pt-4">
           <TellMeMore
             title={t("settings.advanced.tellMeMore.acceleration.title", {
@@ -171,6 +186,7 @@ export const AdvancedSettings: React.FC = () => {
           </TellMeMore>
         </div>
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
+        <AppPasteOverrides descriptionMode="tooltip" grouped={true} />
         <div className="px-6 pt-4">
           <TellMeMore
             title={t("settings.advanced.tellMeMore.clipboardHandling.title")}
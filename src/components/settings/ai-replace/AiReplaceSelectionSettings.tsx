@@ -77,6 +77,9 @@ const CommonVars: React.FC = () => {
   return (
     <>
       <Var name="${current_app}" desc={t("settings.aiReplace.promptHelp.variables.currentApp")} />
+      <Var name="${window_title}" desc={t("settings.aiReplace.promptHelp.variables.windowTitle")} />
+      <Var name="${selection_url}" desc={t("settings.aiReplace.promptHelp.variables.selectionUrl")} />
+      <Var name="${clipboard}" desc={t("settings.aiReplace.promptHelp.variables.clipboard")} />
       <Var name="${language}" desc={t("settings.aiReplace.promptHelp.variables.language")} />
       <Var name="${profile_name}" desc={t("settings.aiReplace.promptHelp.variables.profileName")} />
       <Var name="${time_local}" desc={t("settings.aiReplace.promptHelp.variables.timeLocal")} />
@@ -379,6 +382,47 @@ export const AiReplaceSelectionSettings: React.FC = () => {
           onChange={(checked) => void updateSetting("ai_replace_restore_on_error", checked)}
           disabled={isUpdating("ai_replace_restore_on_error")}
         />
+        <ToggleSwitch
+          label={t("settings.aiReplace.withSelection.confirmBeforePaste.label")}
+          description={t("settings.aiReplace.withSelection.confirmBeforePaste.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+          checked={getSetting("ai_replace_confirm_before_paste" as any) ?? false}
+          onChange={(checked) =>
+            void updateSetting("ai_replace_confirm_before_paste" as any, checked)
+          }
+          disabled={isUpdating("ai_replace_confirm_before_paste" as any)}
+        />
+        {(getSetting("ai_replace_confirm_before_paste" as any) ?? false) && (
+          <SettingContainer
+            title={t("settings.aiReplace.withSelection.confirmTimeout.title")}
+            description={t("settings.aiReplace.withSelection.confirmTimeout.description")}
+            descriptionMode="tooltip"
+            grouped={true}
+            layout="horizontal"
+          >
+            <div className="flex items-center space-x-2">
+              <Input
+                type="number"
+                min="5"
+                max="600"
+                value={getSetting("ai_replace_confirm_timeout_seconds" as any) ?? 60}
+                onChange={(e) => {
+                  const val = parseInt(e.target.value, 10);
+                  if (!isNaN(val)) {
+                    const clamped = Math.min(600, Math.max(5, val));
+                    void updateSetting("ai_replace_confirm_timeout_seconds" as any, clamped);
+                  }
+                }}
+                disabled={isUpdating("ai_replace_confirm_timeout_seconds" as any)}
+                className="w-24"
+              />
+              <span className="text-sm text-text">
+                {t("settings.aiReplace.withSelection.confirmTimeout.suffix")}
+              </span>
+            </div>
+          </SettingContainer>
+        )}
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.aiReplace.api.title")}>
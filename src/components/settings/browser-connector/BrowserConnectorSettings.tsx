@@ -17,6 +17,8 @@ import { Textarea } from "../../ui/Textarea";
 import { ToggleSwitch } from "../../ui/ToggleSwitch";
 import { ConfirmationModal } from "../../ui/ConfirmationModal";
 import { ConnectorStatusIndicator } from "./ConnectorStatus";
+import { PendingMessages } from "./PendingMessages";
+import { ConnectorHeartbeat } from "./ConnectorHeartbeat";
 
 // Preset sites for auto-open dropdown (matches extension manifest)
 const AUTO_OPEN_SITES = [
@@ -889,6 +891,14 @@ export const BrowserConnectorSettings: React.FC = () => {
           status={connectorStatus}
           restartNotice={connectorRestartNotice}
         />
+        <ConnectorHeartbeat />
+      </SettingsGroup>
+
+      <SettingsGroup
+        title={t("settings.browserConnector.pending.sectionTitle")}
+        description={t("settings.browserConnector.pending.sectionDescription")}
+      >
+        <PendingMessages />
       </SettingsGroup>
 
       {/* Feature 1: Send Transcription Directly to Extension */}
@@ -1183,6 +1193,22 @@ export const BrowserConnectorSettings: React.FC = () => {
                       {t("settings.browserConnector.screenshot.method.native")}
                     </span>
                   </label>
+                  <label className="flex items-center gap-3 cursor-pointer group">
+                    <div className="relative flex items-center justify-center">
+                      <input
+                        type="radio"
+                        name="capture_method"
+                        value="clipboard"
+                        checked={captureMethod === "clipboard"}
+                        onChange={(e) => handleCaptureMethodChange(e.target.value)}
+                        className="peer appearance-none w-4 h-4 rounded-full border border-gray-400 checked:border-purple-500 checked:bg-purple-500 transition-colors"
+                      />
+                      <div className="absolute w-2 h-2 rounded-full bg-white opacity-0 peer-checked:opacity-100 pointer-events-none transition-opacity" />
+                    </div>
+                    <span className="text-sm text-text/90 group-hover:text-text transition-colors">
+                      {t("settings.browserConnector.screenshot.method.clipboard")}
+                    </span>
+                  </label>
                 </div>
               </SettingContainer>
 
@@ -1210,7 +1236,8 @@ export const BrowserConnectorSettings: React.FC = () => {
                 </SettingContainer>
               )}
 
-              {captureMethod === "external_program" && (
+              {(captureMethod === "external_program" ||
+                captureMethod === "clipboard") && (
                 <>
               <SettingContainer
                 title={t("settings.browserConnector.screenshot.command.title")}
@@ -0,0 +1,63 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../../hooks/useSettings";
+import { Input } from "../../ui/Input";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { ToggleSwitch } from "../../ui/ToggleSwitch";
+
+const MAX_HEARTBEAT_SECONDS = 30;
+
+const SECONDS_SETTINGS = [
+  { key: "connector_heartbeat_interval_seconds", i18n: "interval", fallback: 3 },
+  { key: "connector_heartbeat_grace_seconds", i18n: "grace", fallback: 2 },
+] as const;
+
+export const ConnectorHeartbeat: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, updateSetting, isUpdating } = useSettings();
+
+  return (
+    <>
+      {SECONDS_SETTINGS.map(({ key, i18n, fallback }) => (
+        <SettingContainer
+          key={key}
+          title={t(`settings.browserConnector.heartbeat.${i18n}.title`)}
+          description={t(`settings.browserConnector.heartbeat.${i18n}.description`)}
+          descriptionMode="tooltip"
+          grouped={true}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="number"
+              min={1}
+              max={MAX_HEARTBEAT_SECONDS}
+              value={(settings as any)?.[key] ?? fallback}
+              onChange={(e) => {
+                const value = parseInt(e.target.value, 10);
+                if (!isNaN(value)) {
+                  (updateSetting as any)(key, Math.min(MAX_HEARTBEAT_SECONDS, Math.max(1, value)));
+                }
+              }}
+              disabled={isUpdating(key as any)}
+              className="w-24 text-right"
+            />
+            <span className="text-sm text-text/70">
+              {t("settings.browserConnector.heartbeat.unit")}
+            </span>
+          </div>
+        </SettingContainer>
+      ))}
+      <ToggleSwitch
+        checked={(settings as any)?.extension_offline_fallback_to_clipboard ?? true}
+        onChange={(enabled) =>
+          void (updateSetting as any)("extension_offline_fallback_to_clipboard", enabled)
+        }
+        isUpdating={isUpdating("extension_offline_fallback_to_clipboard" as any)}
+        label={t("settings.browserConnector.heartbeat.clipboardFallback.title")}
+        description={t("settings.browserConnector.heartbeat.clipboardFallback.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+    </>
+  );
+};
@@ -0,0 +1,134 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { Image, Trash2 } from "lucide-react";
+import { useSettings } from "../../../hooks/useSettings";
+import { Input } from "../../ui/Input";
+import { SettingContainer } from "../../ui/SettingContainer";
+
+const MAX_MESSAGE_AGE_MINUTES = 1440;
+
+interface PendingConnectorMessage {
+  id: string;
+  type: string;
+  text: string;
+  queued_at: number;
+  delivered: boolean;
+  attachment_count: number;
+}
+
+// Anything that changes what the outbox holds.
+const OUTBOX_EVENTS = [
+  "connector-message-queued",
+  "connector-message-delivered",
+  "connector-message-acknowledged",
+  "connector-message-expired",
+  "connector-message-cancelled",
+];
+
+export const PendingMessages: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, updateSetting, isUpdating } = useSettings();
+  const [messages, setMessages] = useState<PendingConnectorMessage[]>([]);
+
+  const fetchPending = useCallback(async () => {
+    try {
+      setMessages(await invoke<PendingConnectorMessage[]>("get_pending_connector_messages"));
+    } catch {
+      setMessages([]);
+    }
+  }, []);
+
+  useEffect(() => {
+    void fetchPending();
+    const unlisteners = OUTBOX_EVENTS.map((event) =>
+      listen(event, () => {
+        void fetchPending();
+      })
+    );
+
+    return () => {
+      unlisteners.forEach((unlisten) => void unlisten.then((fn) => fn()));
+    };
+  }, [fetchPending]);
+
+  const discard = async (id: string) => {
+    await invoke("discard_connector_message", { id });
+    void fetchPending();
+  };
+
+  return (
+    <>
+      <SettingContainer
+        title={t("settings.browserConnector.pending.maxAge.title")}
+        description={t("settings.browserConnector.pending.maxAge.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      >
+        <div className="flex items-center gap-2">
+          <Input
+            type="number"
+            min={1}
+            max={MAX_MESSAGE_AGE_MINUTES}
+            value={(settings as any)?.connector_message_max_age_minutes ?? 10}
+            onChange={(e) => {
+              const value = parseInt(e.target.value, 10);
+              if (!isNaN(value)) {
+                (updateSetting as any)(
+                  "connector_message_max_age_minutes",
+                  Math.min(MAX_MESSAGE_AGE_MINUTES, Math.max(1, value))
+                );
+              }
+            }}
+            disabled={isUpdating("connector_message_max_age_minutes" as any)}
+            className="w-24 text-right"
+          />
+          <span className="text-sm text-text/70">
+            {t("settings.browserConnector.pending.maxAge.unit")}
+          </span>
+        </div>
+      </SettingContainer>
+
+      <div className="px-4 py-3 space-y-2">
+        <div className="text-sm font-medium">
+          {t("settings.browserConnector.pending.title", { count: messages.length })}
+        </div>
+        {messages.length === 0 ? (
+          <p className="text-xs text-mid-gray">{t("settings.browserConnector.pending.empty")}</p>
+        ) : (
+          <ul className="space-y-1">
+            {messages.map((message) => (
+              <li
+                key={message.id}
+                className="flex items-center gap-2 px-2 py-1 rounded bg-mid-gray/10 border border-mid-gray/20"
+              >
+                {message.attachment_count > 0 && (
+                  <Image className="w-4 h-4 shrink-0 text-mid-gray" />
+                )}
+                <span className="flex-1 min-w-0 truncate text-sm" title={message.text}>
+                  {message.text || t("settings.browserConnector.pending.noText")}
+                </span>
+                <span className="shrink-0 text-xs text-mid-gray">
+                  {message.delivered
+                    ? t("settings.browserConnector.pending.awaitingAck")
+                    : t("settings.browserConnector.pending.queued")}
+                  {" · "}
+                  {new Date(message.queued_at).toLocaleTimeString()}
+                </span>
+                <button
+                  type="button"
+                  onClick={() => void discard(message.id)}
+                  title={t("settings.browserConnector.pending.discard")}
+                  className="shrink-0 p-1 rounded text-mid-gray hover:text-red-400 hover:bg-mid-gray/20"
+                >
+                  <Trash2 className="w-4 h-4" />
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </div>
+    </>
+  );
+};
@@ -0,0 +1,118 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { Button } from "../../ui/Button";
+
+interface AudioDeviceDiagnostics {
+  device_name: string;
+  is_default: boolean;
+  open_ok: boolean;
+  got_audio: boolean;
+  error: string | null;
+  sample_rates: number[];
+  channels: number[];
+  opened_sample_rate: number | null;
+  exclusive_mode_conflict: boolean;
+  timed_out: boolean;
+}
+
+interface AudioDiagnosticsReport {
+  devices: AudioDeviceDiagnostics[];
+}
+
+interface AudioDiagnosticsProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const AudioDiagnostics: React.FC<AudioDiagnosticsProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [report, setReport] = useState<AudioDiagnosticsReport | null>(null);
+  const [running, setRunning] = useState(false);
+
+  const handleRun = async () => {
+    setRunning(true);
+    try {
+      setReport(await invoke<AudioDiagnosticsReport>("run_audio_diagnostics"));
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  const status = (device: AudioDeviceDiagnostics) => {
+    if (device.timed_out) return t("settings.debug.audioDiagnostics.timedOut");
+    if (device.exclusive_mode_conflict)
+      return t("settings.debug.audioDiagnostics.exclusive");
+    if (!device.open_ok) return t("settings.debug.audioDiagnostics.openFailed");
+    if (!device.got_audio) return t("settings.debug.audioDiagnostics.silent");
+    return t("settings.debug.audioDiagnostics.ok");
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.audioDiagnostics.title")}
+      description={t("settings.debug.audioDiagnostics.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="space-y-2">
+        <Button
+          onClick={handleRun}
+          variant="secondary"
+          size="sm"
+          disabled={running}
+          className="px-3 py-2"
+        >
+          {running
+            ? t("settings.debug.audioDiagnostics.running")
+            : t("settings.debug.audioDiagnostics.run")}
+        </Button>
+        {report && report.devices.length === 0 && (
+          <p className="text-xs text-mid-gray">
+            {t("settings.debug.audioDiagnostics.noDevices")}
+          </p>
+        )}
+        {report?.devices.map((device) => (
+          <div
+            key={device.device_name}
+            className="rounded-md border border-mid-gray/30 px-2 py-1 text-xs"
+          >
+            <div className="flex items-center gap-2">
+              <span className="font-medium flex-1 truncate">
+                {device.device_name}
+                {device.is_default &&
+                  ` (${t("settings.debug.audioDiagnostics.default")})`}
+              </span>
+              <span
+                className={
+                  device.open_ok && device.got_audio
+                    ? "text-green-400"
+                    : "text-red-400"
+                }
+              >
+                {status(device)}
+              </span>
+            </div>
+            <div className="text-mid-gray font-mono break-all">
+              {t("settings.debug.audioDiagnostics.formats", {
+                rates: device.sample_rates.join(", ") || "-",
+                channels: device.channels.join(", ") || "-",
+              })}
+            </div>
+            {device.error && (
+              <div className="text-red-400 break-all">{device.error}</div>
+            )}
+          </div>
+        ))}
+      </div>
+    </SettingContainer>
+  );
+};
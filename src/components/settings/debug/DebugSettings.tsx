@@ -3,6 +3,7 @@ import { useTranslation } from "react-i18next";
 import { type } from "@tauri-apps/plugin-os";
 import { AlertTriangle } from "lucide-react";
 import { LogDirectory } from "./LogDirectory";
+import { AudioDiagnostics } from "./AudioDiagnostics";
 import { SettingsDirectory } from "./SettingsDirectory";
 import { LogLevelSelector } from "./LogLevelSelector";
 import { DevConsoleLogLevelSelector } from "./DevConsoleLogLevelSelector";
@@ -69,6 +70,7 @@ export const DebugSettings: React.FC = () => {
         <AlwaysOnMicrophone descriptionMode="tooltip" grouped={true} />
         <ClamshellMicrophoneSelector descriptionMode="tooltip" grouped={true} />
         <RecordingBuffer descriptionMode="tooltip" grouped={true} />
+        <AudioDiagnostics descriptionMode="tooltip" grouped={true} />
         {/* Cancel shortcut is disabled on Linux due to instability with dynamic shortcut registration */}
         {!isLinux && (
           <HandyShortcut
@@ -3,10 +3,15 @@ import { useTranslation } from "react-i18next";
 import { invoke } from "@tauri-apps/api/core";
 import { relaunch } from "@tauri-apps/plugin-process";
 import { AlertTriangle, Info, RefreshCw, CheckCircle } from "lucide-react";
+import { Input } from "../../ui/Input";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { TellMeMore } from "../../ui/TellMeMore";
 import { useSettings } from "../../../hooks/useSettings";
 
+// Matches DOUBLE_TAP_WINDOW_RANGE_MS in the key listener.
+const MIN_DOUBLE_TAP_WINDOW_MS = 100;
+const MAX_DOUBLE_TAP_WINDOW_MS = 1000;
+
 // ShortcutBinding type from backend
 interface ShortcutBinding {
   id: string;
@@ -18,7 +23,7 @@ interface ShortcutBinding {
 
 export const ShortcutEngineSelector: React.FC = () => {
   const { t } = useTranslation();
-  const { settings, isUpdating, refreshSettings } = useSettings();
+  const { settings, updateSetting, isUpdating, refreshSettings } = useSettings();
   const [incompatibleShortcuts, setIncompatibleShortcuts] = useState<ShortcutBinding[]>([]);
   const [isChanging, setIsChanging] = useState(false);
   const [error, setError] = useState<string | null>(null);
@@ -75,6 +80,8 @@ export const ShortcutEngineSelector: React.FC = () => {
         return t("settings.debug.shortcutEngine.options.rdev");
       case "handy_keys":
         return t("settings.debug.shortcutEngine.options.handyKeys");
+      case "portal":
+        return t("settings.debug.shortcutEngine.options.portal");
       default:
         return t("settings.debug.shortcutEngine.options.tauri");
     }
@@ -130,6 +137,7 @@ export const ShortcutEngineSelector: React.FC = () => {
         await refreshSettings();
         setPendingEngine(null);
       }
+      await invoke("flush_app_settings");
       await relaunch();
     } catch (err) {
       console.error("Failed to restart app:", err);
@@ -172,6 +180,40 @@ export const ShortcutEngineSelector: React.FC = () => {
         </div>
       </SettingContainer>
 
+      {configuredEngine === "rdev" && (
+        <SettingContainer
+          title={t("settings.debug.shortcutEngine.doubleTapWindow.title")}
+          description={t("settings.debug.shortcutEngine.doubleTapWindow.description")}
+          descriptionMode="tooltip"
+          grouped={true}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="number"
+              min={MIN_DOUBLE_TAP_WINDOW_MS}
+              max={MAX_DOUBLE_TAP_WINDOW_MS}
+              step={50}
+              value={(settings as any)?.shortcut_double_tap_window_ms ?? 350}
+              onChange={(e) => {
+                const value = parseInt(e.target.value, 10);
+                if (!isNaN(value)) {
+                  (updateSetting as any)(
+                    "shortcut_double_tap_window_ms",
+                    Math.min(
+                      MAX_DOUBLE_TAP_WINDOW_MS,
+                      Math.max(MIN_DOUBLE_TAP_WINDOW_MS, value)
+                    )
+                  );
+                }
+              }}
+              disabled={isUpdating("shortcut_double_tap_window_ms" as any)}
+              className="w-24 text-right"
+            />
+            <span className="text-sm text-text/70">ms</span>
+          </div>
+        </SettingContainer>
+      )}
+
       {/* Currently active engine indicator */}
       <div className="mx-4 p-3 bg-[#2b2b2b]/50 border border-[#3c3c3c] rounded-lg">
         <div className="flex items-center gap-2 text-xs text-gray-400">
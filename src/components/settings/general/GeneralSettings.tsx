@@ -3,6 +3,9 @@ import { useTranslation } from "react-i18next";
 import { AutomaticMicrophoneMask } from "../AutomaticMicrophoneMask";
 import { MicrophoneInputBoost } from "../MicrophoneInputBoost";
 import { MicrophoneNoiseCancellation } from "../MicrophoneNoiseCancellation";
+import { AutoRecoverInputDevice } from "../AutoRecoverInputDevice";
+import { RecordingLevelMeter } from "../RecordingLevelMeter";
+import { AudioPreprocessing } from "../AudioPreprocessing";
 import { MicrophoneSelector } from "../MicrophoneSelector";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { OutputDeviceSelector } from "../OutputDeviceSelector";
@@ -190,6 +193,9 @@ export const GeneralSettings: React.FC = () => {
             descriptionMode="tooltip"
             grouped={true}
           />
+          <AutoRecoverInputDevice descriptionMode="tooltip" grouped={true} />
+          <AudioPreprocessing descriptionMode="tooltip" grouped={true} />
+          <RecordingLevelMeter descriptionMode="tooltip" grouped={true} />
           <AutomaticMicrophoneMask descriptionMode="tooltip" grouped={true} />
           <div>
             <AudioFeedback descriptionMode="tooltip" grouped={true} />
@@ -3,6 +3,7 @@ import { useTranslation } from "react-i18next";
 import { AudioPlayer, AudioPlayerGroup } from "../../ui/AudioPlayer";
 import { Button } from "../../ui/Button";
 import { ConfirmationModal } from "../../ui/ConfirmationModal";
+import { Dropdown } from "../../ui/Dropdown";
 import {
   Copy,
   Star,
@@ -12,8 +13,14 @@ import {
   Wand2,
   AlertTriangle,
   RotateCcw,
+  Languages,
+  Download,
+  Volume2,
+  Captions,
+  Sparkles,
 } from "lucide-react";
 import { convertFileSrc, invoke } from "@tauri-apps/api/core";
+import { revealItemInDir } from "@tauri-apps/plugin-opener";
 import { listen } from "@tauri-apps/api/event";
 import { sessionToast as toast } from "@/lib/sessionToast";
 import { commands, type HistoryEntry } from "@/bindings";
@@ -24,9 +31,11 @@ import { RecordingRetentionPeriodSelector } from "../RecordingRetentionPeriod";
 import { Alert } from "../../ui/Alert";
 import { Input } from "../../ui/Input";
 import { SettingsGroup } from "../../ui/SettingsGroup";
+import { Slider } from "../../ui/Slider";
 import { ToggleSwitch } from "../../ui/ToggleSwitch";
 import { SettingContainer } from "../../ui/SettingContainer";
 import { useSettings } from "@/hooks/useSettings";
+import { UsageStatsSection } from "./UsageStats";
 
 const PAGE_SIZE = 30;
 
@@ -46,12 +55,14 @@ interface HistoryActionsProps {
   deleteAllDisabled: boolean;
   onDeleteAll: () => void;
   onOpenRecordings: () => void;
+  onExport: (format: "json" | "csv") => void;
 }
 
 const HistoryActions: React.FC<HistoryActionsProps> = ({
   deleteAllDisabled,
   onDeleteAll,
   onOpenRecordings,
+  onExport,
 }) => {
   const { t } = useTranslation();
 
@@ -78,12 +89,36 @@ const HistoryActions: React.FC<HistoryActionsProps> = ({
         <FolderOpen className="w-4 h-4" />
         <span>{t("settings.history.openFolder")}</span>
       </Button>
+      {(["json", "csv"] as const).map((format) => (
+        <Button
+          key={format}
+          onClick={() => onExport(format)}
+          variant="secondary"
+          size="sm"
+          className="flex items-center gap-2"
+          title={t(`settings.history.export.${format}`)}
+        >
+          <Download className="w-4 h-4" />
+          <span>{t(`settings.history.export.${format}`)}</span>
+        </Button>
+      ))}
     </div>
   );
 };
 
 const HistoryConfigurationSection: React.FC = () => {
   const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const timestampsEnabled =
+    getSetting("history_timestamps_enabled" as any) ?? false;
+  const lowConfidenceThreshold = Number(
+    getSetting("low_confidence_warning_threshold" as any) ?? 0,
+  );
+  const trimSilenceEnabled =
+    getSetting("history_trim_silence_enabled" as any) ?? false;
+  const trimSilencePaddingMs = Number(
+    getSetting("history_trim_silence_padding_ms" as any) ?? 200,
+  );
 
   return (
     <SettingsGroup title={t("settings.history.settings.title")}>
@@ -92,6 +127,62 @@ const HistoryConfigurationSection: React.FC = () => {
         descriptionMode="tooltip"
         grouped={true}
       />
+      <ToggleSwitch
+        checked={timestampsEnabled}
+        onChange={(enabled) =>
+          updateSetting("history_timestamps_enabled" as any, enabled)
+        }
+        isUpdating={isUpdating("history_timestamps_enabled" as any)}
+        label={t("settings.history.timestamps.label")}
+        description={t("settings.history.timestamps.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <ToggleSwitch
+        checked={trimSilenceEnabled}
+        onChange={(enabled) =>
+          updateSetting("history_trim_silence_enabled" as any, enabled)
+        }
+        isUpdating={isUpdating("history_trim_silence_enabled" as any)}
+        label={t("settings.history.trimSilence.label")}
+        description={t("settings.history.trimSilence.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      {trimSilenceEnabled && (
+        <Slider
+          value={trimSilencePaddingMs}
+          onChange={(value) =>
+            updateSetting("history_trim_silence_padding_ms" as any, value)
+          }
+          min={0}
+          max={2000}
+          step={50}
+          formatValue={(value) => `${value} ms`}
+          label={t("settings.history.trimSilence.paddingLabel")}
+          description={t("settings.history.trimSilence.paddingDescription")}
+          descriptionMode="tooltip"
+          grouped={true}
+        />
+      )}
+      <Slider
+        value={lowConfidenceThreshold}
+        onChange={(value) =>
+          updateSetting("low_confidence_warning_threshold" as any, value)
+        }
+        min={0}
+        max={1}
+        step={0.05}
+        formatValue={(value) =>
+          value > 0
+            ? `${Math.round(value * 100)}%`
+            : t("settings.history.confidence.off")
+        }
+        label={t("settings.history.confidence.label")}
+        description={t("settings.history.confidence.description")}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
     </SettingsGroup>
   );
 };
@@ -652,8 +743,30 @@ const IconButton: React.FC<{
   </button>
 );
 
+const REPASTE_LAST_COUNT_MAX = 20;
+const UNDO_LAST_PASTE_TIMEOUT_MAX_SECS = 600;
+
+// The separator input is single-line, so newlines and tabs are edited as
+// "\n" / "\t" escapes.
+const encodeSeparator = (separator: string) =>
+  separator.replace(/\n/g, "\\n").replace(/\t/g, "\\t");
+const decodeSeparator = (separator: string) =>
+  separator.replace(/\\n/g, "\n").replace(/\\t/g, "\t");
+
 const RepasteShortcutSection: React.FC = () => {
   const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const repasteCount = Number(getSetting("repaste_last_count" as any) ?? 3);
+  const separator = encodeSeparator(
+    getSetting("repaste_last_separator" as any) ?? "\n",
+  );
+  const [separatorDraft, setSeparatorDraft] = useState(separator);
+  useEffect(() => setSeparatorDraft(separator), [separator]);
+  const includeAiReplace =
+    getSetting("repaste_last_include_ai_replace" as any) ?? false;
+  const undoTimeoutSecs = Number(
+    getSetting("undo_last_paste_timeout_secs" as any) ?? 30,
+  );
 
   return (
     <SettingsGroup title={t("settings.history.shortcut.title")}>
@@ -662,6 +775,105 @@ const RepasteShortcutSection: React.FC = () => {
         grouped={true}
         descriptionMode="inline"
       />
+      <HandyShortcut
+        shortcutId="repaste_last_n"
+        grouped={true}
+        descriptionMode="inline"
+      />
+      <SettingContainer
+        title={t("settings.history.repasteLastN.count")}
+        description={t("settings.history.repasteLastN.countDescription")}
+        descriptionMode="tooltip"
+        grouped={true}
+        layout="horizontal"
+      >
+        <Input
+          type="number"
+          min={1}
+          max={REPASTE_LAST_COUNT_MAX}
+          value={repasteCount}
+          onChange={(event) => {
+            const value = parseInt(event.target.value, 10);
+            if (!isNaN(value)) {
+              updateSetting(
+                "repaste_last_count" as any,
+                Math.min(REPASTE_LAST_COUNT_MAX, Math.max(1, value)) as any,
+              );
+            }
+          }}
+          disabled={isUpdating("repaste_last_count" as any)}
+          className="w-20"
+        />
+      </SettingContainer>
+      <SettingContainer
+        title={t("settings.history.repasteLastN.separator")}
+        description={t("settings.history.repasteLastN.separatorDescription")}
+        descriptionMode="tooltip"
+        grouped={true}
+        layout="horizontal"
+      >
+        <Input
+          type="text"
+          value={separatorDraft}
+          onChange={(event) => setSeparatorDraft(event.target.value)}
+          onBlur={() => {
+            if (separatorDraft !== separator) {
+              updateSetting(
+                "repaste_last_separator" as any,
+                decodeSeparator(separatorDraft) as any,
+              );
+            }
+          }}
+          disabled={isUpdating("repaste_last_separator" as any)}
+          className="w-28"
+        />
+      </SettingContainer>
+      <ToggleSwitch
+        checked={includeAiReplace}
+        onChange={(enabled) =>
+          updateSetting("repaste_last_include_ai_replace" as any, enabled)
+        }
+        isUpdating={isUpdating("repaste_last_include_ai_replace" as any)}
+        label={t("settings.history.repasteLastN.includeAiReplace")}
+        description={t(
+          "settings.history.repasteLastN.includeAiReplaceDescription",
+        )}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
+      <HandyShortcut
+        shortcutId="undo_last_paste"
+        grouped={true}
+        descriptionMode="inline"
+      />
+      <SettingContainer
+        title={t("settings.history.undoLastPaste.timeout")}
+        description={t("settings.history.undoLastPaste.timeoutDescription")}
+        descriptionMode="tooltip"
+        grouped={true}
+        layout="horizontal"
+      >
+        <Input
+          type="number"
+          min={1}
+          max={UNDO_LAST_PASTE_TIMEOUT_MAX_SECS}
+          value={undoTimeoutSecs}
+          onChange={(event) => {
+            const value = parseInt(event.target.value, 10);
+            if (!isNaN(value)) {
+              updateSetting(
+                "undo_last_paste_timeout_secs" as any,
+                Math.min(
+                  UNDO_LAST_PASTE_TIMEOUT_MAX_SECS,
+                  Math.max(1, value),
+                ) as any,
+              );
+            }
+          }}
+          disabled={isUpdating("undo_last_paste_timeout_secs" as any)}
+          className="w-20"
+        />
+      </SettingContainer>
     </SettingsGroup>
   );
 };
@@ -673,6 +885,7 @@ const HistoryPageLayout: React.FC<{ children: React.ReactNode }> = ({
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <HistoryConfigurationSection />
       <DictationStatsSection />
+      <UsageStatsSection />
       <RepasteShortcutSection />
       {children}
     </div>
@@ -925,7 +1138,19 @@ export const HistorySettings: React.FC = () => {
   };
 
   const retryHistoryEntry = async (id: number) => {
-    await invoke("retry_history_entry_transcription", { id });
+    await invoke("retranscribe_history_entry", {
+      entryId: id,
+      useCurrentSettings: true,
+    });
+  };
+
+  const playHistoryAudio = async (id: number) => {
+    try {
+      await invoke("play_history_audio", { entryId: id });
+    } catch (error) {
+      console.error("Failed to play recording:", error);
+      toast.error(t("settings.history.playError", { error: String(error) }));
+    }
   };
 
   const openRecordingsFolder = async () => {
@@ -936,6 +1161,17 @@ export const HistorySettings: React.FC = () => {
     }
   };
 
+  const exportHistory = async (format: "json" | "csv") => {
+    try {
+      const path = await invoke<string>("export_history", { format });
+      toast.success(t("settings.history.export.success"));
+      await revealItemInDir(path);
+    } catch (error) {
+      console.error("Failed to export history:", error);
+      toast.error(t("settings.history.export.error", { error: String(error) }));
+    }
+  };
+
   const deleteAllModal = (
     <ConfirmationModal
       isOpen={showDeleteAllConfirm}
@@ -964,6 +1200,7 @@ export const HistorySettings: React.FC = () => {
                 deleteAllDisabled={true}
                 onDeleteAll={() => setShowDeleteAllConfirm(true)}
                 onOpenRecordings={openRecordingsFolder}
+                onExport={exportHistory}
               />
             </div>
             <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
@@ -993,6 +1230,7 @@ export const HistorySettings: React.FC = () => {
                 deleteAllDisabled={true}
                 onDeleteAll={() => setShowDeleteAllConfirm(true)}
                 onOpenRecordings={openRecordingsFolder}
+                onExport={exportHistory}
               />
             </div>
             <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
@@ -1022,6 +1260,7 @@ export const HistorySettings: React.FC = () => {
               deleteAllDisabled={isDeletingAll || historyEntries.length === 0}
               onDeleteAll={() => setShowDeleteAllConfirm(true)}
               onOpenRecordings={openRecordingsFolder}
+              onExport={exportHistory}
             />
           </div>
           <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
@@ -1043,6 +1282,7 @@ export const HistorySettings: React.FC = () => {
                     getAudioUrl={getAudioUrl}
                     deleteAudio={deleteAudioEntry}
                     retryTranscription={retryHistoryEntry}
+                    playAudio={playHistoryAudio}
                   />
                 ))}
               </div>
@@ -1063,6 +1303,7 @@ interface HistoryEntryProps {
   getAudioUrl: (fileName: string) => Promise<string | null>;
   deleteAudio: (id: number) => Promise<void>;
   retryTranscription: (id: number) => Promise<void>;
+  playAudio: (id: number) => Promise<void>;
 }
 
 const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
@@ -1072,16 +1313,40 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   getAudioUrl,
   deleteAudio,
   retryTranscription,
+  playAudio,
 }) => {
   const { t, i18n } = useTranslation();
   const [showCopied, setShowCopied] = useState(false);
   const [retrying, setRetrying] = useState(false);
+  const [showSubtitleFormats, setShowSubtitleFormats] = useState(false);
+  const [showReprocess, setShowReprocess] = useState(false);
+  const [reprocessing, setReprocessing] = useState(false);
+  const { settings } = useSettings();
+  const prompts: { id: string; name: string }[] =
+    (settings as any)?.post_process_prompts ?? [];
+  const [reprocessPromptId, setReprocessPromptId] = useState<string | null>(
+    null,
+  );
+  const selectedReprocessPromptId =
+    reprocessPromptId ??
+    (settings as any)?.post_process_selected_prompt_id ??
+    prompts[0]?.id ??
+    null;
 
   const isAiReplace = entry.action_type === "ai_replace";
   const displayText = isAiReplace
     ? (entry.ai_response ?? entry.transcription_text)
     : (entry.post_processed_text ?? entry.transcription_text);
   const hasDisplayText = displayText.trim().length > 0;
+  const rawResponse = (
+    entry as HistoryEntry & { post_process_raw_response?: string | null }
+  ).post_process_raw_response;
+  const translationLanguages =
+    (entry as HistoryEntry & { translation_languages?: string[] })
+      .translation_languages ?? [];
+  const confidence =
+    (entry as HistoryEntry & { confidence?: number | null }).confidence ??
+    null;
 
   const handleLoadAudio = useCallback(
     () => getAudioUrl(entry.file_name),
@@ -1119,6 +1384,48 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     }
   };
 
+  const handleCopySubtitles = async (format: "srt" | "vtt") => {
+    try {
+      const subtitles = await invoke<string>("export_history_entry_subtitles", {
+        entryId: entry.id,
+        format,
+      });
+      await navigator.clipboard.writeText(subtitles);
+      toast.success(t("settings.history.timestamps.copied"));
+      setShowSubtitleFormats(false);
+    } catch (error) {
+      toast.error(
+        t("settings.history.timestamps.error", { error: String(error) }),
+      );
+    }
+  };
+
+  const handleReprocess = async (paste: boolean) => {
+    if (!selectedReprocessPromptId) return;
+    setReprocessing(true);
+    try {
+      await invoke("reprocess_history_entry", {
+        entryId: entry.id,
+        promptId: selectedReprocessPromptId,
+        paste,
+      });
+      setShowReprocess(false);
+    } catch (error) {
+      const kind = (error as { kind?: string })?.kind;
+      if (kind !== "cancelled") {
+        toast.error(
+          kind
+            ? t(`settings.history.reprocess.errors.${kind}`, {
+                message: (error as { message?: string }).message ?? "",
+              })
+            : String(error),
+        );
+      }
+    } finally {
+      setReprocessing(false);
+    }
+  };
+
   const formattedDate = formatDateTime(String(entry.timestamp), i18n.language);
 
   // Truncate text for display
@@ -1140,6 +1447,24 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               {t("settings.history.aiReplace.badge")}
             </span>
           )}
+          {translationLanguages.length > 0 && (
+            <span
+              className="flex items-center gap-1 text-xs bg-mid-gray/20 text-text/70 px-2 py-0.5 rounded"
+              title={t("settings.history.translations.available", {
+                languages: translationLanguages.join(", "),
+              })}
+            >
+              <Languages width={12} height={12} />
+              {translationLanguages.join(", ")}
+            </span>
+          )}
+          {confidence !== null && (
+            <span className="text-xs bg-mid-gray/20 text-text/70 px-2 py-0.5 rounded">
+              {t("settings.history.confidence.badge", {
+                percent: Math.round(confidence * 100),
+              })}
+            </span>
+          )}
         </div>
         <div className="flex items-center gap-1">
           <IconButton
@@ -1169,6 +1494,33 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               fill={entry.saved ? "currentColor" : "none"}
             />
           </IconButton>
+          {!isAiReplace && (
+            <IconButton
+              onClick={() => void playAudio(entry.id)}
+              title={t("settings.history.playOnOutputDevice")}
+            >
+              <Volume2 width={16} height={16} />
+            </IconButton>
+          )}
+          {!isAiReplace && (
+            <IconButton
+              onClick={() => setShowSubtitleFormats((value) => !value)}
+              active={showSubtitleFormats}
+              title={t("settings.history.timestamps.export")}
+            >
+              <Captions width={16} height={16} />
+            </IconButton>
+          )}
+          {!isAiReplace && (
+            <IconButton
+              onClick={() => setShowReprocess((value) => !value)}
+              active={showReprocess}
+              disabled={retrying || reprocessing}
+              title={t("settings.history.reprocess.title")}
+            >
+              <Sparkles width={16} height={16} />
+            </IconButton>
+          )}
           {!isAiReplace && (
             <IconButton
               onClick={handleRetranscribe}
@@ -1196,6 +1548,62 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
         </div>
       </div>
 
+      {showSubtitleFormats && (
+        <div className="flex justify-end gap-2">
+          {(["srt", "vtt"] as const).map((format) => (
+            <Button
+              key={format}
+              variant="secondary"
+              size="sm"
+              onClick={() => void handleCopySubtitles(format)}
+            >
+              {t(`settings.history.timestamps.copy.${format}`)}
+            </Button>
+          ))}
+        </div>
+      )}
+
+      {showReprocess && (
+        <div className="flex justify-end items-center gap-2">
+          {prompts.length === 0 ? (
+            <p className="text-xs text-mid-gray">
+              {t("settings.history.reprocess.noPrompts")}
+            </p>
+          ) : (
+            <>
+              <Dropdown
+                selectedValue={selectedReprocessPromptId}
+                options={prompts.map((prompt) => ({
+                  value: prompt.id,
+                  label: prompt.name,
+                }))}
+                onSelect={setReprocessPromptId}
+                disabled={reprocessing}
+                dropUp={false}
+              />
+              <Button
+                variant="secondary"
+                size="sm"
+                disabled={reprocessing}
+                onClick={() => void handleReprocess(false)}
+              >
+                {reprocessing
+                  ? t("settings.history.reprocess.running")
+                  : t("settings.history.reprocess.run")}
+              </Button>
+              <Button
+                variant="secondary"
+                size="sm"
+                disabled={reprocessing}
+                onClick={() => void handleReprocess(true)}
+              >
+                {t("settings.history.reprocess.runAndPaste")}
+              </Button>
+            </>
+          )}
+        </div>
+      )}
+
       {isAiReplace ? (
         // AI Replace Entry Display
         <div className="space-y-2">
@@ -1230,9 +1638,17 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               {t("settings.history.aiReplace.response")}
             </p>
             {entry.ai_response ? (
-              <p className="text-text/90 text-sm select-text cursor-text">
-                {entry.ai_response}
-              </p>
+              <>
+                <p className="text-text/90 text-sm select-text cursor-text">
+                  {entry.ai_response}
+                </p>
+                {(entry as HistoryEntry & { ai_response_rejected?: boolean })
+                  .ai_response_rejected && (
+                  <p className="text-xs text-mid-gray mt-1">
+                    {t("settings.history.aiReplace.rejected")}
+                  </p>
+                )}
+              </>
             ) : (
               <div className="flex items-center gap-2 text-amber-500">
                 <AlertTriangle width={14} height={14} />
@@ -1274,6 +1690,16 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
                 ? displayText
                 : t("settings.history.transcriptionFailed")}
           </p>
+          {rawResponse && (
+            <details className="cursor-pointer pb-2 text-xs">
+              <summary className="text-blue-400 hover:text-blue-300">
+                {t("settings.history.rawResponse")}
+              </summary>
+              <pre className="mt-1 p-2 rounded bg-mid-gray/10 text-text/80 select-text cursor-text whitespace-pre-wrap break-words">
+                {rawResponse}
+              </pre>
+            </details>
+          )}
           <AudioPlayer onLoadRequest={handleLoadAudio} className="w-full" />
         </>
       )}
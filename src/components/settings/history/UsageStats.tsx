@@ -0,0 +1,205 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { Button } from "../../ui/Button";
+import { ConfirmationModal } from "../../ui/ConfirmationModal";
+import { Dropdown } from "../../ui/Dropdown";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { SettingsGroup } from "../../ui/SettingsGroup";
+
+type UsageStatsRange = "today" | "last_7_days" | "last_30_days" | "all_time";
+
+interface UsageCounters {
+  dictations: number;
+  words: number;
+  characters: number;
+  audio_ms: number;
+  llm_post_processes: number;
+  ai_replaces: number;
+  extension_messages: number;
+}
+
+interface UsageGroup {
+  key: string;
+  counters: UsageCounters;
+}
+
+interface UsageStats {
+  range: UsageStatsRange;
+  totals: UsageCounters;
+  days: { date: string; counters: UsageCounters }[];
+  by_stt_provider: UsageGroup[];
+  by_llm_provider: UsageGroup[];
+  by_profile: UsageGroup[];
+}
+
+const RANGES: UsageStatsRange[] = [
+  "today",
+  "last_7_days",
+  "last_30_days",
+  "all_time",
+];
+
+const formatMinutes = (audioMs: number) => (audioMs / 60000).toFixed(1);
+
+const GroupList: React.FC<{ title: string; groups: UsageGroup[] }> = ({
+  title,
+  groups,
+}) => {
+  const { t } = useTranslation();
+  if (groups.length === 0) return null;
+  return (
+    <div className="space-y-1">
+      <div className="text-xs font-semibold text-text/70">{title}</div>
+      {groups.map((group) => (
+        <div key={group.key} className="flex justify-between text-xs">
+          <span className="truncate">{group.key}</span>
+          <span className="text-mid-gray">
+            {t("settings.history.usageStats.groupSummary", {
+              words: group.counters.words,
+              minutes: formatMinutes(group.counters.audio_ms),
+            })}
+          </span>
+        </div>
+      ))}
+    </div>
+  );
+};
+
+export const UsageStatsSection: React.FC = () => {
+  const { t } = useTranslation();
+  const [range, setRange] = useState<UsageStatsRange>("last_7_days");
+  const [stats, setStats] = useState<UsageStats | null>(null);
+  const [showResetConfirm, setShowResetConfirm] = useState(false);
+
+  const load = useCallback(async () => {
+    try {
+      setStats(await invoke<UsageStats>("get_usage_stats", { range }));
+    } catch (error) {
+      toast.error(String(error));
+    }
+  }, [range]);
+
+  useEffect(() => {
+    void load();
+  }, [load]);
+
+  const handleReset = async () => {
+    setShowResetConfirm(false);
+    try {
+      await invoke("reset_usage_stats");
+      await load();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const maxWords = Math.max(
+    1,
+    ...(stats?.days ?? []).map((day) => day.counters.words),
+  );
+  const totals = stats?.totals;
+
+  return (
+    <SettingsGroup title={t("settings.history.usageStats.title")}>
+      <SettingContainer
+        title={t("settings.history.usageStats.range")}
+        description={t("settings.history.usageStats.description")}
+        descriptionMode="tooltip"
+        grouped
+        layout="stacked"
+      >
+        <div className="space-y-3">
+          <div className="flex items-center gap-2">
+            <Dropdown
+              selectedValue={range}
+              options={RANGES.map((value) => ({
+                value,
+                label: t(`settings.history.usageStats.ranges.${value}`),
+              }))}
+              onSelect={(value) => setRange(value as UsageStatsRange)}
+            />
+            <Button variant="secondary" size="sm" onClick={() => void load()}>
+              {t("settings.history.usageStats.refresh")}
+            </Button>
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={() => setShowResetConfirm(true)}
+            >
+              {t("settings.history.usageStats.reset")}
+            </Button>
+          </div>
+          {totals && (
+            <div className="grid grid-cols-3 gap-2 text-xs">
+              {(
+                [
+                  ["dictations", totals.dictations],
+                  ["words", totals.words],
+                  ["minutes", formatMinutes(totals.audio_ms)],
+                  ["llmPostProcesses", totals.llm_post_processes],
+                  ["aiReplaces", totals.ai_replaces],
+                  ["extensionMessages", totals.extension_messages],
+                ] as const
+              ).map(([key, value]) => (
+                <div
+                  key={key}
+                  className="rounded-md border border-mid-gray/30 px-2 py-1"
+                >
+                  <div className="text-mid-gray">
+                    {t(`settings.history.usageStats.totals.${key}`)}
+                  </div>
+                  <div className="font-medium">{value}</div>
+                </div>
+              ))}
+            </div>
+          )}
+          {stats && stats.days.length > 1 && (
+            <div className="flex items-end gap-px h-16">
+              {stats.days.map((day) => (
+                <div
+                  key={day.date}
+                  className="flex-1 bg-logo-primary/60 rounded-t-sm min-h-px"
+                  style={{
+                    height: `${(day.counters.words / maxWords) * 100}%`,
+                  }}
+                  title={t("settings.history.usageStats.dayTooltip", {
+                    date: day.date,
+                    words: day.counters.words,
+                  })}
+                />
+              ))}
+            </div>
+          )}
+          {stats && (
+            <>
+              <GroupList
+                title={t("settings.history.usageStats.byProvider")}
+                groups={stats.by_stt_provider}
+              />
+              <GroupList
+                title={t("settings.history.usageStats.byProfile")}
+                groups={stats.by_profile}
+              />
+              <GroupList
+                title={t("settings.history.usageStats.byLlmProvider")}
+                groups={stats.by_llm_provider}
+              />
+            </>
+          )}
+        </div>
+      </SettingContainer>
+      <ConfirmationModal
+        isOpen={showResetConfirm}
+        onClose={() => setShowResetConfirm(false)}
+        onConfirm={handleReset}
+        title={t("settings.history.usageStats.resetConfirmTitle")}
+        message={t("settings.history.usageStats.resetConfirmMessage")}
+        confirmText={t("settings.history.usageStats.reset")}
+        cancelText={t("common.cancel")}
+        variant="danger"
+      />
+    </SettingsGroup>
+  );
+};
@@ -15,6 +15,7 @@ import { Button } from "../../ui/Button";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { TellMeMore } from "../../ui/TellMeMore";
 import { RemoteSttSettings } from "../remote-stt/RemoteSttSettings";
+import { OpenAiRealtimeSettings } from "../remote-stt/OpenAiRealtimeSettings";
 import { ModelMetadataPanel } from "./ModelMetadataPanel";
 import { ModelFilterBar } from "./ModelFilterBar";
 import { ModelReleaseDate } from "../../shared/ModelReleaseDate";
@@ -130,6 +131,7 @@ export const ModelsSettings: React.FC = () => {
     selectModel,
     downloadModel,
     cancelDownload,
+    pauseDownload,
     deleteModel,
   } = useModels();
   const {
@@ -164,11 +166,13 @@ export const ModelsSettings: React.FC = () => {
   );
   const remoteStt = (getSetting("remote_stt") || {}) as RemoteSttSettingsConfig;
   const remotePreset = remoteStt.provider_preset ?? "groq";
-  const remoteModelId = remoteStt.model_id ?? "";
+  // Model IDs are matched case-insensitively, as in the backend.
+  const remoteModelId = (remoteStt.model_id ?? "").trim().toLowerCase();
   const isRemoteProvider =
     transcriptionProvider === "remote_openai_compatible" ||
     transcriptionProvider === "remote_soniox" ||
-    transcriptionProvider === "remote_deepgram";
+    transcriptionProvider === "remote_deepgram" ||
+    transcriptionProvider === "remote_openai_realtime";
   const activeRemoteApiId: RemoteApiRowId | null =
     transcriptionProvider !== "remote_openai_compatible"
       ? null
@@ -575,6 +579,55 @@ export const ModelsSettings: React.FC = () => {
 
         <div className="border-t border-[#3d3d3d]" />
 
+        {/* Remote via OpenAI Realtime */}
+        <div
+          className={`px-6 py-4 flex flex-col gap-3 transition-colors ${
+            transcriptionProvider === "remote_openai_realtime"
+              ? "bg-green-500/5"
+              : ""
+          }`}
+        >
+          <div className="flex items-center justify-between">
+            <div className="min-w-0">
+              <div className="flex items-center gap-2">
+                <Radio className="w-4 h-4 text-emerald-400" />
+                <p className="text-sm font-medium text-[#f5f5f5]">
+                  {t("modelSelector.remoteOpenAiRealtimeMode")}
+                </p>
+                {transcriptionProvider === "remote_openai_realtime" && (
+                  <span className="text-xs text-emerald-400">
+                    {t("modelSelector.active")}
+                  </span>
+                )}
+              </div>
+              {transcriptionProvider === "remote_openai_realtime" && (
+                <p className="text-xs text-[#a0a0a0] mt-1">
+                  {t("modelSelector.remoteOpenAiRealtimeModeDescription")}
+                </p>
+              )}
+            </div>
+            {transcriptionProvider !== "remote_openai_realtime" && (
+              <Button
+                variant="secondary"
+                size="sm"
+                onClick={() => {
+                  invalidateModelDownloadActivationIntent();
+                  void setTranscriptionProvider("remote_openai_realtime");
+                }}
+              >
+                {t("modelSelector.chooseModel")}
+              </Button>
+            )}
+          </div>
+          {transcriptionProvider === "remote_openai_realtime" && (
+            <div className="border-t border-[#3d3d3d] pt-3">
+              <OpenAiRealtimeSettings descriptionMode="tooltip" grouped={true} />
+            </div>
+          )}
+        </div>
+
+        <div className="border-t border-[#3d3d3d]" />
+
         {renderRemoteApiRows(discouragedRemoteApiRows)}
       </SettingsGroup>
 
@@ -765,6 +818,26 @@ export const ModelsSettings: React.FC = () => {
                 {isDownloading && (
                   <p className="text-xs text-[#ff4d8d] mt-1">
                     {t("modelSelector.downloading", { percentage: percent })}
+                    {progress && progress.bytes_per_second > 0 && (
+                      <>
+                        {" · "}
+                        {t("modelSelector.downloadSpeed", {
+                          speed: (
+                            progress.bytes_per_second /
+                            (1024 * 1024)
+                          ).toFixed(1),
+                        })}
+                      </>
+                    )}
+                  </p>
+                )}
+                {!isDownloading && Number(model.partial_size) > 0 && (
+                  <p className="text-xs text-[#8a8a8a] mt-1">
+                    {t("modelSelector.downloadPaused", {
+                      size: formatModelSize(
+                        Number(model.partial_size) / (1024 * 1024),
+                      ),
+                    })}
                   </p>
                 )}
                 {isExtracting && (
@@ -776,13 +849,25 @@ export const ModelsSettings: React.FC = () => {
 
               <div className="flex items-center gap-2">
                 {isDownloading ? (
-                  <Button
-                    variant="secondary"
-                    size="sm"
-                    onClick={() => cancelDownload(model.id)}
-                  >
-                    {t("common.cancel")}
-                  </Button>
+                  <>
+                    {/* Hugging Face downloads cannot resume, only restart. */}
+                    {!model.url?.startsWith("hf://") && (
+                      <Button
+                        variant="secondary"
+                        size="sm"
+                        onClick={() => pauseDownload(model.id)}
+                      >
+                        {t("modelSelector.pause")}
+                      </Button>
+                    )}
+                    <Button
+                      variant="secondary"
+                      size="sm"
+                      onClick={() => cancelDownload(model.id)}
+                    >
+                      {t("common.cancel")}
+                    </Button>
+                  </>
                 ) : (
                   <Button
                     variant="primary"
@@ -790,7 +875,9 @@ export const ModelsSettings: React.FC = () => {
                     onClick={() => handleDownloadModel(model.id)}
                     disabled={isExtracting}
                   >
-                    {t("modelSelector.download")}
+                    {Number(model.partial_size) > 0
+                      ? t("modelSelector.resume")
+                      : t("modelSelector.download")}
                   </Button>
                 )}
               </div>
@@ -0,0 +1,46 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../../hooks/useSettings";
+import { Slider } from "../../ui/Slider";
+
+interface ClipboardVariableLimitProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const ClipboardVariableLimit: React.FC<ClipboardVariableLimitProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting } = useSettings();
+
+    return (
+      <Slider
+        value={
+          (getSetting("llm_clipboard_variable_max_chars" as any) as number) ??
+          8000
+        }
+        onChange={(value) =>
+          updateSetting(
+            "llm_clipboard_variable_max_chars" as any,
+            Math.round(value) as any,
+          )
+        }
+        min={500}
+        max={32000}
+        step={500}
+        label={t("settings.postProcessing.prompts.clipboardLimit.title")}
+        description={t(
+          "settings.postProcessing.prompts.clipboardLimit.description",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        formatValue={(value) =>
+          t("settings.postProcessing.prompts.clipboardLimit.value", {
+            count: Math.round(value),
+          })
+        }
+      />
+    );
+  });
+
+ClipboardVariableLimit.displayName = "ClipboardVariableLimit";
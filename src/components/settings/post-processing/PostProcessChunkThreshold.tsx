@@ -0,0 +1,49 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../../hooks/useSettings";
+import { Slider } from "../../ui/Slider";
+
+interface PostProcessChunkThresholdProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PostProcessChunkThreshold: React.FC<PostProcessChunkThresholdProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting } = useSettings();
+
+    return (
+      <Slider
+        value={
+          (getSetting("post_process_chunk_threshold_chars" as any) as
+            | number
+            | null) ?? 0
+        }
+        onChange={(value) =>
+          updateSetting(
+            "post_process_chunk_threshold_chars" as any,
+            (Math.round(value) || null) as any,
+          )
+        }
+        min={0}
+        max={100000}
+        step={1000}
+        label={t("settings.postProcessing.prompts.chunkThreshold.title")}
+        description={t(
+          "settings.postProcessing.prompts.chunkThreshold.description",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        formatValue={(value) =>
+          Math.round(value) > 0
+            ? t("settings.postProcessing.prompts.chunkThreshold.value", {
+                count: Math.round(value),
+              })
+            : t("settings.postProcessing.prompts.chunkThreshold.off")
+        }
+      />
+    );
+  });
+
+PostProcessChunkThreshold.displayName = "PostProcessChunkThreshold";
@@ -1,7 +1,12 @@
 import React, { useEffect, useState } from "react";
 import { Trans, useTranslation } from "react-i18next";
 import { ChevronDown, Loader2, RefreshCcw, Trash2 } from "lucide-react";
-import { commands, type LlmPostProcessBenchmarkResult } from "@/bindings";
+import { invoke } from "@tauri-apps/api/core";
+import {
+  commands,
+  type LLMPrompt,
+  type LlmPostProcessBenchmarkResult,
+} from "@/bindings";
 
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { TellMeMore } from "../../ui/TellMeMore";
@@ -20,9 +25,91 @@ import { usePostProcessProviderState } from "../PostProcessingSettingsApi/usePos
 import { useSettings } from "../../../hooks/useSettings";
 import { ExtendedThinkingSection } from "../ExtendedThinkingSection";
 import { LlmConfigSection } from "../PostProcessingSettingsApi/LlmConfigSection";
+import { ClipboardVariableLimit } from "./ClipboardVariableLimit";
+import { PostProcessChunkThreshold } from "./PostProcessChunkThreshold";
 
 
 
+const PostProcessingFallbackComponent: React.FC = () => {
+  const { t } = useTranslation();
+  const { settings, updateSetting, isUpdating } = useSettings();
+
+  const providers = settings?.post_process_providers ?? [];
+  const primaryProviderId = settings?.post_process_provider_id ?? "";
+  const fallbackProviderId =
+    ((settings as any)?.post_process_fallback_provider_id as string | null) ??
+    "";
+  const savedModel =
+    ((settings as any)?.post_process_fallback_model as string | null) ?? "";
+  const [modelDraft, setModelDraft] = useState(savedModel);
+
+  useEffect(() => {
+    setModelDraft(savedModel);
+  }, [savedModel]);
+
+  const options = [
+    { value: "", label: t("settings.postProcessing.api.fallback.none") },
+    ...providers
+      .filter(
+        (provider) =>
+          provider.id !== primaryProviderId &&
+          provider.id !== "apple_intelligence",
+      )
+      .map((provider) => ({ value: provider.id, label: provider.label })),
+  ];
+
+  return (
+    <div className="pt-6">
+      <SettingContainer
+        title={t("settings.postProcessing.api.fallback.title")}
+        description={t("settings.postProcessing.api.fallback.description")}
+        descriptionMode="inline"
+        grouped={true}
+        layout="horizontal"
+      >
+        <Dropdown
+          options={options}
+          selectedValue={fallbackProviderId}
+          onSelect={(value) =>
+            updateSetting("post_process_fallback_provider_id" as any, value)
+          }
+          disabled={isUpdating("post_process_fallback_provider_id" as any)}
+        />
+      </SettingContainer>
+      {fallbackProviderId && (
+        <SettingContainer
+          title={t("settings.postProcessing.api.fallback.modelTitle")}
+          description={t(
+            "settings.postProcessing.api.fallback.modelDescription",
+          )}
+          descriptionMode="inline"
+          grouped={true}
+          layout="horizontal"
+        >
+          <Input
+            type="text"
+            value={modelDraft}
+            onChange={(event) => setModelDraft(event.target.value)}
+            onBlur={() => {
+              if (modelDraft.trim() !== savedModel) {
+                updateSetting(
+                  "post_process_fallback_model" as any,
+                  modelDraft.trim(),
+                );
+              }
+            }}
+            placeholder={t(
+              "settings.postProcessing.api.fallback.modelPlaceholder",
+            )}
+            disabled={isUpdating("post_process_fallback_model" as any)}
+            className="w-64"
+          />
+        </SettingContainer>
+      )}
+    </div>
+  );
+};
+
 const PostProcessingSettingsApiComponent: React.FC = () => {
   const { t } = useTranslation();
   const postProcessState = usePostProcessProviderState();
@@ -36,6 +123,7 @@ const PostProcessingSettingsApiComponent: React.FC = () => {
         apiKeyFeature="post_processing"
         reasoningSettingPrefix="post_process"
       />
+      <PostProcessingFallbackComponent />
     </div>
   );
 };
@@ -442,6 +530,152 @@ const PostProcessingBenchmarkComponent: React.FC = () => {
   );
 };
 
+type PromptReasoningMode = "inherit" | "on" | "off";
+
+interface PromptOverrideDraft {
+  model: string;
+  reasoning: PromptReasoningMode;
+  budget: string;
+}
+
+const EMPTY_PROMPT_OVERRIDES: PromptOverrideDraft = {
+  model: "",
+  reasoning: "inherit",
+  budget: "",
+};
+
+const promptOverridesFromPrompt = (
+  prompt: LLMPrompt,
+): PromptOverrideDraft => ({
+  model: prompt.model_override ?? "",
+  reasoning:
+    prompt.reasoning_enabled_override == null
+      ? "inherit"
+      : prompt.reasoning_enabled_override
+        ? "on"
+        : "off",
+  budget: prompt.reasoning_budget_override?.toString() ?? "",
+});
+
+const promptOverrideArgs = (
+  draft: PromptOverrideDraft,
+): [string | null, boolean | null, number | null] => {
+  const budget = parseInt(draft.budget, 10);
+  return [
+    draft.model.trim() || null,
+    draft.reasoning === "inherit" ? null : draft.reasoning === "on",
+    Number.isFinite(budget) && budget > 0 ? budget : null,
+  ];
+};
+
+const promptOverridesEqual = (
+  a: PromptOverrideDraft,
+  b: PromptOverrideDraft,
+) =>
+  JSON.stringify(promptOverrideArgs(a)) ===
+  JSON.stringify(promptOverrideArgs(b));
+
+const PromptOverrideFields: React.FC<{
+  draft: PromptOverrideDraft;
+  onChange: (draft: PromptOverrideDraft) => void;
+}> = ({ draft, onChange }) => {
+  const { t } = useTranslation();
+
+  return (
+    <div className="space-y-2 flex flex-col">
+      <label className="text-sm font-semibold">
+        {t("settings.postProcessing.prompts.overrides.title")}
+      </label>
+      <p className="text-xs text-mid-gray/70">
+        {t("settings.postProcessing.prompts.overrides.description")}
+      </p>
+      <div className="flex flex-wrap gap-2">
+        <Input
+          type="text"
+          value={draft.model}
+          onChange={(e) => onChange({ ...draft, model: e.target.value })}
+          placeholder={t(
+            "settings.postProcessing.prompts.overrides.modelPlaceholder",
+          )}
+          variant="compact"
+          className="flex-1 min-w-[12rem]"
+        />
+        <Dropdown
+          selectedValue={draft.reasoning}
+          options={[
+            {
+              value: "inherit",
+              label: t(
+                "settings.postProcessing.prompts.overrides.reasoningInherit",
+              ),
+            },
+            {
+              value: "on",
+              label: t(
+                "settings.postProcessing.prompts.overrides.reasoningOn",
+              ),
+            },
+            {
+              value: "off",
+              label: t(
+                "settings.postProcessing.prompts.overrides.reasoningOff",
+              ),
+            },
+          ]}
+          onSelect={(value) =>
+            onChange({ ...draft, reasoning: value as PromptReasoningMode })
+          }
+        />
+        <Input
+          type="number"
+          min={1024}
+          step={1024}
+          value={draft.budget}
+          onChange={(e) => onChange({ ...draft, budget: e.target.value })}
+          placeholder={t(
+            "settings.postProcessing.prompts.overrides.budgetPlaceholder",
+          )}
+          variant="compact"
+          className="w-36"
+          disabled={draft.reasoning === "off"}
+        />
+      </div>
+    </div>
+  );
+};
+
+const PromptJsonPathField: React.FC<{
+  value: string;
+  onChange: (value: string) => void;
+}> = ({ value, onChange }) => {
+  const { t } = useTranslation();
+
+  return (
+    <div className="space-y-2 flex flex-col">
+      <label className="text-sm font-semibold">
+        {t("settings.postProcessing.prompts.jsonPath.title")}
+      </label>
+      <p className="text-xs text-mid-gray/70">
+        {t("settings.postProcessing.prompts.jsonPath.description")}
+      </p>
+      <Input
+        type="text"
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+        placeholder={t("settings.postProcessing.prompts.jsonPath.placeholder")}
+        variant="compact"
+      />
+    </div>
+  );
+};
+
+// The JSON path has its own command; an empty value clears it.
+const savePromptJsonPath = (id: string, path: string) =>
+  invoke("change_post_process_prompt_output_json_path", {
+    id,
+    path: path.trim() || null,
+  });
+
 const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const { t } = useTranslation();
   const { getSetting, updateSetting, isUpdating, refreshSettings } =
@@ -449,6 +683,8 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const [isCreating, setIsCreating] = useState(false);
   const [draftName, setDraftName] = useState("");
   const [draftText, setDraftText] = useState("");
+  const [draftOverrides, setDraftOverrides] = useState(EMPTY_PROMPT_OVERRIDES);
+  const [draftJsonPath, setDraftJsonPath] = useState("");
 
   const enabled = getSetting("post_process_enabled") || false;
   const prompts = getSetting("post_process_prompts") || [];
@@ -462,15 +698,23 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
     if (selectedPrompt) {
       setDraftName(selectedPrompt.name);
       setDraftText(selectedPrompt.prompt);
+      setDraftOverrides(promptOverridesFromPrompt(selectedPrompt));
+      setDraftJsonPath(selectedPrompt.output_json_path ?? "");
     } else {
       setDraftName("");
       setDraftText("");
+      setDraftOverrides(EMPTY_PROMPT_OVERRIDES);
+      setDraftJsonPath("");
     }
   }, [
     isCreating,
     selectedPromptId,
     selectedPrompt?.name,
     selectedPrompt?.prompt,
+    selectedPrompt?.model_override,
+    selectedPrompt?.reasoning_enabled_override,
+    selectedPrompt?.reasoning_budget_override,
+    selectedPrompt?.output_json_path,
   ]);
 
   const handlePromptSelect = (promptId: string | null) => {
@@ -486,8 +730,12 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
       const result = await commands.addPostProcessPrompt(
         draftName.trim(),
         draftText.trim(),
+        ...promptOverrideArgs(draftOverrides),
       );
       if (result.status === "ok") {
+        if (draftJsonPath.trim()) {
+          await savePromptJsonPath(result.data.id, draftJsonPath);
+        }
         await refreshSettings();
         updateSetting("post_process_selected_prompt_id", result.data.id);
         setIsCreating(false);
@@ -505,7 +753,9 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
         selectedPromptId,
         draftName.trim(),
         draftText.trim(),
+        ...promptOverrideArgs(draftOverrides),
       );
+      await savePromptJsonPath(selectedPromptId, draftJsonPath);
       await refreshSettings();
     } catch (error) {
       console.error("Failed to update prompt:", error);
@@ -529,9 +779,13 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
     if (selectedPrompt) {
       setDraftName(selectedPrompt.name);
       setDraftText(selectedPrompt.prompt);
+      setDraftOverrides(promptOverridesFromPrompt(selectedPrompt));
+      setDraftJsonPath(selectedPrompt.output_json_path ?? "");
     } else {
       setDraftName("");
       setDraftText("");
+      setDraftOverrides(EMPTY_PROMPT_OVERRIDES);
+      setDraftJsonPath("");
     }
   };
 
@@ -539,6 +793,8 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
     setIsCreating(true);
     setDraftName("");
     setDraftText("");
+    setDraftOverrides(EMPTY_PROMPT_OVERRIDES);
+    setDraftJsonPath("");
   };
 
 
@@ -547,7 +803,12 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const isDirty =
     !!selectedPrompt &&
     (draftName.trim() !== selectedPrompt.name ||
-      draftText.trim() !== selectedPrompt.prompt.trim());
+      draftText.trim() !== selectedPrompt.prompt.trim() ||
+      !promptOverridesEqual(
+        draftOverrides,
+        promptOverridesFromPrompt(selectedPrompt),
+      ) ||
+      draftJsonPath.trim() !== (selectedPrompt.output_json_path ?? ""));
 
   return (
     <SettingContainer
@@ -625,6 +886,16 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
               </p>
             </div>
 
+            <PromptOverrideFields
+              draft={draftOverrides}
+              onChange={setDraftOverrides}
+            />
+
+            <PromptJsonPathField
+              value={draftJsonPath}
+              onChange={setDraftJsonPath}
+            />
+
             <div className="flex gap-2 pt-2">
               <Button
                 onClick={handleUpdatePrompt}
@@ -692,6 +963,16 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
               </p>
             </div>
 
+            <PromptOverrideFields
+              draft={draftOverrides}
+              onChange={setDraftOverrides}
+            />
+
+            <PromptJsonPathField
+              value={draftJsonPath}
+              onChange={setDraftJsonPath}
+            />
+
             <div className="flex gap-2 pt-2">
               <Button
                 onClick={handleCreatePrompt}
@@ -803,6 +1084,8 @@ export const PostProcessingSettings: React.FC = () => {
       <SettingsGroup title={t("settings.postProcessing.prompts.title")}>
         <PostProcessingToggle descriptionMode="inline" grouped={true} />
         <PostProcessingSettingsPrompts />
+        <ClipboardVariableLimit descriptionMode="tooltip" grouped={true} />
+        <PostProcessChunkThreshold descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.postProcessing.api.title")}>
@@ -0,0 +1,247 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { type } from "@tauri-apps/plugin-os";
+import { sessionToast as toast } from "@/lib/sessionToast";
+import { useSettings } from "../../../hooks/useSettings";
+import { ApiKeyEditor, StoredApiKeyDisplay } from "../ApiKeyControls";
+import { Input } from "../../ui/Input";
+import { Select, type SelectOption } from "../../ui/Select";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { ToggleSwitch } from "../../ui/ToggleSwitch";
+
+const OPENAI_REALTIME_WHISPER_MODEL = "gpt-realtime-whisper";
+
+const DELAY_OPTIONS: SelectOption[] = [
+  { value: "minimal", label: "minimal - fastest, ~1.5s chunks" },
+  { value: "low", label: "low - quick, ~3s chunks" },
+  { value: "medium", label: "medium - balanced, ~5s chunks" },
+  { value: "high", label: "high - more context, ~7s chunks" },
+  { value: "xhigh", label: "xhigh - most context, ~10s chunks" },
+];
+
+interface OpenAiRealtimeSettingsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const OpenAiRealtimeSettings: React.FC<OpenAiRealtimeSettingsProps> = ({
+  descriptionMode = "tooltip",
+  grouped = true,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating, refreshSettings } =
+    useSettings();
+  const isWindows = type() === "windows";
+
+  const baseUrl: string = getSetting("openai_realtime_base_url" as any) ?? "";
+  const model: string = getSetting("openai_realtime_model" as any) ?? "";
+  const delay: string =
+    getSetting("openai_realtime_whisper_delay" as any) ?? "low";
+  const flattenEnabled: boolean =
+    getSetting("openai_realtime_whisper_flatten_enabled" as any) ?? false;
+  // Model IDs are matched case-insensitively, as in the backend.
+  const isWhisperModel =
+    model.trim().toLowerCase() === OPENAI_REALTIME_WHISPER_MODEL;
+
+  const [baseUrlInput, setBaseUrlInput] = useState(baseUrl);
+  const [modelInput, setModelInput] = useState(model);
+  const [hasApiKey, setHasApiKey] = useState(false);
+  const [isEditingKey, setIsEditingKey] = useState(false);
+  const [apiKeyInput, setApiKeyInput] = useState("");
+  const [apiKeyLoading, setApiKeyLoading] = useState(false);
+
+  useEffect(() => {
+    setBaseUrlInput(baseUrl);
+  }, [baseUrl]);
+
+  useEffect(() => {
+    setModelInput(model);
+  }, [model]);
+
+  useEffect(() => {
+    if (!isWindows) return;
+    invoke<boolean>("openai_realtime_has_api_key")
+      .then((stored) => setHasApiKey(Boolean(stored)))
+      .catch((error) =>
+        console.error("Failed to check API key status:", error),
+      );
+  }, [isWindows]);
+
+  const run = async (command: string, args: Record<string, unknown>) => {
+    try {
+      await invoke(command, args);
+      await refreshSettings();
+      return true;
+    } catch (e) {
+      toast.error(String(e));
+      return false;
+    }
+  };
+
+  // The backend validates the URL once, here; a rejected value is reverted.
+  const commitBaseUrl = async () => {
+    if (baseUrlInput.trim() === baseUrl) return;
+    if (
+      !(await run("change_openai_realtime_base_url_setting", {
+        baseUrl: baseUrlInput,
+      }))
+    ) {
+      setBaseUrlInput(baseUrl);
+    }
+  };
+
+  const commitModel = async () => {
+    if (modelInput.trim() === model) return;
+    await run("change_openai_realtime_model_setting", { model: modelInput });
+  };
+
+  const handleSaveApiKey = async () => {
+    if (!apiKeyInput.trim()) return;
+    setApiKeyLoading(true);
+    try {
+      await invoke("openai_realtime_set_api_key", {
+        apiKey: apiKeyInput.trim(),
+      });
+      setApiKeyInput("");
+      setHasApiKey(true);
+      setIsEditingKey(false);
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setApiKeyLoading(false);
+    }
+  };
+
+  const handleClearApiKey = async () => {
+    setApiKeyLoading(true);
+    try {
+      await invoke("openai_realtime_clear_api_key");
+      setHasApiKey(false);
+      setApiKeyInput("");
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setApiKeyLoading(false);
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-3">
+      {isWindows && (
+        <SettingContainer
+          title={t("settings.advanced.openaiRealtime.apiKey.title")}
+          description={t("settings.advanced.openaiRealtime.apiKey.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="stacked"
+        >
+          <div className="flex flex-col gap-3 rounded-lg border border-mid-gray/30 bg-mid-gray/5 p-3">
+            {hasApiKey && !isEditingKey ? (
+              <StoredApiKeyDisplay
+                loading={apiKeyLoading}
+                onDelete={handleClearApiKey}
+                onReplace={() => setIsEditingKey(true)}
+              />
+            ) : (
+              <ApiKeyEditor
+                loading={apiKeyLoading}
+                value={apiKeyInput}
+                onChange={setApiKeyInput}
+                onSave={handleSaveApiKey}
+                onCancel={() => {
+                  setApiKeyInput("");
+                  setIsEditingKey(false);
+                }}
+                placeholder={t(
+                  "settings.advanced.openaiRealtime.apiKey.placeholder",
+                )}
+                showCancel={hasApiKey}
+                hint={
+                  hasApiKey
+                    ? t("settings.advanced.remoteStt.apiKey.replaceHint")
+                    : t("settings.advanced.remoteStt.apiKey.statusMissing")
+                }
+              />
+            )}
+          </div>
+        </SettingContainer>
+      )}
+
+      <SettingContainer
+        title={t("settings.advanced.openaiRealtime.baseUrl.title")}
+        description={t("settings.advanced.openaiRealtime.baseUrl.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="stacked"
+      >
+        <Input
+          type="url"
+          value={baseUrlInput}
+          onChange={(event) => setBaseUrlInput(event.target.value)}
+          onBlur={() => void commitBaseUrl()}
+          placeholder="https://api.openai.com/v1"
+          className="w-full"
+        />
+      </SettingContainer>
+
+      <SettingContainer
+        title={t("settings.advanced.openaiRealtime.model.title")}
+        description={t("settings.advanced.openaiRealtime.model.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="stacked"
+      >
+        <Input
+          type="text"
+          value={modelInput}
+          onChange={(event) => setModelInput(event.target.value)}
+          onBlur={() => void commitModel()}
+          placeholder={OPENAI_REALTIME_WHISPER_MODEL}
+          className="w-full"
+        />
+      </SettingContainer>
+
+      {isWhisperModel && (
+        <SettingContainer
+          title="Realtime Whisper Delay"
+          description="Controls how much audio context gpt-realtime-whisper gets before AivoRelay asks for a transcript chunk. Faster settings show text sooner; slower settings cut speech less often."
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="stacked"
+        >
+          <Select
+            value={delay}
+            options={DELAY_OPTIONS}
+            onChange={(value) =>
+              value &&
+              void updateSetting(
+                "openai_realtime_whisper_delay" as any,
+                value as any,
+              )
+            }
+            isClearable={false}
+            className="w-full"
+          />
+        </SettingContainer>
+      )}
+
+      <ToggleSwitch
+        checked={flattenEnabled}
+        onChange={(enabled) =>
+          void updateSetting(
+            "openai_realtime_whisper_flatten_enabled" as any,
+            enabled as any,
+          )
+        }
+        isUpdating={isUpdating(
+          "openai_realtime_whisper_flatten_enabled" as any,
+        )}
+        label={t("settings.advanced.openaiRealtime.flatten.label")}
+        description={t("settings.advanced.openaiRealtime.flatten.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    </div>
+  );
+};
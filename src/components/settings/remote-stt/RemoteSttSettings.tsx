@@ -18,6 +18,7 @@ import {
 import { LANGUAGES } from "../../../lib/constants/languages";
 import { parseAndNormalizeSonioxLanguageHints } from "../../../lib/constants/sonioxLanguages";
 import { ApiKeyEditor, StoredApiKeyDisplay } from "../ApiKeyControls";
+import { EndpointAuthOptions } from "../EndpointAuthOptions";
 import { Button } from "../../ui/Button";
 import { Input } from "../../ui/Input";
 import { Select, type SelectOption } from "../../ui/Select";
@@ -161,6 +162,12 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
   );
   const sonioxModel = (settings as any)?.soniox_model ?? SONIOX_DEFAULT_REALTIME_MODEL;
   const sonioxTimeout = Number((settings as any)?.soniox_timeout_seconds ?? 30);
+  const remoteRetryAttempts = Number(
+    (settings as any)?.remote_stt_retry_attempts ?? 3,
+  );
+  const remoteRetryBackoffMs = Number(
+    (settings as any)?.remote_stt_retry_backoff_ms ?? 500,
+  );
   const sonioxLiveEnabled = Boolean(
     (settings as any)?.soniox_live_enabled ?? true,
   );
@@ -190,9 +197,15 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
   const sonioxEnableSpeakerDiarization = Boolean(
     (settings as any)?.soniox_enable_speaker_diarization ?? true,
   );
+  const sonioxIncludeSpeakerLabels = Boolean(
+    (settings as any)?.soniox_include_speaker_labels ?? false,
+  );
   const sonioxKeepaliveSeconds = Number(
     (settings as any)?.soniox_keepalive_interval_seconds ?? 10,
   );
+  const sonioxReconnectAttempts = Number(
+    (settings as any)?.soniox_live_reconnect_attempts ?? 3,
+  );
   const sonioxLiveFinalizeTimeoutMs = Number(
     (settings as any)?.soniox_live_finalize_timeout_ms ?? 500,
   );
@@ -233,6 +246,9 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
   const openAiRealtimeWhisperFlattenEnabled = Boolean(
     (settings as any)?.openai_realtime_whisper_flatten_enabled ?? false,
   );
+  const transcriptionRaceMode = Boolean(
+    (settings as any)?.transcription_race_mode ?? false,
+  );
   const isRemoteOpenAiProvider = provider === "remote_openai_compatible";
   const isSonioxProvider = provider === "remote_soniox";
   const isDeepgramProvider = provider === "remote_deepgram";
@@ -257,14 +273,18 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
       : (REMOTE_STT_PRESETS[remotePreset]?.baseUrl ??
           remoteSettings?.base_url ??
           "");
+  // Model IDs are matched case-insensitively, as in the backend.
+  const normalizedRemoteModelId = (remoteSettings?.model_id ?? "")
+    .trim()
+    .toLowerCase();
   const currentRemoteInterface: RemoteSttInterfaceId =
     remotePreset === "groq"
       ? "groq"
       : remotePreset === "custom"
         ? "custom"
-        : (remoteSettings?.model_id ?? "") === "gpt-realtime-whisper"
+        : normalizedRemoteModelId === "gpt-realtime-whisper"
           ? "openai_realtime_whisper"
-        : (remoteSettings?.model_id ?? "") === "gpt-realtime-translate"
+        : normalizedRemoteModelId === "gpt-realtime-translate"
           ? "openai_realtime_translate"
           : "openai_realtime_agent";
   const remoteApiKeyTitle =
@@ -385,6 +405,12 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
   const [sonioxTimeoutInput, setSonioxTimeoutInput] = useState(
     String(sonioxTimeout),
   );
+  const [remoteRetryAttemptsInput, setRemoteRetryAttemptsInput] = useState(
+    String(remoteRetryAttempts),
+  );
+  const [remoteRetryBackoffMsInput, setRemoteRetryBackoffMsInput] = useState(
+    String(remoteRetryBackoffMs),
+  );
   const [sonioxLanguageHintsInput, setSonioxLanguageHintsInput] = useState(
     sonioxLanguageHints.join(", "),
   );
@@ -394,6 +420,8 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     useState(String(sonioxEndpointSensitivity));
   const [sonioxKeepaliveSecondsInput, setSonioxKeepaliveSecondsInput] =
     useState(String(sonioxKeepaliveSeconds));
+  const [sonioxReconnectAttemptsInput, setSonioxReconnectAttemptsInput] =
+    useState(String(sonioxReconnectAttempts));
   const [sonioxLiveFinalizeTimeoutInput, setSonioxLiveFinalizeTimeoutInput] =
     useState(String(sonioxLiveFinalizeTimeoutMs));
   const [deepgramModelInput, setDeepgramModelInput] = useState(deepgramModel);
@@ -460,6 +488,14 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     setSonioxTimeoutInput(String(sonioxTimeout));
   }, [sonioxTimeout]);
 
+  useEffect(() => {
+    setRemoteRetryAttemptsInput(String(remoteRetryAttempts));
+  }, [remoteRetryAttempts]);
+
+  useEffect(() => {
+    setRemoteRetryBackoffMsInput(String(remoteRetryBackoffMs));
+  }, [remoteRetryBackoffMs]);
+
   useEffect(() => {
     setSonioxLanguageHintsInput(sonioxLanguageHints.join(", "));
   }, [sonioxLanguageHints]);
@@ -476,6 +512,10 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     setSonioxKeepaliveSecondsInput(String(sonioxKeepaliveSeconds));
   }, [sonioxKeepaliveSeconds]);
 
+  useEffect(() => {
+    setSonioxReconnectAttemptsInput(String(sonioxReconnectAttempts));
+  }, [sonioxReconnectAttempts]);
+
   useEffect(() => {
     setSonioxLiveFinalizeTimeoutInput(String(sonioxLiveFinalizeTimeoutMs));
   }, [sonioxLiveFinalizeTimeoutMs]);
@@ -802,6 +842,18 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     }
   };
 
+  const handleRemoteAuthStyleChange = async (authStyle: string) => {
+    await invoke("change_remote_stt_auth_style_setting", { authStyle });
+    await refreshSettings();
+  };
+
+  const handleRemoteQueryParamsChange = async (
+    params: Record<string, string>,
+  ) => {
+    await invoke("change_remote_stt_extra_query_params_setting", { params });
+    await refreshSettings();
+  };
+
   const handleBaseUrlBlur = async () => {
     const trimmed = baseUrlInput.trim();
     if (trimmed !== (remoteSettings?.base_url ?? "")) {
@@ -986,6 +1038,28 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     }
   };
 
+  const handleRemoteRetryAttemptsBlur = () => {
+    const parsed = Number.parseInt(remoteRetryAttemptsInput, 10);
+    if (Number.isNaN(parsed) || parsed < 1 || parsed > 10) {
+      setRemoteRetryAttemptsInput(String(remoteRetryAttempts));
+      return;
+    }
+    if (parsed !== remoteRetryAttempts) {
+      void updateSetting("remote_stt_retry_attempts" as any, parsed as any);
+    }
+  };
+
+  const handleRemoteRetryBackoffBlur = () => {
+    const parsed = Number.parseInt(remoteRetryBackoffMsInput, 10);
+    if (Number.isNaN(parsed) || parsed < 0) {
+      setRemoteRetryBackoffMsInput(String(remoteRetryBackoffMs));
+      return;
+    }
+    if (parsed !== remoteRetryBackoffMs) {
+      void updateSetting("remote_stt_retry_backoff_ms" as any, parsed as any);
+    }
+  };
+
   const handleSonioxLanguageHintsBlur = () => {
     const parsed = parseAndNormalizeSonioxLanguageHints(sonioxLanguageHintsInput);
     const current = parseAndNormalizeSonioxLanguageHints(
@@ -1055,6 +1129,17 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
     }
   };
 
+  const handleSonioxReconnectAttemptsBlur = () => {
+    const parsed = Number.parseInt(sonioxReconnectAttemptsInput, 10);
+    if (Number.isNaN(parsed)) {
+      setSonioxReconnectAttemptsInput(String(sonioxReconnectAttempts));
+      return;
+    }
+    if (parsed !== sonioxReconnectAttempts) {
+      void updateSetting("soniox_live_reconnect_attempts" as any, parsed as any);
+    }
+  };
+
   const handleSonioxLiveFinalizeTimeoutBlur = () => {
     const parsed = Number.parseInt(sonioxLiveFinalizeTimeoutInput, 10);
     if (Number.isNaN(parsed)) {
@@ -1292,6 +1377,18 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
 
       {showRemoteFields && (
         <>
+          <ToggleSwitch
+            label={t("settings.advanced.transcriptionRaceMode.title")}
+            description={t("settings.advanced.transcriptionRaceMode.description")}
+            checked={transcriptionRaceMode}
+            onChange={(enabled) =>
+              void updateSetting("transcription_race_mode" as any, enabled as any)
+            }
+            isUpdating={isUpdating("transcription_race_mode")}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+
           {showOpenAiFields && (
             <>
               {!hideRemoteInterfaceSelector && (
@@ -1661,6 +1758,17 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
                       {t("settings.advanced.remoteStt.customHttpOverride.warning")}
                     </div>
                   ) : null}
+
+                  <EndpointAuthOptions
+                    authStyle={(remoteSettings as any)?.auth_style ?? "bearer"}
+                    extraQueryParams={
+                      (remoteSettings as any)?.extra_query_params ?? {}
+                    }
+                    onAuthStyleChange={handleRemoteAuthStyleChange}
+                    onExtraQueryParamsChange={handleRemoteQueryParamsChange}
+                    descriptionMode={descriptionMode}
+                    grouped={grouped}
+                  />
                 </>
               ) : null}
 
@@ -1704,6 +1812,55 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
                   />
                 </SettingContainer>
               )}
+
+              <SettingContainer
+                title={t("settings.advanced.remoteStt.retryAttempts.title")}
+                description={t(
+                  "settings.advanced.remoteStt.retryAttempts.description",
+                )}
+                descriptionMode={descriptionMode}
+                grouped={grouped}
+                layout="stacked"
+              >
+                <PersistentHintInput
+                  type="number"
+                  value={remoteRetryAttemptsInput}
+                  onChange={(event) =>
+                    setRemoteRetryAttemptsInput(event.target.value)
+                  }
+                  onBlur={handleRemoteRetryAttemptsBlur}
+                  min={1}
+                  max={10}
+                  hint="3"
+                  hintClassName="right-8"
+                  inputPaddingClassName="pr-20"
+                />
+              </SettingContainer>
+
+              <SettingContainer
+                title={t("settings.advanced.remoteStt.retryBackoff.title")}
+                description={t(
+                  "settings.advanced.remoteStt.retryBackoff.description",
+                )}
+                descriptionMode={descriptionMode}
+                grouped={grouped}
+                layout="stacked"
+              >
+                <PersistentHintInput
+                  type="number"
+                  value={remoteRetryBackoffMsInput}
+                  onChange={(event) =>
+                    setRemoteRetryBackoffMsInput(event.target.value)
+                  }
+                  onBlur={handleRemoteRetryBackoffBlur}
+                  min={0}
+                  max={30000}
+                  step={100}
+                  hint="500 ms"
+                  hintClassName="right-8"
+                  inputPaddingClassName="pr-24"
+                />
+              </SettingContainer>
             </>
           )}
 
@@ -2211,6 +2368,32 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
                 />
               </SettingContainer>
 
+              <SettingContainer
+                title={t("settings.advanced.soniox.reconnectAttempts.title")}
+                description={t(
+                  "settings.advanced.soniox.reconnectAttempts.description",
+                )}
+                descriptionMode={descriptionMode}
+                grouped={grouped}
+                layout="stacked"
+                disabled={!sonioxRealtimeControlsEnabled}
+              >
+                <PersistentHintInput
+                  type="number"
+                  value={sonioxReconnectAttemptsInput}
+                  onChange={(event) =>
+                    setSonioxReconnectAttemptsInput(event.target.value)
+                  }
+                  onBlur={handleSonioxReconnectAttemptsBlur}
+                  min={0}
+                  max={10}
+                  disabled={!sonioxRealtimeControlsEnabled}
+                  hint="3"
+                  hintClassName="right-8"
+                  inputPaddingClassName="pr-20"
+                />
+              </SettingContainer>
+
               <SettingContainer
                 title={t("settings.advanced.soniox.finalizeTimeout.title")}
                 description={t("settings.advanced.soniox.finalizeTimeout.description")}
@@ -2281,6 +2464,25 @@ export const RemoteSttSettings: React.FC<RemoteSttSettingsProps> = ({
                 descriptionMode={descriptionMode}
                 grouped={grouped}
               />
+
+              {sonioxEnableSpeakerDiarization && (
+                <ToggleSwitch
+                  label={t("settings.advanced.soniox.speakerLabels.title")}
+                  description={t(
+                    "settings.advanced.soniox.speakerLabels.description",
+                  )}
+                  checked={sonioxIncludeSpeakerLabels}
+                  onChange={(enabled) =>
+                    void updateSetting(
+                      "soniox_include_speaker_labels" as any,
+                      enabled as any,
+                    )
+                  }
+                  isUpdating={isUpdating("soniox_include_speaker_labels" as any)}
+                  descriptionMode={descriptionMode}
+                  grouped={grouped}
+                />
+              )}
             </>
           )}
 
@@ -2,6 +2,7 @@ import React, { useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { Plus, Trash2, ArrowRight, HelpCircle, ChevronDown, ChevronUp, CaseSensitive, Regex, Check, X, AlertTriangle } from "lucide-react";
 import { type as getOsType } from "@tauri-apps/plugin-os";
+import { invoke } from "@tauri-apps/api/core";
 import { useSettings } from "@/hooks/useSettings";
 import { useNavigationStore } from "@/stores/navigationStore";
 import { SettingsGroup } from "@/components/ui/SettingsGroup";
@@ -25,6 +26,21 @@ interface TextReplacementRule {
 
 type OutputWhitespaceMode = "preserve" | "remove_if_present" | "add_if_missing";
 
+type WhitespacePolicyPreview = {
+  output: string;
+  pasted: string;
+  steps: { rule: string; before: string; after: string }[];
+  stream_adjustment: { delete_chars: number; append: string };
+};
+
+// Matches STT_PROMPT_CUSTOM_WORDS_MAX_CHARS_RANGE in the backend settings.
+const MIN_CUSTOM_WORDS_PROMPT_CHARS = 100;
+const MAX_CUSTOM_WORDS_PROMPT_CHARS = 2000;
+
+// Makes boundary whitespace visible in the preview.
+const showWhitespace = (text: string) =>
+  text.replace(/\r/g, "␍").replace(/\n/g, "↵").replace(/ /g, "·");
+
 const MODIFIER_SHORTCUT_TOKENS = new Set([
   "ctrl",
   "control",
@@ -94,6 +110,30 @@ export const TextReplacementSettings: React.FC = () => {
   const trailingWhitespaceMode =
     (settings?.output_whitespace_trailing_mode ?? "remove_if_present") as OutputWhitespaceMode;
 
+  const [whitespacePreviewSample, setWhitespacePreviewSample] = useState(" Hello world\\n");
+  const [whitespacePreview, setWhitespacePreview] = useState<WhitespacePolicyPreview | null>(
+    null
+  );
+
+  useEffect(() => {
+    const sample = whitespacePreviewSample.replace(/\\n/g, "\n");
+    let cancelled = false;
+    invoke<WhitespacePolicyPreview>("preview_whitespace_policy", { sampleText: sample })
+      .then((preview) => {
+        if (!cancelled) setWhitespacePreview(preview);
+      })
+      .catch((error) => console.error("Failed to preview whitespace policy:", error));
+    return () => {
+      cancelled = true;
+    };
+  }, [
+    whitespacePreviewSample,
+    leadingWhitespaceMode,
+    trailingWhitespaceMode,
+    settings?.convert_lf_to_crlf,
+    settings?.paste_method,
+  ]);
+
   const setLeadingWhitespaceMode = (mode: OutputWhitespaceMode) =>
     (updateSetting as any)("output_whitespace_leading_mode", mode);
   const setTrailingWhitespaceMode = (mode: OutputWhitespaceMode) =>
@@ -1265,6 +1305,53 @@ export const TextReplacementSettings: React.FC = () => {
             grouped={true}
           />
         </div>
+
+        {/* Custom words in STT prompts (${custom_words}) */}
+        <div className="px-4 py-3 border-t border-white/[0.05]">
+          <ToggleSwitch
+            checked={(settings as any)?.stt_prompt_auto_inject_custom_words ?? false}
+            onChange={(enabled) =>
+              (updateSetting as any)("stt_prompt_auto_inject_custom_words", enabled)
+            }
+            isUpdating={isUpdating("stt_prompt_auto_inject_custom_words")}
+            label={t(
+              "textReplacement.sttPromptAutoInjectLabel",
+              "Add Custom Words to Transcription Prompts"
+            )}
+            description={t(
+              "textReplacement.sttPromptAutoInjectDescription",
+              "Append the custom word list to every transcription prompt, even without a ${custom_words} placeholder. Soniox receives the words as context terms."
+            )}
+            descriptionMode="inline"
+            grouped={true}
+          />
+        </div>
+
+        <div className="px-4 py-3 border-t border-white/[0.05]">
+          <Slider
+            value={(settings as any)?.stt_prompt_custom_words_max_chars ?? 600}
+            onChange={(value) =>
+              (updateSetting as any)(
+                "stt_prompt_custom_words_max_chars",
+                Math.round(value)
+              )
+            }
+            min={MIN_CUSTOM_WORDS_PROMPT_CHARS}
+            max={MAX_CUSTOM_WORDS_PROMPT_CHARS}
+            step={50}
+            label={t(
+              "textReplacement.sttPromptCustomWordsBudgetLabel",
+              "Custom Words Prompt Budget"
+            )}
+            description={t(
+              "textReplacement.sttPromptCustomWordsBudgetDescription",
+              "Maximum characters of custom words inserted into transcription prompts. Words past the budget are left out."
+            )}
+            descriptionMode="inline"
+            grouped={true}
+            formatValue={(value) => `${Math.round(value)} chars`}
+          />
+        </div>
         
         {/* Word Correction Threshold */}
         <div className="px-4 py-3 border-t border-white/[0.05]">
@@ -1363,6 +1450,90 @@ export const TextReplacementSettings: React.FC = () => {
             descriptionMode="inline"
           />
         </div>
+        <div className="px-4 py-3 border-t border-white/[0.05] space-y-2">
+          <div className="text-sm font-medium">
+            {t("textReplacement.outputWhitespacePreviewLabel", "Preview")}
+          </div>
+          <Input
+            value={whitespacePreviewSample}
+            onChange={(e) => setWhitespacePreviewSample(e.target.value)}
+            placeholder={t(
+              "textReplacement.outputWhitespacePreviewPlaceholder",
+              "Sample text (use \\n for a newline)"
+            )}
+          />
+          {whitespacePreview && (
+            <div className="text-xs font-mono space-y-1 text-mid-gray">
+              <div>
+                {t("textReplacement.outputWhitespacePreviewPasted", "Pasted")}:{" "}
+                <span className="text-text">{showWhitespace(whitespacePreview.pasted)}</span>
+              </div>
+              {whitespacePreview.steps.length === 0 ? (
+                <div>
+                  {t("textReplacement.outputWhitespacePreviewUnchanged", "No rule changes this sample.")}
+                </div>
+              ) : (
+                whitespacePreview.steps.map((step, index) => (
+                  <div key={index}>
+                    {t(`textReplacement.outputWhitespaceRule.${step.rule}`, step.rule)}:{" "}
+                    {showWhitespace(step.before)} → {showWhitespace(step.after)}
+                  </div>
+                ))
+              )}
+              {(whitespacePreview.stream_adjustment.delete_chars > 0 ||
+                whitespacePreview.stream_adjustment.append.length > 0) && (
+                <div>
+                  {t("textReplacement.outputWhitespacePreviewStream", {
+                    defaultValue:
+                      "Streaming: delete {{count}} trailing character(s), then type \"{{append}}\"",
+                    count: whitespacePreview.stream_adjustment.delete_chars,
+                    append: showWhitespace(whitespacePreview.stream_adjustment.append),
+                  })}
+                </div>
+              )}
+            </div>
+          )}
+        </div>
+        <div className="px-4 py-3 border-t border-white/[0.05]">
+          <ToggleSwitch
+            checked={(settings as any)?.smart_join_enabled ?? false}
+            onChange={(enabled) => (updateSetting as any)("smart_join_enabled", enabled)}
+            isUpdating={isUpdating("smart_join_enabled" as any)}
+            label={t("textReplacement.smartJoinLabel", "Continuation mode")}
+            description={t(
+              "textReplacement.smartJoinDescription",
+              "When you dictate again into the same app shortly after a paste, add a separating space and capitalize after a finished sentence. Not applied to streaming insertion."
+            )}
+            descriptionMode="inline"
+          />
+          {((settings as any)?.smart_join_enabled ?? false) && (
+            <div className="flex items-center gap-2 mt-2">
+              <span className="text-sm">
+                {t("textReplacement.smartJoinExpiryLabel", "Remember last paste for")}
+              </span>
+              <Input
+                type="number"
+                min={1}
+                max={3600}
+                value={(settings as any)?.smart_join_expiry_secs ?? 120}
+                onChange={(e) => {
+                  const value = parseInt(e.target.value, 10);
+                  if (!isNaN(value)) {
+                    (updateSetting as any)(
+                      "smart_join_expiry_secs",
+                      Math.min(3600, Math.max(1, value))
+                    );
+                  }
+                }}
+                disabled={isUpdating("smart_join_expiry_secs" as any)}
+                className="w-24 text-right"
+              />
+              <span className="text-sm text-text/70">
+                {t("textReplacement.smartJoinExpirySeconds", "seconds")}
+              </span>
+            </div>
+          )}
+        </div>
       </SettingsGroup>
 
       <SettingsGroup
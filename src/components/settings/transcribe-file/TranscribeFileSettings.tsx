@@ -17,6 +17,7 @@ import {
 } from "lucide-react";
 import { open } from "@tauri-apps/plugin-dialog";
 import { convertFileSrc, invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { stat } from "@tauri-apps/plugin-fs";
 import {
@@ -40,10 +41,29 @@ import {
 } from "@/stores/transcribeFileStore";
 import { parseAndNormalizeSonioxLanguageHints } from "@/lib/constants/sonioxLanguages";
 
-const supportedExtensions = ["wav", "mp3", "m4a", "ogg", "flac", "webm"];
+const supportedExtensions = [
+  "wav",
+  "mp3",
+  "m4a",
+  "ogg",
+  "flac",
+  "webm",
+  "mp4",
+  "mkv",
+  "mov",
+  "m4v",
+];
 const DEEPGRAM_MAX_FILE_DURATION_SECONDS = 10 * 60;
 const SONIOX_MAX_FILE_DURATION_SECONDS = 300 * 60;
 
+type FileTranscriptionProgressEvent = {
+  segmentIndex: number;
+  segmentCount: number;
+  startSecs: number;
+  endSecs: number;
+  status: "started" | "completed" | "failed";
+};
+
 type SpeakerNameSetProfile = {
   id: string;
   name: string;
@@ -313,6 +333,10 @@ export const TranscribeFileSettings: React.FC = () => {
   const [isRecording, setIsRecording] = useState(false);
   const [copied, setCopied] = useState(false);
   const [isDragOver, setIsDragOver] = useState(false);
+  const [segmentProgress, setSegmentProgress] = useState<{
+    current: number;
+    total: number;
+  } | null>(null);
   const [availableModels, setAvailableModels] = useState<ModelInfo[]>([]);
   const [infoMessage, setInfoMessage] = useState<string | null>(null);
   const [sonioxLanguageHintsInput, setSonioxLanguageHintsInput] = useState("");
@@ -371,6 +395,12 @@ export const TranscribeFileSettings: React.FC = () => {
   const fileChunkingMaxMinutes = Number.isFinite(fileChunkingMaxMinutesRaw)
     ? Math.min(10, Math.max(0.25, fileChunkingMaxMinutesRaw))
     : 0.5;
+  const fileSegmentMinutesRaw = Number(
+    (settings as any)?.file_transcription_segment_minutes ?? 10,
+  );
+  const fileSegmentMinutes = Number.isFinite(fileSegmentMinutesRaw)
+    ? Math.min(120, Math.max(1, fileSegmentMinutesRaw))
+    : 10;
   const showSonioxFileOptions =
     !!selectedFile && isSonioxProvider && !overrideModelId;
   const showDeepgramFileOptions =
@@ -459,12 +489,42 @@ export const TranscribeFileSettings: React.FC = () => {
     [updateSetting],
   );
 
+  const updateFileSegmentMinutes = useCallback(
+    async (minutes: number) => {
+      if (!Number.isFinite(minutes)) {
+        return;
+      }
+      await updateSetting(
+        "file_transcription_segment_minutes" as any,
+        Math.min(120, Math.max(1, minutes)) as any,
+      );
+    },
+    [updateSetting],
+  );
+
   useEffect(() => {
     return () => {
       void cleanupPreparedPreviewAsset(selectedFileRef.current);
     };
   }, []);
 
+  // Long files are transcribed in segments; show which one is running
+  useEffect(() => {
+    const unlistenProgress = listen<FileTranscriptionProgressEvent>(
+      "file-transcription-progress",
+      (event) => {
+        const { segmentIndex, segmentCount, status } = event.payload;
+        if (status === "started") {
+          setSegmentProgress({ current: segmentIndex, total: segmentCount });
+        }
+      },
+    );
+
+    return () => {
+      unlistenProgress.then((fn) => fn());
+    };
+  }, []);
+
   useEffect(() => {
     setSonioxLanguageHintsInput(globalSonioxLanguageHints.join(", "));
     setSonioxEnableSpeakerDiarization(globalSonioxEnableSpeakerDiarization);
@@ -485,6 +545,7 @@ export const TranscribeFileSettings: React.FC = () => {
   useEffect(() => {
     if (!isTranscribing) {
       setIsCancellingTranscription(false);
+      setSegmentProgress(null);
     }
   }, [isTranscribing]);
 
@@ -671,14 +732,19 @@ export const TranscribeFileSettings: React.FC = () => {
   const canUpdateSpeakerNameProfile =
     !!selectedSpeakerNameProfile && canPersistSpeakerNameProfile;
   const selectedFileDurationSeconds = selectedFile?.durationSeconds ?? null;
+  // Provider limits apply per request, and long files are sent in segments.
+  const selectedFileRequestSeconds =
+    selectedFileDurationSeconds != null
+      ? Math.min(selectedFileDurationSeconds, fileSegmentMinutes * 60)
+      : null;
   const selectedFileExceedsDeepgramLimit =
     showDeepgramFileOptions &&
-    selectedFileDurationSeconds != null &&
-    selectedFileDurationSeconds > DEEPGRAM_MAX_FILE_DURATION_SECONDS;
+    selectedFileRequestSeconds != null &&
+    selectedFileRequestSeconds > DEEPGRAM_MAX_FILE_DURATION_SECONDS;
   const selectedFileExceedsSonioxLimit =
     showSonioxFileOptions &&
-    selectedFileDurationSeconds != null &&
-    selectedFileDurationSeconds > SONIOX_MAX_FILE_DURATION_SECONDS;
+    selectedFileRequestSeconds != null &&
+    selectedFileRequestSeconds > SONIOX_MAX_FILE_DURATION_SECONDS;
   const selectedFileHasUnknownRemoteDuration =
     (showDeepgramFileOptions || showSonioxFileOptions) &&
     selectedFileDurationSeconds == null;
@@ -1430,6 +1496,31 @@ export const TranscribeFileSettings: React.FC = () => {
               </div>
             )}
 
+            {selectedFile && (
+              <div className="mt-4 space-y-2 rounded-lg border border-[#333333] bg-[#151515] p-3">
+                <label className="text-sm text-[#f5f5f5]">
+                  {t("transcribeFile.segmenting.minutesLabel")}
+                </label>
+                <input
+                  type="number"
+                  min={1}
+                  max={120}
+                  step={1}
+                  value={fileSegmentMinutes}
+                  onChange={(event) => {
+                    const nextValue = Number(event.target.value);
+                    if (Number.isFinite(nextValue)) {
+                      void updateFileSegmentMinutes(nextValue);
+                    }
+                  }}
+                  className="w-full rounded border border-[#333333] bg-[#0f0f0f] px-3 py-2 text-sm text-[#f5f5f5] focus:border-[#9b5de5] focus:outline-none"
+                />
+                <p className="text-xs text-[#606060]">
+                  {t("transcribeFile.segmenting.hint")}
+                </p>
+              </div>
+            )}
+
             {showSonioxFileOptions && (
               <div className="mt-4 space-y-3 rounded-lg border border-[#333333] bg-[#151515] p-3">
                 <p className="text-sm text-[#f5f5f5]">
@@ -1625,7 +1716,12 @@ export const TranscribeFileSettings: React.FC = () => {
                 ) : isTranscribing ? (
                   <>
                     <Loader2 className="w-4 h-4 animate-spin" />
-                    {t("transcribeFile.transcribing")}
+                    {segmentProgress
+                      ? t("transcribeFile.transcribingSegment", {
+                          current: segmentProgress.current,
+                          total: segmentProgress.total,
+                        })
+                      : t("transcribeFile.transcribing")}
                   </>
                 ) : (
                   t("transcribeFile.transcribe")
@@ -348,6 +348,7 @@ export const RecordingOverlaySettings: React.FC = () => {
   const showCancelButton = Boolean(
     (settings as any)?.recording_overlay_show_cancel_button ?? true,
   );
+  const showTimer = Boolean((settings as any)?.overlay_show_timer ?? true);
   const rawBarCount = Number((settings as any)?.recording_overlay_bar_count ?? 9);
   const rawBarWidthPx = Number(
     (settings as any)?.recording_overlay_bar_width_px ?? 6,
@@ -1116,6 +1117,23 @@ export const RecordingOverlaySettings: React.FC = () => {
               "Classic mode stays simpler, but still keeps the same overlay moving, drag handle, and placement behavior.",
             )}
       </div>
+      <ToggleSwitch
+        checked={showTimer}
+        onChange={(enabled) =>
+          void updateSetting("overlay_show_timer" as any, enabled as any)
+        }
+        isUpdating={isUpdating("overlay_show_timer" as any)}
+        label={t(
+          "settings.userInterface.recordingOverlay.timer.label",
+          "Show Recording Timer",
+        )}
+        description={t(
+          "settings.userInterface.recordingOverlay.timer.description",
+          "Show how long you have been recording, or a countdown when a maximum recording duration is set. Takes effect from the next recording.",
+        )}
+        descriptionMode="tooltip"
+        grouped={true}
+      />
         </div>
 
         <div
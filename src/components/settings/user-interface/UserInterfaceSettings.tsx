@@ -16,6 +16,7 @@ import { useSettings } from "../../../hooks/useSettings";
 import { useModels } from "../../../hooks/useModels";
 import { ShowTrayIcon } from "../ShowTrayIcon";
 import { ShowTrayShortcutGuide } from "../ShowTrayShortcutGuide";
+import { ScreenReaderAnnouncements } from "../ScreenReaderAnnouncements";
 import { RecordingOverlaySettings } from "./RecordingOverlaySettings";
 import type { OSType } from "../../../lib/utils/keyboard";
 import {
@@ -189,6 +190,11 @@ export const UserInterfaceSettings: React.FC = () => {
     (settings as any)?.voice_button_show_aot_toggle ?? false;
   const voiceButtonSingleClickClose =
     (settings as any)?.voice_button_single_click_close ?? false;
+  const voiceButtonMonitor =
+    ((settings as any)?.voice_button_monitor ?? "cursor") as string;
+  const [voiceButtonMonitors, setVoiceButtonMonitors] = React.useState<string[]>(
+    [],
+  );
   const sonioxLivePreviewEnabled =
     (settings as any)?.soniox_live_preview_enabled ?? false;
   const sonioxLivePreviewPosition =
@@ -230,6 +236,9 @@ export const UserInterfaceSettings: React.FC = () => {
   const localPreviewAutoFlushEnabled = Boolean(
     (settings as any)?.local_preview_auto_flush_enabled ?? true,
   );
+  const localStreamingPreviewEnabled = Boolean(
+    (settings as any)?.local_streaming_preview_enabled ?? false,
+  );
   const localPreviewAutoFlushIntervalMs = Number(
     (settings as any)?.local_preview_auto_flush_interval_ms ?? 8000,
   );
@@ -297,6 +306,22 @@ export const UserInterfaceSettings: React.FC = () => {
     }
   };
 
+  React.useEffect(() => {
+    if (!isWindows) return;
+    invoke<string[]>("voice_activation_button_list_monitors")
+      .then(setVoiceButtonMonitors)
+      .catch(() => setVoiceButtonMonitors([]));
+  }, [isWindows]);
+
+  const handleResetVoiceButtonPosition = async () => {
+    try {
+      await invoke("reset_voice_button_position");
+    } catch (error) {
+      console.error("Failed to reset voice activation button position:", error);
+      toast.error(String(error));
+    }
+  };
+
   const handleSpawnVoiceButton = async () => {
     try {
       await invoke("spawn_voice_activation_button_window");
@@ -364,6 +389,7 @@ export const UserInterfaceSettings: React.FC = () => {
       <SettingsGroup title={t("settings.userInterface.title")}>
         <ShowTrayIcon descriptionMode="tooltip" grouped={true} />
         <ShowTrayShortcutGuide descriptionMode="tooltip" grouped={true} />
+        <ScreenReaderAnnouncements descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <RecordingOverlaySettings />
@@ -478,6 +504,7 @@ export const UserInterfaceSettings: React.FC = () => {
                           enabled: activeProfile.llm_post_process_enabled ?? false,
                           promptOverride: activeProfile.llm_prompt_override ?? null,
                           modelOverride: activeProfile.llm_model_override ?? null,
+                          outputJsonPath: activeProfile.llm_output_json_path ?? null,
                         },
                         sonioxContextGeneralJson: activeProfile.soniox_context_general_json || "",
                         sonioxContextText: activeProfile.soniox_context_text || "",
@@ -1436,6 +1463,27 @@ export const UserInterfaceSettings: React.FC = () => {
             description="Old chunk-based local preview mode kept for compatibility. Not recommended when native streaming or provider live preview is available."
             disabled={!sonioxLivePreviewEnabled}
           >
+          <SettingContainer
+            title="Show Local Partial Results"
+            description="Transcribe the recording in short chunks cut at pauses and show the text here while you speak. Nothing is pasted until you stop; the pasted text comes from a full pass over the whole recording."
+            descriptionMode="inline"
+            grouped={true}
+            disabled={!sonioxLivePreviewEnabled}
+          >
+            <ToggleSwitch
+              checked={localStreamingPreviewEnabled}
+              onChange={(enabled) =>
+                void updateSetting(
+                  "local_streaming_preview_enabled" as any,
+                  enabled as any,
+                )
+              }
+              disabled={
+                !sonioxLivePreviewEnabled ||
+                isUpdating("local_streaming_preview_enabled")
+              }
+            />
+          </SettingContainer>
           <SettingContainer
             title="Enable Legacy Local Preview Updates"
             description="Use the old local auto-flush path to process repeated audio chunks while recording continues. Prefer native streaming models/providers when possible."
@@ -1688,6 +1736,42 @@ export const UserInterfaceSettings: React.FC = () => {
               Spawn button
             </button>
           </SettingContainer>
+          <SettingContainer
+            title="Button Monitor"
+            description="Where the button opens until you drag it somewhere. Dragged positions are remembered for each monitor setup."
+            descriptionMode="inline"
+            grouped={true}
+          >
+            <Dropdown
+              options={[
+                { value: "cursor", label: "Monitor with Cursor" },
+                { value: "primary", label: "Primary Monitor" },
+                ...voiceButtonMonitors.map((name, index) => ({
+                  value: String(index),
+                  label: name,
+                })),
+              ]}
+              selectedValue={voiceButtonMonitor}
+              onSelect={(value) =>
+                void updateSetting("voice_button_monitor" as any, value as any)
+              }
+              disabled={isUpdating("voice_button_monitor")}
+            />
+          </SettingContainer>
+          <SettingContainer
+            title="Reset Button Position"
+            description="Forget where the button was dragged and move it back to its default spot."
+            descriptionMode="inline"
+            grouped={true}
+          >
+            <button
+              type="button"
+              onClick={handleResetVoiceButtonPosition}
+              className="px-3 py-1.5 bg-[#2b2b2b] hover:bg-[#3c3c3c] border border-[#3c3c3c] rounded-lg text-xs text-gray-200 font-medium transition-colors"
+            >
+              Reset position
+            </button>
+          </SettingContainer>
           <SettingContainer
             title="Show AOT Toggle in Button Window"
             description="Show the bottom always-on-top control inside the floating voice button window."
@@ -0,0 +1,255 @@
+import { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { invoke } from "@tauri-apps/api/core";
+import { useSettings } from "@/hooks/useSettings";
+import { sessionToast as toast } from "@/lib/sessionToast";
+
+const HOOK_KINDS = [
+  "app_start",
+  "app_exit",
+  "recording_start",
+  "recording_end",
+] as const;
+type LifecycleHookKind = (typeof HOOK_KINDS)[number];
+
+interface LifecycleHook {
+  id: string;
+  script: string;
+  enabled: boolean;
+  silent: boolean;
+  no_profile: boolean;
+  use_pwsh: boolean;
+  execution_policy: string | null;
+  working_directory: string | null;
+}
+
+type LifecycleHooks = Record<LifecycleHookKind, LifecycleHook[]> & {
+  timeout_secs: number;
+};
+
+interface LifecycleHookOutput {
+  hook_id: string;
+  exit_code: number | null;
+  stdout: string;
+  stderr: string;
+  timed_out: boolean;
+  error: string | null;
+}
+
+const EMPTY_HOOKS: LifecycleHooks = {
+  app_start: [],
+  app_exit: [],
+  recording_start: [],
+  recording_end: [],
+  timeout_secs: 30,
+};
+
+export function LifecycleHooksSettings() {
+  const { t } = useTranslation();
+  const { settings, refreshSettings } = useSettings();
+  const hooks: LifecycleHooks =
+    (settings as any)?.lifecycle_hooks ?? EMPTY_HOOKS;
+  const [testing, setTesting] = useState<LifecycleHookKind | null>(null);
+  const [results, setResults] = useState<
+    Partial<Record<LifecycleHookKind, LifecycleHookOutput[]>>
+  >({});
+
+  const save = async (next: LifecycleHooks) => {
+    try {
+      await invoke("change_lifecycle_hooks_setting", { hooks: next });
+      await refreshSettings();
+    } catch (e) {
+      toast.error(String(e));
+    }
+  };
+
+  const updateHook = (
+    kind: LifecycleHookKind,
+    index: number,
+    patch: Partial<LifecycleHook>,
+  ) =>
+    save({
+      ...hooks,
+      [kind]: hooks[kind].map((hook, i) =>
+        i === index ? { ...hook, ...patch } : hook,
+      ),
+    });
+
+  const addHook = (kind: LifecycleHookKind) =>
+    save({
+      ...hooks,
+      [kind]: [
+        ...hooks[kind],
+        {
+          id: `lh_${Date.now()}`,
+          script: "",
+          enabled: true,
+          silent: true,
+          no_profile: false,
+          use_pwsh: false,
+          execution_policy: null,
+          working_directory: null,
+        },
+      ],
+    });
+
+  const runTest = async (kind: LifecycleHookKind) => {
+    setTesting(kind);
+    try {
+      const output = await invoke<LifecycleHookOutput[]>(
+        "test_lifecycle_hook",
+        { kind },
+      );
+      setResults((prev) => ({ ...prev, [kind]: output }));
+    } catch (e) {
+      toast.error(String(e));
+    } finally {
+      setTesting(null);
+    }
+  };
+
+  const describe = (output: LifecycleHookOutput) => {
+    if (output.error) return output.error;
+    if (output.timed_out) return t("voiceCommands.lifecycleHooks.timedOut");
+    if (output.exit_code === null)
+      return t("voiceCommands.lifecycleHooks.openedInWindow");
+    return t("voiceCommands.lifecycleHooks.exitCode", {
+      code: output.exit_code,
+    });
+  };
+
+  return (
+    <div className="mock-testing-section">
+      <div className="section-divider">
+        <span>{t("voiceCommands.lifecycleHooks.title")}</span>
+      </div>
+      <p className="mock-description">
+        {t("voiceCommands.lifecycleHooks.description")}
+      </p>
+      <div className="execution-option-row">
+        <span>{t("voiceCommands.lifecycleHooks.timeout")}</span>
+        <input
+          type="number"
+          min={1}
+          className="working-directory-input"
+          defaultValue={hooks.timeout_secs}
+          onBlur={(e) => {
+            const value = parseInt(e.target.value, 10);
+            if (value >= 1 && value !== hooks.timeout_secs) {
+              save({ ...hooks, timeout_secs: value });
+            }
+          }}
+        />
+      </div>
+
+      {HOOK_KINDS.map((kind) => (
+        <div key={kind} className="voice-command-execution-section">
+          <div className="execution-option-row">
+            <span>{t(`voiceCommands.lifecycleHooks.kinds.${kind}`)}</span>
+            <div className="voice-command-controls">
+              <button
+                className="btn-mock-test"
+                onClick={() => runTest(kind)}
+                disabled={testing !== null || hooks[kind].length === 0}
+              >
+                {testing === kind
+                  ? t("voiceCommands.lifecycleHooks.testing")
+                  : t("voiceCommands.lifecycleHooks.test")}
+              </button>
+              <button className="btn-edit" onClick={() => addHook(kind)}>
+                ＋
+              </button>
+            </div>
+          </div>
+
+          {hooks[kind].map((hook, index) => (
+            <div
+              key={hook.id}
+              className={`voice-command-card ${!hook.enabled ? "disabled" : ""}`}
+            >
+              <div className="voice-command-header">
+                <input
+                  type="text"
+                  className="mono working-directory-input"
+                  defaultValue={hook.script}
+                  placeholder="Start-Process 'C:\\Tools\\router.exe'"
+                  onBlur={(e) =>
+                    e.target.value !== hook.script &&
+                    updateHook(kind, index, { script: e.target.value })
+                  }
+                />
+                <div className="voice-command-controls">
+                  <label className="toggle-switch small">
+                    <input
+                      type="checkbox"
+                      checked={hook.enabled}
+                      onChange={(e) =>
+                        updateHook(kind, index, { enabled: e.target.checked })
+                      }
+                    />
+                    <span className="slider"></span>
+                  </label>
+                  <button
+                    className="btn-delete"
+                    onClick={() =>
+                      save({
+                        ...hooks,
+                        [kind]: hooks[kind].filter((_, i) => i !== index),
+                      })
+                    }
+                  >
+                    🗑️
+                  </button>
+                </div>
+              </div>
+              <div className="execution-options-content">
+                {(["silent", "no_profile", "use_pwsh"] as const).map(
+                  (option) => (
+                    <div key={option} className="execution-option-row">
+                      <span>
+                        {t(`voiceCommands.lifecycleHooks.options.${option}`)}
+                      </span>
+                      <label className="toggle-switch small">
+                        <input
+                          type="checkbox"
+                          checked={hook[option]}
+                          onChange={(e) =>
+                            updateHook(kind, index, {
+                              [option]: e.target.checked,
+                            })
+                          }
+                        />
+                        <span className="slider"></span>
+                      </label>
+                    </div>
+                  ),
+                )}
+                <div className="execution-option-row column">
+                  <span>{t("voiceCommands.workingDirectory")}</span>
+                  <input
+                    type="text"
+                    className="working-directory-input"
+                    defaultValue={hook.working_directory ?? ""}
+                    onBlur={(e) =>
+                      updateHook(kind, index, {
+                        working_directory: e.target.value.trim() || null,
+                      })
+                    }
+                  />
+                </div>
+              </div>
+            </div>
+          ))}
+
+          {results[kind]?.map((output) => (
+            <div key={output.hook_id} className="mock-status">
+              <div>{describe(output)}</div>
+              {output.stdout && <pre className="mono">{output.stdout}</pre>}
+              {output.stderr && <pre className="mono">{output.stderr}</pre>}
+            </div>
+          ))}
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -16,6 +16,7 @@ import { ResetButton } from "../../ui/ResetButton";
 import { TellMeMore } from "../../ui/TellMeMore";
 import { ToggleSwitch } from "../../ui/ToggleSwitch";
 import { useVoiceCommandProviderState } from "./useVoiceCommandProviderState";
+import { LifecycleHooksSettings } from "./LifecycleHooksSettings";
 import { sessionToast as toast } from "@/lib/sessionToast";
 import "./VoiceCommandSettings.css";
 
@@ -299,6 +300,28 @@ function VoiceCommandCard({
   );
 }
 
+interface DryRunWordMatch {
+  trigger_word: string;
+  score: number;
+  kind: "exact" | "levenshtein" | "phonetic";
+}
+
+interface VoiceCommandDryRun {
+  selected_command_id: string | null;
+  commands: {
+    command_id: string;
+    name: string;
+    threshold: number;
+    passed: boolean;
+    selected: boolean;
+    breakdown: {
+      transcription_words: string[];
+      best_word_matches: (DryRunWordMatch | null)[];
+      score: number;
+    };
+  }[];
+}
+
 export default function VoiceCommandSettings() {
   const { t } = useTranslation();
   const { settings, updateSetting, refreshSettings, isUpdating } = useSettings();
@@ -310,6 +333,7 @@ export default function VoiceCommandSettings() {
     type: "success" | "error" | "loading";
     message: string;
   } | null>(null);
+  const [dryRun, setDryRun] = useState<VoiceCommandDryRun | null>(null);
   const [isLlmSettingsOpen, setIsLlmSettingsOpen] = useState(false);
   const [isFuzzyMatchingOpen, setIsFuzzyMatchingOpen] = useState(false);
 
@@ -432,6 +456,24 @@ export default function VoiceCommandSettings() {
     }
   };
 
+  const handleDryRun = async () => {
+    if (!mockInput.trim()) {
+      setMockStatus({ type: "error", message: "Please enter mock text" });
+      return;
+    }
+    try {
+      setMockStatus(null);
+      setDryRun(
+        await invoke<VoiceCommandDryRun>("test_voice_command_match", {
+          transcription: mockInput.trim(),
+        }),
+      );
+    } catch (err) {
+      setDryRun(null);
+      setMockStatus({ type: "error", message: String(err) });
+    }
+  };
+
   const modelDescription = voiceCommandProviderState.isAppleProvider
     ? t("settings.postProcessing.api.model.descriptionApple")
     : voiceCommandProviderState.isCustomProvider
@@ -1407,15 +1449,60 @@ export default function VoiceCommandSettings() {
               >
                 {mockStatus?.type === "loading" ? "Testing..." : "🧪 Test"}
               </button>
+              <button
+                className="btn-mock-test"
+                onClick={handleDryRun}
+                title={t(
+                  "voiceCommands.dryRunDesc",
+                  "Show match scores without running anything",
+                )}
+              >
+                {t("voiceCommands.dryRun", "Dry run")}
+              </button>
             </div>
             {mockStatus && (
               <div className={`mock-status ${mockStatus.type}`}>
                 {mockStatus.message}
               </div>
             )}
+            {dryRun && (
+              <div className="dry-run-results">
+                {dryRun.commands.length === 0 && (
+                  <p className="mock-description">
+                    {t("voiceCommands.dryRunEmpty", "No enabled commands")}
+                  </p>
+                )}
+                {dryRun.commands.map((command) => (
+                  <div
+                    key={command.command_id}
+                    className={`dry-run-row ${command.selected ? "selected" : ""}`}
+                  >
+                    <div className="dry-run-header">
+                      <span>{command.name}</span>
+                      <span className={command.passed ? "passed" : "failed"}>
+                        {(command.breakdown.score * 100).toFixed(0)}% /{" "}
+                        {(command.threshold * 100).toFixed(0)}%
+                      </span>
+                    </div>
+                    <div className="dry-run-words">
+                      {command.breakdown.transcription_words
+                        .map((word, i) => {
+                          const match = command.breakdown.best_word_matches[i];
+                          return match
+                            ? `${word} → ${match.trigger_word} (${match.kind}, ${(match.score * 100).toFixed(0)}%)`
+                            : `${word} → –`;
+                        })
+                        .join(" · ")}
+                    </div>
+                  </div>
+                ))}
+              </div>
+            )}
           </div>
         </>
       )}
+
+      <LifecycleHooksSettings />
     </div>
   );
 }
@@ -1,4 +1,5 @@
 import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { commands, type ModelInfo } from "@/bindings";
 import {
@@ -12,6 +13,7 @@ interface DownloadProgress {
   downloaded: number;
   total: number;
   percentage: number;
+  bytes_per_second: number;
 }
 
 interface ModelDownloadFailedEvent {
@@ -172,6 +174,29 @@ export const useModels = () => {
     }
   };
 
+  // Stops the transfer but keeps the partial file for a later resume.
+  const pauseDownload = async (modelId: string) => {
+    try {
+      setError(null);
+      await invoke("cancel_model_download", { modelId });
+      setDownloadingModels((prev) => {
+        const next = new Set(prev);
+        next.delete(modelId);
+        return next;
+      });
+      setDownloadProgress((prev) => {
+        const next = new Map(prev);
+        next.delete(modelId);
+        return next;
+      });
+      await loadModels();
+      return true;
+    } catch (err) {
+      setError(`Failed to pause download: ${err}`);
+      return false;
+    }
+  };
+
   const getModelInfo = (modelId: string): ModelInfo | undefined => {
     return models.find((model) => model.id === modelId);
   };
@@ -339,6 +364,7 @@ export const useModels = () => {
     selectModel,
     downloadModel,
     cancelDownload,
+    pauseDownload,
     deleteModel,
     getModelInfo,
     isModelDownloading,
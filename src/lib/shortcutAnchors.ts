@@ -5,6 +5,8 @@ const SHORTCUT_SECTION_BY_ID: Record<string, SidebarSection> = {
   cancel: "debug",
   cycle_profile: "general",
   repaste_last: "history",
+  repaste_last_n: "history",
+  undo_last_paste: "history",
   send_screenshot_to_extension: "browserConnector",
   send_to_extension: "browserConnector",
   send_to_extension_with_selection: "browserConnector",
@@ -221,8 +221,56 @@ export const formatKeyCombination = (
   osType: OSType,
 ): string => {
   if (!combination) return "";
-  const normalized = combination.replace(/numpad\s*\+/gi, "numadd");
-  return normalized.split("+").map((part) => formatKeyPart(part, osType)).join(" + ");
+  const { trigger, chord, holdMs } = splitShortcutTrigger(combination);
+  const normalized = chord.replace(/numpad\s*\+/gi, "numadd");
+  const keys = normalized
+    .split("+")
+    .map((part) => formatKeyPart(part, osType))
+    .join(" + ");
+  if (trigger === "double") return `${keys} ×2`;
+  if (trigger === "hold") return `${keys} (${holdMs} ms)`;
+  return keys;
+};
+
+/**
+ * rdev-only trigger prefixes: "double:f9" fires on a double tap and
+ * "hold:f9/600" once the key has been held for 600 ms.
+ */
+export type ShortcutTrigger = "press" | "double" | "hold";
+
+export const DEFAULT_HOLD_THRESHOLD_MS = 500;
+
+export const splitShortcutTrigger = (
+  binding: string,
+): { trigger: ShortcutTrigger; chord: string; holdMs: number } => {
+  const trimmed = binding.trim();
+  const lower = trimmed.toLowerCase();
+  if (lower.startsWith("double:")) {
+    return {
+      trigger: "double",
+      chord: trimmed.slice("double:".length),
+      holdMs: DEFAULT_HOLD_THRESHOLD_MS,
+    };
+  }
+  if (lower.startsWith("hold:")) {
+    const rest = trimmed.slice("hold:".length);
+    // "/" is also a key, so only a numeric suffix is the threshold.
+    const match = rest.match(/^(.*)\/(\d+)$/);
+    return match
+      ? { trigger: "hold", chord: match[1], holdMs: Number(match[2]) }
+      : { trigger: "hold", chord: rest, holdMs: DEFAULT_HOLD_THRESHOLD_MS };
+  }
+  return { trigger: "press", chord: trimmed, holdMs: DEFAULT_HOLD_THRESHOLD_MS };
+};
+
+export const applyShortcutTrigger = (
+  chord: string,
+  trigger: ShortcutTrigger,
+  holdMs: number = DEFAULT_HOLD_THRESHOLD_MS,
+): string => {
+  if (!chord || trigger === "press") return chord;
+  if (trigger === "double") return `double:${chord}`;
+  return `hold:${chord}/${holdMs}`;
 };
 
 /**
@@ -7,7 +7,7 @@ const SHORTCUT_ENGINE_COMPATIBILITY_PATTERNS = [
 ];
 const SHORTCUT_ENGINE_TOAST_DURATION_MS = 8000;
 
-type ShortcutEngine = "tauri" | "handy_keys" | "rdev" | string;
+type ShortcutEngine = "tauri" | "handy_keys" | "rdev" | "portal" | string;
 
 const getShortcutEngineName = (engine: ShortcutEngine, t: TFunction) => {
   switch (engine) {
@@ -15,6 +15,8 @@ const getShortcutEngineName = (engine: ShortcutEngine, t: TFunction) => {
       return t("settings.debug.shortcutEngine.shortNames.handyKeys");
     case "rdev":
       return t("settings.debug.shortcutEngine.shortNames.rdev");
+    case "portal":
+      return t("settings.debug.shortcutEngine.shortNames.portal");
     case "tauri":
     default:
       return t("settings.debug.shortcutEngine.shortNames.tauri");
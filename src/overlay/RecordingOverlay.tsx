@@ -47,6 +47,7 @@ import type {
   OverlayErrorCategory,
   OverlayErrorEnvelope,
   OverlayErrorPhase,
+  OverlayRetryAction,
 } from "./plus_overlay_states";
 import {
   formatKeyCombination,
@@ -68,6 +69,10 @@ type RecordingOverlayAppearanceState = RecordingOverlayAppearancePayload & {
 
 const windowRef = getCurrentWindow();
 
+// `recording-level` arrives every ~100 ms; ~2 s below this RMS means a dead mic.
+const SILENT_INPUT_RMS = 0.001;
+const SILENT_INPUT_WINDOWS = 20;
+
 const COMPACT_ERROR_CODE_MAP: Record<string, string> = {
   E_AUTH: "AUTH",
   E_BADREQ: "BAD_REQ",
@@ -144,6 +149,12 @@ type OverlayPhysicalPosition = {
   y: number;
 };
 
+type ProfileSwitchDetails = {
+  language: string;
+  translate_to_english: boolean;
+  post_process_enabled: boolean;
+};
+
 function normalizeOsType(osType: string): OSType {
   return osType === "macos" || osType === "windows" || osType === "linux"
     ? osType
@@ -364,6 +375,27 @@ function getOverlayErrorCopy(
           "Check mic access, selected device, or other apps using it.",
         ),
       };
+    case "MicrophoneDisconnected":
+      return {
+        title: t(
+          "overlay.errors.microphoneDisconnected.title",
+          "Microphone disconnected",
+        ),
+        hint: t(
+          "overlay.errors.microphoneDisconnected.hint",
+          "Recording stopped early. Reconnect the mic or turn on Recover From Disconnects.",
+        ),
+      };
+    case "ModelMissing":
+      return {
+        title: t("overlay.errors.modelMissing.title", "Model file missing"),
+        hint:
+          envelope?.user_message ||
+          t(
+            "overlay.errors.modelMissing.hint",
+            "Download the model again or pick another one in Models.",
+          ),
+      };
     case "Unknown":
     default:
       return {
@@ -403,18 +435,62 @@ function getOverlayErrorTooltip(
   return [technicalMessage, configurationHint].filter(Boolean).join("\n\n");
 }
 
+interface RecordingTimer {
+  elapsedMs: number;
+  remainingMs: number | null;
+  paused: boolean;
+}
+
+/** Formats a duration as `M:SS`, or `H:MM:SS` past an hour. */
+function formatRecordingTime(ms: number): string {
+  const totalSeconds = Math.floor(ms / 1000);
+  const hours = Math.floor(totalSeconds / 3600);
+  const minutes = Math.floor((totalSeconds % 3600) / 60);
+  const seconds = String(totalSeconds % 60).padStart(2, "0");
+  return hours > 0
+    ? `${hours}:${String(minutes).padStart(2, "0")}:${seconds}`
+    : `${minutes}:${seconds}`;
+}
+
 const RecordingOverlay: React.FC = () => {
   const { t } = useTranslation();
   const [isVisible, setIsVisible] = useState(false);
+  const [visualFeedbackKind, setVisualFeedbackKind] = useState<
+    "start" | "stop" | "error" | null
+  >(null);
+  const visualFeedbackTimerRef = useRef<number | null>(null);
   const [state, setState] = useState<ExtendedOverlayState>("recording");
+  const [retryProgress, setRetryProgress] = useState<{
+    attempt: number;
+    max_attempts: number;
+  } | null>(null);
   const [transientMessage, setTransientMessage] = useState<string>("");
+  const [profileSwitchDetails, setProfileSwitchDetails] =
+    useState<ProfileSwitchDetails | null>(null);
   const [decapIndicatorEligible, setDecapIndicatorEligible] = useState(false);
   const [decapIndicatorArmed, setDecapIndicatorArmed] = useState(false);
+  const [privacyModeActive, setPrivacyModeActive] = useState(false);
+  const [boostActive, setBoostActive] = useState(false);
+  const [autoStopPending, setAutoStopPending] = useState(false);
+  const [durationWarning, setDurationWarning] = useState(false);
+  const [recordingTimer, setRecordingTimer] = useState<RecordingTimer | null>(
+    null,
+  );
+  const [extensionOffline, setExtensionOffline] = useState<{
+    fallbackToClipboard: boolean;
+  } | null>(null);
+  const [inputLevelWarning, setInputLevelWarning] = useState<
+    "silent" | "clipping" | null
+  >(null);
+  const silentInputWindowsRef = useRef(0);
   const [errorMessage, setErrorMessage] = useState<string | null>(null);
   const [errorHint, setErrorHint] = useState<string | null>(null);
   const [errorCode, setErrorCode] = useState<string | null>(null);
   const [errorTechnical, setErrorTechnical] = useState<string | null>(null);
   const [errorRetryAvailable, setErrorRetryAvailable] = useState(false);
+  const [errorAction, setErrorAction] = useState<OverlayRetryAction | null>(
+    null,
+  );
   const [repasteShortcutLabel, setRepasteShortcutLabel] = useState<
     string | null
   >(null);
@@ -701,12 +777,17 @@ const RecordingOverlay: React.FC = () => {
         // Sync language from settings each time overlay is shown
         await syncLanguageFromSettings();
 
+        silentInputWindowsRef.current = 0;
+        setInputLevelWarning(null);
+
         const payload = event.payload;
         // Handle both extended payload objects and legacy string payloads
         if (isExtendedPayload(payload)) {
           setState(payload.state);
           setDecapIndicatorEligible(payload.decapitalize_eligible ?? false);
           setDecapIndicatorArmed(payload.decapitalize_armed ?? false);
+          setPrivacyModeActive(payload.privacy_mode ?? false);
+          setBoostActive(payload.boost ?? false);
           if (payload.state === "error") {
             const envelope = payload.error_envelope;
             const copy = getOverlayErrorCopy(
@@ -723,8 +804,16 @@ const RecordingOverlay: React.FC = () => {
             setErrorTechnical(
               getOverlayErrorTooltip(t, payload.error_category, envelope),
             );
-            setErrorRetryAvailable(Boolean(payload.retry_action));
-            if (payload.retry_action) {
+            const isRemoteRetry =
+              payload.retry_action?.command ===
+              "retry_last_remote_transcription";
+            setErrorRetryAvailable(isRemoteRetry);
+            setErrorAction(
+              payload.retry_action && !isRemoteRetry
+                ? payload.retry_action
+                : null,
+            );
+            if (isRemoteRetry) {
               void resolveRepasteShortcutLabel().then(setRepasteShortcutLabel);
             } else {
               setRepasteShortcutLabel(null);
@@ -735,6 +824,7 @@ const RecordingOverlay: React.FC = () => {
             setErrorCode(null);
             setErrorTechnical(null);
             setErrorRetryAvailable(false);
+            setErrorAction(null);
             setRepasteShortcutLabel(null);
           }
         } else {
@@ -747,18 +837,25 @@ const RecordingOverlay: React.FC = () => {
           setErrorCode(null);
           setErrorTechnical(null);
           setErrorRetryAvailable(false);
+          setErrorAction(null);
           setRepasteShortcutLabel(null);
         }
         setIsVisible(true);
       });
 
       const unlistenMessageOverlay = await listen<{
-        state: "profile_switch" | "microphone_switch";
+        state:
+          | "profile_switch"
+          | "microphone_switch"
+          | "low_confidence"
+          | "undo_paste";
         message: string;
+        profile: ProfileSwitchDetails | null;
       }>("show-message-overlay", async (event) => {
         await syncLanguageFromSettings();
 
         setTransientMessage(event.payload.message);
+        setProfileSwitchDetails(event.payload.profile ?? null);
         setState(event.payload.state);
         setDecapIndicatorEligible(false);
         setDecapIndicatorArmed(false);
@@ -767,6 +864,7 @@ const RecordingOverlay: React.FC = () => {
         setErrorCode(null);
         setErrorTechnical(null);
         setErrorRetryAvailable(false);
+        setErrorAction(null);
         setRepasteShortcutLabel(null);
         setIsVisible(true);
       });
@@ -777,7 +875,80 @@ const RecordingOverlay: React.FC = () => {
         setDecapIndicatorEligible(false);
         setDecapIndicatorArmed(false);
         setErrorRetryAvailable(false);
+        setErrorAction(null);
         setRepasteShortcutLabel(null);
+        setAutoStopPending(false);
+        setDurationWarning(false);
+        setRecordingTimer(null);
+        setExtensionOffline(null);
+        silentInputWindowsRef.current = 0;
+        setInputLevelWarning(null);
+      });
+
+      // Remote STT retry after a transient failure
+      const unlistenRemoteRetry = await listen<{
+        attempt: number;
+        max_attempts: number;
+      }>("remote-stt-retrying", async (event) => {
+        await syncLanguageFromSettings();
+
+        setRetryProgress(event.payload);
+        setState("retrying");
+        setErrorMessage(null);
+        setErrorHint(null);
+        setErrorCode(null);
+        setErrorTechnical(null);
+        setErrorRetryAvailable(false);
+        setErrorAction(null);
+        setRepasteShortcutLabel(null);
+        setIsVisible(true);
+      });
+
+      // Soniox endpoint auto-stop: show a hint during the grace window
+      const unlistenAutoStop = await listen<{
+        binding_id: string;
+        state: "pending" | "cancelled" | "stopping";
+        grace_ms: number;
+      }>("soniox-live-auto-stop", (event) => {
+        setAutoStopPending(event.payload.state !== "cancelled");
+      });
+
+      // Max recording duration is close: flash until the recording is finalized
+      const unlistenDurationWarning = await listen(
+        "recording-duration-warning",
+        () => {
+          setDurationWarning(true);
+        },
+      );
+
+      // Elapsed time from Rust; elapsed stays frozen while paused, but the
+      // max-duration countdown keeps running
+      const unlistenRecordingElapsed = await listen<{
+        elapsed_ms: number;
+        remaining_ms: number | null;
+        paused: boolean;
+      }>("recording-elapsed", (event) => {
+        const { elapsed_ms, remaining_ms, paused } = event.payload;
+        setRecordingTimer((previous) => ({
+          elapsedMs: paused && previous ? previous.elapsedMs : elapsed_ms,
+          remainingMs: remaining_ms,
+          paused,
+        }));
+      });
+
+      // Extension dropped out while recording for it
+      const unlistenConnectorStatus = await listen<{
+        online: boolean;
+        extension_recording: boolean;
+        fallback_to_clipboard: boolean;
+      }>("connector-status-changed", (event) => {
+        const { online, extension_recording, fallback_to_clipboard } =
+          event.payload;
+        setExtensionOffline(
+          !online && extension_recording
+            ? { fallbackToClipboard: fallback_to_clipboard }
+            : null,
+        );
       });
 
       // Listen for mic-level updates
@@ -794,11 +965,54 @@ const RecordingOverlay: React.FC = () => {
         setLevels(smoothed);
       });
 
+      // Recording input level: warn about a dead or clipping microphone
+      const unlistenRecordingLevel = await listen<{
+        rms: number;
+        peak: number;
+        clipping: boolean;
+      }>("recording-level", (event) => {
+        const { rms, clipping } = event.payload;
+        silentInputWindowsRef.current =
+          rms < SILENT_INPUT_RMS ? silentInputWindowsRef.current + 1 : 0;
+        if (clipping) {
+          setInputLevelWarning("clipping");
+        } else if (silentInputWindowsRef.current >= SILENT_INPUT_WINDOWS) {
+          setInputLevelWarning("silent");
+        } else {
+          setInputLevelWarning(null);
+        }
+      });
+
+      // Silent start/stop/error cue used when audio feedback is disabled
+      const unlistenVisualFeedback = await listen<{
+        kind: "start" | "stop" | "error";
+      }>("visual-feedback", (event) => {
+        if (visualFeedbackTimerRef.current !== null) {
+          window.clearTimeout(visualFeedbackTimerRef.current);
+        }
+        setVisualFeedbackKind(event.payload.kind);
+        visualFeedbackTimerRef.current = window.setTimeout(() => {
+          setVisualFeedbackKind(null);
+          visualFeedbackTimerRef.current = null;
+        }, 450);
+      });
+
       cleanup = () => {
         unlistenShow();
         unlistenMessageOverlay();
         unlistenHide();
+        unlistenRemoteRetry();
+        unlistenAutoStop();
+        unlistenDurationWarning();
+        unlistenRecordingElapsed();
+        unlistenConnectorStatus();
         unlistenLevel();
+        unlistenRecordingLevel();
+        unlistenVisualFeedback();
+        if (visualFeedbackTimerRef.current !== null) {
+          window.clearTimeout(visualFeedbackTimerRef.current);
+          visualFeedbackTimerRef.current = null;
+        }
       };
     };
 
@@ -815,6 +1029,8 @@ const RecordingOverlay: React.FC = () => {
       decapIndicatorEligible &&
       state !== "profile_switch" &&
       state !== "microphone_switch" &&
+      state !== "low_confidence" &&
+      state !== "undo_paste" &&
       state !== "error";
     if (!shouldPoll) {
       if (!decapIndicatorEligible || !isVisible) {
@@ -906,16 +1122,18 @@ const RecordingOverlay: React.FC = () => {
   const motionStyle = getRecordingOverlayMotionStyle({
     isVisible,
     state:
-      state === "recording" ||
-      state === "sending" ||
-      state === "thinking" ||
-      state === "finalizing" ||
-      state === "transcribing" ||
-      state === "error" ||
-      state === "profile_switch" ||
-      state === "microphone_switch"
-        ? state
-        : "transcribing",
+      state === "retrying"
+        ? "sending"
+        : state === "recording" ||
+            state === "sending" ||
+            state === "thinking" ||
+            state === "finalizing" ||
+            state === "transcribing" ||
+            state === "error" ||
+            state === "profile_switch" ||
+            state === "microphone_switch"
+          ? state
+          : "transcribing",
     levels: visibleLevels,
     audioReactiveScale: appearance.audio_reactive_scale,
     audioReactiveScaleMaxPercent: appearance.audio_reactive_scale_max_percent,
@@ -939,8 +1157,10 @@ const RecordingOverlay: React.FC = () => {
   const getIcon = () => {
     switch (state) {
       case "recording":
+      case "paused":
         return <MicrophoneIcon color={statusIconColor} />;
       case "sending":
+      case "retrying":
         return <SendingIcon color={statusIconColor} />;
       case "thinking":
         return <ThinkingIcon color={statusIconColor} />;
@@ -959,6 +1179,24 @@ const RecordingOverlay: React.FC = () => {
         return <TranscriptionIcon color={statusIconColor} />;
       case "microphone_switch":
         return <MicrophoneIcon color={statusIconColor} />;
+      case "undo_paste":
+        return (
+          <span
+            className="overlay-icon-emoji"
+            style={{ color: statusIconColor }}
+          >
+            ↩️
+          </span>
+        );
+      case "low_confidence":
+        return (
+          <span
+            className="overlay-icon-emoji"
+            style={{ color: statusIconColor }}
+          >
+            ⚠️
+          </span>
+        );
       case "transcribing":
       default:
         return <TranscriptionIcon color={statusIconColor} />;
@@ -970,13 +1208,19 @@ const RecordingOverlay: React.FC = () => {
       ? "is-error"
       : state === "recording"
         ? "is-recording"
-        : state === "sending" || state === "thinking" || state === "finalizing"
+        : state === "sending" ||
+            state === "retrying" ||
+            state === "thinking" ||
+            state === "finalizing"
           ? "is-busy"
           : "is-idle";
   const overlayStateClass =
     state === "recording"
       ? "overlay-state-recording"
-      : state === "sending" || state === "thinking" || state === "finalizing"
+      : state === "sending" ||
+          state === "retrying" ||
+          state === "thinking" ||
+          state === "finalizing"
         ? "overlay-state-busy"
         : state === "error"
           ? "overlay-state-error"
@@ -1034,6 +1278,16 @@ const RecordingOverlay: React.FC = () => {
     });
   };
 
+  const handleErrorAction = () => {
+    if (!errorAction) return;
+    void invoke(errorAction.command).catch((error) => {
+      console.error(
+        `Failed to run overlay action ${errorAction.command}:`,
+        error,
+      );
+    });
+  };
+
   const retryButtonTitle = errorRetryAvailable
     ? [
         repasteShortcutLabel
@@ -1060,7 +1314,7 @@ const RecordingOverlay: React.FC = () => {
 
   return (
     <div
-      className={`recording-overlay ${customOverlayEnabled ? "recording-overlay-custom" : "recording-overlay-legacy"} ${overlayStateClass} ${isVisible ? "fade-in" : ""} ${state === "error" ? "overlay-error" : ""} ${state === "microphone_switch" ? "overlay-microphone-switch" : ""}`}
+      className={`recording-overlay ${customOverlayEnabled ? "recording-overlay-custom" : "recording-overlay-legacy"} ${overlayStateClass} ${isVisible ? "fade-in" : ""} ${state === "error" ? "overlay-error" : ""} ${state === "microphone_switch" ? "overlay-microphone-switch" : ""} ${durationWarning && state === "recording" ? "overlay-duration-warning" : ""} ${visualFeedbackKind ? `visual-feedback-${visualFeedbackKind}` : ""}`}
       style={{
         ...resolvedSurfaceStyle,
         ...(customOverlayEnabled ? motionStyle : {}),
@@ -1124,6 +1378,8 @@ const RecordingOverlay: React.FC = () => {
         appearance.decapitalize_indicator_mode !== "hidden" &&
         state !== "profile_switch" &&
         state !== "microphone_switch" &&
+        state !== "low_confidence" &&
+        state !== "undo_paste" &&
         state !== "error" && (
           <div
             className="overlay-decapitalize-indicator"
@@ -1137,6 +1393,77 @@ const RecordingOverlay: React.FC = () => {
             {decapIndicatorText}
           </div>
         )}
+      {recordingTimer && (state === "recording" || state === "paused") && (
+        <div
+          className={`overlay-timer-indicator ${recordingTimer.paused ? "overlay-timer-paused" : ""}`}
+          title={
+            recordingTimer.remainingMs !== null
+              ? t("overlay.timeRemaining", "Time remaining")
+              : t("overlay.timeElapsed", "Recording time")
+          }
+        >
+          {recordingTimer.remainingMs !== null
+            ? `-${formatRecordingTime(recordingTimer.remainingMs)}`
+            : formatRecordingTime(recordingTimer.elapsedMs)}
+        </div>
+      )}
+      {privacyModeActive &&
+        state !== "profile_switch" &&
+        state !== "microphone_switch" &&
+        state !== "low_confidence" &&
+        state !== "undo_paste" &&
+        state !== "error" && (
+          <div className="overlay-privacy-indicator" title="Privacy mode">
+            🔒
+          </div>
+        )}
+      {boostActive &&
+        state !== "profile_switch" &&
+        state !== "microphone_switch" &&
+        state !== "low_confidence" &&
+        state !== "undo_paste" &&
+        state !== "error" && (
+          <div
+            className="overlay-boost-indicator"
+            title={t("overlay.boost", "Boosted dictation")}
+          >
+            ⚡
+          </div>
+        )}
+      {autoStopPending && state === "recording" && (
+        <div className="overlay-auto-stop-indicator">
+          {t("overlay.autoStopping", "Auto-stopping…")}
+        </div>
+      )}
+      {!autoStopPending && durationWarning && state === "recording" && (
+        <div className="overlay-auto-stop-indicator">
+          {t("overlay.durationLimit", "Time limit soon")}
+        </div>
+      )}
+      {!autoStopPending &&
+        !durationWarning &&
+        extensionOffline &&
+        state === "recording" && (
+          <div className="overlay-auto-stop-indicator">
+            {extensionOffline.fallbackToClipboard
+              ? t(
+                  "overlay.extensionOfflineClipboard",
+                  "Extension offline, text will be copied",
+                )
+              : t("overlay.extensionOffline", "Extension offline")}
+          </div>
+        )}
+      {!autoStopPending &&
+        !durationWarning &&
+        !extensionOffline &&
+        inputLevelWarning &&
+        state === "recording" && (
+          <div className="overlay-auto-stop-indicator">
+            {inputLevelWarning === "clipping"
+              ? t("overlay.inputClipping", "Input clipping")
+              : t("overlay.noInput", "No microphone input")}
+          </div>
+        )}
 
       <div className="overlay-left">
         {showStatusIcon ? (
@@ -1186,6 +1513,19 @@ const RecordingOverlay: React.FC = () => {
             {t("overlay.sending", "Processing speech...")}
           </div>
         )}
+        {state === "retrying" && (
+          <div className="sending-text">
+            {t("overlay.retrying", "Retrying ({{attempt}}/{{max}})...", {
+              attempt: retryProgress?.attempt ?? 2,
+              max: retryProgress?.max_attempts ?? 3,
+            })}
+          </div>
+        )}
+        {state === "paused" && (
+          <div className="transcribing-text">
+            {t("overlay.paused", "Paused")}
+          </div>
+        )}
         {state === "thinking" && (
           <div className="thinking-text">
             {t("overlay.thinking", "Thinking...")}
@@ -1219,9 +1559,35 @@ const RecordingOverlay: React.FC = () => {
             )}
           </div>
         )}
-        {state === "profile_switch" && (
+        {state === "profile_switch" &&
+          (profileSwitchDetails ? (
+            <div className="profile-switch-copy">
+              <span className="profile-switch-name">{transientMessage}</span>
+              <span className="profile-switch-details">
+                {[
+                  profileSwitchDetails.language === "auto"
+                    ? t("overlay.profileSwitch.autoLanguage", "Auto")
+                    : profileSwitchDetails.language.toUpperCase(),
+                  profileSwitchDetails.translate_to_english &&
+                    t("overlay.profileSwitch.translate", "→ EN"),
+                  profileSwitchDetails.post_process_enabled &&
+                    t("overlay.profileSwitch.postProcess", "AI"),
+                ]
+                  .filter(Boolean)
+                  .join(" · ")}
+              </span>
+            </div>
+          ) : (
+            <div className="transcribing-text">{transientMessage}</div>
+          ))}
+        {state === "undo_paste" && (
           <div className="transcribing-text">{transientMessage}</div>
         )}
+        {state === "low_confidence" && (
+          <div className="transcribing-text overlay-low-confidence-text">
+            {transientMessage}
+          </div>
+        )}
         {state === "microphone_switch" && (
           <div className="microphone-switch-copy">
             <span className="microphone-switch-label">
@@ -1233,9 +1599,11 @@ const RecordingOverlay: React.FC = () => {
       </div>
 
       <div className="overlay-right">
-        {/* Show cancel button for: recording, sending, thinking, finalizing */}
+        {/* Show cancel button for: recording, paused, sending, thinking, finalizing */}
         {(state === "recording" ||
+          state === "paused" ||
           state === "sending" ||
+          state === "retrying" ||
           state === "thinking" ||
           state === "finalizing") &&
           appearance.show_cancel_button && (
@@ -1249,7 +1617,16 @@ const RecordingOverlay: React.FC = () => {
               <CancelIcon color={cancelIconColor} />
             </button>
           )}
-        {state === "error" && errorRetryAvailable ? (
+        {state === "error" && errorAction ? (
+          <button
+            type="button"
+            className="error-retry-button"
+            title={errorTechnical || undefined}
+            onClick={handleErrorAction}
+          >
+            {errorAction.label}
+          </button>
+        ) : state === "error" && errorRetryAvailable ? (
           <button
             type="button"
             className="error-retry-button"
@@ -8,13 +8,17 @@
  */
 export type ExtendedOverlayState =
   | "recording"
+  | "paused"
   | "sending"
+  | "retrying"
   | "transcribing"
   | "thinking"
   | "finalizing"
   | "error"
   | "profile_switch"
-  | "microphone_switch";
+  | "microphone_switch"
+  | "low_confidence"
+  | "undo_paste";
 
 /**
  * Error categories matching Rust OverlayErrorCategory enum
@@ -32,6 +36,8 @@ export type OverlayErrorCategory =
   | "ParseError"
   | "ExtensionOffline"
   | "MicrophoneUnavailable"
+  | "MicrophoneDisconnected"
+  | "ModelMissing"
   | "Unknown";
 
 export type OverlayErrorProvider =
@@ -66,6 +72,7 @@ export type OverlayCanonicalErrorCode =
   | "E_PARSE"
   | "E_EXTENSION_OFFLINE"
   | "E_MIC_UNAVAILABLE"
+  | "E_MODEL_MISSING"
   | "E_UNKNOWN";
 
 export interface OverlayErrorEnvelope {
@@ -99,6 +106,8 @@ export interface OverlayPayload {
   retry_action?: OverlayRetryAction;
   decapitalize_eligible?: boolean;
   decapitalize_armed?: boolean;
+  privacy_mode?: boolean;
+  boost?: boolean;
 }
 
 /**
@@ -131,6 +140,8 @@ export function fallbackCodeFromCategory(
     ParseError: "E_PARSE",
     ExtensionOffline: "E_EXT",
     MicrophoneUnavailable: "E_MIC",
+    MicrophoneDisconnected: "E_MIC",
+    ModelMissing: "E_MODEL",
     Unknown: "E_UNKNOWN",
   };
   return map[category];
@@ -367,6 +367,9 @@ export default function SonioxLivePreview() {
   const [workflowState, setWorkflowState] =
     useState<PreviewOutputModeState>(DEFAULT_WORKFLOW_STATE);
   const [isActionBusy, setIsActionBusy] = useState(false);
+  const [editDraft, setEditDraft] = useState<string | null>(null);
+  const editCommitRef = useRef<Promise<void> | null>(null);
+  const editCancelledRef = useRef(false);
   const scrollRef = useRef<HTMLDivElement | null>(null);
   const dragGripStateRef = useRef<{
     armed: boolean;
@@ -797,6 +800,7 @@ export default function SonioxLivePreview() {
   const canFlush = !actionLocked && workflowState.flushVisible && canRunTextActions;
   const canProcess = !actionLocked && canRunTextActions;
   const canInsert = !actionLocked && (hasText || workflowState.recording);
+  const isEditing = editDraft !== null;
   const closeHotkeyLabel = useMemo(
     () => formatPreviewHotkeyForDisplay(appearance.closeHotkey, osType),
     [appearance.closeHotkey, osType],
@@ -890,6 +894,14 @@ export default function SonioxLivePreview() {
     element.scrollTop = element.scrollHeight;
   }, [fullText]);
 
+  useEffect(() => {
+    // Recording resumed mid-edit: the draft is stale, drop it.
+    if (workflowState.recording) {
+      editCancelledRef.current = true;
+      setEditDraft(null);
+    }
+  }, [workflowState.recording]);
+
   useEffect(() => {
     let unlistenMoved: (() => void) | null = null;
     let unlistenResized: (() => void) | null = null;
@@ -1012,6 +1024,8 @@ export default function SonioxLivePreview() {
   const invokePreviewAction = useCallback(async (command: string) => {
     setIsActionBusy(true);
     try {
+      // Let a pending edit reach the backend so insert/process see it.
+      await editCommitRef.current;
       await invoke(command);
     } catch (error) {
       console.error(`Preview command failed: ${command}`, error);
@@ -1024,6 +1038,38 @@ export default function SonioxLivePreview() {
     void invokePreviewAction("preview_close_action");
   };
 
+  const handleStartEdit = () => {
+    // A running recording still owns the text; edit once it has stopped.
+    if (
+      !workflowState.active ||
+      workflowState.recording ||
+      actionLocked ||
+      editDraft !== null
+    ) {
+      return;
+    }
+    editCancelledRef.current = false;
+    setEditDraft(fullText);
+  };
+
+  const handleCommitEdit = () => {
+    if (editDraft === null) {
+      return;
+    }
+    const text = editDraft;
+    setEditDraft(null);
+    if (editCancelledRef.current || text === fullText) {
+      return;
+    }
+    editCommitRef.current = invoke<void>("preview_set_text", { text })
+      .catch((error) => {
+        console.error("Failed to update preview text:", error);
+      })
+      .finally(() => {
+        editCommitRef.current = null;
+      });
+  };
+
   const handleClear = () => {
     void invokePreviewAction("preview_clear_action");
   };
@@ -1133,6 +1179,10 @@ export default function SonioxLivePreview() {
 
   useEffect(() => {
     const handleKeyDown = (event: KeyboardEvent) => {
+      if (isEditing) {
+        return;
+      }
+
       if (
         appearance.ctrlBackspaceDeleteLastWord &&
         canDelete &&
@@ -1263,6 +1313,7 @@ export default function SonioxLivePreview() {
     canInsert,
     canProcess,
     invokePreviewAction,
+    isEditing,
     osType,
   ]);
 
@@ -1303,8 +1354,30 @@ export default function SonioxLivePreview() {
           </button>
         </div>
       )}
-      <div className="soniox-live-preview-body" ref={scrollRef}>
-        {fullText.length === 0 ? (
+      <div
+        className="soniox-live-preview-body"
+        ref={scrollRef}
+        onDoubleClick={handleStartEdit}
+      >
+        {isEditing ? (
+          <textarea
+            className="soniox-live-preview-editor"
+            value={editDraft ?? ""}
+            autoFocus
+            onChange={(event) => setEditDraft(event.target.value)}
+            onBlur={handleCommitEdit}
+            onKeyDown={(event) => {
+              if (event.key === "Escape") {
+                event.preventDefault();
+                editCancelledRef.current = true;
+                setEditDraft(null);
+              } else if (event.key === "Enter" && event.ctrlKey) {
+                event.preventDefault();
+                event.currentTarget.blur();
+              }
+            }}
+          />
+        ) : fullText.length === 0 ? (
           <span className="soniox-live-preview-empty">{emptyStateMessage}</span>
         ) : (
           <>
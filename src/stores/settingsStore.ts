@@ -3,6 +3,7 @@ import { subscribeWithSelector } from "zustand/middleware";
 import type {
   AppSettings as Settings,
   AudioDevice,
+  CustomWord,
   ModelUnloadTimeout,
 } from "@/bindings";
 import { commands } from "@/bindings";
@@ -177,7 +178,7 @@ const settingUpdaters: {
     commands.changeSelectedLanguageSetting(value as string),
   overlay_position: (value) =>
     commands.changeOverlayPositionSetting(value as string),
-  custom_words: (value) => commands.updateCustomWords(value as string[]),
+  custom_words: (value) => commands.updateCustomWords(value as CustomWord[]),
   custom_words_enabled: (value) =>
     commands.changeCustomWordsEnabledSetting(value as boolean),
   word_correction_threshold: (value) =>
@@ -327,6 +328,8 @@ const settingUpdaters: {
 
 (settingUpdaters as any).soniox_live_enabled = (value: any) =>
   invoke("change_soniox_live_enabled_setting", { enabled: value });
+(settingUpdaters as any).transcription_race_mode = (value: any) =>
+  invoke("change_transcription_race_mode_setting", { enabled: value });
 (settingUpdaters as any).show_tray_shortcut_guide = (value: any) =>
   invoke("change_show_tray_shortcut_guide_setting", { enabled: value });
 settingUpdaters.error_overlay_auto_hide_ms = (value) =>
@@ -335,6 +338,12 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   });
 (settingUpdaters as any).error_feedback_enabled = (value: any) =>
   invoke("change_error_feedback_enabled_setting", { enabled: value });
+(settingUpdaters as any).screen_reader_announcements_enabled = (value: any) =>
+  invoke("change_screen_reader_announcements_enabled_setting", {
+    enabled: value,
+  });
+(settingUpdaters as any).screen_reader_verbosity = (value: any) =>
+  invoke("change_screen_reader_verbosity_setting", { verbosity: value });
 (settingUpdaters as any).recording_overlay_enabled = (value: any) =>
   invoke("change_recording_overlay_enabled_setting", {
     enabled: Boolean(value),
@@ -363,10 +372,16 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_soniox_language_identification_setting", { enabled: value });
 (settingUpdaters as any).soniox_enable_speaker_diarization = (value: any) =>
   invoke("change_soniox_speaker_diarization_setting", { enabled: value });
+(settingUpdaters as any).soniox_include_speaker_labels = (value: any) =>
+  invoke("change_soniox_include_speaker_labels_setting", { enabled: value });
 (settingUpdaters as any).soniox_keepalive_interval_seconds = (value: any) =>
   invoke("change_soniox_keepalive_interval_seconds_setting", {
     seconds: value,
   });
+(settingUpdaters as any).soniox_live_reconnect_attempts = (value: any) =>
+  invoke("change_soniox_live_reconnect_attempts_setting", {
+    attempts: value,
+  });
 (settingUpdaters as any).soniox_live_finalize_timeout_ms = (value: any) =>
   invoke("change_soniox_live_finalize_timeout_ms_setting", {
     timeoutMs: value,
@@ -445,6 +460,10 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_file_transcription_chunking_max_minutes_setting", {
     minutes: Number(value),
   });
+(settingUpdaters as any).file_transcription_segment_minutes = (value: any) =>
+  invoke("change_file_transcription_segment_minutes_setting", {
+    minutes: Number(value),
+  });
 (settingUpdaters as any).microphone_input_boost_db = (value: any) =>
   invoke("change_microphone_input_boost_db_setting", {
     db: Number(value),
@@ -453,6 +472,18 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_microphone_noise_cancellation_enabled_setting", {
     enabled: Boolean(value),
   });
+(settingUpdaters as any).auto_recover_input_device = (value: any) =>
+  invoke("change_auto_recover_input_device_setting", {
+    enabled: Boolean(value),
+  });
+(settingUpdaters as any).audio_preprocessing_enabled = (value: any) =>
+  invoke("change_audio_preprocessing_enabled_setting", {
+    enabled: Boolean(value),
+  });
+(settingUpdaters as any).audio_preprocessing_high_pass_hz = (value: any) =>
+  invoke("change_audio_preprocessing_high_pass_hz_setting", {
+    cutoffHz: Math.round(Number(value)),
+  });
 (settingUpdaters as any).recording_auto_stop_timeout_seconds = (value: any) =>
   invoke("change_recording_auto_stop_timeout_seconds_setting", {
     seconds: value,
@@ -463,8 +494,44 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   commands.changeOrtAcceleratorSetting(value as any);
 (settingUpdaters as any).whisper_gpu_device = (value: any) =>
   commands.changeWhisperGpuDevice(Math.trunc(Number(value)));
+(settingUpdaters as any).post_process_fallback_provider_id = (value: any) =>
+  invoke("change_post_process_fallback_provider_setting", {
+    providerId: value || null,
+  });
+(settingUpdaters as any).post_process_fallback_model = (value: any) =>
+  invoke("change_post_process_fallback_model_setting", {
+    model: value || null,
+  });
 (settingUpdaters as any).recording_auto_stop_paste = (value: any) =>
   invoke("change_recording_auto_stop_paste_setting", { paste: value });
+(settingUpdaters as any).auto_stop_on_silence_enabled = (value: any) =>
+  invoke("change_auto_stop_on_silence_enabled_setting", {
+    enabled: Boolean(value),
+  });
+(settingUpdaters as any).auto_stop_silence_seconds = (value: any) =>
+  invoke("change_auto_stop_silence_seconds_setting", {
+    seconds: Math.round(Number(value)),
+  });
+(settingUpdaters as any).ai_replace_confirm_before_paste = (value: any) =>
+  invoke("change_ai_replace_confirm_before_paste_setting", {
+    enabled: Boolean(value),
+  });
+(settingUpdaters as any).ai_replace_confirm_timeout_seconds = (value: any) =>
+  invoke("change_ai_replace_confirm_timeout_seconds_setting", {
+    seconds: Math.round(Number(value)),
+  });
+(settingUpdaters as any).model_keep_loaded_minutes = (value: any) =>
+  invoke("change_model_keep_loaded_minutes_setting", {
+    minutes: Math.round(Number(value)),
+  });
+(settingUpdaters as any).model_preload_on_startup = (value: any) =>
+  invoke("change_model_preload_on_startup_setting", {
+    enabled: Boolean(value),
+  });
+(settingUpdaters as any).max_recording_duration_seconds = (value: any) =>
+  invoke("change_max_recording_duration_seconds_setting", {
+    seconds: Math.round(Number(value)),
+  });
 (settingUpdaters as any).extra_recording_buffer_ms = (value: any) =>
   invoke("change_extra_recording_buffer_setting", {
     valueMs: Math.round(Number(value)),
@@ -479,12 +546,18 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_voice_button_show_aot_toggle_setting", { enabled: value });
 (settingUpdaters as any).voice_button_single_click_close = (value: any) =>
   invoke("change_voice_button_single_click_close_setting", { enabled: value });
+(settingUpdaters as any).voice_button_monitor = (value: any) =>
+  invoke("change_voice_button_monitor_setting", { monitor: value });
 (settingUpdaters as any).soniox_live_preview_enabled = (value: any) =>
   invoke("change_soniox_live_preview_enabled_setting", { enabled: value });
 (settingUpdaters as any).native_streaming_show_interim_longer = (value: any) =>
   invoke("change_native_streaming_show_interim_longer_setting", {
     enabled: value,
   });
+(settingUpdaters as any).local_streaming_preview_enabled = (value: any) =>
+  invoke("change_local_streaming_preview_enabled_setting", {
+    enabled: value,
+  });
 (settingUpdaters as any).local_preview_auto_flush_enabled = (
   value: any,
 ) =>
@@ -671,6 +744,10 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_recording_overlay_show_cancel_button_setting", {
     enabled: Boolean(value),
   });
+(settingUpdaters as any).overlay_show_timer = (value: any) =>
+  invoke("change_overlay_show_timer_setting", {
+    enabled: Boolean(value),
+  });
 (settingUpdaters as any).recording_overlay_bar_count = (value: any) =>
   commands.changeRecordingOverlayBarCountSetting(Number(value));
 (settingUpdaters as any).recording_overlay_width_px = (value: any) =>
@@ -829,6 +906,18 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
 (settingUpdaters as any).voice_commands = (value: any) =>
   invoke("change_voice_commands_setting", { commands: value });
 
+// Local fallback model retry
+(settingUpdaters as any).local_fallback_enabled = (value: any) =>
+  invoke("change_local_fallback_enabled_setting", { enabled: value });
+(settingUpdaters as any).local_fallback_model = (value: any) =>
+  invoke("change_local_fallback_model_setting", { modelId: value });
+(settingUpdaters as any).local_fallback_repetition_threshold = (
+  value: any,
+) =>
+  invoke("change_local_fallback_repetition_threshold_setting", {
+    threshold: value,
+  });
+
 // Transcription Profiles settings
 (settingUpdaters as any).active_profile_id = (value: any) =>
   invoke("set_active_profile", { id: value });
@@ -838,10 +927,28 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   commands.changeDiarizationSpeakerNameProfilesSetting(value);
 (settingUpdaters as any).preview_output_only_enabled = (value: any) =>
   invoke("change_preview_output_only_enabled_setting", { enabled: value });
+(settingUpdaters as any).output_casing = (value: any) =>
+  invoke("change_output_casing_setting", { mode: value });
+(settingUpdaters as any).output_casing_exceptions = (value: any) =>
+  invoke("change_output_casing_exceptions_setting", { words: value });
+(settingUpdaters as any).output_translation_language = (value: any) =>
+  invoke("change_output_translation_language_setting", { language: value });
 
 // Clipboard / Paste settings
 (settingUpdaters as any).convert_lf_to_crlf = (value: any) =>
   invoke("change_convert_lf_to_crlf_setting", { enabled: value });
+(settingUpdaters as any).stuck_modifier_recovery_enabled = (value: any) =>
+  invoke("change_stuck_modifier_recovery_enabled_setting", { enabled: value });
+
+// Boost settings
+(settingUpdaters as any).boost_override = (value: any) =>
+  invoke("change_boost_override_setting", { boost: value });
+(settingUpdaters as any).boost_expiry_secs = (value: any) =>
+  invoke("change_boost_expiry_secs_setting", { seconds: value });
+
+// History settings
+(settingUpdaters as any).history_session_gap_minutes = (value: any) =>
+  invoke("change_history_session_gap_minutes_setting", { minutes: value });
 
 // Text Replacement settings
 (settingUpdaters as any).text_replacements_enabled = (value: any) =>
@@ -901,12 +1008,60 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   commands.changeOutputWhitespaceLeadingModeSetting(value);
 (settingUpdaters as any).output_whitespace_trailing_mode = (value: any) =>
   commands.changeOutputWhitespaceTrailingModeSetting(value);
+(settingUpdaters as any).smart_join_enabled = (value: any) =>
+  invoke("change_smart_join_enabled_setting", { enabled: value });
+(settingUpdaters as any).smart_join_expiry_secs = (value: any) =>
+  invoke("change_smart_join_expiry_setting", { seconds: value });
+(settingUpdaters as any).connector_message_max_age_minutes = (value: any) =>
+  invoke("change_connector_message_max_age_setting", { minutes: value });
+(settingUpdaters as any).connector_heartbeat_interval_seconds = (value: any) =>
+  invoke("change_connector_heartbeat_interval_seconds_setting", {
+    seconds: value,
+  });
+(settingUpdaters as any).connector_heartbeat_grace_seconds = (value: any) =>
+  invoke("change_connector_heartbeat_grace_seconds_setting", { seconds: value });
+(settingUpdaters as any).extension_offline_fallback_to_clipboard = (
+  value: any,
+) =>
+  invoke("change_extension_offline_fallback_to_clipboard_setting", {
+    enabled: value,
+  });
+(settingUpdaters as any).shortcut_double_tap_window_ms = (value: any) =>
+  invoke("change_shortcut_double_tap_window_setting", { windowMs: value });
 (settingUpdaters as any).custom_words_ngram_enabled = (value: any) =>
   invoke("change_custom_words_ngram_enabled_setting", { enabled: value });
+(settingUpdaters as any).post_process_chunk_threshold_chars = (value: any) =>
+  invoke("change_post_process_chunk_threshold_chars_setting", {
+    threshold: value ? Math.round(value as number) : null,
+  });
+(settingUpdaters as any).llm_clipboard_variable_max_chars = (value: any) =>
+  invoke("change_llm_clipboard_variable_max_chars_setting", {
+    maxChars: Math.round(value as number),
+  });
+(settingUpdaters as any).stt_prompt_auto_inject_custom_words = (value: any) =>
+  invoke("change_stt_prompt_auto_inject_custom_words_setting", {
+    enabled: value,
+  });
+(settingUpdaters as any).stt_prompt_custom_words_max_chars = (value: any) =>
+  invoke("change_stt_prompt_custom_words_max_chars_setting", {
+    maxChars: Math.round(value as number),
+  });
 
 // UI State settings
 (settingUpdaters as any).sidebar_pinned = (value: any) =>
   invoke("change_sidebar_pinned_setting", { pinned: value });
+(settingUpdaters as any).remote_stt_retry_attempts = (value: any) =>
+  invoke("change_remote_stt_retry_attempts_setting", {
+    attempts: Math.round(value as number),
+  });
+(settingUpdaters as any).remote_stt_retry_backoff_ms = (value: any) =>
+  invoke("change_remote_stt_retry_backoff_ms_setting", {
+    backoffMs: Math.round(value as number),
+  });
+(settingUpdaters as any).type_characters_delay_ms = (value: any) =>
+  invoke("change_type_characters_delay_ms_setting", {
+    delay: Math.round(value as number),
+  });
 (settingUpdaters as any).sidebar_width = (value: any) =>
   invoke("change_sidebar_width_setting", { width: value });
 (settingUpdaters as any).selected_microphone_auto_switch_enabled = (
@@ -919,6 +1074,30 @@ settingUpdaters.error_overlay_auto_hide_ms = (value) =>
   invoke("change_selected_microphone_name_pattern_setting", {
     pattern: value,
   });
+(settingUpdaters as any).history_timestamps_enabled = (value: any) =>
+  invoke("change_history_timestamps_enabled_setting", { enabled: value });
+(settingUpdaters as any).history_trim_silence_enabled = (value: any) =>
+  invoke("change_history_trim_silence_enabled_setting", { enabled: value });
+(settingUpdaters as any).history_trim_silence_padding_ms = (value: any) =>
+  invoke("change_history_trim_silence_padding_setting", { paddingMs: value });
+(settingUpdaters as any).recording_level_meter_enabled = (value: any) =>
+  invoke("change_recording_level_meter_enabled_setting", { enabled: value });
+(settingUpdaters as any).repaste_last_count = (value: any) =>
+  invoke("change_repaste_last_count_setting", { count: value });
+(settingUpdaters as any).repaste_last_separator = (value: any) =>
+  invoke("change_repaste_last_separator_setting", { separator: value });
+(settingUpdaters as any).repaste_last_include_ai_replace = (value: any) =>
+  invoke("change_repaste_last_include_ai_replace_setting", { enabled: value });
+(settingUpdaters as any).undo_last_paste_timeout_secs = (value: any) =>
+  invoke("change_undo_last_paste_timeout_setting", { seconds: value });
+(settingUpdaters as any).low_confidence_warning_threshold = (value: any) =>
+  invoke("change_low_confidence_warning_threshold_setting", {
+    threshold: value,
+  });
+(settingUpdaters as any).action_api_enabled = (value: any) =>
+  invoke("change_action_api_enabled_setting", { enabled: value });
+(settingUpdaters as any).action_api_port = (value: any) =>
+  invoke("change_action_api_port_setting", { port: value });
 
 export const useSettingsStore = create<SettingsStore>()(
   subscribeWithSelector((set, get) => ({
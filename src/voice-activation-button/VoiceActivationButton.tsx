@@ -3,6 +3,7 @@ import {
   useEffect,
   useRef,
   useState,
+  type CSSProperties,
   type MouseEvent,
   type PointerEvent,
 } from "react";
@@ -28,6 +29,7 @@ export default function VoiceActivationButton() {
   const [showAotToggle, setShowAotToggle] = useState(false);
   const [isSingleClickClose, setIsSingleClickClose] = useState(false);
   const [isBusy, setIsBusy] = useState(false);
+  const [inputLevel, setInputLevel] = useState(0);
   const activeVoicePointerIdRef = useRef<number | null>(null);
   const closeButtonSuppressClickRef = useRef(false);
   const closeButtonDragRef = useRef({
@@ -108,6 +110,12 @@ export default function VoiceActivationButton() {
       void refreshPushToTalk();
       void refreshRecordingState();
     });
+    const unlistenRecordingLevel = listen<{ rms: number }>(
+      "recording-level",
+      (event) => {
+        setInputLevel(Math.min(1, event.payload.rms * 5));
+      },
+    );
     const unlistenSettings = listen<SettingsChangedPayload>(
       "settings-changed",
       (event) => {
@@ -123,6 +131,7 @@ export default function VoiceActivationButton() {
 
     return () => {
       unlistenProfile.then((fn) => fn());
+      unlistenRecordingLevel.then((fn) => fn());
       unlistenSettings.then((fn) => fn());
     };
   }, [
@@ -317,7 +326,14 @@ export default function VoiceActivationButton() {
           onClick={handleToggleModeClick}
           disabled={isBusy}
         >
-          <span className="voice-dot" />
+          <span
+            className="voice-dot"
+            style={
+              {
+                "--voice-level": isRecording ? inputLevel : 0,
+              } as CSSProperties
+            }
+          />
         </button>
         <button
           type="button"